@@ -152,5 +152,132 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     writeln!(f, "    }};")?;
     writeln!(f, "}}")?;
 
+    #[cfg(feature = "borrowed")]
+    variants::generate(enabled_version, k8s_openapi_codegen_common::swagger20::TypeVariant::Borrowed, "borrowed")?;
+
+    #[cfg(feature = "compact")]
+    variants::generate(enabled_version, k8s_openapi_codegen_common::swagger20::TypeVariant::Compact, "compact")?;
+
     Ok(())
 }
+
+/// The borrowed and compact variants of the types are not checked in. Instead they're generated from the `variants.txt` manifest
+/// that the code generator writes for each version, so that only the crates that enable these features pay for them.
+#[cfg(any(feature = "borrowed", feature = "compact"))]
+mod variants {
+    pub(super) fn generate(
+        enabled_version: usize,
+        variant: k8s_openapi_codegen_common::swagger20::TypeVariant,
+        namespace: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut manifest_path: std::path::PathBuf = std::env::var_os("CARGO_MANIFEST_DIR").ok_or("CARGO_MANIFEST_DIR not set")?.into();
+        manifest_path.push("src");
+        manifest_path.push(format!("v1_{enabled_version}"));
+        manifest_path.push("variants.txt");
+        println!("cargo::rerun-if-changed={}", manifest_path.display());
+
+        let manifest = std::fs::read_to_string(&manifest_path)?;
+        let mut definitions = k8s_openapi_codegen_common::variants::read_manifest(&manifest)?;
+        k8s_openapi_codegen_common::variants::add_variants(&mut definitions, variant, &MapNamespace)?;
+
+        let definition_path_prefix = format!("io.k8s.{namespace}.");
+
+        let mut root: Module = Default::default();
+
+        for definition_path in definitions.keys() {
+            if !definition_path.starts_with(&definition_path_prefix) {
+                continue;
+            }
+
+            let run_state = RunState {
+                root: &mut root,
+                parts: vec![],
+            };
+
+            _ = k8s_openapi_codegen_common::run(
+                &definitions,
+                &mut vec![],
+                definition_path,
+                &MapNamespace,
+                "pub ",
+                k8s_openapi_codegen_common::GenerateSchema::Yes { feature: Some("schemars") },
+                k8s_openapi_codegen_common::PreserveUnknownFields::No,
+                run_state,
+            )?;
+        }
+
+        let mut out_file: std::path::PathBuf = std::env::var_os("OUT_DIR").ok_or("OUT_DIR not set")?.into();
+        out_file.push(format!("{namespace}.rs"));
+        let mut f = std::io::BufWriter::new(std::fs::File::create(out_file)?);
+        root.write(&mut f)?;
+        f.flush()?;
+
+        Ok(())
+    }
+
+    struct MapNamespace;
+
+    impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
+        fn map_namespace<'a>(&self, path_parts: &[&'a str]) -> Option<Vec<&'a str>> {
+            match path_parts {
+                ["io", "k8s", rest @ ..] => Some(std::iter::once("crate").chain(rest.iter().copied()).collect()),
+                _ => None,
+            }
+        }
+    }
+
+    /// The generated code of the types of a module and its submodules.
+    #[derive(Default)]
+    struct Module {
+        types: std::collections::BTreeMap<String, Vec<u8>>,
+        modules: std::collections::BTreeMap<String, Module>,
+    }
+
+    impl Module {
+        fn write(&self, f: &mut impl std::io::Write) -> std::io::Result<()> {
+            for (type_name, code) in &self.types {
+                let mod_name = k8s_openapi_codegen_common::get_rust_ident(type_name);
+                writeln!(f, "mod {mod_name} {{")?;
+                f.write_all(code)?;
+                writeln!(f, "}}")?;
+                writeln!(f, "pub use self::{mod_name}::{type_name};")?;
+                writeln!(f)?;
+            }
+
+            for (mod_name, module) in &self.modules {
+                writeln!(f, "pub mod {mod_name} {{")?;
+                module.write(f)?;
+                writeln!(f, "}}")?;
+                writeln!(f)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    struct RunState<'a> {
+        root: &'a mut Module,
+        parts: Vec<String>,
+    }
+
+    impl k8s_openapi_codegen_common::RunState for RunState<'_> {
+        type Writer = Vec<u8>;
+
+        fn make_writer(&mut self, parts: &[&str]) -> std::io::Result<Self::Writer> {
+            // Skip the `crate` and `borrowed` / `compact` components, since the generated code is included in the module of the same name.
+            self.parts = parts.iter().skip(2).map(|&part| part.to_owned()).collect();
+            Ok(vec![])
+        }
+
+        fn finish(&mut self, writer: Self::Writer) {
+            let (type_name, mod_names) = self.parts.split_last().expect("type path is empty");
+            let module = mod_names.iter().fold(&mut *self.root, |module, mod_name| {
+                let mod_name = k8s_openapi_codegen_common::get_rust_ident(mod_name).into_owned();
+                module.modules.entry(mod_name).or_default()
+            });
+            module.types.insert(type_name.clone(), writer);
+        }
+    }
+}
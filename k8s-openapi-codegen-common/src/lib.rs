@@ -17,11 +17,18 @@
 //!
 //! 1. Create a [`swagger20::Spec`] value, either by deserializing it from an OpenAPI spec JSON file or by creating it manually.
 //! 1. Invoke the [`run`] function for each definition in the spec.
+//! 1. Optionally, invoke the [`generate_visitors`] function to generate visitor traits for all the definitions.
+//! 1. Optionally, invoke the [`generate_api_resources`] function with all the operations before they're passed to [`run`],
+//!    to generate descriptors of the resources with their verbs and subresources, and the relationships between subresources and their parents.
+//!
+//! The borrowed and compact variants of the types can be generated separately from a manifest of the spec's definitions. See the [`variants`] module.
 
 pub mod swagger20;
 
 mod templates;
 
+pub mod variants;
+
 /// Statistics from a successful invocation of [`run`]
 #[derive(Clone, Copy, Debug)]
 pub struct RunResult {
@@ -119,6 +126,16 @@ pub enum GenerateSchema<'a> {
     No,
 }
 
+/// Whether [`run`] should generate structs that keep the fields that their `Deserialize` impl does not recognize, and emit them again in their `Serialize` impl.
+///
+/// The fields are kept in an `unknown_fields` field of every struct. Since adding a field to a struct breaks code that constructs it with a struct literal,
+/// this is a choice of the code generator rather than something a crate feature can turn on.
+#[derive(Clone, Copy, Debug)]
+pub enum PreserveUnknownFields {
+    Yes,
+    No,
+}
+
 /// Each invocation of this function generates a single type specified by the `definition_path` parameter.
 ///
 /// # Parameters
@@ -134,6 +151,8 @@ pub enum GenerateSchema<'a> {
 ///
 /// - `vis`: The visibility modifier that should be emitted on the generated code.
 ///
+/// - `preserve_unknown_fields`: See the documentation of the [`PreserveUnknownFields`] enum.
+///
 /// - `state`: See the documentation of the [`RunState`] trait.
 pub fn run(
     definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
@@ -142,6 +161,7 @@ pub fn run(
     map_namespace: &impl MapNamespace,
     vis: &str,
     generate_schema: GenerateSchema<'_>,
+    preserve_unknown_fields: PreserveUnknownFields,
     mut state: impl RunState,
 ) -> Result<RunResult, Error> {
     let definition = definitions.get(definition_path).ok_or_else(|| format!("definition for {definition_path} does not exist in spec"))?;
@@ -175,121 +195,10 @@ pub fn run(
 
     match &definition.kind {
         swagger20::SchemaKind::Properties(properties) => {
-            let (template_properties, resource_metadata, metadata_ty) = {
-                let mut result = Vec::with_capacity(properties.len());
-
-                let mut single_group_version_kind = match &definition.kubernetes_group_kind_versions[..] {
-                    [group_version_kind] => Some((group_version_kind, false, false)),
-                    _ => None,
-                };
-
-                let mut metadata_ty = None;
-
-                for (name, (schema, required)) in properties {
-                    if name.0 == "apiVersion" {
-                        if let Some((_, has_api_version, _)) = &mut single_group_version_kind {
-                            *has_api_version = true;
-                            continue;
-                        }
-                    }
-
-                    if name.0 == "kind" {
-                        if let Some((_, _, has_kind)) = &mut single_group_version_kind {
-                            *has_kind = true;
-                            continue;
-                        }
-                    }
-
-                    let field_name = get_rust_ident(name);
-
-                    let mut field_type_name = String::new();
-
-                    let required = match required {
-                        true => templates::PropertyRequired::Required {
-                            is_default: is_default(&schema.kind, definitions, map_namespace)?,
-                        },
-                        false => templates::PropertyRequired::Optional,
-                    };
-
-                    if let templates::PropertyRequired::Optional = required {
-                        field_type_name.push_str("Option<");
-                    }
-
-                    let type_name = get_rust_type(&schema.kind, map_namespace)?;
-
-                    if name.0 == "metadata" {
-                        metadata_ty = Some((type_name.clone(), required));
-                    }
-
-                    // Fix cases of infinite recursion
-                    if let swagger20::SchemaKind::Ref(swagger20::RefPath { path, .. }) = &schema.kind {
-                        match (&**definition_path, &**name, &**path) {
-                            (
-                                "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps",
-                                "not",
-                                "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps",
-                            ) |
-                            (
-                                "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.JSONSchemaProps",
-                                "not",
-                                "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.JSONSchemaProps",
-                            ) => {
-                                field_type_name.push_str("std::boxed::Box<");
-                                field_type_name.push_str(&type_name);
-                                field_type_name.push('>');
-                            },
-
-                            _ => field_type_name.push_str(&type_name),
-                        }
-                    }
-                    else {
-                        field_type_name.push_str(&type_name);
-                    }
-
-                    if let templates::PropertyRequired::Optional = required {
-                        field_type_name.push('>');
-                    }
-
-                    let is_flattened = matches!(&schema.kind, swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(_)));
-
-                    result.push(templates::Property {
-                        name,
-                        comment: schema.description.as_deref(),
-                        field_name,
-                        field_type_name,
-                        required,
-                        is_flattened,
-                        merge_type: &schema.merge_type,
-                    });
-                }
+            let (template_properties, resource_metadata, metadata_ty) =
+                get_template_properties(definition_path, definition, properties, definitions, map_namespace)?;
 
-                let resource_metadata = match single_group_version_kind {
-                    Some((single_group_version_kind, true, true)) =>
-                        Some(if single_group_version_kind.group.is_empty() {
-                            (
-                                format!("{:?}", single_group_version_kind.version),
-                                format!("{:?}", ""),
-                                format!("{:?}", single_group_version_kind.kind),
-                                format!("{:?}", single_group_version_kind.version),
-                                definition.list_kind.as_ref().map(|kind| format!("{kind:?}")),
-                            )
-                        }
-                        else {
-                            (
-                                format!("{:?}", format!("{}/{}", single_group_version_kind.group, single_group_version_kind.version)),
-                                format!("{:?}", single_group_version_kind.group),
-                                format!("{:?}", single_group_version_kind.kind),
-                                format!("{:?}", single_group_version_kind.version),
-                                definition.list_kind.as_ref().map(|kind| format!("{kind:?}")),
-                            )
-                        }),
-                    Some((_, true, false)) => return Err(format!("{definition_path} has an apiVersion property but not a kind property").into()),
-                    Some((_, false, true)) => return Err(format!("{definition_path} has a kind property but not an apiVersion property").into()),
-                    Some((_, false, false)) | None => None,
-                };
-
-                (result, resource_metadata, metadata_ty)
-            };
+            let unknown_fields = get_unknown_fields(preserve_unknown_fields, &template_properties);
 
             templates::r#struct::generate(
                 &mut out,
@@ -297,6 +206,8 @@ pub fn run(
                 type_name,
                 Default::default(),
                 &template_properties,
+                unknown_fields,
+                map_namespace,
             )?;
 
             let mut namespace_or_cluster_scoped_url_path_segment_and_scope = vec![];
@@ -329,49 +240,27 @@ pub fn run(
                                 ) => (),
                                 _ => continue,
                             }
-                            let mut components = operation.path.rsplit('/');
-                            let components = (
-                                components.next().expect("str::rsplit returns at least one component"),
-                                components.next(),
-                                components.next(),
-                                components.next(),
-                            );
-
-                            let (url_path_segment_, scope_, url_path_segment_and_scope) = match components {
-                                ("{name}", Some(url_path_segment), Some("{namespace}"), Some("namespaces")) =>
+                            let (url_path_segment_, scope_, url_path_segment_and_scope) = match get_operation_path_scope(&operation.path) {
+                                OperationPathScope::Namespace(url_path_segment) =>
                                     (
                                         format!("{url_path_segment:?}"),
                                         format!("{local}NamespaceResourceScope"),
                                         &mut namespace_or_cluster_scoped_url_path_segment_and_scope,
                                     ),
 
-                                ("{name}", Some(url_path_segment), _, _) =>
+                                OperationPathScope::Cluster(url_path_segment) =>
                                     (
                                         format!("{url_path_segment:?}"),
                                         format!("{local}ClusterResourceScope"),
                                         &mut namespace_or_cluster_scoped_url_path_segment_and_scope,
                                     ),
 
-                                (url_path_segment, Some("{name}"), _, _) =>
+                                OperationPathScope::SubResource(url_path_segment) =>
                                     (
                                         format!("{url_path_segment:?}"),
                                         format!("{local}SubResourceScope"),
                                         &mut subresource_url_path_segment_and_scope,
                                     ),
-
-                                (url_path_segment, Some("{namespace}"), Some("namespaces"), _) =>
-                                    (
-                                        format!("{url_path_segment:?}"),
-                                        format!("{local}NamespaceResourceScope"),
-                                        &mut namespace_or_cluster_scoped_url_path_segment_and_scope,
-                                    ),
-
-                                (url_path_segment, _, _, _) =>
-                                    (
-                                        format!("{url_path_segment:?}"),
-                                        format!("{local}ClusterResourceScope"),
-                                        &mut namespace_or_cluster_scoped_url_path_segment_and_scope,
-                                    ),
                             };
 
                             url_path_segment_and_scope.push((url_path_segment_, scope_));
@@ -481,15 +370,98 @@ pub fn run(
                     type_name,
                     Default::default(),
                     &template_properties,
+                    unknown_fields,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_defaulting {
+                templates::struct_defaulting::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    &template_properties,
+                    Some(properties),
+                    definition.defaulting_function.as_deref(),
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_diff {
+                templates::struct_diff::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    &template_properties,
+                    unknown_fields,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_field_paths {
+                templates::struct_field_paths::generate(
+                    &mut out,
+                    vis,
+                    type_name,
+                    Default::default(),
+                    &template_properties,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_normalize {
+                let object_meta_rust_type = get_rust_type(
+                    &swagger20::SchemaKind::Ref(swagger20::RefPath {
+                        path: "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned(),
+                        can_be_default: None,
+                    }),
+                    map_namespace,
+                )?;
+
+                let server_populated_fields =
+                    if &**definition_path == "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" {
+                        templates::struct_normalize::ServerPopulatedFields::ObjectMeta
+                    }
+                    else if template_resource_metadata.as_ref().and_then(|resource_metadata| resource_metadata.metadata_ty) == Some(&*object_meta_rust_type) {
+                        templates::struct_normalize::ServerPopulatedFields::Resource
+                    }
+                    else {
+                        templates::struct_normalize::ServerPopulatedFields::None
+                    };
+
+                templates::struct_normalize::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    &template_properties,
+                    unknown_fields,
+                    server_populated_fields,
                     map_namespace,
                 )?;
             }
 
+            match &*definition.extensions.kubernetes_unions {
+                [] => (),
+
+                [union] => templates::union::generate(
+                    &mut out,
+                    type_name,
+                    derives,
+                    vis,
+                    union,
+                    &template_properties,
+                    map_namespace,
+                )?,
+
+                [_, _, ..] => return Err(format!("definition {definition_path} has more than one union").into()),
+            }
+
             templates::impl_deserialize::generate(
                 &mut out,
                 type_name,
                 Default::default(),
                 &template_properties,
+                unknown_fields,
                 map_namespace,
                 template_resource_metadata.as_ref(),
             )?;
@@ -499,10 +471,43 @@ pub fn run(
                 type_name,
                 Default::default(),
                 &template_properties,
+                unknown_fields,
                 map_namespace,
                 template_resource_metadata.as_ref(),
             )?;
 
+            if is_protobuf_message(definition_path, definitions, map_namespace) {
+                templates::impl_protobuf_message::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    templates::impl_protobuf_message::Message::Struct(&template_properties, unknown_fields),
+                    map_namespace,
+                )?;
+            }
+
+            // The `preserve-unknown-fields` feature that the impl is gated by is a feature of the `k8s-openapi` crate.
+            if local == "crate::" {
+                let fields =
+                    template_properties.iter()
+                    .filter(|property| !property.is_flattened)
+                    .map(|property| templates::impl_list_map_keys::Field {
+                        name: property.name,
+                        rust_type: &property.field_type_name,
+                        merge_type: property.merge_type,
+                        contains_list_maps: contains_list_maps(&properties[property.name].0.kind, definitions),
+                    })
+                    .collect();
+
+                templates::impl_list_map_keys::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    templates::impl_list_map_keys::ListMapKeys::Fields(fields),
+                    map_namespace,
+                )?;
+            }
+
             run_result.num_generated_structs += 1;
         },
 
@@ -515,6 +520,14 @@ pub fn run(
                 map_namespace,
             )?;
 
+            templates::impl_protobuf_message::generate(
+                &mut out,
+                type_name,
+                Default::default(),
+                templates::impl_protobuf_message::Message::IntOrString,
+                map_namespace,
+            )?;
+
             run_result.num_generated_structs += 1;
         },
 
@@ -541,6 +554,19 @@ pub fn run(
                 map_namespace,
             )?;
 
+            if is_protobuf_message(definition_path, definitions, map_namespace) {
+                templates::impl_protobuf_message::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    templates::impl_protobuf_message::Message::JsonSchemaPropsOr {
+                        or: json_schema_props_or,
+                        json_schema_props_type_name: &json_schema_props_type_name,
+                    },
+                    map_namespace,
+                )?;
+            }
+
             run_result.num_generated_structs += 1;
         },
 
@@ -551,6 +577,14 @@ pub fn run(
                 map_namespace,
             )?;
 
+            templates::impl_protobuf_message::generate(
+                &mut out,
+                type_name,
+                Default::default(),
+                templates::impl_protobuf_message::Message::Quantity,
+                map_namespace,
+            )?;
+
             run_result.num_generated_structs += 1;
         },
 
@@ -586,6 +620,34 @@ pub fn run(
                 map_namespace,
             )?;
 
+            if is_protobuf_message(definition_path, definitions, map_namespace) {
+                let object_meta_rust_type = get_rust_type(
+                    &swagger20::SchemaKind::Ref(swagger20::RefPath {
+                        path: "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned(),
+                        can_be_default: None,
+                    }),
+                    map_namespace,
+                )?;
+
+                let template_generics_where_part = format!("T: {local}Resource + {local}protobuf::Message");
+                let template_generics = templates::Generics {
+                    type_part: Some("T"),
+                    where_part: Some(&template_generics_where_part),
+                };
+
+                templates::impl_protobuf_message::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    templates::impl_protobuf_message::Message::WatchEvent {
+                        error_status_rust_type: &error_status_rust_type,
+                        error_other_rust_type: &error_other_rust_type,
+                        object_meta_rust_type: &object_meta_rust_type,
+                    },
+                    map_namespace,
+                )?;
+            }
+
             run_result.num_generated_structs += 1;
         },
 
@@ -613,6 +675,7 @@ pub fn run(
                     required: templates::PropertyRequired::Required { is_default: true },
                     is_flattened: false,
                     merge_type: &items_merge_type,
+                    protobuf_field_number: Some(swagger20::ProtobufFieldNumber::Field(2)),
                 },
 
                 templates::Property {
@@ -623,9 +686,12 @@ pub fn run(
                     required: templates::PropertyRequired::Required { is_default: true },
                     is_flattened: false,
                     merge_type: &swagger20::MergeType::Default,
+                    protobuf_field_number: Some(swagger20::ProtobufFieldNumber::Field(1)),
                 },
             ];
 
+            let unknown_fields = get_unknown_fields(preserve_unknown_fields, &template_properties);
+
             let template_resource_metadata = templates::ResourceMetadata {
                 api_version: "<T as crate::Resource>::API_VERSION",
                 group: "<T as crate::Resource>::GROUP",
@@ -642,6 +708,8 @@ pub fn run(
                 type_name,
                 template_generics,
                 &template_properties,
+                unknown_fields,
+                map_namespace,
             )?;
 
             templates::impl_resource::generate(
@@ -680,6 +748,71 @@ pub fn run(
                     type_name,
                     template_generics,
                     &template_properties,
+                    unknown_fields,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_defaulting {
+                let template_generics_where_part = format!("T: {local}Defaulting + {local}Metadata<Ty = {local}apimachinery::pkg::apis::meta::v1::ObjectMeta> + {local}ListableResource");
+                let template_generics = templates::Generics {
+                    where_part: Some(&template_generics_where_part),
+                    ..template_generics
+                };
+
+                templates::struct_defaulting::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    &template_properties,
+                    None,
+                    definition.defaulting_function.as_deref(),
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_diff {
+                let template_generics_where_part = format!("T: {local}diff::Diff + {local}serde::Serialize + {local}Metadata<Ty = {local}apimachinery::pkg::apis::meta::v1::ObjectMeta> + {local}ListableResource");
+                let template_generics = templates::Generics {
+                    where_part: Some(&template_generics_where_part),
+                    ..template_generics
+                };
+
+                templates::struct_diff::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    &template_properties,
+                    unknown_fields,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_field_paths {
+                templates::struct_field_paths::generate(
+                    &mut out,
+                    vis,
+                    type_name,
+                    template_generics,
+                    &template_properties,
+                    map_namespace,
+                )?;
+            }
+
+            if definition.impl_normalize {
+                let template_generics_where_part = format!("T: {local}Normalize + {local}Metadata<Ty = {local}apimachinery::pkg::apis::meta::v1::ObjectMeta> + {local}ListableResource");
+                let template_generics = templates::Generics {
+                    where_part: Some(&template_generics_where_part),
+                    ..template_generics
+                };
+
+                templates::struct_normalize::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    &template_properties,
+                    unknown_fields,
+                    templates::struct_normalize::ServerPopulatedFields::List,
                     map_namespace,
                 )?;
             }
@@ -696,6 +829,7 @@ pub fn run(
                     type_name,
                     template_generics,
                     &template_properties,
+                    unknown_fields,
                     map_namespace,
                     Some(&template_resource_metadata),
                 )?;
@@ -713,42 +847,294 @@ pub fn run(
                     type_name,
                     template_generics,
                     &template_properties,
+                    unknown_fields,
                     map_namespace,
                     Some(&template_resource_metadata),
                 )?;
             }
 
+            if is_protobuf_message(definition_path, definitions, map_namespace) {
+                let template_generics_where_part = format!("T: {local}protobuf::Message + {local}ListableResource");
+                let template_generics = templates::Generics {
+                    where_part: Some(&template_generics_where_part),
+                    ..template_generics
+                };
+
+                templates::impl_protobuf_message::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    templates::impl_protobuf_message::Message::Struct(&template_properties, unknown_fields),
+                    map_namespace,
+                )?;
+            }
+
+            if local == "crate::" {
+                let template_generics_where_part = format!("T: {local}unknown_fields::ListMapKeys + {local}ListableResource");
+                let template_generics = templates::Generics {
+                    where_part: Some(&template_generics_where_part),
+                    ..template_generics
+                };
+
+                let fields =
+                    template_properties.iter()
+                    .map(|property| templates::impl_list_map_keys::Field {
+                        name: property.name,
+                        rust_type: &property.field_type_name,
+                        merge_type: property.merge_type,
+                        contains_list_maps: property.name == "items",
+                    })
+                    .collect();
+
+                templates::impl_list_map_keys::generate(
+                    &mut out,
+                    type_name,
+                    template_generics,
+                    templates::impl_list_map_keys::ListMapKeys::Fields(fields),
+                    map_namespace,
+                )?;
+            }
+
             run_result.num_generated_structs += 1;
         },
 
-        swagger20::SchemaKind::Ty(swagger20::Type::ListRef { .. }) => return Err(format!("definition {definition_path} is a ListRef").into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::AnyResource { resources }) => {
+            let mut template_resources = Vec::with_capacity(resources.len());
 
-        swagger20::SchemaKind::Ty(_) => {
-            let inner_type_name = get_rust_type(&definition.kind, map_namespace)?;
+            for ref_path in resources {
+                let resource_definition =
+                    definitions.get(&*ref_path.path)
+                    .ok_or_else(|| format!("definition {definition_path} refers to {} which does not exist in spec", ref_path.path))?;
 
-            // Kubernetes requires MicroTime to be serialized with exactly six decimal digits, instead of the default serde serialization of `jiff::Timestamp`
-            // that uses a variable number up to nine.
-            //
-            // Furthermore, while Kubernetes does deserialize a Time from a string with one or more decimal digits,
-            // the format string it uses to *serialize* datetimes does not contain any decimal digits. So match that behavior just to be safe, and to have
-            // the same behavior as the golang client.
-            //
-            // Refs:
-            // - https://github.com/Arnavion/k8s-openapi/issues/63
-            // - https://github.com/deislabs/krustlet/issues/5
-            // - https://github.com/kubernetes/apimachinery/issues/88
-            let datetime_serialization_format = match (&**definition_path, &definition.kind) {
-                (
-                    "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime",
-                    swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
-                ) => templates::DateTimeSerializationFormat::SixDecimalDigits,
+                let [kubernetes_group_kind_version] = &resource_definition.kubernetes_group_kind_versions[..] else {
+                    return Err(format!(
+                        "definition {definition_path} refers to {} which does not have a single group-version-kind", ref_path.path).into());
+                };
 
-                (
-                    "io.k8s.apimachinery.pkg.apis.meta.v1.Time",
-                    swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
-                ) => templates::DateTimeSerializationFormat::ZeroDecimalDigits,
+                let api_version =
+                    if kubernetes_group_kind_version.group.is_empty() {
+                        kubernetes_group_kind_version.version.clone()
+                    }
+                    else {
+                        format!("{}/{}", kubernetes_group_kind_version.group, kubernetes_group_kind_version.version)
+                    };
 
-                _ => templates::DateTimeSerializationFormat::Default,
+                let variant_name = get_any_resource_variant_name(ref_path, map_namespace)?;
+
+                template_resources.push(templates::any_resource::Resource {
+                    variant_name,
+                    rust_type: get_rust_type(&swagger20::SchemaKind::Ref(ref_path.clone()), map_namespace)?,
+                    api_version,
+                    kind: &kubernetes_group_kind_version.kind,
+                });
+            }
+
+            let object_meta_rust_type = get_rust_type(
+                &swagger20::SchemaKind::Ref(swagger20::RefPath {
+                    path: "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta".to_owned(),
+                    can_be_default: None,
+                }),
+                map_namespace,
+            )?;
+
+            templates::any_resource::generate(
+                &mut out,
+                type_name,
+                &template_resources,
+                &object_meta_rust_type,
+                map_namespace,
+            )?;
+
+            run_result.num_generated_structs += 1;
+        },
+
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantDef { variant, owned, properties }) => {
+            let (template_properties, resource_metadata, metadata_ty) =
+                get_template_properties(definition_path, definition, properties, definitions, map_namespace)?;
+
+            let generics = match variant {
+                swagger20::TypeVariant::Borrowed => templates::Generics {
+                    type_part: Some("'a"),
+                    where_part: None,
+                },
+                swagger20::TypeVariant::Compact => Default::default(),
+            };
+
+            // Borrowed variants are only deserialized, so there is nothing to emit the unknown fields to.
+            let unknown_fields = match variant {
+                swagger20::TypeVariant::Borrowed => templates::UnknownFields::Ignore,
+                swagger20::TypeVariant::Compact => get_unknown_fields(preserve_unknown_fields, &template_properties),
+            };
+
+            templates::r#struct::generate(
+                &mut out,
+                vis,
+                type_name,
+                generics,
+                &template_properties,
+                unknown_fields,
+                map_namespace,
+            )?;
+
+            // The variant has the same URL path segment and scope as the owned type.
+            let owned_rust_type = get_rust_type(&swagger20::SchemaKind::Ref(owned.clone()), map_namespace)?;
+            let url_path_segment = format!("<{owned_rust_type} as {local}Resource>::URL_PATH_SEGMENT");
+            let scope = format!("<{owned_rust_type} as {local}Resource>::Scope");
+
+            let template_resource_metadata = match (&resource_metadata, &metadata_ty) {
+                (Some((api_version, group, kind, version, list_kind)), metadata_ty) => Some(templates::ResourceMetadata {
+                    api_version,
+                    group,
+                    kind,
+                    version,
+                    list_kind: list_kind.as_deref(),
+                    metadata_ty: metadata_ty.as_ref().map(|(metadata_ty, _)| &**metadata_ty),
+                    url_path_segment_and_scope: (&url_path_segment, &scope),
+                }),
+
+                (None, _) => None,
+            };
+
+            if let Some(template_resource_metadata) = &template_resource_metadata {
+                let elided_generics = match variant {
+                    swagger20::TypeVariant::Borrowed => templates::Generics {
+                        type_part: Some("'_"),
+                        where_part: None,
+                    },
+                    swagger20::TypeVariant::Compact => Default::default(),
+                };
+
+                templates::impl_resource::generate(
+                    &mut out,
+                    type_name,
+                    elided_generics,
+                    map_namespace,
+                    template_resource_metadata,
+                )?;
+
+                templates::impl_listable_resource::generate(
+                    &mut out,
+                    type_name,
+                    elided_generics,
+                    map_namespace,
+                    template_resource_metadata,
+                )?;
+
+                templates::impl_metadata::generate(
+                    &mut out,
+                    type_name,
+                    generics,
+                    map_namespace,
+                    template_resource_metadata,
+                )?;
+            }
+
+            match variant {
+                swagger20::TypeVariant::Borrowed => {
+                    templates::impl_deserialize::generate(
+                        &mut out,
+                        type_name,
+                        templates::Generics {
+                            type_part: Some("'a"),
+                            where_part: Some("'de: 'a"),
+                        },
+                        &template_properties,
+                        unknown_fields,
+                        map_namespace,
+                        template_resource_metadata.as_ref(),
+                    )?;
+                },
+
+                swagger20::TypeVariant::Compact => {
+                    templates::impl_deserialize::generate(
+                        &mut out,
+                        type_name,
+                        Default::default(),
+                        &template_properties,
+                        unknown_fields,
+                        map_namespace,
+                        template_resource_metadata.as_ref(),
+                    )?;
+
+                    templates::impl_serialize::generate(
+                        &mut out,
+                        type_name,
+                        Default::default(),
+                        &template_properties,
+                        unknown_fields,
+                        map_namespace,
+                        template_resource_metadata.as_ref(),
+                    )?;
+
+                    let mut conversions = Vec::with_capacity(template_properties.len());
+                    for templates::Property { name, field_name, field_type_name, required, .. } in &template_properties {
+                        let (schema, _) = &properties[*name];
+                        let is_boxed = field_type_name.contains("std::boxed::Box<");
+                        let conversion = match required {
+                            templates::PropertyRequired::Required { .. } =>
+                                get_variant_conversion(&schema.kind, &format!("value.{field_name}"), is_boxed)
+                                .unwrap_or_else(|| format!("value.{field_name}")),
+
+                            templates::PropertyRequired::Optional |
+                            templates::PropertyRequired::OptionalDefault => match get_variant_conversion(&schema.kind, "value", is_boxed) {
+                                Some(conversion) if conversion == "value.into()" => format!("value.{field_name}.map(Into::into)"),
+                                Some(conversion) => format!("value.{field_name}.map(|value| {conversion})"),
+                                None => format!("value.{field_name}"),
+                            },
+                        };
+                        conversions.push((&**field_name, conversion));
+                    }
+
+                    templates::impl_from_variant::generate(
+                        &mut out,
+                        type_name,
+                        &owned_rust_type,
+                        &conversions,
+                        unknown_fields,
+                    )?;
+
+                    templates::impl_list_map_keys::generate(
+                        &mut out,
+                        type_name,
+                        Default::default(),
+                        templates::impl_list_map_keys::ListMapKeys::Owned(&owned_rust_type),
+                        map_namespace,
+                    )?;
+                },
+            }
+
+            run_result.num_generated_structs += 1;
+        },
+
+        swagger20::SchemaKind::Ty(swagger20::Type::ListRef { .. }) => return Err(format!("definition {definition_path} is a ListRef").into()),
+
+        swagger20::SchemaKind::Ty(_) => {
+            let inner_type_name = get_rust_type(&definition.kind, map_namespace)?;
+
+            // Kubernetes requires MicroTime to be serialized with exactly six decimal digits, instead of the default serde serialization of `jiff::Timestamp`
+            // that uses a variable number up to nine.
+            //
+            // Furthermore, while Kubernetes does deserialize a Time from a string with one or more decimal digits,
+            // the format string it uses to *serialize* datetimes does not contain any decimal digits. So match that behavior just to be safe, and to have
+            // the same behavior as the golang client. The `full-precision-time` feature of k8s-openapi opts out of this for users who need
+            // a round-tripped Time to keep the decimal digits it was deserialized with.
+            //
+            // Refs:
+            // - https://github.com/Arnavion/k8s-openapi/issues/63
+            // - https://github.com/deislabs/krustlet/issues/5
+            // - https://github.com/kubernetes/apimachinery/issues/88
+            let datetime_serialization_format = match (&**definition_path, &definition.kind) {
+                (
+                    "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime",
+                    swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
+                ) => templates::DateTimeSerializationFormat::SixDecimalDigits,
+
+                (
+                    "io.k8s.apimachinery.pkg.apis.meta.v1.Time",
+                    swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
+                ) => templates::DateTimeSerializationFormat::ZeroDecimalDigits { full_precision_feature: Some("full-precision-time") },
+
+                _ => templates::DateTimeSerializationFormat::Default,
             };
 
             templates::newtype::generate(
@@ -760,6 +1146,16 @@ pub fn run(
                 map_namespace,
             )?;
 
+            if let Some(protobuf_message) = get_protobuf_newtype_message(definition_path, &definition.kind) {
+                templates::impl_protobuf_message::generate(
+                    &mut out,
+                    type_name,
+                    Default::default(),
+                    protobuf_message,
+                    map_namespace,
+                )?;
+            }
+
             run_result.num_generated_type_aliases += 1;
         },
     }
@@ -815,6 +1211,464 @@ pub fn run(
     Ok(run_result)
 }
 
+/// Generates the `visit` and `visit_mut` modules, which contain visitor traits with a method for each type that [`run`] generates
+/// for the given definitions, and a function for each type that walks the values of other types that it contains.
+///
+/// The borrowed and compact variants of types, `Patch` and `WatchEvent` do not have methods.
+///
+/// # Parameters
+///
+/// - `definitions`: The definitions parsed from the OpenAPI spec that are emitted as model types.
+///
+/// - `map_namespace`: An instance of the [`MapNamespace`] trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
+///
+/// - `visit_out`, `visit_mut_out`: The writers that the `visit` and `visit_mut` modules are written to.
+pub fn generate_visitors(
+    definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+    map_namespace: &impl MapNamespace,
+    visit_out: impl std::io::Write,
+    visit_mut_out: impl std::io::Write,
+) -> Result<(), Error> {
+    fn get_method_name(path: &str, map_namespace: &impl MapNamespace) -> Result<String, Error> {
+        let path_parts: Vec<_> = path.split('.').collect();
+        let namespace_parts = map_namespace.map_namespace(&path_parts).ok_or_else(|| format!("unexpected path {path:?}"))?;
+        let mut method_name = "visit".to_owned();
+        for part in namespace_parts.iter().skip(1) {
+            method_name.push('_');
+            method_name.push_str(&get_rust_ident(part));
+        }
+        Ok(method_name)
+    }
+
+    fn get_value(
+        kind: &swagger20::SchemaKind,
+        method_names: &std::collections::BTreeMap<&str, String>,
+    ) -> Option<templates::visit::Value> {
+        match kind {
+            swagger20::SchemaKind::Ref(ref_path) =>
+                method_names.get(&*ref_path.path).map(|method_name| templates::visit::Value::Visit(method_name.clone())),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) =>
+                get_value(&items.kind, method_names).map(|value| templates::visit::Value::Array(Box::new(value))),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) =>
+                get_value(&additional_properties.kind, method_names).map(|value| templates::visit::Value::Map(Box::new(value))),
+
+            _ => None,
+        }
+    }
+
+    let mut method_names = std::collections::BTreeMap::new();
+    for (definition_path, definition) in definitions {
+        match &definition.kind {
+            swagger20::SchemaKind::Ty(swagger20::Type::Patch | swagger20::Type::WatchEvent(_) | swagger20::Type::VariantDef { .. }) => (),
+
+            _ => {
+                method_names.insert(&**definition_path, get_method_name(definition_path, map_namespace)?);
+            },
+        }
+    }
+
+    let mut types = vec![];
+    for (definition_path, method_name) in &method_names {
+        let definition = &definitions[*definition_path];
+
+        let walk = match &definition.kind {
+            swagger20::SchemaKind::Properties(properties) => {
+                let fields: Vec<_> =
+                    properties.iter()
+                    .filter_map(|(name, (schema, required))| {
+                        let value = get_value(&schema.kind, &method_names)?;
+                        Some((get_rust_ident(name), *required, value))
+                    })
+                    .collect();
+                if fields.is_empty() { templates::visit::Walk::None } else { templates::visit::Walk::Fields(fields) }
+            },
+
+            swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOr(namespace, json_schema_props_or)) => {
+                let json_schema_props_method_name =
+                    &method_names[&*format!("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.{namespace}.JSONSchemaProps")];
+                let visit_json_schema_props = templates::visit::Value::Visit(json_schema_props_method_name.clone());
+                let or_variant = match json_schema_props_or {
+                    swagger20::JsonSchemaPropsOr::Array => ("Schemas".to_owned(), Some(templates::visit::Value::Array(Box::new(visit_json_schema_props)))),
+                    swagger20::JsonSchemaPropsOr::Bool => ("Bool".to_owned(), None),
+                    swagger20::JsonSchemaPropsOr::StringArray => ("Strings".to_owned(), None),
+                };
+                templates::visit::Walk::Variants(vec![
+                    ("Schema".to_owned(), Some(templates::visit::Value::Visit(json_schema_props_method_name.clone()))),
+                    or_variant,
+                ])
+            },
+
+            swagger20::SchemaKind::Ty(swagger20::Type::AnyResource { resources }) => {
+                let mut variants = Vec::with_capacity(resources.len());
+                for ref_path in resources {
+                    let variant_name = get_any_resource_variant_name(ref_path, map_namespace)?;
+                    variants.push((variant_name, get_value(&swagger20::SchemaKind::Ref(ref_path.clone()), &method_names)));
+                }
+                // The `Other` variant holds resources of types that are not in the spec.
+                variants.push(("Other".to_owned(), None));
+                templates::visit::Walk::Variants(variants)
+            },
+
+            swagger20::SchemaKind::Ty(swagger20::Type::ListDef { metadata }) => {
+                let swagger20::SchemaKind::Ref(metadata_ref_path) = &**metadata else {
+                    return Err(format!("definition {definition_path} has a metadata type that is not a ref").into());
+                };
+                templates::visit::Walk::List {
+                    metadata_method_name: method_names[&*metadata_ref_path.path].clone(),
+                }
+            },
+
+            _ => templates::visit::Walk::None,
+        };
+
+        let type_name = definition_path.rsplit('.').next().ok_or_else(|| format!("path for {definition_path} has no parts"))?;
+
+        types.push(templates::visit::Visited {
+            type_name,
+            rust_type: get_fully_qualified_type_name(
+                &swagger20::RefPath {
+                    path: (*definition_path).to_owned(),
+                    can_be_default: None,
+                },
+                map_namespace,
+            ),
+            method_name: method_name.clone(),
+            walk,
+        });
+    }
+
+    templates::visit::generate(visit_out, &types, templates::visit::Mutability::Shared, map_namespace)?;
+    templates::visit::generate(visit_mut_out, &types, templates::visit::Mutability::Mut, map_namespace)?;
+
+    Ok(())
+}
+
+/// Generates a `DiscoverableResource` impl for each resource in the given definitions that is served under its own URL path,
+/// with the verbs and subresources that the given operations have for it. Also generates a `SubresourceOf<Parent>` impl for each type
+/// that the operations of a subresource of a `Parent` resource are associated with, like `Scale` for `Deployment`.
+///
+/// Unlike [`run`], this function needs to see all the operations at the same time, since the operations of a resource's subresources are associated with
+/// the types of the subresources. Eg the operations of the `deployments/{name}/scale` subresource are associated with the `Scale` type.
+/// So it must be invoked with the operations before they are passed to [`run`].
+///
+/// # Parameters
+///
+/// - `definitions`: The definitions parsed from the OpenAPI spec that are emitted as model types.
+///
+/// - `operations`: The list of operations parsed from the OpenAPI spec.
+///
+/// - `map_namespace`: An instance of the [`MapNamespace`] trait that controls how OpenAPI namespaces of the definitions are mapped to rust namespaces.
+///
+/// - `out`: The writer that the impls are written to.
+pub fn generate_api_resources(
+    definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+    operations: &[swagger20::Operation],
+    map_namespace: &impl MapNamespace,
+    out: impl std::io::Write,
+) -> Result<(), Error> {
+    let mut resource_types = std::collections::BTreeMap::new();
+    for (definition_path, definition) in definitions {
+        let [kubernetes_group_kind_version] = &definition.kubernetes_group_kind_versions[..] else { continue; };
+
+        let swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+        if !properties.contains_key("apiVersion") || !properties.contains_key("kind") {
+            continue;
+        }
+
+        let rust_type = get_fully_qualified_type_name(
+            &swagger20::RefPath {
+                path: definition_path.0.clone(),
+                can_be_default: None,
+            },
+            map_namespace,
+        );
+        resource_types.insert(kubernetes_group_kind_version, (definition_path, rust_type));
+    }
+
+    let mut api_resources = vec![];
+    let mut parent_collection_paths = vec![];
+
+    for (kubernetes_group_kind_version, (definition_path, rust_type)) in &resource_types {
+
+        let resource_operations: Vec<_> =
+            operations.iter()
+            .filter(|operation| operation.kubernetes_group_kind_version.as_ref() == Some(*kubernetes_group_kind_version))
+            .collect();
+
+        // Use the CRUD operations to determine the resource's URL path and scope, like `run` does.
+        let mut collection_paths = std::collections::BTreeSet::new();
+        let mut plural_and_scope = None;
+        for operation in &resource_operations {
+            match operation.kubernetes_action {
+                Some(
+                    swagger20::KubernetesAction::Delete |
+                    swagger20::KubernetesAction::Get |
+                    swagger20::KubernetesAction::Post |
+                    swagger20::KubernetesAction::Put
+                ) => (),
+                _ => continue,
+            }
+
+            let (plural, scope) = match get_operation_path_scope(&operation.path) {
+                OperationPathScope::Namespace(plural) => (plural, templates::api_resources::Scope::Namespace),
+                OperationPathScope::Cluster(plural) => (plural, templates::api_resources::Scope::Cluster),
+                OperationPathScope::SubResource(_) => continue,
+            };
+
+            match plural_and_scope {
+                None => plural_and_scope = Some((plural, scope)),
+                Some(existing) if existing == (plural, scope) => (),
+                Some(_) => return Err(format!("definition {definition_path} was inferred to have multiple scopes").into()),
+            }
+
+            collection_paths.insert(operation.path.strip_suffix("/{name}").unwrap_or(&operation.path));
+        }
+
+        let Some((plural, scope)) = plural_and_scope else { continue; };
+
+        let mut verbs = std::collections::BTreeSet::new();
+        for operation in &resource_operations {
+            // Operations on the resource's subresources that are associated with the resource's own type, like `pods/{name}/status`,
+            // are not verbs of the resource.
+            match get_operation_path_scope(&operation.path) {
+                OperationPathScope::Namespace(plural_) | OperationPathScope::Cluster(plural_) if plural_ == plural => (),
+                _ => continue,
+            }
+
+            let verb = match operation.kubernetes_action {
+                Some(swagger20::KubernetesAction::Get) => templates::api_resources::Verb::Get,
+                Some(swagger20::KubernetesAction::List) => templates::api_resources::Verb::List,
+                Some(swagger20::KubernetesAction::Watch | swagger20::KubernetesAction::WatchList) => templates::api_resources::Verb::Watch,
+                Some(swagger20::KubernetesAction::Post) => templates::api_resources::Verb::Create,
+                Some(swagger20::KubernetesAction::Put) => templates::api_resources::Verb::Update,
+                Some(swagger20::KubernetesAction::Patch) => templates::api_resources::Verb::Patch,
+                Some(swagger20::KubernetesAction::Delete) => templates::api_resources::Verb::Delete,
+                Some(swagger20::KubernetesAction::DeleteCollection) => templates::api_resources::Verb::DeleteCollection,
+                Some(swagger20::KubernetesAction::Connect | swagger20::KubernetesAction::Proxy) | None => continue,
+            };
+            verbs.insert(verb);
+        }
+
+        // The subresources are found from the paths of all operations, regardless of the type they're associated with.
+        let mut subresources = std::collections::BTreeSet::new();
+        for operation in operations {
+            for collection_path in &collection_paths {
+                let subresource =
+                    operation.path.strip_prefix(collection_path)
+                    .and_then(|path| path.strip_prefix("/{name}/"))
+                    .and_then(|path| path.split('/').next());
+                if let Some(subresource) = subresource {
+                    subresources.insert(subresource);
+                }
+            }
+        }
+
+        for collection_path in collection_paths {
+            parent_collection_paths.push((collection_path, &**rust_type));
+        }
+
+        api_resources.push(templates::api_resources::ApiResource {
+            rust_type,
+            group: &kubernetes_group_kind_version.group,
+            version: &kubernetes_group_kind_version.version,
+            kind: &kubernetes_group_kind_version.kind,
+            plural,
+            scope,
+            verbs: verbs.into_iter().collect(),
+            subresources: subresources.into_iter().collect(),
+        });
+    }
+
+    // An operation on a subresource of a resource that is associated with another resource type, like `deployments/{name}/scale` with `Scale`,
+    // makes that type a subresource of the resource.
+    let mut subresources_of = std::collections::BTreeSet::new();
+    for operation in operations {
+        let Some(kubernetes_group_kind_version) = &operation.kubernetes_group_kind_version else { continue; };
+        let Some((_, rust_type)) = resource_types.get(kubernetes_group_kind_version) else { continue; };
+
+        for (collection_path, parent_rust_type) in &parent_collection_paths {
+            if rust_type == parent_rust_type {
+                continue;
+            }
+
+            let name =
+                operation.path.strip_prefix(collection_path)
+                .and_then(|path| path.strip_prefix("/{name}/"))
+                .filter(|path| !path.contains('/'));
+            if let Some(name) = name {
+                subresources_of.insert(templates::api_resources::SubresourceOf {
+                    parent_rust_type,
+                    rust_type,
+                    name,
+                });
+            }
+        }
+    }
+
+    templates::api_resources::generate(out, &api_resources, &subresources_of, map_namespace)?;
+
+    Ok(())
+}
+
+/// The fields of a struct, the string forms of its resource metadata if it's a resource, and the type of its `metadata` field if it has one.
+type TemplateProperties<'a> = (
+    Vec<templates::Property<'a>>,
+    Option<(String, String, String, String, Option<String>)>,
+    Option<(std::borrow::Cow<'static, str>, templates::PropertyRequired)>,
+);
+
+fn get_template_properties<'a>(
+    definition_path: &swagger20::DefinitionPath,
+    definition: &swagger20::Schema,
+    properties: &'a std::collections::BTreeMap<swagger20::PropertyName, (swagger20::Schema, bool)>,
+    definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+    map_namespace: &impl MapNamespace,
+) -> Result<TemplateProperties<'a>, Error> {
+    let mut result = Vec::with_capacity(properties.len());
+
+    let mut single_group_version_kind = match &definition.kubernetes_group_kind_versions[..] {
+        [group_version_kind] => Some((group_version_kind, false, false)),
+        _ => None,
+    };
+
+    let mut metadata_ty = None;
+
+    for (name, (schema, required)) in properties {
+        if name.0 == "apiVersion" {
+            if let Some((_, has_api_version, _)) = &mut single_group_version_kind {
+                *has_api_version = true;
+                continue;
+            }
+        }
+
+        if name.0 == "kind" {
+            if let Some((_, _, has_kind)) = &mut single_group_version_kind {
+                *has_kind = true;
+                continue;
+            }
+        }
+
+        let field_name = get_rust_ident(name);
+
+        let mut field_type_name = String::new();
+
+        let required = match required {
+            true => templates::PropertyRequired::Required {
+                is_default: is_default(&schema.kind, definitions, map_namespace)?,
+            },
+            false => templates::PropertyRequired::Optional,
+        };
+
+        if let templates::PropertyRequired::Optional = required {
+            field_type_name.push_str("Option<");
+        }
+
+        let type_name = get_rust_type(&schema.kind, map_namespace)?;
+
+        if name.0 == "metadata" {
+            metadata_ty = Some((type_name.clone(), required));
+        }
+
+        // Fix cases of infinite recursion
+        if let swagger20::SchemaKind::Ref(swagger20::RefPath { path, .. }) = &schema.kind {
+            match (&**definition_path, &**name, &**path) {
+                (
+                    "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps",
+                    "not",
+                    "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSONSchemaProps",
+                ) |
+                (
+                    "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.JSONSchemaProps",
+                    "not",
+                    "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.JSONSchemaProps",
+                ) => {
+                    field_type_name.push_str("std::boxed::Box<");
+                    field_type_name.push_str(&type_name);
+                    field_type_name.push('>');
+                },
+
+                _ => field_type_name.push_str(&type_name),
+            }
+        }
+        else if matches!(&schema.kind, swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(_, swagger20::RefPath { path, .. })) if path == &definition_path.0) {
+            field_type_name.push_str("std::boxed::Box<");
+            field_type_name.push_str(&type_name);
+            field_type_name.push('>');
+        }
+        else {
+            field_type_name.push_str(&type_name);
+        }
+
+        if let templates::PropertyRequired::Optional = required {
+            field_type_name.push('>');
+        }
+
+        let is_flattened = matches!(&schema.kind, swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(_)));
+
+        result.push(templates::Property {
+            name,
+            comment: schema.description.as_deref(),
+            field_name,
+            field_type_name,
+            required,
+            is_flattened,
+            merge_type: &schema.merge_type,
+            protobuf_field_number: schema.protobuf_field_number.clone(),
+        });
+    }
+
+    let resource_metadata = match single_group_version_kind {
+        Some((single_group_version_kind, true, true)) =>
+            Some(if single_group_version_kind.group.is_empty() {
+                (
+                    format!("{:?}", single_group_version_kind.version),
+                    format!("{:?}", ""),
+                    format!("{:?}", single_group_version_kind.kind),
+                    format!("{:?}", single_group_version_kind.version),
+                    definition.list_kind.as_ref().map(|kind| format!("{kind:?}")),
+                )
+            }
+            else {
+                (
+                    format!("{:?}", format!("{}/{}", single_group_version_kind.group, single_group_version_kind.version)),
+                    format!("{:?}", single_group_version_kind.group),
+                    format!("{:?}", single_group_version_kind.kind),
+                    format!("{:?}", single_group_version_kind.version),
+                    definition.list_kind.as_ref().map(|kind| format!("{kind:?}")),
+                )
+            }),
+        Some((_, true, false)) => return Err(format!("{definition_path} has an apiVersion property but not a kind property").into()),
+        Some((_, false, true)) => return Err(format!("{definition_path} has a kind property but not an apiVersion property").into()),
+        Some((_, false, false)) | None => None,
+    };
+
+    Ok((result, resource_metadata, metadata_ty))
+}
+
+/// Returns the expression that converts the given expression between a field of a variant of a type and the corresponding field of the owned type,
+/// or `None` if the field has the same type in both.
+fn get_variant_conversion(kind: &swagger20::SchemaKind, expr: &str, is_boxed: bool) -> Option<String> {
+    match kind {
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(..)) if is_boxed => Some(format!("std::boxed::Box::new((*{expr}).into())")),
+
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(..) | swagger20::Type::VariantString { .. }) => Some(format!("{expr}.into()")),
+
+        swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => match get_variant_conversion(&items.kind, "value", false)? {
+            conversion if conversion == "value.into()" => Some(format!("{expr}.into_iter().map(Into::into).collect()")),
+            conversion => Some(format!("{expr}.into_iter().map(|value| {conversion}).collect()")),
+        },
+
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantObject { additional_properties, .. }) => {
+            let conversion = get_variant_conversion(&additional_properties.kind, "value", false).unwrap_or_else(|| "value".to_owned());
+            Some(format!("{expr}.into_iter().map(|(key, value)| (key.into(), {conversion})).collect()"))
+        },
+
+        _ => None,
+    }
+}
+
 fn map_namespace_local_to_string(map_namespace: &impl MapNamespace) -> Result<String, Error> {
     let namespace_parts = map_namespace.map_namespace(&["io", "k8s"]).ok_or(r#"unexpected path "io.k8s""#)?;
 
@@ -826,6 +1680,17 @@ fn map_namespace_local_to_string(map_namespace: &impl MapNamespace) -> Result<St
     Ok(result)
 }
 
+fn get_unknown_fields(preserve_unknown_fields: PreserveUnknownFields, properties: &[templates::Property<'_>]) -> templates::UnknownFields {
+    match preserve_unknown_fields {
+        // A flattened field already receives all the fields that aren't known.
+        PreserveUnknownFields::Yes if !properties.iter().any(|property| property.is_flattened) =>
+            templates::UnknownFields::Preserve,
+
+        PreserveUnknownFields::Yes |
+        PreserveUnknownFields::No => templates::UnknownFields::Ignore,
+    }
+}
+
 fn get_derives(
     kind: &swagger20::SchemaKind,
     definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
@@ -876,7 +1741,8 @@ fn get_derives(
         swagger20::SchemaKind::Ty(
             swagger20::Type::JsonSchemaPropsOr(_, _) |
             swagger20::Type::Patch |
-            swagger20::Type::WatchEvent(_)
+            swagger20::Type::WatchEvent(_) |
+            swagger20::Type::AnyResource { .. }
         ) => Ok(false),
 
         _ => Ok(true),
@@ -952,7 +1818,8 @@ fn is_default(
         swagger20::SchemaKind::Ty(
             swagger20::Type::JsonSchemaPropsOr(_, _) |
             swagger20::Type::Patch |
-            swagger20::Type::WatchEvent(_)
+            swagger20::Type::WatchEvent(_) |
+            swagger20::Type::AnyResource { .. }
         ) => Ok(false),
 
         _ => Ok(true),
@@ -1023,6 +1890,30 @@ fn evaluate_trait_bound(
                 trait_bound
             },
 
+            swagger20::SchemaKind::Ty(swagger20::Type::VariantDef { properties, .. }) => {
+                let properties = swagger20::SchemaKind::Properties(properties.clone());
+                evaluate_trait_bound_inner(
+                    &std::borrow::Cow::Owned(properties),
+                    required,
+                    array_follows_elements,
+                    definitions,
+                    map_namespace,
+                    visited,
+                    f,
+                )
+            },
+
+            swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(_, ref_path)) =>
+                evaluate_trait_bound_inner(
+                    &std::borrow::Cow::Owned(swagger20::SchemaKind::Ref(ref_path.clone())),
+                    required,
+                    array_follows_elements,
+                    definitions,
+                    map_namespace,
+                    visited,
+                    f,
+                ),
+
             swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) if array_follows_elements =>
                 evaluate_trait_bound_inner(
                     &std::borrow::Cow::Owned(items.kind.clone()),
@@ -1090,6 +1981,220 @@ fn evaluate_trait_bound(
     )
 }
 
+/// Returns the protobuf encoding of the given newtype definition, if it has one.
+///
+/// These types have handwritten marshalers in the upstream golang code, so their encoding is not described by any `generated.proto`.
+fn get_protobuf_newtype_message(
+    definition_path: &swagger20::DefinitionPath,
+    kind: &swagger20::SchemaKind,
+) -> Option<templates::impl_protobuf_message::Message<'static>> {
+    match (&**definition_path, kind) {
+        (
+            "io.k8s.apimachinery.pkg.apis.meta.v1.MicroTime",
+            swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
+        ) => Some(templates::impl_protobuf_message::Message::Timestamp { subsecond_precision: true }),
+
+        (
+            "io.k8s.apimachinery.pkg.apis.meta.v1.Time",
+            swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }),
+        ) => Some(templates::impl_protobuf_message::Message::Timestamp { subsecond_precision: false }),
+
+        (
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.JSON" |
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.JSON" |
+            "io.k8s.apimachinery.pkg.apis.meta.v1.FieldsV1" |
+            "io.k8s.apimachinery.pkg.runtime.RawExtension",
+            swagger20::SchemaKind::Ty(swagger20::Type::Any),
+        ) => Some(templates::impl_protobuf_message::Message::RawJson),
+
+        // These are empty structs in the golang code, but have no properties in the spec so they're emitted as `serde_json::Value` newtypes.
+        (
+            "io.k8s.api.apiserverinternal.v1alpha1.StorageVersionSpec" |
+            "io.k8s.api.scheduling.v1alpha1.BasicSchedulingPolicy" |
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1.CustomResourceSubresourceStatus" |
+            "io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.v1beta1.CustomResourceSubresourceStatus",
+            swagger20::SchemaKind::Ty(swagger20::Type::Any),
+        ) => Some(templates::impl_protobuf_message::Message::EmptyObject),
+
+        _ => None,
+    }
+}
+
+/// Returns whether the given definition can be encoded as a protobuf message.
+///
+/// This requires all its properties to have protobuf field numbers, and all the types it references to be protobuf messages themselves.
+/// The exception is the `apiVersion` and `kind` properties of types with a GVK, since these are encoded in the `runtime.Unknown` envelope instead.
+fn is_protobuf_message(
+    definition_path: &str,
+    definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+    map_namespace: &impl MapNamespace,
+) -> bool {
+    fn is_protobuf_message_inner(
+        definition_path: &str,
+        definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+        map_namespace: &impl MapNamespace,
+        visited: &mut std::collections::BTreeSet<String>,
+    ) -> bool {
+        if !visited.insert(definition_path.to_owned()) {
+            // In case of recursive types, assume the definition is a message.
+            return true;
+        }
+
+        let Some(definition) = definitions.get(definition_path) else {
+            return false;
+        };
+
+        match &definition.kind {
+            swagger20::SchemaKind::Properties(properties) =>
+                properties.iter().all(|(name, (schema, _))| match schema.protobuf_field_number {
+                    Some(_) => is_protobuf_field(&schema.kind, definitions, map_namespace, visited),
+                    // apiVersion and kind are not part of the golang type's protobuf message, eg in DeleteOptions.
+                    None => name.0 == "apiVersion" || name.0 == "kind",
+                }),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::IntOrString | swagger20::Type::Quantity) => true,
+
+            swagger20::SchemaKind::Ty(swagger20::Type::JsonSchemaPropsOr(namespace, _)) =>
+                is_protobuf_message_inner(
+                    &format!("io.k8s.apiextensions-apiserver.pkg.apis.apiextensions.{namespace}.JSONSchemaProps"),
+                    definitions,
+                    map_namespace,
+                    visited,
+                ),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(_)) =>
+                is_protobuf_message_inner("io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta", definitions, map_namespace, visited) &&
+                is_protobuf_message_inner("io.k8s.apimachinery.pkg.apis.meta.v1.Status", definitions, map_namespace, visited),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::ListDef { metadata }) => is_protobuf_field(metadata, definitions, map_namespace, visited),
+
+            kind => get_protobuf_newtype_message(&swagger20::DefinitionPath(definition_path.to_owned()), kind).is_some(),
+        }
+    }
+
+    fn is_protobuf_field(
+        kind: &swagger20::SchemaKind,
+        definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+        map_namespace: &impl MapNamespace,
+        visited: &mut std::collections::BTreeSet<String>,
+    ) -> bool {
+        match kind {
+            swagger20::SchemaKind::Ref(ref_path) if !ref_path.references_scope(map_namespace) =>
+                is_protobuf_message_inner(&ref_path.path, definitions, map_namespace, visited),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => is_protobuf_field(&items.kind, definitions, map_namespace, visited),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) =>
+                is_protobuf_field(&additional_properties.kind, definitions, map_namespace, visited),
+
+            swagger20::SchemaKind::Ty(
+                swagger20::Type::Boolean |
+                swagger20::Type::Integer { .. } |
+                swagger20::Type::Number { .. } |
+                swagger20::Type::String { format: None | Some(swagger20::StringFormat::Byte | swagger20::StringFormat::Duration) }
+            ) => true,
+
+            _ => false,
+        }
+    }
+
+    let mut visited = Default::default();
+    is_protobuf_message_inner(definition_path, definitions, map_namespace, &mut visited)
+}
+
+/// Returns whether a value of the given kind can contain lists with `x-kubernetes-list-type: map`.
+fn contains_list_maps(
+    kind: &swagger20::SchemaKind,
+    definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+) -> bool {
+    fn contains_list_maps_inner(
+        kind: &swagger20::SchemaKind,
+        definitions: &std::collections::BTreeMap<swagger20::DefinitionPath, swagger20::Schema>,
+        visited: &mut std::collections::BTreeSet<String>,
+    ) -> bool {
+        match kind {
+            swagger20::SchemaKind::Ref(ref_path) => {
+                // A recursive type only contains list-maps if the rest of it does.
+                if !visited.insert(ref_path.path.clone()) {
+                    return false;
+                }
+
+                let Some(swagger20::Schema { kind: swagger20::SchemaKind::Properties(properties), .. }) = definitions.get(&*ref_path.path) else {
+                    return false;
+                };
+
+                properties.values().any(|(schema, _)|
+                    matches!(schema.merge_type, swagger20::MergeType::List { strategy: swagger20::KubernetesListType::Map, .. }) ||
+                    contains_list_maps_inner(&schema.kind, definitions, visited))
+            },
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Array { items }) => contains_list_maps_inner(&items.kind, definitions, visited),
+
+            swagger20::SchemaKind::Ty(swagger20::Type::Object { additional_properties }) =>
+                contains_list_maps_inner(&additional_properties.kind, definitions, visited),
+
+            _ => false,
+        }
+    }
+
+    let mut visited = Default::default();
+    contains_list_maps_inner(kind, definitions, &mut visited)
+}
+
+/// The resource that an operation operates on, as determined from the operation's path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OperationPathScope<'a> {
+    /// The operation operates on a namespace-scoped resource with the given URL path segment.
+    Namespace(&'a str),
+
+    /// The operation operates on a cluster-scoped resource with the given URL path segment.
+    Cluster(&'a str),
+
+    /// The operation operates on a subresource with the given URL path segment.
+    SubResource(&'a str),
+}
+
+fn get_operation_path_scope(path: &str) -> OperationPathScope<'_> {
+    let mut components = path.rsplit('/');
+    let components = (
+        components.next().expect("str::rsplit returns at least one component"),
+        components.next(),
+        components.next(),
+        components.next(),
+    );
+
+    match components {
+        ("{name}", Some(url_path_segment), Some("{namespace}"), Some("namespaces")) => OperationPathScope::Namespace(url_path_segment),
+        ("{name}", Some(url_path_segment), _, _) => OperationPathScope::Cluster(url_path_segment),
+        (url_path_segment, Some("{name}"), _, _) => OperationPathScope::SubResource(url_path_segment),
+        (url_path_segment, Some("{namespace}"), Some("namespaces"), _) => OperationPathScope::Namespace(url_path_segment),
+        (url_path_segment, _, _, _) => OperationPathScope::Cluster(url_path_segment),
+    }
+}
+
+/// Returns the name of the variant of `AnyResource` for the given resource type.
+///
+/// The variant is named after the group and version modules of the type, eg `AppsV1Deployment` for `crate::api::apps::v1::Deployment`
+fn get_any_resource_variant_name(ref_path: &swagger20::RefPath, map_namespace: &impl MapNamespace) -> Result<String, Error> {
+    let resource_path_parts: Vec<_> = ref_path.path.split('.').collect();
+    let resource_namespace_parts =
+        map_namespace.map_namespace(&resource_path_parts)
+        .ok_or_else(|| format!("unexpected path {:?}", ref_path.path))?;
+    let [.., group, version, resource_type_name] = &resource_namespace_parts[..] else {
+        return Err(format!("unexpected path {:?}", ref_path.path).into());
+    };
+    let mut variant_name = String::new();
+    for part in group.split(['-', '_']).chain(version.split(['-', '_'])) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            variant_name.extend(first.to_uppercase());
+            variant_name.extend(chars);
+        }
+    }
+    variant_name.push_str(resource_type_name);
+    Ok(variant_name)
+}
+
 fn get_comment_text<'a>(s: &'a str, indent: &'a str) -> impl Iterator<Item = std::borrow::Cow<'static, str>> + 'a {
     s.lines().scan(true, move |previous_line_was_empty, line|
         if line.is_empty() {
@@ -1210,7 +2315,8 @@ fn get_rust_type(
     match schema_kind {
         swagger20::SchemaKind::Properties(_) => Err("Nested anonymous types not supported".into()),
 
-        swagger20::SchemaKind::Ref(ref_path) =>
+        swagger20::SchemaKind::Ref(ref_path) |
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(swagger20::TypeVariant::Compact, ref_path)) =>
             Ok(get_fully_qualified_type_name(ref_path, map_namespace).into()),
 
         swagger20::SchemaKind::Ty(swagger20::Type::Any) => Ok(format!("{local}serde_json::Value").into()),
@@ -1232,6 +2338,8 @@ fn get_rust_type(
             Ok(format!("{local}ByteString").into()),
         swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::DateTime) }) =>
             Ok(format!("{local}jiff::Timestamp").into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::String { format: Some(swagger20::StringFormat::Duration) }) =>
+            Ok(format!("{local}Duration").into()),
         swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }) => Ok("std::string::String".into()),
 
         swagger20::SchemaKind::Ty(swagger20::Type::CustomResourceSubresources(namespace)) => {
@@ -1258,6 +2366,22 @@ fn get_rust_type(
         swagger20::SchemaKind::Ty(swagger20::Type::WatchEvent(_)) => Err("WatchEvent type not supported".into()),
 
         swagger20::SchemaKind::Ty(swagger20::Type::ListDef { .. }) => Err("ListDef type not supported".into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::AnyResource { .. }) => Err("AnyResource type not supported".into()),
+
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantDef { .. }) => Err("VariantDef type not supported".into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantRef(swagger20::TypeVariant::Borrowed, ref_path)) =>
+            Ok(format!("{}<'a>", get_fully_qualified_type_name(ref_path, map_namespace)).into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantObject { variant: swagger20::TypeVariant::Borrowed, additional_properties }) =>
+            Ok(format!("std::collections::BTreeMap<{local}BorrowedStr<'a>, {}>", get_rust_type(&additional_properties.kind, map_namespace)?).into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantObject { variant: swagger20::TypeVariant::Compact, additional_properties }) =>
+            Ok(format!("std::collections::BTreeMap<{local}InternedStr, {}>", get_rust_type(&additional_properties.kind, map_namespace)?).into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantString { variant: swagger20::TypeVariant::Borrowed, format: None }) =>
+            Ok(format!("{local}BorrowedStr<'a>").into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantString { variant: swagger20::TypeVariant::Compact, format: None }) =>
+            Ok(format!("{local}compact_str::CompactString").into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantString { variant: swagger20::TypeVariant::Compact, format: Some(swagger20::StringFormat::Byte) }) =>
+            Ok(format!("{local}CompactByteString").into()),
+        swagger20::SchemaKind::Ty(swagger20::Type::VariantString { .. }) => Err("VariantString type not supported".into()),
         swagger20::SchemaKind::Ty(swagger20::Type::ListRef { items }) =>
             Ok(format!("{local}List<{}>", get_rust_type(items, map_namespace)?).into()),
     }
@@ -30,6 +30,130 @@ pub enum IntegerFormat {
     Int64,
 }
 
+/// A JSON number, as found in the `"default"`, `"enum"`, `"maximum"` and `"minimum"` properties of a schema.
+#[derive(Clone, Copy, Debug)]
+pub enum JsonNumber {
+    Float(f64),
+    NegInt(i64),
+    PosInt(u64),
+}
+
+impl std::cmp::Eq for JsonNumber {}
+
+impl std::cmp::Ord for JsonNumber {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (JsonNumber::Float(this), JsonNumber::Float(other)) => this.total_cmp(other),
+            (JsonNumber::NegInt(this), JsonNumber::NegInt(other)) => this.cmp(other),
+            (JsonNumber::PosInt(this), JsonNumber::PosInt(other)) => this.cmp(other),
+            (JsonNumber::Float(_), _) | (JsonNumber::NegInt(_), JsonNumber::PosInt(_)) => std::cmp::Ordering::Less,
+            (JsonNumber::NegInt(_) | JsonNumber::PosInt(_), _) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+impl std::cmp::PartialEq for JsonNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl std::cmp::PartialOrd for JsonNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JsonNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        match serde::Deserialize::deserialize(deserializer)? {
+            JsonValue::Number(value) => Ok(value),
+            _ => Err(serde::de::Error::invalid_type(serde::de::Unexpected::Other("non-number"), &"a number")),
+        }
+    }
+}
+
+/// An arbitrary JSON value, as found in the `"default"` and `"enum"` properties and the unrecognized extensions of a schema.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(JsonNumber),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(std::collections::BTreeMap<String, JsonValue>),
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JsonValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = JsonValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a JSON value")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Bool(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Number(u64::try_from(v).map_or(JsonNumber::NegInt(v), JsonNumber::PosInt)))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Number(JsonNumber::PosInt(v)))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Number(JsonNumber::Float(v)))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::String(v.to_owned()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::String(v))
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Null)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+                serde::Deserialize::deserialize(deserializer)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(JsonValue::Null)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+                let mut result = vec![];
+                while let Some(value) = seq.next_element()? {
+                    result.push(value);
+                }
+                Ok(JsonValue::Array(result))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut result = std::collections::BTreeMap::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    result.insert(key, value);
+                }
+                Ok(JsonValue::Object(result))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
 /// The value of an `x-kubernetes-list-type` annotation on a property.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -57,6 +181,43 @@ pub enum KubernetesMapType {
     Granular,
 }
 
+/// An entry of an `x-kubernetes-unions` annotation on a type.
+///
+/// Ref: <https://github.com/kubernetes/enhancements/tree/master/keps/sig-api-machinery/1027-api-unions>
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct KubernetesUnion {
+    /// The property whose value names the member of the union that is set, if any.
+    pub discriminator: Option<String>,
+
+    /// The members of the union, mapped to the corresponding values of the discriminator.
+    #[cfg_attr(feature = "serde", serde(default, rename = "fields-to-discriminateBy"))]
+    pub fields_to_discriminate_by: std::collections::BTreeMap<String, String>,
+}
+
+/// An entry of an `x-kubernetes-validations` annotation on a type or property.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct KubernetesValidationRule {
+    /// The CEL expression that must evaluate to true.
+    pub rule: String,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(default, rename = "messageExpression"))]
+    pub message_expression: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reason: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(default, rename = "fieldPath"))]
+    pub field_path: Option<String>,
+
+    #[cfg_attr(feature = "serde", serde(default, rename = "optionalOldSelf"))]
+    pub optional_old_self: bool,
+}
+
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum MergeType {
     Default,
@@ -145,7 +306,26 @@ impl RefPath {
     }
 }
 
+/// The protobuf field number of a property.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ProtobufFieldNumber {
+    /// The property is the field with this number.
+    Field(u32),
+
+    /// The property is a field of a golang struct that is embedded in the property's struct, like the `ProbeHandler` of a `Probe`.
+    /// The embedded struct is encoded as a message in the field with the number `embedded_in`, and the property is the field with the number
+    /// `field_number` of that message.
+    ///
+    /// An embedded struct can itself embed another struct, like the `Rule` embedded in the `RuleWithOperations` embedded in a `NamedRuleWithOperations`.
+    /// In that case `embedded_in` has the field numbers of all the embedded messages, outermost first.
+    Embedded {
+        embedded_in: Vec<u32>,
+        field_number: u32,
+    },
+}
+
 /// The schema of a definition or operation parameter.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Schema {
     pub description: Option<String>,
@@ -154,28 +334,66 @@ pub struct Schema {
 
     pub merge_type: MergeType,
 
+    pub validation: SchemaValidation,
+    pub extensions: SchemaExtensions,
+
     /// Used to store the definition path of the corresponding list type, if any.
     pub list_kind: Option<String>,
 
     /// Used to enable or disable the auto-generated impl of `k8s_openapi::DeepMerge` on the generated type.
     pub impl_deep_merge: bool,
+
+    /// Used to enable or disable the auto-generated impl of `k8s_openapi::Normalize` on the generated type.
+    pub impl_normalize: bool,
+
+    /// Used to enable or disable the auto-generated impl of `k8s_openapi::diff::Diff` on the generated type.
+    pub impl_diff: bool,
+
+    /// Used to enable or disable the auto-generated `fields()` function of the generated type and the methods of `k8s_openapi::FieldPath` for its fields.
+    /// Outside the `k8s-openapi` crate, these methods are declared in a `{type name}Fields` trait that is generated next to the type.
+    pub impl_field_paths: bool,
+
+    /// Used to enable or disable the auto-generated impl of `k8s_openapi::Defaulting` on the generated type.
+    pub impl_defaulting: bool,
+
+    /// Used to store the path of a function that sets the defaults of the generated type that can't be expressed as the `default`s of its properties,
+    /// like defaults that depend on the values of other fields. The auto-generated impl of `k8s_openapi::Defaulting` invokes it with `&mut self`.
+    pub defaulting_function: Option<String>,
+
+    /// Used to store the protobuf field number of a property, if any.
+    pub protobuf_field_number: Option<ProtobufFieldNumber>,
 }
 
 #[cfg(feature = "serde")]
 #[allow(clippy::use_self)]
 impl<'de> serde::Deserialize<'de> for Schema {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        #[allow(clippy::struct_excessive_bools)]
         #[derive(Debug, serde::Deserialize)]
         struct InnerSchema {
             #[serde(rename = "additionalProperties")]
             additional_properties: Option<Box<Schema>>,
 
+            default: Option<JsonValue>,
+
             description: Option<String>,
 
+            #[serde(rename = "enum")]
+            enum_values: Option<Vec<JsonValue>>,
+
+            #[serde(default, rename = "exclusiveMaximum")]
+            exclusive_maximum: bool,
+
+            #[serde(default, rename = "exclusiveMinimum")]
+            exclusive_minimum: bool,
+
             format: Option<String>,
 
             items: Option<Box<Schema>>,
 
+            #[serde(default, rename = "x-kubernetes-embedded-resource")]
+            kubernetes_embedded_resource: bool,
+
             #[serde(default, rename = "x-kubernetes-group-version-kind")]
             kubernetes_group_kind_versions: Vec<super::KubernetesGroupKindVersion>,
 
@@ -196,6 +414,36 @@ impl<'de> serde::Deserialize<'de> for Schema {
             #[serde(default, rename = "x-kubernetes-patch-strategy")]
             kubernetes_patch_strategy: String,
 
+            #[serde(default, rename = "x-kubernetes-preserve-unknown-fields")]
+            kubernetes_preserve_unknown_fields: bool,
+
+            #[serde(default, rename = "x-kubernetes-unions")]
+            kubernetes_unions: Vec<KubernetesUnion>,
+
+            #[serde(default, rename = "x-kubernetes-validations")]
+            kubernetes_validations: Vec<KubernetesValidationRule>,
+
+            #[serde(rename = "maxItems")]
+            max_items: Option<u64>,
+
+            #[serde(rename = "maxLength")]
+            max_length: Option<u64>,
+
+            maximum: Option<JsonNumber>,
+
+            #[serde(rename = "minItems")]
+            min_items: Option<u64>,
+
+            #[serde(rename = "minLength")]
+            min_length: Option<u64>,
+
+            minimum: Option<JsonNumber>,
+
+            #[serde(default)]
+            nullable: bool,
+
+            pattern: Option<String>,
+
             properties: Option<std::collections::BTreeMap<PropertyName, Schema>>,
 
             #[serde(rename = "$ref")]
@@ -206,6 +454,13 @@ impl<'de> serde::Deserialize<'de> for Schema {
 
             #[serde(rename = "type")]
             ty: Option<String>,
+
+            #[serde(default, rename = "uniqueItems")]
+            unique_items: bool,
+
+            /// All other properties. Only the ones that are `x-*` extensions are kept.
+            #[serde(flatten)]
+            other: std::collections::BTreeMap<String, JsonValue>,
         }
 
         let mut value: InnerSchema = serde::Deserialize::deserialize(deserializer)?;
@@ -271,11 +526,56 @@ impl<'de> serde::Deserialize<'de> for Schema {
             kubernetes_group_kind_versions: value.kubernetes_group_kind_versions,
             list_kind: None,
             merge_type,
+            validation: SchemaValidation {
+                default: value.default,
+                enum_values: value.enum_values,
+                maximum: value.maximum,
+                exclusive_maximum: value.exclusive_maximum,
+                minimum: value.minimum,
+                exclusive_minimum: value.exclusive_minimum,
+                max_length: value.max_length,
+                min_length: value.min_length,
+                pattern: value.pattern,
+                max_items: value.max_items,
+                min_items: value.min_items,
+                unique_items: value.unique_items,
+                nullable: value.nullable,
+                kubernetes_validations: value.kubernetes_validations,
+            },
+            extensions: SchemaExtensions {
+                kubernetes_embedded_resource: value.kubernetes_embedded_resource,
+                kubernetes_preserve_unknown_fields: value.kubernetes_preserve_unknown_fields,
+                kubernetes_unions: value.kubernetes_unions,
+                other: value.other.into_iter().filter(|(key, _)| key.starts_with("x-")).collect(),
+            },
             impl_deep_merge: true,
+            impl_normalize: true,
+            impl_diff: true,
+            impl_field_paths: true,
+            impl_defaulting: true,
+            defaulting_function: None,
+            protobuf_field_number: None,
         })
     }
 }
 
+/// The `x-kubernetes-*` extensions of a [`Schema`] that aren't already reflected in its other fields,
+/// and any other `x-*` extensions that aren't recognized.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SchemaExtensions {
+    /// The value of the `x-kubernetes-embedded-resource` annotation.
+    pub kubernetes_embedded_resource: bool,
+
+    /// The value of the `x-kubernetes-preserve-unknown-fields` annotation.
+    pub kubernetes_preserve_unknown_fields: bool,
+
+    /// The value of the `x-kubernetes-unions` annotation.
+    pub kubernetes_unions: Vec<KubernetesUnion>,
+
+    /// All other `x-*` extensions, keyed by their full name.
+    pub other: std::collections::BTreeMap<String, JsonValue>,
+}
+
 /// The kind of a [`Schema`]
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum SchemaKind {
@@ -284,11 +584,40 @@ pub enum SchemaKind {
     Ty(Type),
 }
 
+/// The validation properties of a [`Schema`].
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SchemaValidation {
+    pub default: Option<JsonValue>,
+
+    /// The value of the `"enum"` property.
+    pub enum_values: Option<Vec<JsonValue>>,
+
+    pub maximum: Option<JsonNumber>,
+    pub exclusive_maximum: bool,
+    pub minimum: Option<JsonNumber>,
+    pub exclusive_minimum: bool,
+
+    pub max_length: Option<u64>,
+    pub min_length: Option<u64>,
+    pub pattern: Option<String>,
+
+    pub max_items: Option<u64>,
+    pub min_items: Option<u64>,
+    pub unique_items: bool,
+
+    pub nullable: bool,
+
+    /// The value of the `x-kubernetes-validations` annotation.
+    pub kubernetes_validations: Vec<KubernetesValidationRule>,
+}
+
 /// A string format. This corresponds to the `"format"` property of an `"string"` schema type.
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum StringFormat {
     Byte,
     DateTime,
+    Duration,
 }
 
 /// A type definition.
@@ -316,6 +645,21 @@ pub enum Type {
     // Special type for lists
     ListDef { metadata: Box<SchemaKind> }, // The definition of the List type
     ListRef { items: Box<SchemaKind> }, // A reference to a specialization of the List type for a particular resource type, eg List<Pod> for PodList
+
+    // Special type for an enum of all the given resource types
+    AnyResource { resources: Vec<RefPath> },
+
+    // Special types for the borrowed and compact variants of types
+    VariantDef { variant: TypeVariant, owned: RefPath, properties: std::collections::BTreeMap<PropertyName, (Schema, bool)> }, // The definition of the variant of the owned type
+    VariantRef(TypeVariant, RefPath), // A reference to the variant of a type
+    VariantObject { variant: TypeVariant, additional_properties: Box<Schema> }, // An object whose keys are the variant's string type
+    VariantString { variant: TypeVariant, format: Option<StringFormat> }, // A string or byte string of the variant's string type
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TypeVariant {
+    Borrowed, // Strings borrow from the deserializer's input where possible
+    Compact, // Strings are stored inline where possible, object keys are interned, and byte strings are reference-counted
 }
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -367,8 +711,9 @@ impl Type {
                 let format = match format {
                     Some("byte") => Some(StringFormat::Byte),
                     Some("date-time") => Some(StringFormat::DateTime),
+                    Some("duration") => Some(StringFormat::Duration),
                     Some("int-or-string") => return Ok(Type::IntOrString),
-                    Some(format) => return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(format), &"one of byte, date-time, int-or-string")),
+                    Some(format) => return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(format), &"one of byte, date-time, duration, int-or-string")),
                     None => None,
                 };
                 Ok(Type::String { format })
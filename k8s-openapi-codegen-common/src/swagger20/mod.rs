@@ -46,9 +46,15 @@ impl<'de> serde::Deserialize<'de> for Spec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
         #[derive(Debug, serde::Deserialize)]
         pub struct InnerSpec {
+            #[serde(default)]
+            consumes: Vec<String>,
             info: Info,
             definitions: std::collections::BTreeMap<DefinitionPath, Schema>,
+            #[serde(default)]
+            parameters: std::collections::BTreeMap<String, std::sync::Arc<Parameter>>,
             paths: std::collections::BTreeMap<Path, InnerPathItem>,
+            #[serde(default)]
+            produces: Vec<String>,
             swagger: String,
         }
 
@@ -56,6 +62,10 @@ impl<'de> serde::Deserialize<'de> for Spec {
         struct InnerPathItem {
             delete: Option<InnerOperation>,
             get: Option<InnerOperation>,
+            head: Option<InnerOperation>,
+            options: Option<InnerOperation>,
+            #[serde(default)]
+            parameters: Vec<InnerParameter>,
             patch: Option<InnerOperation>,
             post: Option<InnerOperation>,
             put: Option<InnerOperation>,
@@ -63,12 +73,38 @@ impl<'de> serde::Deserialize<'de> for Spec {
 
         #[derive(Debug, serde::Deserialize)]
         struct InnerOperation {
+            consumes: Option<Vec<String>>,
+            #[serde(default)]
+            deprecated: bool,
+            description: Option<String>,
             #[serde(rename = "operationId")]
             id: String,
             #[serde(rename = "x-kubernetes-action")]
             kubernetes_action: Option<KubernetesAction>,
             #[serde(rename = "x-kubernetes-group-version-kind")]
             kubernetes_group_kind_version: Option<KubernetesGroupKindVersion>,
+            #[serde(default)]
+            parameters: Vec<InnerParameter>,
+            produces: Option<Vec<String>>,
+            #[serde(default)]
+            responses: std::collections::BTreeMap<String, Response>,
+        }
+
+        fn resolve_parameter<'de, D>(
+            parameter: InnerParameter,
+            parameters: &std::collections::BTreeMap<String, std::sync::Arc<Parameter>>,
+        ) -> Result<std::sync::Arc<Parameter>, D::Error> where D: serde::Deserializer<'de> {
+            let Some(ref_path) = &parameter.ref_path else {
+                return Ok(std::sync::Arc::new(parameter.into_parameter::<D>()?));
+            };
+
+            let name =
+                ref_path.strip_prefix("#/parameters/")
+                .ok_or_else(|| serde::de::Error::invalid_value(serde::de::Unexpected::Str(ref_path), &"path like `#/parameters/$parameterName`"))?;
+            let parameter =
+                parameters.get(name)
+                .ok_or_else(|| serde::de::Error::custom(format!("parameter {ref_path} does not exist in spec")))?;
+            Ok(parameter.clone())
         }
 
         let result: InnerSpec = serde::Deserialize::deserialize(deserializer)?;
@@ -80,48 +116,48 @@ impl<'de> serde::Deserialize<'de> for Spec {
         let mut operations = vec![];
 
         for (path, path_item) in result.paths {
-            if let Some(delete) = path_item.delete {
-                operations.push(Operation {
-                    id: delete.id,
-                    kubernetes_action: delete.kubernetes_action,
-                    kubernetes_group_kind_version: delete.kubernetes_group_kind_version,
-                    path: path.clone(),
-                });
-            }
-
-            if let Some(get) = path_item.get {
-                operations.push(Operation {
-                    id: get.id,
-                    kubernetes_action: get.kubernetes_action,
-                    kubernetes_group_kind_version: get.kubernetes_group_kind_version,
-                    path: path.clone(),
-                });
-            }
-
-            if let Some(patch) = path_item.patch {
-                operations.push(Operation {
-                    id: patch.id,
-                    kubernetes_action: patch.kubernetes_action,
-                    kubernetes_group_kind_version: patch.kubernetes_group_kind_version,
-                    path: path.clone(),
-                });
-            }
+            let path_parameters: Vec<_> =
+                path_item.parameters.into_iter()
+                .map(|parameter| resolve_parameter::<D>(parameter, &result.parameters))
+                .collect::<Result<_, _>>()?;
+
+            let path_operations = [
+                (Method::Delete, path_item.delete),
+                (Method::Get, path_item.get),
+                (Method::Head, path_item.head),
+                (Method::Options, path_item.options),
+                (Method::Patch, path_item.patch),
+                (Method::Post, path_item.post),
+                (Method::Put, path_item.put),
+            ];
+
+            for (method, operation) in path_operations {
+                let Some(operation) = operation else { continue; };
+
+                let mut parameters = vec![];
+                for parameter in operation.parameters {
+                    parameters.push(resolve_parameter::<D>(parameter, &result.parameters)?);
+                }
+
+                // A parameter of the path is overridden by a parameter of the operation with the same name and location.
+                for path_parameter in &path_parameters {
+                    if !parameters.iter().any(|parameter| parameter.name == path_parameter.name && parameter.location == path_parameter.location) {
+                        parameters.push(path_parameter.clone());
+                    }
+                }
 
-            if let Some(post) = path_item.post {
                 operations.push(Operation {
-                    id: post.id,
-                    kubernetes_action: post.kubernetes_action,
-                    kubernetes_group_kind_version: post.kubernetes_group_kind_version,
+                    consumes: operation.consumes.unwrap_or_else(|| result.consumes.clone()),
+                    deprecated: operation.deprecated,
+                    description: operation.description,
+                    id: operation.id,
+                    kubernetes_action: operation.kubernetes_action,
+                    kubernetes_group_kind_version: operation.kubernetes_group_kind_version,
+                    method,
+                    parameters,
                     path: path.clone(),
-                });
-            }
-
-            if let Some(put) = path_item.put {
-                operations.push(Operation {
-                    id: put.id,
-                    kubernetes_action: put.kubernetes_action,
-                    kubernetes_group_kind_version: put.kubernetes_group_kind_version,
-                    path,
+                    produces: operation.produces.unwrap_or_else(|| result.produces.clone()),
+                    responses: operation.responses,
                 });
             }
         }
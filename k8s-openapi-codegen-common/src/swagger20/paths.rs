@@ -1,3 +1,28 @@
+/// The value of the `"collectionFormat"` property of a [`Parameter`] whose type is an array.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum CollectionFormat {
+    /// Comma-separated values, like `a,b`.
+    #[cfg_attr(feature = "serde", serde(rename = "csv"))]
+    Csv,
+
+    /// Each value is a separate instance of the parameter, like `?foo=a&foo=b`.
+    #[cfg_attr(feature = "serde", serde(rename = "multi"))]
+    Multi,
+
+    /// Pipe-separated values, like `a|b`.
+    #[cfg_attr(feature = "serde", serde(rename = "pipes"))]
+    Pipes,
+
+    /// Space-separated values, like `a b`.
+    #[cfg_attr(feature = "serde", serde(rename = "ssv"))]
+    Ssv,
+
+    /// Tab-separated values.
+    #[cfg_attr(feature = "serde", serde(rename = "tsv"))]
+    Tsv,
+}
+
 /// The value of an `"x-kubernetes-action"` annotation on an operation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum KubernetesAction {
@@ -49,13 +74,170 @@ impl<'de> serde::Deserialize<'de> for KubernetesAction {
     }
 }
 
+/// The HTTP method of an API operation.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Method {
+    Delete,
+    Get,
+    Head,
+    Options,
+    Patch,
+    Post,
+    Put,
+}
+
 /// An API operation.
 #[derive(Clone, Debug)]
 pub struct Operation {
+    /// The MIME types of the request bodies that the operation accepts.
+    pub consumes: Vec<String>,
+    pub deprecated: bool,
+    pub description: Option<String>,
     pub id: String,
     pub kubernetes_action: Option<KubernetesAction>,
     pub kubernetes_group_kind_version: Option<super::KubernetesGroupKindVersion>,
+    pub method: Method,
+
+    /// The parameters of the operation, including the ones shared by all operations of its path.
+    /// Parameters that were specified as references to the spec's shared parameters are resolved, so parameters that are used by multiple operations
+    /// are shared via the `Arc`.
+    pub parameters: Vec<std::sync::Arc<Parameter>>,
+
     pub path: Path,
+
+    /// The MIME types of the response bodies that the operation returns.
+    pub produces: Vec<String>,
+
+    /// The responses of the operation, keyed by HTTP status code or `"default"`.
+    pub responses: std::collections::BTreeMap<String, Response>,
+}
+
+/// A parameter of an API operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameter {
+    /// The format of the values of the parameter if its type is an array, for parameters other than [`ParameterLocation::Body`] parameters.
+    pub collection_format: Option<CollectionFormat>,
+
+    pub description: Option<String>,
+    pub location: ParameterLocation,
+    pub name: String,
+    pub required: bool,
+
+    /// The schema of the parameter. For [`ParameterLocation::Body`] parameters, this is the schema of the request body.
+    /// For other parameters, this is the schema formed by the parameter's `"type"`, `"format"` and `"items"` properties,
+    /// with its `"default"` and `"enum"` properties in the schema's [`validation`](super::Schema::validation).
+    pub schema: super::Schema,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Parameter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let value: InnerParameter = serde::Deserialize::deserialize(deserializer)?;
+        if let Some(ref_path) = value.ref_path {
+            return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&ref_path), &"parameter that is not a reference"));
+        }
+        value.into_parameter::<D>()
+    }
+}
+
+/// A parameter as it appears in the spec, which is either a parameter or a reference to one of the spec's shared parameters.
+#[cfg(feature = "serde")]
+#[derive(Debug, serde::Deserialize)]
+pub(super) struct InnerParameter {
+    #[serde(rename = "$ref")]
+    pub(super) ref_path: Option<String>,
+
+    #[serde(rename = "collectionFormat")]
+    collection_format: Option<CollectionFormat>,
+
+    default: Option<super::JsonValue>,
+
+    description: Option<String>,
+
+    #[serde(rename = "enum")]
+    enum_values: Option<Vec<super::JsonValue>>,
+
+    format: Option<String>,
+
+    #[serde(rename = "in")]
+    location: Option<ParameterLocation>,
+
+    items: Option<Box<super::Schema>>,
+
+    name: Option<String>,
+
+    required: Option<bool>,
+
+    schema: Option<super::Schema>,
+
+    #[serde(rename = "type")]
+    ty: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl InnerParameter {
+    pub(super) fn into_parameter<'de, D>(self) -> Result<Parameter, D::Error> where D: serde::Deserializer<'de> {
+        let location = self.location.ok_or_else(|| serde::de::Error::missing_field("in"))?;
+        let name = self.name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
+
+        let schema =
+            if location == ParameterLocation::Body {
+                self.schema.ok_or_else(|| serde::de::Error::missing_field("schema"))?
+            }
+            else {
+                let ty = self.ty.ok_or_else(|| serde::de::Error::missing_field("type"))?;
+                super::Schema {
+                    description: None,
+                    kind: super::SchemaKind::Ty(super::Type::parse::<D>(&ty, None, self.format.as_deref(), self.items)?),
+                    kubernetes_group_kind_versions: vec![],
+                    list_kind: None,
+                    merge_type: super::MergeType::Default,
+                    validation: super::SchemaValidation {
+                        default: self.default,
+                        enum_values: self.enum_values,
+                        ..Default::default()
+                    },
+                    extensions: super::SchemaExtensions::default(),
+                    impl_deep_merge: true,
+                    impl_normalize: true,
+                    impl_diff: true,
+                    impl_field_paths: true,
+                    impl_defaulting: true,
+                    defaulting_function: None,
+                    protobuf_field_number: None,
+                }
+            };
+
+        Ok(Parameter {
+            collection_format: self.collection_format,
+            description: self.description,
+            location,
+            name,
+            // Path parameters are always required.
+            required: self.required.unwrap_or(false) || location == ParameterLocation::Path,
+            schema,
+        })
+    }
+}
+
+/// The location of a [`Parameter`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ParameterLocation {
+    #[cfg_attr(feature = "serde", serde(rename = "body"))]
+    Body,
+
+    #[cfg_attr(feature = "serde", serde(rename = "formData"))]
+    FormData,
+
+    #[cfg_attr(feature = "serde", serde(rename = "header"))]
+    Header,
+
+    #[cfg_attr(feature = "serde", serde(rename = "path"))]
+    Path,
+
+    #[cfg_attr(feature = "serde", serde(rename = "query"))]
+    Query,
 }
 
 /// The path of an API operation.
@@ -76,3 +258,13 @@ impl std::fmt::Display for Path {
         self.0.fmt(f)
     }
 }
+
+/// A response of an API operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct Response {
+    pub description: String,
+
+    /// The schema of the response body, if the response has one.
+    pub schema: Option<super::Schema>,
+}
@@ -0,0 +1,73 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    resources: &[Resource<'_>],
+    object_meta_rust_type: &str,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let mut variants = String::new();
+    let mut deserialize_arms = String::new();
+    let mut api_version_arms = String::new();
+    let mut kind_arms = String::new();
+    let mut metadata_arms = String::new();
+    let mut metadata_mut_arms = String::new();
+    let mut serialize_arms = String::new();
+    let mut from_impls = String::new();
+
+    for Resource { variant_name, rust_type, api_version, kind } in resources {
+        writeln!(variants, "    /// `{api_version}` `{kind}`")?;
+        writeln!(variants, "    {variant_name}({rust_type}),")?;
+        writeln!(variants)?;
+
+        writeln!(
+            deserialize_arms,
+            "            ({api_version:?}, {kind:?}) => {type_name}::{variant_name}({local}serde::Deserialize::deserialize(deserializer)?),",
+        )?;
+
+        writeln!(api_version_arms, "            {type_name}::{variant_name}(value) => {local}api_version(value),")?;
+
+        writeln!(kind_arms, "            {type_name}::{variant_name}(value) => {local}kind(value),")?;
+
+        writeln!(metadata_arms, "            {type_name}::{variant_name}(value) => {local}Metadata::metadata(value),")?;
+
+        writeln!(metadata_mut_arms, "            {type_name}::{variant_name}(value) => {local}Metadata::metadata_mut(value),")?;
+
+        writeln!(serialize_arms, "            {type_name}::{variant_name}(value) => {local}serde::Serialize::serialize(value, serializer),")?;
+
+        writeln!(from_impls)?;
+        writeln!(from_impls, "impl From<{rust_type}> for {type_name} {{")?;
+        writeln!(from_impls, "    fn from(value: {rust_type}) -> Self {{")?;
+        writeln!(from_impls, "        {type_name}::{variant_name}(value)")?;
+        writeln!(from_impls, "    }}")?;
+        writeln!(from_impls, "}}")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/any_resource.rs")),
+        local = local,
+        type_name = type_name,
+        variants = variants,
+        deserialize_arms = deserialize_arms,
+        api_version_arms = api_version_arms,
+        kind_arms = kind_arms,
+        metadata_arms = metadata_arms,
+        metadata_mut_arms = metadata_mut_arms,
+        object_meta_rust_type = object_meta_rust_type,
+        serialize_arms = serialize_arms,
+        from_impls = from_impls,
+    )?;
+
+    Ok(())
+}
+
+pub(crate) struct Resource<'a> {
+    pub(crate) variant_name: String,
+    pub(crate) rust_type: std::borrow::Cow<'a, str>,
+    pub(crate) api_version: String,
+    pub(crate) kind: &'a str,
+}
@@ -0,0 +1,105 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    api_resources: &[ApiResource<'_>],
+    subresources_of: &std::collections::BTreeSet<SubresourceOf<'_>>,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let mut impls = String::new();
+
+    for ApiResource { rust_type, group, version, kind, plural, scope, verbs, subresources } in api_resources {
+        let mut verbs_ = String::new();
+        for (i, verb) in verbs.iter().enumerate() {
+            if i > 0 {
+                verbs_.push_str(", ");
+            }
+            write!(verbs_, "{local}Verb::{verb:?}")?;
+        }
+
+        let mut subresources_ = String::new();
+        for (i, subresource) in subresources.iter().enumerate() {
+            if i > 0 {
+                subresources_.push_str(", ");
+            }
+            write!(subresources_, "{subresource:?}")?;
+        }
+
+        writeln!(
+            impls,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_discoverable_resource.rs")),
+            local = local,
+            rust_type = rust_type,
+            group = group,
+            version = version,
+            kind = kind,
+            plural = plural,
+            scope = match scope {
+                Scope::Cluster => "Cluster",
+                Scope::Namespace => "Namespace",
+            },
+            verbs = verbs_,
+            subresources = subresources_,
+        )?;
+    }
+
+    for SubresourceOf { parent_rust_type, rust_type, name } in subresources_of {
+        writeln!(
+            impls,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_subresource_of.rs")),
+            local = local,
+            parent_rust_type = parent_rust_type,
+            rust_type = rust_type,
+            name = name,
+        )?;
+    }
+
+    write!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/api_resources.rs")),
+        impls = impls,
+    )?;
+
+    Ok(())
+}
+
+/// A resource that is served under its own URL path.
+pub(crate) struct ApiResource<'a> {
+    pub(crate) rust_type: &'a str,
+    pub(crate) group: &'a str,
+    pub(crate) version: &'a str,
+    pub(crate) kind: &'a str,
+    pub(crate) plural: &'a str,
+    pub(crate) scope: Scope,
+    pub(crate) verbs: Vec<Verb>,
+    pub(crate) subresources: Vec<&'a str>,
+}
+
+/// A type that is the subresource with the given name of a parent resource.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct SubresourceOf<'a> {
+    pub(crate) parent_rust_type: &'a str,
+    pub(crate) rust_type: &'a str,
+    pub(crate) name: &'a str,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Scope {
+    Cluster,
+    Namespace,
+}
+
+/// The variants have the same names and order as those of `k8s_openapi::Verb`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Verb {
+    Get,
+    List,
+    Watch,
+    Create,
+    Update,
+    Patch,
+    Delete,
+    DeleteCollection,
+}
@@ -3,6 +3,7 @@ pub(crate) fn generate(
     type_name: &str,
     generics: super::Generics<'_>,
     fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
     map_namespace: &impl crate::MapNamespace,
     resource_metadata: Option<&super::ResourceMetadata<'_>>,
 ) -> Result<(), crate::Error> {
@@ -17,13 +18,11 @@ pub(crate) fn generate(
     let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
     let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
 
-    let (visitor_field, visitor_create_field) =
-        if type_generics_type.is_empty() {
-            (String::new(), "")
-        }
-        else {
-            (format!("(core::marker::PhantomData{type_generics_type})"), "(Default::default())")
-        };
+    let (visitor_field, visitor_create_field) = match generics.type_part {
+        None => (String::new(), ""),
+        Some(part) if part.starts_with('\'') => (format!("(core::marker::PhantomData<&{part} ()>)"), "(Default::default())"),
+        Some(_) => (format!("(core::marker::PhantomData{type_generics_type})"), "(Default::default())"),
+    };
 
     let mut fields_string = String::new();
     let mut str_to_field_match_arms = String::new();
@@ -128,6 +127,10 @@ pub(crate) fn generate(
     }
 
     if let Some((field_name, _)) = flattened_field {
+        if unknown_fields.preserve() {
+            return Err(format!("{type_name} has a flattened field, so it cannot also preserve unknown fields").into());
+        }
+
         writeln!(fields_string, "            Other(String),")?;
 
         writeln!(str_to_field_match_arms, "                            v => Field::Other(v.into()),")?;
@@ -145,12 +148,37 @@ pub(crate) fn generate(
             "                    }},")?;
     }
     else {
-        writeln!(fields_string, "            Other,")?;
+        if unknown_fields.preserve() {
+            writeln!(fields_string, "            Other(std::string::String),")?;
+        }
+        if !unknown_fields.preserve() {
+            writeln!(fields_string, "            Other,")?;
+        }
 
-        writeln!(str_to_field_match_arms, "                            _ => Field::Other,")?;
+        if unknown_fields.preserve() {
+            writeln!(str_to_field_match_arms, "                            v => Field::Other(v.into()),")?;
+        }
+        if !unknown_fields.preserve() {
+            writeln!(str_to_field_match_arms, "                            _ => Field::Other,")?;
+        }
 
-        writeln!(field_value_match_arms,
-            "                        Field::Other => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},")?;
+        if unknown_fields.preserve() {
+            writeln!(field_value_defs,
+                "                let mut value_unknown_fields: std::collections::BTreeMap<std::string::String, {local}serde_json::Value> = Default::default();")?;
+        }
+
+        if unknown_fields.preserve() {
+            writeln!(field_value_match_arms,
+                "                        Field::Other(key) => {{ value_unknown_fields.insert(key, {local}serde::de::MapAccess::next_value(&mut map)?); }},")?;
+        }
+        if !unknown_fields.preserve() {
+            writeln!(field_value_match_arms,
+                "                        Field::Other => {{ let _: {local}serde::de::IgnoredAny = {local}serde::de::MapAccess::next_value(&mut map)?; }},")?;
+        }
+
+        if unknown_fields.preserve() {
+            writeln!(field_value_assignment, "                    unknown_fields: value_unknown_fields,")?;
+        }
     }
 
     let deserialize_type_name =
@@ -161,6 +189,22 @@ pub(crate) fn generate(
             format!("{type_name:?}")
         };
 
+    let mut deserialize = String::new();
+    if flattened_field.is_some() {
+        // The keys of the flattened field aren't known statically, so this isn't deserialized as a struct.
+        // This matches what `#[derive(serde::Deserialize)]` does for `#[serde(flatten)]`.
+        writeln!(deserialize, "        deserializer.deserialize_map(Visitor{visitor_create_field})")?;
+    }
+    else {
+        writeln!(deserialize, "        deserializer.deserialize_struct(")?;
+        writeln!(deserialize, "            {deserialize_type_name},")?;
+        writeln!(deserialize, "            &[")?;
+        deserialize.push_str(&field_name_list);
+        writeln!(deserialize, "            ],")?;
+        writeln!(deserialize, "            Visitor{visitor_create_field},")?;
+        writeln!(deserialize, "        )")?;
+    }
+
     let visitor_expecting_type_name =
         if resource_metadata.is_some() {
             format!("<Self::Value as {local}Resource>::KIND")
@@ -182,10 +226,8 @@ pub(crate) fn generate(
         field_value_defs = field_value_defs,
         field_value_match_arms = field_value_match_arms,
         field_value_assignment = field_value_assignment,
-        field_name_list = field_name_list,
         visitor_field = visitor_field,
-        visitor_create_field = visitor_create_field,
-        deserialize_type_name = deserialize_type_name,
+        deserialize = deserialize,
         visitor_expecting_type_name = visitor_expecting_type_name,
     )?;
 
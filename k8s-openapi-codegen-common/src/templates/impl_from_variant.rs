@@ -0,0 +1,27 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    owned_type_name: &str,
+    conversions: &[(&str, String)],
+    unknown_fields: super::UnknownFields,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let mut fields = String::new();
+    for (field_name, conversion) in conversions {
+        writeln!(fields, "            {field_name}: {conversion},")?;
+    }
+    if unknown_fields.preserve() {
+        writeln!(fields, "            unknown_fields: value.unknown_fields,")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_from_variant.rs")),
+        type_name = type_name,
+        owned_type_name = owned_type_name,
+        fields = fields,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    list_map_keys: ListMapKeys<'_>,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_impl = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    let mut body = String::new();
+
+    match list_map_keys {
+        ListMapKeys::Fields(fields) => {
+            // Fields with the same merge keys or the same type share an arm.
+            let mut own_arms: Vec<(String, Vec<&str>)> = vec![];
+            let mut nested_arms: Vec<(String, Vec<&str>)> = vec![];
+
+            for Field { name, rust_type, merge_type, contains_list_maps } in fields {
+                if let crate::swagger20::MergeType::List { strategy: crate::swagger20::KubernetesListType::Map, keys, item_merge_type: _ } = merge_type {
+                    let arm_body = format!(
+                        "Some(&[{keys}])",
+                        keys = keys.iter().map(|k| format!("{:?}", k.replace("()", ""))).collect::<Vec<_>>().join(", "),
+                    );
+                    push_arm(&mut own_arms, arm_body, name);
+                }
+
+                if contains_list_maps {
+                    let arm_body = format!("<{rust_type} as {local}unknown_fields::ListMapKeys>::list_map_keys(path)");
+                    push_arm(&mut nested_arms, arm_body, name);
+                }
+            }
+
+            if !own_arms.is_empty() || !nested_arms.is_empty() {
+                writeln!(body)?;
+                writeln!(body, "    fn list_map_keys(path: &[{local}unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {{")?;
+                writeln!(body, "        match path {{")?;
+                for (pattern, arms) in [
+                    (format!("[{local}unknown_fields::PathSegment::Key(key)]"), own_arms),
+                    (format!("[{local}unknown_fields::PathSegment::Key(key), path @ ..]"), nested_arms),
+                ] {
+                    if arms.is_empty() {
+                        continue;
+                    }
+
+                    writeln!(body, "            {pattern} => match &**key {{")?;
+                    for (arm_body, names) in arms {
+                        writeln!(
+                            body,
+                            "                {names} => {arm_body},",
+                            names = names.iter().map(|name| format!("{name:?}")).collect::<Vec<_>>().join(" | "),
+                        )?;
+                    }
+                    writeln!(body, "                _ => None,")?;
+                    writeln!(body, "            }},")?;
+                }
+                writeln!(body, "            _ => None,")?;
+                writeln!(body, "        }}")?;
+                writeln!(body, "    }}")?;
+            }
+        },
+
+        ListMapKeys::Owned(owned_type_name) => {
+            writeln!(body)?;
+            writeln!(body, "    fn list_map_keys(path: &[{local}unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {{")?;
+            writeln!(body, "        <{owned_type_name} as {local}unknown_fields::ListMapKeys>::list_map_keys(path)")?;
+            writeln!(body, "    }}")?;
+        },
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_list_map_keys.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_impl = type_generics_impl,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        body = body,
+    )?;
+
+    Ok(())
+}
+
+fn push_arm<'a>(arms: &mut Vec<(String, Vec<&'a str>)>, arm_body: String, name: &'a str) {
+    if let Some((_, names)) = arms.iter_mut().find(|(existing_arm_body, _)| *existing_arm_body == arm_body) {
+        names.push(name);
+    }
+    else {
+        arms.push((arm_body, vec![name]));
+    }
+}
+
+/// Where the merge keys of the lists in a value of a type come from.
+pub(crate) enum ListMapKeys<'a> {
+    /// The type is a struct with the given fields.
+    Fields(Vec<Field<'a>>),
+
+    /// The type is a variant of the given owned type, so it has the same lists.
+    Owned(&'a str),
+}
+
+pub(crate) struct Field<'a> {
+    /// The name of the field in the JSON representation of the struct.
+    pub(crate) name: &'a str,
+    pub(crate) rust_type: &'a str,
+    pub(crate) merge_type: &'a crate::swagger20::MergeType,
+
+    /// Whether the value of the field can itself contain lists with `x-kubernetes-list-type: map`.
+    pub(crate) contains_list_maps: bool,
+}
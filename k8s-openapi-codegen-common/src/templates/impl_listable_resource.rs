@@ -8,7 +8,7 @@ pub(crate) fn generate(
     if let Some(list_kind) = resource_metadata.list_kind {
         let local = crate::map_namespace_local_to_string(map_namespace)?;
 
-        let type_generics_impl = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+        let type_generics_impl = generics.type_part.filter(|part| *part != "'_").map(|part| format!("<{part}>")).unwrap_or_default();
         let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
         let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
 
@@ -0,0 +1,449 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    message: Message<'_>,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_impl = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    let mut encode_body = String::new();
+    let mut decode_body = String::new();
+    let mut decode_buf = "mut buf";
+
+    match message {
+        Message::Struct(fields, unknown_fields) => {
+            let mut field_value_defs = String::new();
+            let mut field_value_assignment = String::new();
+
+            // The properties keyed by field number, so that they're written in the same order as the golang type writes them.
+            let mut fields_by_number: std::collections::BTreeMap<u32, NumberedField<'_>> = Default::default();
+
+            for property in fields {
+                let super::Property { name, field_name, field_type_name, required, protobuf_field_number, .. } = property;
+
+                let Some(protobuf_field_number) = protobuf_field_number else {
+                    // apiVersion and kind are not part of the protobuf message. For resources they are encoded in the `runtime.Unknown` envelope instead,
+                    // and other types like `DeleteOptions` leave them unset.
+                    writeln!(field_value_assignment, "            {field_name}: Default::default(),")?;
+                    continue;
+                };
+
+                let (embedded_in, field_number) = match protobuf_field_number {
+                    crate::swagger20::ProtobufFieldNumber::Field(field_number) => (&[][..], *field_number),
+                    crate::swagger20::ProtobufFieldNumber::Embedded { embedded_in, field_number } => (&**embedded_in, *field_number),
+                };
+
+                let mut fields_by_number = &mut fields_by_number;
+                for &embedded_in in embedded_in {
+                    let NumberedField::Embedded(embedded_fields) =
+                        fields_by_number.entry(embedded_in).or_insert_with(|| NumberedField::Embedded(Default::default()))
+                    else {
+                        return Err(format!("{type_name}.{name} is embedded in field {embedded_in} which is not an embedded message").into());
+                    };
+                    fields_by_number = embedded_fields;
+                }
+
+                match fields_by_number.entry(field_number) {
+                    std::collections::btree_map::Entry::Vacant(entry) => { entry.insert(NumberedField::Field(property)); },
+                    std::collections::btree_map::Entry::Occupied(_) =>
+                        return Err(format!("{type_name}.{name} has the same field number {field_number} as another field").into()),
+                }
+
+                match required {
+                    super::PropertyRequired::Required { .. } =>
+                        writeln!(field_value_defs, "        let mut value_{field_name}: Option<{field_type_name}> = None;")?,
+
+                    super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault =>
+                        writeln!(field_value_defs, "        let mut value_{field_name}: {field_type_name} = None;")?,
+                }
+
+                // Like the Deserialize impl, a missing required field is treated as its default value if it has one.
+                match required {
+                    super::PropertyRequired::Required { is_default: true } =>
+                        writeln!(field_value_assignment, "            {field_name}: value_{field_name}.unwrap_or_default(),")?,
+
+                    super::PropertyRequired::Required { is_default: false } =>
+                        writeln!(field_value_assignment,
+                            "            {field_name}: value_{field_name}.ok_or({local}protobuf::DecodeError::MissingField({name:?}))?,")?,
+
+                    super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault =>
+                        writeln!(field_value_assignment, "            {field_name}: value_{field_name},")?,
+                }
+            }
+
+            let mut field_value_match_arms = String::new();
+            write_numbered_fields(&fields_by_number, 0, &mut encode_body, &mut field_value_match_arms, &local)?;
+
+            decode_body.push_str(&field_value_defs);
+            if !field_value_defs.is_empty() {
+                writeln!(decode_body)?;
+            }
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            if field_value_match_arms.is_empty() {
+                writeln!(decode_body, "            let (_, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+                writeln!(decode_body, "            {local}protobuf::skip_field(wire_type, &mut buf)?;")?;
+            }
+            else {
+                writeln!(decode_body, "            let (field_number, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+                writeln!(decode_body, "            match field_number {{")?;
+                decode_body.push_str(&field_value_match_arms);
+                writeln!(decode_body, "                _ => {local}protobuf::skip_field(wire_type, &mut buf)?,")?;
+                writeln!(decode_body, "            }}")?;
+            }
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        Ok({type_name} {{")?;
+            decode_body.push_str(&field_value_assignment);
+            if unknown_fields.preserve() {
+                writeln!(decode_body, "            unknown_fields: Default::default(),")?;
+            }
+            writeln!(decode_body, "        }})")?;
+        },
+
+        Message::Timestamp { subsecond_precision: false } => {
+            // Like the golang type, and like the Serialize impl, subsecond precision is dropped.
+            writeln!(encode_body, "        {local}protobuf::encode_timestamp(self.0.as_second(), 0, buf);")?;
+
+            writeln!(decode_body, "        Ok({type_name}({local}protobuf::decode_timestamp(buf)?))")?;
+            decode_buf = "buf";
+        },
+
+        Message::Timestamp { subsecond_precision: true } => {
+            // Like the golang type, and like the Serialize impl, precision is truncated to microseconds.
+            writeln!(encode_body, "        {local}protobuf::encode_timestamp(self.0.as_second(), self.0.subsec_microsecond() * 1000, buf);")?;
+
+            writeln!(decode_body, "        Ok({type_name}({local}protobuf::decode_timestamp(buf)?))")?;
+            decode_buf = "buf";
+        },
+
+        Message::RawJson => {
+            writeln!(encode_body, "        {local}protobuf::encode_raw_json(&self.0, buf);")?;
+
+            writeln!(decode_body, "        Ok({type_name}({local}protobuf::decode_raw_json(buf)?))")?;
+            decode_buf = "buf";
+        },
+
+        Message::EmptyObject => {
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            writeln!(decode_body, "            let (_, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+            writeln!(decode_body, "            {local}protobuf::skip_field(wire_type, &mut buf)?;")?;
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        Ok({type_name}({local}serde_json::Value::Object(Default::default())))")?;
+        },
+
+        Message::Quantity => {
+            writeln!(encode_body, "        {local}protobuf::Field::encode_field(&self.0, 1, buf);")?;
+
+            writeln!(decode_body, "        let mut value_string: Option<std::string::String> = None;")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            writeln!(decode_body, "            let (field_number, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+            writeln!(decode_body, "            match field_number {{")?;
+            writeln!(decode_body, "                1 => {local}protobuf::Field::merge_field(&mut value_string, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                _ => {local}protobuf::skip_field(wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "            }}")?;
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        Ok({type_name}(value_string.unwrap_or_default()))")?;
+        },
+
+        Message::IntOrString => {
+            // The golang type always encodes all three fields.
+            writeln!(encode_body, "        let (value_type, value_int_val, value_str_val) = match self {{")?;
+            writeln!(encode_body, r#"            {type_name}::Int(value) => (0_i64, *value, ""),"#)?;
+            writeln!(encode_body, "            {type_name}::String(value) => (1_i64, 0_i32, &**value),")?;
+            writeln!(encode_body, "        }};")?;
+            writeln!(encode_body, "        {local}protobuf::Field::encode_field(&value_type, 1, buf);")?;
+            writeln!(encode_body, "        {local}protobuf::Field::encode_field(&value_int_val, 2, buf);")?;
+            writeln!(encode_body, "        {local}protobuf::encode_length_delimited(3, value_str_val.as_bytes(), buf);")?;
+
+            writeln!(decode_body, "        let mut value_type: Option<i64> = None;")?;
+            writeln!(decode_body, "        let mut value_int_val: Option<i32> = None;")?;
+            writeln!(decode_body, "        let mut value_str_val: Option<std::string::String> = None;")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            writeln!(decode_body, "            let (field_number, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+            writeln!(decode_body, "            match field_number {{")?;
+            writeln!(decode_body, "                1 => {local}protobuf::Field::merge_field(&mut value_type, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                2 => {local}protobuf::Field::merge_field(&mut value_int_val, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                3 => {local}protobuf::Field::merge_field(&mut value_str_val, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                _ => {local}protobuf::skip_field(wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "            }}")?;
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        Ok(match value_type {{")?;
+            writeln!(decode_body, "            Some(1) => {type_name}::String(value_str_val.unwrap_or_default()),")?;
+            writeln!(decode_body, "            _ => {type_name}::Int(value_int_val.unwrap_or_default()),")?;
+            writeln!(decode_body, "        }})")?;
+        },
+
+        Message::JsonSchemaPropsOr { or, json_schema_props_type_name } => {
+            // The golang types are structs with a field for the schema and a field for the alternative.
+            let (or_variant_name, or_variant_type, schema_field_number, or_field_number) = match or {
+                super::json_schema_props_or::Or::Array => ("Schemas", format!("std::vec::Vec<{json_schema_props_type_name}>"), 1, 2),
+                super::json_schema_props_or::Or::Bool => ("Bool", "bool".to_owned(), 2, 1),
+                super::json_schema_props_or::Or::StringArray => ("Strings", "std::vec::Vec<std::string::String>".to_owned(), 1, 2),
+            };
+
+            writeln!(encode_body, "        match self {{")?;
+            writeln!(encode_body, "            {type_name}::Schema(value) => {{")?;
+            if let super::json_schema_props_or::Or::Bool = or {
+                writeln!(encode_body, "                {local}protobuf::Field::encode_field(&true, {or_field_number}, buf);")?;
+            }
+            writeln!(encode_body, "                {local}protobuf::Field::encode_field(value, {schema_field_number}, buf);")?;
+            writeln!(encode_body, "            }},")?;
+            writeln!(encode_body, "            {type_name}::{or_variant_name}(value) => {local}protobuf::Field::encode_field(value, {or_field_number}, buf),")?;
+            writeln!(encode_body, "        }}")?;
+
+            writeln!(decode_body, "        let mut value_schema: Option<std::boxed::Box<{json_schema_props_type_name}>> = None;")?;
+            writeln!(decode_body, "        let mut value_or: Option<{or_variant_type}> = None;")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            writeln!(decode_body, "            let (field_number, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+            writeln!(decode_body, "            match field_number {{")?;
+            writeln!(decode_body, "                {schema_field_number} => {local}protobuf::Field::merge_field(&mut value_schema, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                {or_field_number} => {local}protobuf::Field::merge_field(&mut value_or, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                _ => {local}protobuf::skip_field(wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "            }}")?;
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            if let super::json_schema_props_or::Or::Bool = or {
+                writeln!(decode_body, "        Ok(match value_schema {{")?;
+                writeln!(decode_body, "            Some(value_schema) => {type_name}::Schema(value_schema),")?;
+                writeln!(decode_body, "            None => {type_name}::Bool(value_or.unwrap_or_default()),")?;
+                writeln!(decode_body, "        }})")?;
+            }
+            else {
+                writeln!(decode_body, "        Ok(match (value_schema, value_or) {{")?;
+                writeln!(decode_body, "            (Some(value_schema), None) => {type_name}::Schema(value_schema),")?;
+                writeln!(decode_body, "            (_, value_or) => {type_name}::{or_variant_name}(value_or.unwrap_or_default()),")?;
+                writeln!(decode_body, "        }})")?;
+            }
+        },
+
+        Message::WatchEvent { error_status_rust_type, error_other_rust_type, object_meta_rust_type } => {
+            // The object is a `RawExtension` whose `raw` field holds the object in the same `runtime.Unknown` envelope as a standalone object.
+            //
+            // Bookmark events only contain the object's metadata, which by convention is field number 1 of every resource message.
+            writeln!(encode_body, "        let (value_type, raw) = match self {{")?;
+            writeln!(encode_body, r#"            {type_name}::Added(object) => ("ADDED", {local}protobuf::encode(object)),"#)?;
+            writeln!(encode_body, r#"            {type_name}::Deleted(object) => ("DELETED", {local}protobuf::encode(object)),"#)?;
+            writeln!(encode_body, r#"            {type_name}::Modified(object) => ("MODIFIED", {local}protobuf::encode(object)),"#)?;
+            writeln!(encode_body, "            {type_name}::Bookmark {{ annotations, resource_version }} => {{")?;
+            writeln!(encode_body, "                let metadata = {object_meta_rust_type} {{")?;
+            writeln!(encode_body, "                    annotations: Some(annotations.clone()),")?;
+            writeln!(encode_body, "                    resource_version: Some(resource_version.clone()),")?;
+            writeln!(encode_body, "                    ..Default::default()")?;
+            writeln!(encode_body, "                }};")?;
+            writeln!(encode_body, "                let mut raw = std::vec![];")?;
+            writeln!(encode_body, "                {local}protobuf::Field::encode_field(&metadata, 1, &mut raw);")?;
+            writeln!(encode_body, "                let unknown = {local}protobuf::Unknown {{")?;
+            writeln!(encode_body, "                    api_version: <T as {local}Resource>::API_VERSION.into(),")?;
+            writeln!(encode_body, "                    kind: <T as {local}Resource>::KIND.into(),")?;
+            writeln!(encode_body, "                    raw,")?;
+            writeln!(encode_body, "                    content_encoding: std::string::String::new(),")?;
+            writeln!(encode_body, "                    content_type: std::string::String::new(),")?;
+            writeln!(encode_body, "                }};")?;
+            writeln!(encode_body, r#"                ("BOOKMARK", unknown.encode_with_magic())"#)?;
+            writeln!(encode_body, "            }},")?;
+            writeln!(encode_body, r#"            {type_name}::ErrorStatus(status) => ("ERROR", {local}protobuf::encode(status)),"#)?;
+            writeln!(encode_body,
+                r#"            {type_name}::ErrorOther(value) => ("ERROR", {local}serde_json::to_vec(&value.0).expect("serde_json::Value always serializes successfully")),"#)?;
+            writeln!(encode_body, "        }};")?;
+            writeln!(encode_body, "        {local}protobuf::encode_length_delimited(1, value_type.as_bytes(), buf);")?;
+            writeln!(encode_body, "        let mut object = std::vec![];")?;
+            writeln!(encode_body, "        {local}protobuf::encode_raw(&raw, &mut object);")?;
+            writeln!(encode_body, "        {local}protobuf::encode_length_delimited(2, &object, buf);")?;
+
+            writeln!(decode_body, "        let mut value_type: Option<std::string::String> = None;")?;
+            writeln!(decode_body, "        let mut value_object: Option<&[u8]> = None;")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        while !buf.is_empty() {{")?;
+            writeln!(decode_body, "            let (field_number, wire_type) = {local}protobuf::decode_key(&mut buf)?;")?;
+            writeln!(decode_body, "            match field_number {{")?;
+            writeln!(decode_body, "                1 => {local}protobuf::Field::merge_field(&mut value_type, wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "                2 => value_object = Some({local}protobuf::decode_length_delimited(wire_type, &mut buf)?),")?;
+            writeln!(decode_body, "                _ => {local}protobuf::skip_field(wire_type, &mut buf)?,")?;
+            writeln!(decode_body, "            }}")?;
+            writeln!(decode_body, "        }}")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, r#"        let value_type = value_type.ok_or({local}protobuf::DecodeError::MissingField("type"))?;"#)?;
+            writeln!(decode_body, r#"        let value_object = value_object.ok_or({local}protobuf::DecodeError::MissingField("object"))?;"#)?;
+            writeln!(decode_body, "        let raw = {local}protobuf::decode_raw(value_object)?;")?;
+            writeln!(decode_body)?;
+            writeln!(decode_body, "        Ok(match &*value_type {{")?;
+            writeln!(decode_body, r#"            "ADDED" => {type_name}::Added({local}protobuf::decode(raw)?),"#)?;
+            writeln!(decode_body, r#"            "DELETED" => {type_name}::Deleted({local}protobuf::decode(raw)?),"#)?;
+            writeln!(decode_body, r#"            "MODIFIED" => {type_name}::Modified({local}protobuf::decode(raw)?),"#)?;
+            writeln!(decode_body, r#"            "BOOKMARK" => {{"#)?;
+            writeln!(decode_body, "                let unknown = {local}protobuf::Unknown::decode_with_magic(raw)?;")?;
+            writeln!(decode_body, "                let mut raw = &unknown.raw[..];")?;
+            writeln!(decode_body, "                let mut metadata: Option<{object_meta_rust_type}> = None;")?;
+            writeln!(decode_body, "                while !raw.is_empty() {{")?;
+            writeln!(decode_body, "                    let (field_number, wire_type) = {local}protobuf::decode_key(&mut raw)?;")?;
+            writeln!(decode_body, "                    match field_number {{")?;
+            writeln!(decode_body, "                        1 => {local}protobuf::Field::merge_field(&mut metadata, wire_type, &mut raw)?,")?;
+            writeln!(decode_body, "                        _ => {local}protobuf::skip_field(wire_type, &mut raw)?,")?;
+            writeln!(decode_body, "                    }}")?;
+            writeln!(decode_body, "                }}")?;
+            writeln!(decode_body, "                let metadata = metadata.unwrap_or_default();")?;
+            writeln!(decode_body, "                {type_name}::Bookmark {{")?;
+            writeln!(decode_body, "                    annotations: metadata.annotations.unwrap_or_default(),")?;
+            writeln!(decode_body, "                    resource_version: metadata.resource_version.unwrap_or_default(),")?;
+            writeln!(decode_body, "                }}")?;
+            writeln!(decode_body, "            }},")?;
+            writeln!(decode_body, r#"            "ERROR" => match {local}protobuf::Unknown::decode_with_magic(raw) {{"#)?;
+            writeln!(decode_body,
+                r#"                Ok(unknown) if unknown.kind == <{error_status_rust_type} as {local}Resource>::KIND => {type_name}::ErrorStatus({local}protobuf::decode(raw)?),"#)?;
+            writeln!(decode_body, "                _ => {type_name}::ErrorOther({error_other_rust_type}(")?;
+            writeln!(decode_body, r#"                    {local}serde_json::from_slice(raw).map_err(|_| {local}protobuf::DecodeError::InvalidValue("a JSON value"))?,"#)?;
+            writeln!(decode_body, "                )),")?;
+            writeln!(decode_body, "            }},")?;
+            writeln!(decode_body, r#"            _ => return Err({local}protobuf::DecodeError::InvalidValue("a watch event type")),"#)?;
+            writeln!(decode_body, "        }})")?;
+        },
+    }
+
+    // Messages with no fields don't write anything.
+    let encode_buf = if encode_body.is_empty() { "_buf" } else { "buf" };
+
+    let mut decode_optional = String::new();
+    if let Message::Timestamp { .. } = message {
+        // The golang type encodes its zero value as an empty message, and as `null` in JSON.
+        writeln!(decode_optional)?;
+        writeln!(decode_optional)?;
+        writeln!(decode_optional, "    fn decode_optional(buf: &[u8]) -> Result<Option<Self>, {local}protobuf::DecodeError> {{")?;
+        writeln!(decode_optional, "        if buf.is_empty() {{")?;
+        writeln!(decode_optional, "            Ok(None)")?;
+        writeln!(decode_optional, "        }}")?;
+        writeln!(decode_optional, "        else {{")?;
+        writeln!(decode_optional, "            Self::decode(buf).map(Some)")?;
+        writeln!(decode_optional, "        }}")?;
+        write!(decode_optional, "    }}")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_protobuf_message.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_impl = type_generics_impl,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        encode_buf = encode_buf,
+        encode_body = encode_body,
+        decode_buf = decode_buf,
+        decode_body = decode_body,
+        decode_optional = decode_optional,
+    )?;
+
+    Ok(())
+}
+
+/// A field number of a [`Message::Struct`], and the property or the properties of the embedded struct that use it.
+enum NumberedField<'a> {
+    Field(&'a super::Property<'a>),
+    Embedded(std::collections::BTreeMap<u32, NumberedField<'a>>),
+}
+
+/// Writes the code that encodes the given fields and the match arms that decode them.
+///
+/// `depth` is the number of embedded messages that the fields are nested in.
+fn write_numbered_fields(
+    fields_by_number: &std::collections::BTreeMap<u32, NumberedField<'_>>,
+    depth: usize,
+    encode_body: &mut String,
+    decode_match_arms: &mut String,
+    local: &str,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let embedded_buf_name = |depth| if depth == 1 { "embedded".to_owned() } else { format!("embedded_{depth}") };
+
+    let (encode_buf, decode_buf) =
+        if depth == 0 {
+            ("buf".to_owned(), "buf".to_owned())
+        }
+        else {
+            let embedded_buf_name = embedded_buf_name(depth);
+            (format!("&mut {embedded_buf_name}"), embedded_buf_name)
+        };
+    let encode_indent = " ".repeat(8 + 4 * depth);
+    let decode_indent = " ".repeat(16 + 12 * depth);
+
+    for (field_number, numbered_field) in fields_by_number {
+        match numbered_field {
+            NumberedField::Field(super::Property { field_name, required, .. }) => {
+                match required {
+                    super::PropertyRequired::Required { .. } =>
+                        writeln!(encode_body, "{encode_indent}{local}protobuf::Field::encode_field(&self.{field_name}, {field_number}, {encode_buf});")?,
+
+                    super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault => {
+                        writeln!(encode_body, "{encode_indent}if let Some(value) = &self.{field_name} {{")?;
+                        writeln!(encode_body, "{encode_indent}    {local}protobuf::Field::encode_field(value, {field_number}, {encode_buf});")?;
+                        writeln!(encode_body, "{encode_indent}}}")?;
+                    },
+                }
+
+                writeln!(decode_match_arms,
+                    "{decode_indent}{field_number} => {local}protobuf::Field::merge_field(&mut value_{field_name}, wire_type, &mut {decode_buf})?,")?;
+            },
+
+            NumberedField::Embedded(embedded_fields) => {
+                let embedded_buf_name = embedded_buf_name(depth + 1);
+
+                // Like the golang type, the embedded struct is always encoded, even if it has no fields set.
+                writeln!(encode_body, "{encode_indent}{{")?;
+                writeln!(encode_body, "{encode_indent}    let mut {embedded_buf_name} = std::vec![];")?;
+                let mut embedded_decode_match_arms = String::new();
+                write_numbered_fields(embedded_fields, depth + 1, encode_body, &mut embedded_decode_match_arms, local)?;
+                writeln!(encode_body, "{encode_indent}    {local}protobuf::encode_length_delimited({field_number}, &{embedded_buf_name}, {encode_buf});")?;
+                writeln!(encode_body, "{encode_indent}}}")?;
+
+                writeln!(decode_match_arms, "{decode_indent}{field_number} => {{")?;
+                writeln!(decode_match_arms,
+                    "{decode_indent}    let mut {embedded_buf_name} = {local}protobuf::decode_length_delimited(wire_type, &mut {decode_buf})?;")?;
+                writeln!(decode_match_arms, "{decode_indent}    while !{embedded_buf_name}.is_empty() {{")?;
+                writeln!(decode_match_arms,
+                    "{decode_indent}        let (field_number, wire_type) = {local}protobuf::decode_key(&mut {embedded_buf_name})?;")?;
+                writeln!(decode_match_arms, "{decode_indent}        match field_number {{")?;
+                decode_match_arms.push_str(&embedded_decode_match_arms);
+                writeln!(decode_match_arms, "{decode_indent}            _ => {local}protobuf::skip_field(wire_type, &mut {embedded_buf_name})?,")?;
+                writeln!(decode_match_arms, "{decode_indent}        }}")?;
+                writeln!(decode_match_arms, "{decode_indent}    }}")?;
+                writeln!(decode_match_arms, "{decode_indent}}},")?;
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Message<'a> {
+    Struct(&'a [super::Property<'a>], super::UnknownFields),
+    Timestamp {
+        subsecond_precision: bool,
+    },
+    RawJson,
+    EmptyObject,
+    Quantity,
+    IntOrString,
+    JsonSchemaPropsOr {
+        or: super::json_schema_props_or::Or,
+        json_schema_props_type_name: &'a str,
+    },
+    WatchEvent {
+        error_status_rust_type: &'a str,
+        error_other_rust_type: &'a str,
+        object_meta_rust_type: &'a str,
+    },
+}
@@ -269,6 +269,7 @@ fn gen_type(
             match format {
                 Some(swagger20::StringFormat::Byte) => writeln!(out, r#"{indent}"format": "byte","#)?,
                 Some(swagger20::StringFormat::DateTime) => writeln!(out, r#"{indent}"format": "date-time","#)?,
+                Some(swagger20::StringFormat::Duration) => writeln!(out, r#"{indent}"format": "duration","#)?,
                 None => (),
             }
         }
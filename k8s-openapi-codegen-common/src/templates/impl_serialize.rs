@@ -3,6 +3,7 @@ pub(crate) fn generate(
     type_name: &str,
     generics: super::Generics<'_>,
     fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
     map_namespace: &impl crate::MapNamespace,
     resource_metadata: Option<&super::ResourceMetadata<'_>>,
 ) -> Result<(), crate::Error> {
@@ -17,34 +18,50 @@ pub(crate) fn generate(
     let mut fields_string = String::new();
     let mut required_fields_num = 0_usize;
     let mut fields_num = vec![];
-    let mut has_flattened_field = false;
+
+    // The keys of unknown fields are not `&'static str`, so they can't be serialized with `serde::ser::SerializeStruct`.
+    let serialize_unknown_fields = unknown_fields.preserve();
+
+    // The flattened field's keys are also not known statically, so the struct is serialized as a map in that case too.
+    let has_flattened_field = fields.iter().any(|field| field.is_flattened);
+    let serialize_map = serialize_unknown_fields || has_flattened_field;
+
+    let serialize_field =
+        if serialize_map {
+            format!("{local}serde::ser::SerializeMap::serialize_entry")
+        }
+        else {
+            format!("{local}serde::ser::SerializeStruct::serialize_field")
+        };
 
     if resource_metadata.is_some() {
         writeln!(fields_string,
-            r#"        {local}serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as {local}Resource>::API_VERSION)?;"#)?;
+            r#"        {serialize_field}(&mut state, "apiVersion", <Self as {local}Resource>::API_VERSION)?;"#)?;
         writeln!(fields_string,
-            r#"        {local}serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as {local}Resource>::KIND)?;"#)?;
+            r#"        {serialize_field}(&mut state, "kind", <Self as {local}Resource>::KIND)?;"#)?;
 
         required_fields_num += 2;
     }
 
     for super::Property { name, field_name, required, is_flattened, .. } in fields {
         if *is_flattened {
-            writeln!(fields_string, "        {local}serde::Serialize::serialize(&self.{field_name}, SerializerWrapper(&mut state))?;")?;
+            if serialize_unknown_fields {
+                return Err(format!("{type_name} has a flattened field, so it cannot also preserve unknown fields").into());
+            }
 
-            has_flattened_field = true;
+            writeln!(fields_string, "        {local}serde::Serialize::serialize(&self.{field_name}, SerializerWrapper(&mut state))?;")?;
         }
         else {
             match required {
                 super::PropertyRequired::Required { is_default: _ } => {
-                    writeln!(fields_string, "        {local}serde::ser::SerializeStruct::serialize_field(&mut state, {name:?}, &self.{field_name})?;")?;
+                    writeln!(fields_string, "        {serialize_field}(&mut state, {name:?}, &self.{field_name})?;")?;
 
                     required_fields_num += 1;
                 },
 
                 super::PropertyRequired::Optional => {
                     writeln!(fields_string, "        if let Some(value) = &self.{field_name} {{")?;
-                    writeln!(fields_string, "            {local}serde::ser::SerializeStruct::serialize_field(&mut state, {name:?}, value)?;")?;
+                    writeln!(fields_string, "            {serialize_field}(&mut state, {name:?}, value)?;")?;
                     writeln!(fields_string, "        }}")?;
 
                     fields_num.push(format!("self.{field_name}.as_ref().map_or(0, |_| 1)"));
@@ -52,7 +69,7 @@ pub(crate) fn generate(
 
                 super::PropertyRequired::OptionalDefault => {
                     writeln!(fields_string, "        if !self.{field_name}.is_empty() {{")?;
-                    writeln!(fields_string, "            {local}serde::ser::SerializeStruct::serialize_field(&mut state, {name:?}, &self.{field_name})?;")?;
+                    writeln!(fields_string, "            {serialize_field}(&mut state, {name:?}, &self.{field_name})?;")?;
                     writeln!(fields_string, "        }}")?;
 
                     fields_num.push(format!("usize::from(!self.{field_name}.is_empty())"));
@@ -61,6 +78,14 @@ pub(crate) fn generate(
         }
     }
 
+    if serialize_unknown_fields {
+        writeln!(fields_string, "        for (key, value) in &self.unknown_fields {{")?;
+        writeln!(fields_string, "            {serialize_field}(&mut state, key, value)?;")?;
+        writeln!(fields_string, "        }}")?;
+
+        fields_num.push("self.unknown_fields.len()".to_owned());
+    }
+
     let fields_num: std::borrow::Cow<'_, str> = match (required_fields_num, fields_num.is_empty()) {
         (0, true) => "            0".into(),
 
@@ -108,15 +133,15 @@ pub(crate) fn generate(
 
             writeln!(out, "        struct SerializerWrapper<'a, S>(&'a mut S);")?;
             writeln!(out)?;
-            writeln!(out, "        impl<'a, S> {local}serde::Serializer for SerializerWrapper<'a, S> where S: {local}serde::ser::SerializeStruct {{")?;
+            writeln!(out, "        impl<'a, S> {local}serde::Serializer for SerializerWrapper<'a, S> where S: {local}serde::ser::SerializeMap {{")?;
             writeln!(out, "            type Ok = ();")?;
-            writeln!(out, "            type Error = <S as {local}serde::ser::SerializeStruct>::Error;")?;
+            writeln!(out, "            type Error = <S as {local}serde::ser::SerializeMap>::Error;")?;
             writeln!(out)?;
             writeln!(out, "            type SerializeSeq = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
             writeln!(out, "            type SerializeTuple = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
             writeln!(out, "            type SerializeTupleStruct = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
             writeln!(out, "            type SerializeTupleVariant = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
-            writeln!(out, "            type SerializeMap = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
+            writeln!(out, "            type SerializeMap = SerializerStructWrapper<'a, S>;")?;
             writeln!(out, "            type SerializeStruct = SerializerStructWrapper<'a, S>;")?;
             writeln!(out, "            type SerializeStructVariant = {local}serde::ser::Impossible<Self::Ok, Self::Error>;")?;
             writeln!(out)?;
@@ -237,7 +262,7 @@ pub(crate) fn generate(
             writeln!(out, "            }}")?;
             writeln!(out)?;
             writeln!(out, "            fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {{")?;
-            writeln!(out, r#"                Err({local}serde::ser::Error::custom("map is not supported"))"#)?;
+            writeln!(out, "                Ok(SerializerStructWrapper(self.0))")?;
             writeln!(out, "            }}")?;
             writeln!(out)?;
             writeln!(out, "            fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Self::Error> {{")?;
@@ -257,12 +282,29 @@ pub(crate) fn generate(
             writeln!(out)?;
             writeln!(out, "        struct SerializerStructWrapper<'a, S>(&'a mut S);")?;
             writeln!(out)?;
-            writeln!(out, "        impl<'a, S> {local}serde::ser::SerializeStruct for SerializerStructWrapper<'a, S> where S: {local}serde::ser::SerializeStruct {{")?;
+            writeln!(out, "        impl<'a, S> {local}serde::ser::SerializeStruct for SerializerStructWrapper<'a, S> where S: {local}serde::ser::SerializeMap {{")?;
             writeln!(out, "            type Ok = ();")?;
-            writeln!(out, "            type Error = <S as {local}serde::ser::SerializeStruct>::Error;")?;
+            writeln!(out, "            type Error = <S as {local}serde::ser::SerializeMap>::Error;")?;
             writeln!(out)?;
             writeln!(out, "            fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> where T: {local}serde::Serialize + ?Sized {{")?;
-            writeln!(out, r#"                self.0.serialize_field(key, value)"#)?;
+            writeln!(out, r#"                self.0.serialize_entry(key, value)"#)?;
+            writeln!(out, "            }}")?;
+            writeln!(out)?;
+            writeln!(out, "            fn end(self) -> Result<Self::Ok, Self::Error> {{")?;
+            writeln!(out, r#"                Ok(())"#)?;
+            writeln!(out, "            }}")?;
+            writeln!(out, "        }}")?;
+            writeln!(out)?;
+            writeln!(out, "        impl<S> {local}serde::ser::SerializeMap for SerializerStructWrapper<'_, S> where S: {local}serde::ser::SerializeMap {{")?;
+            writeln!(out, "            type Ok = ();")?;
+            writeln!(out, "            type Error = <S as {local}serde::ser::SerializeMap>::Error;")?;
+            writeln!(out)?;
+            writeln!(out, "            fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error> where T: {local}serde::Serialize + ?Sized {{")?;
+            writeln!(out, r#"                self.0.serialize_key(key)"#)?;
+            writeln!(out, "            }}")?;
+            writeln!(out)?;
+            writeln!(out, "            fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: {local}serde::Serialize + ?Sized {{")?;
+            writeln!(out, r#"                self.0.serialize_value(value)"#)?;
             writeln!(out, "            }}")?;
             writeln!(out)?;
             writeln!(out, "            fn end(self) -> Result<Self::Ok, Self::Error> {{")?;
@@ -277,6 +319,27 @@ pub(crate) fn generate(
             "".into()
         };
 
+    let (state, serialize_trait) =
+        if has_flattened_field {
+            // The number of fields in the flattened field isn't known.
+            (
+                "        let mut state = serializer.serialize_map(None)?;\n".to_owned(),
+                "SerializeMap",
+            )
+        }
+        else if serialize_map {
+            (
+                format!("        let mut state = serializer.serialize_map(Some(\n{fields_num},\n        ))?;\n"),
+                "SerializeMap",
+            )
+        }
+        else {
+            (
+                format!("        let mut state = serializer.serialize_struct(\n            {serialize_type_name},\n{fields_num},\n        )?;\n"),
+                "SerializeStruct",
+            )
+        };
+
     writeln!(
         writer,
         include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/impl_serialize.rs")),
@@ -286,9 +349,9 @@ pub(crate) fn generate(
         type_generics_type = type_generics_type,
         type_generics_where = type_generics_where,
         struct_serializer = struct_serializer,
-        fields_num = fields_num,
+        state = state,
         fields = fields_string,
-        serialize_type_name = serialize_type_name,
+        serialize_trait = serialize_trait,
     )?;
 
     Ok(())
@@ -19,6 +19,12 @@ pub(crate) fn generate(
         Or::StringArray => "std::vec::Vec<std::string::String>".into(),
     };
 
+    let or_variant_normalize = match or {
+        Or::Array | Or::StringArray =>
+            format!("            {type_name}::{or_variant_name}(value) => {local}normalize_strategies::list::atomic(value, {local}Normalize::normalize),\n"),
+        Or::Bool => format!("            {type_name}::{or_variant_name}(_) => (),\n"),
+    };
+
     let mut or_visit = String::new();
     match or {
         Or::Array => {
@@ -56,6 +62,7 @@ pub(crate) fn generate(
         json_schema_props_type_name = json_schema_props_type_name,
         or_variant_name = or_variant_name,
         or_variant_type = or_variant_type,
+        or_variant_normalize = or_variant_normalize,
         or_visit = or_visit,
     )?;
 
@@ -1,11 +1,21 @@
 #![allow(clippy::needless_raw_string_hashes)]
 
+pub(crate) mod any_resource;
+
+pub(crate) mod api_resources;
+
 pub(crate) mod impl_deserialize;
 
+pub(crate) mod impl_from_variant;
+
+pub(crate) mod impl_list_map_keys;
+
 pub(crate) mod impl_listable_resource;
 
 pub(crate) mod impl_metadata;
 
+pub(crate) mod impl_protobuf_message;
+
 pub(crate) mod impl_resource;
 
 pub(crate) mod impl_schema;
@@ -26,8 +36,20 @@ pub(crate) mod r#struct;
 
 pub(crate) mod struct_deep_merge;
 
+pub(crate) mod struct_defaulting;
+
+pub(crate) mod struct_diff;
+
+pub(crate) mod struct_field_paths;
+
+pub(crate) mod struct_normalize;
+
 pub(crate) mod type_header;
 
+pub(crate) mod union;
+
+pub(crate) mod visit;
+
 pub(crate) mod watch_event;
 
 #[derive(Clone, Copy, Default)]
@@ -44,6 +66,7 @@ pub(crate) struct Property<'a> {
     pub(crate) required: PropertyRequired,
     pub(crate) is_flattened: bool,
     pub(crate) merge_type: &'a crate::swagger20::MergeType,
+    pub(crate) protobuf_field_number: Option<crate::swagger20::ProtobufFieldNumber>,
 }
 
 #[derive(Clone, Copy)]
@@ -70,8 +93,25 @@ pub(crate) struct ResourceMetadata<'a> {
 }
 
 #[derive(Clone, Copy)]
-pub(crate) enum DateTimeSerializationFormat {
+pub(crate) enum DateTimeSerializationFormat<'a> {
     Default,
     SixDecimalDigits,
-    ZeroDecimalDigits,
+    ZeroDecimalDigits {
+        /// If set, the type instead serializes with its full precision when this feature is enabled.
+        full_precision_feature: Option<&'a str>,
+    },
+}
+
+/// Whether a struct keeps the keys that its `Deserialize` impl does not recognize in an `unknown_fields` map,
+/// so that its `Serialize` impl can emit them again.
+#[derive(Clone, Copy)]
+pub(crate) enum UnknownFields {
+    Ignore,
+    Preserve,
+}
+
+impl UnknownFields {
+    pub(crate) fn preserve(self) -> bool {
+        matches!(self, UnknownFields::Preserve)
+    }
 }
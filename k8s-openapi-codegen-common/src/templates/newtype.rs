@@ -3,7 +3,7 @@ pub(crate) fn generate(
     vis: &str,
     type_name: &str,
     inner_type_name: &str,
-    datetime_serialization_format: super::DateTimeSerializationFormat,
+    datetime_serialization_format: super::DateTimeSerializationFormat<'_>,
     map_namespace: &impl crate::MapNamespace,
 ) -> Result<(), crate::Error> {
     let local = crate::map_namespace_local_to_string(map_namespace)?;
@@ -18,10 +18,21 @@ pub(crate) fn generate(
     //   and then using it as `serializer.serialize_newtype_struct("Time", &SerializeWithCollectStr(self.0.strftime("...")))`.
     //   This will either require generating that private type within each newtype's `Serialize` impl, or making it a k8s-openapi-level type and referencing that.
     let jiff_fmt = |fmt: &str| format!(r#"&{local}jiff::fmt::strtime::format({fmt:?}, self.0).map_err({local}serde::ser::Error::custom)?"#);
-    let inner_value: std::borrow::Cow<'static, str> = match datetime_serialization_format {
-        super::DateTimeSerializationFormat::Default => "&self.0".into(),
-        super::DateTimeSerializationFormat::SixDecimalDigits => jiff_fmt("%Y-%m-%dT%H:%M:%S%.6fZ").into(),
-        super::DateTimeSerializationFormat::ZeroDecimalDigits => jiff_fmt("%Y-%m-%dT%H:%M:%SZ").into(),
+    let (serialize_prelude, inner_value): (String, std::borrow::Cow<'static, str>) = match datetime_serialization_format {
+        super::DateTimeSerializationFormat::Default => (String::new(), "&self.0".into()),
+        super::DateTimeSerializationFormat::SixDecimalDigits => (String::new(), jiff_fmt("%Y-%m-%dT%H:%M:%S%.6fZ").into()),
+        super::DateTimeSerializationFormat::ZeroDecimalDigits { full_precision_feature: None } => (String::new(), jiff_fmt("%Y-%m-%dT%H:%M:%SZ").into()),
+        super::DateTimeSerializationFormat::ZeroDecimalDigits { full_precision_feature: Some(feature) } => {
+            // The default serialization of `jiff::Timestamp` is RFC 3339 with as many decimal digits as needed, like golang's `time.RFC3339Nano`.
+            let serialize_prelude = format!(
+                "        #[cfg(not(feature = {feature:?}))]\n        \
+                         let value = {};\n        \
+                         #[cfg(feature = {feature:?})]\n        \
+                         let value = &self.0;\n",
+                jiff_fmt("%Y-%m-%dT%H:%M:%SZ"),
+            );
+            (serialize_prelude, "value".into())
+        },
     };
 
     writeln!(
@@ -32,6 +43,7 @@ pub(crate) fn generate(
         vis = vis,
         inner_type_name = inner_type_name,
         inner_value = inner_value,
+        serialize_prelude = serialize_prelude,
     )?;
 
     Ok(())
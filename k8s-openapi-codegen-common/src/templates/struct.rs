@@ -4,9 +4,13 @@ pub(crate) fn generate(
     type_name: &str,
     generics: super::Generics<'_>,
     fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
+    map_namespace: &impl crate::MapNamespace,
 ) -> Result<(), crate::Error> {
     use std::fmt::Write;
 
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
     let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
     let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
 
@@ -28,6 +32,15 @@ pub(crate) fn generate(
         )?;
     }
 
+    if unknown_fields.preserve() {
+        if !fields_string.is_empty() {
+            writeln!(fields_string)?;
+        }
+
+        writeln!(fields_string, "    /// Fields that are not known to this version of the API. They are emitted again when this value is serialized.")?;
+        writeln!(fields_string, "    {vis}unknown_fields: std::collections::BTreeMap<std::string::String, {local}serde_json::Value>,")?;
+    }
+
     writeln!(
         writer,
         include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct.rs")),
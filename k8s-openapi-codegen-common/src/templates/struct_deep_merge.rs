@@ -3,6 +3,7 @@ pub(crate) fn generate(
     type_name: &str,
     generics: super::Generics<'_>,
     fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
     map_namespace: &impl crate::MapNamespace,
 ) -> Result<(), crate::Error> {
     let local = crate::map_namespace_local_to_string(map_namespace)?;
@@ -22,6 +23,20 @@ pub(crate) fn generate(
         )?;
     }
 
+    if unknown_fields.preserve() {
+        generate_field(
+            &mut merge_body,
+            &local,
+            "&mut self.unknown_fields",
+            "other.unknown_fields",
+            &crate::swagger20::MergeType::Map {
+                strategy: crate::swagger20::KubernetesMapType::Granular,
+                value_merge_type: Box::new(crate::swagger20::MergeType::Default),
+            },
+            8,
+        )?;
+    }
+
     writeln!(
         writer,
         include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct_deep_merge.rs")),
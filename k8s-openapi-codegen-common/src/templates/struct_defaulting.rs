@@ -0,0 +1,131 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    fields: &[super::Property<'_>],
+    properties: Option<&std::collections::BTreeMap<crate::swagger20::PropertyName, (crate::swagger20::Schema, bool)>>,
+    defaulting_function: Option<&str>,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    let mut set_defaults_body = String::new();
+
+    // The defaults of the properties are set first, so that the defaulting function and the defaulting of the fields' values see them.
+    if let Some(properties) = properties {
+        for super::Property { name, field_name, field_type_name, required, .. } in fields {
+            let Some((crate::swagger20::Schema { validation: crate::swagger20::SchemaValidation { default: Some(default), .. }, .. }, _)) =
+                properties.get(*name)
+            else {
+                continue;
+            };
+
+            generate_default(&mut set_defaults_body, &local, type_name, name, field_name, field_type_name, *required, default)?;
+        }
+    }
+
+    if let Some(defaulting_function) = defaulting_function {
+        writeln!(set_defaults_body, "        {defaulting_function}(self);")?;
+    }
+
+    for super::Property { field_name, .. } in fields {
+        writeln!(set_defaults_body, "        {local}Defaulting::set_defaults(&mut self.{field_name});")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct_defaulting.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        set_defaults_body = set_defaults_body,
+    )?;
+
+    Ok(())
+}
+
+fn generate_default(
+    writer: &mut String,
+    local: &str,
+    type_name: &str,
+    name: &str,
+    field_name: &str,
+    field_type_name: &str,
+    required: super::PropertyRequired,
+    default: &crate::swagger20::JsonValue,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    use crate::swagger20::{JsonNumber, JsonValue};
+
+    let optional = matches!(required, super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault);
+    let value_type_name =
+        if optional {
+            field_type_name.strip_prefix("Option<").and_then(|s| s.strip_suffix('>')).unwrap_or(field_type_name)
+        }
+        else {
+            field_type_name
+        };
+
+    let is_zero_value = match default {
+        JsonValue::Null => true,
+        JsonValue::Bool(value) => !value,
+        JsonValue::Number(JsonNumber::PosInt(value)) => *value == 0,
+        JsonValue::Number(JsonNumber::NegInt(_)) => false,
+        JsonValue::Number(JsonNumber::Float(value)) => *value == 0.,
+        JsonValue::String(value) => value.is_empty(),
+        JsonValue::Array(value) => value.is_empty(),
+        JsonValue::Object(value) => value.is_empty(),
+    };
+
+    // A required field already has its zero value when it's unset, and an empty list is the same as an unset list.
+    if (!optional && is_zero_value) || matches!(default, JsonValue::Null) || matches!(default, JsonValue::Array(value) if value.is_empty()) {
+        return Ok(());
+    }
+
+    let value = match (value_type_name, default) {
+        ("bool", JsonValue::Bool(value)) => value.to_string(),
+
+        ("f64", JsonValue::Number(JsonNumber::Float(value))) => format!("{value:?}"),
+        ("f64", JsonValue::Number(JsonNumber::NegInt(value))) => format!("{value}."),
+        ("f64", JsonValue::Number(JsonNumber::PosInt(value))) => format!("{value}."),
+
+        ("i32" | "i64", JsonValue::Number(JsonNumber::NegInt(value))) => value.to_string(),
+        ("i32" | "i64", JsonValue::Number(JsonNumber::PosInt(value))) => value.to_string(),
+
+        ("std::string::String", JsonValue::String(value)) => format!("{value:?}.into()"),
+
+        (value_type_name, JsonValue::Number(JsonNumber::NegInt(value))) if value_type_name.ends_with("::IntOrString") =>
+            format!("{local}apimachinery::pkg::util::intstr::IntOrString::Int({value})"),
+        (value_type_name, JsonValue::Number(JsonNumber::PosInt(value))) if value_type_name.ends_with("::IntOrString") =>
+            format!("{local}apimachinery::pkg::util::intstr::IntOrString::Int({value})"),
+        (value_type_name, JsonValue::String(value)) if value_type_name.ends_with("::IntOrString") =>
+            format!("{local}apimachinery::pkg::util::intstr::IntOrString::String({value:?}.into())"),
+
+        (_, JsonValue::Object(value)) if value.is_empty() => "Default::default()".to_owned(),
+
+        _ => return Err(format!("default {default:?} of property {name} of {type_name} cannot be expressed as a value of {value_type_name}").into()),
+    };
+
+    if optional {
+        writeln!(writer, "        if self.{field_name}.is_none() {{")?;
+        writeln!(writer, "            self.{field_name} = Some({value});")?;
+        writeln!(writer, "        }}")?;
+    }
+    else if value_type_name == "std::string::String" {
+        writeln!(writer, "        if self.{field_name}.is_empty() {{")?;
+        writeln!(writer, "            self.{field_name} = {value};")?;
+        writeln!(writer, "        }}")?;
+    }
+    else {
+        return Err(format!("default {default:?} of required property {name} of {type_name} cannot be applied to a value of {value_type_name}").into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    let mut diff_body = String::new();
+    for super::Property { name, field_name, merge_type, .. } in fields {
+        generate_field(
+            &mut diff_body,
+            &local,
+            &format!("&self.{field_name}"),
+            &format!("&other.{field_name}"),
+            &format!("&{local}diff::Path::Field {{ parent: path, name: {name:?} }}"),
+            merge_type,
+            8,
+        )?;
+    }
+
+    if unknown_fields.preserve() {
+        generate_field(
+            &mut diff_body,
+            &local,
+            "&self.unknown_fields",
+            "&other.unknown_fields",
+            "path",
+            &crate::swagger20::MergeType::Map {
+                strategy: crate::swagger20::KubernetesMapType::Granular,
+                value_merge_type: Box::new(crate::swagger20::MergeType::Default),
+            },
+            8,
+        )?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct_diff.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        diff_body = diff_body,
+    )?;
+
+    Ok(())
+}
+
+fn generate_field(
+    writer: &mut impl std::fmt::Write,
+    local: &str,
+    old_name: &str,
+    new_name: &str,
+    path: &str,
+    merge_type: &crate::swagger20::MergeType,
+    indent: usize,
+) -> Result<(), crate::Error> {
+    const S: &str = "";
+
+    match merge_type {
+        crate::swagger20::MergeType::Default => writeln!(
+            writer,
+            "{S:indent$}{local}diff::Diff::diff({old_name}, {new_name}, {path}, changes);",
+        )?,
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Atomic,
+            keys: _,
+            item_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}diff::strategies::list::atomic(")?;
+            writeln!(writer, "{S:indent$}    {old_name},")?;
+            writeln!(writer, "{S:indent$}    {new_name},")?;
+            writeln!(writer, "{S:indent$}    {path},")?;
+            writeln!(writer, "{S:indent$}    changes,")?;
+            writeln!(writer, "{S:indent$}    |old_item, new_item, path, changes| {{")?;
+            generate_field(writer, local, "old_item", "new_item", "path", item_merge_type, indent + 8)?;
+            writeln!(writer, "{S:indent$}    }},")?;
+            writeln!(writer, "{S:indent$});")?;
+        },
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Map,
+            keys,
+            item_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}diff::strategies::list::map(")?;
+            writeln!(writer, "{S:indent$}    {old_name},")?;
+            writeln!(writer, "{S:indent$}    {new_name},")?;
+            writeln!(writer, "{S:indent$}    {path},")?;
+            writeln!(writer, "{S:indent$}    changes,")?;
+            writeln!(
+                writer,
+                "{S:indent$}    &[{keys}],",
+                keys = keys.iter().map(|k| format!(
+                    "({name:?}, |item| {local}diff::MergeKey::merge_key(&item.{k}))",
+                    name = k.replace("()", ""),
+                    k = crate::get_rust_ident(k),
+                )).collect::<Vec<_>>().join(", "),
+            )?;
+            writeln!(writer, "{S:indent$}    |old_item, new_item, path, changes| {{")?;
+            generate_field(writer, local, "old_item", "new_item", "path", item_merge_type, indent + 8)?;
+            writeln!(writer, "{S:indent$}    }},")?;
+            writeln!(writer, "{S:indent$});")?;
+        },
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Set,
+            keys: _,
+            item_merge_type: _,
+        } => writeln!(
+            writer,
+            "{S:indent$}{local}diff::strategies::list::set({old_name}, {new_name}, {path}, changes);",
+        )?,
+
+        crate::swagger20::MergeType::Map {
+            strategy: crate::swagger20::KubernetesMapType::Granular | crate::swagger20::KubernetesMapType::Atomic,
+            value_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}diff::strategies::map::values(")?;
+            writeln!(writer, "{S:indent$}    {old_name},")?;
+            writeln!(writer, "{S:indent$}    {new_name},")?;
+            writeln!(writer, "{S:indent$}    {path},")?;
+            writeln!(writer, "{S:indent$}    changes,")?;
+            writeln!(writer, "{S:indent$}    |old_value, new_value, path, changes| {{")?;
+            generate_field(writer, local, "old_value", "new_value", "path", value_merge_type, indent + 8)?;
+            writeln!(writer, "{S:indent$}    }},")?;
+            writeln!(writer, "{S:indent$});")?;
+        },
+    }
+
+    Ok(())
+}
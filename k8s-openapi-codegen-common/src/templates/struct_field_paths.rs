@@ -0,0 +1,90 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    vis: &str,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    fields: &[super::Property<'_>],
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    // Inherent methods can only be added to `FieldPath` in the crate that defines it, ie when generating the `k8s-openapi` crate itself.
+    // Elsewhere, the methods are declared in a `{type_name}Fields` trait instead.
+    let fields_trait = (local != "crate::").then(|| format!("{type_name}Fields"));
+
+    let mut field_method_decls = String::new();
+    let mut field_method_impls = String::new();
+    for super::Property { name, field_name, field_type_name, required, is_flattened, .. } in fields {
+        if *is_flattened {
+            continue;
+        }
+
+        // The path of an optional field is the path of its inner type.
+        let mut field_type_name = &**field_type_name;
+        if let super::PropertyRequired::Optional | super::PropertyRequired::OptionalDefault = required {
+            field_type_name = field_type_name.strip_prefix("Option<").and_then(|s| s.strip_suffix('>')).unwrap_or(field_type_name);
+        }
+        field_type_name = field_type_name.strip_prefix("std::boxed::Box<").and_then(|s| s.strip_suffix('>')).unwrap_or(field_type_name);
+
+        let method_doc = if fields_trait.is_some() { &mut field_method_decls } else { &mut field_method_impls };
+        if !method_doc.is_empty() {
+            writeln!(method_doc)?;
+        }
+        writeln!(method_doc, "    /// The path of the `{name}` field.")?;
+        if field_name == "not" && fields_trait.is_none() {
+            writeln!(method_doc, "    #[allow(clippy::should_implement_trait)]")?;
+        }
+        if field_type_name.rsplit("::").next() == Some(type_name) {
+            writeln!(method_doc, "    #[must_use]")?;
+        }
+
+        if fields_trait.is_some() {
+            writeln!(field_method_decls, "    fn {field_name}(self) -> {local}FieldPath<{field_type_name}>;")?;
+
+            if !field_method_impls.is_empty() {
+                writeln!(field_method_impls)?;
+            }
+            writeln!(field_method_impls, "    fn {field_name}(self) -> {local}FieldPath<{field_type_name}> {{")?;
+        }
+        else {
+            writeln!(field_method_impls, "    pub fn {field_name}(self) -> {local}FieldPath<{field_type_name}> {{")?;
+        }
+        writeln!(field_method_impls, "        self.child({name:?})")?;
+        writeln!(field_method_impls, "    }}")?;
+    }
+
+    let mut field_methods = String::new();
+    if !field_method_impls.is_empty() {
+        writeln!(field_methods)?;
+        writeln!(field_methods)?;
+        if let Some(fields_trait) = &fields_trait {
+            writeln!(field_methods, "/// The methods of the [`{local}FieldPath`] of a [`{type_name}`] that return the paths of its fields.")?;
+            writeln!(field_methods, "{vis}trait {fields_trait}{type_generics_type}{type_generics_where} {{")?;
+            write!(field_methods, "{field_method_decls}")?;
+            writeln!(field_methods, "}}")?;
+            writeln!(field_methods)?;
+            writeln!(field_methods, "impl{type_generics_type} {fields_trait}{type_generics_type} for {local}FieldPath<{type_name}{type_generics_type}>{type_generics_where} {{")?;
+        }
+        else {
+            writeln!(field_methods, "impl{type_generics_type} {local}FieldPath<{type_name}{type_generics_type}>{type_generics_where} {{")?;
+        }
+        write!(field_methods, "{field_method_impls}}}")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct_field_paths.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        field_methods = field_methods,
+    )?;
+
+    Ok(())
+}
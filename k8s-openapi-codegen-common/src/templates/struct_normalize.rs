@@ -0,0 +1,171 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    generics: super::Generics<'_>,
+    fields: &[super::Property<'_>],
+    unknown_fields: super::UnknownFields,
+    server_populated_fields: ServerPopulatedFields,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let type_generics_type = generics.type_part.map(|part| format!("<{part}>")).unwrap_or_default();
+    let type_generics_where = generics.where_part.map(|part| format!(" where {part}")).unwrap_or_default();
+
+    let mut normalize_body = String::new();
+    for super::Property { field_name, merge_type, .. } in fields {
+        generate_field(
+            &mut normalize_body,
+            &local,
+            &format!("&mut self.{field_name}"),
+            merge_type,
+            8,
+        )?;
+    }
+
+    if unknown_fields.preserve() {
+        generate_field(
+            &mut normalize_body,
+            &local,
+            "&mut self.unknown_fields",
+            &crate::swagger20::MergeType::Map {
+                strategy: crate::swagger20::KubernetesMapType::Granular,
+                value_merge_type: Box::new(crate::swagger20::MergeType::Default),
+            },
+            8,
+        )?;
+    }
+
+    let mut clear_server_populated_fields = String::new();
+    match server_populated_fields {
+        ServerPopulatedFields::None => (),
+
+        ServerPopulatedFields::ObjectMeta => {
+            writeln!(clear_server_populated_fields)?;
+            writeln!(clear_server_populated_fields, "    fn clear_server_populated_fields(&mut self) {{")?;
+            for field_name in [
+                "creation_timestamp",
+                "deletion_grace_period_seconds",
+                "deletion_timestamp",
+                "generation",
+                "managed_fields",
+                "resource_version",
+                "self_link",
+                "uid",
+            ] {
+                if fields.iter().any(|property| property.field_name == field_name) {
+                    writeln!(clear_server_populated_fields, "        self.{field_name} = None;")?;
+                }
+            }
+            writeln!(clear_server_populated_fields, "    }}")?;
+        },
+
+        ServerPopulatedFields::Resource => {
+            writeln!(clear_server_populated_fields)?;
+            writeln!(clear_server_populated_fields, "    fn clear_server_populated_fields(&mut self) {{")?;
+            writeln!(clear_server_populated_fields, "        {local}Normalize::clear_server_populated_fields(&mut self.metadata);")?;
+            if fields.iter().any(|property| property.name == "status" && matches!(property.required, super::PropertyRequired::Optional)) {
+                writeln!(clear_server_populated_fields, "        self.status = None;")?;
+            }
+            writeln!(clear_server_populated_fields, "    }}")?;
+        },
+
+        ServerPopulatedFields::List => {
+            writeln!(clear_server_populated_fields)?;
+            writeln!(clear_server_populated_fields, "    fn clear_server_populated_fields(&mut self) {{")?;
+            writeln!(clear_server_populated_fields, "        for item in &mut self.items {{")?;
+            writeln!(clear_server_populated_fields, "            {local}Normalize::clear_server_populated_fields(item);")?;
+            writeln!(clear_server_populated_fields, "        }}")?;
+            writeln!(clear_server_populated_fields, "    }}")?;
+        },
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/struct_normalize.rs")),
+        local = local,
+        type_name = type_name,
+        type_generics_type = type_generics_type,
+        type_generics_where = type_generics_where,
+        normalize_body = normalize_body,
+        clear_server_populated_fields = clear_server_populated_fields,
+    )?;
+
+    Ok(())
+}
+
+/// The fields of a type that the generated `Normalize::clear_server_populated_fields` impl clears.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ServerPopulatedFields {
+    /// The type has no server-populated fields.
+    None,
+
+    /// The type is `ObjectMeta`, so its server-populated fields like `uid` and `resourceVersion` are cleared.
+    ObjectMeta,
+
+    /// The type is a resource whose metadata is an `ObjectMeta`, so its metadata's server-populated fields and its `status` field are cleared.
+    Resource,
+
+    /// The type is a list of resources, so the server-populated fields of its items are cleared.
+    List,
+}
+
+fn generate_field(
+    writer: &mut impl std::fmt::Write,
+    local: &str,
+    self_name: &str,
+    merge_type: &crate::swagger20::MergeType,
+    indent: usize,
+) -> Result<(), crate::Error> {
+    const S: &str = "";
+
+    match merge_type {
+        crate::swagger20::MergeType::Default => writeln!(
+            writer,
+            "{S:indent$}{local}Normalize::normalize({self_name});",
+        )?,
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Atomic,
+            keys: _,
+            item_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}normalize_strategies::list::atomic({self_name}, |item| {{")?;
+            generate_field(writer, local, "item", item_merge_type, indent + 4)?;
+            writeln!(writer, "{S:indent$}}});")?;
+        },
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Map,
+            keys: _,
+            item_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}normalize_strategies::list::map({self_name}, |item| {{")?;
+            generate_field(writer, local, "item", item_merge_type, indent + 4)?;
+            writeln!(writer, "{S:indent$}}});")?;
+        },
+
+        crate::swagger20::MergeType::List {
+            strategy: crate::swagger20::KubernetesListType::Set,
+            keys: _,
+            item_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}normalize_strategies::list::set({self_name}, |item| {{")?;
+            generate_field(writer, local, "item", item_merge_type, indent + 4)?;
+            writeln!(writer, "{S:indent$}}});")?;
+        },
+
+        crate::swagger20::MergeType::Map {
+            strategy: crate::swagger20::KubernetesMapType::Granular | crate::swagger20::KubernetesMapType::Atomic,
+            value_merge_type,
+        } => {
+            writeln!(writer, "{S:indent$}{local}normalize_strategies::map::values({self_name}, |value| {{")?;
+            generate_field(writer, local, "value", value_merge_type, indent + 4)?;
+            writeln!(writer, "{S:indent$}}});")?;
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    type_name: &str,
+    derives: Option<super::type_header::Derives>,
+    vis: &str,
+    union: &crate::swagger20::KubernetesUnion,
+    fields: &[super::Property<'_>],
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let union_name = format!("{type_name}Union");
+
+    let impl_from = derives.is_some_and(|derives| derives.default);
+    let impl_from_ref = derives.is_some_and(|derives| derives.clone);
+
+    let ref_derives =
+        derives
+        .map(|super::type_header::Derives { clone: _, copy: _, default: _, eq, ord, partial_eq, partial_ord }| format!(
+            "#[derive(Clone, Copy, Debug{eq}{ord}{partial_eq}{partial_ord})]\n",
+            eq = if eq { ", Eq" } else { "" },
+            ord = if ord { ", Ord" } else { "" },
+            partial_eq = if partial_eq { ", PartialEq" } else { "" },
+            partial_ord = if partial_ord { ", PartialOrd" } else { "" },
+        ))
+        .unwrap_or_default();
+
+    let derives =
+        derives
+        .map(|super::type_header::Derives { clone, copy, default: _, eq, ord, partial_eq, partial_ord }| format!(
+            "#[derive({clone}{copy}Debug{eq}{ord}{partial_eq}{partial_ord})]\n",
+            clone = if clone { "Clone, " } else { "" },
+            copy = if copy { "Copy, " } else { "" },
+            eq = if eq { ", Eq" } else { "" },
+            ord = if ord { ", Ord" } else { "" },
+            partial_eq = if partial_eq { ", PartialEq" } else { "" },
+            partial_ord = if partial_ord { ", PartialOrd" } else { "" },
+        ))
+        .unwrap_or_default();
+
+    let discriminator = match &union.discriminator {
+        Some(discriminator) => {
+            let field = fields.iter().find(|field| field.name == discriminator).ok_or_else(|| format!(
+                "discriminator {discriminator} of union of {type_name} is not a field"))?;
+            let required = match (&field.required, &*field.field_type_name) {
+                (super::PropertyRequired::Required { .. }, "std::string::String") => true,
+                (super::PropertyRequired::Optional, "Option<std::string::String>") => false,
+                _ => return Err(format!("discriminator {discriminator} of union of {type_name} is not a string").into()),
+            };
+            Some((discriminator, &*field.field_name, required))
+        },
+
+        None => None,
+    };
+
+    let mut variants = String::new();
+    let mut ref_variants = String::new();
+    let mut from_ref_arms = String::new();
+    let mut get_members = String::new();
+    let mut unset_members = String::new();
+    let mut set_members = String::new();
+    let mut discriminator_arms = String::new();
+
+    for (member, discriminator_value) in &union.fields_to_discriminate_by {
+        let field = fields.iter().find(|field| field.name == member).ok_or_else(|| format!(
+            "member {member} of union of {type_name} is not a field"))?;
+        let field_name = &field.field_name;
+
+        let Some(member_type_name) = field.field_type_name.strip_prefix("Option<").and_then(|s| s.strip_suffix('>')) else {
+            return Err(format!("member {member} of union of {type_name} is not optional").into());
+        };
+
+        let mut chars = member.chars();
+        let variant_name: String = chars.next().into_iter().map(|first| first.to_ascii_uppercase()).chain(chars).collect();
+
+        writeln!(variants, "    /// The `{member}` member.")?;
+        writeln!(variants, "    {variant_name}({member_type_name}),")?;
+        writeln!(variants)?;
+
+        writeln!(ref_variants, "    /// The `{member}` member.")?;
+        writeln!(ref_variants, "    {variant_name}(&'a {member_type_name}),")?;
+        writeln!(ref_variants)?;
+
+        writeln!(from_ref_arms, "            {union_name}Ref::{variant_name}(value) => {union_name}::{variant_name}(value.clone()),")?;
+
+        writeln!(get_members, "        if let Some(value) = &self.{field_name} {{")?;
+        writeln!(get_members, "            members.push({member:?});")?;
+        writeln!(get_members, "            result = Some({union_name}Ref::{variant_name}(value));")?;
+        writeln!(get_members, "        }}")?;
+
+        writeln!(unset_members, "        self.{field_name} = None;")?;
+
+        if let Some((_, discriminator_field_name, required)) = discriminator {
+            writeln!(set_members, "            Some({union_name}::{variant_name}(value)) => {{")?;
+            writeln!(set_members, "                self.{field_name} = Some(value);")?;
+            if required {
+                writeln!(set_members, "                self.{discriminator_field_name} = {discriminator_value:?}.into();")?;
+            }
+            else {
+                writeln!(set_members, "                self.{discriminator_field_name} = Some({discriminator_value:?}.into());")?;
+            }
+            writeln!(set_members, "            }},")?;
+
+            writeln!(discriminator_arms, "            {discriminator_value:?} => Some({member:?}),")?;
+        }
+        else {
+            writeln!(set_members, "            Some({union_name}::{variant_name}(value)) => self.{field_name} = Some(value),")?;
+        }
+    }
+
+    // Remove the blank line after the last variant.
+    variants.pop();
+    ref_variants.pop();
+
+    let (discriminator_doc, set_discriminator_doc, check_discriminator) =
+        if let Some((discriminator, discriminator_field_name, required)) = discriminator {
+            let mut check_discriminator = String::new();
+            writeln!(check_discriminator)?;
+            if required {
+                writeln!(check_discriminator, "        let discriminator = &*self.{discriminator_field_name};")?;
+            }
+            else {
+                writeln!(check_discriminator, "        let discriminator = self.{discriminator_field_name}.as_deref().unwrap_or_default();")?;
+            }
+            writeln!(check_discriminator, "        let member = members.first().copied();")?;
+            writeln!(check_discriminator, "        let discriminated_member = match discriminator {{")?;
+            write!(check_discriminator, "{discriminator_arms}")?;
+            writeln!(check_discriminator, "            _ => member,")?;
+            writeln!(check_discriminator, "        }};")?;
+            writeln!(check_discriminator, "        if discriminated_member != member {{")?;
+            writeln!(check_discriminator, "            return Err({local}UnionError::DiscriminatorMismatch {{ discriminator: discriminator.into(), member }});")?;
+            writeln!(check_discriminator, "        }}")?;
+
+            // A required discriminator can't be cleared, and setting it to an empty string would make the object invalid,
+            // so it's left unchanged.
+            let set_discriminator_doc =
+                if required {
+                    writeln!(set_members, "            None => (),")?;
+                    format!(" The `{discriminator}` discriminator is set to match, or left unchanged if `value` is `None` since it is required.")
+                }
+                else {
+                    writeln!(set_members, "            None => self.{discriminator_field_name} = None,")?;
+                    format!(" The `{discriminator}` discriminator is set to match, or cleared if `value` is `None`.")
+                };
+
+            (
+                format!(", or if the `{discriminator}` discriminator names a different member than the one that is set"),
+                set_discriminator_doc,
+                check_discriminator,
+            )
+        }
+        else {
+            writeln!(set_members, "            None => (),")?;
+            (String::new(), String::new(), String::new())
+        };
+
+    let mut impl_from =
+        if impl_from {
+            format!("
+
+impl From<{union_name}> for {type_name} {{
+    fn from(value: {union_name}) -> Self {{
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }}
+}}")
+        }
+        else {
+            String::new()
+        };
+
+    if impl_from_ref {
+        write!(impl_from, "
+
+impl From<{union_name}Ref<'_>> for {union_name} {{
+    fn from(value: {union_name}Ref<'_>) -> Self {{
+        match value {{
+{from_ref_arms}        }}
+    }}
+}}")?;
+    }
+
+    writeln!(
+        writer,
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/union.rs")),
+        type_name = type_name,
+        derives = derives,
+        vis = vis,
+        union_name = union_name,
+        variants = variants,
+        ref_derives = ref_derives,
+        ref_variants = ref_variants,
+        local = local,
+        discriminator_doc = discriminator_doc,
+        get_members = get_members,
+        check_discriminator = check_discriminator,
+        set_discriminator_doc = set_discriminator_doc,
+        unset_members = unset_members,
+        set_members = set_members,
+        impl_from = impl_from,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+pub(crate) fn generate(
+    mut writer: impl std::io::Write,
+    types: &[Visited<'_>],
+    mutability: Mutability,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    use std::fmt::Write;
+
+    let local = crate::map_namespace_local_to_string(map_namespace)?;
+
+    let (suffix, visitor_trait, visitable_trait, node_ref) = match mutability {
+        Mutability::Shared => ("", "Visit<'ast>", "Visitable", "&'ast "),
+        Mutability::Mut => ("_mut", "VisitMut", "VisitableMut", "&mut "),
+    };
+    let lifetime_param = match mutability {
+        Mutability::Shared => "'ast, ",
+        Mutability::Mut => "",
+    };
+
+    let mut trait_methods = String::new();
+    let mut walk_fns = String::new();
+    let mut visitable_impls = String::new();
+
+    for (i, Visited { type_name, rust_type, method_name, walk }) in types.iter().enumerate() {
+        let (generics, type_generics, generics_where) = match walk {
+            Walk::List { .. } => ("<T>", "<T>", format!(" where T: {local}ListableResource + {visitable_trait}")),
+            _ => ("", "", String::new()),
+        };
+
+        if i > 0 {
+            writeln!(trait_methods)?;
+        }
+        writeln!(trait_methods, "    /// Visits a value of type [`{type_name}`]({rust_type}).")?;
+        writeln!(trait_methods, "    fn {method_name}{suffix}{generics}(&mut self, node: {node_ref}{rust_type}{type_generics}){generics_where} {{")?;
+        writeln!(trait_methods, "        {method_name}{suffix}(self, node);")?;
+        writeln!(trait_methods, "    }}")?;
+
+        let mut walk_body = String::new();
+        match walk {
+            Walk::Fields(fields) => for (field_name, required, value) in fields {
+                if *required {
+                    generate_value(&mut walk_body, &format!("node.{field_name}"), false, value, suffix, mutability, 4)?;
+                }
+                else {
+                    writeln!(walk_body, "    if let Some(it) = {borrow}node.{field_name} {{", borrow = mutability.borrow())?;
+                    generate_value(&mut walk_body, "it", true, value, suffix, mutability, 8)?;
+                    writeln!(walk_body, "    }}")?;
+                }
+            },
+
+            Walk::Variants(variants) => {
+                writeln!(walk_body, "    match node {{")?;
+                for (variant_name, value) in variants {
+                    if let Some(value) = value {
+                        writeln!(walk_body, "        {rust_type}::{variant_name}(it) => {{")?;
+                        generate_value(&mut walk_body, "it", true, value, suffix, mutability, 12)?;
+                        writeln!(walk_body, "        }},")?;
+                    }
+                    else {
+                        writeln!(walk_body, "        {rust_type}::{variant_name}(_) => (),")?;
+                    }
+                }
+                writeln!(walk_body, "    }}")?;
+            },
+
+            Walk::List { metadata_method_name } => {
+                writeln!(walk_body, "    for it in {borrow}node.items {{", borrow = mutability.borrow())?;
+                writeln!(walk_body, "        {visitable_trait}::visit{suffix}(it, v);")?;
+                writeln!(walk_body, "    }}")?;
+                writeln!(walk_body, "    v.{metadata_method_name}{suffix}({borrow}node.metadata);", borrow = mutability.borrow())?;
+            },
+
+            Walk::None => (),
+        }
+
+        let (v, node) = if walk_body.is_empty() { ("_v", "_node") } else { ("v", "node") };
+
+        writeln!(walk_fns)?;
+        if walk_body.is_empty() {
+            writeln!(walk_fns, "/// Visits a value of type [`{type_name}`]({rust_type}), which does not contain values of other types that can be visited.")?;
+        }
+        else {
+            writeln!(walk_fns, "/// Visits the values contained in a value of type [`{type_name}`]({rust_type}).")?;
+        }
+        writeln!(
+            walk_fns,
+            "pub fn {method_name}{suffix}<{lifetime_param}V{generics_comma}>({v}: &mut V, {node}: {node_ref}{rust_type}{type_generics}) where V: {visitor_trait} + ?Sized{generics_where_comma} {{",
+            generics_comma = if generics.is_empty() { "" } else { ", T" },
+            generics_where_comma = generics_where.replace(" where ", ", "),
+        )?;
+        write!(walk_fns, "{walk_body}")?;
+        writeln!(walk_fns, "}}")?;
+
+        writeln!(visitable_impls)?;
+        writeln!(visitable_impls, "impl{generics} {visitable_trait} for {rust_type}{type_generics}{generics_where} {{")?;
+        match mutability {
+            Mutability::Shared => writeln!(visitable_impls, "    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {{")?,
+            Mutability::Mut => writeln!(visitable_impls, "    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {{")?,
+        }
+        writeln!(visitable_impls, "        v.{method_name}{suffix}(self);")?;
+        writeln!(visitable_impls, "    }}")?;
+        writeln!(visitable_impls, "}}")?;
+    }
+
+    match mutability {
+        Mutability::Shared => write!(
+            writer,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/visit.rs")),
+            trait_methods = trait_methods,
+            walk_fns = walk_fns,
+            visitable_impls = visitable_impls,
+        )?,
+
+        Mutability::Mut => write!(
+            writer,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/visit_mut.rs")),
+            trait_methods = trait_methods,
+            walk_fns = walk_fns,
+            visitable_impls = visitable_impls,
+        )?,
+    }
+
+    Ok(())
+}
+
+/// A type that the visitor traits have a method for.
+pub(crate) struct Visited<'a> {
+    pub(crate) type_name: &'a str,
+    pub(crate) rust_type: String,
+    pub(crate) method_name: String,
+    pub(crate) walk: Walk,
+}
+
+/// How the visitor walks the values contained in a value of a type.
+pub(crate) enum Walk {
+    /// The type contains no values that can be visited.
+    None,
+
+    /// The type is a struct. Each field has its name, whether it is required, and how its value is visited.
+    Fields(Vec<(std::borrow::Cow<'static, str>, bool, Value)>),
+
+    /// The type is an enum of newtype variants. Each variant has its name, and how its value is visited if it can be.
+    Variants(Vec<(String, Option<Value>)>),
+
+    /// The type is the generic `List<T>` type.
+    List { metadata_method_name: String },
+}
+
+/// How a value that can contain values of other types is visited.
+pub(crate) enum Value {
+    /// The value is of a type that the visitor traits have the given method for.
+    Visit(String),
+
+    /// The value is an array, and each item is visited.
+    Array(Box<Value>),
+
+    /// The value is a map, and each value is visited.
+    Map(Box<Value>),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Mutability {
+    Shared,
+    Mut,
+}
+
+impl Mutability {
+    fn borrow(self) -> &'static str {
+        match self {
+            Mutability::Shared => "&",
+            Mutability::Mut => "&mut ",
+        }
+    }
+}
+
+fn generate_value(
+    writer: &mut impl std::fmt::Write,
+    expr: &str,
+    expr_is_ref: bool,
+    value: &Value,
+    suffix: &str,
+    mutability: Mutability,
+    indent: usize,
+) -> Result<(), crate::Error> {
+    const S: &str = "";
+
+    let borrow = if expr_is_ref { "" } else { mutability.borrow() };
+
+    match value {
+        Value::Visit(method_name) => writeln!(writer, "{S:indent$}v.{method_name}{suffix}({borrow}{expr});")?,
+
+        Value::Array(value) => {
+            writeln!(writer, "{S:indent$}for it in {borrow}{expr} {{")?;
+            generate_value(writer, "it", true, value, suffix, mutability, indent + 4)?;
+            writeln!(writer, "{S:indent$}}}")?;
+        },
+
+        Value::Map(value) => {
+            let values = match mutability {
+                Mutability::Shared => "values",
+                Mutability::Mut => "values_mut",
+            };
+            writeln!(writer, "{S:indent$}for it in {expr}.{values}() {{")?;
+            generate_value(writer, "it", true, value, suffix, mutability, indent + 4)?;
+            writeln!(writer, "{S:indent$}}}")?;
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,358 @@
+//! Functions for generating the borrowed and compact variants of the types of a spec.
+//!
+//! The variants of a type are structs with the same properties as the type, but with different types for their strings.
+//! They can be generated without the rest of the spec from a manifest of the spec's struct definitions:
+//!
+//! 1. Write the manifest of the spec's struct definitions with [`write_manifest`].
+//! 1. Parse the manifest with [`read_manifest`], and synthesize the definitions of the variants with [`add_variants`].
+//! 1. Invoke the [`run`](crate::run) function for each of the variants' definitions.
+
+/// Writes the manifest of the struct definitions of the given spec.
+///
+/// The manifest does not contain the descriptions of the definitions or their properties.
+pub fn write_manifest(
+    definitions: &std::collections::BTreeMap<crate::swagger20::DefinitionPath, crate::swagger20::Schema>,
+    map_namespace: &impl crate::MapNamespace,
+    mut out: impl std::io::Write,
+) -> Result<(), crate::Error> {
+    fn write_kind(
+        kind: &crate::swagger20::SchemaKind,
+        definitions: &std::collections::BTreeMap<crate::swagger20::DefinitionPath, crate::swagger20::Schema>,
+        map_namespace: &impl crate::MapNamespace,
+        out: &mut impl std::io::Write,
+    ) -> Result<(), crate::Error> {
+        match kind {
+            // The manifest does not have the definitions of non-struct types, so whether they implement `Default` is stored in the reference.
+            crate::swagger20::SchemaKind::Ref(ref_path) => {
+                let can_be_default = crate::is_default(kind, definitions, map_namespace)?;
+                write!(out, " ref {} {}", ref_path.path, if can_be_default { "default" } else { "nodefault" })?;
+            },
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Any) => write!(out, " any")?,
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array { items }) => {
+                write!(out, " array")?;
+                write_kind(&items.kind, definitions, map_namespace, out)?;
+            },
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Boolean) => write!(out, " boolean")?,
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Integer { format: crate::swagger20::IntegerFormat::Int32 }) => write!(out, " integer/int32")?,
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Integer { format: crate::swagger20::IntegerFormat::Int64 }) => write!(out, " integer/int64")?,
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Number { format: crate::swagger20::NumberFormat::Double }) => write!(out, " number/double")?,
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Object { additional_properties }) => {
+                write!(out, " object")?;
+                write_kind(&additional_properties.kind, definitions, map_namespace, out)?;
+            },
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }) => write!(out, " string")?,
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::Byte) }) => write!(out, " string/byte")?,
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::DateTime) }) => write!(out, " string/date-time")?,
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::Duration) }) => write!(out, " string/duration")?,
+
+            kind => return Err(format!("unsupported schema kind {kind:?} in manifest").into()),
+        }
+
+        Ok(())
+    }
+
+    writeln!(out, "# The struct definitions that the borrowed and compact variants of types are generated from.")?;
+
+    for (definition_path, definition) in definitions {
+        let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+
+        writeln!(out)?;
+        writeln!(out, "definition {definition_path}")?;
+
+        for crate::swagger20::KubernetesGroupKindVersion { group, kind, version } in &definition.kubernetes_group_kind_versions {
+            writeln!(out, "gvk {group}/{version}/{kind}")?;
+        }
+
+        if let Some(list_kind) = &definition.list_kind {
+            writeln!(out, "list {list_kind}")?;
+        }
+
+        for (name, (schema, required)) in properties {
+            write!(out, "property {name} {}", if *required { "required" } else { "optional" })?;
+            write_kind(&schema.kind, definitions, map_namespace, &mut out)?;
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a manifest written by [`write_manifest`] into the struct definitions it contains.
+pub fn read_manifest(manifest: &str) -> Result<std::collections::BTreeMap<crate::swagger20::DefinitionPath, crate::swagger20::Schema>, crate::Error> {
+    fn read_kind<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<crate::swagger20::SchemaKind, crate::Error> {
+        let token = tokens.next().ok_or("expected schema kind")?;
+        Ok(match token {
+            "ref" => {
+                let path = tokens.next().ok_or("expected ref path")?;
+                let can_be_default = match tokens.next() {
+                    Some("default") => true,
+                    Some("nodefault") => false,
+                    token => return Err(format!("expected default or nodefault but got {token:?}").into()),
+                };
+                crate::swagger20::SchemaKind::Ref(crate::swagger20::RefPath { path: path.to_owned(), can_be_default: Some(can_be_default) })
+            },
+
+            "any" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Any),
+
+            "array" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array { items: Box::new(schema(read_kind(tokens)?)) }),
+
+            "boolean" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Boolean),
+
+            "integer/int32" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Integer { format: crate::swagger20::IntegerFormat::Int32 }),
+            "integer/int64" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Integer { format: crate::swagger20::IntegerFormat::Int64 }),
+
+            "number/double" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Number { format: crate::swagger20::NumberFormat::Double }),
+
+            "object" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Object { additional_properties: Box::new(schema(read_kind(tokens)?)) }),
+
+            "string" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: None }),
+            "string/byte" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::Byte) }),
+            "string/date-time" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::DateTime) }),
+            "string/duration" => crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format: Some(crate::swagger20::StringFormat::Duration) }),
+
+            token => return Err(format!("unexpected schema kind {token:?}").into()),
+        })
+    }
+
+    let mut result = std::collections::BTreeMap::new();
+
+    let mut current: Option<(crate::swagger20::DefinitionPath, crate::swagger20::Schema)> = None;
+
+    for (line_number, line) in manifest.lines().enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let mut read_line = || -> Result<(), crate::Error> {
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                None => (),
+
+                Some("definition") => {
+                    let definition_path = tokens.next().ok_or("expected definition path")?;
+                    let definition = schema(crate::swagger20::SchemaKind::Properties(Default::default()));
+                    if let Some((definition_path, definition)) = current.replace((crate::swagger20::DefinitionPath(definition_path.to_owned()), definition)) {
+                        result.insert(definition_path, definition);
+                    }
+                },
+
+                Some("gvk") => {
+                    let (_, definition) = current.as_mut().ok_or("gvk outside definition")?;
+                    let gvk = tokens.next().ok_or("expected group, version and kind")?;
+                    let mut parts = gvk.split('/');
+                    let (Some(group), Some(version), Some(kind), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+                        return Err(format!("expected group/version/kind but got {gvk:?}").into());
+                    };
+                    definition.kubernetes_group_kind_versions.push(crate::swagger20::KubernetesGroupKindVersion {
+                        group: group.to_owned(),
+                        kind: kind.to_owned(),
+                        version: version.to_owned(),
+                    });
+                },
+
+                Some("list") => {
+                    let (_, definition) = current.as_mut().ok_or("list outside definition")?;
+                    definition.list_kind = Some(tokens.next().ok_or("expected list kind")?.to_owned());
+                },
+
+                Some("property") => {
+                    let (_, definition) = current.as_mut().ok_or("property outside definition")?;
+                    let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind else { unreachable!(); };
+                    let name = tokens.next().ok_or("expected property name")?;
+                    let required = match tokens.next() {
+                        Some("required") => true,
+                        Some("optional") => false,
+                        token => return Err(format!("expected required or optional but got {token:?}").into()),
+                    };
+                    let kind = read_kind(&mut tokens)?;
+                    properties.insert(crate::swagger20::PropertyName(name.to_owned()), (schema(kind), required));
+                },
+
+                Some(token) => return Err(format!("unexpected {token:?}").into()),
+            }
+
+            if let Some(token) = tokens.next() {
+                return Err(format!("unexpected {token:?}").into());
+            }
+
+            Ok(())
+        };
+
+        read_line().map_err(|err| format!("line {}: {err}", line_number + 1))?;
+    }
+
+    if let Some((definition_path, definition)) = current {
+        result.insert(definition_path, definition);
+    }
+
+    Ok(result)
+}
+
+/// Synthesizes the `swagger20::Type::VariantDef` definitions of the given variant of all struct types that contain strings, directly or through other struct types.
+///
+/// The compact variant also replaces byte strings. The definitions are under the `io.k8s.borrowed.` and `io.k8s.compact.` namespaces respectively.
+pub fn add_variants(
+    definitions: &mut std::collections::BTreeMap<crate::swagger20::DefinitionPath, crate::swagger20::Schema>,
+    variant: crate::swagger20::TypeVariant,
+    map_namespace: &impl crate::MapNamespace,
+) -> Result<(), crate::Error> {
+    fn is_variant_string(format: Option<crate::swagger20::StringFormat>, variant: crate::swagger20::TypeVariant) -> bool {
+        matches!((format, variant), (None, _) | (Some(crate::swagger20::StringFormat::Byte), crate::swagger20::TypeVariant::Compact))
+    }
+
+    fn contains_variant(
+        kind: &crate::swagger20::SchemaKind,
+        variant: crate::swagger20::TypeVariant,
+        variants: &std::collections::BTreeSet<String>,
+    ) -> bool {
+        match kind {
+            crate::swagger20::SchemaKind::Ref(ref_path) => variants.contains(&ref_path.path),
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array { items }) => contains_variant(&items.kind, variant, variants),
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Object { .. }) => true,
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format }) => is_variant_string(*format, variant),
+            _ => false,
+        }
+    }
+
+    fn to_variant(
+        kind: &crate::swagger20::SchemaKind,
+        variant: crate::swagger20::TypeVariant,
+        variants: &std::collections::BTreeSet<String>,
+        namespace: &str,
+    ) -> crate::swagger20::SchemaKind {
+        match kind {
+            crate::swagger20::SchemaKind::Ref(ref_path) if variants.contains(&ref_path.path) =>
+                crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::VariantRef(variant, crate::swagger20::RefPath {
+                    path: variant_path(&ref_path.path, namespace),
+                    can_be_default: ref_path.can_be_default,
+                })),
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array { items }) =>
+                crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Array {
+                    items: Box::new(crate::swagger20::Schema {
+                        kind: to_variant(&items.kind, variant, variants, namespace),
+                        ..(**items).clone()
+                    }),
+                }),
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::Object { additional_properties }) =>
+                crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::VariantObject {
+                    variant,
+                    additional_properties: Box::new(crate::swagger20::Schema {
+                        kind: to_variant(&additional_properties.kind, variant, variants, namespace),
+                        ..(**additional_properties).clone()
+                    }),
+                }),
+
+            crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::String { format }) if is_variant_string(*format, variant) =>
+                crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::VariantString { variant, format: *format }),
+
+            kind => kind.clone(),
+        }
+    }
+
+    fn variant_path(path: &str, namespace: &str) -> String {
+        let path = path.strip_prefix("io.k8s.").expect("all definitions are under the io.k8s. namespace");
+        format!("io.k8s.{namespace}.{path}")
+    }
+
+    let namespace = match variant {
+        crate::swagger20::TypeVariant::Borrowed => "borrowed",
+        crate::swagger20::TypeVariant::Compact => "compact",
+    };
+
+    // Find all struct types that contain strings, directly or through other struct types.
+    let mut variants = std::collections::BTreeSet::new();
+    loop {
+        let mut changed = false;
+
+        for (definition_path, definition) in &*definitions {
+            let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+            if variants.contains(&definition_path.0) {
+                continue;
+            }
+
+            if properties.values().any(|(schema, _)| contains_variant(&schema.kind, variant, &variants)) {
+                variants.insert(definition_path.0.clone());
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    if variants.is_empty() {
+        return Err(format!("did not find any types for the {namespace} variant").into());
+    }
+
+    for path in &variants {
+        let definition = &definitions[&**path];
+        let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else { unreachable!(); };
+
+        let properties =
+            properties.iter()
+            .map(|(name, (schema, required))| (
+                name.clone(),
+                (
+                    crate::swagger20::Schema {
+                        kind: to_variant(&schema.kind, variant, &variants, namespace),
+                        ..schema.clone()
+                    },
+                    *required,
+                ),
+            ))
+            .collect();
+
+        let owned = crate::swagger20::RefPath {
+            path: path.clone(),
+            can_be_default: None,
+        };
+
+        let owned_rust_type = crate::get_rust_type(&crate::swagger20::SchemaKind::Ref(owned.clone()), map_namespace)?;
+
+        let variant_definition = crate::swagger20::Schema {
+            description: Some(format!("The {namespace} variant of [`{owned_rust_type}`]. See that type for the documentation of its fields.")),
+            kubernetes_group_kind_versions: definition.kubernetes_group_kind_versions.clone(),
+            list_kind: definition.list_kind.clone(),
+            ..schema(crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::VariantDef {
+                variant,
+                owned,
+                properties,
+            }))
+        };
+
+        definitions.insert(crate::swagger20::DefinitionPath(variant_path(path, namespace)), variant_definition);
+    }
+
+    Ok(())
+}
+
+/// A schema of the given kind, without any description, validation, extensions or generated impls.
+fn schema(kind: crate::swagger20::SchemaKind) -> crate::swagger20::Schema {
+    crate::swagger20::Schema {
+        description: None,
+        kind,
+        kubernetes_group_kind_versions: vec![],
+        list_kind: None,
+        merge_type: crate::swagger20::MergeType::Default,
+        validation: crate::swagger20::SchemaValidation::default(),
+        extensions: crate::swagger20::SchemaExtensions::default(),
+        impl_deep_merge: false,
+        impl_normalize: false,
+        impl_diff: false,
+        impl_field_paths: false,
+        impl_defaulting: false,
+        defaulting_function: None,
+        protobuf_field_number: None,
+    }
+}
@@ -0,0 +1,77 @@
+enum {type_name} {{
+{variants}    /// A resource of any other type.
+    Other({local}DynamicObject),
+}}
+
+impl {type_name} {{
+    /// Deserializes a resource of the type with the given `apiVersion` and `kind` from the given deserializer.
+    ///
+    /// Resources of types that are not known to this crate are deserialized as [`{type_name}::Other`].
+    pub fn deserialize_with_type<'de, D>(api_version: &str, kind: &str, deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
+        Ok(match (api_version, kind) {{
+{deserialize_arms}            _ => {type_name}::Other({local}serde::Deserialize::deserialize(deserializer)?),
+        }})
+    }}
+
+    /// Gets the `apiVersion` of this resource.
+    pub fn api_version(&self) -> &str {{
+        match self {{
+{api_version_arms}            {type_name}::Other(value) => &value.api_version,
+        }}
+    }}
+
+    /// Gets the `kind` of this resource.
+    pub fn kind(&self) -> &str {{
+        match self {{
+{kind_arms}            {type_name}::Other(value) => &value.kind,
+        }}
+    }}
+
+    /// Gets a reference to the metadata of this resource.
+    pub fn metadata(&self) -> &{object_meta_rust_type} {{
+        match self {{
+{metadata_arms}            {type_name}::Other(value) => &value.metadata,
+        }}
+    }}
+
+    /// Gets a mutable reference to the metadata of this resource.
+    pub fn metadata_mut(&mut self) -> &mut {object_meta_rust_type} {{
+        match self {{
+{metadata_mut_arms}            {type_name}::Other(value) => &mut value.metadata,
+        }}
+    }}
+}}
+
+impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
+        let value: {local}serde_json::Value = {local}serde::Deserialize::deserialize(deserializer)?;
+
+        let api_version = match value.get("apiVersion") {{
+            Some({local}serde_json::Value::String(api_version)) => api_version.clone(),
+            Some(_) => return Err({local}serde::de::Error::invalid_type({local}serde::de::Unexpected::Other("non-string apiVersion"), &"a string")),
+            None => return Err({local}serde::de::Error::missing_field("apiVersion")),
+        }};
+
+        let kind = match value.get("kind") {{
+            Some({local}serde_json::Value::String(kind)) => kind.clone(),
+            Some(_) => return Err({local}serde::de::Error::invalid_type({local}serde::de::Unexpected::Other("non-string kind"), &"a string")),
+            None => return Err({local}serde::de::Error::missing_field("kind")),
+        }};
+
+        Self::deserialize_with_type(&api_version, &kind, value).map_err({local}serde::de::Error::custom)
+    }}
+}}
+
+impl {local}serde::Serialize for {type_name} {{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
+        match self {{
+{serialize_arms}            {type_name}::Other(value) => {local}serde::Serialize::serialize(value, serializer),
+        }}
+    }}
+}}
+{from_impls}
+impl From<{local}DynamicObject> for {type_name} {{
+    fn from(value: {local}DynamicObject) -> Self {{
+        {type_name}::Other(value)
+    }}
+}}
\ No newline at end of file
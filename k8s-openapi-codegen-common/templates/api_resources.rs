@@ -0,0 +1,2 @@
+// Generated from all definitions and operations
+{impls}
\ No newline at end of file
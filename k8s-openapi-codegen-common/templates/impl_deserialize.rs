@@ -47,11 +47,5 @@ impl{type_generics_impl} {local}serde::Deserialize<'de> for {type_name}{type_gen
             }}
         }}
 
-        deserializer.deserialize_struct(
-            {deserialize_type_name},
-            &[
-{field_name_list}            ],
-            Visitor{visitor_create_field},
-        )
-    }}
+{deserialize}    }}
 }}
\ No newline at end of file
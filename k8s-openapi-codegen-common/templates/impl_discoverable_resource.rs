@@ -0,0 +1,12 @@
+
+impl {local}DiscoverableResource for {rust_type} {{
+    const API_RESOURCE: {local}ApiResource = {local}ApiResource {{
+        group: {group:?},
+        version: {version:?},
+        kind: {kind:?},
+        plural: {plural:?},
+        scope: {local}ApiResourceScope::{scope},
+        verbs: &[{verbs}],
+        subresources: &[{subresources}],
+    }};
+}}
\ No newline at end of file
@@ -0,0 +1,14 @@
+
+impl From<{owned_type_name}> for {type_name} {{
+    fn from(value: {owned_type_name}) -> Self {{
+        {type_name} {{
+{fields}        }}
+    }}
+}}
+
+impl From<{type_name}> for {owned_type_name} {{
+    fn from(value: {type_name}) -> Self {{
+        {owned_type_name} {{
+{fields}        }}
+    }}
+}}
\ No newline at end of file
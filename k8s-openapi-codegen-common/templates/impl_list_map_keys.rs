@@ -0,0 +1,3 @@
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl{type_generics_impl} {local}unknown_fields::ListMapKeys for {type_name}{type_generics_type}{type_generics_where} {{{body}}}
\ No newline at end of file
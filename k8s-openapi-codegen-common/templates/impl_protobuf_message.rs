@@ -0,0 +1,9 @@
+
+#[cfg(feature = "protobuf")]
+impl{type_generics_impl} {local}protobuf::Message for {type_name}{type_generics_type}{type_generics_where} {{
+    fn encode(&self, {encode_buf}: &mut std::vec::Vec<u8>) {{
+{encode_body}    }}
+
+    fn decode({decode_buf}: &[u8]) -> Result<Self, {local}protobuf::DecodeError> {{
+{decode_body}    }}{decode_optional}
+}}
\ No newline at end of file
@@ -0,0 +1,4 @@
+
+impl {local}SubresourceOf<{parent_rust_type}> for {rust_type} {{
+    const NAME: &'static str = {name:?};
+}}
\ No newline at end of file
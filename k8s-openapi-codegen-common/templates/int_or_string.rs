@@ -15,6 +15,22 @@ impl {local}DeepMerge for {type_name} {{
     }}
 }}
 
+impl {local}Defaulting for {type_name} {{
+    fn set_defaults(&mut self) {{
+    }}
+}}
+
+impl {local}Normalize for {type_name} {{
+    fn normalize(&mut self) {{
+    }}
+}}
+
+impl {local}diff::Diff for {type_name} {{
+    fn diff(&self, other: &Self, path: &{local}diff::Path<'_>, changes: &mut std::vec::Vec<{local}diff::Change>) {{
+        {local}diff::strategies::scalar(self, other, path, changes);
+    }}
+}}
+
 impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
         struct Visitor;
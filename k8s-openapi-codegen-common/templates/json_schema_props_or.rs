@@ -9,6 +9,29 @@ impl {local}DeepMerge for {type_name} {{
     }}
 }}
 
+impl {local}Defaulting for {type_name} {{
+    fn set_defaults(&mut self) {{
+        match self {{
+            {type_name}::Schema(value) => {local}Defaulting::set_defaults(value),
+            {type_name}::{or_variant_name}(value) => {local}Defaulting::set_defaults(value),
+        }}
+    }}
+}}
+
+impl {local}Normalize for {type_name} {{
+    fn normalize(&mut self) {{
+        match self {{
+            {type_name}::Schema(value) => {local}Normalize::normalize(value),
+{or_variant_normalize}        }}
+    }}
+}}
+
+impl {local}diff::Diff for {type_name} {{
+    fn diff(&self, other: &Self, path: &{local}diff::Path<'_>, changes: &mut std::vec::Vec<{local}diff::Change>) {{
+        {local}diff::strategies::scalar(self, other, path, changes);
+    }}
+}}
+
 impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
         struct Visitor;
@@ -6,6 +6,24 @@ impl {local}DeepMerge for {type_name} {{
     }}
 }}
 
+impl {local}Defaulting for {type_name} {{
+    fn set_defaults(&mut self) {{
+        {local}Defaulting::set_defaults(&mut self.0);
+    }}
+}}
+
+impl {local}Normalize for {type_name} {{
+    fn normalize(&mut self) {{
+        {local}Normalize::normalize(&mut self.0);
+    }}
+}}
+
+impl {local}diff::Diff for {type_name} {{
+    fn diff(&self, other: &Self, path: &{local}diff::Path<'_>, changes: &mut std::vec::Vec<{local}diff::Change>) {{
+        {local}diff::strategies::scalar(self, other, path, changes);
+    }}
+}}
+
 impl<'de> {local}serde::Deserialize<'de> for {type_name} {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: {local}serde::Deserializer<'de> {{
         struct Visitor;
@@ -34,6 +52,6 @@ impl From<{inner_type_name}> for {type_name} {{
 
 impl {local}serde::Serialize for {type_name} {{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: {local}serde::Serializer {{
-        serializer.serialize_newtype_struct({type_name:?}, {inner_value})
+{serialize_prelude}        serializer.serialize_newtype_struct({type_name:?}, {inner_value})
     }}
 }}
\ No newline at end of file
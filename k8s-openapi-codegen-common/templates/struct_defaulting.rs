@@ -0,0 +1,5 @@
+
+impl{type_generics_type} {local}Defaulting for {type_name}{type_generics_type}{type_generics_where} {{
+    fn set_defaults(&mut self) {{
+{set_defaults_body}    }}
+}}
\ No newline at end of file
@@ -0,0 +1,5 @@
+
+impl{type_generics_type} {local}diff::Diff for {type_name}{type_generics_type}{type_generics_where} {{
+    fn diff(&self, other: &Self, path: &{local}diff::Path<'_>, changes: &mut std::vec::Vec<{local}diff::Change>) {{
+{diff_body}    }}
+}}
\ No newline at end of file
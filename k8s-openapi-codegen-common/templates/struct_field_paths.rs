@@ -0,0 +1,7 @@
+
+impl{type_generics_type} {type_name}{type_generics_type}{type_generics_where} {{
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> {local}FieldPath<Self> {{
+        {local}FieldPath::root()
+    }}
+}}{field_methods}
\ No newline at end of file
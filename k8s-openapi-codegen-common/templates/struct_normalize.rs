@@ -0,0 +1,5 @@
+
+impl{type_generics_type} {local}Normalize for {type_name}{type_generics_type}{type_generics_where} {{
+    fn normalize(&mut self) {{
+{normalize_body}    }}
+{clear_server_populated_fields}}}
\ No newline at end of file
@@ -0,0 +1,31 @@
+
+/// The members of the [`{type_name}`] union. At most one of them may be set.
+{derives}{vis}enum {union_name} {{
+{variants}}}
+
+/// A reference to the member of the [`{type_name}`] union that is set, as returned by [`{type_name}::union`].
+{ref_derives}{vis}enum {union_name}Ref<'a> {{
+{ref_variants}}}
+
+impl {type_name} {{
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set{discriminator_doc}.
+    pub fn union(&self) -> Result<Option<{union_name}Ref<'_>>, {local}UnionError> {{
+        let mut members = std::vec![];
+        let mut result = None;
+{get_members}
+        if members.len() > 1 {{
+            return Err({local}UnionError::MultipleMembersSet(members));
+        }}
+{check_discriminator}
+        Ok(result)
+    }}
+
+    /// Sets the given member of the union, and unsets all the others.{set_discriminator_doc}
+    pub fn set_union(&mut self, value: Option<{union_name}>) {{
+{unset_members}
+        match value {{
+{set_members}        }}
+    }}
+}}{impl_from}
\ No newline at end of file
@@ -0,0 +1,36 @@
+// Generated from all definitions
+
+//! Visitors that walk a value and the values of other types that it contains, in the style of the `syn::visit` module.
+//!
+//! [`Visit`] has a method for each type, which by default calls the function of the same name in this module to walk the values that
+//! the value contains. A visitor overrides the methods of the types that it is interested in, and calls the function of the same name
+//! if it wants to continue walking the value.
+//!
+//! ```rust,ignore
+//! struct Images<'ast>(Vec<&'ast str>);
+//!
+//! impl<'ast> k8s_openapi::visit::Visit<'ast> for Images<'ast> {{
+//!     fn visit_api_core_v1_container(&mut self, node: &'ast Container) {{
+//!         self.0.extend(node.image.as_deref());
+//!         k8s_openapi::visit::visit_api_core_v1_container(self, node);
+//!     }}
+//! }}
+//!
+//! let mut images = Images(vec![]);
+//! k8s_openapi::visit::Visitable::visit(&deployment, &mut images);
+//! ```
+//!
+//! See the [`visit_mut`](super::visit_mut) module for visitors that can modify the values.
+
+/// A visitor of values of the types in this crate.
+///
+/// The method of each type is named after the path of the type, like `visit_api_core_v1_pod` for `api::core::v1::Pod`.
+pub trait Visit<'ast> {{
+{trait_methods}}}
+
+/// A value that can be walked by a [`Visit`] visitor.
+pub trait Visitable {{
+    /// Calls the method of the visitor for the type of this value.
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized;
+}}
+{walk_fns}{visitable_impls}
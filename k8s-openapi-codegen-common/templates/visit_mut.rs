@@ -0,0 +1,37 @@
+// Generated from all definitions
+
+//! Visitors that walk a value and the values of other types that it contains and can modify them, in the style of the `syn::visit_mut` module.
+//!
+//! [`VisitMut`] has a method for each type, which by default calls the function of the same name in this module to walk the values that
+//! the value contains. A visitor overrides the methods of the types that it is interested in, and calls the function of the same name
+//! if it wants to continue walking the value.
+//!
+//! ```rust,ignore
+//! struct PinImages;
+//!
+//! impl k8s_openapi::visit_mut::VisitMut for PinImages {{
+//!     fn visit_api_core_v1_container_mut(&mut self, node: &mut Container) {{
+//!         if let Some(image) = &mut node.image {{
+//!             *image = pin(image);
+//!         }}
+//!         k8s_openapi::visit_mut::visit_api_core_v1_container_mut(self, node);
+//!     }}
+//! }}
+//!
+//! k8s_openapi::visit_mut::VisitableMut::visit_mut(&mut deployment, &mut PinImages);
+//! ```
+//!
+//! See the [`visit`](super::visit) module for visitors that only read the values.
+
+/// A visitor of values of the types in this crate that can modify them.
+///
+/// The method of each type is named after the path of the type, like `visit_api_core_v1_pod_mut` for `api::core::v1::Pod`.
+pub trait VisitMut {{
+{trait_methods}}}
+
+/// A value that can be walked by a [`VisitMut`] visitor.
+pub trait VisitableMut {{
+    /// Calls the method of the visitor for the type of this value.
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized;
+}}
+{walk_fns}{visitable_impls}
@@ -224,13 +224,29 @@ pub(crate) fn list(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error
                                 kubernetes_group_kind_versions: vec![],
                                 list_kind: None,
                                 merge_type: crate::swagger20::MergeType::Default,
+                                validation: crate::swagger20::SchemaValidation::default(),
+                                extensions: crate::swagger20::SchemaExtensions::default(),
                                 impl_deep_merge: true,
+                                impl_normalize: true,
+                                impl_diff: true,
+                                impl_field_paths: true,
+                                impl_defaulting: true,
+                                defaulting_function: None,
+                                protobuf_field_number: None,
                             }),
                         }),
                         kubernetes_group_kind_versions: vec![],
                         list_kind: None,
                         merge_type: crate::swagger20::MergeType::Default,
+                        validation: crate::swagger20::SchemaValidation::default(),
+                        extensions: crate::swagger20::SchemaExtensions::default(),
                         impl_deep_merge: true,
+                        impl_normalize: true,
+                        impl_diff: true,
+                        impl_field_paths: true,
+                        impl_defaulting: true,
+                        defaulting_function: None,
+                        protobuf_field_number: None,
                     },
                     true,
                 ));
@@ -253,7 +269,15 @@ pub(crate) fn list(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error
             kubernetes_group_kind_versions: vec![],
             list_kind: None,
             merge_type: crate::swagger20::MergeType::Default,
+            validation: crate::swagger20::SchemaValidation::default(),
+            extensions: crate::swagger20::SchemaExtensions::default(),
             impl_deep_merge: true,
+            impl_normalize: true,
+            impl_diff: true,
+            impl_field_paths: true,
+            impl_defaulting: true,
+            defaulting_function: None,
+            protobuf_field_number: None,
         });
 
 
@@ -324,3 +348,358 @@ pub(crate) fn resource_metadata_not_optional(spec: &mut crate::swagger20::Spec)
         Err("never applied override to make resource metadata non-optional".into())
     }
 }
+
+// Set the defaults of the properties of built-in types that the API server's `SetDefaults_*` functions set, for the codegen of `Defaulting` impls,
+// along with the functions that set the defaults that depend on the values of other properties.
+// Upstream only declares the defaults that are set with `+default` markers in its spec, so only the properties that don't already have a default are overridden.
+//
+// Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/v1/defaults.go and the `defaults.go` files of the other API groups
+pub(crate) fn defaults(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+    use crate::swagger20::{JsonNumber, JsonValue};
+
+    fn string(value: &str) -> JsonValue {
+        JsonValue::String(value.to_owned())
+    }
+
+    fn int(value: u64) -> JsonValue {
+        JsonValue::Number(JsonNumber::PosInt(value))
+    }
+
+    fn object() -> JsonValue {
+        JsonValue::Object(Default::default())
+    }
+
+    // The definition path, and the name of the function in `k8s_openapi::defaulting_strategies` that sets the rest of its defaults.
+    const DEFAULTING_FUNCTIONS: &[(&str, &str)] = &[
+        ("io.k8s.api.apps.v1.DaemonSetUpdateStrategy", "apps_v1::daemon_set_update_strategy"),
+        ("io.k8s.api.apps.v1.DeploymentStrategy", "apps_v1::deployment_strategy"),
+        ("io.k8s.api.apps.v1.StatefulSetUpdateStrategy", "apps_v1::stateful_set_update_strategy"),
+        ("io.k8s.api.autoscaling.v2.HorizontalPodAutoscalerSpec", "autoscaling_v2::horizontal_pod_autoscaler_spec"),
+        ("io.k8s.api.batch.v1.Job", "batch_v1::job"),
+        ("io.k8s.api.core.v1.Container", "core_v1::container"),
+        ("io.k8s.api.core.v1.EphemeralContainer", "core_v1::ephemeral_container"),
+        ("io.k8s.api.core.v1.Pod", "core_v1::pod"),
+        ("io.k8s.api.core.v1.ReplicationController", "core_v1::replication_controller"),
+        ("io.k8s.api.core.v1.ServicePort", "core_v1::service_port"),
+        ("io.k8s.api.core.v1.ServiceSpec", "core_v1::service_spec"),
+        ("io.k8s.api.networking.v1.NetworkPolicySpec", "networking_v1::network_policy_spec"),
+        ("io.k8s.api.rbac.v1.Subject", "rbac_v1::subject"),
+    ];
+
+    // The definition path, and the names and defaults of its properties.
+    let defaults = [
+        ("io.k8s.api.admissionregistration.v1.MutatingWebhook", vec![
+            ("failurePolicy", string("Fail")),
+            ("matchPolicy", string("Equivalent")),
+            ("namespaceSelector", object()),
+            ("objectSelector", object()),
+            ("reinvocationPolicy", string("Never")),
+            ("timeoutSeconds", int(10)),
+        ]),
+        ("io.k8s.api.admissionregistration.v1.RuleWithOperations", vec![
+            ("scope", string("*")),
+        ]),
+        ("io.k8s.api.admissionregistration.v1.ServiceReference", vec![
+            ("port", int(443)),
+        ]),
+        ("io.k8s.api.admissionregistration.v1.ValidatingWebhook", vec![
+            ("failurePolicy", string("Fail")),
+            ("matchPolicy", string("Equivalent")),
+            ("namespaceSelector", object()),
+            ("objectSelector", object()),
+            ("timeoutSeconds", int(10)),
+        ]),
+        ("io.k8s.api.apps.v1.DaemonSetSpec", vec![
+            ("revisionHistoryLimit", int(10)),
+            ("updateStrategy", object()),
+        ]),
+        ("io.k8s.api.apps.v1.DaemonSetUpdateStrategy", vec![
+            ("type", string("RollingUpdate")),
+        ]),
+        ("io.k8s.api.apps.v1.DeploymentSpec", vec![
+            ("progressDeadlineSeconds", int(600)),
+            ("replicas", int(1)),
+            ("revisionHistoryLimit", int(10)),
+            ("strategy", object()),
+        ]),
+        ("io.k8s.api.apps.v1.DeploymentStrategy", vec![
+            ("type", string("RollingUpdate")),
+        ]),
+        ("io.k8s.api.apps.v1.ReplicaSetSpec", vec![
+            ("replicas", int(1)),
+        ]),
+        ("io.k8s.api.apps.v1.RollingUpdateDaemonSet", vec![
+            ("maxSurge", int(0)),
+            ("maxUnavailable", int(1)),
+        ]),
+        ("io.k8s.api.apps.v1.RollingUpdateDeployment", vec![
+            ("maxSurge", string("25%")),
+            ("maxUnavailable", string("25%")),
+        ]),
+        ("io.k8s.api.apps.v1.RollingUpdateStatefulSetStrategy", vec![
+            ("partition", int(0)),
+        ]),
+        ("io.k8s.api.apps.v1.StatefulSetPersistentVolumeClaimRetentionPolicy", vec![
+            ("whenDeleted", string("Retain")),
+            ("whenScaled", string("Retain")),
+        ]),
+        ("io.k8s.api.apps.v1.StatefulSetSpec", vec![
+            ("persistentVolumeClaimRetentionPolicy", object()),
+            ("podManagementPolicy", string("OrderedReady")),
+            ("replicas", int(1)),
+            ("revisionHistoryLimit", int(10)),
+            ("updateStrategy", object()),
+        ]),
+        ("io.k8s.api.autoscaling.v1.HorizontalPodAutoscalerSpec", vec![
+            ("minReplicas", int(1)),
+        ]),
+        ("io.k8s.api.autoscaling.v2.HorizontalPodAutoscalerSpec", vec![
+            ("minReplicas", int(1)),
+        ]),
+        ("io.k8s.api.batch.v1.CronJobSpec", vec![
+            ("concurrencyPolicy", string("Allow")),
+            ("failedJobsHistoryLimit", int(1)),
+            ("successfulJobsHistoryLimit", int(3)),
+            ("suspend", JsonValue::Bool(false)),
+        ]),
+        ("io.k8s.api.core.v1.AzureDiskVolumeSource", vec![
+            ("cachingMode", string("ReadWrite")),
+            ("fsType", string("ext4")),
+            ("kind", string("Shared")),
+            ("readOnly", JsonValue::Bool(false)),
+        ]),
+        ("io.k8s.api.core.v1.ConfigMapVolumeSource", vec![
+            ("defaultMode", int(0o644)),
+        ]),
+        ("io.k8s.api.core.v1.Container", vec![
+            ("terminationMessagePath", string("/dev/termination-log")),
+            ("terminationMessagePolicy", string("File")),
+        ]),
+        ("io.k8s.api.core.v1.ContainerPort", vec![
+            ("protocol", string("TCP")),
+        ]),
+        ("io.k8s.api.core.v1.DownwardAPIVolumeSource", vec![
+            ("defaultMode", int(0o644)),
+        ]),
+        ("io.k8s.api.core.v1.EndpointPort", vec![
+            ("protocol", string("TCP")),
+        ]),
+        ("io.k8s.api.core.v1.EphemeralContainer", vec![
+            ("terminationMessagePath", string("/dev/termination-log")),
+            ("terminationMessagePolicy", string("File")),
+        ]),
+        ("io.k8s.api.core.v1.HTTPGetAction", vec![
+            ("path", string("/")),
+            ("scheme", string("HTTP")),
+        ]),
+        ("io.k8s.api.core.v1.ISCSIPersistentVolumeSource", vec![
+            ("iscsiInterface", string("default")),
+        ]),
+        ("io.k8s.api.core.v1.ISCSIVolumeSource", vec![
+            ("iscsiInterface", string("default")),
+        ]),
+        ("io.k8s.api.core.v1.NamespaceStatus", vec![
+            ("phase", string("Active")),
+        ]),
+        ("io.k8s.api.core.v1.ObjectFieldSelector", vec![
+            ("apiVersion", string("v1")),
+        ]),
+        ("io.k8s.api.core.v1.PersistentVolumeClaimSpec", vec![
+            ("volumeMode", string("Filesystem")),
+        ]),
+        ("io.k8s.api.core.v1.PersistentVolumeSpec", vec![
+            ("persistentVolumeReclaimPolicy", string("Retain")),
+            ("volumeMode", string("Filesystem")),
+        ]),
+        ("io.k8s.api.core.v1.PodSpec", vec![
+            ("dnsPolicy", string("ClusterFirst")),
+            ("restartPolicy", string("Always")),
+            ("schedulerName", string("default-scheduler")),
+            ("securityContext", object()),
+            ("terminationGracePeriodSeconds", int(30)),
+        ]),
+        ("io.k8s.api.core.v1.Probe", vec![
+            ("failureThreshold", int(3)),
+            ("periodSeconds", int(10)),
+            ("successThreshold", int(1)),
+            ("timeoutSeconds", int(1)),
+        ]),
+        ("io.k8s.api.core.v1.ProjectedVolumeSource", vec![
+            ("defaultMode", int(0o644)),
+        ]),
+        ("io.k8s.api.core.v1.RBDPersistentVolumeSource", vec![
+            ("keyring", string("/etc/ceph/keyring")),
+            ("pool", string("rbd")),
+            ("user", string("admin")),
+        ]),
+        ("io.k8s.api.core.v1.RBDVolumeSource", vec![
+            ("keyring", string("/etc/ceph/keyring")),
+            ("pool", string("rbd")),
+            ("user", string("admin")),
+        ]),
+        ("io.k8s.api.core.v1.ReplicationControllerSpec", vec![
+            ("replicas", int(1)),
+        ]),
+        ("io.k8s.api.core.v1.Secret", vec![
+            ("type", string("Opaque")),
+        ]),
+        ("io.k8s.api.core.v1.SecretVolumeSource", vec![
+            ("defaultMode", int(0o644)),
+        ]),
+        ("io.k8s.api.core.v1.ServiceAccountTokenProjection", vec![
+            ("expirationSeconds", int(3600)),
+        ]),
+        ("io.k8s.api.core.v1.ServicePort", vec![
+            ("protocol", string("TCP")),
+        ]),
+        ("io.k8s.api.core.v1.ServiceSpec", vec![
+            ("sessionAffinity", string("None")),
+            ("type", string("ClusterIP")),
+        ]),
+        ("io.k8s.api.networking.v1.NetworkPolicyPort", vec![
+            ("protocol", string("TCP")),
+        ]),
+        ("io.k8s.api.rbac.v1.RoleRef", vec![
+            ("apiGroup", string("rbac.authorization.k8s.io")),
+        ]),
+        ("io.k8s.api.storage.v1.StorageClass", vec![
+            ("reclaimPolicy", string("Delete")),
+            ("volumeBindingMode", string("Immediate")),
+        ]),
+    ];
+
+    for (definition_path, property_defaults) in defaults {
+        let definition =
+            spec.definitions.get_mut(definition_path)
+            .ok_or_else(|| format!("never applied defaults override to {definition_path}"))?;
+        let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind else {
+            return Err(format!("never applied defaults override to {definition_path}").into());
+        };
+
+        for (property_name, default) in property_defaults {
+            let (property, _) =
+                properties.get_mut(property_name)
+                .ok_or_else(|| format!("never applied defaults override to {definition_path}.{property_name}"))?;
+            if property.validation.default.is_none() {
+                property.validation.default = Some(default);
+            }
+        }
+    }
+
+    for &(definition_path, function_name) in DEFAULTING_FUNCTIONS {
+        let definition =
+            spec.definitions.get_mut(definition_path)
+            .ok_or_else(|| format!("never applied defaulting function override to {definition_path}"))?;
+        definition.defaulting_function = Some(format!("crate::defaulting_strategies::{function_name}"));
+    }
+
+    Ok(())
+}
+
+// Annotate the types whose properties are the members of a union with `x-kubernetes-unions`, for the codegen of typed enums of their members.
+// Upstream only documents these unions in the descriptions of the types, so only the types that don't already have the annotation are annotated.
+//
+// Ref: https://github.com/kubernetes/enhancements/tree/master/keps/sig-api-machinery/1027-api-unions
+pub(crate) fn unions(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+    // The definition path, the name of the discriminator property if any, and the names of the properties that are not members of the union.
+    // All other properties are members of the union.
+    const UNIONS: &[(&str, Option<&str>, &[&str])] = &[
+        ("io.k8s.api.autoscaling.v2.MetricSpec", Some("type"), &[]),
+        ("io.k8s.api.autoscaling.v2.MetricStatus", Some("type"), &[]),
+        ("io.k8s.api.core.v1.LifecycleHandler", None, &[]),
+        ("io.k8s.api.core.v1.Probe", None, &[
+            "failureThreshold",
+            "initialDelaySeconds",
+            "periodSeconds",
+            "successThreshold",
+            "terminationGracePeriodSeconds",
+            "timeoutSeconds",
+        ]),
+        ("io.k8s.api.core.v1.Volume", None, &["name"]),
+    ];
+
+    for &(definition_path, discriminator, non_members) in UNIONS {
+        let definition =
+            spec.definitions.get_mut(definition_path)
+            .ok_or_else(|| format!("never applied union override to {definition_path}"))?;
+        let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else {
+            return Err(format!("never applied union override to {definition_path}").into());
+        };
+
+        if !definition.extensions.kubernetes_unions.is_empty() {
+            continue;
+        }
+
+        let fields_to_discriminate_by =
+            properties.keys()
+            .filter(|name| Some(&*name.0) != discriminator && !non_members.contains(&&*name.0))
+            .map(|name| {
+                // The discriminator values are the names of the members in PascalCase, like `ContainerResource` for `containerResource`.
+                let mut chars = name.chars();
+                let value = chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default();
+                (name.0.clone(), value)
+            })
+            .collect();
+
+        definition.extensions.kubernetes_unions.push(crate::swagger20::KubernetesUnion {
+            discriminator: discriminator.map(ToOwned::to_owned),
+            fields_to_discriminate_by,
+        });
+    }
+
+    Ok(())
+}
+
+// Synthesize the `swagger20::Type::AnyResource` enum of all resource types with object metadata for special codegen.
+pub(crate) fn any_resource(spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
+    let mut resources = vec![];
+
+    for (definition_path, definition) in &spec.definitions {
+        if definition.kubernetes_group_kind_versions.len() != 1 {
+            continue;
+        }
+
+        let crate::swagger20::SchemaKind::Properties(properties) = &definition.kind else { continue; };
+
+        if !properties.contains_key("apiVersion") || !properties.contains_key("kind") {
+            continue;
+        }
+
+        let Some((metadata_schema, true)) = properties.get("metadata") else { continue; };
+
+        let crate::swagger20::SchemaKind::Ref(metadata_ref_path) = &metadata_schema.kind else { continue; };
+        if metadata_ref_path.path != "io.k8s.apimachinery.pkg.apis.meta.v1.ObjectMeta" {
+            continue;
+        }
+
+        resources.push(crate::swagger20::RefPath {
+            path: definition_path.0.clone(),
+            can_be_default: None,
+        });
+    }
+
+    if resources.is_empty() {
+        return Err("did not find any resource types".into());
+    }
+
+    spec.definitions.insert(
+        crate::swagger20::DefinitionPath("io.k8s.AnyResource".to_owned()),
+        crate::swagger20::Schema {
+            description: Some("AnyResource is a resource of any type.".to_owned()),
+            kind: crate::swagger20::SchemaKind::Ty(crate::swagger20::Type::AnyResource { resources }),
+            kubernetes_group_kind_versions: vec![],
+            list_kind: None,
+            merge_type: crate::swagger20::MergeType::Default,
+            validation: crate::swagger20::SchemaValidation::default(),
+            extensions: crate::swagger20::SchemaExtensions::default(),
+            impl_deep_merge: false,
+            impl_normalize: false,
+            impl_diff: false,
+            impl_field_paths: false,
+            impl_defaulting: false,
+            defaulting_function: None,
+            protobuf_field_number: None,
+        });
+
+    Ok(())
+}
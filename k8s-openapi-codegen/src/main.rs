@@ -8,6 +8,7 @@
 
 mod fixups;
 mod logger;
+mod protobuf;
 mod supported_version;
 
 use futures_util::TryStreamExt;
@@ -68,7 +69,7 @@ impl_from_for_error! {
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<(), Error> {
-    let Options { versions: requested_versions } = clap::Parser::parse();
+    let Options { versions: requested_versions, proto_base_urls } = clap::Parser::parse();
 
     {
         let logger = logger::Logger;
@@ -99,12 +100,17 @@ async fn main() -> Result<(), Error> {
         .map(|RequestedVersion { version, overriden_spec_url }| {
             let out_dir_base = out_dir_base.clone();
             let client = client.clone();
+            let overriden_proto_base_url =
+                proto_base_urls.iter()
+                .find(|proto_base_url| proto_base_url.version.name() == version.name())
+                .map(|proto_base_url| proto_base_url.url.clone());
 
             async move {
                 let task_local_logger = logger::make_local_logger(version.name());
                 let spec_url = overriden_spec_url.as_deref().unwrap_or_else(|| version.spec_url());
+                let proto_base_url = overriden_proto_base_url.as_deref().unwrap_or_else(|| version.proto_base_url());
                 logger::TASK_LOCAL_LOGGER.scope(task_local_logger, async {
-                    match run(version, spec_url, &out_dir_base, &client).await {
+                    match run(version, spec_url, proto_base_url, &out_dir_base, &client).await {
                         Ok(()) => Ok(()),
                         Err(err) => {
                             log::error!("Error: {err}");
@@ -140,6 +146,16 @@ struct Options {
     /// using their respective OpenAPI specs from the <https://github.com/kubernetes/kubernetes> repository.
     #[clap(long = "generate", value_name = "VERSION")]
     versions: Vec<RequestedVersion>,
+
+    /// This parameter overrides the location of the `generated.proto` files that the protobuf field numbers are read from.
+    ///
+    /// `--proto=1.27:file:///path/to/kubernetes/staging/src/k8s.io` means "read the field numbers of the v1.27 bindings
+    /// from the `generated.proto` files under the directory /path/to/kubernetes/staging/src/k8s.io".
+    ///
+    /// If this parameter isn't specified for a version, the files are read from that version's tag
+    /// of the <https://github.com/kubernetes/kubernetes> repository.
+    #[clap(long = "proto", value_name = "VERSION:URL")]
+    proto_base_urls: Vec<ProtoBaseUrl>,
 }
 
 #[derive(Clone)]
@@ -183,9 +199,29 @@ impl std::str::FromStr for RequestedVersion {
     }
 }
 
+#[derive(Clone)]
+struct ProtoBaseUrl {
+    version: supported_version::SupportedVersion,
+    url: String,
+}
+
+impl std::str::FromStr for ProtoBaseUrl {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let RequestedVersion { version, overriden_spec_url: url } = s.parse()?;
+        let url = url.ok_or_else(|| format!("{s:?} does not specify a URL"))?;
+        Ok(ProtoBaseUrl {
+            version,
+            url,
+        })
+    }
+}
+
 async fn run(
     supported_version: supported_version::SupportedVersion,
     spec_url: &str,
+    proto_base_url: &str,
     out_dir_base: &std::path::Path,
     client: &reqwest::Client,
 ) -> Result<(), Error> {
@@ -215,6 +251,8 @@ async fn run(
         }
     };
 
+    protobuf::set_field_numbers(&mut spec, proto_base_url, client).await?;
+
     let () = tokio::task::spawn_blocking(move || -> Result<(), Error> {
         {
             let thread_local_logger = logger::make_local_logger(supported_version.name());
@@ -248,6 +286,22 @@ async fn run(
             }
         }
 
+        log::info!("Generating API resources...");
+        {
+            // The operations are consumed by `run`, so this must be done before generating the types.
+            let api_resources_out = std::io::BufWriter::new(std::fs::File::create(out_dir.join("api_resources.rs"))?);
+            k8s_openapi_codegen_common::generate_api_resources(&spec.definitions, &spec.operations, &MapNamespace, api_resources_out)?;
+        }
+        log::info!("OK");
+
+        log::info!("Generating variants manifest...");
+        {
+            // The borrowed and compact variants of the types are generated from this manifest by the k8s-openapi build script.
+            let variants_out = std::io::BufWriter::new(std::fs::File::create(out_dir.join("variants.txt"))?);
+            k8s_openapi_codegen_common::variants::write_manifest(&spec.definitions, &MapNamespace, variants_out)?;
+        }
+        log::info!("OK");
+
         log::info!("Generating types...");
 
         for definition_path in spec.definitions.keys() {
@@ -255,6 +309,7 @@ async fn run(
 
             let run_state = RunState {
                 out_dir: &out_dir,
+                has_union: !spec.definitions[definition_path].extensions.kubernetes_unions.is_empty(),
                 parent_mod_rs_file_and_mod_name: None,
             };
 
@@ -265,6 +320,7 @@ async fn run(
                 &MapNamespace,
                 "pub ",
                 k8s_openapi_codegen_common::GenerateSchema::Yes { feature: Some("schemars") },
+                k8s_openapi_codegen_common::PreserveUnknownFields::No,
                 run_state,
             )?;
 
@@ -273,6 +329,25 @@ async fn run(
         }
 
         log::info!("OK");
+
+        log::info!("Generating visitors...");
+        {
+            use std::io::Write;
+
+            let visit_out = std::io::BufWriter::new(std::fs::File::create(out_dir.join("visit.rs"))?);
+            let visit_mut_out = std::io::BufWriter::new(std::fs::File::create(out_dir.join("visit_mut.rs"))?);
+            k8s_openapi_codegen_common::generate_visitors(&spec.definitions, &MapNamespace, visit_out, visit_mut_out)?;
+
+            let mut mod_rs = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).open(out_dir.join("mod.rs"))?);
+            writeln!(mod_rs)?;
+            writeln!(mod_rs, "pub mod visit;")?;
+            writeln!(mod_rs)?;
+            writeln!(mod_rs, "pub mod visit_mut;")?;
+            writeln!(mod_rs)?;
+            writeln!(mod_rs, "mod api_resources;")?;
+        }
+        log::info!("OK");
+
         log::info!("Generated {num_generated_structs} structs");
         log::info!("Generated {num_generated_type_aliases} type aliases");
 
@@ -301,6 +376,7 @@ impl k8s_openapi_codegen_common::MapNamespace for MapNamespace {
 
 struct RunState<'a> {
     out_dir: &'a std::path::Path,
+    has_union: bool,
     parent_mod_rs_file_and_mod_name: Option<(<Self as k8s_openapi_codegen_common::RunState>::Writer, std::borrow::Cow<'static, str>)>,
 }
 
@@ -349,7 +425,12 @@ impl k8s_openapi_codegen_common::RunState for RunState<'_> {
         let mut parent_mod_rs = std::io::BufWriter::new(std::fs::OpenOptions::new().append(true).create(true).open(current.join("mod.rs"))?);
         writeln!(parent_mod_rs)?;
         writeln!(parent_mod_rs, "mod {mod_name};")?;
-        writeln!(parent_mod_rs, "pub use self::{mod_name}::{type_name};")?;
+        if self.has_union {
+            writeln!(parent_mod_rs, "pub use self::{mod_name}::{{{type_name}, {type_name}Union, {type_name}UnionRef}};")?;
+        }
+        else {
+            writeln!(parent_mod_rs, "pub use self::{mod_name}::{type_name};")?;
+        }
 
         let file_name = current.join(&*mod_name).with_extension("rs");
         let file = std::io::BufWriter::new(std::fs::File::create(file_name)?);
@@ -0,0 +1,226 @@
+/// Sets the protobuf field numbers of the properties of the definitions in the spec.
+///
+/// The field numbers are taken from the `generated.proto` file of the Go package corresponding to each definition's namespace.
+/// Properties that are not found in the `generated.proto` file, and definitions whose package does not have one, are left without field numbers,
+/// which means no `k8s_openapi::protobuf::Message` impl is generated for them.
+///
+/// The base URL can also be a `file://` URL of a local directory, such as the `staging/src/k8s.io` directory of a checkout of the kubernetes repository.
+pub(crate) async fn set_field_numbers(
+    spec: &mut crate::swagger20::Spec,
+    proto_base_url: &str,
+    client: &reqwest::Client,
+) -> Result<(), crate::Error> {
+    let proto_base_url: url::Url = proto_base_url.parse()?;
+
+    let namespaces: std::collections::BTreeSet<String> =
+        spec.definitions.keys()
+        .filter_map(|definition_path| {
+            let (namespace, _) = definition_path.rsplit_once('.')?;
+            let namespace = namespace.strip_prefix("io.k8s.")?;
+            Some(namespace.to_owned())
+        })
+        .collect();
+
+    let mut protos = vec![];
+
+    for namespace in namespaces {
+        let proto_url = format!("{}/{}/generated.proto", proto_base_url.as_str().trim_end_matches('/'), namespace.replace('.', "/"));
+
+        log::info!("Parsing proto file at {proto_url} ...");
+        let proto =
+            if proto_base_url.scheme() == "file" {
+                let proto_url: url::Url = proto_url.parse()?;
+                let proto_path = proto_url.to_file_path().map_err(|()| "not a file path")?;
+                match std::fs::read_to_string(proto_path) {
+                    Ok(proto) => proto,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                        log::warn!("{namespace} does not have a generated.proto");
+                        continue;
+                    },
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            else {
+                let response = client.get(&proto_url).send().await?;
+                let status = response.status();
+                if status == reqwest::StatusCode::NOT_FOUND {
+                    log::warn!("{namespace} does not have a generated.proto");
+                    continue;
+                }
+                if status != reqwest::StatusCode::OK {
+                    return Err(status.to_string().into());
+                }
+                response.text().await?
+            };
+
+        protos.push((namespace, proto_url, proto));
+    }
+
+    // The messages of all packages, keyed by their fully-qualified names like `k8s.io.api.core.v1.Pod`,
+    // since the structs embedded in a type are not necessarily in the same package as the type.
+    let mut messages = Default::default();
+    let mut namespace_packages = std::collections::BTreeMap::new();
+    for (namespace, proto_url, proto) in &protos {
+        let package = parse(proto, &mut messages).map_err(|err| format!("could not parse {proto_url}: {err}"))?;
+        namespace_packages.insert(&**namespace, package);
+    }
+
+    for (definition_path, definition) in &mut spec.definitions {
+        let Some((namespace, type_name)) = definition_path.rsplit_once('.') else { continue; };
+        let Some(package) = namespace.strip_prefix("io.k8s.").and_then(|namespace| namespace_packages.get(namespace)) else { continue; };
+
+        let Some(message) = messages.get(&format!("{package}.{type_name}")) else { continue; };
+
+        let crate::swagger20::SchemaKind::Properties(properties) = &mut definition.kind else { continue; };
+
+        let property_names: std::collections::BTreeSet<_> = properties.keys().map(|name| normalize_name(name)).collect();
+
+        for (name, (schema, _)) in properties.iter_mut() {
+            schema.protobuf_field_number = find_field(message, &normalize_name(name), &property_names, &messages, &mut vec![], &mut vec![]);
+            if schema.protobuf_field_number.is_none() {
+                log::trace!("{definition_path} property {name} does not have a protobuf field number");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the field of the given message that corresponds to the property with the given normalized name.
+///
+/// Fields of message type that don't correspond to any property are golang structs embedded in the message,
+/// whose fields are inlined into the message's properties in the JSON representation. So if the message does not have the field itself,
+/// the field is searched for in the embedded messages, and in the messages embedded in them.
+fn find_field<'a>(
+    message: &Message,
+    normalized_name: &str,
+    property_names: &std::collections::BTreeSet<String>,
+    messages: &'a std::collections::BTreeMap<String, Message>,
+    embedded_in: &mut Vec<u32>,
+    embedded_types: &mut Vec<&'a str>,
+) -> Option<crate::swagger20::ProtobufFieldNumber> {
+    if let Some(field) = message.fields.get(normalized_name) {
+        return Some(
+            if embedded_in.is_empty() {
+                crate::swagger20::ProtobufFieldNumber::Field(field.number)
+            }
+            else {
+                crate::swagger20::ProtobufFieldNumber::Embedded { embedded_in: embedded_in.clone(), field_number: field.number }
+            });
+    }
+
+    for (field_name, field) in &message.fields {
+        if field.repeated || property_names.contains(field_name) {
+            continue;
+        }
+
+        let Some((embedded_type, embedded_message)) = messages.get_key_value(&field.ty) else { continue; };
+        if embedded_types.contains(&&**embedded_type) {
+            // Recursive types like `JSONSchemaProps` can't be embedded in themselves.
+            continue;
+        }
+
+        embedded_in.push(field.number);
+        embedded_types.push(embedded_type);
+        let result = find_field(embedded_message, normalized_name, property_names, messages, embedded_in, embedded_types);
+        embedded_types.pop();
+        embedded_in.pop();
+        if result.is_some() {
+            return result;
+        }
+    }
+
+    None
+}
+
+/// A message in a `generated.proto` file.
+#[derive(Default)]
+struct Message {
+    /// The fields of the message, keyed by their normalized names.
+    fields: std::collections::BTreeMap<String, Field>,
+}
+
+/// A field of a message in a `generated.proto` file.
+struct Field {
+    /// The fully-qualified type of the field, or the type of the values for repeated and map fields.
+    ty: String,
+
+    /// Whether the field is a repeated or map field.
+    repeated: bool,
+
+    number: u32,
+}
+
+/// Parses the messages of a `generated.proto` file into the given map of fully-qualified message names to messages,
+/// and returns the file's package name.
+///
+/// This is not a general-purpose protobuf parser. It only understands the subset of the syntax used by the files generated by `go-to-protobuf`,
+/// which have one field per line and no nested messages.
+fn parse(proto: &str, messages: &mut std::collections::BTreeMap<String, Message>) -> Result<String, String> {
+    let mut package = None;
+    let mut current_message = None;
+
+    for line in proto.lines() {
+        let line = line.split_once("//").map_or(line, |(line, _)| line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(package_name) = line.strip_prefix("package ").and_then(|line| line.strip_suffix(';')) {
+            package = Some(package_name.trim().to_owned());
+            continue;
+        }
+
+        if let Some(message_name) = line.strip_prefix("message ").and_then(|line| line.strip_suffix('{')) {
+            if current_message.is_some() {
+                return Err(format!("nested message {line:?}"));
+            }
+
+            let package = package.as_deref().ok_or_else(|| format!("message {line:?} before the package declaration"))?;
+            let message_name = format!("{package}.{}", message_name.trim());
+            messages.entry(message_name.clone()).or_default();
+            current_message = Some(message_name);
+            continue;
+        }
+
+        let Some(message_name) = &current_message else { continue; };
+
+        if line == "}" {
+            current_message = None;
+            continue;
+        }
+
+        // Lines are of the form `optional Type name = 1;`, `repeated Type name = 1;` or `map<KeyType, ValueType> name = 1;`
+        let Some((declaration, field_number)) = line.strip_suffix(';').and_then(|line| line.split_once('=')) else {
+            return Err(format!("unexpected line {line:?} in message {message_name}"));
+        };
+        let field_number = field_number.split('[').next().unwrap_or_default().trim();
+        let field_number: u32 = field_number.parse().map_err(|err| format!("invalid field number in line {line:?}: {err}"))?;
+        let (ty, field_name) = declaration.trim().rsplit_once(char::is_whitespace).ok_or_else(|| format!("unexpected line {line:?} in message {message_name}"))?;
+        let repeated = !ty.starts_with("optional ");
+        let ty = ty.rsplit([' ', ',']).next().unwrap_or_default().trim_end_matches('>');
+
+        // Types in the same package are not qualified.
+        let ty =
+            if ty.contains('.') {
+                ty.to_owned()
+            }
+            else {
+                let package = package.as_deref().unwrap_or_default();
+                format!("{package}.{ty}")
+            };
+
+        messages.entry(message_name.clone()).or_default().fields.insert(normalize_name(field_name), Field { ty, repeated, number: field_number });
+    }
+
+    package.ok_or_else(|| "file does not have a package declaration".to_owned())
+}
+
+/// Normalizes a property or protobuf field name so that they can be compared with each other.
+///
+/// The protobuf field names are usually the same as the JSON property names, but not always.
+/// For example, the `$ref` property of `JSONSchemaProps` is the `ref` field, and its `x-kubernetes-embedded-resource` property
+/// is the `xKubernetesEmbeddedResource` field.
+fn normalize_name(name: &str) -> String {
+    name.chars().filter(char::is_ascii_alphanumeric).map(|c| c.to_ascii_lowercase()).collect()
+}
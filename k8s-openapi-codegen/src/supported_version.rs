@@ -46,6 +46,17 @@ impl SupportedVersion {
         }
     }
 
+    /// The base URL of the Go packages whose `generated.proto` files contain the protobuf field numbers of the types in the spec.
+    pub(crate) fn proto_base_url(self) -> &'static str {
+        match self {
+            SupportedVersion::V1_31 => "https://raw.githubusercontent.com/kubernetes/kubernetes/v1.31.14/staging/src/k8s.io",
+            SupportedVersion::V1_32 => "https://raw.githubusercontent.com/kubernetes/kubernetes/v1.32.13/staging/src/k8s.io",
+            SupportedVersion::V1_33 => "https://raw.githubusercontent.com/kubernetes/kubernetes/v1.33.9/staging/src/k8s.io",
+            SupportedVersion::V1_34 => "https://raw.githubusercontent.com/kubernetes/kubernetes/v1.34.5/staging/src/k8s.io",
+            SupportedVersion::V1_35 => "https://raw.githubusercontent.com/kubernetes/kubernetes/v1.35.2/staging/src/k8s.io",
+        }
+    }
+
     pub(crate) fn fixup(self, spec: &mut crate::swagger20::Spec) -> Result<(), crate::Error> {
         #[allow(clippy::match_same_arms, clippy::type_complexity)]
         let upstream_bugs_fixups: &[fn(&mut crate::swagger20::Spec) -> Result<(), crate::Error>] = match self {
@@ -129,6 +140,9 @@ impl SupportedVersion {
             crate::fixups::special::watch_event,
             crate::fixups::special::list,
             crate::fixups::special::resource_metadata_not_optional,
+            crate::fixups::special::defaults,
+            crate::fixups::special::unions,
+            crate::fixups::special::any_resource,
         ];
 
         for fixup in upstream_bugs_fixups.iter().chain(special_fixups) {
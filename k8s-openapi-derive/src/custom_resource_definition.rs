@@ -2,6 +2,7 @@ use k8s_openapi_codegen_common::swagger20;
 
 use super::ResultExt;
 
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct CustomResourceDefinition {
     ident: proc_macro2::Ident,
     vis: syn::Visibility,
@@ -14,6 +15,10 @@ pub(super) struct CustomResourceDefinition {
     namespaced: bool,
     has_subresources: Option<String>,
     impl_deep_merge: bool,
+    impl_normalize: bool,
+    impl_diff: bool,
+    impl_field_paths: bool,
+    impl_defaulting: bool,
 }
 
 impl super::CustomDerive for CustomResourceDefinition {
@@ -28,6 +33,10 @@ impl super::CustomDerive for CustomResourceDefinition {
         let mut namespaced = false;
         let mut has_subresources = None;
         let mut impl_deep_merge = false;
+        let mut impl_normalize = false;
+        let mut impl_diff = false;
+        let mut impl_field_paths = false;
+        let mut impl_defaulting = false;
 
         for attr in &input.attrs {
             let syn::AttrStyle::Outer = attr.style else { continue; };
@@ -85,6 +94,22 @@ impl super::CustomDerive for CustomResourceDefinition {
                             impl_deep_merge = true;
                             continue;
                         }
+                        else if path.is_ident("impl_normalize") {
+                            impl_normalize = true;
+                            continue;
+                        }
+                        else if path.is_ident("impl_diff") {
+                            impl_diff = true;
+                            continue;
+                        }
+                        else if path.is_ident("impl_field_paths") {
+                            impl_field_paths = true;
+                            continue;
+                        }
+                        else if path.is_ident("impl_defaulting") {
+                            impl_defaulting = true;
+                            continue;
+                        }
                         else {
                             &meta
                         },
@@ -125,11 +150,15 @@ impl super::CustomDerive for CustomResourceDefinition {
             namespaced,
             has_subresources,
             impl_deep_merge,
+            impl_normalize,
+            impl_diff,
+            impl_field_paths,
+            impl_defaulting,
         })
     }
 
     fn emit(self) -> Result<proc_macro2::TokenStream, syn::Error> {
-        let CustomResourceDefinition { ident: cr_spec_name, vis, tokens, group, version, plural, generate_schema, namespaced, has_subresources, impl_deep_merge } = self;
+        let CustomResourceDefinition { ident: cr_spec_name, vis, tokens, group, version, plural, generate_schema, namespaced, has_subresources, impl_deep_merge, impl_normalize, impl_diff, impl_field_paths, impl_defaulting } = self;
 
         let vis: std::borrow::Cow<'_, str> = match vis {
             syn::Visibility::Inherited => "".into(),
@@ -168,7 +197,15 @@ impl super::CustomDerive for CustomResourceDefinition {
                             kubernetes_group_kind_versions: vec![],
                             list_kind: None,
                             merge_type: swagger20::MergeType::Default,
+                            validation: swagger20::SchemaValidation::default(),
+                            extensions: swagger20::SchemaExtensions::default(),
                             impl_deep_merge: true,
+                            impl_normalize: true,
+                            impl_diff: true,
+                            impl_field_paths: true,
+                            impl_defaulting: true,
+                            defaulting_function: None,
+                            protobuf_field_number: None,
                         }, false)),
                         (swagger20::PropertyName("kind".to_owned()), (swagger20::Schema {
                             description: Some("Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#types-kinds>".to_owned()),
@@ -176,7 +213,15 @@ impl super::CustomDerive for CustomResourceDefinition {
                             kubernetes_group_kind_versions: vec![],
                             list_kind: None,
                             merge_type: swagger20::MergeType::Default,
+                            validation: swagger20::SchemaValidation::default(),
+                            extensions: swagger20::SchemaExtensions::default(),
                             impl_deep_merge: true,
+                            impl_normalize: true,
+                            impl_diff: true,
+                            impl_field_paths: true,
+                            impl_defaulting: true,
+                            defaulting_function: None,
+                            protobuf_field_number: None,
                         }, false)),
                         (swagger20::PropertyName("metadata".to_owned()), (swagger20::Schema {
                             description: Some("Standard object's metadata. More info: <https://git.k8s.io/community/contributors/devel/sig-architecture/api-conventions.md#metadata>".to_owned()),
@@ -187,7 +232,15 @@ impl super::CustomDerive for CustomResourceDefinition {
                             kubernetes_group_kind_versions: vec![],
                             list_kind: None,
                             merge_type: swagger20::MergeType::Default,
+                            validation: swagger20::SchemaValidation::default(),
+                            extensions: swagger20::SchemaExtensions::default(),
                             impl_deep_merge: true,
+                            impl_normalize: true,
+                            impl_diff: true,
+                            impl_field_paths: true,
+                            impl_defaulting: true,
+                            defaulting_function: None,
+                            protobuf_field_number: None,
                         }, true)),
                         (swagger20::PropertyName("spec".to_owned()), (swagger20::Schema {
                             description: Some(format!("Specification of the `{cr_name}` custom resource")),
@@ -198,7 +251,15 @@ impl super::CustomDerive for CustomResourceDefinition {
                             kubernetes_group_kind_versions: vec![],
                             list_kind: None,
                             merge_type: swagger20::MergeType::Default,
+                            validation: swagger20::SchemaValidation::default(),
+                            extensions: swagger20::SchemaExtensions::default(),
                             impl_deep_merge: true,
+                            impl_normalize: true,
+                            impl_diff: true,
+                            impl_field_paths: true,
+                            impl_defaulting: true,
+                            defaulting_function: None,
+                            protobuf_field_number: None,
                         }, false)),
                     ].into_iter().chain(
                         has_subresources.map(|has_subresources|
@@ -208,7 +269,15 @@ impl super::CustomDerive for CustomResourceDefinition {
                                 kubernetes_group_kind_versions: vec![],
                                 list_kind: None,
                                 merge_type: swagger20::MergeType::Default,
+                                validation: swagger20::SchemaValidation::default(),
+                                extensions: swagger20::SchemaExtensions::default(),
                                 impl_deep_merge: true,
+                                impl_normalize: true,
+                                impl_diff: true,
+                                impl_field_paths: true,
+                                impl_defaulting: true,
+                                defaulting_function: None,
+                                protobuf_field_number: None,
                             }, true)))
                     ).collect()),
                     kubernetes_group_kind_versions: vec![
@@ -220,11 +289,22 @@ impl super::CustomDerive for CustomResourceDefinition {
                     ],
                     list_kind: Some(format!("{cr_name}List")),
                     merge_type: swagger20::MergeType::Default,
+                    validation: swagger20::SchemaValidation::default(),
+                    extensions: swagger20::SchemaExtensions::default(),
                     impl_deep_merge,
+                    impl_normalize,
+                    impl_diff,
+                    impl_field_paths,
+                    impl_defaulting,
+                    defaulting_function: None,
+                    protobuf_field_number: None,
                 }),
             ].into(),
             operations: vec![
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("create{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Post),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -232,10 +312,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Post,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("delete{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Delete),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -243,10 +330,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Delete,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("deleteCollection{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::DeleteCollection),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -254,10 +348,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Delete,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("list{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::List),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -265,10 +366,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Get,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("patch{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Patch),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -276,10 +384,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Patch,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("patch{namespace_operation_id_component}{cr_name}Status"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Patch),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -287,10 +402,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Patch,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("read{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Get),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -298,10 +420,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Get,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("read{namespace_operation_id_component}{cr_name}Status"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Get),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -309,10 +438,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Get,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("replace{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Put),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -320,10 +456,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Put,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("replace{namespace_operation_id_component}{cr_name}Status"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Put),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -331,10 +474,17 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Put,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}/{{name}}/status")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
 
                 swagger20::Operation {
+                    consumes: vec![],
+                    deprecated: false,
+                    description: None,
                     id: format!("watch{namespace_operation_id_component}{cr_name}"),
                     kubernetes_action: Some(swagger20::KubernetesAction::Watch),
                     kubernetes_group_kind_version: Some(swagger20::KubernetesGroupKindVersion {
@@ -342,7 +492,11 @@ impl super::CustomDerive for CustomResourceDefinition {
                         kind: cr_name.clone(),
                         version: version.clone(),
                     }),
+                    method: swagger20::Method::Get,
+                    parameters: vec![],
                     path: swagger20::Path(format!("/apis/{group}/{version}{namespace_path_component}/{plural}")),
+                    produces: vec![],
+                    responses: std::collections::BTreeMap::new(),
                 },
             ],
         };
@@ -351,6 +505,15 @@ impl super::CustomDerive for CustomResourceDefinition {
             writer: vec![],
         };
 
+        k8s_openapi_codegen_common::generate_api_resources(
+            &spec.definitions,
+            &spec.operations,
+            &MapNamespace,
+            &mut run_state.writer,
+        )
+        .map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {err}"))
+        .spanning(&tokens)?;
+
         let _ =
             k8s_openapi_codegen_common::run(
                 &spec.definitions,
@@ -359,6 +522,7 @@ impl super::CustomDerive for CustomResourceDefinition {
                 &MapNamespace,
                 &vis,
                 if generate_schema { k8s_openapi_codegen_common::GenerateSchema::Yes { feature: None } } else { k8s_openapi_codegen_common::GenerateSchema::No },
+                k8s_openapi_codegen_common::PreserveUnknownFields::No,
                 &mut run_state,
             )
             .map_err(|err| format!("#[derive(CustomResourceDefinition)] failed: {err}"))
@@ -53,6 +53,10 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 ///     namespaced,
 ///     has_subresources = "v1",
 ///     impl_deep_merge,
+///     impl_normalize,
+///     impl_diff,
+///     impl_field_paths,
+///     impl_defaulting,
 /// )]
 /// struct FooBarSpec {
 ///     prop1: String,
@@ -86,6 +90,8 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 ///
 /// impl k8s_openapi::ListableResource for FooBar { ... }
 ///
+/// impl k8s_openapi::DiscoverableResource for FooBar { ... }
+///
 /// impl k8s_openapi::Metadata for FooBar {
 ///     type Ty = k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 ///
@@ -104,6 +110,7 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 /// The `group` and `version` meta items of the `#[custom_resource_definition]` attribute of the macro are used to set
 /// the "group" and "API version" in the `k8s_openapi::Resource` impl respectively. The "kind" is automatically set to be the same as the resource type name,
 /// ie `"FooBar"` in this example. The `plural` meta item is used to construct the URLs of API operations for this custom resource.
+/// The `k8s_openapi::DiscoverableResource` impl describes the resource as supporting all verbs and having a `status` subresource.
 ///
 /// The `generate_schema` meta item is optional. If set, the generated custom resource type will have an impl of `schemars::JsonSchema` from the `schemars` crate.
 /// The `schemars` feature of the `k8s-openapi` crate must be enabled so that the types in that crate also have their `schemars::JsonSchema` impls enabled.
@@ -116,6 +123,20 @@ impl<T, E> ResultExt<T> for Result<T, E> where E: std::fmt::Display {
 /// The `impl_deep_merge` meta item is optional. If set, the generated custom resource type will impl the `k8s_openapi::DeepMerge` trait. This impl will require
 /// you to impl `k8s_openapi::DeepMerge` on the spec type yourself.
 ///
+/// The `impl_normalize` meta item is optional. If set, the generated custom resource type will impl the `k8s_openapi::Normalize` trait. This impl will require
+/// you to impl `k8s_openapi::Normalize` on the spec type yourself.
+///
+/// The `impl_diff` meta item is optional. If set, the generated custom resource type will impl the `k8s_openapi::diff::Diff` trait. This impl will require
+/// you to impl `k8s_openapi::diff::Diff` on the spec type yourself.
+///
+/// The `impl_field_paths` meta item is optional. If set, the generated custom resource type will have a `fields()` associated function
+/// that returns its `k8s_openapi::FieldPath`, and a `FooBarFields` trait with the methods that return the paths of its fields, like
+/// `FooBar::fields().spec()`. Methods for the fields of the spec type can be added the same way, by implementing a trait for
+/// `k8s_openapi::FieldPath<FooBarSpec>` using `k8s_openapi::FieldPath::child`.
+///
+/// The `impl_defaulting` meta item is optional. If set, the generated custom resource type will impl the `k8s_openapi::Defaulting` trait. This impl will require
+/// you to impl `k8s_openapi::Defaulting` on the spec type yourself.
+///
 /// You would then register this custom resource definition with Kubernetes, with code like this:
 ///
 /// ```rust,ignore
@@ -18,6 +18,9 @@
     plural = "foobars",
     namespaced,
     impl_deep_merge,
+    impl_normalize,
+    impl_diff,
+    impl_defaulting,
 )]
 #[custom_resource_definition(has_subresources = "v1")]
 struct FooBarSpec {
@@ -45,3 +48,23 @@ impl k8s_openapi::DeepMerge for FooBarSpec {
         self.prop3.merge_from(other.prop3);
     }
 }
+
+impl k8s_openapi::Normalize for FooBarSpec {
+    fn normalize(&mut self) {
+        k8s_openapi::normalize_strategies::list::atomic(&mut self.prop2, k8s_openapi::Normalize::normalize);
+    }
+}
+
+impl k8s_openapi::Defaulting for FooBarSpec {
+    fn set_defaults(&mut self) {
+        if self.prop3.is_none() {
+            self.prop3 = Some(1);
+        }
+    }
+}
+
+impl k8s_openapi::diff::Diff for FooBarSpec {
+    fn diff(&self, other: &Self, path: &k8s_openapi::diff::Path<'_>, changes: &mut Vec<k8s_openapi::diff::Change>) {
+        k8s_openapi::diff::Diff::diff(&self.prop1, &other.prop1, &k8s_openapi::diff::Path::Field { parent: path, name: "prop1" }, changes);
+    }
+}
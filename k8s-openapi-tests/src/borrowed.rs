@@ -0,0 +1,89 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn list() {
+    use k8s_openapi::borrowed::api::core::v1 as api;
+
+    let buf = br#"{
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": { "resourceVersion": "123" },
+        "items": [
+            {
+                "apiVersion": "v1",
+                "kind": "Pod",
+                "metadata": {
+                    "name": "foo",
+                    "namespace": "default",
+                    "labels": { "app": "foo" },
+                    "annotations": { "note": "line 1\nline 2" }
+                },
+                "spec": {
+                    "containers": [
+                        {
+                            "name": "app",
+                            "image": "foo:1.0",
+                            "resources": { "limits": { "cpu": "500m" } }
+                        }
+                    ]
+                },
+                "status": {
+                    "phase": "Running",
+                    "startTime": "2020-03-05T12:34:56Z"
+                }
+            }
+        ]
+    }"#;
+
+    let list: k8s_openapi::List<api::Pod<'_>> = serde_json::from_slice(buf).unwrap();
+    assert_eq!(list.metadata.resource_version.as_deref(), Some("123"));
+    assert_eq!(list.items.len(), 1);
+
+    let pod = &list.items[0];
+    assert!(matches!(pod.metadata.name, Some(k8s_openapi::BorrowedStr(std::borrow::Cow::Borrowed("foo")))), "{:?}", pod.metadata.name);
+    assert_eq!(pod.metadata.namespace.as_deref(), Some("default"));
+
+    // Maps can be looked up by `&str`
+    let labels = pod.metadata.labels.as_ref().unwrap();
+    assert!(matches!(labels["app"], k8s_openapi::BorrowedStr(std::borrow::Cow::Borrowed("foo"))), "{labels:?}");
+
+    // Strings with escape sequences cannot be borrowed.
+    let annotations = pod.metadata.annotations.as_ref().unwrap();
+    assert!(matches!(annotations["note"].0, std::borrow::Cow::Owned(_)), "{annotations:?}");
+    assert_eq!(annotations["note"], "line 1\nline 2");
+
+    let container = &pod.spec.as_ref().unwrap().containers[0];
+    assert_eq!(container.name, "app");
+    assert_eq!(container.image.as_deref(), Some("foo:1.0"));
+
+    // Types without borrowed variants are owned.
+    let limits = container.resources.as_ref().unwrap().limits.as_ref().unwrap();
+    assert_eq!(limits["cpu"], k8s_openapi::apimachinery::pkg::api::resource::Quantity("500m".to_owned()));
+
+    let status = pod.status.as_ref().unwrap();
+    assert_eq!(status.phase.as_deref(), Some("Running"));
+    assert_eq!(status.start_time, Some(k8s_openapi::apimachinery::pkg::apis::meta::v1::Time("2020-03-05T12:34:56Z".parse().unwrap())));
+
+    // The borrowed type has the same resource metadata as the owned type.
+    assert_eq!(k8s_openapi::api_version(pod), "v1");
+    assert_eq!(k8s_openapi::kind(pod), "Pod");
+    assert_eq!(<api::Pod<'_> as k8s_openapi::Resource>::URL_PATH_SEGMENT, "pods");
+    assert_eq!(<api::Pod<'_> as k8s_openapi::ListableResource>::LIST_KIND, "PodList");
+    assert_eq!(k8s_openapi::Metadata::metadata(pod).name.as_deref(), Some("foo"));
+
+    let err = serde_json::from_slice::<api::Pod<'_>>(br#"{ "apiVersion": "v1", "kind": "Service" }"#).unwrap_err();
+    assert!(err.to_string().contains("invalid value"), "{err}");
+}
+
+#[test]
+fn watch_event() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+    use k8s_openapi::borrowed::api::core::v1 as api;
+
+    let buf = br#"{ "type": "ADDED", "object": { "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "foo" }, "data": { "a": "b" } } }"#;
+
+    let event: meta::WatchEvent<api::ConfigMap<'_>> = serde_json::from_slice(buf).unwrap();
+    let meta::WatchEvent::Added(config_map) = event else { panic!("{event:?}"); };
+    assert_eq!(config_map.metadata.name.as_deref(), Some("foo"));
+    assert_eq!(config_map.data.unwrap()["a"], "b");
+}
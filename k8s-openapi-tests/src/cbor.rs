@@ -0,0 +1,203 @@
+use k8s_openapi::cbor;
+use k8s_openapi::serde_json;
+
+#[test]
+fn config_map() {
+    use k8s_openapi::api::core::v1 as api;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+    let config_map = api::ConfigMap {
+        metadata: meta::ObjectMeta {
+            name: Some("cm".to_owned()),
+            creation_timestamp: Some(meta::Time("2020-03-05T12:34:56Z".parse().unwrap())),
+            ..Default::default()
+        },
+        data: Some([("a".to_owned(), "b".to_owned())].into()),
+        binary_data: Some([("c".to_owned(), k8s_openapi::ByteString(vec![0x00, 0xff]))].into()),
+        ..Default::default()
+    };
+
+    // Keys are byte strings sorted by their encoding, so shorter keys come first.
+    let expected_encoded = [
+        &b"\xd9\xd9\xf7"[..], // self-described CBOR
+        b"\xa5", // map(5)
+        b"\x44data", b"\xa1\x41a\x41b",
+        b"\x44kind", b"\x49ConfigMap",
+        b"\x48metadata", b"\xa2",
+            b"\x44name", b"\x42cm",
+            b"\x51creationTimestamp", b"\x542020-03-05T12:34:56Z",
+        b"\x4aapiVersion", b"\x42v1",
+        b"\x4abinaryData", b"\xa1\x41c\xd6\x42\x00\xff", // tag 22 (expected base64 encoding)
+    ].concat();
+
+    let encoded = cbor::to_vec(&config_map).unwrap();
+    assert_eq!(encoded, expected_encoded);
+
+    let decoded: api::ConfigMap = cbor::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, config_map);
+}
+
+#[test]
+fn int_or_string_and_quantity() {
+    use k8s_openapi::api::core::v1 as api;
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    for (target_port, expected_encoded_target_port) in [
+        (IntOrString::Int(8080), &b"\x19\x1f\x90"[..]),
+        (IntOrString::String("http".to_owned()), b"\x44http"),
+    ] {
+        let port = api::ServicePort {
+            port: 80,
+            target_port: Some(target_port),
+            ..Default::default()
+        };
+
+        let expected_encoded = [
+            &b"\xd9\xd9\xf7"[..],
+            b"\xa2",
+            b"\x44port", b"\x18\x50",
+            b"\x4atargetPort", expected_encoded_target_port,
+        ].concat();
+
+        let encoded = cbor::to_vec(&port).unwrap();
+        assert_eq!(encoded, expected_encoded);
+
+        let decoded: api::ServicePort = cbor::from_slice(&encoded).unwrap();
+        assert_eq!(decoded, port);
+    }
+
+    let resources = api::ResourceRequirements {
+        limits: Some([("cpu".to_owned(), Quantity("500m".to_owned()))].into()),
+        ..Default::default()
+    };
+
+    let expected_encoded = [
+        &b"\xd9\xd9\xf7"[..],
+        b"\xa1",
+        b"\x46limits", b"\xa1\x43cpu\x44500m",
+    ].concat();
+
+    let encoded = cbor::to_vec(&resources).unwrap();
+    assert_eq!(encoded, expected_encoded);
+
+    let decoded: api::ResourceRequirements = cbor::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, resources);
+
+    // Quantities may also be integers.
+    let decoded: api::ResourceRequirements = cbor::from_slice(b"\xa1\x46limits\xa1\x43cpu\x02").unwrap();
+    assert_eq!(decoded.limits.unwrap()["cpu"], Quantity("2".to_owned()));
+}
+
+#[test]
+fn micro_time() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+    let value = meta::MicroTime("2020-03-05T12:34:56.789012Z".parse().unwrap());
+
+    let expected_encoded = [&b"\xd9\xd9\xf7"[..], b"\x58\x1b2020-03-05T12:34:56.789012Z"].concat();
+
+    let encoded = cbor::to_vec(&value).unwrap();
+    assert_eq!(encoded, expected_encoded);
+
+    let decoded: meta::MicroTime = cbor::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, value);
+
+    // Tag 0 (standard date/time string) is accepted.
+    let decoded: meta::MicroTime = cbor::from_slice(b"\xc0\x78\x1b2020-03-05T12:34:56.789012Z").unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn raw_extension() {
+    use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+
+    let value = RawExtension(serde_json::json!({
+        "z": null,
+        "y": -1,
+        "x": 1.5,
+        "w": [true],
+    }));
+
+    // Floats use the shortest encoding that preserves their value.
+    let expected_encoded = [
+        &b"\xd9\xd9\xf7"[..],
+        b"\xa4",
+        b"\x41w", b"\x81\xf5",
+        b"\x41x", b"\xf9\x3e\x00",
+        b"\x41y", b"\x20",
+        b"\x41z", b"\xf6",
+    ].concat();
+
+    let encoded = cbor::to_vec(&value).unwrap();
+    assert_eq!(encoded, expected_encoded);
+
+    let decoded: RawExtension = cbor::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, value);
+
+    let err = cbor::to_vec(&f64::NAN).unwrap_err();
+    assert!(matches!(err, cbor::Error::NonFiniteFloat), "{err:?}");
+}
+
+#[test]
+fn decode() {
+    use k8s_openapi::api::core::v1 as api;
+
+    // Text strings, unsorted keys and a missing prefix are all accepted.
+    let decoded: api::ConfigMap = cbor::from_slice(b"\xa2\x64data\xa1\x61a\x61b\x6abinaryData\xa1\x61c\x64AP8=").unwrap();
+    assert_eq!(decoded, api::ConfigMap {
+        data: Some([("a".to_owned(), "b".to_owned())].into()),
+        binary_data: Some([("c".to_owned(), k8s_openapi::ByteString(vec![0x00, 0xff]))].into()),
+        ..Default::default()
+    });
+
+    let err = cbor::from_slice::<api::ConfigMap>(b"\xa1\x44data\xa2\x41a\x41b\x61a\x41c").unwrap_err();
+    assert!(matches!(err, cbor::Error::DuplicateMapKey), "{err:?}");
+
+    let err = cbor::from_slice::<api::ConfigMap>(b"\xa0\xa0").unwrap_err();
+    assert!(matches!(err, cbor::Error::TrailingData), "{err:?}");
+
+    let err = cbor::from_slice::<api::ConfigMap>(b"\xd8\x20\xa0").unwrap_err();
+    assert!(matches!(err, cbor::Error::UnsupportedTag(32)), "{err:?}");
+
+    let err = cbor::from_slice::<api::ConfigMap>(b"\xa1\x44data\x41a").unwrap_err();
+    assert!(matches!(err, cbor::Error::Deserialize(_)), "{err:?}");
+}
+
+#[test]
+fn byte_string() {
+    use k8s_openapi::api::core::v1 as api;
+
+    // Bytes that happen to be valid UTF-8 are still encoded and decoded as bytes.
+    let secret = api::Secret {
+        data: Some([("a".to_owned(), k8s_openapi::ByteString(b"abc".to_vec()))].into()),
+        ..Default::default()
+    };
+
+    let expected_encoded = [
+        &b"\xd9\xd9\xf7"[..],
+        b"\xa4",
+        b"\x44data", b"\xa1\x41a\xd6\x43abc",
+        b"\x44kind", b"\x46Secret",
+        b"\x48metadata", b"\xa0",
+        b"\x4aapiVersion", b"\x42v1",
+    ].concat();
+
+    let encoded = cbor::to_vec(&secret).unwrap();
+    assert_eq!(encoded, expected_encoded);
+
+    let decoded: api::Secret = cbor::from_slice(&encoded).unwrap();
+    assert_eq!(decoded, secret);
+
+    // An untagged byte string is also used as is, rather than being treated as a base64-encoded string.
+    let decoded: api::Secret = cbor::from_slice(b"\xa1\x44data\xa1\x41a\x43abc").unwrap();
+    assert_eq!(decoded.data.unwrap()["a"], k8s_openapi::ByteString(b"abc".to_vec()));
+
+    // A tagged byte string where a string is expected is treated as its base64 encoding, like in JSON.
+    let decoded: api::ConfigMap = cbor::from_slice(b"\xa1\x44data\xa1\x41a\xd6\x42\x00\xff").unwrap();
+    assert_eq!(decoded.data.unwrap()["a"], "AP8=");
+
+    // Other serializers use the base64-encoded string.
+    let value = serde_json::to_value(k8s_openapi::ByteString(b"abc".to_vec())).unwrap();
+    assert_eq!(value, serde_json::Value::String("YWJj".to_owned()));
+}
@@ -0,0 +1,141 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn pod() {
+    use k8s_openapi::api::core::v1 as api;
+    use k8s_openapi::compact::api::core::v1 as compact;
+
+    let buf = br#"{
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "namespace": "default",
+            "labels": { "app": "foo" },
+            "annotations": { "note": "bar" }
+        },
+        "spec": {
+            "containers": [
+                {
+                    "name": "app",
+                    "image": "foo:1.0",
+                    "resources": { "limits": { "cpu": "500m" } }
+                }
+            ]
+        },
+        "status": {
+            "phase": "Running",
+            "startTime": "2020-03-05T12:34:56Z"
+        }
+    }"#;
+
+    let pod: compact::Pod = serde_json::from_slice(buf).unwrap();
+    assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+    assert_eq!(pod.metadata.labels.as_ref().unwrap()["app"], "foo");
+
+    let container = &pod.spec.as_ref().unwrap().containers[0];
+    assert_eq!(container.name, "app");
+
+    // Types without compact variants are the owned types.
+    let limits = container.resources.as_ref().unwrap().limits.as_ref().unwrap();
+    assert_eq!(limits["cpu"], k8s_openapi::apimachinery::pkg::api::resource::Quantity("500m".to_owned()));
+
+    // The compact type has the same resource metadata as the owned type.
+    assert_eq!(k8s_openapi::api_version(&pod), "v1");
+    assert_eq!(k8s_openapi::kind(&pod), "Pod");
+    assert_eq!(<compact::Pod as k8s_openapi::Resource>::URL_PATH_SEGMENT, "pods");
+    assert_eq!(<compact::Pod as k8s_openapi::ListableResource>::LIST_KIND, "PodList");
+
+    // Converting between the owned and compact types is lossless.
+    let owned: api::Pod = serde_json::from_slice(buf).unwrap();
+    assert_eq!(compact::Pod::from(owned.clone()), pod);
+    assert_eq!(api::Pod::from(pod.clone()), owned);
+    assert_eq!(serde_json::to_value(&pod).unwrap(), serde_json::to_value(&owned).unwrap());
+
+    let list: k8s_openapi::List<compact::Pod> = serde_json::from_str(&format!(
+        r#"{{ "apiVersion": "v1", "kind": "PodList", "metadata": {{}}, "items": [{}, {}] }}"#,
+        std::str::from_utf8(buf).unwrap(),
+        std::str::from_utf8(buf).unwrap(),
+    )).unwrap();
+
+    // Map keys share a single allocation.
+    let (key1, _) = list.items[0].metadata.labels.as_ref().unwrap().first_key_value().unwrap();
+    let (key2, _) = list.items[1].metadata.labels.as_ref().unwrap().first_key_value().unwrap();
+    assert!(std::ptr::eq(key1.as_str(), key2.as_str()));
+    assert!(std::ptr::eq(key1.as_str(), k8s_openapi::InternedStr::new("app").as_str()));
+}
+
+#[test]
+fn secret() {
+    use k8s_openapi::compact::api::core::v1 as compact;
+
+    let secret: compact::Secret = serde_json::from_str(r#"{ "apiVersion": "v1", "kind": "Secret", "metadata": {}, "data": { "a": "Zm9v" } }"#).unwrap();
+    assert_eq!(secret.data.as_ref().unwrap()["a"], k8s_openapi::CompactByteString(b"foo"[..].into()));
+    assert_eq!(serde_json::to_value(&secret).unwrap()["data"], serde_json::json!({ "a": "Zm9v" }));
+
+    let owned = k8s_openapi::api::core::v1::Secret::from(secret);
+    assert_eq!(owned.data.unwrap()["a"], k8s_openapi::ByteString(b"foo".to_vec()));
+}
+
+#[test]
+fn strip_for_cache() {
+    use k8s_openapi::compact::api::core::v1 as compact;
+
+    let mut pod: compact::Pod = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "annotations": {
+                "kubectl.kubernetes.io/last-applied-configuration": "{}",
+                "note": "bar",
+            },
+            "managedFields": [
+                { "manager": "kubectl", "operation": "Apply", "fieldsType": "FieldsV1", "fieldsV1": { "f:metadata": {} } },
+            ],
+        },
+    })).unwrap();
+    pod.metadata.strip_for_cache();
+    assert_eq!(serde_json::to_value(&pod.metadata).unwrap(), serde_json::json!({
+        "name": "foo",
+        "annotations": { "note": "bar" },
+    }));
+
+    let mut pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "annotations": { "kubectl.kubernetes.io/last-applied-configuration": "{}" },
+        },
+    })).unwrap();
+    pod.metadata.strip_for_cache();
+    assert_eq!(pod.metadata.annotations, None);
+}
+
+#[test]
+fn interned_str() {
+    // Strings interned concurrently by different threads share the same allocation.
+    let keys: Vec<Vec<k8s_openapi::InternedStr>> = std::thread::scope(|scope| {
+        let threads: Vec<_> =
+            (0..8)
+            .map(|_| scope.spawn(|| (0..1000).map(|i| k8s_openapi::InternedStr::new(&format!("example.com/key-{i}"))).collect()))
+            .collect();
+        threads.into_iter().map(|thread| thread.join().unwrap()).collect()
+    });
+
+    for thread_keys in &keys[1..] {
+        for (key1, key2) in keys[0].iter().zip(thread_keys) {
+            assert_eq!(key1, key2);
+            assert!(std::ptr::eq(key1.as_str(), key2.as_str()));
+        }
+    }
+
+    // Strings that are no longer used are eventually dropped by the interner.
+    drop(keys);
+    let key = k8s_openapi::InternedStr::new("example.com/key-0");
+    for i in 0..10_000 {
+        _ = k8s_openapi::InternedStr::new(&format!("example.com/other-key-{i}"));
+    }
+    assert!(std::ptr::eq(key.as_str(), k8s_openapi::InternedStr::new("example.com/key-0").as_str()));
+}
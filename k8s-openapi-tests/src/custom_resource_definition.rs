@@ -32,6 +32,10 @@ async fn test() {
         namespaced,
         has_subresources = "v1",
         impl_deep_merge,
+        impl_normalize,
+        impl_diff,
+        impl_field_paths,
+        impl_defaulting,
     )]
     struct FooBarSpec {
         prop1: String,
@@ -50,6 +54,38 @@ async fn test() {
         }
     }
 
+    impl k8s_openapi::Normalize for FooBarSpec {
+        fn normalize(&mut self) {
+            k8s_openapi::normalize_strategies::list::atomic(&mut self.prop2, k8s_openapi::Normalize::normalize);
+            self.prop5.normalize();
+        }
+    }
+
+    impl k8s_openapi::Defaulting for FooBarSpec {
+        fn set_defaults(&mut self) {
+            if self.prop3.is_none() {
+                self.prop3 = Some(1);
+            }
+        }
+    }
+
+    impl k8s_openapi::diff::Diff for FooBarSpec {
+        fn diff(&self, other: &Self, path: &k8s_openapi::diff::Path<'_>, changes: &mut Vec<k8s_openapi::diff::Change>) {
+            self.prop1.diff(&other.prop1, &k8s_openapi::diff::Path::Field { parent: path, name: "prop1" }, changes);
+            self.prop5.diff(&other.prop5, &k8s_openapi::diff::Path::Field { parent: path, name: "prop5" }, changes);
+        }
+    }
+
+    trait FooBarSpecFields {
+        fn prop1(self) -> k8s_openapi::FieldPath<String>;
+    }
+
+    impl FooBarSpecFields for k8s_openapi::FieldPath<FooBarSpec> {
+        fn prop1(self) -> k8s_openapi::FieldPath<String> {
+            self.child("prop1")
+        }
+    }
+
     assert_eq!(<FooBar as k8s_openapi::Resource>::API_VERSION, "k8s-openapi-tests-custom-resource-definition.com/v1");
     assert_eq!(<FooBar as k8s_openapi::Resource>::GROUP, "k8s-openapi-tests-custom-resource-definition.com");
     assert_eq!(<FooBar as k8s_openapi::Resource>::KIND, "FooBar");
@@ -57,6 +93,14 @@ async fn test() {
 
     assert_eq!(<FooBar as k8s_openapi::ListableResource>::LIST_KIND, "FooBarList");
 
+    assert_eq!(<FooBar as k8s_openapi::DiscoverableResource>::API_RESOURCE.plural, "foobars");
+    assert_eq!(<FooBar as k8s_openapi::DiscoverableResource>::API_RESOURCE.scope, k8s_openapi::ApiResourceScope::Namespace);
+    assert_eq!(<FooBar as k8s_openapi::DiscoverableResource>::API_RESOURCE.verbs.len(), 8);
+    assert_eq!(<FooBar as k8s_openapi::DiscoverableResource>::API_RESOURCE.subresources, ["status"]);
+
+    assert_eq!(FooBar::fields().metadata().name().to_dotted(), "metadata.name");
+    assert_eq!(FooBar::fields().spec().prop1().to_dotted(), "spec.prop1");
+
     {
         let fb: FooBar =
             serde_json::from_str(r#"{ "metadata": {}, "spec": { "prop1": "foo", "prop2": [true, false], "prop3": 5, "prop4": 6, "prop5": 7 } }"#).unwrap();
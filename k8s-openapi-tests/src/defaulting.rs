@@ -0,0 +1,222 @@
+use k8s_openapi::Defaulting;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::rbac::v1::RoleBinding;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+#[test]
+fn pod() {
+    let mut pod: Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "pod" },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "image": "registry.example.com:5000/app",
+                "ports": [{ "containerPort": 8080 }],
+                "readinessProbe": { "httpGet": { "port": 8080 } },
+                "resources": { "limits": { "cpu": "1", "memory": "1Gi" }, "requests": { "cpu": "500m" } },
+            }],
+            "initContainers": [{
+                "name": "init",
+                "image": "busybox:1.36",
+                "imagePullPolicy": "Always",
+            }],
+            "hostNetwork": true,
+            "restartPolicy": "Never",
+        },
+    })).expect("couldn't deserialize Pod");
+    pod.set_defaults();
+
+    let spec = pod.spec.as_ref().unwrap();
+    assert_eq!(spec.dns_policy.as_deref(), Some("ClusterFirst"));
+    assert_eq!(spec.enable_service_links, Some(true));
+    assert_eq!(spec.restart_policy.as_deref(), Some("Never"));
+    assert_eq!(spec.scheduler_name.as_deref(), Some("default-scheduler"));
+    assert_eq!(spec.security_context, Some(Default::default()));
+    assert_eq!(spec.termination_grace_period_seconds, Some(30));
+
+    let container = &spec.containers[0];
+    assert_eq!(container.image_pull_policy.as_deref(), Some("Always"));
+    assert_eq!(container.termination_message_path.as_deref(), Some("/dev/termination-log"));
+    assert_eq!(container.termination_message_policy.as_deref(), Some("File"));
+
+    let port = &container.ports.as_ref().unwrap()[0];
+    assert_eq!(port.host_port, Some(8080));
+    assert_eq!(port.protocol.as_deref(), Some("TCP"));
+
+    let probe = container.readiness_probe.as_ref().unwrap();
+    assert_eq!((probe.failure_threshold, probe.period_seconds, probe.success_threshold, probe.timeout_seconds), (Some(3), Some(10), Some(1), Some(1)));
+    let http_get = probe.http_get.as_ref().unwrap();
+    assert_eq!(http_get.path.as_deref(), Some("/"));
+    assert_eq!(http_get.scheme.as_deref(), Some("HTTP"));
+
+    let requests = container.resources.as_ref().unwrap().requests.as_ref().unwrap();
+    assert_eq!(requests["cpu"].0, "500m");
+    assert_eq!(requests["memory"].0, "1Gi");
+
+    let init_container = &spec.init_containers.as_ref().unwrap()[0];
+    assert_eq!(init_container.image_pull_policy.as_deref(), Some("Always"));
+    assert_eq!(init_container.termination_message_policy.as_deref(), Some("File"));
+
+    // Setting the defaults again changes nothing.
+    let mut pod2 = pod.clone();
+    pod2.set_defaults();
+    assert_eq!(pod, pod2);
+}
+
+#[test]
+fn image_pull_policy() {
+    use k8s_openapi::defaulting_strategies::core_v1::image_pull_policy;
+
+    for (image, expected) in [
+        ("", "IfNotPresent"),
+        ("nginx", "Always"),
+        ("nginx:latest", "Always"),
+        ("nginx:1.25", "IfNotPresent"),
+        ("registry.example.com:5000/nginx", "Always"),
+        ("registry.example.com:5000/nginx:1.25", "IfNotPresent"),
+        ("nginx@sha256:0000000000000000000000000000000000000000000000000000000000000000", "IfNotPresent"),
+        ("nginx:1.25@sha256:0000000000000000000000000000000000000000000000000000000000000000", "IfNotPresent"),
+        ("nginx:latest@sha256:0000000000000000000000000000000000000000000000000000000000000000", "Always"),
+        ("registry.example.com:5000/nginx:latest@sha256:0000000000000000000000000000000000000000000000000000000000000000", "Always"),
+    ] {
+        assert_eq!(image_pull_policy(image), expected, "{image}");
+    }
+}
+
+#[test]
+fn deployment() {
+    let mut deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "deployment" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "app" } },
+            "template": {
+                "metadata": { "labels": { "app": "app" } },
+                "spec": { "containers": [{ "name": "app", "image": "app:1.0" }] },
+            },
+        },
+    })).expect("couldn't deserialize Deployment");
+    deployment.set_defaults();
+
+    let spec = deployment.spec.as_ref().unwrap();
+    assert_eq!(spec.progress_deadline_seconds, Some(600));
+    assert_eq!(spec.replicas, Some(1));
+    assert_eq!(spec.revision_history_limit, Some(10));
+
+    let strategy = spec.strategy.as_ref().unwrap();
+    assert_eq!(strategy.type_.as_deref(), Some("RollingUpdate"));
+    let rolling_update = strategy.rolling_update.as_ref().unwrap();
+    assert_eq!(rolling_update.max_surge, Some(IntOrString::String("25%".to_owned())));
+    assert_eq!(rolling_update.max_unavailable, Some(IntOrString::String("25%".to_owned())));
+
+    let container = &spec.template.spec.as_ref().unwrap().containers[0];
+    assert_eq!(container.image_pull_policy.as_deref(), Some("IfNotPresent"));
+
+    // A `Recreate` strategy doesn't get a `rollingUpdate`.
+    let mut deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "deployment" },
+        "spec": {
+            "selector": {},
+            "strategy": { "type": "Recreate" },
+            "template": {},
+        },
+    })).expect("couldn't deserialize Deployment");
+    deployment.set_defaults();
+    let strategy = deployment.spec.as_ref().unwrap().strategy.as_ref().unwrap();
+    assert_eq!(strategy.type_.as_deref(), Some("Recreate"));
+    assert_eq!(strategy.rolling_update, None);
+}
+
+#[test]
+fn service() {
+    let mut service: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "service" },
+        "spec": {
+            "type": "LoadBalancer",
+            "sessionAffinity": "ClientIP",
+            "ports": [{ "port": 80 }, { "port": 443, "targetPort": "https" }],
+        },
+    })).expect("couldn't deserialize Service");
+    service.set_defaults();
+
+    let spec = service.spec.as_ref().unwrap();
+    assert_eq!(spec.allocate_load_balancer_node_ports, Some(true));
+    assert_eq!(spec.external_traffic_policy.as_deref(), Some("Cluster"));
+    assert_eq!(spec.internal_traffic_policy.as_deref(), Some("Cluster"));
+    assert_eq!(spec.session_affinity_config.as_ref().unwrap().client_ip.as_ref().unwrap().timeout_seconds, Some(10800));
+
+    let ports = spec.ports.as_ref().unwrap();
+    assert_eq!(ports[0].protocol.as_deref(), Some("TCP"));
+    assert_eq!(ports[0].target_port, Some(IntOrString::Int(80)));
+    assert_eq!(ports[1].target_port, Some(IntOrString::String("https".to_owned())));
+
+    let mut service: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "service" },
+        "spec": {},
+    })).expect("couldn't deserialize Service");
+    service.set_defaults();
+
+    let spec = service.spec.as_ref().unwrap();
+    assert_eq!(spec.type_.as_deref(), Some("ClusterIP"));
+    assert_eq!(spec.session_affinity.as_deref(), Some("None"));
+    assert_eq!(spec.allocate_load_balancer_node_ports, None);
+    assert_eq!(spec.external_traffic_policy, None);
+    assert_eq!(spec.internal_traffic_policy.as_deref(), Some("Cluster"));
+}
+
+#[test]
+fn job() {
+    let mut job: Job = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "job" },
+        "spec": {
+            "template": {
+                "metadata": { "labels": { "app": "job" } },
+                "spec": { "containers": [{ "name": "job", "image": "job" }], "restartPolicy": "OnFailure" },
+            },
+        },
+    })).expect("couldn't deserialize Job");
+    job.set_defaults();
+
+    assert_eq!(job.metadata.labels.as_ref().unwrap()["app"], "job");
+
+    let spec = job.spec.as_ref().unwrap();
+    assert_eq!(spec.backoff_limit, Some(6));
+    assert_eq!(spec.completion_mode.as_deref(), Some("NonIndexed"));
+    assert_eq!(spec.completions, Some(1));
+    assert_eq!(spec.parallelism, Some(1));
+    assert_eq!(spec.pod_replacement_policy.as_deref(), Some("TerminatingOrFailed"));
+    assert_eq!(spec.suspend, Some(false));
+    assert_eq!(spec.template.spec.as_ref().unwrap().restart_policy.as_deref(), Some("OnFailure"));
+
+    // A job with only `parallelism` set doesn't get `completions`.
+    let mut job: Job = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "job" },
+        "spec": { "parallelism": 3, "backoffLimitPerIndex": 1, "template": {} },
+    })).expect("couldn't deserialize Job");
+    job.set_defaults();
+
+    let spec = job.spec.as_ref().unwrap();
+    assert_eq!(spec.backoff_limit, Some(i32::MAX));
+    assert_eq!(spec.completions, None);
+    assert_eq!(spec.parallelism, Some(3));
+}
+
+#[test]
+fn role_binding() {
+    let mut role_binding: RoleBinding = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "role-binding" },
+        "roleRef": { "kind": "Role", "name": "role" },
+        "subjects": [
+            { "kind": "ServiceAccount", "name": "default", "namespace": "default" },
+            { "kind": "User", "name": "user" },
+        ],
+    })).expect("couldn't deserialize RoleBinding");
+    role_binding.set_defaults();
+
+    assert_eq!(role_binding.role_ref.api_group, "rbac.authorization.k8s.io");
+
+    let subjects = role_binding.subjects.as_ref().unwrap();
+    assert_eq!(subjects[0].api_group, None);
+    assert_eq!(subjects[1].api_group.as_deref(), Some("rbac.authorization.k8s.io"));
+}
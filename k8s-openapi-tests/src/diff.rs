@@ -0,0 +1,151 @@
+use k8s_openapi::diff::{Change, ChangeKind};
+
+fn deployment(image: &str, replicas: i32, other_containers: Vec<serde_json::Value>, labels: &serde_json::Value) -> k8s_openapi::api::apps::v1::Deployment {
+    let mut containers = vec![serde_json::json!({ "name": "app", "image": image })];
+    containers.extend(other_containers);
+
+    serde_json::from_value(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": {
+            "name": "app",
+            "labels": labels,
+        },
+        "spec": {
+            "replicas": replicas,
+            "selector": { "matchLabels": { "app": "app" } },
+            "template": {
+                "spec": {
+                    "containers": containers,
+                },
+            },
+        },
+    })).expect("couldn't deserialize Deployment")
+}
+
+#[test]
+fn no_changes() {
+    let old = deployment("app:1", 1, vec![], &serde_json::json!({ "app": "app" }));
+    assert_eq!(k8s_openapi::diff::diff(&old, &old.clone()), []);
+    assert_eq!(k8s_openapi::diff::Unified { changes: &[], old_label: "live", new_label: "desired" }.to_string(), "");
+}
+
+#[test]
+fn changes() {
+    let old = deployment(
+        "app:1",
+        1,
+        vec![
+            serde_json::json!({ "name": "sidecar", "image": "sidecar:1", "ports": [{ "containerPort": 80, "protocol": "TCP" }] }),
+            serde_json::json!({ "name": "old", "image": "old:1" }),
+        ],
+        &serde_json::json!({ "app": "app", "app.kubernetes.io/version": "1" }),
+    );
+    let new = deployment(
+        "app:2",
+        3,
+        vec![
+            serde_json::json!({ "name": "new", "image": "new:1" }),
+            serde_json::json!({ "name": "sidecar", "image": "sidecar:1", "ports": [{ "containerPort": 80, "protocol": "TCP" }, { "containerPort": 443, "protocol": "TCP" }] }),
+        ],
+        &serde_json::json!({ "app": "app", "tier": "web" }),
+    );
+
+    let changes = k8s_openapi::diff::diff(&old, &new);
+    assert_eq!(changes, [
+        Change {
+            path: r#"metadata.labels["app.kubernetes.io/version"]"#.to_owned(),
+            kind: ChangeKind::Removed(serde_json::json!("1")),
+        },
+        Change {
+            path: "metadata.labels.tier".to_owned(),
+            kind: ChangeKind::Added(serde_json::json!("web")),
+        },
+        Change {
+            path: "spec.replicas".to_owned(),
+            kind: ChangeKind::Changed { old: serde_json::json!(1), new: serde_json::json!(3) },
+        },
+        Change {
+            path: "spec.template.spec.containers[name=app].image".to_owned(),
+            kind: ChangeKind::Changed { old: serde_json::json!("app:1"), new: serde_json::json!("app:2") },
+        },
+        Change {
+            path: "spec.template.spec.containers[name=sidecar].ports[containerPort=443]".to_owned(),
+            kind: ChangeKind::Added(serde_json::json!({ "containerPort": 443, "protocol": "TCP" })),
+        },
+        Change {
+            path: "spec.template.spec.containers[name=old]".to_owned(),
+            kind: ChangeKind::Removed(serde_json::json!({ "name": "old", "image": "old:1" })),
+        },
+        Change {
+            path: "spec.template.spec.containers[name=new]".to_owned(),
+            kind: ChangeKind::Added(serde_json::json!({ "name": "new", "image": "new:1" })),
+        },
+    ]);
+
+    assert_eq!(changes[2].to_string(), "spec.replicas: changed 1 to 3");
+
+    assert_eq!(
+        k8s_openapi::diff::Unified { changes: &changes[2..], old_label: "live", new_label: "desired" }.to_string(),
+        r#"--- live
++++ desired
+@@ spec.replicas @@
+-1
++3
+@@ spec.template.spec.containers[name=app].image @@
+-"app:1"
++"app:2"
+@@ spec.template.spec.containers[name=sidecar].ports[containerPort=443] @@
++{
++  "containerPort": 443,
++  "protocol": "TCP"
++}
+@@ spec.template.spec.containers[name=old] @@
+-{
+-  "image": "old:1",
+-  "name": "old"
+-}
+@@ spec.template.spec.containers[name=new] @@
++{
++  "image": "new:1",
++  "name": "new"
++}
+"#,
+    );
+}
+
+#[test]
+fn lists() {
+    let old = k8s_openapi::api::core::v1::Container {
+        name: "app".to_owned(),
+        args: Some(vec!["a".to_owned(), "b".to_owned()]),
+        ..Default::default()
+    };
+    let new = k8s_openapi::api::core::v1::Container {
+        name: "app".to_owned(),
+        args: Some(vec!["a".to_owned(), "c".to_owned(), "d".to_owned()]),
+        env: Some(vec![]),
+        ..Default::default()
+    };
+
+    // Atomic lists are diffed by index, and an empty list is the same as an absent one.
+    assert_eq!(k8s_openapi::diff::diff(&old, &new), [
+        Change { path: "args[1]".to_owned(), kind: ChangeKind::Changed { old: serde_json::json!("b"), new: serde_json::json!("c") } },
+        Change { path: "args[2]".to_owned(), kind: ChangeKind::Added(serde_json::json!("d")) },
+    ]);
+
+    let old = k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        finalizers: Some(vec!["a".to_owned(), "b".to_owned()]),
+        ..Default::default()
+    };
+    let new = k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        finalizers: Some(vec!["c".to_owned(), "a".to_owned()]),
+        ..Default::default()
+    };
+
+    // Sets ignore the order of their items.
+    assert_eq!(k8s_openapi::diff::diff(&old, &new), [
+        Change { path: "finalizers".to_owned(), kind: ChangeKind::Removed(serde_json::json!("b")) },
+        Change { path: "finalizers".to_owned(), kind: ChangeKind::Added(serde_json::json!("c")) },
+    ]);
+}
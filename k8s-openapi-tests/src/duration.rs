@@ -0,0 +1,144 @@
+use k8s_openapi::Duration;
+
+const NANOSECOND: i64 = 1;
+const MICROSECOND: i64 = 1_000 * NANOSECOND;
+const MILLISECOND: i64 = 1_000 * MICROSECOND;
+const SECOND: i64 = 1_000 * MILLISECOND;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+
+// Test cases from https://github.com/golang/go/blob/go1.24.0/src/time/time_test.go
+
+#[test]
+fn format() {
+    for (expected, nanos) in [
+        ("0s", 0),
+        ("1ns", NANOSECOND),
+        ("1.1\u{b5}s", 1100 * NANOSECOND),
+        ("2.2ms", 2200 * MICROSECOND),
+        ("3.3s", 3300 * MILLISECOND),
+        ("4m5s", 4 * MINUTE + 5 * SECOND),
+        ("4m5.001s", 4 * MINUTE + 5001 * MILLISECOND),
+        ("5h6m7.001s", 5 * HOUR + 6 * MINUTE + 7001 * MILLISECOND),
+        ("8m0.000000001s", 8 * MINUTE + NANOSECOND),
+        ("1h0m0s", HOUR),
+        ("2562047h47m16.854775807s", i64::MAX),
+        ("-2562047h47m16.854775808s", i64::MIN),
+    ] {
+        assert_eq!(Duration::from_nanos(nanos).to_string(), expected);
+    }
+
+    // Durations beyond golang's range are still formatted.
+    let duration = Duration(k8s_openapi::jiff::SignedDuration::new(i64::MAX, 999_999_999));
+    assert_eq!(duration.to_string(), "2562047788015215h30m7.999999999s");
+}
+
+#[test]
+fn parse() {
+    for (s, nanos) in [
+        // simple
+        ("0", 0),
+        ("5s", 5 * SECOND),
+        ("30s", 30 * SECOND),
+        ("1478s", 1478 * SECOND),
+        // sign
+        ("-5s", -5 * SECOND),
+        ("+5s", 5 * SECOND),
+        ("-0", 0),
+        ("+0", 0),
+        // decimal
+        ("5.0s", 5 * SECOND),
+        ("5.6s", 5 * SECOND + 600 * MILLISECOND),
+        ("5.s", 5 * SECOND),
+        (".5s", 500 * MILLISECOND),
+        ("1.0s", SECOND),
+        ("1.00s", SECOND),
+        ("1.004s", SECOND + 4 * MILLISECOND),
+        ("1.0040s", SECOND + 4 * MILLISECOND),
+        ("100.00100s", 100 * SECOND + MILLISECOND),
+        // different units
+        ("10ns", 10 * NANOSECOND),
+        ("11us", 11 * MICROSECOND),
+        ("12\u{b5}s", 12 * MICROSECOND),
+        ("12\u{3bc}s", 12 * MICROSECOND),
+        ("13ms", 13 * MILLISECOND),
+        ("14s", 14 * SECOND),
+        ("15m", 15 * MINUTE),
+        ("16h", 16 * HOUR),
+        // composite durations
+        ("3h30m", 3 * HOUR + 30 * MINUTE),
+        ("10.5s4m", 4 * MINUTE + 10 * SECOND + 500 * MILLISECOND),
+        ("-2m3.4s", -(2 * MINUTE + 3 * SECOND + 400 * MILLISECOND)),
+        ("1h2m3s4ms5us6ns", HOUR + 2 * MINUTE + 3 * SECOND + 4 * MILLISECOND + 5 * MICROSECOND + 6 * NANOSECOND),
+        ("39h9m14.425s", 39 * HOUR + 9 * MINUTE + 14 * SECOND + 425 * MILLISECOND),
+        // large value
+        ("52763797000ns", 52_763_797_000 * NANOSECOND),
+        // more than 9 digits after decimal point, see https://golang.org/issue/6617
+        ("0.3333333333333333333h", 20 * MINUTE),
+        // 9007199254740993 = 1<<53+1 cannot be stored precisely in a float64
+        ("9007199254740993ns", (1 << 53) + 1),
+        // largest duration that can be represented by int64 in nanoseconds
+        ("9223372036854775807ns", i64::MAX),
+        ("9223372036854775.807us", i64::MAX),
+        ("9223372036854ms775us807ns", i64::MAX),
+        ("-9223372036854775808ns", i64::MIN),
+        ("-9223372036854775.808us", i64::MIN),
+        // largest negative value
+        ("-2562047h47m16.854775808s", i64::MIN),
+        // huge string; issue 15011.
+        ("0.100000000000000000000h", 6 * MINUTE),
+        // This value tests the first overflow check in leadingFraction.
+        ("0.830103483285477580700h", 49 * MINUTE + 48 * SECOND + 372_539_827 * NANOSECOND),
+    ] {
+        let duration: Duration = s.parse().unwrap_or_else(|err| panic!("couldn't parse {s:?}: {err}"));
+        assert_eq!(duration, Duration::from_nanos(nanos), "{s:?}");
+    }
+
+    for (s, expected_err) in [
+        ("", r#"time: invalid duration """#),
+        ("3", r#"time: missing unit in duration "3""#),
+        ("-", r#"time: invalid duration "-""#),
+        ("s", r#"time: invalid duration "s""#),
+        (".", r#"time: invalid duration ".""#),
+        ("-.", r#"time: invalid duration "-.""#),
+        (".s", r#"time: invalid duration ".s""#),
+        ("+.s", r#"time: invalid duration "+.s""#),
+        ("1d", r#"time: unknown unit "d" in duration "1d""#),
+        ("9223372036854775810ns", r#"time: invalid duration "9223372036854775810ns""#),
+        ("9223372036854775808ns", r#"time: invalid duration "9223372036854775808ns""#),
+        ("-9223372036854775809ns", r#"time: invalid duration "-9223372036854775809ns""#),
+        ("9223372036854776us", r#"time: invalid duration "9223372036854776us""#),
+        ("3000000h", r#"time: invalid duration "3000000h""#),
+        ("9223372036854775.808us", r#"time: invalid duration "9223372036854775.808us""#),
+        ("9223372036854ms775us808ns", r#"time: invalid duration "9223372036854ms775us808ns""#),
+    ] {
+        let err = s.parse::<Duration>().expect_err(s);
+        assert_eq!(err.to_string(), expected_err);
+    }
+}
+
+#[test]
+fn conversions() {
+    let duration = Duration::from_nanos(90 * MINUTE + 500 * MILLISECOND);
+
+    let signed: k8s_openapi::jiff::SignedDuration = duration.into();
+    assert_eq!(signed, k8s_openapi::jiff::SignedDuration::new(5400, 500_000_000));
+    assert_eq!(Duration::from(signed), duration);
+
+    let std_duration: std::time::Duration = duration.try_into().unwrap();
+    assert_eq!(std_duration, std::time::Duration::new(5400, 500_000_000));
+    assert_eq!(Duration::try_from(std_duration).unwrap(), duration);
+
+    std::time::Duration::try_from(Duration::from_nanos(-1)).unwrap_err();
+    Duration::try_from(std::time::Duration::MAX).unwrap_err();
+}
+
+#[test]
+fn serde() {
+    let duration: Duration = k8s_openapi::serde_json::from_str(r#""1h30m0.5s""#).unwrap();
+    assert_eq!(duration, Duration::from_nanos(90 * MINUTE + 500 * MILLISECOND));
+    assert_eq!(k8s_openapi::serde_json::to_string(&duration).unwrap(), r#""1h30m0.5s""#);
+
+    let err = k8s_openapi::serde_json::from_str::<Duration>(r#""1d""#).unwrap_err();
+    assert_eq!(err.to_string(), r#"time: unknown unit "d" in duration "1d" at line 1 column 4"#);
+}
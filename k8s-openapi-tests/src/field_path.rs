@@ -0,0 +1,44 @@
+use k8s_openapi::api::core::v1::Pod;
+
+#[test]
+fn dotted() {
+    assert_eq!(Pod::fields().to_dotted(), "");
+    assert_eq!(Pod::fields().spec().node_name().to_dotted(), "spec.nodeName");
+    assert_eq!(Pod::fields().status().pod_ip().to_string(), "status.podIP");
+    assert_eq!(Pod::fields().spec().containers().at(1).security_context().run_as_user().to_dotted(), "spec.containers[1].securityContext.runAsUser");
+    assert_eq!(Pod::fields().metadata().labels().key("app").to_dotted(), "metadata.labels.app");
+    assert_eq!(Pod::fields().metadata().annotations().key("app.kubernetes.io/name").to_dotted(), "metadata.annotations['app.kubernetes.io/name']");
+    assert_eq!(
+        k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps::fields().not().ref_path().to_dotted(),
+        "not.$ref",
+    );
+    assert_eq!(
+        k8s_openapi::List::<Pod>::fields().items().at(0).metadata().name().to_dotted(),
+        "items[0].metadata.name",
+    );
+}
+
+#[test]
+fn json_pointer() {
+    assert_eq!(Pod::fields().to_json_pointer(), "");
+    assert_eq!(Pod::fields().spec().node_name().to_json_pointer(), "/spec/nodeName");
+    assert_eq!(Pod::fields().spec().containers().at(0).image().to_json_pointer(), "/spec/containers/0/image");
+    assert_eq!(Pod::fields().metadata().annotations().key("app.kubernetes.io/name~1").to_json_pointer(), "/metadata/annotations/app.kubernetes.io~1name~01");
+}
+
+#[test]
+fn jsonpath() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "a", "labels": { "app.kubernetes.io/name": "web" } },
+        "spec": { "containers": [{ "name": "app", "image": "app:1" }], "nodeName": "node-1" },
+    })).expect("couldn't deserialize Pod");
+
+    for (path, expected) in [
+        (Pod::fields().spec().node_name().to_dotted(), "node-1"),
+        (Pod::fields().spec().containers().at(0).image().to_dotted(), "app:1"),
+        (Pod::fields().metadata().labels().key("app.kubernetes.io/name").to_dotted(), "web"),
+    ] {
+        let template: k8s_openapi::jsonpath::JsonPath = format!("{{.{path}}}").parse().expect("couldn't parse template");
+        assert_eq!(template.execute(&pod).expect("couldn't execute template"), expected);
+    }
+}
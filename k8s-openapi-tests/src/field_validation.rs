@@ -0,0 +1,112 @@
+use k8s_openapi::field_validation::{FieldValidation, InvalidField, InvalidFieldKind};
+
+const POD: &str = r#"{
+    "apiVersion": "v1",
+    "kind": "Pod",
+    "metadata": {
+        "name": "foo",
+        "labels": {
+            "app": "foo",
+            "app": "bar"
+        },
+        "name": "bar"
+    },
+    "spec": {
+        "containers": [{
+            "name": "app",
+            "image": "alpine"
+        }, {
+            "name": "sidecar",
+            "imagePullPolice": "Always"
+        }],
+        "hostNetwork": true,
+        "hostnetwork": true
+    },
+    "extra": {
+        "a": 1
+    }
+}"#;
+
+#[test]
+fn ignore() {
+    let (pod, invalid_fields): (k8s_openapi::api::core::v1::Pod, _) =
+        k8s_openapi::field_validation::from_slice(POD.as_bytes(), FieldValidation::Ignore).expect("couldn't deserialize Pod");
+    assert_eq!(pod.metadata.name.as_deref(), Some("bar"));
+    assert_eq!(invalid_fields, []);
+}
+
+#[test]
+fn warn() {
+    let (pod, invalid_fields): (k8s_openapi::api::core::v1::Pod, _) =
+        k8s_openapi::field_validation::from_slice(POD.as_bytes(), FieldValidation::Warn).expect("couldn't deserialize Pod");
+    assert_eq!(pod.metadata.name.as_deref(), Some("bar"));
+    assert_eq!(invalid_fields, [
+        InvalidField { kind: InvalidFieldKind::Duplicate, path: "metadata.labels.app".to_owned() },
+        InvalidField { kind: InvalidFieldKind::Duplicate, path: "metadata.name".to_owned() },
+        InvalidField { kind: InvalidFieldKind::Unknown, path: "spec.containers[1].imagePullPolice".to_owned() },
+        InvalidField { kind: InvalidFieldKind::Unknown, path: "spec.hostnetwork".to_owned() },
+        InvalidField { kind: InvalidFieldKind::Unknown, path: "extra".to_owned() },
+    ]);
+}
+
+#[test]
+fn strict() {
+    let err =
+        k8s_openapi::field_validation::from_slice::<k8s_openapi::api::core::v1::Pod>(POD.as_bytes(), FieldValidation::Strict)
+        .expect_err("expected Pod to fail to deserialize");
+    assert_eq!(
+        err.to_string(),
+        "strict decoding error: \
+            duplicate field \"metadata.labels.app\", \
+            duplicate field \"metadata.name\", \
+            unknown field \"spec.containers[1].imagePullPolice\", \
+            unknown field \"spec.hostnetwork\", \
+            unknown field \"extra\"",
+    );
+
+    let (pod, invalid_fields): (k8s_openapi::api::core::v1::Pod, _) =
+        k8s_openapi::field_validation::from_slice(br#"{"apiVersion":"v1","kind":"Pod","metadata":{"name":"foo"}}"#, FieldValidation::Strict)
+        .expect("couldn't deserialize Pod");
+    assert_eq!(pod.metadata.name.as_deref(), Some("foo"));
+    assert_eq!(invalid_fields, []);
+}
+
+#[test]
+fn yaml() {
+    let manifest = "\
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: foo
+data:
+  a: b
+date: c
+---
+apiVersion: v1
+kind: List
+items:
+- apiVersion: v1
+  kind: Secret
+  metadata:
+    name: bar
+    namespce: baz
+- apiVersion: example.com/v1
+  kind: FooBar
+  metadata:
+    name: qux
+  spec:
+    anything: goes
+";
+
+    let resources = k8s_openapi::yaml::from_str_with_field_validation(manifest, FieldValidation::Warn).expect("couldn't deserialize manifest");
+    let invalid_fields: Vec<_> = resources.iter().map(|(resource, invalid_fields)| (resource.kind(), &**invalid_fields)).collect();
+    assert_eq!(invalid_fields, [
+        ("ConfigMap", &[InvalidField { kind: InvalidFieldKind::Unknown, path: "date".to_owned() }][..]),
+        ("Secret", &[InvalidField { kind: InvalidFieldKind::Unknown, path: "metadata.namespce".to_owned() }][..]),
+        ("FooBar", &[][..]),
+    ]);
+
+    let err = k8s_openapi::yaml::from_str_with_field_validation(manifest, FieldValidation::Strict).expect_err("expected manifest to fail to deserialize");
+    assert_eq!(err.document_index, 0);
+    assert!(err.to_string().contains(r#"strict decoding error: unknown field "date""#), "{err}");
+}
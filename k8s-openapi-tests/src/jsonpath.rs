@@ -0,0 +1,85 @@
+use k8s_openapi::jsonpath::JsonPath;
+
+fn pods() -> k8s_openapi::List<k8s_openapi::api::core::v1::Pod> {
+    serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": {},
+        "items": [
+            {
+                "metadata": { "name": "a", "labels": { "app.kubernetes.io/name": "web" } },
+                "spec": { "containers": [{ "name": "app", "image": "app:1" }, { "name": "sidecar", "image": "sidecar:1" }] },
+                "status": { "phase": "Running", "containerStatuses": [{ "name": "app", "restartCount": 3, "image": "app:1", "imageID": "", "ready": true }] },
+            },
+            {
+                "metadata": { "name": "b" },
+                "spec": { "containers": [{ "name": "app", "image": "app:2" }] },
+                "status": { "phase": "Pending" },
+            },
+            {
+                "metadata": { "name": "c" },
+                "spec": { "containers": [{ "name": "app", "image": "app:1" }] },
+                "status": { "phase": "Running" },
+            },
+        ],
+    })).expect("couldn't deserialize PodList")
+}
+
+fn execute(template: &str) -> String {
+    template.parse::<JsonPath>().expect("couldn't parse template").allow_missing_keys(true).execute(&pods()).expect("couldn't execute template")
+}
+
+#[test]
+fn paths() {
+    assert_eq!(execute("{.kind}"), "PodList");
+    assert_eq!(execute("{.items[*].metadata.name}"), "a b c");
+    assert_eq!(execute("{$.items[0].metadata.name}"), "a");
+    assert_eq!(execute("{.items[-1].metadata.name}"), "c");
+    assert_eq!(execute("{.items[0,2].metadata.name}"), "a c");
+    assert_eq!(execute("{.items[1:].metadata.name}"), "b c");
+    assert_eq!(execute("{.items[::2].metadata.name}"), "a c");
+    assert_eq!(execute("{.items[0].metadata.labels.app\\.kubernetes\\.io/name}"), "web");
+    assert_eq!(execute("{.items[0].metadata.labels['app.kubernetes.io/name']}"), "web");
+    assert_eq!(execute("{.items[0].spec.containers[*]['name', 'image']}"), "app sidecar app:1 sidecar:1");
+    assert_eq!(execute("{..image}"), "app:1 sidecar:1 app:1 app:2 app:1");
+    assert_eq!(execute("{.items[0].status.containerStatuses[0].restartCount}"), "3");
+    assert_eq!(execute("{.items[0].status.containerStatuses[0].ready}"), "true");
+    assert_eq!(execute("{.items[1].spec.containers}"), r#"[{"image":"app:2","name":"app"}]"#);
+    assert_eq!(execute("{.items[5].metadata.name}{.items[0].metadata.namespace}"), "");
+}
+
+#[test]
+fn filters() {
+    assert_eq!(execute(r#"{.items[?(@.status.phase=="Running")].metadata.name}"#), "a c");
+    assert_eq!(execute("{.items[?(@.status.phase!='Running')].metadata.name}"), "b");
+    assert_eq!(execute("{.items[?(@.metadata.labels)].metadata.name}"), "a");
+    assert_eq!(execute("{.items[*].status.containerStatuses[?(@.restartCount > 2)].name}"), "app");
+    assert_eq!(execute("{.items[?(@.spec.containers[0].image == 'app:1')].metadata.name}"), "a c");
+}
+
+#[test]
+fn range() {
+    assert_eq!(
+        execute(r#"{range .items[*]}{.metadata.name}{"\t"}{range .spec.containers[*]}{.name}={.image} {end}{"\n"}{end}"#),
+        "a\tapp=app:1 sidecar=sidecar:1 \nb\tapp=app:2 \nc\tapp=app:1 \n",
+    );
+    assert_eq!(execute("names: {range .items[*]}[{@.metadata.name}]{end}"), "names: [a][b][c]");
+}
+
+#[test]
+fn errors() {
+    for template in ["{.items", "{end}", "{range .items[*]}", "{.items[?(@.a ~ 1)]}", "{.items[a]}"] {
+        let err = template.parse::<JsonPath>().expect_err(template);
+        assert!(matches!(err, k8s_openapi::jsonpath::Error::Parse(_)), "{template}: {err}");
+    }
+
+    let pods = pods();
+    for template in ["{.items[0].metadata.namespace}", "{.items[3]}", "{.kind[0]}", "{items}"] {
+        let err = template.parse::<JsonPath>().expect("couldn't parse template").execute(&pods).expect_err(template);
+        assert!(matches!(err, k8s_openapi::jsonpath::Error::Evaluate(_)), "{template}: {err}");
+    }
+
+    // Like kubectl, bare identifiers are parsed but fail to evaluate, even if missing keys are allowed.
+    let err = "{items}".parse::<JsonPath>().expect("couldn't parse template").allow_missing_keys(true).execute(&pods).expect_err("{items}");
+    assert_eq!(err.to_string(), "could not evaluate JSONPath template: unrecognized identifier items");
+}
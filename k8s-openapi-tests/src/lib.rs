@@ -498,24 +498,66 @@ fn deserialize_null_to_empty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D
 
 mod api_versions;
 
+mod borrowed;
+
+mod cbor;
+
+mod compact;
+
 mod clientset;
 
 mod custom_resource_definition;
 
+mod defaulting;
+
 mod deserialize_leniency;
 
 mod deployment;
 
+mod diff;
+
+mod duration;
+
+mod field_path;
+
+mod field_validation;
+
 mod job;
 
+mod jsonpath;
+
+mod normalize;
+
+mod partial_object_metadata;
+
 mod patch;
 
 mod pod;
 
+mod pod_security;
+
+mod protobuf;
+
 mod resource;
 
 mod special_idents;
 
+mod status;
+
+mod swagger20;
+
+mod table;
+
 mod time;
 
+mod union;
+
+mod unknown_fields;
+
+mod validation;
+
+mod visit;
+
 mod watch_event;
+
+mod yaml;
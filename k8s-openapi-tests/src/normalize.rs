@@ -0,0 +1,184 @@
+use k8s_openapi::Normalize;
+
+#[test]
+fn quantity() {
+    for (value, expected) in [
+        ("0", "0"),
+        ("0Mi", "0"),
+        ("1", "1"),
+        ("1000m", "1"),
+        ("-1000m", "-1"),
+        ("+1.5", "1500m"),
+        ("0.1", "100m"),
+        ("100m", "100m"),
+        ("1000", "1k"),
+        ("1500", "1500"),
+        ("1k", "1k"),
+        ("1000k", "1M"),
+        ("12E", "12E"),
+        ("1e3", "1e3"),
+        ("1000e0", "1e3"),
+        ("1E-3", "1e-3"),
+        ("1Ki", "1Ki"),
+        ("1024", "1024"),
+        ("1024Mi", "1Gi"),
+        ("1.5Gi", "1536Mi"),
+        ("0.5Gi", "512Mi"),
+        ("0.5Ki", "512"),
+        ("1.5Ki", "1536"),
+        ("0.0000000001", "1n"),
+        ("1.0000000001", "1000000001n"),
+        ("16Ei", "9223372036854775807"),
+
+        // Not quantities
+        ("", ""),
+        ("abc", "abc"),
+        ("1Zi", "1Zi"),
+        ("1e", "1e"),
+        (".", "."),
+    ] {
+        let mut quantity = k8s_openapi::apimachinery::pkg::api::resource::Quantity(value.to_owned());
+        quantity.normalize();
+        assert_eq!(quantity.0, expected, "{value}");
+    }
+}
+
+#[test]
+fn lists_and_maps() {
+    let mut pod = k8s_openapi::api::core::v1::Pod {
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            finalizers: Some(vec!["b".to_owned(), "a".to_owned(), "b".to_owned()]),
+            labels: Some(Default::default()),
+            ..Default::default()
+        },
+        spec: Some(k8s_openapi::api::core::v1::PodSpec {
+            containers: vec![
+                k8s_openapi::api::core::v1::Container {
+                    name: "sidecar".to_owned(),
+                    args: Some(vec!["b".to_owned(), "a".to_owned()]),
+                    ..Default::default()
+                },
+                k8s_openapi::api::core::v1::Container {
+                    name: "app".to_owned(),
+                    env: Some(vec![]),
+                    resources: Some(k8s_openapi::api::core::v1::ResourceRequirements {
+                        limits: Some([
+                            ("cpu".to_owned(), k8s_openapi::apimachinery::pkg::api::resource::Quantity("1000m".to_owned())),
+                        ].into()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    pod.normalize();
+
+    assert_eq!(pod, k8s_openapi::api::core::v1::Pod {
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            finalizers: Some(vec!["a".to_owned(), "b".to_owned()]),
+            ..Default::default()
+        },
+        spec: Some(k8s_openapi::api::core::v1::PodSpec {
+            // Map lists keep their order too, since it is significant for lists like `initContainers`.
+            containers: vec![
+                k8s_openapi::api::core::v1::Container {
+                    name: "sidecar".to_owned(),
+                    // Atomic lists keep their order.
+                    args: Some(vec!["b".to_owned(), "a".to_owned()]),
+                    ..Default::default()
+                },
+                k8s_openapi::api::core::v1::Container {
+                    name: "app".to_owned(),
+                    resources: Some(k8s_openapi::api::core::v1::ResourceRequirements {
+                        limits: Some([
+                            ("cpu".to_owned(), k8s_openapi::apimachinery::pkg::api::resource::Quantity("1".to_owned())),
+                        ].into()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn semantically_eq() {
+    let desired: k8s_openapi::api::apps::v1::Deployment = serde_json::from_value(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": {
+            "name": "app",
+            "namespace": "default",
+            "labels": { "app": "app" },
+        },
+        "spec": {
+            "selector": { "matchLabels": { "app": "app" } },
+            "template": {
+                "metadata": { "labels": { "app": "app" } },
+                "spec": {
+                    "containers": [{
+                        "name": "app",
+                        "image": "app:1",
+                        "resources": { "requests": { "cpu": "0.5", "memory": "1024Mi" } },
+                        "ports": [{ "containerPort": 80 }, { "containerPort": 8080 }],
+                    }],
+                    "volumes": [],
+                },
+            },
+        },
+    })).expect("couldn't deserialize Deployment");
+
+    let live: k8s_openapi::api::apps::v1::Deployment = serde_json::from_value(serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": {
+            "name": "app",
+            "namespace": "default",
+            "labels": { "app": "app" },
+            "uid": "5bb7ed5b-6e7d-4b42-9b2e-2b5b4c3c8c3d",
+            "resourceVersion": "12345",
+            "generation": 3,
+            "creationTimestamp": "2024-01-01T00:00:00Z",
+            "managedFields": [{ "manager": "kubectl", "operation": "Apply" }],
+        },
+        "spec": {
+            "selector": { "matchLabels": { "app": "app" } },
+            "template": {
+                "metadata": { "labels": { "app": "app" } },
+                "spec": {
+                    "containers": [{
+                        "name": "app",
+                        "image": "app:1",
+                        "resources": { "requests": { "cpu": "500m", "memory": "1Gi" } },
+                        "ports": [{ "containerPort": 80 }, { "containerPort": 8080 }],
+                    }],
+                },
+            },
+        },
+        "status": {
+            "replicas": 1,
+            "readyReplicas": 1,
+        },
+    })).expect("couldn't deserialize Deployment");
+
+    assert_ne!(desired, live);
+    assert!(desired.semantically_eq(&live));
+
+    let mut changed = desired.clone();
+    changed.spec.as_mut().unwrap().template.spec.as_mut().unwrap().containers[0].image = Some("app:2".to_owned());
+    assert!(!changed.semantically_eq(&live));
+
+    let mut reordered = desired.clone();
+    reordered.spec.as_mut().unwrap().template.spec.as_mut().unwrap().containers[0].ports.as_mut().unwrap().reverse();
+    assert!(!reordered.semantically_eq(&live));
+
+    let list = k8s_openapi::List { items: vec![desired], ..Default::default() };
+    let live_list = k8s_openapi::List { items: vec![live], ..Default::default() };
+    assert!(list.semantically_eq(&live_list));
+}
@@ -0,0 +1,108 @@
+use k8s_openapi::serde_json;
+
+#[test]
+fn list() {
+    use k8s_openapi::api::core::v1 as api;
+
+    assert_eq!(
+        k8s_openapi::PartialObjectMetadataList::<api::Pod>::ACCEPT,
+        "application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1",
+    );
+
+    let list: k8s_openapi::PartialObjectMetadataList<api::Pod> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "meta.k8s.io/v1",
+        "kind": "PartialObjectMetadataList",
+        "metadata": { "resourceVersion": "123" },
+        "items": [
+            {
+                "apiVersion": "meta.k8s.io/v1",
+                "kind": "PartialObjectMetadata",
+                "metadata": { "name": "foo", "namespace": "default", "labels": { "app": "foo" } },
+            },
+            {
+                "apiVersion": "meta.k8s.io/v1",
+                "kind": "PartialObjectMetadata",
+                "metadata": { "name": "bar", "namespace": "default" },
+            },
+        ],
+    })).unwrap();
+    assert_eq!(list.metadata.resource_version.as_deref(), Some("123"));
+    assert_eq!(
+        list.items.iter().map(|item| k8s_openapi::Metadata::metadata(item).name.as_deref().unwrap()).collect::<Vec<_>>(),
+        ["foo", "bar"],
+    );
+    assert_eq!(list.items[0].metadata.labels.as_ref().unwrap()["app"], "foo");
+
+    // The list type has the same scope as the resource type.
+    let _: std::marker::PhantomData<k8s_openapi::NamespaceResourceScope> =
+        std::marker::PhantomData::<<k8s_openapi::PartialObjectMetadataList<api::Pod> as k8s_openapi::Resource>::Scope>;
+
+    // The list kind of the resource type is also accepted.
+    let list: k8s_openapi::PartialObjectMetadataList<api::Pod> =
+        serde_json::from_str(r#"{ "apiVersion": "v1", "kind": "PodList", "metadata": {}, "items": [] }"#).unwrap();
+    assert!(list.items.is_empty());
+
+    let err =
+        serde_json::from_str::<k8s_openapi::PartialObjectMetadataList<api::Pod>>(r#"{ "apiVersion": "v1", "kind": "ServiceList", "items": [] }"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid value"), "{err}");
+}
+
+#[test]
+fn object() {
+    use k8s_openapi::api::apps::v1 as apps;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+    assert_eq!(
+        k8s_openapi::PartialObjectMetadata::<apps::Deployment>::ACCEPT,
+        "application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1",
+    );
+
+    let object: k8s_openapi::PartialObjectMetadata<apps::Deployment> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "meta.k8s.io/v1",
+        "kind": "PartialObjectMetadata",
+        "metadata": { "name": "foo", "namespace": "default" },
+    })).unwrap();
+    assert_eq!(object, k8s_openapi::PartialObjectMetadata::new(meta::ObjectMeta {
+        name: Some("foo".to_owned()),
+        namespace: Some("default".to_owned()),
+        ..Default::default()
+    }));
+
+    // The object has the type of the resource type, so that it can be used to apply the metadata of the resource.
+    assert_eq!(k8s_openapi::api_version(&object), "apps/v1");
+    assert_eq!(k8s_openapi::kind(&object), "Deployment");
+    assert_eq!(<k8s_openapi::PartialObjectMetadata<apps::Deployment> as k8s_openapi::Resource>::URL_PATH_SEGMENT, "deployments");
+    assert_eq!(<k8s_openapi::PartialObjectMetadata<apps::Deployment> as k8s_openapi::ListableResource>::LIST_KIND, "DeploymentList");
+    assert_eq!(serde_json::to_value(&object).unwrap(), serde_json::json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "foo", "namespace": "default" },
+    }));
+
+    let object: k8s_openapi::PartialObjectMetadata<apps::Deployment> =
+        serde_json::from_str(r#"{ "apiVersion": "apps/v1", "kind": "Deployment", "metadata": { "name": "foo" }, "spec": {} }"#).unwrap();
+    assert_eq!(object.metadata.name.as_deref(), Some("foo"));
+
+    let err =
+        serde_json::from_str::<k8s_openapi::PartialObjectMetadata<apps::Deployment>>(r#"{ "apiVersion": "v1", "kind": "Deployment" }"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid value"), "{err}");
+}
+
+#[test]
+fn watch_event() {
+    use k8s_openapi::api::core::v1 as api;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+
+    let event: meta::WatchEvent<k8s_openapi::PartialObjectMetadata<api::ConfigMap>> = serde_json::from_value(serde_json::json!({
+        "type": "MODIFIED",
+        "object": {
+            "apiVersion": "meta.k8s.io/v1",
+            "kind": "PartialObjectMetadata",
+            "metadata": { "name": "foo", "resourceVersion": "2" },
+        },
+    })).unwrap();
+    let meta::WatchEvent::Modified(object) = event else { panic!("{event:?}"); };
+    assert_eq!(object.metadata.resource_version.as_deref(), Some("2"));
+}
@@ -0,0 +1,187 @@
+use k8s_openapi::pod_security::{Level, Mode, ParsePolicyError, Policy, Version, evaluate};
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::batch::v1::CronJob;
+use k8s_openapi::api::core::v1::Pod;
+
+fn pod(spec: serde_json::Value) -> Pod {
+    Pod {
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta { name: Some("pod".to_owned()), ..Default::default() },
+        spec: Some(serde_json::from_value(spec).expect("couldn't deserialize PodSpec")),
+        ..Default::default()
+    }
+}
+
+fn details(object: &impl k8s_openapi::pod_security::ExtractPodSpec, policy: &str) -> Vec<String> {
+    match evaluate(object, policy.parse().unwrap()) {
+        Ok(()) => vec![],
+        Err(violations) => violations.results.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn policy() {
+    assert_eq!("restricted:v1.29".parse(), Ok(Policy { level: Level::Restricted, version: Version::V1(29) }));
+    assert_eq!("baseline".parse(), Ok(Policy { level: Level::Baseline, version: Version::Latest }));
+    assert_eq!("privileged:latest".parse::<Policy>().unwrap().to_string(), "privileged:latest");
+
+    assert_eq!("strict".parse::<Policy>(), Err(ParsePolicyError::InvalidLevel("strict".to_owned())));
+    for version in ["1.29", "v1.029", "v2.0", "v1.", "v1.x"] {
+        assert_eq!(version.parse::<Version>(), Err(ParsePolicyError::InvalidVersion(version.to_owned())), "{version}");
+    }
+
+    let labels = [
+        ("pod-security.kubernetes.io/enforce".to_owned(), "baseline".to_owned()),
+        ("pod-security.kubernetes.io/enforce-version".to_owned(), "v1.28".to_owned()),
+        ("pod-security.kubernetes.io/warn".to_owned(), "restricted".to_owned()),
+    ].into_iter().collect();
+    assert_eq!(Policy::from_namespace_labels(&labels, Mode::Enforce), Ok(Policy { level: Level::Baseline, version: Version::V1(28) }));
+    assert_eq!(Policy::from_namespace_labels(&labels, Mode::Warn), Ok(Policy { level: Level::Restricted, version: Version::Latest }));
+    assert_eq!(Policy::from_namespace_labels(&labels, Mode::Audit), Ok(Policy { level: Level::Privileged, version: Version::Latest }));
+}
+
+#[test]
+fn baseline() {
+    let pod = pod(serde_json::json!({
+        "hostNetwork": true,
+        "hostPID": true,
+        "containers": [
+            {
+                "name": "a",
+                "securityContext": { "privileged": true, "capabilities": { "add": ["NET_ADMIN", "CHOWN", "SYS_ADMIN"] } },
+                "ports": [{ "containerPort": 80, "hostPort": 80 }, { "containerPort": 8080, "hostPort": 8080 }],
+            },
+            { "name": "b", "securityContext": { "privileged": true, "seccompProfile": { "type": "Unconfined" } } },
+        ],
+        "securityContext": {
+            "seLinuxOptions": { "type": "spc_t", "user": "root" },
+            "sysctls": [{ "name": "kernel.msgmax", "value": "65536" }, { "name": "net.ipv4.tcp_syncookies", "value": "1" }],
+        },
+        "volumes": [{ "name": "host", "hostPath": { "path": "/" } }],
+    }));
+
+    assert_eq!(details(&pod, "privileged"), Vec::<String>::new());
+
+    assert_eq!(details(&pod, "baseline:latest"), [
+        r#"non-default capabilities (container "a" must not include "NET_ADMIN", "SYS_ADMIN" in securityContext.capabilities.add)"#,
+        "host namespaces (hostNetwork=true, hostPID=true)",
+        r#"hostPath volumes (volume "host")"#,
+        r#"hostPort (container "a" uses hostPorts 80, 8080)"#,
+        r#"privileged (containers "a", "b" must not set securityContext.privileged=true)"#,
+        r#"seLinuxOptions (pod set forbidden securityContext.seLinuxOptions: type "spc_t"; user may not be set; role may not be set)"#,
+        r#"seccompProfile (container "b" must not set securityContext.seccompProfile.type to "Unconfined")"#,
+        "forbidden sysctls (kernel.msgmax)",
+    ]);
+
+    // The message of the admission plugin.
+    let pod = self::pod(serde_json::json!({
+        "hostIPC": true,
+        "containers": [{ "name": "app", "securityContext": { "privileged": true } }],
+    }));
+    assert_eq!(
+        evaluate(&pod, "baseline:v1.30".parse().unwrap()).unwrap_err().to_string(),
+        r#"violates PodSecurity "baseline:v1.30": host namespaces (hostIPC=true), privileged (container "app" must not set securityContext.privileged=true)"#,
+    );
+}
+
+#[test]
+fn versions() {
+    // Sysctls allowed by newer versions of the policy.
+    let pod = pod(serde_json::json!({
+        "containers": [{ "name": "app" }],
+        "securityContext": { "sysctls": [{ "name": "net.ipv4.ip_local_reserved_ports", "value": "" }, { "name": "net.ipv4.tcp_rmem", "value": "" }] },
+    }));
+    assert_eq!(details(&pod, "baseline:v1.26"), ["forbidden sysctls (net.ipv4.ip_local_reserved_ports, net.ipv4.tcp_rmem)"]);
+    assert_eq!(details(&pod, "baseline:v1.31"), ["forbidden sysctls (net.ipv4.tcp_rmem)"]);
+    assert_eq!(details(&pod, "baseline:v1.32"), Vec::<String>::new());
+    assert_eq!(details(&pod, "baseline:v1.99"), Vec::<String>::new());
+
+    // Probe and lifecycle hosts are forbidden since v1.34.
+    let probes = self::pod(serde_json::json!({
+        "initContainers": [{ "name": "init", "startupProbe": { "tcpSocket": { "port": 80, "host": "" } } }],
+        "containers": [
+            { "name": "a", "livenessProbe": { "httpGet": { "port": 80, "host": "example.com" } } },
+            { "name": "b", "lifecycle": { "preStop": { "tcpSocket": { "port": 80, "host": "10.0.0.1" } } } },
+        ],
+    }));
+    assert_eq!(details(&probes, "baseline:v1.33"), Vec::<String>::new());
+    assert_eq!(details(&probes, "baseline:v1.34"), [
+        r#"probe or lifecycle host (containers "a", "b" use probe or lifecycle hosts "10.0.0.1", "example.com")"#,
+    ]);
+
+    // Before v1.19, seccomp profiles were set with annotations.
+    let mut pod = pod.clone();
+    pod.metadata.annotations = Some([("seccomp.security.alpha.kubernetes.io/pod".to_owned(), "unconfined".to_owned())].into_iter().collect());
+    pod.spec.as_mut().unwrap().security_context = None;
+    assert_eq!(details(&pod, "baseline:v1.18"), [r#"seccompProfile (forbidden annotation seccomp.security.alpha.kubernetes.io/pod="unconfined")"#]);
+    assert_eq!(details(&pod, "baseline:v1.19"), Vec::<String>::new());
+
+    // Restricted checks that didn't exist in older versions of the policy.
+    pod.metadata.annotations = None;
+    pod.spec.as_mut().unwrap().security_context = Some(serde_json::from_value(serde_json::json!({ "runAsNonRoot": true, "runAsUser": 0 })).unwrap());
+    assert_eq!(details(&pod, "restricted:v1.7"), Vec::<String>::new());
+    assert_eq!(details(&pod, "restricted:v1.22"), [
+        r#"allowPrivilegeEscalation != false (container "app" must set securityContext.allowPrivilegeEscalation=false)"#,
+        r#"unrestricted capabilities (container "app" must set securityContext.capabilities.drop=["ALL"])"#,
+        r#"seccompProfile (pod or container "app" must set securityContext.seccompProfile.type to "RuntimeDefault" or "Localhost")"#,
+    ]);
+    assert_eq!(details(&pod, "restricted:v1.23").len(), 4);
+
+    // Windows pods are exempt from some restricted checks since v1.25.
+    pod.spec.as_mut().unwrap().os = Some(serde_json::from_value(serde_json::json!({ "name": "windows" })).unwrap());
+    assert_eq!(details(&pod, "restricted:v1.25"), ["runAsUser=0 (pod must not set runAsUser=0)"]);
+}
+
+#[test]
+fn restricted() {
+    let deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "web" } },
+            "template": {
+                "metadata": { "labels": { "app": "web" } },
+                "spec": {
+                    "initContainers": [{ "name": "init", "securityContext": { "runAsNonRoot": false } }],
+                    "containers": [{
+                        "name": "web",
+                        "securityContext": {
+                            "allowPrivilegeEscalation": false,
+                            "capabilities": { "add": ["NET_BIND_SERVICE", "NET_RAW"], "drop": ["ALL"] },
+                        },
+                    }],
+                    "volumes": [{ "name": "data", "nfs": { "server": "nfs", "path": "/" } }, { "name": "config", "configMap": { "name": "config" } }],
+                },
+            },
+        },
+    })).expect("couldn't deserialize Deployment");
+
+    // The restricted capabilities check replaces the baseline one.
+    assert_eq!(details(&deployment, "restricted"), [
+        r#"allowPrivilegeEscalation != false (container "init" must set securityContext.allowPrivilegeEscalation=false)"#,
+        r#"unrestricted capabilities (container "init" must set securityContext.capabilities.drop=["ALL"]; container "web" must not include "NET_RAW" in securityContext.capabilities.add)"#,
+        r#"restricted volume types (volume "data" uses restricted volume type "nfs")"#,
+        r#"runAsNonRoot != true (container "init" must not set securityContext.runAsNonRoot=false)"#,
+        r#"seccompProfile (pod or containers "init", "web" must set securityContext.seccompProfile.type to "RuntimeDefault" or "Localhost")"#,
+    ]);
+    assert_eq!(details(&deployment, "baseline"), [r#"non-default capabilities (container "web" must not include "NET_RAW" in securityContext.capabilities.add)"#]);
+
+    let cron_job: CronJob = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "cron-job" },
+        "spec": {
+            "schedule": "@daily",
+            "jobTemplate": {
+                "spec": {
+                    "template": {
+                        "spec": {
+                            "containers": [{
+                                "name": "job",
+                                "securityContext": { "allowPrivilegeEscalation": false, "capabilities": { "drop": ["ALL"] } },
+                            }],
+                            "securityContext": { "runAsNonRoot": true, "seccompProfile": { "type": "RuntimeDefault" } },
+                        },
+                    },
+                },
+            },
+        },
+    })).expect("couldn't deserialize CronJob");
+    assert_eq!(details(&cron_job, "restricted"), Vec::<String>::new());
+}
@@ -46,6 +46,74 @@ fn impl_resource() {
     assert_is_namespace_scoped::<k8s_openapi::List<k8s_openapi::api::core::v1::Pod>>("");
 }
 
+#[test]
+fn api_resource() {
+    use k8s_openapi::{ApiResourceScope, DiscoverableResource, Verb};
+
+    const ALL_VERBS: &[Verb] = &[
+        Verb::Get, Verb::List, Verb::Watch, Verb::Create, Verb::Update, Verb::Patch, Verb::Delete, Verb::DeleteCollection,
+    ];
+
+    let api_resource = <k8s_openapi::api::apps::v1::Deployment as DiscoverableResource>::API_RESOURCE;
+    assert_eq!(api_resource.group, "apps");
+    assert_eq!(api_resource.version, "v1");
+    assert_eq!(api_resource.kind, "Deployment");
+    assert_eq!(api_resource.plural, "deployments");
+    assert_eq!(api_resource.scope, ApiResourceScope::Namespace);
+    assert_eq!(api_resource.verbs, ALL_VERBS);
+    assert_eq!(api_resource.subresources, ["scale", "status"]);
+
+    let api_resource = <k8s_openapi::api::core::v1::Pod as DiscoverableResource>::API_RESOURCE;
+    assert!(api_resource.has_subresource("binding"));
+    assert!(api_resource.has_subresource("eviction"));
+    assert!(api_resource.has_subresource("log"));
+    assert!(api_resource.has_subresource("status"));
+    assert!(!api_resource.has_subresource("scale"));
+
+    let api_resource = <k8s_openapi::api::core::v1::Namespace as DiscoverableResource>::API_RESOURCE;
+    assert_eq!(api_resource.scope, ApiResourceScope::Cluster);
+    assert!(api_resource.supports(Verb::Delete));
+    assert!(!api_resource.supports(Verb::DeleteCollection));
+    assert_eq!(api_resource.subresources, ["finalize", "status"]);
+
+    let api_resource = <k8s_openapi::api::core::v1::ServiceAccount as DiscoverableResource>::API_RESOURCE;
+    assert_eq!(api_resource.subresources, ["token"]);
+
+    let api_resource = <k8s_openapi::api::authorization::v1::SubjectAccessReview as DiscoverableResource>::API_RESOURCE;
+    assert_eq!(api_resource.scope, ApiResourceScope::Cluster);
+    assert_eq!(api_resource.verbs, [Verb::Create]);
+    assert_eq!(api_resource.subresources, [""; 0]);
+
+    let api_resource = <k8s_openapi::api::core::v1::Binding as DiscoverableResource>::API_RESOURCE;
+    assert_eq!(api_resource.plural, "bindings");
+    assert_eq!(api_resource.verbs, [Verb::Create]);
+
+    assert_eq!(Verb::DeleteCollection.to_string(), "deletecollection");
+}
+
+#[test]
+fn subresource_of() {
+    use k8s_openapi::{
+        api::{apps::v1 as apps, authentication::v1 as authentication, autoscaling::v1 as autoscaling, core::v1 as core, policy::v1 as policy},
+        NamespaceResourceScope, Resource, SubresourceOf,
+    };
+
+    fn scale_url_path<T>(name: &str, namespace: &str) -> String where autoscaling::Scale: SubresourceOf<T>, T: Resource<Scope = NamespaceResourceScope> {
+        <autoscaling::Scale as SubresourceOf<T>>::url_path(name, Some(namespace))
+    }
+
+    assert_eq!(scale_url_path::<apps::Deployment>("foo", "default"), "/apis/apps/v1/namespaces/default/deployments/foo/scale");
+    assert_eq!(scale_url_path::<apps::ReplicaSet>("foo", "default"), "/apis/apps/v1/namespaces/default/replicasets/foo/scale");
+    assert_eq!(scale_url_path::<apps::StatefulSet>("foo", "default"), "/apis/apps/v1/namespaces/default/statefulsets/foo/scale");
+
+    assert_eq!(
+        <policy::Eviction as SubresourceOf<core::Pod>>::url_path("foo", Some("default")),
+        "/api/v1/namespaces/default/pods/foo/eviction",
+    );
+    assert_eq!(<core::Binding as SubresourceOf<core::Pod>>::NAME, "binding");
+    assert_eq!(<authentication::TokenRequest as SubresourceOf<core::ServiceAccount>>::NAME, "token");
+}
+
 fn assert_is_cluster_scoped<T: k8s_openapi::Resource<Scope = k8s_openapi::ClusterResourceScope>>(expected_url_path_segment: &str) {
     assert_inner::<T>(expected_url_path_segment);
 }
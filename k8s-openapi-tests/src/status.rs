@@ -0,0 +1,139 @@
+use k8s_openapi::status::{Cause, CauseType, StatusError, StatusReason};
+
+#[test]
+fn status_body() {
+    let body = br#"{
+        "kind": "Status",
+        "apiVersion": "v1",
+        "metadata": {},
+        "status": "Failure",
+        "message": "Pod \"foo\" is invalid: spec.containers[0].image: Required value",
+        "reason": "Invalid",
+        "details": {
+            "name": "foo",
+            "kind": "Pod",
+            "causes": [{
+                "reason": "FieldValueRequired",
+                "message": "Required value",
+                "field": "spec.containers[0].image"
+            }, {
+                "reason": "FieldValueSomethingNew",
+                "message": "Something new"
+            }]
+        },
+        "code": 422
+    }"#;
+
+    let err = StatusError::from_response(422, None, body).expect("expected an error");
+    assert_eq!(*err.reason(), StatusReason::Invalid);
+    assert_eq!(err.code(), 422);
+    assert_eq!(err.to_string(), r#"Pod "foo" is invalid: spec.containers[0].image: Required value"#);
+    assert!(err.is_invalid());
+    assert!(!err.is_not_found());
+    assert!(!err.is_unexpected_server_error());
+    assert_eq!(err.retry_after(), None);
+    assert_eq!(err.causes().collect::<Vec<_>>(), [
+        Cause { cause_type: Some(CauseType::FieldValueRequired), message: "Required value", field: "spec.containers[0].image" },
+        Cause { cause_type: Some(CauseType::Other("FieldValueSomethingNew".to_owned())), message: "Something new", field: "" },
+    ]);
+}
+
+#[test]
+fn predicates() {
+    let status = |reason: &str, code: i32| StatusError::new(k8s_openapi::apimachinery::pkg::apis::meta::v1::Status {
+        code: Some(code),
+        reason: Some(reason.to_owned()),
+        ..Default::default()
+    });
+
+    assert!(status("NotFound", 404).is_not_found());
+    assert!(status("", 404).is_not_found());
+    assert!(status("SomethingNew", 404).is_not_found());
+    assert!(!status("Forbidden", 404).is_not_found());
+
+    assert!(status("AlreadyExists", 409).is_already_exists());
+    assert!(!status("AlreadyExists", 409).is_conflict());
+    assert!(!status("", 409).is_already_exists());
+    assert!(status("", 409).is_conflict());
+
+    assert!(status("Gone", 410).is_gone());
+    assert!(!status("Expired", 410).is_gone());
+    assert!(status("Expired", 410).is_resource_expired());
+
+    assert!(status("TooManyRequests", 429).is_too_many_requests());
+    assert!(status("ServiceUnavailable", 429).is_too_many_requests());
+
+    assert_eq!(*status("StorageReadError", 500).reason(), StatusReason::StoreReadError);
+    assert!(status("StorageReadError", 500).is_store_read_error());
+    assert!(!status("StorageReadError", 500).is_internal_error());
+}
+
+#[test]
+fn retry_after() {
+    let status = |reason: &str, retry_after_seconds: Option<i32>| StatusError::new(k8s_openapi::apimachinery::pkg::apis::meta::v1::Status {
+        code: Some(429),
+        details: Some(k8s_openapi::apimachinery::pkg::apis::meta::v1::StatusDetails {
+            retry_after_seconds,
+            ..Default::default()
+        }),
+        reason: Some(reason.to_owned()),
+        ..Default::default()
+    });
+
+    assert_eq!(status("TooManyRequests", Some(5)).retry_after(), Some(std::time::Duration::from_secs(5)));
+    assert_eq!(status("TooManyRequests", Some(0)).retry_after(), None);
+    assert_eq!(status("TooManyRequests", None).retry_after(), None);
+    assert_eq!(status("ServerTimeout", None).retry_after(), Some(std::time::Duration::ZERO));
+}
+
+#[test]
+fn unstructured_body() {
+    let err = StatusError::from_response(404, None, b"404 page not found\n").expect("expected an error");
+    assert_eq!(*err.reason(), StatusReason::NotFound);
+    assert_eq!(err.to_string(), "the server could not find the requested resource");
+    assert!(err.is_not_found());
+    assert!(err.is_unexpected_server_error());
+    assert_eq!(err.causes().collect::<Vec<_>>(), [
+        Cause { cause_type: Some(CauseType::UnexpectedServerResponse), message: "404 page not found", field: "" },
+    ]);
+
+    let err = StatusError::from_response(502, None, b"<html>Bad Gateway</html>").expect("expected an error");
+    assert_eq!(*err.reason(), StatusReason::InternalError);
+    assert_eq!(err.to_string(), r#"an error on the server ("<html>Bad Gateway</html>") has prevented the request from succeeding"#);
+    assert!(err.is_internal_error());
+
+    let err = StatusError::from_response(403, None, b"\xff").expect("expected an error");
+    assert!(err.is_forbidden());
+    assert_eq!(err.to_string(), "unknown");
+
+    let err = StatusError::from_response(302, None, b"").expect("expected an error");
+    assert_eq!(*err.reason(), StatusReason::Unknown);
+    assert_eq!(err.to_string(), "the server responded with the status code 302 but did not return more information");
+
+    // Unlike 406, 415 always uses the server message.
+    let err = StatusError::from_response(415, None, b"").expect("expected an error");
+    assert_eq!(*err.reason(), StatusReason::UnsupportedMediaType);
+    assert_eq!(err.to_string(), "");
+    let err = StatusError::from_response(406, None, b"").expect("expected an error");
+    assert_eq!(err.to_string(), "the server was unable to respond with a content type that the client supports");
+
+    // The Retry-After header is only used for 429 and 5xx responses.
+    let err = StatusError::from_response(429, Some("5"), b"").expect("expected an error");
+    assert!(err.is_too_many_requests());
+    assert_eq!(err.status().details.as_ref().unwrap().retry_after_seconds, Some(5));
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(5)));
+    let err = StatusError::from_response(503, Some("10"), b"").expect("expected an error");
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(10)));
+    let err = StatusError::from_response(503, Some("Wed, 21 Oct 2015 07:28:00 GMT"), b"").expect("expected an error");
+    assert_eq!(err.retry_after(), None);
+    let err = StatusError::from_response(404, Some("5"), b"").expect("expected an error");
+    assert_eq!(err.status().details.as_ref().unwrap().retry_after_seconds, None);
+    assert_eq!(err.retry_after(), None);
+}
+
+#[test]
+fn success() {
+    assert_eq!(StatusError::from_response(200, None, br#"{"kind":"Pod","apiVersion":"v1","metadata":{"name":"foo"}}"#), None);
+    assert_eq!(StatusError::from_response(200, None, br#"{"kind":"Status","apiVersion":"v1","metadata":{},"status":"Success"}"#), None);
+    assert_eq!(StatusError::from_response(201, None, b""), None);
+}
@@ -0,0 +1,238 @@
+use k8s_openapi::serde_json;
+use k8s_openapi_codegen_common::swagger20;
+
+#[test]
+fn operations() {
+    let spec: swagger20::Spec = serde_json::from_value(serde_json::json!({
+        "swagger": "2.0",
+        "info": { "title": "Kubernetes", "version": "v1.35.0" },
+        "consumes": ["application/json"],
+        "produces": ["application/json", "application/yaml"],
+        "definitions": {},
+        "parameters": {
+            "pretty-nN7o5FEq": {
+                "name": "pretty",
+                "in": "query",
+                "description": "If 'true', then the output is pretty printed.",
+                "type": "string",
+                "uniqueItems": true,
+            },
+        },
+        "paths": {
+            "/api/v1/namespaces/{namespace}/pods/{name}": {
+                "parameters": [
+                    { "name": "name", "in": "path", "description": "name of the Pod", "type": "string", "uniqueItems": true },
+                    { "$ref": "#/parameters/pretty-nN7o5FEq" },
+                    { "name": "propagationPolicy", "in": "query", "type": "string", "enum": ["Background", "Foreground", "Orphan"], "default": "Background" },
+                ],
+                "get": {
+                    "operationId": "readCoreV1NamespacedPod",
+                    "description": "read the specified Pod",
+                    "x-kubernetes-action": "get",
+                    "x-kubernetes-group-version-kind": { "group": "", "kind": "Pod", "version": "v1" },
+                    "parameters": [
+                        { "name": "propagationPolicy", "in": "query", "description": "overridden", "type": "string" },
+                        { "name": "fieldSelector", "in": "query", "type": "array", "items": { "type": "string" }, "collectionFormat": "multi" },
+                    ],
+                    "responses": {
+                        "200": { "description": "OK", "schema": { "$ref": "#/definitions/io.k8s.api.core.v1.Pod" } },
+                        "401": { "description": "Unauthorized" },
+                    },
+                },
+                "patch": {
+                    "operationId": "patchCoreV1NamespacedPod",
+                    "deprecated": true,
+                    "consumes": ["application/merge-patch+json"],
+                    "x-kubernetes-action": "patch",
+                    "parameters": [
+                        { "name": "body", "in": "body", "required": true, "schema": { "type": "object" } },
+                    ],
+                },
+            },
+        },
+    })).expect("couldn't deserialize spec");
+
+    assert_eq!(spec.operations.len(), 2);
+
+    let get = &spec.operations[0];
+    assert_eq!(get.id, "readCoreV1NamespacedPod");
+    assert_eq!(get.method, swagger20::Method::Get);
+    assert_eq!(&*get.path, "/api/v1/namespaces/{namespace}/pods/{name}");
+    assert_eq!(get.description.as_deref(), Some("read the specified Pod"));
+    assert!(!get.deprecated);
+    assert_eq!(get.kubernetes_action, Some(swagger20::KubernetesAction::Get));
+    assert_eq!(get.consumes, ["application/json"]);
+    assert_eq!(get.produces, ["application/json", "application/yaml"]);
+    assert_eq!(get.responses.keys().map(String::as_str).collect::<Vec<_>>(), ["200", "401"]);
+    assert_eq!(get.responses["200"].description, "OK");
+    assert!(matches!(&get.responses["200"].schema, Some(swagger20::Schema { kind: swagger20::SchemaKind::Ref(ref_path), .. }) if ref_path.path == "io.k8s.api.core.v1.Pod"));
+    assert_eq!(get.responses["401"].schema, None);
+
+    // The operation's own parameters come first. The path's `propagationPolicy` parameter is overridden by the operation's.
+    assert_eq!(
+        get.parameters.iter().map(|parameter| (&*parameter.name, parameter.location)).collect::<Vec<_>>(),
+        [
+            ("propagationPolicy", swagger20::ParameterLocation::Query),
+            ("fieldSelector", swagger20::ParameterLocation::Query),
+            ("name", swagger20::ParameterLocation::Path),
+            ("pretty", swagger20::ParameterLocation::Query),
+        ],
+    );
+
+    let propagation_policy = &get.parameters[0];
+    assert_eq!(propagation_policy.description.as_deref(), Some("overridden"));
+    assert_eq!(propagation_policy.schema.validation, Default::default());
+
+    let field_selector = &get.parameters[1];
+    assert_eq!(field_selector.collection_format, Some(swagger20::CollectionFormat::Multi));
+    assert!(matches!(&field_selector.schema.kind, swagger20::SchemaKind::Ty(swagger20::Type::Array { .. })));
+    assert!(!field_selector.required);
+
+    // Path parameters are always required.
+    let name = &get.parameters[2];
+    assert!(name.required);
+    assert_eq!(name.collection_format, None);
+
+    let pretty = &get.parameters[3];
+    assert_eq!(pretty.description.as_deref(), Some("If 'true', then the output is pretty printed."));
+    assert_eq!(pretty.schema.kind, swagger20::SchemaKind::Ty(swagger20::Type::String { format: None }));
+
+    let patch = &spec.operations[1];
+    assert_eq!(patch.id, "patchCoreV1NamespacedPod");
+    assert_eq!(patch.method, swagger20::Method::Patch);
+    assert!(patch.deprecated);
+    assert_eq!(patch.consumes, ["application/merge-patch+json"]);
+    assert!(patch.responses.is_empty());
+
+    assert_eq!(
+        patch.parameters.iter().map(|parameter| (&*parameter.name, parameter.location)).collect::<Vec<_>>(),
+        [
+            ("body", swagger20::ParameterLocation::Body),
+            ("name", swagger20::ParameterLocation::Path),
+            ("pretty", swagger20::ParameterLocation::Query),
+            ("propagationPolicy", swagger20::ParameterLocation::Query),
+        ],
+    );
+    assert!(patch.parameters[0].required);
+
+    // Parameters that are shared between operations, either via the path or via a reference, are shared via the `Arc`.
+    assert!(std::sync::Arc::ptr_eq(&get.parameters[2], &patch.parameters[1]));
+    assert!(std::sync::Arc::ptr_eq(&get.parameters[3], &patch.parameters[2]));
+
+    // The `enum` and `default` of a non-body parameter are in its schema's validation.
+    let propagation_policy = &patch.parameters[3];
+    assert_eq!(propagation_policy.schema.validation, swagger20::SchemaValidation {
+        default: Some(swagger20::JsonValue::String("Background".to_owned())),
+        enum_values: Some(vec![
+            swagger20::JsonValue::String("Background".to_owned()),
+            swagger20::JsonValue::String("Foreground".to_owned()),
+            swagger20::JsonValue::String("Orphan".to_owned()),
+        ]),
+        ..Default::default()
+    });
+
+    let err = serde_json::from_value::<swagger20::Spec>(serde_json::json!({
+        "swagger": "2.0",
+        "info": { "title": "Kubernetes", "version": "v1.35.0" },
+        "definitions": {},
+        "paths": {
+            "/api/v1/pods": {
+                "get": { "operationId": "listCoreV1PodForAllNamespaces", "parameters": [{ "$ref": "#/parameters/missing" }] },
+            },
+        },
+    })).unwrap_err();
+    assert_eq!(err.to_string(), "parameter #/parameters/missing does not exist in spec");
+}
+
+#[test]
+fn schema_keywords() {
+    let schema: swagger20::Schema = serde_json::from_value(serde_json::json!({
+        "type": "object",
+        "description": "A schema with every keyword.",
+        "properties": {
+            "mode": { "type": "string" },
+        },
+        "default": { "mode": "a" },
+        "enum": [{ "mode": "a" }, { "mode": "b" }, null],
+        "minimum": -1,
+        "maximum": 1.5,
+        "exclusiveMinimum": true,
+        "exclusiveMaximum": true,
+        "pattern": "^[a-z]+$",
+        "minLength": 1,
+        "maxLength": 63,
+        "minItems": 2,
+        "maxItems": 10,
+        "uniqueItems": true,
+        "nullable": true,
+        "x-kubernetes-validations": [
+            { "rule": "self.mode != 'c'", "message": "mode must not be c", "reason": "FieldValueInvalid", "fieldPath": ".mode" },
+            { "rule": "self.mode == oldSelf.mode", "messageExpression": "'mode is immutable'", "optionalOldSelf": true },
+        ],
+        "x-kubernetes-unions": [
+            { "discriminator": "mode", "fields-to-discriminateBy": { "a": "A" } },
+        ],
+        "x-kubernetes-preserve-unknown-fields": true,
+        "x-kubernetes-embedded-resource": true,
+        "x-foo": { "bar": [1] },
+        "title": "not an extension, so not kept",
+    })).expect("couldn't deserialize schema");
+
+    assert_eq!(schema.description.as_deref(), Some("A schema with every keyword."));
+    assert!(matches!(&schema.kind, swagger20::SchemaKind::Properties(properties) if properties.contains_key("mode")));
+
+    let mode_a = swagger20::JsonValue::Object([("mode".to_owned(), swagger20::JsonValue::String("a".to_owned()))].into());
+    let mode_b = swagger20::JsonValue::Object([("mode".to_owned(), swagger20::JsonValue::String("b".to_owned()))].into());
+
+    assert_eq!(schema.validation, swagger20::SchemaValidation {
+        default: Some(mode_a.clone()),
+        enum_values: Some(vec![mode_a, mode_b, swagger20::JsonValue::Null]),
+        maximum: Some(swagger20::JsonNumber::Float(1.5)),
+        exclusive_maximum: true,
+        minimum: Some(swagger20::JsonNumber::NegInt(-1)),
+        exclusive_minimum: true,
+        max_length: Some(63),
+        min_length: Some(1),
+        pattern: Some("^[a-z]+$".to_owned()),
+        max_items: Some(10),
+        min_items: Some(2),
+        unique_items: true,
+        nullable: true,
+        kubernetes_validations: vec![
+            swagger20::KubernetesValidationRule {
+                rule: "self.mode != 'c'".to_owned(),
+                message: Some("mode must not be c".to_owned()),
+                reason: Some("FieldValueInvalid".to_owned()),
+                field_path: Some(".mode".to_owned()),
+                ..Default::default()
+            },
+            swagger20::KubernetesValidationRule {
+                rule: "self.mode == oldSelf.mode".to_owned(),
+                message_expression: Some("'mode is immutable'".to_owned()),
+                optional_old_self: true,
+                ..Default::default()
+            },
+        ],
+    });
+
+    assert_eq!(schema.extensions, swagger20::SchemaExtensions {
+        kubernetes_embedded_resource: true,
+        kubernetes_preserve_unknown_fields: true,
+        kubernetes_unions: vec![swagger20::KubernetesUnion {
+            discriminator: Some("mode".to_owned()),
+            fields_to_discriminate_by: [("a".to_owned(), "A".to_owned())].into(),
+        }],
+        other: [(
+            "x-foo".to_owned(),
+            swagger20::JsonValue::Object([(
+                "bar".to_owned(),
+                swagger20::JsonValue::Array(vec![swagger20::JsonValue::Number(swagger20::JsonNumber::PosInt(1))]),
+            )].into()),
+        )].into(),
+    });
+
+    // A schema without any of the keywords has the default values.
+    let schema: swagger20::Schema = serde_json::from_value(serde_json::json!({ "type": "string" })).expect("couldn't deserialize schema");
+    assert_eq!(schema.validation, Default::default());
+    assert_eq!(schema.extensions, Default::default());
+}
@@ -0,0 +1,105 @@
+use k8s_openapi::serde_json;
+use k8s_openapi::table;
+
+#[test]
+fn pods() {
+    use k8s_openapi::api::core::v1 as api;
+
+    assert_eq!(table::Table::<()>::ACCEPT, "application/json;as=Table;v=v1;g=meta.k8s.io");
+
+    let table: table::Table<k8s_openapi::PartialObjectMetadata<api::Pod>> = serde_json::from_value(serde_json::json!({
+        "kind": "Table",
+        "apiVersion": "meta.k8s.io/v1",
+        "metadata": { "resourceVersion": "123" },
+        "columnDefinitions": [
+            { "name": "Name", "type": "string", "format": "name", "description": "Name must be unique within a namespace.", "priority": 0 },
+            { "name": "Ready", "type": "string", "format": "", "description": "The aggregate readiness state of this pod for accepting traffic.", "priority": 0 },
+            { "name": "Status", "type": "string", "format": "", "description": "The aggregate status of the containers in this pod.", "priority": 0 },
+            { "name": "Restarts", "type": "string", "format": "", "description": "The number of times the containers in this pod have been restarted.", "priority": 0 },
+            { "name": "Age", "type": "string", "format": "", "description": "CreationTimestamp is a timestamp.", "priority": 0 },
+            { "name": "IP", "type": "string", "format": "", "description": "IP address allocated to the pod.", "priority": 1 },
+            { "name": "Node", "type": "string", "format": "", "description": "Name of the node this pod is running on.", "priority": 1 },
+        ],
+        "rows": [
+            {
+                "cells": ["coredns-5d78c9869d-8xk2p", "1/1", "Running", "2 (3d ago)", "12d", "10.244.0.3", "kind-control-plane"],
+                "object": {
+                    "kind": "PartialObjectMetadata",
+                    "apiVersion": "meta.k8s.io/v1",
+                    "metadata": { "name": "coredns-5d78c9869d-8xk2p", "namespace": "kube-system" },
+                },
+            },
+            {
+                "cells": ["etcd", "0/1", "Completed", "0", "5m", null, "kind-control-plane"],
+                "conditions": [{ "type": "Completed", "status": "True" }],
+                "object": {
+                    "kind": "PartialObjectMetadata",
+                    "apiVersion": "meta.k8s.io/v1",
+                    "metadata": { "name": "etcd", "namespace": "kube-system" },
+                },
+            },
+        ],
+    })).unwrap();
+
+    assert_eq!(table.metadata.resource_version.as_deref(), Some("123"));
+    assert_eq!(table.column_definitions[5].priority, 1);
+    assert_eq!(table.rows[0].object.as_ref().unwrap().metadata.namespace.as_deref(), Some("kube-system"));
+    assert_eq!(table.rows[1].conditions.as_ref().unwrap()[0].type_, "Completed");
+
+    assert_eq!(table.render(Default::default()), "\
+NAME                       READY   STATUS      RESTARTS     AGE
+coredns-5d78c9869d-8xk2p   1/1     Running     2 (3d ago)   12d
+etcd                       0/1     Completed   0            5m
+");
+
+    assert_eq!(table.render(table::RenderOptions { wide: true, no_headers: true }), "\
+coredns-5d78c9869d-8xk2p   1/1   Running     2 (3d ago)   12d   10.244.0.3   kind-control-plane
+etcd                       0/1   Completed   0            5m                 kind-control-plane
+");
+
+    // Empty tables render as nothing.
+    let empty = table::Table { rows: vec![], ..table };
+    assert_eq!(empty.render(Default::default()), "");
+}
+
+#[test]
+fn cells() {
+    let table: table::Table = serde_json::from_value(serde_json::json!({
+        "kind": "Table",
+        "apiVersion": "meta.k8s.io/v1",
+        "columnDefinitions": [
+            { "name": "a", "type": "string" },
+            { "name": "b", "type": "integer" },
+            { "name": "c", "type": "number" },
+            { "name": "d", "type": "boolean" },
+            { "name": "e", "type": "string" },
+        ],
+        "rows": [
+            { "cells": ["line 1\nline 2", 3, 1.5, true, ["x", { "k": 1, "j": null }]], "object": null },
+            { "cells": ["\u{1b}[31mred", -2, 2_500_000.0, false, "\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}"] },
+            { "cells": ["\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}", 0, 0.000_01] },
+        ],
+    })).unwrap();
+    assert_eq!(table.rows[0].object, None);
+
+    assert_eq!(table.render(Default::default()), "\
+A            B     C         D       E
+line 1...    3     1.5       true    [x map[j:<nil> k:1]]
+^[[31mred    -2    2.5e+06   false   \u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}
+\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}   0     1e-05
+");
+}
+
+#[test]
+fn table_options() {
+    let options = table::TableOptions { include_object: Some(table::IncludeObjectPolicy::Metadata) };
+    assert_eq!(serde_json::to_value(&options).unwrap(), serde_json::json!({
+        "apiVersion": "meta.k8s.io/v1",
+        "kind": "TableOptions",
+        "includeObject": "Metadata",
+    }));
+    assert_eq!(serde_json::from_value::<table::TableOptions>(serde_json::json!({ "includeObject": "None" })).unwrap(), table::TableOptions {
+        include_object: Some(table::IncludeObjectPolicy::None),
+    });
+    assert_eq!(table::IncludeObjectPolicy::Object.as_str(), "Object");
+}
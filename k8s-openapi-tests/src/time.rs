@@ -4,34 +4,99 @@ use k8s_openapi::serde_json;
 fn time() {
     use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
 
-    for (input, expected_time, expected_micro_time) in [
+    for (input, expected_time, expected_full_precision_time, expected_micro_time) in [
         (
             r#""2020-03-05T12:34:56.789012345Z""#,
             r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56.789012345Z""#,
             r#""2020-03-05T12:34:56.789012Z""#,
         ),
         (
             r#""2020-03-05T12:34:56.789012Z""#,
             r#""2020-03-05T12:34:56Z""#,
             r#""2020-03-05T12:34:56.789012Z""#,
+            r#""2020-03-05T12:34:56.789012Z""#,
         ),
         (
             r#""2020-03-05T12:34:56.789000Z""#,
             r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56.789Z""#,
             r#""2020-03-05T12:34:56.789000Z""#,
         ),
         (
             r#""2020-03-05T12:34:56.789Z""#,
             r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56.789Z""#,
             r#""2020-03-05T12:34:56.789000Z""#,
         ),
+        (
+            r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56Z""#,
+            r#""2020-03-05T12:34:56.000000Z""#,
+        ),
     ] {
         let time: meta::Time = serde_json::from_str(input).unwrap();
         let time_serialized = serde_json::to_string(&time).unwrap();
-        assert_eq!(time_serialized, expected_time);
+        if cfg!(feature = "full-precision-time") {
+            assert_eq!(time_serialized, expected_full_precision_time);
+        }
+        else {
+            assert_eq!(time_serialized, expected_time);
+        }
 
         let micro_time: meta::MicroTime = serde_json::from_str(input).unwrap();
         let micro_time_serialized = serde_json::to_string(&micro_time).unwrap();
         assert_eq!(micro_time_serialized, expected_micro_time);
     }
 }
+
+#[test]
+fn chrono_conversions() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+    use k8s_openapi::chrono;
+
+    for (timestamp, expected) in [
+        ("2020-03-05T12:34:56.789012345Z", chrono::DateTime::from_timestamp(1_583_411_696, 789_012_345).unwrap()),
+        ("1969-12-31T23:59:59.5Z", chrono::DateTime::from_timestamp(-1, 500_000_000).unwrap()),
+    ] {
+        let meta_time = meta::Time(timestamp.parse().unwrap());
+        let date_time: chrono::DateTime<chrono::Utc> = meta_time.clone().into();
+        assert_eq!(date_time, expected);
+        assert_eq!(meta::Time::try_from(date_time).unwrap(), meta_time);
+
+        let micro_time = meta::MicroTime(timestamp.parse().unwrap());
+        let date_time: chrono::DateTime<chrono::Utc> = micro_time.clone().into();
+        assert_eq!(date_time, expected);
+        assert_eq!(meta::MicroTime::try_from(date_time).unwrap(), micro_time);
+    }
+
+    meta::Time::try_from(chrono::DateTime::<chrono::Utc>::MAX_UTC).unwrap_err();
+}
+
+#[test]
+fn time_conversions() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1 as meta;
+    use k8s_openapi::time;
+
+    for (timestamp, expected) in [
+        ("2020-03-05T12:34:56.789012345Z", time::OffsetDateTime::from_unix_timestamp_nanos(1_583_411_696_789_012_345).unwrap()),
+        ("1969-12-31T23:59:59.5Z", time::OffsetDateTime::from_unix_timestamp_nanos(-500_000_000).unwrap()),
+    ] {
+        let meta_time = meta::Time(timestamp.parse().unwrap());
+        let date_time: time::OffsetDateTime = meta_time.clone().into();
+        assert_eq!(date_time, expected);
+        assert_eq!(meta::Time::try_from(date_time).unwrap(), meta_time);
+
+        let micro_time = meta::MicroTime(timestamp.parse().unwrap());
+        let date_time: time::OffsetDateTime = micro_time.clone().into();
+        assert_eq!(date_time, expected);
+        assert_eq!(meta::MicroTime::try_from(date_time).unwrap(), micro_time);
+    }
+
+    // The offset is ignored.
+    let date_time = time::OffsetDateTime::from_unix_timestamp(1_583_411_696).unwrap().to_offset(time::UtcOffset::from_hms(5, 30, 0).unwrap());
+    assert_eq!(meta::Time::try_from(date_time).unwrap(), meta::Time("2020-03-05T12:34:56Z".parse().unwrap()));
+
+    meta::Time::try_from(time::OffsetDateTime::new_utc(time::Date::MAX, time::Time::MIDNIGHT)).unwrap_err();
+}
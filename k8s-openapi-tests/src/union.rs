@@ -0,0 +1,88 @@
+use k8s_openapi::api::autoscaling::v2::{MetricSpec, MetricSpecUnion, MetricSpecUnionRef, PodsMetricSource};
+use k8s_openapi::api::core::v1::{
+    ConfigMapVolumeSource, EmptyDirVolumeSource, ExecAction, HTTPGetAction, Probe, ProbeUnion, ProbeUnionRef, Volume, VolumeUnion, VolumeUnionRef,
+};
+
+#[test]
+fn implicit() {
+    let volume: Volume = serde_json::from_value(serde_json::json!({
+        "name": "cache",
+        "emptyDir": { "medium": "Memory" },
+    })).expect("couldn't deserialize Volume");
+    let empty_dir = EmptyDirVolumeSource { medium: Some("Memory".to_owned()), ..Default::default() };
+    assert_eq!(volume.union(), Ok(Some(VolumeUnionRef::EmptyDir(&empty_dir))));
+
+    // The member is borrowed from the value, and can be converted into the owned union.
+    let member = volume.union().unwrap().unwrap();
+    assert!(matches!(member, VolumeUnionRef::EmptyDir(value) if std::ptr::eq(value, volume.empty_dir.as_ref().unwrap())));
+    assert_eq!(VolumeUnion::from(member), VolumeUnion::EmptyDir(empty_dir));
+
+    let volume = Volume { name: "empty".to_owned(), ..Default::default() };
+    assert_eq!(volume.union(), Ok(None));
+
+    let volume: Volume = serde_json::from_value(serde_json::json!({
+        "name": "both",
+        "configMap": { "name": "config" },
+        "emptyDir": {},
+    })).expect("couldn't deserialize Volume");
+    let err = volume.union().unwrap_err();
+    assert_eq!(err, k8s_openapi::UnionError::MultipleMembersSet(vec!["configMap", "emptyDir"]));
+    assert_eq!(err.to_string(), "may not specify more than one of configMap, emptyDir");
+}
+
+#[test]
+fn set_union() {
+    let mut volume = Volume {
+        name: "config".to_owned(),
+        empty_dir: Some(Default::default()),
+        ..Default::default()
+    };
+    volume.set_union(Some(VolumeUnion::ConfigMap(ConfigMapVolumeSource { name: "config".to_owned(), ..Default::default() })));
+    assert_eq!(volume.name, "config");
+    assert_eq!(volume.empty_dir, None);
+    assert_eq!(volume.config_map.as_ref().map(|config_map| &*config_map.name), Some("config"));
+
+    volume.set_union(None);
+    assert_eq!(volume, Volume { name: "config".to_owned(), ..Default::default() });
+
+    let probe: Probe = ProbeUnion::HttpGet(HTTPGetAction { path: Some("/healthz".to_owned()), ..Default::default() }).into();
+    assert_eq!(
+        serde_json::to_value(&probe).expect("couldn't serialize Probe"),
+        serde_json::json!({ "httpGet": { "path": "/healthz", "port": 0 } }),
+    );
+
+    let mut probe = Probe { period_seconds: Some(10), ..probe };
+    probe.set_union(Some(ProbeUnion::Exec(ExecAction { command: Some(vec!["true".to_owned()]) })));
+    assert_eq!(probe.http_get, None);
+    assert_eq!(probe.period_seconds, Some(10));
+    assert!(matches!(probe.union(), Ok(Some(ProbeUnionRef::Exec(_)))));
+}
+
+#[test]
+fn discriminated() {
+    let metric: MetricSpec = MetricSpecUnion::Pods(PodsMetricSource::default()).into();
+    assert_eq!(metric.type_, "Pods");
+    assert!(metric.pods.is_some());
+    assert_eq!(metric.union(), Ok(Some(MetricSpecUnionRef::Pods(&PodsMetricSource::default()))));
+
+    let metric = MetricSpec { type_: "Resource".to_owned(), ..metric };
+    let err = metric.union().unwrap_err();
+    assert_eq!(err, k8s_openapi::UnionError::DiscriminatorMismatch { discriminator: "Resource".to_owned(), member: Some("pods") });
+    assert_eq!(err.to_string(), r#"discriminator "Resource" does not match the member pods that is set"#);
+
+    let metric = MetricSpec { type_: "Pods".to_owned(), ..Default::default() };
+    assert_eq!(
+        metric.union(),
+        Err(k8s_openapi::UnionError::DiscriminatorMismatch { discriminator: "Pods".to_owned(), member: None }),
+    );
+
+    // Unknown discriminator values are not checked, since they may be for members added in newer versions.
+    let metric = MetricSpec { type_: "Custom".to_owned(), ..Default::default() };
+    assert_eq!(metric.union(), Ok(None));
+
+    // The discriminator is required, so it's left unchanged when the member is unset.
+    let mut metric: MetricSpec = MetricSpecUnion::Pods(PodsMetricSource::default()).into();
+    metric.set_union(None);
+    assert_eq!(metric.type_, "Pods");
+    assert!(metric.pods.is_none());
+}
@@ -0,0 +1,271 @@
+use k8s_openapi::serde_json;
+use k8s_openapi::unknown_fields::{PathSegment, Preserved, UnknownField};
+
+#[test]
+fn round_trip() {
+    let input = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "futureMetadataField": "a",
+        },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "image": "alpine",
+                "futureContainerField": { "b": [1, 2] },
+            }],
+            "futurePodSpecField": true,
+        },
+        "futurePodField": null,
+    });
+
+    let pod: Preserved<k8s_openapi::api::core::v1::Pod> = serde_json::from_value(input.clone()).expect("couldn't deserialize Pod");
+
+    assert_eq!(pod.value.metadata.name.as_deref(), Some("foo"));
+    assert_eq!(pod.unknown_fields, [
+        UnknownField { path: vec!["futurePodField".into()], value: serde_json::Value::Null },
+        UnknownField { path: vec!["metadata".into(), "futureMetadataField".into()], value: "a".into() },
+        UnknownField {
+            path: vec!["spec".into(), "containers".into(), PathSegment::MergeKey(vec![("name".to_owned(), "app".to_owned())]), "futureContainerField".into()],
+            value: serde_json::json!({ "b": [1, 2] }),
+        },
+        UnknownField { path: vec!["spec".into(), "futurePodSpecField".into()], value: true.into() },
+    ]);
+
+    let output = serde_json::to_value(&pod).expect("couldn't serialize Pod");
+    assert_eq!(output, input);
+
+    // Without the wrapper, the unknown fields are dropped as usual.
+    let output = serde_json::to_value(&pod.value).expect("couldn't serialize Pod");
+    assert_eq!(output, serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+        },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "image": "alpine",
+            }],
+        },
+    }));
+}
+
+#[test]
+fn modified() {
+    let mut pod: Preserved<k8s_openapi::api::core::v1::Pod> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+        },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "futureContainerField": "a",
+            }],
+            "futurePodSpecField": "b",
+        },
+    })).expect("couldn't deserialize Pod");
+
+    // Unknown fields are emitted again next to the modified fields, and dropped if the object they were in is gone.
+    pod.value.metadata.labels = Some([("app".to_owned(), "foo".to_owned())].into());
+    pod.value.spec.as_mut().expect("couldn't get PodSpec").containers.clear();
+
+    let output = serde_json::to_value(&pod).expect("couldn't serialize Pod");
+    assert_eq!(output, serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "labels": { "app": "foo" },
+        },
+        "spec": {
+            "containers": [],
+            "futurePodSpecField": "b",
+        },
+    }));
+
+    // A value that wasn't deserialized has no unknown fields.
+    let pod = Preserved::new(pod.value);
+    assert!(pod.unknown_fields.is_empty());
+}
+
+#[test]
+fn list_map_items() {
+    let mut pod: Preserved<k8s_openapi::api::core::v1::Pod> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+        },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "ports": [{ "containerPort": 80, "protocol": "TCP", "futurePortField": "a" }],
+            }, {
+                "name": "sidecar",
+                "futureContainerField": "b",
+            }],
+            "tolerations": [{ "key": "a" }, { "key": "b", "futureTolerationField": "c" }],
+        },
+    })).expect("couldn't deserialize Pod");
+
+    // Items of lists with `x-kubernetes-list-type: map` are identified by their merge keys, and items of other lists by their index.
+    assert_eq!(pod.unknown_fields, [
+        UnknownField {
+            path: vec![
+                "spec".into(),
+                "containers".into(),
+                PathSegment::MergeKey(vec![("name".to_owned(), "app".to_owned())]),
+                "ports".into(),
+                PathSegment::MergeKey(vec![("containerPort".to_owned(), "80".to_owned())]),
+                "futurePortField".into(),
+            ],
+            value: "a".into(),
+        },
+        UnknownField {
+            path: vec!["spec".into(), "containers".into(), PathSegment::MergeKey(vec![("name".to_owned(), "sidecar".to_owned())]), "futureContainerField".into()],
+            value: "b".into(),
+        },
+        UnknownField { path: vec!["spec".into(), "tolerations".into(), PathSegment::Index(1), "futureTolerationField".into()], value: "c".into() },
+    ]);
+
+    // So the unknown fields of the containers stay with them when the containers are reordered or inserted.
+    let spec = pod.value.spec.as_mut().expect("couldn't get PodSpec");
+    spec.containers.reverse();
+    spec.containers.insert(0, k8s_openapi::api::core::v1::Container { name: "init".to_owned(), ..Default::default() });
+    spec.tolerations.as_mut().expect("couldn't get tolerations").reverse();
+
+    let output = serde_json::to_value(&pod).expect("couldn't serialize Pod");
+    assert_eq!(output, serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+        },
+        "spec": {
+            "containers": [{
+                "name": "init",
+            }, {
+                "name": "sidecar",
+                "futureContainerField": "b",
+            }, {
+                "name": "app",
+                "ports": [{ "containerPort": 80, "protocol": "TCP", "futurePortField": "a" }],
+            }],
+            "tolerations": [{ "key": "b" }, { "key": "a", "futureTolerationField": "c" }],
+        },
+    }));
+}
+
+#[test]
+fn deep_merge() {
+    use k8s_openapi::DeepMerge;
+
+    let mut pod: Preserved<k8s_openapi::api::core::v1::Pod> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "futureMetadataField": { "a": 1, "b": 2 },
+        },
+        "spec": {
+            "containers": [{ "name": "app", "futureContainerField": "a" }],
+            "tolerations": [{ "key": "a", "futureTolerationField": "b" }],
+        },
+    })).expect("couldn't deserialize Pod");
+
+    let other: Preserved<k8s_openapi::api::core::v1::Pod> = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "futureMetadataField": { "b": null, "c": 3 },
+        },
+        "spec": {
+            "containers": [{ "name": "sidecar", "futureContainerField": "c" }, { "name": "app", "image": "app:1" }],
+            "tolerations": [{ "key": "c" }],
+            "futurePodSpecField": "d",
+        },
+    })).expect("couldn't deserialize Pod");
+
+    // Unknown fields at the same path are merged, and the unknown fields of the replaced tolerations are dropped.
+    pod.merge_from(other);
+
+    let output = serde_json::to_value(&pod).expect("couldn't serialize Pod");
+    assert_eq!(output, serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": "foo",
+            "futureMetadataField": { "a": 1, "c": 3 },
+        },
+        "spec": {
+            "containers": [
+                { "name": "app", "image": "app:1", "futureContainerField": "a" },
+                { "name": "sidecar", "futureContainerField": "c" },
+            ],
+            "tolerations": [{ "key": "c" }],
+            "futurePodSpecField": "d",
+        },
+    }));
+}
+
+#[test]
+fn list() {
+    let input = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": {
+            "resourceVersion": "1",
+        },
+        "items": [{
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": {
+                "name": "foo",
+            },
+            "futurePodField": 6,
+        }],
+        "futureListField": 5,
+    });
+
+    let list: Preserved<k8s_openapi::List<k8s_openapi::api::core::v1::Pod>> =
+        serde_json::from_value(input.clone()).expect("couldn't deserialize PodList");
+    assert_eq!(list.unknown_fields, [
+        UnknownField { path: vec!["futureListField".into()], value: 5.into() },
+        UnknownField {
+            path: vec!["items".into(), PathSegment::MergeKey(vec![("metadata.name".to_owned(), "foo".to_owned())]), "futurePodField".into()],
+            value: 6.into(),
+        },
+    ]);
+
+    let output = serde_json::to_value(&list).expect("couldn't serialize PodList");
+    assert_eq!(output, input);
+}
+
+#[test]
+fn compact() {
+    let input = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "ConfigMap",
+        "metadata": {
+            "name": "foo",
+        },
+        "data": {
+            "key": "value",
+        },
+        "futureConfigMapField": "a",
+    });
+
+    let config_map: Preserved<k8s_openapi::compact::api::core::v1::ConfigMap> =
+        serde_json::from_value(input.clone()).expect("couldn't deserialize ConfigMap");
+    assert_eq!(config_map.unknown_fields, [UnknownField { path: vec!["futureConfigMapField".into()], value: "a".into() }]);
+
+    let output = serde_json::to_value(&config_map).expect("couldn't serialize ConfigMap");
+    assert_eq!(output, input);
+}
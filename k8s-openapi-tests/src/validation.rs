@@ -0,0 +1,459 @@
+use k8s_openapi::validation::{FieldErrorKind, Validate};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{ConfigMap, Pod, Secret, Service};
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
+
+fn messages(errors: &[k8s_openapi::validation::FieldError]) -> Vec<String> {
+    errors.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn pod() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "pod", "labels": { "app.kubernetes.io/name": "app" } },
+        "spec": {
+            "containers": [{
+                "name": "app",
+                "image": "app:1.0",
+                "ports": [{ "name": "http", "containerPort": 8080 }, { "name": "metrics", "containerPort": 9090, "protocol": "TCP" }],
+                "volumeMounts": [{ "name": "data", "mountPath": "/data" }],
+                "livenessProbe": { "httpGet": { "port": "http" } },
+            }],
+            "volumes": [{ "name": "data", "emptyDir": {} }],
+        },
+    })).expect("couldn't deserialize Pod");
+    assert_eq!(messages(&pod.validate()), Vec::<String>::new());
+
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "generateName": "Pod-" },
+        "spec": {
+            "initContainers": [{ "name": "app", "image": "busybox " }],
+            "containers": [{
+                "name": "app",
+                "ports": [{ "name": "http", "containerPort": 0 }, { "name": "http", "containerPort": 8080, "protocol": "HTTP" }],
+                "volumeMounts": [{ "name": "data", "mountPath": "/data" }],
+                "livenessProbe": { "successThreshold": 2 },
+            }],
+            "restartPolicy": "Sometimes",
+        },
+    })).expect("couldn't deserialize Pod");
+    assert_eq!(messages(&pod.validate()), [
+        r#"metadata.generateName: Invalid value: "Pod-": a lowercase RFC 1123 subdomain must consist of lower case alphanumeric characters, '-' or '.', and must start and end with an alphanumeric character (e.g. 'example.com', regex used for validation is '[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*')"#,
+        r#"spec.containers[0].name: Duplicate value: "app""#,
+        "spec.containers[0].image: Required value",
+        "spec.containers[0].ports[0].containerPort: Invalid value: 0: must be between 1 and 65535, inclusive",
+        r#"spec.containers[0].ports[1].name: Duplicate value: "http""#,
+        r#"spec.containers[0].ports[1].protocol: Unsupported value: "HTTP": supported values: "SCTP", "TCP", "UDP""#,
+        r#"spec.containers[0].volumeMounts[0].name: Not found: "data""#,
+        "spec.containers[0].livenessProbe: Required value: must specify a handler type",
+        "spec.containers[0].livenessProbe.successThreshold: Invalid value: 2: must be 1",
+        r#"spec.restartPolicy: Unsupported value: "Sometimes": supported values: "Always", "OnFailure", "Never""#,
+        r#"spec.initContainers[0].image: Invalid value: "busybox ": must not have leading or trailing whitespace"#,
+    ]);
+
+    let error = &pod.validate()[1];
+    assert_eq!(error.kind, FieldErrorKind::Duplicate);
+    assert_eq!(error.path, "spec.containers[0].name");
+    assert_eq!(error.kind.cause_type(), k8s_openapi::status::CauseType::FieldValueDuplicate);
+}
+
+#[test]
+fn pod_update() {
+    let old: Pod = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "pod", "namespace": "default" },
+        "spec": {
+            "containers": [{ "name": "app", "image": "app:1.0" }],
+            "tolerations": [{ "key": "dedicated", "operator": "Equal", "value": "app", "effect": "NoSchedule" }],
+        },
+    })).expect("couldn't deserialize Pod");
+
+    // Changing the image and adding a toleration is allowed.
+    let mut new = old.clone();
+    let spec = new.spec.as_mut().unwrap();
+    spec.containers[0].image = Some("app:2.0".to_owned());
+    spec.tolerations.as_mut().unwrap().push(serde_json::from_value(serde_json::json!({ "operator": "Exists" })).unwrap());
+    assert_eq!(messages(&new.validate_update(&old)), Vec::<String>::new());
+
+    // Changing anything else is not.
+    let mut new = old.clone();
+    new.metadata.name = Some("pod2".to_owned());
+    let spec = new.spec.as_mut().unwrap();
+    spec.containers[0].args = Some(vec!["--verbose".to_owned()]);
+    spec.tolerations = None;
+    assert_eq!(messages(&new.validate_update(&old)), [
+        r#"metadata.name: Invalid value: "pod2": field is immutable"#,
+        "spec.tolerations: Forbidden: existing toleration can not be modified except its tolerationSeconds",
+        "spec: Forbidden: pod updates may not change fields other than `spec.containers[*].image`,`spec.initContainers[*].image`,\
+         `spec.activeDeadlineSeconds`,`spec.tolerations` (only additions to existing tolerations),\
+         `spec.terminationGracePeriodSeconds` (allow it to be set to 1 if it was previously negative)",
+    ]);
+}
+
+#[test]
+fn deployment() {
+    let deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "web" }, "matchExpressions": [{ "key": "tier", "operator": "NotIn", "values": ["db"] }] },
+            "strategy": { "type": "RollingUpdate", "rollingUpdate": { "maxSurge": 0, "maxUnavailable": "10%" } },
+            "template": {
+                "metadata": { "labels": { "app": "web", "tier": "frontend" } },
+                "spec": { "containers": [{ "name": "web", "image": "web" }] },
+            },
+        },
+    })).expect("couldn't deserialize Deployment");
+    assert_eq!(messages(&deployment.validate()), Vec::<String>::new());
+
+    let deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "replicas": -1,
+            "selector": { "matchLabels": { "app": "web" } },
+            "strategy": { "type": "Recreate", "rollingUpdate": {} },
+            "template": {
+                "metadata": { "labels": { "app": "api" } },
+                // Only pods reject images with leading or trailing whitespace.
+                "spec": { "containers": [{ "name": "web", "image": " web" }], "restartPolicy": "Never" },
+            },
+            "minReadySeconds": 10,
+            "progressDeadlineSeconds": 10,
+        },
+    })).expect("couldn't deserialize Deployment");
+    assert_eq!(messages(&deployment.validate()), [
+        "spec.replicas: Invalid value: -1: must be greater than or equal to 0",
+        r#"spec.template.metadata.labels: Invalid value: {"app":"api"}: `selector` does not match template `labels`"#,
+        r#"spec.template.spec.restartPolicy: Unsupported value: "Never": supported values: "Always""#,
+        "spec.strategy.rollingUpdate: Forbidden: may not be specified when strategy `type` is 'Recreate'",
+        "spec.progressDeadlineSeconds: Invalid value: 10: must be greater than minReadySeconds",
+    ]);
+
+    let deployment: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "selector": {},
+            "strategy": { "rollingUpdate": { "maxSurge": "0%", "maxUnavailable": "150%" }, "type": "RollingUpdate" },
+            "template": { "spec": { "containers": [{ "name": "web", "image": "web" }] } },
+        },
+    })).expect("couldn't deserialize Deployment");
+    assert_eq!(messages(&deployment.validate()), [
+        "spec.selector: Invalid value: {}: empty selector is invalid for deployment",
+        r#"spec.strategy.rollingUpdate.maxUnavailable: Invalid value: "150%": must not be greater than 100%"#,
+    ]);
+}
+
+#[test]
+fn deployment_update() {
+    let old: Deployment = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "web" } },
+            "template": {
+                "metadata": { "labels": { "app": "web", "version": "v1" } },
+                "spec": { "containers": [{ "name": "web", "image": "web" }] },
+            },
+        },
+    })).expect("couldn't deserialize Deployment");
+
+    let mut new = old.clone();
+    new.spec.as_mut().unwrap().selector.match_labels.as_mut().unwrap().insert("version".to_owned(), "v1".to_owned());
+    assert_eq!(messages(&new.validate_update(&old)), [
+        r#"spec.selector: Invalid value: {"matchLabels":{"app":"web","version":"v1"}}: field is immutable"#,
+    ]);
+}
+
+#[test]
+fn daemon_set() {
+    let daemon_set: DaemonSet = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "agent" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "agent" } },
+            "template": {
+                "metadata": { "labels": { "app": "agent" } },
+                "spec": { "containers": [{ "name": "agent", "image": "agent" }] },
+            },
+            "updateStrategy": { "type": "RollingUpdate", "rollingUpdate": { "maxSurge": 1, "maxUnavailable": 1 } },
+        },
+    })).expect("couldn't deserialize DaemonSet");
+    assert_eq!(messages(&daemon_set.validate()), [
+        "spec.updateStrategy.rollingUpdate.maxSurge: Invalid value: 1: may not be set when maxUnavailable is non-zero",
+    ]);
+}
+
+#[test]
+fn stateful_set() {
+    let old: StatefulSet = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "db" },
+        "spec": {
+            "selector": { "matchLabels": { "app": "db" } },
+            "serviceName": "db",
+            "template": {
+                "metadata": { "labels": { "app": "db" } },
+                "spec": { "containers": [{ "name": "db", "image": "db" }] },
+            },
+            "updateStrategy": { "type": "OnDelete" },
+        },
+    })).expect("couldn't deserialize StatefulSet");
+    assert_eq!(messages(&old.validate()), Vec::<String>::new());
+
+    let mut new = old.clone();
+    let spec = new.spec.as_mut().unwrap();
+    spec.replicas = Some(3);
+    spec.service_name = Some("db-headless".to_owned());
+    assert_eq!(messages(&new.validate_update(&old)), [
+        "spec: Forbidden: updates to statefulset spec for fields other than 'replicas', 'ordinals', 'template', 'updateStrategy', \
+         'revisionHistoryLimit', 'persistentVolumeClaimRetentionPolicy' and 'minReadySeconds' are forbidden",
+    ]);
+}
+
+#[test]
+fn job() {
+    let job: Job = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "job" },
+        "spec": {
+            "completionMode": "Indexed",
+            "template": { "spec": { "containers": [{ "name": "job", "image": "job" }] } },
+        },
+    })).expect("couldn't deserialize Job");
+    assert_eq!(messages(&job.validate()), [
+        "spec.completions: Required value: when completion mode is Indexed",
+        r#"spec.template.spec.restartPolicy: Unsupported value: "Always": supported values: "OnFailure", "Never""#,
+    ]);
+
+    // The template of a job may only be changed while it is suspended.
+    let mut old: Job = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "job" },
+        "spec": {
+            "template": { "spec": { "containers": [{ "name": "job", "image": "job:1.0" }], "restartPolicy": "Never" } },
+        },
+    })).expect("couldn't deserialize Job");
+    let mut new = old.clone();
+    new.spec.as_mut().unwrap().template.spec.as_mut().unwrap().containers[0].image = Some("job:2.0".to_owned());
+    assert_eq!(new.validate_update(&old).len(), 1);
+    assert_eq!(new.validate_update(&old)[0].path, "spec.template");
+
+    old.spec.as_mut().unwrap().suspend = Some(true);
+    assert_eq!(messages(&new.validate_update(&old)), Vec::<String>::new());
+}
+
+#[test]
+fn cron_job() {
+    let cron_job: CronJob = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "a-cron-job-with-a-name-that-is-much-too-long-for-its-jobs" },
+        "spec": {
+            "schedule": "*/5 * * *",
+            "concurrencyPolicy": "Sometimes",
+            "jobTemplate": {
+                "spec": { "template": { "spec": { "containers": [{ "name": "job", "image": "job" }], "restartPolicy": "OnFailure" } } },
+            },
+        },
+    })).expect("couldn't deserialize CronJob");
+    assert_eq!(messages(&cron_job.validate()), [
+        r#"metadata.name: Invalid value: "a-cron-job-with-a-name-that-is-much-too-long-for-its-jobs": must be no more than 52 characters"#,
+        r#"spec.schedule: Invalid value: "*/5 * * *": expected exactly 5 fields, found 4: [*/5 * * *]"#,
+        r#"spec.concurrencyPolicy: Unsupported value: "Sometimes": supported values: "Allow", "Forbid", "Replace""#,
+    ]);
+
+    let cron_job: CronJob = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "cron-job" },
+        "spec": {
+            "schedule": "CRON_TZ=UTC 0 * * * *",
+            "jobTemplate": { "spec": { "template": { "spec": { "containers": [{ "name": "job" }], "restartPolicy": "OnFailure" } } } },
+        },
+    })).expect("couldn't deserialize CronJob");
+    assert_eq!(messages(&cron_job.validate()), [
+        r#"spec.schedule: Invalid value: "CRON_TZ=UTC 0 * * * *": cannot use TZ or CRON_TZ in schedule, use timeZone field instead"#,
+        "spec.jobTemplate.spec.template.spec.containers[0].image: Required value",
+    ]);
+}
+
+#[test]
+fn service() {
+    let service: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": {
+            "type": "NodePort",
+            "selector": { "app": "web" },
+            "ports": [{ "name": "http", "port": 80, "targetPort": "http" }, { "name": "https", "port": 443, "nodePort": 30443 }],
+            "externalTrafficPolicy": "Local",
+        },
+    })).expect("couldn't deserialize Service");
+    assert_eq!(messages(&service.validate()), Vec::<String>::new());
+
+    let service: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "1web" },
+        "spec": {
+            "ports": [{ "port": 80, "nodePort": 30080 }, { "name": "http", "port": 80, "targetPort": 70000 }],
+            "externalTrafficPolicy": "Local",
+        },
+    })).expect("couldn't deserialize Service");
+    assert_eq!(messages(&service.validate()), [
+        r#"metadata.name: Invalid value: "1web": a DNS-1035 label must consist of lower case alphanumeric characters or '-', start with an alphabetic character, and end with an alphanumeric character (e.g. 'my-name',  or 'abc-123', regex used for validation is '[a-z]([-a-z0-9]*[a-z0-9])?')"#,
+        "spec.ports[0].name: Required value",
+        "spec.ports[0].nodePort: Forbidden: may not be used when `type` is 'ClusterIP'",
+        r#"spec.ports[1]: Duplicate value: {"port":80,"protocol":"TCP"}"#,
+        "spec.ports[1].targetPort: Invalid value: 70000: must be between 1 and 65535, inclusive",
+        r#"spec.externalTrafficPolicy: Invalid value: "Local": may only be set for externally-accessible services"#,
+    ]);
+
+    let old: Service = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "web" },
+        "spec": { "clusterIP": "10.0.0.1", "ports": [{ "port": 80 }] },
+    })).expect("couldn't deserialize Service");
+    let mut new = old.clone();
+    new.spec.as_mut().unwrap().cluster_ip = Some("10.0.0.2".to_owned());
+    assert_eq!(messages(&new.validate_update(&old)), [r#"spec.clusterIP: Invalid value: "10.0.0.2": field is immutable"#]);
+}
+
+#[test]
+fn config_map() {
+    let config_map: ConfigMap = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "config" },
+        "data": { "config.yaml": "a: b", "..data": "", "key": "" },
+        "binaryData": { "key": "" },
+    })).expect("couldn't deserialize ConfigMap");
+    assert_eq!(messages(&config_map.validate()), [
+        r#"data['..data']: Invalid value: "..data": must not start with '..'"#,
+        r#"data.key: Invalid value: "key": duplicate of key present in binaryData"#,
+        r#"binaryData.key: Invalid value: "key": duplicate of key present in data"#,
+    ]);
+
+    let config_map: ConfigMap = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "config" },
+        "data": { "a": "a".repeat(1024 * 1024), "b": "b" },
+    })).expect("couldn't deserialize ConfigMap");
+    assert_eq!(messages(&config_map.validate()), [": Too long: may not be more than 1048576 bytes"]);
+
+    let old: ConfigMap = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "config" },
+        "data": { "a": "a" },
+        "immutable": true,
+    })).expect("couldn't deserialize ConfigMap");
+    let mut new = old.clone();
+    new.data.as_mut().unwrap().insert("b".to_owned(), "b".to_owned());
+    assert_eq!(messages(&new.validate_update(&old)), ["data: Forbidden: field is immutable when `immutable` is set"]);
+}
+
+#[test]
+fn secret() {
+    let secret: Secret = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "tls" },
+        "type": "kubernetes.io/tls",
+        "stringData": { "tls.crt": "" },
+    })).expect("couldn't deserialize Secret");
+    assert_eq!(messages(&secret.validate()), ["data['tls.key']: Required value"]);
+
+    let secret: Secret = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "token" },
+        "type": "kubernetes.io/service-account-token",
+    })).expect("couldn't deserialize Secret");
+    assert_eq!(messages(&secret.validate()), ["metadata.annotations['kubernetes.io/service-account.name']: Required value"]);
+
+    let old: Secret = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "secret" },
+        "data": { "password": "aHVudGVyMg==" },
+    })).expect("couldn't deserialize Secret");
+    let mut new = old.clone();
+    new.type_ = Some("kubernetes.io/basic-auth".to_owned());
+    assert_eq!(messages(&new.validate_update(&old)), [r#"type: Invalid value: "kubernetes.io/basic-auth": field is immutable"#]);
+}
+
+#[test]
+fn ingress() {
+    let ingress: Ingress = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "ingress" },
+        "spec": {
+            "ingressClassName": "nginx",
+            "rules": [{
+                "host": "*.example.com",
+                "http": { "paths": [{ "path": "/", "pathType": "Prefix", "backend": { "service": { "name": "web", "port": { "name": "http" } } } }] },
+            }],
+            "tls": [{ "hosts": ["*.example.com"], "secretName": "tls" }],
+        },
+    })).expect("couldn't deserialize Ingress");
+    assert_eq!(messages(&ingress.validate()), Vec::<String>::new());
+
+    let ingress: Ingress = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "ingress" },
+        "spec": {
+            "rules": [{
+                "host": "10.0.0.1",
+                "http": { "paths": [
+                    { "path": "api", "pathType": "Exact", "backend": { "service": { "name": "api", "port": { "name": "http", "number": 80 } } } },
+                    { "path": "/", "pathType": "", "backend": {} },
+                ] },
+            }],
+        },
+    })).expect("couldn't deserialize Ingress");
+    assert_eq!(messages(&ingress.validate()), [
+        r#"spec.rules[0].host: Invalid value: "10.0.0.1": must be a DNS name, not an IP address"#,
+        r#"spec.rules[0].http.paths[0].path: Invalid value: "api": must be an absolute path"#,
+        r#"spec.rules[0].http.paths[0].backend.service.port: Invalid value: "": cannot set both port name & port number"#,
+        "spec.rules[0].http.paths[1].pathType: Required value: pathType must be specified",
+        r#"spec.rules[0].http.paths[1].backend: Invalid value: "": resource or service backend is required"#,
+    ]);
+
+    let ingress: Ingress = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "ingress" },
+        "spec": {},
+    })).expect("couldn't deserialize Ingress");
+    assert_eq!(messages(&ingress.validate()), ["spec: Invalid value: []: either `defaultBackend` or `rules` must be specified"]);
+}
+
+#[test]
+fn rbac() {
+    let role: Role = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "role", "namespace": "default" },
+        "rules": [
+            { "apiGroups": [""], "resources": ["pods"], "verbs": ["get", "list"] },
+            { "nonResourceURLs": ["/healthz"], "verbs": ["get"] },
+            { "apiGroups": ["apps"], "verbs": [] },
+        ],
+    })).expect("couldn't deserialize Role");
+    assert_eq!(messages(&role.validate()), [
+        r#"rules[1].nonResourceURLs: Invalid value: ["/healthz"]: namespaced rules cannot apply to non-resource URLs"#,
+        "rules[2].verbs: Required value: verbs must contain at least one value",
+        "rules[2].resources: Required value: resource rules must supply at least one resource",
+    ]);
+
+    let cluster_role: ClusterRole = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "system:role", "namespace": "default" },
+        "aggregationRule": {},
+        "rules": [{ "nonResourceURLs": ["/healthz"], "verbs": ["get"] }],
+    })).expect("couldn't deserialize ClusterRole");
+    assert_eq!(messages(&cluster_role.validate()), [
+        "metadata.namespace: Forbidden: not allowed on this type",
+        "aggregationRule.clusterRoleSelectors: Required value: at least one clusterRoleSelector required if aggregationRule is non-nil",
+    ]);
+
+    let role_binding: RoleBinding = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "role-binding", "namespace": "default" },
+        "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "role" },
+        "subjects": [
+            { "kind": "ServiceAccount", "name": "default" },
+            { "kind": "User", "name": "user" },
+            { "kind": "Robot", "name": "robot" },
+        ],
+    })).expect("couldn't deserialize RoleBinding");
+    assert_eq!(messages(&role_binding.validate()), [
+        r#"subjects[1].apiGroup: Unsupported value: "": supported values: "rbac.authorization.k8s.io""#,
+        r#"subjects[2].kind: Unsupported value: "Robot": supported values: "ServiceAccount", "User", "Group""#,
+    ]);
+
+    let mut new = role_binding.clone();
+    new.role_ref.name = "other-role".to_owned();
+    assert!(messages(&new.validate_update(&role_binding)).contains(
+        &r#"roleRef: Invalid value: {"apiGroup":"rbac.authorization.k8s.io","kind":"Role","name":"other-role"}: cannot change roleRef"#.to_owned(),
+    ));
+
+    let cluster_role_binding: ClusterRoleBinding = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": "cluster-role-binding" },
+        "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "role" },
+        "subjects": [{ "kind": "ServiceAccount", "name": "default" }],
+    })).expect("couldn't deserialize ClusterRoleBinding");
+    assert_eq!(messages(&cluster_role_binding.validate()), [
+        r#"roleRef.kind: Unsupported value: "Role": supported values: "ClusterRole""#,
+        "subjects[0].namespace: Required value",
+    ]);
+}
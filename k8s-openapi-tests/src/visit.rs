@@ -0,0 +1,113 @@
+use k8s_openapi::visit::{Visit, Visitable};
+use k8s_openapi::visit_mut::{VisitMut, VisitableMut};
+
+fn pod(name: &str, images: &[&str]) -> k8s_openapi::api::core::v1::Pod {
+    let containers: Vec<_> = images.iter().enumerate().map(|(i, image)| serde_json::json!({
+        "name": format!("c{i}"),
+        "image": image,
+        "resources": { "limits": { "cpu": "500m", "memory": "1Gi" } },
+    })).collect();
+
+    serde_json::from_value(serde_json::json!({
+        "metadata": { "name": name },
+        "spec": {
+            "containers": containers,
+            "initContainers": [{ "name": "init", "image": "init:1" }],
+        },
+    })).expect("couldn't deserialize Pod")
+}
+
+#[derive(Default)]
+struct Collect<'ast> {
+    images: Vec<&'ast str>,
+    quantities: Vec<&'ast str>,
+    names: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for Collect<'ast> {
+    fn visit_api_core_v1_container(&mut self, node: &'ast k8s_openapi::api::core::v1::Container) {
+        self.images.extend(node.image.as_deref());
+        k8s_openapi::visit::visit_api_core_v1_container(self, node);
+    }
+
+    fn visit_apimachinery_pkg_api_resource_quantity(&mut self, node: &'ast k8s_openapi::apimachinery::pkg::api::resource::Quantity) {
+        self.quantities.push(&node.0);
+    }
+
+    fn visit_apimachinery_pkg_apis_meta_v1_object_meta(&mut self, node: &'ast k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta) {
+        self.names.extend(node.name.as_deref());
+    }
+}
+
+#[test]
+fn visit() {
+    let pod = pod("a", &["app:1", "sidecar:1"]);
+    let mut collect = Collect::default();
+    pod.visit(&mut collect);
+    assert_eq!(collect.images, ["app:1", "sidecar:1", "init:1"]);
+    assert_eq!(collect.quantities, ["500m", "1Gi", "500m", "1Gi"]);
+    assert_eq!(collect.names, ["a"]);
+
+    let list = k8s_openapi::List {
+        items: vec![pod, self::pod("b", &["app:2"])],
+        ..Default::default()
+    };
+    let mut collect = Collect::default();
+    list.visit(&mut collect);
+    assert_eq!(collect.images, ["app:1", "sidecar:1", "init:1", "app:2", "init:1"]);
+    assert_eq!(collect.names, ["a", "b"]);
+
+    let any_resource = k8s_openapi::AnyResource::from(self::pod("c", &["app:3"]));
+    let mut collect = Collect::default();
+    any_resource.visit(&mut collect);
+    assert_eq!(collect.images, ["app:3", "init:1"]);
+    assert_eq!(collect.names, ["c"]);
+}
+
+#[test]
+fn visit_json_schema_props() {
+    struct Types<'ast>(Vec<&'ast str>);
+
+    impl<'ast> Visit<'ast> for Types<'ast> {
+        fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(
+            &mut self,
+            node: &'ast k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps,
+        ) {
+            self.0.extend(node.type_.as_deref());
+            k8s_openapi::visit::visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(self, node);
+        }
+    }
+
+    let schema: k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps = serde_json::from_value(serde_json::json!({
+        "type": "object",
+        "properties": {
+            "a": { "type": "array", "items": { "type": "integer" } },
+            "b": { "type": "object", "additionalProperties": { "type": "boolean" } },
+        },
+    })).expect("couldn't deserialize JSONSchemaProps");
+
+    let mut types = Types(vec![]);
+    schema.visit(&mut types);
+    assert_eq!(types.0, ["object", "array", "integer", "object", "boolean"]);
+}
+
+#[test]
+fn visit_mut() {
+    struct PinImages;
+
+    impl VisitMut for PinImages {
+        fn visit_api_core_v1_container_mut(&mut self, node: &mut k8s_openapi::api::core::v1::Container) {
+            if let Some(image) = &mut node.image {
+                image.push_str("@sha256:0");
+            }
+            k8s_openapi::visit_mut::visit_api_core_v1_container_mut(self, node);
+        }
+    }
+
+    let mut pod = pod("a", &["app:1"]);
+    pod.visit_mut(&mut PinImages);
+
+    let spec = pod.spec.expect("pod has a spec");
+    assert_eq!(spec.containers[0].image.as_deref(), Some("app:1@sha256:0"));
+    assert_eq!(spec.init_containers.expect("pod has init containers")[0].image.as_deref(), Some("init:1@sha256:0"));
+}
@@ -0,0 +1,220 @@
+use k8s_openapi::yaml;
+use k8s_openapi::serde_json;
+
+#[test]
+fn documents() {
+    use k8s_openapi::api::{apps::v1 as apps, core::v1 as api};
+
+    let manifest = r"
+# Comments and empty documents are skipped.
+---
+apiVersion: v1
+kind: Namespace
+metadata:
+  name: foo
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: bar
+  namespace: foo
+spec:
+  selector:
+    matchLabels:
+      app: bar
+  template:
+    metadata:
+      labels:
+        app: bar
+    spec:
+      containers:
+      - name: bar
+        image: bar:1.0
+---
+apiVersion: example.com/v1
+kind: Foo
+metadata:
+  name: baz
+spec:
+  replicas: 3
+";
+
+    let resources = yaml::from_str(manifest).unwrap();
+    assert_eq!(resources.len(), 3);
+
+    let k8s_openapi::AnyResource::CoreV1Namespace(namespace) = &resources[0] else { panic!("{:?}", resources[0]); };
+    assert_eq!(namespace, &api::Namespace {
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("foo".to_owned()),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    let k8s_openapi::AnyResource::AppsV1Deployment(deployment) = &resources[1] else { panic!("{:?}", resources[1]); };
+    let _: &apps::Deployment = deployment;
+    assert_eq!(deployment.metadata.namespace.as_deref(), Some("foo"));
+    assert_eq!(deployment.spec.as_ref().unwrap().template.spec.as_ref().unwrap().containers[0].image.as_deref(), Some("bar:1.0"));
+
+    let k8s_openapi::AnyResource::Other(other) = &resources[2] else { panic!("{:?}", resources[2]); };
+    assert_eq!(other, &k8s_openapi::DynamicObject {
+        api_version: "example.com/v1".to_owned(),
+        kind: "Foo".to_owned(),
+        metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+            name: Some("baz".to_owned()),
+            ..Default::default()
+        },
+        data: [("spec".to_owned(), serde_json::json!({ "replicas": 3 }))].into_iter().collect(),
+    });
+
+    assert_eq!(resources.iter().map(|resource| (resource.api_version(), resource.kind())).collect::<Vec<_>>(), [
+        ("v1", "Namespace"),
+        ("apps/v1", "Deployment"),
+        ("example.com/v1", "Foo"),
+    ]);
+    assert_eq!(resources[2].metadata().name.as_deref(), Some("baz"));
+
+    // Resources serialize back to the original document.
+    assert_eq!(serde_json::to_value(&resources[2]).unwrap(), serde_json::json!({
+        "apiVersion": "example.com/v1",
+        "kind": "Foo",
+        "metadata": { "name": "baz" },
+        "spec": { "replicas": 3 },
+    }));
+}
+
+#[test]
+fn list() {
+    let manifest = r"
+apiVersion: v1
+kind: List
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: a
+- apiVersion: v1
+  kind: Secret
+  metadata:
+    name: b
+---
+apiVersion: v1
+kind: ServiceAccount
+metadata:
+  name: c
+---
+apiVersion: v1
+kind: ConfigMapList
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: d
+";
+
+    let resources = yaml::from_str(manifest).unwrap();
+    assert_eq!(
+        resources.iter().map(|resource| (resource.kind(), resource.metadata().name.as_deref().unwrap())).collect::<Vec<_>>(),
+        [("ConfigMap", "a"), ("Secret", "b"), ("ServiceAccount", "c"), ("ConfigMap", "d")],
+    );
+    assert!(matches!(resources[0], k8s_openapi::AnyResource::CoreV1ConfigMap(_)));
+}
+
+#[test]
+fn errors() {
+    let manifest = r"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: a
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: b
+data:
+  key: [1, 2]
+---
+kind: ConfigMap
+metadata:
+  name: c
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: d
+";
+
+    let err = yaml::from_str(manifest).unwrap_err();
+    assert_eq!(err.document_index, 1);
+    assert_eq!(err.line(), Some(12));
+    assert!(err.to_string().starts_with("document 1: "), "{err}");
+
+    // The iterator continues after errors.
+    let results: Vec<_> = yaml::Documents::new(manifest).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().metadata().name.as_deref(), Some("a"));
+    assert_eq!(results[1].as_ref().unwrap_err().document_index, 1);
+    let err = results[2].as_ref().unwrap_err();
+    assert_eq!(err.document_index, 2);
+    assert_eq!(err.line(), Some(14));
+    assert!(err.to_string().contains("missing field `apiVersion`"), "{err}");
+    assert_eq!(results[3].as_ref().unwrap().metadata().name.as_deref(), Some("d"));
+}
+
+#[test]
+fn list_errors() {
+    use k8s_openapi::field_validation::{FieldValidation, InvalidField, InvalidFieldKind};
+
+    let manifest = r"
+apiVersion: v1
+kind: List
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: a
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: b
+  data:
+    key: [1, 2]
+";
+
+    // Errors in list items have the location and path of the item's field.
+    let err = yaml::from_str(manifest).unwrap_err();
+    assert_eq!(err.document_index, 0);
+    assert_eq!(err.line(), Some(14));
+    assert!(err.to_string().contains("items[1].data.key: "), "{err}");
+
+    let manifest = r"
+apiVersion: v1
+kind: List
+items:
+- kind: ConfigMap
+  metadata:
+    name: a
+";
+
+    let err = yaml::from_str(manifest).unwrap_err();
+    assert_eq!(err.line(), Some(5));
+    assert!(err.to_string().contains("items[0]: missing field `apiVersion`"), "{err}");
+
+    // Duplicate fields of list items are detected.
+    let manifest = r"
+apiVersion: v1
+kind: List
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: a
+    name: b
+";
+
+    let resources = yaml::from_str_with_field_validation(manifest, FieldValidation::Warn).unwrap();
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].0.metadata().name.as_deref(), Some("b"));
+    assert_eq!(resources[0].1, [InvalidField { kind: InvalidFieldKind::Duplicate, path: "metadata.name".to_owned() }]);
+}
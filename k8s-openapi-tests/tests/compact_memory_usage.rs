@@ -0,0 +1,279 @@
+// This test is in its own test target because it replaces the global allocator with one that counts allocations,
+// and the counts are only meaningful if no other test is running in the same process.
+
+#![deny(rust_2018_idioms, warnings)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::too_many_lines,
+)]
+
+use k8s_openapi::serde_json;
+
+/// Measures the heap memory used by a cache of pods in the owned and compact types, with and without `strip_for_cache()`.
+///
+/// Run with `cargo test -p k8s-openapi-tests --test compact_memory_usage -- --ignored --nocapture`
+#[test]
+#[ignore = "deserializes tens of thousands of pods, which is slow in debug builds"]
+fn memory_usage() {
+    use k8s_openapi::api::core::v1 as api;
+    use k8s_openapi::compact::api::core::v1 as compact;
+
+    const NUM_PODS: usize = 10_000;
+
+    /// Returns the number of bytes and the number of allocations used by the cache.
+    fn measure<T>(corpus: &[std::string::String], f: impl Fn(&str) -> T) -> (usize, usize) {
+        let bytes_before = ALLOCATOR.bytes.load(std::sync::atomic::Ordering::SeqCst);
+        let allocations_before = ALLOCATOR.allocations.load(std::sync::atomic::Ordering::SeqCst);
+        let cache: Vec<T> = corpus.iter().map(|pod| f(pod)).collect();
+        let bytes_after = ALLOCATOR.bytes.load(std::sync::atomic::Ordering::SeqCst);
+        let allocations_after = ALLOCATOR.allocations.load(std::sync::atomic::Ordering::SeqCst);
+        drop(cache);
+        (bytes_after - bytes_before, allocations_after - allocations_before)
+    }
+
+    let corpus: Vec<_> = (0..NUM_PODS).map(|i| serde_json::to_string(&corpus_pod(i)).unwrap()).collect();
+
+    // Intern the keys up-front so that the interner's own allocations aren't counted.
+    let _: Vec<compact::Pod> = corpus.iter().map(|pod| serde_json::from_str(pod).unwrap()).collect();
+
+    let owned = measure(&corpus, |pod| serde_json::from_str::<api::Pod>(pod).unwrap());
+    let owned_stripped = measure(&corpus, |pod| {
+        let mut pod: api::Pod = serde_json::from_str(pod).unwrap();
+        pod.metadata.strip_for_cache();
+        pod
+    });
+    let compact = measure(&corpus, |pod| serde_json::from_str::<compact::Pod>(pod).unwrap());
+    let compact_stripped = measure(&corpus, |pod| {
+        let mut pod: compact::Pod = serde_json::from_str(pod).unwrap();
+        pod.metadata.strip_for_cache();
+        pod
+    });
+
+    #[allow(clippy::cast_precision_loss)]
+    let print = |name: &str, (bytes, allocations): (usize, usize)| println!(
+        "{name:>17}: {:>5} B per pod ({:>5.1}% of owned), {:>4} allocations per pod ({:>5.1}% of owned)",
+        bytes / NUM_PODS, bytes as f64 * 100. / owned.0 as f64,
+        allocations / NUM_PODS, allocations as f64 * 100. / owned.1 as f64,
+    );
+    print("owned", owned);
+    print("owned, stripped", owned_stripped);
+    print("compact", compact);
+    print("compact, stripped", compact_stripped);
+
+    assert!(compact.0 < owned.0);
+    assert!(compact_stripped.0 < owned_stripped.0);
+}
+
+/// A pod as created by a Deployment and applied with `kubectl apply`, varying the pod-specific fields by `i`.
+fn corpus_pod(i: usize) -> serde_json::Value {
+    let app = format!("app-{}", i % 50);
+    let hash = format!("{:010x}", i % 500);
+    let name = format!("{app}-{hash}-{i:05x}");
+    let uid = format!("{i:08x}-0000-4000-8000-{i:012x}");
+
+    let labels = serde_json::json!({
+        "app.kubernetes.io/name": app,
+        "app.kubernetes.io/instance": app,
+        "app.kubernetes.io/managed-by": "Helm",
+        "app.kubernetes.io/part-of": "example",
+        "pod-template-hash": hash,
+    });
+
+    let container = |name: &str, image: &str| serde_json::json!({
+        "name": name,
+        "image": image,
+        "imagePullPolicy": "IfNotPresent",
+        "args": ["--port=8080", "--log-level=info"],
+        "env": [
+            { "name": "POD_NAME", "valueFrom": { "fieldRef": { "apiVersion": "v1", "fieldPath": "metadata.name" } } },
+            { "name": "POD_NAMESPACE", "valueFrom": { "fieldRef": { "apiVersion": "v1", "fieldPath": "metadata.namespace" } } },
+            { "name": "LOG_FORMAT", "value": "json" },
+        ],
+        "ports": [{ "name": "http", "containerPort": 8080, "protocol": "TCP" }],
+        "resources": {
+            "limits": { "cpu": "500m", "memory": "256Mi" },
+            "requests": { "cpu": "100m", "memory": "128Mi" },
+        },
+        "volumeMounts": [
+            { "name": "config", "mountPath": "/etc/app" },
+            { "name": "kube-api-access", "mountPath": "/var/run/secrets/kubernetes.io/serviceaccount", "readOnly": true },
+        ],
+        "terminationMessagePath": "/dev/termination-log",
+        "terminationMessagePolicy": "File",
+    });
+
+    let container_status = |name: &str, image: &str| serde_json::json!({
+        "name": name,
+        "image": image,
+        "imageID": format!("docker.io/library/{image}@sha256:{:064x}", i % 50),
+        "containerID": format!("containerd://{:064x}", i),
+        "ready": true,
+        "restartCount": 0,
+        "started": true,
+        "state": { "running": { "startedAt": "2024-01-01T00:00:00Z" } },
+    });
+
+    let spec = serde_json::json!({
+        "containers": [container("app", "example/app:1.2.3"), container("sidecar", "example/proxy:4.5.6")],
+        "dnsPolicy": "ClusterFirst",
+        "enableServiceLinks": true,
+        "nodeName": format!("node-{}", i % 100),
+        "preemptionPolicy": "PreemptLowerPriority",
+        "priority": 0,
+        "restartPolicy": "Always",
+        "schedulerName": "default-scheduler",
+        "securityContext": {},
+        "serviceAccount": "default",
+        "serviceAccountName": "default",
+        "terminationGracePeriodSeconds": 30,
+        "tolerations": [
+            { "effect": "NoExecute", "key": "node.kubernetes.io/not-ready", "operator": "Exists", "tolerationSeconds": 300 },
+            { "effect": "NoExecute", "key": "node.kubernetes.io/unreachable", "operator": "Exists", "tolerationSeconds": 300 },
+        ],
+        "volumes": [
+            { "name": "config", "configMap": { "name": app, "defaultMode": 420 } },
+            { "name": "kube-api-access", "projected": { "defaultMode": 420, "sources": [
+                { "serviceAccountToken": { "expirationSeconds": 3607, "path": "token" } },
+                { "configMap": { "name": "kube-root-ca.crt", "items": [{ "key": "ca.crt", "path": "ca.crt" }] } },
+            ] } },
+        ],
+    });
+
+    let last_applied_configuration = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": { "labels": labels, "name": name, "namespace": "default" },
+        "spec": { "containers": [
+            { "name": "app", "image": "example/app:1.2.3" },
+            { "name": "sidecar", "image": "example/proxy:4.5.6" },
+        ] },
+    });
+
+    let managed_field = |manager: &str, operation: &str, subresource: Option<&str>, fields: serde_json::Value| {
+        let mut entry = serde_json::json!({
+            "apiVersion": "v1",
+            "fieldsType": "FieldsV1",
+            "fieldsV1": fields,
+            "manager": manager,
+            "operation": operation,
+            "time": "2024-01-01T00:00:00Z",
+        });
+        if let Some(subresource) = subresource {
+            entry["subresource"] = subresource.into();
+        }
+        entry
+    };
+
+    serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": name,
+            "generateName": format!("{app}-{hash}-"),
+            "namespace": "default",
+            "uid": uid,
+            "resourceVersion": (1_000_000 + i).to_string(),
+            "creationTimestamp": "2024-01-01T00:00:00Z",
+            "labels": labels,
+            "annotations": {
+                "kubectl.kubernetes.io/last-applied-configuration": last_applied_configuration.to_string(),
+                "prometheus.io/scrape": "true",
+                "prometheus.io/port": "8080",
+            },
+            "ownerReferences": [{
+                "apiVersion": "apps/v1",
+                "kind": "ReplicaSet",
+                "name": format!("{app}-{hash}"),
+                "uid": format!("{:08x}-0000-4000-8000-{:012x}", i % 500, i % 500),
+                "controller": true,
+                "blockOwnerDeletion": true,
+            }],
+            "managedFields": [
+                managed_field("kube-controller-manager", "Update", None, serde_json::json!({
+                    "f:metadata": {
+                        "f:generateName": {},
+                        "f:labels": { ".": {}, "f:app.kubernetes.io/instance": {}, "f:app.kubernetes.io/name": {}, "f:pod-template-hash": {} },
+                        "f:ownerReferences": { ".": {}, format!("k:{{\"uid\":\"{uid}\"}}"): {} },
+                    },
+                    "f:spec": {
+                        "f:containers": {
+                            "k:{\"name\":\"app\"}": { ".": {}, "f:image": {}, "f:imagePullPolicy": {}, "f:name": {}, "f:resources": {} },
+                            "k:{\"name\":\"sidecar\"}": { ".": {}, "f:image": {}, "f:imagePullPolicy": {}, "f:name": {}, "f:resources": {} },
+                        },
+                        "f:dnsPolicy": {},
+                        "f:restartPolicy": {},
+                        "f:schedulerName": {},
+                        "f:terminationGracePeriodSeconds": {},
+                        "f:volumes": { ".": {}, "k:{\"name\":\"config\"}": { ".": {}, "f:configMap": {}, "f:name": {} } },
+                    },
+                })),
+                managed_field("kubelet", "Update", Some("status"), serde_json::json!({
+                    "f:status": {
+                        "f:conditions": {
+                            "k:{\"type\":\"ContainersReady\"}": { ".": {}, "f:status": {}, "f:type": {} },
+                            "k:{\"type\":\"Initialized\"}": { ".": {}, "f:status": {}, "f:type": {} },
+                            "k:{\"type\":\"Ready\"}": { ".": {}, "f:status": {}, "f:type": {} },
+                        },
+                        "f:containerStatuses": {},
+                        "f:hostIP": {},
+                        "f:phase": {},
+                        "f:podIP": {},
+                        "f:podIPs": { ".": {}, format!("k:{{\"ip\":\"10.0.{}.{}\"}}", i / 256 % 256, i % 256): { ".": {}, "f:ip": {} } },
+                        "f:startTime": {},
+                    },
+                })),
+            ],
+        },
+        "spec": spec,
+        "status": {
+            "phase": "Running",
+            "hostIP": format!("192.168.0.{}", i % 100),
+            "podIP": format!("10.0.{}.{}", i / 256 % 256, i % 256),
+            "podIPs": [{ "ip": format!("10.0.{}.{}", i / 256 % 256, i % 256) }],
+            "qosClass": "Burstable",
+            "startTime": "2024-01-01T00:00:00Z",
+            "conditions": [
+                { "type": "Initialized", "status": "True", "lastTransitionTime": "2024-01-01T00:00:00Z" },
+                { "type": "Ready", "status": "True", "lastTransitionTime": "2024-01-01T00:00:00Z" },
+                { "type": "ContainersReady", "status": "True", "lastTransitionTime": "2024-01-01T00:00:00Z" },
+                { "type": "PodScheduled", "status": "True", "lastTransitionTime": "2024-01-01T00:00:00Z" },
+            ],
+            "containerStatuses": [
+                container_status("app", "example/app:1.2.3"),
+                container_status("sidecar", "example/proxy:4.5.6"),
+            ],
+        },
+    })
+}
+
+/// An allocator that counts the bytes and allocations that are currently allocated, for the `memory_usage` test.
+struct CountingAllocator {
+    bytes: std::sync::atomic::AtomicUsize,
+    allocations: std::sync::atomic::AtomicUsize,
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator {
+    bytes: std::sync::atomic::AtomicUsize::new(0),
+    allocations: std::sync::atomic::AtomicUsize::new(0),
+};
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        self.bytes.fetch_add(layout.size(), std::sync::atomic::Ordering::SeqCst);
+        self.allocations.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        unsafe { std::alloc::System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        self.bytes.fetch_sub(layout.size(), std::sync::atomic::Ordering::SeqCst);
+        self.allocations.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+        unsafe { std::alloc::System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        self.bytes.fetch_add(new_size, std::sync::atomic::Ordering::SeqCst);
+        self.bytes.fetch_sub(layout.size(), std::sync::atomic::Ordering::SeqCst);
+        unsafe { std::alloc::System.realloc(ptr, layout, new_size) }
+    }
+}
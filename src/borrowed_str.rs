@@ -0,0 +1,97 @@
+/// A string that borrows from the input it was deserialized from where possible.
+///
+/// Used in the borrowed variants of the Kubernetes types in the `borrowed` module. Deserializers like `serde_json::from_slice` can only lend out
+/// strings that don't contain escape sequences, so the string is owned otherwise.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BorrowedStr<'a>(pub std::borrow::Cow<'a, str>);
+
+impl BorrowedStr<'_> {
+    /// Returns the string, copying it if it is borrowed.
+    pub fn into_owned(self) -> std::string::String {
+        self.0.into_owned()
+    }
+}
+
+impl core::ops::Deref for BorrowedStr<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for BorrowedStr<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::borrow::Borrow<str> for BorrowedStr<'_> {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for BorrowedStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> From<&'a str> for BorrowedStr<'a> {
+    fn from(s: &'a str) -> Self {
+        BorrowedStr(std::borrow::Cow::Borrowed(s))
+    }
+}
+
+impl From<std::string::String> for BorrowedStr<'_> {
+    fn from(s: std::string::String) -> Self {
+        BorrowedStr(std::borrow::Cow::Owned(s))
+    }
+}
+
+impl PartialEq<str> for BorrowedStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<&str> for BorrowedStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for BorrowedStr<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = std::borrow::Cow<'de, str>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(std::borrow::Cow::Borrowed(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(std::borrow::Cow::Owned(v.into()))
+            }
+
+            fn visit_string<E>(self, v: std::string::String) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(std::borrow::Cow::Owned(v))
+            }
+        }
+
+        Ok(BorrowedStr(deserializer.deserialize_str(Visitor)?))
+    }
+}
+
+impl serde::Serialize for BorrowedStr<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(&self.0)
+    }
+}
@@ -1,9 +1,18 @@
 /// A wrapper around a list of bytes.
 ///
 /// Used in Kubernetes types whose JSON representation uses a base64-encoded string for a list of bytes.
+///
+/// It serializes as a base64-encoded string with every serializer, except the one in the `cbor` module which encodes it as a byte string
+/// the way the API server does.
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct ByteString(pub std::vec::Vec<u8>);
 
+/// The name of the newtype struct that `ByteString` serializes and deserializes as.
+///
+/// Serializers and deserializers in general treat newtype structs as their inner value, so this has no effect on them.
+/// The ones in the `cbor` module recognize it and use a byte string instead of the inner base64-encoded string.
+pub(crate) const NEWTYPE_STRUCT_NAME: &str = "$k8s_openapi::ByteString";
+
 impl<'de> serde::Deserialize<'de> for ByteString {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
         struct Visitor;
@@ -15,6 +24,10 @@ impl<'de> serde::Deserialize<'de> for ByteString {
                 formatter.write_str("a base64-encoded string")
             }
 
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+                deserializer.deserialize_option(self)
+            }
+
             fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
                 Ok(ByteString(std::vec![]))
             }
@@ -27,15 +40,23 @@ impl<'de> serde::Deserialize<'de> for ByteString {
                 let v = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, v).map_err(serde::de::Error::custom)?;
                 Ok(ByteString(v))
             }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(ByteString(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E>(self, v: std::vec::Vec<u8>) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(ByteString(v))
+            }
         }
 
-        deserializer.deserialize_option(Visitor)
+        deserializer.deserialize_newtype_struct(NEWTYPE_STRUCT_NAME, Visitor)
     }
 }
 
 impl serde::Serialize for ByteString {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
         let s = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &self.0);
-        s.serialize(serializer)
+        serializer.serialize_newtype_struct(NEWTYPE_STRUCT_NAME, &s)
     }
 }
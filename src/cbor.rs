@@ -0,0 +1,613 @@
+//! CBOR encoding of Kubernetes types.
+//!
+//! The API server supports the `application/cbor` content type as an alternative to JSON. The encoding is the CBOR data model of the JSON value,
+//! with some additional rules that the API server's encoder follows and that its decoder expects:
+//!
+//! - The value is prefixed with the self-described CBOR tag (55799), ie the bytes `d9 d9 f7`.
+//!
+//! - Strings, including map keys, are encoded as byte strings rather than text strings. Text strings are also accepted when decoding.
+//!
+//! - Byte slices, ie [`ByteString`](crate::ByteString) values, are encoded as byte strings enclosed in the "expected later base64 encoding" tag (22),
+//!   which distinguishes them from strings.
+//!
+//! - Map keys are sorted in bytewise lexical order of their encoding, as required by the "Core Deterministic Encoding Requirements"
+//!   of RFC 8949 section 4.2.1. Duplicate map keys are rejected when decoding.
+//!
+//! - Floats are encoded in the shortest form that preserves their value. NaN and infinite values cannot be encoded.
+//!
+//! Use [`to_vec`] and [`from_slice`] to convert a value to and from this format.
+//!
+//! Other serde backends for CBOR like `ciborium` do not implement these rules, so their output is not byte-for-byte identical to the API server's
+//! and they may not be able to decode the API server's output. In particular they encode [`ByteString`](crate::ByteString) values as base64-encoded
+//! strings, as in JSON.
+
+/// The content type of the CBOR encoding.
+pub const CONTENT_TYPE: &str = "application/cbor";
+
+/// The self-described CBOR tag that prefixes all CBOR-encoded values sent by the API server.
+pub const SELF_DESCRIBED_TAG: u64 = 55799;
+
+/// The "expected later base64 encoding" tag that encloses byte slices.
+const EXPECTED_BASE64_TAG: u64 = 22;
+
+/// The "standard date/time string" tag, which may enclose timestamps.
+const DATE_TIME_STRING_TAG: u64 = 0;
+
+/// Encodes the given value in the API server's CBOR format.
+pub fn to_vec<T>(value: &T) -> Result<std::vec::Vec<u8>, Error> where T: serde::Serialize + ?Sized {
+    let value = value.serialize(Serializer)?;
+    let value = ciborium::Value::Tag(SELF_DESCRIBED_TAG, std::boxed::Box::new(value));
+
+    let mut buf = std::vec![];
+    ciborium::into_writer(&value, &mut buf).map_err(|err| Error::Serialize(std::string::ToString::to_string(&err)))?;
+    Ok(buf)
+}
+
+/// Decodes a value from the API server's CBOR format.
+///
+/// The self-described CBOR tag prefix is optional.
+pub fn from_slice<T>(mut buf: &[u8]) -> Result<T, Error> where T: serde::de::DeserializeOwned {
+    let value: ciborium::Value = ciborium::from_reader(&mut buf).map_err(|err| Error::Deserialize(std::string::ToString::to_string(&err)))?;
+    if !buf.is_empty() {
+        return Err(Error::TrailingData);
+    }
+
+    T::deserialize(Deserializer::new(value)?)
+}
+
+/// An error from encoding or decoding a CBOR-encoded value.
+#[derive(Debug)]
+pub enum Error {
+    /// The value could not be serialized.
+    Serialize(std::string::String),
+
+    /// The value could not be deserialized.
+    Deserialize(std::string::String),
+
+    /// The value contains a NaN or infinite float.
+    NonFiniteFloat,
+
+    /// The value contains a tag that is not understood by the API server.
+    UnsupportedTag(u64),
+
+    /// The value contains a map with duplicate keys.
+    DuplicateMapKey,
+
+    /// The value is followed by more data.
+    TrailingData,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Serialize(err) => write!(f, "could not serialize value: {err}"),
+            Error::Deserialize(err) => write!(f, "could not deserialize value: {err}"),
+            Error::NonFiniteFloat => f.write_str("NaN and infinite floats cannot be encoded"),
+            Error::UnsupportedTag(tag) => write!(f, "unsupported CBOR tag {tag}"),
+            Error::DuplicateMapKey => f.write_str("duplicate map key"),
+            Error::TrailingData => f.write_str("trailing data after value"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T>(msg: T) -> Self where T: core::fmt::Display {
+        Error::Serialize(std::string::ToString::to_string(&msg))
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T>(msg: T) -> Self where T: core::fmt::Display {
+        Error::Deserialize(std::string::ToString::to_string(&msg))
+    }
+}
+
+
+/// Serializes a value into the one that the API server would produce.
+#[derive(Clone, Copy)]
+struct Serializer;
+
+impl serde::Serializer for Serializer {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeVariant<SerializeSeq>;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeVariant<SerializeMap>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Integer(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        let v = v.try_into().map_err(|_| Error::Serialize(std::format!("integer {v} is out of range")))?;
+        Ok(ciborium::Value::Integer(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Integer(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        let v = v.try_into().map_err(|_| Error::Serialize(std::format!("integer {v} is out of range")))?;
+        Ok(ciborium::Value::Integer(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        if !v.is_finite() {
+            return Err(Error::NonFiniteFloat);
+        }
+
+        Ok(ciborium::Value::Float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Bytes(v.as_bytes().to_vec()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Tag(EXPECTED_BASE64_TAG, std::boxed::Box::new(ciborium::Value::Bytes(v.to_vec()))))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Null)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        if name == crate::_byte_string::NEWTYPE_STRUCT_NAME {
+            // The inner value is the base64-encoded string of the bytes.
+            let ciborium::Value::Bytes(s) = value.serialize(self)? else {
+                return Err(Error::Serialize("ByteString did not serialize as a string".into()));
+            };
+            let v = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, s).map_err(|err| Error::Serialize(std::string::ToString::to_string(&err)))?;
+            return self.serialize_bytes(&v);
+        }
+
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> where T: serde::Serialize + ?Sized {
+        Ok(ciborium::Value::Map(std::vec![(self.serialize_str(variant)?, value.serialize(self)?)]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeSeq(std::vec::Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeVariant { variant, inner: self.serialize_seq(Some(len))? })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeMap { entries: std::vec::Vec::with_capacity(len.unwrap_or_default()), next_key: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeVariant { variant, inner: self.serialize_map(Some(len))? })
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error> where T: core::fmt::Display + ?Sized {
+        self.serialize_str(&std::string::ToString::to_string(value))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+struct SerializeSeq(std::vec::Vec<ciborium::Value>);
+
+impl serde::ser::SerializeSeq for SerializeSeq {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        self.0.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(ciborium::Value::Array(self.0))
+    }
+}
+
+impl serde::ser::SerializeTuple for SerializeSeq {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+struct SerializeMap {
+    entries: std::vec::Vec<(ciborium::Value, ciborium::Value)>,
+    next_key: Option<ciborium::Value>,
+}
+
+impl serde::ser::SerializeMap for SerializeMap {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        self.next_key = Some(key.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        let key = self.next_key.take().ok_or_else(|| Error::Serialize("map value serialized before its key".into()))?;
+        self.entries.push((key, value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        // Map keys are sorted in bytewise lexical order of their encoding.
+        let mut entries: std::vec::Vec<_> =
+            self.entries.into_iter()
+            .map(|(key, value)| {
+                let mut encoded_key = std::vec![];
+                ciborium::into_writer(&key, &mut encoded_key).map_err(|err| Error::Serialize(std::string::ToString::to_string(&err)))?;
+                Ok((encoded_key, key, value))
+            })
+            .collect::<Result<_, Error>>()?;
+        entries.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        if entries.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(Error::DuplicateMapKey);
+        }
+        Ok(ciborium::Value::Map(entries.into_iter().map(|(_, key, value)| (key, value)).collect()))
+    }
+}
+
+impl serde::ser::SerializeStruct for SerializeMap {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        serde::ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        serde::ser::SerializeMap::end(self)
+    }
+}
+
+/// Serializes the value of a tuple or struct variant, which is encoded as a map from the variant name to the value.
+struct SerializeVariant<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl serde::ser::SerializeTupleVariant for SerializeVariant<SerializeSeq> {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        serde::ser::SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let value = serde::ser::SerializeSeq::end(self.inner)?;
+        Ok(ciborium::Value::Map(std::vec![(serde::Serializer::serialize_str(Serializer, self.variant)?, value)]))
+    }
+}
+
+impl serde::ser::SerializeStructVariant for SerializeVariant<SerializeMap> {
+    type Ok = ciborium::Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> where T: serde::Serialize + ?Sized {
+        serde::ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let value = serde::ser::SerializeStruct::end(self.inner)?;
+        Ok(ciborium::Value::Map(std::vec![(serde::Serializer::serialize_str(Serializer, self.variant)?, value)]))
+    }
+}
+
+/// Deserializes a value produced by the API server.
+///
+/// Since the API server encodes strings as byte strings, untagged byte strings are deserialized as strings,
+/// except when the value being deserialized is a [`ByteString`](crate::ByteString) or bytes. Conversely,
+/// byte strings enclosed in the "expected later base64 encoding" tag are deserialized as bytes, except when the value being deserialized
+/// is a string, in which case they are deserialized as their base64 encoding like in JSON.
+enum Deserializer {
+    /// Any value except a byte string enclosed in the "expected later base64 encoding" tag. It does not have any tags.
+    Value(ciborium::Value),
+
+    /// A byte string enclosed in the "expected later base64 encoding" tag.
+    ByteSlice(std::vec::Vec<u8>),
+}
+
+impl Deserializer {
+    fn new(mut value: ciborium::Value) -> Result<Self, Error> {
+        loop {
+            value = match value {
+                ciborium::Value::Tag(EXPECTED_BASE64_TAG, value) => match *value {
+                    ciborium::Value::Bytes(b) => return Ok(Deserializer::ByteSlice(b)),
+                    value => value,
+                },
+
+                ciborium::Value::Tag(SELF_DESCRIBED_TAG | DATE_TIME_STRING_TAG, value) => *value,
+
+                ciborium::Value::Tag(tag, _) => return Err(Error::UnsupportedTag(tag)),
+
+                value => return Ok(Deserializer::Value(value)),
+            };
+        }
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Deserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        let value = match self {
+            Deserializer::Value(value) => value,
+            Deserializer::ByteSlice(b) => return visitor.visit_string(base64::Engine::encode(&base64::engine::general_purpose::STANDARD, b)),
+        };
+
+        match value {
+            ciborium::Value::Bytes(b) => match std::string::String::from_utf8(b) {
+                Ok(s) => visitor.visit_string(s),
+                Err(err) => visitor.visit_byte_buf(err.into_bytes()),
+            },
+
+            ciborium::Value::Text(s) => visitor.visit_string(s),
+
+            ciborium::Value::Integer(i) => {
+                let i = i128::from(i);
+                if let Ok(i) = u64::try_from(i) {
+                    visitor.visit_u64(i)
+                }
+                else if let Ok(i) = i64::try_from(i) {
+                    visitor.visit_i64(i)
+                }
+                else {
+                    visitor.visit_i128(i)
+                }
+            },
+
+            ciborium::Value::Float(f) => visitor.visit_f64(f),
+
+            ciborium::Value::Bool(b) => visitor.visit_bool(b),
+
+            ciborium::Value::Null => visitor.visit_unit(),
+
+            ciborium::Value::Array(values) => {
+                let len = values.len();
+                let mut seq = SeqAccess(values.into_iter());
+                let result = visitor.visit_seq(&mut seq)?;
+                if seq.0.len() != 0 {
+                    return Err(serde::de::Error::invalid_length(len, &"fewer elements in array"));
+                }
+                Ok(result)
+            },
+
+            ciborium::Value::Map(entries) => visitor.visit_map(MapAccess::new(entries)?),
+
+            value => Err(Error::Deserialize(std::format!("unsupported value {value:?}"))),
+        }
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        match self {
+            Deserializer::Value(ciborium::Value::Bytes(b)) | Deserializer::ByteSlice(b) => visitor.visit_byte_buf(b),
+            deserializer @ Deserializer::Value(_) => deserializer.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        match self {
+            Deserializer::Value(ciborium::Value::Null) => visitor.visit_none(),
+            deserializer => visitor.visit_some(deserializer),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        if name == crate::_byte_string::NEWTYPE_STRUCT_NAME {
+            match self {
+                // The bytes of a `ByteString` are used as is, even if they happen to be valid UTF-8.
+                Deserializer::Value(ciborium::Value::Bytes(b)) | Deserializer::ByteSlice(b) => visitor.visit_byte_buf(b),
+                deserializer @ Deserializer::Value(_) => visitor.visit_newtype_struct(deserializer),
+            }
+        }
+        else {
+            visitor.visit_newtype_struct(self)
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        match self {
+            // Tuple and struct variants are encoded as a map from the variant name to the value.
+            Deserializer::Value(ciborium::Value::Map(entries)) =>
+                visitor.visit_enum(serde::de::value::MapAccessDeserializer::new(MapAccess::new(entries)?)),
+
+            deserializer => {
+                let variant: std::string::String = serde::Deserialize::deserialize(deserializer)?;
+                visitor.visit_enum(serde::de::value::StrDeserializer::new(&variant))
+            },
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqAccess(std::vec::IntoIter<ciborium::Value>);
+
+impl<'de> serde::de::SeqAccess<'de> for SeqAccess {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> where T: serde::de::DeserializeSeed<'de> {
+        let Some(value) = self.0.next() else { return Ok(None); };
+        seed.deserialize(Deserializer::new(value)?).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct MapAccess {
+    entries: std::vec::IntoIter<(ciborium::Value, ciborium::Value)>,
+    next_value: Option<ciborium::Value>,
+}
+
+impl MapAccess {
+    fn new(entries: std::vec::Vec<(ciborium::Value, ciborium::Value)>) -> Result<Self, Error> {
+        let mut keys = std::collections::BTreeSet::new();
+        for (key, _) in &entries {
+            // Non-string keys are rejected by deserialization anyway.
+            let key = match key {
+                ciborium::Value::Bytes(key) => &**key,
+                ciborium::Value::Text(key) => key.as_bytes(),
+                _ => continue,
+            };
+            if !keys.insert(key) {
+                return Err(Error::DuplicateMapKey);
+            }
+        }
+
+        Ok(MapAccess { entries: entries.into_iter(), next_value: None })
+    }
+}
+
+impl<'de> serde::de::MapAccess<'de> for MapAccess {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> where K: serde::de::DeserializeSeed<'de> {
+        let Some((key, value)) = self.entries.next() else { return Ok(None); };
+        self.next_value = Some(value);
+        seed.deserialize(Deserializer::new(key)?).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        let value = self.next_value.take().ok_or_else(|| Error::Deserialize("map value deserialized before its key".into()))?;
+        seed.deserialize(Deserializer::new(value)?)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
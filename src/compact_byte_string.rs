@@ -0,0 +1,32 @@
+/// A wrapper around a reference-counted list of bytes.
+///
+/// Used in the compact variants of the Kubernetes types in the `compact` module instead of [`ByteString`](crate::ByteString).
+/// Cloning it does not copy the bytes.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CompactByteString(pub bytes::Bytes);
+
+impl From<crate::ByteString> for CompactByteString {
+    fn from(s: crate::ByteString) -> Self {
+        CompactByteString(s.0.into())
+    }
+}
+
+impl From<CompactByteString> for crate::ByteString {
+    fn from(s: CompactByteString) -> Self {
+        crate::ByteString(s.0.into())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CompactByteString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let crate::ByteString(v) = serde::Deserialize::deserialize(deserializer)?;
+        Ok(CompactByteString(v.into()))
+    }
+}
+
+impl serde::Serialize for CompactByteString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let s = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &self.0);
+        serializer.serialize_newtype_struct(crate::_byte_string::NEWTYPE_STRUCT_NAME, &s)
+    }
+}
@@ -0,0 +1,389 @@
+/// A trait applies to types whose unset fields can be filled in with the default values that the API server sets for them.
+///
+/// `value.set_defaults()` behaves in the following ways:
+///
+/// ## `struct`s
+///
+/// The structs in the `k8s-openapi` crate first set the defaults of their own fields, then set the defaults of the values of all their fields.
+/// The defaults are the ones that the `SetDefaults_*` functions of the API server's `pkg/apis/*/v1` packages set, eg a `PodSpec`'s `restartPolicy`
+/// defaults to `"Always"` and a `Container`'s `terminationMessagePath` defaults to `"/dev/termination-log"`.
+///
+/// Defaults that depend on the values of other fields, like a `Container`'s `imagePullPolicy` that depends on the tag of its `image`,
+/// are set by the functions in [`defaulting_strategies`](crate::defaulting_strategies).
+///
+/// Fields that already have a value are left unchanged, even if the value is not one the API server would accept.
+///
+/// ## `Option`, `Box`, `Vec` and `BTreeMap`
+///
+/// The defaults of the inner values, if any, are set. An unset optional value stays unset unless its parent sets a default for it.
+///
+/// ## Other types
+///
+/// The value is unchanged.
+///
+/// # Limitations
+///
+/// Defaults that the API server sets from its configuration or from other objects, like the `serviceAccountName` of a pod,
+/// the `clusterIP` of a service or the priority of a pod, are not set.
+pub trait Defaulting {
+    /// Fill in the unset fields of `self` with their defaults.
+    fn set_defaults(&mut self);
+}
+
+macro_rules! default_unchanged_impl {
+    () => {
+        fn set_defaults(&mut self) {}
+    };
+}
+
+impl Defaulting for bool { default_unchanged_impl! {} }
+impl Defaulting for i32 { default_unchanged_impl! {} }
+impl Defaulting for i64 { default_unchanged_impl! {} }
+impl Defaulting for f64 { default_unchanged_impl! {} }
+impl Defaulting for std::string::String { default_unchanged_impl! {} }
+impl Defaulting for crate::ByteString { default_unchanged_impl! {} }
+impl Defaulting for crate::Duration { default_unchanged_impl! {} }
+impl Defaulting for jiff::Timestamp { default_unchanged_impl! {} }
+impl Defaulting for serde_json::Value { default_unchanged_impl! {} }
+
+impl<T> Defaulting for std::boxed::Box<T> where T: Defaulting {
+    fn set_defaults(&mut self) {
+        (**self).set_defaults();
+    }
+}
+
+impl<T> Defaulting for Option<T> where T: Defaulting {
+    fn set_defaults(&mut self) {
+        if let Some(value) = self {
+            value.set_defaults();
+        }
+    }
+}
+
+impl<T> Defaulting for std::vec::Vec<T> where T: Defaulting {
+    fn set_defaults(&mut self) {
+        for value in self {
+            value.set_defaults();
+        }
+    }
+}
+
+impl<K, V> Defaulting for std::collections::BTreeMap<K, V> where V: Defaulting {
+    fn set_defaults(&mut self) {
+        for value in self.values_mut() {
+            value.set_defaults();
+        }
+    }
+}
+
+/// Functions that set the defaults of built-in types that depend on the values of other fields.
+///
+/// The [`Defaulting`] impls of the types call these after setting the defaults of their own fields,
+/// and before setting the defaults of the values of their fields.
+pub mod strategies {
+    /// Defaults of the `apps/v1` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/apps/v1/defaults.go>
+    pub mod apps_v1 {
+        /// A `RollingUpdate` strategy gets a `rollingUpdate`, whose defaults are then set.
+        pub fn daemon_set_update_strategy(strategy: &mut crate::api::apps::v1::DaemonSetUpdateStrategy) {
+            if strategy.type_.as_deref() == Some("RollingUpdate") && strategy.rolling_update.is_none() {
+                strategy.rolling_update = Some(Default::default());
+            }
+        }
+
+        /// A `RollingUpdate` strategy gets a `rollingUpdate`, whose defaults are then set.
+        pub fn deployment_strategy(strategy: &mut crate::api::apps::v1::DeploymentStrategy) {
+            if strategy.type_.as_deref() == Some("RollingUpdate") && strategy.rolling_update.is_none() {
+                strategy.rolling_update = Some(Default::default());
+            }
+        }
+
+        /// A strategy without a `type` becomes a `RollingUpdate` strategy with a `rollingUpdate`, whose defaults are then set.
+        pub fn stateful_set_update_strategy(strategy: &mut crate::api::apps::v1::StatefulSetUpdateStrategy) {
+            if strategy.type_.as_deref().is_none_or(str::is_empty) {
+                strategy.type_ = Some("RollingUpdate".into());
+                if strategy.rolling_update.is_none() {
+                    strategy.rolling_update = Some(Default::default());
+                }
+            }
+        }
+    }
+
+    /// Defaults of the `autoscaling/v2` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/autoscaling/v2/defaults.go>
+    pub mod autoscaling_v2 {
+        use crate::api::autoscaling::v2::{HPAScalingPolicy, HPAScalingRules, HorizontalPodAutoscalerSpec, MetricSpec, MetricTarget, ResourceMetricSource};
+
+        /// An autoscaler without `metrics` targets an average CPU utilization of 80%.
+        ///
+        /// The unset fields of the `scaleUp` and `scaleDown` rules of the `behavior`, if any, are set from the default rules.
+        #[allow(clippy::needless_update)] // `HPAScalingRules` has more fields in newer versions.
+        pub fn horizontal_pod_autoscaler_spec(spec: &mut HorizontalPodAutoscalerSpec) {
+            if spec.metrics.as_ref().is_none_or(std::vec::Vec::is_empty) {
+                spec.metrics = Some(std::vec![MetricSpec {
+                    type_: "Resource".into(),
+                    resource: Some(ResourceMetricSource {
+                        name: "cpu".into(),
+                        target: MetricTarget {
+                            type_: "Utilization".into(),
+                            average_utilization: Some(80),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                }]);
+            }
+
+            if let Some(behavior) = &mut spec.behavior {
+                set_scaling_rules_defaults(&mut behavior.scale_up, HPAScalingRules {
+                    policies: Some(std::vec![
+                        HPAScalingPolicy { period_seconds: 15, type_: "Pods".into(), value: 4 },
+                        HPAScalingPolicy { period_seconds: 15, type_: "Percent".into(), value: 100 },
+                    ]),
+                    select_policy: Some("Max".into()),
+                    stabilization_window_seconds: Some(0),
+                    ..Default::default()
+                });
+
+                // The stabilization window of scaling down is left unset, since it defaults to the controller manager's
+                // `--horizontal-pod-autoscaler-downscale-stabilization` flag.
+                set_scaling_rules_defaults(&mut behavior.scale_down, HPAScalingRules {
+                    policies: Some(std::vec![
+                        HPAScalingPolicy { period_seconds: 15, type_: "Percent".into(), value: 100 },
+                    ]),
+                    select_policy: Some("Max".into()),
+                    ..Default::default()
+                });
+            }
+        }
+
+        fn set_scaling_rules_defaults(rules: &mut Option<HPAScalingRules>, default_rules: HPAScalingRules) {
+            match rules {
+                Some(rules) => {
+                    if rules.policies.is_none() {
+                        rules.policies = default_rules.policies;
+                    }
+                    if rules.select_policy.is_none() {
+                        rules.select_policy = default_rules.select_policy;
+                    }
+                    if rules.stabilization_window_seconds.is_none() {
+                        rules.stabilization_window_seconds = default_rules.stabilization_window_seconds;
+                    }
+                },
+
+                None => *rules = Some(default_rules),
+            }
+        }
+    }
+
+    /// Defaults of the `batch/v1` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/batch/v1/defaults.go>
+    pub mod batch_v1 {
+        /// Sets the defaults of the job's `completions`, `parallelism`, `backoffLimit`, `completionMode`, `suspend` and `podReplacementPolicy`,
+        /// and copies the labels of the pod template to the job if it has none.
+        pub fn job(job: &mut crate::api::batch::v1::Job) {
+            let Some(spec) = &mut job.spec else {
+                return;
+            };
+
+            // A non-parallel job can leave both `completions` and `parallelism` unset.
+            if spec.completions.is_none() && spec.parallelism.is_none() {
+                spec.completions = Some(1);
+            }
+            if spec.parallelism.is_none() {
+                spec.parallelism = Some(1);
+            }
+
+            if spec.backoff_limit.is_none() {
+                spec.backoff_limit = Some(if spec.backoff_limit_per_index.is_some() { i32::MAX } else { 6 });
+            }
+
+            if let Some(labels) = spec.template.metadata.as_ref().and_then(|metadata| metadata.labels.as_ref()) {
+                if job.metadata.labels.as_ref().is_none_or(std::collections::BTreeMap::is_empty) {
+                    job.metadata.labels = Some(labels.clone());
+                }
+            }
+
+            if spec.completion_mode.is_none() {
+                spec.completion_mode = Some("NonIndexed".into());
+            }
+
+            if spec.suspend.is_none() {
+                spec.suspend = Some(false);
+            }
+
+            if spec.pod_replacement_policy.is_none() {
+                spec.pod_replacement_policy = Some(if spec.pod_failure_policy.is_some() { "Failed" } else { "TerminatingOrFailed" }.into());
+            }
+        }
+    }
+
+    /// Defaults of the core `v1` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/v1/defaults.go>
+    pub mod core_v1 {
+        use crate::api::core::v1::{Container, ContainerPort, EphemeralContainer, Pod, ReplicationController, ServicePort, ServiceSpec};
+        use crate::apimachinery::pkg::util::intstr::IntOrString;
+
+        /// Sets the container's `imagePullPolicy` from its `image`. See [`image_pull_policy`] for details.
+        pub fn container(container: &mut Container) {
+            if container.image_pull_policy.is_none() {
+                container.image_pull_policy = Some(image_pull_policy(container.image.as_deref().unwrap_or_default()).into());
+            }
+        }
+
+        /// Sets the ephemeral container's `imagePullPolicy` from its `image`. See [`image_pull_policy`] for details.
+        pub fn ephemeral_container(container: &mut EphemeralContainer) {
+            if container.image_pull_policy.is_none() {
+                container.image_pull_policy = Some(image_pull_policy(container.image.as_deref().unwrap_or_default()).into());
+            }
+        }
+
+        /// The default `imagePullPolicy` of a container with the given `image`.
+        ///
+        /// This is `"Always"` for images with the `latest` tag, even if they also have a digest, or with neither a tag nor a digest,
+        /// and `"IfNotPresent"` for all other images.
+        pub fn image_pull_policy(image: &str) -> &'static str {
+            if image.is_empty() {
+                return "IfNotPresent";
+            }
+
+            let (name, digest) = image.split_once('@').map_or((image, None), |(name, digest)| (name, Some(digest)));
+
+            // A `:` in the last component of the name separates the tag. A `:` in an earlier component separates the port of the registry host.
+            let last_component = name.rsplit('/').next().unwrap_or(name);
+            let tag = last_component.split_once(':').map(|(_, tag)| tag);
+
+            match (tag, digest) {
+                (Some("latest"), _) | (None, None) => "Always",
+                _ => "IfNotPresent",
+            }
+        }
+
+        /// Sets the pod's `enableServiceLinks`, defaults the `requests` of its containers to their `limits`,
+        /// and defaults the `hostPort`s of its containers to their `containerPort`s if the pod uses the host's network.
+        pub fn pod(pod: &mut Pod) {
+            let Some(spec) = &mut pod.spec else {
+                return;
+            };
+
+            for container in spec.containers.iter_mut().chain(spec.init_containers.iter_mut().flatten()) {
+                let Some(resources) = &mut container.resources else {
+                    continue;
+                };
+                let Some(limits) = &resources.limits else {
+                    continue;
+                };
+
+                let requests = resources.requests.get_or_insert_with(Default::default);
+                for (name, limit) in limits {
+                    requests.entry(name.clone()).or_insert_with(|| limit.clone());
+                }
+            }
+
+            if spec.enable_service_links.is_none() {
+                spec.enable_service_links = Some(true);
+            }
+
+            if spec.host_network == Some(true) {
+                for container in spec.containers.iter_mut().chain(spec.init_containers.iter_mut().flatten()) {
+                    for ContainerPort { container_port, host_port, .. } in container.ports.iter_mut().flatten() {
+                        if host_port.unwrap_or_default() == 0 {
+                            *host_port = Some(*container_port);
+                        }
+                    }
+                }
+            }
+        }
+
+        /// Defaults the replication controller's `selector`, and its own labels if it has none, to the labels of its pod template.
+        pub fn replication_controller(replication_controller: &mut ReplicationController) {
+            let Some(spec) = &mut replication_controller.spec else {
+                return;
+            };
+            let Some(labels) = spec.template.as_ref().and_then(|template| template.metadata.as_ref()).and_then(|metadata| metadata.labels.as_ref()) else {
+                return;
+            };
+
+            if spec.selector.as_ref().is_none_or(std::collections::BTreeMap::is_empty) {
+                spec.selector = Some(labels.clone());
+            }
+
+            if replication_controller.metadata.labels.as_ref().is_none_or(std::collections::BTreeMap::is_empty) {
+                replication_controller.metadata.labels = Some(labels.clone());
+            }
+        }
+
+        /// Defaults the port's `targetPort` to its `port`.
+        pub fn service_port(port: &mut ServicePort) {
+            if matches!(&port.target_port, None | Some(IntOrString::Int(0))) || matches!(&port.target_port, Some(IntOrString::String(s)) if s.is_empty()) {
+                port.target_port = Some(IntOrString::Int(port.port));
+            }
+        }
+
+        /// Sets the defaults of the service's `sessionAffinityConfig`, `externalTrafficPolicy`, `internalTrafficPolicy`
+        /// and `allocateLoadBalancerNodePorts` according to its `type` and `sessionAffinity`.
+        pub fn service_spec(spec: &mut ServiceSpec) {
+            match spec.session_affinity.as_deref() {
+                Some("None") => spec.session_affinity_config = None,
+
+                Some("ClientIP") => {
+                    let client_ip = spec.session_affinity_config.get_or_insert_with(Default::default).client_ip.get_or_insert_with(Default::default);
+                    if client_ip.timeout_seconds.is_none() {
+                        client_ip.timeout_seconds = Some(10800);
+                    }
+                },
+
+                _ => (),
+            }
+
+            let type_ = spec.type_.as_deref().unwrap_or_default();
+
+            let is_externally_accessible =
+                matches!(type_, "NodePort" | "LoadBalancer") ||
+                spec.external_ips.as_ref().is_some_and(|external_ips| !external_ips.is_empty());
+            if spec.external_traffic_policy.as_deref().is_none_or(str::is_empty) && is_externally_accessible {
+                spec.external_traffic_policy = Some("Cluster".into());
+            }
+
+            if spec.internal_traffic_policy.is_none() && matches!(type_, "ClusterIP" | "NodePort" | "LoadBalancer") {
+                spec.internal_traffic_policy = Some("Cluster".into());
+            }
+
+            if type_ == "LoadBalancer" && spec.allocate_load_balancer_node_ports.is_none() {
+                spec.allocate_load_balancer_node_ports = Some(true);
+            }
+        }
+    }
+
+    /// Defaults of the `networking.k8s.io/v1` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/networking/v1/defaults.go>
+    pub mod networking_v1 {
+        /// A network policy without `policyTypes` applies to ingress, and also to egress if it has `egress` rules.
+        pub fn network_policy_spec(spec: &mut crate::api::networking::v1::NetworkPolicySpec) {
+            if spec.policy_types.as_ref().is_none_or(std::vec::Vec::is_empty) {
+                let mut policy_types = std::vec!["Ingress".into()];
+                if spec.egress.as_ref().is_some_and(|egress| !egress.is_empty()) {
+                    policy_types.push("Egress".into());
+                }
+                spec.policy_types = Some(policy_types);
+            }
+        }
+    }
+
+    /// Defaults of the `rbac.authorization.k8s.io/v1` API group.
+    ///
+    /// Ref: <https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/rbac/v1/defaults.go>
+    pub mod rbac_v1 {
+        /// `User` and `Group` subjects default to the `rbac.authorization.k8s.io` API group. `ServiceAccount` subjects are in the core API group.
+        pub fn subject(subject: &mut crate::api::rbac::v1::Subject) {
+            if subject.api_group.as_deref().is_none_or(str::is_empty) && matches!(&*subject.kind, "User" | "Group") {
+                subject.api_group = Some("rbac.authorization.k8s.io".into());
+            }
+        }
+    }
+}
@@ -0,0 +1,512 @@
+//! Structured diffs between two values of the same type.
+//!
+//! [`diff`] walks two values and returns the list of [`Change`]s between them, each with the path of the field that changed.
+//! Items of lists with `x-kubernetes-list-type: map` are identified by their merge keys rather than their index,
+//! like `spec.containers[name=app].image`, so that inserting an item into such a list does not report every following item as changed.
+//!
+//! [`Unified`] renders a list of changes as text, in the style of `kubectl diff`.
+
+/// A trait applies to types whose values can be diffed.
+///
+/// `old.diff(new, path, changes)` behaves in the following ways:
+///
+/// ## `struct`s
+///
+/// Each field is diffed separately, under the path of the field's name in the JSON representation of the struct.
+/// The structs in the `k8s-openapi` crate diff their lists and maps according to the merge metadata of the field in the OpenAPI spec.
+/// See [`strategies`] for the functions that implement these.
+///
+/// ## `Option`
+///
+/// If only one of the values is `Some`, the field is reported as [added](ChangeKind::Added) or [removed](ChangeKind::Removed).
+/// If both are `Some`, the inner values are diffed.
+///
+/// ## `serde_json::Value`
+///
+/// Objects are diffed key by key. Other values are diffed like scalars.
+///
+/// ## Other types
+///
+/// The values are treated as scalars, and are reported as [changed](ChangeKind::Changed) if they are not equal.
+pub trait Diff {
+    /// Appends the changes from `self` to `other` to `changes`. `path` is the path of `self` and `other`.
+    fn diff(&self, other: &Self, path: &Path<'_>, changes: &mut std::vec::Vec<Change>);
+}
+
+/// Returns the changes from `old` to `new`.
+pub fn diff<T>(old: &T, new: &T) -> std::vec::Vec<Change> where T: Diff + ?Sized {
+    let mut changes = std::vec![];
+    old.diff(new, &Path::Root, &mut changes);
+    changes
+}
+
+/// A change from one value to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    /// The path of the field that changed, like `spec.containers[name=app].image`.
+    pub path: std::string::String,
+
+    /// What changed.
+    pub kind: ChangeKind,
+}
+
+impl core::fmt::Display for Change {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.kind {
+            ChangeKind::Added(new) => write!(f, "{}: added {new}", self.path),
+            ChangeKind::Removed(old) => write!(f, "{}: removed {old}", self.path),
+            ChangeKind::Changed { old, new } => write!(f, "{}: changed {old} to {new}", self.path),
+        }
+    }
+}
+
+/// The kind of a [`Change`]. The values are in their JSON representation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangeKind {
+    /// The field is only set in the new value.
+    Added(serde_json::Value),
+
+    /// The field is only set in the old value.
+    Removed(serde_json::Value),
+
+    /// The field is set to different values in the old and new values.
+    Changed {
+        /// The old value of the field.
+        old: serde_json::Value,
+
+        /// The new value of the field.
+        new: serde_json::Value,
+    },
+}
+
+/// The path of the values currently being diffed.
+#[derive(Clone, Copy, Debug)]
+pub enum Path<'a> {
+    /// The values passed to [`diff`].
+    Root,
+
+    /// A field of a struct.
+    Field {
+        /// The path of the struct.
+        parent: &'a Path<'a>,
+
+        /// The name of the field in the JSON representation of the struct.
+        name: &'a str,
+    },
+
+    /// A value of a map.
+    Key {
+        /// The path of the map.
+        parent: &'a Path<'a>,
+
+        /// The key of the value.
+        key: &'a str,
+    },
+
+    /// An item of a list, identified by its index.
+    Index {
+        /// The path of the list.
+        parent: &'a Path<'a>,
+
+        /// The index of the item.
+        index: usize,
+    },
+
+    /// An item of a list with `x-kubernetes-list-type: map`, identified by its merge keys.
+    MergeKey {
+        /// The path of the list.
+        parent: &'a Path<'a>,
+
+        /// The merge keys of the item, like `name=app` or `containerPort=80,protocol=TCP`.
+        key: &'a str,
+    },
+}
+
+impl core::fmt::Display for Path<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Path::Root => Ok(()),
+            Path::Field { parent: Path::Root, name } => f.write_str(name),
+            Path::Field { parent, name } => write!(f, "{parent}.{name}"),
+            Path::Key { parent: Path::Root, key } if is_simple_key(key) => f.write_str(key),
+            Path::Key { parent, key } if is_simple_key(key) => write!(f, "{parent}.{key}"),
+            Path::Key { parent, key } => write!(f, "{parent}[{key:?}]"),
+            Path::Index { parent, index } => write!(f, "{parent}[{index}]"),
+            Path::MergeKey { parent, key } => write!(f, "{parent}[{key}]"),
+        }
+    }
+}
+
+/// Map keys like `app.kubernetes.io/name` would be ambiguous after a `.`, so they are rendered in brackets instead.
+fn is_simple_key(key: &str) -> bool {
+    !key.is_empty() && key.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_')
+}
+
+/// A type that can be used as the merge key of an item of a list with `x-kubernetes-list-type: map`.
+pub trait MergeKey {
+    /// Returns the value of the key as it appears in a [`Path::MergeKey`], or `None` if the key is unset.
+    fn merge_key(&self) -> Option<std::string::String>;
+}
+
+impl MergeKey for bool {
+    fn merge_key(&self) -> Option<std::string::String> {
+        Some(std::string::ToString::to_string(self))
+    }
+}
+
+impl MergeKey for i32 {
+    fn merge_key(&self) -> Option<std::string::String> {
+        Some(std::string::ToString::to_string(self))
+    }
+}
+
+impl MergeKey for i64 {
+    fn merge_key(&self) -> Option<std::string::String> {
+        Some(std::string::ToString::to_string(self))
+    }
+}
+
+impl MergeKey for std::string::String {
+    fn merge_key(&self) -> Option<std::string::String> {
+        Some(self.clone())
+    }
+}
+
+impl<T> MergeKey for Option<T> where T: MergeKey {
+    fn merge_key(&self) -> Option<std::string::String> {
+        self.as_ref().and_then(MergeKey::merge_key)
+    }
+}
+
+macro_rules! scalar_impl {
+    () => {
+        fn diff(&self, other: &Self, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) {
+            strategies::scalar(self, other, path, changes);
+        }
+    };
+}
+
+impl Diff for bool { scalar_impl! {} }
+impl Diff for i32 { scalar_impl! {} }
+impl Diff for i64 { scalar_impl! {} }
+impl Diff for f64 { scalar_impl! {} }
+impl Diff for std::string::String { scalar_impl! {} }
+impl Diff for crate::ByteString { scalar_impl! {} }
+impl Diff for crate::Duration { scalar_impl! {} }
+impl Diff for jiff::Timestamp { scalar_impl! {} }
+
+impl Diff for serde_json::Value {
+    fn diff(&self, other: &Self, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) {
+        if let (serde_json::Value::Object(this), serde_json::Value::Object(other)) = (self, other) {
+            for (key, value) in this {
+                let path = Path::Key { parent: path, key };
+                match other.get(key) {
+                    Some(other_value) => value.diff(other_value, &path, changes),
+                    None => changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Removed(value.clone()) }),
+                }
+            }
+
+            for (key, other_value) in other {
+                if !this.contains_key(key) {
+                    let path = Path::Key { parent: path, key };
+                    changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Added(other_value.clone()) });
+                }
+            }
+        }
+        else {
+            strategies::scalar(self, other, path, changes);
+        }
+    }
+}
+
+impl<T> Diff for std::boxed::Box<T> where T: Diff + ?Sized {
+    fn diff(&self, other: &Self, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) {
+        (**self).diff(other, path, changes);
+    }
+}
+
+impl<T> Diff for Option<T> where T: Diff + serde::Serialize {
+    fn diff(&self, other: &Self, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) {
+        match (self, other) {
+            (None, None) => (),
+            (None, Some(other)) => changes.push(Change { path: std::string::ToString::to_string(path), kind: ChangeKind::Added(to_value(other)) }),
+            (Some(this), None) => changes.push(Change { path: std::string::ToString::to_string(path), kind: ChangeKind::Removed(to_value(this)) }),
+            (Some(this), Some(other)) => this.diff(other, path, changes),
+        }
+    }
+}
+
+/// Serializes the value to its JSON representation. Values that fail to serialize are represented as `null`.
+fn to_value<T>(value: &T) -> serde_json::Value where T: serde::Serialize + ?Sized {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+/// Strategies for diffing scalars and collections.
+pub mod strategies {
+    use super::{Change, ChangeKind, Path};
+
+    /// The values are compared as a whole, and reported as changed if they are not equal.
+    pub fn scalar<T>(old: &T, new: &T, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) where T: PartialEq + serde::Serialize + ?Sized {
+        if old != new {
+            changes.push(Change {
+                path: std::string::ToString::to_string(path),
+                kind: ChangeKind::Changed { old: super::to_value(old), new: super::to_value(new) },
+            });
+        }
+    }
+
+    /// Strategies for diffing [`std::vec::Vec`]s.
+    ///
+    /// These correspond to [`JSONSchemaProps.x-kubernetes-list-type`](https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.26/#jsonschemaprops-v1-apiextensions-k8s-io).
+    /// An optional list that is [`None`] is diffed like an empty list.
+    pub mod list {
+        mod private {
+            pub trait AsOptVec {
+                type Item;
+
+                fn as_slice(&self) -> &[Self::Item];
+            }
+
+            impl<T> AsOptVec for std::vec::Vec<T> {
+                type Item = T;
+
+                fn as_slice(&self) -> &[T] {
+                    self
+                }
+            }
+
+            impl<T> AsOptVec for Option<std::vec::Vec<T>> {
+                type Item = T;
+
+                fn as_slice(&self) -> &[T] {
+                    self.as_deref().unwrap_or_default()
+                }
+            }
+        }
+        use private::AsOptVec;
+
+        use super::{Change, ChangeKind, Path};
+
+        /// The items are identified by their index.
+        ///
+        /// Items at the same index are diffed, and any extra items are reported as added or removed.
+        pub fn atomic<V>(
+            old: &V,
+            new: &V,
+            path: &Path<'_>,
+            changes: &mut std::vec::Vec<Change>,
+            diff_item: fn(&V::Item, &V::Item, &Path<'_>, &mut std::vec::Vec<Change>),
+        )
+        where
+            V: AsOptVec,
+            V::Item: serde::Serialize,
+        {
+            let (old, new) = (old.as_slice(), new.as_slice());
+            for index in 0..old.len().max(new.len()) {
+                let path = Path::Index { parent: path, index };
+                match (old.get(index), new.get(index)) {
+                    (Some(old_item), Some(new_item)) => diff_item(old_item, new_item, &path, changes),
+                    (Some(old_item), None) =>
+                        changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Removed(super::super::to_value(old_item)) }),
+                    (None, Some(new_item)) =>
+                        changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Added(super::super::to_value(new_item)) }),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+
+        /// The list is treated as a map.
+        ///
+        /// The items are identified by their merge keys, which are given as pairs of the name of the key and a function to get its value from an item.
+        /// Items with the same keys are diffed, and any other items are reported as added or removed.
+        pub fn map<V>(
+            old: &V,
+            new: &V,
+            path: &Path<'_>,
+            changes: &mut std::vec::Vec<Change>,
+            keys: &[(&str, fn(&V::Item) -> Option<std::string::String>)],
+            diff_item: fn(&V::Item, &V::Item, &Path<'_>, &mut std::vec::Vec<Change>),
+        )
+        where
+            V: AsOptVec,
+            V::Item: serde::Serialize,
+        {
+            let merge_key = |item: &V::Item| {
+                let mut merge_key = std::string::String::new();
+                for (name, value) in keys {
+                    if let Some(value) = value(item) {
+                        if !merge_key.is_empty() {
+                            merge_key.push(',');
+                        }
+                        merge_key.push_str(name);
+                        merge_key.push('=');
+                        merge_key.push_str(&value);
+                    }
+                }
+                merge_key
+            };
+
+            let (old, new) = (old.as_slice(), new.as_slice());
+            let new_keys: std::vec::Vec<_> = new.iter().map(merge_key).collect();
+            let mut matched = std::vec![false; new.len()];
+
+            for old_item in old {
+                let key = merge_key(old_item);
+                let path = Path::MergeKey { parent: path, key: &key };
+                if let Some(index) = new_keys.iter().zip(&matched).position(|(new_key, &matched)| !matched && *new_key == key) {
+                    matched[index] = true;
+                    diff_item(old_item, &new[index], &path, changes);
+                }
+                else {
+                    changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Removed(super::super::to_value(old_item)) });
+                }
+            }
+
+            for ((new_item, key), matched) in new.iter().zip(&new_keys).zip(matched) {
+                if !matched {
+                    let path = Path::MergeKey { parent: path, key };
+                    changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Added(super::super::to_value(new_item)) });
+                }
+            }
+        }
+
+        /// The list is treated as a set.
+        ///
+        /// Items that are only in the old list are reported as removed, and items that are only in the new list are reported as added,
+        /// both under the path of the list itself.
+        pub fn set<V>(old: &V, new: &V, path: &Path<'_>, changes: &mut std::vec::Vec<Change>) where V: AsOptVec, V::Item: PartialEq + serde::Serialize {
+            let (old, new) = (old.as_slice(), new.as_slice());
+            for old_item in old {
+                if !new.contains(old_item) {
+                    changes.push(Change { path: std::string::ToString::to_string(path), kind: ChangeKind::Removed(super::super::to_value(old_item)) });
+                }
+            }
+            for new_item in new {
+                if !old.contains(new_item) {
+                    changes.push(Change { path: std::string::ToString::to_string(path), kind: ChangeKind::Added(super::super::to_value(new_item)) });
+                }
+            }
+        }
+    }
+
+    /// Strategies for diffing [`BTreeMap`s.](std::collections::BTreeMap)
+    pub mod map {
+        mod private {
+            use std::collections::BTreeMap;
+
+            pub trait AsOptMap {
+                type Value;
+
+                fn as_opt(&self) -> Option<&BTreeMap<std::string::String, Self::Value>>;
+            }
+
+            impl<V> AsOptMap for BTreeMap<std::string::String, V> {
+                type Value = V;
+
+                fn as_opt(&self) -> Option<&Self> {
+                    Some(self)
+                }
+            }
+
+            impl<V> AsOptMap for Option<BTreeMap<std::string::String, V>> {
+                type Value = V;
+
+                fn as_opt(&self) -> Option<&BTreeMap<std::string::String, V>> {
+                    self.as_ref()
+                }
+            }
+        }
+        use private::AsOptMap;
+
+        use super::{Change, ChangeKind, Path};
+
+        /// Values with the same key are diffed, and any other values are reported as added or removed.
+        ///
+        /// This applies to both values of [`JSONSchemaProps.x-kubernetes-map-type`](https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.26/#jsonschemaprops-v1-apiextensions-k8s-io).
+        /// An optional map that is [`None`] is diffed like an empty map.
+        pub fn values<M>(
+            old: &M,
+            new: &M,
+            path: &Path<'_>,
+            changes: &mut std::vec::Vec<Change>,
+            diff_value: fn(&M::Value, &M::Value, &Path<'_>, &mut std::vec::Vec<Change>),
+        )
+        where
+            M: AsOptMap,
+            M::Value: serde::Serialize,
+        {
+            let (old, new) = (old.as_opt(), new.as_opt());
+            for (key, old_value) in old.into_iter().flatten() {
+                let path = Path::Key { parent: path, key };
+                match new.and_then(|new| new.get(key)) {
+                    Some(new_value) => diff_value(old_value, new_value, &path, changes),
+                    None => changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Removed(super::super::to_value(old_value)) }),
+                }
+            }
+
+            for (key, new_value) in new.into_iter().flatten() {
+                if !old.is_some_and(|old| old.contains_key(key)) {
+                    let path = Path::Key { parent: path, key };
+                    changes.push(Change { path: std::string::ToString::to_string(&path), kind: ChangeKind::Added(super::super::to_value(new_value)) });
+                }
+            }
+        }
+    }
+}
+
+/// Renders a list of changes as text, in the style of `kubectl diff`.
+///
+/// Each change is rendered as a hunk whose header is the path of the field, followed by the old value prefixed with `-`
+/// and the new value prefixed with `+`. Values are rendered as JSON, with objects and arrays pretty-printed over multiple lines.
+///
+/// ```text
+/// --- live
+/// +++ desired
+/// @@ spec.template.spec.containers[name=app].image @@
+/// -"app:1"
+/// +"app:2"
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Unified<'a> {
+    /// The changes to render.
+    pub changes: &'a [Change],
+
+    /// The label of the old value, rendered in the `---` header line.
+    pub old_label: &'a str,
+
+    /// The label of the new value, rendered in the `+++` header line.
+    pub new_label: &'a str,
+}
+
+impl core::fmt::Display for Unified<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn write_value(f: &mut core::fmt::Formatter<'_>, prefix: char, value: &serde_json::Value) -> core::fmt::Result {
+            let value = serde_json::to_string_pretty(value).map_err(|_| core::fmt::Error)?;
+            for line in value.lines() {
+                writeln!(f, "{prefix}{line}")?;
+            }
+            Ok(())
+        }
+
+        if self.changes.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "--- {}", self.old_label)?;
+        writeln!(f, "+++ {}", self.new_label)?;
+
+        for Change { path, kind } in self.changes {
+            writeln!(f, "@@ {path} @@")?;
+            match kind {
+                ChangeKind::Added(new) => write_value(f, '+', new)?,
+                ChangeKind::Removed(old) => write_value(f, '-', old)?,
+                ChangeKind::Changed { old, new } => {
+                    write_value(f, '-', old)?;
+                    write_value(f, '+', new)?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
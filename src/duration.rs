@@ -0,0 +1,325 @@
+/// A span of time, represented in the syntax of golang's `time.Duration`, like `"1h30m0.5s"`.
+///
+/// This is also how golang's `metav1.Duration` is represented in JSON. But none of the types in this crate use it, because the OpenAPI spec of Kubernetes
+/// describes `metav1.Duration` as a plain string and none of the resource types of the supported versions have such a field anyway.
+/// Code generated by `k8s-openapi-codegen-common` uses it for string properties with the `duration` format. Otherwise it can be used on its own,
+/// such as to parse the durations in the configuration files of Kubernetes components.
+///
+/// The value is parsed and formatted like golang's `time.ParseDuration` and `time.Duration.String` do, so only values that fit in
+/// an `int64` of nanoseconds (about 292 years) can be parsed. Larger values can be created from a [`jiff::SignedDuration`] and are still formatted correctly.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Duration(pub jiff::SignedDuration);
+
+const NANOSECOND: u64 = 1;
+const MICROSECOND: u64 = 1_000 * NANOSECOND;
+const MILLISECOND: u64 = 1_000 * MICROSECOND;
+const SECOND: u64 = 1_000 * MILLISECOND;
+const MINUTE: u64 = 60 * SECOND;
+const HOUR: u64 = 60 * MINUTE;
+
+impl Duration {
+    /// A duration of zero time.
+    pub const ZERO: Self = Duration(jiff::SignedDuration::ZERO);
+
+    /// Creates a duration from the given number of nanoseconds, like golang's `time.Duration(nanos)`.
+    pub const fn from_nanos(nanos: i64) -> Self {
+        Duration(jiff::SignedDuration::from_nanos(nanos))
+    }
+}
+
+impl From<jiff::SignedDuration> for Duration {
+    fn from(value: jiff::SignedDuration) -> Self {
+        Duration(value)
+    }
+}
+
+impl From<Duration> for jiff::SignedDuration {
+    fn from(value: Duration) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<core::time::Duration> for Duration {
+    type Error = jiff::Error;
+
+    fn try_from(value: core::time::Duration) -> Result<Self, Self::Error> {
+        Ok(Duration(value.try_into()?))
+    }
+}
+
+impl TryFrom<Duration> for core::time::Duration {
+    type Error = jiff::Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value.0.try_into()
+    }
+}
+
+impl core::fmt::Display for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        /// The fractional part of a number, without trailing zeros. Formats as nothing if the fraction is zero.
+        struct Fraction {
+            digits: u128,
+            width: usize,
+        }
+
+        impl core::fmt::Display for Fraction {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.width == 0 {
+                    Ok(())
+                }
+                else {
+                    write!(f, ".{:0width$}", self.digits, width = self.width)
+                }
+            }
+        }
+
+        /// Splits `v / 10^prec` into its integer and fractional parts.
+        fn split_fraction(v: u128, prec: u32) -> (u128, Fraction) {
+            let divisor = 10_u128.pow(prec);
+            let mut fraction = Fraction { digits: v % divisor, width: prec as usize };
+            if fraction.digits == 0 {
+                fraction.width = 0;
+            }
+            else {
+                while fraction.digits.is_multiple_of(10) {
+                    fraction.digits /= 10;
+                    fraction.width -= 1;
+                }
+            }
+            (v / divisor, fraction)
+        }
+
+        let nanos = self.0.as_nanos();
+        if nanos == 0 {
+            return f.write_str("0s");
+        }
+
+        if nanos < 0 {
+            f.write_str("-")?;
+        }
+        let nanos = nanos.unsigned_abs();
+
+        if nanos < u128::from(SECOND) {
+            let (prec, unit) =
+                if nanos < u128::from(MICROSECOND) { (0, "ns") }
+                else if nanos < u128::from(MILLISECOND) { (3, "\u{b5}s") }
+                else { (6, "ms") };
+            let (whole, fraction) = split_fraction(nanos, prec);
+            write!(f, "{whole}{fraction}{unit}")
+        }
+        else {
+            let (seconds, fraction) = split_fraction(nanos, 9);
+            let (minutes, seconds) = (seconds / 60, seconds % 60);
+            let (hours, minutes) = (minutes / 60, minutes % 60);
+            if hours > 0 {
+                write!(f, "{hours}h{minutes}m{seconds}{fraction}s")
+            }
+            else if minutes > 0 {
+                write!(f, "{minutes}m{seconds}{fraction}s")
+            }
+            else {
+                write!(f, "{seconds}{fraction}s")
+            }
+        }
+    }
+}
+
+impl core::str::FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Ref: https://github.com/golang/go/blob/go1.24.0/src/time/format.go#L1589-L1684
+
+        /// Consumes leading `[0-9]*` from `s`, returning `None` on overflow.
+        fn leading_int(s: &mut &[u8]) -> Option<u64> {
+            let mut x: u64 = 0;
+            while let Some((&c @ b'0'..=b'9', rest)) = s.split_first() {
+                if x > (1 << 63) / 10 {
+                    return None;
+                }
+                x = x * 10 + u64::from(c - b'0');
+                if x > 1 << 63 {
+                    return None;
+                }
+                *s = rest;
+            }
+            Some(x)
+        }
+
+        /// Consumes leading `[0-9]*` from `s` as a fraction, returning the fraction and its scale. Digits beyond the precision of `u64` are ignored.
+        fn leading_fraction(s: &mut &[u8]) -> (u64, f64) {
+            let mut x: u64 = 0;
+            let mut scale = 1.;
+            let mut overflow = false;
+            while let Some((&c @ b'0'..=b'9', rest)) = s.split_first() {
+                *s = rest;
+                if overflow {
+                    continue;
+                }
+                if x > ((1 << 63) - 1) / 10 {
+                    overflow = true;
+                    continue;
+                }
+                let y = x * 10 + u64::from(c - b'0');
+                if y > 1 << 63 {
+                    overflow = true;
+                    continue;
+                }
+                x = y;
+                scale *= 10.;
+            }
+            (x, scale)
+        }
+
+        let invalid = || ParseDurationError::Invalid(s.into());
+
+        let mut rest = s.as_bytes();
+        let mut neg = false;
+        if let Some((&c @ (b'-' | b'+'), rest_)) = rest.split_first() {
+            neg = c == b'-';
+            rest = rest_;
+        }
+
+        // Special case: if all that is left is "0", this is zero.
+        if rest == b"0" {
+            return Ok(Duration::ZERO);
+        }
+        if rest.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut total: u64 = 0;
+        while !rest.is_empty() {
+            // The next character must be [0-9.]
+            if !matches!(rest[0], b'.' | b'0'..=b'9') {
+                return Err(invalid());
+            }
+
+            // Consume [0-9]*
+            let pl = rest.len();
+            let mut value = leading_int(&mut rest).ok_or_else(invalid)?;
+            let pre = pl != rest.len();
+
+            // Consume (\.[0-9]*)?
+            let mut post = false;
+            let mut fraction = 0;
+            let mut scale = 1.;
+            if let Some((b'.', rest_)) = rest.split_first() {
+                rest = rest_;
+                let pl = rest.len();
+                (fraction, scale) = leading_fraction(&mut rest);
+                post = pl != rest.len();
+            }
+            if !pre && !post {
+                // no digits (e.g. ".s" or "-.s")
+                return Err(invalid());
+            }
+
+            // Consume unit.
+            let i = rest.iter().position(|&c| matches!(c, b'.' | b'0'..=b'9')).unwrap_or(rest.len());
+            if i == 0 {
+                return Err(ParseDurationError::MissingUnit(s.into()));
+            }
+            let (unit, rest_) = rest.split_at(i);
+            rest = rest_;
+            let unit = match unit {
+                b"ns" => NANOSECOND,
+                // "us", U+00B5 = micro symbol, U+03BC = Greek letter mu
+                b"us" | b"\xc2\xb5s" | b"\xce\xbcs" => MICROSECOND,
+                b"ms" => MILLISECOND,
+                b"s" => SECOND,
+                b"m" => MINUTE,
+                b"h" => HOUR,
+                unit => return Err(ParseDurationError::UnknownUnit {
+                    unit: std::string::String::from_utf8_lossy(unit).into_owned(),
+                    duration: s.into(),
+                }),
+            };
+            if value > (1 << 63) / unit {
+                // overflow
+                return Err(invalid());
+            }
+            value *= unit;
+            if fraction > 0 {
+                // f64 is needed to be nanosecond accurate for fractions of hours.
+                // value >= 0 && (fraction * unit / scale) <= 3.6e+12 (ns/h, h is the largest unit)
+                #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+                {
+                    value += (fraction as f64 * (unit as f64 / scale)) as u64;
+                }
+                if value > 1 << 63 {
+                    // overflow
+                    return Err(invalid());
+                }
+            }
+            total += value;
+            if total > 1 << 63 {
+                return Err(invalid());
+            }
+        }
+
+        let nanos =
+            if neg { 0_i64.checked_sub_unsigned(total) }
+            else { i64::try_from(total).ok() };
+        let nanos = nanos.ok_or_else(invalid)?;
+        Ok(Duration::from_nanos(nanos))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Duration;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a duration string like \"1h30m\"")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                v.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl serde::Serialize for Duration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+/// An error from parsing a [`Duration`]. Its `Display` impl prints the same message as golang's `time.ParseDuration`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseDurationError {
+    /// The string is not a valid duration, or the duration is too large.
+    Invalid(std::string::String),
+
+    /// A number in the string is not followed by a unit.
+    MissingUnit(std::string::String),
+
+    /// A number in the string is followed by an unknown unit.
+    UnknownUnit {
+        /// The unknown unit.
+        unit: std::string::String,
+
+        /// The string being parsed.
+        duration: std::string::String,
+    },
+}
+
+impl core::fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseDurationError::Invalid(duration) => write!(f, "time: invalid duration {duration:?}"),
+            ParseDurationError::MissingUnit(duration) => write!(f, "time: missing unit in duration {duration:?}"),
+            ParseDurationError::UnknownUnit { unit, duration } => write!(f, "time: unknown unit {unit:?} in duration {duration:?}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDurationError {}
@@ -0,0 +1,60 @@
+/// A resource of a type that is not known to this crate, such as a custom resource.
+///
+/// The `apiVersion`, `kind` and `metadata` fields are parsed. All other fields are preserved as-is in [`DynamicObject::data`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamicObject {
+    /// APIVersion defines the versioned schema of this representation of an object.
+    pub api_version: std::string::String,
+
+    /// Kind is a string value representing the REST resource this object represents.
+    pub kind: std::string::String,
+
+    /// Standard object's metadata.
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+
+    /// All other fields of the object, such as `spec` and `status`.
+    pub data: serde_json::Map<std::string::String, serde_json::Value>,
+}
+
+impl<'de> serde::Deserialize<'de> for DynamicObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let mut data: serde_json::Map<std::string::String, serde_json::Value> = serde::Deserialize::deserialize(deserializer)?;
+
+        let api_version = match data.remove("apiVersion") {
+            Some(serde_json::Value::String(api_version)) => api_version,
+            Some(_) => return Err(serde::de::Error::invalid_type(serde::de::Unexpected::Other("non-string apiVersion"), &"a string")),
+            None => return Err(serde::de::Error::missing_field("apiVersion")),
+        };
+
+        let kind = match data.remove("kind") {
+            Some(serde_json::Value::String(kind)) => kind,
+            Some(_) => return Err(serde::de::Error::invalid_type(serde::de::Unexpected::Other("non-string kind"), &"a string")),
+            None => return Err(serde::de::Error::missing_field("kind")),
+        };
+
+        let metadata = match data.remove("metadata") {
+            Some(metadata) => serde::Deserialize::deserialize(metadata).map_err(serde::de::Error::custom)?,
+            None => Default::default(),
+        };
+
+        Ok(DynamicObject {
+            api_version,
+            kind,
+            metadata,
+            data,
+        })
+    }
+}
+
+impl serde::Serialize for DynamicObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_map(Some(3 + self.data.len()))?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "apiVersion", &self.api_version)?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "kind", &self.kind)?;
+        serde::ser::SerializeMap::serialize_entry(&mut state, "metadata", &self.metadata)?;
+        for (key, value) in &self.data {
+            serde::ser::SerializeMap::serialize_entry(&mut state, key, value)?;
+        }
+        serde::ser::SerializeMap::end(state)
+    }
+}
@@ -0,0 +1,176 @@
+/// The path of a field within a value of type `T`, like `spec.nodeName` within a `Pod`.
+///
+/// Every struct generated from the OpenAPI spec has a `fields()` associated function that returns the path of the value itself,
+/// and `FieldPath`s of struct types have one method per field of the struct that returns the path of that field.
+/// So a path like `Pod::fields().spec().node_name()` is checked at compile time against the fields of the types,
+/// and uses the names of the fields in their JSON representation.
+///
+/// ```rust,ignore
+/// let path = Pod::fields().spec().node_name();
+/// assert_eq!(path.to_dotted(), "spec.nodeName");
+/// assert_eq!(path.to_json_pointer(), "/spec/nodeName");
+///
+/// let path = Pod::fields().spec().containers().at(0).image();
+/// assert_eq!(path.to_dotted(), "spec.containers[0].image");
+///
+/// let path = Pod::fields().metadata().labels().key("app.kubernetes.io/name");
+/// assert_eq!(path.to_dotted(), "metadata.labels['app.kubernetes.io/name']");
+/// assert_eq!(path.to_json_pointer(), "/metadata/labels/app.kubernetes.io~1name");
+/// ```
+///
+/// The path of an optional field is the path of its inner type, since the field has the same name in the JSON representation whether it's set or not.
+///
+/// Since inherent methods can only be added to `FieldPath` in this crate, the field methods of types generated by `k8s-openapi-codegen-common`
+/// and `k8s-openapi-derive` in other crates are declared in a `FooFields` trait next to the type `Foo` instead, which must be imported to use them.
+/// Other types can get field methods the same way, by implementing such a trait for their `FieldPath` with [`FieldPath::child`].
+pub struct FieldPath<T> where T: ?Sized {
+    segments: std::vec::Vec<Segment>,
+    _ty: core::marker::PhantomData<fn() -> T>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Segment {
+    Field(&'static str),
+    Index(usize),
+    Key(std::string::String),
+}
+
+impl<T> FieldPath<T> where T: ?Sized {
+    /// The path of the value itself. It is rendered as an empty string.
+    pub fn root() -> Self {
+        FieldPath {
+            segments: std::vec![],
+            _ty: core::marker::PhantomData,
+        }
+    }
+
+    /// Renders the path with `.` between field names, and `[index]` and `['key']` for array items and map values,
+    /// like the paths used in field selectors and API server error messages. Map keys that only contain alphanumeric characters, `-` and `_`
+    /// are rendered as field names instead.
+    pub fn to_dotted(&self) -> std::string::String {
+        use core::fmt::Write;
+
+        let mut result = std::string::String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Field(name) => {
+                    if !result.is_empty() {
+                        result.push('.');
+                    }
+                    result.push_str(name);
+                },
+
+                Segment::Index(index) => write!(result, "[{index}]").expect("writing to a String cannot fail"),
+
+                Segment::Key(key) if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') => {
+                    if !result.is_empty() {
+                        result.push('.');
+                    }
+                    result.push_str(key);
+                },
+
+                Segment::Key(key) => {
+                    result.push_str("['");
+                    for c in key.chars() {
+                        if matches!(c, '\'' | '\\') {
+                            result.push('\\');
+                        }
+                        result.push(c);
+                    }
+                    result.push_str("']");
+                },
+            }
+        }
+        result
+    }
+
+    /// Renders the path as a JSON Pointer as defined in RFC 6901, like the paths used in JSON patches.
+    pub fn to_json_pointer(&self) -> std::string::String {
+        use core::fmt::Write;
+
+        let mut result = std::string::String::new();
+        for segment in &self.segments {
+            result.push('/');
+            match segment {
+                Segment::Field(name) => push_json_pointer_token(&mut result, name),
+                Segment::Index(index) => write!(result, "{index}").expect("writing to a String cannot fail"),
+                Segment::Key(key) => push_json_pointer_token(&mut result, key),
+            }
+        }
+        result
+    }
+
+    /// Returns the path of the field with the given name in the JSON representation of `T`. Used by the generated field methods.
+    ///
+    /// The type of the field is not checked against the fields of `T`, so this should only be used to implement the field methods of `T`.
+    pub fn child<U>(self, name: &'static str) -> FieldPath<U> where U: ?Sized {
+        self.push(Segment::Field(name))
+    }
+
+    fn push<U>(mut self, segment: Segment) -> FieldPath<U> where U: ?Sized {
+        self.segments.push(segment);
+        FieldPath {
+            segments: self.segments,
+            _ty: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> FieldPath<std::vec::Vec<T>> {
+    /// The path of the item at the given index of the array.
+    pub fn at(self, index: usize) -> FieldPath<T> {
+        self.push(Segment::Index(index))
+    }
+}
+
+impl<T> FieldPath<std::collections::BTreeMap<std::string::String, T>> {
+    /// The path of the value with the given key in the map.
+    pub fn key(self, key: impl Into<std::string::String>) -> FieldPath<T> {
+        self.push(Segment::Key(key.into()))
+    }
+}
+
+impl<T> Clone for FieldPath<T> where T: ?Sized {
+    fn clone(&self) -> Self {
+        FieldPath {
+            segments: self.segments.clone(),
+            _ty: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for FieldPath<T> where T: ?Sized {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FieldPath").field(&self.to_dotted()).finish()
+    }
+}
+
+impl<T> core::fmt::Display for FieldPath<T> where T: ?Sized {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_dotted())
+    }
+}
+
+impl<T> PartialEq for FieldPath<T> where T: ?Sized {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl<T> Eq for FieldPath<T> where T: ?Sized {}
+
+impl<T> core::hash::Hash for FieldPath<T> where T: ?Sized {
+    fn hash<H>(&self, state: &mut H) where H: core::hash::Hasher {
+        self.segments.hash(state);
+    }
+}
+
+fn push_json_pointer_token(result: &mut std::string::String, token: &str) {
+    for c in token.chars() {
+        match c {
+            '~' => result.push_str("~0"),
+            '/' => result.push_str("~1"),
+            c => result.push(c),
+        }
+    }
+}
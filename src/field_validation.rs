@@ -0,0 +1,525 @@
+//! Validation of the fields of deserialized objects, like the `fieldValidation` query parameter of the API server.
+//!
+//! The `Deserialize` impls of the types in this crate ignore unknown fields, and use the last value of duplicate fields.
+//! The functions in this module deserialize a value the same way, but also report every unknown and duplicate field with its path.
+//! Depending on the [`FieldValidation`], the fields are either returned to the caller or make deserialization fail.
+//!
+//! ```rust,ignore
+//! let (pod, invalid_fields): (Pod, _) = k8s_openapi::field_validation::from_slice(json, FieldValidation::Warn)?;
+//! for invalid_field in invalid_fields {
+//!     eprintln!("warning: {invalid_field}");
+//! }
+//! ```
+//!
+//! ```text
+//! warning: unknown field "spec.containers[0].imagePullPolice"
+//! warning: duplicate field "metadata.name"
+//! ```
+//!
+//! A field is unknown if it's in an object that is deserialized as a struct but is not one of the struct's fields.
+//! Types whose `Deserialize` impl first deserializes into a [`serde_json::Value`], like [`AnyResource`](crate::AnyResource)
+//! and [`DynamicObject`](crate::DynamicObject), are only checked for duplicate fields. Use [`deserialize_seed`] with
+//! [`AnyResource::deserialize_with_type`](crate::AnyResource::deserialize_with_type) to also check an `AnyResource` for unknown fields.
+
+/// How unknown and duplicate fields are handled. This corresponds to the `fieldValidation` query parameter of the API server.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FieldValidation {
+    /// Unknown and duplicate fields are ignored. This is what the `Deserialize` impls of the types in this crate do.
+    #[default]
+    Ignore,
+
+    /// Unknown and duplicate fields are returned alongside the deserialized value.
+    Warn,
+
+    /// Deserialization fails if there are any unknown or duplicate fields. The error lists all of them.
+    Strict,
+}
+
+impl FieldValidation {
+    /// The value of the `fieldValidation` query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FieldValidation::Ignore => "Ignore",
+            FieldValidation::Warn => "Warn",
+            FieldValidation::Strict => "Strict",
+        }
+    }
+}
+
+/// An unknown or duplicate field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidField {
+    /// Whether the field is unknown or duplicate.
+    pub kind: InvalidFieldKind,
+
+    /// The path of the field, like `spec.containers[0].image`.
+    pub path: std::string::String,
+}
+
+impl core::fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            InvalidFieldKind::Unknown => write!(f, "unknown field {:?}", self.path),
+            InvalidFieldKind::Duplicate => write!(f, "duplicate field {:?}", self.path),
+        }
+    }
+}
+
+/// The kind of an [`InvalidField`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvalidFieldKind {
+    /// The field is not a field of the struct it was found in.
+    Unknown,
+
+    /// The field was found more than once in the same object.
+    Duplicate,
+}
+
+/// Deserializes a value from the given deserializer, and validates its fields according to the given [`FieldValidation`].
+pub fn deserialize<'de, T, D>(deserializer: D, field_validation: FieldValidation) -> Result<(T, std::vec::Vec<InvalidField>), D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    deserialize_seed(core::marker::PhantomData, deserializer, field_validation)
+}
+
+/// Like [`deserialize`], but deserializes the value with the given [`serde::de::DeserializeSeed`].
+pub fn deserialize_seed<'de, S, D>(seed: S, deserializer: D, field_validation: FieldValidation) -> Result<(S::Value, std::vec::Vec<InvalidField>), D::Error>
+where
+    S: serde::de::DeserializeSeed<'de>,
+    D: serde::Deserializer<'de>,
+{
+    if field_validation == FieldValidation::Ignore {
+        return Ok((seed.deserialize(deserializer)?, std::vec![]));
+    }
+
+    let invalid_fields = Default::default();
+    let value = seed.deserialize(Deserializer {
+        inner: deserializer,
+        context: Context {
+            path: &Path::Root,
+            invalid_fields: &invalid_fields,
+            #[cfg(feature = "preserve-unknown-fields")]
+            unknown_fields: None,
+        },
+        key: None,
+    })?;
+    let invalid_fields = invalid_fields.into_inner();
+
+    if field_validation == FieldValidation::Strict && !invalid_fields.is_empty() {
+        return Err(serde::de::Error::custom(StrictDecodingError(&invalid_fields)));
+    }
+
+    Ok((value, invalid_fields))
+}
+
+/// Deserializes a value from the given JSON, and validates its fields according to the given [`FieldValidation`].
+pub fn from_slice<T>(v: &[u8], field_validation: FieldValidation) -> Result<(T, std::vec::Vec<InvalidField>), serde_json::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(v);
+    let result = deserialize(&mut deserializer, field_validation)?;
+    deserializer.end()?;
+    Ok(result)
+}
+
+/// Deserializes a value from the given deserializer, and returns the unknown fields along with their values.
+///
+/// Duplicate fields are not reported, and the last value of a duplicate field is used, like with [`FieldValidation::Ignore`].
+#[cfg(feature = "preserve-unknown-fields")]
+pub(crate) fn deserialize_preserving_unknown_fields<'de, T, D>(deserializer: D) -> Result<(T, std::vec::Vec<crate::unknown_fields::UnknownField>), D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    let invalid_fields = Default::default();
+    let unknown_fields = Default::default();
+    let value = serde::Deserialize::deserialize(Deserializer {
+        inner: deserializer,
+        context: Context {
+            path: &Path::Root,
+            invalid_fields: &invalid_fields,
+            unknown_fields: Some(&unknown_fields),
+        },
+        key: None,
+    })?;
+    Ok((value, unknown_fields.into_inner()))
+}
+
+/// The error message of [`FieldValidation::Strict`], in the same format as the API server's.
+struct StrictDecodingError<'a>(&'a [InvalidField]);
+
+impl core::fmt::Display for StrictDecodingError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("strict decoding error: ")?;
+        for (i, invalid_field) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{invalid_field}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The path of the value currently being deserialized.
+enum Path<'a> {
+    Root,
+    Key { parent: &'a Path<'a>, key: &'a str },
+    Index { parent: &'a Path<'a>, index: usize },
+}
+
+impl core::fmt::Display for Path<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Path::Root => Ok(()),
+            Path::Key { parent: Path::Root, key } => f.write_str(key),
+            Path::Key { parent, key } => write!(f, "{parent}.{key}"),
+            Path::Index { parent, index } => write!(f, "{parent}[{index}]"),
+        }
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl Path<'_> {
+    fn to_segments(&self, segments: &mut std::vec::Vec<crate::unknown_fields::PathSegment>) {
+        match self {
+            Path::Root => (),
+            Path::Key { parent, key } => {
+                parent.to_segments(segments);
+                segments.push(crate::unknown_fields::PathSegment::Key((*key).into()));
+            },
+            Path::Index { parent, index } => {
+                parent.to_segments(segments);
+                segments.push(crate::unknown_fields::PathSegment::Index(*index));
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    path: &'a Path<'a>,
+    invalid_fields: &'a core::cell::RefCell<std::vec::Vec<InvalidField>>,
+
+    /// If set, the values of unknown fields are collected here.
+    #[cfg(feature = "preserve-unknown-fields")]
+    unknown_fields: Option<&'a core::cell::RefCell<std::vec::Vec<crate::unknown_fields::UnknownField>>>,
+}
+
+impl Context<'_> {
+    fn push(self, kind: InvalidFieldKind, key: &str) {
+        let path = Path::Key { parent: self.path, key };
+        self.invalid_fields.borrow_mut().push(InvalidField { kind, path: std::string::ToString::to_string(&path) });
+    }
+}
+
+/// Wraps a deserializer to track the path of the value being deserialized.
+///
+/// If `key` is set, the value is a key of a map, and its string value is stored in `key`.
+struct Deserializer<'a, D> {
+    inner: D,
+    context: Context<'a>,
+    key: Option<&'a core::cell::Cell<Option<std::string::String>>>,
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident ($($arg:ident : $ty:ty),*))*) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+                self.inner.$method($($arg,)* Visitor { inner: visitor, context: self.context, key: self.key, fields: None })
+            }
+        )*
+    };
+}
+
+impl<'de, D> serde::Deserializer<'de> for Deserializer<'_, D> where D: serde::Deserializer<'de> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+    }
+
+    fn deserialize_struct<V>(self, name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.inner.deserialize_struct(name, fields, Visitor { inner: visitor, context: self.context, key: self.key, fields: Some(fields) })
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        // The fields of an ignored value are not validated.
+        self.inner.deserialize_ignored_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Wraps a visitor to track the path of the value being deserialized.
+///
+/// If `fields` is set, the value is a struct with these fields.
+struct Visitor<'a, V> {
+    inner: V,
+    context: Context<'a>,
+    key: Option<&'a core::cell::Cell<Option<std::string::String>>>,
+    fields: Option<&'static [&'static str]>,
+}
+
+macro_rules! forward_visit {
+    ($($method:ident ($ty:ty))*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E> where E: serde::de::Error {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V> serde::de::Visitor<'de> for Visitor<'_, V> where V: serde::de::Visitor<'de> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool)
+        visit_i8(i8)
+        visit_i16(i16)
+        visit_i32(i32)
+        visit_i64(i64)
+        visit_i128(i128)
+        visit_u8(u8)
+        visit_u16(u16)
+        visit_u32(u32)
+        visit_u64(u64)
+        visit_u128(u128)
+        visit_f32(f32)
+        visit_f64(f64)
+        visit_char(char)
+        visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8])
+        visit_byte_buf(std::vec::Vec<u8>)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        if let Some(key) = self.key {
+            key.set(Some(v.into()));
+        }
+        self.inner.visit_str(v)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> where E: serde::de::Error {
+        if let Some(key) = self.key {
+            key.set(Some(v.into()));
+        }
+        self.inner.visit_borrowed_str(v)
+    }
+
+    fn visit_string<E>(self, v: std::string::String) -> Result<Self::Value, E> where E: serde::de::Error {
+        if let Some(key) = self.key {
+            key.set(Some(v.clone()));
+        }
+        self.inner.visit_string(v)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.inner.visit_some(Deserializer { inner: deserializer, context: self.context, key: self.key })
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.inner.visit_newtype_struct(Deserializer { inner: deserializer, context: self.context, key: self.key })
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        self.inner.visit_seq(SeqAccess { inner: seq, context: self.context, index: 0 })
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+        self.inner.visit_map(MapAccess {
+            inner: map,
+            context: self.context,
+            fields: self.fields,
+            keys: Default::default(),
+            key: None,
+        })
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error> where A: serde::de::EnumAccess<'de> {
+        self.inner.visit_enum(EnumAccess { inner: data, context: self.context })
+    }
+}
+
+/// Wraps a seed to track the path of the value being deserialized.
+struct DeserializeSeed<'a, S> {
+    inner: S,
+    context: Context<'a>,
+    key: Option<&'a core::cell::Cell<Option<std::string::String>>>,
+}
+
+impl<'de, S> serde::de::DeserializeSeed<'de> for DeserializeSeed<'_, S> where S: serde::de::DeserializeSeed<'de> {
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        self.inner.deserialize(Deserializer { inner: deserializer, context: self.context, key: self.key })
+    }
+}
+
+struct SeqAccess<'a, A> {
+    inner: A,
+    context: Context<'a>,
+    index: usize,
+}
+
+impl<'de, A> serde::de::SeqAccess<'de> for SeqAccess<'_, A> where A: serde::de::SeqAccess<'de> {
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> where T: serde::de::DeserializeSeed<'de> {
+        let path = Path::Index { parent: self.context.path, index: self.index };
+        self.index += 1;
+        self.inner.next_element_seed(DeserializeSeed { inner: seed, context: Context { path: &path, ..self.context }, key: None })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct MapAccess<'a, A> {
+    inner: A,
+    context: Context<'a>,
+    fields: Option<&'static [&'static str]>,
+    keys: std::collections::BTreeSet<std::string::String>,
+
+    /// The key of the value that will be deserialized next, if it was a string.
+    key: Option<std::string::String>,
+}
+
+impl<'de, A> serde::de::MapAccess<'de> for MapAccess<'_, A> where A: serde::de::MapAccess<'de> {
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> where K: serde::de::DeserializeSeed<'de> {
+        let key = core::cell::Cell::new(None);
+        let result = self.inner.next_key_seed(DeserializeSeed { inner: seed, context: self.context, key: Some(&key) })?;
+        self.key = key.into_inner();
+
+        if let Some(key) = &self.key {
+            if self.fields.is_some_and(|fields| !fields.contains(&&**key)) {
+                self.context.push(InvalidFieldKind::Unknown, key);
+            }
+
+            if !self.keys.insert(key.clone()) {
+                self.context.push(InvalidFieldKind::Duplicate, key);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        let key = self.key.take();
+        let path = match &key {
+            Some(key) => Path::Key { parent: self.context.path, key },
+            None => Path::Key { parent: self.context.path, key: "?" },
+        };
+
+        #[cfg(feature = "preserve-unknown-fields")]
+        if let (Some(key), Some(fields), Some(unknown_fields)) = (&key, self.fields, self.context.unknown_fields) {
+            if !fields.contains(&&**key) {
+                // The struct ignores the value, so deserialize it as a `serde_json::Value` and let the struct ignore that instead.
+                let value: serde_json::Value = self.inner.next_value()?;
+                let result = seed.deserialize(value.clone()).map_err(serde::de::Error::custom)?;
+                let mut path_segments = std::vec![];
+                path.to_segments(&mut path_segments);
+                unknown_fields.borrow_mut().push(crate::unknown_fields::UnknownField { path: path_segments, value });
+                return Ok(result);
+            }
+        }
+
+        self.inner.next_value_seed(DeserializeSeed { inner: seed, context: Context { path: &path, ..self.context }, key: None })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct EnumAccess<'a, A> {
+    inner: A,
+    context: Context<'a>,
+}
+
+impl<'a, 'de, A> serde::de::EnumAccess<'de> for EnumAccess<'a, A> where A: serde::de::EnumAccess<'de> {
+    type Error = A::Error;
+    type Variant = VariantAccess<'a, A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error> where V: serde::de::DeserializeSeed<'de> {
+        let (value, variant) = self.inner.variant_seed(DeserializeSeed { inner: seed, context: self.context, key: None })?;
+        Ok((value, VariantAccess { inner: variant, context: self.context }))
+    }
+}
+
+struct VariantAccess<'a, A> {
+    inner: A,
+    context: Context<'a>,
+}
+
+impl<'de, A> serde::de::VariantAccess<'de> for VariantAccess<'_, A> where A: serde::de::VariantAccess<'de> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error> where T: serde::de::DeserializeSeed<'de> {
+        self.inner.newtype_variant_seed(DeserializeSeed { inner: seed, context: self.context, key: None })
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        self.inner.tuple_variant(len, Visitor { inner: visitor, context: self.context, key: None, fields: None })
+    }
+
+    fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> where V: serde::de::Visitor<'de> {
+        self.inner.struct_variant(fields, Visitor { inner: visitor, context: self.context, key: None, fields: Some(fields) })
+    }
+}
@@ -0,0 +1,147 @@
+/// A string that shares its allocation with all other `InternedStr`s of the same contents.
+///
+/// Used for the keys of maps in the compact variants of the Kubernetes types in the `compact` module. Objects tend to use the same small set of
+/// label and annotation keys, like `app.kubernetes.io/name`, so all copies of a key share a single allocation from a process-wide interner.
+/// The interner is sharded by the hash of the string, and drops strings that are no longer used as it grows.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InternedStr(std::sync::Arc<str>);
+
+impl InternedStr {
+    /// Returns the interned string with the given contents.
+    pub fn new(s: &str) -> Self {
+        InternedStr(intern(s))
+    }
+
+    /// Returns the string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for InternedStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::borrow::Borrow<str> for InternedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for InternedStr {
+    fn from(s: &str) -> Self {
+        InternedStr::new(s)
+    }
+}
+
+impl From<std::string::String> for InternedStr {
+    fn from(s: std::string::String) -> Self {
+        InternedStr::new(&s)
+    }
+}
+
+impl From<InternedStr> for std::string::String {
+    fn from(s: InternedStr) -> Self {
+        (*s.0).into()
+    }
+}
+
+impl PartialEq<str> for InternedStr {
+    fn eq(&self, other: &str) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<&str> for InternedStr {
+    fn eq(&self, other: &&str) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InternedStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = InternedStr;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(InternedStr::new(v))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl serde::Serialize for InternedStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+/// One shard of the process-wide interner.
+///
+/// Strings are assigned to shards by their hash, so that threads that deserialize objects concurrently rarely contend for the same lock.
+struct Interner {
+    strings: std::collections::BTreeSet<std::sync::Arc<str>>,
+
+    /// The number of strings at which the shard next drops the strings that are no longer used by any `InternedStr`.
+    purge_len: usize,
+}
+
+const NUM_SHARDS: usize = 32;
+
+const MIN_PURGE_LEN: usize = 1024 / NUM_SHARDS;
+
+static INTERNER: [std::sync::Mutex<Interner>; NUM_SHARDS] = [const {
+    std::sync::Mutex::new(Interner {
+        strings: std::collections::BTreeSet::new(),
+        purge_len: MIN_PURGE_LEN,
+    })
+}; NUM_SHARDS];
+
+fn intern(s: &str) -> std::sync::Arc<str> {
+    let shard = {
+        let mut hasher = std::hash::DefaultHasher::new();
+        std::hash::Hash::hash(s, &mut hasher);
+        #[allow(clippy::cast_possible_truncation)] // Only the low bits are used.
+        let hash = std::hash::Hasher::finish(&hasher) as usize;
+        hash % NUM_SHARDS
+    };
+
+    let mut interner = INTERNER[shard].lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if let Some(s) = interner.strings.get(s) {
+        return s.clone();
+    }
+
+    if interner.strings.len() >= interner.purge_len {
+        interner.strings.retain(|s| std::sync::Arc::strong_count(s) > 1);
+        interner.purge_len = (interner.strings.len() * 2).max(MIN_PURGE_LEN);
+    }
+
+    let s: std::sync::Arc<str> = s.into();
+    interner.strings.insert(s.clone());
+    s
+}
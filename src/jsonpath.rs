@@ -0,0 +1,683 @@
+//! JSONPath templates in the dialect of `kubectl get -o jsonpath`.
+//!
+//! A [`JsonPath`] template is text with actions in braces. Each action is evaluated against the JSON representation of a value,
+//! so templates written for `kubectl` work unchanged against the types in this crate, including [`List`](crate::List)s.
+//!
+//! ```rust,ignore
+//! let pods: k8s_openapi::List<Pod> = ...;
+//! let template: k8s_openapi::jsonpath::JsonPath = r#"{range .items[?(@.status.phase=="Running")]}{.metadata.name}{"\n"}{end}"#.parse()?;
+//! print!("{}", template.execute(&pods)?);
+//! ```
+//!
+//! The supported syntax is:
+//!
+//! | Syntax                          | Meaning                                                              |
+//! |---------------------------------|----------------------------------------------------------------------|
+//! | `text`                          | Literal text.                                                        |
+//! | `{"\n"}`                        | A quoted string, with `\n`, `\t`, `\\` and `\"` escapes.            |
+//! | `$`, `@`                        | The root value and the current value. Paths start at `@` by default. |
+//! | `.name`, `['name']`             | A field of an object. `.` in field names can be escaped as `\.`.     |
+//! | `..name`                        | The `name` fields of the value and all of its descendants.           |
+//! | `*`, `[*]`                      | All fields of an object or items of an array.                       |
+//! | `[0]`, `[-1]`, `[0,2]`          | Items of an array, counting from the end if negative.               |
+//! | `[start:end:step]`              | A slice of an array.                                                 |
+//! | `[?(@.name=="value")]`          | The items of an array that match a filter. The operators are `==`, `!=`, `<`, `<=`, `>` and `>=`. A filter without an operator matches the items that have the field. |
+//! | `{range .items[*]}...{end}`     | Evaluates the template between `range` and `end` once for each result, with the result as the current value. |
+//!
+//! The results of an action are printed separated by spaces. Strings are printed without quotes, and objects and arrays are printed as JSON,
+//! like `kubectl` does.
+
+/// A parsed JSONPath template.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonPath {
+    nodes: std::vec::Vec<Node>,
+    allow_missing_keys: bool,
+}
+
+impl JsonPath {
+    /// Parses a JSONPath template.
+    pub fn parse(template: &str) -> Result<Self, Error> {
+        let mut nodes = std::vec![];
+
+        // Each `range` action pushes a frame, and the matching `end` pops it into a `Node::Range`.
+        let mut ranges: std::vec::Vec<(Path, std::vec::Vec<Node>)> = std::vec![];
+
+        let mut rest = template;
+        while !rest.is_empty() {
+            let Some(start) = rest.find('{') else {
+                push_node(&mut nodes, &mut ranges, Node::Text(rest.into()));
+                break;
+            };
+            if start > 0 {
+                push_node(&mut nodes, &mut ranges, Node::Text(rest[..start].into()));
+            }
+            rest = &rest[(start + 1)..];
+
+            let end = find_unquoted(rest, |c| c == '}').ok_or_else(|| Error::Parse("unclosed action".into()))?;
+            let action = rest[..end].trim();
+            rest = &rest[(end + 1)..];
+
+            if action == "end" {
+                let Some((path, range_nodes)) = ranges.pop() else {
+                    return Err(Error::Parse("not in range, nothing to end".into()));
+                };
+                push_node(&mut nodes, &mut ranges, Node::Range(path, range_nodes));
+            }
+            else if let Some(path) = action.strip_prefix("range").filter(|path| path.starts_with(char::is_whitespace)) {
+                ranges.push((Path::parse(path.trim())?, std::vec![]));
+            }
+            else if action.starts_with(['"', '\'']) {
+                let (s, rest) = parse_quoted(action)?;
+                if !rest.is_empty() {
+                    return Err(Error::Parse(std::format!("unrecognized character in action: {rest}")));
+                }
+                push_node(&mut nodes, &mut ranges, Node::Text(s));
+            }
+            else {
+                push_node(&mut nodes, &mut ranges, Node::Path(Path::parse(action)?));
+            }
+        }
+
+        if !ranges.is_empty() {
+            return Err(Error::Parse("unclosed range".into()));
+        }
+
+        Ok(JsonPath {
+            nodes,
+            allow_missing_keys: false,
+        })
+    }
+
+    /// Sets whether fields and array items that are not found are ignored, instead of failing the evaluation.
+    ///
+    /// This is `false` by default. `kubectl get -o jsonpath` sets it to `true`.
+    #[must_use]
+    pub fn allow_missing_keys(mut self, allow_missing_keys: bool) -> Self {
+        self.allow_missing_keys = allow_missing_keys;
+        self
+    }
+
+    /// Evaluates the template against the JSON representation of the given value.
+    pub fn execute<T>(&self, value: &T) -> Result<std::string::String, Error> where T: serde::Serialize + ?Sized {
+        let value = serde_json::to_value(value).map_err(Error::Serialize)?;
+        self.execute_value(&value)
+    }
+
+    /// Evaluates the template against the given JSON value.
+    pub fn execute_value(&self, value: &serde_json::Value) -> Result<std::string::String, Error> {
+        let mut out = std::string::String::new();
+        self.render(&self.nodes, value, value, &mut out)?;
+        Ok(out)
+    }
+
+    fn render(&self, nodes: &[Node], root: &serde_json::Value, current: &serde_json::Value, out: &mut std::string::String) -> Result<(), Error> {
+        for node in nodes {
+            match node {
+                Node::Text(text) => out.push_str(text),
+
+                Node::Path(path) => {
+                    for (i, value) in evaluate(path, root, current, self.allow_missing_keys)?.into_iter().enumerate() {
+                        if i > 0 {
+                            out.push(' ');
+                        }
+                        write_value(value, out)?;
+                    }
+                },
+
+                Node::Range(path, nodes) => {
+                    for value in evaluate(path, root, current, self.allow_missing_keys)? {
+                        self.render(nodes, root, value, out)?;
+                    }
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for JsonPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        JsonPath::parse(s)
+    }
+}
+
+/// An error from parsing or evaluating a [`JsonPath`] template.
+#[derive(Debug)]
+pub enum Error {
+    /// The template could not be parsed.
+    Parse(std::string::String),
+
+    /// The template could not be evaluated against the value, such as because a field was not found.
+    Evaluate(std::string::String),
+
+    /// The value could not be serialized to JSON.
+    Serialize(serde_json::Error),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "could not parse JSONPath template: {err}"),
+            Error::Evaluate(err) => write!(f, "could not evaluate JSONPath template: {err}"),
+            Error::Serialize(err) => write!(f, "could not serialize value: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Parse(_) | Error::Evaluate(_) => None,
+            Error::Serialize(err) => Some(err),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Text(std::string::String),
+    Path(Path),
+    Range(Path, std::vec::Vec<Node>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Path {
+    from_root: bool,
+    steps: std::vec::Vec<Step>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    /// A bare identifier like `items`. kubectl parses these, but fails to evaluate any other than `range` and `end`.
+    Identifier(std::string::String),
+    Field(std::string::String),
+    RecursiveDescent,
+    Wildcard,
+    Selectors(std::vec::Vec<Selector>),
+    Filter(std::boxed::Box<Filter>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Selector {
+    Key(std::string::String),
+    Index(i64),
+    Slice { start: Option<i64>, end: Option<i64>, step: Option<i64> },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Filter {
+    left: Path,
+    right: Option<(Operator, Operand)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Literal(serde_json::Value),
+    Path(Path),
+}
+
+impl Path {
+    fn parse(s: &str) -> Result<Self, Error> {
+        let mut steps = std::vec![];
+
+        let (from_root, mut rest) =
+            if let Some(rest) = s.strip_prefix('$') { (true, rest) }
+            else if let Some(rest) = s.strip_prefix('@') { (false, rest) }
+            else if s.starts_with(['.', '[']) { (false, s) }
+            else {
+                let (name, rest) = parse_field_name(s);
+                if name.is_empty() {
+                    return Err(Error::Parse(std::format!("unrecognized character in action: {s}")));
+                }
+                steps.push(Step::Identifier(name));
+                (false, rest)
+            };
+
+        loop {
+            if let Some(after) = rest.strip_prefix("..") {
+                steps.push(Step::RecursiveDescent);
+                rest = after;
+                if let Some(after) = rest.strip_prefix('*') {
+                    steps.push(Step::Wildcard);
+                    rest = after;
+                }
+                else if !rest.starts_with('[') {
+                    let (name, after) = parse_field_name(rest);
+                    if name.is_empty() {
+                        return Err(Error::Parse("missing field name after ..".into()));
+                    }
+                    steps.push(Step::Field(name));
+                    rest = after;
+                }
+            }
+            else if let Some(after) = rest.strip_prefix('.') {
+                rest = after;
+                if let Some(after) = rest.strip_prefix('*') {
+                    steps.push(Step::Wildcard);
+                    rest = after;
+                }
+                else {
+                    let (name, after) = parse_field_name(rest);
+                    if !name.is_empty() {
+                        steps.push(Step::Field(name));
+                    }
+                    rest = after;
+                }
+            }
+            else if let Some(after) = rest.strip_prefix('[') {
+                let end = find_unquoted(after, |c| c == ']').ok_or_else(|| Error::Parse("unclosed array expect ]".into()))?;
+                steps.push(parse_brackets(after[..end].trim())?);
+                rest = &after[(end + 1)..];
+            }
+            else {
+                break;
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(Error::Parse(std::format!("unrecognized character in action: {rest}")));
+        }
+
+        Ok(Path { from_root, steps })
+    }
+}
+
+fn push_node(nodes: &mut std::vec::Vec<Node>, ranges: &mut [(Path, std::vec::Vec<Node>)], node: Node) {
+    match ranges.last_mut() {
+        Some((_, range_nodes)) => range_nodes.push(node),
+        None => nodes.push(node),
+    }
+}
+
+/// Parses the contents of a `[...]` step.
+fn parse_brackets(s: &str) -> Result<Step, Error> {
+    if s == "*" {
+        return Ok(Step::Wildcard);
+    }
+
+    if let Some(filter) = s.strip_prefix('?') {
+        let filter =
+            filter.trim().strip_prefix('(').and_then(|filter| filter.strip_suffix(')'))
+            .ok_or_else(|| Error::Parse(std::format!("unmatched filter {s}")))?;
+        return Ok(Step::Filter(std::boxed::Box::new(parse_filter(filter.trim())?)));
+    }
+
+    let mut selectors = std::vec![];
+    let mut rest = s;
+    loop {
+        let end = find_unquoted(rest, |c| c == ',').unwrap_or(rest.len());
+        let selector = rest[..end].trim();
+
+        if selector.starts_with(['"', '\'']) {
+            let (key, after) = parse_quoted(selector)?;
+            if !after.is_empty() {
+                return Err(Error::Parse(std::format!("unrecognized character in array: {after}")));
+            }
+            selectors.push(Selector::Key(key));
+        }
+        else if selector.contains(':') {
+            let mut parts = selector.split(':').map(|part| {
+                let part = part.trim();
+                if part.is_empty() { Ok(None) } else { parse_index(part).map(Some) }
+            });
+            let start = parts.next().transpose()?.flatten();
+            let end = parts.next().transpose()?.flatten();
+            let step = parts.next().transpose()?.flatten();
+            if parts.next().is_some() {
+                return Err(Error::Parse(std::format!("invalid array slice {selector}")));
+            }
+            selectors.push(Selector::Slice { start, end, step });
+        }
+        else {
+            selectors.push(Selector::Index(parse_index(selector)?));
+        }
+
+        if end == rest.len() {
+            break;
+        }
+        rest = &rest[(end + 1)..];
+    }
+
+    Ok(Step::Selectors(selectors))
+}
+
+fn parse_index(s: &str) -> Result<i64, Error> {
+    s.parse().map_err(|_| Error::Parse(std::format!("invalid array index {s}")))
+}
+
+fn parse_filter(s: &str) -> Result<Filter, Error> {
+    const OPERATORS: &[(&str, Operator)] = &[
+        ("==", Operator::Eq),
+        ("!=", Operator::Ne),
+        ("<=", Operator::Le),
+        (">=", Operator::Ge),
+        ("<", Operator::Lt),
+        (">", Operator::Gt),
+    ];
+
+    let Some(op_start) = find_unquoted(s, |c| matches!(c, '=' | '!' | '<' | '>')) else {
+        return Ok(Filter { left: Path::parse(s)?, right: None });
+    };
+
+    let (left, rest) = s.split_at(op_start);
+    let (op_str, op) =
+        OPERATORS.iter().find(|(op_str, _)| rest.starts_with(op_str))
+        .ok_or_else(|| Error::Parse(std::format!("unrecognized filter operator in {s}")))?;
+    let left = Path::parse(left.trim())?;
+    let right = rest[op_str.len()..].trim();
+
+    let right =
+        if right.starts_with(['"', '\'']) {
+            let (right, after) = parse_quoted(right)?;
+            if !after.is_empty() {
+                return Err(Error::Parse(std::format!("unrecognized character in filter: {after}")));
+            }
+            Operand::Literal(serde_json::Value::String(right))
+        }
+        else if right.starts_with(['$', '@', '.']) {
+            Operand::Path(Path::parse(right)?)
+        }
+        else if let Ok(right) = right.parse::<i64>() {
+            Operand::Literal(right.into())
+        }
+        else if let Some(right) = right.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+            Operand::Literal(serde_json::Value::Number(right))
+        }
+        else {
+            match right {
+                "true" => Operand::Literal(true.into()),
+                "false" => Operand::Literal(false.into()),
+                "null" => Operand::Literal(serde_json::Value::Null),
+                right => return Err(Error::Parse(std::format!("unrecognized filter operand {right}"))),
+            }
+        };
+
+    Ok(Filter { left, right: Some((*op, right)) })
+}
+
+/// Parses a field name up to the next character that cannot be part of it, and returns the name and the rest of the string.
+/// A `\` escapes the following character, so that field names can contain `.`
+fn parse_field_name(s: &str) -> (std::string::String, &str) {
+    let mut name = std::string::String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => if let Some((_, c)) = chars.next() {
+                name.push(c);
+            },
+            '.' | '[' | ']' | '(' | ')' | ',' | '=' | '!' | '<' | '>' | '"' | '\'' => return (name, &s[i..]),
+            c if c.is_whitespace() => return (name, &s[i..]),
+            c => name.push(c),
+        }
+    }
+    (name, "")
+}
+
+/// Parses a quoted string at the start of `s`, and returns the unescaped string and the rest of `s`.
+fn parse_quoted(s: &str) -> Result<(std::string::String, &str), Error> {
+    let mut chars = s.char_indices();
+    let Some((_, quote)) = chars.next() else {
+        return Err(Error::Parse("expected quoted string".into()));
+    };
+
+    let mut result = std::string::String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'n')) => result.push('\n'),
+                Some((_, 't')) => result.push('\t'),
+                Some((_, 'r')) => result.push('\r'),
+                Some((_, c)) => result.push(c),
+                None => break,
+            },
+            c if c == quote => return Ok((result, s[(i + c.len_utf8())..].trim_start())),
+            c => result.push(c),
+        }
+    }
+
+    Err(Error::Parse(std::format!("unterminated quoted string {s}")))
+}
+
+/// Returns the index of the first character of `s` that matches `f` and is not inside a quoted string or parentheses.
+fn find_unquoted(s: &str, f: impl Fn(char) -> bool) -> Option<usize> {
+    let mut quote = None;
+    let mut depth = 0_usize;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => { chars.next(); },
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if depth == 0 && f(c) => return Some(i),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+    None
+}
+
+fn evaluate<'a>(
+    path: &Path,
+    root: &'a serde_json::Value,
+    current: &'a serde_json::Value,
+    allow_missing_keys: bool,
+) -> Result<std::vec::Vec<&'a serde_json::Value>, Error> {
+    let mut values = std::vec![if path.from_root { root } else { current }];
+
+    // The step after `..` is applied to every descendant, so not finding it is not an error.
+    let mut after_recursive_descent = false;
+
+    for step in &path.steps {
+        let allow_missing_keys = allow_missing_keys || after_recursive_descent;
+        let mut next = std::vec![];
+
+        match step {
+            Step::Identifier(name) => return Err(Error::Evaluate(std::format!("unrecognized identifier {name}"))),
+
+            Step::Field(name) => for value in values {
+                match value.get(name) {
+                    Some(value) => next.push(value),
+                    None if allow_missing_keys => (),
+                    None => return Err(Error::Evaluate(std::format!("{name} is not found"))),
+                }
+            },
+
+            Step::RecursiveDescent => for value in values {
+                push_descendants(value, &mut next);
+            },
+
+            Step::Wildcard => for value in values {
+                match value {
+                    serde_json::Value::Object(map) => next.extend(map.values()),
+                    serde_json::Value::Array(items) => next.extend(items),
+                    _ => (),
+                }
+            },
+
+            // Like kubectl, each selector of a union is applied to all the values before the next one,
+            // so `[*]['name', 'image']` returns all the names before all the images.
+            Step::Selectors(selectors) => for selector in selectors {
+                for &value in &values {
+                    select(value, selector, allow_missing_keys, &mut next)?;
+                }
+            },
+
+            Step::Filter(filter) => for value in values {
+                match value {
+                    serde_json::Value::Array(items) => for item in items {
+                        if filter_matches(filter, root, item)? {
+                            next.push(item);
+                        }
+                    },
+                    _ if allow_missing_keys => (),
+                    _ => return Err(Error::Evaluate(std::format!("{value} is not array or slice"))),
+                }
+            },
+        }
+
+        values = next;
+        after_recursive_descent = matches!(step, Step::RecursiveDescent);
+    }
+
+    Ok(values)
+}
+
+fn filter_matches(filter: &Filter, root: &serde_json::Value, item: &serde_json::Value) -> Result<bool, Error> {
+    // Fields that are missing from an item never match, regardless of `allow_missing_keys`.
+    let lefts = evaluate(&filter.left, root, item, true)?;
+    let Some((op, right)) = &filter.right else {
+        return Ok(!lefts.is_empty());
+    };
+    let left = match &*lefts {
+        [] => return Ok(false),
+        [left] => *left,
+        _ => return Err(Error::Evaluate("can only compare one element at a time".into())),
+    };
+
+    let rights = match right {
+        Operand::Literal(right) => std::vec![right],
+        Operand::Path(right) => evaluate(right, root, item, true)?,
+    };
+    let right = match &*rights {
+        [] => return Ok(false),
+        [right] => *right,
+        _ => return Err(Error::Evaluate("can only compare one element at a time".into())),
+    };
+
+    let ordering = match (left, right) {
+        (serde_json::Value::Number(left), serde_json::Value::Number(right)) => match (left.as_i64(), right.as_i64()) {
+            (Some(left), Some(right)) => Some(left.cmp(&right)),
+            _ => left.as_f64().zip(right.as_f64()).and_then(|(left, right)| left.partial_cmp(&right)),
+        },
+        (serde_json::Value::String(left), serde_json::Value::String(right)) => Some(left.cmp(right)),
+        (serde_json::Value::Bool(_), serde_json::Value::Bool(_)) |
+        (serde_json::Value::Null, serde_json::Value::Null) => None,
+        _ => return Err(Error::Evaluate("incompatible types for comparison".into())),
+    };
+
+    Ok(match (op, ordering) {
+        (Operator::Eq, Some(ordering)) => ordering.is_eq(),
+        (Operator::Eq, None) => left == right,
+        (Operator::Ne, Some(ordering)) => ordering.is_ne(),
+        (Operator::Ne, None) => left != right,
+        (Operator::Lt, Some(ordering)) => ordering.is_lt(),
+        (Operator::Le, Some(ordering)) => ordering.is_le(),
+        (Operator::Gt, Some(ordering)) => ordering.is_gt(),
+        (Operator::Ge, Some(ordering)) => ordering.is_ge(),
+        (_, None) => return Err(Error::Evaluate("invalid type for comparison".into())),
+    })
+}
+
+fn push_descendants<'a>(value: &'a serde_json::Value, out: &mut std::vec::Vec<&'a serde_json::Value>) {
+    out.push(value);
+    match value {
+        serde_json::Value::Object(map) => for value in map.values() {
+            push_descendants(value, out);
+        },
+        serde_json::Value::Array(items) => for value in items {
+            push_descendants(value, out);
+        },
+        _ => (),
+    }
+}
+
+fn select<'a>(
+    value: &'a serde_json::Value,
+    selector: &Selector,
+    allow_missing_keys: bool,
+    out: &mut std::vec::Vec<&'a serde_json::Value>,
+) -> Result<(), Error> {
+    match (value, selector) {
+        (serde_json::Value::Object(map), Selector::Key(key)) => match map.get(key) {
+            Some(value) => out.push(value),
+            None if allow_missing_keys => (),
+            None => return Err(Error::Evaluate(std::format!("{key} is not found"))),
+        },
+
+        (serde_json::Value::Array(items), Selector::Index(index)) => {
+            let len = items.len();
+            match resolve_index(*index, len).and_then(|index| items.get(index)) {
+                Some(value) => out.push(value),
+                None if allow_missing_keys => (),
+                None => return Err(Error::Evaluate(std::format!("array index out of bounds: index {index}, length {len}"))),
+            }
+        },
+
+        (serde_json::Value::Array(items), Selector::Slice { start, end, step }) => {
+            let len = items.len();
+            let clamp = |index: i64| resolve_index(index, len).unwrap_or(if index < 0 { 0 } else { len });
+            let start = start.map_or(0, clamp);
+            let end = end.map_or(len, clamp);
+            let step = match step {
+                None => 1,
+                Some(step) => usize::try_from(*step).ok().filter(|&step| step > 0)
+                    .ok_or_else(|| Error::Evaluate(std::format!("invalid array slice step {step}")))?,
+            };
+            if start < end {
+                out.extend(items[start..end].iter().step_by(step));
+            }
+        },
+
+        _ if allow_missing_keys => (),
+
+        (_, Selector::Key(key)) => return Err(Error::Evaluate(std::format!("{key} is not found"))),
+
+        (value, Selector::Index(_) | Selector::Slice { .. }) => return Err(Error::Evaluate(std::format!("{value} is not array or slice"))),
+    }
+
+    Ok(())
+}
+
+/// Resolves an array index that counts from the end if it's negative. Returns `None` if the index is before the start of the array.
+/// The returned index may be past the end of the array.
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    if index < 0 {
+        len.checked_sub(usize::try_from(index.unsigned_abs()).ok()?)
+    }
+    else {
+        usize::try_from(index).ok()
+    }
+}
+
+/// Writes a result the way `kubectl` does. Strings are written without quotes, and objects and arrays are written as JSON
+/// with the same escaping as Go's `encoding/json`.
+fn write_value(value: &serde_json::Value, out: &mut std::string::String) -> Result<(), Error> {
+    match value {
+        serde_json::Value::String(s) => out.push_str(s),
+
+        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+            let json = serde_json::to_string(value).map_err(Error::Serialize)?;
+            for c in json.chars() {
+                match c {
+                    '<' => out.push_str("\\u003c"),
+                    '>' => out.push_str("\\u003e"),
+                    '&' => out.push_str("\\u0026"),
+                    '\u{2028}' => out.push_str("\\u2028"),
+                    '\u{2029}' => out.push_str("\\u2029"),
+                    c => out.push(c),
+                }
+            }
+        },
+
+        value => {
+            use core::fmt::Write;
+            write!(out, "{value}").expect("writing to a String cannot fail");
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,375 @@
+/// A trait applies to types that can be put in a canonical form, so that two values that the API server treats the same compare equal.
+///
+/// `value.normalize()` behaves in the following ways:
+///
+/// ## `struct`s
+///
+/// Structs are normalized by individually normalizing each of their fields. The structs in the `k8s-openapi` crate
+/// normalize their fields according to the merge metadata of the field in the OpenAPI spec:
+///
+/// - Lists with `x-kubernetes-list-type: set` are sorted and deduplicated.
+///
+/// - Lists with `x-kubernetes-list-type: map` keep their order, since the API server preserves it and it is significant for some of them
+///   like `initContainers` and `env`. Only their items are normalized.
+///
+/// - Optional lists and maps that are empty are replaced with [`None`], since the API server does not distinguish between the two.
+///
+/// See [`normalize_strategies`](crate::normalize_strategies) for the functions that implement these.
+///
+/// ## `Quantity`
+///
+/// Quantities are rewritten in the canonical form that the API server returns them in, eg `"1000m"` becomes `"1"` and `"0.5Gi"` becomes `"512Mi"`.
+///
+/// ## `Option`
+///
+/// The inner value, if any, is normalized.
+///
+/// ## Other types
+///
+/// The value is unchanged.
+///
+/// # Server defaulting
+///
+/// Normalization does not fill in the default values that the API server sets for fields that an object does not specify,
+/// so an object that leaves such fields unset is not semantically equal to the object that the API server returns for it.
+/// Use [`Defaulting::set_defaults`](crate::Defaulting::set_defaults) to fill them in first.
+pub trait Normalize {
+    /// Put `self` in its canonical form.
+    fn normalize(&mut self);
+
+    /// Clears the fields of `self` that are populated by the API server rather than the client.
+    ///
+    /// For resource types whose metadata is an `ObjectMeta`, this clears the `status` field if any, and the `uid`, `resourceVersion`, `generation`,
+    /// `creationTimestamp`, `deletionTimestamp`, `deletionGracePeriodSeconds`, `managedFields` and `selfLink` fields of the metadata.
+    /// For other types this does nothing.
+    fn clear_server_populated_fields(&mut self) {}
+
+    /// Returns whether `self` and `other` are equal after clearing their server-populated fields and normalizing them.
+    ///
+    /// This is useful for controllers that compare the object they want to the object they read from the API server,
+    /// to decide whether the object needs to be updated.
+    fn semantically_eq(&self, other: &Self) -> bool where Self: Clone + PartialEq {
+        let mut this = self.clone();
+        this.clear_server_populated_fields();
+        this.normalize();
+
+        let mut other = other.clone();
+        other.clear_server_populated_fields();
+        other.normalize();
+
+        this == other
+    }
+}
+
+macro_rules! default_unchanged_impl {
+    () => {
+        fn normalize(&mut self) {}
+    };
+}
+
+impl Normalize for bool { default_unchanged_impl! {} }
+impl Normalize for i32 { default_unchanged_impl! {} }
+impl Normalize for i64 { default_unchanged_impl! {} }
+impl Normalize for f64 { default_unchanged_impl! {} }
+impl Normalize for std::string::String { default_unchanged_impl! {} }
+impl Normalize for crate::ByteString { default_unchanged_impl! {} }
+impl Normalize for crate::Duration { default_unchanged_impl! {} }
+impl Normalize for jiff::Timestamp { default_unchanged_impl! {} }
+impl Normalize for serde_json::Value { default_unchanged_impl! {} }
+
+impl<T> Normalize for std::boxed::Box<T> where T: Normalize {
+    fn normalize(&mut self) {
+        (**self).normalize();
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        (**self).clear_server_populated_fields();
+    }
+}
+
+impl<T> Normalize for Option<T> where T: Normalize {
+    fn normalize(&mut self) {
+        if let Some(value) = self {
+            value.normalize();
+        }
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        if let Some(value) = self {
+            value.clear_server_populated_fields();
+        }
+    }
+}
+
+/// Strategies for normalizing collections and quantities.
+pub mod strategies {
+    /// Strategies for normalizing [`std::vec::Vec`]s.
+    ///
+    /// These correspond to [`JSONSchemaProps.x-kubernetes-list-type`](https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.26/#jsonschemaprops-v1-apiextensions-k8s-io).
+    /// All of them normalize each item of the list, and replace an empty optional list with [`None`].
+    pub mod list {
+        mod private {
+            pub trait AsOptVec {
+                type Item;
+
+                fn as_mut_opt(&mut self) -> Option<&mut std::vec::Vec<Self::Item>>;
+                fn remove_if_empty(&mut self);
+            }
+
+            impl<T> AsOptVec for std::vec::Vec<T> {
+                type Item = T;
+
+                fn as_mut_opt(&mut self) -> Option<&mut Self> {
+                    Some(self)
+                }
+
+                fn remove_if_empty(&mut self) {
+                }
+            }
+
+            impl<T> AsOptVec for Option<std::vec::Vec<T>> {
+                type Item = T;
+
+                fn as_mut_opt(&mut self) -> Option<&mut std::vec::Vec<T>> {
+                    self.as_mut()
+                }
+
+                fn remove_if_empty(&mut self) {
+                    if self.as_ref().is_some_and(std::vec::Vec::is_empty) {
+                        *self = None;
+                    }
+                }
+            }
+        }
+        use private::AsOptVec;
+
+        /// The order of the list is significant, so only the items are normalized.
+        pub fn atomic<V>(list: &mut V, normalize_item: fn(&mut V::Item)) where V: AsOptVec {
+            if let Some(list) = list.as_mut_opt() {
+                list.iter_mut().for_each(normalize_item);
+            }
+            list.remove_if_empty();
+        }
+
+        /// The list is treated as a map.
+        ///
+        /// The API server preserves the order of the items, and the order is significant for some lists like `initContainers`,
+        /// so like [`atomic`] only the items are normalized.
+        pub fn map<V>(list: &mut V, normalize_item: fn(&mut V::Item)) where V: AsOptVec {
+            atomic(list, normalize_item);
+        }
+
+        /// The list is treated as a set.
+        ///
+        /// The items are sorted, and duplicate items are removed.
+        pub fn set<V>(list: &mut V, normalize_item: fn(&mut V::Item)) where V: AsOptVec, V::Item: Ord {
+            if let Some(list) = list.as_mut_opt() {
+                list.iter_mut().for_each(normalize_item);
+                list.sort();
+                list.dedup();
+            }
+            list.remove_if_empty();
+        }
+    }
+
+    /// Strategies for normalizing [`BTreeMap`s.](std::collections::BTreeMap)
+    pub mod map {
+        mod private {
+            use std::collections::BTreeMap;
+
+            pub trait AsOptMap {
+                type Key;
+                type Value;
+
+                fn as_mut_opt(&mut self) -> Option<&mut BTreeMap<Self::Key, Self::Value>>;
+                fn remove_if_empty(&mut self);
+            }
+
+            impl<K, V> AsOptMap for BTreeMap<K, V> {
+                type Key = K;
+                type Value = V;
+
+                fn as_mut_opt(&mut self) -> Option<&mut Self> {
+                    Some(self)
+                }
+
+                fn remove_if_empty(&mut self) {
+                }
+            }
+
+            impl<K, V> AsOptMap for Option<BTreeMap<K, V>> {
+                type Key = K;
+                type Value = V;
+
+                fn as_mut_opt(&mut self) -> Option<&mut BTreeMap<K, V>> {
+                    self.as_mut()
+                }
+
+                fn remove_if_empty(&mut self) {
+                    if self.as_ref().is_some_and(BTreeMap::is_empty) {
+                        *self = None;
+                    }
+                }
+            }
+        }
+        use private::AsOptMap;
+
+        /// Each value of the map is normalized, and an empty optional map is replaced with [`None`].
+        ///
+        /// This applies to both values of [`JSONSchemaProps.x-kubernetes-map-type`](https://kubernetes.io/docs/reference/generated/kubernetes-api/v1.26/#jsonschemaprops-v1-apiextensions-k8s-io).
+        pub fn values<M>(map: &mut M, normalize_value: fn(&mut M::Value)) where M: AsOptMap {
+            if let Some(map) = map.as_mut_opt() {
+                map.values_mut().for_each(normalize_value);
+            }
+            map.remove_if_empty();
+        }
+    }
+
+    /// Rewrites the given quantity in the canonical form that the API server returns it in, like golang's `resource.Quantity.String()`.
+    ///
+    /// For example, `"1000m"` becomes `"1"`, `"0.1"` becomes `"100m"`, `"1024Mi"` becomes `"1Gi"`, and `"0.5Gi"` becomes `"512Mi"`.
+    /// Like the API server, values more precise than a nano-unit are rounded up to the next nano-unit.
+    ///
+    /// Strings that are not valid quantities, or whose digits do not fit in a `u128`, are left unchanged.
+    pub fn quantity(value: &mut std::string::String) {
+        if let Some(canonical) = canonicalize_quantity(value) {
+            *value = canonical;
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum QuantityFormat {
+        BinarySI,
+        DecimalExponent,
+        DecimalSI,
+    }
+
+    fn canonicalize_quantity(s: &str) -> Option<std::string::String> {
+        // Ref: https://github.com/kubernetes/apimachinery/blob/v0.35.0/pkg/api/resource/quantity.go
+
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (whole, s) = s.split_at(s.bytes().position(|c| !c.is_ascii_digit()).unwrap_or(s.len()));
+        let (fraction, suffix) =
+            if let Some(s) = s.strip_prefix('.') {
+                s.split_at(s.bytes().position(|c| !c.is_ascii_digit()).unwrap_or(s.len()))
+            }
+            else {
+                ("", s)
+            };
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        let (mut format, binary_exponent, mut exponent): (_, u32, i32) = match suffix {
+            "" => (QuantityFormat::DecimalSI, 0, 0),
+            "n" => (QuantityFormat::DecimalSI, 0, -9),
+            "u" => (QuantityFormat::DecimalSI, 0, -6),
+            "m" => (QuantityFormat::DecimalSI, 0, -3),
+            "k" => (QuantityFormat::DecimalSI, 0, 3),
+            "M" => (QuantityFormat::DecimalSI, 0, 6),
+            "G" => (QuantityFormat::DecimalSI, 0, 9),
+            "T" => (QuantityFormat::DecimalSI, 0, 12),
+            "P" => (QuantityFormat::DecimalSI, 0, 15),
+            "E" => (QuantityFormat::DecimalSI, 0, 18),
+            "Ki" => (QuantityFormat::BinarySI, 10, 0),
+            "Mi" => (QuantityFormat::BinarySI, 20, 0),
+            "Gi" => (QuantityFormat::BinarySI, 30, 0),
+            "Ti" => (QuantityFormat::BinarySI, 40, 0),
+            "Pi" => (QuantityFormat::BinarySI, 50, 0),
+            "Ei" => (QuantityFormat::BinarySI, 60, 0),
+            suffix => {
+                let exponent = suffix.strip_prefix(['e', 'E'])?;
+                if !exponent.trim_start_matches(['+', '-']).bytes().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                (QuantityFormat::DecimalExponent, 0, exponent.parse().ok()?)
+            },
+        };
+
+        // The value of the quantity is `mantissa * 10^exponent`
+        let mut mantissa: u128 = [whole, fraction].concat().parse().ok()?;
+        exponent = exponent.checked_sub(i32::try_from(fraction.len()).ok()?)?;
+        mantissa = mantissa.checked_mul(1 << binary_exponent)?;
+
+        if mantissa == 0 {
+            return Some("0".into());
+        }
+
+        // Round up to the nearest nano-unit.
+        if exponent < -9 {
+            mantissa = 10_u128.checked_pow((-9 - exponent).cast_unsigned()).map_or(1, |divisor| mantissa.div_ceil(divisor));
+            exponent = -9;
+        }
+
+        if let QuantityFormat::BinarySI = format {
+            // Binary quantities are capped at the maximum value of an int64.
+            let max = u128::from(i64::MAX.cast_unsigned());
+            let exceeds_max =
+                if let Ok(exponent) = u32::try_from(exponent) {
+                    10_u128.checked_pow(exponent).and_then(|multiplier| mantissa.checked_mul(multiplier)).is_none_or(|value| value > max)
+                }
+                else {
+                    10_u128.checked_pow(exponent.unsigned_abs()).and_then(|divisor| max.checked_mul(divisor)).is_some_and(|max| mantissa > max)
+                };
+            if exceeds_max {
+                mantissa = max;
+                exponent = 0;
+            }
+
+            // Binary quantities less than 1024 or with a fractional part are formatted as decimal quantities instead.
+            let integer =
+                if let Ok(exponent) = u32::try_from(exponent) {
+                    10_u128.checked_pow(exponent).and_then(|multiplier| mantissa.checked_mul(multiplier))
+                }
+                else {
+                    let divisor = 10_u128.pow(exponent.unsigned_abs());
+                    mantissa.is_multiple_of(divisor).then_some(mantissa / divisor)
+                };
+            match integer {
+                Some(mut integer) if integer >= 1024 => {
+                    let mut binary_exponent = 0;
+                    while integer.is_multiple_of(1024) {
+                        integer /= 1024;
+                        binary_exponent += 1;
+                    }
+                    let suffix = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei"][binary_exponent];
+                    let sign = if negative { "-" } else { "" };
+                    return Some(std::format!("{sign}{integer}{suffix}"));
+                },
+
+                _ => format = QuantityFormat::DecimalSI,
+            }
+        }
+
+        // Move all factors of 10 into the exponent, then make the exponent a multiple of 3.
+        while mantissa.is_multiple_of(10) {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        while exponent % 3 != 0 {
+            mantissa = mantissa.checked_mul(10)?;
+            exponent -= 1;
+        }
+
+        let suffix = match (format, exponent) {
+            (_, 0) => std::string::String::new(),
+            (QuantityFormat::DecimalSI, -9) => "n".into(),
+            (QuantityFormat::DecimalSI, -6) => "u".into(),
+            (QuantityFormat::DecimalSI, -3) => "m".into(),
+            (QuantityFormat::DecimalSI, 3) => "k".into(),
+            (QuantityFormat::DecimalSI, 6) => "M".into(),
+            (QuantityFormat::DecimalSI, 9) => "G".into(),
+            (QuantityFormat::DecimalSI, 12) => "T".into(),
+            (QuantityFormat::DecimalSI, 15) => "P".into(),
+            (QuantityFormat::DecimalSI, 18) => "E".into(),
+            (_, exponent) => std::format!("e{exponent}"),
+        };
+        let sign = if negative { "-" } else { "" };
+        Some(std::format!("{sign}{mantissa}{suffix}"))
+    }
+}
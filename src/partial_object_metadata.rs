@@ -0,0 +1,305 @@
+/// The metadata of a resource of type `K`, without its other fields.
+///
+/// The API server returns objects in this form when it is asked for the [`PartialObjectMetadata::ACCEPT`] content type,
+/// such as when watching resources with `Accept: application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1`.
+/// This saves bandwidth for clients that only need the metadata of the resources, such as the label selectors of controllers.
+///
+/// The API server sends these objects with the `apiVersion` and `kind` of `meta.k8s.io/v1` `PartialObjectMetadata`. This type accepts either that
+/// or the `apiVersion` and `kind` of `K`. It implements [`Resource`](crate::Resource) with the `apiVersion` and `kind` of `K`, and serializes with them,
+/// so that it can also be used as the body of a server-side apply request that only sets the metadata of a resource.
+pub struct PartialObjectMetadata<K> where K: crate::Resource {
+    /// Standard object's metadata.
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+
+    resource: core::marker::PhantomData<fn() -> K>,
+}
+
+impl<K> PartialObjectMetadata<K> where K: crate::Resource {
+    /// The value of the `Accept` header to request a single object, or the objects of a watch, as `PartialObjectMetadata`.
+    pub const ACCEPT: &'static str = "application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1";
+
+    /// Creates a `PartialObjectMetadata` with the given metadata.
+    pub fn new(metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) -> Self {
+        PartialObjectMetadata {
+            metadata,
+            resource: Default::default(),
+        }
+    }
+}
+
+impl<K> Clone for PartialObjectMetadata<K> where K: crate::Resource {
+    fn clone(&self) -> Self {
+        PartialObjectMetadata::new(self.metadata.clone())
+    }
+}
+
+impl<K> core::fmt::Debug for PartialObjectMetadata<K> where K: crate::Resource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PartialObjectMetadata")
+            .field("api_version", &<K as crate::Resource>::API_VERSION)
+            .field("kind", &<K as crate::Resource>::KIND)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+impl<K> Default for PartialObjectMetadata<K> where K: crate::Resource {
+    fn default() -> Self {
+        PartialObjectMetadata::new(Default::default())
+    }
+}
+
+impl<K> PartialEq for PartialObjectMetadata<K> where K: crate::Resource {
+    fn eq(&self, other: &Self) -> bool {
+        self.metadata == other.metadata
+    }
+}
+
+impl<K> From<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> for PartialObjectMetadata<K> where K: crate::Resource {
+    fn from(metadata: crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) -> Self {
+        PartialObjectMetadata::new(metadata)
+    }
+}
+
+impl<K> crate::Resource for PartialObjectMetadata<K> where K: crate::Resource {
+    const API_VERSION: &'static str = <K as crate::Resource>::API_VERSION;
+    const GROUP: &'static str = <K as crate::Resource>::GROUP;
+    const KIND: &'static str = <K as crate::Resource>::KIND;
+    const VERSION: &'static str = <K as crate::Resource>::VERSION;
+    const URL_PATH_SEGMENT: &'static str = <K as crate::Resource>::URL_PATH_SEGMENT;
+    type Scope = <K as crate::Resource>::Scope;
+}
+
+impl<K> crate::ListableResource for PartialObjectMetadata<K> where K: crate::ListableResource {
+    const LIST_KIND: &'static str = <K as crate::ListableResource>::LIST_KIND;
+}
+
+impl<K> crate::Metadata for PartialObjectMetadata<K> where K: crate::Resource {
+    type Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn metadata(&self) -> &<Self as crate::Metadata>::Ty {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut<Self as crate::Metadata>::Ty {
+        &mut self.metadata
+    }
+}
+
+impl<'de, K> serde::Deserialize<'de> for PartialObjectMetadata<K> where K: crate::Resource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<K>(core::marker::PhantomData<fn() -> K>);
+
+        impl<'de, K> serde::de::Visitor<'de> for Visitor<K> where K: crate::Resource {
+            type Value = PartialObjectMetadata<K>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("PartialObjectMetadata")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_api_version: Option<std::string::String> = None;
+                let mut value_kind: Option<std::string::String> = None;
+                let mut value_metadata = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "apiVersion" => value_api_version = Some(serde::de::MapAccess::next_value(&mut map)?),
+                        "kind" => value_kind = Some(serde::de::MapAccess::next_value(&mut map)?),
+                        "metadata" => value_metadata = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                validate_type_meta(
+                    value_api_version.as_deref(),
+                    value_kind.as_deref(),
+                    ("meta.k8s.io/v1", "PartialObjectMetadata"),
+                    (<K as crate::Resource>::API_VERSION, <K as crate::Resource>::KIND),
+                )?;
+
+                Ok(PartialObjectMetadata::new(value_metadata.unwrap_or_default()))
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "PartialObjectMetadata",
+            &[
+                "apiVersion",
+                "kind",
+                "metadata",
+            ],
+            Visitor(Default::default()),
+        )
+    }
+}
+
+impl<K> serde::Serialize for PartialObjectMetadata<K> where K: crate::Resource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct("PartialObjectMetadata", 3)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <K as crate::Resource>::API_VERSION)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <K as crate::Resource>::KIND)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A list of the metadata of resources of type `K`.
+///
+/// The API server returns lists in this form when it is asked for the [`PartialObjectMetadataList::ACCEPT`] content type,
+/// such as when listing resources with `Accept: application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1`.
+///
+/// Unlike [`PartialObjectMetadata`], this type implements [`Resource`](crate::Resource) with the `apiVersion` and `kind` that the API server sends it with,
+/// ie `meta.k8s.io/v1` `PartialObjectMetadataList`. It also accepts the `apiVersion` and list kind of `K`.
+pub struct PartialObjectMetadataList<K> where K: crate::ListableResource {
+    /// List of objects.
+    pub items: std::vec::Vec<PartialObjectMetadata<K>>,
+
+    /// Standard list metadata.
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ListMeta,
+}
+
+impl<K> PartialObjectMetadataList<K> where K: crate::ListableResource {
+    /// The value of the `Accept` header to request a list as `PartialObjectMetadataList`.
+    pub const ACCEPT: &'static str = "application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1";
+}
+
+impl<K> Clone for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn clone(&self) -> Self {
+        PartialObjectMetadataList {
+            items: self.items.clone(),
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+impl<K> core::fmt::Debug for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PartialObjectMetadataList")
+            .field("items", &self.items)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+impl<K> Default for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn default() -> Self {
+        PartialObjectMetadataList {
+            items: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+impl<K> PartialEq for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.metadata == other.metadata
+    }
+}
+
+impl<K> crate::Resource for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    const API_VERSION: &'static str = "meta.k8s.io/v1";
+    const GROUP: &'static str = "meta.k8s.io";
+    const KIND: &'static str = "PartialObjectMetadataList";
+    const VERSION: &'static str = "v1";
+    const URL_PATH_SEGMENT: &'static str = "";
+    type Scope = <K as crate::Resource>::Scope;
+}
+
+impl<K> crate::Metadata for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    type Ty = crate::apimachinery::pkg::apis::meta::v1::ListMeta;
+
+    fn metadata(&self) -> &<Self as crate::Metadata>::Ty {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut<Self as crate::Metadata>::Ty {
+        &mut self.metadata
+    }
+}
+
+impl<'de, K> serde::Deserialize<'de> for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<K>(core::marker::PhantomData<fn() -> K>);
+
+        impl<'de, K> serde::de::Visitor<'de> for Visitor<K> where K: crate::ListableResource {
+            type Value = PartialObjectMetadataList<K>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(<Self::Value as crate::Resource>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_api_version: Option<std::string::String> = None;
+                let mut value_kind: Option<std::string::String> = None;
+                let mut value_items = None;
+                let mut value_metadata = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "apiVersion" => value_api_version = Some(serde::de::MapAccess::next_value(&mut map)?),
+                        "kind" => value_kind = Some(serde::de::MapAccess::next_value(&mut map)?),
+                        "items" => value_items = serde::de::MapAccess::next_value(&mut map)?,
+                        "metadata" => value_metadata = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                validate_type_meta(
+                    value_api_version.as_deref(),
+                    value_kind.as_deref(),
+                    (<Self::Value as crate::Resource>::API_VERSION, <Self::Value as crate::Resource>::KIND),
+                    (<K as crate::Resource>::API_VERSION, <K as crate::ListableResource>::LIST_KIND),
+                )?;
+
+                Ok(PartialObjectMetadataList {
+                    items: value_items.unwrap_or_default(),
+                    metadata: value_metadata.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            <Self as crate::Resource>::KIND,
+            &[
+                "apiVersion",
+                "kind",
+                "items",
+                "metadata",
+            ],
+            Visitor(Default::default()),
+        )
+    }
+}
+
+impl<K> serde::Serialize for PartialObjectMetadataList<K> where K: crate::ListableResource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct(<Self as crate::Resource>::KIND, 4)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", <Self as crate::Resource>::API_VERSION)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "kind", <Self as crate::Resource>::KIND)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "items", &self.items)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// Validates that the `apiVersion` and `kind` of a deserialized object, if present, are one of the two given pairs.
+fn validate_type_meta<E>(
+    api_version: Option<&str>,
+    kind: Option<&str>,
+    (partial_api_version, partial_kind): (&'static str, &'static str),
+    (resource_api_version, resource_kind): (&'static str, &'static str),
+) -> Result<(), E> where E: serde::de::Error {
+    let expected_api_version = match kind {
+        Some(kind) if kind == partial_kind => partial_api_version,
+        Some(kind) if kind == resource_kind => resource_api_version,
+        Some(kind) => return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(kind), &partial_kind)),
+        None => return Ok(()),
+    };
+
+    match api_version {
+        Some(api_version) if api_version != expected_api_version =>
+            Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(api_version), &expected_api_version)),
+        _ => Ok(()),
+    }
+}
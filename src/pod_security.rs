@@ -0,0 +1,1145 @@
+//! Evaluation of pods against the Pod Security Standards, like the PodSecurity admission plugin of the API server.
+//!
+//! [`evaluate`] checks the pod spec of a [`Pod`](crate::api::core::v1::Pod) or of a workload's pod template against a [`Policy`],
+//! which is a [`Level`] of the Pod Security Standards at a [`Version`] of the policy. The violations are reported with the same
+//! reasons and details as the PodSecurity admission plugin.
+//!
+//! ```rust,ignore
+//! let policy: Policy = "restricted:latest".parse()?;
+//! if let Err(violations) = k8s_openapi::pod_security::evaluate(&deployment, policy) {
+//!     eprintln!("error: {violations}");
+//! }
+//! ```
+//!
+//! ```text
+//! error: violates PodSecurity "restricted:latest": allowPrivilegeEscalation != false (container "app" must set securityContext.allowPrivilegeEscalation=false), unrestricted capabilities (container "app" must set securityContext.capabilities.drop=["ALL"]), runAsNonRoot != true (pod or container "app" must set securityContext.runAsNonRoot=true), seccompProfile (pod or container "app" must set securityContext.seccompProfile.type to "RuntimeDefault" or "Localhost")
+//! ```
+//!
+//! The checks are ported from the `k8s.io/pod-security-admission/policy` package. Each check applies the rules of the newest version of it
+//! that is not newer than the policy's version, so a policy pinned to an older version like `baseline:v1.26` evaluates pods
+//! the same way as a cluster of that version. Checks that are behind feature gates of the API server are not included.
+
+use crate::api::core::v1::{Container, ContainerPort, Lifecycle, PodSpec, Probe, SecurityContext};
+use crate::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+/// A level of the Pod Security Standards.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Unrestricted. Every pod is allowed.
+    Privileged,
+
+    /// Minimally restrictive. Prevents known privilege escalations.
+    Baseline,
+
+    /// Heavily restricted, following current pod hardening best practices.
+    Restricted,
+}
+
+impl Level {
+    /// The name of the level, as used in the `pod-security.kubernetes.io/*` labels of namespaces.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Privileged => "privileged",
+            Level::Baseline => "baseline",
+            Level::Restricted => "restricted",
+        }
+    }
+}
+
+impl core::fmt::Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for Level {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "privileged" => Ok(Level::Privileged),
+            "baseline" => Ok(Level::Baseline),
+            "restricted" => Ok(Level::Restricted),
+            _ => Err(ParsePolicyError::InvalidLevel(s.into())),
+        }
+    }
+}
+
+/// A version of the Pod Security Standards. Each version corresponds to a minor version of Kubernetes.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Version {
+    /// The version `v1.{minor}`.
+    V1(u32),
+
+    /// The newest version.
+    Latest,
+}
+
+impl core::fmt::Display for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Version::V1(minor) => write!(f, "v1.{minor}"),
+            Version::Latest => f.write_str("latest"),
+        }
+    }
+}
+
+impl core::str::FromStr for Version {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Ref: https://github.com/kubernetes/pod-security-admission/blob/master/api/helpers.go
+
+        if s == "latest" {
+            return Ok(Version::Latest);
+        }
+
+        let minor =
+            s.strip_prefix("v1.")
+            .filter(|minor| !minor.is_empty() && minor.bytes().all(|c| c.is_ascii_digit()) && (*minor == "0" || !minor.starts_with('0')))
+            .and_then(|minor| minor.parse().ok());
+        match minor {
+            Some(minor) => Ok(Version::V1(minor)),
+            None => Err(ParsePolicyError::InvalidVersion(s.into())),
+        }
+    }
+}
+
+/// A level of the Pod Security Standards at a version.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Policy {
+    /// The level.
+    pub level: Level,
+
+    /// The version.
+    pub version: Version,
+}
+
+impl Policy {
+    /// Returns the policy of the given mode set by the labels of a namespace, like `pod-security.kubernetes.io/enforce: restricted`
+    /// and `pod-security.kubernetes.io/enforce-version: v1.29`.
+    ///
+    /// Like the API server, the level defaults to `privileged` and the version defaults to `latest` if their labels are not set.
+    pub fn from_namespace_labels(labels: &std::collections::BTreeMap<std::string::String, std::string::String>, mode: Mode) -> Result<Self, ParsePolicyError> {
+        let level_label = std::format!("pod-security.kubernetes.io/{}", mode.as_str());
+        let version_label = std::format!("pod-security.kubernetes.io/{}-version", mode.as_str());
+
+        let level = match labels.get(&level_label) {
+            Some(level) => level.parse()?,
+            None => Level::Privileged,
+        };
+        let version = match labels.get(&version_label) {
+            Some(version) => version.parse()?,
+            None => Version::Latest,
+        };
+        Ok(Policy { level, version })
+    }
+}
+
+impl core::fmt::Display for Policy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.level, self.version)
+    }
+}
+
+impl core::str::FromStr for Policy {
+    type Err = ParsePolicyError;
+
+    /// Parses a policy like `restricted:v1.29`. The version defaults to `latest` if it is not specified.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (level, version) = match s.split_once(':') {
+            Some((level, version)) => (level.parse()?, version.parse()?),
+            None => (s.parse()?, Version::Latest),
+        };
+        Ok(Policy { level, version })
+    }
+}
+
+/// The mode of the `pod-security.kubernetes.io/*` labels of a namespace that a policy is read from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mode {
+    /// Pods that violate the policy are rejected.
+    Enforce,
+
+    /// Violations of the policy are recorded in the audit log.
+    Audit,
+
+    /// Violations of the policy are returned as warnings to the client.
+    Warn,
+}
+
+impl Mode {
+    /// The name of the mode, as used in the `pod-security.kubernetes.io/*` labels of namespaces.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Mode::Enforce => "enforce",
+            Mode::Audit => "audit",
+            Mode::Warn => "warn",
+        }
+    }
+}
+
+/// An error from parsing a [`Level`], [`Version`] or [`Policy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParsePolicyError {
+    /// The string is not a valid level.
+    InvalidLevel(std::string::String),
+
+    /// The string is not a valid version.
+    InvalidVersion(std::string::String),
+}
+
+impl core::fmt::Display for ParsePolicyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParsePolicyError::InvalidLevel(level) => write!(f, "invalid level {level:?}: must be one of privileged, baseline, restricted"),
+            ParsePolicyError::InvalidVersion(version) => write!(f, r#"invalid version {version:?}: must be "latest" or "v1.x""#),
+        }
+    }
+}
+
+impl core::error::Error for ParsePolicyError {}
+
+/// A check of the Pod Security Standards that a pod does not pass.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckResult {
+    /// The ID of the check, like `runAsNonRoot`.
+    pub id: &'static str,
+
+    /// The short description of why the pod is forbidden, like `runAsNonRoot != true`.
+    pub reason: &'static str,
+
+    /// The details of why the pod is forbidden, like `pod or container "app" must set securityContext.runAsNonRoot=true`.
+    pub detail: std::string::String,
+}
+
+impl core::fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.detail.is_empty() {
+            f.write_str(self.reason)
+        }
+        else {
+            write!(f, "{} ({})", self.reason, self.detail)
+        }
+    }
+}
+
+/// The checks of a policy that a pod does not pass.
+///
+/// The `Display` impl prints the same message as the PodSecurity admission plugin prints when it rejects a pod.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violations {
+    /// The policy that was evaluated.
+    pub policy: Policy,
+
+    /// The checks that the pod does not pass, in the order that the PodSecurity admission plugin reports them.
+    pub results: std::vec::Vec<CheckResult>,
+}
+
+impl core::fmt::Display for Violations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "violates PodSecurity \"{}\": ", self.policy)?;
+        for (i, result) in self.results.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{result}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for Violations {}
+
+/// A type that contains a pod spec, like a [`Pod`](crate::api::core::v1::Pod) or a workload with a pod template.
+pub trait ExtractPodSpec {
+    /// Returns the metadata and spec of the pod, or `None` if the object does not have a pod spec.
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)>;
+}
+
+impl ExtractPodSpec for crate::api::core::v1::Pod {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        Some((Some(&self.metadata), self.spec.as_ref()?))
+    }
+}
+
+impl ExtractPodSpec for crate::api::core::v1::PodTemplateSpec {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        Some((self.metadata.as_ref(), self.spec.as_ref()?))
+    }
+}
+
+impl ExtractPodSpec for crate::api::core::v1::PodTemplate {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.template.as_ref()?.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::core::v1::ReplicationController {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.as_ref()?.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::apps::v1::DaemonSet {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::apps::v1::Deployment {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::apps::v1::ReplicaSet {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.as_ref()?.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::apps::v1::StatefulSet {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::batch::v1::CronJob {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.job_template.spec.as_ref()?.template.extract_pod_spec()
+    }
+}
+
+impl ExtractPodSpec for crate::api::batch::v1::Job {
+    fn extract_pod_spec(&self) -> Option<(Option<&ObjectMeta>, &PodSpec)> {
+        self.spec.as_ref()?.template.extract_pod_spec()
+    }
+}
+
+/// Evaluates the pod spec of an object against the given policy.
+///
+/// Objects without a pod spec, and every object under the `privileged` level, pass the evaluation.
+pub fn evaluate<T>(object: &T, policy: Policy) -> Result<(), Violations> where T: ExtractPodSpec + ?Sized {
+    match object.extract_pod_spec() {
+        Some((metadata, spec)) => evaluate_pod_spec(metadata, spec, policy),
+        None => Ok(()),
+    }
+}
+
+/// Evaluates a pod spec against the given policy. The annotations of the pod's metadata are used by some checks of older policy versions.
+pub fn evaluate_pod_spec(metadata: Option<&ObjectMeta>, spec: &PodSpec, policy: Policy) -> Result<(), Violations> {
+    // Ref: https://github.com/kubernetes/pod-security-admission/blob/master/policy/registry.go
+
+    let pod = Pod::new(metadata, spec);
+
+    let checks: &[&Check] = match policy.level {
+        Level::Privileged => return Ok(()),
+        Level::Baseline => &[],
+        Level::Restricted => RESTRICTED_CHECKS,
+    };
+
+    // Restricted checks replace the baseline checks that they are stricter versions of, if they apply at the policy's version.
+    let overridden_ids: std::vec::Vec<_> =
+        checks.iter()
+        .filter(|check| check.versioned_check(policy.version).is_some())
+        .flat_map(|check| check.overrides)
+        .collect();
+
+    let mut results = std::vec![];
+
+    for check in BASELINE_CHECKS.iter().chain(checks) {
+        if overridden_ids.contains(&&check.id) {
+            continue;
+        }
+
+        let Some(check_fn) = check.versioned_check(policy.version) else {
+            continue;
+        };
+
+        if let Some(detail) = check_fn(&pod) {
+            results.push(CheckResult { id: check.id, reason: check.reason, detail });
+        }
+    }
+
+    if results.is_empty() {
+        Ok(())
+    }
+    else {
+        Err(Violations { policy, results })
+    }
+}
+
+// Checks
+
+/// A check, with the versions of it that were introduced at each policy version.
+struct Check {
+    id: &'static str,
+    reason: &'static str,
+    versions: &'static [(u32, fn(&Pod<'_>) -> Option<std::string::String>)],
+    overrides: &'static [&'static str],
+}
+
+impl Check {
+    fn versioned_check(&self, version: Version) -> Option<fn(&Pod<'_>) -> Option<std::string::String>> {
+        let minor = match version {
+            Version::V1(minor) => minor,
+            Version::Latest => u32::MAX,
+        };
+        self.versions.iter().rev().find(|(min_minor, _)| *min_minor <= minor).map(|(_, check_fn)| *check_fn)
+    }
+}
+
+/// The checks of the baseline level, ordered by ID.
+const BASELINE_CHECKS: &[&Check] = &[
+    &Check { id: "appArmorProfile", reason: "forbidden AppArmor profile", versions: &[(0, app_armor_profile_1_0)], overrides: &[] },
+    &Check { id: "capabilities_baseline", reason: "non-default capabilities", versions: &[(0, capabilities_baseline_1_0)], overrides: &[] },
+    &Check { id: "hostNamespaces", reason: "host namespaces", versions: &[(0, host_namespaces_1_0)], overrides: &[] },
+    &Check { id: "hostPathVolumes", reason: "hostPath volumes", versions: &[(0, host_path_volumes_1_0)], overrides: &[] },
+    &Check { id: "hostPorts", reason: "hostPort", versions: &[(0, host_ports_1_0)], overrides: &[] },
+    &Check { id: "hostProbesAndLifecycle", reason: "probe or lifecycle host", versions: &[(34, host_probes_and_lifecycle_1_34)], overrides: &[] },
+    &Check { id: "privileged", reason: "privileged", versions: &[(0, privileged_1_0)], overrides: &[] },
+    &Check { id: "procMount", reason: "procMount", versions: &[(0, proc_mount_1_0)], overrides: &[] },
+    &Check { id: "seLinuxOptions", reason: "seLinuxOptions", versions: &[(0, se_linux_options_1_0), (31, se_linux_options_1_31)], overrides: &[] },
+    &Check { id: "seccompProfile_baseline", reason: "seccompProfile", versions: &[(0, seccomp_profile_baseline_1_0), (19, seccomp_profile_baseline_1_19)], overrides: &[] },
+    &Check { id: "sysctls", reason: "forbidden sysctls", versions: &[(0, sysctls_1_0), (27, sysctls_1_27), (29, sysctls_1_29), (32, sysctls_1_32)], overrides: &[] },
+    &Check { id: "windowsHostProcess", reason: "hostProcess", versions: &[(0, windows_host_process_1_0)], overrides: &[] },
+];
+
+/// The checks of the restricted level in addition to the baseline checks, ordered by ID.
+const RESTRICTED_CHECKS: &[&Check] = &[
+    &Check { id: "allowPrivilegeEscalation", reason: "allowPrivilegeEscalation != false", versions: &[(8, allow_privilege_escalation_1_8), (25, allow_privilege_escalation_1_25)], overrides: &[] },
+    &Check { id: "capabilities_restricted", reason: "unrestricted capabilities", versions: &[(22, capabilities_restricted_1_22), (25, capabilities_restricted_1_25)], overrides: &["capabilities_baseline"] },
+    &Check { id: "restrictedVolumes", reason: "restricted volume types", versions: &[(0, restricted_volumes_1_0)], overrides: &[] },
+    &Check { id: "runAsNonRoot", reason: "runAsNonRoot != true", versions: &[(0, run_as_non_root_1_0)], overrides: &[] },
+    &Check { id: "runAsUser", reason: "runAsUser=0", versions: &[(23, run_as_user_1_23)], overrides: &[] },
+    &Check { id: "seccompProfile_restricted", reason: "seccompProfile", versions: &[(19, seccomp_profile_restricted_1_19), (25, seccomp_profile_restricted_1_25)], overrides: &["seccompProfile_baseline"] },
+];
+
+fn allow_privilege_escalation_1_8(pod: &Pod<'_>) -> Option<std::string::String> {
+    let bad_containers: std::vec::Vec<_> =
+        pod.containers.iter()
+        .filter(|container| container.security_context.and_then(|security_context| security_context.allow_privilege_escalation) != Some(false))
+        .map(|container| container.name)
+        .collect();
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} must set securityContext.allowPrivilegeEscalation=false",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+    ))
+}
+
+fn allow_privilege_escalation_1_25(pod: &Pod<'_>) -> Option<std::string::String> {
+    if pod.is_windows() {
+        return None;
+    }
+    allow_privilege_escalation_1_8(pod)
+}
+
+fn app_armor_profile_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    fn allowed_profile(profile: Option<&crate::api::core::v1::AppArmorProfile>) -> bool {
+        profile.is_none_or(|profile| matches!(&*profile.type_, "RuntimeDefault" | "Localhost"))
+    }
+
+    const ANNOTATION_KEY_PREFIX: &str = "container.apparmor.security.beta.kubernetes.io/";
+
+    let mut bad_setters = std::vec![];
+    let mut bad_values = std::collections::BTreeSet::new();
+
+    if let Some(profile) = pod.spec.security_context.as_ref().and_then(|security_context| security_context.app_armor_profile.as_ref()) {
+        if !allowed_profile(Some(profile)) {
+            bad_setters.push("pod".into());
+            bad_values.insert(&*profile.type_);
+        }
+    }
+
+    let mut bad_containers = std::vec![];
+    for container in &pod.containers {
+        let profile = container.security_context.and_then(|security_context| security_context.app_armor_profile.as_ref());
+        if let Some(profile) = profile.filter(|profile| !allowed_profile(Some(profile))) {
+            bad_containers.push(container.name);
+            bad_values.insert(&*profile.type_);
+        }
+    }
+    if !bad_containers.is_empty() {
+        bad_setters.push(std::format!("{} {}", pluralize("container", "containers", bad_containers.len()), join_quote(&bad_containers)));
+    }
+
+    let mut bad_value_list: std::vec::Vec<std::string::String> = bad_values.into_iter().map(Into::into).collect();
+
+    let forbidden_annotations: std::vec::Vec<_> =
+        pod.annotations.into_iter().flatten()
+        .filter(|(key, value)| key.starts_with(ANNOTATION_KEY_PREFIX) && *value != "runtime/default" && !value.starts_with("localhost/"))
+        .map(|(key, value)| std::format!("{key}={value:?}"))
+        .collect();
+    if !forbidden_annotations.is_empty() {
+        bad_setters.push(pluralize("annotation", "annotations", forbidden_annotations.len()).into());
+        bad_value_list.extend(forbidden_annotations);
+    }
+
+    (!bad_setters.is_empty()).then(|| std::format!(
+        "{} must not set AppArmor profile type to {}",
+        bad_setters.join(" and "),
+        join_quote(&bad_value_list),
+    ))
+}
+
+fn capabilities_baseline_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    const ALLOWED_CAPABILITIES: &[&str] = &[
+        "AUDIT_WRITE", "CHOWN", "DAC_OVERRIDE", "FOWNER", "FSETID", "KILL", "MKNOD", "NET_BIND_SERVICE", "SETFCAP", "SETGID", "SETPCAP", "SETUID", "SYS_CHROOT",
+    ];
+
+    let mut bad_containers = std::vec![];
+    let mut non_default_capabilities = std::collections::BTreeSet::new();
+    for container in &pod.containers {
+        let mut valid = true;
+        for capability in container.added_capabilities() {
+            if !ALLOWED_CAPABILITIES.contains(&capability) {
+                valid = false;
+                non_default_capabilities.insert(capability);
+            }
+        }
+        if !valid {
+            bad_containers.push(container.name);
+        }
+    }
+
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} must not include {} in securityContext.capabilities.add",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+        join_quote(&non_default_capabilities.into_iter().collect::<std::vec::Vec<_>>()),
+    ))
+}
+
+fn capabilities_restricted_1_22(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut containers_missing_drop_all = std::vec![];
+    let mut containers_adding_forbidden_capabilities = std::vec![];
+    let mut forbidden_capabilities = std::collections::BTreeSet::new();
+
+    for container in &pod.containers {
+        let capabilities = container.security_context.and_then(|security_context| security_context.capabilities.as_ref());
+
+        if !capabilities.and_then(|capabilities| capabilities.drop.as_ref()).is_some_and(|drop| drop.iter().any(|capability| capability == "ALL")) {
+            containers_missing_drop_all.push(container.name);
+        }
+
+        let mut adds_forbidden_capability = false;
+        for capability in container.added_capabilities() {
+            if capability != "NET_BIND_SERVICE" {
+                adds_forbidden_capability = true;
+                forbidden_capabilities.insert(capability);
+            }
+        }
+        if adds_forbidden_capability {
+            containers_adding_forbidden_capabilities.push(container.name);
+        }
+    }
+
+    let mut forbidden_details = std::vec![];
+    if !containers_missing_drop_all.is_empty() {
+        forbidden_details.push(std::format!(
+            r#"{} {} must set securityContext.capabilities.drop=["ALL"]"#,
+            pluralize("container", "containers", containers_missing_drop_all.len()),
+            join_quote(&containers_missing_drop_all),
+        ));
+    }
+    if !containers_adding_forbidden_capabilities.is_empty() {
+        forbidden_details.push(std::format!(
+            "{} {} must not include {} in securityContext.capabilities.add",
+            pluralize("container", "containers", containers_adding_forbidden_capabilities.len()),
+            join_quote(&containers_adding_forbidden_capabilities),
+            join_quote(&forbidden_capabilities.into_iter().collect::<std::vec::Vec<_>>()),
+        ));
+    }
+
+    (!forbidden_details.is_empty()).then(|| forbidden_details.join("; "))
+}
+
+fn capabilities_restricted_1_25(pod: &Pod<'_>) -> Option<std::string::String> {
+    if pod.is_windows() {
+        return None;
+    }
+    capabilities_restricted_1_22(pod)
+}
+
+fn host_namespaces_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let host_namespaces: std::vec::Vec<_> = [
+        (pod.spec.host_network, "hostNetwork=true"),
+        (pod.spec.host_pid, "hostPID=true"),
+        (pod.spec.host_ipc, "hostIPC=true"),
+    ].into_iter().filter(|(value, _)| *value == Some(true)).map(|(_, host_namespace)| host_namespace).collect();
+    (!host_namespaces.is_empty()).then(|| host_namespaces.join(", "))
+}
+
+fn host_path_volumes_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let host_volumes: std::vec::Vec<_> =
+        pod.spec.volumes.iter().flatten()
+        .filter(|volume| volume.host_path.is_some())
+        .map(|volume| &*volume.name)
+        .collect();
+    (!host_volumes.is_empty()).then(|| std::format!("{} {}", pluralize("volume", "volumes", host_volumes.len()), join_quote(&host_volumes)))
+}
+
+fn host_ports_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut bad_containers = std::vec![];
+    let mut forbidden_host_ports = std::collections::BTreeSet::new();
+    for container in &pod.containers {
+        let mut valid = true;
+        for port in container.ports {
+            if let Some(host_port) = port.host_port.filter(|&host_port| host_port != 0) {
+                valid = false;
+                // The ports are sorted as strings.
+                forbidden_host_ports.insert(std::string::ToString::to_string(&host_port));
+            }
+        }
+        if !valid {
+            bad_containers.push(container.name);
+        }
+    }
+
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} {} {} {}",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+        pluralize("uses", "use", bad_containers.len()),
+        pluralize("hostPort", "hostPorts", forbidden_host_ports.len()),
+        forbidden_host_ports.into_iter().collect::<std::vec::Vec<_>>().join(", "),
+    ))
+}
+
+fn host_probes_and_lifecycle_1_34(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut bad_containers = std::vec![];
+    let mut forbidden_hosts = std::collections::BTreeSet::new();
+    for container in &pod.containers {
+        let mut valid = true;
+        for host in container.probe_and_lifecycle_hosts().filter(|host| !host.is_empty()) {
+            valid = false;
+            forbidden_hosts.insert(host);
+        }
+        if !valid {
+            bad_containers.push(container.name);
+        }
+    }
+
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} {} {} {}",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+        pluralize("uses", "use", bad_containers.len()),
+        pluralize("probe or lifecycle host", "probe or lifecycle hosts", forbidden_hosts.len()),
+        join_quote(&forbidden_hosts.into_iter().collect::<std::vec::Vec<_>>()),
+    ))
+}
+
+fn privileged_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let bad_containers: std::vec::Vec<_> =
+        pod.containers.iter()
+        .filter(|container| container.security_context.and_then(|security_context| security_context.privileged) == Some(true))
+        .map(|container| container.name)
+        .collect();
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} must not set securityContext.privileged=true",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+    ))
+}
+
+fn proc_mount_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut bad_containers = std::vec![];
+    let mut forbidden_proc_mount_types = std::collections::BTreeSet::new();
+    for container in &pod.containers {
+        if let Some(proc_mount) = container.security_context.and_then(|security_context| security_context.proc_mount.as_deref()) {
+            if proc_mount != "Default" {
+                bad_containers.push(container.name);
+                forbidden_proc_mount_types.insert(proc_mount);
+            }
+        }
+    }
+
+    (!bad_containers.is_empty()).then(|| std::format!(
+        "{} {} must not set securityContext.procMount to {}",
+        pluralize("container", "containers", bad_containers.len()),
+        join_quote(&bad_containers),
+        join_quote(&forbidden_proc_mount_types.into_iter().collect::<std::vec::Vec<_>>()),
+    ))
+}
+
+fn restricted_volumes_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut bad_volumes = std::vec![];
+    let mut bad_volume_types = std::collections::BTreeSet::new();
+
+    for volume in pod.spec.volumes.iter().flatten() {
+        let is_allowed =
+            volume.config_map.is_some() ||
+            volume.csi.is_some() ||
+            volume.downward_api.is_some() ||
+            volume.empty_dir.is_some() ||
+            volume.ephemeral.is_some() ||
+            volume.persistent_volume_claim.is_some() ||
+            volume.projected.is_some() ||
+            volume.secret.is_some();
+        if is_allowed {
+            continue;
+        }
+
+        let volume_type = [
+            (volume.gce_persistent_disk.is_some(), "gcePersistentDisk"),
+            (volume.aws_elastic_block_store.is_some(), "awsElasticBlockStore"),
+            (volume.git_repo.is_some(), "gitRepo"),
+            (volume.nfs.is_some(), "nfs"),
+            (volume.iscsi.is_some(), "iscsi"),
+            (volume.glusterfs.is_some(), "glusterfs"),
+            (volume.rbd.is_some(), "rbd"),
+            (volume.flex_volume.is_some(), "flexVolume"),
+            (volume.cinder.is_some(), "cinder"),
+            (volume.cephfs.is_some(), "cephfs"),
+            (volume.flocker.is_some(), "flocker"),
+            (volume.fc.is_some(), "fc"),
+            (volume.azure_file.is_some(), "azureFile"),
+            (volume.vsphere_volume.is_some(), "vsphereVolume"),
+            (volume.quobyte.is_some(), "quobyte"),
+            (volume.azure_disk.is_some(), "azureDisk"),
+            (volume.portworx_volume.is_some(), "portworxVolume"),
+            (volume.photon_persistent_disk.is_some(), "photonPersistentDisk"),
+            (volume.scale_io.is_some(), "scaleIO"),
+            (volume.storageos.is_some(), "storageos"),
+            (volume.host_path.is_some(), "hostPath"),
+            (volume.image.is_some(), "image"),
+        ].into_iter().find(|(is_set, _)| *is_set).map_or("unknown", |(_, volume_type)| volume_type);
+
+        bad_volumes.push(&*volume.name);
+        bad_volume_types.insert(volume_type);
+    }
+
+    (!bad_volumes.is_empty()).then(|| std::format!(
+        "{} {} {} {} {}",
+        pluralize("volume", "volumes", bad_volumes.len()),
+        join_quote(&bad_volumes),
+        pluralize("uses", "use", bad_volumes.len()),
+        pluralize("restricted volume type", "restricted volume types", bad_volume_types.len()),
+        join_quote(&bad_volume_types.into_iter().collect::<std::vec::Vec<_>>()),
+    ))
+}
+
+fn run_as_non_root_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    // Things that explicitly set runAsNonRoot=false.
+    let mut bad_setters = std::vec![];
+
+    let mut pod_run_as_non_root = false;
+    match pod.spec.security_context.as_ref().and_then(|security_context| security_context.run_as_non_root) {
+        Some(false) => bad_setters.push("pod".into()),
+        Some(true) => pod_run_as_non_root = true,
+        None => (),
+    }
+
+    // Containers that explicitly set runAsNonRoot=false.
+    let mut explicitly_bad_containers = std::vec![];
+    // Containers that didn't set runAsNonRoot and aren't caught by a pod-level runAsNonRoot=true.
+    let mut implicitly_bad_containers = std::vec![];
+    for container in &pod.containers {
+        match container.security_context.and_then(|security_context| security_context.run_as_non_root) {
+            Some(false) => explicitly_bad_containers.push(container.name),
+            Some(true) => (),
+            None => if !pod_run_as_non_root {
+                implicitly_bad_containers.push(container.name);
+            },
+        }
+    }
+    if !explicitly_bad_containers.is_empty() {
+        bad_setters.push(std::format!(
+            "{} {}",
+            pluralize("container", "containers", explicitly_bad_containers.len()),
+            join_quote(&explicitly_bad_containers),
+        ));
+    }
+
+    if !bad_setters.is_empty() {
+        return Some(std::format!("{} must not set securityContext.runAsNonRoot=false", bad_setters.join(" and ")));
+    }
+
+    (!implicitly_bad_containers.is_empty()).then(|| std::format!(
+        "pod or {} {} must set securityContext.runAsNonRoot=true",
+        pluralize("container", "containers", implicitly_bad_containers.len()),
+        join_quote(&implicitly_bad_containers),
+    ))
+}
+
+fn run_as_user_1_23(pod: &Pod<'_>) -> Option<std::string::String> {
+    let mut bad_setters = std::vec![];
+    if pod.spec.security_context.as_ref().and_then(|security_context| security_context.run_as_user) == Some(0) {
+        bad_setters.push("pod".into());
+    }
+
+    let explicitly_bad_containers: std::vec::Vec<_> =
+        pod.containers.iter()
+        .filter(|container| container.security_context.and_then(|security_context| security_context.run_as_user) == Some(0))
+        .map(|container| container.name)
+        .collect();
+    if !explicitly_bad_containers.is_empty() {
+        bad_setters.push(std::format!(
+            "{} {}",
+            pluralize("container", "containers", explicitly_bad_containers.len()),
+            join_quote(&explicitly_bad_containers),
+        ));
+    }
+
+    (!bad_setters.is_empty()).then(|| std::format!("{} must not set runAsUser=0", bad_setters.join(" and ")))
+}
+
+fn se_linux_options(pod: &Pod<'_>, allowed_types: &[&str]) -> Option<std::string::String> {
+    let mut bad_types = std::collections::BTreeSet::new();
+    let mut set_user_or_role = false;
+    let mut valid_se_linux_options = |options: &crate::api::core::v1::SELinuxOptions| {
+        let mut valid = true;
+        let type_ = options.type_.as_deref().unwrap_or_default();
+        if !allowed_types.contains(&type_) {
+            valid = false;
+            bad_types.insert(std::string::String::from(type_));
+        }
+        if options.user.as_ref().is_some_and(|user| !user.is_empty()) || options.role.as_ref().is_some_and(|role| !role.is_empty()) {
+            valid = false;
+            set_user_or_role = true;
+        }
+        valid
+    };
+
+    let mut bad_setters = std::vec![];
+
+    if let Some(options) = pod.spec.security_context.as_ref().and_then(|security_context| security_context.se_linux_options.as_ref()) {
+        if !valid_se_linux_options(options) {
+            bad_setters.push("pod".into());
+        }
+    }
+
+    let mut bad_containers = std::vec![];
+    for container in &pod.containers {
+        if let Some(options) = container.security_context.and_then(|security_context| security_context.se_linux_options.as_ref()) {
+            if !valid_se_linux_options(options) {
+                bad_containers.push(container.name);
+            }
+        }
+    }
+    if !bad_containers.is_empty() {
+        bad_setters.push(std::format!("{} {}", pluralize("container", "containers", bad_containers.len()), join_quote(&bad_containers)));
+    }
+
+    if bad_setters.is_empty() {
+        return None;
+    }
+
+    let mut bad_data = std::vec![];
+    if !bad_types.is_empty() {
+        bad_data.push(std::format!(
+            "{} {}",
+            pluralize("type", "types", bad_types.len()),
+            join_quote(&bad_types.into_iter().collect::<std::vec::Vec<_>>()),
+        ));
+    }
+    if set_user_or_role {
+        bad_data.push("user may not be set".into());
+        bad_data.push("role may not be set".into());
+    }
+
+    Some(std::format!("{} set forbidden securityContext.seLinuxOptions: {}", bad_setters.join(" and "), bad_data.join("; ")))
+}
+
+fn se_linux_options_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    se_linux_options(pod, &["", "container_t", "container_init_t", "container_kvm_t"])
+}
+
+fn se_linux_options_1_31(pod: &Pod<'_>) -> Option<std::string::String> {
+    se_linux_options(pod, &["", "container_t", "container_init_t", "container_kvm_t", "container_engine_t"])
+}
+
+fn seccomp_profile_baseline_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    // Before the `seccompProfile` fields, seccomp profiles were set with annotations.
+    const ANNOTATION_KEY_POD: &str = "seccomp.security.alpha.kubernetes.io/pod";
+    const ANNOTATION_KEY_CONTAINER_PREFIX: &str = "container.seccomp.security.alpha.kubernetes.io/";
+
+    let annotation = |key: &str| pod.annotations.and_then(|annotations| annotations.get(key));
+
+    let mut forbidden = std::collections::BTreeSet::new();
+    if let Some(value) = annotation(ANNOTATION_KEY_POD).filter(|value| *value == "unconfined") {
+        forbidden.insert(std::format!("{ANNOTATION_KEY_POD}={value:?}"));
+    }
+    for container in &pod.containers {
+        let key = std::format!("{ANNOTATION_KEY_CONTAINER_PREFIX}{}", container.name);
+        if let Some(value) = annotation(&key).filter(|value| *value == "unconfined") {
+            forbidden.insert(std::format!("{key}={value:?}"));
+        }
+    }
+
+    (!forbidden.is_empty()).then(|| std::format!(
+        "forbidden {} {}",
+        pluralize("annotation", "annotations", forbidden.len()),
+        forbidden.into_iter().collect::<std::vec::Vec<_>>().join(", "),
+    ))
+}
+
+fn seccomp_profile_baseline_1_19(pod: &Pod<'_>) -> Option<std::string::String> {
+    let valid_seccomp = |type_: &str| type_ != "Unconfined";
+
+    let mut bad_setters = std::vec![];
+    let mut bad_values = std::collections::BTreeSet::new();
+
+    if let Some(profile) = pod.spec.security_context.as_ref().and_then(|security_context| security_context.seccomp_profile.as_ref()) {
+        if !valid_seccomp(&profile.type_) {
+            bad_setters.push("pod".into());
+            bad_values.insert(&*profile.type_);
+        }
+    }
+
+    let mut explicitly_bad_containers = std::vec![];
+    for container in &pod.containers {
+        if let Some(profile) = container.security_context.and_then(|security_context| security_context.seccomp_profile.as_ref()) {
+            if !valid_seccomp(&profile.type_) {
+                explicitly_bad_containers.push(container.name);
+                bad_values.insert(&*profile.type_);
+            }
+        }
+    }
+    if !explicitly_bad_containers.is_empty() {
+        bad_setters.push(std::format!(
+            "{} {}",
+            pluralize("container", "containers", explicitly_bad_containers.len()),
+            join_quote(&explicitly_bad_containers),
+        ));
+    }
+
+    (!bad_setters.is_empty()).then(|| std::format!(
+        "{} must not set securityContext.seccompProfile.type to {}",
+        bad_setters.join(" and "),
+        join_quote(&bad_values.into_iter().collect::<std::vec::Vec<_>>()),
+    ))
+}
+
+fn seccomp_profile_restricted_1_19(pod: &Pod<'_>) -> Option<std::string::String> {
+    let valid_seccomp = |type_: &str| matches!(type_, "Localhost" | "RuntimeDefault");
+
+    // Things that explicitly set seccompProfile.type to a bad value.
+    let mut bad_setters = std::vec![];
+    let mut bad_values = std::collections::BTreeSet::new();
+
+    let mut pod_seccomp_set = false;
+    if let Some(profile) = pod.spec.security_context.as_ref().and_then(|security_context| security_context.seccomp_profile.as_ref()) {
+        if valid_seccomp(&profile.type_) {
+            pod_seccomp_set = true;
+        }
+        else {
+            bad_setters.push("pod".into());
+            bad_values.insert(&*profile.type_);
+        }
+    }
+
+    // Containers that explicitly set seccompProfile.type to a bad value.
+    let mut explicitly_bad_containers = std::vec![];
+    // Containers that didn't set seccompProfile and aren't caught by a pod-level seccompProfile.
+    let mut implicitly_bad_containers = std::vec![];
+    for container in &pod.containers {
+        match container.security_context.and_then(|security_context| security_context.seccomp_profile.as_ref()) {
+            Some(profile) => if !valid_seccomp(&profile.type_) {
+                explicitly_bad_containers.push(container.name);
+                bad_values.insert(&*profile.type_);
+            },
+
+            None => if !pod_seccomp_set {
+                implicitly_bad_containers.push(container.name);
+            },
+        }
+    }
+    if !explicitly_bad_containers.is_empty() {
+        bad_setters.push(std::format!(
+            "{} {}",
+            pluralize("container", "containers", explicitly_bad_containers.len()),
+            join_quote(&explicitly_bad_containers),
+        ));
+    }
+
+    let mut forbidden_details = std::vec![];
+    if !bad_setters.is_empty() {
+        forbidden_details.push(std::format!(
+            "{} must not set securityContext.seccompProfile.type to {}",
+            bad_setters.join(" and "),
+            join_quote(&bad_values.into_iter().collect::<std::vec::Vec<_>>()),
+        ));
+    }
+    if !implicitly_bad_containers.is_empty() {
+        forbidden_details.push(std::format!(
+            r#"pod or {} {} must set securityContext.seccompProfile.type to "RuntimeDefault" or "Localhost""#,
+            pluralize("container", "containers", implicitly_bad_containers.len()),
+            join_quote(&implicitly_bad_containers),
+        ));
+    }
+
+    (!forbidden_details.is_empty()).then(|| forbidden_details.join("; "))
+}
+
+fn seccomp_profile_restricted_1_25(pod: &Pod<'_>) -> Option<std::string::String> {
+    if pod.is_windows() {
+        return None;
+    }
+    seccomp_profile_restricted_1_19(pod)
+}
+
+fn sysctls(pod: &Pod<'_>, allowed_sysctls: &[&str]) -> Option<std::string::String> {
+    let forbidden_sysctls: std::vec::Vec<_> =
+        pod.spec.security_context.as_ref()
+        .and_then(|security_context| security_context.sysctls.as_ref())
+        .into_iter().flatten()
+        .filter(|sysctl| !allowed_sysctls.contains(&&*sysctl.name))
+        .map(|sysctl| &*sysctl.name)
+        .collect();
+    (!forbidden_sysctls.is_empty()).then(|| forbidden_sysctls.join(", "))
+}
+
+const SYSCTLS_ALLOWED_1_0: &[&str] = &[
+    "kernel.shm_rmid_forced",
+    "net.ipv4.ip_local_port_range",
+    "net.ipv4.tcp_syncookies",
+    "net.ipv4.ping_group_range",
+    "net.ipv4.ip_unprivileged_port_start",
+];
+
+const SYSCTLS_ALLOWED_1_27: &[&str] = &[
+    "net.ipv4.ip_local_reserved_ports",
+];
+
+const SYSCTLS_ALLOWED_1_29: &[&str] = &[
+    "net.ipv4.tcp_keepalive_time",
+    "net.ipv4.tcp_fin_timeout",
+    "net.ipv4.tcp_keepalive_intvl",
+    "net.ipv4.tcp_keepalive_probes",
+];
+
+const SYSCTLS_ALLOWED_1_32: &[&str] = &[
+    "net.ipv4.tcp_rmem",
+    "net.ipv4.tcp_wmem",
+];
+
+fn sysctls_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    sysctls(pod, SYSCTLS_ALLOWED_1_0)
+}
+
+fn sysctls_1_27(pod: &Pod<'_>) -> Option<std::string::String> {
+    sysctls(pod, &[SYSCTLS_ALLOWED_1_0, SYSCTLS_ALLOWED_1_27].concat())
+}
+
+fn sysctls_1_29(pod: &Pod<'_>) -> Option<std::string::String> {
+    sysctls(pod, &[SYSCTLS_ALLOWED_1_0, SYSCTLS_ALLOWED_1_27, SYSCTLS_ALLOWED_1_29].concat())
+}
+
+fn sysctls_1_32(pod: &Pod<'_>) -> Option<std::string::String> {
+    sysctls(pod, &[SYSCTLS_ALLOWED_1_0, SYSCTLS_ALLOWED_1_27, SYSCTLS_ALLOWED_1_29, SYSCTLS_ALLOWED_1_32].concat())
+}
+
+fn windows_host_process_1_0(pod: &Pod<'_>) -> Option<std::string::String> {
+    let is_host_process = |windows_options: Option<&crate::api::core::v1::WindowsSecurityContextOptions>|
+        windows_options.and_then(|windows_options| windows_options.host_process) == Some(true);
+
+    let bad_containers =
+        pod.containers.iter()
+        .filter(|container| is_host_process(container.security_context.and_then(|security_context| security_context.windows_options.as_ref())))
+        .count();
+    let pod_spec_forbidden = is_host_process(pod.spec.security_context.as_ref().and_then(|security_context| security_context.windows_options.as_ref()));
+
+    match (pod_spec_forbidden, bad_containers) {
+        (false, 0) => None,
+        (true, 0) => Some("pod must not set securityContext.windowsOptions.hostProcess=true".into()),
+        (false, bad_containers) => Some(std::format!(
+            "{bad_containers} {} must not set securityContext.windowsOptions.hostProcess=true",
+            pluralize("container", "containers", bad_containers),
+        )),
+        (true, bad_containers) => Some(std::format!(
+            "pod and {bad_containers} {} must not set securityContext.windowsOptions.hostProcess=true",
+            pluralize("container", "containers", bad_containers),
+        )),
+    }
+}
+
+// Helpers
+
+/// The parts of a pod that the checks look at, with its init, regular and ephemeral containers in one list.
+struct Pod<'a> {
+    annotations: Option<&'a std::collections::BTreeMap<std::string::String, std::string::String>>,
+    spec: &'a PodSpec,
+    containers: std::vec::Vec<PodContainer<'a>>,
+}
+
+struct PodContainer<'a> {
+    name: &'a str,
+    security_context: Option<&'a SecurityContext>,
+    ports: &'a [ContainerPort],
+    probes: [Option<&'a Probe>; 3],
+    lifecycle: Option<&'a Lifecycle>,
+}
+
+impl<'a> Pod<'a> {
+    fn new(metadata: Option<&'a ObjectMeta>, spec: &'a PodSpec) -> Self {
+        let container = |container: &'a Container| PodContainer {
+            name: &container.name,
+            security_context: container.security_context.as_ref(),
+            ports: container.ports.as_deref().unwrap_or_default(),
+            probes: [container.liveness_probe.as_ref(), container.readiness_probe.as_ref(), container.startup_probe.as_ref()],
+            lifecycle: container.lifecycle.as_ref(),
+        };
+
+        let containers =
+            spec.init_containers.iter().flatten().map(container)
+            .chain(spec.containers.iter().map(container))
+            .chain(spec.ephemeral_containers.iter().flatten().map(|container| PodContainer {
+                name: &container.name,
+                security_context: container.security_context.as_ref(),
+                ports: container.ports.as_deref().unwrap_or_default(),
+                probes: [container.liveness_probe.as_ref(), container.readiness_probe.as_ref(), container.startup_probe.as_ref()],
+                lifecycle: container.lifecycle.as_ref(),
+            }))
+            .collect();
+
+        Pod {
+            annotations: metadata.and_then(|metadata| metadata.annotations.as_ref()),
+            spec,
+            containers,
+        }
+    }
+
+    fn is_windows(&self) -> bool {
+        self.spec.os.as_ref().is_some_and(|os| os.name == "windows")
+    }
+}
+
+impl PodContainer<'_> {
+    /// The `host`s of the `httpGet` and `tcpSocket` handlers of the probes and lifecycle hooks of the container.
+    fn probe_and_lifecycle_hosts(&self) -> impl Iterator<Item = &str> {
+        let probe_hosts = self.probes.into_iter().flatten().flat_map(|probe| [
+            probe.http_get.as_ref().and_then(|action| action.host.as_deref()),
+            probe.tcp_socket.as_ref().and_then(|action| action.host.as_deref()),
+        ]);
+        let lifecycle_hosts =
+            self.lifecycle.into_iter()
+            .flat_map(|lifecycle| [lifecycle.post_start.as_ref(), lifecycle.pre_stop.as_ref()])
+            .flatten()
+            .flat_map(|handler| [
+                handler.http_get.as_ref().and_then(|action| action.host.as_deref()),
+                handler.tcp_socket.as_ref().and_then(|action| action.host.as_deref()),
+            ]);
+        probe_hosts.chain(lifecycle_hosts).flatten()
+    }
+
+    fn added_capabilities(&self) -> impl Iterator<Item = &str> {
+        self.security_context
+            .and_then(|security_context| security_context.capabilities.as_ref())
+            .and_then(|capabilities| capabilities.add.as_ref())
+            .into_iter().flatten()
+            .map(|capability| &**capability)
+    }
+}
+
+fn pluralize(singular: &'static str, plural: &'static str, count: usize) -> &'static str {
+    if count == 1 { singular } else { plural }
+}
+
+/// Quotes and joins the items like `"a", "b"`.
+fn join_quote<T>(items: &[T]) -> std::string::String where T: AsRef<str> {
+    items.iter().map(|item| std::format!("{:?}", item.as_ref())).collect::<std::vec::Vec<_>>().join(", ")
+}
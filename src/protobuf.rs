@@ -0,0 +1,660 @@
+//! Protobuf encoding of Kubernetes types.
+//!
+//! The API server supports the `application/vnd.kubernetes.protobuf` content type as a more compact alternative to JSON. A value in this format is
+//! the four-byte [`MAGIC`] prefix `k8s\0` followed by the protobuf encoding of an [`Unknown`] message. The `Unknown` message holds the `apiVersion`
+//! and `kind` of the object, and the protobuf encoding of the object itself in its `raw` field.
+//!
+//! Use [`encode`] and [`decode`] to convert a resource to and from this format. Types that support protobuf encoding implement the [`Message`] trait,
+//! which can also be used directly to encode and decode the bare message without the envelope.
+//!
+//! The field numbers used by the generated types are the ones from the upstream `generated.proto` files. All the generated resource types
+//! and the types of their fields implement [`Message`]. The exception is [`Info`](crate::apimachinery::pkg::version::Info), which has no protobuf form. Note that protobuf encoding is not supported
+//! for custom resources; the API server only serves them as JSON.
+//!
+//! The golang types always encode their non-pointer fields, even when they have their zero value. Such fields decode as `Some` of the zero value,
+//! eg `Some(String::new())`, where the JSON form of the same value would have omitted them.
+
+/// The content type of the protobuf encoding.
+pub const CONTENT_TYPE: &str = "application/vnd.kubernetes.protobuf";
+
+/// The content type of the protobuf encoding of a watch stream.
+pub const WATCH_CONTENT_TYPE: &str = "application/vnd.kubernetes.protobuf;stream=watch";
+
+/// The prefix of all protobuf-encoded values sent and received by the API server.
+pub const MAGIC: [u8; 4] = *b"k8s\0";
+
+/// The wire type of a protobuf field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WireType {
+    Varint,
+    Fixed64,
+    LengthDelimited,
+    Fixed32,
+}
+
+/// A trait applied to all types that can be encoded as a protobuf message.
+pub trait Message: Sized {
+    /// Appends the encoded fields of this value to the given buffer.
+    fn encode(&self, buf: &mut std::vec::Vec<u8>);
+
+    /// Decodes a value from the encoded fields in the given buffer.
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError>;
+
+    /// Decodes the value of an optional field from the encoded fields in the given buffer.
+    ///
+    /// This is the same as [`Message::decode`] for most types. The exceptions are types like `Time`, whose zero value is encoded as an empty message
+    /// and is `null` in JSON. These decode an empty message as `None`.
+    fn decode_optional(buf: &[u8]) -> Result<Option<Self>, DecodeError> {
+        Self::decode(buf).map(Some)
+    }
+}
+
+/// A trait applied to all types that can be the value of a field of a protobuf message.
+///
+/// This is implemented for the scalar types used by Kubernetes, for all types that implement [`Message`],
+/// and for `Vec`s and `BTreeMap`s of such types.
+pub trait Field: Sized {
+    /// The wire type of a single encoded value of this type.
+    const WIRE_TYPE: WireType;
+
+    /// Whether the type is encoded as a repeated field.
+    ///
+    /// Repeated values that are themselves nested inside a repeated field or a map are wrapped in a message with the values in field number 1,
+    /// like the `ExtraValue` type of `UserInfo.extra`.
+    const REPEATED: bool = false;
+
+    /// Appends the given field with this value to the given buffer.
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>);
+
+    /// Merges an encoded value of the field from the given buffer into the given field value.
+    ///
+    /// Scalar and message values replace the previous value. Repeated values and map entries are appended to it.
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError>;
+}
+
+/// The `runtime.Unknown` envelope that wraps all protobuf-encoded values sent and received by the API server.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Unknown {
+    /// The `apiVersion` of the value in `raw`.
+    pub api_version: std::string::String,
+
+    /// The `kind` of the value in `raw`.
+    pub kind: std::string::String,
+
+    /// The encoded value.
+    pub raw: std::vec::Vec<u8>,
+
+    /// The encoding of `raw`, if it is compressed.
+    pub content_encoding: std::string::String,
+
+    /// The content type of `raw`. This is empty if `raw` is protobuf-encoded.
+    pub content_type: std::string::String,
+}
+
+impl Message for Unknown {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        let mut type_meta = std::vec![];
+        self.api_version.encode_field(1, &mut type_meta);
+        self.kind.encode_field(2, &mut type_meta);
+        encode_length_delimited(1, &type_meta, buf);
+
+        encode_length_delimited(2, &self.raw, buf);
+        self.content_encoding.encode_field(3, buf);
+        self.content_type.encode_field(4, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let mut result: Unknown = Default::default();
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = decode_key(&mut buf)?;
+            match field_number {
+                1 => {
+                    let mut type_meta = decode_length_delimited(wire_type, &mut buf)?;
+                    while !type_meta.is_empty() {
+                        let (field_number, wire_type) = decode_key(&mut type_meta)?;
+                        match field_number {
+                            1 => result.api_version = decode_string(wire_type, &mut type_meta)?,
+                            2 => result.kind = decode_string(wire_type, &mut type_meta)?,
+                            _ => skip_field(wire_type, &mut type_meta)?,
+                        }
+                    }
+                },
+                2 => result.raw = decode_length_delimited(wire_type, &mut buf)?.to_vec(),
+                3 => result.content_encoding = decode_string(wire_type, &mut buf)?,
+                4 => result.content_type = decode_string(wire_type, &mut buf)?,
+                _ => skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Unknown {
+    /// Encodes this envelope with the [`MAGIC`] prefix.
+    pub fn encode_with_magic(&self) -> std::vec::Vec<u8> {
+        let mut buf = MAGIC.to_vec();
+        self.encode(&mut buf);
+        buf
+    }
+
+    /// Decodes an envelope that has the [`MAGIC`] prefix.
+    pub fn decode_with_magic(buf: &[u8]) -> Result<Self, DecodeError> {
+        let buf = buf.strip_prefix(&MAGIC[..]).ok_or(DecodeError::InvalidMagic)?;
+        Self::decode(buf)
+    }
+}
+
+/// Encodes the given resource in the format used by the API server, ie the [`MAGIC`] prefix followed by an [`Unknown`] envelope.
+pub fn encode<T>(value: &T) -> std::vec::Vec<u8> where T: crate::Resource + Message {
+    let mut raw = std::vec![];
+    value.encode(&mut raw);
+
+    let unknown = Unknown {
+        api_version: <T as crate::Resource>::API_VERSION.into(),
+        kind: <T as crate::Resource>::KIND.into(),
+        raw,
+        content_encoding: std::string::String::new(),
+        content_type: std::string::String::new(),
+    };
+    unknown.encode_with_magic()
+}
+
+/// Decodes a resource from the format used by the API server, ie the [`MAGIC`] prefix followed by an [`Unknown`] envelope.
+///
+/// Fails if the `apiVersion` or `kind` of the envelope does not match the resource type.
+pub fn decode<T>(buf: &[u8]) -> Result<T, DecodeError> where T: crate::Resource + Message {
+    let unknown = Unknown::decode_with_magic(buf)?;
+
+    if unknown.api_version != <T as crate::Resource>::API_VERSION || unknown.kind != <T as crate::Resource>::KIND {
+        return Err(DecodeError::UnexpectedTypeMeta {
+            api_version: unknown.api_version,
+            kind: unknown.kind,
+        });
+    }
+
+    if !unknown.content_encoding.is_empty() || !unknown.content_type.is_empty() {
+        return Err(DecodeError::UnsupportedContentType {
+            content_encoding: unknown.content_encoding,
+            content_type: unknown.content_type,
+        });
+    }
+
+    T::decode(&unknown.raw)
+}
+
+/// An error from decoding a protobuf-encoded value.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The value does not start with the [`MAGIC`] prefix.
+    InvalidMagic,
+
+    /// A varint is longer than ten bytes.
+    InvalidVarint,
+
+    /// A field key has an invalid field number or wire type.
+    InvalidKey(u64),
+
+    /// A length-delimited field has invalid content.
+    InvalidValue(&'static str),
+
+    /// A field has a different wire type than expected.
+    UnexpectedWireType {
+        expected: WireType,
+        actual: WireType,
+    },
+
+    /// The value ended in the middle of a field.
+    UnexpectedEof,
+
+    /// A required field was not set.
+    MissingField(&'static str),
+
+    /// The `apiVersion` or `kind` of the envelope does not match the type being decoded.
+    UnexpectedTypeMeta {
+        api_version: std::string::String,
+        kind: std::string::String,
+    },
+
+    /// The `raw` value of the envelope is not protobuf-encoded.
+    UnsupportedContentType {
+        content_encoding: std::string::String,
+        content_type: std::string::String,
+    },
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidMagic => f.write_str("value does not start with the k8s protobuf prefix"),
+            DecodeError::InvalidVarint => f.write_str("invalid varint"),
+            DecodeError::InvalidKey(key) => write!(f, "invalid field key {key}"),
+            DecodeError::InvalidValue(expected) => write!(f, "invalid value, expected {expected}"),
+            DecodeError::UnexpectedWireType { expected, actual } => write!(f, "expected wire type {expected:?} but got {actual:?}"),
+            DecodeError::UnexpectedEof => f.write_str("unexpected end of input"),
+            DecodeError::MissingField(name) => write!(f, "missing field {name:?}"),
+            DecodeError::UnexpectedTypeMeta { api_version, kind } => write!(f, "unexpected apiVersion {api_version:?} and kind {kind:?}"),
+            DecodeError::UnsupportedContentType { content_encoding, content_type } =>
+                write!(f, "unsupported content encoding {content_encoding:?} and content type {content_type:?}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Appends the given value as a varint.
+#[allow(clippy::cast_possible_truncation)]
+pub fn encode_varint(mut value: u64, buf: &mut std::vec::Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Decodes a varint from the front of the given buffer.
+pub fn decode_varint(buf: &mut &[u8]) -> Result<u64, DecodeError> {
+    let mut result = 0_u64;
+    for i in 0..10 {
+        let (&byte, rest) = buf.split_first().ok_or(DecodeError::UnexpectedEof)?;
+        *buf = rest;
+        result |= u64::from(byte & 0x7f) << (i * 7);
+        if byte < 0x80 {
+            return Ok(result);
+        }
+    }
+    Err(DecodeError::InvalidVarint)
+}
+
+/// Appends the key of a field with the given field number and wire type.
+pub fn encode_key(field_number: u32, wire_type: WireType, buf: &mut std::vec::Vec<u8>) {
+    let wire_type = match wire_type {
+        WireType::Varint => 0,
+        WireType::Fixed64 => 1,
+        WireType::LengthDelimited => 2,
+        WireType::Fixed32 => 5,
+    };
+    encode_varint((u64::from(field_number) << 3) | wire_type, buf);
+}
+
+/// Decodes the key of a field from the front of the given buffer.
+pub fn decode_key(buf: &mut &[u8]) -> Result<(u32, WireType), DecodeError> {
+    let key = decode_varint(buf)?;
+    let wire_type = match key & 0x07 {
+        0 => WireType::Varint,
+        1 => WireType::Fixed64,
+        2 => WireType::LengthDelimited,
+        5 => WireType::Fixed32,
+        _ => return Err(DecodeError::InvalidKey(key)),
+    };
+    let field_number = match u32::try_from(key >> 3) {
+        Ok(field_number) if field_number > 0 => field_number,
+        _ => return Err(DecodeError::InvalidKey(key)),
+    };
+    Ok((field_number, wire_type))
+}
+
+/// Appends a length-delimited field with the given contents.
+pub fn encode_length_delimited(field_number: u32, value: &[u8], buf: &mut std::vec::Vec<u8>) {
+    encode_key(field_number, WireType::LengthDelimited, buf);
+    encode_varint(value.len() as u64, buf);
+    buf.extend_from_slice(value);
+}
+
+/// Decodes the contents of a length-delimited field from the front of the given buffer.
+pub fn decode_length_delimited<'a>(wire_type: WireType, buf: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    expect_wire_type(WireType::LengthDelimited, wire_type)?;
+    let len = decode_varint(buf)?;
+    let len = usize::try_from(len).map_err(|_| DecodeError::UnexpectedEof)?;
+    if buf.len() < len {
+        return Err(DecodeError::UnexpectedEof);
+    }
+    let (value, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(value)
+}
+
+/// Skips over the value of a field with the given wire type at the front of the given buffer.
+pub fn skip_field(wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+    let len = match wire_type {
+        WireType::Varint => {
+            let _ = decode_varint(buf)?;
+            return Ok(());
+        },
+        WireType::Fixed64 => 8,
+        WireType::LengthDelimited => {
+            let _ = decode_length_delimited(wire_type, buf)?;
+            return Ok(());
+        },
+        WireType::Fixed32 => 4,
+    };
+    *buf = buf.get(len..).ok_or(DecodeError::UnexpectedEof)?;
+    Ok(())
+}
+
+fn expect_wire_type(expected: WireType, actual: WireType) -> Result<(), DecodeError> {
+    if expected == actual {
+        Ok(())
+    }
+    else {
+        Err(DecodeError::UnexpectedWireType { expected, actual })
+    }
+}
+
+fn decode_string(wire_type: WireType, buf: &mut &[u8]) -> Result<std::string::String, DecodeError> {
+    let value = decode_length_delimited(wire_type, buf)?;
+    let value = core::str::from_utf8(value).map_err(|_| DecodeError::InvalidValue("a UTF-8 string"))?;
+    Ok(value.into())
+}
+
+impl Field for bool {
+    const WIRE_TYPE: WireType = WireType::Varint;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        encode_key(field_number, WireType::Varint, buf);
+        encode_varint(u64::from(*self), buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        expect_wire_type(WireType::Varint, wire_type)?;
+        *value = Some(decode_varint(buf)? != 0);
+        Ok(())
+    }
+}
+
+impl Field for i32 {
+    const WIRE_TYPE: WireType = WireType::Varint;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        // Negative int32 values are sign-extended to 64 bits, as protobuf requires.
+        encode_key(field_number, WireType::Varint, buf);
+        encode_varint(i64::from(*self).cast_unsigned(), buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        expect_wire_type(WireType::Varint, wire_type)?;
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            *value = Some(decode_varint(buf)? as i32);
+        }
+        Ok(())
+    }
+}
+
+impl Field for i64 {
+    const WIRE_TYPE: WireType = WireType::Varint;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        encode_key(field_number, WireType::Varint, buf);
+        encode_varint(self.cast_unsigned(), buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        expect_wire_type(WireType::Varint, wire_type)?;
+        *value = Some(decode_varint(buf)?.cast_signed());
+        Ok(())
+    }
+}
+
+impl Field for f64 {
+    const WIRE_TYPE: WireType = WireType::Fixed64;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        encode_key(field_number, WireType::Fixed64, buf);
+        buf.extend_from_slice(&self.to_le_bytes());
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        expect_wire_type(WireType::Fixed64, wire_type)?;
+        let (bytes, rest) = buf.split_first_chunk::<8>().ok_or(DecodeError::UnexpectedEof)?;
+        *buf = rest;
+        *value = Some(f64::from_le_bytes(*bytes));
+        Ok(())
+    }
+}
+
+impl Field for std::string::String {
+    const WIRE_TYPE: WireType = WireType::LengthDelimited;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        encode_length_delimited(field_number, self.as_bytes(), buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        *value = Some(decode_string(wire_type, buf)?);
+        Ok(())
+    }
+}
+
+impl Field for crate::ByteString {
+    const WIRE_TYPE: WireType = WireType::LengthDelimited;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        encode_length_delimited(field_number, &self.0, buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        *value = Some(crate::ByteString(decode_length_delimited(wire_type, buf)?.to_vec()));
+        Ok(())
+    }
+}
+
+impl<T> Field for T where T: Message {
+    const WIRE_TYPE: WireType = WireType::LengthDelimited;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        let mut value = std::vec![];
+        self.encode(&mut value);
+        encode_length_delimited(field_number, &value, buf);
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        *value = T::decode_optional(decode_length_delimited(wire_type, buf)?)?;
+        Ok(())
+    }
+}
+
+impl<T> Message for std::boxed::Box<T> where T: Message {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        (**self).encode(buf);
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, DecodeError> {
+        Ok(std::boxed::Box::new(T::decode(buf)?))
+    }
+
+    fn decode_optional(buf: &[u8]) -> Result<Option<Self>, DecodeError> {
+        Ok(T::decode_optional(buf)?.map(std::boxed::Box::new))
+    }
+}
+
+/// A `metav1.Duration` is encoded as a message with its nanoseconds in field 1.
+///
+/// Durations that do not fit in an `int64` of nanoseconds are saturated.
+impl Message for crate::Duration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        let nanos = self.0.as_nanos();
+        let nanos = i64::try_from(nanos).unwrap_or(if nanos < 0 { i64::MIN } else { i64::MAX });
+        nanos.encode_field(1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, DecodeError> {
+        let mut nanos = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = decode_key(&mut buf)?;
+            match field_number {
+                1 => Field::merge_field(&mut nanos, wire_type, &mut buf)?,
+                _ => skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(crate::Duration::from_nanos(nanos.unwrap_or_default()))
+    }
+}
+
+/// Encodes a value nested inside a repeated field or a map entry, wrapping it in a message if it is itself repeated.
+fn encode_nested<T>(value: &T, field_number: u32, buf: &mut std::vec::Vec<u8>) where T: Field {
+    if T::REPEATED {
+        let mut wrapper = std::vec![];
+        value.encode_field(1, &mut wrapper);
+        encode_length_delimited(field_number, &wrapper, buf);
+    }
+    else {
+        value.encode_field(field_number, buf);
+    }
+}
+
+/// Merges a value nested inside a repeated field or a map entry, unwrapping it from a message if it is itself repeated.
+fn merge_nested<T>(value: &mut Option<T>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> where T: Field {
+    if T::REPEATED {
+        let mut wrapper = decode_length_delimited(wire_type, buf)?;
+        while !wrapper.is_empty() {
+            let (field_number, wire_type) = decode_key(&mut wrapper)?;
+            if field_number == 1 {
+                T::merge_field(value, wire_type, &mut wrapper)?;
+            }
+            else {
+                skip_field(wire_type, &mut wrapper)?;
+            }
+        }
+        Ok(())
+    }
+    else {
+        T::merge_field(value, wire_type, buf)
+    }
+}
+
+impl<T> Field for std::vec::Vec<T> where T: Field {
+    const WIRE_TYPE: WireType = T::WIRE_TYPE;
+
+    const REPEATED: bool = true;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        for item in self {
+            encode_nested(item, field_number, buf);
+        }
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        let value = value.get_or_insert_with(std::vec::Vec::new);
+
+        if wire_type == WireType::LengthDelimited && T::WIRE_TYPE != WireType::LengthDelimited {
+            // Packed encoding of scalars
+            let mut packed = decode_length_delimited(wire_type, buf)?;
+            while !packed.is_empty() {
+                let mut item = None;
+                T::merge_field(&mut item, T::WIRE_TYPE, &mut packed)?;
+                value.extend(item);
+            }
+        }
+        else {
+            let mut item = None;
+            merge_nested(&mut item, wire_type, buf)?;
+            value.extend(item);
+        }
+
+        Ok(())
+    }
+}
+
+impl<V> Field for std::collections::BTreeMap<std::string::String, V> where V: Field {
+    const WIRE_TYPE: WireType = WireType::LengthDelimited;
+
+    const REPEATED: bool = true;
+
+    fn encode_field(&self, field_number: u32, buf: &mut std::vec::Vec<u8>) {
+        for (key, value) in self {
+            let mut entry = std::vec![];
+            key.encode_field(1, &mut entry);
+            encode_nested(value, 2, &mut entry);
+            encode_length_delimited(field_number, &entry, buf);
+        }
+    }
+
+    fn merge_field(value: &mut Option<Self>, wire_type: WireType, buf: &mut &[u8]) -> Result<(), DecodeError> {
+        let mut entry = decode_length_delimited(wire_type, buf)?;
+
+        let mut entry_key = None;
+        let mut entry_value = None;
+        while !entry.is_empty() {
+            let (field_number, wire_type) = decode_key(&mut entry)?;
+            match field_number {
+                1 => Field::merge_field(&mut entry_key, wire_type, &mut entry)?,
+                2 => merge_nested(&mut entry_value, wire_type, &mut entry)?,
+                _ => skip_field(wire_type, &mut entry)?,
+            }
+        }
+
+        let entry_key = entry_key.unwrap_or_default();
+        let entry_value = entry_value.ok_or(DecodeError::MissingField("value"))?;
+        value.get_or_insert_with(Default::default).insert(entry_key, entry_value);
+
+        Ok(())
+    }
+}
+
+/// Encodes the given bytes as the `raw` field of a message, as used by `RawExtension`, `JSON` and `FieldsV1`.
+pub fn encode_raw(raw: &[u8], buf: &mut std::vec::Vec<u8>) {
+    encode_length_delimited(1, raw, buf);
+}
+
+/// Decodes the `raw` field of a message, as used by `RawExtension`, `JSON` and `FieldsV1`.
+pub fn decode_raw(mut buf: &[u8]) -> Result<&[u8], DecodeError> {
+    let mut result: &[u8] = &[];
+
+    while !buf.is_empty() {
+        let (field_number, wire_type) = decode_key(&mut buf)?;
+        if field_number == 1 {
+            result = decode_length_delimited(wire_type, &mut buf)?;
+        }
+        else {
+            skip_field(wire_type, &mut buf)?;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Encodes the given JSON value as the `raw` field of a message, as used by `RawExtension`, `JSON` and `FieldsV1`.
+#[allow(clippy::missing_panics_doc)] // serde_json::Value always serializes successfully.
+pub fn encode_raw_json(value: &serde_json::Value, buf: &mut std::vec::Vec<u8>) {
+    let raw = serde_json::to_vec(value).expect("serde_json::Value always serializes successfully");
+    encode_raw(&raw, buf);
+}
+
+/// Decodes a JSON value from the `raw` field of a message, as used by `RawExtension`, `JSON` and `FieldsV1`.
+///
+/// An empty `raw` field is decoded as `null`.
+pub fn decode_raw_json(buf: &[u8]) -> Result<serde_json::Value, DecodeError> {
+    let raw = decode_raw(buf)?;
+    if raw.is_empty() {
+        return Ok(serde_json::Value::Null);
+    }
+
+    serde_json::from_slice(raw).map_err(|_| DecodeError::InvalidValue("a JSON value"))
+}
+
+/// Encodes the given seconds and nanoseconds since the Unix epoch as a `Time` or `MicroTime` message, ie a `google.protobuf.Timestamp`.
+pub fn encode_timestamp(seconds: i64, nanos: i32, buf: &mut std::vec::Vec<u8>) {
+    seconds.encode_field(1, buf);
+    nanos.encode_field(2, buf);
+}
+
+/// Decodes a timestamp from a `Time` or `MicroTime` message, ie a `google.protobuf.Timestamp`.
+pub fn decode_timestamp(mut buf: &[u8]) -> Result<jiff::Timestamp, DecodeError> {
+    let mut seconds = None;
+    let mut nanos = None;
+
+    while !buf.is_empty() {
+        let (field_number, wire_type) = decode_key(&mut buf)?;
+        match field_number {
+            1 => Field::merge_field(&mut seconds, wire_type, &mut buf)?,
+            2 => Field::merge_field(&mut nanos, wire_type, &mut buf)?,
+            _ => skip_field(wire_type, &mut buf)?,
+        }
+    }
+
+    jiff::Timestamp::new(seconds.unwrap_or_default(), nanos.unwrap_or_default()).map_err(|_| DecodeError::InvalidValue("a timestamp"))
+}
@@ -49,6 +49,153 @@ impl ResourceScope for NamespaceResourceScope {}
 pub struct SubResourceScope {}
 impl ResourceScope for SubResourceScope {}
 
+/// A trait applied to all Kubernetes resources that are served under their own URL path, as opposed to subresources.
+pub trait DiscoverableResource: Resource {
+    /// Describes the resource the way the API server's discovery endpoints do.
+    ///
+    /// The verbs and subresources are the ones that the API server's OpenAPI spec has operations for.
+    const API_RESOURCE: ApiResource;
+}
+
+/// Describes a [`DiscoverableResource`], like an `APIResource` returned by the API server's discovery endpoints.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ApiResource {
+    /// The group of the resource, or the empty string if the resource doesn't have a group.
+    pub group: &'static str,
+
+    /// The version of the resource.
+    pub version: &'static str,
+
+    /// The kind of the resource.
+    pub kind: &'static str,
+
+    /// The plural name of the resource, like `"pods"`. This is the same as [`Resource::URL_PATH_SEGMENT`].
+    pub plural: &'static str,
+
+    /// Whether the resource is namespace-scoped or cluster-scoped.
+    pub scope: ApiResourceScope,
+
+    /// The verbs that the resource supports.
+    pub verbs: &'static [Verb],
+
+    /// The names of the subresources of the resource, like `"status"` and `"scale"`, sorted by name.
+    pub subresources: &'static [&'static str],
+}
+
+impl ApiResource {
+    /// Returns whether the resource supports the given verb.
+    pub fn supports(&self, verb: Verb) -> bool {
+        self.verbs.contains(&verb)
+    }
+
+    /// Returns whether the resource has a subresource with the given name.
+    pub fn has_subresource(&self, name: &str) -> bool {
+        self.subresources.contains(&name)
+    }
+}
+
+/// The scope of an [`ApiResource`]. This is the value-level equivalent of [`Resource::Scope`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ApiResourceScope {
+    /// The resource is cluster-scoped. Corresponds to [`ClusterResourceScope`].
+    Cluster,
+
+    /// The resource is namespace-scoped. Corresponds to [`NamespaceResourceScope`].
+    Namespace,
+}
+
+/// A verb that a resource supports, as used in discovery and authorization checks.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Verb {
+    /// The resource can be read individually by name. Corresponds to `"get"`.
+    Get,
+
+    /// The resource can be listed. Corresponds to `"list"`.
+    List,
+
+    /// The resource can be watched for changes. Corresponds to `"watch"`.
+    Watch,
+
+    /// The resource can be created. Corresponds to `"create"`.
+    Create,
+
+    /// The resource can be replaced. Corresponds to `"update"`.
+    Update,
+
+    /// The resource can be patched. Corresponds to `"patch"`.
+    Patch,
+
+    /// The resource can be deleted individually by name. Corresponds to `"delete"`.
+    Delete,
+
+    /// The resource can be deleted as a collection. Corresponds to `"deletecollection"`.
+    DeleteCollection,
+}
+
+impl Verb {
+    /// The name of the verb, like `"get"` and `"deletecollection"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verb::Get => "get",
+            Verb::List => "list",
+            Verb::Watch => "watch",
+            Verb::Create => "create",
+            Verb::Update => "update",
+            Verb::Patch => "patch",
+            Verb::Delete => "delete",
+            Verb::DeleteCollection => "deletecollection",
+        }
+    }
+}
+
+impl core::fmt::Display for Verb {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A trait applied to a resource type that is a subresource of the `Parent` resource.
+///
+/// For example, [`api::autoscaling::v1::Scale`](crate::api::autoscaling::v1::Scale) is the `"scale"` subresource of
+/// [`api::apps::v1::Deployment`](crate::api::apps::v1::Deployment), [`api::apps::v1::ReplicaSet`](crate::api::apps::v1::ReplicaSet)
+/// and [`api::apps::v1::StatefulSet`](crate::api::apps::v1::StatefulSet), so it impls this trait for each of them.
+///
+/// ```rust,ignore
+/// fn scale_url_path<T>(name: &str, namespace: &str) -> String where Scale: SubresourceOf<T>, T: Resource<Scope = NamespaceResourceScope> {
+///     <Scale as SubresourceOf<T>>::url_path(name, Some(namespace))
+/// }
+///
+/// assert_eq!(scale_url_path::<Deployment>("foo", "default"), "/apis/apps/v1/namespaces/default/deployments/foo/scale");
+/// ```
+pub trait SubresourceOf<Parent>: Resource where Parent: Resource {
+    /// The name of the subresource, which comes after the parent resource's name in the URLs of the subresource.
+    ///
+    /// This is usually the same as [`Resource::URL_PATH_SEGMENT`], but not for types that are also served under their own URL path.
+    /// For example, [`api::core::v1::Binding`](crate::api::core::v1::Binding)'s value is `"binding"`,
+    /// but its `URL_PATH_SEGMENT` is `"bindings"`.
+    const NAME: &'static str;
+
+    /// Returns the URL path of the subresource of the parent resource with the given name, like `/api/v1/namespaces/default/pods/foo/eviction`.
+    ///
+    /// The namespace must be set if the parent resource is namespace-scoped, and unset if it is cluster-scoped.
+    fn url_path(parent_name: &str, namespace: Option<&str>) -> std::string::String {
+        use core::fmt::Write;
+
+        let mut result =
+            if Parent::GROUP.is_empty() {
+                std::format!("/api/{}", Parent::VERSION)
+            }
+            else {
+                std::format!("/apis/{}/{}", Parent::GROUP, Parent::VERSION)
+            };
+        if let Some(namespace) = namespace {
+            write!(result, "/namespaces/{namespace}").expect("writing to a String cannot fail");
+        }
+        write!(result, "/{}/{parent_name}/{}", Parent::URL_PATH_SEGMENT, Self::NAME).expect("writing to a String cannot fail");
+        result
+    }
+}
+
 /// A trait applied to all Kubernetes resources that can be part of a corresponding list.
 pub trait ListableResource: Resource {
     /// The kind of the list type of the resource.
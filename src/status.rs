@@ -0,0 +1,522 @@
+//! Typed errors decoded from `meta.k8s.io/v1` `Status` responses.
+//!
+//! When a request fails, the API server responds with a [`Status`] whose `reason` and `code` describe the failure. [`StatusError`] wraps such a `Status`
+//! and parses its `reason` into a [`StatusReason`] and its causes into [`Cause`]s. Its `is_*` predicates mirror the ones of client-go's
+//! `k8s.io/apimachinery/pkg/api/errors` package, including their fallback to the HTTP status code when the reason is empty or not recognized.
+//!
+//! Use [`StatusError::from_response`] to classify the status code, `Retry-After` header and body of an HTTP response.
+//!
+//! ```rust,ignore
+//! let retry_after = response.headers().get("retry-after").and_then(|value| value.to_str().ok());
+//! if let Some(err) = k8s_openapi::status::StatusError::from_response(response.status().as_u16(), retry_after, &body) {
+//!     if err.is_not_found() {
+//!         return Ok(None);
+//!     }
+//!
+//!     return Err(err.into());
+//! }
+//! ```
+
+use crate::apimachinery::pkg::apis::meta::v1::{Status, StatusCause, StatusDetails};
+
+/// The largest prefix of a body that is not a `Status` that [`StatusError::from_response`] includes in the error.
+const MAX_UNSTRUCTURED_RESPONSE_TEXT_BYTES: usize = 2048;
+
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+
+            /// A value that is not known to this crate.
+            Other(std::string::String),
+        }
+
+        impl $name {
+            /// The string representation of this value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( $name::$variant => $value, )*
+                    $name::Other(value) => value,
+                }
+            }
+
+            /// Whether this value is one of the values known to this crate, ie not [`Other`]($name::Other).
+            pub fn is_known(&self) -> bool {
+                !matches!(self, $name::Other(_))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $( $value => $name::$variant, )*
+                    value => $name::Other(value.into()),
+                }
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The machine-readable reason of a failed [`Status`], ie the values of apimachinery's `metav1.StatusReason`.
+    #[derive(Default)]
+    pub enum StatusReason {
+        /// The server has declined to indicate a specific reason. The `code` of the status may contain more information.
+        #[default]
+        Unknown = "",
+
+        /// The server can be reached and understood the request, but requires the user to present appropriate authorization credentials
+        /// in order for the action to be completed. Status code 401.
+        Unauthorized = "Unauthorized",
+
+        /// The server can be reached and understood the request, but refuses to take any further action. Status code 403.
+        Forbidden = "Forbidden",
+
+        /// One or more resources required for this operation could not be found. Status code 404.
+        NotFound = "NotFound",
+
+        /// The resource being created already exists. Status code 409.
+        AlreadyExists = "AlreadyExists",
+
+        /// The requested operation cannot be completed due to a conflict in the operation, such as an update with a stale `resourceVersion`.
+        /// Status code 409.
+        Conflict = "Conflict",
+
+        /// The item is no longer available at the server and no forwarding address is known. Status code 410.
+        Gone = "Gone",
+
+        /// The requested create or update operation cannot be completed due to invalid data provided as part of the request. Status code 422.
+        Invalid = "Invalid",
+
+        /// The server can be reached and understood the request, but cannot complete the action in a reasonable time.
+        /// The client should retry the request. Status code 500.
+        ServerTimeout = "ServerTimeout",
+
+        /// The server encountered an error while retrieving resources from the backend object store, such as a failure to decode or transform them.
+        /// Status code 500.
+        StoreReadError = "StorageReadError",
+
+        /// The request could not be completed within the given time. Status code 504.
+        Timeout = "Timeout",
+
+        /// The server experienced too many requests within a given window and the client must wait to perform the action again. Status code 429.
+        TooManyRequests = "TooManyRequests",
+
+        /// The request itself was invalid, because the request doesn't make any sense. Status code 400.
+        BadRequest = "BadRequest",
+
+        /// The action the client attempted to perform on the resource was not supported by the code. Status code 405.
+        MethodNotAllowed = "MethodNotAllowed",
+
+        /// The accept types indicated by the client were not acceptable to the server. Status code 406.
+        NotAcceptable = "NotAcceptable",
+
+        /// The request entity is too large. Status code 413.
+        RequestEntityTooLarge = "RequestEntityTooLarge",
+
+        /// The content type sent by the client is not acceptable to the server. Status code 415.
+        UnsupportedMediaType = "UnsupportedMediaType",
+
+        /// An internal error occurred. It is unexpected and the outcome of the call is unknown. Status code 500.
+        InternalError = "InternalError",
+
+        /// The content has expired and is no longer available, such as a list or watch with a `resourceVersion` that has been compacted.
+        /// Status code 410.
+        Expired = "Expired",
+
+        /// The request is valid, but the requested service is unavailable at this time. Status code 503.
+        ServiceUnavailable = "ServiceUnavailable",
+    }
+}
+
+string_enum! {
+    /// The machine-readable reason of a [`Cause`], ie the values of apimachinery's `metav1.CauseType`.
+    pub enum CauseType {
+        /// A required value could not be found.
+        FieldValueNotFound = "FieldValueNotFound",
+
+        /// A required value was not provided.
+        FieldValueRequired = "FieldValueRequired",
+
+        /// A value that must be unique has been repeated.
+        FieldValueDuplicate = "FieldValueDuplicate",
+
+        /// A value is invalid.
+        FieldValueInvalid = "FieldValueInvalid",
+
+        /// A value is not one of the supported values.
+        FieldValueNotSupported = "FieldValueNotSupported",
+
+        /// A value is valid, but is not allowed.
+        Forbidden = "FieldValueForbidden",
+
+        /// A value is too long.
+        TooLong = "FieldValueTooLong",
+
+        /// A list has too many items.
+        TooMany = "FieldValueTooMany",
+
+        /// An internal error occurred that is not related to the user's input.
+        Internal = "InternalError",
+
+        /// A value did not match the schema type for that field.
+        TypeInvalid = "FieldValueTypeInvalid",
+
+        /// The server responded to the client without the expected return type. The presence of this cause indicates
+        /// the error may be due to an intervening proxy or the server software malfunctioning.
+        UnexpectedServerResponse = "UnexpectedServerResponse",
+
+        /// A request to update the fields of a resource failed because they are owned by another field manager.
+        FieldManagerConflict = "FieldManagerConflict",
+
+        /// The requested `resourceVersion` is newer than the data observed by the API server, so the request cannot be served.
+        ResourceVersionTooLarge = "ResourceVersionTooLarge",
+    }
+}
+
+/// A typed view of a [`StatusCause`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cause<'a> {
+    /// The machine-readable reason of the cause, or `None` if the cause does not have one.
+    pub cause_type: Option<CauseType>,
+
+    /// The human-readable description of the cause.
+    pub message: &'a str,
+
+    /// The field of the resource that caused the error, such as `spec.containers[0].image`, or an empty string if the cause does not have one.
+    pub field: &'a str,
+}
+
+impl<'a> From<&'a StatusCause> for Cause<'a> {
+    fn from(cause: &'a StatusCause) -> Self {
+        Cause {
+            cause_type: cause.reason.as_deref().filter(|reason| !reason.is_empty()).map(Into::into),
+            message: cause.message.as_deref().unwrap_or_default(),
+            field: cause.field.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+/// An error returned by the API server, in the form of a [`Status`].
+///
+/// This is the equivalent of client-go's `errors.StatusError`. Its `Display` impl prints the message of the status.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusError {
+    status: Status,
+    reason: StatusReason,
+}
+
+impl StatusError {
+    /// Wraps the given `Status`.
+    pub fn new(status: Status) -> Self {
+        let reason = status.reason.as_deref().unwrap_or_default().into();
+        StatusError { status, reason }
+    }
+
+    /// Classifies the response to an API request by its HTTP status code, the value of its `Retry-After` header if any, and its body.
+    ///
+    /// This mirrors how client-go's REST client turns a response into an error:
+    ///
+    /// - If the body is a `Status` whose `status` is not `"Success"`, that `Status` is the error, regardless of the status code.
+    ///
+    /// - Otherwise, if the status code is not in the range 200 to 206, the error is synthesized from the status code like `errors.NewGenericServerResponse` does.
+    ///   Its message describes the status code, and its details have a [`CauseType::UnexpectedServerResponse`] cause with (the first 2 KiB of)
+    ///   the body as its message if it's UTF-8, or `"unknown"` otherwise.
+    ///
+    ///   For a 429 or 5xx response, the `Retry-After` header is parsed as a number of seconds like client-go's `checkWait` does,
+    ///   and becomes the `retryAfterSeconds` of the details. See [`StatusError::retry_after`].
+    ///
+    ///   client-go uses the [`StatusReason::AlreadyExists`] reason for a 409 response to a `POST` request, but since the request method is not known here,
+    ///   this always uses [`StatusReason::Conflict`]. Use [`StatusError::is_conflict`] for such errors.
+    ///
+    /// - Otherwise the response is not an error and this returns `None`.
+    pub fn from_response(status_code: u16, retry_after: Option<&str>, body: &[u8]) -> Option<Self> {
+        if let Ok(serde_json::Value::Object(value)) = serde_json::from_slice(body) {
+            if value.get("kind").and_then(serde_json::Value::as_str) == Some("Status") {
+                if let Ok(status) = serde_json::from_value::<Status>(serde_json::Value::Object(value)) {
+                    if status.status.as_deref() != Some("Success") {
+                        return Some(StatusError::new(status));
+                    }
+                }
+            }
+        }
+
+        if (200..=206).contains(&status_code) {
+            return None;
+        }
+
+        let body = &body[..body.len().min(MAX_UNSTRUCTURED_RESPONSE_TEXT_BYTES)];
+        let body = match core::str::from_utf8(body) {
+            Ok(body) => Some(body),
+            // The body was cut off in the middle of a UTF-8 sequence.
+            Err(err) if err.error_len().is_none() => core::str::from_utf8(&body[..err.valid_up_to()]).ok(),
+            Err(_) => None,
+        };
+        let server_message = body.map_or("unknown", str::trim);
+
+        let retry_after_seconds =
+            if status_code == 429 || status_code >= 500 { retry_after.and_then(|retry_after| retry_after.parse().ok()) }
+            else { None };
+
+        Some(StatusError::from_status_code(status_code, server_message, retry_after_seconds))
+    }
+
+    /// Synthesizes an error from a status code, like client-go's `errors.NewGenericServerResponse`.
+    fn from_status_code(status_code: u16, server_message: &str, retry_after_seconds: Option<i32>) -> Self {
+        let (reason, message) = match status_code {
+            400 => (StatusReason::BadRequest, "the server rejected our request for an unknown reason".into()),
+            401 => (StatusReason::Unauthorized, "the server has asked for the client to provide credentials".into()),
+            // The server message has details about who is trying to perform what action.
+            403 => (StatusReason::Forbidden, server_message.into()),
+            404 => (StatusReason::NotFound, "the server could not find the requested resource".into()),
+            405 => (StatusReason::MethodNotAllowed, "the server does not allow this method on the requested resource".into()),
+            // The server message has details about which content types are acceptable.
+            406 => (
+                StatusReason::NotAcceptable,
+                if server_message.is_empty() || server_message == "unknown" {
+                    "the server was unable to respond with a content type that the client supports".into()
+                }
+                else {
+                    server_message.into()
+                },
+            ),
+            // The server message has details about which content types are acceptable.
+            415 => (StatusReason::UnsupportedMediaType, server_message.into()),
+            409 => (StatusReason::Conflict, "the server reported a conflict".into()),
+            422 => (StatusReason::Invalid, "the server rejected our request due to an error in our request".into()),
+            429 => (StatusReason::TooManyRequests, "the server has received too many requests and has asked us to try again later".into()),
+            503 => (StatusReason::ServiceUnavailable, "the server is currently unable to handle the request".into()),
+            504 => (
+                StatusReason::Timeout,
+                "the server was unable to return a response in the time allotted, but may still be processing the request".into(),
+            ),
+            500.. => (
+                StatusReason::InternalError,
+                std::format!("an error on the server ({server_message:?}) has prevented the request from succeeding"),
+            ),
+            _ => (StatusReason::Unknown, std::format!("the server responded with the status code {status_code} but did not return more information")),
+        };
+
+        let status = Status {
+            code: Some(status_code.into()),
+            details: Some(StatusDetails {
+                causes: Some(std::vec![StatusCause {
+                    message: Some(server_message.into()),
+                    reason: Some(CauseType::UnexpectedServerResponse.as_str().into()),
+                    ..Default::default()
+                }]),
+                retry_after_seconds,
+                ..Default::default()
+            }),
+            message: Some(message),
+            reason: Some(reason.as_str().into()),
+            status: Some("Failure".into()),
+            ..Default::default()
+        };
+
+        StatusError { status, reason }
+    }
+
+    /// The `Status` of this error.
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    /// Consumes this error and returns its `Status`.
+    pub fn into_status(self) -> Status {
+        self.status
+    }
+
+    /// The HTTP status code of this error, or 0 if the status does not have one.
+    pub fn code(&self) -> i32 {
+        self.status.code.unwrap_or_default()
+    }
+
+    /// The reason of this error.
+    pub fn reason(&self) -> &StatusReason {
+        &self.reason
+    }
+
+    /// The human-readable message of this error.
+    pub fn message(&self) -> &str {
+        self.status.message.as_deref().unwrap_or_default()
+    }
+
+    /// The details of this error, if any.
+    pub fn details(&self) -> Option<&StatusDetails> {
+        self.status.details.as_ref()
+    }
+
+    /// The causes of this error.
+    pub fn causes(&self) -> impl Iterator<Item = Cause<'_>> {
+        self.details()
+            .and_then(|details| details.causes.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .map(Into::into)
+    }
+
+    /// The time after which the client should retry the request, if the server suggested one.
+    ///
+    /// Like client-go's `errors.SuggestsClientDelay`, this is the `retryAfterSeconds` of the details if it's positive.
+    /// For a [`StatusReason::ServerTimeout`] error, which explicitly requests the client to delay the request, it's the `retryAfterSeconds` of the details
+    /// even if it's zero or not set.
+    pub fn retry_after(&self) -> Option<core::time::Duration> {
+        let details = self.details()?;
+        let retry_after_seconds = details.retry_after_seconds.unwrap_or_default();
+        if self.reason == StatusReason::ServerTimeout || retry_after_seconds > 0 {
+            Some(core::time::Duration::from_secs(retry_after_seconds.try_into().unwrap_or_default()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Whether this error has the given reason, or has a reason that is empty or not recognized and the given status code.
+    fn has_reason_or_code(&self, reason: &StatusReason, code: i32) -> bool {
+        self.reason == *reason || ((self.reason == StatusReason::Unknown || !self.reason.is_known()) && self.code() == code)
+    }
+
+    /// Whether the requested resource could not be found.
+    pub fn is_not_found(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::NotFound, 404)
+    }
+
+    /// Whether the resource being created already exists.
+    pub fn is_already_exists(&self) -> bool {
+        self.reason == StatusReason::AlreadyExists
+    }
+
+    /// Whether the request could not be completed due to a conflict, such as an update with a stale `resourceVersion`.
+    pub fn is_conflict(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Conflict, 409)
+    }
+
+    /// Whether the request was rejected because the resource was invalid.
+    pub fn is_invalid(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Invalid, 422)
+    }
+
+    /// Whether the requested content is no longer available, such as a watch with a `resourceVersion` that has been compacted.
+    pub fn is_gone(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Gone, 410)
+    }
+
+    /// Whether the requested content has expired, such as the continue token of a list.
+    pub fn is_resource_expired(&self) -> bool {
+        self.reason == StatusReason::Expired
+    }
+
+    /// Whether the server was unable to respond with a content type that the client supports.
+    pub fn is_not_acceptable(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::NotAcceptable, 406)
+    }
+
+    /// Whether the server does not support the content type of the request.
+    pub fn is_unsupported_media_type(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::UnsupportedMediaType, 415)
+    }
+
+    /// Whether the server does not allow the method of the request on the requested resource.
+    pub fn is_method_not_supported(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::MethodNotAllowed, 405)
+    }
+
+    /// Whether the server is unavailable.
+    pub fn is_service_unavailable(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::ServiceUnavailable, 503)
+    }
+
+    /// Whether the request was malformed.
+    pub fn is_bad_request(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::BadRequest, 400)
+    }
+
+    /// Whether the request requires the client to provide credentials.
+    pub fn is_unauthorized(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Unauthorized, 401)
+    }
+
+    /// Whether the client is not allowed to perform the request.
+    pub fn is_forbidden(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Forbidden, 403)
+    }
+
+    /// Whether the request did not complete in time.
+    pub fn is_timeout(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::Timeout, 504)
+    }
+
+    /// Whether the server could not complete the request in a reasonable time and asked the client to retry it.
+    pub fn is_server_timeout(&self) -> bool {
+        self.reason == StatusReason::ServerTimeout
+    }
+
+    /// Whether the server encountered an internal error.
+    pub fn is_internal_error(&self) -> bool {
+        self.has_reason_or_code(&StatusReason::InternalError, 500)
+    }
+
+    /// Whether the server asked the client to slow down.
+    ///
+    /// Like client-go, this also checks the status code when the reason is a different known reason.
+    pub fn is_too_many_requests(&self) -> bool {
+        self.reason == StatusReason::TooManyRequests || self.code() == 429
+    }
+
+    /// Whether the request was rejected because its body was too large.
+    ///
+    /// Like client-go, this also checks the status code when the reason is a different known reason.
+    pub fn is_request_entity_too_large(&self) -> bool {
+        self.reason == StatusReason::RequestEntityTooLarge || self.code() == 413
+    }
+
+    /// Whether the server failed to read the resources from its backend store.
+    pub fn is_store_read_error(&self) -> bool {
+        self.reason == StatusReason::StoreReadError
+    }
+
+    /// Whether the server responded without the expected return type, such as when the response came from an intervening proxy.
+    pub fn is_unexpected_server_error(&self) -> bool {
+        self.causes().any(|cause| cause.cause_type == Some(CauseType::UnexpectedServerResponse))
+    }
+}
+
+impl From<Status> for StatusError {
+    fn from(status: Status) -> Self {
+        StatusError::new(status)
+    }
+}
+
+impl From<StatusError> for Status {
+    fn from(err: StatusError) -> Self {
+        err.status
+    }
+}
+
+impl core::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for StatusError {}
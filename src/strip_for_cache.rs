@@ -0,0 +1,33 @@
+/// The annotation that `kubectl apply` uses to store the last applied configuration of an object.
+const LAST_APPLIED_CONFIGURATION_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+impl crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+    /// Removes the fields that controllers rarely need but that take up a large part of the memory of a cached object,
+    /// ie `managedFields` and the `kubectl.kubernetes.io/last-applied-configuration` annotation.
+    pub fn strip_for_cache(&mut self) {
+        self.managed_fields = None;
+
+        if let Some(annotations) = &mut self.annotations {
+            annotations.remove(LAST_APPLIED_CONFIGURATION_ANNOTATION);
+            if annotations.is_empty() {
+                self.annotations = None;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "compact")]
+impl crate::compact::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+    /// Removes the fields that controllers rarely need but that take up a large part of the memory of a cached object,
+    /// ie `managedFields` and the `kubectl.kubernetes.io/last-applied-configuration` annotation.
+    pub fn strip_for_cache(&mut self) {
+        self.managed_fields = None;
+
+        if let Some(annotations) = &mut self.annotations {
+            annotations.remove(LAST_APPLIED_CONFIGURATION_ANNOTATION);
+            if annotations.is_empty() {
+                self.annotations = None;
+            }
+        }
+    }
+}
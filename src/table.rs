@@ -0,0 +1,687 @@
+//! The `meta.k8s.io/v1` `Table` type for server-side printing.
+//!
+//! When a request has the [`Table::ACCEPT`] `Accept` header, the API server returns a [`Table`] of the requested resources, with the same columns that
+//! `kubectl get` prints. This includes the `additionalPrinterColumns` of custom resources. The `includeObject` query parameter controls whether
+//! each row also contains the object it was created from. See [`IncludeObjectPolicy`] for its values.
+//!
+//! [`Table::render`] renders a table as aligned text like `kubectl get` does.
+//!
+//! ```rust,ignore
+//! let table: k8s_openapi::table::Table<k8s_openapi::PartialObjectMetadata<Pod>> = ...;
+//! print!("{}", table.render(Default::default()));
+//! ```
+//!
+//! ```text
+//! NAME   READY   STATUS    RESTARTS   AGE
+//! foo    1/1     Running   0          5d
+//! ```
+
+/// A tabular representation of a list of resources.
+///
+/// `T` is the type of the objects in [`TableRow::object`], such as [`PartialObjectMetadata`](crate::PartialObjectMetadata) when the table
+/// was requested with `includeObject=Metadata`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table<T = serde_json::Value> {
+    /// The columns of the table.
+    pub column_definitions: std::vec::Vec<TableColumnDefinition>,
+
+    /// Standard list metadata.
+    pub metadata: crate::apimachinery::pkg::apis::meta::v1::ListMeta,
+
+    /// The rows of the table, in the same order as the resources in the list.
+    pub rows: std::vec::Vec<TableRow<T>>,
+}
+
+impl<T> Table<T> {
+    /// The value of the `Accept` header to request a `Table`.
+    pub const ACCEPT: &'static str = "application/json;as=Table;v=v1;g=meta.k8s.io";
+
+    /// The `apiVersion` of a `Table`.
+    pub const API_VERSION: &'static str = "meta.k8s.io/v1";
+
+    /// The `kind` of a `Table`.
+    pub const KIND: &'static str = "Table";
+
+    /// Renders the table as aligned text, like `kubectl get` does.
+    ///
+    /// Columns are separated by at least three spaces and are at least six characters wide, and cells are cut off at their first line break.
+    /// As with `kubectl get`, an empty table renders as an empty string, even if headers are enabled.
+    pub fn render(&self, options: RenderOptions) -> std::string::String {
+        let columns: std::vec::Vec<_> =
+            self.column_definitions.iter()
+            .enumerate()
+            .filter(|(_, column)| options.wide || column.priority == 0)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut lines: std::vec::Vec<std::vec::Vec<std::string::String>> = std::vec![];
+
+        if !options.no_headers {
+            if self.rows.is_empty() {
+                return std::string::String::new();
+            }
+
+            lines.push(columns.iter().map(|&i| self.column_definitions[i].name.to_uppercase()).collect());
+        }
+
+        for row in &self.rows {
+            lines.push(
+                columns.iter()
+                .take_while(|&&i| i < row.cells.len())
+                .map(|&i| {
+                    let mut cell = std::string::String::new();
+                    write_cell(&mut cell, &row.cells[i]);
+                    cell
+                })
+                .collect());
+        }
+
+        align(&lines)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Table<T> where T: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<T>(core::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for Visitor<T> where T: serde::Deserialize<'de> {
+            type Value = Table<T>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Table::<T>::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_column_definitions = None;
+                let mut value_metadata = None;
+                let mut value_rows = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "apiVersion" => {
+                            let value_api_version: std::string::String = serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != Table::<T>::API_VERSION {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value_api_version), &Table::<T>::API_VERSION));
+                            }
+                        },
+                        "kind" => {
+                            let value_kind: std::string::String = serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != Table::<T>::KIND {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value_kind), &Table::<T>::KIND));
+                            }
+                        },
+                        "columnDefinitions" => value_column_definitions = serde::de::MapAccess::next_value(&mut map)?,
+                        "metadata" => value_metadata = serde::de::MapAccess::next_value(&mut map)?,
+                        "rows" => value_rows = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(Table {
+                    column_definitions: value_column_definitions.unwrap_or_default(),
+                    metadata: value_metadata.unwrap_or_default(),
+                    rows: value_rows.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            Table::<T>::KIND,
+            &[
+                "apiVersion",
+                "kind",
+                "columnDefinitions",
+                "metadata",
+                "rows",
+            ],
+            Visitor(Default::default()),
+        )
+    }
+}
+
+impl<T> serde::Serialize for Table<T> where T: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct(Table::<T>::KIND, 5)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", Table::<T>::API_VERSION)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "kind", Table::<T>::KIND)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "columnDefinitions", &self.column_definitions)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "metadata", &self.metadata)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "rows", &self.rows)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableColumnDefinition {
+    /// A human readable description of the column.
+    pub description: std::string::String,
+
+    /// An optional modifier of the column's type, such as `name` or `password`.
+    pub format: std::string::String,
+
+    /// A human readable name for the column.
+    pub name: std::string::String,
+
+    /// How important the column is. Columns with a priority of 0 are shown by default, and columns with a higher priority are only shown
+    /// by `kubectl get -o wide` or with [`RenderOptions::wide`].
+    pub priority: i32,
+
+    /// The OpenAPI type of the column's cells, such as `string`, `integer`, `number`, `boolean` or `date`.
+    pub type_: std::string::String,
+}
+
+impl<'de> serde::Deserialize<'de> for TableColumnDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TableColumnDefinition;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("TableColumnDefinition")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_description = None;
+                let mut value_format = None;
+                let mut value_name = None;
+                let mut value_priority = None;
+                let mut value_type = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "description" => value_description = serde::de::MapAccess::next_value(&mut map)?,
+                        "format" => value_format = serde::de::MapAccess::next_value(&mut map)?,
+                        "name" => value_name = serde::de::MapAccess::next_value(&mut map)?,
+                        "priority" => value_priority = serde::de::MapAccess::next_value(&mut map)?,
+                        "type" => value_type = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(TableColumnDefinition {
+                    description: value_description.unwrap_or_default(),
+                    format: value_format.unwrap_or_default(),
+                    name: value_name.unwrap_or_default(),
+                    priority: value_priority.unwrap_or_default(),
+                    type_: value_type.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TableColumnDefinition",
+            &[
+                "description",
+                "format",
+                "name",
+                "priority",
+                "type",
+            ],
+            Visitor,
+        )
+    }
+}
+
+impl serde::Serialize for TableColumnDefinition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct("TableColumnDefinition", 5)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "description", &self.description)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "format", &self.format)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "name", &self.name)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "priority", &self.priority)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A row of a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableRow<T = serde_json::Value> {
+    /// The cells of the row, one for each of [`Table::column_definitions`]. Each cell is a string, number, boolean or null,
+    /// or occasionally an array or object.
+    pub cells: std::vec::Vec<serde_json::Value>,
+
+    /// Conditions that describe the state of the row, such as `Completed`.
+    pub conditions: Option<std::vec::Vec<TableRowCondition>>,
+
+    /// The object that the row was created from, if the table was requested with `includeObject=Metadata` or `includeObject=Object`.
+    pub object: Option<T>,
+}
+
+impl<'de, T> serde::Deserialize<'de> for TableRow<T> where T: serde::Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor<T>(core::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for Visitor<T> where T: serde::Deserialize<'de> {
+            type Value = TableRow<T>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("TableRow")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_cells = None;
+                let mut value_conditions = None;
+                let mut value_object = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "cells" => value_cells = serde::de::MapAccess::next_value(&mut map)?,
+                        "conditions" => value_conditions = serde::de::MapAccess::next_value(&mut map)?,
+                        "object" => value_object = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(TableRow {
+                    cells: value_cells.unwrap_or_default(),
+                    conditions: value_conditions,
+                    object: value_object,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TableRow",
+            &[
+                "cells",
+                "conditions",
+                "object",
+            ],
+            Visitor(Default::default()),
+        )
+    }
+}
+
+impl<T> serde::Serialize for TableRow<T> where T: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TableRow",
+            1 +
+            self.conditions.as_ref().map_or(0, |_| 1) +
+            self.object.as_ref().map_or(0, |_| 1),
+        )?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "cells", &self.cells)?;
+        if let Some(value) = &self.conditions {
+            serde::ser::SerializeStruct::serialize_field(&mut state, "conditions", value)?;
+        }
+        if let Some(value) = &self.object {
+            serde::ser::SerializeStruct::serialize_field(&mut state, "object", value)?;
+        }
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// A condition of a [`TableRow`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableRowCondition {
+    /// A human readable message with details about the condition.
+    pub message: Option<std::string::String>,
+
+    /// A machine readable reason for the condition.
+    pub reason: Option<std::string::String>,
+
+    /// The status of the condition, one of `True`, `False` or `Unknown`.
+    pub status: std::string::String,
+
+    /// The type of the condition. The only type currently defined is `Completed`, which indicates that the row's object has run to completion.
+    pub type_: std::string::String,
+}
+
+impl<'de> serde::Deserialize<'de> for TableRowCondition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TableRowCondition;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("TableRowCondition")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_message = None;
+                let mut value_reason = None;
+                let mut value_status = None;
+                let mut value_type = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "message" => value_message = serde::de::MapAccess::next_value(&mut map)?,
+                        "reason" => value_reason = serde::de::MapAccess::next_value(&mut map)?,
+                        "status" => value_status = serde::de::MapAccess::next_value(&mut map)?,
+                        "type" => value_type = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(TableRowCondition {
+                    message: value_message,
+                    reason: value_reason,
+                    status: value_status.unwrap_or_default(),
+                    type_: value_type.unwrap_or_default(),
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "TableRowCondition",
+            &[
+                "message",
+                "reason",
+                "status",
+                "type",
+            ],
+            Visitor,
+        )
+    }
+}
+
+impl serde::Serialize for TableRowCondition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            "TableRowCondition",
+            2 +
+            self.message.as_ref().map_or(0, |_| 1) +
+            self.reason.as_ref().map_or(0, |_| 1),
+        )?;
+        if let Some(value) = &self.message {
+            serde::ser::SerializeStruct::serialize_field(&mut state, "message", value)?;
+        }
+        if let Some(value) = &self.reason {
+            serde::ser::SerializeStruct::serialize_field(&mut state, "reason", value)?;
+        }
+        serde::ser::SerializeStruct::serialize_field(&mut state, "status", &self.status)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "type", &self.type_)?;
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// The options of a request for a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableOptions {
+    /// Which object each row of the table contains. The API server defaults to [`IncludeObjectPolicy::Metadata`].
+    ///
+    /// This is sent as the `includeObject` query parameter.
+    pub include_object: Option<IncludeObjectPolicy>,
+}
+
+impl TableOptions {
+    /// The `apiVersion` of a `TableOptions`.
+    pub const API_VERSION: &'static str = "meta.k8s.io/v1";
+
+    /// The `kind` of a `TableOptions`.
+    pub const KIND: &'static str = "TableOptions";
+}
+
+impl<'de> serde::Deserialize<'de> for TableOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = TableOptions;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(TableOptions::KIND)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+                let mut value_include_object = None;
+
+                while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+                    match &*key {
+                        "apiVersion" => {
+                            let value_api_version: std::string::String = serde::de::MapAccess::next_value(&mut map)?;
+                            if value_api_version != TableOptions::API_VERSION {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value_api_version), &TableOptions::API_VERSION));
+                            }
+                        },
+                        "kind" => {
+                            let value_kind: std::string::String = serde::de::MapAccess::next_value(&mut map)?;
+                            if value_kind != TableOptions::KIND {
+                                return Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value_kind), &TableOptions::KIND));
+                            }
+                        },
+                        "includeObject" => value_include_object = serde::de::MapAccess::next_value(&mut map)?,
+                        _ => { let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?; },
+                    }
+                }
+
+                Ok(TableOptions {
+                    include_object: value_include_object,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            TableOptions::KIND,
+            &[
+                "apiVersion",
+                "kind",
+                "includeObject",
+            ],
+            Visitor,
+        )
+    }
+}
+
+impl serde::Serialize for TableOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut state = serializer.serialize_struct(
+            TableOptions::KIND,
+            2 +
+            self.include_object.as_ref().map_or(0, |_| 1),
+        )?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "apiVersion", TableOptions::API_VERSION)?;
+        serde::ser::SerializeStruct::serialize_field(&mut state, "kind", TableOptions::KIND)?;
+        if let Some(value) = &self.include_object {
+            serde::ser::SerializeStruct::serialize_field(&mut state, "includeObject", value)?;
+        }
+        serde::ser::SerializeStruct::end(state)
+    }
+}
+
+/// Which object each row of a [`Table`] contains.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IncludeObjectPolicy {
+    /// Rows do not contain an object.
+    None,
+
+    /// Rows contain the metadata of their object as a [`PartialObjectMetadata`](crate::PartialObjectMetadata).
+    Metadata,
+
+    /// Rows contain their full object.
+    Object,
+}
+
+impl IncludeObjectPolicy {
+    /// The value of the `includeObject` query parameter for this policy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IncludeObjectPolicy::None => "None",
+            IncludeObjectPolicy::Metadata => "Metadata",
+            IncludeObjectPolicy::Object => "Object",
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IncludeObjectPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = IncludeObjectPolicy;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("IncludeObjectPolicy")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+                Ok(match v {
+                    "None" => IncludeObjectPolicy::None,
+                    "Metadata" => IncludeObjectPolicy::Metadata,
+                    "Object" => IncludeObjectPolicy::Object,
+                    v => return Err(serde::de::Error::unknown_variant(v, &["None", "Metadata", "Object"])),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl serde::Serialize for IncludeObjectPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Options for [`Table::render`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RenderOptions {
+    /// Don't render the header line of column names, like `kubectl get --no-headers`.
+    pub no_headers: bool,
+
+    /// Render columns with a non-zero priority, like `kubectl get -o wide`.
+    pub wide: bool,
+}
+
+/// Writes a cell the way that kubectl's `printTable` does.
+///
+/// Strings are cut off at their first line break and terminal escape characters are escaped.
+/// Other values are written the way golang's `fmt.Sprint` formats them.
+fn write_cell(s: &mut std::string::String, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Null => (),
+
+        serde_json::Value::String(value) => match value.find(['\x0c', '\n', '\r']) {
+            Some(line_break) => {
+                write_escaped(s, &value[..line_break]);
+                s.push_str("...");
+            },
+            None => write_escaped(s, value),
+        },
+
+        value => {
+            let mut value_s = std::string::String::new();
+            write_go_value(&mut value_s, value);
+            write_escaped(s, &value_s);
+        },
+    }
+}
+
+fn write_escaped(s: &mut std::string::String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '\x1b' => s.push_str("^["),
+            '\r' => s.push_str("\\r"),
+            c => s.push(c),
+        }
+    }
+}
+
+/// Writes a value the way golang's `fmt.Sprint` formats the corresponding `interface{}` value decoded by the API machinery,
+/// where integers are `int64` and other numbers are `float64`.
+fn write_go_value(s: &mut std::string::String, value: &serde_json::Value) {
+    use core::fmt::Write;
+
+    match value {
+        serde_json::Value::Null => s.push_str("<nil>"),
+
+        serde_json::Value::Bool(value) => { let _ = write!(s, "{value}"); },
+
+        serde_json::Value::Number(value) =>
+            if let Some(value) = value.as_i64() {
+                let _ = write!(s, "{value}");
+            }
+            else if let Some(value) = value.as_u64() {
+                let _ = write!(s, "{value}");
+            }
+            else if let Some(value) = value.as_f64() {
+                write_go_float(s, value);
+            },
+
+        serde_json::Value::String(value) => s.push_str(value),
+
+        serde_json::Value::Array(values) => {
+            s.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    s.push(' ');
+                }
+                write_go_value(s, value);
+            }
+            s.push(']');
+        },
+
+        serde_json::Value::Object(values) => {
+            // golang sorts map keys when formatting maps.
+            let mut values: std::vec::Vec<_> = values.iter().collect();
+            values.sort_unstable_by_key(|(key, _)| &**key);
+
+            s.push_str("map[");
+            for (i, (key, value)) in values.into_iter().enumerate() {
+                if i > 0 {
+                    s.push(' ');
+                }
+                s.push_str(key);
+                s.push(':');
+                write_go_value(s, value);
+            }
+            s.push(']');
+        },
+    }
+}
+
+/// Writes a float the way golang's `%v` verb does, ie the shortest representation that round-trips,
+/// using an exponent if the exponent is less than -4 or at least 6.
+fn write_go_float(s: &mut std::string::String, value: f64) {
+    use core::fmt::Write;
+
+    let abs = value.abs();
+    if abs != 0. && !(1e-4..1e6).contains(&abs) {
+        let formatted = std::format!("{value:e}");
+        let (mantissa, exponent) = formatted.split_once('e').expect("exponent format always contains e");
+        let (sign, exponent) = exponent.strip_prefix('-').map_or(('+', exponent), |exponent| ('-', exponent));
+        let _ = write!(s, "{mantissa}e{sign}{exponent:0>2}");
+    }
+    else {
+        let _ = write!(s, "{value}");
+    }
+}
+
+/// Aligns the given lines of cells the way golang's `text/tabwriter` does with the parameters that kubectl uses,
+/// ie a minimum cell width of 6, a padding of 3 and a padding character of a space.
+///
+/// The last cell of each line is not padded, since kubectl does not terminate it with a tab.
+fn align(lines: &[std::vec::Vec<std::string::String>]) -> std::string::String {
+    const MIN_WIDTH: usize = 6;
+    const PADDING: usize = 3;
+
+    let mut widths: std::vec::Vec<usize> = std::vec![];
+    for line in lines {
+        for (i, cell) in line.iter().enumerate().take(line.len().saturating_sub(1)) {
+            let width = (cell.chars().count() + PADDING).max(MIN_WIDTH);
+            match widths.get_mut(i) {
+                Some(existing) => *existing = (*existing).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+
+    let mut result = std::string::String::new();
+    for line in lines {
+        for (i, cell) in line.iter().enumerate() {
+            result.push_str(cell);
+            if i + 1 < line.len() {
+                for _ in cell.chars().count()..widths[i] {
+                    result.push(' ');
+                }
+            }
+        }
+        result.push('\n');
+    }
+    result
+}
@@ -0,0 +1,64 @@
+//! Conversions between `Time` / `MicroTime` and the timestamp types of the `chrono` and `time` crates.
+//!
+//! The range of `jiff::Timestamp` (years -9999 to 9999) is a subset of the ranges of `chrono::DateTime<chrono::Utc>` and `time::OffsetDateTime`,
+//! so converting to those types is infallible, while converting from them fails for values outside that range.
+
+/// Splits the given timestamp into its seconds since the Unix epoch and its non-negative subsecond nanoseconds,
+/// since `jiff::Timestamp` represents a timestamp before the epoch with negative subsecond nanoseconds.
+#[cfg(feature = "chrono")]
+fn to_second_and_nanosecond(timestamp: jiff::Timestamp) -> (i64, u32) {
+    let second = timestamp.as_second();
+    let nanosecond = timestamp.subsec_nanosecond();
+    if nanosecond < 0 {
+        (second - 1, (nanosecond + 1_000_000_000).cast_unsigned())
+    }
+    else {
+        (second, nanosecond.cast_unsigned())
+    }
+}
+
+macro_rules! impl_conversions {
+    ($($ty:ident)*) => {
+        $(
+            #[cfg(feature = "chrono")]
+            impl From<crate::apimachinery::pkg::apis::meta::v1::$ty> for chrono::DateTime<chrono::Utc> {
+                fn from(value: crate::apimachinery::pkg::apis::meta::v1::$ty) -> Self {
+                    let (second, nanosecond) = to_second_and_nanosecond(value.0);
+                    chrono::DateTime::from_timestamp(second, nanosecond).expect("jiff::Timestamp is always in the range of chrono::DateTime")
+                }
+            }
+
+            #[cfg(feature = "chrono")]
+            impl TryFrom<chrono::DateTime<chrono::Utc>> for crate::apimachinery::pkg::apis::meta::v1::$ty {
+                type Error = jiff::Error;
+
+                /// Fails if the value is outside the range of `jiff::Timestamp`, or is a leap second.
+                fn try_from(value: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+                    let nanosecond = i32::try_from(value.timestamp_subsec_nanos()).expect("subsecond nanoseconds always fit in an i32");
+                    Ok(Self(jiff::Timestamp::new(value.timestamp(), nanosecond)?))
+                }
+            }
+
+            #[cfg(feature = "time")]
+            impl From<crate::apimachinery::pkg::apis::meta::v1::$ty> for time::OffsetDateTime {
+                fn from(value: crate::apimachinery::pkg::apis::meta::v1::$ty) -> Self {
+                    time::OffsetDateTime::from_unix_timestamp_nanos(value.0.as_nanosecond())
+                        .expect("jiff::Timestamp is always in the range of time::OffsetDateTime")
+                }
+            }
+
+            #[cfg(feature = "time")]
+            impl TryFrom<time::OffsetDateTime> for crate::apimachinery::pkg::apis::meta::v1::$ty {
+                type Error = jiff::Error;
+
+                /// Fails if the value is outside the range of `jiff::Timestamp`. The offset of the value is ignored, ie the result is the same instant in time.
+                fn try_from(value: time::OffsetDateTime) -> Result<Self, Self::Error> {
+                    let nanosecond = i32::try_from(value.nanosecond()).expect("subsecond nanoseconds always fit in an i32");
+                    Ok(Self(jiff::Timestamp::new(value.unix_timestamp(), nanosecond)?))
+                }
+            }
+        )*
+    };
+}
+
+impl_conversions! { MicroTime Time }
@@ -0,0 +1,49 @@
+/// An error from getting the member of a union type that is set, like with `Volume::union`.
+///
+/// Union types are types where at most one of a set of optional fields, the members of the union, may be set,
+/// like the `VolumeSource` fields of `Volume` or the handler fields of `Probe`. Some unions also have a discriminator field
+/// whose value names the member that is set, like the `type` field of `MetricSpec`. The API server rejects objects
+/// that violate these constraints.
+///
+/// Every union type has a corresponding enum of its members, like `VolumeUnion` for `Volume`, which can be set with the type's `set_union()` method
+/// or converted into the type with `From`. The type's `union()` method returns the member that is set as a `VolumeUnionRef`,
+/// which borrows the member from the value and can be converted into a `VolumeUnion` with `From`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnionError {
+    /// More than one member of the union is set. Contains the names of the members that are set, as they appear in the JSON representation.
+    MultipleMembersSet(std::vec::Vec<&'static str>),
+
+    /// The discriminator of the union names a different member than the one that is set.
+    DiscriminatorMismatch {
+        /// The value of the discriminator.
+        discriminator: std::string::String,
+
+        /// The name of the member that is set, as it appears in the JSON representation, if any.
+        member: Option<&'static str>,
+    },
+}
+
+impl core::fmt::Display for UnionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UnionError::MultipleMembersSet(members) => {
+                f.write_str("may not specify more than one of ")?;
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    f.write_str(member)?;
+                }
+                Ok(())
+            },
+
+            UnionError::DiscriminatorMismatch { discriminator, member: Some(member) } =>
+                write!(f, "discriminator {discriminator:?} does not match the member {member} that is set"),
+
+            UnionError::DiscriminatorMismatch { discriminator, member: None } =>
+                write!(f, "discriminator {discriminator:?} names a member that is not set"),
+        }
+    }
+}
+
+impl core::error::Error for UnionError {}
@@ -0,0 +1,234 @@
+//! Preservation of the fields that the types in this crate do not know about.
+//!
+//! The `Deserialize` impls of the types in this crate ignore the fields that the selected version of Kubernetes does not know about.
+//! So a client that reads an object from a newer API server and writes it back would drop the newer fields when it updates the object.
+//!
+//! Deserializing the object as a [`Preserved`] keeps these fields alongside the object, and serializing it emits them again
+//! at the same paths:
+//!
+//! ```rust,ignore
+//! let mut pod: Preserved<Pod> = serde_json::from_slice(json)?;
+//! pod.value.metadata.labels.get_or_insert_default().insert("app".to_owned(), "foo".to_owned());
+//! let json = serde_json::to_vec(&pod)?;
+//! ```
+//!
+//! The types themselves do not change, so code that constructs them with struct literals compiles the same way regardless of this feature.
+
+/// A value along with the fields of its serialized form that its type does not know about.
+///
+/// Unknown fields are found the same way as [`field_validation`](crate::field_validation) finds them, so they can be anywhere inside the value,
+/// such as `spec.containers[name=app].futureField`. When the value is serialized, each unknown field is inserted again at its path,
+/// unless the path no longer leads to an object.
+///
+/// Items of lists with `x-kubernetes-list-type: map`, like `spec.containers`, are identified in the paths by their merge keys,
+/// as given by the [`ListMapKeys`] impl of the type. So their unknown fields stay with the same item even if the list is reordered,
+/// or items are inserted into or removed from it. Items of other lists are identified by their index.
+///
+/// Serializing and deserializing the value goes through a [`serde_json::Value`], so the value is serialized the same way as it would be to JSON.
+///
+/// [`DeepMerge`](crate::DeepMerge)ing a `Preserved` into another merges the values, and merges the unknown fields at the same path
+/// with the JSON merge algorithm (RFC 7396). The unknown fields of the items of lists that are identified by their index are dropped
+/// if the other value has the list, since merging it replaces the items.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Preserved<T> {
+    /// The value.
+    pub value: T,
+
+    /// The fields of the serialized form of the value that its type does not know about.
+    pub unknown_fields: std::vec::Vec<UnknownField>,
+}
+
+impl<T> Preserved<T> {
+    /// Wraps the given value without any unknown fields.
+    pub fn new(value: T) -> Self {
+        Preserved {
+            value,
+            unknown_fields: std::vec![],
+        }
+    }
+}
+
+impl<T> From<T> for Preserved<T> {
+    fn from(value: T) -> Self {
+        Preserved::new(value)
+    }
+}
+
+impl<T> crate::DeepMerge for Preserved<T> where T: crate::DeepMerge + serde::Serialize {
+    fn merge_from(&mut self, other: Self) {
+        let other_value = serde_json::to_value(&other.value).unwrap_or_default();
+        self.unknown_fields.retain(|UnknownField { path, value: _ }| {
+            let Some(list_path_len) = path.iter().position(|segment| matches!(segment, PathSegment::Index(_))) else { return true; };
+            path[..list_path_len].iter().try_fold(&other_value, get).is_none()
+        });
+
+        crate::DeepMerge::merge_from(&mut self.value, other.value);
+
+        for other_field in other.unknown_fields {
+            if let Some(field) = self.unknown_fields.iter_mut().find(|field| field.path == other_field.path) {
+                crate::DeepMerge::merge_from(&mut field.value, other_field.value);
+            }
+            else {
+                self.unknown_fields.push(other_field);
+            }
+        }
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Preserved<T> where T: serde::Deserialize<'de> + serde::Serialize + ListMapKeys {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        let (value, mut unknown_fields): (T, std::vec::Vec<UnknownField>) = crate::field_validation::deserialize_preserving_unknown_fields(deserializer)?;
+
+        // Replace the indices of the items of list-maps with their merge keys, which are read from the value that was deserialized.
+        if unknown_fields.iter().any(|UnknownField { path, value: _ }| path.iter().any(|segment| matches!(segment, PathSegment::Index(_)))) {
+            let json = serde_json::to_value(&value).map_err(serde::de::Error::custom)?;
+
+            for UnknownField { path, value: _ } in &mut unknown_fields {
+                let mut parent = &json;
+                for i in 0..path.len() {
+                    let Some(value) = get(parent, &path[i]) else { break; };
+
+                    if let (PathSegment::Index(_), Some(keys)) = (&path[i], T::list_map_keys(&path[..i])) {
+                        let merge_key: std::vec::Vec<_> =
+                            keys.iter()
+                            .filter_map(|&key| Some((key.into(), merge_key_value(value, key)?)))
+                            .collect();
+                        if !merge_key.is_empty() {
+                            path[i] = PathSegment::MergeKey(merge_key);
+                        }
+                    }
+
+                    parent = value;
+                }
+            }
+        }
+
+        Ok(Preserved {
+            value,
+            unknown_fields,
+        })
+    }
+}
+
+impl<T> serde::Serialize for Preserved<T> where T: serde::Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        let mut value = serde_json::to_value(&self.value).map_err(serde::ser::Error::custom)?;
+
+        for UnknownField { path, value: field_value } in &self.unknown_fields {
+            let Some((PathSegment::Key(key), parent_path)) = path.split_last() else { continue; };
+
+            let parent = parent_path.iter().try_fold(&mut value, get_mut);
+
+            if let Some(serde_json::Value::Object(parent)) = parent {
+                parent.insert(key.clone(), field_value.clone());
+            }
+        }
+
+        serde::Serialize::serialize(&value, serializer)
+    }
+}
+
+/// Returns the value at the given segment of a path within `value`.
+fn get<'a>(value: &'a serde_json::Value, segment: &PathSegment) -> Option<&'a serde_json::Value> {
+    match segment {
+        PathSegment::Key(key) => value.get(&**key),
+        PathSegment::Index(index) => value.get(*index),
+        PathSegment::MergeKey(merge_key) => value.as_array()?.iter().find(|item| is_merge_key_of(merge_key, item)),
+    }
+}
+
+/// Returns the value at the given segment of a path within `value`.
+fn get_mut<'a>(value: &'a mut serde_json::Value, segment: &PathSegment) -> Option<&'a mut serde_json::Value> {
+    match segment {
+        PathSegment::Key(key) => value.get_mut(&**key),
+        PathSegment::Index(index) => value.get_mut(*index),
+        PathSegment::MergeKey(merge_key) => value.as_array_mut()?.iter_mut().find(|item| is_merge_key_of(merge_key, item)),
+    }
+}
+
+fn is_merge_key_of(merge_key: &[(std::string::String, std::string::String)], item: &serde_json::Value) -> bool {
+    merge_key.iter().all(|(key, value)| merge_key_value(item, key).as_ref() == Some(value))
+}
+
+/// Returns the value of the merge key of an item of a list-map, like `name` or `metadata.name`, as it appears in a [`PathSegment::MergeKey`].
+fn merge_key_value(item: &serde_json::Value, key: &str) -> Option<std::string::String> {
+    match key.split('.').try_fold(item, |value, key| value.get(key))? {
+        serde_json::Value::String(value) => Some(value.clone()),
+        value @ (serde_json::Value::Bool(_) | serde_json::Value::Number(_)) => Some(std::string::ToString::to_string(value)),
+        _ => None,
+    }
+}
+
+/// A field that the type of the value it was found in does not know about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownField {
+    /// The path of the field, like `["spec", "containers", [("name", "app")], "futureField"]`. The last segment is always a [`PathSegment::Key`].
+    pub path: std::vec::Vec<PathSegment>,
+
+    /// The value of the field.
+    pub value: serde_json::Value,
+}
+
+/// A segment of the path of an [`UnknownField`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// The value of a field of an object.
+    Key(std::string::String),
+
+    /// An item of a list, identified by its index.
+    Index(usize),
+
+    /// An item of a list with `x-kubernetes-list-type: map`, identified by the names and values of its merge keys, like `[("name", "app")]`.
+    /// Merge keys that are not set in the item are omitted.
+    MergeKey(std::vec::Vec<(std::string::String, std::string::String)>),
+}
+
+impl From<&str> for PathSegment {
+    fn from(key: &str) -> Self {
+        PathSegment::Key(key.into())
+    }
+}
+
+/// A type whose JSON representation can contain lists with `x-kubernetes-list-type: map`, whose items are identified by their merge keys
+/// in the paths of [`UnknownField`]s.
+///
+/// All the types in this crate implement this trait. Other types like custom resources can implement it with the default method,
+/// in which case the items of their lists are identified by their index.
+pub trait ListMapKeys {
+    /// Returns the names of the merge keys of the list at the given path within a value of this type, like `["name"]`,
+    /// or `None` if the path does not lead to a list with `x-kubernetes-list-type: map`. Merge keys of nested fields are joined by `.`,
+    /// like `metadata.name`.
+    fn list_map_keys(_path: &[PathSegment]) -> Option<&'static [&'static str]> {
+        None
+    }
+}
+
+impl<T> ListMapKeys for Option<T> where T: ListMapKeys {
+    fn list_map_keys(path: &[PathSegment]) -> Option<&'static [&'static str]> {
+        T::list_map_keys(path)
+    }
+}
+
+impl<T> ListMapKeys for std::boxed::Box<T> where T: ListMapKeys {
+    fn list_map_keys(path: &[PathSegment]) -> Option<&'static [&'static str]> {
+        T::list_map_keys(path)
+    }
+}
+
+impl<T> ListMapKeys for std::vec::Vec<T> where T: ListMapKeys {
+    fn list_map_keys(path: &[PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [PathSegment::Index(_) | PathSegment::MergeKey(_), path @ ..] => T::list_map_keys(path),
+            _ => None,
+        }
+    }
+}
+
+impl<T> ListMapKeys for std::collections::BTreeMap<std::string::String, T> where T: ListMapKeys {
+    fn list_map_keys(path: &[PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [PathSegment::Key(_), path @ ..] => T::list_map_keys(path),
+            _ => None,
+        }
+    }
+}
@@ -25,6 +25,65 @@ impl crate::DeepMerge for ServiceReference {
     }
 }
 
+impl crate::Defaulting for ServiceReference {
+    fn set_defaults(&mut self) {
+        if self.port.is_none() {
+            self.port = Some(443);
+        }
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.port);
+    }
+}
+
+impl crate::diff::Diff for ServiceReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+    }
+}
+
+impl ServiceReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServiceReference> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<i32> {
+        self.child("port")
+    }
+}
+
+impl crate::Normalize for ServiceReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.port);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +188,48 @@ impl crate::serde::Serialize for ServiceReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServiceReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.namespace, 1, buf);
+        crate::protobuf::Field::encode_field(&self.name, 2, buf);
+        if let Some(value) = &self.path {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.port {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_port: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServiceReference {
+            name: value_name.unwrap_or_default(),
+            namespace: value_namespace.unwrap_or_default(),
+            path: value_path,
+            port: value_port,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServiceReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -49,6 +49,61 @@ impl crate::DeepMerge for ValidatingWebhookConfiguration {
     }
 }
 
+impl crate::Defaulting for ValidatingWebhookConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.webhooks);
+    }
+}
+
+impl crate::diff::Diff for ValidatingWebhookConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::list::map(
+            &self.webhooks,
+            &other.webhooks,
+            &crate::diff::Path::Field { parent: path, name: "webhooks" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ValidatingWebhookConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingWebhookConfiguration> {
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `webhooks` field.
+    pub fn webhooks(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::ValidatingWebhook>> {
+        self.child("webhooks")
+    }
+}
+
+impl crate::Normalize for ValidatingWebhookConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::list::map(&mut self.webhooks, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingWebhookConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -156,6 +211,53 @@ impl crate::serde::Serialize for ValidatingWebhookConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingWebhookConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.webhooks {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_webhooks: Option<std::vec::Vec<crate::api::admissionregistration::v1::ValidatingWebhook>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_webhooks, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingWebhookConfiguration {
+            metadata: value_metadata.unwrap_or_default(),
+            webhooks: value_webhooks,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingWebhookConfiguration {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "webhooks" => Some(&["name"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "webhooks" => <Option<std::vec::Vec<crate::api::admissionregistration::v1::ValidatingWebhook>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingWebhookConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
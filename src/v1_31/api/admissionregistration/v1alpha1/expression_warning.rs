@@ -17,6 +17,46 @@ impl crate::DeepMerge for ExpressionWarning {
     }
 }
 
+impl crate::Defaulting for ExpressionWarning {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.field_ref);
+        crate::Defaulting::set_defaults(&mut self.warning);
+    }
+}
+
+impl crate::diff::Diff for ExpressionWarning {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.field_ref, &other.field_ref, &crate::diff::Path::Field { parent: path, name: "fieldRef" }, changes);
+        crate::diff::Diff::diff(&self.warning, &other.warning, &crate::diff::Path::Field { parent: path, name: "warning" }, changes);
+    }
+}
+
+impl ExpressionWarning {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ExpressionWarning> {
+    /// The path of the `fieldRef` field.
+    pub fn field_ref(self) -> crate::FieldPath<std::string::String> {
+        self.child("fieldRef")
+    }
+
+    /// The path of the `warning` field.
+    pub fn warning(self) -> crate::FieldPath<std::string::String> {
+        self.child("warning")
+    }
+}
+
+impl crate::Normalize for ExpressionWarning {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.field_ref);
+        crate::Normalize::normalize(&mut self.warning);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ExpressionWarning {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for ExpressionWarning {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ExpressionWarning {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.field_ref, 2, buf);
+        crate::protobuf::Field::encode_field(&self.warning, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_field_ref: Option<std::string::String> = None;
+        let mut value_warning: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_field_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_warning, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ExpressionWarning {
+            field_ref: value_field_ref.unwrap_or_default(),
+            warning: value_warning.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ExpressionWarning {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExpressionWarning {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -43,6 +43,64 @@ impl crate::DeepMerge for ValidatingAdmissionPolicyBindingSpec {
     }
 }
 
+impl crate::Defaulting for ValidatingAdmissionPolicyBindingSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.match_resources);
+        crate::Defaulting::set_defaults(&mut self.param_ref);
+        crate::Defaulting::set_defaults(&mut self.policy_name);
+        crate::Defaulting::set_defaults(&mut self.validation_actions);
+    }
+}
+
+impl crate::diff::Diff for ValidatingAdmissionPolicyBindingSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.match_resources, &other.match_resources, &crate::diff::Path::Field { parent: path, name: "matchResources" }, changes);
+        crate::diff::Diff::diff(&self.param_ref, &other.param_ref, &crate::diff::Path::Field { parent: path, name: "paramRef" }, changes);
+        crate::diff::Diff::diff(&self.policy_name, &other.policy_name, &crate::diff::Path::Field { parent: path, name: "policyName" }, changes);
+        crate::diff::strategies::list::set(&self.validation_actions, &other.validation_actions, &crate::diff::Path::Field { parent: path, name: "validationActions" }, changes);
+    }
+}
+
+impl ValidatingAdmissionPolicyBindingSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingAdmissionPolicyBindingSpec> {
+    /// The path of the `matchResources` field.
+    pub fn match_resources(self) -> crate::FieldPath<crate::api::admissionregistration::v1alpha1::MatchResources> {
+        self.child("matchResources")
+    }
+
+    /// The path of the `paramRef` field.
+    pub fn param_ref(self) -> crate::FieldPath<crate::api::admissionregistration::v1alpha1::ParamRef> {
+        self.child("paramRef")
+    }
+
+    /// The path of the `policyName` field.
+    pub fn policy_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("policyName")
+    }
+
+    /// The path of the `validationActions` field.
+    pub fn validation_actions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("validationActions")
+    }
+}
+
+impl crate::Normalize for ValidatingAdmissionPolicyBindingSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.match_resources);
+        crate::Normalize::normalize(&mut self.param_ref);
+        crate::Normalize::normalize(&mut self.policy_name);
+        crate::normalize_strategies::list::set(&mut self.validation_actions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingAdmissionPolicyBindingSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -152,6 +210,52 @@ impl crate::serde::Serialize for ValidatingAdmissionPolicyBindingSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingAdmissionPolicyBindingSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.policy_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.param_ref {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.match_resources {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.validation_actions {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_match_resources: Option<crate::api::admissionregistration::v1alpha1::MatchResources> = None;
+        let mut value_param_ref: Option<crate::api::admissionregistration::v1alpha1::ParamRef> = None;
+        let mut value_policy_name: Option<std::string::String> = None;
+        let mut value_validation_actions: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_policy_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_param_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_match_resources, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_validation_actions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingAdmissionPolicyBindingSpec {
+            match_resources: value_match_resources,
+            param_ref: value_param_ref,
+            policy_name: value_policy_name,
+            validation_actions: value_validation_actions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingAdmissionPolicyBindingSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingAdmissionPolicyBindingSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
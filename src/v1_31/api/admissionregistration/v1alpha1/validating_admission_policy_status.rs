@@ -28,6 +28,65 @@ impl crate::DeepMerge for ValidatingAdmissionPolicyStatus {
     }
 }
 
+impl crate::Defaulting for ValidatingAdmissionPolicyStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+        crate::Defaulting::set_defaults(&mut self.type_checking);
+    }
+}
+
+impl crate::diff::Diff for ValidatingAdmissionPolicyStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+        crate::diff::Diff::diff(&self.type_checking, &other.type_checking, &crate::diff::Path::Field { parent: path, name: "typeChecking" }, changes);
+    }
+}
+
+impl ValidatingAdmissionPolicyStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingAdmissionPolicyStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+
+    /// The path of the `typeChecking` field.
+    pub fn type_checking(self) -> crate::FieldPath<crate::api::admissionregistration::v1alpha1::TypeChecking> {
+        self.child("typeChecking")
+    }
+}
+
+impl crate::Normalize for ValidatingAdmissionPolicyStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.observed_generation);
+        crate::Normalize::normalize(&mut self.type_checking);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingAdmissionPolicyStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -127,6 +186,56 @@ impl crate::serde::Serialize for ValidatingAdmissionPolicyStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingAdmissionPolicyStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.type_checking {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> = None;
+        let mut value_observed_generation: Option<i64> = None;
+        let mut value_type_checking: Option<crate::api::admissionregistration::v1alpha1::TypeChecking> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_type_checking, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingAdmissionPolicyStatus {
+            conditions: value_conditions,
+            observed_generation: value_observed_generation,
+            type_checking: value_type_checking,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingAdmissionPolicyStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingAdmissionPolicyStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
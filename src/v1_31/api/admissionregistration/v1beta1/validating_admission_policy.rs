@@ -46,6 +46,59 @@ impl crate::DeepMerge for ValidatingAdmissionPolicy {
     }
 }
 
+impl crate::Defaulting for ValidatingAdmissionPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.spec);
+        crate::Defaulting::set_defaults(&mut self.status);
+    }
+}
+
+impl crate::diff::Diff for ValidatingAdmissionPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.spec, &other.spec, &crate::diff::Path::Field { parent: path, name: "spec" }, changes);
+        crate::diff::Diff::diff(&self.status, &other.status, &crate::diff::Path::Field { parent: path, name: "status" }, changes);
+    }
+}
+
+impl ValidatingAdmissionPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingAdmissionPolicy> {
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `spec` field.
+    pub fn spec(self) -> crate::FieldPath<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec> {
+        self.child("spec")
+    }
+
+    /// The path of the `status` field.
+    pub fn status(self) -> crate::FieldPath<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus> {
+        self.child("status")
+    }
+}
+
+impl crate::Normalize for ValidatingAdmissionPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.spec);
+        crate::Normalize::normalize(&mut self.status);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+        self.status = None;
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingAdmissionPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -163,6 +216,56 @@ impl crate::serde::Serialize for ValidatingAdmissionPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingAdmissionPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.spec {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.status {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_spec: Option<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec> = None;
+        let mut value_status: Option<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_spec, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_status, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingAdmissionPolicy {
+            metadata: value_metadata.unwrap_or_default(),
+            spec: value_spec,
+            status: value_status,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingAdmissionPolicy {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "spec" => <Option<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "status" => <Option<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingAdmissionPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
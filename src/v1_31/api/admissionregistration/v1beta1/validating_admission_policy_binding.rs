@@ -46,6 +46,50 @@ impl crate::DeepMerge for ValidatingAdmissionPolicyBinding {
     }
 }
 
+impl crate::Defaulting for ValidatingAdmissionPolicyBinding {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.spec);
+    }
+}
+
+impl crate::diff::Diff for ValidatingAdmissionPolicyBinding {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.spec, &other.spec, &crate::diff::Path::Field { parent: path, name: "spec" }, changes);
+    }
+}
+
+impl ValidatingAdmissionPolicyBinding {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingAdmissionPolicyBinding> {
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `spec` field.
+    pub fn spec(self) -> crate::FieldPath<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec> {
+        self.child("spec")
+    }
+}
+
+impl crate::Normalize for ValidatingAdmissionPolicyBinding {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.spec);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingAdmissionPolicyBinding {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -153,6 +197,48 @@ impl crate::serde::Serialize for ValidatingAdmissionPolicyBinding {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingAdmissionPolicyBinding {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.spec {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_spec: Option<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_spec, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingAdmissionPolicyBinding {
+            metadata: value_metadata.unwrap_or_default(),
+            spec: value_spec,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingAdmissionPolicyBinding {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingAdmissionPolicyBinding {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,49 @@ impl crate::DeepMerge for RollingUpdateStatefulSetStrategy {
     }
 }
 
+impl crate::Defaulting for RollingUpdateStatefulSetStrategy {
+    fn set_defaults(&mut self) {
+        if self.partition.is_none() {
+            self.partition = Some(0);
+        }
+        crate::Defaulting::set_defaults(&mut self.max_unavailable);
+        crate::Defaulting::set_defaults(&mut self.partition);
+    }
+}
+
+impl crate::diff::Diff for RollingUpdateStatefulSetStrategy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max_unavailable, &other.max_unavailable, &crate::diff::Path::Field { parent: path, name: "maxUnavailable" }, changes);
+        crate::diff::Diff::diff(&self.partition, &other.partition, &crate::diff::Path::Field { parent: path, name: "partition" }, changes);
+    }
+}
+
+impl RollingUpdateStatefulSetStrategy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<RollingUpdateStatefulSetStrategy> {
+    /// The path of the `maxUnavailable` field.
+    pub fn max_unavailable(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("maxUnavailable")
+    }
+
+    /// The path of the `partition` field.
+    pub fn partition(self) -> crate::FieldPath<i32> {
+        self.child("partition")
+    }
+}
+
+impl crate::Normalize for RollingUpdateStatefulSetStrategy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max_unavailable);
+        crate::Normalize::normalize(&mut self.partition);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RollingUpdateStatefulSetStrategy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +149,40 @@ impl crate::serde::Serialize for RollingUpdateStatefulSetStrategy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for RollingUpdateStatefulSetStrategy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.partition {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.max_unavailable {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_partition: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_partition, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_max_unavailable, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(RollingUpdateStatefulSetStrategy {
+            max_unavailable: value_max_unavailable,
+            partition: value_partition,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for RollingUpdateStatefulSetStrategy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollingUpdateStatefulSetStrategy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for TokenRequestStatus {
     }
 }
 
+impl crate::Defaulting for TokenRequestStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.expiration_timestamp);
+        crate::Defaulting::set_defaults(&mut self.token);
+    }
+}
+
+impl crate::diff::Diff for TokenRequestStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.expiration_timestamp, &other.expiration_timestamp, &crate::diff::Path::Field { parent: path, name: "expirationTimestamp" }, changes);
+        crate::diff::Diff::diff(&self.token, &other.token, &crate::diff::Path::Field { parent: path, name: "token" }, changes);
+    }
+}
+
+impl TokenRequestStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TokenRequestStatus> {
+    /// The path of the `expirationTimestamp` field.
+    pub fn expiration_timestamp(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("expirationTimestamp")
+    }
+
+    /// The path of the `token` field.
+    pub fn token(self) -> crate::FieldPath<std::string::String> {
+        self.child("token")
+    }
+}
+
+impl crate::Normalize for TokenRequestStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.expiration_timestamp);
+        crate::Normalize::normalize(&mut self.token);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TokenRequestStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for TokenRequestStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TokenRequestStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.token, 1, buf);
+        crate::protobuf::Field::encode_field(&self.expiration_timestamp, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_expiration_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_token: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_token, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_expiration_timestamp, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TokenRequestStatus {
+            expiration_timestamp: value_expiration_timestamp.ok_or(crate::protobuf::DecodeError::MissingField("expirationTimestamp"))?,
+            token: value_token.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TokenRequestStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenRequestStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for SubjectAccessReviewStatus {
     }
 }
 
+impl crate::Defaulting for SubjectAccessReviewStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allowed);
+        crate::Defaulting::set_defaults(&mut self.denied);
+        crate::Defaulting::set_defaults(&mut self.evaluation_error);
+        crate::Defaulting::set_defaults(&mut self.reason);
+    }
+}
+
+impl crate::diff::Diff for SubjectAccessReviewStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allowed, &other.allowed, &crate::diff::Path::Field { parent: path, name: "allowed" }, changes);
+        crate::diff::Diff::diff(&self.denied, &other.denied, &crate::diff::Path::Field { parent: path, name: "denied" }, changes);
+        crate::diff::Diff::diff(&self.evaluation_error, &other.evaluation_error, &crate::diff::Path::Field { parent: path, name: "evaluationError" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+    }
+}
+
+impl SubjectAccessReviewStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SubjectAccessReviewStatus> {
+    /// The path of the `allowed` field.
+    pub fn allowed(self) -> crate::FieldPath<bool> {
+        self.child("allowed")
+    }
+
+    /// The path of the `denied` field.
+    pub fn denied(self) -> crate::FieldPath<bool> {
+        self.child("denied")
+    }
+
+    /// The path of the `evaluationError` field.
+    pub fn evaluation_error(self) -> crate::FieldPath<std::string::String> {
+        self.child("evaluationError")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+}
+
+impl crate::Normalize for SubjectAccessReviewStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allowed);
+        crate::Normalize::normalize(&mut self.denied);
+        crate::Normalize::normalize(&mut self.evaluation_error);
+        crate::Normalize::normalize(&mut self.reason);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for SubjectAccessReviewStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SubjectAccessReviewStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.allowed, 1, buf);
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.evaluation_error {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.denied {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allowed: Option<bool> = None;
+        let mut value_denied: Option<bool> = None;
+        let mut value_evaluation_error: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_allowed, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_evaluation_error, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_denied, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SubjectAccessReviewStatus {
+            allowed: value_allowed.unwrap_or_default(),
+            denied: value_denied,
+            evaluation_error: value_evaluation_error,
+            reason: value_reason,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SubjectAccessReviewStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectAccessReviewStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
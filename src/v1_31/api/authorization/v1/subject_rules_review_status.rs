@@ -25,6 +25,82 @@ impl crate::DeepMerge for SubjectRulesReviewStatus {
     }
 }
 
+impl crate::Defaulting for SubjectRulesReviewStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.evaluation_error);
+        crate::Defaulting::set_defaults(&mut self.incomplete);
+        crate::Defaulting::set_defaults(&mut self.non_resource_rules);
+        crate::Defaulting::set_defaults(&mut self.resource_rules);
+    }
+}
+
+impl crate::diff::Diff for SubjectRulesReviewStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.evaluation_error, &other.evaluation_error, &crate::diff::Path::Field { parent: path, name: "evaluationError" }, changes);
+        crate::diff::Diff::diff(&self.incomplete, &other.incomplete, &crate::diff::Path::Field { parent: path, name: "incomplete" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.non_resource_rules,
+            &other.non_resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "nonResourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.resource_rules,
+            &other.resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "resourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl SubjectRulesReviewStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SubjectRulesReviewStatus> {
+    /// The path of the `evaluationError` field.
+    pub fn evaluation_error(self) -> crate::FieldPath<std::string::String> {
+        self.child("evaluationError")
+    }
+
+    /// The path of the `incomplete` field.
+    pub fn incomplete(self) -> crate::FieldPath<bool> {
+        self.child("incomplete")
+    }
+
+    /// The path of the `nonResourceRules` field.
+    pub fn non_resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::authorization::v1::NonResourceRule>> {
+        self.child("nonResourceRules")
+    }
+
+    /// The path of the `resourceRules` field.
+    pub fn resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::authorization::v1::ResourceRule>> {
+        self.child("resourceRules")
+    }
+}
+
+impl crate::Normalize for SubjectRulesReviewStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.evaluation_error);
+        crate::Normalize::normalize(&mut self.incomplete);
+        crate::normalize_strategies::list::atomic(&mut self.non_resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SubjectRulesReviewStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -126,6 +202,46 @@ impl crate::serde::Serialize for SubjectRulesReviewStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SubjectRulesReviewStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.resource_rules, 1, buf);
+        crate::protobuf::Field::encode_field(&self.non_resource_rules, 2, buf);
+        crate::protobuf::Field::encode_field(&self.incomplete, 3, buf);
+        if let Some(value) = &self.evaluation_error {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_evaluation_error: Option<std::string::String> = None;
+        let mut value_incomplete: Option<bool> = None;
+        let mut value_non_resource_rules: Option<std::vec::Vec<crate::api::authorization::v1::NonResourceRule>> = None;
+        let mut value_resource_rules: Option<std::vec::Vec<crate::api::authorization::v1::ResourceRule>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_rules, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_non_resource_rules, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_incomplete, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_evaluation_error, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SubjectRulesReviewStatus {
+            evaluation_error: value_evaluation_error,
+            incomplete: value_incomplete.unwrap_or_default(),
+            non_resource_rules: value_non_resource_rules.unwrap_or_default(),
+            resource_rules: value_resource_rules.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SubjectRulesReviewStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectRulesReviewStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,70 @@ impl crate::DeepMerge for HorizontalPodAutoscalerStatus {
     }
 }
 
+impl crate::Defaulting for HorizontalPodAutoscalerStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.current_cpu_utilization_percentage);
+        crate::Defaulting::set_defaults(&mut self.current_replicas);
+        crate::Defaulting::set_defaults(&mut self.desired_replicas);
+        crate::Defaulting::set_defaults(&mut self.last_scale_time);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+    }
+}
+
+impl crate::diff::Diff for HorizontalPodAutoscalerStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.current_cpu_utilization_percentage, &other.current_cpu_utilization_percentage, &crate::diff::Path::Field { parent: path, name: "currentCPUUtilizationPercentage" }, changes);
+        crate::diff::Diff::diff(&self.current_replicas, &other.current_replicas, &crate::diff::Path::Field { parent: path, name: "currentReplicas" }, changes);
+        crate::diff::Diff::diff(&self.desired_replicas, &other.desired_replicas, &crate::diff::Path::Field { parent: path, name: "desiredReplicas" }, changes);
+        crate::diff::Diff::diff(&self.last_scale_time, &other.last_scale_time, &crate::diff::Path::Field { parent: path, name: "lastScaleTime" }, changes);
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+    }
+}
+
+impl HorizontalPodAutoscalerStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HorizontalPodAutoscalerStatus> {
+    /// The path of the `currentCPUUtilizationPercentage` field.
+    pub fn current_cpu_utilization_percentage(self) -> crate::FieldPath<i32> {
+        self.child("currentCPUUtilizationPercentage")
+    }
+
+    /// The path of the `currentReplicas` field.
+    pub fn current_replicas(self) -> crate::FieldPath<i32> {
+        self.child("currentReplicas")
+    }
+
+    /// The path of the `desiredReplicas` field.
+    pub fn desired_replicas(self) -> crate::FieldPath<i32> {
+        self.child("desiredReplicas")
+    }
+
+    /// The path of the `lastScaleTime` field.
+    pub fn last_scale_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("lastScaleTime")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+}
+
+impl crate::Normalize for HorizontalPodAutoscalerStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.current_cpu_utilization_percentage);
+        crate::Normalize::normalize(&mut self.current_replicas);
+        crate::Normalize::normalize(&mut self.desired_replicas);
+        crate::Normalize::normalize(&mut self.last_scale_time);
+        crate::Normalize::normalize(&mut self.observed_generation);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -143,6 +207,54 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HorizontalPodAutoscalerStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.last_scale_time {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.current_replicas, 3, buf);
+        crate::protobuf::Field::encode_field(&self.desired_replicas, 4, buf);
+        if let Some(value) = &self.current_cpu_utilization_percentage {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_current_cpu_utilization_percentage: Option<i32> = None;
+        let mut value_current_replicas: Option<i32> = None;
+        let mut value_desired_replicas: Option<i32> = None;
+        let mut value_last_scale_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_observed_generation: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_last_scale_time, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_current_replicas, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_desired_replicas, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_current_cpu_utilization_percentage, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HorizontalPodAutoscalerStatus {
+            current_cpu_utilization_percentage: value_current_cpu_utilization_percentage,
+            current_replicas: value_current_replicas.unwrap_or_default(),
+            desired_replicas: value_desired_replicas.unwrap_or_default(),
+            last_scale_time: value_last_scale_time,
+            observed_generation: value_observed_generation,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HorizontalPodAutoscalerStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
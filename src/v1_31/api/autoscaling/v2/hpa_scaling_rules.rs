@@ -21,6 +21,64 @@ impl crate::DeepMerge for HPAScalingRules {
     }
 }
 
+impl crate::Defaulting for HPAScalingRules {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.policies);
+        crate::Defaulting::set_defaults(&mut self.select_policy);
+        crate::Defaulting::set_defaults(&mut self.stabilization_window_seconds);
+    }
+}
+
+impl crate::diff::Diff for HPAScalingRules {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.policies,
+            &other.policies,
+            &crate::diff::Path::Field { parent: path, name: "policies" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.select_policy, &other.select_policy, &crate::diff::Path::Field { parent: path, name: "selectPolicy" }, changes);
+        crate::diff::Diff::diff(&self.stabilization_window_seconds, &other.stabilization_window_seconds, &crate::diff::Path::Field { parent: path, name: "stabilizationWindowSeconds" }, changes);
+    }
+}
+
+impl HPAScalingRules {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HPAScalingRules> {
+    /// The path of the `policies` field.
+    pub fn policies(self) -> crate::FieldPath<std::vec::Vec<crate::api::autoscaling::v2::HPAScalingPolicy>> {
+        self.child("policies")
+    }
+
+    /// The path of the `selectPolicy` field.
+    pub fn select_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("selectPolicy")
+    }
+
+    /// The path of the `stabilizationWindowSeconds` field.
+    pub fn stabilization_window_seconds(self) -> crate::FieldPath<i32> {
+        self.child("stabilizationWindowSeconds")
+    }
+}
+
+impl crate::Normalize for HPAScalingRules {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.policies, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.select_policy);
+        crate::Normalize::normalize(&mut self.stabilization_window_seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HPAScalingRules {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +178,46 @@ impl crate::serde::Serialize for HPAScalingRules {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HPAScalingRules {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.select_policy {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.policies {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.stabilization_window_seconds {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_policies: Option<std::vec::Vec<crate::api::autoscaling::v2::HPAScalingPolicy>> = None;
+        let mut value_select_policy: Option<std::string::String> = None;
+        let mut value_stabilization_window_seconds: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_select_policy, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_policies, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_stabilization_window_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HPAScalingRules {
+            policies: value_policies,
+            select_policy: value_select_policy,
+            stabilization_window_seconds: value_stabilization_window_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HPAScalingRules {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HPAScalingRules {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,48 @@ impl crate::DeepMerge for PodFailurePolicy {
     }
 }
 
+impl crate::Defaulting for PodFailurePolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.rules);
+    }
+}
+
+impl crate::diff::Diff for PodFailurePolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.rules,
+            &other.rules,
+            &crate::diff::Path::Field { parent: path, name: "rules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodFailurePolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodFailurePolicy> {
+    /// The path of the `rules` field.
+    pub fn rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::batch::v1::PodFailurePolicyRule>> {
+        self.child("rules")
+    }
+}
+
+impl crate::Normalize for PodFailurePolicy {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodFailurePolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +132,32 @@ impl crate::serde::Serialize for PodFailurePolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodFailurePolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.rules, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_rules: Option<std::vec::Vec<crate::api::batch::v1::PodFailurePolicyRule>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_rules, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodFailurePolicy {
+            rules: value_rules.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodFailurePolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodFailurePolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for SuccessPolicyRule {
     }
 }
 
+impl crate::Defaulting for SuccessPolicyRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.succeeded_count);
+        crate::Defaulting::set_defaults(&mut self.succeeded_indexes);
+    }
+}
+
+impl crate::diff::Diff for SuccessPolicyRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.succeeded_count, &other.succeeded_count, &crate::diff::Path::Field { parent: path, name: "succeededCount" }, changes);
+        crate::diff::Diff::diff(&self.succeeded_indexes, &other.succeeded_indexes, &crate::diff::Path::Field { parent: path, name: "succeededIndexes" }, changes);
+    }
+}
+
+impl SuccessPolicyRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SuccessPolicyRule> {
+    /// The path of the `succeededCount` field.
+    pub fn succeeded_count(self) -> crate::FieldPath<i32> {
+        self.child("succeededCount")
+    }
+
+    /// The path of the `succeededIndexes` field.
+    pub fn succeeded_indexes(self) -> crate::FieldPath<std::string::String> {
+        self.child("succeededIndexes")
+    }
+}
+
+impl crate::Normalize for SuccessPolicyRule {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.succeeded_count);
+        crate::Normalize::normalize(&mut self.succeeded_indexes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SuccessPolicyRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for SuccessPolicyRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SuccessPolicyRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.succeeded_indexes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.succeeded_count {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_succeeded_count: Option<i32> = None;
+        let mut value_succeeded_indexes: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_succeeded_indexes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_succeeded_count, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SuccessPolicyRule {
+            succeeded_count: value_succeeded_count,
+            succeeded_indexes: value_succeeded_indexes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SuccessPolicyRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SuccessPolicyRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -37,6 +37,88 @@ impl crate::DeepMerge for LeaseCandidateSpec {
     }
 }
 
+impl crate::Defaulting for LeaseCandidateSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.binary_version);
+        crate::Defaulting::set_defaults(&mut self.emulation_version);
+        crate::Defaulting::set_defaults(&mut self.lease_name);
+        crate::Defaulting::set_defaults(&mut self.ping_time);
+        crate::Defaulting::set_defaults(&mut self.preferred_strategies);
+        crate::Defaulting::set_defaults(&mut self.renew_time);
+    }
+}
+
+impl crate::diff::Diff for LeaseCandidateSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.binary_version, &other.binary_version, &crate::diff::Path::Field { parent: path, name: "binaryVersion" }, changes);
+        crate::diff::Diff::diff(&self.emulation_version, &other.emulation_version, &crate::diff::Path::Field { parent: path, name: "emulationVersion" }, changes);
+        crate::diff::Diff::diff(&self.lease_name, &other.lease_name, &crate::diff::Path::Field { parent: path, name: "leaseName" }, changes);
+        crate::diff::Diff::diff(&self.ping_time, &other.ping_time, &crate::diff::Path::Field { parent: path, name: "pingTime" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.preferred_strategies,
+            &other.preferred_strategies,
+            &crate::diff::Path::Field { parent: path, name: "preferredStrategies" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.renew_time, &other.renew_time, &crate::diff::Path::Field { parent: path, name: "renewTime" }, changes);
+    }
+}
+
+impl LeaseCandidateSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LeaseCandidateSpec> {
+    /// The path of the `binaryVersion` field.
+    pub fn binary_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("binaryVersion")
+    }
+
+    /// The path of the `emulationVersion` field.
+    pub fn emulation_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("emulationVersion")
+    }
+
+    /// The path of the `leaseName` field.
+    pub fn lease_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("leaseName")
+    }
+
+    /// The path of the `pingTime` field.
+    pub fn ping_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("pingTime")
+    }
+
+    /// The path of the `preferredStrategies` field.
+    pub fn preferred_strategies(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("preferredStrategies")
+    }
+
+    /// The path of the `renewTime` field.
+    pub fn renew_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("renewTime")
+    }
+}
+
+impl crate::Normalize for LeaseCandidateSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.binary_version);
+        crate::Normalize::normalize(&mut self.emulation_version);
+        crate::Normalize::normalize(&mut self.lease_name);
+        crate::Normalize::normalize(&mut self.ping_time);
+        crate::normalize_strategies::list::atomic(&mut self.preferred_strategies, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.renew_time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LeaseCandidateSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -161,6 +243,60 @@ impl crate::serde::Serialize for LeaseCandidateSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LeaseCandidateSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.lease_name, 1, buf);
+        if let Some(value) = &self.ping_time {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.renew_time {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.binary_version {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.emulation_version {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.preferred_strategies, 6, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_binary_version: Option<std::string::String> = None;
+        let mut value_emulation_version: Option<std::string::String> = None;
+        let mut value_lease_name: Option<std::string::String> = None;
+        let mut value_ping_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+        let mut value_preferred_strategies: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_lease_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_ping_time, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_renew_time, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_binary_version, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_emulation_version, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_preferred_strategies, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LeaseCandidateSpec {
+            binary_version: value_binary_version,
+            emulation_version: value_emulation_version,
+            lease_name: value_lease_name.unwrap_or_default(),
+            ping_time: value_ping_time,
+            preferred_strategies: value_preferred_strategies.unwrap_or_default(),
+            renew_time: value_renew_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LeaseCandidateSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LeaseCandidateSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
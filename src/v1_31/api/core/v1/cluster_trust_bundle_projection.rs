@@ -29,6 +29,70 @@ impl crate::DeepMerge for ClusterTrustBundleProjection {
     }
 }
 
+impl crate::Defaulting for ClusterTrustBundleProjection {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.label_selector);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.optional);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.signer_name);
+    }
+}
+
+impl crate::diff::Diff for ClusterTrustBundleProjection {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.label_selector, &other.label_selector, &crate::diff::Path::Field { parent: path, name: "labelSelector" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.optional, &other.optional, &crate::diff::Path::Field { parent: path, name: "optional" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.signer_name, &other.signer_name, &crate::diff::Path::Field { parent: path, name: "signerName" }, changes);
+    }
+}
+
+impl ClusterTrustBundleProjection {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ClusterTrustBundleProjection> {
+    /// The path of the `labelSelector` field.
+    pub fn label_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("labelSelector")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `optional` field.
+    pub fn optional(self) -> crate::FieldPath<bool> {
+        self.child("optional")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `signerName` field.
+    pub fn signer_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("signerName")
+    }
+}
+
+impl crate::Normalize for ClusterTrustBundleProjection {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.label_selector);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.optional);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.signer_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ClusterTrustBundleProjection {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -146,6 +210,56 @@ impl crate::serde::Serialize for ClusterTrustBundleProjection {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ClusterTrustBundleProjection {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.signer_name {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.label_selector {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.path, 4, buf);
+        if let Some(value) = &self.optional {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_optional: Option<bool> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_signer_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_signer_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_label_selector, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_optional, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ClusterTrustBundleProjection {
+            label_selector: value_label_selector,
+            name: value_name,
+            optional: value_optional,
+            path: value_path.unwrap_or_default(),
+            signer_name: value_signer_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ClusterTrustBundleProjection {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClusterTrustBundleProjection {
     fn schema_name() -> std::borrow::Cow<'static, str> {
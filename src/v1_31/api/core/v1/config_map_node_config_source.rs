@@ -29,6 +29,70 @@ impl crate::DeepMerge for ConfigMapNodeConfigSource {
     }
 }
 
+impl crate::Defaulting for ConfigMapNodeConfigSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.kubelet_config_key);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.resource_version);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for ConfigMapNodeConfigSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.kubelet_config_key, &other.kubelet_config_key, &crate::diff::Path::Field { parent: path, name: "kubeletConfigKey" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::Diff::diff(&self.resource_version, &other.resource_version, &crate::diff::Path::Field { parent: path, name: "resourceVersion" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl ConfigMapNodeConfigSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ConfigMapNodeConfigSource> {
+    /// The path of the `kubeletConfigKey` field.
+    pub fn kubelet_config_key(self) -> crate::FieldPath<std::string::String> {
+        self.child("kubeletConfigKey")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `resourceVersion` field.
+    pub fn resource_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceVersion")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for ConfigMapNodeConfigSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.kubelet_config_key);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::Normalize::normalize(&mut self.resource_version);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ConfigMapNodeConfigSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -140,6 +204,52 @@ impl crate::serde::Serialize for ConfigMapNodeConfigSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ConfigMapNodeConfigSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.namespace, 1, buf);
+        crate::protobuf::Field::encode_field(&self.name, 2, buf);
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.resource_version {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.kubelet_config_key, 5, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_kubelet_config_key: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_resource_version: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_resource_version, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_kubelet_config_key, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ConfigMapNodeConfigSource {
+            kubelet_config_key: value_kubelet_config_key.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            namespace: value_namespace.unwrap_or_default(),
+            resource_version: value_resource_version,
+            uid: value_uid,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ConfigMapNodeConfigSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapNodeConfigSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -133,6 +133,313 @@ impl crate::DeepMerge for Container {
     }
 }
 
+impl crate::Defaulting for Container {
+    fn set_defaults(&mut self) {
+        if self.termination_message_path.is_none() {
+            self.termination_message_path = Some("/dev/termination-log".into());
+        }
+        if self.termination_message_policy.is_none() {
+            self.termination_message_policy = Some("File".into());
+        }
+        crate::defaulting_strategies::core_v1::container(self);
+        crate::Defaulting::set_defaults(&mut self.args);
+        crate::Defaulting::set_defaults(&mut self.command);
+        crate::Defaulting::set_defaults(&mut self.env);
+        crate::Defaulting::set_defaults(&mut self.env_from);
+        crate::Defaulting::set_defaults(&mut self.image);
+        crate::Defaulting::set_defaults(&mut self.image_pull_policy);
+        crate::Defaulting::set_defaults(&mut self.lifecycle);
+        crate::Defaulting::set_defaults(&mut self.liveness_probe);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.ports);
+        crate::Defaulting::set_defaults(&mut self.readiness_probe);
+        crate::Defaulting::set_defaults(&mut self.resize_policy);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.restart_policy);
+        crate::Defaulting::set_defaults(&mut self.security_context);
+        crate::Defaulting::set_defaults(&mut self.startup_probe);
+        crate::Defaulting::set_defaults(&mut self.stdin);
+        crate::Defaulting::set_defaults(&mut self.stdin_once);
+        crate::Defaulting::set_defaults(&mut self.termination_message_path);
+        crate::Defaulting::set_defaults(&mut self.termination_message_policy);
+        crate::Defaulting::set_defaults(&mut self.tty);
+        crate::Defaulting::set_defaults(&mut self.volume_devices);
+        crate::Defaulting::set_defaults(&mut self.volume_mounts);
+        crate::Defaulting::set_defaults(&mut self.working_dir);
+    }
+}
+
+impl crate::diff::Diff for Container {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.args,
+            &other.args,
+            &crate::diff::Path::Field { parent: path, name: "args" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.command,
+            &other.command,
+            &crate::diff::Path::Field { parent: path, name: "command" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.env,
+            &other.env,
+            &crate::diff::Path::Field { parent: path, name: "env" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.env_from,
+            &other.env_from,
+            &crate::diff::Path::Field { parent: path, name: "envFrom" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.image, &other.image, &crate::diff::Path::Field { parent: path, name: "image" }, changes);
+        crate::diff::Diff::diff(&self.image_pull_policy, &other.image_pull_policy, &crate::diff::Path::Field { parent: path, name: "imagePullPolicy" }, changes);
+        crate::diff::Diff::diff(&self.lifecycle, &other.lifecycle, &crate::diff::Path::Field { parent: path, name: "lifecycle" }, changes);
+        crate::diff::Diff::diff(&self.liveness_probe, &other.liveness_probe, &crate::diff::Path::Field { parent: path, name: "livenessProbe" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::strategies::list::map(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            &[("containerPort", |item| crate::diff::MergeKey::merge_key(&item.container_port))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.readiness_probe, &other.readiness_probe, &crate::diff::Path::Field { parent: path, name: "readinessProbe" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.resize_policy,
+            &other.resize_policy,
+            &crate::diff::Path::Field { parent: path, name: "resizePolicy" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.resources, &other.resources, &crate::diff::Path::Field { parent: path, name: "resources" }, changes);
+        crate::diff::Diff::diff(&self.restart_policy, &other.restart_policy, &crate::diff::Path::Field { parent: path, name: "restartPolicy" }, changes);
+        crate::diff::Diff::diff(&self.security_context, &other.security_context, &crate::diff::Path::Field { parent: path, name: "securityContext" }, changes);
+        crate::diff::Diff::diff(&self.startup_probe, &other.startup_probe, &crate::diff::Path::Field { parent: path, name: "startupProbe" }, changes);
+        crate::diff::Diff::diff(&self.stdin, &other.stdin, &crate::diff::Path::Field { parent: path, name: "stdin" }, changes);
+        crate::diff::Diff::diff(&self.stdin_once, &other.stdin_once, &crate::diff::Path::Field { parent: path, name: "stdinOnce" }, changes);
+        crate::diff::Diff::diff(&self.termination_message_path, &other.termination_message_path, &crate::diff::Path::Field { parent: path, name: "terminationMessagePath" }, changes);
+        crate::diff::Diff::diff(&self.termination_message_policy, &other.termination_message_policy, &crate::diff::Path::Field { parent: path, name: "terminationMessagePolicy" }, changes);
+        crate::diff::Diff::diff(&self.tty, &other.tty, &crate::diff::Path::Field { parent: path, name: "tty" }, changes);
+        crate::diff::strategies::list::map(
+            &self.volume_devices,
+            &other.volume_devices,
+            &crate::diff::Path::Field { parent: path, name: "volumeDevices" },
+            changes,
+            &[("devicePath", |item| crate::diff::MergeKey::merge_key(&item.device_path))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.volume_mounts,
+            &other.volume_mounts,
+            &crate::diff::Path::Field { parent: path, name: "volumeMounts" },
+            changes,
+            &[("mountPath", |item| crate::diff::MergeKey::merge_key(&item.mount_path))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.working_dir, &other.working_dir, &crate::diff::Path::Field { parent: path, name: "workingDir" }, changes);
+    }
+}
+
+impl Container {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Container> {
+    /// The path of the `args` field.
+    pub fn args(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("args")
+    }
+
+    /// The path of the `command` field.
+    pub fn command(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("command")
+    }
+
+    /// The path of the `env` field.
+    pub fn env(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EnvVar>> {
+        self.child("env")
+    }
+
+    /// The path of the `envFrom` field.
+    pub fn env_from(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EnvFromSource>> {
+        self.child("envFrom")
+    }
+
+    /// The path of the `image` field.
+    pub fn image(self) -> crate::FieldPath<std::string::String> {
+        self.child("image")
+    }
+
+    /// The path of the `imagePullPolicy` field.
+    pub fn image_pull_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("imagePullPolicy")
+    }
+
+    /// The path of the `lifecycle` field.
+    pub fn lifecycle(self) -> crate::FieldPath<crate::api::core::v1::Lifecycle> {
+        self.child("lifecycle")
+    }
+
+    /// The path of the `livenessProbe` field.
+    pub fn liveness_probe(self) -> crate::FieldPath<crate::api::core::v1::Probe> {
+        self.child("livenessProbe")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerPort>> {
+        self.child("ports")
+    }
+
+    /// The path of the `readinessProbe` field.
+    pub fn readiness_probe(self) -> crate::FieldPath<crate::api::core::v1::Probe> {
+        self.child("readinessProbe")
+    }
+
+    /// The path of the `resizePolicy` field.
+    pub fn resize_policy(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerResizePolicy>> {
+        self.child("resizePolicy")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<crate::api::core::v1::ResourceRequirements> {
+        self.child("resources")
+    }
+
+    /// The path of the `restartPolicy` field.
+    pub fn restart_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("restartPolicy")
+    }
+
+    /// The path of the `securityContext` field.
+    pub fn security_context(self) -> crate::FieldPath<crate::api::core::v1::SecurityContext> {
+        self.child("securityContext")
+    }
+
+    /// The path of the `startupProbe` field.
+    pub fn startup_probe(self) -> crate::FieldPath<crate::api::core::v1::Probe> {
+        self.child("startupProbe")
+    }
+
+    /// The path of the `stdin` field.
+    pub fn stdin(self) -> crate::FieldPath<bool> {
+        self.child("stdin")
+    }
+
+    /// The path of the `stdinOnce` field.
+    pub fn stdin_once(self) -> crate::FieldPath<bool> {
+        self.child("stdinOnce")
+    }
+
+    /// The path of the `terminationMessagePath` field.
+    pub fn termination_message_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("terminationMessagePath")
+    }
+
+    /// The path of the `terminationMessagePolicy` field.
+    pub fn termination_message_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("terminationMessagePolicy")
+    }
+
+    /// The path of the `tty` field.
+    pub fn tty(self) -> crate::FieldPath<bool> {
+        self.child("tty")
+    }
+
+    /// The path of the `volumeDevices` field.
+    pub fn volume_devices(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::VolumeDevice>> {
+        self.child("volumeDevices")
+    }
+
+    /// The path of the `volumeMounts` field.
+    pub fn volume_mounts(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::VolumeMount>> {
+        self.child("volumeMounts")
+    }
+
+    /// The path of the `workingDir` field.
+    pub fn working_dir(self) -> crate::FieldPath<std::string::String> {
+        self.child("workingDir")
+    }
+}
+
+impl crate::Normalize for Container {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.args, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.command, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.env, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.env_from, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.image);
+        crate::Normalize::normalize(&mut self.image_pull_policy);
+        crate::Normalize::normalize(&mut self.lifecycle);
+        crate::Normalize::normalize(&mut self.liveness_probe);
+        crate::Normalize::normalize(&mut self.name);
+        crate::normalize_strategies::list::map(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.readiness_probe);
+        crate::normalize_strategies::list::atomic(&mut self.resize_policy, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.resources);
+        crate::Normalize::normalize(&mut self.restart_policy);
+        crate::Normalize::normalize(&mut self.security_context);
+        crate::Normalize::normalize(&mut self.startup_probe);
+        crate::Normalize::normalize(&mut self.stdin);
+        crate::Normalize::normalize(&mut self.stdin_once);
+        crate::Normalize::normalize(&mut self.termination_message_path);
+        crate::Normalize::normalize(&mut self.termination_message_policy);
+        crate::Normalize::normalize(&mut self.tty);
+        crate::normalize_strategies::list::map(&mut self.volume_devices, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.volume_mounts, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.working_dir);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Container {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -440,6 +747,187 @@ impl crate::serde::Serialize for Container {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Container {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.image {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.command {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.args {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.working_dir {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.env {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.volume_mounts {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.liveness_probe {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.readiness_probe {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.lifecycle {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.termination_message_path {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.image_pull_policy {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.security_context {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+        if let Some(value) = &self.stdin {
+            crate::protobuf::Field::encode_field(value, 16, buf);
+        }
+        if let Some(value) = &self.stdin_once {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+        if let Some(value) = &self.tty {
+            crate::protobuf::Field::encode_field(value, 18, buf);
+        }
+        if let Some(value) = &self.env_from {
+            crate::protobuf::Field::encode_field(value, 19, buf);
+        }
+        if let Some(value) = &self.termination_message_policy {
+            crate::protobuf::Field::encode_field(value, 20, buf);
+        }
+        if let Some(value) = &self.volume_devices {
+            crate::protobuf::Field::encode_field(value, 21, buf);
+        }
+        if let Some(value) = &self.startup_probe {
+            crate::protobuf::Field::encode_field(value, 22, buf);
+        }
+        if let Some(value) = &self.resize_policy {
+            crate::protobuf::Field::encode_field(value, 23, buf);
+        }
+        if let Some(value) = &self.restart_policy {
+            crate::protobuf::Field::encode_field(value, 24, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_args: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_command: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_env: Option<std::vec::Vec<crate::api::core::v1::EnvVar>> = None;
+        let mut value_env_from: Option<std::vec::Vec<crate::api::core::v1::EnvFromSource>> = None;
+        let mut value_image: Option<std::string::String> = None;
+        let mut value_image_pull_policy: Option<std::string::String> = None;
+        let mut value_lifecycle: Option<crate::api::core::v1::Lifecycle> = None;
+        let mut value_liveness_probe: Option<crate::api::core::v1::Probe> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_ports: Option<std::vec::Vec<crate::api::core::v1::ContainerPort>> = None;
+        let mut value_readiness_probe: Option<crate::api::core::v1::Probe> = None;
+        let mut value_resize_policy: Option<std::vec::Vec<crate::api::core::v1::ContainerResizePolicy>> = None;
+        let mut value_resources: Option<crate::api::core::v1::ResourceRequirements> = None;
+        let mut value_restart_policy: Option<std::string::String> = None;
+        let mut value_security_context: Option<crate::api::core::v1::SecurityContext> = None;
+        let mut value_startup_probe: Option<crate::api::core::v1::Probe> = None;
+        let mut value_stdin: Option<bool> = None;
+        let mut value_stdin_once: Option<bool> = None;
+        let mut value_termination_message_path: Option<std::string::String> = None;
+        let mut value_termination_message_policy: Option<std::string::String> = None;
+        let mut value_tty: Option<bool> = None;
+        let mut value_volume_devices: Option<std::vec::Vec<crate::api::core::v1::VolumeDevice>> = None;
+        let mut value_volume_mounts: Option<std::vec::Vec<crate::api::core::v1::VolumeMount>> = None;
+        let mut value_working_dir: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_image, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_command, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_args, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_working_dir, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_env, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_volume_mounts, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_liveness_probe, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_readiness_probe, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_lifecycle, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_termination_message_path, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_image_pull_policy, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_security_context, wire_type, &mut buf)?,
+                16 => crate::protobuf::Field::merge_field(&mut value_stdin, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_stdin_once, wire_type, &mut buf)?,
+                18 => crate::protobuf::Field::merge_field(&mut value_tty, wire_type, &mut buf)?,
+                19 => crate::protobuf::Field::merge_field(&mut value_env_from, wire_type, &mut buf)?,
+                20 => crate::protobuf::Field::merge_field(&mut value_termination_message_policy, wire_type, &mut buf)?,
+                21 => crate::protobuf::Field::merge_field(&mut value_volume_devices, wire_type, &mut buf)?,
+                22 => crate::protobuf::Field::merge_field(&mut value_startup_probe, wire_type, &mut buf)?,
+                23 => crate::protobuf::Field::merge_field(&mut value_resize_policy, wire_type, &mut buf)?,
+                24 => crate::protobuf::Field::merge_field(&mut value_restart_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Container {
+            args: value_args,
+            command: value_command,
+            env: value_env,
+            env_from: value_env_from,
+            image: value_image,
+            image_pull_policy: value_image_pull_policy,
+            lifecycle: value_lifecycle,
+            liveness_probe: value_liveness_probe,
+            name: value_name.unwrap_or_default(),
+            ports: value_ports,
+            readiness_probe: value_readiness_probe,
+            resize_policy: value_resize_policy,
+            resources: value_resources,
+            restart_policy: value_restart_policy,
+            security_context: value_security_context,
+            startup_probe: value_startup_probe,
+            stdin: value_stdin,
+            stdin_once: value_stdin_once,
+            termination_message_path: value_termination_message_path,
+            termination_message_policy: value_termination_message_policy,
+            tty: value_tty,
+            volume_devices: value_volume_devices,
+            volume_mounts: value_volume_mounts,
+            working_dir: value_working_dir,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Container {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "env" => Some(&["name"]),
+                "ports" => Some(&["containerPort"]),
+                "volumeDevices" => Some(&["devicePath"]),
+                "volumeMounts" => Some(&["mountPath"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "resources" => <Option<crate::api::core::v1::ResourceRequirements> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Container {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for EndpointAddress {
     }
 }
 
+impl crate::Defaulting for EndpointAddress {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hostname);
+        crate::Defaulting::set_defaults(&mut self.ip);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.target_ref);
+    }
+}
+
+impl crate::diff::Diff for EndpointAddress {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.hostname, &other.hostname, &crate::diff::Path::Field { parent: path, name: "hostname" }, changes);
+        crate::diff::Diff::diff(&self.ip, &other.ip, &crate::diff::Path::Field { parent: path, name: "ip" }, changes);
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.target_ref, &other.target_ref, &crate::diff::Path::Field { parent: path, name: "targetRef" }, changes);
+    }
+}
+
+impl EndpointAddress {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointAddress> {
+    /// The path of the `hostname` field.
+    pub fn hostname(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostname")
+    }
+
+    /// The path of the `ip` field.
+    pub fn ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("ip")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `targetRef` field.
+    pub fn target_ref(self) -> crate::FieldPath<crate::api::core::v1::ObjectReference> {
+        self.child("targetRef")
+    }
+}
+
+impl crate::Normalize for EndpointAddress {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.hostname);
+        crate::Normalize::normalize(&mut self.ip);
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.target_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointAddress {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for EndpointAddress {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointAddress {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.ip, 1, buf);
+        if let Some(value) = &self.target_ref {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.hostname {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.node_name {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hostname: Option<std::string::String> = None;
+        let mut value_ip: Option<std::string::String> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_target_ref: Option<crate::api::core::v1::ObjectReference> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ip, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_target_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_hostname, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointAddress {
+            hostname: value_hostname,
+            ip: value_ip.unwrap_or_default(),
+            node_name: value_node_name,
+            target_ref: value_target_ref,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointAddress {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointAddress {
     fn schema_name() -> std::borrow::Cow<'static, str> {
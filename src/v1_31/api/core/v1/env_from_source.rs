@@ -21,6 +21,54 @@ impl crate::DeepMerge for EnvFromSource {
     }
 }
 
+impl crate::Defaulting for EnvFromSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config_map_ref);
+        crate::Defaulting::set_defaults(&mut self.prefix);
+        crate::Defaulting::set_defaults(&mut self.secret_ref);
+    }
+}
+
+impl crate::diff::Diff for EnvFromSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.config_map_ref, &other.config_map_ref, &crate::diff::Path::Field { parent: path, name: "configMapRef" }, changes);
+        crate::diff::Diff::diff(&self.prefix, &other.prefix, &crate::diff::Path::Field { parent: path, name: "prefix" }, changes);
+        crate::diff::Diff::diff(&self.secret_ref, &other.secret_ref, &crate::diff::Path::Field { parent: path, name: "secretRef" }, changes);
+    }
+}
+
+impl EnvFromSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EnvFromSource> {
+    /// The path of the `configMapRef` field.
+    pub fn config_map_ref(self) -> crate::FieldPath<crate::api::core::v1::ConfigMapEnvSource> {
+        self.child("configMapRef")
+    }
+
+    /// The path of the `prefix` field.
+    pub fn prefix(self) -> crate::FieldPath<std::string::String> {
+        self.child("prefix")
+    }
+
+    /// The path of the `secretRef` field.
+    pub fn secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretEnvSource> {
+        self.child("secretRef")
+    }
+}
+
+impl crate::Normalize for EnvFromSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.config_map_ref);
+        crate::Normalize::normalize(&mut self.prefix);
+        crate::Normalize::normalize(&mut self.secret_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EnvFromSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for EnvFromSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EnvFromSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.prefix {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.config_map_ref {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config_map_ref: Option<crate::api::core::v1::ConfigMapEnvSource> = None;
+        let mut value_prefix: Option<std::string::String> = None;
+        let mut value_secret_ref: Option<crate::api::core::v1::SecretEnvSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_prefix, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_config_map_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_secret_ref, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EnvFromSource {
+            config_map_ref: value_config_map_ref,
+            prefix: value_prefix,
+            secret_ref: value_secret_ref,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EnvFromSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvFromSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for EnvVar {
     }
 }
 
+impl crate::Defaulting for EnvVar {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.value);
+        crate::Defaulting::set_defaults(&mut self.value_from);
+    }
+}
+
+impl crate::diff::Diff for EnvVar {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+        crate::diff::Diff::diff(&self.value_from, &other.value_from, &crate::diff::Path::Field { parent: path, name: "valueFrom" }, changes);
+    }
+}
+
+impl EnvVar {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EnvVar> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<std::string::String> {
+        self.child("value")
+    }
+
+    /// The path of the `valueFrom` field.
+    pub fn value_from(self) -> crate::FieldPath<crate::api::core::v1::EnvVarSource> {
+        self.child("valueFrom")
+    }
+}
+
+impl crate::Normalize for EnvVar {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.value);
+        crate::Normalize::normalize(&mut self.value_from);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EnvVar {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +166,44 @@ impl crate::serde::Serialize for EnvVar {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EnvVar {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.value {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.value_from {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_value: Option<std::string::String> = None;
+        let mut value_value_from: Option<crate::api::core::v1::EnvVarSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_value_from, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EnvVar {
+            name: value_name.unwrap_or_default(),
+            value: value_value,
+            value_from: value_value_from,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EnvVar {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvVar {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,62 @@ impl crate::DeepMerge for GCEPersistentDiskVolumeSource {
     }
 }
 
+impl crate::Defaulting for GCEPersistentDiskVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.partition);
+        crate::Defaulting::set_defaults(&mut self.pd_name);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+    }
+}
+
+impl crate::diff::Diff for GCEPersistentDiskVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.partition, &other.partition, &crate::diff::Path::Field { parent: path, name: "partition" }, changes);
+        crate::diff::Diff::diff(&self.pd_name, &other.pd_name, &crate::diff::Path::Field { parent: path, name: "pdName" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+    }
+}
+
+impl GCEPersistentDiskVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GCEPersistentDiskVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `partition` field.
+    pub fn partition(self) -> crate::FieldPath<i32> {
+        self.child("partition")
+    }
+
+    /// The path of the `pdName` field.
+    pub fn pd_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("pdName")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+}
+
+impl crate::Normalize for GCEPersistentDiskVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.partition);
+        crate::Normalize::normalize(&mut self.pd_name);
+        crate::Normalize::normalize(&mut self.read_only);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GCEPersistentDiskVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,50 @@ impl crate::serde::Serialize for GCEPersistentDiskVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GCEPersistentDiskVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.pd_name, 1, buf);
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.partition {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_partition: Option<i32> = None;
+        let mut value_pd_name: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pd_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_partition, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GCEPersistentDiskVolumeSource {
+            fs_type: value_fs_type,
+            partition: value_partition,
+            pd_name: value_pd_name.unwrap_or_default(),
+            read_only: value_read_only,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GCEPersistentDiskVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GCEPersistentDiskVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -18,6 +18,46 @@ impl crate::DeepMerge for GRPCAction {
     }
 }
 
+impl crate::Defaulting for GRPCAction {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.port);
+        crate::Defaulting::set_defaults(&mut self.service);
+    }
+}
+
+impl crate::diff::Diff for GRPCAction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+        crate::diff::Diff::diff(&self.service, &other.service, &crate::diff::Path::Field { parent: path, name: "service" }, changes);
+    }
+}
+
+impl GRPCAction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GRPCAction> {
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<i32> {
+        self.child("port")
+    }
+
+    /// The path of the `service` field.
+    pub fn service(self) -> crate::FieldPath<std::string::String> {
+        self.child("service")
+    }
+}
+
+impl crate::Normalize for GRPCAction {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.port);
+        crate::Normalize::normalize(&mut self.service);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GRPCAction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -105,6 +145,38 @@ impl crate::serde::Serialize for GRPCAction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GRPCAction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.port, 1, buf);
+        if let Some(value) = &self.service {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_port: Option<i32> = None;
+        let mut value_service: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_service, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GRPCAction {
+            port: value_port.unwrap_or_default(),
+            service: value_service,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GRPCAction {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GRPCAction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,56 @@ impl crate::DeepMerge for HostAlias {
     }
 }
 
+impl crate::Defaulting for HostAlias {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hostnames);
+        crate::Defaulting::set_defaults(&mut self.ip);
+    }
+}
+
+impl crate::diff::Diff for HostAlias {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.hostnames,
+            &other.hostnames,
+            &crate::diff::Path::Field { parent: path, name: "hostnames" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.ip, &other.ip, &crate::diff::Path::Field { parent: path, name: "ip" }, changes);
+    }
+}
+
+impl HostAlias {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HostAlias> {
+    /// The path of the `hostnames` field.
+    pub fn hostnames(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("hostnames")
+    }
+
+    /// The path of the `ip` field.
+    pub fn ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("ip")
+    }
+}
+
+impl crate::Normalize for HostAlias {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.hostnames, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.ip);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HostAlias {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +154,38 @@ impl crate::serde::Serialize for HostAlias {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HostAlias {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.ip, 1, buf);
+        if let Some(value) = &self.hostnames {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hostnames: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_ip: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ip, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_hostnames, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HostAlias {
+            hostnames: value_hostnames,
+            ip: value_ip.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HostAlias {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HostAlias {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for ImageVolumeSource {
     }
 }
 
+impl crate::Defaulting for ImageVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.pull_policy);
+        crate::Defaulting::set_defaults(&mut self.reference);
+    }
+}
+
+impl crate::diff::Diff for ImageVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.pull_policy, &other.pull_policy, &crate::diff::Path::Field { parent: path, name: "pullPolicy" }, changes);
+        crate::diff::Diff::diff(&self.reference, &other.reference, &crate::diff::Path::Field { parent: path, name: "reference" }, changes);
+    }
+}
+
+impl ImageVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ImageVolumeSource> {
+    /// The path of the `pullPolicy` field.
+    pub fn pull_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("pullPolicy")
+    }
+
+    /// The path of the `reference` field.
+    pub fn reference(self) -> crate::FieldPath<std::string::String> {
+        self.child("reference")
+    }
+}
+
+impl crate::Normalize for ImageVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.pull_policy);
+        crate::Normalize::normalize(&mut self.reference);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ImageVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for ImageVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ImageVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.reference {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.pull_policy {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_pull_policy: Option<std::string::String> = None;
+        let mut value_reference: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_reference, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pull_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ImageVolumeSource {
+            pull_policy: value_pull_policy,
+            reference: value_reference,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ImageVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ImageVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -53,6 +53,131 @@ impl crate::DeepMerge for ISCSIVolumeSource {
     }
 }
 
+impl crate::Defaulting for ISCSIVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.iscsi_interface.is_none() {
+            self.iscsi_interface = Some("default".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.chap_auth_discovery);
+        crate::Defaulting::set_defaults(&mut self.chap_auth_session);
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.initiator_name);
+        crate::Defaulting::set_defaults(&mut self.iqn);
+        crate::Defaulting::set_defaults(&mut self.iscsi_interface);
+        crate::Defaulting::set_defaults(&mut self.lun);
+        crate::Defaulting::set_defaults(&mut self.portals);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.secret_ref);
+        crate::Defaulting::set_defaults(&mut self.target_portal);
+    }
+}
+
+impl crate::diff::Diff for ISCSIVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.chap_auth_discovery, &other.chap_auth_discovery, &crate::diff::Path::Field { parent: path, name: "chapAuthDiscovery" }, changes);
+        crate::diff::Diff::diff(&self.chap_auth_session, &other.chap_auth_session, &crate::diff::Path::Field { parent: path, name: "chapAuthSession" }, changes);
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.initiator_name, &other.initiator_name, &crate::diff::Path::Field { parent: path, name: "initiatorName" }, changes);
+        crate::diff::Diff::diff(&self.iqn, &other.iqn, &crate::diff::Path::Field { parent: path, name: "iqn" }, changes);
+        crate::diff::Diff::diff(&self.iscsi_interface, &other.iscsi_interface, &crate::diff::Path::Field { parent: path, name: "iscsiInterface" }, changes);
+        crate::diff::Diff::diff(&self.lun, &other.lun, &crate::diff::Path::Field { parent: path, name: "lun" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.portals,
+            &other.portals,
+            &crate::diff::Path::Field { parent: path, name: "portals" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.secret_ref, &other.secret_ref, &crate::diff::Path::Field { parent: path, name: "secretRef" }, changes);
+        crate::diff::Diff::diff(&self.target_portal, &other.target_portal, &crate::diff::Path::Field { parent: path, name: "targetPortal" }, changes);
+    }
+}
+
+impl ISCSIVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ISCSIVolumeSource> {
+    /// The path of the `chapAuthDiscovery` field.
+    pub fn chap_auth_discovery(self) -> crate::FieldPath<bool> {
+        self.child("chapAuthDiscovery")
+    }
+
+    /// The path of the `chapAuthSession` field.
+    pub fn chap_auth_session(self) -> crate::FieldPath<bool> {
+        self.child("chapAuthSession")
+    }
+
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `initiatorName` field.
+    pub fn initiator_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("initiatorName")
+    }
+
+    /// The path of the `iqn` field.
+    pub fn iqn(self) -> crate::FieldPath<std::string::String> {
+        self.child("iqn")
+    }
+
+    /// The path of the `iscsiInterface` field.
+    pub fn iscsi_interface(self) -> crate::FieldPath<std::string::String> {
+        self.child("iscsiInterface")
+    }
+
+    /// The path of the `lun` field.
+    pub fn lun(self) -> crate::FieldPath<i32> {
+        self.child("lun")
+    }
+
+    /// The path of the `portals` field.
+    pub fn portals(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("portals")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `secretRef` field.
+    pub fn secret_ref(self) -> crate::FieldPath<crate::api::core::v1::LocalObjectReference> {
+        self.child("secretRef")
+    }
+
+    /// The path of the `targetPortal` field.
+    pub fn target_portal(self) -> crate::FieldPath<std::string::String> {
+        self.child("targetPortal")
+    }
+}
+
+impl crate::Normalize for ISCSIVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.chap_auth_discovery);
+        crate::Normalize::normalize(&mut self.chap_auth_session);
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.initiator_name);
+        crate::Normalize::normalize(&mut self.iqn);
+        crate::Normalize::normalize(&mut self.iscsi_interface);
+        crate::Normalize::normalize(&mut self.lun);
+        crate::normalize_strategies::list::atomic(&mut self.portals, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.secret_ref);
+        crate::Normalize::normalize(&mut self.target_portal);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ISCSIVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -224,6 +349,88 @@ impl crate::serde::Serialize for ISCSIVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ISCSIVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.target_portal, 1, buf);
+        crate::protobuf::Field::encode_field(&self.iqn, 2, buf);
+        crate::protobuf::Field::encode_field(&self.lun, 3, buf);
+        if let Some(value) = &self.iscsi_interface {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.portals {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.chap_auth_discovery {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.chap_auth_session {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.initiator_name {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_chap_auth_discovery: Option<bool> = None;
+        let mut value_chap_auth_session: Option<bool> = None;
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_initiator_name: Option<std::string::String> = None;
+        let mut value_iqn: Option<std::string::String> = None;
+        let mut value_iscsi_interface: Option<std::string::String> = None;
+        let mut value_lun: Option<i32> = None;
+        let mut value_portals: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
+        let mut value_target_portal: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_target_portal, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_iqn, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_lun, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_iscsi_interface, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_portals, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_chap_auth_discovery, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_secret_ref, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_chap_auth_session, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_initiator_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ISCSIVolumeSource {
+            chap_auth_discovery: value_chap_auth_discovery,
+            chap_auth_session: value_chap_auth_session,
+            fs_type: value_fs_type,
+            initiator_name: value_initiator_name,
+            iqn: value_iqn.unwrap_or_default(),
+            iscsi_interface: value_iscsi_interface,
+            lun: value_lun.unwrap_or_default(),
+            portals: value_portals,
+            read_only: value_read_only,
+            secret_ref: value_secret_ref,
+            target_portal: value_target_portal.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ISCSIVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ISCSIVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
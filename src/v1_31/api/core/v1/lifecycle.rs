@@ -17,6 +17,46 @@ impl crate::DeepMerge for Lifecycle {
     }
 }
 
+impl crate::Defaulting for Lifecycle {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.post_start);
+        crate::Defaulting::set_defaults(&mut self.pre_stop);
+    }
+}
+
+impl crate::diff::Diff for Lifecycle {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.post_start, &other.post_start, &crate::diff::Path::Field { parent: path, name: "postStart" }, changes);
+        crate::diff::Diff::diff(&self.pre_stop, &other.pre_stop, &crate::diff::Path::Field { parent: path, name: "preStop" }, changes);
+    }
+}
+
+impl Lifecycle {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Lifecycle> {
+    /// The path of the `postStart` field.
+    pub fn post_start(self) -> crate::FieldPath<crate::api::core::v1::LifecycleHandler> {
+        self.child("postStart")
+    }
+
+    /// The path of the `preStop` field.
+    pub fn pre_stop(self) -> crate::FieldPath<crate::api::core::v1::LifecycleHandler> {
+        self.child("preStop")
+    }
+}
+
+impl crate::Normalize for Lifecycle {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.post_start);
+        crate::Normalize::normalize(&mut self.pre_stop);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Lifecycle {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for Lifecycle {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Lifecycle {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.post_start {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.pre_stop {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_post_start: Option<crate::api::core::v1::LifecycleHandler> = None;
+        let mut value_pre_stop: Option<crate::api::core::v1::LifecycleHandler> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_post_start, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pre_stop, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Lifecycle {
+            post_start: value_post_start,
+            pre_stop: value_pre_stop,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Lifecycle {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Lifecycle {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -43,6 +43,128 @@ impl crate::DeepMerge for LimitRangeItem {
     }
 }
 
+impl crate::Defaulting for LimitRangeItem {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.default);
+        crate::Defaulting::set_defaults(&mut self.default_request);
+        crate::Defaulting::set_defaults(&mut self.max);
+        crate::Defaulting::set_defaults(&mut self.max_limit_request_ratio);
+        crate::Defaulting::set_defaults(&mut self.min);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for LimitRangeItem {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.default,
+            &other.default,
+            &crate::diff::Path::Field { parent: path, name: "default" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.default_request,
+            &other.default_request,
+            &crate::diff::Path::Field { parent: path, name: "defaultRequest" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.max,
+            &other.max,
+            &crate::diff::Path::Field { parent: path, name: "max" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.max_limit_request_ratio,
+            &other.max_limit_request_ratio,
+            &crate::diff::Path::Field { parent: path, name: "maxLimitRequestRatio" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.min,
+            &other.min,
+            &crate::diff::Path::Field { parent: path, name: "min" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl LimitRangeItem {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LimitRangeItem> {
+    /// The path of the `default` field.
+    pub fn default(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("default")
+    }
+
+    /// The path of the `defaultRequest` field.
+    pub fn default_request(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("defaultRequest")
+    }
+
+    /// The path of the `max` field.
+    pub fn max(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("max")
+    }
+
+    /// The path of the `maxLimitRequestRatio` field.
+    pub fn max_limit_request_ratio(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("maxLimitRequestRatio")
+    }
+
+    /// The path of the `min` field.
+    pub fn min(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("min")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for LimitRangeItem {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.default, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.default_request, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.max, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.max_limit_request_ratio, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.min, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LimitRangeItem {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -170,6 +292,62 @@ impl crate::serde::Serialize for LimitRangeItem {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LimitRangeItem {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.max {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.min {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.default {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.default_request {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.max_limit_request_ratio {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_default_request: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_max: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_max_limit_request_ratio: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_min: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_max, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_min, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_default, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_default_request, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_max_limit_request_ratio, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LimitRangeItem {
+            default: value_default,
+            default_request: value_default_request,
+            max: value_max,
+            max_limit_request_ratio: value_max_limit_request_ratio,
+            min: value_min,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LimitRangeItem {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitRangeItem {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for LocalObjectReference {
     }
 }
 
+impl crate::Defaulting for LocalObjectReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+    }
+}
+
+impl crate::diff::Diff for LocalObjectReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+    }
+}
+
+impl LocalObjectReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LocalObjectReference> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+}
+
+impl crate::Normalize for LocalObjectReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LocalObjectReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for LocalObjectReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LocalObjectReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LocalObjectReference {
+            name: value_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LocalObjectReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LocalObjectReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -26,6 +26,70 @@ impl crate::DeepMerge for NamespaceCondition {
     }
 }
 
+impl crate::Defaulting for NamespaceCondition {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.last_transition_time);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.status);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for NamespaceCondition {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.last_transition_time, &other.last_transition_time, &crate::diff::Path::Field { parent: path, name: "lastTransitionTime" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.status, &other.status, &crate::diff::Path::Field { parent: path, name: "status" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl NamespaceCondition {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NamespaceCondition> {
+    /// The path of the `lastTransitionTime` field.
+    pub fn last_transition_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("lastTransitionTime")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `status` field.
+    pub fn status(self) -> crate::FieldPath<std::string::String> {
+        self.child("status")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for NamespaceCondition {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.last_transition_time);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.status);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NamespaceCondition {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -140,6 +204,54 @@ impl crate::serde::Serialize for NamespaceCondition {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NamespaceCondition {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        crate::protobuf::Field::encode_field(&self.status, 2, buf);
+        if let Some(value) = &self.last_transition_time {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_last_transition_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_status: Option<std::string::String> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_status, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_last_transition_time, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NamespaceCondition {
+            last_transition_time: value_last_transition_time,
+            message: value_message,
+            reason: value_reason,
+            status: value_status.unwrap_or_default(),
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NamespaceCondition {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NamespaceCondition {
     fn schema_name() -> std::borrow::Cow<'static, str> {
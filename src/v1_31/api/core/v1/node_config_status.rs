@@ -25,6 +25,62 @@ impl crate::DeepMerge for NodeConfigStatus {
     }
 }
 
+impl crate::Defaulting for NodeConfigStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.active);
+        crate::Defaulting::set_defaults(&mut self.assigned);
+        crate::Defaulting::set_defaults(&mut self.error);
+        crate::Defaulting::set_defaults(&mut self.last_known_good);
+    }
+}
+
+impl crate::diff::Diff for NodeConfigStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.active, &other.active, &crate::diff::Path::Field { parent: path, name: "active" }, changes);
+        crate::diff::Diff::diff(&self.assigned, &other.assigned, &crate::diff::Path::Field { parent: path, name: "assigned" }, changes);
+        crate::diff::Diff::diff(&self.error, &other.error, &crate::diff::Path::Field { parent: path, name: "error" }, changes);
+        crate::diff::Diff::diff(&self.last_known_good, &other.last_known_good, &crate::diff::Path::Field { parent: path, name: "lastKnownGood" }, changes);
+    }
+}
+
+impl NodeConfigStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeConfigStatus> {
+    /// The path of the `active` field.
+    pub fn active(self) -> crate::FieldPath<crate::api::core::v1::NodeConfigSource> {
+        self.child("active")
+    }
+
+    /// The path of the `assigned` field.
+    pub fn assigned(self) -> crate::FieldPath<crate::api::core::v1::NodeConfigSource> {
+        self.child("assigned")
+    }
+
+    /// The path of the `error` field.
+    pub fn error(self) -> crate::FieldPath<std::string::String> {
+        self.child("error")
+    }
+
+    /// The path of the `lastKnownGood` field.
+    pub fn last_known_good(self) -> crate::FieldPath<crate::api::core::v1::NodeConfigSource> {
+        self.child("lastKnownGood")
+    }
+}
+
+impl crate::Normalize for NodeConfigStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.active);
+        crate::Normalize::normalize(&mut self.assigned);
+        crate::Normalize::normalize(&mut self.error);
+        crate::Normalize::normalize(&mut self.last_known_good);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeConfigStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for NodeConfigStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeConfigStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.assigned {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.active {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.last_known_good {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.error {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_active: Option<crate::api::core::v1::NodeConfigSource> = None;
+        let mut value_assigned: Option<crate::api::core::v1::NodeConfigSource> = None;
+        let mut value_error: Option<std::string::String> = None;
+        let mut value_last_known_good: Option<crate::api::core::v1::NodeConfigSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_assigned, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_active, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_last_known_good, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_error, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeConfigStatus {
+            active: value_active,
+            assigned: value_assigned,
+            error: value_error,
+            last_known_good: value_last_known_good,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeConfigStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeConfigStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for NodeRuntimeHandler {
     }
 }
 
+impl crate::Defaulting for NodeRuntimeHandler {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.features);
+        crate::Defaulting::set_defaults(&mut self.name);
+    }
+}
+
+impl crate::diff::Diff for NodeRuntimeHandler {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.features, &other.features, &crate::diff::Path::Field { parent: path, name: "features" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+    }
+}
+
+impl NodeRuntimeHandler {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeRuntimeHandler> {
+    /// The path of the `features` field.
+    pub fn features(self) -> crate::FieldPath<crate::api::core::v1::NodeRuntimeHandlerFeatures> {
+        self.child("features")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+}
+
+impl crate::Normalize for NodeRuntimeHandler {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.features);
+        crate::Normalize::normalize(&mut self.name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeRuntimeHandler {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for NodeRuntimeHandler {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeRuntimeHandler {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.features {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_features: Option<crate::api::core::v1::NodeRuntimeHandlerFeatures> = None;
+        let mut value_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_features, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeRuntimeHandler {
+            features: value_features,
+            name: value_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeRuntimeHandler {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeRuntimeHandler {
     fn schema_name() -> std::borrow::Cow<'static, str> {
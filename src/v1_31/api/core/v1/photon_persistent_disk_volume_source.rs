@@ -17,6 +17,46 @@ impl crate::DeepMerge for PhotonPersistentDiskVolumeSource {
     }
 }
 
+impl crate::Defaulting for PhotonPersistentDiskVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.pd_id);
+    }
+}
+
+impl crate::diff::Diff for PhotonPersistentDiskVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.pd_id, &other.pd_id, &crate::diff::Path::Field { parent: path, name: "pdID" }, changes);
+    }
+}
+
+impl PhotonPersistentDiskVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PhotonPersistentDiskVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `pdID` field.
+    pub fn pd_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("pdID")
+    }
+}
+
+impl crate::Normalize for PhotonPersistentDiskVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.pd_id);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PhotonPersistentDiskVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for PhotonPersistentDiskVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PhotonPersistentDiskVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.pd_id, 1, buf);
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_pd_id: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pd_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PhotonPersistentDiskVolumeSource {
+            fs_type: value_fs_type,
+            pd_id: value_pd_id.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PhotonPersistentDiskVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PhotonPersistentDiskVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,84 @@ impl crate::DeepMerge for PodDNSConfig {
     }
 }
 
+impl crate::Defaulting for PodDNSConfig {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.nameservers);
+        crate::Defaulting::set_defaults(&mut self.options);
+        crate::Defaulting::set_defaults(&mut self.searches);
+    }
+}
+
+impl crate::diff::Diff for PodDNSConfig {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.nameservers,
+            &other.nameservers,
+            &crate::diff::Path::Field { parent: path, name: "nameservers" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.options,
+            &other.options,
+            &crate::diff::Path::Field { parent: path, name: "options" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.searches,
+            &other.searches,
+            &crate::diff::Path::Field { parent: path, name: "searches" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodDNSConfig {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodDNSConfig> {
+    /// The path of the `nameservers` field.
+    pub fn nameservers(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("nameservers")
+    }
+
+    /// The path of the `options` field.
+    pub fn options(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodDNSConfigOption>> {
+        self.child("options")
+    }
+
+    /// The path of the `searches` field.
+    pub fn searches(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("searches")
+    }
+}
+
+impl crate::Normalize for PodDNSConfig {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.nameservers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.options, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.searches, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodDNSConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +198,46 @@ impl crate::serde::Serialize for PodDNSConfig {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodDNSConfig {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.nameservers {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.searches {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.options {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_nameservers: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_options: Option<std::vec::Vec<crate::api::core::v1::PodDNSConfigOption>> = None;
+        let mut value_searches: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_nameservers, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_searches, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_options, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodDNSConfig {
+            nameservers: value_nameservers,
+            options: value_options,
+            searches: value_searches,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodDNSConfig {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDNSConfig {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for PodReadinessGate {
     }
 }
 
+impl crate::Defaulting for PodReadinessGate {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.condition_type);
+    }
+}
+
+impl crate::diff::Diff for PodReadinessGate {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.condition_type, &other.condition_type, &crate::diff::Path::Field { parent: path, name: "conditionType" }, changes);
+    }
+}
+
+impl PodReadinessGate {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodReadinessGate> {
+    /// The path of the `conditionType` field.
+    pub fn condition_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("conditionType")
+    }
+}
+
+impl crate::Normalize for PodReadinessGate {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.condition_type);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodReadinessGate {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for PodReadinessGate {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodReadinessGate {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.condition_type, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_condition_type: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_condition_type, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodReadinessGate {
+            condition_type: value_condition_type.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodReadinessGate {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodReadinessGate {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for PodResourceClaimStatus {
     }
 }
 
+impl crate::Defaulting for PodResourceClaimStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.resource_claim_name);
+    }
+}
+
+impl crate::diff::Diff for PodResourceClaimStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.resource_claim_name, &other.resource_claim_name, &crate::diff::Path::Field { parent: path, name: "resourceClaimName" }, changes);
+    }
+}
+
+impl PodResourceClaimStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodResourceClaimStatus> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `resourceClaimName` field.
+    pub fn resource_claim_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceClaimName")
+    }
+}
+
+impl crate::Normalize for PodResourceClaimStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.resource_claim_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodResourceClaimStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for PodResourceClaimStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodResourceClaimStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.resource_claim_name {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_resource_claim_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_claim_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodResourceClaimStatus {
+            name: value_name.unwrap_or_default(),
+            resource_claim_name: value_resource_claim_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodResourceClaimStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodResourceClaimStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
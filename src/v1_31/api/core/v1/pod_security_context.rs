@@ -61,6 +61,146 @@ impl crate::DeepMerge for PodSecurityContext {
     }
 }
 
+impl crate::Defaulting for PodSecurityContext {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.app_armor_profile);
+        crate::Defaulting::set_defaults(&mut self.fs_group);
+        crate::Defaulting::set_defaults(&mut self.fs_group_change_policy);
+        crate::Defaulting::set_defaults(&mut self.run_as_group);
+        crate::Defaulting::set_defaults(&mut self.run_as_non_root);
+        crate::Defaulting::set_defaults(&mut self.run_as_user);
+        crate::Defaulting::set_defaults(&mut self.se_linux_options);
+        crate::Defaulting::set_defaults(&mut self.seccomp_profile);
+        crate::Defaulting::set_defaults(&mut self.supplemental_groups);
+        crate::Defaulting::set_defaults(&mut self.supplemental_groups_policy);
+        crate::Defaulting::set_defaults(&mut self.sysctls);
+        crate::Defaulting::set_defaults(&mut self.windows_options);
+    }
+}
+
+impl crate::diff::Diff for PodSecurityContext {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.app_armor_profile, &other.app_armor_profile, &crate::diff::Path::Field { parent: path, name: "appArmorProfile" }, changes);
+        crate::diff::Diff::diff(&self.fs_group, &other.fs_group, &crate::diff::Path::Field { parent: path, name: "fsGroup" }, changes);
+        crate::diff::Diff::diff(&self.fs_group_change_policy, &other.fs_group_change_policy, &crate::diff::Path::Field { parent: path, name: "fsGroupChangePolicy" }, changes);
+        crate::diff::Diff::diff(&self.run_as_group, &other.run_as_group, &crate::diff::Path::Field { parent: path, name: "runAsGroup" }, changes);
+        crate::diff::Diff::diff(&self.run_as_non_root, &other.run_as_non_root, &crate::diff::Path::Field { parent: path, name: "runAsNonRoot" }, changes);
+        crate::diff::Diff::diff(&self.run_as_user, &other.run_as_user, &crate::diff::Path::Field { parent: path, name: "runAsUser" }, changes);
+        crate::diff::Diff::diff(&self.se_linux_options, &other.se_linux_options, &crate::diff::Path::Field { parent: path, name: "seLinuxOptions" }, changes);
+        crate::diff::Diff::diff(&self.seccomp_profile, &other.seccomp_profile, &crate::diff::Path::Field { parent: path, name: "seccompProfile" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.supplemental_groups,
+            &other.supplemental_groups,
+            &crate::diff::Path::Field { parent: path, name: "supplementalGroups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.supplemental_groups_policy, &other.supplemental_groups_policy, &crate::diff::Path::Field { parent: path, name: "supplementalGroupsPolicy" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.sysctls,
+            &other.sysctls,
+            &crate::diff::Path::Field { parent: path, name: "sysctls" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.windows_options, &other.windows_options, &crate::diff::Path::Field { parent: path, name: "windowsOptions" }, changes);
+    }
+}
+
+impl PodSecurityContext {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodSecurityContext> {
+    /// The path of the `appArmorProfile` field.
+    pub fn app_armor_profile(self) -> crate::FieldPath<crate::api::core::v1::AppArmorProfile> {
+        self.child("appArmorProfile")
+    }
+
+    /// The path of the `fsGroup` field.
+    pub fn fs_group(self) -> crate::FieldPath<i64> {
+        self.child("fsGroup")
+    }
+
+    /// The path of the `fsGroupChangePolicy` field.
+    pub fn fs_group_change_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsGroupChangePolicy")
+    }
+
+    /// The path of the `runAsGroup` field.
+    pub fn run_as_group(self) -> crate::FieldPath<i64> {
+        self.child("runAsGroup")
+    }
+
+    /// The path of the `runAsNonRoot` field.
+    pub fn run_as_non_root(self) -> crate::FieldPath<bool> {
+        self.child("runAsNonRoot")
+    }
+
+    /// The path of the `runAsUser` field.
+    pub fn run_as_user(self) -> crate::FieldPath<i64> {
+        self.child("runAsUser")
+    }
+
+    /// The path of the `seLinuxOptions` field.
+    pub fn se_linux_options(self) -> crate::FieldPath<crate::api::core::v1::SELinuxOptions> {
+        self.child("seLinuxOptions")
+    }
+
+    /// The path of the `seccompProfile` field.
+    pub fn seccomp_profile(self) -> crate::FieldPath<crate::api::core::v1::SeccompProfile> {
+        self.child("seccompProfile")
+    }
+
+    /// The path of the `supplementalGroups` field.
+    pub fn supplemental_groups(self) -> crate::FieldPath<std::vec::Vec<i64>> {
+        self.child("supplementalGroups")
+    }
+
+    /// The path of the `supplementalGroupsPolicy` field.
+    pub fn supplemental_groups_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("supplementalGroupsPolicy")
+    }
+
+    /// The path of the `sysctls` field.
+    pub fn sysctls(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Sysctl>> {
+        self.child("sysctls")
+    }
+
+    /// The path of the `windowsOptions` field.
+    pub fn windows_options(self) -> crate::FieldPath<crate::api::core::v1::WindowsSecurityContextOptions> {
+        self.child("windowsOptions")
+    }
+}
+
+impl crate::Normalize for PodSecurityContext {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.app_armor_profile);
+        crate::Normalize::normalize(&mut self.fs_group);
+        crate::Normalize::normalize(&mut self.fs_group_change_policy);
+        crate::Normalize::normalize(&mut self.run_as_group);
+        crate::Normalize::normalize(&mut self.run_as_non_root);
+        crate::Normalize::normalize(&mut self.run_as_user);
+        crate::Normalize::normalize(&mut self.se_linux_options);
+        crate::Normalize::normalize(&mut self.seccomp_profile);
+        crate::normalize_strategies::list::atomic(&mut self.supplemental_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.supplemental_groups_policy);
+        crate::normalize_strategies::list::atomic(&mut self.sysctls, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.windows_options);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodSecurityContext {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -250,6 +390,100 @@ impl crate::serde::Serialize for PodSecurityContext {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodSecurityContext {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.se_linux_options {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.run_as_user {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.run_as_non_root {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.supplemental_groups {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.fs_group {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.run_as_group {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.sysctls {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.windows_options {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.fs_group_change_policy {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.seccomp_profile {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.app_armor_profile {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.supplemental_groups_policy {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_app_armor_profile: Option<crate::api::core::v1::AppArmorProfile> = None;
+        let mut value_fs_group: Option<i64> = None;
+        let mut value_fs_group_change_policy: Option<std::string::String> = None;
+        let mut value_run_as_group: Option<i64> = None;
+        let mut value_run_as_non_root: Option<bool> = None;
+        let mut value_run_as_user: Option<i64> = None;
+        let mut value_se_linux_options: Option<crate::api::core::v1::SELinuxOptions> = None;
+        let mut value_seccomp_profile: Option<crate::api::core::v1::SeccompProfile> = None;
+        let mut value_supplemental_groups: Option<std::vec::Vec<i64>> = None;
+        let mut value_supplemental_groups_policy: Option<std::string::String> = None;
+        let mut value_sysctls: Option<std::vec::Vec<crate::api::core::v1::Sysctl>> = None;
+        let mut value_windows_options: Option<crate::api::core::v1::WindowsSecurityContextOptions> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_se_linux_options, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_run_as_user, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_run_as_non_root, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_supplemental_groups, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_fs_group, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_run_as_group, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_sysctls, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_windows_options, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_fs_group_change_policy, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_seccomp_profile, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_app_armor_profile, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_supplemental_groups_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodSecurityContext {
+            app_armor_profile: value_app_armor_profile,
+            fs_group: value_fs_group,
+            fs_group_change_policy: value_fs_group_change_policy,
+            run_as_group: value_run_as_group,
+            run_as_non_root: value_run_as_non_root,
+            run_as_user: value_run_as_user,
+            se_linux_options: value_se_linux_options,
+            seccomp_profile: value_seccomp_profile,
+            supplemental_groups: value_supplemental_groups,
+            supplemental_groups_policy: value_supplemental_groups_policy,
+            sysctls: value_sysctls,
+            windows_options: value_windows_options,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodSecurityContext {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSecurityContext {
     fn schema_name() -> std::borrow::Cow<'static, str> {
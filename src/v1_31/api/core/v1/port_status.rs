@@ -23,6 +23,54 @@ impl crate::DeepMerge for PortStatus {
     }
 }
 
+impl crate::Defaulting for PortStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.error);
+        crate::Defaulting::set_defaults(&mut self.port);
+        crate::Defaulting::set_defaults(&mut self.protocol);
+    }
+}
+
+impl crate::diff::Diff for PortStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.error, &other.error, &crate::diff::Path::Field { parent: path, name: "error" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+        crate::diff::Diff::diff(&self.protocol, &other.protocol, &crate::diff::Path::Field { parent: path, name: "protocol" }, changes);
+    }
+}
+
+impl PortStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PortStatus> {
+    /// The path of the `error` field.
+    pub fn error(self) -> crate::FieldPath<std::string::String> {
+        self.child("error")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<i32> {
+        self.child("port")
+    }
+
+    /// The path of the `protocol` field.
+    pub fn protocol(self) -> crate::FieldPath<std::string::String> {
+        self.child("protocol")
+    }
+}
+
+impl crate::Normalize for PortStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.error);
+        crate::Normalize::normalize(&mut self.port);
+        crate::Normalize::normalize(&mut self.protocol);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PortStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -117,6 +165,42 @@ impl crate::serde::Serialize for PortStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PortStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.port, 1, buf);
+        crate::protobuf::Field::encode_field(&self.protocol, 2, buf);
+        if let Some(value) = &self.error {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_error: Option<std::string::String> = None;
+        let mut value_port: Option<i32> = None;
+        let mut value_protocol: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_protocol, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_error, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PortStatus {
+            error: value_error,
+            port: value_port.unwrap_or_default(),
+            protocol: value_protocol.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PortStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PortStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for ResourceClaim {
     }
 }
 
+impl crate::Defaulting for ResourceClaim {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.request);
+    }
+}
+
+impl crate::diff::Diff for ResourceClaim {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.request, &other.request, &crate::diff::Path::Field { parent: path, name: "request" }, changes);
+    }
+}
+
+impl ResourceClaim {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceClaim> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `request` field.
+    pub fn request(self) -> crate::FieldPath<std::string::String> {
+        self.child("request")
+    }
+}
+
+impl crate::Normalize for ResourceClaim {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.request);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceClaim {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for ResourceClaim {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceClaim {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.request {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_request: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_request, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceClaim {
+            name: value_name.unwrap_or_default(),
+            request: value_request,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceClaim {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceClaim {
     fn schema_name() -> std::borrow::Cow<'static, str> {
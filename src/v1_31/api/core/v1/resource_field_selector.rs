@@ -21,6 +21,54 @@ impl crate::DeepMerge for ResourceFieldSelector {
     }
 }
 
+impl crate::Defaulting for ResourceFieldSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_name);
+        crate::Defaulting::set_defaults(&mut self.divisor);
+        crate::Defaulting::set_defaults(&mut self.resource);
+    }
+}
+
+impl crate::diff::Diff for ResourceFieldSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_name, &other.container_name, &crate::diff::Path::Field { parent: path, name: "containerName" }, changes);
+        crate::diff::Diff::diff(&self.divisor, &other.divisor, &crate::diff::Path::Field { parent: path, name: "divisor" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+    }
+}
+
+impl ResourceFieldSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceFieldSelector> {
+    /// The path of the `containerName` field.
+    pub fn container_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerName")
+    }
+
+    /// The path of the `divisor` field.
+    pub fn divisor(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("divisor")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+}
+
+impl crate::Normalize for ResourceFieldSelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_name);
+        crate::Normalize::normalize(&mut self.divisor);
+        crate::Normalize::normalize(&mut self.resource);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceFieldSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +166,44 @@ impl crate::serde::Serialize for ResourceFieldSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceFieldSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.container_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.resource, 2, buf);
+        if let Some(value) = &self.divisor {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_name: Option<std::string::String> = None;
+        let mut value_divisor: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_resource: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_container_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_divisor, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceFieldSelector {
+            container_name: value_container_name,
+            divisor: value_divisor,
+            resource: value_resource.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceFieldSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceFieldSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -23,6 +23,57 @@ impl crate::DeepMerge for ResourceStatus {
     }
 }
 
+impl crate::Defaulting for ResourceStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.resources);
+    }
+}
+
+impl crate::diff::Diff for ResourceStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::strategies::list::map(
+            &self.resources,
+            &other.resources,
+            &crate::diff::Path::Field { parent: path, name: "resources" },
+            changes,
+            &[("resourceID", |item| crate::diff::MergeKey::merge_key(&item.resource_id))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceStatus> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ResourceHealth>> {
+        self.child("resources")
+    }
+}
+
+impl crate::Normalize for ResourceStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::normalize_strategies::list::map(&mut self.resources, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -110,6 +161,48 @@ impl crate::serde::Serialize for ResourceStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_resources: Option<std::vec::Vec<crate::api::core::v1::ResourceHealth>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceStatus {
+            name: value_name.unwrap_or_default(),
+            resources: value_resources,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "resources" => Some(&["resourceID"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
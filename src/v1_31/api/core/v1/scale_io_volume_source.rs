@@ -49,6 +49,110 @@ impl crate::DeepMerge for ScaleIOVolumeSource {
     }
 }
 
+impl crate::Defaulting for ScaleIOVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.gateway);
+        crate::Defaulting::set_defaults(&mut self.protection_domain);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.secret_ref);
+        crate::Defaulting::set_defaults(&mut self.ssl_enabled);
+        crate::Defaulting::set_defaults(&mut self.storage_mode);
+        crate::Defaulting::set_defaults(&mut self.storage_pool);
+        crate::Defaulting::set_defaults(&mut self.system);
+        crate::Defaulting::set_defaults(&mut self.volume_name);
+    }
+}
+
+impl crate::diff::Diff for ScaleIOVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.gateway, &other.gateway, &crate::diff::Path::Field { parent: path, name: "gateway" }, changes);
+        crate::diff::Diff::diff(&self.protection_domain, &other.protection_domain, &crate::diff::Path::Field { parent: path, name: "protectionDomain" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.secret_ref, &other.secret_ref, &crate::diff::Path::Field { parent: path, name: "secretRef" }, changes);
+        crate::diff::Diff::diff(&self.ssl_enabled, &other.ssl_enabled, &crate::diff::Path::Field { parent: path, name: "sslEnabled" }, changes);
+        crate::diff::Diff::diff(&self.storage_mode, &other.storage_mode, &crate::diff::Path::Field { parent: path, name: "storageMode" }, changes);
+        crate::diff::Diff::diff(&self.storage_pool, &other.storage_pool, &crate::diff::Path::Field { parent: path, name: "storagePool" }, changes);
+        crate::diff::Diff::diff(&self.system, &other.system, &crate::diff::Path::Field { parent: path, name: "system" }, changes);
+        crate::diff::Diff::diff(&self.volume_name, &other.volume_name, &crate::diff::Path::Field { parent: path, name: "volumeName" }, changes);
+    }
+}
+
+impl ScaleIOVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ScaleIOVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `gateway` field.
+    pub fn gateway(self) -> crate::FieldPath<std::string::String> {
+        self.child("gateway")
+    }
+
+    /// The path of the `protectionDomain` field.
+    pub fn protection_domain(self) -> crate::FieldPath<std::string::String> {
+        self.child("protectionDomain")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `secretRef` field.
+    pub fn secret_ref(self) -> crate::FieldPath<crate::api::core::v1::LocalObjectReference> {
+        self.child("secretRef")
+    }
+
+    /// The path of the `sslEnabled` field.
+    pub fn ssl_enabled(self) -> crate::FieldPath<bool> {
+        self.child("sslEnabled")
+    }
+
+    /// The path of the `storageMode` field.
+    pub fn storage_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("storageMode")
+    }
+
+    /// The path of the `storagePool` field.
+    pub fn storage_pool(self) -> crate::FieldPath<std::string::String> {
+        self.child("storagePool")
+    }
+
+    /// The path of the `system` field.
+    pub fn system(self) -> crate::FieldPath<std::string::String> {
+        self.child("system")
+    }
+
+    /// The path of the `volumeName` field.
+    pub fn volume_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeName")
+    }
+}
+
+impl crate::Normalize for ScaleIOVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.gateway);
+        crate::Normalize::normalize(&mut self.protection_domain);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.secret_ref);
+        crate::Normalize::normalize(&mut self.ssl_enabled);
+        crate::Normalize::normalize(&mut self.storage_mode);
+        crate::Normalize::normalize(&mut self.storage_pool);
+        crate::Normalize::normalize(&mut self.system);
+        crate::Normalize::normalize(&mut self.volume_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ScaleIOVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -210,6 +314,82 @@ impl crate::serde::Serialize for ScaleIOVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ScaleIOVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.gateway, 1, buf);
+        crate::protobuf::Field::encode_field(&self.system, 2, buf);
+        crate::protobuf::Field::encode_field(&self.secret_ref, 3, buf);
+        if let Some(value) = &self.ssl_enabled {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.protection_domain {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.storage_pool {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.storage_mode {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.volume_name {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_gateway: Option<std::string::String> = None;
+        let mut value_protection_domain: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
+        let mut value_ssl_enabled: Option<bool> = None;
+        let mut value_storage_mode: Option<std::string::String> = None;
+        let mut value_storage_pool: Option<std::string::String> = None;
+        let mut value_system: Option<std::string::String> = None;
+        let mut value_volume_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_gateway, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_system, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_secret_ref, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_ssl_enabled, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_protection_domain, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_storage_pool, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_storage_mode, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_volume_name, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ScaleIOVolumeSource {
+            fs_type: value_fs_type,
+            gateway: value_gateway.unwrap_or_default(),
+            protection_domain: value_protection_domain,
+            read_only: value_read_only,
+            secret_ref: value_secret_ref.unwrap_or_default(),
+            ssl_enabled: value_ssl_enabled,
+            storage_mode: value_storage_mode,
+            storage_pool: value_storage_pool,
+            system: value_system.unwrap_or_default(),
+            volume_name: value_volume_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ScaleIOVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScaleIOVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,75 @@ impl crate::DeepMerge for SecretVolumeSource {
     }
 }
 
+impl crate::Defaulting for SecretVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.default_mode.is_none() {
+            self.default_mode = Some(420);
+        }
+        crate::Defaulting::set_defaults(&mut self.default_mode);
+        crate::Defaulting::set_defaults(&mut self.items);
+        crate::Defaulting::set_defaults(&mut self.optional);
+        crate::Defaulting::set_defaults(&mut self.secret_name);
+    }
+}
+
+impl crate::diff::Diff for SecretVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.default_mode, &other.default_mode, &crate::diff::Path::Field { parent: path, name: "defaultMode" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.items,
+            &other.items,
+            &crate::diff::Path::Field { parent: path, name: "items" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.optional, &other.optional, &crate::diff::Path::Field { parent: path, name: "optional" }, changes);
+        crate::diff::Diff::diff(&self.secret_name, &other.secret_name, &crate::diff::Path::Field { parent: path, name: "secretName" }, changes);
+    }
+}
+
+impl SecretVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SecretVolumeSource> {
+    /// The path of the `defaultMode` field.
+    pub fn default_mode(self) -> crate::FieldPath<i32> {
+        self.child("defaultMode")
+    }
+
+    /// The path of the `items` field.
+    pub fn items(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::KeyToPath>> {
+        self.child("items")
+    }
+
+    /// The path of the `optional` field.
+    pub fn optional(self) -> crate::FieldPath<bool> {
+        self.child("optional")
+    }
+
+    /// The path of the `secretName` field.
+    pub fn secret_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("secretName")
+    }
+}
+
+impl crate::Normalize for SecretVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.default_mode);
+        crate::normalize_strategies::list::atomic(&mut self.items, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.optional);
+        crate::Normalize::normalize(&mut self.secret_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SecretVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -136,6 +205,52 @@ impl crate::serde::Serialize for SecretVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SecretVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.secret_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.items {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.default_mode {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.optional {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default_mode: Option<i32> = None;
+        let mut value_items: Option<std::vec::Vec<crate::api::core::v1::KeyToPath>> = None;
+        let mut value_optional: Option<bool> = None;
+        let mut value_secret_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_secret_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_items, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_default_mode, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_optional, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SecretVolumeSource {
+            default_mode: value_default_mode,
+            items: value_items,
+            optional: value_optional,
+            secret_name: value_secret_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SecretVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SecretVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
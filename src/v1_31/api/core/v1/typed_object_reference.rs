@@ -24,6 +24,62 @@ impl crate::DeepMerge for TypedObjectReference {
     }
 }
 
+impl crate::Defaulting for TypedObjectReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_group);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+    }
+}
+
+impl crate::diff::Diff for TypedObjectReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_group, &other.api_group, &crate::diff::Path::Field { parent: path, name: "apiGroup" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+    }
+}
+
+impl TypedObjectReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TypedObjectReference> {
+    /// The path of the `apiGroup` field.
+    pub fn api_group(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiGroup")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+}
+
+impl crate::Normalize for TypedObjectReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_group);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TypedObjectReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -128,6 +184,48 @@ impl crate::serde::Serialize for TypedObjectReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TypedObjectReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.api_group {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.kind, 2, buf);
+        crate::protobuf::Field::encode_field(&self.name, 3, buf);
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_group: Option<std::string::String> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_api_group, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TypedObjectReference {
+            api_group: value_api_group,
+            kind: value_kind.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            namespace: value_namespace,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TypedObjectReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TypedObjectReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
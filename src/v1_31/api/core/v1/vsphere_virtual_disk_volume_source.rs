@@ -25,6 +25,62 @@ impl crate::DeepMerge for VsphereVirtualDiskVolumeSource {
     }
 }
 
+impl crate::Defaulting for VsphereVirtualDiskVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.storage_policy_id);
+        crate::Defaulting::set_defaults(&mut self.storage_policy_name);
+        crate::Defaulting::set_defaults(&mut self.volume_path);
+    }
+}
+
+impl crate::diff::Diff for VsphereVirtualDiskVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.storage_policy_id, &other.storage_policy_id, &crate::diff::Path::Field { parent: path, name: "storagePolicyID" }, changes);
+        crate::diff::Diff::diff(&self.storage_policy_name, &other.storage_policy_name, &crate::diff::Path::Field { parent: path, name: "storagePolicyName" }, changes);
+        crate::diff::Diff::diff(&self.volume_path, &other.volume_path, &crate::diff::Path::Field { parent: path, name: "volumePath" }, changes);
+    }
+}
+
+impl VsphereVirtualDiskVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VsphereVirtualDiskVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `storagePolicyID` field.
+    pub fn storage_policy_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("storagePolicyID")
+    }
+
+    /// The path of the `storagePolicyName` field.
+    pub fn storage_policy_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("storagePolicyName")
+    }
+
+    /// The path of the `volumePath` field.
+    pub fn volume_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumePath")
+    }
+}
+
+impl crate::Normalize for VsphereVirtualDiskVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.storage_policy_id);
+        crate::Normalize::normalize(&mut self.storage_policy_name);
+        crate::Normalize::normalize(&mut self.volume_path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VsphereVirtualDiskVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for VsphereVirtualDiskVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VsphereVirtualDiskVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.volume_path, 1, buf);
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.storage_policy_name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.storage_policy_id {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_storage_policy_id: Option<std::string::String> = None;
+        let mut value_storage_policy_name: Option<std::string::String> = None;
+        let mut value_volume_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_volume_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_storage_policy_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_storage_policy_id, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VsphereVirtualDiskVolumeSource {
+            fs_type: value_fs_type,
+            storage_policy_id: value_storage_policy_id,
+            storage_policy_name: value_storage_policy_name,
+            volume_path: value_volume_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VsphereVirtualDiskVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VsphereVirtualDiskVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
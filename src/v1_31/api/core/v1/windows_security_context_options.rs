@@ -25,6 +25,62 @@ impl crate::DeepMerge for WindowsSecurityContextOptions {
     }
 }
 
+impl crate::Defaulting for WindowsSecurityContextOptions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.gmsa_credential_spec);
+        crate::Defaulting::set_defaults(&mut self.gmsa_credential_spec_name);
+        crate::Defaulting::set_defaults(&mut self.host_process);
+        crate::Defaulting::set_defaults(&mut self.run_as_user_name);
+    }
+}
+
+impl crate::diff::Diff for WindowsSecurityContextOptions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.gmsa_credential_spec, &other.gmsa_credential_spec, &crate::diff::Path::Field { parent: path, name: "gmsaCredentialSpec" }, changes);
+        crate::diff::Diff::diff(&self.gmsa_credential_spec_name, &other.gmsa_credential_spec_name, &crate::diff::Path::Field { parent: path, name: "gmsaCredentialSpecName" }, changes);
+        crate::diff::Diff::diff(&self.host_process, &other.host_process, &crate::diff::Path::Field { parent: path, name: "hostProcess" }, changes);
+        crate::diff::Diff::diff(&self.run_as_user_name, &other.run_as_user_name, &crate::diff::Path::Field { parent: path, name: "runAsUserName" }, changes);
+    }
+}
+
+impl WindowsSecurityContextOptions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WindowsSecurityContextOptions> {
+    /// The path of the `gmsaCredentialSpec` field.
+    pub fn gmsa_credential_spec(self) -> crate::FieldPath<std::string::String> {
+        self.child("gmsaCredentialSpec")
+    }
+
+    /// The path of the `gmsaCredentialSpecName` field.
+    pub fn gmsa_credential_spec_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("gmsaCredentialSpecName")
+    }
+
+    /// The path of the `hostProcess` field.
+    pub fn host_process(self) -> crate::FieldPath<bool> {
+        self.child("hostProcess")
+    }
+
+    /// The path of the `runAsUserName` field.
+    pub fn run_as_user_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("runAsUserName")
+    }
+}
+
+impl crate::Normalize for WindowsSecurityContextOptions {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.gmsa_credential_spec);
+        crate::Normalize::normalize(&mut self.gmsa_credential_spec_name);
+        crate::Normalize::normalize(&mut self.host_process);
+        crate::Normalize::normalize(&mut self.run_as_user_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WindowsSecurityContextOptions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for WindowsSecurityContextOptions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WindowsSecurityContextOptions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.gmsa_credential_spec_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.gmsa_credential_spec {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.run_as_user_name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.host_process {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_gmsa_credential_spec: Option<std::string::String> = None;
+        let mut value_gmsa_credential_spec_name: Option<std::string::String> = None;
+        let mut value_host_process: Option<bool> = None;
+        let mut value_run_as_user_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_gmsa_credential_spec_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_gmsa_credential_spec, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_run_as_user_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_host_process, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WindowsSecurityContextOptions {
+            gmsa_credential_spec: value_gmsa_credential_spec,
+            gmsa_credential_spec_name: value_gmsa_credential_spec_name,
+            host_process: value_host_process,
+            run_as_user_name: value_run_as_user_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WindowsSecurityContextOptions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WindowsSecurityContextOptions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -34,6 +34,62 @@ impl crate::DeepMerge for EndpointPort {
     }
 }
 
+impl crate::Defaulting for EndpointPort {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.app_protocol);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.port);
+        crate::Defaulting::set_defaults(&mut self.protocol);
+    }
+}
+
+impl crate::diff::Diff for EndpointPort {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.app_protocol, &other.app_protocol, &crate::diff::Path::Field { parent: path, name: "appProtocol" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+        crate::diff::Diff::diff(&self.protocol, &other.protocol, &crate::diff::Path::Field { parent: path, name: "protocol" }, changes);
+    }
+}
+
+impl EndpointPort {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointPort> {
+    /// The path of the `appProtocol` field.
+    pub fn app_protocol(self) -> crate::FieldPath<std::string::String> {
+        self.child("appProtocol")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<i32> {
+        self.child("port")
+    }
+
+    /// The path of the `protocol` field.
+    pub fn protocol(self) -> crate::FieldPath<std::string::String> {
+        self.child("protocol")
+    }
+}
+
+impl crate::Normalize for EndpointPort {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.app_protocol);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.port);
+        crate::Normalize::normalize(&mut self.protocol);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointPort {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -143,6 +199,52 @@ impl crate::serde::Serialize for EndpointPort {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointPort {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.protocol {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.port {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.app_protocol {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_app_protocol: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_port: Option<i32> = None;
+        let mut value_protocol: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_protocol, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_app_protocol, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointPort {
+            app_protocol: value_app_protocol,
+            name: value_name,
+            port: value_port,
+            protocol: value_protocol,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointPort {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointPort {
     fn schema_name() -> std::borrow::Cow<'static, str> {
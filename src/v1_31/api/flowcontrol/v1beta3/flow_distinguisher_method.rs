@@ -13,6 +13,38 @@ impl crate::DeepMerge for FlowDistinguisherMethod {
     }
 }
 
+impl crate::Defaulting for FlowDistinguisherMethod {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for FlowDistinguisherMethod {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl FlowDistinguisherMethod {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FlowDistinguisherMethod> {
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for FlowDistinguisherMethod {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FlowDistinguisherMethod {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for FlowDistinguisherMethod {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FlowDistinguisherMethod {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FlowDistinguisherMethod {
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FlowDistinguisherMethod {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlowDistinguisherMethod {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,72 @@ impl crate::DeepMerge for FlowSchemaSpec {
     }
 }
 
+impl crate::Defaulting for FlowSchemaSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.distinguisher_method);
+        crate::Defaulting::set_defaults(&mut self.matching_precedence);
+        crate::Defaulting::set_defaults(&mut self.priority_level_configuration);
+        crate::Defaulting::set_defaults(&mut self.rules);
+    }
+}
+
+impl crate::diff::Diff for FlowSchemaSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.distinguisher_method, &other.distinguisher_method, &crate::diff::Path::Field { parent: path, name: "distinguisherMethod" }, changes);
+        crate::diff::Diff::diff(&self.matching_precedence, &other.matching_precedence, &crate::diff::Path::Field { parent: path, name: "matchingPrecedence" }, changes);
+        crate::diff::Diff::diff(&self.priority_level_configuration, &other.priority_level_configuration, &crate::diff::Path::Field { parent: path, name: "priorityLevelConfiguration" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.rules,
+            &other.rules,
+            &crate::diff::Path::Field { parent: path, name: "rules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl FlowSchemaSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FlowSchemaSpec> {
+    /// The path of the `distinguisherMethod` field.
+    pub fn distinguisher_method(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::FlowDistinguisherMethod> {
+        self.child("distinguisherMethod")
+    }
+
+    /// The path of the `matchingPrecedence` field.
+    pub fn matching_precedence(self) -> crate::FieldPath<i32> {
+        self.child("matchingPrecedence")
+    }
+
+    /// The path of the `priorityLevelConfiguration` field.
+    pub fn priority_level_configuration(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::PriorityLevelConfigurationReference> {
+        self.child("priorityLevelConfiguration")
+    }
+
+    /// The path of the `rules` field.
+    pub fn rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::flowcontrol::v1beta3::PolicyRulesWithSubjects>> {
+        self.child("rules")
+    }
+}
+
+impl crate::Normalize for FlowSchemaSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.distinguisher_method);
+        crate::Normalize::normalize(&mut self.matching_precedence);
+        crate::Normalize::normalize(&mut self.priority_level_configuration);
+        crate::normalize_strategies::list::atomic(&mut self.rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FlowSchemaSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +198,50 @@ impl crate::serde::Serialize for FlowSchemaSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FlowSchemaSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.priority_level_configuration, 1, buf);
+        if let Some(value) = &self.matching_precedence {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.distinguisher_method {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.rules {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_distinguisher_method: Option<crate::api::flowcontrol::v1beta3::FlowDistinguisherMethod> = None;
+        let mut value_matching_precedence: Option<i32> = None;
+        let mut value_priority_level_configuration: Option<crate::api::flowcontrol::v1beta3::PriorityLevelConfigurationReference> = None;
+        let mut value_rules: Option<std::vec::Vec<crate::api::flowcontrol::v1beta3::PolicyRulesWithSubjects>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_priority_level_configuration, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_matching_precedence, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_distinguisher_method, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_rules, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FlowSchemaSpec {
+            distinguisher_method: value_distinguisher_method,
+            matching_precedence: value_matching_precedence,
+            priority_level_configuration: value_priority_level_configuration.unwrap_or_default(),
+            rules: value_rules,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FlowSchemaSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlowSchemaSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
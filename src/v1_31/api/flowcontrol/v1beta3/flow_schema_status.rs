@@ -20,6 +20,49 @@ impl crate::DeepMerge for FlowSchemaStatus {
     }
 }
 
+impl crate::Defaulting for FlowSchemaStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+    }
+}
+
+impl crate::diff::Diff for FlowSchemaStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl FlowSchemaStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FlowSchemaStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::flowcontrol::v1beta3::FlowSchemaCondition>> {
+        self.child("conditions")
+    }
+}
+
+impl crate::Normalize for FlowSchemaStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FlowSchemaStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -99,6 +142,44 @@ impl crate::serde::Serialize for FlowSchemaStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FlowSchemaStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::api::flowcontrol::v1beta3::FlowSchemaCondition>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FlowSchemaStatus {
+            conditions: value_conditions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FlowSchemaStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlowSchemaStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for LimitResponse {
     }
 }
 
+impl crate::Defaulting for LimitResponse {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.queuing);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for LimitResponse {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.queuing, &other.queuing, &crate::diff::Path::Field { parent: path, name: "queuing" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl LimitResponse {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LimitResponse> {
+    /// The path of the `queuing` field.
+    pub fn queuing(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::QueuingConfiguration> {
+        self.child("queuing")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for LimitResponse {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.queuing);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LimitResponse {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for LimitResponse {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LimitResponse {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.queuing {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_queuing: Option<crate::api::flowcontrol::v1beta3::QueuingConfiguration> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_queuing, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LimitResponse {
+            queuing: value_queuing,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LimitResponse {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitResponse {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -37,6 +37,62 @@ impl crate::DeepMerge for LimitedPriorityLevelConfiguration {
     }
 }
 
+impl crate::Defaulting for LimitedPriorityLevelConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.borrowing_limit_percent);
+        crate::Defaulting::set_defaults(&mut self.lendable_percent);
+        crate::Defaulting::set_defaults(&mut self.limit_response);
+        crate::Defaulting::set_defaults(&mut self.nominal_concurrency_shares);
+    }
+}
+
+impl crate::diff::Diff for LimitedPriorityLevelConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.borrowing_limit_percent, &other.borrowing_limit_percent, &crate::diff::Path::Field { parent: path, name: "borrowingLimitPercent" }, changes);
+        crate::diff::Diff::diff(&self.lendable_percent, &other.lendable_percent, &crate::diff::Path::Field { parent: path, name: "lendablePercent" }, changes);
+        crate::diff::Diff::diff(&self.limit_response, &other.limit_response, &crate::diff::Path::Field { parent: path, name: "limitResponse" }, changes);
+        crate::diff::Diff::diff(&self.nominal_concurrency_shares, &other.nominal_concurrency_shares, &crate::diff::Path::Field { parent: path, name: "nominalConcurrencyShares" }, changes);
+    }
+}
+
+impl LimitedPriorityLevelConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LimitedPriorityLevelConfiguration> {
+    /// The path of the `borrowingLimitPercent` field.
+    pub fn borrowing_limit_percent(self) -> crate::FieldPath<i32> {
+        self.child("borrowingLimitPercent")
+    }
+
+    /// The path of the `lendablePercent` field.
+    pub fn lendable_percent(self) -> crate::FieldPath<i32> {
+        self.child("lendablePercent")
+    }
+
+    /// The path of the `limitResponse` field.
+    pub fn limit_response(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::LimitResponse> {
+        self.child("limitResponse")
+    }
+
+    /// The path of the `nominalConcurrencyShares` field.
+    pub fn nominal_concurrency_shares(self) -> crate::FieldPath<i32> {
+        self.child("nominalConcurrencyShares")
+    }
+}
+
+impl crate::Normalize for LimitedPriorityLevelConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.borrowing_limit_percent);
+        crate::Normalize::normalize(&mut self.lendable_percent);
+        crate::Normalize::normalize(&mut self.limit_response);
+        crate::Normalize::normalize(&mut self.nominal_concurrency_shares);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LimitedPriorityLevelConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -146,6 +202,52 @@ impl crate::serde::Serialize for LimitedPriorityLevelConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LimitedPriorityLevelConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.nominal_concurrency_shares {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.limit_response {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.lendable_percent {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.borrowing_limit_percent {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_borrowing_limit_percent: Option<i32> = None;
+        let mut value_lendable_percent: Option<i32> = None;
+        let mut value_limit_response: Option<crate::api::flowcontrol::v1beta3::LimitResponse> = None;
+        let mut value_nominal_concurrency_shares: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_nominal_concurrency_shares, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_limit_response, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_lendable_percent, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_borrowing_limit_percent, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LimitedPriorityLevelConfiguration {
+            borrowing_limit_percent: value_borrowing_limit_percent,
+            lendable_percent: value_lendable_percent,
+            limit_response: value_limit_response,
+            nominal_concurrency_shares: value_nominal_concurrency_shares,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LimitedPriorityLevelConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitedPriorityLevelConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for PriorityLevelConfigurationSpec {
     }
 }
 
+impl crate::Defaulting for PriorityLevelConfigurationSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.exempt);
+        crate::Defaulting::set_defaults(&mut self.limited);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for PriorityLevelConfigurationSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.exempt, &other.exempt, &crate::diff::Path::Field { parent: path, name: "exempt" }, changes);
+        crate::diff::Diff::diff(&self.limited, &other.limited, &crate::diff::Path::Field { parent: path, name: "limited" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl PriorityLevelConfigurationSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PriorityLevelConfigurationSpec> {
+    /// The path of the `exempt` field.
+    pub fn exempt(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::ExemptPriorityLevelConfiguration> {
+        self.child("exempt")
+    }
+
+    /// The path of the `limited` field.
+    pub fn limited(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::LimitedPriorityLevelConfiguration> {
+        self.child("limited")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for PriorityLevelConfigurationSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.exempt);
+        crate::Normalize::normalize(&mut self.limited);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PriorityLevelConfigurationSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +166,44 @@ impl crate::serde::Serialize for PriorityLevelConfigurationSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PriorityLevelConfigurationSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.limited {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.exempt {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_exempt: Option<crate::api::flowcontrol::v1beta3::ExemptPriorityLevelConfiguration> = None;
+        let mut value_limited: Option<crate::api::flowcontrol::v1beta3::LimitedPriorityLevelConfiguration> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_limited, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_exempt, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PriorityLevelConfigurationSpec {
+            exempt: value_exempt,
+            limited: value_limited,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PriorityLevelConfigurationSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PriorityLevelConfigurationSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for Subject {
     }
 }
 
+impl crate::Defaulting for Subject {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.service_account);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for Subject {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.service_account, &other.service_account, &crate::diff::Path::Field { parent: path, name: "serviceAccount" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl Subject {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Subject> {
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::GroupSubject> {
+        self.child("group")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `serviceAccount` field.
+    pub fn service_account(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::ServiceAccountSubject> {
+        self.child("serviceAccount")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<crate::api::flowcontrol::v1beta3::UserSubject> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for Subject {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.service_account);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Subject {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for Subject {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Subject {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.kind, 1, buf);
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.service_account {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group: Option<crate::api::flowcontrol::v1beta3::GroupSubject> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_service_account: Option<crate::api::flowcontrol::v1beta3::ServiceAccountSubject> = None;
+        let mut value_user: Option<crate::api::flowcontrol::v1beta3::UserSubject> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_service_account, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Subject {
+            group: value_group,
+            kind: value_kind.unwrap_or_default(),
+            service_account: value_service_account,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Subject {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Subject {
     fn schema_name() -> std::borrow::Cow<'static, str> {
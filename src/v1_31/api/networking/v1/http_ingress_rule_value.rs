@@ -13,6 +13,48 @@ impl crate::DeepMerge for HTTPIngressRuleValue {
     }
 }
 
+impl crate::Defaulting for HTTPIngressRuleValue {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.paths);
+    }
+}
+
+impl crate::diff::Diff for HTTPIngressRuleValue {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.paths,
+            &other.paths,
+            &crate::diff::Path::Field { parent: path, name: "paths" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl HTTPIngressRuleValue {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HTTPIngressRuleValue> {
+    /// The path of the `paths` field.
+    pub fn paths(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::HTTPIngressPath>> {
+        self.child("paths")
+    }
+}
+
+impl crate::Normalize for HTTPIngressRuleValue {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.paths, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HTTPIngressRuleValue {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +132,32 @@ impl crate::serde::Serialize for HTTPIngressRuleValue {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HTTPIngressRuleValue {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.paths, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_paths: Option<std::vec::Vec<crate::api::networking::v1::HTTPIngressPath>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_paths, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HTTPIngressRuleValue {
+            paths: value_paths.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HTTPIngressRuleValue {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPIngressRuleValue {
     fn schema_name() -> std::borrow::Cow<'static, str> {
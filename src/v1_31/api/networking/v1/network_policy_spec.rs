@@ -25,6 +25,93 @@ impl crate::DeepMerge for NetworkPolicySpec {
     }
 }
 
+impl crate::Defaulting for NetworkPolicySpec {
+    fn set_defaults(&mut self) {
+        crate::defaulting_strategies::networking_v1::network_policy_spec(self);
+        crate::Defaulting::set_defaults(&mut self.egress);
+        crate::Defaulting::set_defaults(&mut self.ingress);
+        crate::Defaulting::set_defaults(&mut self.pod_selector);
+        crate::Defaulting::set_defaults(&mut self.policy_types);
+    }
+}
+
+impl crate::diff::Diff for NetworkPolicySpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.egress,
+            &other.egress,
+            &crate::diff::Path::Field { parent: path, name: "egress" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.ingress,
+            &other.ingress,
+            &crate::diff::Path::Field { parent: path, name: "ingress" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.pod_selector, &other.pod_selector, &crate::diff::Path::Field { parent: path, name: "podSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.policy_types,
+            &other.policy_types,
+            &crate::diff::Path::Field { parent: path, name: "policyTypes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NetworkPolicySpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NetworkPolicySpec> {
+    /// The path of the `egress` field.
+    pub fn egress(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::NetworkPolicyEgressRule>> {
+        self.child("egress")
+    }
+
+    /// The path of the `ingress` field.
+    pub fn ingress(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::NetworkPolicyIngressRule>> {
+        self.child("ingress")
+    }
+
+    /// The path of the `podSelector` field.
+    pub fn pod_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("podSelector")
+    }
+
+    /// The path of the `policyTypes` field.
+    pub fn policy_types(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("policyTypes")
+    }
+}
+
+impl crate::Normalize for NetworkPolicySpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.egress, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.ingress, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.pod_selector);
+        crate::normalize_strategies::list::atomic(&mut self.policy_types, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NetworkPolicySpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +221,52 @@ impl crate::serde::Serialize for NetworkPolicySpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NetworkPolicySpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.pod_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.ingress {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.egress {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.policy_types {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_egress: Option<std::vec::Vec<crate::api::networking::v1::NetworkPolicyEgressRule>> = None;
+        let mut value_ingress: Option<std::vec::Vec<crate::api::networking::v1::NetworkPolicyIngressRule>> = None;
+        let mut value_pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_policy_types: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pod_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_ingress, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_egress, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_policy_types, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NetworkPolicySpec {
+            egress: value_egress,
+            ingress: value_ingress,
+            pod_selector: value_pod_selector,
+            policy_types: value_policy_types,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NetworkPolicySpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicySpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
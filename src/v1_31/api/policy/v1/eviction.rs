@@ -38,6 +38,50 @@ impl crate::DeepMerge for Eviction {
     }
 }
 
+impl crate::Defaulting for Eviction {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.delete_options);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+    }
+}
+
+impl crate::diff::Diff for Eviction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.delete_options, &other.delete_options, &crate::diff::Path::Field { parent: path, name: "deleteOptions" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+    }
+}
+
+impl Eviction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Eviction> {
+    /// The path of the `deleteOptions` field.
+    pub fn delete_options(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::DeleteOptions> {
+        self.child("deleteOptions")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+}
+
+impl crate::Normalize for Eviction {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.delete_options);
+        crate::Normalize::normalize(&mut self.metadata);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Eviction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -145,6 +189,48 @@ impl crate::serde::Serialize for Eviction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Eviction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.delete_options {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_delete_options: Option<crate::apimachinery::pkg::apis::meta::v1::DeleteOptions> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_delete_options, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Eviction {
+            delete_options: value_delete_options,
+            metadata: value_metadata.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Eviction {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Eviction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
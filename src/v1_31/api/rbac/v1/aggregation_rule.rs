@@ -13,6 +13,48 @@ impl crate::DeepMerge for AggregationRule {
     }
 }
 
+impl crate::Defaulting for AggregationRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.cluster_role_selectors);
+    }
+}
+
+impl crate::diff::Diff for AggregationRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.cluster_role_selectors,
+            &other.cluster_role_selectors,
+            &crate::diff::Path::Field { parent: path, name: "clusterRoleSelectors" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl AggregationRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AggregationRule> {
+    /// The path of the `clusterRoleSelectors` field.
+    pub fn cluster_role_selectors(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>> {
+        self.child("clusterRoleSelectors")
+    }
+}
+
+impl crate::Normalize for AggregationRule {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.cluster_role_selectors, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AggregationRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for AggregationRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AggregationRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.cluster_role_selectors {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_cluster_role_selectors: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelector>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_cluster_role_selectors, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AggregationRule {
+            cluster_role_selectors: value_cluster_role_selectors,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AggregationRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AggregationRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
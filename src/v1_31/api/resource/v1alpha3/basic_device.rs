@@ -25,6 +25,66 @@ impl crate::DeepMerge for BasicDevice {
     }
 }
 
+impl crate::Defaulting for BasicDevice {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.attributes);
+        crate::Defaulting::set_defaults(&mut self.capacity);
+    }
+}
+
+impl crate::diff::Diff for BasicDevice {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.attributes,
+            &other.attributes,
+            &crate::diff::Path::Field { parent: path, name: "attributes" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.capacity,
+            &other.capacity,
+            &crate::diff::Path::Field { parent: path, name: "capacity" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl BasicDevice {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<BasicDevice> {
+    /// The path of the `attributes` field.
+    pub fn attributes(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::api::resource::v1alpha3::DeviceAttribute>> {
+        self.child("attributes")
+    }
+
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("capacity")
+    }
+}
+
+impl crate::Normalize for BasicDevice {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.attributes, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.capacity, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for BasicDevice {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -114,6 +174,40 @@ impl crate::serde::Serialize for BasicDevice {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for BasicDevice {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.attributes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_attributes: Option<std::collections::BTreeMap<std::string::String, crate::api::resource::v1alpha3::DeviceAttribute>> = None;
+        let mut value_capacity: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_attributes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(BasicDevice {
+            attributes: value_attributes,
+            capacity: value_capacity,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for BasicDevice {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for BasicDevice {
     fn schema_name() -> std::borrow::Cow<'static, str> {
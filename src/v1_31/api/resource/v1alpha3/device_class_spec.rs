@@ -27,6 +27,74 @@ impl crate::DeepMerge for DeviceClassSpec {
     }
 }
 
+impl crate::Defaulting for DeviceClassSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config);
+        crate::Defaulting::set_defaults(&mut self.selectors);
+        crate::Defaulting::set_defaults(&mut self.suitable_nodes);
+    }
+}
+
+impl crate::diff::Diff for DeviceClassSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.config,
+            &other.config,
+            &crate::diff::Path::Field { parent: path, name: "config" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.selectors,
+            &other.selectors,
+            &crate::diff::Path::Field { parent: path, name: "selectors" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.suitable_nodes, &other.suitable_nodes, &crate::diff::Path::Field { parent: path, name: "suitableNodes" }, changes);
+    }
+}
+
+impl DeviceClassSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceClassSpec> {
+    /// The path of the `config` field.
+    pub fn config(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceClassConfiguration>> {
+        self.child("config")
+    }
+
+    /// The path of the `selectors` field.
+    pub fn selectors(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceSelector>> {
+        self.child("selectors")
+    }
+
+    /// The path of the `suitableNodes` field.
+    pub fn suitable_nodes(self) -> crate::FieldPath<crate::api::core::v1::NodeSelector> {
+        self.child("suitableNodes")
+    }
+}
+
+impl crate::Normalize for DeviceClassSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.config, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.selectors, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.suitable_nodes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceClassSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -126,6 +194,46 @@ impl crate::serde::Serialize for DeviceClassSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceClassSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.selectors {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.config {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.suitable_nodes {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceClassConfiguration>> = None;
+        let mut value_selectors: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceSelector>> = None;
+        let mut value_suitable_nodes: Option<crate::api::core::v1::NodeSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_selectors, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_config, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_suitable_nodes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceClassSpec {
+            config: value_config,
+            selectors: value_selectors,
+            suitable_nodes: value_suitable_nodes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceClassSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceClassSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,46 @@ impl crate::DeepMerge for OpaqueDeviceConfiguration {
     }
 }
 
+impl crate::Defaulting for OpaqueDeviceConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.driver);
+        crate::Defaulting::set_defaults(&mut self.parameters);
+    }
+}
+
+impl crate::diff::Diff for OpaqueDeviceConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.driver, &other.driver, &crate::diff::Path::Field { parent: path, name: "driver" }, changes);
+        crate::diff::Diff::diff(&self.parameters, &other.parameters, &crate::diff::Path::Field { parent: path, name: "parameters" }, changes);
+    }
+}
+
+impl OpaqueDeviceConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<OpaqueDeviceConfiguration> {
+    /// The path of the `driver` field.
+    pub fn driver(self) -> crate::FieldPath<std::string::String> {
+        self.child("driver")
+    }
+
+    /// The path of the `parameters` field.
+    pub fn parameters(self) -> crate::FieldPath<crate::apimachinery::pkg::runtime::RawExtension> {
+        self.child("parameters")
+    }
+}
+
+impl crate::Normalize for OpaqueDeviceConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.driver);
+        crate::Normalize::normalize(&mut self.parameters);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for OpaqueDeviceConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -105,6 +145,36 @@ impl crate::serde::Serialize for OpaqueDeviceConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for OpaqueDeviceConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.driver, 1, buf);
+        crate::protobuf::Field::encode_field(&self.parameters, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_driver: Option<std::string::String> = None;
+        let mut value_parameters: Option<crate::apimachinery::pkg::runtime::RawExtension> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_driver, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_parameters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(OpaqueDeviceConfiguration {
+            driver: value_driver.unwrap_or_default(),
+            parameters: value_parameters.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for OpaqueDeviceConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for OpaqueDeviceConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -19,6 +19,56 @@ impl crate::DeepMerge for PodSchedulingContextSpec {
     }
 }
 
+impl crate::Defaulting for PodSchedulingContextSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.potential_nodes);
+        crate::Defaulting::set_defaults(&mut self.selected_node);
+    }
+}
+
+impl crate::diff::Diff for PodSchedulingContextSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.potential_nodes,
+            &other.potential_nodes,
+            &crate::diff::Path::Field { parent: path, name: "potentialNodes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.selected_node, &other.selected_node, &crate::diff::Path::Field { parent: path, name: "selectedNode" }, changes);
+    }
+}
+
+impl PodSchedulingContextSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodSchedulingContextSpec> {
+    /// The path of the `potentialNodes` field.
+    pub fn potential_nodes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("potentialNodes")
+    }
+
+    /// The path of the `selectedNode` field.
+    pub fn selected_node(self) -> crate::FieldPath<std::string::String> {
+        self.child("selectedNode")
+    }
+}
+
+impl crate::Normalize for PodSchedulingContextSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.potential_nodes, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.selected_node);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodSchedulingContextSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -108,6 +158,40 @@ impl crate::serde::Serialize for PodSchedulingContextSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodSchedulingContextSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.selected_node {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.potential_nodes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_potential_nodes: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_selected_node: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_selected_node, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_potential_nodes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodSchedulingContextSpec {
+            potential_nodes: value_potential_nodes,
+            selected_node: value_selected_node,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodSchedulingContextSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSchedulingContextSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -20,6 +20,49 @@ impl crate::DeepMerge for PodSchedulingContextStatus {
     }
 }
 
+impl crate::Defaulting for PodSchedulingContextStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.resource_claims);
+    }
+}
+
+impl crate::diff::Diff for PodSchedulingContextStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.resource_claims,
+            &other.resource_claims,
+            &crate::diff::Path::Field { parent: path, name: "resourceClaims" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodSchedulingContextStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodSchedulingContextStatus> {
+    /// The path of the `resourceClaims` field.
+    pub fn resource_claims(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::ResourceClaimSchedulingStatus>> {
+        self.child("resourceClaims")
+    }
+}
+
+impl crate::Normalize for PodSchedulingContextStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.resource_claims, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodSchedulingContextStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -99,6 +142,44 @@ impl crate::serde::Serialize for PodSchedulingContextStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodSchedulingContextStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.resource_claims {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_resource_claims: Option<std::vec::Vec<crate::api::resource::v1alpha3::ResourceClaimSchedulingStatus>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_claims, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodSchedulingContextStatus {
+            resource_claims: value_resource_claims,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodSchedulingContextStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "resourceClaims" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSchedulingContextStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
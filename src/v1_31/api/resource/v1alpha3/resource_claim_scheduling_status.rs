@@ -19,6 +19,56 @@ impl crate::DeepMerge for ResourceClaimSchedulingStatus {
     }
 }
 
+impl crate::Defaulting for ResourceClaimSchedulingStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.unsuitable_nodes);
+    }
+}
+
+impl crate::diff::Diff for ResourceClaimSchedulingStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.unsuitable_nodes,
+            &other.unsuitable_nodes,
+            &crate::diff::Path::Field { parent: path, name: "unsuitableNodes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceClaimSchedulingStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceClaimSchedulingStatus> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `unsuitableNodes` field.
+    pub fn unsuitable_nodes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("unsuitableNodes")
+    }
+}
+
+impl crate::Normalize for ResourceClaimSchedulingStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::normalize_strategies::list::atomic(&mut self.unsuitable_nodes, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceClaimSchedulingStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +156,38 @@ impl crate::serde::Serialize for ResourceClaimSchedulingStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceClaimSchedulingStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.unsuitable_nodes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_unsuitable_nodes: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_unsuitable_nodes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceClaimSchedulingStatus {
+            name: value_name.unwrap_or_default(),
+            unsuitable_nodes: value_unsuitable_nodes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceClaimSchedulingStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceClaimSchedulingStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
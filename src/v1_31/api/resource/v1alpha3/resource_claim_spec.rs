@@ -21,6 +21,46 @@ impl crate::DeepMerge for ResourceClaimSpec {
     }
 }
 
+impl crate::Defaulting for ResourceClaimSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.controller);
+        crate::Defaulting::set_defaults(&mut self.devices);
+    }
+}
+
+impl crate::diff::Diff for ResourceClaimSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.controller, &other.controller, &crate::diff::Path::Field { parent: path, name: "controller" }, changes);
+        crate::diff::Diff::diff(&self.devices, &other.devices, &crate::diff::Path::Field { parent: path, name: "devices" }, changes);
+    }
+}
+
+impl ResourceClaimSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceClaimSpec> {
+    /// The path of the `controller` field.
+    pub fn controller(self) -> crate::FieldPath<std::string::String> {
+        self.child("controller")
+    }
+
+    /// The path of the `devices` field.
+    pub fn devices(self) -> crate::FieldPath<crate::api::resource::v1alpha3::DeviceClaim> {
+        self.child("devices")
+    }
+}
+
+impl crate::Normalize for ResourceClaimSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.controller);
+        crate::Normalize::normalize(&mut self.devices);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceClaimSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -110,6 +150,40 @@ impl crate::serde::Serialize for ResourceClaimSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceClaimSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.devices {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.controller {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_controller: Option<std::string::String> = None;
+        let mut value_devices: Option<crate::api::resource::v1alpha3::DeviceClaim> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_devices, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_controller, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceClaimSpec {
+            controller: value_controller,
+            devices: value_devices,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceClaimSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceClaimSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
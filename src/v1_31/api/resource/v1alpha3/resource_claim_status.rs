@@ -38,6 +38,65 @@ impl crate::DeepMerge for ResourceClaimStatus {
     }
 }
 
+impl crate::Defaulting for ResourceClaimStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allocation);
+        crate::Defaulting::set_defaults(&mut self.deallocation_requested);
+        crate::Defaulting::set_defaults(&mut self.reserved_for);
+    }
+}
+
+impl crate::diff::Diff for ResourceClaimStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allocation, &other.allocation, &crate::diff::Path::Field { parent: path, name: "allocation" }, changes);
+        crate::diff::Diff::diff(&self.deallocation_requested, &other.deallocation_requested, &crate::diff::Path::Field { parent: path, name: "deallocationRequested" }, changes);
+        crate::diff::strategies::list::map(
+            &self.reserved_for,
+            &other.reserved_for,
+            &crate::diff::Path::Field { parent: path, name: "reservedFor" },
+            changes,
+            &[("uid", |item| crate::diff::MergeKey::merge_key(&item.uid))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceClaimStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceClaimStatus> {
+    /// The path of the `allocation` field.
+    pub fn allocation(self) -> crate::FieldPath<crate::api::resource::v1alpha3::AllocationResult> {
+        self.child("allocation")
+    }
+
+    /// The path of the `deallocationRequested` field.
+    pub fn deallocation_requested(self) -> crate::FieldPath<bool> {
+        self.child("deallocationRequested")
+    }
+
+    /// The path of the `reservedFor` field.
+    pub fn reserved_for(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::ResourceClaimConsumerReference>> {
+        self.child("reservedFor")
+    }
+}
+
+impl crate::Normalize for ResourceClaimStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allocation);
+        crate::Normalize::normalize(&mut self.deallocation_requested);
+        crate::normalize_strategies::list::map(&mut self.reserved_for, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceClaimStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -137,6 +196,56 @@ impl crate::serde::Serialize for ResourceClaimStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceClaimStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.allocation {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.reserved_for {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.deallocation_requested {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allocation: Option<crate::api::resource::v1alpha3::AllocationResult> = None;
+        let mut value_deallocation_requested: Option<bool> = None;
+        let mut value_reserved_for: Option<std::vec::Vec<crate::api::resource::v1alpha3::ResourceClaimConsumerReference>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_allocation, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_reserved_for, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_deallocation_requested, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceClaimStatus {
+            allocation: value_allocation,
+            deallocation_requested: value_deallocation_requested,
+            reserved_for: value_reserved_for,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceClaimStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "reservedFor" => Some(&["uid"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceClaimStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for TokenRequest {
     }
 }
 
+impl crate::Defaulting for TokenRequest {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.audience);
+        crate::Defaulting::set_defaults(&mut self.expiration_seconds);
+    }
+}
+
+impl crate::diff::Diff for TokenRequest {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.audience, &other.audience, &crate::diff::Path::Field { parent: path, name: "audience" }, changes);
+        crate::diff::Diff::diff(&self.expiration_seconds, &other.expiration_seconds, &crate::diff::Path::Field { parent: path, name: "expirationSeconds" }, changes);
+    }
+}
+
+impl TokenRequest {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TokenRequest> {
+    /// The path of the `audience` field.
+    pub fn audience(self) -> crate::FieldPath<std::string::String> {
+        self.child("audience")
+    }
+
+    /// The path of the `expirationSeconds` field.
+    pub fn expiration_seconds(self) -> crate::FieldPath<i64> {
+        self.child("expirationSeconds")
+    }
+}
+
+impl crate::Normalize for TokenRequest {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.audience);
+        crate::Normalize::normalize(&mut self.expiration_seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TokenRequest {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for TokenRequest {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TokenRequest {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.audience, 1, buf);
+        if let Some(value) = &self.expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audience: Option<std::string::String> = None;
+        let mut value_expiration_seconds: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_audience, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_expiration_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TokenRequest {
+            audience: value_audience.unwrap_or_default(),
+            expiration_seconds: value_expiration_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TokenRequest {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenRequest {
     fn schema_name() -> std::borrow::Cow<'static, str> {
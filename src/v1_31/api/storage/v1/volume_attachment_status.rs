@@ -27,6 +27,72 @@ impl crate::DeepMerge for VolumeAttachmentStatus {
     }
 }
 
+impl crate::Defaulting for VolumeAttachmentStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.attach_error);
+        crate::Defaulting::set_defaults(&mut self.attached);
+        crate::Defaulting::set_defaults(&mut self.attachment_metadata);
+        crate::Defaulting::set_defaults(&mut self.detach_error);
+    }
+}
+
+impl crate::diff::Diff for VolumeAttachmentStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.attach_error, &other.attach_error, &crate::diff::Path::Field { parent: path, name: "attachError" }, changes);
+        crate::diff::Diff::diff(&self.attached, &other.attached, &crate::diff::Path::Field { parent: path, name: "attached" }, changes);
+        crate::diff::strategies::map::values(
+            &self.attachment_metadata,
+            &other.attachment_metadata,
+            &crate::diff::Path::Field { parent: path, name: "attachmentMetadata" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.detach_error, &other.detach_error, &crate::diff::Path::Field { parent: path, name: "detachError" }, changes);
+    }
+}
+
+impl VolumeAttachmentStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeAttachmentStatus> {
+    /// The path of the `attachError` field.
+    pub fn attach_error(self) -> crate::FieldPath<crate::api::storage::v1::VolumeError> {
+        self.child("attachError")
+    }
+
+    /// The path of the `attached` field.
+    pub fn attached(self) -> crate::FieldPath<bool> {
+        self.child("attached")
+    }
+
+    /// The path of the `attachmentMetadata` field.
+    pub fn attachment_metadata(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("attachmentMetadata")
+    }
+
+    /// The path of the `detachError` field.
+    pub fn detach_error(self) -> crate::FieldPath<crate::api::storage::v1::VolumeError> {
+        self.child("detachError")
+    }
+}
+
+impl crate::Normalize for VolumeAttachmentStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.attach_error);
+        crate::Normalize::normalize(&mut self.attached);
+        crate::normalize_strategies::map::values(&mut self.attachment_metadata, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.detach_error);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +200,50 @@ impl crate::serde::Serialize for VolumeAttachmentStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeAttachmentStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.attached, 1, buf);
+        if let Some(value) = &self.attachment_metadata {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.attach_error {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.detach_error {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_attach_error: Option<crate::api::storage::v1::VolumeError> = None;
+        let mut value_attached: Option<bool> = None;
+        let mut value_attachment_metadata: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_detach_error: Option<crate::api::storage::v1::VolumeError> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_attached, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_attachment_metadata, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_attach_error, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_detach_error, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeAttachmentStatus {
+            attach_error: value_attach_error,
+            attached: value_attached.unwrap_or_default(),
+            attachment_metadata: value_attachment_metadata,
+            detach_error: value_detach_error,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeAttachmentStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for VolumeNodeResources {
     }
 }
 
+impl crate::Defaulting for VolumeNodeResources {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.count);
+    }
+}
+
+impl crate::diff::Diff for VolumeNodeResources {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.count, &other.count, &crate::diff::Path::Field { parent: path, name: "count" }, changes);
+    }
+}
+
+impl VolumeNodeResources {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeNodeResources> {
+    /// The path of the `count` field.
+    pub fn count(self) -> crate::FieldPath<i32> {
+        self.child("count")
+    }
+}
+
+impl crate::Normalize for VolumeNodeResources {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.count);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeNodeResources {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for VolumeNodeResources {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeNodeResources {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.count {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_count: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_count, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeNodeResources {
+            count: value_count,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeNodeResources {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeNodeResources {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -24,6 +24,57 @@ impl crate::DeepMerge for StorageVersionMigrationStatus {
     }
 }
 
+impl crate::Defaulting for StorageVersionMigrationStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.resource_version);
+    }
+}
+
+impl crate::diff::Diff for StorageVersionMigrationStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.resource_version, &other.resource_version, &crate::diff::Path::Field { parent: path, name: "resourceVersion" }, changes);
+    }
+}
+
+impl StorageVersionMigrationStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StorageVersionMigrationStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::storagemigration::v1alpha1::MigrationCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `resourceVersion` field.
+    pub fn resource_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceVersion")
+    }
+}
+
+impl crate::Normalize for StorageVersionMigrationStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.resource_version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StorageVersionMigrationStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -113,6 +164,50 @@ impl crate::serde::Serialize for StorageVersionMigrationStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StorageVersionMigrationStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.resource_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::api::storagemigration::v1alpha1::MigrationCondition>> = None;
+        let mut value_resource_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_version, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StorageVersionMigrationStatus {
+            conditions: value_conditions,
+            resource_version: value_resource_version,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StorageVersionMigrationStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StorageVersionMigrationStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
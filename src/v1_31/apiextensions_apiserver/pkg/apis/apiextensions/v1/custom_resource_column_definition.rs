@@ -33,6 +33,78 @@ impl crate::DeepMerge for CustomResourceColumnDefinition {
     }
 }
 
+impl crate::Defaulting for CustomResourceColumnDefinition {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.description);
+        crate::Defaulting::set_defaults(&mut self.format);
+        crate::Defaulting::set_defaults(&mut self.json_path);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.priority);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceColumnDefinition {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.description, &other.description, &crate::diff::Path::Field { parent: path, name: "description" }, changes);
+        crate::diff::Diff::diff(&self.format, &other.format, &crate::diff::Path::Field { parent: path, name: "format" }, changes);
+        crate::diff::Diff::diff(&self.json_path, &other.json_path, &crate::diff::Path::Field { parent: path, name: "jsonPath" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.priority, &other.priority, &crate::diff::Path::Field { parent: path, name: "priority" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl CustomResourceColumnDefinition {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceColumnDefinition> {
+    /// The path of the `description` field.
+    pub fn description(self) -> crate::FieldPath<std::string::String> {
+        self.child("description")
+    }
+
+    /// The path of the `format` field.
+    pub fn format(self) -> crate::FieldPath<std::string::String> {
+        self.child("format")
+    }
+
+    /// The path of the `jsonPath` field.
+    pub fn json_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("jsonPath")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `priority` field.
+    pub fn priority(self) -> crate::FieldPath<i32> {
+        self.child("priority")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for CustomResourceColumnDefinition {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.description);
+        crate::Normalize::normalize(&mut self.format);
+        crate::Normalize::normalize(&mut self.json_path);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.priority);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceColumnDefinition {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -154,6 +226,58 @@ impl crate::serde::Serialize for CustomResourceColumnDefinition {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceColumnDefinition {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.type_, 2, buf);
+        if let Some(value) = &self.format {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.description {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.priority {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.json_path, 6, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_description: Option<std::string::String> = None;
+        let mut value_format: Option<std::string::String> = None;
+        let mut value_json_path: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_priority: Option<i32> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_format, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_description, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_priority, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_json_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceColumnDefinition {
+            description: value_description,
+            format: value_format,
+            json_path: value_json_path.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            priority: value_priority,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceColumnDefinition {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceColumnDefinition {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -18,6 +18,46 @@ impl crate::DeepMerge for CustomResourceConversion {
     }
 }
 
+impl crate::Defaulting for CustomResourceConversion {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.strategy);
+        crate::Defaulting::set_defaults(&mut self.webhook);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceConversion {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.strategy, &other.strategy, &crate::diff::Path::Field { parent: path, name: "strategy" }, changes);
+        crate::diff::Diff::diff(&self.webhook, &other.webhook, &crate::diff::Path::Field { parent: path, name: "webhook" }, changes);
+    }
+}
+
+impl CustomResourceConversion {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceConversion> {
+    /// The path of the `strategy` field.
+    pub fn strategy(self) -> crate::FieldPath<std::string::String> {
+        self.child("strategy")
+    }
+
+    /// The path of the `webhook` field.
+    pub fn webhook(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion> {
+        self.child("webhook")
+    }
+}
+
+impl crate::Normalize for CustomResourceConversion {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.strategy);
+        crate::Normalize::normalize(&mut self.webhook);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceConversion {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -105,6 +145,38 @@ impl crate::serde::Serialize for CustomResourceConversion {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceConversion {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.strategy, 1, buf);
+        if let Some(value) = &self.webhook {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_strategy: Option<std::string::String> = None;
+        let mut value_webhook: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_strategy, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_webhook, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceConversion {
+            strategy: value_strategy.unwrap_or_default(),
+            webhook: value_webhook,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceConversion {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceConversion {
     fn schema_name() -> std::borrow::Cow<'static, str> {
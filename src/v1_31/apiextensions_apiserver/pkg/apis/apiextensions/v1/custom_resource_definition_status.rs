@@ -28,6 +28,75 @@ impl crate::DeepMerge for CustomResourceDefinitionStatus {
     }
 }
 
+impl crate::Defaulting for CustomResourceDefinitionStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.accepted_names);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.stored_versions);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceDefinitionStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.accepted_names, &other.accepted_names, &crate::diff::Path::Field { parent: path, name: "acceptedNames" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.stored_versions,
+            &other.stored_versions,
+            &crate::diff::Path::Field { parent: path, name: "storedVersions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl CustomResourceDefinitionStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceDefinitionStatus> {
+    /// The path of the `acceptedNames` field.
+    pub fn accepted_names(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames> {
+        self.child("acceptedNames")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `storedVersions` field.
+    pub fn stored_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("storedVersions")
+    }
+}
+
+impl crate::Normalize for CustomResourceDefinitionStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.accepted_names);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.stored_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -127,6 +196,56 @@ impl crate::serde::Serialize for CustomResourceDefinitionStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceDefinitionStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.accepted_names {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.stored_versions {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_accepted_names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition>> = None;
+        let mut value_stored_versions: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_accepted_names, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_stored_versions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceDefinitionStatus {
+            accepted_names: value_accepted_names,
+            conditions: value_conditions,
+            stored_versions: value_stored_versions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceDefinitionStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for CustomResourceSubresourceScale {
     }
 }
 
+impl crate::Defaulting for CustomResourceSubresourceScale {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.label_selector_path);
+        crate::Defaulting::set_defaults(&mut self.spec_replicas_path);
+        crate::Defaulting::set_defaults(&mut self.status_replicas_path);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceSubresourceScale {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.label_selector_path, &other.label_selector_path, &crate::diff::Path::Field { parent: path, name: "labelSelectorPath" }, changes);
+        crate::diff::Diff::diff(&self.spec_replicas_path, &other.spec_replicas_path, &crate::diff::Path::Field { parent: path, name: "specReplicasPath" }, changes);
+        crate::diff::Diff::diff(&self.status_replicas_path, &other.status_replicas_path, &crate::diff::Path::Field { parent: path, name: "statusReplicasPath" }, changes);
+    }
+}
+
+impl CustomResourceSubresourceScale {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceSubresourceScale> {
+    /// The path of the `labelSelectorPath` field.
+    pub fn label_selector_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("labelSelectorPath")
+    }
+
+    /// The path of the `specReplicasPath` field.
+    pub fn spec_replicas_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("specReplicasPath")
+    }
+
+    /// The path of the `statusReplicasPath` field.
+    pub fn status_replicas_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("statusReplicasPath")
+    }
+}
+
+impl crate::Normalize for CustomResourceSubresourceScale {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.label_selector_path);
+        crate::Normalize::normalize(&mut self.spec_replicas_path);
+        crate::Normalize::normalize(&mut self.status_replicas_path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresourceScale {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +163,42 @@ impl crate::serde::Serialize for CustomResourceSubresourceScale {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceSubresourceScale {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.spec_replicas_path, 1, buf);
+        crate::protobuf::Field::encode_field(&self.status_replicas_path, 2, buf);
+        if let Some(value) = &self.label_selector_path {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_label_selector_path: Option<std::string::String> = None;
+        let mut value_spec_replicas_path: Option<std::string::String> = None;
+        let mut value_status_replicas_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_spec_replicas_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_status_replicas_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_label_selector_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceSubresourceScale {
+            label_selector_path: value_label_selector_path,
+            spec_replicas_path: value_spec_replicas_path.unwrap_or_default(),
+            status_replicas_path: value_status_replicas_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceSubresourceScale {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceSubresourceScale {
     fn schema_name() -> std::borrow::Cow<'static, str> {
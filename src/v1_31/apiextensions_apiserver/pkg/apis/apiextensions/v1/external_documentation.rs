@@ -15,6 +15,46 @@ impl crate::DeepMerge for ExternalDocumentation {
     }
 }
 
+impl crate::Defaulting for ExternalDocumentation {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.description);
+        crate::Defaulting::set_defaults(&mut self.url);
+    }
+}
+
+impl crate::diff::Diff for ExternalDocumentation {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.description, &other.description, &crate::diff::Path::Field { parent: path, name: "description" }, changes);
+        crate::diff::Diff::diff(&self.url, &other.url, &crate::diff::Path::Field { parent: path, name: "url" }, changes);
+    }
+}
+
+impl ExternalDocumentation {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ExternalDocumentation> {
+    /// The path of the `description` field.
+    pub fn description(self) -> crate::FieldPath<std::string::String> {
+        self.child("description")
+    }
+
+    /// The path of the `url` field.
+    pub fn url(self) -> crate::FieldPath<std::string::String> {
+        self.child("url")
+    }
+}
+
+impl crate::Normalize for ExternalDocumentation {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.description);
+        crate::Normalize::normalize(&mut self.url);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ExternalDocumentation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,40 @@ impl crate::serde::Serialize for ExternalDocumentation {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ExternalDocumentation {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.description {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.url {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_description: Option<std::string::String> = None;
+        let mut value_url: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_description, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_url, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ExternalDocumentation {
+            description: value_description,
+            url: value_url,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ExternalDocumentation {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExternalDocumentation {
     fn schema_name() -> std::borrow::Cow<'static, str> {
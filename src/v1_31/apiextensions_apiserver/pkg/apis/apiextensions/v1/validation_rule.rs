@@ -71,6 +71,78 @@ impl crate::DeepMerge for ValidationRule {
     }
 }
 
+impl crate::Defaulting for ValidationRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.field_path);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.message_expression);
+        crate::Defaulting::set_defaults(&mut self.optional_old_self);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.rule);
+    }
+}
+
+impl crate::diff::Diff for ValidationRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.field_path, &other.field_path, &crate::diff::Path::Field { parent: path, name: "fieldPath" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.message_expression, &other.message_expression, &crate::diff::Path::Field { parent: path, name: "messageExpression" }, changes);
+        crate::diff::Diff::diff(&self.optional_old_self, &other.optional_old_self, &crate::diff::Path::Field { parent: path, name: "optionalOldSelf" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.rule, &other.rule, &crate::diff::Path::Field { parent: path, name: "rule" }, changes);
+    }
+}
+
+impl ValidationRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidationRule> {
+    /// The path of the `fieldPath` field.
+    pub fn field_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("fieldPath")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `messageExpression` field.
+    pub fn message_expression(self) -> crate::FieldPath<std::string::String> {
+        self.child("messageExpression")
+    }
+
+    /// The path of the `optionalOldSelf` field.
+    pub fn optional_old_self(self) -> crate::FieldPath<bool> {
+        self.child("optionalOldSelf")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `rule` field.
+    pub fn rule(self) -> crate::FieldPath<std::string::String> {
+        self.child("rule")
+    }
+}
+
+impl crate::Normalize for ValidationRule {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.field_path);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.message_expression);
+        crate::Normalize::normalize(&mut self.optional_old_self);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.rule);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidationRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -198,6 +270,62 @@ impl crate::serde::Serialize for ValidationRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidationRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.rule, 1, buf);
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.message_expression {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.field_path {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.optional_old_self {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_field_path: Option<std::string::String> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_message_expression: Option<std::string::String> = None;
+        let mut value_optional_old_self: Option<bool> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_rule: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_rule, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_message_expression, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_field_path, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_optional_old_self, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidationRule {
+            field_path: value_field_path,
+            message: value_message,
+            message_expression: value_message_expression,
+            optional_old_self: value_optional_old_self,
+            reason: value_reason,
+            rule: value_rule.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidationRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidationRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
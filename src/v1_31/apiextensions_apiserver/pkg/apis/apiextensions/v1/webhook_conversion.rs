@@ -17,6 +17,56 @@ impl crate::DeepMerge for WebhookConversion {
     }
 }
 
+impl crate::Defaulting for WebhookConversion {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.client_config);
+        crate::Defaulting::set_defaults(&mut self.conversion_review_versions);
+    }
+}
+
+impl crate::diff::Diff for WebhookConversion {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.client_config, &other.client_config, &crate::diff::Path::Field { parent: path, name: "clientConfig" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.conversion_review_versions,
+            &other.conversion_review_versions,
+            &crate::diff::Path::Field { parent: path, name: "conversionReviewVersions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl WebhookConversion {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WebhookConversion> {
+    /// The path of the `clientConfig` field.
+    pub fn client_config(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig> {
+        self.child("clientConfig")
+    }
+
+    /// The path of the `conversionReviewVersions` field.
+    pub fn conversion_review_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("conversionReviewVersions")
+    }
+}
+
+impl crate::Normalize for WebhookConversion {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.client_config);
+        crate::normalize_strategies::list::atomic(&mut self.conversion_review_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WebhookConversion {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +154,38 @@ impl crate::serde::Serialize for WebhookConversion {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WebhookConversion {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.client_config {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.conversion_review_versions, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_client_config: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig> = None;
+        let mut value_conversion_review_versions: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_client_config, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_conversion_review_versions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WebhookConversion {
+            client_config: value_client_config,
+            conversion_review_versions: value_conversion_review_versions.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WebhookConversion {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WebhookConversion {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -46,6 +46,23 @@ impl crate::DeepMerge for Quantity {
     }
 }
 
+impl crate::Defaulting for Quantity {
+    fn set_defaults(&mut self) {
+    }
+}
+
+impl crate::Normalize for Quantity {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::quantity(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for Quantity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Quantity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -92,6 +109,27 @@ impl crate::serde::Serialize for Quantity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Quantity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.0, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_string: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_string, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Quantity(value_string.unwrap_or_default()))
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Quantity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -49,6 +49,140 @@ impl crate::DeepMerge for APIResource {
     }
 }
 
+impl crate::Defaulting for APIResource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.categories);
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespaced);
+        crate::Defaulting::set_defaults(&mut self.short_names);
+        crate::Defaulting::set_defaults(&mut self.singular_name);
+        crate::Defaulting::set_defaults(&mut self.storage_version_hash);
+        crate::Defaulting::set_defaults(&mut self.verbs);
+        crate::Defaulting::set_defaults(&mut self.version);
+    }
+}
+
+impl crate::diff::Diff for APIResource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.categories,
+            &other.categories,
+            &crate::diff::Path::Field { parent: path, name: "categories" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespaced, &other.namespaced, &crate::diff::Path::Field { parent: path, name: "namespaced" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.short_names,
+            &other.short_names,
+            &crate::diff::Path::Field { parent: path, name: "shortNames" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.singular_name, &other.singular_name, &crate::diff::Path::Field { parent: path, name: "singularName" }, changes);
+        crate::diff::Diff::diff(&self.storage_version_hash, &other.storage_version_hash, &crate::diff::Path::Field { parent: path, name: "storageVersionHash" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.verbs,
+            &other.verbs,
+            &crate::diff::Path::Field { parent: path, name: "verbs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+    }
+}
+
+impl APIResource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIResource> {
+    /// The path of the `categories` field.
+    pub fn categories(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("categories")
+    }
+
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespaced` field.
+    pub fn namespaced(self) -> crate::FieldPath<bool> {
+        self.child("namespaced")
+    }
+
+    /// The path of the `shortNames` field.
+    pub fn short_names(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("shortNames")
+    }
+
+    /// The path of the `singularName` field.
+    pub fn singular_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("singularName")
+    }
+
+    /// The path of the `storageVersionHash` field.
+    pub fn storage_version_hash(self) -> crate::FieldPath<std::string::String> {
+        self.child("storageVersionHash")
+    }
+
+    /// The path of the `verbs` field.
+    pub fn verbs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("verbs")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+}
+
+impl crate::Normalize for APIResource {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.categories, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespaced);
+        crate::normalize_strategies::list::atomic(&mut self.short_names, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.singular_name);
+        crate::Normalize::normalize(&mut self.storage_version_hash);
+        crate::normalize_strategies::list::atomic(&mut self.verbs, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIResource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -204,6 +338,78 @@ impl crate::serde::Serialize for APIResource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIResource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.namespaced, 2, buf);
+        crate::protobuf::Field::encode_field(&self.kind, 3, buf);
+        crate::protobuf::Field::encode_field(&self.verbs, 4, buf);
+        if let Some(value) = &self.short_names {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.singular_name, 6, buf);
+        if let Some(value) = &self.categories {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.version {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.storage_version_hash {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_categories: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespaced: Option<bool> = None;
+        let mut value_short_names: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_singular_name: Option<std::string::String> = None;
+        let mut value_storage_version_hash: Option<std::string::String> = None;
+        let mut value_verbs: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_namespaced, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_verbs, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_short_names, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_singular_name, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_categories, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_storage_version_hash, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIResource {
+            categories: value_categories,
+            group: value_group,
+            kind: value_kind.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            namespaced: value_namespaced.unwrap_or_default(),
+            short_names: value_short_names,
+            singular_name: value_singular_name.unwrap_or_default(),
+            storage_version_hash: value_storage_version_hash,
+            verbs: value_verbs.unwrap_or_default(),
+            version: value_version,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIResource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIResource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
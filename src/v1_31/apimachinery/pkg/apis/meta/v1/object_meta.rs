@@ -94,6 +94,204 @@ impl crate::DeepMerge for ObjectMeta {
     }
 }
 
+impl crate::Defaulting for ObjectMeta {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.annotations);
+        crate::Defaulting::set_defaults(&mut self.creation_timestamp);
+        crate::Defaulting::set_defaults(&mut self.deletion_grace_period_seconds);
+        crate::Defaulting::set_defaults(&mut self.deletion_timestamp);
+        crate::Defaulting::set_defaults(&mut self.finalizers);
+        crate::Defaulting::set_defaults(&mut self.generate_name);
+        crate::Defaulting::set_defaults(&mut self.generation);
+        crate::Defaulting::set_defaults(&mut self.labels);
+        crate::Defaulting::set_defaults(&mut self.managed_fields);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.owner_references);
+        crate::Defaulting::set_defaults(&mut self.resource_version);
+        crate::Defaulting::set_defaults(&mut self.self_link);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for ObjectMeta {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.annotations,
+            &other.annotations,
+            &crate::diff::Path::Field { parent: path, name: "annotations" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.creation_timestamp, &other.creation_timestamp, &crate::diff::Path::Field { parent: path, name: "creationTimestamp" }, changes);
+        crate::diff::Diff::diff(&self.deletion_grace_period_seconds, &other.deletion_grace_period_seconds, &crate::diff::Path::Field { parent: path, name: "deletionGracePeriodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.deletion_timestamp, &other.deletion_timestamp, &crate::diff::Path::Field { parent: path, name: "deletionTimestamp" }, changes);
+        crate::diff::strategies::list::set(&self.finalizers, &other.finalizers, &crate::diff::Path::Field { parent: path, name: "finalizers" }, changes);
+        crate::diff::Diff::diff(&self.generate_name, &other.generate_name, &crate::diff::Path::Field { parent: path, name: "generateName" }, changes);
+        crate::diff::Diff::diff(&self.generation, &other.generation, &crate::diff::Path::Field { parent: path, name: "generation" }, changes);
+        crate::diff::strategies::map::values(
+            &self.labels,
+            &other.labels,
+            &crate::diff::Path::Field { parent: path, name: "labels" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.managed_fields,
+            &other.managed_fields,
+            &crate::diff::Path::Field { parent: path, name: "managedFields" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::strategies::list::map(
+            &self.owner_references,
+            &other.owner_references,
+            &crate::diff::Path::Field { parent: path, name: "ownerReferences" },
+            changes,
+            &[("uid", |item| crate::diff::MergeKey::merge_key(&item.uid))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.resource_version, &other.resource_version, &crate::diff::Path::Field { parent: path, name: "resourceVersion" }, changes);
+        crate::diff::Diff::diff(&self.self_link, &other.self_link, &crate::diff::Path::Field { parent: path, name: "selfLink" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl ObjectMeta {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ObjectMeta> {
+    /// The path of the `annotations` field.
+    pub fn annotations(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("annotations")
+    }
+
+    /// The path of the `creationTimestamp` field.
+    pub fn creation_timestamp(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("creationTimestamp")
+    }
+
+    /// The path of the `deletionGracePeriodSeconds` field.
+    pub fn deletion_grace_period_seconds(self) -> crate::FieldPath<i64> {
+        self.child("deletionGracePeriodSeconds")
+    }
+
+    /// The path of the `deletionTimestamp` field.
+    pub fn deletion_timestamp(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("deletionTimestamp")
+    }
+
+    /// The path of the `finalizers` field.
+    pub fn finalizers(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("finalizers")
+    }
+
+    /// The path of the `generateName` field.
+    pub fn generate_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("generateName")
+    }
+
+    /// The path of the `generation` field.
+    pub fn generation(self) -> crate::FieldPath<i64> {
+        self.child("generation")
+    }
+
+    /// The path of the `labels` field.
+    pub fn labels(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("labels")
+    }
+
+    /// The path of the `managedFields` field.
+    pub fn managed_fields(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry>> {
+        self.child("managedFields")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `ownerReferences` field.
+    pub fn owner_references(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::OwnerReference>> {
+        self.child("ownerReferences")
+    }
+
+    /// The path of the `resourceVersion` field.
+    pub fn resource_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceVersion")
+    }
+
+    /// The path of the `selfLink` field.
+    pub fn self_link(self) -> crate::FieldPath<std::string::String> {
+        self.child("selfLink")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for ObjectMeta {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.annotations, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.creation_timestamp);
+        crate::Normalize::normalize(&mut self.deletion_grace_period_seconds);
+        crate::Normalize::normalize(&mut self.deletion_timestamp);
+        crate::normalize_strategies::list::set(&mut self.finalizers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.generate_name);
+        crate::Normalize::normalize(&mut self.generation);
+        crate::normalize_strategies::map::values(&mut self.labels, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.managed_fields, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::normalize_strategies::list::map(&mut self.owner_references, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.resource_version);
+        crate::Normalize::normalize(&mut self.self_link);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        self.creation_timestamp = None;
+        self.deletion_grace_period_seconds = None;
+        self.deletion_timestamp = None;
+        self.generation = None;
+        self.managed_fields = None;
+        self.resource_version = None;
+        self.self_link = None;
+        self.uid = None;
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ObjectMeta {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -313,6 +511,128 @@ impl crate::serde::Serialize for ObjectMeta {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ObjectMeta {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.generate_name {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.self_link {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.resource_version {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.generation {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.creation_timestamp {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.deletion_timestamp {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.deletion_grace_period_seconds {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.labels {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.annotations {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.owner_references {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.finalizers {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.managed_fields {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_annotations: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_creation_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_deletion_grace_period_seconds: Option<i64> = None;
+        let mut value_deletion_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_finalizers: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_generate_name: Option<std::string::String> = None;
+        let mut value_generation: Option<i64> = None;
+        let mut value_labels: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_managed_fields: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry>> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_owner_references: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::OwnerReference>> = None;
+        let mut value_resource_version: Option<std::string::String> = None;
+        let mut value_self_link: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_generate_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_self_link, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_resource_version, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_generation, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_creation_timestamp, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_deletion_timestamp, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_deletion_grace_period_seconds, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_labels, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_annotations, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_owner_references, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_finalizers, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_managed_fields, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ObjectMeta {
+            annotations: value_annotations,
+            creation_timestamp: value_creation_timestamp,
+            deletion_grace_period_seconds: value_deletion_grace_period_seconds,
+            deletion_timestamp: value_deletion_timestamp,
+            finalizers: value_finalizers,
+            generate_name: value_generate_name,
+            generation: value_generation,
+            labels: value_labels,
+            managed_fields: value_managed_fields,
+            name: value_name,
+            namespace: value_namespace,
+            owner_references: value_owner_references,
+            resource_version: value_resource_version,
+            self_link: value_self_link,
+            uid: value_uid,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ObjectMeta {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "ownerReferences" => Some(&["uid"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMeta {
     fn schema_name() -> std::borrow::Cow<'static, str> {
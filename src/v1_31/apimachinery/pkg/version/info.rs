@@ -36,6 +36,102 @@ impl crate::DeepMerge for Info {
     }
 }
 
+impl crate::Defaulting for Info {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.build_date);
+        crate::Defaulting::set_defaults(&mut self.compiler);
+        crate::Defaulting::set_defaults(&mut self.git_commit);
+        crate::Defaulting::set_defaults(&mut self.git_tree_state);
+        crate::Defaulting::set_defaults(&mut self.git_version);
+        crate::Defaulting::set_defaults(&mut self.go_version);
+        crate::Defaulting::set_defaults(&mut self.major);
+        crate::Defaulting::set_defaults(&mut self.minor);
+        crate::Defaulting::set_defaults(&mut self.platform);
+    }
+}
+
+impl crate::diff::Diff for Info {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.build_date, &other.build_date, &crate::diff::Path::Field { parent: path, name: "buildDate" }, changes);
+        crate::diff::Diff::diff(&self.compiler, &other.compiler, &crate::diff::Path::Field { parent: path, name: "compiler" }, changes);
+        crate::diff::Diff::diff(&self.git_commit, &other.git_commit, &crate::diff::Path::Field { parent: path, name: "gitCommit" }, changes);
+        crate::diff::Diff::diff(&self.git_tree_state, &other.git_tree_state, &crate::diff::Path::Field { parent: path, name: "gitTreeState" }, changes);
+        crate::diff::Diff::diff(&self.git_version, &other.git_version, &crate::diff::Path::Field { parent: path, name: "gitVersion" }, changes);
+        crate::diff::Diff::diff(&self.go_version, &other.go_version, &crate::diff::Path::Field { parent: path, name: "goVersion" }, changes);
+        crate::diff::Diff::diff(&self.major, &other.major, &crate::diff::Path::Field { parent: path, name: "major" }, changes);
+        crate::diff::Diff::diff(&self.minor, &other.minor, &crate::diff::Path::Field { parent: path, name: "minor" }, changes);
+        crate::diff::Diff::diff(&self.platform, &other.platform, &crate::diff::Path::Field { parent: path, name: "platform" }, changes);
+    }
+}
+
+impl Info {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Info> {
+    /// The path of the `buildDate` field.
+    pub fn build_date(self) -> crate::FieldPath<std::string::String> {
+        self.child("buildDate")
+    }
+
+    /// The path of the `compiler` field.
+    pub fn compiler(self) -> crate::FieldPath<std::string::String> {
+        self.child("compiler")
+    }
+
+    /// The path of the `gitCommit` field.
+    pub fn git_commit(self) -> crate::FieldPath<std::string::String> {
+        self.child("gitCommit")
+    }
+
+    /// The path of the `gitTreeState` field.
+    pub fn git_tree_state(self) -> crate::FieldPath<std::string::String> {
+        self.child("gitTreeState")
+    }
+
+    /// The path of the `gitVersion` field.
+    pub fn git_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("gitVersion")
+    }
+
+    /// The path of the `goVersion` field.
+    pub fn go_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("goVersion")
+    }
+
+    /// The path of the `major` field.
+    pub fn major(self) -> crate::FieldPath<std::string::String> {
+        self.child("major")
+    }
+
+    /// The path of the `minor` field.
+    pub fn minor(self) -> crate::FieldPath<std::string::String> {
+        self.child("minor")
+    }
+
+    /// The path of the `platform` field.
+    pub fn platform(self) -> crate::FieldPath<std::string::String> {
+        self.child("platform")
+    }
+}
+
+impl crate::Normalize for Info {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.build_date);
+        crate::Normalize::normalize(&mut self.compiler);
+        crate::Normalize::normalize(&mut self.git_commit);
+        crate::Normalize::normalize(&mut self.git_tree_state);
+        crate::Normalize::normalize(&mut self.git_version);
+        crate::Normalize::normalize(&mut self.go_version);
+        crate::Normalize::normalize(&mut self.major);
+        crate::Normalize::normalize(&mut self.minor);
+        crate::Normalize::normalize(&mut self.platform);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Info {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -169,6 +265,9 @@ impl crate::serde::Serialize for Info {
     }
 }
 
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Info {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Info {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -1,4 +1,7 @@
 
+mod any_resource;
+pub use self::any_resource::AnyResource;
+
 mod list;
 pub use self::list::List;
 
@@ -9,3 +12,9 @@ pub mod apiextensions_apiserver;
 pub mod apimachinery;
 
 pub mod kube_aggregator;
+
+pub mod visit;
+
+pub mod visit_mut;
+
+mod api_resources;
@@ -50,6 +50,62 @@ impl crate::DeepMerge for Validation {
     }
 }
 
+impl crate::Defaulting for Validation {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.expression);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.message_expression);
+        crate::Defaulting::set_defaults(&mut self.reason);
+    }
+}
+
+impl crate::diff::Diff for Validation {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.expression, &other.expression, &crate::diff::Path::Field { parent: path, name: "expression" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.message_expression, &other.message_expression, &crate::diff::Path::Field { parent: path, name: "messageExpression" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+    }
+}
+
+impl Validation {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Validation> {
+    /// The path of the `expression` field.
+    pub fn expression(self) -> crate::FieldPath<std::string::String> {
+        self.child("expression")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `messageExpression` field.
+    pub fn message_expression(self) -> crate::FieldPath<std::string::String> {
+        self.child("messageExpression")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+}
+
+impl crate::Normalize for Validation {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.expression);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.message_expression);
+        crate::Normalize::normalize(&mut self.reason);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Validation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -157,6 +213,50 @@ impl crate::serde::Serialize for Validation {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Validation {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.expression, 1, buf);
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.message_expression {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_expression: Option<std::string::String> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_message_expression: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_expression, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_message_expression, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Validation {
+            expression: value_expression.unwrap_or_default(),
+            message: value_message,
+            message_expression: value_message_expression,
+            reason: value_reason,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Validation {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Validation {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -43,6 +43,62 @@ impl crate::DeepMerge for ParamRef {
     }
 }
 
+impl crate::Defaulting for ParamRef {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.parameter_not_found_action);
+        crate::Defaulting::set_defaults(&mut self.selector);
+    }
+}
+
+impl crate::diff::Diff for ParamRef {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::Diff::diff(&self.parameter_not_found_action, &other.parameter_not_found_action, &crate::diff::Path::Field { parent: path, name: "parameterNotFoundAction" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+    }
+}
+
+impl ParamRef {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ParamRef> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `parameterNotFoundAction` field.
+    pub fn parameter_not_found_action(self) -> crate::FieldPath<std::string::String> {
+        self.child("parameterNotFoundAction")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+}
+
+impl crate::Normalize for ParamRef {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::Normalize::normalize(&mut self.parameter_not_found_action);
+        crate::Normalize::normalize(&mut self.selector);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ParamRef {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -152,6 +208,52 @@ impl crate::serde::Serialize for ParamRef {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ParamRef {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.parameter_not_found_action {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_parameter_not_found_action: Option<std::string::String> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_parameter_not_found_action, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ParamRef {
+            name: value_name,
+            namespace: value_namespace,
+            parameter_not_found_action: value_parameter_not_found_action,
+            selector: value_selector,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ParamRef {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ParamRef {
     fn schema_name() -> std::borrow::Cow<'static, str> {
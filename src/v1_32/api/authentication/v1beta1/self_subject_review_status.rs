@@ -13,6 +13,38 @@ impl crate::DeepMerge for SelfSubjectReviewStatus {
     }
 }
 
+impl crate::Defaulting for SelfSubjectReviewStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.user_info);
+    }
+}
+
+impl crate::diff::Diff for SelfSubjectReviewStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.user_info, &other.user_info, &crate::diff::Path::Field { parent: path, name: "userInfo" }, changes);
+    }
+}
+
+impl SelfSubjectReviewStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SelfSubjectReviewStatus> {
+    /// The path of the `userInfo` field.
+    pub fn user_info(self) -> crate::FieldPath<crate::api::authentication::v1::UserInfo> {
+        self.child("userInfo")
+    }
+}
+
+impl crate::Normalize for SelfSubjectReviewStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.user_info);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SelfSubjectReviewStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for SelfSubjectReviewStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SelfSubjectReviewStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.user_info {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_user_info: Option<crate::api::authentication::v1::UserInfo> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_user_info, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SelfSubjectReviewStatus {
+            user_info: value_user_info,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SelfSubjectReviewStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelfSubjectReviewStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
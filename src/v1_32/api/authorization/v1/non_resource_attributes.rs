@@ -17,6 +17,46 @@ impl crate::DeepMerge for NonResourceAttributes {
     }
 }
 
+impl crate::Defaulting for NonResourceAttributes {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.verb);
+    }
+}
+
+impl crate::diff::Diff for NonResourceAttributes {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.verb, &other.verb, &crate::diff::Path::Field { parent: path, name: "verb" }, changes);
+    }
+}
+
+impl NonResourceAttributes {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NonResourceAttributes> {
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `verb` field.
+    pub fn verb(self) -> crate::FieldPath<std::string::String> {
+        self.child("verb")
+    }
+}
+
+impl crate::Normalize for NonResourceAttributes {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.verb);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NonResourceAttributes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for NonResourceAttributes {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NonResourceAttributes {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.path {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.verb {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_verb: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_verb, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NonResourceAttributes {
+            path: value_path,
+            verb: value_verb,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NonResourceAttributes {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NonResourceAttributes {
     fn schema_name() -> std::borrow::Cow<'static, str> {
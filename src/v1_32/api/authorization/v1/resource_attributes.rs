@@ -49,6 +49,102 @@ impl crate::DeepMerge for ResourceAttributes {
     }
 }
 
+impl crate::Defaulting for ResourceAttributes {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.field_selector);
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.label_selector);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.subresource);
+        crate::Defaulting::set_defaults(&mut self.verb);
+        crate::Defaulting::set_defaults(&mut self.version);
+    }
+}
+
+impl crate::diff::Diff for ResourceAttributes {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.field_selector, &other.field_selector, &crate::diff::Path::Field { parent: path, name: "fieldSelector" }, changes);
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.label_selector, &other.label_selector, &crate::diff::Path::Field { parent: path, name: "labelSelector" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.subresource, &other.subresource, &crate::diff::Path::Field { parent: path, name: "subresource" }, changes);
+        crate::diff::Diff::diff(&self.verb, &other.verb, &crate::diff::Path::Field { parent: path, name: "verb" }, changes);
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+    }
+}
+
+impl ResourceAttributes {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceAttributes> {
+    /// The path of the `fieldSelector` field.
+    pub fn field_selector(self) -> crate::FieldPath<crate::api::authorization::v1::FieldSelectorAttributes> {
+        self.child("fieldSelector")
+    }
+
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `labelSelector` field.
+    pub fn label_selector(self) -> crate::FieldPath<crate::api::authorization::v1::LabelSelectorAttributes> {
+        self.child("labelSelector")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+
+    /// The path of the `subresource` field.
+    pub fn subresource(self) -> crate::FieldPath<std::string::String> {
+        self.child("subresource")
+    }
+
+    /// The path of the `verb` field.
+    pub fn verb(self) -> crate::FieldPath<std::string::String> {
+        self.child("verb")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+}
+
+impl crate::Normalize for ResourceAttributes {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.field_selector);
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.label_selector);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.subresource);
+        crate::Normalize::normalize(&mut self.verb);
+        crate::Normalize::normalize(&mut self.version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceAttributes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -208,6 +304,82 @@ impl crate::serde::Serialize for ResourceAttributes {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceAttributes {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.verb {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.version {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.resource {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.subresource {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.field_selector {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.label_selector {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_field_selector: Option<crate::api::authorization::v1::FieldSelectorAttributes> = None;
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_label_selector: Option<crate::api::authorization::v1::LabelSelectorAttributes> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_resource: Option<std::string::String> = None;
+        let mut value_subresource: Option<std::string::String> = None;
+        let mut value_verb: Option<std::string::String> = None;
+        let mut value_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_verb, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_subresource, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_field_selector, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_label_selector, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceAttributes {
+            field_selector: value_field_selector,
+            group: value_group,
+            label_selector: value_label_selector,
+            name: value_name,
+            namespace: value_namespace,
+            resource: value_resource,
+            subresource: value_subresource,
+            verb: value_verb,
+            version: value_version,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceAttributes {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceAttributes {
     fn schema_name() -> std::borrow::Cow<'static, str> {
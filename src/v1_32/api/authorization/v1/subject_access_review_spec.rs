@@ -35,6 +35,108 @@ impl crate::DeepMerge for SubjectAccessReviewSpec {
     }
 }
 
+impl crate::Defaulting for SubjectAccessReviewSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.extra);
+        crate::Defaulting::set_defaults(&mut self.groups);
+        crate::Defaulting::set_defaults(&mut self.non_resource_attributes);
+        crate::Defaulting::set_defaults(&mut self.resource_attributes);
+        crate::Defaulting::set_defaults(&mut self.uid);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for SubjectAccessReviewSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.extra,
+            &other.extra,
+            &crate::diff::Path::Field { parent: path, name: "extra" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::strategies::list::atomic(
+                    old_value,
+                    new_value,
+                    path,
+                    changes,
+                    |old_item, new_item, path, changes| {
+                        crate::diff::Diff::diff(old_item, new_item, path, changes);
+                    },
+                );
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.groups,
+            &other.groups,
+            &crate::diff::Path::Field { parent: path, name: "groups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.non_resource_attributes, &other.non_resource_attributes, &crate::diff::Path::Field { parent: path, name: "nonResourceAttributes" }, changes);
+        crate::diff::Diff::diff(&self.resource_attributes, &other.resource_attributes, &crate::diff::Path::Field { parent: path, name: "resourceAttributes" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl SubjectAccessReviewSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SubjectAccessReviewSpec> {
+    /// The path of the `extra` field.
+    pub fn extra(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> {
+        self.child("extra")
+    }
+
+    /// The path of the `groups` field.
+    pub fn groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("groups")
+    }
+
+    /// The path of the `nonResourceAttributes` field.
+    pub fn non_resource_attributes(self) -> crate::FieldPath<crate::api::authorization::v1::NonResourceAttributes> {
+        self.child("nonResourceAttributes")
+    }
+
+    /// The path of the `resourceAttributes` field.
+    pub fn resource_attributes(self) -> crate::FieldPath<crate::api::authorization::v1::ResourceAttributes> {
+        self.child("resourceAttributes")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<std::string::String> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for SubjectAccessReviewSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.extra, |value| {
+            crate::normalize_strategies::list::atomic(value, |item| {
+                crate::Normalize::normalize(item);
+            });
+        });
+        crate::normalize_strategies::list::atomic(&mut self.groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.non_resource_attributes);
+        crate::Normalize::normalize(&mut self.resource_attributes);
+        crate::Normalize::normalize(&mut self.uid);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SubjectAccessReviewSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -164,6 +266,64 @@ impl crate::serde::Serialize for SubjectAccessReviewSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SubjectAccessReviewSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.resource_attributes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.non_resource_attributes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.groups {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.extra {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_extra: Option<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> = None;
+        let mut value_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes> = None;
+        let mut value_resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes> = None;
+        let mut value_uid: Option<std::string::String> = None;
+        let mut value_user: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_attributes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_non_resource_attributes, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_groups, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_extra, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SubjectAccessReviewSpec {
+            extra: value_extra,
+            groups: value_groups,
+            non_resource_attributes: value_non_resource_attributes,
+            resource_attributes: value_resource_attributes,
+            uid: value_uid,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SubjectAccessReviewSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SubjectAccessReviewSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
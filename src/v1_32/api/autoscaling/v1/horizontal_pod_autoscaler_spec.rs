@@ -25,6 +25,65 @@ impl crate::DeepMerge for HorizontalPodAutoscalerSpec {
     }
 }
 
+impl crate::Defaulting for HorizontalPodAutoscalerSpec {
+    fn set_defaults(&mut self) {
+        if self.min_replicas.is_none() {
+            self.min_replicas = Some(1);
+        }
+        crate::Defaulting::set_defaults(&mut self.max_replicas);
+        crate::Defaulting::set_defaults(&mut self.min_replicas);
+        crate::Defaulting::set_defaults(&mut self.scale_target_ref);
+        crate::Defaulting::set_defaults(&mut self.target_cpu_utilization_percentage);
+    }
+}
+
+impl crate::diff::Diff for HorizontalPodAutoscalerSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max_replicas, &other.max_replicas, &crate::diff::Path::Field { parent: path, name: "maxReplicas" }, changes);
+        crate::diff::Diff::diff(&self.min_replicas, &other.min_replicas, &crate::diff::Path::Field { parent: path, name: "minReplicas" }, changes);
+        crate::diff::Diff::diff(&self.scale_target_ref, &other.scale_target_ref, &crate::diff::Path::Field { parent: path, name: "scaleTargetRef" }, changes);
+        crate::diff::Diff::diff(&self.target_cpu_utilization_percentage, &other.target_cpu_utilization_percentage, &crate::diff::Path::Field { parent: path, name: "targetCPUUtilizationPercentage" }, changes);
+    }
+}
+
+impl HorizontalPodAutoscalerSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HorizontalPodAutoscalerSpec> {
+    /// The path of the `maxReplicas` field.
+    pub fn max_replicas(self) -> crate::FieldPath<i32> {
+        self.child("maxReplicas")
+    }
+
+    /// The path of the `minReplicas` field.
+    pub fn min_replicas(self) -> crate::FieldPath<i32> {
+        self.child("minReplicas")
+    }
+
+    /// The path of the `scaleTargetRef` field.
+    pub fn scale_target_ref(self) -> crate::FieldPath<crate::api::autoscaling::v1::CrossVersionObjectReference> {
+        self.child("scaleTargetRef")
+    }
+
+    /// The path of the `targetCPUUtilizationPercentage` field.
+    pub fn target_cpu_utilization_percentage(self) -> crate::FieldPath<i32> {
+        self.child("targetCPUUtilizationPercentage")
+    }
+}
+
+impl crate::Normalize for HorizontalPodAutoscalerSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max_replicas);
+        crate::Normalize::normalize(&mut self.min_replicas);
+        crate::Normalize::normalize(&mut self.scale_target_ref);
+        crate::Normalize::normalize(&mut self.target_cpu_utilization_percentage);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +188,48 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HorizontalPodAutoscalerSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.scale_target_ref, 1, buf);
+        if let Some(value) = &self.min_replicas {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.max_replicas, 3, buf);
+        if let Some(value) = &self.target_cpu_utilization_percentage {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max_replicas: Option<i32> = None;
+        let mut value_min_replicas: Option<i32> = None;
+        let mut value_scale_target_ref: Option<crate::api::autoscaling::v1::CrossVersionObjectReference> = None;
+        let mut value_target_cpu_utilization_percentage: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_scale_target_ref, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_min_replicas, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_max_replicas, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_target_cpu_utilization_percentage, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HorizontalPodAutoscalerSpec {
+            max_replicas: value_max_replicas.unwrap_or_default(),
+            min_replicas: value_min_replicas,
+            scale_target_ref: value_scale_target_ref.unwrap_or_default(),
+            target_cpu_utilization_percentage: value_target_cpu_utilization_percentage,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HorizontalPodAutoscalerSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for ScaleSpec {
     }
 }
 
+impl crate::Defaulting for ScaleSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.replicas);
+    }
+}
+
+impl crate::diff::Diff for ScaleSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+    }
+}
+
+impl ScaleSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ScaleSpec> {
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+}
+
+impl crate::Normalize for ScaleSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.replicas);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ScaleSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for ScaleSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ScaleSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.replicas {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_replicas: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ScaleSpec {
+            replicas: value_replicas,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ScaleSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScaleSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for ScaleStatus {
     }
 }
 
+impl crate::Defaulting for ScaleStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.replicas);
+        crate::Defaulting::set_defaults(&mut self.selector);
+    }
+}
+
+impl crate::diff::Diff for ScaleStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+    }
+}
+
+impl ScaleStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ScaleStatus> {
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<std::string::String> {
+        self.child("selector")
+    }
+}
+
+impl crate::Normalize for ScaleStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.replicas);
+        crate::Normalize::normalize(&mut self.selector);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ScaleStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for ScaleStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ScaleStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.replicas, 1, buf);
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_replicas: Option<i32> = None;
+        let mut value_selector: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ScaleStatus {
+            replicas: value_replicas.unwrap_or_default(),
+            selector: value_selector,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ScaleStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScaleStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
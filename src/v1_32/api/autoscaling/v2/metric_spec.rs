@@ -33,6 +33,219 @@ impl crate::DeepMerge for MetricSpec {
     }
 }
 
+impl crate::Defaulting for MetricSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_resource);
+        crate::Defaulting::set_defaults(&mut self.external);
+        crate::Defaulting::set_defaults(&mut self.object);
+        crate::Defaulting::set_defaults(&mut self.pods);
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for MetricSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_resource, &other.container_resource, &crate::diff::Path::Field { parent: path, name: "containerResource" }, changes);
+        crate::diff::Diff::diff(&self.external, &other.external, &crate::diff::Path::Field { parent: path, name: "external" }, changes);
+        crate::diff::Diff::diff(&self.object, &other.object, &crate::diff::Path::Field { parent: path, name: "object" }, changes);
+        crate::diff::Diff::diff(&self.pods, &other.pods, &crate::diff::Path::Field { parent: path, name: "pods" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl MetricSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<MetricSpec> {
+    /// The path of the `containerResource` field.
+    pub fn container_resource(self) -> crate::FieldPath<crate::api::autoscaling::v2::ContainerResourceMetricSource> {
+        self.child("containerResource")
+    }
+
+    /// The path of the `external` field.
+    pub fn external(self) -> crate::FieldPath<crate::api::autoscaling::v2::ExternalMetricSource> {
+        self.child("external")
+    }
+
+    /// The path of the `object` field.
+    pub fn object(self) -> crate::FieldPath<crate::api::autoscaling::v2::ObjectMetricSource> {
+        self.child("object")
+    }
+
+    /// The path of the `pods` field.
+    pub fn pods(self) -> crate::FieldPath<crate::api::autoscaling::v2::PodsMetricSource> {
+        self.child("pods")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<crate::api::autoscaling::v2::ResourceMetricSource> {
+        self.child("resource")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for MetricSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_resource);
+        crate::Normalize::normalize(&mut self.external);
+        crate::Normalize::normalize(&mut self.object);
+        crate::Normalize::normalize(&mut self.pods);
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
+/// The members of the [`MetricSpec`] union. At most one of them may be set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetricSpecUnion {
+    /// The `containerResource` member.
+    ContainerResource(crate::api::autoscaling::v2::ContainerResourceMetricSource),
+
+    /// The `external` member.
+    External(crate::api::autoscaling::v2::ExternalMetricSource),
+
+    /// The `object` member.
+    Object(crate::api::autoscaling::v2::ObjectMetricSource),
+
+    /// The `pods` member.
+    Pods(crate::api::autoscaling::v2::PodsMetricSource),
+
+    /// The `resource` member.
+    Resource(crate::api::autoscaling::v2::ResourceMetricSource),
+}
+
+/// A reference to the member of the [`MetricSpec`] union that is set, as returned by [`MetricSpec::union`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricSpecUnionRef<'a> {
+    /// The `containerResource` member.
+    ContainerResource(&'a crate::api::autoscaling::v2::ContainerResourceMetricSource),
+
+    /// The `external` member.
+    External(&'a crate::api::autoscaling::v2::ExternalMetricSource),
+
+    /// The `object` member.
+    Object(&'a crate::api::autoscaling::v2::ObjectMetricSource),
+
+    /// The `pods` member.
+    Pods(&'a crate::api::autoscaling::v2::PodsMetricSource),
+
+    /// The `resource` member.
+    Resource(&'a crate::api::autoscaling::v2::ResourceMetricSource),
+}
+
+impl MetricSpec {
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set, or if the `type` discriminator names a different member than the one that is set.
+    pub fn union(&self) -> Result<Option<MetricSpecUnionRef<'_>>, crate::UnionError> {
+        let mut members = std::vec![];
+        let mut result = None;
+        if let Some(value) = &self.container_resource {
+            members.push("containerResource");
+            result = Some(MetricSpecUnionRef::ContainerResource(value));
+        }
+        if let Some(value) = &self.external {
+            members.push("external");
+            result = Some(MetricSpecUnionRef::External(value));
+        }
+        if let Some(value) = &self.object {
+            members.push("object");
+            result = Some(MetricSpecUnionRef::Object(value));
+        }
+        if let Some(value) = &self.pods {
+            members.push("pods");
+            result = Some(MetricSpecUnionRef::Pods(value));
+        }
+        if let Some(value) = &self.resource {
+            members.push("resource");
+            result = Some(MetricSpecUnionRef::Resource(value));
+        }
+
+        if members.len() > 1 {
+            return Err(crate::UnionError::MultipleMembersSet(members));
+        }
+
+        let discriminator = &*self.type_;
+        let member = members.first().copied();
+        let discriminated_member = match discriminator {
+            "ContainerResource" => Some("containerResource"),
+            "External" => Some("external"),
+            "Object" => Some("object"),
+            "Pods" => Some("pods"),
+            "Resource" => Some("resource"),
+            _ => member,
+        };
+        if discriminated_member != member {
+            return Err(crate::UnionError::DiscriminatorMismatch { discriminator: discriminator.into(), member });
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the given member of the union, and unsets all the others. The `type` discriminator is set to match, or left unchanged if `value` is `None` since it is required.
+    pub fn set_union(&mut self, value: Option<MetricSpecUnion>) {
+        self.container_resource = None;
+        self.external = None;
+        self.object = None;
+        self.pods = None;
+        self.resource = None;
+
+        match value {
+            Some(MetricSpecUnion::ContainerResource(value)) => {
+                self.container_resource = Some(value);
+                self.type_ = "ContainerResource".into();
+            },
+            Some(MetricSpecUnion::External(value)) => {
+                self.external = Some(value);
+                self.type_ = "External".into();
+            },
+            Some(MetricSpecUnion::Object(value)) => {
+                self.object = Some(value);
+                self.type_ = "Object".into();
+            },
+            Some(MetricSpecUnion::Pods(value)) => {
+                self.pods = Some(value);
+                self.type_ = "Pods".into();
+            },
+            Some(MetricSpecUnion::Resource(value)) => {
+                self.resource = Some(value);
+                self.type_ = "Resource".into();
+            },
+            None => (),
+        }
+    }
+}
+
+impl From<MetricSpecUnion> for MetricSpec {
+    fn from(value: MetricSpecUnion) -> Self {
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }
+}
+
+impl From<MetricSpecUnionRef<'_>> for MetricSpecUnion {
+    fn from(value: MetricSpecUnionRef<'_>) -> Self {
+        match value {
+            MetricSpecUnionRef::ContainerResource(value) => MetricSpecUnion::ContainerResource(value.clone()),
+            MetricSpecUnionRef::External(value) => MetricSpecUnion::External(value.clone()),
+            MetricSpecUnionRef::Object(value) => MetricSpecUnion::Object(value.clone()),
+            MetricSpecUnionRef::Pods(value) => MetricSpecUnion::Pods(value.clone()),
+            MetricSpecUnionRef::Resource(value) => MetricSpecUnion::Resource(value.clone()),
+        }
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MetricSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -160,6 +373,62 @@ impl crate::serde::Serialize for MetricSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MetricSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.object {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.pods {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.resource {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.external {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.container_resource {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_resource: Option<crate::api::autoscaling::v2::ContainerResourceMetricSource> = None;
+        let mut value_external: Option<crate::api::autoscaling::v2::ExternalMetricSource> = None;
+        let mut value_object: Option<crate::api::autoscaling::v2::ObjectMetricSource> = None;
+        let mut value_pods: Option<crate::api::autoscaling::v2::PodsMetricSource> = None;
+        let mut value_resource: Option<crate::api::autoscaling::v2::ResourceMetricSource> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_object, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pods, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_external, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_container_resource, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(MetricSpec {
+            container_resource: value_container_resource,
+            external: value_external,
+            object: value_object,
+            pods: value_pods,
+            resource: value_resource,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for MetricSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
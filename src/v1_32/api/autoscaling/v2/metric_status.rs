@@ -33,6 +33,219 @@ impl crate::DeepMerge for MetricStatus {
     }
 }
 
+impl crate::Defaulting for MetricStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_resource);
+        crate::Defaulting::set_defaults(&mut self.external);
+        crate::Defaulting::set_defaults(&mut self.object);
+        crate::Defaulting::set_defaults(&mut self.pods);
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for MetricStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_resource, &other.container_resource, &crate::diff::Path::Field { parent: path, name: "containerResource" }, changes);
+        crate::diff::Diff::diff(&self.external, &other.external, &crate::diff::Path::Field { parent: path, name: "external" }, changes);
+        crate::diff::Diff::diff(&self.object, &other.object, &crate::diff::Path::Field { parent: path, name: "object" }, changes);
+        crate::diff::Diff::diff(&self.pods, &other.pods, &crate::diff::Path::Field { parent: path, name: "pods" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl MetricStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<MetricStatus> {
+    /// The path of the `containerResource` field.
+    pub fn container_resource(self) -> crate::FieldPath<crate::api::autoscaling::v2::ContainerResourceMetricStatus> {
+        self.child("containerResource")
+    }
+
+    /// The path of the `external` field.
+    pub fn external(self) -> crate::FieldPath<crate::api::autoscaling::v2::ExternalMetricStatus> {
+        self.child("external")
+    }
+
+    /// The path of the `object` field.
+    pub fn object(self) -> crate::FieldPath<crate::api::autoscaling::v2::ObjectMetricStatus> {
+        self.child("object")
+    }
+
+    /// The path of the `pods` field.
+    pub fn pods(self) -> crate::FieldPath<crate::api::autoscaling::v2::PodsMetricStatus> {
+        self.child("pods")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<crate::api::autoscaling::v2::ResourceMetricStatus> {
+        self.child("resource")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for MetricStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_resource);
+        crate::Normalize::normalize(&mut self.external);
+        crate::Normalize::normalize(&mut self.object);
+        crate::Normalize::normalize(&mut self.pods);
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
+/// The members of the [`MetricStatus`] union. At most one of them may be set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetricStatusUnion {
+    /// The `containerResource` member.
+    ContainerResource(crate::api::autoscaling::v2::ContainerResourceMetricStatus),
+
+    /// The `external` member.
+    External(crate::api::autoscaling::v2::ExternalMetricStatus),
+
+    /// The `object` member.
+    Object(crate::api::autoscaling::v2::ObjectMetricStatus),
+
+    /// The `pods` member.
+    Pods(crate::api::autoscaling::v2::PodsMetricStatus),
+
+    /// The `resource` member.
+    Resource(crate::api::autoscaling::v2::ResourceMetricStatus),
+}
+
+/// A reference to the member of the [`MetricStatus`] union that is set, as returned by [`MetricStatus::union`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricStatusUnionRef<'a> {
+    /// The `containerResource` member.
+    ContainerResource(&'a crate::api::autoscaling::v2::ContainerResourceMetricStatus),
+
+    /// The `external` member.
+    External(&'a crate::api::autoscaling::v2::ExternalMetricStatus),
+
+    /// The `object` member.
+    Object(&'a crate::api::autoscaling::v2::ObjectMetricStatus),
+
+    /// The `pods` member.
+    Pods(&'a crate::api::autoscaling::v2::PodsMetricStatus),
+
+    /// The `resource` member.
+    Resource(&'a crate::api::autoscaling::v2::ResourceMetricStatus),
+}
+
+impl MetricStatus {
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set, or if the `type` discriminator names a different member than the one that is set.
+    pub fn union(&self) -> Result<Option<MetricStatusUnionRef<'_>>, crate::UnionError> {
+        let mut members = std::vec![];
+        let mut result = None;
+        if let Some(value) = &self.container_resource {
+            members.push("containerResource");
+            result = Some(MetricStatusUnionRef::ContainerResource(value));
+        }
+        if let Some(value) = &self.external {
+            members.push("external");
+            result = Some(MetricStatusUnionRef::External(value));
+        }
+        if let Some(value) = &self.object {
+            members.push("object");
+            result = Some(MetricStatusUnionRef::Object(value));
+        }
+        if let Some(value) = &self.pods {
+            members.push("pods");
+            result = Some(MetricStatusUnionRef::Pods(value));
+        }
+        if let Some(value) = &self.resource {
+            members.push("resource");
+            result = Some(MetricStatusUnionRef::Resource(value));
+        }
+
+        if members.len() > 1 {
+            return Err(crate::UnionError::MultipleMembersSet(members));
+        }
+
+        let discriminator = &*self.type_;
+        let member = members.first().copied();
+        let discriminated_member = match discriminator {
+            "ContainerResource" => Some("containerResource"),
+            "External" => Some("external"),
+            "Object" => Some("object"),
+            "Pods" => Some("pods"),
+            "Resource" => Some("resource"),
+            _ => member,
+        };
+        if discriminated_member != member {
+            return Err(crate::UnionError::DiscriminatorMismatch { discriminator: discriminator.into(), member });
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the given member of the union, and unsets all the others. The `type` discriminator is set to match, or left unchanged if `value` is `None` since it is required.
+    pub fn set_union(&mut self, value: Option<MetricStatusUnion>) {
+        self.container_resource = None;
+        self.external = None;
+        self.object = None;
+        self.pods = None;
+        self.resource = None;
+
+        match value {
+            Some(MetricStatusUnion::ContainerResource(value)) => {
+                self.container_resource = Some(value);
+                self.type_ = "ContainerResource".into();
+            },
+            Some(MetricStatusUnion::External(value)) => {
+                self.external = Some(value);
+                self.type_ = "External".into();
+            },
+            Some(MetricStatusUnion::Object(value)) => {
+                self.object = Some(value);
+                self.type_ = "Object".into();
+            },
+            Some(MetricStatusUnion::Pods(value)) => {
+                self.pods = Some(value);
+                self.type_ = "Pods".into();
+            },
+            Some(MetricStatusUnion::Resource(value)) => {
+                self.resource = Some(value);
+                self.type_ = "Resource".into();
+            },
+            None => (),
+        }
+    }
+}
+
+impl From<MetricStatusUnion> for MetricStatus {
+    fn from(value: MetricStatusUnion) -> Self {
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }
+}
+
+impl From<MetricStatusUnionRef<'_>> for MetricStatusUnion {
+    fn from(value: MetricStatusUnionRef<'_>) -> Self {
+        match value {
+            MetricStatusUnionRef::ContainerResource(value) => MetricStatusUnion::ContainerResource(value.clone()),
+            MetricStatusUnionRef::External(value) => MetricStatusUnion::External(value.clone()),
+            MetricStatusUnionRef::Object(value) => MetricStatusUnion::Object(value.clone()),
+            MetricStatusUnionRef::Pods(value) => MetricStatusUnion::Pods(value.clone()),
+            MetricStatusUnionRef::Resource(value) => MetricStatusUnion::Resource(value.clone()),
+        }
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MetricStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -160,6 +373,62 @@ impl crate::serde::Serialize for MetricStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MetricStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.object {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.pods {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.resource {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.external {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.container_resource {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_resource: Option<crate::api::autoscaling::v2::ContainerResourceMetricStatus> = None;
+        let mut value_external: Option<crate::api::autoscaling::v2::ExternalMetricStatus> = None;
+        let mut value_object: Option<crate::api::autoscaling::v2::ObjectMetricStatus> = None;
+        let mut value_pods: Option<crate::api::autoscaling::v2::PodsMetricStatus> = None;
+        let mut value_resource: Option<crate::api::autoscaling::v2::ResourceMetricStatus> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_object, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pods, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_external, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_container_resource, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(MetricStatus {
+            container_resource: value_container_resource,
+            external: value_external,
+            object: value_object,
+            pods: value_pods,
+            resource: value_resource,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for MetricStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for MetricTarget {
     }
 }
 
+impl crate::Defaulting for MetricTarget {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.average_utilization);
+        crate::Defaulting::set_defaults(&mut self.average_value);
+        crate::Defaulting::set_defaults(&mut self.type_);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for MetricTarget {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.average_utilization, &other.average_utilization, &crate::diff::Path::Field { parent: path, name: "averageUtilization" }, changes);
+        crate::diff::Diff::diff(&self.average_value, &other.average_value, &crate::diff::Path::Field { parent: path, name: "averageValue" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl MetricTarget {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<MetricTarget> {
+    /// The path of the `averageUtilization` field.
+    pub fn average_utilization(self) -> crate::FieldPath<i32> {
+        self.child("averageUtilization")
+    }
+
+    /// The path of the `averageValue` field.
+    pub fn average_value(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("averageValue")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for MetricTarget {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.average_utilization);
+        crate::Normalize::normalize(&mut self.average_value);
+        crate::Normalize::normalize(&mut self.type_);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MetricTarget {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for MetricTarget {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MetricTarget {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.value {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.average_value {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.average_utilization {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_average_utilization: Option<i32> = None;
+        let mut value_average_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_type_: Option<std::string::String> = None;
+        let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_average_value, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_average_utilization, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(MetricTarget {
+            average_utilization: value_average_utilization,
+            average_value: value_average_value,
+            type_: value_type_.unwrap_or_default(),
+            value: value_value,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for MetricTarget {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MetricTarget {
     fn schema_name() -> std::borrow::Cow<'static, str> {
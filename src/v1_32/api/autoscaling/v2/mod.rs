@@ -39,10 +39,10 @@ mod metric_identifier;
 pub use self::metric_identifier::MetricIdentifier;
 
 mod metric_spec;
-pub use self::metric_spec::MetricSpec;
+pub use self::metric_spec::{MetricSpec, MetricSpecUnion, MetricSpecUnionRef};
 
 mod metric_status;
-pub use self::metric_status::MetricStatus;
+pub use self::metric_status::{MetricStatus, MetricStatusUnion, MetricStatusUnionRef};
 
 mod metric_target;
 pub use self::metric_target::MetricTarget;
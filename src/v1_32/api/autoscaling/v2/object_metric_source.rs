@@ -21,6 +21,54 @@ impl crate::DeepMerge for ObjectMetricSource {
     }
 }
 
+impl crate::Defaulting for ObjectMetricSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.described_object);
+        crate::Defaulting::set_defaults(&mut self.metric);
+        crate::Defaulting::set_defaults(&mut self.target);
+    }
+}
+
+impl crate::diff::Diff for ObjectMetricSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.described_object, &other.described_object, &crate::diff::Path::Field { parent: path, name: "describedObject" }, changes);
+        crate::diff::Diff::diff(&self.metric, &other.metric, &crate::diff::Path::Field { parent: path, name: "metric" }, changes);
+        crate::diff::Diff::diff(&self.target, &other.target, &crate::diff::Path::Field { parent: path, name: "target" }, changes);
+    }
+}
+
+impl ObjectMetricSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ObjectMetricSource> {
+    /// The path of the `describedObject` field.
+    pub fn described_object(self) -> crate::FieldPath<crate::api::autoscaling::v2::CrossVersionObjectReference> {
+        self.child("describedObject")
+    }
+
+    /// The path of the `metric` field.
+    pub fn metric(self) -> crate::FieldPath<crate::api::autoscaling::v2::MetricIdentifier> {
+        self.child("metric")
+    }
+
+    /// The path of the `target` field.
+    pub fn target(self) -> crate::FieldPath<crate::api::autoscaling::v2::MetricTarget> {
+        self.child("target")
+    }
+}
+
+impl crate::Normalize for ObjectMetricSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.described_object);
+        crate::Normalize::normalize(&mut self.metric);
+        crate::Normalize::normalize(&mut self.target);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ObjectMetricSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +160,40 @@ impl crate::serde::Serialize for ObjectMetricSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ObjectMetricSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.described_object, 1, buf);
+        crate::protobuf::Field::encode_field(&self.target, 2, buf);
+        crate::protobuf::Field::encode_field(&self.metric, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_described_object: Option<crate::api::autoscaling::v2::CrossVersionObjectReference> = None;
+        let mut value_metric: Option<crate::api::autoscaling::v2::MetricIdentifier> = None;
+        let mut value_target: Option<crate::api::autoscaling::v2::MetricTarget> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_described_object, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_target, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_metric, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ObjectMetricSource {
+            described_object: value_described_object.unwrap_or_default(),
+            metric: value_metric.unwrap_or_default(),
+            target: value_target.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ObjectMetricSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectMetricSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
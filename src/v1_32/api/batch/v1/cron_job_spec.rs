@@ -43,6 +43,106 @@ impl crate::DeepMerge for CronJobSpec {
     }
 }
 
+impl crate::Defaulting for CronJobSpec {
+    fn set_defaults(&mut self) {
+        if self.concurrency_policy.is_none() {
+            self.concurrency_policy = Some("Allow".into());
+        }
+        if self.failed_jobs_history_limit.is_none() {
+            self.failed_jobs_history_limit = Some(1);
+        }
+        if self.successful_jobs_history_limit.is_none() {
+            self.successful_jobs_history_limit = Some(3);
+        }
+        if self.suspend.is_none() {
+            self.suspend = Some(false);
+        }
+        crate::Defaulting::set_defaults(&mut self.concurrency_policy);
+        crate::Defaulting::set_defaults(&mut self.failed_jobs_history_limit);
+        crate::Defaulting::set_defaults(&mut self.job_template);
+        crate::Defaulting::set_defaults(&mut self.schedule);
+        crate::Defaulting::set_defaults(&mut self.starting_deadline_seconds);
+        crate::Defaulting::set_defaults(&mut self.successful_jobs_history_limit);
+        crate::Defaulting::set_defaults(&mut self.suspend);
+        crate::Defaulting::set_defaults(&mut self.time_zone);
+    }
+}
+
+impl crate::diff::Diff for CronJobSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.concurrency_policy, &other.concurrency_policy, &crate::diff::Path::Field { parent: path, name: "concurrencyPolicy" }, changes);
+        crate::diff::Diff::diff(&self.failed_jobs_history_limit, &other.failed_jobs_history_limit, &crate::diff::Path::Field { parent: path, name: "failedJobsHistoryLimit" }, changes);
+        crate::diff::Diff::diff(&self.job_template, &other.job_template, &crate::diff::Path::Field { parent: path, name: "jobTemplate" }, changes);
+        crate::diff::Diff::diff(&self.schedule, &other.schedule, &crate::diff::Path::Field { parent: path, name: "schedule" }, changes);
+        crate::diff::Diff::diff(&self.starting_deadline_seconds, &other.starting_deadline_seconds, &crate::diff::Path::Field { parent: path, name: "startingDeadlineSeconds" }, changes);
+        crate::diff::Diff::diff(&self.successful_jobs_history_limit, &other.successful_jobs_history_limit, &crate::diff::Path::Field { parent: path, name: "successfulJobsHistoryLimit" }, changes);
+        crate::diff::Diff::diff(&self.suspend, &other.suspend, &crate::diff::Path::Field { parent: path, name: "suspend" }, changes);
+        crate::diff::Diff::diff(&self.time_zone, &other.time_zone, &crate::diff::Path::Field { parent: path, name: "timeZone" }, changes);
+    }
+}
+
+impl CronJobSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CronJobSpec> {
+    /// The path of the `concurrencyPolicy` field.
+    pub fn concurrency_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("concurrencyPolicy")
+    }
+
+    /// The path of the `failedJobsHistoryLimit` field.
+    pub fn failed_jobs_history_limit(self) -> crate::FieldPath<i32> {
+        self.child("failedJobsHistoryLimit")
+    }
+
+    /// The path of the `jobTemplate` field.
+    pub fn job_template(self) -> crate::FieldPath<crate::api::batch::v1::JobTemplateSpec> {
+        self.child("jobTemplate")
+    }
+
+    /// The path of the `schedule` field.
+    pub fn schedule(self) -> crate::FieldPath<std::string::String> {
+        self.child("schedule")
+    }
+
+    /// The path of the `startingDeadlineSeconds` field.
+    pub fn starting_deadline_seconds(self) -> crate::FieldPath<i64> {
+        self.child("startingDeadlineSeconds")
+    }
+
+    /// The path of the `successfulJobsHistoryLimit` field.
+    pub fn successful_jobs_history_limit(self) -> crate::FieldPath<i32> {
+        self.child("successfulJobsHistoryLimit")
+    }
+
+    /// The path of the `suspend` field.
+    pub fn suspend(self) -> crate::FieldPath<bool> {
+        self.child("suspend")
+    }
+
+    /// The path of the `timeZone` field.
+    pub fn time_zone(self) -> crate::FieldPath<std::string::String> {
+        self.child("timeZone")
+    }
+}
+
+impl crate::Normalize for CronJobSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.concurrency_policy);
+        crate::Normalize::normalize(&mut self.failed_jobs_history_limit);
+        crate::Normalize::normalize(&mut self.job_template);
+        crate::Normalize::normalize(&mut self.schedule);
+        crate::Normalize::normalize(&mut self.starting_deadline_seconds);
+        crate::Normalize::normalize(&mut self.successful_jobs_history_limit);
+        crate::Normalize::normalize(&mut self.suspend);
+        crate::Normalize::normalize(&mut self.time_zone);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CronJobSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -187,6 +287,82 @@ impl crate::serde::Serialize for CronJobSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CronJobSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.schedule, 1, buf);
+        if let Some(value) = &self.starting_deadline_seconds {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.concurrency_policy {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.suspend {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.job_template, 5, buf);
+        if let Some(value) = &self.successful_jobs_history_limit {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.failed_jobs_history_limit {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.time_zone {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_concurrency_policy: Option<std::string::String> = None;
+        let mut value_failed_jobs_history_limit: Option<i32> = None;
+        let mut value_job_template: Option<crate::api::batch::v1::JobTemplateSpec> = None;
+        let mut value_schedule: Option<std::string::String> = None;
+        let mut value_starting_deadline_seconds: Option<i64> = None;
+        let mut value_successful_jobs_history_limit: Option<i32> = None;
+        let mut value_suspend: Option<bool> = None;
+        let mut value_time_zone: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_schedule, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_starting_deadline_seconds, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_concurrency_policy, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_suspend, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_job_template, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_successful_jobs_history_limit, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_failed_jobs_history_limit, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_time_zone, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CronJobSpec {
+            concurrency_policy: value_concurrency_policy,
+            failed_jobs_history_limit: value_failed_jobs_history_limit,
+            job_template: value_job_template.unwrap_or_default(),
+            schedule: value_schedule.unwrap_or_default(),
+            starting_deadline_seconds: value_starting_deadline_seconds,
+            successful_jobs_history_limit: value_successful_jobs_history_limit,
+            suspend: value_suspend,
+            time_zone: value_time_zone,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CronJobSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "jobTemplate" => <crate::api::batch::v1::JobTemplateSpec as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CronJobSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,56 @@ impl crate::DeepMerge for PodFailurePolicyOnExitCodesRequirement {
     }
 }
 
+impl crate::Defaulting for PodFailurePolicyOnExitCodesRequirement {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_name);
+        crate::Defaulting::set_defaults(&mut self.operator);
+        crate::Defaulting::set_defaults(&mut self.values);
+    }
+}
+
+impl crate::diff::Diff for PodFailurePolicyOnExitCodesRequirement {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_name, &other.container_name, &crate::diff::Path::Field { parent: path, name: "containerName" }, changes);
+        crate::diff::Diff::diff(&self.operator, &other.operator, &crate::diff::Path::Field { parent: path, name: "operator" }, changes);
+        crate::diff::strategies::list::set(&self.values, &other.values, &crate::diff::Path::Field { parent: path, name: "values" }, changes);
+    }
+}
+
+impl PodFailurePolicyOnExitCodesRequirement {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodFailurePolicyOnExitCodesRequirement> {
+    /// The path of the `containerName` field.
+    pub fn container_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerName")
+    }
+
+    /// The path of the `operator` field.
+    pub fn operator(self) -> crate::FieldPath<std::string::String> {
+        self.child("operator")
+    }
+
+    /// The path of the `values` field.
+    pub fn values(self) -> crate::FieldPath<std::vec::Vec<i32>> {
+        self.child("values")
+    }
+}
+
+impl crate::Normalize for PodFailurePolicyOnExitCodesRequirement {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_name);
+        crate::Normalize::normalize(&mut self.operator);
+        crate::normalize_strategies::list::set(&mut self.values, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodFailurePolicyOnExitCodesRequirement {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -123,6 +173,42 @@ impl crate::serde::Serialize for PodFailurePolicyOnExitCodesRequirement {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodFailurePolicyOnExitCodesRequirement {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.container_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.operator, 2, buf);
+        crate::protobuf::Field::encode_field(&self.values, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_name: Option<std::string::String> = None;
+        let mut value_operator: Option<std::string::String> = None;
+        let mut value_values: Option<std::vec::Vec<i32>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_container_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_operator, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_values, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodFailurePolicyOnExitCodesRequirement {
+            container_name: value_container_name,
+            operator: value_operator.unwrap_or_default(),
+            values: value_values.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodFailurePolicyOnExitCodesRequirement {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodFailurePolicyOnExitCodesRequirement {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for AttachedVolume {
     }
 }
 
+impl crate::Defaulting for AttachedVolume {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.device_path);
+        crate::Defaulting::set_defaults(&mut self.name);
+    }
+}
+
+impl crate::diff::Diff for AttachedVolume {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.device_path, &other.device_path, &crate::diff::Path::Field { parent: path, name: "devicePath" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+    }
+}
+
+impl AttachedVolume {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AttachedVolume> {
+    /// The path of the `devicePath` field.
+    pub fn device_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("devicePath")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+}
+
+impl crate::Normalize for AttachedVolume {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.device_path);
+        crate::Normalize::normalize(&mut self.name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AttachedVolume {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for AttachedVolume {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AttachedVolume {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.device_path, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_device_path: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_device_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AttachedVolume {
+            device_path: value_device_path.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AttachedVolume {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AttachedVolume {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,62 @@ impl crate::DeepMerge for AWSElasticBlockStoreVolumeSource {
     }
 }
 
+impl crate::Defaulting for AWSElasticBlockStoreVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.partition);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.volume_id);
+    }
+}
+
+impl crate::diff::Diff for AWSElasticBlockStoreVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.partition, &other.partition, &crate::diff::Path::Field { parent: path, name: "partition" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.volume_id, &other.volume_id, &crate::diff::Path::Field { parent: path, name: "volumeID" }, changes);
+    }
+}
+
+impl AWSElasticBlockStoreVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AWSElasticBlockStoreVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `partition` field.
+    pub fn partition(self) -> crate::FieldPath<i32> {
+        self.child("partition")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `volumeID` field.
+    pub fn volume_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeID")
+    }
+}
+
+impl crate::Normalize for AWSElasticBlockStoreVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.partition);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.volume_id);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AWSElasticBlockStoreVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,50 @@ impl crate::serde::Serialize for AWSElasticBlockStoreVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AWSElasticBlockStoreVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.volume_id, 1, buf);
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.partition {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_partition: Option<i32> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_volume_id: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_volume_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_partition, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AWSElasticBlockStoreVolumeSource {
+            fs_type: value_fs_type,
+            partition: value_partition,
+            read_only: value_read_only,
+            volume_id: value_volume_id.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AWSElasticBlockStoreVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AWSElasticBlockStoreVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
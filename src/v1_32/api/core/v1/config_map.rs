@@ -54,6 +54,86 @@ impl crate::DeepMerge for ConfigMap {
     }
 }
 
+impl crate::Defaulting for ConfigMap {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.binary_data);
+        crate::Defaulting::set_defaults(&mut self.data);
+        crate::Defaulting::set_defaults(&mut self.immutable);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+    }
+}
+
+impl crate::diff::Diff for ConfigMap {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.binary_data,
+            &other.binary_data,
+            &crate::diff::Path::Field { parent: path, name: "binaryData" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.data,
+            &other.data,
+            &crate::diff::Path::Field { parent: path, name: "data" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.immutable, &other.immutable, &crate::diff::Path::Field { parent: path, name: "immutable" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+    }
+}
+
+impl ConfigMap {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ConfigMap> {
+    /// The path of the `binaryData` field.
+    pub fn binary_data(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::ByteString>> {
+        self.child("binaryData")
+    }
+
+    /// The path of the `data` field.
+    pub fn data(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("data")
+    }
+
+    /// The path of the `immutable` field.
+    pub fn immutable(self) -> crate::FieldPath<bool> {
+        self.child("immutable")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+}
+
+impl crate::Normalize for ConfigMap {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.binary_data, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.data, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.immutable);
+        crate::Normalize::normalize(&mut self.metadata);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ConfigMap {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -181,6 +261,60 @@ impl crate::serde::Serialize for ConfigMap {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ConfigMap {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.data {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.binary_data {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.immutable {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_binary_data: Option<std::collections::BTreeMap<std::string::String, crate::ByteString>> = None;
+        let mut value_data: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_immutable: Option<bool> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_data, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_binary_data, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_immutable, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ConfigMap {
+            binary_data: value_binary_data,
+            data: value_data,
+            immutable: value_immutable,
+            metadata: value_metadata.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ConfigMap {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMap {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for ContainerResizePolicy {
     }
 }
 
+impl crate::Defaulting for ContainerResizePolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.resource_name);
+        crate::Defaulting::set_defaults(&mut self.restart_policy);
+    }
+}
+
+impl crate::diff::Diff for ContainerResizePolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.resource_name, &other.resource_name, &crate::diff::Path::Field { parent: path, name: "resourceName" }, changes);
+        crate::diff::Diff::diff(&self.restart_policy, &other.restart_policy, &crate::diff::Path::Field { parent: path, name: "restartPolicy" }, changes);
+    }
+}
+
+impl ContainerResizePolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerResizePolicy> {
+    /// The path of the `resourceName` field.
+    pub fn resource_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceName")
+    }
+
+    /// The path of the `restartPolicy` field.
+    pub fn restart_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("restartPolicy")
+    }
+}
+
+impl crate::Normalize for ContainerResizePolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.resource_name);
+        crate::Normalize::normalize(&mut self.restart_policy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerResizePolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for ContainerResizePolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerResizePolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.resource_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.restart_policy, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_resource_name: Option<std::string::String> = None;
+        let mut value_restart_policy: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_restart_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerResizePolicy {
+            resource_name: value_resource_name.unwrap_or_default(),
+            restart_policy: value_restart_policy.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerResizePolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerResizePolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for ContainerStateRunning {
     }
 }
 
+impl crate::Defaulting for ContainerStateRunning {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.started_at);
+    }
+}
+
+impl crate::diff::Diff for ContainerStateRunning {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.started_at, &other.started_at, &crate::diff::Path::Field { parent: path, name: "startedAt" }, changes);
+    }
+}
+
+impl ContainerStateRunning {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerStateRunning> {
+    /// The path of the `startedAt` field.
+    pub fn started_at(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("startedAt")
+    }
+}
+
+impl crate::Normalize for ContainerStateRunning {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.started_at);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerStateRunning {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for ContainerStateRunning {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerStateRunning {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.started_at {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_started_at, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerStateRunning {
+            started_at: value_started_at,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerStateRunning {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStateRunning {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -37,6 +37,86 @@ impl crate::DeepMerge for ContainerStateTerminated {
     }
 }
 
+impl crate::Defaulting for ContainerStateTerminated {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_id);
+        crate::Defaulting::set_defaults(&mut self.exit_code);
+        crate::Defaulting::set_defaults(&mut self.finished_at);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.signal);
+        crate::Defaulting::set_defaults(&mut self.started_at);
+    }
+}
+
+impl crate::diff::Diff for ContainerStateTerminated {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_id, &other.container_id, &crate::diff::Path::Field { parent: path, name: "containerID" }, changes);
+        crate::diff::Diff::diff(&self.exit_code, &other.exit_code, &crate::diff::Path::Field { parent: path, name: "exitCode" }, changes);
+        crate::diff::Diff::diff(&self.finished_at, &other.finished_at, &crate::diff::Path::Field { parent: path, name: "finishedAt" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.signal, &other.signal, &crate::diff::Path::Field { parent: path, name: "signal" }, changes);
+        crate::diff::Diff::diff(&self.started_at, &other.started_at, &crate::diff::Path::Field { parent: path, name: "startedAt" }, changes);
+    }
+}
+
+impl ContainerStateTerminated {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerStateTerminated> {
+    /// The path of the `containerID` field.
+    pub fn container_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerID")
+    }
+
+    /// The path of the `exitCode` field.
+    pub fn exit_code(self) -> crate::FieldPath<i32> {
+        self.child("exitCode")
+    }
+
+    /// The path of the `finishedAt` field.
+    pub fn finished_at(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("finishedAt")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `signal` field.
+    pub fn signal(self) -> crate::FieldPath<i32> {
+        self.child("signal")
+    }
+
+    /// The path of the `startedAt` field.
+    pub fn started_at(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("startedAt")
+    }
+}
+
+impl crate::Normalize for ContainerStateTerminated {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_id);
+        crate::Normalize::normalize(&mut self.exit_code);
+        crate::Normalize::normalize(&mut self.finished_at);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.signal);
+        crate::Normalize::normalize(&mut self.started_at);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerStateTerminated {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -174,6 +254,68 @@ impl crate::serde::Serialize for ContainerStateTerminated {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerStateTerminated {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.exit_code, 1, buf);
+        if let Some(value) = &self.signal {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.started_at {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.finished_at {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.container_id {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_id: Option<std::string::String> = None;
+        let mut value_exit_code: Option<i32> = None;
+        let mut value_finished_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_signal: Option<i32> = None;
+        let mut value_started_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_exit_code, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_signal, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_started_at, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_finished_at, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_container_id, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerStateTerminated {
+            container_id: value_container_id,
+            exit_code: value_exit_code.unwrap_or_default(),
+            finished_at: value_finished_at,
+            message: value_message,
+            reason: value_reason,
+            signal: value_signal,
+            started_at: value_started_at,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerStateTerminated {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStateTerminated {
     fn schema_name() -> std::borrow::Cow<'static, str> {
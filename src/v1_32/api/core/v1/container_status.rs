@@ -83,6 +83,174 @@ impl crate::DeepMerge for ContainerStatus {
     }
 }
 
+impl crate::Defaulting for ContainerStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allocated_resources);
+        crate::Defaulting::set_defaults(&mut self.allocated_resources_status);
+        crate::Defaulting::set_defaults(&mut self.container_id);
+        crate::Defaulting::set_defaults(&mut self.image);
+        crate::Defaulting::set_defaults(&mut self.image_id);
+        crate::Defaulting::set_defaults(&mut self.last_state);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.ready);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.restart_count);
+        crate::Defaulting::set_defaults(&mut self.started);
+        crate::Defaulting::set_defaults(&mut self.state);
+        crate::Defaulting::set_defaults(&mut self.user);
+        crate::Defaulting::set_defaults(&mut self.volume_mounts);
+    }
+}
+
+impl crate::diff::Diff for ContainerStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.allocated_resources,
+            &other.allocated_resources,
+            &crate::diff::Path::Field { parent: path, name: "allocatedResources" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.allocated_resources_status,
+            &other.allocated_resources_status,
+            &crate::diff::Path::Field { parent: path, name: "allocatedResourcesStatus" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.container_id, &other.container_id, &crate::diff::Path::Field { parent: path, name: "containerID" }, changes);
+        crate::diff::Diff::diff(&self.image, &other.image, &crate::diff::Path::Field { parent: path, name: "image" }, changes);
+        crate::diff::Diff::diff(&self.image_id, &other.image_id, &crate::diff::Path::Field { parent: path, name: "imageID" }, changes);
+        crate::diff::Diff::diff(&self.last_state, &other.last_state, &crate::diff::Path::Field { parent: path, name: "lastState" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.ready, &other.ready, &crate::diff::Path::Field { parent: path, name: "ready" }, changes);
+        crate::diff::Diff::diff(&self.resources, &other.resources, &crate::diff::Path::Field { parent: path, name: "resources" }, changes);
+        crate::diff::Diff::diff(&self.restart_count, &other.restart_count, &crate::diff::Path::Field { parent: path, name: "restartCount" }, changes);
+        crate::diff::Diff::diff(&self.started, &other.started, &crate::diff::Path::Field { parent: path, name: "started" }, changes);
+        crate::diff::Diff::diff(&self.state, &other.state, &crate::diff::Path::Field { parent: path, name: "state" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+        crate::diff::strategies::list::map(
+            &self.volume_mounts,
+            &other.volume_mounts,
+            &crate::diff::Path::Field { parent: path, name: "volumeMounts" },
+            changes,
+            &[("mountPath", |item| crate::diff::MergeKey::merge_key(&item.mount_path))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ContainerStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerStatus> {
+    /// The path of the `allocatedResources` field.
+    pub fn allocated_resources(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("allocatedResources")
+    }
+
+    /// The path of the `allocatedResourcesStatus` field.
+    pub fn allocated_resources_status(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ResourceStatus>> {
+        self.child("allocatedResourcesStatus")
+    }
+
+    /// The path of the `containerID` field.
+    pub fn container_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerID")
+    }
+
+    /// The path of the `image` field.
+    pub fn image(self) -> crate::FieldPath<std::string::String> {
+        self.child("image")
+    }
+
+    /// The path of the `imageID` field.
+    pub fn image_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("imageID")
+    }
+
+    /// The path of the `lastState` field.
+    pub fn last_state(self) -> crate::FieldPath<crate::api::core::v1::ContainerState> {
+        self.child("lastState")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `ready` field.
+    pub fn ready(self) -> crate::FieldPath<bool> {
+        self.child("ready")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<crate::api::core::v1::ResourceRequirements> {
+        self.child("resources")
+    }
+
+    /// The path of the `restartCount` field.
+    pub fn restart_count(self) -> crate::FieldPath<i32> {
+        self.child("restartCount")
+    }
+
+    /// The path of the `started` field.
+    pub fn started(self) -> crate::FieldPath<bool> {
+        self.child("started")
+    }
+
+    /// The path of the `state` field.
+    pub fn state(self) -> crate::FieldPath<crate::api::core::v1::ContainerState> {
+        self.child("state")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<crate::api::core::v1::ContainerUser> {
+        self.child("user")
+    }
+
+    /// The path of the `volumeMounts` field.
+    pub fn volume_mounts(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::VolumeMountStatus>> {
+        self.child("volumeMounts")
+    }
+}
+
+impl crate::Normalize for ContainerStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.allocated_resources, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::map(&mut self.allocated_resources_status, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.container_id);
+        crate::Normalize::normalize(&mut self.image);
+        crate::Normalize::normalize(&mut self.image_id);
+        crate::Normalize::normalize(&mut self.last_state);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.ready);
+        crate::Normalize::normalize(&mut self.resources);
+        crate::Normalize::normalize(&mut self.restart_count);
+        crate::Normalize::normalize(&mut self.started);
+        crate::Normalize::normalize(&mut self.state);
+        crate::Normalize::normalize(&mut self.user);
+        crate::normalize_strategies::list::map(&mut self.volume_mounts, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -278,6 +446,118 @@ impl crate::serde::Serialize for ContainerStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.state {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.last_state {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.ready, 4, buf);
+        crate::protobuf::Field::encode_field(&self.restart_count, 5, buf);
+        crate::protobuf::Field::encode_field(&self.image, 6, buf);
+        crate::protobuf::Field::encode_field(&self.image_id, 7, buf);
+        if let Some(value) = &self.container_id {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.started {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.allocated_resources {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.volume_mounts {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.allocated_resources_status {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allocated_resources: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_allocated_resources_status: Option<std::vec::Vec<crate::api::core::v1::ResourceStatus>> = None;
+        let mut value_container_id: Option<std::string::String> = None;
+        let mut value_image: Option<std::string::String> = None;
+        let mut value_image_id: Option<std::string::String> = None;
+        let mut value_last_state: Option<crate::api::core::v1::ContainerState> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_ready: Option<bool> = None;
+        let mut value_resources: Option<crate::api::core::v1::ResourceRequirements> = None;
+        let mut value_restart_count: Option<i32> = None;
+        let mut value_started: Option<bool> = None;
+        let mut value_state: Option<crate::api::core::v1::ContainerState> = None;
+        let mut value_user: Option<crate::api::core::v1::ContainerUser> = None;
+        let mut value_volume_mounts: Option<std::vec::Vec<crate::api::core::v1::VolumeMountStatus>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_state, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_last_state, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_ready, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_restart_count, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_image, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_image_id, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_container_id, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_started, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_allocated_resources, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_volume_mounts, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_allocated_resources_status, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerStatus {
+            allocated_resources: value_allocated_resources,
+            allocated_resources_status: value_allocated_resources_status,
+            container_id: value_container_id,
+            image: value_image.unwrap_or_default(),
+            image_id: value_image_id.unwrap_or_default(),
+            last_state: value_last_state,
+            name: value_name.unwrap_or_default(),
+            ready: value_ready.unwrap_or_default(),
+            resources: value_resources,
+            restart_count: value_restart_count.unwrap_or_default(),
+            started: value_started,
+            state: value_state,
+            user: value_user,
+            volume_mounts: value_volume_mounts,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "allocatedResourcesStatus" => Some(&["name"]),
+                "volumeMounts" => Some(&["mountPath"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "allocatedResourcesStatus" => <Option<std::vec::Vec<crate::api::core::v1::ResourceStatus>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "resources" => <Option<crate::api::core::v1::ResourceRequirements> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for ContainerUser {
     }
 }
 
+impl crate::Defaulting for ContainerUser {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.linux);
+    }
+}
+
+impl crate::diff::Diff for ContainerUser {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.linux, &other.linux, &crate::diff::Path::Field { parent: path, name: "linux" }, changes);
+    }
+}
+
+impl ContainerUser {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerUser> {
+    /// The path of the `linux` field.
+    pub fn linux(self) -> crate::FieldPath<crate::api::core::v1::LinuxContainerUser> {
+        self.child("linux")
+    }
+}
+
+impl crate::Normalize for ContainerUser {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.linux);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerUser {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for ContainerUser {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerUser {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.linux {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_linux: Option<crate::api::core::v1::LinuxContainerUser> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_linux, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerUser {
+            linux: value_linux,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerUser {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerUser {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -31,6 +31,84 @@ impl crate::DeepMerge for EndpointSubset {
     }
 }
 
+impl crate::Defaulting for EndpointSubset {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.addresses);
+        crate::Defaulting::set_defaults(&mut self.not_ready_addresses);
+        crate::Defaulting::set_defaults(&mut self.ports);
+    }
+}
+
+impl crate::diff::Diff for EndpointSubset {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.addresses,
+            &other.addresses,
+            &crate::diff::Path::Field { parent: path, name: "addresses" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.not_ready_addresses,
+            &other.not_ready_addresses,
+            &crate::diff::Path::Field { parent: path, name: "notReadyAddresses" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl EndpointSubset {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointSubset> {
+    /// The path of the `addresses` field.
+    pub fn addresses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EndpointAddress>> {
+        self.child("addresses")
+    }
+
+    /// The path of the `notReadyAddresses` field.
+    pub fn not_ready_addresses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EndpointAddress>> {
+        self.child("notReadyAddresses")
+    }
+
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EndpointPort>> {
+        self.child("ports")
+    }
+}
+
+impl crate::Normalize for EndpointSubset {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.addresses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.not_ready_addresses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointSubset {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -130,6 +208,46 @@ impl crate::serde::Serialize for EndpointSubset {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointSubset {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.addresses {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.not_ready_addresses {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_addresses: Option<std::vec::Vec<crate::api::core::v1::EndpointAddress>> = None;
+        let mut value_not_ready_addresses: Option<std::vec::Vec<crate::api::core::v1::EndpointAddress>> = None;
+        let mut value_ports: Option<std::vec::Vec<crate::api::core::v1::EndpointPort>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_addresses, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_not_ready_addresses, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointSubset {
+            addresses: value_addresses,
+            not_ready_addresses: value_not_ready_addresses,
+            ports: value_ports,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointSubset {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointSubset {
     fn schema_name() -> std::borrow::Cow<'static, str> {
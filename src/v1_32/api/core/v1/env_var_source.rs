@@ -25,6 +25,62 @@ impl crate::DeepMerge for EnvVarSource {
     }
 }
 
+impl crate::Defaulting for EnvVarSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config_map_key_ref);
+        crate::Defaulting::set_defaults(&mut self.field_ref);
+        crate::Defaulting::set_defaults(&mut self.resource_field_ref);
+        crate::Defaulting::set_defaults(&mut self.secret_key_ref);
+    }
+}
+
+impl crate::diff::Diff for EnvVarSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.config_map_key_ref, &other.config_map_key_ref, &crate::diff::Path::Field { parent: path, name: "configMapKeyRef" }, changes);
+        crate::diff::Diff::diff(&self.field_ref, &other.field_ref, &crate::diff::Path::Field { parent: path, name: "fieldRef" }, changes);
+        crate::diff::Diff::diff(&self.resource_field_ref, &other.resource_field_ref, &crate::diff::Path::Field { parent: path, name: "resourceFieldRef" }, changes);
+        crate::diff::Diff::diff(&self.secret_key_ref, &other.secret_key_ref, &crate::diff::Path::Field { parent: path, name: "secretKeyRef" }, changes);
+    }
+}
+
+impl EnvVarSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EnvVarSource> {
+    /// The path of the `configMapKeyRef` field.
+    pub fn config_map_key_ref(self) -> crate::FieldPath<crate::api::core::v1::ConfigMapKeySelector> {
+        self.child("configMapKeyRef")
+    }
+
+    /// The path of the `fieldRef` field.
+    pub fn field_ref(self) -> crate::FieldPath<crate::api::core::v1::ObjectFieldSelector> {
+        self.child("fieldRef")
+    }
+
+    /// The path of the `resourceFieldRef` field.
+    pub fn resource_field_ref(self) -> crate::FieldPath<crate::api::core::v1::ResourceFieldSelector> {
+        self.child("resourceFieldRef")
+    }
+
+    /// The path of the `secretKeyRef` field.
+    pub fn secret_key_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretKeySelector> {
+        self.child("secretKeyRef")
+    }
+}
+
+impl crate::Normalize for EnvVarSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.config_map_key_ref);
+        crate::Normalize::normalize(&mut self.field_ref);
+        crate::Normalize::normalize(&mut self.resource_field_ref);
+        crate::Normalize::normalize(&mut self.secret_key_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EnvVarSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for EnvVarSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EnvVarSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.field_ref {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.resource_field_ref {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.config_map_key_ref {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.secret_key_ref {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config_map_key_ref: Option<crate::api::core::v1::ConfigMapKeySelector> = None;
+        let mut value_field_ref: Option<crate::api::core::v1::ObjectFieldSelector> = None;
+        let mut value_resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector> = None;
+        let mut value_secret_key_ref: Option<crate::api::core::v1::SecretKeySelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_field_ref, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_field_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_config_map_key_ref, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_secret_key_ref, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EnvVarSource {
+            config_map_key_ref: value_config_map_key_ref,
+            field_ref: value_field_ref,
+            resource_field_ref: value_resource_field_ref,
+            secret_key_ref: value_secret_key_ref,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EnvVarSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EnvVarSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
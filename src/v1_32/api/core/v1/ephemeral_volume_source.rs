@@ -19,6 +19,38 @@ impl crate::DeepMerge for EphemeralVolumeSource {
     }
 }
 
+impl crate::Defaulting for EphemeralVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.volume_claim_template);
+    }
+}
+
+impl crate::diff::Diff for EphemeralVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.volume_claim_template, &other.volume_claim_template, &crate::diff::Path::Field { parent: path, name: "volumeClaimTemplate" }, changes);
+    }
+}
+
+impl EphemeralVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EphemeralVolumeSource> {
+    /// The path of the `volumeClaimTemplate` field.
+    pub fn volume_claim_template(self) -> crate::FieldPath<crate::api::core::v1::PersistentVolumeClaimTemplate> {
+        self.child("volumeClaimTemplate")
+    }
+}
+
+impl crate::Normalize for EphemeralVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.volume_claim_template);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EphemeralVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -98,6 +130,44 @@ impl crate::serde::Serialize for EphemeralVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EphemeralVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.volume_claim_template {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_volume_claim_template: Option<crate::api::core::v1::PersistentVolumeClaimTemplate> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_volume_claim_template, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EphemeralVolumeSource {
+            volume_claim_template: value_volume_claim_template,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EphemeralVolumeSource {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "volumeClaimTemplate" => <Option<crate::api::core::v1::PersistentVolumeClaimTemplate> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EphemeralVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
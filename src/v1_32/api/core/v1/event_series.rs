@@ -17,6 +17,46 @@ impl crate::DeepMerge for EventSeries {
     }
 }
 
+impl crate::Defaulting for EventSeries {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.count);
+        crate::Defaulting::set_defaults(&mut self.last_observed_time);
+    }
+}
+
+impl crate::diff::Diff for EventSeries {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.count, &other.count, &crate::diff::Path::Field { parent: path, name: "count" }, changes);
+        crate::diff::Diff::diff(&self.last_observed_time, &other.last_observed_time, &crate::diff::Path::Field { parent: path, name: "lastObservedTime" }, changes);
+    }
+}
+
+impl EventSeries {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EventSeries> {
+    /// The path of the `count` field.
+    pub fn count(self) -> crate::FieldPath<i32> {
+        self.child("count")
+    }
+
+    /// The path of the `lastObservedTime` field.
+    pub fn last_observed_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("lastObservedTime")
+    }
+}
+
+impl crate::Normalize for EventSeries {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.count);
+        crate::Normalize::normalize(&mut self.last_observed_time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EventSeries {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for EventSeries {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EventSeries {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.count {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.last_observed_time {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_count: Option<i32> = None;
+        let mut value_last_observed_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_count, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_last_observed_time, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EventSeries {
+            count: value_count,
+            last_observed_time: value_last_observed_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EventSeries {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EventSeries {
     fn schema_name() -> std::borrow::Cow<'static, str> {
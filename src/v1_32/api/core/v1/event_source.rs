@@ -17,6 +17,46 @@ impl crate::DeepMerge for EventSource {
     }
 }
 
+impl crate::Defaulting for EventSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.component);
+        crate::Defaulting::set_defaults(&mut self.host);
+    }
+}
+
+impl crate::diff::Diff for EventSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.component, &other.component, &crate::diff::Path::Field { parent: path, name: "component" }, changes);
+        crate::diff::Diff::diff(&self.host, &other.host, &crate::diff::Path::Field { parent: path, name: "host" }, changes);
+    }
+}
+
+impl EventSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EventSource> {
+    /// The path of the `component` field.
+    pub fn component(self) -> crate::FieldPath<std::string::String> {
+        self.child("component")
+    }
+
+    /// The path of the `host` field.
+    pub fn host(self) -> crate::FieldPath<std::string::String> {
+        self.child("host")
+    }
+}
+
+impl crate::Normalize for EventSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.component);
+        crate::Normalize::normalize(&mut self.host);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EventSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for EventSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EventSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.component {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.host {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_component: Option<std::string::String> = None;
+        let mut value_host: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_component, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_host, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EventSource {
+            component: value_component,
+            host: value_host,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EventSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EventSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
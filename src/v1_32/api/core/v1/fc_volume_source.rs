@@ -29,6 +29,90 @@ impl crate::DeepMerge for FCVolumeSource {
     }
 }
 
+impl crate::Defaulting for FCVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.lun);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.target_wwns);
+        crate::Defaulting::set_defaults(&mut self.wwids);
+    }
+}
+
+impl crate::diff::Diff for FCVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.lun, &other.lun, &crate::diff::Path::Field { parent: path, name: "lun" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.target_wwns,
+            &other.target_wwns,
+            &crate::diff::Path::Field { parent: path, name: "targetWWNs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.wwids,
+            &other.wwids,
+            &crate::diff::Path::Field { parent: path, name: "wwids" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl FCVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FCVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `lun` field.
+    pub fn lun(self) -> crate::FieldPath<i32> {
+        self.child("lun")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `targetWWNs` field.
+    pub fn target_wwns(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("targetWWNs")
+    }
+
+    /// The path of the `wwids` field.
+    pub fn wwids(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("wwids")
+    }
+}
+
+impl crate::Normalize for FCVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.lun);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::normalize_strategies::list::atomic(&mut self.target_wwns, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.wwids, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FCVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -148,6 +232,58 @@ impl crate::serde::Serialize for FCVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FCVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.target_wwns {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.lun {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.wwids {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_lun: Option<i32> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_target_wwns: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_wwids: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_target_wwns, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_lun, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_wwids, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FCVolumeSource {
+            fs_type: value_fs_type,
+            lun: value_lun,
+            read_only: value_read_only,
+            target_wwns: value_target_wwns,
+            wwids: value_wwids,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FCVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FCVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for HTTPHeader {
     }
 }
 
+impl crate::Defaulting for HTTPHeader {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for HTTPHeader {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl HTTPHeader {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HTTPHeader> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<std::string::String> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for HTTPHeader {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HTTPHeader {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for HTTPHeader {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HTTPHeader {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.value, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_value: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HTTPHeader {
+            name: value_name.unwrap_or_default(),
+            value: value_value.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HTTPHeader {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPHeader {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,48 @@ impl crate::DeepMerge for NamespaceSpec {
     }
 }
 
+impl crate::Defaulting for NamespaceSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.finalizers);
+    }
+}
+
+impl crate::diff::Diff for NamespaceSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.finalizers,
+            &other.finalizers,
+            &crate::diff::Path::Field { parent: path, name: "finalizers" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NamespaceSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NamespaceSpec> {
+    /// The path of the `finalizers` field.
+    pub fn finalizers(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("finalizers")
+    }
+}
+
+impl crate::Normalize for NamespaceSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.finalizers, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NamespaceSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for NamespaceSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NamespaceSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.finalizers {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_finalizers: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_finalizers, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NamespaceSpec {
+            finalizers: value_finalizers,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NamespaceSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NamespaceSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for NodeDaemonEndpoints {
     }
 }
 
+impl crate::Defaulting for NodeDaemonEndpoints {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.kubelet_endpoint);
+    }
+}
+
+impl crate::diff::Diff for NodeDaemonEndpoints {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.kubelet_endpoint, &other.kubelet_endpoint, &crate::diff::Path::Field { parent: path, name: "kubeletEndpoint" }, changes);
+    }
+}
+
+impl NodeDaemonEndpoints {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeDaemonEndpoints> {
+    /// The path of the `kubeletEndpoint` field.
+    pub fn kubelet_endpoint(self) -> crate::FieldPath<crate::api::core::v1::DaemonEndpoint> {
+        self.child("kubeletEndpoint")
+    }
+}
+
+impl crate::Normalize for NodeDaemonEndpoints {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.kubelet_endpoint);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeDaemonEndpoints {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for NodeDaemonEndpoints {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeDaemonEndpoints {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.kubelet_endpoint {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_kubelet_endpoint: Option<crate::api::core::v1::DaemonEndpoint> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_kubelet_endpoint, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeDaemonEndpoints {
+            kubelet_endpoint: value_kubelet_endpoint,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeDaemonEndpoints {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeDaemonEndpoints {
     fn schema_name() -> std::borrow::Cow<'static, str> {
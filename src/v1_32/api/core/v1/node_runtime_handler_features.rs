@@ -17,6 +17,46 @@ impl crate::DeepMerge for NodeRuntimeHandlerFeatures {
     }
 }
 
+impl crate::Defaulting for NodeRuntimeHandlerFeatures {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.recursive_read_only_mounts);
+        crate::Defaulting::set_defaults(&mut self.user_namespaces);
+    }
+}
+
+impl crate::diff::Diff for NodeRuntimeHandlerFeatures {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.recursive_read_only_mounts, &other.recursive_read_only_mounts, &crate::diff::Path::Field { parent: path, name: "recursiveReadOnlyMounts" }, changes);
+        crate::diff::Diff::diff(&self.user_namespaces, &other.user_namespaces, &crate::diff::Path::Field { parent: path, name: "userNamespaces" }, changes);
+    }
+}
+
+impl NodeRuntimeHandlerFeatures {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeRuntimeHandlerFeatures> {
+    /// The path of the `recursiveReadOnlyMounts` field.
+    pub fn recursive_read_only_mounts(self) -> crate::FieldPath<bool> {
+        self.child("recursiveReadOnlyMounts")
+    }
+
+    /// The path of the `userNamespaces` field.
+    pub fn user_namespaces(self) -> crate::FieldPath<bool> {
+        self.child("userNamespaces")
+    }
+}
+
+impl crate::Normalize for NodeRuntimeHandlerFeatures {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.recursive_read_only_mounts);
+        crate::Normalize::normalize(&mut self.user_namespaces);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeRuntimeHandlerFeatures {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for NodeRuntimeHandlerFeatures {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeRuntimeHandlerFeatures {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.recursive_read_only_mounts {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.user_namespaces {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_recursive_read_only_mounts: Option<bool> = None;
+        let mut value_user_namespaces: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_recursive_read_only_mounts, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_user_namespaces, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeRuntimeHandlerFeatures {
+            recursive_read_only_mounts: value_recursive_read_only_mounts,
+            user_namespaces: value_user_namespaces,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeRuntimeHandlerFeatures {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeRuntimeHandlerFeatures {
     fn schema_name() -> std::borrow::Cow<'static, str> {
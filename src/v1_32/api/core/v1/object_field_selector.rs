@@ -17,6 +17,49 @@ impl crate::DeepMerge for ObjectFieldSelector {
     }
 }
 
+impl crate::Defaulting for ObjectFieldSelector {
+    fn set_defaults(&mut self) {
+        if self.api_version.is_none() {
+            self.api_version = Some("v1".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.api_version);
+        crate::Defaulting::set_defaults(&mut self.field_path);
+    }
+}
+
+impl crate::diff::Diff for ObjectFieldSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_version, &other.api_version, &crate::diff::Path::Field { parent: path, name: "apiVersion" }, changes);
+        crate::diff::Diff::diff(&self.field_path, &other.field_path, &crate::diff::Path::Field { parent: path, name: "fieldPath" }, changes);
+    }
+}
+
+impl ObjectFieldSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ObjectFieldSelector> {
+    /// The path of the `apiVersion` field.
+    pub fn api_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiVersion")
+    }
+
+    /// The path of the `fieldPath` field.
+    pub fn field_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("fieldPath")
+    }
+}
+
+impl crate::Normalize for ObjectFieldSelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_version);
+        crate::Normalize::normalize(&mut self.field_path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ObjectFieldSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +147,38 @@ impl crate::serde::Serialize for ObjectFieldSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ObjectFieldSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.api_version {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.field_path, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_version: Option<std::string::String> = None;
+        let mut value_field_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_api_version, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_field_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ObjectFieldSelector {
+            api_version: value_api_version,
+            field_path: value_field_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ObjectFieldSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ObjectFieldSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
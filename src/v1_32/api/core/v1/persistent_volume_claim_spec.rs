@@ -52,6 +52,115 @@ impl crate::DeepMerge for PersistentVolumeClaimSpec {
     }
 }
 
+impl crate::Defaulting for PersistentVolumeClaimSpec {
+    fn set_defaults(&mut self) {
+        if self.volume_mode.is_none() {
+            self.volume_mode = Some("Filesystem".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.access_modes);
+        crate::Defaulting::set_defaults(&mut self.data_source);
+        crate::Defaulting::set_defaults(&mut self.data_source_ref);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.storage_class_name);
+        crate::Defaulting::set_defaults(&mut self.volume_attributes_class_name);
+        crate::Defaulting::set_defaults(&mut self.volume_mode);
+        crate::Defaulting::set_defaults(&mut self.volume_name);
+    }
+}
+
+impl crate::diff::Diff for PersistentVolumeClaimSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.access_modes,
+            &other.access_modes,
+            &crate::diff::Path::Field { parent: path, name: "accessModes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.data_source, &other.data_source, &crate::diff::Path::Field { parent: path, name: "dataSource" }, changes);
+        crate::diff::Diff::diff(&self.data_source_ref, &other.data_source_ref, &crate::diff::Path::Field { parent: path, name: "dataSourceRef" }, changes);
+        crate::diff::Diff::diff(&self.resources, &other.resources, &crate::diff::Path::Field { parent: path, name: "resources" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.storage_class_name, &other.storage_class_name, &crate::diff::Path::Field { parent: path, name: "storageClassName" }, changes);
+        crate::diff::Diff::diff(&self.volume_attributes_class_name, &other.volume_attributes_class_name, &crate::diff::Path::Field { parent: path, name: "volumeAttributesClassName" }, changes);
+        crate::diff::Diff::diff(&self.volume_mode, &other.volume_mode, &crate::diff::Path::Field { parent: path, name: "volumeMode" }, changes);
+        crate::diff::Diff::diff(&self.volume_name, &other.volume_name, &crate::diff::Path::Field { parent: path, name: "volumeName" }, changes);
+    }
+}
+
+impl PersistentVolumeClaimSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PersistentVolumeClaimSpec> {
+    /// The path of the `accessModes` field.
+    pub fn access_modes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("accessModes")
+    }
+
+    /// The path of the `dataSource` field.
+    pub fn data_source(self) -> crate::FieldPath<crate::api::core::v1::TypedLocalObjectReference> {
+        self.child("dataSource")
+    }
+
+    /// The path of the `dataSourceRef` field.
+    pub fn data_source_ref(self) -> crate::FieldPath<crate::api::core::v1::TypedObjectReference> {
+        self.child("dataSourceRef")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<crate::api::core::v1::VolumeResourceRequirements> {
+        self.child("resources")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `storageClassName` field.
+    pub fn storage_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("storageClassName")
+    }
+
+    /// The path of the `volumeAttributesClassName` field.
+    pub fn volume_attributes_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeAttributesClassName")
+    }
+
+    /// The path of the `volumeMode` field.
+    pub fn volume_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeMode")
+    }
+
+    /// The path of the `volumeName` field.
+    pub fn volume_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeName")
+    }
+}
+
+impl crate::Normalize for PersistentVolumeClaimSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.access_modes, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.data_source);
+        crate::Normalize::normalize(&mut self.data_source_ref);
+        crate::Normalize::normalize(&mut self.resources);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.storage_class_name);
+        crate::Normalize::normalize(&mut self.volume_attributes_class_name);
+        crate::Normalize::normalize(&mut self.volume_mode);
+        crate::Normalize::normalize(&mut self.volume_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -211,6 +320,82 @@ impl crate::serde::Serialize for PersistentVolumeClaimSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PersistentVolumeClaimSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.access_modes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.volume_name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.storage_class_name {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.volume_mode {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.data_source {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.data_source_ref {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.volume_attributes_class_name {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_access_modes: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_data_source: Option<crate::api::core::v1::TypedLocalObjectReference> = None;
+        let mut value_data_source_ref: Option<crate::api::core::v1::TypedObjectReference> = None;
+        let mut value_resources: Option<crate::api::core::v1::VolumeResourceRequirements> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_storage_class_name: Option<std::string::String> = None;
+        let mut value_volume_attributes_class_name: Option<std::string::String> = None;
+        let mut value_volume_mode: Option<std::string::String> = None;
+        let mut value_volume_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_access_modes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_volume_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_storage_class_name, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_volume_mode, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_data_source, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_data_source_ref, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_volume_attributes_class_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PersistentVolumeClaimSpec {
+            access_modes: value_access_modes,
+            data_source: value_data_source,
+            data_source_ref: value_data_source_ref,
+            resources: value_resources,
+            selector: value_selector,
+            storage_class_name: value_storage_class_name,
+            volume_attributes_class_name: value_volume_attributes_class_name,
+            volume_mode: value_volume_mode,
+            volume_name: value_volume_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PersistentVolumeClaimSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
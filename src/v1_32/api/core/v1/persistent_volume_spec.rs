@@ -135,6 +135,314 @@ impl crate::DeepMerge for PersistentVolumeSpec {
     }
 }
 
+impl crate::Defaulting for PersistentVolumeSpec {
+    fn set_defaults(&mut self) {
+        if self.persistent_volume_reclaim_policy.is_none() {
+            self.persistent_volume_reclaim_policy = Some("Retain".into());
+        }
+        if self.volume_mode.is_none() {
+            self.volume_mode = Some("Filesystem".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.access_modes);
+        crate::Defaulting::set_defaults(&mut self.aws_elastic_block_store);
+        crate::Defaulting::set_defaults(&mut self.azure_disk);
+        crate::Defaulting::set_defaults(&mut self.azure_file);
+        crate::Defaulting::set_defaults(&mut self.capacity);
+        crate::Defaulting::set_defaults(&mut self.cephfs);
+        crate::Defaulting::set_defaults(&mut self.cinder);
+        crate::Defaulting::set_defaults(&mut self.claim_ref);
+        crate::Defaulting::set_defaults(&mut self.csi);
+        crate::Defaulting::set_defaults(&mut self.fc);
+        crate::Defaulting::set_defaults(&mut self.flex_volume);
+        crate::Defaulting::set_defaults(&mut self.flocker);
+        crate::Defaulting::set_defaults(&mut self.gce_persistent_disk);
+        crate::Defaulting::set_defaults(&mut self.glusterfs);
+        crate::Defaulting::set_defaults(&mut self.host_path);
+        crate::Defaulting::set_defaults(&mut self.iscsi);
+        crate::Defaulting::set_defaults(&mut self.local);
+        crate::Defaulting::set_defaults(&mut self.mount_options);
+        crate::Defaulting::set_defaults(&mut self.nfs);
+        crate::Defaulting::set_defaults(&mut self.node_affinity);
+        crate::Defaulting::set_defaults(&mut self.persistent_volume_reclaim_policy);
+        crate::Defaulting::set_defaults(&mut self.photon_persistent_disk);
+        crate::Defaulting::set_defaults(&mut self.portworx_volume);
+        crate::Defaulting::set_defaults(&mut self.quobyte);
+        crate::Defaulting::set_defaults(&mut self.rbd);
+        crate::Defaulting::set_defaults(&mut self.scale_io);
+        crate::Defaulting::set_defaults(&mut self.storage_class_name);
+        crate::Defaulting::set_defaults(&mut self.storageos);
+        crate::Defaulting::set_defaults(&mut self.volume_attributes_class_name);
+        crate::Defaulting::set_defaults(&mut self.volume_mode);
+        crate::Defaulting::set_defaults(&mut self.vsphere_volume);
+    }
+}
+
+impl crate::diff::Diff for PersistentVolumeSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.access_modes,
+            &other.access_modes,
+            &crate::diff::Path::Field { parent: path, name: "accessModes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.aws_elastic_block_store, &other.aws_elastic_block_store, &crate::diff::Path::Field { parent: path, name: "awsElasticBlockStore" }, changes);
+        crate::diff::Diff::diff(&self.azure_disk, &other.azure_disk, &crate::diff::Path::Field { parent: path, name: "azureDisk" }, changes);
+        crate::diff::Diff::diff(&self.azure_file, &other.azure_file, &crate::diff::Path::Field { parent: path, name: "azureFile" }, changes);
+        crate::diff::strategies::map::values(
+            &self.capacity,
+            &other.capacity,
+            &crate::diff::Path::Field { parent: path, name: "capacity" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.cephfs, &other.cephfs, &crate::diff::Path::Field { parent: path, name: "cephfs" }, changes);
+        crate::diff::Diff::diff(&self.cinder, &other.cinder, &crate::diff::Path::Field { parent: path, name: "cinder" }, changes);
+        crate::diff::Diff::diff(&self.claim_ref, &other.claim_ref, &crate::diff::Path::Field { parent: path, name: "claimRef" }, changes);
+        crate::diff::Diff::diff(&self.csi, &other.csi, &crate::diff::Path::Field { parent: path, name: "csi" }, changes);
+        crate::diff::Diff::diff(&self.fc, &other.fc, &crate::diff::Path::Field { parent: path, name: "fc" }, changes);
+        crate::diff::Diff::diff(&self.flex_volume, &other.flex_volume, &crate::diff::Path::Field { parent: path, name: "flexVolume" }, changes);
+        crate::diff::Diff::diff(&self.flocker, &other.flocker, &crate::diff::Path::Field { parent: path, name: "flocker" }, changes);
+        crate::diff::Diff::diff(&self.gce_persistent_disk, &other.gce_persistent_disk, &crate::diff::Path::Field { parent: path, name: "gcePersistentDisk" }, changes);
+        crate::diff::Diff::diff(&self.glusterfs, &other.glusterfs, &crate::diff::Path::Field { parent: path, name: "glusterfs" }, changes);
+        crate::diff::Diff::diff(&self.host_path, &other.host_path, &crate::diff::Path::Field { parent: path, name: "hostPath" }, changes);
+        crate::diff::Diff::diff(&self.iscsi, &other.iscsi, &crate::diff::Path::Field { parent: path, name: "iscsi" }, changes);
+        crate::diff::Diff::diff(&self.local, &other.local, &crate::diff::Path::Field { parent: path, name: "local" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.mount_options,
+            &other.mount_options,
+            &crate::diff::Path::Field { parent: path, name: "mountOptions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.nfs, &other.nfs, &crate::diff::Path::Field { parent: path, name: "nfs" }, changes);
+        crate::diff::Diff::diff(&self.node_affinity, &other.node_affinity, &crate::diff::Path::Field { parent: path, name: "nodeAffinity" }, changes);
+        crate::diff::Diff::diff(&self.persistent_volume_reclaim_policy, &other.persistent_volume_reclaim_policy, &crate::diff::Path::Field { parent: path, name: "persistentVolumeReclaimPolicy" }, changes);
+        crate::diff::Diff::diff(&self.photon_persistent_disk, &other.photon_persistent_disk, &crate::diff::Path::Field { parent: path, name: "photonPersistentDisk" }, changes);
+        crate::diff::Diff::diff(&self.portworx_volume, &other.portworx_volume, &crate::diff::Path::Field { parent: path, name: "portworxVolume" }, changes);
+        crate::diff::Diff::diff(&self.quobyte, &other.quobyte, &crate::diff::Path::Field { parent: path, name: "quobyte" }, changes);
+        crate::diff::Diff::diff(&self.rbd, &other.rbd, &crate::diff::Path::Field { parent: path, name: "rbd" }, changes);
+        crate::diff::Diff::diff(&self.scale_io, &other.scale_io, &crate::diff::Path::Field { parent: path, name: "scaleIO" }, changes);
+        crate::diff::Diff::diff(&self.storage_class_name, &other.storage_class_name, &crate::diff::Path::Field { parent: path, name: "storageClassName" }, changes);
+        crate::diff::Diff::diff(&self.storageos, &other.storageos, &crate::diff::Path::Field { parent: path, name: "storageos" }, changes);
+        crate::diff::Diff::diff(&self.volume_attributes_class_name, &other.volume_attributes_class_name, &crate::diff::Path::Field { parent: path, name: "volumeAttributesClassName" }, changes);
+        crate::diff::Diff::diff(&self.volume_mode, &other.volume_mode, &crate::diff::Path::Field { parent: path, name: "volumeMode" }, changes);
+        crate::diff::Diff::diff(&self.vsphere_volume, &other.vsphere_volume, &crate::diff::Path::Field { parent: path, name: "vsphereVolume" }, changes);
+    }
+}
+
+impl PersistentVolumeSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PersistentVolumeSpec> {
+    /// The path of the `accessModes` field.
+    pub fn access_modes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("accessModes")
+    }
+
+    /// The path of the `awsElasticBlockStore` field.
+    pub fn aws_elastic_block_store(self) -> crate::FieldPath<crate::api::core::v1::AWSElasticBlockStoreVolumeSource> {
+        self.child("awsElasticBlockStore")
+    }
+
+    /// The path of the `azureDisk` field.
+    pub fn azure_disk(self) -> crate::FieldPath<crate::api::core::v1::AzureDiskVolumeSource> {
+        self.child("azureDisk")
+    }
+
+    /// The path of the `azureFile` field.
+    pub fn azure_file(self) -> crate::FieldPath<crate::api::core::v1::AzureFilePersistentVolumeSource> {
+        self.child("azureFile")
+    }
+
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("capacity")
+    }
+
+    /// The path of the `cephfs` field.
+    pub fn cephfs(self) -> crate::FieldPath<crate::api::core::v1::CephFSPersistentVolumeSource> {
+        self.child("cephfs")
+    }
+
+    /// The path of the `cinder` field.
+    pub fn cinder(self) -> crate::FieldPath<crate::api::core::v1::CinderPersistentVolumeSource> {
+        self.child("cinder")
+    }
+
+    /// The path of the `claimRef` field.
+    pub fn claim_ref(self) -> crate::FieldPath<crate::api::core::v1::ObjectReference> {
+        self.child("claimRef")
+    }
+
+    /// The path of the `csi` field.
+    pub fn csi(self) -> crate::FieldPath<crate::api::core::v1::CSIPersistentVolumeSource> {
+        self.child("csi")
+    }
+
+    /// The path of the `fc` field.
+    pub fn fc(self) -> crate::FieldPath<crate::api::core::v1::FCVolumeSource> {
+        self.child("fc")
+    }
+
+    /// The path of the `flexVolume` field.
+    pub fn flex_volume(self) -> crate::FieldPath<crate::api::core::v1::FlexPersistentVolumeSource> {
+        self.child("flexVolume")
+    }
+
+    /// The path of the `flocker` field.
+    pub fn flocker(self) -> crate::FieldPath<crate::api::core::v1::FlockerVolumeSource> {
+        self.child("flocker")
+    }
+
+    /// The path of the `gcePersistentDisk` field.
+    pub fn gce_persistent_disk(self) -> crate::FieldPath<crate::api::core::v1::GCEPersistentDiskVolumeSource> {
+        self.child("gcePersistentDisk")
+    }
+
+    /// The path of the `glusterfs` field.
+    pub fn glusterfs(self) -> crate::FieldPath<crate::api::core::v1::GlusterfsPersistentVolumeSource> {
+        self.child("glusterfs")
+    }
+
+    /// The path of the `hostPath` field.
+    pub fn host_path(self) -> crate::FieldPath<crate::api::core::v1::HostPathVolumeSource> {
+        self.child("hostPath")
+    }
+
+    /// The path of the `iscsi` field.
+    pub fn iscsi(self) -> crate::FieldPath<crate::api::core::v1::ISCSIPersistentVolumeSource> {
+        self.child("iscsi")
+    }
+
+    /// The path of the `local` field.
+    pub fn local(self) -> crate::FieldPath<crate::api::core::v1::LocalVolumeSource> {
+        self.child("local")
+    }
+
+    /// The path of the `mountOptions` field.
+    pub fn mount_options(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("mountOptions")
+    }
+
+    /// The path of the `nfs` field.
+    pub fn nfs(self) -> crate::FieldPath<crate::api::core::v1::NFSVolumeSource> {
+        self.child("nfs")
+    }
+
+    /// The path of the `nodeAffinity` field.
+    pub fn node_affinity(self) -> crate::FieldPath<crate::api::core::v1::VolumeNodeAffinity> {
+        self.child("nodeAffinity")
+    }
+
+    /// The path of the `persistentVolumeReclaimPolicy` field.
+    pub fn persistent_volume_reclaim_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("persistentVolumeReclaimPolicy")
+    }
+
+    /// The path of the `photonPersistentDisk` field.
+    pub fn photon_persistent_disk(self) -> crate::FieldPath<crate::api::core::v1::PhotonPersistentDiskVolumeSource> {
+        self.child("photonPersistentDisk")
+    }
+
+    /// The path of the `portworxVolume` field.
+    pub fn portworx_volume(self) -> crate::FieldPath<crate::api::core::v1::PortworxVolumeSource> {
+        self.child("portworxVolume")
+    }
+
+    /// The path of the `quobyte` field.
+    pub fn quobyte(self) -> crate::FieldPath<crate::api::core::v1::QuobyteVolumeSource> {
+        self.child("quobyte")
+    }
+
+    /// The path of the `rbd` field.
+    pub fn rbd(self) -> crate::FieldPath<crate::api::core::v1::RBDPersistentVolumeSource> {
+        self.child("rbd")
+    }
+
+    /// The path of the `scaleIO` field.
+    pub fn scale_io(self) -> crate::FieldPath<crate::api::core::v1::ScaleIOPersistentVolumeSource> {
+        self.child("scaleIO")
+    }
+
+    /// The path of the `storageClassName` field.
+    pub fn storage_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("storageClassName")
+    }
+
+    /// The path of the `storageos` field.
+    pub fn storageos(self) -> crate::FieldPath<crate::api::core::v1::StorageOSPersistentVolumeSource> {
+        self.child("storageos")
+    }
+
+    /// The path of the `volumeAttributesClassName` field.
+    pub fn volume_attributes_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeAttributesClassName")
+    }
+
+    /// The path of the `volumeMode` field.
+    pub fn volume_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeMode")
+    }
+
+    /// The path of the `vsphereVolume` field.
+    pub fn vsphere_volume(self) -> crate::FieldPath<crate::api::core::v1::VsphereVirtualDiskVolumeSource> {
+        self.child("vsphereVolume")
+    }
+}
+
+impl crate::Normalize for PersistentVolumeSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.access_modes, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.aws_elastic_block_store);
+        crate::Normalize::normalize(&mut self.azure_disk);
+        crate::Normalize::normalize(&mut self.azure_file);
+        crate::normalize_strategies::map::values(&mut self.capacity, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.cephfs);
+        crate::Normalize::normalize(&mut self.cinder);
+        crate::Normalize::normalize(&mut self.claim_ref);
+        crate::Normalize::normalize(&mut self.csi);
+        crate::Normalize::normalize(&mut self.fc);
+        crate::Normalize::normalize(&mut self.flex_volume);
+        crate::Normalize::normalize(&mut self.flocker);
+        crate::Normalize::normalize(&mut self.gce_persistent_disk);
+        crate::Normalize::normalize(&mut self.glusterfs);
+        crate::Normalize::normalize(&mut self.host_path);
+        crate::Normalize::normalize(&mut self.iscsi);
+        crate::Normalize::normalize(&mut self.local);
+        crate::normalize_strategies::list::atomic(&mut self.mount_options, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.nfs);
+        crate::Normalize::normalize(&mut self.node_affinity);
+        crate::Normalize::normalize(&mut self.persistent_volume_reclaim_policy);
+        crate::Normalize::normalize(&mut self.photon_persistent_disk);
+        crate::Normalize::normalize(&mut self.portworx_volume);
+        crate::Normalize::normalize(&mut self.quobyte);
+        crate::Normalize::normalize(&mut self.rbd);
+        crate::Normalize::normalize(&mut self.scale_io);
+        crate::Normalize::normalize(&mut self.storage_class_name);
+        crate::Normalize::normalize(&mut self.storageos);
+        crate::Normalize::normalize(&mut self.volume_attributes_class_name);
+        crate::Normalize::normalize(&mut self.volume_mode);
+        crate::Normalize::normalize(&mut self.vsphere_volume);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -514,6 +822,227 @@ impl crate::serde::Serialize for PersistentVolumeSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PersistentVolumeSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.gce_persistent_disk {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            if let Some(value) = &self.aws_elastic_block_store {
+                crate::protobuf::Field::encode_field(value, 2, &mut embedded);
+            }
+            if let Some(value) = &self.host_path {
+                crate::protobuf::Field::encode_field(value, 3, &mut embedded);
+            }
+            if let Some(value) = &self.glusterfs {
+                crate::protobuf::Field::encode_field(value, 4, &mut embedded);
+            }
+            if let Some(value) = &self.nfs {
+                crate::protobuf::Field::encode_field(value, 5, &mut embedded);
+            }
+            if let Some(value) = &self.rbd {
+                crate::protobuf::Field::encode_field(value, 6, &mut embedded);
+            }
+            if let Some(value) = &self.iscsi {
+                crate::protobuf::Field::encode_field(value, 7, &mut embedded);
+            }
+            if let Some(value) = &self.cinder {
+                crate::protobuf::Field::encode_field(value, 8, &mut embedded);
+            }
+            if let Some(value) = &self.cephfs {
+                crate::protobuf::Field::encode_field(value, 9, &mut embedded);
+            }
+            if let Some(value) = &self.fc {
+                crate::protobuf::Field::encode_field(value, 10, &mut embedded);
+            }
+            if let Some(value) = &self.flocker {
+                crate::protobuf::Field::encode_field(value, 11, &mut embedded);
+            }
+            if let Some(value) = &self.flex_volume {
+                crate::protobuf::Field::encode_field(value, 12, &mut embedded);
+            }
+            if let Some(value) = &self.azure_file {
+                crate::protobuf::Field::encode_field(value, 13, &mut embedded);
+            }
+            if let Some(value) = &self.vsphere_volume {
+                crate::protobuf::Field::encode_field(value, 14, &mut embedded);
+            }
+            if let Some(value) = &self.quobyte {
+                crate::protobuf::Field::encode_field(value, 15, &mut embedded);
+            }
+            if let Some(value) = &self.azure_disk {
+                crate::protobuf::Field::encode_field(value, 16, &mut embedded);
+            }
+            if let Some(value) = &self.photon_persistent_disk {
+                crate::protobuf::Field::encode_field(value, 17, &mut embedded);
+            }
+            if let Some(value) = &self.portworx_volume {
+                crate::protobuf::Field::encode_field(value, 18, &mut embedded);
+            }
+            if let Some(value) = &self.scale_io {
+                crate::protobuf::Field::encode_field(value, 19, &mut embedded);
+            }
+            if let Some(value) = &self.local {
+                crate::protobuf::Field::encode_field(value, 20, &mut embedded);
+            }
+            if let Some(value) = &self.storageos {
+                crate::protobuf::Field::encode_field(value, 21, &mut embedded);
+            }
+            if let Some(value) = &self.csi {
+                crate::protobuf::Field::encode_field(value, 22, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(2, &embedded, buf);
+        }
+        if let Some(value) = &self.access_modes {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.claim_ref {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.persistent_volume_reclaim_policy {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.storage_class_name {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.mount_options {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.volume_mode {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.node_affinity {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.volume_attributes_class_name {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_access_modes: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSource> = None;
+        let mut value_azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSource> = None;
+        let mut value_azure_file: Option<crate::api::core::v1::AzureFilePersistentVolumeSource> = None;
+        let mut value_capacity: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_cephfs: Option<crate::api::core::v1::CephFSPersistentVolumeSource> = None;
+        let mut value_cinder: Option<crate::api::core::v1::CinderPersistentVolumeSource> = None;
+        let mut value_claim_ref: Option<crate::api::core::v1::ObjectReference> = None;
+        let mut value_csi: Option<crate::api::core::v1::CSIPersistentVolumeSource> = None;
+        let mut value_fc: Option<crate::api::core::v1::FCVolumeSource> = None;
+        let mut value_flex_volume: Option<crate::api::core::v1::FlexPersistentVolumeSource> = None;
+        let mut value_flocker: Option<crate::api::core::v1::FlockerVolumeSource> = None;
+        let mut value_gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSource> = None;
+        let mut value_glusterfs: Option<crate::api::core::v1::GlusterfsPersistentVolumeSource> = None;
+        let mut value_host_path: Option<crate::api::core::v1::HostPathVolumeSource> = None;
+        let mut value_iscsi: Option<crate::api::core::v1::ISCSIPersistentVolumeSource> = None;
+        let mut value_local: Option<crate::api::core::v1::LocalVolumeSource> = None;
+        let mut value_mount_options: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_nfs: Option<crate::api::core::v1::NFSVolumeSource> = None;
+        let mut value_node_affinity: Option<crate::api::core::v1::VolumeNodeAffinity> = None;
+        let mut value_persistent_volume_reclaim_policy: Option<std::string::String> = None;
+        let mut value_photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource> = None;
+        let mut value_portworx_volume: Option<crate::api::core::v1::PortworxVolumeSource> = None;
+        let mut value_quobyte: Option<crate::api::core::v1::QuobyteVolumeSource> = None;
+        let mut value_rbd: Option<crate::api::core::v1::RBDPersistentVolumeSource> = None;
+        let mut value_scale_io: Option<crate::api::core::v1::ScaleIOPersistentVolumeSource> = None;
+        let mut value_storage_class_name: Option<std::string::String> = None;
+        let mut value_storageos: Option<crate::api::core::v1::StorageOSPersistentVolumeSource> = None;
+        let mut value_volume_attributes_class_name: Option<std::string::String> = None;
+        let mut value_volume_mode: Option<std::string::String> = None;
+        let mut value_vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                2 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_gce_persistent_disk, wire_type, &mut embedded)?,
+                            2 => crate::protobuf::Field::merge_field(&mut value_aws_elastic_block_store, wire_type, &mut embedded)?,
+                            3 => crate::protobuf::Field::merge_field(&mut value_host_path, wire_type, &mut embedded)?,
+                            4 => crate::protobuf::Field::merge_field(&mut value_glusterfs, wire_type, &mut embedded)?,
+                            5 => crate::protobuf::Field::merge_field(&mut value_nfs, wire_type, &mut embedded)?,
+                            6 => crate::protobuf::Field::merge_field(&mut value_rbd, wire_type, &mut embedded)?,
+                            7 => crate::protobuf::Field::merge_field(&mut value_iscsi, wire_type, &mut embedded)?,
+                            8 => crate::protobuf::Field::merge_field(&mut value_cinder, wire_type, &mut embedded)?,
+                            9 => crate::protobuf::Field::merge_field(&mut value_cephfs, wire_type, &mut embedded)?,
+                            10 => crate::protobuf::Field::merge_field(&mut value_fc, wire_type, &mut embedded)?,
+                            11 => crate::protobuf::Field::merge_field(&mut value_flocker, wire_type, &mut embedded)?,
+                            12 => crate::protobuf::Field::merge_field(&mut value_flex_volume, wire_type, &mut embedded)?,
+                            13 => crate::protobuf::Field::merge_field(&mut value_azure_file, wire_type, &mut embedded)?,
+                            14 => crate::protobuf::Field::merge_field(&mut value_vsphere_volume, wire_type, &mut embedded)?,
+                            15 => crate::protobuf::Field::merge_field(&mut value_quobyte, wire_type, &mut embedded)?,
+                            16 => crate::protobuf::Field::merge_field(&mut value_azure_disk, wire_type, &mut embedded)?,
+                            17 => crate::protobuf::Field::merge_field(&mut value_photon_persistent_disk, wire_type, &mut embedded)?,
+                            18 => crate::protobuf::Field::merge_field(&mut value_portworx_volume, wire_type, &mut embedded)?,
+                            19 => crate::protobuf::Field::merge_field(&mut value_scale_io, wire_type, &mut embedded)?,
+                            20 => crate::protobuf::Field::merge_field(&mut value_local, wire_type, &mut embedded)?,
+                            21 => crate::protobuf::Field::merge_field(&mut value_storageos, wire_type, &mut embedded)?,
+                            22 => crate::protobuf::Field::merge_field(&mut value_csi, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                3 => crate::protobuf::Field::merge_field(&mut value_access_modes, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_claim_ref, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_persistent_volume_reclaim_policy, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_storage_class_name, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_mount_options, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_volume_mode, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_node_affinity, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_volume_attributes_class_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PersistentVolumeSpec {
+            access_modes: value_access_modes,
+            aws_elastic_block_store: value_aws_elastic_block_store,
+            azure_disk: value_azure_disk,
+            azure_file: value_azure_file,
+            capacity: value_capacity,
+            cephfs: value_cephfs,
+            cinder: value_cinder,
+            claim_ref: value_claim_ref,
+            csi: value_csi,
+            fc: value_fc,
+            flex_volume: value_flex_volume,
+            flocker: value_flocker,
+            gce_persistent_disk: value_gce_persistent_disk,
+            glusterfs: value_glusterfs,
+            host_path: value_host_path,
+            iscsi: value_iscsi,
+            local: value_local,
+            mount_options: value_mount_options,
+            nfs: value_nfs,
+            node_affinity: value_node_affinity,
+            persistent_volume_reclaim_policy: value_persistent_volume_reclaim_policy,
+            photon_persistent_disk: value_photon_persistent_disk,
+            portworx_volume: value_portworx_volume,
+            quobyte: value_quobyte,
+            rbd: value_rbd,
+            scale_io: value_scale_io,
+            storage_class_name: value_storage_class_name,
+            storageos: value_storageos,
+            volume_attributes_class_name: value_volume_attributes_class_name,
+            volume_mode: value_volume_mode,
+            vsphere_volume: value_vsphere_volume,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PersistentVolumeSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
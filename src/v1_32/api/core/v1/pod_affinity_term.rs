@@ -33,6 +33,108 @@ impl crate::DeepMerge for PodAffinityTerm {
     }
 }
 
+impl crate::Defaulting for PodAffinityTerm {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.label_selector);
+        crate::Defaulting::set_defaults(&mut self.match_label_keys);
+        crate::Defaulting::set_defaults(&mut self.mismatch_label_keys);
+        crate::Defaulting::set_defaults(&mut self.namespace_selector);
+        crate::Defaulting::set_defaults(&mut self.namespaces);
+        crate::Defaulting::set_defaults(&mut self.topology_key);
+    }
+}
+
+impl crate::diff::Diff for PodAffinityTerm {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.label_selector, &other.label_selector, &crate::diff::Path::Field { parent: path, name: "labelSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.match_label_keys,
+            &other.match_label_keys,
+            &crate::diff::Path::Field { parent: path, name: "matchLabelKeys" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.mismatch_label_keys,
+            &other.mismatch_label_keys,
+            &crate::diff::Path::Field { parent: path, name: "mismatchLabelKeys" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.namespace_selector, &other.namespace_selector, &crate::diff::Path::Field { parent: path, name: "namespaceSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.namespaces,
+            &other.namespaces,
+            &crate::diff::Path::Field { parent: path, name: "namespaces" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.topology_key, &other.topology_key, &crate::diff::Path::Field { parent: path, name: "topologyKey" }, changes);
+    }
+}
+
+impl PodAffinityTerm {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodAffinityTerm> {
+    /// The path of the `labelSelector` field.
+    pub fn label_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("labelSelector")
+    }
+
+    /// The path of the `matchLabelKeys` field.
+    pub fn match_label_keys(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("matchLabelKeys")
+    }
+
+    /// The path of the `mismatchLabelKeys` field.
+    pub fn mismatch_label_keys(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("mismatchLabelKeys")
+    }
+
+    /// The path of the `namespaceSelector` field.
+    pub fn namespace_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("namespaceSelector")
+    }
+
+    /// The path of the `namespaces` field.
+    pub fn namespaces(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("namespaces")
+    }
+
+    /// The path of the `topologyKey` field.
+    pub fn topology_key(self) -> crate::FieldPath<std::string::String> {
+        self.child("topologyKey")
+    }
+}
+
+impl crate::Normalize for PodAffinityTerm {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.label_selector);
+        crate::normalize_strategies::list::atomic(&mut self.match_label_keys, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.mismatch_label_keys, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.namespace_selector);
+        crate::normalize_strategies::list::atomic(&mut self.namespaces, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.topology_key);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodAffinityTerm {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -160,6 +262,62 @@ impl crate::serde::Serialize for PodAffinityTerm {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodAffinityTerm {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.label_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.namespaces {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.topology_key, 3, buf);
+        if let Some(value) = &self.namespace_selector {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.match_label_keys {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.mismatch_label_keys {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_match_label_keys: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_mismatch_label_keys: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_namespaces: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_topology_key: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_label_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_namespaces, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_topology_key, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_namespace_selector, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_match_label_keys, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_mismatch_label_keys, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodAffinityTerm {
+            label_selector: value_label_selector,
+            match_label_keys: value_match_label_keys,
+            mismatch_label_keys: value_mismatch_label_keys,
+            namespace_selector: value_namespace_selector,
+            namespaces: value_namespaces,
+            topology_key: value_topology_key.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodAffinityTerm {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodAffinityTerm {
     fn schema_name() -> std::borrow::Cow<'static, str> {
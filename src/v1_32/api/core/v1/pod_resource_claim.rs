@@ -31,6 +31,54 @@ impl crate::DeepMerge for PodResourceClaim {
     }
 }
 
+impl crate::Defaulting for PodResourceClaim {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.resource_claim_name);
+        crate::Defaulting::set_defaults(&mut self.resource_claim_template_name);
+    }
+}
+
+impl crate::diff::Diff for PodResourceClaim {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.resource_claim_name, &other.resource_claim_name, &crate::diff::Path::Field { parent: path, name: "resourceClaimName" }, changes);
+        crate::diff::Diff::diff(&self.resource_claim_template_name, &other.resource_claim_template_name, &crate::diff::Path::Field { parent: path, name: "resourceClaimTemplateName" }, changes);
+    }
+}
+
+impl PodResourceClaim {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodResourceClaim> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `resourceClaimName` field.
+    pub fn resource_claim_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceClaimName")
+    }
+
+    /// The path of the `resourceClaimTemplateName` field.
+    pub fn resource_claim_template_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceClaimTemplateName")
+    }
+}
+
+impl crate::Normalize for PodResourceClaim {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.resource_claim_name);
+        crate::Normalize::normalize(&mut self.resource_claim_template_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodResourceClaim {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -128,6 +176,44 @@ impl crate::serde::Serialize for PodResourceClaim {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodResourceClaim {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.resource_claim_name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.resource_claim_template_name {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_resource_claim_name: Option<std::string::String> = None;
+        let mut value_resource_claim_template_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource_claim_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_resource_claim_template_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodResourceClaim {
+            name: value_name.unwrap_or_default(),
+            resource_claim_name: value_resource_claim_name,
+            resource_claim_template_name: value_resource_claim_template_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodResourceClaim {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodResourceClaim {
     fn schema_name() -> std::borrow::Cow<'static, str> {
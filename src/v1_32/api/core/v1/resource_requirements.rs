@@ -36,6 +36,85 @@ impl crate::DeepMerge for ResourceRequirements {
     }
 }
 
+impl crate::Defaulting for ResourceRequirements {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.claims);
+        crate::Defaulting::set_defaults(&mut self.limits);
+        crate::Defaulting::set_defaults(&mut self.requests);
+    }
+}
+
+impl crate::diff::Diff for ResourceRequirements {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.claims,
+            &other.claims,
+            &crate::diff::Path::Field { parent: path, name: "claims" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.limits,
+            &other.limits,
+            &crate::diff::Path::Field { parent: path, name: "limits" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.requests,
+            &other.requests,
+            &crate::diff::Path::Field { parent: path, name: "requests" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceRequirements {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceRequirements> {
+    /// The path of the `claims` field.
+    pub fn claims(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ResourceClaim>> {
+        self.child("claims")
+    }
+
+    /// The path of the `limits` field.
+    pub fn limits(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("limits")
+    }
+
+    /// The path of the `requests` field.
+    pub fn requests(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("requests")
+    }
+}
+
+impl crate::Normalize for ResourceRequirements {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.claims, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::map::values(&mut self.limits, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.requests, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceRequirements {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -135,6 +214,56 @@ impl crate::serde::Serialize for ResourceRequirements {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceRequirements {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.limits {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.requests {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.claims {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_claims: Option<std::vec::Vec<crate::api::core::v1::ResourceClaim>> = None;
+        let mut value_limits: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_requests: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_limits, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_requests, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_claims, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceRequirements {
+            claims: value_claims,
+            limits: value_limits,
+            requests: value_requests,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceRequirements {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "claims" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceRequirements {
     fn schema_name() -> std::borrow::Cow<'static, str> {
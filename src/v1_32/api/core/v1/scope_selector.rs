@@ -13,6 +13,48 @@ impl crate::DeepMerge for ScopeSelector {
     }
 }
 
+impl crate::Defaulting for ScopeSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.match_expressions);
+    }
+}
+
+impl crate::diff::Diff for ScopeSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.match_expressions,
+            &other.match_expressions,
+            &crate::diff::Path::Field { parent: path, name: "matchExpressions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ScopeSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ScopeSelector> {
+    /// The path of the `matchExpressions` field.
+    pub fn match_expressions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>> {
+        self.child("matchExpressions")
+    }
+}
+
+impl crate::Normalize for ScopeSelector {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.match_expressions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ScopeSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for ScopeSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ScopeSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.match_expressions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_match_expressions: Option<std::vec::Vec<crate::api::core::v1::ScopedResourceSelectorRequirement>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_match_expressions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ScopeSelector {
+            match_expressions: value_match_expressions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ScopeSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ScopeSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
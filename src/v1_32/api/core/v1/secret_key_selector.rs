@@ -21,6 +21,54 @@ impl crate::DeepMerge for SecretKeySelector {
     }
 }
 
+impl crate::Defaulting for SecretKeySelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.optional);
+    }
+}
+
+impl crate::diff::Diff for SecretKeySelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.optional, &other.optional, &crate::diff::Path::Field { parent: path, name: "optional" }, changes);
+    }
+}
+
+impl SecretKeySelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SecretKeySelector> {
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `optional` field.
+    pub fn optional(self) -> crate::FieldPath<bool> {
+        self.child("optional")
+    }
+}
+
+impl crate::Normalize for SecretKeySelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.optional);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SecretKeySelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +163,55 @@ impl crate::serde::Serialize for SecretKeySelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SecretKeySelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        {
+            let mut embedded = std::vec![];
+            crate::protobuf::Field::encode_field(&self.name, 1, &mut embedded);
+            crate::protobuf::encode_length_delimited(1, &embedded, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.key, 2, buf);
+        if let Some(value) = &self.optional {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_optional: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                2 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_optional, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SecretKeySelector {
+            key: value_key.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            optional: value_optional,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SecretKeySelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SecretKeySelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
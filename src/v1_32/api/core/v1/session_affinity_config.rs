@@ -13,6 +13,38 @@ impl crate::DeepMerge for SessionAffinityConfig {
     }
 }
 
+impl crate::Defaulting for SessionAffinityConfig {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.client_ip);
+    }
+}
+
+impl crate::diff::Diff for SessionAffinityConfig {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.client_ip, &other.client_ip, &crate::diff::Path::Field { parent: path, name: "clientIP" }, changes);
+    }
+}
+
+impl SessionAffinityConfig {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SessionAffinityConfig> {
+    /// The path of the `clientIP` field.
+    pub fn client_ip(self) -> crate::FieldPath<crate::api::core::v1::ClientIPConfig> {
+        self.child("clientIP")
+    }
+}
+
+impl crate::Normalize for SessionAffinityConfig {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.client_ip);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SessionAffinityConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for SessionAffinityConfig {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SessionAffinityConfig {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.client_ip {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_client_ip: Option<crate::api::core::v1::ClientIPConfig> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_client_ip, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SessionAffinityConfig {
+            client_ip: value_client_ip,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SessionAffinityConfig {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SessionAffinityConfig {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for SleepAction {
     }
 }
 
+impl crate::Defaulting for SleepAction {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.seconds);
+    }
+}
+
+impl crate::diff::Diff for SleepAction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.seconds, &other.seconds, &crate::diff::Path::Field { parent: path, name: "seconds" }, changes);
+    }
+}
+
+impl SleepAction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SleepAction> {
+    /// The path of the `seconds` field.
+    pub fn seconds(self) -> crate::FieldPath<i64> {
+        self.child("seconds")
+    }
+}
+
+impl crate::Normalize for SleepAction {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SleepAction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for SleepAction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SleepAction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.seconds, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_seconds: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SleepAction {
+            seconds: value_seconds.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SleepAction {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SleepAction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
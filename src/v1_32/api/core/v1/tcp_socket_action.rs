@@ -17,6 +17,46 @@ impl crate::DeepMerge for TCPSocketAction {
     }
 }
 
+impl crate::Defaulting for TCPSocketAction {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.host);
+        crate::Defaulting::set_defaults(&mut self.port);
+    }
+}
+
+impl crate::diff::Diff for TCPSocketAction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.host, &other.host, &crate::diff::Path::Field { parent: path, name: "host" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+    }
+}
+
+impl TCPSocketAction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TCPSocketAction> {
+    /// The path of the `host` field.
+    pub fn host(self) -> crate::FieldPath<std::string::String> {
+        self.child("host")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("port")
+    }
+}
+
+impl crate::Normalize for TCPSocketAction {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.host);
+        crate::Normalize::normalize(&mut self.port);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TCPSocketAction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for TCPSocketAction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TCPSocketAction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.port, 1, buf);
+        if let Some(value) = &self.host {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_host: Option<std::string::String> = None;
+        let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_host, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TCPSocketAction {
+            host: value_host,
+            port: value_port.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TCPSocketAction {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TCPSocketAction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
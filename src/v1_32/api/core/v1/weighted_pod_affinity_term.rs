@@ -17,6 +17,46 @@ impl crate::DeepMerge for WeightedPodAffinityTerm {
     }
 }
 
+impl crate::Defaulting for WeightedPodAffinityTerm {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.pod_affinity_term);
+        crate::Defaulting::set_defaults(&mut self.weight);
+    }
+}
+
+impl crate::diff::Diff for WeightedPodAffinityTerm {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.pod_affinity_term, &other.pod_affinity_term, &crate::diff::Path::Field { parent: path, name: "podAffinityTerm" }, changes);
+        crate::diff::Diff::diff(&self.weight, &other.weight, &crate::diff::Path::Field { parent: path, name: "weight" }, changes);
+    }
+}
+
+impl WeightedPodAffinityTerm {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WeightedPodAffinityTerm> {
+    /// The path of the `podAffinityTerm` field.
+    pub fn pod_affinity_term(self) -> crate::FieldPath<crate::api::core::v1::PodAffinityTerm> {
+        self.child("podAffinityTerm")
+    }
+
+    /// The path of the `weight` field.
+    pub fn weight(self) -> crate::FieldPath<i32> {
+        self.child("weight")
+    }
+}
+
+impl crate::Normalize for WeightedPodAffinityTerm {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.pod_affinity_term);
+        crate::Normalize::normalize(&mut self.weight);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WeightedPodAffinityTerm {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for WeightedPodAffinityTerm {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WeightedPodAffinityTerm {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.weight, 1, buf);
+        crate::protobuf::Field::encode_field(&self.pod_affinity_term, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_pod_affinity_term: Option<crate::api::core::v1::PodAffinityTerm> = None;
+        let mut value_weight: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_weight, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_affinity_term, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WeightedPodAffinityTerm {
+            pod_affinity_term: value_pod_affinity_term.unwrap_or_default(),
+            weight: value_weight.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WeightedPodAffinityTerm {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WeightedPodAffinityTerm {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for EndpointConditions {
     }
 }
 
+impl crate::Defaulting for EndpointConditions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ready);
+        crate::Defaulting::set_defaults(&mut self.serving);
+        crate::Defaulting::set_defaults(&mut self.terminating);
+    }
+}
+
+impl crate::diff::Diff for EndpointConditions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ready, &other.ready, &crate::diff::Path::Field { parent: path, name: "ready" }, changes);
+        crate::diff::Diff::diff(&self.serving, &other.serving, &crate::diff::Path::Field { parent: path, name: "serving" }, changes);
+        crate::diff::Diff::diff(&self.terminating, &other.terminating, &crate::diff::Path::Field { parent: path, name: "terminating" }, changes);
+    }
+}
+
+impl EndpointConditions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointConditions> {
+    /// The path of the `ready` field.
+    pub fn ready(self) -> crate::FieldPath<bool> {
+        self.child("ready")
+    }
+
+    /// The path of the `serving` field.
+    pub fn serving(self) -> crate::FieldPath<bool> {
+        self.child("serving")
+    }
+
+    /// The path of the `terminating` field.
+    pub fn terminating(self) -> crate::FieldPath<bool> {
+        self.child("terminating")
+    }
+}
+
+impl crate::Normalize for EndpointConditions {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ready);
+        crate::Normalize::normalize(&mut self.serving);
+        crate::Normalize::normalize(&mut self.terminating);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointConditions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for EndpointConditions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointConditions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.ready {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.serving {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.terminating {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ready: Option<bool> = None;
+        let mut value_serving: Option<bool> = None;
+        let mut value_terminating: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ready, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_serving, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_terminating, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointConditions {
+            ready: value_ready,
+            serving: value_serving,
+            terminating: value_terminating,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointConditions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointConditions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -50,6 +50,86 @@ impl crate::DeepMerge for EndpointSlice {
     }
 }
 
+impl crate::Defaulting for EndpointSlice {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.address_type);
+        crate::Defaulting::set_defaults(&mut self.endpoints);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.ports);
+    }
+}
+
+impl crate::diff::Diff for EndpointSlice {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.address_type, &other.address_type, &crate::diff::Path::Field { parent: path, name: "addressType" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.endpoints,
+            &other.endpoints,
+            &crate::diff::Path::Field { parent: path, name: "endpoints" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl EndpointSlice {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointSlice> {
+    /// The path of the `addressType` field.
+    pub fn address_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("addressType")
+    }
+
+    /// The path of the `endpoints` field.
+    pub fn endpoints(self) -> crate::FieldPath<std::vec::Vec<crate::api::discovery::v1::Endpoint>> {
+        self.child("endpoints")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::discovery::v1::EndpointPort>> {
+        self.child("ports")
+    }
+}
+
+impl crate::Normalize for EndpointSlice {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.address_type);
+        crate::normalize_strategies::list::atomic(&mut self.endpoints, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::list::atomic(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointSlice {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -171,6 +251,56 @@ impl crate::serde::Serialize for EndpointSlice {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointSlice {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.endpoints, 2, buf);
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.address_type, 4, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_address_type: Option<std::string::String> = None;
+        let mut value_endpoints: Option<std::vec::Vec<crate::api::discovery::v1::Endpoint>> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_ports: Option<std::vec::Vec<crate::api::discovery::v1::EndpointPort>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_endpoints, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_address_type, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointSlice {
+            address_type: value_address_type.unwrap_or_default(),
+            endpoints: value_endpoints.unwrap_or_default(),
+            metadata: value_metadata.unwrap_or_default(),
+            ports: value_ports,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointSlice {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointSlice {
     fn schema_name() -> std::borrow::Cow<'static, str> {
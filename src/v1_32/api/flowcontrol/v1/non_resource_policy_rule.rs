@@ -23,6 +23,50 @@ impl crate::DeepMerge for NonResourcePolicyRule {
     }
 }
 
+impl crate::Defaulting for NonResourcePolicyRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.non_resource_urls);
+        crate::Defaulting::set_defaults(&mut self.verbs);
+    }
+}
+
+impl crate::diff::Diff for NonResourcePolicyRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::set(&self.non_resource_urls, &other.non_resource_urls, &crate::diff::Path::Field { parent: path, name: "nonResourceURLs" }, changes);
+        crate::diff::strategies::list::set(&self.verbs, &other.verbs, &crate::diff::Path::Field { parent: path, name: "verbs" }, changes);
+    }
+}
+
+impl NonResourcePolicyRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NonResourcePolicyRule> {
+    /// The path of the `nonResourceURLs` field.
+    pub fn non_resource_urls(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("nonResourceURLs")
+    }
+
+    /// The path of the `verbs` field.
+    pub fn verbs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("verbs")
+    }
+}
+
+impl crate::Normalize for NonResourcePolicyRule {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::set(&mut self.non_resource_urls, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::set(&mut self.verbs, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NonResourcePolicyRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -107,6 +151,36 @@ impl crate::serde::Serialize for NonResourcePolicyRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NonResourcePolicyRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.verbs, 1, buf);
+        crate::protobuf::Field::encode_field(&self.non_resource_urls, 6, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_non_resource_urls: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_verbs: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_verbs, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_non_resource_urls, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NonResourcePolicyRule {
+            non_resource_urls: value_non_resource_urls.unwrap_or_default(),
+            verbs: value_verbs.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NonResourcePolicyRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NonResourcePolicyRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
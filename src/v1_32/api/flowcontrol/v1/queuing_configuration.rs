@@ -21,6 +21,54 @@ impl crate::DeepMerge for QueuingConfiguration {
     }
 }
 
+impl crate::Defaulting for QueuingConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hand_size);
+        crate::Defaulting::set_defaults(&mut self.queue_length_limit);
+        crate::Defaulting::set_defaults(&mut self.queues);
+    }
+}
+
+impl crate::diff::Diff for QueuingConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.hand_size, &other.hand_size, &crate::diff::Path::Field { parent: path, name: "handSize" }, changes);
+        crate::diff::Diff::diff(&self.queue_length_limit, &other.queue_length_limit, &crate::diff::Path::Field { parent: path, name: "queueLengthLimit" }, changes);
+        crate::diff::Diff::diff(&self.queues, &other.queues, &crate::diff::Path::Field { parent: path, name: "queues" }, changes);
+    }
+}
+
+impl QueuingConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<QueuingConfiguration> {
+    /// The path of the `handSize` field.
+    pub fn hand_size(self) -> crate::FieldPath<i32> {
+        self.child("handSize")
+    }
+
+    /// The path of the `queueLengthLimit` field.
+    pub fn queue_length_limit(self) -> crate::FieldPath<i32> {
+        self.child("queueLengthLimit")
+    }
+
+    /// The path of the `queues` field.
+    pub fn queues(self) -> crate::FieldPath<i32> {
+        self.child("queues")
+    }
+}
+
+impl crate::Normalize for QueuingConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.hand_size);
+        crate::Normalize::normalize(&mut self.queue_length_limit);
+        crate::Normalize::normalize(&mut self.queues);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for QueuingConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for QueuingConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for QueuingConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.queues {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.hand_size {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.queue_length_limit {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hand_size: Option<i32> = None;
+        let mut value_queue_length_limit: Option<i32> = None;
+        let mut value_queues: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_queues, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_hand_size, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_queue_length_limit, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(QueuingConfiguration {
+            hand_size: value_hand_size,
+            queue_length_limit: value_queue_length_limit,
+            queues: value_queues,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for QueuingConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for QueuingConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,78 @@ impl crate::DeepMerge for ResourcePolicyRule {
     }
 }
 
+impl crate::Defaulting for ResourcePolicyRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_groups);
+        crate::Defaulting::set_defaults(&mut self.cluster_scope);
+        crate::Defaulting::set_defaults(&mut self.namespaces);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.verbs);
+    }
+}
+
+impl crate::diff::Diff for ResourcePolicyRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::set(&self.api_groups, &other.api_groups, &crate::diff::Path::Field { parent: path, name: "apiGroups" }, changes);
+        crate::diff::Diff::diff(&self.cluster_scope, &other.cluster_scope, &crate::diff::Path::Field { parent: path, name: "clusterScope" }, changes);
+        crate::diff::strategies::list::set(&self.namespaces, &other.namespaces, &crate::diff::Path::Field { parent: path, name: "namespaces" }, changes);
+        crate::diff::strategies::list::set(&self.resources, &other.resources, &crate::diff::Path::Field { parent: path, name: "resources" }, changes);
+        crate::diff::strategies::list::set(&self.verbs, &other.verbs, &crate::diff::Path::Field { parent: path, name: "verbs" }, changes);
+    }
+}
+
+impl ResourcePolicyRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourcePolicyRule> {
+    /// The path of the `apiGroups` field.
+    pub fn api_groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("apiGroups")
+    }
+
+    /// The path of the `clusterScope` field.
+    pub fn cluster_scope(self) -> crate::FieldPath<bool> {
+        self.child("clusterScope")
+    }
+
+    /// The path of the `namespaces` field.
+    pub fn namespaces(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("namespaces")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("resources")
+    }
+
+    /// The path of the `verbs` field.
+    pub fn verbs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("verbs")
+    }
+}
+
+impl crate::Normalize for ResourcePolicyRule {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::set(&mut self.api_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.cluster_scope);
+        crate::normalize_strategies::list::set(&mut self.namespaces, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::set(&mut self.resources, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::set(&mut self.verbs, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourcePolicyRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -140,6 +212,52 @@ impl crate::serde::Serialize for ResourcePolicyRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourcePolicyRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.verbs, 1, buf);
+        crate::protobuf::Field::encode_field(&self.api_groups, 2, buf);
+        crate::protobuf::Field::encode_field(&self.resources, 3, buf);
+        if let Some(value) = &self.cluster_scope {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.namespaces {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_cluster_scope: Option<bool> = None;
+        let mut value_namespaces: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_resources: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_verbs: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_verbs, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_api_groups, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_cluster_scope, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_namespaces, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourcePolicyRule {
+            api_groups: value_api_groups.unwrap_or_default(),
+            cluster_scope: value_cluster_scope,
+            namespaces: value_namespaces,
+            resources: value_resources.unwrap_or_default(),
+            verbs: value_verbs.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourcePolicyRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourcePolicyRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
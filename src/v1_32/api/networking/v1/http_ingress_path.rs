@@ -31,6 +31,54 @@ impl crate::DeepMerge for HTTPIngressPath {
     }
 }
 
+impl crate::Defaulting for HTTPIngressPath {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.backend);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.path_type);
+    }
+}
+
+impl crate::diff::Diff for HTTPIngressPath {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.backend, &other.backend, &crate::diff::Path::Field { parent: path, name: "backend" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.path_type, &other.path_type, &crate::diff::Path::Field { parent: path, name: "pathType" }, changes);
+    }
+}
+
+impl HTTPIngressPath {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HTTPIngressPath> {
+    /// The path of the `backend` field.
+    pub fn backend(self) -> crate::FieldPath<crate::api::networking::v1::IngressBackend> {
+        self.child("backend")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `pathType` field.
+    pub fn path_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("pathType")
+    }
+}
+
+impl crate::Normalize for HTTPIngressPath {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.backend);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.path_type);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HTTPIngressPath {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -125,6 +173,42 @@ impl crate::serde::Serialize for HTTPIngressPath {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HTTPIngressPath {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.path {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.backend, 2, buf);
+        crate::protobuf::Field::encode_field(&self.path_type, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_backend: Option<crate::api::networking::v1::IngressBackend> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_path_type: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_backend, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_path_type, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HTTPIngressPath {
+            backend: value_backend.unwrap_or_default(),
+            path: value_path,
+            path_type: value_path_type.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HTTPIngressPath {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPIngressPath {
     fn schema_name() -> std::borrow::Cow<'static, str> {
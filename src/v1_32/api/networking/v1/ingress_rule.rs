@@ -23,6 +23,46 @@ impl crate::DeepMerge for IngressRule {
     }
 }
 
+impl crate::Defaulting for IngressRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.host);
+        crate::Defaulting::set_defaults(&mut self.http);
+    }
+}
+
+impl crate::diff::Diff for IngressRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.host, &other.host, &crate::diff::Path::Field { parent: path, name: "host" }, changes);
+        crate::diff::Diff::diff(&self.http, &other.http, &crate::diff::Path::Field { parent: path, name: "http" }, changes);
+    }
+}
+
+impl IngressRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressRule> {
+    /// The path of the `host` field.
+    pub fn host(self) -> crate::FieldPath<std::string::String> {
+        self.child("host")
+    }
+
+    /// The path of the `http` field.
+    pub fn http(self) -> crate::FieldPath<crate::api::networking::v1::HTTPIngressRuleValue> {
+        self.child("http")
+    }
+}
+
+impl crate::Normalize for IngressRule {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.host);
+        crate::Normalize::normalize(&mut self.http);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +152,53 @@ impl crate::serde::Serialize for IngressRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.host {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.http {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(2, &embedded, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_host: Option<std::string::String> = None;
+        let mut value_http: Option<crate::api::networking::v1::HTTPIngressRuleValue> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_host, wire_type, &mut buf)?,
+                2 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_http, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressRule {
+            host: value_host,
+            http: value_http,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
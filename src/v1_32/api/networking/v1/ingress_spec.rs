@@ -25,6 +25,82 @@ impl crate::DeepMerge for IngressSpec {
     }
 }
 
+impl crate::Defaulting for IngressSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.default_backend);
+        crate::Defaulting::set_defaults(&mut self.ingress_class_name);
+        crate::Defaulting::set_defaults(&mut self.rules);
+        crate::Defaulting::set_defaults(&mut self.tls);
+    }
+}
+
+impl crate::diff::Diff for IngressSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.default_backend, &other.default_backend, &crate::diff::Path::Field { parent: path, name: "defaultBackend" }, changes);
+        crate::diff::Diff::diff(&self.ingress_class_name, &other.ingress_class_name, &crate::diff::Path::Field { parent: path, name: "ingressClassName" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.rules,
+            &other.rules,
+            &crate::diff::Path::Field { parent: path, name: "rules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.tls,
+            &other.tls,
+            &crate::diff::Path::Field { parent: path, name: "tls" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl IngressSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressSpec> {
+    /// The path of the `defaultBackend` field.
+    pub fn default_backend(self) -> crate::FieldPath<crate::api::networking::v1::IngressBackend> {
+        self.child("defaultBackend")
+    }
+
+    /// The path of the `ingressClassName` field.
+    pub fn ingress_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("ingressClassName")
+    }
+
+    /// The path of the `rules` field.
+    pub fn rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::IngressRule>> {
+        self.child("rules")
+    }
+
+    /// The path of the `tls` field.
+    pub fn tls(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::IngressTLS>> {
+        self.child("tls")
+    }
+}
+
+impl crate::Normalize for IngressSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.default_backend);
+        crate::Normalize::normalize(&mut self.ingress_class_name);
+        crate::normalize_strategies::list::atomic(&mut self.rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.tls, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +210,52 @@ impl crate::serde::Serialize for IngressSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.default_backend {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.tls {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.rules {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.ingress_class_name {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default_backend: Option<crate::api::networking::v1::IngressBackend> = None;
+        let mut value_ingress_class_name: Option<std::string::String> = None;
+        let mut value_rules: Option<std::vec::Vec<crate::api::networking::v1::IngressRule>> = None;
+        let mut value_tls: Option<std::vec::Vec<crate::api::networking::v1::IngressTLS>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_default_backend, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_tls, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_rules, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_ingress_class_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressSpec {
+            default_backend: value_default_backend,
+            ingress_class_name: value_ingress_class_name,
+            rules: value_rules,
+            tls: value_tls,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,56 @@ impl crate::DeepMerge for IPBlock {
     }
 }
 
+impl crate::Defaulting for IPBlock {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.cidr);
+        crate::Defaulting::set_defaults(&mut self.except);
+    }
+}
+
+impl crate::diff::Diff for IPBlock {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.cidr, &other.cidr, &crate::diff::Path::Field { parent: path, name: "cidr" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.except,
+            &other.except,
+            &crate::diff::Path::Field { parent: path, name: "except" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl IPBlock {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IPBlock> {
+    /// The path of the `cidr` field.
+    pub fn cidr(self) -> crate::FieldPath<std::string::String> {
+        self.child("cidr")
+    }
+
+    /// The path of the `except` field.
+    pub fn except(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("except")
+    }
+}
+
+impl crate::Normalize for IPBlock {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.cidr);
+        crate::normalize_strategies::list::atomic(&mut self.except, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IPBlock {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +154,38 @@ impl crate::serde::Serialize for IPBlock {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IPBlock {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.cidr, 1, buf);
+        if let Some(value) = &self.except {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_cidr: Option<std::string::String> = None;
+        let mut value_except: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_cidr, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_except, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IPBlock {
+            cidr: value_cidr.unwrap_or_default(),
+            except: value_except,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IPBlock {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IPBlock {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for ParentReference {
     }
 }
 
+impl crate::Defaulting for ParentReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace);
+        crate::Defaulting::set_defaults(&mut self.resource);
+    }
+}
+
+impl crate::diff::Diff for ParentReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+    }
+}
+
+impl ParentReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ParentReference> {
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+}
+
+impl crate::Normalize for ParentReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace);
+        crate::Normalize::normalize(&mut self.resource);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ParentReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +185,48 @@ impl crate::serde::Serialize for ParentReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ParentReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.resource, 2, buf);
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.name, 4, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace: Option<std::string::String> = None;
+        let mut value_resource: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ParentReference {
+            group: value_group,
+            name: value_name.unwrap_or_default(),
+            namespace: value_namespace,
+            resource: value_resource.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ParentReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ParentReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -51,6 +51,66 @@ impl crate::DeepMerge for RuntimeClass {
     }
 }
 
+impl crate::Defaulting for RuntimeClass {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.handler);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.overhead);
+        crate::Defaulting::set_defaults(&mut self.scheduling);
+    }
+}
+
+impl crate::diff::Diff for RuntimeClass {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.handler, &other.handler, &crate::diff::Path::Field { parent: path, name: "handler" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.overhead, &other.overhead, &crate::diff::Path::Field { parent: path, name: "overhead" }, changes);
+        crate::diff::Diff::diff(&self.scheduling, &other.scheduling, &crate::diff::Path::Field { parent: path, name: "scheduling" }, changes);
+    }
+}
+
+impl RuntimeClass {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<RuntimeClass> {
+    /// The path of the `handler` field.
+    pub fn handler(self) -> crate::FieldPath<std::string::String> {
+        self.child("handler")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `overhead` field.
+    pub fn overhead(self) -> crate::FieldPath<crate::api::node::v1::Overhead> {
+        self.child("overhead")
+    }
+
+    /// The path of the `scheduling` field.
+    pub fn scheduling(self) -> crate::FieldPath<crate::api::node::v1::Scheduling> {
+        self.child("scheduling")
+    }
+}
+
+impl crate::Normalize for RuntimeClass {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.handler);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.overhead);
+        crate::Normalize::normalize(&mut self.scheduling);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RuntimeClass {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -175,6 +235,58 @@ impl crate::serde::Serialize for RuntimeClass {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for RuntimeClass {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.handler, 2, buf);
+        if let Some(value) = &self.overhead {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.scheduling {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_handler: Option<std::string::String> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_overhead: Option<crate::api::node::v1::Overhead> = None;
+        let mut value_scheduling: Option<crate::api::node::v1::Scheduling> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_handler, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_overhead, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_scheduling, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(RuntimeClass {
+            handler: value_handler.unwrap_or_default(),
+            metadata: value_metadata.unwrap_or_default(),
+            overhead: value_overhead,
+            scheduling: value_scheduling,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for RuntimeClass {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RuntimeClass {
     fn schema_name() -> std::borrow::Cow<'static, str> {
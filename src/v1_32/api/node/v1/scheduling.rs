@@ -17,6 +17,66 @@ impl crate::DeepMerge for Scheduling {
     }
 }
 
+impl crate::Defaulting for Scheduling {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.node_selector);
+        crate::Defaulting::set_defaults(&mut self.tolerations);
+    }
+}
+
+impl crate::diff::Diff for Scheduling {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.node_selector,
+            &other.node_selector,
+            &crate::diff::Path::Field { parent: path, name: "nodeSelector" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.tolerations,
+            &other.tolerations,
+            &crate::diff::Path::Field { parent: path, name: "tolerations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl Scheduling {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Scheduling> {
+    /// The path of the `nodeSelector` field.
+    pub fn node_selector(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("nodeSelector")
+    }
+
+    /// The path of the `tolerations` field.
+    pub fn tolerations(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Toleration>> {
+        self.child("tolerations")
+    }
+}
+
+impl crate::Normalize for Scheduling {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.node_selector, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.tolerations, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Scheduling {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for Scheduling {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Scheduling {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.node_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.tolerations {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_node_selector: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_tolerations: Option<std::vec::Vec<crate::api::core::v1::Toleration>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_node_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_tolerations, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Scheduling {
+            node_selector: value_node_selector,
+            tolerations: value_tolerations,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Scheduling {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Scheduling {
     fn schema_name() -> std::borrow::Cow<'static, str> {
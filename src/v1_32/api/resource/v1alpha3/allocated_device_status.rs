@@ -46,6 +46,89 @@ impl crate::DeepMerge for AllocatedDeviceStatus {
     }
 }
 
+impl crate::Defaulting for AllocatedDeviceStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.data);
+        crate::Defaulting::set_defaults(&mut self.device);
+        crate::Defaulting::set_defaults(&mut self.driver);
+        crate::Defaulting::set_defaults(&mut self.network_data);
+        crate::Defaulting::set_defaults(&mut self.pool);
+    }
+}
+
+impl crate::diff::Diff for AllocatedDeviceStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.data, &other.data, &crate::diff::Path::Field { parent: path, name: "data" }, changes);
+        crate::diff::Diff::diff(&self.device, &other.device, &crate::diff::Path::Field { parent: path, name: "device" }, changes);
+        crate::diff::Diff::diff(&self.driver, &other.driver, &crate::diff::Path::Field { parent: path, name: "driver" }, changes);
+        crate::diff::Diff::diff(&self.network_data, &other.network_data, &crate::diff::Path::Field { parent: path, name: "networkData" }, changes);
+        crate::diff::Diff::diff(&self.pool, &other.pool, &crate::diff::Path::Field { parent: path, name: "pool" }, changes);
+    }
+}
+
+impl AllocatedDeviceStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AllocatedDeviceStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `data` field.
+    pub fn data(self) -> crate::FieldPath<crate::apimachinery::pkg::runtime::RawExtension> {
+        self.child("data")
+    }
+
+    /// The path of the `device` field.
+    pub fn device(self) -> crate::FieldPath<std::string::String> {
+        self.child("device")
+    }
+
+    /// The path of the `driver` field.
+    pub fn driver(self) -> crate::FieldPath<std::string::String> {
+        self.child("driver")
+    }
+
+    /// The path of the `networkData` field.
+    pub fn network_data(self) -> crate::FieldPath<crate::api::resource::v1alpha3::NetworkDeviceData> {
+        self.child("networkData")
+    }
+
+    /// The path of the `pool` field.
+    pub fn pool(self) -> crate::FieldPath<std::string::String> {
+        self.child("pool")
+    }
+}
+
+impl crate::Normalize for AllocatedDeviceStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.data);
+        crate::Normalize::normalize(&mut self.device);
+        crate::Normalize::normalize(&mut self.driver);
+        crate::Normalize::normalize(&mut self.network_data);
+        crate::Normalize::normalize(&mut self.pool);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AllocatedDeviceStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -167,6 +250,68 @@ impl crate::serde::Serialize for AllocatedDeviceStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AllocatedDeviceStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.driver, 1, buf);
+        crate::protobuf::Field::encode_field(&self.pool, 2, buf);
+        crate::protobuf::Field::encode_field(&self.device, 3, buf);
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.data {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.network_data {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> = None;
+        let mut value_data: Option<crate::apimachinery::pkg::runtime::RawExtension> = None;
+        let mut value_device: Option<std::string::String> = None;
+        let mut value_driver: Option<std::string::String> = None;
+        let mut value_network_data: Option<crate::api::resource::v1alpha3::NetworkDeviceData> = None;
+        let mut value_pool: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_driver, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pool, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_device, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_data, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_network_data, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AllocatedDeviceStatus {
+            conditions: value_conditions,
+            data: value_data,
+            device: value_device.unwrap_or_default(),
+            driver: value_driver.unwrap_or_default(),
+            network_data: value_network_data,
+            pool: value_pool.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AllocatedDeviceStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AllocatedDeviceStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
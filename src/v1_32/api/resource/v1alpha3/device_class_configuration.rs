@@ -13,6 +13,38 @@ impl crate::DeepMerge for DeviceClassConfiguration {
     }
 }
 
+impl crate::Defaulting for DeviceClassConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.opaque);
+    }
+}
+
+impl crate::diff::Diff for DeviceClassConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.opaque, &other.opaque, &crate::diff::Path::Field { parent: path, name: "opaque" }, changes);
+    }
+}
+
+impl DeviceClassConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceClassConfiguration> {
+    /// The path of the `opaque` field.
+    pub fn opaque(self) -> crate::FieldPath<crate::api::resource::v1alpha3::OpaqueDeviceConfiguration> {
+        self.child("opaque")
+    }
+}
+
+impl crate::Normalize for DeviceClassConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.opaque);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceClassConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,47 @@ impl crate::serde::Serialize for DeviceClassConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceClassConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.opaque {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(1, &embedded, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_opaque: Option<crate::api::resource::v1alpha3::OpaqueDeviceConfiguration> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_opaque, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceClassConfiguration {
+            opaque: value_opaque,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceClassConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceClassConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
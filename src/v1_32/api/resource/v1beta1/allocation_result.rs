@@ -17,6 +17,46 @@ impl crate::DeepMerge for AllocationResult {
     }
 }
 
+impl crate::Defaulting for AllocationResult {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.devices);
+        crate::Defaulting::set_defaults(&mut self.node_selector);
+    }
+}
+
+impl crate::diff::Diff for AllocationResult {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.devices, &other.devices, &crate::diff::Path::Field { parent: path, name: "devices" }, changes);
+        crate::diff::Diff::diff(&self.node_selector, &other.node_selector, &crate::diff::Path::Field { parent: path, name: "nodeSelector" }, changes);
+    }
+}
+
+impl AllocationResult {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AllocationResult> {
+    /// The path of the `devices` field.
+    pub fn devices(self) -> crate::FieldPath<crate::api::resource::v1beta1::DeviceAllocationResult> {
+        self.child("devices")
+    }
+
+    /// The path of the `nodeSelector` field.
+    pub fn node_selector(self) -> crate::FieldPath<crate::api::core::v1::NodeSelector> {
+        self.child("nodeSelector")
+    }
+}
+
+impl crate::Normalize for AllocationResult {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.devices);
+        crate::Normalize::normalize(&mut self.node_selector);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AllocationResult {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for AllocationResult {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AllocationResult {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.devices {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.node_selector {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_devices: Option<crate::api::resource::v1beta1::DeviceAllocationResult> = None;
+        let mut value_node_selector: Option<crate::api::core::v1::NodeSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_devices, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_node_selector, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AllocationResult {
+            devices: value_devices,
+            node_selector: value_node_selector,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AllocationResult {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AllocationResult {
     fn schema_name() -> std::borrow::Cow<'static, str> {
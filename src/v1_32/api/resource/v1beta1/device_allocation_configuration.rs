@@ -21,6 +21,64 @@ impl crate::DeepMerge for DeviceAllocationConfiguration {
     }
 }
 
+impl crate::Defaulting for DeviceAllocationConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.opaque);
+        crate::Defaulting::set_defaults(&mut self.requests);
+        crate::Defaulting::set_defaults(&mut self.source);
+    }
+}
+
+impl crate::diff::Diff for DeviceAllocationConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.opaque, &other.opaque, &crate::diff::Path::Field { parent: path, name: "opaque" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.requests,
+            &other.requests,
+            &crate::diff::Path::Field { parent: path, name: "requests" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.source, &other.source, &crate::diff::Path::Field { parent: path, name: "source" }, changes);
+    }
+}
+
+impl DeviceAllocationConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceAllocationConfiguration> {
+    /// The path of the `opaque` field.
+    pub fn opaque(self) -> crate::FieldPath<crate::api::resource::v1beta1::OpaqueDeviceConfiguration> {
+        self.child("opaque")
+    }
+
+    /// The path of the `requests` field.
+    pub fn requests(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("requests")
+    }
+
+    /// The path of the `source` field.
+    pub fn source(self) -> crate::FieldPath<std::string::String> {
+        self.child("source")
+    }
+}
+
+impl crate::Normalize for DeviceAllocationConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.opaque);
+        crate::normalize_strategies::list::atomic(&mut self.requests, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.source);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceAllocationConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +176,57 @@ impl crate::serde::Serialize for DeviceAllocationConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceAllocationConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.source, 1, buf);
+        if let Some(value) = &self.requests {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.opaque {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(3, &embedded, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_opaque: Option<crate::api::resource::v1beta1::OpaqueDeviceConfiguration> = None;
+        let mut value_requests: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_source: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_source, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_requests, wire_type, &mut buf)?,
+                3 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_opaque, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceAllocationConfiguration {
+            opaque: value_opaque,
+            requests: value_requests,
+            source: value_source.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceAllocationConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceAllocationConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -35,6 +35,70 @@ impl crate::DeepMerge for DeviceRequestAllocationResult {
     }
 }
 
+impl crate::Defaulting for DeviceRequestAllocationResult {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.admin_access);
+        crate::Defaulting::set_defaults(&mut self.device);
+        crate::Defaulting::set_defaults(&mut self.driver);
+        crate::Defaulting::set_defaults(&mut self.pool);
+        crate::Defaulting::set_defaults(&mut self.request);
+    }
+}
+
+impl crate::diff::Diff for DeviceRequestAllocationResult {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.admin_access, &other.admin_access, &crate::diff::Path::Field { parent: path, name: "adminAccess" }, changes);
+        crate::diff::Diff::diff(&self.device, &other.device, &crate::diff::Path::Field { parent: path, name: "device" }, changes);
+        crate::diff::Diff::diff(&self.driver, &other.driver, &crate::diff::Path::Field { parent: path, name: "driver" }, changes);
+        crate::diff::Diff::diff(&self.pool, &other.pool, &crate::diff::Path::Field { parent: path, name: "pool" }, changes);
+        crate::diff::Diff::diff(&self.request, &other.request, &crate::diff::Path::Field { parent: path, name: "request" }, changes);
+    }
+}
+
+impl DeviceRequestAllocationResult {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceRequestAllocationResult> {
+    /// The path of the `adminAccess` field.
+    pub fn admin_access(self) -> crate::FieldPath<bool> {
+        self.child("adminAccess")
+    }
+
+    /// The path of the `device` field.
+    pub fn device(self) -> crate::FieldPath<std::string::String> {
+        self.child("device")
+    }
+
+    /// The path of the `driver` field.
+    pub fn driver(self) -> crate::FieldPath<std::string::String> {
+        self.child("driver")
+    }
+
+    /// The path of the `pool` field.
+    pub fn pool(self) -> crate::FieldPath<std::string::String> {
+        self.child("pool")
+    }
+
+    /// The path of the `request` field.
+    pub fn request(self) -> crate::FieldPath<std::string::String> {
+        self.child("request")
+    }
+}
+
+impl crate::Normalize for DeviceRequestAllocationResult {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.admin_access);
+        crate::Normalize::normalize(&mut self.device);
+        crate::Normalize::normalize(&mut self.driver);
+        crate::Normalize::normalize(&mut self.pool);
+        crate::Normalize::normalize(&mut self.request);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceRequestAllocationResult {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -143,6 +207,50 @@ impl crate::serde::Serialize for DeviceRequestAllocationResult {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceRequestAllocationResult {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.request, 1, buf);
+        crate::protobuf::Field::encode_field(&self.driver, 2, buf);
+        crate::protobuf::Field::encode_field(&self.pool, 3, buf);
+        crate::protobuf::Field::encode_field(&self.device, 4, buf);
+        if let Some(value) = &self.admin_access {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_admin_access: Option<bool> = None;
+        let mut value_device: Option<std::string::String> = None;
+        let mut value_driver: Option<std::string::String> = None;
+        let mut value_pool: Option<std::string::String> = None;
+        let mut value_request: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_request, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_driver, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pool, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_device, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_admin_access, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceRequestAllocationResult {
+            admin_access: value_admin_access,
+            device: value_device.unwrap_or_default(),
+            driver: value_driver.unwrap_or_default(),
+            pool: value_pool.unwrap_or_default(),
+            request: value_request.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceRequestAllocationResult {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceRequestAllocationResult {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for ResourceClaimConsumerReference {
     }
 }
 
+impl crate::Defaulting for ResourceClaimConsumerReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_group);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for ResourceClaimConsumerReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_group, &other.api_group, &crate::diff::Path::Field { parent: path, name: "apiGroup" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl ResourceClaimConsumerReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceClaimConsumerReference> {
+    /// The path of the `apiGroup` field.
+    pub fn api_group(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiGroup")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for ResourceClaimConsumerReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_group);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceClaimConsumerReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -126,6 +182,46 @@ impl crate::serde::Serialize for ResourceClaimConsumerReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceClaimConsumerReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.api_group {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.resource, 3, buf);
+        crate::protobuf::Field::encode_field(&self.name, 4, buf);
+        crate::protobuf::Field::encode_field(&self.uid, 5, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_group: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_resource: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_api_group, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceClaimConsumerReference {
+            api_group: value_api_group,
+            name: value_name.unwrap_or_default(),
+            resource: value_resource.unwrap_or_default(),
+            uid: value_uid.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceClaimConsumerReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceClaimConsumerReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,72 @@ impl crate::DeepMerge for CSINodeDriver {
     }
 }
 
+impl crate::Defaulting for CSINodeDriver {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allocatable);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.node_id);
+        crate::Defaulting::set_defaults(&mut self.topology_keys);
+    }
+}
+
+impl crate::diff::Diff for CSINodeDriver {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allocatable, &other.allocatable, &crate::diff::Path::Field { parent: path, name: "allocatable" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.node_id, &other.node_id, &crate::diff::Path::Field { parent: path, name: "nodeID" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.topology_keys,
+            &other.topology_keys,
+            &crate::diff::Path::Field { parent: path, name: "topologyKeys" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl CSINodeDriver {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CSINodeDriver> {
+    /// The path of the `allocatable` field.
+    pub fn allocatable(self) -> crate::FieldPath<crate::api::storage::v1::VolumeNodeResources> {
+        self.child("allocatable")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `nodeID` field.
+    pub fn node_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeID")
+    }
+
+    /// The path of the `topologyKeys` field.
+    pub fn topology_keys(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("topologyKeys")
+    }
+}
+
+impl crate::Normalize for CSINodeDriver {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allocatable);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.node_id);
+        crate::normalize_strategies::list::atomic(&mut self.topology_keys, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CSINodeDriver {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +195,48 @@ impl crate::serde::Serialize for CSINodeDriver {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CSINodeDriver {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.node_id, 2, buf);
+        if let Some(value) = &self.topology_keys {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.allocatable {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allocatable: Option<crate::api::storage::v1::VolumeNodeResources> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_node_id: Option<std::string::String> = None;
+        let mut value_topology_keys: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_node_id, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_topology_keys, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_allocatable, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CSINodeDriver {
+            allocatable: value_allocatable,
+            name: value_name.unwrap_or_default(),
+            node_id: value_node_id.unwrap_or_default(),
+            topology_keys: value_topology_keys,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CSINodeDriver {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSINodeDriver {
     fn schema_name() -> std::borrow::Cow<'static, str> {
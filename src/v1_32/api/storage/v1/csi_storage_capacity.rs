@@ -70,6 +70,74 @@ impl crate::DeepMerge for CSIStorageCapacity {
     }
 }
 
+impl crate::Defaulting for CSIStorageCapacity {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.capacity);
+        crate::Defaulting::set_defaults(&mut self.maximum_volume_size);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.node_topology);
+        crate::Defaulting::set_defaults(&mut self.storage_class_name);
+    }
+}
+
+impl crate::diff::Diff for CSIStorageCapacity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.capacity, &other.capacity, &crate::diff::Path::Field { parent: path, name: "capacity" }, changes);
+        crate::diff::Diff::diff(&self.maximum_volume_size, &other.maximum_volume_size, &crate::diff::Path::Field { parent: path, name: "maximumVolumeSize" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.node_topology, &other.node_topology, &crate::diff::Path::Field { parent: path, name: "nodeTopology" }, changes);
+        crate::diff::Diff::diff(&self.storage_class_name, &other.storage_class_name, &crate::diff::Path::Field { parent: path, name: "storageClassName" }, changes);
+    }
+}
+
+impl CSIStorageCapacity {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CSIStorageCapacity> {
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("capacity")
+    }
+
+    /// The path of the `maximumVolumeSize` field.
+    pub fn maximum_volume_size(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("maximumVolumeSize")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `nodeTopology` field.
+    pub fn node_topology(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("nodeTopology")
+    }
+
+    /// The path of the `storageClassName` field.
+    pub fn storage_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("storageClassName")
+    }
+}
+
+impl crate::Normalize for CSIStorageCapacity {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.capacity);
+        crate::Normalize::normalize(&mut self.maximum_volume_size);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.node_topology);
+        crate::Normalize::normalize(&mut self.storage_class_name);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CSIStorageCapacity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -204,6 +272,64 @@ impl crate::serde::Serialize for CSIStorageCapacity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CSIStorageCapacity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.node_topology {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.storage_class_name, 3, buf);
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.maximum_volume_size {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_capacity: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_maximum_volume_size: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_node_topology: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_storage_class_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_node_topology, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_storage_class_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_maximum_volume_size, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CSIStorageCapacity {
+            capacity: value_capacity,
+            maximum_volume_size: value_maximum_volume_size,
+            metadata: value_metadata.unwrap_or_default(),
+            node_topology: value_node_topology,
+            storage_class_name: value_storage_class_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CSIStorageCapacity {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSIStorageCapacity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for VolumeAttachmentSpec {
     }
 }
 
+impl crate::Defaulting for VolumeAttachmentSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.attacher);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.source);
+    }
+}
+
+impl crate::diff::Diff for VolumeAttachmentSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.attacher, &other.attacher, &crate::diff::Path::Field { parent: path, name: "attacher" }, changes);
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.source, &other.source, &crate::diff::Path::Field { parent: path, name: "source" }, changes);
+    }
+}
+
+impl VolumeAttachmentSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeAttachmentSpec> {
+    /// The path of the `attacher` field.
+    pub fn attacher(self) -> crate::FieldPath<std::string::String> {
+        self.child("attacher")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `source` field.
+    pub fn source(self) -> crate::FieldPath<crate::api::storage::v1::VolumeAttachmentSource> {
+        self.child("source")
+    }
+}
+
+impl crate::Normalize for VolumeAttachmentSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.attacher);
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.source);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +160,40 @@ impl crate::serde::Serialize for VolumeAttachmentSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeAttachmentSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.attacher, 1, buf);
+        crate::protobuf::Field::encode_field(&self.source, 2, buf);
+        crate::protobuf::Field::encode_field(&self.node_name, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_attacher: Option<std::string::String> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_source: Option<crate::api::storage::v1::VolumeAttachmentSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_attacher, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_source, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeAttachmentSpec {
+            attacher: value_attacher.unwrap_or_default(),
+            node_name: value_node_name.unwrap_or_default(),
+            source: value_source.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeAttachmentSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for VolumeError {
     }
 }
 
+impl crate::Defaulting for VolumeError {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.time);
+    }
+}
+
+impl crate::diff::Diff for VolumeError {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.time, &other.time, &crate::diff::Path::Field { parent: path, name: "time" }, changes);
+    }
+}
+
+impl VolumeError {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeError> {
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `time` field.
+    pub fn time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("time")
+    }
+}
+
+impl crate::Normalize for VolumeError {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeError {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for VolumeError {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeError {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.time {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_time, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeError {
+            message: value_message,
+            time: value_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeError {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeError {
     fn schema_name() -> std::borrow::Cow<'static, str> {
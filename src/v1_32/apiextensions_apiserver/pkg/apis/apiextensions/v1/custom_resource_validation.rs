@@ -13,6 +13,38 @@ impl crate::DeepMerge for CustomResourceValidation {
     }
 }
 
+impl crate::Defaulting for CustomResourceValidation {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.open_api_v3_schema);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceValidation {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.open_api_v3_schema, &other.open_api_v3_schema, &crate::diff::Path::Field { parent: path, name: "openAPIV3Schema" }, changes);
+    }
+}
+
+impl CustomResourceValidation {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceValidation> {
+    /// The path of the `openAPIV3Schema` field.
+    pub fn open_api_v3_schema(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps> {
+        self.child("openAPIV3Schema")
+    }
+}
+
+impl crate::Normalize for CustomResourceValidation {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.open_api_v3_schema);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceValidation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,44 @@ impl crate::serde::Serialize for CustomResourceValidation {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceValidation {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.open_api_v3_schema {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_open_api_v3_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_open_api_v3_schema, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceValidation {
+            open_api_v3_schema: value_open_api_v3_schema,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceValidation {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "openAPIV3Schema" => <Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceValidation {
     fn schema_name() -> std::borrow::Cow<'static, str> {
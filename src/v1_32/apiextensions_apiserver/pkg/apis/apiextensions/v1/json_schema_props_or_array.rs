@@ -13,6 +13,30 @@ impl crate::DeepMerge for JSONSchemaPropsOrArray {
     }
 }
 
+impl crate::Defaulting for JSONSchemaPropsOrArray {
+    fn set_defaults(&mut self) {
+        match self {
+            JSONSchemaPropsOrArray::Schema(value) => crate::Defaulting::set_defaults(value),
+            JSONSchemaPropsOrArray::Schemas(value) => crate::Defaulting::set_defaults(value),
+        }
+    }
+}
+
+impl crate::Normalize for JSONSchemaPropsOrArray {
+    fn normalize(&mut self) {
+        match self {
+            JSONSchemaPropsOrArray::Schema(value) => crate::Normalize::normalize(value),
+            JSONSchemaPropsOrArray::Schemas(value) => crate::normalize_strategies::list::atomic(value, crate::Normalize::normalize),
+        }
+    }
+}
+
+impl crate::diff::Diff for JSONSchemaPropsOrArray {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JSONSchemaPropsOrArray {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -46,6 +70,37 @@ impl crate::serde::Serialize for JSONSchemaPropsOrArray {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JSONSchemaPropsOrArray {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        match self {
+            JSONSchemaPropsOrArray::Schema(value) => {
+                crate::protobuf::Field::encode_field(value, 1, buf);
+            },
+            JSONSchemaPropsOrArray::Schemas(value) => crate::protobuf::Field::encode_field(value, 2, buf),
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_schema: Option<std::boxed::Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_or: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_schema, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_or, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(match (value_schema, value_or) {
+            (Some(value_schema), None) => JSONSchemaPropsOrArray::Schema(value_schema),
+            (_, value_or) => JSONSchemaPropsOrArray::Schemas(value_or.unwrap_or_default()),
+        })
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaPropsOrArray {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,30 @@ impl crate::DeepMerge for JSONSchemaPropsOrBool {
     }
 }
 
+impl crate::Defaulting for JSONSchemaPropsOrBool {
+    fn set_defaults(&mut self) {
+        match self {
+            JSONSchemaPropsOrBool::Schema(value) => crate::Defaulting::set_defaults(value),
+            JSONSchemaPropsOrBool::Bool(value) => crate::Defaulting::set_defaults(value),
+        }
+    }
+}
+
+impl crate::Normalize for JSONSchemaPropsOrBool {
+    fn normalize(&mut self) {
+        match self {
+            JSONSchemaPropsOrBool::Schema(value) => crate::Normalize::normalize(value),
+            JSONSchemaPropsOrBool::Bool(_) => (),
+        }
+    }
+}
+
+impl crate::diff::Diff for JSONSchemaPropsOrBool {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JSONSchemaPropsOrBool {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -46,6 +70,38 @@ impl crate::serde::Serialize for JSONSchemaPropsOrBool {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JSONSchemaPropsOrBool {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        match self {
+            JSONSchemaPropsOrBool::Schema(value) => {
+                crate::protobuf::Field::encode_field(&true, 1, buf);
+                crate::protobuf::Field::encode_field(value, 2, buf);
+            },
+            JSONSchemaPropsOrBool::Bool(value) => crate::protobuf::Field::encode_field(value, 1, buf),
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_schema: Option<std::boxed::Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_or: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_schema, wire_type, &mut buf)?,
+                1 => crate::protobuf::Field::merge_field(&mut value_or, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(match value_schema {
+            Some(value_schema) => JSONSchemaPropsOrBool::Schema(value_schema),
+            None => JSONSchemaPropsOrBool::Bool(value_or.unwrap_or_default()),
+        })
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaPropsOrBool {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -34,6 +34,82 @@ impl crate::DeepMerge for APIGroup {
     }
 }
 
+impl crate::Defaulting for APIGroup {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.preferred_version);
+        crate::Defaulting::set_defaults(&mut self.server_address_by_client_cidrs);
+        crate::Defaulting::set_defaults(&mut self.versions);
+    }
+}
+
+impl crate::diff::Diff for APIGroup {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.preferred_version, &other.preferred_version, &crate::diff::Path::Field { parent: path, name: "preferredVersion" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.server_address_by_client_cidrs,
+            &other.server_address_by_client_cidrs,
+            &crate::diff::Path::Field { parent: path, name: "serverAddressByClientCIDRs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.versions,
+            &other.versions,
+            &crate::diff::Path::Field { parent: path, name: "versions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl APIGroup {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIGroup> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `preferredVersion` field.
+    pub fn preferred_version(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery> {
+        self.child("preferredVersion")
+    }
+
+    /// The path of the `serverAddressByClientCIDRs` field.
+    pub fn server_address_by_client_cidrs(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> {
+        self.child("serverAddressByClientCIDRs")
+    }
+
+    /// The path of the `versions` field.
+    pub fn versions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>> {
+        self.child("versions")
+    }
+}
+
+impl crate::Normalize for APIGroup {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.preferred_version);
+        crate::normalize_strategies::list::atomic(&mut self.server_address_by_client_cidrs, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIGroup {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -158,6 +234,48 @@ impl crate::serde::Serialize for APIGroup {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIGroup {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.versions, 2, buf);
+        if let Some(value) = &self.preferred_version {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.server_address_by_client_cidrs {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_preferred_version: Option<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery> = None;
+        let mut value_server_address_by_client_cidrs: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> = None;
+        let mut value_versions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_versions, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_preferred_version, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_server_address_by_client_cidrs, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIGroup {
+            name: value_name.unwrap_or_default(),
+            preferred_version: value_preferred_version,
+            server_address_by_client_cidrs: value_server_address_by_client_cidrs,
+            versions: value_versions.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIGroup {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIGroup {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -22,6 +22,48 @@ impl crate::DeepMerge for APIGroupList {
     }
 }
 
+impl crate::Defaulting for APIGroupList {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.groups);
+    }
+}
+
+impl crate::diff::Diff for APIGroupList {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.groups,
+            &other.groups,
+            &crate::diff::Path::Field { parent: path, name: "groups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl APIGroupList {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIGroupList> {
+    /// The path of the `groups` field.
+    pub fn groups(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>> {
+        self.child("groups")
+    }
+}
+
+impl crate::Normalize for APIGroupList {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIGroupList {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -119,6 +161,32 @@ impl crate::serde::Serialize for APIGroupList {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIGroupList {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.groups, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_groups: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::APIGroup>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_groups, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIGroupList {
+            groups: value_groups.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIGroupList {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIGroupList {
     fn schema_name() -> std::borrow::Cow<'static, str> {
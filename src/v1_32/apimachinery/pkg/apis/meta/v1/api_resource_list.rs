@@ -26,6 +26,56 @@ impl crate::DeepMerge for APIResourceList {
     }
 }
 
+impl crate::Defaulting for APIResourceList {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group_version);
+        crate::Defaulting::set_defaults(&mut self.resources);
+    }
+}
+
+impl crate::diff::Diff for APIResourceList {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group_version, &other.group_version, &crate::diff::Path::Field { parent: path, name: "groupVersion" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.resources,
+            &other.resources,
+            &crate::diff::Path::Field { parent: path, name: "resources" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl APIResourceList {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIResourceList> {
+    /// The path of the `groupVersion` field.
+    pub fn group_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("groupVersion")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>> {
+        self.child("resources")
+    }
+}
+
+impl crate::Normalize for APIResourceList {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group_version);
+        crate::normalize_strategies::list::atomic(&mut self.resources, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIResourceList {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -130,6 +180,36 @@ impl crate::serde::Serialize for APIResourceList {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIResourceList {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.group_version, 1, buf);
+        crate::protobuf::Field::encode_field(&self.resources, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group_version: Option<std::string::String> = None;
+        let mut value_resources: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::APIResource>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group_version, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIResourceList {
+            group_version: value_group_version.unwrap_or_default(),
+            resources: value_resources.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIResourceList {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIResourceList {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,64 @@ impl crate::DeepMerge for LabelSelectorRequirement {
     }
 }
 
+impl crate::Defaulting for LabelSelectorRequirement {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.operator);
+        crate::Defaulting::set_defaults(&mut self.values);
+    }
+}
+
+impl crate::diff::Diff for LabelSelectorRequirement {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.operator, &other.operator, &crate::diff::Path::Field { parent: path, name: "operator" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.values,
+            &other.values,
+            &crate::diff::Path::Field { parent: path, name: "values" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl LabelSelectorRequirement {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LabelSelectorRequirement> {
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `operator` field.
+    pub fn operator(self) -> crate::FieldPath<std::string::String> {
+        self.child("operator")
+    }
+
+    /// The path of the `values` field.
+    pub fn values(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("values")
+    }
+}
+
+impl crate::Normalize for LabelSelectorRequirement {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.operator);
+        crate::normalize_strategies::list::atomic(&mut self.values, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LabelSelectorRequirement {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +173,42 @@ impl crate::serde::Serialize for LabelSelectorRequirement {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LabelSelectorRequirement {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.key, 1, buf);
+        crate::protobuf::Field::encode_field(&self.operator, 2, buf);
+        if let Some(value) = &self.values {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_operator: Option<std::string::String> = None;
+        let mut value_values: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_operator, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_values, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LabelSelectorRequirement {
+            key: value_key.unwrap_or_default(),
+            operator: value_operator.unwrap_or_default(),
+            values: value_values,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LabelSelectorRequirement {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LabelSelectorRequirement {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -37,6 +37,86 @@ impl crate::DeepMerge for ManagedFieldsEntry {
     }
 }
 
+impl crate::Defaulting for ManagedFieldsEntry {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_version);
+        crate::Defaulting::set_defaults(&mut self.fields_type);
+        crate::Defaulting::set_defaults(&mut self.fields_v1);
+        crate::Defaulting::set_defaults(&mut self.manager);
+        crate::Defaulting::set_defaults(&mut self.operation);
+        crate::Defaulting::set_defaults(&mut self.subresource);
+        crate::Defaulting::set_defaults(&mut self.time);
+    }
+}
+
+impl crate::diff::Diff for ManagedFieldsEntry {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_version, &other.api_version, &crate::diff::Path::Field { parent: path, name: "apiVersion" }, changes);
+        crate::diff::Diff::diff(&self.fields_type, &other.fields_type, &crate::diff::Path::Field { parent: path, name: "fieldsType" }, changes);
+        crate::diff::Diff::diff(&self.fields_v1, &other.fields_v1, &crate::diff::Path::Field { parent: path, name: "fieldsV1" }, changes);
+        crate::diff::Diff::diff(&self.manager, &other.manager, &crate::diff::Path::Field { parent: path, name: "manager" }, changes);
+        crate::diff::Diff::diff(&self.operation, &other.operation, &crate::diff::Path::Field { parent: path, name: "operation" }, changes);
+        crate::diff::Diff::diff(&self.subresource, &other.subresource, &crate::diff::Path::Field { parent: path, name: "subresource" }, changes);
+        crate::diff::Diff::diff(&self.time, &other.time, &crate::diff::Path::Field { parent: path, name: "time" }, changes);
+    }
+}
+
+impl ManagedFieldsEntry {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ManagedFieldsEntry> {
+    /// The path of the `apiVersion` field.
+    pub fn api_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiVersion")
+    }
+
+    /// The path of the `fieldsType` field.
+    pub fn fields_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fieldsType")
+    }
+
+    /// The path of the `fieldsV1` field.
+    pub fn fields_v1(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::FieldsV1> {
+        self.child("fieldsV1")
+    }
+
+    /// The path of the `manager` field.
+    pub fn manager(self) -> crate::FieldPath<std::string::String> {
+        self.child("manager")
+    }
+
+    /// The path of the `operation` field.
+    pub fn operation(self) -> crate::FieldPath<std::string::String> {
+        self.child("operation")
+    }
+
+    /// The path of the `subresource` field.
+    pub fn subresource(self) -> crate::FieldPath<std::string::String> {
+        self.child("subresource")
+    }
+
+    /// The path of the `time` field.
+    pub fn time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("time")
+    }
+}
+
+impl crate::Normalize for ManagedFieldsEntry {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_version);
+        crate::Normalize::normalize(&mut self.fields_type);
+        crate::Normalize::normalize(&mut self.fields_v1);
+        crate::Normalize::normalize(&mut self.manager);
+        crate::Normalize::normalize(&mut self.operation);
+        crate::Normalize::normalize(&mut self.subresource);
+        crate::Normalize::normalize(&mut self.time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ManagedFieldsEntry {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -176,6 +256,70 @@ impl crate::serde::Serialize for ManagedFieldsEntry {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ManagedFieldsEntry {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.manager {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.operation {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.api_version {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.time {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.fields_type {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.fields_v1 {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.subresource {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_version: Option<std::string::String> = None;
+        let mut value_fields_type: Option<std::string::String> = None;
+        let mut value_fields_v1: Option<crate::apimachinery::pkg::apis::meta::v1::FieldsV1> = None;
+        let mut value_manager: Option<std::string::String> = None;
+        let mut value_operation: Option<std::string::String> = None;
+        let mut value_subresource: Option<std::string::String> = None;
+        let mut value_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_manager, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_operation, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_api_version, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_time, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_fields_type, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_fields_v1, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_subresource, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ManagedFieldsEntry {
+            api_version: value_api_version,
+            fields_type: value_fields_type,
+            fields_v1: value_fields_v1,
+            manager: value_manager,
+            operation: value_operation,
+            subresource: value_subresource,
+            time: value_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ManagedFieldsEntry {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ManagedFieldsEntry {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -33,6 +33,78 @@ impl crate::DeepMerge for OwnerReference {
     }
 }
 
+impl crate::Defaulting for OwnerReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_version);
+        crate::Defaulting::set_defaults(&mut self.block_owner_deletion);
+        crate::Defaulting::set_defaults(&mut self.controller);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for OwnerReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_version, &other.api_version, &crate::diff::Path::Field { parent: path, name: "apiVersion" }, changes);
+        crate::diff::Diff::diff(&self.block_owner_deletion, &other.block_owner_deletion, &crate::diff::Path::Field { parent: path, name: "blockOwnerDeletion" }, changes);
+        crate::diff::Diff::diff(&self.controller, &other.controller, &crate::diff::Path::Field { parent: path, name: "controller" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl OwnerReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<OwnerReference> {
+    /// The path of the `apiVersion` field.
+    pub fn api_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiVersion")
+    }
+
+    /// The path of the `blockOwnerDeletion` field.
+    pub fn block_owner_deletion(self) -> crate::FieldPath<bool> {
+        self.child("blockOwnerDeletion")
+    }
+
+    /// The path of the `controller` field.
+    pub fn controller(self) -> crate::FieldPath<bool> {
+        self.child("controller")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for OwnerReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_version);
+        crate::Normalize::normalize(&mut self.block_owner_deletion);
+        crate::Normalize::normalize(&mut self.controller);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for OwnerReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -151,6 +223,56 @@ impl crate::serde::Serialize for OwnerReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for OwnerReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.kind, 1, buf);
+        crate::protobuf::Field::encode_field(&self.name, 3, buf);
+        crate::protobuf::Field::encode_field(&self.uid, 4, buf);
+        crate::protobuf::Field::encode_field(&self.api_version, 5, buf);
+        if let Some(value) = &self.controller {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.block_owner_deletion {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_version: Option<std::string::String> = None;
+        let mut value_block_owner_deletion: Option<bool> = None;
+        let mut value_controller: Option<bool> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_api_version, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_controller, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_block_owner_deletion, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(OwnerReference {
+            api_version: value_api_version.unwrap_or_default(),
+            block_owner_deletion: value_block_owner_deletion,
+            controller: value_controller,
+            kind: value_kind.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            uid: value_uid.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for OwnerReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for OwnerReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
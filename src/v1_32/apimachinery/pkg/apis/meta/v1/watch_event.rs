@@ -371,6 +371,88 @@ impl crate::serde::Serialize for BookmarkObjectMeta<'_> {
 }
 
 
+#[cfg(feature = "protobuf")]
+impl<T> crate::protobuf::Message for WatchEvent<T> where T: crate::Resource + crate::protobuf::Message {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        let (value_type, raw) = match self {
+            WatchEvent::Added(object) => ("ADDED", crate::protobuf::encode(object)),
+            WatchEvent::Deleted(object) => ("DELETED", crate::protobuf::encode(object)),
+            WatchEvent::Modified(object) => ("MODIFIED", crate::protobuf::encode(object)),
+            WatchEvent::Bookmark { annotations, resource_version } => {
+                let metadata = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+                    annotations: Some(annotations.clone()),
+                    resource_version: Some(resource_version.clone()),
+                    ..Default::default()
+                };
+                let mut raw = std::vec![];
+                crate::protobuf::Field::encode_field(&metadata, 1, &mut raw);
+                let unknown = crate::protobuf::Unknown {
+                    api_version: <T as crate::Resource>::API_VERSION.into(),
+                    kind: <T as crate::Resource>::KIND.into(),
+                    raw,
+                    content_encoding: std::string::String::new(),
+                    content_type: std::string::String::new(),
+                };
+                ("BOOKMARK", unknown.encode_with_magic())
+            },
+            WatchEvent::ErrorStatus(status) => ("ERROR", crate::protobuf::encode(status)),
+            WatchEvent::ErrorOther(value) => ("ERROR", crate::serde_json::to_vec(&value.0).expect("serde_json::Value always serializes successfully")),
+        };
+        crate::protobuf::encode_length_delimited(1, value_type.as_bytes(), buf);
+        let mut object = std::vec![];
+        crate::protobuf::encode_raw(&raw, &mut object);
+        crate::protobuf::encode_length_delimited(2, &object, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_type: Option<std::string::String> = None;
+        let mut value_object: Option<&[u8]> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type, wire_type, &mut buf)?,
+                2 => value_object = Some(crate::protobuf::decode_length_delimited(wire_type, &mut buf)?),
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        let value_type = value_type.ok_or(crate::protobuf::DecodeError::MissingField("type"))?;
+        let value_object = value_object.ok_or(crate::protobuf::DecodeError::MissingField("object"))?;
+        let raw = crate::protobuf::decode_raw(value_object)?;
+
+        Ok(match &*value_type {
+            "ADDED" => WatchEvent::Added(crate::protobuf::decode(raw)?),
+            "DELETED" => WatchEvent::Deleted(crate::protobuf::decode(raw)?),
+            "MODIFIED" => WatchEvent::Modified(crate::protobuf::decode(raw)?),
+            "BOOKMARK" => {
+                let unknown = crate::protobuf::Unknown::decode_with_magic(raw)?;
+                let mut raw = &unknown.raw[..];
+                let mut metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+                while !raw.is_empty() {
+                    let (field_number, wire_type) = crate::protobuf::decode_key(&mut raw)?;
+                    match field_number {
+                        1 => crate::protobuf::Field::merge_field(&mut metadata, wire_type, &mut raw)?,
+                        _ => crate::protobuf::skip_field(wire_type, &mut raw)?,
+                    }
+                }
+                let metadata = metadata.unwrap_or_default();
+                WatchEvent::Bookmark {
+                    annotations: metadata.annotations.unwrap_or_default(),
+                    resource_version: metadata.resource_version.unwrap_or_default(),
+                }
+            },
+            "ERROR" => match crate::protobuf::Unknown::decode_with_magic(raw) {
+                Ok(unknown) if unknown.kind == <crate::apimachinery::pkg::apis::meta::v1::Status as crate::Resource>::KIND => WatchEvent::ErrorStatus(crate::protobuf::decode(raw)?),
+                _ => WatchEvent::ErrorOther(crate::apimachinery::pkg::runtime::RawExtension(
+                    crate::serde_json::from_slice(raw).map_err(|_| crate::protobuf::DecodeError::InvalidValue("a JSON value"))?,
+                )),
+            },
+            _ => return Err(crate::protobuf::DecodeError::InvalidValue("a watch event type")),
+        })
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl<T> crate::schemars::JsonSchema for WatchEvent<T> {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -19,6 +19,22 @@ impl crate::DeepMerge for IntOrString {
     }
 }
 
+impl crate::Defaulting for IntOrString {
+    fn set_defaults(&mut self) {
+    }
+}
+
+impl crate::Normalize for IntOrString {
+    fn normalize(&mut self) {
+    }
+}
+
+impl crate::diff::Diff for IntOrString {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IntOrString {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -66,6 +82,40 @@ impl crate::serde::Serialize for IntOrString {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IntOrString {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        let (value_type, value_int_val, value_str_val) = match self {
+            IntOrString::Int(value) => (0_i64, *value, ""),
+            IntOrString::String(value) => (1_i64, 0_i32, &**value),
+        };
+        crate::protobuf::Field::encode_field(&value_type, 1, buf);
+        crate::protobuf::Field::encode_field(&value_int_val, 2, buf);
+        crate::protobuf::encode_length_delimited(3, value_str_val.as_bytes(), buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_type: Option<i64> = None;
+        let mut value_int_val: Option<i32> = None;
+        let mut value_str_val: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_int_val, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_str_val, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(match value_type {
+            Some(1) => IntOrString::String(value_str_val.unwrap_or_default()),
+            _ => IntOrString::Int(value_int_val.unwrap_or_default()),
+        })
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IntOrString {
     fn schema_name() -> std::borrow::Cow<'static, str> {
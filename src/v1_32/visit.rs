@@ -0,0 +1,11471 @@
+// Generated from all definitions
+
+//! Visitors that walk a value and the values of other types that it contains, in the style of the `syn::visit` module.
+//!
+//! [`Visit`] has a method for each type, which by default calls the function of the same name in this module to walk the values that
+//! the value contains. A visitor overrides the methods of the types that it is interested in, and calls the function of the same name
+//! if it wants to continue walking the value.
+//!
+//! ```rust,ignore
+//! struct Images<'ast>(Vec<&'ast str>);
+//!
+//! impl<'ast> k8s_openapi::visit::Visit<'ast> for Images<'ast> {
+//!     fn visit_api_core_v1_container(&mut self, node: &'ast Container) {
+//!         self.0.extend(node.image.as_deref());
+//!         k8s_openapi::visit::visit_api_core_v1_container(self, node);
+//!     }
+//! }
+//!
+//! let mut images = Images(vec![]);
+//! k8s_openapi::visit::Visitable::visit(&deployment, &mut images);
+//! ```
+//!
+//! See the [`visit_mut`](super::visit_mut) module for visitors that can modify the values.
+
+/// A visitor of values of the types in this crate.
+///
+/// The method of each type is named after the path of the type, like `visit_api_core_v1_pod` for `api::core::v1::Pod`.
+pub trait Visit<'ast> {
+    /// Visits a value of type [`AnyResource`](crate::AnyResource).
+    fn visit_any_resource(&mut self, node: &'ast crate::AnyResource) {
+        visit_any_resource(self, node);
+    }
+
+    /// Visits a value of type [`List`](crate::List).
+    fn visit_list<T>(&mut self, node: &'ast crate::List<T>) where T: crate::ListableResource + Visitable {
+        visit_list(self, node);
+    }
+
+    /// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1::AuditAnnotation).
+    fn visit_api_admissionregistration_v1_audit_annotation(&mut self, node: &'ast crate::api::admissionregistration::v1::AuditAnnotation) {
+        visit_api_admissionregistration_v1_audit_annotation(self, node);
+    }
+
+    /// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1::ExpressionWarning).
+    fn visit_api_admissionregistration_v1_expression_warning(&mut self, node: &'ast crate::api::admissionregistration::v1::ExpressionWarning) {
+        visit_api_admissionregistration_v1_expression_warning(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1::MatchCondition).
+    fn visit_api_admissionregistration_v1_match_condition(&mut self, node: &'ast crate::api::admissionregistration::v1::MatchCondition) {
+        visit_api_admissionregistration_v1_match_condition(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1::MatchResources).
+    fn visit_api_admissionregistration_v1_match_resources(&mut self, node: &'ast crate::api::admissionregistration::v1::MatchResources) {
+        visit_api_admissionregistration_v1_match_resources(self, node);
+    }
+
+    /// Visits a value of type [`MutatingWebhook`](crate::api::admissionregistration::v1::MutatingWebhook).
+    fn visit_api_admissionregistration_v1_mutating_webhook(&mut self, node: &'ast crate::api::admissionregistration::v1::MutatingWebhook) {
+        visit_api_admissionregistration_v1_mutating_webhook(self, node);
+    }
+
+    /// Visits a value of type [`MutatingWebhookConfiguration`](crate::api::admissionregistration::v1::MutatingWebhookConfiguration).
+    fn visit_api_admissionregistration_v1_mutating_webhook_configuration(&mut self, node: &'ast crate::api::admissionregistration::v1::MutatingWebhookConfiguration) {
+        visit_api_admissionregistration_v1_mutating_webhook_configuration(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1_named_rule_with_operations(&mut self, node: &'ast crate::api::admissionregistration::v1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1_named_rule_with_operations(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1::ParamKind).
+    fn visit_api_admissionregistration_v1_param_kind(&mut self, node: &'ast crate::api::admissionregistration::v1::ParamKind) {
+        visit_api_admissionregistration_v1_param_kind(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1::ParamRef).
+    fn visit_api_admissionregistration_v1_param_ref(&mut self, node: &'ast crate::api::admissionregistration::v1::ParamRef) {
+        visit_api_admissionregistration_v1_param_ref(self, node);
+    }
+
+    /// Visits a value of type [`RuleWithOperations`](crate::api::admissionregistration::v1::RuleWithOperations).
+    fn visit_api_admissionregistration_v1_rule_with_operations(&mut self, node: &'ast crate::api::admissionregistration::v1::RuleWithOperations) {
+        visit_api_admissionregistration_v1_rule_with_operations(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::api::admissionregistration::v1::ServiceReference).
+    fn visit_api_admissionregistration_v1_service_reference(&mut self, node: &'ast crate::api::admissionregistration::v1::ServiceReference) {
+        visit_api_admissionregistration_v1_service_reference(self, node);
+    }
+
+    /// Visits a value of type [`TypeChecking`](crate::api::admissionregistration::v1::TypeChecking).
+    fn visit_api_admissionregistration_v1_type_checking(&mut self, node: &'ast crate::api::admissionregistration::v1::TypeChecking) {
+        visit_api_admissionregistration_v1_type_checking(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1_validating_admission_policy(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1_validating_admission_policy(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_binding(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1_validating_admission_policy_binding(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_binding_spec(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1_validating_admission_policy_binding_spec(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_spec(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1_validating_admission_policy_spec(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_status(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus) {
+        visit_api_admissionregistration_v1_validating_admission_policy_status(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingWebhook`](crate::api::admissionregistration::v1::ValidatingWebhook).
+    fn visit_api_admissionregistration_v1_validating_webhook(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingWebhook) {
+        visit_api_admissionregistration_v1_validating_webhook(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingWebhookConfiguration`](crate::api::admissionregistration::v1::ValidatingWebhookConfiguration).
+    fn visit_api_admissionregistration_v1_validating_webhook_configuration(&mut self, node: &'ast crate::api::admissionregistration::v1::ValidatingWebhookConfiguration) {
+        visit_api_admissionregistration_v1_validating_webhook_configuration(self, node);
+    }
+
+    /// Visits a value of type [`Validation`](crate::api::admissionregistration::v1::Validation).
+    fn visit_api_admissionregistration_v1_validation(&mut self, node: &'ast crate::api::admissionregistration::v1::Validation) {
+        visit_api_admissionregistration_v1_validation(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1::Variable).
+    fn visit_api_admissionregistration_v1_variable(&mut self, node: &'ast crate::api::admissionregistration::v1::Variable) {
+        visit_api_admissionregistration_v1_variable(self, node);
+    }
+
+    /// Visits a value of type [`WebhookClientConfig`](crate::api::admissionregistration::v1::WebhookClientConfig).
+    fn visit_api_admissionregistration_v1_webhook_client_config(&mut self, node: &'ast crate::api::admissionregistration::v1::WebhookClientConfig) {
+        visit_api_admissionregistration_v1_webhook_client_config(self, node);
+    }
+
+    /// Visits a value of type [`ApplyConfiguration`](crate::api::admissionregistration::v1alpha1::ApplyConfiguration).
+    fn visit_api_admissionregistration_v1alpha1_apply_configuration(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::ApplyConfiguration) {
+        visit_api_admissionregistration_v1alpha1_apply_configuration(self, node);
+    }
+
+    /// Visits a value of type [`JSONPatch`](crate::api::admissionregistration::v1alpha1::JSONPatch).
+    fn visit_api_admissionregistration_v1alpha1_json_patch(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::JSONPatch) {
+        visit_api_admissionregistration_v1alpha1_json_patch(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1alpha1::MatchCondition).
+    fn visit_api_admissionregistration_v1alpha1_match_condition(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MatchCondition) {
+        visit_api_admissionregistration_v1alpha1_match_condition(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1alpha1::MatchResources).
+    fn visit_api_admissionregistration_v1alpha1_match_resources(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MatchResources) {
+        visit_api_admissionregistration_v1alpha1_match_resources(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicy`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicySpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec(self, node);
+    }
+
+    /// Visits a value of type [`Mutation`](crate::api::admissionregistration::v1alpha1::Mutation).
+    fn visit_api_admissionregistration_v1alpha1_mutation(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::Mutation) {
+        visit_api_admissionregistration_v1alpha1_mutation(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1alpha1_named_rule_with_operations(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1alpha1_named_rule_with_operations(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1alpha1::ParamKind).
+    fn visit_api_admissionregistration_v1alpha1_param_kind(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::ParamKind) {
+        visit_api_admissionregistration_v1alpha1_param_kind(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1alpha1::ParamRef).
+    fn visit_api_admissionregistration_v1alpha1_param_ref(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::ParamRef) {
+        visit_api_admissionregistration_v1alpha1_param_ref(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1alpha1::Variable).
+    fn visit_api_admissionregistration_v1alpha1_variable(&mut self, node: &'ast crate::api::admissionregistration::v1alpha1::Variable) {
+        visit_api_admissionregistration_v1alpha1_variable(self, node);
+    }
+
+    /// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1beta1::AuditAnnotation).
+    fn visit_api_admissionregistration_v1beta1_audit_annotation(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::AuditAnnotation) {
+        visit_api_admissionregistration_v1beta1_audit_annotation(self, node);
+    }
+
+    /// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1beta1::ExpressionWarning).
+    fn visit_api_admissionregistration_v1beta1_expression_warning(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ExpressionWarning) {
+        visit_api_admissionregistration_v1beta1_expression_warning(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1beta1::MatchCondition).
+    fn visit_api_admissionregistration_v1beta1_match_condition(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::MatchCondition) {
+        visit_api_admissionregistration_v1beta1_match_condition(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1beta1::MatchResources).
+    fn visit_api_admissionregistration_v1beta1_match_resources(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::MatchResources) {
+        visit_api_admissionregistration_v1beta1_match_resources(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1beta1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1beta1_named_rule_with_operations(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1beta1_named_rule_with_operations(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1beta1::ParamKind).
+    fn visit_api_admissionregistration_v1beta1_param_kind(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ParamKind) {
+        visit_api_admissionregistration_v1beta1_param_kind(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1beta1::ParamRef).
+    fn visit_api_admissionregistration_v1beta1_param_ref(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ParamRef) {
+        visit_api_admissionregistration_v1beta1_param_ref(self, node);
+    }
+
+    /// Visits a value of type [`TypeChecking`](crate::api::admissionregistration::v1beta1::TypeChecking).
+    fn visit_api_admissionregistration_v1beta1_type_checking(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::TypeChecking) {
+        visit_api_admissionregistration_v1beta1_type_checking(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_binding(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_spec(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_spec(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_status(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_status(self, node);
+    }
+
+    /// Visits a value of type [`Validation`](crate::api::admissionregistration::v1beta1::Validation).
+    fn visit_api_admissionregistration_v1beta1_validation(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::Validation) {
+        visit_api_admissionregistration_v1beta1_validation(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1beta1::Variable).
+    fn visit_api_admissionregistration_v1beta1_variable(&mut self, node: &'ast crate::api::admissionregistration::v1beta1::Variable) {
+        visit_api_admissionregistration_v1beta1_variable(self, node);
+    }
+
+    /// Visits a value of type [`ServerStorageVersion`](crate::api::apiserverinternal::v1alpha1::ServerStorageVersion).
+    fn visit_api_apiserverinternal_v1alpha1_server_storage_version(&mut self, node: &'ast crate::api::apiserverinternal::v1alpha1::ServerStorageVersion) {
+        visit_api_apiserverinternal_v1alpha1_server_storage_version(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersion`](crate::api::apiserverinternal::v1alpha1::StorageVersion).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version(&mut self, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersion) {
+        visit_api_apiserverinternal_v1alpha1_storage_version(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionCondition`](crate::api::apiserverinternal::v1alpha1::StorageVersionCondition).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_condition(&mut self, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionCondition) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_condition(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionSpec`](crate::api::apiserverinternal::v1alpha1::StorageVersionSpec).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_spec(&mut self, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionSpec) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_spec(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionStatus`](crate::api::apiserverinternal::v1alpha1::StorageVersionStatus).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_status(&mut self, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionStatus) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_status(self, node);
+    }
+
+    /// Visits a value of type [`ControllerRevision`](crate::api::apps::v1::ControllerRevision).
+    fn visit_api_apps_v1_controller_revision(&mut self, node: &'ast crate::api::apps::v1::ControllerRevision) {
+        visit_api_apps_v1_controller_revision(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSet`](crate::api::apps::v1::DaemonSet).
+    fn visit_api_apps_v1_daemon_set(&mut self, node: &'ast crate::api::apps::v1::DaemonSet) {
+        visit_api_apps_v1_daemon_set(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetCondition`](crate::api::apps::v1::DaemonSetCondition).
+    fn visit_api_apps_v1_daemon_set_condition(&mut self, node: &'ast crate::api::apps::v1::DaemonSetCondition) {
+        visit_api_apps_v1_daemon_set_condition(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetSpec`](crate::api::apps::v1::DaemonSetSpec).
+    fn visit_api_apps_v1_daemon_set_spec(&mut self, node: &'ast crate::api::apps::v1::DaemonSetSpec) {
+        visit_api_apps_v1_daemon_set_spec(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetStatus`](crate::api::apps::v1::DaemonSetStatus).
+    fn visit_api_apps_v1_daemon_set_status(&mut self, node: &'ast crate::api::apps::v1::DaemonSetStatus) {
+        visit_api_apps_v1_daemon_set_status(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetUpdateStrategy`](crate::api::apps::v1::DaemonSetUpdateStrategy).
+    fn visit_api_apps_v1_daemon_set_update_strategy(&mut self, node: &'ast crate::api::apps::v1::DaemonSetUpdateStrategy) {
+        visit_api_apps_v1_daemon_set_update_strategy(self, node);
+    }
+
+    /// Visits a value of type [`Deployment`](crate::api::apps::v1::Deployment).
+    fn visit_api_apps_v1_deployment(&mut self, node: &'ast crate::api::apps::v1::Deployment) {
+        visit_api_apps_v1_deployment(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentCondition`](crate::api::apps::v1::DeploymentCondition).
+    fn visit_api_apps_v1_deployment_condition(&mut self, node: &'ast crate::api::apps::v1::DeploymentCondition) {
+        visit_api_apps_v1_deployment_condition(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentSpec`](crate::api::apps::v1::DeploymentSpec).
+    fn visit_api_apps_v1_deployment_spec(&mut self, node: &'ast crate::api::apps::v1::DeploymentSpec) {
+        visit_api_apps_v1_deployment_spec(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentStatus`](crate::api::apps::v1::DeploymentStatus).
+    fn visit_api_apps_v1_deployment_status(&mut self, node: &'ast crate::api::apps::v1::DeploymentStatus) {
+        visit_api_apps_v1_deployment_status(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentStrategy`](crate::api::apps::v1::DeploymentStrategy).
+    fn visit_api_apps_v1_deployment_strategy(&mut self, node: &'ast crate::api::apps::v1::DeploymentStrategy) {
+        visit_api_apps_v1_deployment_strategy(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSet`](crate::api::apps::v1::ReplicaSet).
+    fn visit_api_apps_v1_replica_set(&mut self, node: &'ast crate::api::apps::v1::ReplicaSet) {
+        visit_api_apps_v1_replica_set(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetCondition`](crate::api::apps::v1::ReplicaSetCondition).
+    fn visit_api_apps_v1_replica_set_condition(&mut self, node: &'ast crate::api::apps::v1::ReplicaSetCondition) {
+        visit_api_apps_v1_replica_set_condition(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetSpec`](crate::api::apps::v1::ReplicaSetSpec).
+    fn visit_api_apps_v1_replica_set_spec(&mut self, node: &'ast crate::api::apps::v1::ReplicaSetSpec) {
+        visit_api_apps_v1_replica_set_spec(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetStatus`](crate::api::apps::v1::ReplicaSetStatus).
+    fn visit_api_apps_v1_replica_set_status(&mut self, node: &'ast crate::api::apps::v1::ReplicaSetStatus) {
+        visit_api_apps_v1_replica_set_status(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateDaemonSet`](crate::api::apps::v1::RollingUpdateDaemonSet).
+    fn visit_api_apps_v1_rolling_update_daemon_set(&mut self, node: &'ast crate::api::apps::v1::RollingUpdateDaemonSet) {
+        visit_api_apps_v1_rolling_update_daemon_set(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateDeployment`](crate::api::apps::v1::RollingUpdateDeployment).
+    fn visit_api_apps_v1_rolling_update_deployment(&mut self, node: &'ast crate::api::apps::v1::RollingUpdateDeployment) {
+        visit_api_apps_v1_rolling_update_deployment(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateStatefulSetStrategy`](crate::api::apps::v1::RollingUpdateStatefulSetStrategy).
+    fn visit_api_apps_v1_rolling_update_stateful_set_strategy(&mut self, node: &'ast crate::api::apps::v1::RollingUpdateStatefulSetStrategy) {
+        visit_api_apps_v1_rolling_update_stateful_set_strategy(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSet`](crate::api::apps::v1::StatefulSet).
+    fn visit_api_apps_v1_stateful_set(&mut self, node: &'ast crate::api::apps::v1::StatefulSet) {
+        visit_api_apps_v1_stateful_set(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetCondition`](crate::api::apps::v1::StatefulSetCondition).
+    fn visit_api_apps_v1_stateful_set_condition(&mut self, node: &'ast crate::api::apps::v1::StatefulSetCondition) {
+        visit_api_apps_v1_stateful_set_condition(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetOrdinals`](crate::api::apps::v1::StatefulSetOrdinals).
+    fn visit_api_apps_v1_stateful_set_ordinals(&mut self, node: &'ast crate::api::apps::v1::StatefulSetOrdinals) {
+        visit_api_apps_v1_stateful_set_ordinals(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetPersistentVolumeClaimRetentionPolicy`](crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy).
+    fn visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy(&mut self, node: &'ast crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy) {
+        visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetSpec`](crate::api::apps::v1::StatefulSetSpec).
+    fn visit_api_apps_v1_stateful_set_spec(&mut self, node: &'ast crate::api::apps::v1::StatefulSetSpec) {
+        visit_api_apps_v1_stateful_set_spec(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetStatus`](crate::api::apps::v1::StatefulSetStatus).
+    fn visit_api_apps_v1_stateful_set_status(&mut self, node: &'ast crate::api::apps::v1::StatefulSetStatus) {
+        visit_api_apps_v1_stateful_set_status(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetUpdateStrategy`](crate::api::apps::v1::StatefulSetUpdateStrategy).
+    fn visit_api_apps_v1_stateful_set_update_strategy(&mut self, node: &'ast crate::api::apps::v1::StatefulSetUpdateStrategy) {
+        visit_api_apps_v1_stateful_set_update_strategy(self, node);
+    }
+
+    /// Visits a value of type [`BoundObjectReference`](crate::api::authentication::v1::BoundObjectReference).
+    fn visit_api_authentication_v1_bound_object_reference(&mut self, node: &'ast crate::api::authentication::v1::BoundObjectReference) {
+        visit_api_authentication_v1_bound_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReview`](crate::api::authentication::v1::SelfSubjectReview).
+    fn visit_api_authentication_v1_self_subject_review(&mut self, node: &'ast crate::api::authentication::v1::SelfSubjectReview) {
+        visit_api_authentication_v1_self_subject_review(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1::SelfSubjectReviewStatus).
+    fn visit_api_authentication_v1_self_subject_review_status(&mut self, node: &'ast crate::api::authentication::v1::SelfSubjectReviewStatus) {
+        visit_api_authentication_v1_self_subject_review_status(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequest`](crate::api::authentication::v1::TokenRequest).
+    fn visit_api_authentication_v1_token_request(&mut self, node: &'ast crate::api::authentication::v1::TokenRequest) {
+        visit_api_authentication_v1_token_request(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequestSpec`](crate::api::authentication::v1::TokenRequestSpec).
+    fn visit_api_authentication_v1_token_request_spec(&mut self, node: &'ast crate::api::authentication::v1::TokenRequestSpec) {
+        visit_api_authentication_v1_token_request_spec(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequestStatus`](crate::api::authentication::v1::TokenRequestStatus).
+    fn visit_api_authentication_v1_token_request_status(&mut self, node: &'ast crate::api::authentication::v1::TokenRequestStatus) {
+        visit_api_authentication_v1_token_request_status(self, node);
+    }
+
+    /// Visits a value of type [`TokenReview`](crate::api::authentication::v1::TokenReview).
+    fn visit_api_authentication_v1_token_review(&mut self, node: &'ast crate::api::authentication::v1::TokenReview) {
+        visit_api_authentication_v1_token_review(self, node);
+    }
+
+    /// Visits a value of type [`TokenReviewSpec`](crate::api::authentication::v1::TokenReviewSpec).
+    fn visit_api_authentication_v1_token_review_spec(&mut self, node: &'ast crate::api::authentication::v1::TokenReviewSpec) {
+        visit_api_authentication_v1_token_review_spec(self, node);
+    }
+
+    /// Visits a value of type [`TokenReviewStatus`](crate::api::authentication::v1::TokenReviewStatus).
+    fn visit_api_authentication_v1_token_review_status(&mut self, node: &'ast crate::api::authentication::v1::TokenReviewStatus) {
+        visit_api_authentication_v1_token_review_status(self, node);
+    }
+
+    /// Visits a value of type [`UserInfo`](crate::api::authentication::v1::UserInfo).
+    fn visit_api_authentication_v1_user_info(&mut self, node: &'ast crate::api::authentication::v1::UserInfo) {
+        visit_api_authentication_v1_user_info(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReview`](crate::api::authentication::v1beta1::SelfSubjectReview).
+    fn visit_api_authentication_v1beta1_self_subject_review(&mut self, node: &'ast crate::api::authentication::v1beta1::SelfSubjectReview) {
+        visit_api_authentication_v1beta1_self_subject_review(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1beta1::SelfSubjectReviewStatus).
+    fn visit_api_authentication_v1beta1_self_subject_review_status(&mut self, node: &'ast crate::api::authentication::v1beta1::SelfSubjectReviewStatus) {
+        visit_api_authentication_v1beta1_self_subject_review_status(self, node);
+    }
+
+    /// Visits a value of type [`FieldSelectorAttributes`](crate::api::authorization::v1::FieldSelectorAttributes).
+    fn visit_api_authorization_v1_field_selector_attributes(&mut self, node: &'ast crate::api::authorization::v1::FieldSelectorAttributes) {
+        visit_api_authorization_v1_field_selector_attributes(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelectorAttributes`](crate::api::authorization::v1::LabelSelectorAttributes).
+    fn visit_api_authorization_v1_label_selector_attributes(&mut self, node: &'ast crate::api::authorization::v1::LabelSelectorAttributes) {
+        visit_api_authorization_v1_label_selector_attributes(self, node);
+    }
+
+    /// Visits a value of type [`LocalSubjectAccessReview`](crate::api::authorization::v1::LocalSubjectAccessReview).
+    fn visit_api_authorization_v1_local_subject_access_review(&mut self, node: &'ast crate::api::authorization::v1::LocalSubjectAccessReview) {
+        visit_api_authorization_v1_local_subject_access_review(self, node);
+    }
+
+    /// Visits a value of type [`NonResourceAttributes`](crate::api::authorization::v1::NonResourceAttributes).
+    fn visit_api_authorization_v1_non_resource_attributes(&mut self, node: &'ast crate::api::authorization::v1::NonResourceAttributes) {
+        visit_api_authorization_v1_non_resource_attributes(self, node);
+    }
+
+    /// Visits a value of type [`NonResourceRule`](crate::api::authorization::v1::NonResourceRule).
+    fn visit_api_authorization_v1_non_resource_rule(&mut self, node: &'ast crate::api::authorization::v1::NonResourceRule) {
+        visit_api_authorization_v1_non_resource_rule(self, node);
+    }
+
+    /// Visits a value of type [`ResourceAttributes`](crate::api::authorization::v1::ResourceAttributes).
+    fn visit_api_authorization_v1_resource_attributes(&mut self, node: &'ast crate::api::authorization::v1::ResourceAttributes) {
+        visit_api_authorization_v1_resource_attributes(self, node);
+    }
+
+    /// Visits a value of type [`ResourceRule`](crate::api::authorization::v1::ResourceRule).
+    fn visit_api_authorization_v1_resource_rule(&mut self, node: &'ast crate::api::authorization::v1::ResourceRule) {
+        visit_api_authorization_v1_resource_rule(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectAccessReview`](crate::api::authorization::v1::SelfSubjectAccessReview).
+    fn visit_api_authorization_v1_self_subject_access_review(&mut self, node: &'ast crate::api::authorization::v1::SelfSubjectAccessReview) {
+        visit_api_authorization_v1_self_subject_access_review(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectAccessReviewSpec`](crate::api::authorization::v1::SelfSubjectAccessReviewSpec).
+    fn visit_api_authorization_v1_self_subject_access_review_spec(&mut self, node: &'ast crate::api::authorization::v1::SelfSubjectAccessReviewSpec) {
+        visit_api_authorization_v1_self_subject_access_review_spec(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectRulesReview`](crate::api::authorization::v1::SelfSubjectRulesReview).
+    fn visit_api_authorization_v1_self_subject_rules_review(&mut self, node: &'ast crate::api::authorization::v1::SelfSubjectRulesReview) {
+        visit_api_authorization_v1_self_subject_rules_review(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectRulesReviewSpec`](crate::api::authorization::v1::SelfSubjectRulesReviewSpec).
+    fn visit_api_authorization_v1_self_subject_rules_review_spec(&mut self, node: &'ast crate::api::authorization::v1::SelfSubjectRulesReviewSpec) {
+        visit_api_authorization_v1_self_subject_rules_review_spec(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReview`](crate::api::authorization::v1::SubjectAccessReview).
+    fn visit_api_authorization_v1_subject_access_review(&mut self, node: &'ast crate::api::authorization::v1::SubjectAccessReview) {
+        visit_api_authorization_v1_subject_access_review(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReviewSpec`](crate::api::authorization::v1::SubjectAccessReviewSpec).
+    fn visit_api_authorization_v1_subject_access_review_spec(&mut self, node: &'ast crate::api::authorization::v1::SubjectAccessReviewSpec) {
+        visit_api_authorization_v1_subject_access_review_spec(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReviewStatus`](crate::api::authorization::v1::SubjectAccessReviewStatus).
+    fn visit_api_authorization_v1_subject_access_review_status(&mut self, node: &'ast crate::api::authorization::v1::SubjectAccessReviewStatus) {
+        visit_api_authorization_v1_subject_access_review_status(self, node);
+    }
+
+    /// Visits a value of type [`SubjectRulesReviewStatus`](crate::api::authorization::v1::SubjectRulesReviewStatus).
+    fn visit_api_authorization_v1_subject_rules_review_status(&mut self, node: &'ast crate::api::authorization::v1::SubjectRulesReviewStatus) {
+        visit_api_authorization_v1_subject_rules_review_status(self, node);
+    }
+
+    /// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v1::CrossVersionObjectReference).
+    fn visit_api_autoscaling_v1_cross_version_object_reference(&mut self, node: &'ast crate::api::autoscaling::v1::CrossVersionObjectReference) {
+        visit_api_autoscaling_v1_cross_version_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v1::HorizontalPodAutoscaler).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler(&mut self, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscaler) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec(&mut self, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_status(&mut self, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler_status(self, node);
+    }
+
+    /// Visits a value of type [`Scale`](crate::api::autoscaling::v1::Scale).
+    fn visit_api_autoscaling_v1_scale(&mut self, node: &'ast crate::api::autoscaling::v1::Scale) {
+        visit_api_autoscaling_v1_scale(self, node);
+    }
+
+    /// Visits a value of type [`ScaleSpec`](crate::api::autoscaling::v1::ScaleSpec).
+    fn visit_api_autoscaling_v1_scale_spec(&mut self, node: &'ast crate::api::autoscaling::v1::ScaleSpec) {
+        visit_api_autoscaling_v1_scale_spec(self, node);
+    }
+
+    /// Visits a value of type [`ScaleStatus`](crate::api::autoscaling::v1::ScaleStatus).
+    fn visit_api_autoscaling_v1_scale_status(&mut self, node: &'ast crate::api::autoscaling::v1::ScaleStatus) {
+        visit_api_autoscaling_v1_scale_status(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResourceMetricSource`](crate::api::autoscaling::v2::ContainerResourceMetricSource).
+    fn visit_api_autoscaling_v2_container_resource_metric_source(&mut self, node: &'ast crate::api::autoscaling::v2::ContainerResourceMetricSource) {
+        visit_api_autoscaling_v2_container_resource_metric_source(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResourceMetricStatus`](crate::api::autoscaling::v2::ContainerResourceMetricStatus).
+    fn visit_api_autoscaling_v2_container_resource_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::ContainerResourceMetricStatus) {
+        visit_api_autoscaling_v2_container_resource_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v2::CrossVersionObjectReference).
+    fn visit_api_autoscaling_v2_cross_version_object_reference(&mut self, node: &'ast crate::api::autoscaling::v2::CrossVersionObjectReference) {
+        visit_api_autoscaling_v2_cross_version_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`ExternalMetricSource`](crate::api::autoscaling::v2::ExternalMetricSource).
+    fn visit_api_autoscaling_v2_external_metric_source(&mut self, node: &'ast crate::api::autoscaling::v2::ExternalMetricSource) {
+        visit_api_autoscaling_v2_external_metric_source(self, node);
+    }
+
+    /// Visits a value of type [`ExternalMetricStatus`](crate::api::autoscaling::v2::ExternalMetricStatus).
+    fn visit_api_autoscaling_v2_external_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::ExternalMetricStatus) {
+        visit_api_autoscaling_v2_external_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`HPAScalingPolicy`](crate::api::autoscaling::v2::HPAScalingPolicy).
+    fn visit_api_autoscaling_v2_hpa_scaling_policy(&mut self, node: &'ast crate::api::autoscaling::v2::HPAScalingPolicy) {
+        visit_api_autoscaling_v2_hpa_scaling_policy(self, node);
+    }
+
+    /// Visits a value of type [`HPAScalingRules`](crate::api::autoscaling::v2::HPAScalingRules).
+    fn visit_api_autoscaling_v2_hpa_scaling_rules(&mut self, node: &'ast crate::api::autoscaling::v2::HPAScalingRules) {
+        visit_api_autoscaling_v2_hpa_scaling_rules(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v2::HorizontalPodAutoscaler).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler(&mut self, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscaler) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerBehavior`](crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior(&mut self, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerCondition`](crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition(&mut self, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec(&mut self, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_status(&mut self, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_status(self, node);
+    }
+
+    /// Visits a value of type [`MetricIdentifier`](crate::api::autoscaling::v2::MetricIdentifier).
+    fn visit_api_autoscaling_v2_metric_identifier(&mut self, node: &'ast crate::api::autoscaling::v2::MetricIdentifier) {
+        visit_api_autoscaling_v2_metric_identifier(self, node);
+    }
+
+    /// Visits a value of type [`MetricSpec`](crate::api::autoscaling::v2::MetricSpec).
+    fn visit_api_autoscaling_v2_metric_spec(&mut self, node: &'ast crate::api::autoscaling::v2::MetricSpec) {
+        visit_api_autoscaling_v2_metric_spec(self, node);
+    }
+
+    /// Visits a value of type [`MetricStatus`](crate::api::autoscaling::v2::MetricStatus).
+    fn visit_api_autoscaling_v2_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::MetricStatus) {
+        visit_api_autoscaling_v2_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`MetricTarget`](crate::api::autoscaling::v2::MetricTarget).
+    fn visit_api_autoscaling_v2_metric_target(&mut self, node: &'ast crate::api::autoscaling::v2::MetricTarget) {
+        visit_api_autoscaling_v2_metric_target(self, node);
+    }
+
+    /// Visits a value of type [`MetricValueStatus`](crate::api::autoscaling::v2::MetricValueStatus).
+    fn visit_api_autoscaling_v2_metric_value_status(&mut self, node: &'ast crate::api::autoscaling::v2::MetricValueStatus) {
+        visit_api_autoscaling_v2_metric_value_status(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMetricSource`](crate::api::autoscaling::v2::ObjectMetricSource).
+    fn visit_api_autoscaling_v2_object_metric_source(&mut self, node: &'ast crate::api::autoscaling::v2::ObjectMetricSource) {
+        visit_api_autoscaling_v2_object_metric_source(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMetricStatus`](crate::api::autoscaling::v2::ObjectMetricStatus).
+    fn visit_api_autoscaling_v2_object_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::ObjectMetricStatus) {
+        visit_api_autoscaling_v2_object_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`PodsMetricSource`](crate::api::autoscaling::v2::PodsMetricSource).
+    fn visit_api_autoscaling_v2_pods_metric_source(&mut self, node: &'ast crate::api::autoscaling::v2::PodsMetricSource) {
+        visit_api_autoscaling_v2_pods_metric_source(self, node);
+    }
+
+    /// Visits a value of type [`PodsMetricStatus`](crate::api::autoscaling::v2::PodsMetricStatus).
+    fn visit_api_autoscaling_v2_pods_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::PodsMetricStatus) {
+        visit_api_autoscaling_v2_pods_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`ResourceMetricSource`](crate::api::autoscaling::v2::ResourceMetricSource).
+    fn visit_api_autoscaling_v2_resource_metric_source(&mut self, node: &'ast crate::api::autoscaling::v2::ResourceMetricSource) {
+        visit_api_autoscaling_v2_resource_metric_source(self, node);
+    }
+
+    /// Visits a value of type [`ResourceMetricStatus`](crate::api::autoscaling::v2::ResourceMetricStatus).
+    fn visit_api_autoscaling_v2_resource_metric_status(&mut self, node: &'ast crate::api::autoscaling::v2::ResourceMetricStatus) {
+        visit_api_autoscaling_v2_resource_metric_status(self, node);
+    }
+
+    /// Visits a value of type [`CronJob`](crate::api::batch::v1::CronJob).
+    fn visit_api_batch_v1_cron_job(&mut self, node: &'ast crate::api::batch::v1::CronJob) {
+        visit_api_batch_v1_cron_job(self, node);
+    }
+
+    /// Visits a value of type [`CronJobSpec`](crate::api::batch::v1::CronJobSpec).
+    fn visit_api_batch_v1_cron_job_spec(&mut self, node: &'ast crate::api::batch::v1::CronJobSpec) {
+        visit_api_batch_v1_cron_job_spec(self, node);
+    }
+
+    /// Visits a value of type [`CronJobStatus`](crate::api::batch::v1::CronJobStatus).
+    fn visit_api_batch_v1_cron_job_status(&mut self, node: &'ast crate::api::batch::v1::CronJobStatus) {
+        visit_api_batch_v1_cron_job_status(self, node);
+    }
+
+    /// Visits a value of type [`Job`](crate::api::batch::v1::Job).
+    fn visit_api_batch_v1_job(&mut self, node: &'ast crate::api::batch::v1::Job) {
+        visit_api_batch_v1_job(self, node);
+    }
+
+    /// Visits a value of type [`JobCondition`](crate::api::batch::v1::JobCondition).
+    fn visit_api_batch_v1_job_condition(&mut self, node: &'ast crate::api::batch::v1::JobCondition) {
+        visit_api_batch_v1_job_condition(self, node);
+    }
+
+    /// Visits a value of type [`JobSpec`](crate::api::batch::v1::JobSpec).
+    fn visit_api_batch_v1_job_spec(&mut self, node: &'ast crate::api::batch::v1::JobSpec) {
+        visit_api_batch_v1_job_spec(self, node);
+    }
+
+    /// Visits a value of type [`JobStatus`](crate::api::batch::v1::JobStatus).
+    fn visit_api_batch_v1_job_status(&mut self, node: &'ast crate::api::batch::v1::JobStatus) {
+        visit_api_batch_v1_job_status(self, node);
+    }
+
+    /// Visits a value of type [`JobTemplateSpec`](crate::api::batch::v1::JobTemplateSpec).
+    fn visit_api_batch_v1_job_template_spec(&mut self, node: &'ast crate::api::batch::v1::JobTemplateSpec) {
+        visit_api_batch_v1_job_template_spec(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicy`](crate::api::batch::v1::PodFailurePolicy).
+    fn visit_api_batch_v1_pod_failure_policy(&mut self, node: &'ast crate::api::batch::v1::PodFailurePolicy) {
+        visit_api_batch_v1_pod_failure_policy(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyOnExitCodesRequirement`](crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement).
+    fn visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement(&mut self, node: &'ast crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement) {
+        visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyOnPodConditionsPattern`](crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern).
+    fn visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern(&mut self, node: &'ast crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern) {
+        visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyRule`](crate::api::batch::v1::PodFailurePolicyRule).
+    fn visit_api_batch_v1_pod_failure_policy_rule(&mut self, node: &'ast crate::api::batch::v1::PodFailurePolicyRule) {
+        visit_api_batch_v1_pod_failure_policy_rule(self, node);
+    }
+
+    /// Visits a value of type [`SuccessPolicy`](crate::api::batch::v1::SuccessPolicy).
+    fn visit_api_batch_v1_success_policy(&mut self, node: &'ast crate::api::batch::v1::SuccessPolicy) {
+        visit_api_batch_v1_success_policy(self, node);
+    }
+
+    /// Visits a value of type [`SuccessPolicyRule`](crate::api::batch::v1::SuccessPolicyRule).
+    fn visit_api_batch_v1_success_policy_rule(&mut self, node: &'ast crate::api::batch::v1::SuccessPolicyRule) {
+        visit_api_batch_v1_success_policy_rule(self, node);
+    }
+
+    /// Visits a value of type [`UncountedTerminatedPods`](crate::api::batch::v1::UncountedTerminatedPods).
+    fn visit_api_batch_v1_uncounted_terminated_pods(&mut self, node: &'ast crate::api::batch::v1::UncountedTerminatedPods) {
+        visit_api_batch_v1_uncounted_terminated_pods(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequest`](crate::api::certificates::v1::CertificateSigningRequest).
+    fn visit_api_certificates_v1_certificate_signing_request(&mut self, node: &'ast crate::api::certificates::v1::CertificateSigningRequest) {
+        visit_api_certificates_v1_certificate_signing_request(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestCondition`](crate::api::certificates::v1::CertificateSigningRequestCondition).
+    fn visit_api_certificates_v1_certificate_signing_request_condition(&mut self, node: &'ast crate::api::certificates::v1::CertificateSigningRequestCondition) {
+        visit_api_certificates_v1_certificate_signing_request_condition(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestSpec`](crate::api::certificates::v1::CertificateSigningRequestSpec).
+    fn visit_api_certificates_v1_certificate_signing_request_spec(&mut self, node: &'ast crate::api::certificates::v1::CertificateSigningRequestSpec) {
+        visit_api_certificates_v1_certificate_signing_request_spec(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestStatus`](crate::api::certificates::v1::CertificateSigningRequestStatus).
+    fn visit_api_certificates_v1_certificate_signing_request_status(&mut self, node: &'ast crate::api::certificates::v1::CertificateSigningRequestStatus) {
+        visit_api_certificates_v1_certificate_signing_request_status(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundle`](crate::api::certificates::v1alpha1::ClusterTrustBundle).
+    fn visit_api_certificates_v1alpha1_cluster_trust_bundle(&mut self, node: &'ast crate::api::certificates::v1alpha1::ClusterTrustBundle) {
+        visit_api_certificates_v1alpha1_cluster_trust_bundle(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundleSpec`](crate::api::certificates::v1alpha1::ClusterTrustBundleSpec).
+    fn visit_api_certificates_v1alpha1_cluster_trust_bundle_spec(&mut self, node: &'ast crate::api::certificates::v1alpha1::ClusterTrustBundleSpec) {
+        visit_api_certificates_v1alpha1_cluster_trust_bundle_spec(self, node);
+    }
+
+    /// Visits a value of type [`Lease`](crate::api::coordination::v1::Lease).
+    fn visit_api_coordination_v1_lease(&mut self, node: &'ast crate::api::coordination::v1::Lease) {
+        visit_api_coordination_v1_lease(self, node);
+    }
+
+    /// Visits a value of type [`LeaseSpec`](crate::api::coordination::v1::LeaseSpec).
+    fn visit_api_coordination_v1_lease_spec(&mut self, node: &'ast crate::api::coordination::v1::LeaseSpec) {
+        visit_api_coordination_v1_lease_spec(self, node);
+    }
+
+    /// Visits a value of type [`LeaseCandidate`](crate::api::coordination::v1alpha2::LeaseCandidate).
+    fn visit_api_coordination_v1alpha2_lease_candidate(&mut self, node: &'ast crate::api::coordination::v1alpha2::LeaseCandidate) {
+        visit_api_coordination_v1alpha2_lease_candidate(self, node);
+    }
+
+    /// Visits a value of type [`LeaseCandidateSpec`](crate::api::coordination::v1alpha2::LeaseCandidateSpec).
+    fn visit_api_coordination_v1alpha2_lease_candidate_spec(&mut self, node: &'ast crate::api::coordination::v1alpha2::LeaseCandidateSpec) {
+        visit_api_coordination_v1alpha2_lease_candidate_spec(self, node);
+    }
+
+    /// Visits a value of type [`AWSElasticBlockStoreVolumeSource`](crate::api::core::v1::AWSElasticBlockStoreVolumeSource).
+    fn visit_api_core_v1_aws_elastic_block_store_volume_source(&mut self, node: &'ast crate::api::core::v1::AWSElasticBlockStoreVolumeSource) {
+        visit_api_core_v1_aws_elastic_block_store_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Affinity`](crate::api::core::v1::Affinity).
+    fn visit_api_core_v1_affinity(&mut self, node: &'ast crate::api::core::v1::Affinity) {
+        visit_api_core_v1_affinity(self, node);
+    }
+
+    /// Visits a value of type [`AppArmorProfile`](crate::api::core::v1::AppArmorProfile).
+    fn visit_api_core_v1_app_armor_profile(&mut self, node: &'ast crate::api::core::v1::AppArmorProfile) {
+        visit_api_core_v1_app_armor_profile(self, node);
+    }
+
+    /// Visits a value of type [`AttachedVolume`](crate::api::core::v1::AttachedVolume).
+    fn visit_api_core_v1_attached_volume(&mut self, node: &'ast crate::api::core::v1::AttachedVolume) {
+        visit_api_core_v1_attached_volume(self, node);
+    }
+
+    /// Visits a value of type [`AzureDiskVolumeSource`](crate::api::core::v1::AzureDiskVolumeSource).
+    fn visit_api_core_v1_azure_disk_volume_source(&mut self, node: &'ast crate::api::core::v1::AzureDiskVolumeSource) {
+        visit_api_core_v1_azure_disk_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`AzureFilePersistentVolumeSource`](crate::api::core::v1::AzureFilePersistentVolumeSource).
+    fn visit_api_core_v1_azure_file_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::AzureFilePersistentVolumeSource) {
+        visit_api_core_v1_azure_file_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`AzureFileVolumeSource`](crate::api::core::v1::AzureFileVolumeSource).
+    fn visit_api_core_v1_azure_file_volume_source(&mut self, node: &'ast crate::api::core::v1::AzureFileVolumeSource) {
+        visit_api_core_v1_azure_file_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Binding`](crate::api::core::v1::Binding).
+    fn visit_api_core_v1_binding(&mut self, node: &'ast crate::api::core::v1::Binding) {
+        visit_api_core_v1_binding(self, node);
+    }
+
+    /// Visits a value of type [`CSIPersistentVolumeSource`](crate::api::core::v1::CSIPersistentVolumeSource).
+    fn visit_api_core_v1_csi_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::CSIPersistentVolumeSource) {
+        visit_api_core_v1_csi_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`CSIVolumeSource`](crate::api::core::v1::CSIVolumeSource).
+    fn visit_api_core_v1_csi_volume_source(&mut self, node: &'ast crate::api::core::v1::CSIVolumeSource) {
+        visit_api_core_v1_csi_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Capabilities`](crate::api::core::v1::Capabilities).
+    fn visit_api_core_v1_capabilities(&mut self, node: &'ast crate::api::core::v1::Capabilities) {
+        visit_api_core_v1_capabilities(self, node);
+    }
+
+    /// Visits a value of type [`CephFSPersistentVolumeSource`](crate::api::core::v1::CephFSPersistentVolumeSource).
+    fn visit_api_core_v1_ceph_fs_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::CephFSPersistentVolumeSource) {
+        visit_api_core_v1_ceph_fs_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`CephFSVolumeSource`](crate::api::core::v1::CephFSVolumeSource).
+    fn visit_api_core_v1_ceph_fs_volume_source(&mut self, node: &'ast crate::api::core::v1::CephFSVolumeSource) {
+        visit_api_core_v1_ceph_fs_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`CinderPersistentVolumeSource`](crate::api::core::v1::CinderPersistentVolumeSource).
+    fn visit_api_core_v1_cinder_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::CinderPersistentVolumeSource) {
+        visit_api_core_v1_cinder_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`CinderVolumeSource`](crate::api::core::v1::CinderVolumeSource).
+    fn visit_api_core_v1_cinder_volume_source(&mut self, node: &'ast crate::api::core::v1::CinderVolumeSource) {
+        visit_api_core_v1_cinder_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ClientIPConfig`](crate::api::core::v1::ClientIPConfig).
+    fn visit_api_core_v1_client_ip_config(&mut self, node: &'ast crate::api::core::v1::ClientIPConfig) {
+        visit_api_core_v1_client_ip_config(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundleProjection`](crate::api::core::v1::ClusterTrustBundleProjection).
+    fn visit_api_core_v1_cluster_trust_bundle_projection(&mut self, node: &'ast crate::api::core::v1::ClusterTrustBundleProjection) {
+        visit_api_core_v1_cluster_trust_bundle_projection(self, node);
+    }
+
+    /// Visits a value of type [`ComponentCondition`](crate::api::core::v1::ComponentCondition).
+    fn visit_api_core_v1_component_condition(&mut self, node: &'ast crate::api::core::v1::ComponentCondition) {
+        visit_api_core_v1_component_condition(self, node);
+    }
+
+    /// Visits a value of type [`ComponentStatus`](crate::api::core::v1::ComponentStatus).
+    fn visit_api_core_v1_component_status(&mut self, node: &'ast crate::api::core::v1::ComponentStatus) {
+        visit_api_core_v1_component_status(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMap`](crate::api::core::v1::ConfigMap).
+    fn visit_api_core_v1_config_map(&mut self, node: &'ast crate::api::core::v1::ConfigMap) {
+        visit_api_core_v1_config_map(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapEnvSource`](crate::api::core::v1::ConfigMapEnvSource).
+    fn visit_api_core_v1_config_map_env_source(&mut self, node: &'ast crate::api::core::v1::ConfigMapEnvSource) {
+        visit_api_core_v1_config_map_env_source(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapKeySelector`](crate::api::core::v1::ConfigMapKeySelector).
+    fn visit_api_core_v1_config_map_key_selector(&mut self, node: &'ast crate::api::core::v1::ConfigMapKeySelector) {
+        visit_api_core_v1_config_map_key_selector(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapNodeConfigSource`](crate::api::core::v1::ConfigMapNodeConfigSource).
+    fn visit_api_core_v1_config_map_node_config_source(&mut self, node: &'ast crate::api::core::v1::ConfigMapNodeConfigSource) {
+        visit_api_core_v1_config_map_node_config_source(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapProjection`](crate::api::core::v1::ConfigMapProjection).
+    fn visit_api_core_v1_config_map_projection(&mut self, node: &'ast crate::api::core::v1::ConfigMapProjection) {
+        visit_api_core_v1_config_map_projection(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapVolumeSource`](crate::api::core::v1::ConfigMapVolumeSource).
+    fn visit_api_core_v1_config_map_volume_source(&mut self, node: &'ast crate::api::core::v1::ConfigMapVolumeSource) {
+        visit_api_core_v1_config_map_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Container`](crate::api::core::v1::Container).
+    fn visit_api_core_v1_container(&mut self, node: &'ast crate::api::core::v1::Container) {
+        visit_api_core_v1_container(self, node);
+    }
+
+    /// Visits a value of type [`ContainerImage`](crate::api::core::v1::ContainerImage).
+    fn visit_api_core_v1_container_image(&mut self, node: &'ast crate::api::core::v1::ContainerImage) {
+        visit_api_core_v1_container_image(self, node);
+    }
+
+    /// Visits a value of type [`ContainerPort`](crate::api::core::v1::ContainerPort).
+    fn visit_api_core_v1_container_port(&mut self, node: &'ast crate::api::core::v1::ContainerPort) {
+        visit_api_core_v1_container_port(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResizePolicy`](crate::api::core::v1::ContainerResizePolicy).
+    fn visit_api_core_v1_container_resize_policy(&mut self, node: &'ast crate::api::core::v1::ContainerResizePolicy) {
+        visit_api_core_v1_container_resize_policy(self, node);
+    }
+
+    /// Visits a value of type [`ContainerState`](crate::api::core::v1::ContainerState).
+    fn visit_api_core_v1_container_state(&mut self, node: &'ast crate::api::core::v1::ContainerState) {
+        visit_api_core_v1_container_state(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateRunning`](crate::api::core::v1::ContainerStateRunning).
+    fn visit_api_core_v1_container_state_running(&mut self, node: &'ast crate::api::core::v1::ContainerStateRunning) {
+        visit_api_core_v1_container_state_running(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateTerminated`](crate::api::core::v1::ContainerStateTerminated).
+    fn visit_api_core_v1_container_state_terminated(&mut self, node: &'ast crate::api::core::v1::ContainerStateTerminated) {
+        visit_api_core_v1_container_state_terminated(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateWaiting`](crate::api::core::v1::ContainerStateWaiting).
+    fn visit_api_core_v1_container_state_waiting(&mut self, node: &'ast crate::api::core::v1::ContainerStateWaiting) {
+        visit_api_core_v1_container_state_waiting(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStatus`](crate::api::core::v1::ContainerStatus).
+    fn visit_api_core_v1_container_status(&mut self, node: &'ast crate::api::core::v1::ContainerStatus) {
+        visit_api_core_v1_container_status(self, node);
+    }
+
+    /// Visits a value of type [`ContainerUser`](crate::api::core::v1::ContainerUser).
+    fn visit_api_core_v1_container_user(&mut self, node: &'ast crate::api::core::v1::ContainerUser) {
+        visit_api_core_v1_container_user(self, node);
+    }
+
+    /// Visits a value of type [`DaemonEndpoint`](crate::api::core::v1::DaemonEndpoint).
+    fn visit_api_core_v1_daemon_endpoint(&mut self, node: &'ast crate::api::core::v1::DaemonEndpoint) {
+        visit_api_core_v1_daemon_endpoint(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIProjection`](crate::api::core::v1::DownwardAPIProjection).
+    fn visit_api_core_v1_downward_api_projection(&mut self, node: &'ast crate::api::core::v1::DownwardAPIProjection) {
+        visit_api_core_v1_downward_api_projection(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIVolumeFile`](crate::api::core::v1::DownwardAPIVolumeFile).
+    fn visit_api_core_v1_downward_api_volume_file(&mut self, node: &'ast crate::api::core::v1::DownwardAPIVolumeFile) {
+        visit_api_core_v1_downward_api_volume_file(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIVolumeSource`](crate::api::core::v1::DownwardAPIVolumeSource).
+    fn visit_api_core_v1_downward_api_volume_source(&mut self, node: &'ast crate::api::core::v1::DownwardAPIVolumeSource) {
+        visit_api_core_v1_downward_api_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`EmptyDirVolumeSource`](crate::api::core::v1::EmptyDirVolumeSource).
+    fn visit_api_core_v1_empty_dir_volume_source(&mut self, node: &'ast crate::api::core::v1::EmptyDirVolumeSource) {
+        visit_api_core_v1_empty_dir_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`EndpointAddress`](crate::api::core::v1::EndpointAddress).
+    fn visit_api_core_v1_endpoint_address(&mut self, node: &'ast crate::api::core::v1::EndpointAddress) {
+        visit_api_core_v1_endpoint_address(self, node);
+    }
+
+    /// Visits a value of type [`EndpointPort`](crate::api::core::v1::EndpointPort).
+    fn visit_api_core_v1_endpoint_port(&mut self, node: &'ast crate::api::core::v1::EndpointPort) {
+        visit_api_core_v1_endpoint_port(self, node);
+    }
+
+    /// Visits a value of type [`EndpointSubset`](crate::api::core::v1::EndpointSubset).
+    fn visit_api_core_v1_endpoint_subset(&mut self, node: &'ast crate::api::core::v1::EndpointSubset) {
+        visit_api_core_v1_endpoint_subset(self, node);
+    }
+
+    /// Visits a value of type [`Endpoints`](crate::api::core::v1::Endpoints).
+    fn visit_api_core_v1_endpoints(&mut self, node: &'ast crate::api::core::v1::Endpoints) {
+        visit_api_core_v1_endpoints(self, node);
+    }
+
+    /// Visits a value of type [`EnvFromSource`](crate::api::core::v1::EnvFromSource).
+    fn visit_api_core_v1_env_from_source(&mut self, node: &'ast crate::api::core::v1::EnvFromSource) {
+        visit_api_core_v1_env_from_source(self, node);
+    }
+
+    /// Visits a value of type [`EnvVar`](crate::api::core::v1::EnvVar).
+    fn visit_api_core_v1_env_var(&mut self, node: &'ast crate::api::core::v1::EnvVar) {
+        visit_api_core_v1_env_var(self, node);
+    }
+
+    /// Visits a value of type [`EnvVarSource`](crate::api::core::v1::EnvVarSource).
+    fn visit_api_core_v1_env_var_source(&mut self, node: &'ast crate::api::core::v1::EnvVarSource) {
+        visit_api_core_v1_env_var_source(self, node);
+    }
+
+    /// Visits a value of type [`EphemeralContainer`](crate::api::core::v1::EphemeralContainer).
+    fn visit_api_core_v1_ephemeral_container(&mut self, node: &'ast crate::api::core::v1::EphemeralContainer) {
+        visit_api_core_v1_ephemeral_container(self, node);
+    }
+
+    /// Visits a value of type [`EphemeralVolumeSource`](crate::api::core::v1::EphemeralVolumeSource).
+    fn visit_api_core_v1_ephemeral_volume_source(&mut self, node: &'ast crate::api::core::v1::EphemeralVolumeSource) {
+        visit_api_core_v1_ephemeral_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Event`](crate::api::core::v1::Event).
+    fn visit_api_core_v1_event(&mut self, node: &'ast crate::api::core::v1::Event) {
+        visit_api_core_v1_event(self, node);
+    }
+
+    /// Visits a value of type [`EventSeries`](crate::api::core::v1::EventSeries).
+    fn visit_api_core_v1_event_series(&mut self, node: &'ast crate::api::core::v1::EventSeries) {
+        visit_api_core_v1_event_series(self, node);
+    }
+
+    /// Visits a value of type [`EventSource`](crate::api::core::v1::EventSource).
+    fn visit_api_core_v1_event_source(&mut self, node: &'ast crate::api::core::v1::EventSource) {
+        visit_api_core_v1_event_source(self, node);
+    }
+
+    /// Visits a value of type [`ExecAction`](crate::api::core::v1::ExecAction).
+    fn visit_api_core_v1_exec_action(&mut self, node: &'ast crate::api::core::v1::ExecAction) {
+        visit_api_core_v1_exec_action(self, node);
+    }
+
+    /// Visits a value of type [`FCVolumeSource`](crate::api::core::v1::FCVolumeSource).
+    fn visit_api_core_v1_fc_volume_source(&mut self, node: &'ast crate::api::core::v1::FCVolumeSource) {
+        visit_api_core_v1_fc_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`FlexPersistentVolumeSource`](crate::api::core::v1::FlexPersistentVolumeSource).
+    fn visit_api_core_v1_flex_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::FlexPersistentVolumeSource) {
+        visit_api_core_v1_flex_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`FlexVolumeSource`](crate::api::core::v1::FlexVolumeSource).
+    fn visit_api_core_v1_flex_volume_source(&mut self, node: &'ast crate::api::core::v1::FlexVolumeSource) {
+        visit_api_core_v1_flex_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`FlockerVolumeSource`](crate::api::core::v1::FlockerVolumeSource).
+    fn visit_api_core_v1_flocker_volume_source(&mut self, node: &'ast crate::api::core::v1::FlockerVolumeSource) {
+        visit_api_core_v1_flocker_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`GCEPersistentDiskVolumeSource`](crate::api::core::v1::GCEPersistentDiskVolumeSource).
+    fn visit_api_core_v1_gce_persistent_disk_volume_source(&mut self, node: &'ast crate::api::core::v1::GCEPersistentDiskVolumeSource) {
+        visit_api_core_v1_gce_persistent_disk_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`GRPCAction`](crate::api::core::v1::GRPCAction).
+    fn visit_api_core_v1_grpc_action(&mut self, node: &'ast crate::api::core::v1::GRPCAction) {
+        visit_api_core_v1_grpc_action(self, node);
+    }
+
+    /// Visits a value of type [`GitRepoVolumeSource`](crate::api::core::v1::GitRepoVolumeSource).
+    fn visit_api_core_v1_git_repo_volume_source(&mut self, node: &'ast crate::api::core::v1::GitRepoVolumeSource) {
+        visit_api_core_v1_git_repo_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`GlusterfsPersistentVolumeSource`](crate::api::core::v1::GlusterfsPersistentVolumeSource).
+    fn visit_api_core_v1_glusterfs_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::GlusterfsPersistentVolumeSource) {
+        visit_api_core_v1_glusterfs_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`GlusterfsVolumeSource`](crate::api::core::v1::GlusterfsVolumeSource).
+    fn visit_api_core_v1_glusterfs_volume_source(&mut self, node: &'ast crate::api::core::v1::GlusterfsVolumeSource) {
+        visit_api_core_v1_glusterfs_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`HTTPGetAction`](crate::api::core::v1::HTTPGetAction).
+    fn visit_api_core_v1_http_get_action(&mut self, node: &'ast crate::api::core::v1::HTTPGetAction) {
+        visit_api_core_v1_http_get_action(self, node);
+    }
+
+    /// Visits a value of type [`HTTPHeader`](crate::api::core::v1::HTTPHeader).
+    fn visit_api_core_v1_http_header(&mut self, node: &'ast crate::api::core::v1::HTTPHeader) {
+        visit_api_core_v1_http_header(self, node);
+    }
+
+    /// Visits a value of type [`HostAlias`](crate::api::core::v1::HostAlias).
+    fn visit_api_core_v1_host_alias(&mut self, node: &'ast crate::api::core::v1::HostAlias) {
+        visit_api_core_v1_host_alias(self, node);
+    }
+
+    /// Visits a value of type [`HostIP`](crate::api::core::v1::HostIP).
+    fn visit_api_core_v1_host_ip(&mut self, node: &'ast crate::api::core::v1::HostIP) {
+        visit_api_core_v1_host_ip(self, node);
+    }
+
+    /// Visits a value of type [`HostPathVolumeSource`](crate::api::core::v1::HostPathVolumeSource).
+    fn visit_api_core_v1_host_path_volume_source(&mut self, node: &'ast crate::api::core::v1::HostPathVolumeSource) {
+        visit_api_core_v1_host_path_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ISCSIPersistentVolumeSource`](crate::api::core::v1::ISCSIPersistentVolumeSource).
+    fn visit_api_core_v1_iscsi_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::ISCSIPersistentVolumeSource) {
+        visit_api_core_v1_iscsi_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ISCSIVolumeSource`](crate::api::core::v1::ISCSIVolumeSource).
+    fn visit_api_core_v1_iscsi_volume_source(&mut self, node: &'ast crate::api::core::v1::ISCSIVolumeSource) {
+        visit_api_core_v1_iscsi_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ImageVolumeSource`](crate::api::core::v1::ImageVolumeSource).
+    fn visit_api_core_v1_image_volume_source(&mut self, node: &'ast crate::api::core::v1::ImageVolumeSource) {
+        visit_api_core_v1_image_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`KeyToPath`](crate::api::core::v1::KeyToPath).
+    fn visit_api_core_v1_key_to_path(&mut self, node: &'ast crate::api::core::v1::KeyToPath) {
+        visit_api_core_v1_key_to_path(self, node);
+    }
+
+    /// Visits a value of type [`Lifecycle`](crate::api::core::v1::Lifecycle).
+    fn visit_api_core_v1_lifecycle(&mut self, node: &'ast crate::api::core::v1::Lifecycle) {
+        visit_api_core_v1_lifecycle(self, node);
+    }
+
+    /// Visits a value of type [`LifecycleHandler`](crate::api::core::v1::LifecycleHandler).
+    fn visit_api_core_v1_lifecycle_handler(&mut self, node: &'ast crate::api::core::v1::LifecycleHandler) {
+        visit_api_core_v1_lifecycle_handler(self, node);
+    }
+
+    /// Visits a value of type [`LimitRange`](crate::api::core::v1::LimitRange).
+    fn visit_api_core_v1_limit_range(&mut self, node: &'ast crate::api::core::v1::LimitRange) {
+        visit_api_core_v1_limit_range(self, node);
+    }
+
+    /// Visits a value of type [`LimitRangeItem`](crate::api::core::v1::LimitRangeItem).
+    fn visit_api_core_v1_limit_range_item(&mut self, node: &'ast crate::api::core::v1::LimitRangeItem) {
+        visit_api_core_v1_limit_range_item(self, node);
+    }
+
+    /// Visits a value of type [`LimitRangeSpec`](crate::api::core::v1::LimitRangeSpec).
+    fn visit_api_core_v1_limit_range_spec(&mut self, node: &'ast crate::api::core::v1::LimitRangeSpec) {
+        visit_api_core_v1_limit_range_spec(self, node);
+    }
+
+    /// Visits a value of type [`LinuxContainerUser`](crate::api::core::v1::LinuxContainerUser).
+    fn visit_api_core_v1_linux_container_user(&mut self, node: &'ast crate::api::core::v1::LinuxContainerUser) {
+        visit_api_core_v1_linux_container_user(self, node);
+    }
+
+    /// Visits a value of type [`LoadBalancerIngress`](crate::api::core::v1::LoadBalancerIngress).
+    fn visit_api_core_v1_load_balancer_ingress(&mut self, node: &'ast crate::api::core::v1::LoadBalancerIngress) {
+        visit_api_core_v1_load_balancer_ingress(self, node);
+    }
+
+    /// Visits a value of type [`LoadBalancerStatus`](crate::api::core::v1::LoadBalancerStatus).
+    fn visit_api_core_v1_load_balancer_status(&mut self, node: &'ast crate::api::core::v1::LoadBalancerStatus) {
+        visit_api_core_v1_load_balancer_status(self, node);
+    }
+
+    /// Visits a value of type [`LocalObjectReference`](crate::api::core::v1::LocalObjectReference).
+    fn visit_api_core_v1_local_object_reference(&mut self, node: &'ast crate::api::core::v1::LocalObjectReference) {
+        visit_api_core_v1_local_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`LocalVolumeSource`](crate::api::core::v1::LocalVolumeSource).
+    fn visit_api_core_v1_local_volume_source(&mut self, node: &'ast crate::api::core::v1::LocalVolumeSource) {
+        visit_api_core_v1_local_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ModifyVolumeStatus`](crate::api::core::v1::ModifyVolumeStatus).
+    fn visit_api_core_v1_modify_volume_status(&mut self, node: &'ast crate::api::core::v1::ModifyVolumeStatus) {
+        visit_api_core_v1_modify_volume_status(self, node);
+    }
+
+    /// Visits a value of type [`NFSVolumeSource`](crate::api::core::v1::NFSVolumeSource).
+    fn visit_api_core_v1_nfs_volume_source(&mut self, node: &'ast crate::api::core::v1::NFSVolumeSource) {
+        visit_api_core_v1_nfs_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Namespace`](crate::api::core::v1::Namespace).
+    fn visit_api_core_v1_namespace(&mut self, node: &'ast crate::api::core::v1::Namespace) {
+        visit_api_core_v1_namespace(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceCondition`](crate::api::core::v1::NamespaceCondition).
+    fn visit_api_core_v1_namespace_condition(&mut self, node: &'ast crate::api::core::v1::NamespaceCondition) {
+        visit_api_core_v1_namespace_condition(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceSpec`](crate::api::core::v1::NamespaceSpec).
+    fn visit_api_core_v1_namespace_spec(&mut self, node: &'ast crate::api::core::v1::NamespaceSpec) {
+        visit_api_core_v1_namespace_spec(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceStatus`](crate::api::core::v1::NamespaceStatus).
+    fn visit_api_core_v1_namespace_status(&mut self, node: &'ast crate::api::core::v1::NamespaceStatus) {
+        visit_api_core_v1_namespace_status(self, node);
+    }
+
+    /// Visits a value of type [`Node`](crate::api::core::v1::Node).
+    fn visit_api_core_v1_node(&mut self, node: &'ast crate::api::core::v1::Node) {
+        visit_api_core_v1_node(self, node);
+    }
+
+    /// Visits a value of type [`NodeAddress`](crate::api::core::v1::NodeAddress).
+    fn visit_api_core_v1_node_address(&mut self, node: &'ast crate::api::core::v1::NodeAddress) {
+        visit_api_core_v1_node_address(self, node);
+    }
+
+    /// Visits a value of type [`NodeAffinity`](crate::api::core::v1::NodeAffinity).
+    fn visit_api_core_v1_node_affinity(&mut self, node: &'ast crate::api::core::v1::NodeAffinity) {
+        visit_api_core_v1_node_affinity(self, node);
+    }
+
+    /// Visits a value of type [`NodeCondition`](crate::api::core::v1::NodeCondition).
+    fn visit_api_core_v1_node_condition(&mut self, node: &'ast crate::api::core::v1::NodeCondition) {
+        visit_api_core_v1_node_condition(self, node);
+    }
+
+    /// Visits a value of type [`NodeConfigSource`](crate::api::core::v1::NodeConfigSource).
+    fn visit_api_core_v1_node_config_source(&mut self, node: &'ast crate::api::core::v1::NodeConfigSource) {
+        visit_api_core_v1_node_config_source(self, node);
+    }
+
+    /// Visits a value of type [`NodeConfigStatus`](crate::api::core::v1::NodeConfigStatus).
+    fn visit_api_core_v1_node_config_status(&mut self, node: &'ast crate::api::core::v1::NodeConfigStatus) {
+        visit_api_core_v1_node_config_status(self, node);
+    }
+
+    /// Visits a value of type [`NodeDaemonEndpoints`](crate::api::core::v1::NodeDaemonEndpoints).
+    fn visit_api_core_v1_node_daemon_endpoints(&mut self, node: &'ast crate::api::core::v1::NodeDaemonEndpoints) {
+        visit_api_core_v1_node_daemon_endpoints(self, node);
+    }
+
+    /// Visits a value of type [`NodeFeatures`](crate::api::core::v1::NodeFeatures).
+    fn visit_api_core_v1_node_features(&mut self, node: &'ast crate::api::core::v1::NodeFeatures) {
+        visit_api_core_v1_node_features(self, node);
+    }
+
+    /// Visits a value of type [`NodeRuntimeHandler`](crate::api::core::v1::NodeRuntimeHandler).
+    fn visit_api_core_v1_node_runtime_handler(&mut self, node: &'ast crate::api::core::v1::NodeRuntimeHandler) {
+        visit_api_core_v1_node_runtime_handler(self, node);
+    }
+
+    /// Visits a value of type [`NodeRuntimeHandlerFeatures`](crate::api::core::v1::NodeRuntimeHandlerFeatures).
+    fn visit_api_core_v1_node_runtime_handler_features(&mut self, node: &'ast crate::api::core::v1::NodeRuntimeHandlerFeatures) {
+        visit_api_core_v1_node_runtime_handler_features(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelector`](crate::api::core::v1::NodeSelector).
+    fn visit_api_core_v1_node_selector(&mut self, node: &'ast crate::api::core::v1::NodeSelector) {
+        visit_api_core_v1_node_selector(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelectorRequirement`](crate::api::core::v1::NodeSelectorRequirement).
+    fn visit_api_core_v1_node_selector_requirement(&mut self, node: &'ast crate::api::core::v1::NodeSelectorRequirement) {
+        visit_api_core_v1_node_selector_requirement(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelectorTerm`](crate::api::core::v1::NodeSelectorTerm).
+    fn visit_api_core_v1_node_selector_term(&mut self, node: &'ast crate::api::core::v1::NodeSelectorTerm) {
+        visit_api_core_v1_node_selector_term(self, node);
+    }
+
+    /// Visits a value of type [`NodeSpec`](crate::api::core::v1::NodeSpec).
+    fn visit_api_core_v1_node_spec(&mut self, node: &'ast crate::api::core::v1::NodeSpec) {
+        visit_api_core_v1_node_spec(self, node);
+    }
+
+    /// Visits a value of type [`NodeStatus`](crate::api::core::v1::NodeStatus).
+    fn visit_api_core_v1_node_status(&mut self, node: &'ast crate::api::core::v1::NodeStatus) {
+        visit_api_core_v1_node_status(self, node);
+    }
+
+    /// Visits a value of type [`NodeSystemInfo`](crate::api::core::v1::NodeSystemInfo).
+    fn visit_api_core_v1_node_system_info(&mut self, node: &'ast crate::api::core::v1::NodeSystemInfo) {
+        visit_api_core_v1_node_system_info(self, node);
+    }
+
+    /// Visits a value of type [`ObjectFieldSelector`](crate::api::core::v1::ObjectFieldSelector).
+    fn visit_api_core_v1_object_field_selector(&mut self, node: &'ast crate::api::core::v1::ObjectFieldSelector) {
+        visit_api_core_v1_object_field_selector(self, node);
+    }
+
+    /// Visits a value of type [`ObjectReference`](crate::api::core::v1::ObjectReference).
+    fn visit_api_core_v1_object_reference(&mut self, node: &'ast crate::api::core::v1::ObjectReference) {
+        visit_api_core_v1_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolume`](crate::api::core::v1::PersistentVolume).
+    fn visit_api_core_v1_persistent_volume(&mut self, node: &'ast crate::api::core::v1::PersistentVolume) {
+        visit_api_core_v1_persistent_volume(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaim`](crate::api::core::v1::PersistentVolumeClaim).
+    fn visit_api_core_v1_persistent_volume_claim(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaim) {
+        visit_api_core_v1_persistent_volume_claim(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimCondition`](crate::api::core::v1::PersistentVolumeClaimCondition).
+    fn visit_api_core_v1_persistent_volume_claim_condition(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaimCondition) {
+        visit_api_core_v1_persistent_volume_claim_condition(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimSpec`](crate::api::core::v1::PersistentVolumeClaimSpec).
+    fn visit_api_core_v1_persistent_volume_claim_spec(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaimSpec) {
+        visit_api_core_v1_persistent_volume_claim_spec(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimStatus`](crate::api::core::v1::PersistentVolumeClaimStatus).
+    fn visit_api_core_v1_persistent_volume_claim_status(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaimStatus) {
+        visit_api_core_v1_persistent_volume_claim_status(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimTemplate`](crate::api::core::v1::PersistentVolumeClaimTemplate).
+    fn visit_api_core_v1_persistent_volume_claim_template(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaimTemplate) {
+        visit_api_core_v1_persistent_volume_claim_template(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimVolumeSource`](crate::api::core::v1::PersistentVolumeClaimVolumeSource).
+    fn visit_api_core_v1_persistent_volume_claim_volume_source(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeClaimVolumeSource) {
+        visit_api_core_v1_persistent_volume_claim_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeSpec`](crate::api::core::v1::PersistentVolumeSpec).
+    fn visit_api_core_v1_persistent_volume_spec(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeSpec) {
+        visit_api_core_v1_persistent_volume_spec(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeStatus`](crate::api::core::v1::PersistentVolumeStatus).
+    fn visit_api_core_v1_persistent_volume_status(&mut self, node: &'ast crate::api::core::v1::PersistentVolumeStatus) {
+        visit_api_core_v1_persistent_volume_status(self, node);
+    }
+
+    /// Visits a value of type [`PhotonPersistentDiskVolumeSource`](crate::api::core::v1::PhotonPersistentDiskVolumeSource).
+    fn visit_api_core_v1_photon_persistent_disk_volume_source(&mut self, node: &'ast crate::api::core::v1::PhotonPersistentDiskVolumeSource) {
+        visit_api_core_v1_photon_persistent_disk_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Pod`](crate::api::core::v1::Pod).
+    fn visit_api_core_v1_pod(&mut self, node: &'ast crate::api::core::v1::Pod) {
+        visit_api_core_v1_pod(self, node);
+    }
+
+    /// Visits a value of type [`PodAffinity`](crate::api::core::v1::PodAffinity).
+    fn visit_api_core_v1_pod_affinity(&mut self, node: &'ast crate::api::core::v1::PodAffinity) {
+        visit_api_core_v1_pod_affinity(self, node);
+    }
+
+    /// Visits a value of type [`PodAffinityTerm`](crate::api::core::v1::PodAffinityTerm).
+    fn visit_api_core_v1_pod_affinity_term(&mut self, node: &'ast crate::api::core::v1::PodAffinityTerm) {
+        visit_api_core_v1_pod_affinity_term(self, node);
+    }
+
+    /// Visits a value of type [`PodAntiAffinity`](crate::api::core::v1::PodAntiAffinity).
+    fn visit_api_core_v1_pod_anti_affinity(&mut self, node: &'ast crate::api::core::v1::PodAntiAffinity) {
+        visit_api_core_v1_pod_anti_affinity(self, node);
+    }
+
+    /// Visits a value of type [`PodCondition`](crate::api::core::v1::PodCondition).
+    fn visit_api_core_v1_pod_condition(&mut self, node: &'ast crate::api::core::v1::PodCondition) {
+        visit_api_core_v1_pod_condition(self, node);
+    }
+
+    /// Visits a value of type [`PodDNSConfig`](crate::api::core::v1::PodDNSConfig).
+    fn visit_api_core_v1_pod_dns_config(&mut self, node: &'ast crate::api::core::v1::PodDNSConfig) {
+        visit_api_core_v1_pod_dns_config(self, node);
+    }
+
+    /// Visits a value of type [`PodDNSConfigOption`](crate::api::core::v1::PodDNSConfigOption).
+    fn visit_api_core_v1_pod_dns_config_option(&mut self, node: &'ast crate::api::core::v1::PodDNSConfigOption) {
+        visit_api_core_v1_pod_dns_config_option(self, node);
+    }
+
+    /// Visits a value of type [`PodIP`](crate::api::core::v1::PodIP).
+    fn visit_api_core_v1_pod_ip(&mut self, node: &'ast crate::api::core::v1::PodIP) {
+        visit_api_core_v1_pod_ip(self, node);
+    }
+
+    /// Visits a value of type [`PodOS`](crate::api::core::v1::PodOS).
+    fn visit_api_core_v1_pod_os(&mut self, node: &'ast crate::api::core::v1::PodOS) {
+        visit_api_core_v1_pod_os(self, node);
+    }
+
+    /// Visits a value of type [`PodReadinessGate`](crate::api::core::v1::PodReadinessGate).
+    fn visit_api_core_v1_pod_readiness_gate(&mut self, node: &'ast crate::api::core::v1::PodReadinessGate) {
+        visit_api_core_v1_pod_readiness_gate(self, node);
+    }
+
+    /// Visits a value of type [`PodResourceClaim`](crate::api::core::v1::PodResourceClaim).
+    fn visit_api_core_v1_pod_resource_claim(&mut self, node: &'ast crate::api::core::v1::PodResourceClaim) {
+        visit_api_core_v1_pod_resource_claim(self, node);
+    }
+
+    /// Visits a value of type [`PodResourceClaimStatus`](crate::api::core::v1::PodResourceClaimStatus).
+    fn visit_api_core_v1_pod_resource_claim_status(&mut self, node: &'ast crate::api::core::v1::PodResourceClaimStatus) {
+        visit_api_core_v1_pod_resource_claim_status(self, node);
+    }
+
+    /// Visits a value of type [`PodSchedulingGate`](crate::api::core::v1::PodSchedulingGate).
+    fn visit_api_core_v1_pod_scheduling_gate(&mut self, node: &'ast crate::api::core::v1::PodSchedulingGate) {
+        visit_api_core_v1_pod_scheduling_gate(self, node);
+    }
+
+    /// Visits a value of type [`PodSecurityContext`](crate::api::core::v1::PodSecurityContext).
+    fn visit_api_core_v1_pod_security_context(&mut self, node: &'ast crate::api::core::v1::PodSecurityContext) {
+        visit_api_core_v1_pod_security_context(self, node);
+    }
+
+    /// Visits a value of type [`PodSpec`](crate::api::core::v1::PodSpec).
+    fn visit_api_core_v1_pod_spec(&mut self, node: &'ast crate::api::core::v1::PodSpec) {
+        visit_api_core_v1_pod_spec(self, node);
+    }
+
+    /// Visits a value of type [`PodStatus`](crate::api::core::v1::PodStatus).
+    fn visit_api_core_v1_pod_status(&mut self, node: &'ast crate::api::core::v1::PodStatus) {
+        visit_api_core_v1_pod_status(self, node);
+    }
+
+    /// Visits a value of type [`PodTemplate`](crate::api::core::v1::PodTemplate).
+    fn visit_api_core_v1_pod_template(&mut self, node: &'ast crate::api::core::v1::PodTemplate) {
+        visit_api_core_v1_pod_template(self, node);
+    }
+
+    /// Visits a value of type [`PodTemplateSpec`](crate::api::core::v1::PodTemplateSpec).
+    fn visit_api_core_v1_pod_template_spec(&mut self, node: &'ast crate::api::core::v1::PodTemplateSpec) {
+        visit_api_core_v1_pod_template_spec(self, node);
+    }
+
+    /// Visits a value of type [`PortStatus`](crate::api::core::v1::PortStatus).
+    fn visit_api_core_v1_port_status(&mut self, node: &'ast crate::api::core::v1::PortStatus) {
+        visit_api_core_v1_port_status(self, node);
+    }
+
+    /// Visits a value of type [`PortworxVolumeSource`](crate::api::core::v1::PortworxVolumeSource).
+    fn visit_api_core_v1_portworx_volume_source(&mut self, node: &'ast crate::api::core::v1::PortworxVolumeSource) {
+        visit_api_core_v1_portworx_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`PreferredSchedulingTerm`](crate::api::core::v1::PreferredSchedulingTerm).
+    fn visit_api_core_v1_preferred_scheduling_term(&mut self, node: &'ast crate::api::core::v1::PreferredSchedulingTerm) {
+        visit_api_core_v1_preferred_scheduling_term(self, node);
+    }
+
+    /// Visits a value of type [`Probe`](crate::api::core::v1::Probe).
+    fn visit_api_core_v1_probe(&mut self, node: &'ast crate::api::core::v1::Probe) {
+        visit_api_core_v1_probe(self, node);
+    }
+
+    /// Visits a value of type [`ProjectedVolumeSource`](crate::api::core::v1::ProjectedVolumeSource).
+    fn visit_api_core_v1_projected_volume_source(&mut self, node: &'ast crate::api::core::v1::ProjectedVolumeSource) {
+        visit_api_core_v1_projected_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`QuobyteVolumeSource`](crate::api::core::v1::QuobyteVolumeSource).
+    fn visit_api_core_v1_quobyte_volume_source(&mut self, node: &'ast crate::api::core::v1::QuobyteVolumeSource) {
+        visit_api_core_v1_quobyte_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`RBDPersistentVolumeSource`](crate::api::core::v1::RBDPersistentVolumeSource).
+    fn visit_api_core_v1_rbd_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::RBDPersistentVolumeSource) {
+        visit_api_core_v1_rbd_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`RBDVolumeSource`](crate::api::core::v1::RBDVolumeSource).
+    fn visit_api_core_v1_rbd_volume_source(&mut self, node: &'ast crate::api::core::v1::RBDVolumeSource) {
+        visit_api_core_v1_rbd_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationController`](crate::api::core::v1::ReplicationController).
+    fn visit_api_core_v1_replication_controller(&mut self, node: &'ast crate::api::core::v1::ReplicationController) {
+        visit_api_core_v1_replication_controller(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerCondition`](crate::api::core::v1::ReplicationControllerCondition).
+    fn visit_api_core_v1_replication_controller_condition(&mut self, node: &'ast crate::api::core::v1::ReplicationControllerCondition) {
+        visit_api_core_v1_replication_controller_condition(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerSpec`](crate::api::core::v1::ReplicationControllerSpec).
+    fn visit_api_core_v1_replication_controller_spec(&mut self, node: &'ast crate::api::core::v1::ReplicationControllerSpec) {
+        visit_api_core_v1_replication_controller_spec(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerStatus`](crate::api::core::v1::ReplicationControllerStatus).
+    fn visit_api_core_v1_replication_controller_status(&mut self, node: &'ast crate::api::core::v1::ReplicationControllerStatus) {
+        visit_api_core_v1_replication_controller_status(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::core::v1::ResourceClaim).
+    fn visit_api_core_v1_resource_claim(&mut self, node: &'ast crate::api::core::v1::ResourceClaim) {
+        visit_api_core_v1_resource_claim(self, node);
+    }
+
+    /// Visits a value of type [`ResourceFieldSelector`](crate::api::core::v1::ResourceFieldSelector).
+    fn visit_api_core_v1_resource_field_selector(&mut self, node: &'ast crate::api::core::v1::ResourceFieldSelector) {
+        visit_api_core_v1_resource_field_selector(self, node);
+    }
+
+    /// Visits a value of type [`ResourceHealth`](crate::api::core::v1::ResourceHealth).
+    fn visit_api_core_v1_resource_health(&mut self, node: &'ast crate::api::core::v1::ResourceHealth) {
+        visit_api_core_v1_resource_health(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuota`](crate::api::core::v1::ResourceQuota).
+    fn visit_api_core_v1_resource_quota(&mut self, node: &'ast crate::api::core::v1::ResourceQuota) {
+        visit_api_core_v1_resource_quota(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuotaSpec`](crate::api::core::v1::ResourceQuotaSpec).
+    fn visit_api_core_v1_resource_quota_spec(&mut self, node: &'ast crate::api::core::v1::ResourceQuotaSpec) {
+        visit_api_core_v1_resource_quota_spec(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuotaStatus`](crate::api::core::v1::ResourceQuotaStatus).
+    fn visit_api_core_v1_resource_quota_status(&mut self, node: &'ast crate::api::core::v1::ResourceQuotaStatus) {
+        visit_api_core_v1_resource_quota_status(self, node);
+    }
+
+    /// Visits a value of type [`ResourceRequirements`](crate::api::core::v1::ResourceRequirements).
+    fn visit_api_core_v1_resource_requirements(&mut self, node: &'ast crate::api::core::v1::ResourceRequirements) {
+        visit_api_core_v1_resource_requirements(self, node);
+    }
+
+    /// Visits a value of type [`ResourceStatus`](crate::api::core::v1::ResourceStatus).
+    fn visit_api_core_v1_resource_status(&mut self, node: &'ast crate::api::core::v1::ResourceStatus) {
+        visit_api_core_v1_resource_status(self, node);
+    }
+
+    /// Visits a value of type [`SELinuxOptions`](crate::api::core::v1::SELinuxOptions).
+    fn visit_api_core_v1_se_linux_options(&mut self, node: &'ast crate::api::core::v1::SELinuxOptions) {
+        visit_api_core_v1_se_linux_options(self, node);
+    }
+
+    /// Visits a value of type [`ScaleIOPersistentVolumeSource`](crate::api::core::v1::ScaleIOPersistentVolumeSource).
+    fn visit_api_core_v1_scale_io_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::ScaleIOPersistentVolumeSource) {
+        visit_api_core_v1_scale_io_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ScaleIOVolumeSource`](crate::api::core::v1::ScaleIOVolumeSource).
+    fn visit_api_core_v1_scale_io_volume_source(&mut self, node: &'ast crate::api::core::v1::ScaleIOVolumeSource) {
+        visit_api_core_v1_scale_io_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`ScopeSelector`](crate::api::core::v1::ScopeSelector).
+    fn visit_api_core_v1_scope_selector(&mut self, node: &'ast crate::api::core::v1::ScopeSelector) {
+        visit_api_core_v1_scope_selector(self, node);
+    }
+
+    /// Visits a value of type [`ScopedResourceSelectorRequirement`](crate::api::core::v1::ScopedResourceSelectorRequirement).
+    fn visit_api_core_v1_scoped_resource_selector_requirement(&mut self, node: &'ast crate::api::core::v1::ScopedResourceSelectorRequirement) {
+        visit_api_core_v1_scoped_resource_selector_requirement(self, node);
+    }
+
+    /// Visits a value of type [`SeccompProfile`](crate::api::core::v1::SeccompProfile).
+    fn visit_api_core_v1_seccomp_profile(&mut self, node: &'ast crate::api::core::v1::SeccompProfile) {
+        visit_api_core_v1_seccomp_profile(self, node);
+    }
+
+    /// Visits a value of type [`Secret`](crate::api::core::v1::Secret).
+    fn visit_api_core_v1_secret(&mut self, node: &'ast crate::api::core::v1::Secret) {
+        visit_api_core_v1_secret(self, node);
+    }
+
+    /// Visits a value of type [`SecretEnvSource`](crate::api::core::v1::SecretEnvSource).
+    fn visit_api_core_v1_secret_env_source(&mut self, node: &'ast crate::api::core::v1::SecretEnvSource) {
+        visit_api_core_v1_secret_env_source(self, node);
+    }
+
+    /// Visits a value of type [`SecretKeySelector`](crate::api::core::v1::SecretKeySelector).
+    fn visit_api_core_v1_secret_key_selector(&mut self, node: &'ast crate::api::core::v1::SecretKeySelector) {
+        visit_api_core_v1_secret_key_selector(self, node);
+    }
+
+    /// Visits a value of type [`SecretProjection`](crate::api::core::v1::SecretProjection).
+    fn visit_api_core_v1_secret_projection(&mut self, node: &'ast crate::api::core::v1::SecretProjection) {
+        visit_api_core_v1_secret_projection(self, node);
+    }
+
+    /// Visits a value of type [`SecretReference`](crate::api::core::v1::SecretReference).
+    fn visit_api_core_v1_secret_reference(&mut self, node: &'ast crate::api::core::v1::SecretReference) {
+        visit_api_core_v1_secret_reference(self, node);
+    }
+
+    /// Visits a value of type [`SecretVolumeSource`](crate::api::core::v1::SecretVolumeSource).
+    fn visit_api_core_v1_secret_volume_source(&mut self, node: &'ast crate::api::core::v1::SecretVolumeSource) {
+        visit_api_core_v1_secret_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`SecurityContext`](crate::api::core::v1::SecurityContext).
+    fn visit_api_core_v1_security_context(&mut self, node: &'ast crate::api::core::v1::SecurityContext) {
+        visit_api_core_v1_security_context(self, node);
+    }
+
+    /// Visits a value of type [`Service`](crate::api::core::v1::Service).
+    fn visit_api_core_v1_service(&mut self, node: &'ast crate::api::core::v1::Service) {
+        visit_api_core_v1_service(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccount`](crate::api::core::v1::ServiceAccount).
+    fn visit_api_core_v1_service_account(&mut self, node: &'ast crate::api::core::v1::ServiceAccount) {
+        visit_api_core_v1_service_account(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccountTokenProjection`](crate::api::core::v1::ServiceAccountTokenProjection).
+    fn visit_api_core_v1_service_account_token_projection(&mut self, node: &'ast crate::api::core::v1::ServiceAccountTokenProjection) {
+        visit_api_core_v1_service_account_token_projection(self, node);
+    }
+
+    /// Visits a value of type [`ServicePort`](crate::api::core::v1::ServicePort).
+    fn visit_api_core_v1_service_port(&mut self, node: &'ast crate::api::core::v1::ServicePort) {
+        visit_api_core_v1_service_port(self, node);
+    }
+
+    /// Visits a value of type [`ServiceSpec`](crate::api::core::v1::ServiceSpec).
+    fn visit_api_core_v1_service_spec(&mut self, node: &'ast crate::api::core::v1::ServiceSpec) {
+        visit_api_core_v1_service_spec(self, node);
+    }
+
+    /// Visits a value of type [`ServiceStatus`](crate::api::core::v1::ServiceStatus).
+    fn visit_api_core_v1_service_status(&mut self, node: &'ast crate::api::core::v1::ServiceStatus) {
+        visit_api_core_v1_service_status(self, node);
+    }
+
+    /// Visits a value of type [`SessionAffinityConfig`](crate::api::core::v1::SessionAffinityConfig).
+    fn visit_api_core_v1_session_affinity_config(&mut self, node: &'ast crate::api::core::v1::SessionAffinityConfig) {
+        visit_api_core_v1_session_affinity_config(self, node);
+    }
+
+    /// Visits a value of type [`SleepAction`](crate::api::core::v1::SleepAction).
+    fn visit_api_core_v1_sleep_action(&mut self, node: &'ast crate::api::core::v1::SleepAction) {
+        visit_api_core_v1_sleep_action(self, node);
+    }
+
+    /// Visits a value of type [`StorageOSPersistentVolumeSource`](crate::api::core::v1::StorageOSPersistentVolumeSource).
+    fn visit_api_core_v1_storage_os_persistent_volume_source(&mut self, node: &'ast crate::api::core::v1::StorageOSPersistentVolumeSource) {
+        visit_api_core_v1_storage_os_persistent_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`StorageOSVolumeSource`](crate::api::core::v1::StorageOSVolumeSource).
+    fn visit_api_core_v1_storage_os_volume_source(&mut self, node: &'ast crate::api::core::v1::StorageOSVolumeSource) {
+        visit_api_core_v1_storage_os_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`Sysctl`](crate::api::core::v1::Sysctl).
+    fn visit_api_core_v1_sysctl(&mut self, node: &'ast crate::api::core::v1::Sysctl) {
+        visit_api_core_v1_sysctl(self, node);
+    }
+
+    /// Visits a value of type [`TCPSocketAction`](crate::api::core::v1::TCPSocketAction).
+    fn visit_api_core_v1_tcp_socket_action(&mut self, node: &'ast crate::api::core::v1::TCPSocketAction) {
+        visit_api_core_v1_tcp_socket_action(self, node);
+    }
+
+    /// Visits a value of type [`Taint`](crate::api::core::v1::Taint).
+    fn visit_api_core_v1_taint(&mut self, node: &'ast crate::api::core::v1::Taint) {
+        visit_api_core_v1_taint(self, node);
+    }
+
+    /// Visits a value of type [`Toleration`](crate::api::core::v1::Toleration).
+    fn visit_api_core_v1_toleration(&mut self, node: &'ast crate::api::core::v1::Toleration) {
+        visit_api_core_v1_toleration(self, node);
+    }
+
+    /// Visits a value of type [`TopologySelectorLabelRequirement`](crate::api::core::v1::TopologySelectorLabelRequirement).
+    fn visit_api_core_v1_topology_selector_label_requirement(&mut self, node: &'ast crate::api::core::v1::TopologySelectorLabelRequirement) {
+        visit_api_core_v1_topology_selector_label_requirement(self, node);
+    }
+
+    /// Visits a value of type [`TopologySelectorTerm`](crate::api::core::v1::TopologySelectorTerm).
+    fn visit_api_core_v1_topology_selector_term(&mut self, node: &'ast crate::api::core::v1::TopologySelectorTerm) {
+        visit_api_core_v1_topology_selector_term(self, node);
+    }
+
+    /// Visits a value of type [`TopologySpreadConstraint`](crate::api::core::v1::TopologySpreadConstraint).
+    fn visit_api_core_v1_topology_spread_constraint(&mut self, node: &'ast crate::api::core::v1::TopologySpreadConstraint) {
+        visit_api_core_v1_topology_spread_constraint(self, node);
+    }
+
+    /// Visits a value of type [`TypedLocalObjectReference`](crate::api::core::v1::TypedLocalObjectReference).
+    fn visit_api_core_v1_typed_local_object_reference(&mut self, node: &'ast crate::api::core::v1::TypedLocalObjectReference) {
+        visit_api_core_v1_typed_local_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`TypedObjectReference`](crate::api::core::v1::TypedObjectReference).
+    fn visit_api_core_v1_typed_object_reference(&mut self, node: &'ast crate::api::core::v1::TypedObjectReference) {
+        visit_api_core_v1_typed_object_reference(self, node);
+    }
+
+    /// Visits a value of type [`Volume`](crate::api::core::v1::Volume).
+    fn visit_api_core_v1_volume(&mut self, node: &'ast crate::api::core::v1::Volume) {
+        visit_api_core_v1_volume(self, node);
+    }
+
+    /// Visits a value of type [`VolumeDevice`](crate::api::core::v1::VolumeDevice).
+    fn visit_api_core_v1_volume_device(&mut self, node: &'ast crate::api::core::v1::VolumeDevice) {
+        visit_api_core_v1_volume_device(self, node);
+    }
+
+    /// Visits a value of type [`VolumeMount`](crate::api::core::v1::VolumeMount).
+    fn visit_api_core_v1_volume_mount(&mut self, node: &'ast crate::api::core::v1::VolumeMount) {
+        visit_api_core_v1_volume_mount(self, node);
+    }
+
+    /// Visits a value of type [`VolumeMountStatus`](crate::api::core::v1::VolumeMountStatus).
+    fn visit_api_core_v1_volume_mount_status(&mut self, node: &'ast crate::api::core::v1::VolumeMountStatus) {
+        visit_api_core_v1_volume_mount_status(self, node);
+    }
+
+    /// Visits a value of type [`VolumeNodeAffinity`](crate::api::core::v1::VolumeNodeAffinity).
+    fn visit_api_core_v1_volume_node_affinity(&mut self, node: &'ast crate::api::core::v1::VolumeNodeAffinity) {
+        visit_api_core_v1_volume_node_affinity(self, node);
+    }
+
+    /// Visits a value of type [`VolumeProjection`](crate::api::core::v1::VolumeProjection).
+    fn visit_api_core_v1_volume_projection(&mut self, node: &'ast crate::api::core::v1::VolumeProjection) {
+        visit_api_core_v1_volume_projection(self, node);
+    }
+
+    /// Visits a value of type [`VolumeResourceRequirements`](crate::api::core::v1::VolumeResourceRequirements).
+    fn visit_api_core_v1_volume_resource_requirements(&mut self, node: &'ast crate::api::core::v1::VolumeResourceRequirements) {
+        visit_api_core_v1_volume_resource_requirements(self, node);
+    }
+
+    /// Visits a value of type [`VsphereVirtualDiskVolumeSource`](crate::api::core::v1::VsphereVirtualDiskVolumeSource).
+    fn visit_api_core_v1_vsphere_virtual_disk_volume_source(&mut self, node: &'ast crate::api::core::v1::VsphereVirtualDiskVolumeSource) {
+        visit_api_core_v1_vsphere_virtual_disk_volume_source(self, node);
+    }
+
+    /// Visits a value of type [`WeightedPodAffinityTerm`](crate::api::core::v1::WeightedPodAffinityTerm).
+    fn visit_api_core_v1_weighted_pod_affinity_term(&mut self, node: &'ast crate::api::core::v1::WeightedPodAffinityTerm) {
+        visit_api_core_v1_weighted_pod_affinity_term(self, node);
+    }
+
+    /// Visits a value of type [`WindowsSecurityContextOptions`](crate::api::core::v1::WindowsSecurityContextOptions).
+    fn visit_api_core_v1_windows_security_context_options(&mut self, node: &'ast crate::api::core::v1::WindowsSecurityContextOptions) {
+        visit_api_core_v1_windows_security_context_options(self, node);
+    }
+
+    /// Visits a value of type [`Endpoint`](crate::api::discovery::v1::Endpoint).
+    fn visit_api_discovery_v1_endpoint(&mut self, node: &'ast crate::api::discovery::v1::Endpoint) {
+        visit_api_discovery_v1_endpoint(self, node);
+    }
+
+    /// Visits a value of type [`EndpointConditions`](crate::api::discovery::v1::EndpointConditions).
+    fn visit_api_discovery_v1_endpoint_conditions(&mut self, node: &'ast crate::api::discovery::v1::EndpointConditions) {
+        visit_api_discovery_v1_endpoint_conditions(self, node);
+    }
+
+    /// Visits a value of type [`EndpointHints`](crate::api::discovery::v1::EndpointHints).
+    fn visit_api_discovery_v1_endpoint_hints(&mut self, node: &'ast crate::api::discovery::v1::EndpointHints) {
+        visit_api_discovery_v1_endpoint_hints(self, node);
+    }
+
+    /// Visits a value of type [`EndpointPort`](crate::api::discovery::v1::EndpointPort).
+    fn visit_api_discovery_v1_endpoint_port(&mut self, node: &'ast crate::api::discovery::v1::EndpointPort) {
+        visit_api_discovery_v1_endpoint_port(self, node);
+    }
+
+    /// Visits a value of type [`EndpointSlice`](crate::api::discovery::v1::EndpointSlice).
+    fn visit_api_discovery_v1_endpoint_slice(&mut self, node: &'ast crate::api::discovery::v1::EndpointSlice) {
+        visit_api_discovery_v1_endpoint_slice(self, node);
+    }
+
+    /// Visits a value of type [`ForZone`](crate::api::discovery::v1::ForZone).
+    fn visit_api_discovery_v1_for_zone(&mut self, node: &'ast crate::api::discovery::v1::ForZone) {
+        visit_api_discovery_v1_for_zone(self, node);
+    }
+
+    /// Visits a value of type [`Event`](crate::api::events::v1::Event).
+    fn visit_api_events_v1_event(&mut self, node: &'ast crate::api::events::v1::Event) {
+        visit_api_events_v1_event(self, node);
+    }
+
+    /// Visits a value of type [`EventSeries`](crate::api::events::v1::EventSeries).
+    fn visit_api_events_v1_event_series(&mut self, node: &'ast crate::api::events::v1::EventSeries) {
+        visit_api_events_v1_event_series(self, node);
+    }
+
+    /// Visits a value of type [`ExemptPriorityLevelConfiguration`](crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_exempt_priority_level_configuration(&mut self, node: &'ast crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_exempt_priority_level_configuration(self, node);
+    }
+
+    /// Visits a value of type [`FlowDistinguisherMethod`](crate::api::flowcontrol::v1::FlowDistinguisherMethod).
+    fn visit_api_flowcontrol_v1_flow_distinguisher_method(&mut self, node: &'ast crate::api::flowcontrol::v1::FlowDistinguisherMethod) {
+        visit_api_flowcontrol_v1_flow_distinguisher_method(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchema`](crate::api::flowcontrol::v1::FlowSchema).
+    fn visit_api_flowcontrol_v1_flow_schema(&mut self, node: &'ast crate::api::flowcontrol::v1::FlowSchema) {
+        visit_api_flowcontrol_v1_flow_schema(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaCondition`](crate::api::flowcontrol::v1::FlowSchemaCondition).
+    fn visit_api_flowcontrol_v1_flow_schema_condition(&mut self, node: &'ast crate::api::flowcontrol::v1::FlowSchemaCondition) {
+        visit_api_flowcontrol_v1_flow_schema_condition(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaSpec`](crate::api::flowcontrol::v1::FlowSchemaSpec).
+    fn visit_api_flowcontrol_v1_flow_schema_spec(&mut self, node: &'ast crate::api::flowcontrol::v1::FlowSchemaSpec) {
+        visit_api_flowcontrol_v1_flow_schema_spec(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaStatus`](crate::api::flowcontrol::v1::FlowSchemaStatus).
+    fn visit_api_flowcontrol_v1_flow_schema_status(&mut self, node: &'ast crate::api::flowcontrol::v1::FlowSchemaStatus) {
+        visit_api_flowcontrol_v1_flow_schema_status(self, node);
+    }
+
+    /// Visits a value of type [`GroupSubject`](crate::api::flowcontrol::v1::GroupSubject).
+    fn visit_api_flowcontrol_v1_group_subject(&mut self, node: &'ast crate::api::flowcontrol::v1::GroupSubject) {
+        visit_api_flowcontrol_v1_group_subject(self, node);
+    }
+
+    /// Visits a value of type [`LimitResponse`](crate::api::flowcontrol::v1::LimitResponse).
+    fn visit_api_flowcontrol_v1_limit_response(&mut self, node: &'ast crate::api::flowcontrol::v1::LimitResponse) {
+        visit_api_flowcontrol_v1_limit_response(self, node);
+    }
+
+    /// Visits a value of type [`LimitedPriorityLevelConfiguration`](crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_limited_priority_level_configuration(&mut self, node: &'ast crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_limited_priority_level_configuration(self, node);
+    }
+
+    /// Visits a value of type [`NonResourcePolicyRule`](crate::api::flowcontrol::v1::NonResourcePolicyRule).
+    fn visit_api_flowcontrol_v1_non_resource_policy_rule(&mut self, node: &'ast crate::api::flowcontrol::v1::NonResourcePolicyRule) {
+        visit_api_flowcontrol_v1_non_resource_policy_rule(self, node);
+    }
+
+    /// Visits a value of type [`PolicyRulesWithSubjects`](crate::api::flowcontrol::v1::PolicyRulesWithSubjects).
+    fn visit_api_flowcontrol_v1_policy_rules_with_subjects(&mut self, node: &'ast crate::api::flowcontrol::v1::PolicyRulesWithSubjects) {
+        visit_api_flowcontrol_v1_policy_rules_with_subjects(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfiguration`](crate::api::flowcontrol::v1::PriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_priority_level_configuration(&mut self, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_priority_level_configuration(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationCondition`](crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_condition(&mut self, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition) {
+        visit_api_flowcontrol_v1_priority_level_configuration_condition(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationReference`](crate::api::flowcontrol::v1::PriorityLevelConfigurationReference).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_reference(&mut self, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationReference) {
+        visit_api_flowcontrol_v1_priority_level_configuration_reference(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationSpec`](crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_spec(&mut self, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec) {
+        visit_api_flowcontrol_v1_priority_level_configuration_spec(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationStatus`](crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_status(&mut self, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus) {
+        visit_api_flowcontrol_v1_priority_level_configuration_status(self, node);
+    }
+
+    /// Visits a value of type [`QueuingConfiguration`](crate::api::flowcontrol::v1::QueuingConfiguration).
+    fn visit_api_flowcontrol_v1_queuing_configuration(&mut self, node: &'ast crate::api::flowcontrol::v1::QueuingConfiguration) {
+        visit_api_flowcontrol_v1_queuing_configuration(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePolicyRule`](crate::api::flowcontrol::v1::ResourcePolicyRule).
+    fn visit_api_flowcontrol_v1_resource_policy_rule(&mut self, node: &'ast crate::api::flowcontrol::v1::ResourcePolicyRule) {
+        visit_api_flowcontrol_v1_resource_policy_rule(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccountSubject`](crate::api::flowcontrol::v1::ServiceAccountSubject).
+    fn visit_api_flowcontrol_v1_service_account_subject(&mut self, node: &'ast crate::api::flowcontrol::v1::ServiceAccountSubject) {
+        visit_api_flowcontrol_v1_service_account_subject(self, node);
+    }
+
+    /// Visits a value of type [`Subject`](crate::api::flowcontrol::v1::Subject).
+    fn visit_api_flowcontrol_v1_subject(&mut self, node: &'ast crate::api::flowcontrol::v1::Subject) {
+        visit_api_flowcontrol_v1_subject(self, node);
+    }
+
+    /// Visits a value of type [`UserSubject`](crate::api::flowcontrol::v1::UserSubject).
+    fn visit_api_flowcontrol_v1_user_subject(&mut self, node: &'ast crate::api::flowcontrol::v1::UserSubject) {
+        visit_api_flowcontrol_v1_user_subject(self, node);
+    }
+
+    /// Visits a value of type [`HTTPIngressPath`](crate::api::networking::v1::HTTPIngressPath).
+    fn visit_api_networking_v1_http_ingress_path(&mut self, node: &'ast crate::api::networking::v1::HTTPIngressPath) {
+        visit_api_networking_v1_http_ingress_path(self, node);
+    }
+
+    /// Visits a value of type [`HTTPIngressRuleValue`](crate::api::networking::v1::HTTPIngressRuleValue).
+    fn visit_api_networking_v1_http_ingress_rule_value(&mut self, node: &'ast crate::api::networking::v1::HTTPIngressRuleValue) {
+        visit_api_networking_v1_http_ingress_rule_value(self, node);
+    }
+
+    /// Visits a value of type [`IPBlock`](crate::api::networking::v1::IPBlock).
+    fn visit_api_networking_v1_ip_block(&mut self, node: &'ast crate::api::networking::v1::IPBlock) {
+        visit_api_networking_v1_ip_block(self, node);
+    }
+
+    /// Visits a value of type [`Ingress`](crate::api::networking::v1::Ingress).
+    fn visit_api_networking_v1_ingress(&mut self, node: &'ast crate::api::networking::v1::Ingress) {
+        visit_api_networking_v1_ingress(self, node);
+    }
+
+    /// Visits a value of type [`IngressBackend`](crate::api::networking::v1::IngressBackend).
+    fn visit_api_networking_v1_ingress_backend(&mut self, node: &'ast crate::api::networking::v1::IngressBackend) {
+        visit_api_networking_v1_ingress_backend(self, node);
+    }
+
+    /// Visits a value of type [`IngressClass`](crate::api::networking::v1::IngressClass).
+    fn visit_api_networking_v1_ingress_class(&mut self, node: &'ast crate::api::networking::v1::IngressClass) {
+        visit_api_networking_v1_ingress_class(self, node);
+    }
+
+    /// Visits a value of type [`IngressClassParametersReference`](crate::api::networking::v1::IngressClassParametersReference).
+    fn visit_api_networking_v1_ingress_class_parameters_reference(&mut self, node: &'ast crate::api::networking::v1::IngressClassParametersReference) {
+        visit_api_networking_v1_ingress_class_parameters_reference(self, node);
+    }
+
+    /// Visits a value of type [`IngressClassSpec`](crate::api::networking::v1::IngressClassSpec).
+    fn visit_api_networking_v1_ingress_class_spec(&mut self, node: &'ast crate::api::networking::v1::IngressClassSpec) {
+        visit_api_networking_v1_ingress_class_spec(self, node);
+    }
+
+    /// Visits a value of type [`IngressLoadBalancerIngress`](crate::api::networking::v1::IngressLoadBalancerIngress).
+    fn visit_api_networking_v1_ingress_load_balancer_ingress(&mut self, node: &'ast crate::api::networking::v1::IngressLoadBalancerIngress) {
+        visit_api_networking_v1_ingress_load_balancer_ingress(self, node);
+    }
+
+    /// Visits a value of type [`IngressLoadBalancerStatus`](crate::api::networking::v1::IngressLoadBalancerStatus).
+    fn visit_api_networking_v1_ingress_load_balancer_status(&mut self, node: &'ast crate::api::networking::v1::IngressLoadBalancerStatus) {
+        visit_api_networking_v1_ingress_load_balancer_status(self, node);
+    }
+
+    /// Visits a value of type [`IngressPortStatus`](crate::api::networking::v1::IngressPortStatus).
+    fn visit_api_networking_v1_ingress_port_status(&mut self, node: &'ast crate::api::networking::v1::IngressPortStatus) {
+        visit_api_networking_v1_ingress_port_status(self, node);
+    }
+
+    /// Visits a value of type [`IngressRule`](crate::api::networking::v1::IngressRule).
+    fn visit_api_networking_v1_ingress_rule(&mut self, node: &'ast crate::api::networking::v1::IngressRule) {
+        visit_api_networking_v1_ingress_rule(self, node);
+    }
+
+    /// Visits a value of type [`IngressServiceBackend`](crate::api::networking::v1::IngressServiceBackend).
+    fn visit_api_networking_v1_ingress_service_backend(&mut self, node: &'ast crate::api::networking::v1::IngressServiceBackend) {
+        visit_api_networking_v1_ingress_service_backend(self, node);
+    }
+
+    /// Visits a value of type [`IngressSpec`](crate::api::networking::v1::IngressSpec).
+    fn visit_api_networking_v1_ingress_spec(&mut self, node: &'ast crate::api::networking::v1::IngressSpec) {
+        visit_api_networking_v1_ingress_spec(self, node);
+    }
+
+    /// Visits a value of type [`IngressStatus`](crate::api::networking::v1::IngressStatus).
+    fn visit_api_networking_v1_ingress_status(&mut self, node: &'ast crate::api::networking::v1::IngressStatus) {
+        visit_api_networking_v1_ingress_status(self, node);
+    }
+
+    /// Visits a value of type [`IngressTLS`](crate::api::networking::v1::IngressTLS).
+    fn visit_api_networking_v1_ingress_tls(&mut self, node: &'ast crate::api::networking::v1::IngressTLS) {
+        visit_api_networking_v1_ingress_tls(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicy`](crate::api::networking::v1::NetworkPolicy).
+    fn visit_api_networking_v1_network_policy(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicy) {
+        visit_api_networking_v1_network_policy(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyEgressRule`](crate::api::networking::v1::NetworkPolicyEgressRule).
+    fn visit_api_networking_v1_network_policy_egress_rule(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicyEgressRule) {
+        visit_api_networking_v1_network_policy_egress_rule(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyIngressRule`](crate::api::networking::v1::NetworkPolicyIngressRule).
+    fn visit_api_networking_v1_network_policy_ingress_rule(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicyIngressRule) {
+        visit_api_networking_v1_network_policy_ingress_rule(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyPeer`](crate::api::networking::v1::NetworkPolicyPeer).
+    fn visit_api_networking_v1_network_policy_peer(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicyPeer) {
+        visit_api_networking_v1_network_policy_peer(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyPort`](crate::api::networking::v1::NetworkPolicyPort).
+    fn visit_api_networking_v1_network_policy_port(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicyPort) {
+        visit_api_networking_v1_network_policy_port(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicySpec`](crate::api::networking::v1::NetworkPolicySpec).
+    fn visit_api_networking_v1_network_policy_spec(&mut self, node: &'ast crate::api::networking::v1::NetworkPolicySpec) {
+        visit_api_networking_v1_network_policy_spec(self, node);
+    }
+
+    /// Visits a value of type [`ServiceBackendPort`](crate::api::networking::v1::ServiceBackendPort).
+    fn visit_api_networking_v1_service_backend_port(&mut self, node: &'ast crate::api::networking::v1::ServiceBackendPort) {
+        visit_api_networking_v1_service_backend_port(self, node);
+    }
+
+    /// Visits a value of type [`IPAddress`](crate::api::networking::v1beta1::IPAddress).
+    fn visit_api_networking_v1beta1_ip_address(&mut self, node: &'ast crate::api::networking::v1beta1::IPAddress) {
+        visit_api_networking_v1beta1_ip_address(self, node);
+    }
+
+    /// Visits a value of type [`IPAddressSpec`](crate::api::networking::v1beta1::IPAddressSpec).
+    fn visit_api_networking_v1beta1_ip_address_spec(&mut self, node: &'ast crate::api::networking::v1beta1::IPAddressSpec) {
+        visit_api_networking_v1beta1_ip_address_spec(self, node);
+    }
+
+    /// Visits a value of type [`ParentReference`](crate::api::networking::v1beta1::ParentReference).
+    fn visit_api_networking_v1beta1_parent_reference(&mut self, node: &'ast crate::api::networking::v1beta1::ParentReference) {
+        visit_api_networking_v1beta1_parent_reference(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDR`](crate::api::networking::v1beta1::ServiceCIDR).
+    fn visit_api_networking_v1beta1_service_cidr(&mut self, node: &'ast crate::api::networking::v1beta1::ServiceCIDR) {
+        visit_api_networking_v1beta1_service_cidr(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDRSpec`](crate::api::networking::v1beta1::ServiceCIDRSpec).
+    fn visit_api_networking_v1beta1_service_cidr_spec(&mut self, node: &'ast crate::api::networking::v1beta1::ServiceCIDRSpec) {
+        visit_api_networking_v1beta1_service_cidr_spec(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDRStatus`](crate::api::networking::v1beta1::ServiceCIDRStatus).
+    fn visit_api_networking_v1beta1_service_cidr_status(&mut self, node: &'ast crate::api::networking::v1beta1::ServiceCIDRStatus) {
+        visit_api_networking_v1beta1_service_cidr_status(self, node);
+    }
+
+    /// Visits a value of type [`Overhead`](crate::api::node::v1::Overhead).
+    fn visit_api_node_v1_overhead(&mut self, node: &'ast crate::api::node::v1::Overhead) {
+        visit_api_node_v1_overhead(self, node);
+    }
+
+    /// Visits a value of type [`RuntimeClass`](crate::api::node::v1::RuntimeClass).
+    fn visit_api_node_v1_runtime_class(&mut self, node: &'ast crate::api::node::v1::RuntimeClass) {
+        visit_api_node_v1_runtime_class(self, node);
+    }
+
+    /// Visits a value of type [`Scheduling`](crate::api::node::v1::Scheduling).
+    fn visit_api_node_v1_scheduling(&mut self, node: &'ast crate::api::node::v1::Scheduling) {
+        visit_api_node_v1_scheduling(self, node);
+    }
+
+    /// Visits a value of type [`Eviction`](crate::api::policy::v1::Eviction).
+    fn visit_api_policy_v1_eviction(&mut self, node: &'ast crate::api::policy::v1::Eviction) {
+        visit_api_policy_v1_eviction(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudget`](crate::api::policy::v1::PodDisruptionBudget).
+    fn visit_api_policy_v1_pod_disruption_budget(&mut self, node: &'ast crate::api::policy::v1::PodDisruptionBudget) {
+        visit_api_policy_v1_pod_disruption_budget(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudgetSpec`](crate::api::policy::v1::PodDisruptionBudgetSpec).
+    fn visit_api_policy_v1_pod_disruption_budget_spec(&mut self, node: &'ast crate::api::policy::v1::PodDisruptionBudgetSpec) {
+        visit_api_policy_v1_pod_disruption_budget_spec(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudgetStatus`](crate::api::policy::v1::PodDisruptionBudgetStatus).
+    fn visit_api_policy_v1_pod_disruption_budget_status(&mut self, node: &'ast crate::api::policy::v1::PodDisruptionBudgetStatus) {
+        visit_api_policy_v1_pod_disruption_budget_status(self, node);
+    }
+
+    /// Visits a value of type [`AggregationRule`](crate::api::rbac::v1::AggregationRule).
+    fn visit_api_rbac_v1_aggregation_rule(&mut self, node: &'ast crate::api::rbac::v1::AggregationRule) {
+        visit_api_rbac_v1_aggregation_rule(self, node);
+    }
+
+    /// Visits a value of type [`ClusterRole`](crate::api::rbac::v1::ClusterRole).
+    fn visit_api_rbac_v1_cluster_role(&mut self, node: &'ast crate::api::rbac::v1::ClusterRole) {
+        visit_api_rbac_v1_cluster_role(self, node);
+    }
+
+    /// Visits a value of type [`ClusterRoleBinding`](crate::api::rbac::v1::ClusterRoleBinding).
+    fn visit_api_rbac_v1_cluster_role_binding(&mut self, node: &'ast crate::api::rbac::v1::ClusterRoleBinding) {
+        visit_api_rbac_v1_cluster_role_binding(self, node);
+    }
+
+    /// Visits a value of type [`PolicyRule`](crate::api::rbac::v1::PolicyRule).
+    fn visit_api_rbac_v1_policy_rule(&mut self, node: &'ast crate::api::rbac::v1::PolicyRule) {
+        visit_api_rbac_v1_policy_rule(self, node);
+    }
+
+    /// Visits a value of type [`Role`](crate::api::rbac::v1::Role).
+    fn visit_api_rbac_v1_role(&mut self, node: &'ast crate::api::rbac::v1::Role) {
+        visit_api_rbac_v1_role(self, node);
+    }
+
+    /// Visits a value of type [`RoleBinding`](crate::api::rbac::v1::RoleBinding).
+    fn visit_api_rbac_v1_role_binding(&mut self, node: &'ast crate::api::rbac::v1::RoleBinding) {
+        visit_api_rbac_v1_role_binding(self, node);
+    }
+
+    /// Visits a value of type [`RoleRef`](crate::api::rbac::v1::RoleRef).
+    fn visit_api_rbac_v1_role_ref(&mut self, node: &'ast crate::api::rbac::v1::RoleRef) {
+        visit_api_rbac_v1_role_ref(self, node);
+    }
+
+    /// Visits a value of type [`Subject`](crate::api::rbac::v1::Subject).
+    fn visit_api_rbac_v1_subject(&mut self, node: &'ast crate::api::rbac::v1::Subject) {
+        visit_api_rbac_v1_subject(self, node);
+    }
+
+    /// Visits a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1alpha3::AllocatedDeviceStatus).
+    fn visit_api_resource_v1alpha3_allocated_device_status(&mut self, node: &'ast crate::api::resource::v1alpha3::AllocatedDeviceStatus) {
+        visit_api_resource_v1alpha3_allocated_device_status(self, node);
+    }
+
+    /// Visits a value of type [`AllocationResult`](crate::api::resource::v1alpha3::AllocationResult).
+    fn visit_api_resource_v1alpha3_allocation_result(&mut self, node: &'ast crate::api::resource::v1alpha3::AllocationResult) {
+        visit_api_resource_v1alpha3_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`BasicDevice`](crate::api::resource::v1alpha3::BasicDevice).
+    fn visit_api_resource_v1alpha3_basic_device(&mut self, node: &'ast crate::api::resource::v1alpha3::BasicDevice) {
+        visit_api_resource_v1alpha3_basic_device(self, node);
+    }
+
+    /// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1alpha3::CELDeviceSelector).
+    fn visit_api_resource_v1alpha3_cel_device_selector(&mut self, node: &'ast crate::api::resource::v1alpha3::CELDeviceSelector) {
+        visit_api_resource_v1alpha3_cel_device_selector(self, node);
+    }
+
+    /// Visits a value of type [`Device`](crate::api::resource::v1alpha3::Device).
+    fn visit_api_resource_v1alpha3_device(&mut self, node: &'ast crate::api::resource::v1alpha3::Device) {
+        visit_api_resource_v1alpha3_device(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1alpha3::DeviceAllocationConfiguration).
+    fn visit_api_resource_v1alpha3_device_allocation_configuration(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceAllocationConfiguration) {
+        visit_api_resource_v1alpha3_device_allocation_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationResult`](crate::api::resource::v1alpha3::DeviceAllocationResult).
+    fn visit_api_resource_v1alpha3_device_allocation_result(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceAllocationResult) {
+        visit_api_resource_v1alpha3_device_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1alpha3::DeviceAttribute).
+    fn visit_api_resource_v1alpha3_device_attribute(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceAttribute) {
+        visit_api_resource_v1alpha3_device_attribute(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaim`](crate::api::resource::v1alpha3::DeviceClaim).
+    fn visit_api_resource_v1alpha3_device_claim(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceClaim) {
+        visit_api_resource_v1alpha3_device_claim(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1alpha3::DeviceClaimConfiguration).
+    fn visit_api_resource_v1alpha3_device_claim_configuration(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceClaimConfiguration) {
+        visit_api_resource_v1alpha3_device_claim_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClass`](crate::api::resource::v1alpha3::DeviceClass).
+    fn visit_api_resource_v1alpha3_device_class(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceClass) {
+        visit_api_resource_v1alpha3_device_class(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassConfiguration`](crate::api::resource::v1alpha3::DeviceClassConfiguration).
+    fn visit_api_resource_v1alpha3_device_class_configuration(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceClassConfiguration) {
+        visit_api_resource_v1alpha3_device_class_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassSpec`](crate::api::resource::v1alpha3::DeviceClassSpec).
+    fn visit_api_resource_v1alpha3_device_class_spec(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceClassSpec) {
+        visit_api_resource_v1alpha3_device_class_spec(self, node);
+    }
+
+    /// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1alpha3::DeviceConstraint).
+    fn visit_api_resource_v1alpha3_device_constraint(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceConstraint) {
+        visit_api_resource_v1alpha3_device_constraint(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequest`](crate::api::resource::v1alpha3::DeviceRequest).
+    fn visit_api_resource_v1alpha3_device_request(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceRequest) {
+        visit_api_resource_v1alpha3_device_request(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1alpha3::DeviceRequestAllocationResult).
+    fn visit_api_resource_v1alpha3_device_request_allocation_result(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceRequestAllocationResult) {
+        visit_api_resource_v1alpha3_device_request_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`DeviceSelector`](crate::api::resource::v1alpha3::DeviceSelector).
+    fn visit_api_resource_v1alpha3_device_selector(&mut self, node: &'ast crate::api::resource::v1alpha3::DeviceSelector) {
+        visit_api_resource_v1alpha3_device_selector(self, node);
+    }
+
+    /// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1alpha3::NetworkDeviceData).
+    fn visit_api_resource_v1alpha3_network_device_data(&mut self, node: &'ast crate::api::resource::v1alpha3::NetworkDeviceData) {
+        visit_api_resource_v1alpha3_network_device_data(self, node);
+    }
+
+    /// Visits a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1alpha3::OpaqueDeviceConfiguration).
+    fn visit_api_resource_v1alpha3_opaque_device_configuration(&mut self, node: &'ast crate::api::resource::v1alpha3::OpaqueDeviceConfiguration) {
+        visit_api_resource_v1alpha3_opaque_device_configuration(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::resource::v1alpha3::ResourceClaim).
+    fn visit_api_resource_v1alpha3_resource_claim(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaim) {
+        visit_api_resource_v1alpha3_resource_claim(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1alpha3::ResourceClaimConsumerReference).
+    fn visit_api_resource_v1alpha3_resource_claim_consumer_reference(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaimConsumerReference) {
+        visit_api_resource_v1alpha3_resource_claim_consumer_reference(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimSpec`](crate::api::resource::v1alpha3::ResourceClaimSpec).
+    fn visit_api_resource_v1alpha3_resource_claim_spec(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaimSpec) {
+        visit_api_resource_v1alpha3_resource_claim_spec(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimStatus`](crate::api::resource::v1alpha3::ResourceClaimStatus).
+    fn visit_api_resource_v1alpha3_resource_claim_status(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaimStatus) {
+        visit_api_resource_v1alpha3_resource_claim_status(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplate`](crate::api::resource::v1alpha3::ResourceClaimTemplate).
+    fn visit_api_resource_v1alpha3_resource_claim_template(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaimTemplate) {
+        visit_api_resource_v1alpha3_resource_claim_template(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1alpha3::ResourceClaimTemplateSpec).
+    fn visit_api_resource_v1alpha3_resource_claim_template_spec(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceClaimTemplateSpec) {
+        visit_api_resource_v1alpha3_resource_claim_template_spec(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePool`](crate::api::resource::v1alpha3::ResourcePool).
+    fn visit_api_resource_v1alpha3_resource_pool(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourcePool) {
+        visit_api_resource_v1alpha3_resource_pool(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSlice`](crate::api::resource::v1alpha3::ResourceSlice).
+    fn visit_api_resource_v1alpha3_resource_slice(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceSlice) {
+        visit_api_resource_v1alpha3_resource_slice(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSliceSpec`](crate::api::resource::v1alpha3::ResourceSliceSpec).
+    fn visit_api_resource_v1alpha3_resource_slice_spec(&mut self, node: &'ast crate::api::resource::v1alpha3::ResourceSliceSpec) {
+        visit_api_resource_v1alpha3_resource_slice_spec(self, node);
+    }
+
+    /// Visits a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1beta1::AllocatedDeviceStatus).
+    fn visit_api_resource_v1beta1_allocated_device_status(&mut self, node: &'ast crate::api::resource::v1beta1::AllocatedDeviceStatus) {
+        visit_api_resource_v1beta1_allocated_device_status(self, node);
+    }
+
+    /// Visits a value of type [`AllocationResult`](crate::api::resource::v1beta1::AllocationResult).
+    fn visit_api_resource_v1beta1_allocation_result(&mut self, node: &'ast crate::api::resource::v1beta1::AllocationResult) {
+        visit_api_resource_v1beta1_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`BasicDevice`](crate::api::resource::v1beta1::BasicDevice).
+    fn visit_api_resource_v1beta1_basic_device(&mut self, node: &'ast crate::api::resource::v1beta1::BasicDevice) {
+        visit_api_resource_v1beta1_basic_device(self, node);
+    }
+
+    /// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1beta1::CELDeviceSelector).
+    fn visit_api_resource_v1beta1_cel_device_selector(&mut self, node: &'ast crate::api::resource::v1beta1::CELDeviceSelector) {
+        visit_api_resource_v1beta1_cel_device_selector(self, node);
+    }
+
+    /// Visits a value of type [`Device`](crate::api::resource::v1beta1::Device).
+    fn visit_api_resource_v1beta1_device(&mut self, node: &'ast crate::api::resource::v1beta1::Device) {
+        visit_api_resource_v1beta1_device(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1beta1::DeviceAllocationConfiguration).
+    fn visit_api_resource_v1beta1_device_allocation_configuration(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceAllocationConfiguration) {
+        visit_api_resource_v1beta1_device_allocation_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationResult`](crate::api::resource::v1beta1::DeviceAllocationResult).
+    fn visit_api_resource_v1beta1_device_allocation_result(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceAllocationResult) {
+        visit_api_resource_v1beta1_device_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1beta1::DeviceAttribute).
+    fn visit_api_resource_v1beta1_device_attribute(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceAttribute) {
+        visit_api_resource_v1beta1_device_attribute(self, node);
+    }
+
+    /// Visits a value of type [`DeviceCapacity`](crate::api::resource::v1beta1::DeviceCapacity).
+    fn visit_api_resource_v1beta1_device_capacity(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceCapacity) {
+        visit_api_resource_v1beta1_device_capacity(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaim`](crate::api::resource::v1beta1::DeviceClaim).
+    fn visit_api_resource_v1beta1_device_claim(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceClaim) {
+        visit_api_resource_v1beta1_device_claim(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1beta1::DeviceClaimConfiguration).
+    fn visit_api_resource_v1beta1_device_claim_configuration(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceClaimConfiguration) {
+        visit_api_resource_v1beta1_device_claim_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClass`](crate::api::resource::v1beta1::DeviceClass).
+    fn visit_api_resource_v1beta1_device_class(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceClass) {
+        visit_api_resource_v1beta1_device_class(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassConfiguration`](crate::api::resource::v1beta1::DeviceClassConfiguration).
+    fn visit_api_resource_v1beta1_device_class_configuration(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceClassConfiguration) {
+        visit_api_resource_v1beta1_device_class_configuration(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassSpec`](crate::api::resource::v1beta1::DeviceClassSpec).
+    fn visit_api_resource_v1beta1_device_class_spec(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceClassSpec) {
+        visit_api_resource_v1beta1_device_class_spec(self, node);
+    }
+
+    /// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1beta1::DeviceConstraint).
+    fn visit_api_resource_v1beta1_device_constraint(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceConstraint) {
+        visit_api_resource_v1beta1_device_constraint(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequest`](crate::api::resource::v1beta1::DeviceRequest).
+    fn visit_api_resource_v1beta1_device_request(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceRequest) {
+        visit_api_resource_v1beta1_device_request(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1beta1::DeviceRequestAllocationResult).
+    fn visit_api_resource_v1beta1_device_request_allocation_result(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceRequestAllocationResult) {
+        visit_api_resource_v1beta1_device_request_allocation_result(self, node);
+    }
+
+    /// Visits a value of type [`DeviceSelector`](crate::api::resource::v1beta1::DeviceSelector).
+    fn visit_api_resource_v1beta1_device_selector(&mut self, node: &'ast crate::api::resource::v1beta1::DeviceSelector) {
+        visit_api_resource_v1beta1_device_selector(self, node);
+    }
+
+    /// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1beta1::NetworkDeviceData).
+    fn visit_api_resource_v1beta1_network_device_data(&mut self, node: &'ast crate::api::resource::v1beta1::NetworkDeviceData) {
+        visit_api_resource_v1beta1_network_device_data(self, node);
+    }
+
+    /// Visits a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1beta1::OpaqueDeviceConfiguration).
+    fn visit_api_resource_v1beta1_opaque_device_configuration(&mut self, node: &'ast crate::api::resource::v1beta1::OpaqueDeviceConfiguration) {
+        visit_api_resource_v1beta1_opaque_device_configuration(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::resource::v1beta1::ResourceClaim).
+    fn visit_api_resource_v1beta1_resource_claim(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaim) {
+        visit_api_resource_v1beta1_resource_claim(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1beta1::ResourceClaimConsumerReference).
+    fn visit_api_resource_v1beta1_resource_claim_consumer_reference(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaimConsumerReference) {
+        visit_api_resource_v1beta1_resource_claim_consumer_reference(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimSpec`](crate::api::resource::v1beta1::ResourceClaimSpec).
+    fn visit_api_resource_v1beta1_resource_claim_spec(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaimSpec) {
+        visit_api_resource_v1beta1_resource_claim_spec(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimStatus`](crate::api::resource::v1beta1::ResourceClaimStatus).
+    fn visit_api_resource_v1beta1_resource_claim_status(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaimStatus) {
+        visit_api_resource_v1beta1_resource_claim_status(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplate`](crate::api::resource::v1beta1::ResourceClaimTemplate).
+    fn visit_api_resource_v1beta1_resource_claim_template(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaimTemplate) {
+        visit_api_resource_v1beta1_resource_claim_template(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1beta1::ResourceClaimTemplateSpec).
+    fn visit_api_resource_v1beta1_resource_claim_template_spec(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceClaimTemplateSpec) {
+        visit_api_resource_v1beta1_resource_claim_template_spec(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePool`](crate::api::resource::v1beta1::ResourcePool).
+    fn visit_api_resource_v1beta1_resource_pool(&mut self, node: &'ast crate::api::resource::v1beta1::ResourcePool) {
+        visit_api_resource_v1beta1_resource_pool(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSlice`](crate::api::resource::v1beta1::ResourceSlice).
+    fn visit_api_resource_v1beta1_resource_slice(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceSlice) {
+        visit_api_resource_v1beta1_resource_slice(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSliceSpec`](crate::api::resource::v1beta1::ResourceSliceSpec).
+    fn visit_api_resource_v1beta1_resource_slice_spec(&mut self, node: &'ast crate::api::resource::v1beta1::ResourceSliceSpec) {
+        visit_api_resource_v1beta1_resource_slice_spec(self, node);
+    }
+
+    /// Visits a value of type [`PriorityClass`](crate::api::scheduling::v1::PriorityClass).
+    fn visit_api_scheduling_v1_priority_class(&mut self, node: &'ast crate::api::scheduling::v1::PriorityClass) {
+        visit_api_scheduling_v1_priority_class(self, node);
+    }
+
+    /// Visits a value of type [`CSIDriver`](crate::api::storage::v1::CSIDriver).
+    fn visit_api_storage_v1_csi_driver(&mut self, node: &'ast crate::api::storage::v1::CSIDriver) {
+        visit_api_storage_v1_csi_driver(self, node);
+    }
+
+    /// Visits a value of type [`CSIDriverSpec`](crate::api::storage::v1::CSIDriverSpec).
+    fn visit_api_storage_v1_csi_driver_spec(&mut self, node: &'ast crate::api::storage::v1::CSIDriverSpec) {
+        visit_api_storage_v1_csi_driver_spec(self, node);
+    }
+
+    /// Visits a value of type [`CSINode`](crate::api::storage::v1::CSINode).
+    fn visit_api_storage_v1_csi_node(&mut self, node: &'ast crate::api::storage::v1::CSINode) {
+        visit_api_storage_v1_csi_node(self, node);
+    }
+
+    /// Visits a value of type [`CSINodeDriver`](crate::api::storage::v1::CSINodeDriver).
+    fn visit_api_storage_v1_csi_node_driver(&mut self, node: &'ast crate::api::storage::v1::CSINodeDriver) {
+        visit_api_storage_v1_csi_node_driver(self, node);
+    }
+
+    /// Visits a value of type [`CSINodeSpec`](crate::api::storage::v1::CSINodeSpec).
+    fn visit_api_storage_v1_csi_node_spec(&mut self, node: &'ast crate::api::storage::v1::CSINodeSpec) {
+        visit_api_storage_v1_csi_node_spec(self, node);
+    }
+
+    /// Visits a value of type [`CSIStorageCapacity`](crate::api::storage::v1::CSIStorageCapacity).
+    fn visit_api_storage_v1_csi_storage_capacity(&mut self, node: &'ast crate::api::storage::v1::CSIStorageCapacity) {
+        visit_api_storage_v1_csi_storage_capacity(self, node);
+    }
+
+    /// Visits a value of type [`StorageClass`](crate::api::storage::v1::StorageClass).
+    fn visit_api_storage_v1_storage_class(&mut self, node: &'ast crate::api::storage::v1::StorageClass) {
+        visit_api_storage_v1_storage_class(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequest`](crate::api::storage::v1::TokenRequest).
+    fn visit_api_storage_v1_token_request(&mut self, node: &'ast crate::api::storage::v1::TokenRequest) {
+        visit_api_storage_v1_token_request(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachment`](crate::api::storage::v1::VolumeAttachment).
+    fn visit_api_storage_v1_volume_attachment(&mut self, node: &'ast crate::api::storage::v1::VolumeAttachment) {
+        visit_api_storage_v1_volume_attachment(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentSource`](crate::api::storage::v1::VolumeAttachmentSource).
+    fn visit_api_storage_v1_volume_attachment_source(&mut self, node: &'ast crate::api::storage::v1::VolumeAttachmentSource) {
+        visit_api_storage_v1_volume_attachment_source(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentSpec`](crate::api::storage::v1::VolumeAttachmentSpec).
+    fn visit_api_storage_v1_volume_attachment_spec(&mut self, node: &'ast crate::api::storage::v1::VolumeAttachmentSpec) {
+        visit_api_storage_v1_volume_attachment_spec(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentStatus`](crate::api::storage::v1::VolumeAttachmentStatus).
+    fn visit_api_storage_v1_volume_attachment_status(&mut self, node: &'ast crate::api::storage::v1::VolumeAttachmentStatus) {
+        visit_api_storage_v1_volume_attachment_status(self, node);
+    }
+
+    /// Visits a value of type [`VolumeError`](crate::api::storage::v1::VolumeError).
+    fn visit_api_storage_v1_volume_error(&mut self, node: &'ast crate::api::storage::v1::VolumeError) {
+        visit_api_storage_v1_volume_error(self, node);
+    }
+
+    /// Visits a value of type [`VolumeNodeResources`](crate::api::storage::v1::VolumeNodeResources).
+    fn visit_api_storage_v1_volume_node_resources(&mut self, node: &'ast crate::api::storage::v1::VolumeNodeResources) {
+        visit_api_storage_v1_volume_node_resources(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttributesClass`](crate::api::storage::v1alpha1::VolumeAttributesClass).
+    fn visit_api_storage_v1alpha1_volume_attributes_class(&mut self, node: &'ast crate::api::storage::v1alpha1::VolumeAttributesClass) {
+        visit_api_storage_v1alpha1_volume_attributes_class(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttributesClass`](crate::api::storage::v1beta1::VolumeAttributesClass).
+    fn visit_api_storage_v1beta1_volume_attributes_class(&mut self, node: &'ast crate::api::storage::v1beta1::VolumeAttributesClass) {
+        visit_api_storage_v1beta1_volume_attributes_class(self, node);
+    }
+
+    /// Visits a value of type [`GroupVersionResource`](crate::api::storagemigration::v1alpha1::GroupVersionResource).
+    fn visit_api_storagemigration_v1alpha1_group_version_resource(&mut self, node: &'ast crate::api::storagemigration::v1alpha1::GroupVersionResource) {
+        visit_api_storagemigration_v1alpha1_group_version_resource(self, node);
+    }
+
+    /// Visits a value of type [`MigrationCondition`](crate::api::storagemigration::v1alpha1::MigrationCondition).
+    fn visit_api_storagemigration_v1alpha1_migration_condition(&mut self, node: &'ast crate::api::storagemigration::v1alpha1::MigrationCondition) {
+        visit_api_storagemigration_v1alpha1_migration_condition(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigration`](crate::api::storagemigration::v1alpha1::StorageVersionMigration).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration(&mut self, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigration) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigrationSpec`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration_spec(&mut self, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration_spec(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigrationStatus`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration_status(&mut self, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration_status(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceColumnDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionCondition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionNames`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionSpec`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionVersion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresourceScale`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresourceStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresources`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceValidation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation(self, node);
+    }
+
+    /// Visits a value of type [`ExternalDocumentation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation(self, node);
+    }
+
+    /// Visits a value of type [`JSON`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaProps`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrBool`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrStringArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array(self, node);
+    }
+
+    /// Visits a value of type [`SelectableField`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference(self, node);
+    }
+
+    /// Visits a value of type [`ValidationRule`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule(self, node);
+    }
+
+    /// Visits a value of type [`WebhookClientConfig`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config(self, node);
+    }
+
+    /// Visits a value of type [`WebhookConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion(&mut self, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion(self, node);
+    }
+
+    /// Visits a value of type [`Quantity`](crate::apimachinery::pkg::api::resource::Quantity).
+    fn visit_apimachinery_pkg_api_resource_quantity(&mut self, node: &'ast crate::apimachinery::pkg::api::resource::Quantity) {
+        visit_apimachinery_pkg_api_resource_quantity(self, node);
+    }
+
+    /// Visits a value of type [`APIGroup`](crate::apimachinery::pkg::apis::meta::v1::APIGroup).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_group(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIGroup) {
+        visit_apimachinery_pkg_apis_meta_v1_api_group(self, node);
+    }
+
+    /// Visits a value of type [`APIGroupList`](crate::apimachinery::pkg::apis::meta::v1::APIGroupList).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_group_list(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIGroupList) {
+        visit_apimachinery_pkg_apis_meta_v1_api_group_list(self, node);
+    }
+
+    /// Visits a value of type [`APIResource`](crate::apimachinery::pkg::apis::meta::v1::APIResource).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_resource(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIResource) {
+        visit_apimachinery_pkg_apis_meta_v1_api_resource(self, node);
+    }
+
+    /// Visits a value of type [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_resource_list(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIResourceList) {
+        visit_apimachinery_pkg_apis_meta_v1_api_resource_list(self, node);
+    }
+
+    /// Visits a value of type [`APIVersions`](crate::apimachinery::pkg::apis::meta::v1::APIVersions).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_versions(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIVersions) {
+        visit_apimachinery_pkg_apis_meta_v1_api_versions(self, node);
+    }
+
+    /// Visits a value of type [`Condition`](crate::apimachinery::pkg::apis::meta::v1::Condition).
+    fn visit_apimachinery_pkg_apis_meta_v1_condition(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Condition) {
+        visit_apimachinery_pkg_apis_meta_v1_condition(self, node);
+    }
+
+    /// Visits a value of type [`DeleteOptions`](crate::apimachinery::pkg::apis::meta::v1::DeleteOptions).
+    fn visit_apimachinery_pkg_apis_meta_v1_delete_options(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::DeleteOptions) {
+        visit_apimachinery_pkg_apis_meta_v1_delete_options(self, node);
+    }
+
+    /// Visits a value of type [`FieldSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement).
+    fn visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement) {
+        visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement(self, node);
+    }
+
+    /// Visits a value of type [`FieldsV1`](crate::apimachinery::pkg::apis::meta::v1::FieldsV1).
+    fn visit_apimachinery_pkg_apis_meta_v1_fields_v1(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::FieldsV1) {
+        visit_apimachinery_pkg_apis_meta_v1_fields_v1(self, node);
+    }
+
+    /// Visits a value of type [`GroupVersionForDiscovery`](crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery).
+    fn visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery) {
+        visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelector`](crate::apimachinery::pkg::apis::meta::v1::LabelSelector).
+    fn visit_apimachinery_pkg_apis_meta_v1_label_selector(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::LabelSelector) {
+        visit_apimachinery_pkg_apis_meta_v1_label_selector(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement).
+    fn visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement) {
+        visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement(self, node);
+    }
+
+    /// Visits a value of type [`ListMeta`](crate::apimachinery::pkg::apis::meta::v1::ListMeta).
+    fn visit_apimachinery_pkg_apis_meta_v1_list_meta(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ListMeta) {
+        visit_apimachinery_pkg_apis_meta_v1_list_meta(self, node);
+    }
+
+    /// Visits a value of type [`ManagedFieldsEntry`](crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry).
+    fn visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry) {
+        visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry(self, node);
+    }
+
+    /// Visits a value of type [`MicroTime`](crate::apimachinery::pkg::apis::meta::v1::MicroTime).
+    fn visit_apimachinery_pkg_apis_meta_v1_micro_time(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::MicroTime) {
+        visit_apimachinery_pkg_apis_meta_v1_micro_time(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMeta`](crate::apimachinery::pkg::apis::meta::v1::ObjectMeta).
+    fn visit_apimachinery_pkg_apis_meta_v1_object_meta(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) {
+        visit_apimachinery_pkg_apis_meta_v1_object_meta(self, node);
+    }
+
+    /// Visits a value of type [`OwnerReference`](crate::apimachinery::pkg::apis::meta::v1::OwnerReference).
+    fn visit_apimachinery_pkg_apis_meta_v1_owner_reference(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::OwnerReference) {
+        visit_apimachinery_pkg_apis_meta_v1_owner_reference(self, node);
+    }
+
+    /// Visits a value of type [`Preconditions`](crate::apimachinery::pkg::apis::meta::v1::Preconditions).
+    fn visit_apimachinery_pkg_apis_meta_v1_preconditions(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Preconditions) {
+        visit_apimachinery_pkg_apis_meta_v1_preconditions(self, node);
+    }
+
+    /// Visits a value of type [`ServerAddressByClientCIDR`](crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR).
+    fn visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR) {
+        visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr(self, node);
+    }
+
+    /// Visits a value of type [`Status`](crate::apimachinery::pkg::apis::meta::v1::Status).
+    fn visit_apimachinery_pkg_apis_meta_v1_status(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Status) {
+        visit_apimachinery_pkg_apis_meta_v1_status(self, node);
+    }
+
+    /// Visits a value of type [`StatusCause`](crate::apimachinery::pkg::apis::meta::v1::StatusCause).
+    fn visit_apimachinery_pkg_apis_meta_v1_status_cause(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::StatusCause) {
+        visit_apimachinery_pkg_apis_meta_v1_status_cause(self, node);
+    }
+
+    /// Visits a value of type [`StatusDetails`](crate::apimachinery::pkg::apis::meta::v1::StatusDetails).
+    fn visit_apimachinery_pkg_apis_meta_v1_status_details(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::StatusDetails) {
+        visit_apimachinery_pkg_apis_meta_v1_status_details(self, node);
+    }
+
+    /// Visits a value of type [`Time`](crate::apimachinery::pkg::apis::meta::v1::Time).
+    fn visit_apimachinery_pkg_apis_meta_v1_time(&mut self, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Time) {
+        visit_apimachinery_pkg_apis_meta_v1_time(self, node);
+    }
+
+    /// Visits a value of type [`RawExtension`](crate::apimachinery::pkg::runtime::RawExtension).
+    fn visit_apimachinery_pkg_runtime_raw_extension(&mut self, node: &'ast crate::apimachinery::pkg::runtime::RawExtension) {
+        visit_apimachinery_pkg_runtime_raw_extension(self, node);
+    }
+
+    /// Visits a value of type [`IntOrString`](crate::apimachinery::pkg::util::intstr::IntOrString).
+    fn visit_apimachinery_pkg_util_intstr_int_or_string(&mut self, node: &'ast crate::apimachinery::pkg::util::intstr::IntOrString) {
+        visit_apimachinery_pkg_util_intstr_int_or_string(self, node);
+    }
+
+    /// Visits a value of type [`Info`](crate::apimachinery::pkg::version::Info).
+    fn visit_apimachinery_pkg_version_info(&mut self, node: &'ast crate::apimachinery::pkg::version::Info) {
+        visit_apimachinery_pkg_version_info(self, node);
+    }
+
+    /// Visits a value of type [`APIService`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service(&mut self, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceCondition`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition(&mut self, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceSpec`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec(&mut self, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceStatus`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status(&mut self, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference(&mut self, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference(self, node);
+    }
+}
+
+/// A value that can be walked by a [`Visit`] visitor.
+pub trait Visitable {
+    /// Calls the method of the visitor for the type of this value.
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized;
+}
+
+/// Visits the values contained in a value of type [`AnyResource`](crate::AnyResource).
+pub fn visit_any_resource<'ast, V>(v: &mut V, node: &'ast crate::AnyResource) where V: Visit<'ast> + ?Sized {
+    match node {
+        crate::AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(it) => {
+            v.visit_api_admissionregistration_v1_mutating_webhook_configuration(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1_validating_admission_policy(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1_validating_admission_policy_binding(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(it) => {
+            v.visit_api_admissionregistration_v1_validating_webhook_configuration(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1beta1_validating_admission_policy(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding(it);
+        },
+        crate::AnyResource::ApiserverinternalV1alpha1StorageVersion(it) => {
+            v.visit_api_apiserverinternal_v1alpha1_storage_version(it);
+        },
+        crate::AnyResource::AppsV1ControllerRevision(it) => {
+            v.visit_api_apps_v1_controller_revision(it);
+        },
+        crate::AnyResource::AppsV1DaemonSet(it) => {
+            v.visit_api_apps_v1_daemon_set(it);
+        },
+        crate::AnyResource::AppsV1Deployment(it) => {
+            v.visit_api_apps_v1_deployment(it);
+        },
+        crate::AnyResource::AppsV1ReplicaSet(it) => {
+            v.visit_api_apps_v1_replica_set(it);
+        },
+        crate::AnyResource::AppsV1StatefulSet(it) => {
+            v.visit_api_apps_v1_stateful_set(it);
+        },
+        crate::AnyResource::AuthenticationV1SelfSubjectReview(it) => {
+            v.visit_api_authentication_v1_self_subject_review(it);
+        },
+        crate::AnyResource::AuthenticationV1TokenRequest(it) => {
+            v.visit_api_authentication_v1_token_request(it);
+        },
+        crate::AnyResource::AuthenticationV1TokenReview(it) => {
+            v.visit_api_authentication_v1_token_review(it);
+        },
+        crate::AnyResource::AuthenticationV1beta1SelfSubjectReview(it) => {
+            v.visit_api_authentication_v1beta1_self_subject_review(it);
+        },
+        crate::AnyResource::AuthorizationV1LocalSubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_local_subject_access_review(it);
+        },
+        crate::AnyResource::AuthorizationV1SelfSubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_self_subject_access_review(it);
+        },
+        crate::AnyResource::AuthorizationV1SelfSubjectRulesReview(it) => {
+            v.visit_api_authorization_v1_self_subject_rules_review(it);
+        },
+        crate::AnyResource::AuthorizationV1SubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_subject_access_review(it);
+        },
+        crate::AnyResource::AutoscalingV1HorizontalPodAutoscaler(it) => {
+            v.visit_api_autoscaling_v1_horizontal_pod_autoscaler(it);
+        },
+        crate::AnyResource::AutoscalingV1Scale(it) => {
+            v.visit_api_autoscaling_v1_scale(it);
+        },
+        crate::AnyResource::AutoscalingV2HorizontalPodAutoscaler(it) => {
+            v.visit_api_autoscaling_v2_horizontal_pod_autoscaler(it);
+        },
+        crate::AnyResource::BatchV1CronJob(it) => {
+            v.visit_api_batch_v1_cron_job(it);
+        },
+        crate::AnyResource::BatchV1Job(it) => {
+            v.visit_api_batch_v1_job(it);
+        },
+        crate::AnyResource::CertificatesV1CertificateSigningRequest(it) => {
+            v.visit_api_certificates_v1_certificate_signing_request(it);
+        },
+        crate::AnyResource::CertificatesV1alpha1ClusterTrustBundle(it) => {
+            v.visit_api_certificates_v1alpha1_cluster_trust_bundle(it);
+        },
+        crate::AnyResource::CoordinationV1Lease(it) => {
+            v.visit_api_coordination_v1_lease(it);
+        },
+        crate::AnyResource::CoordinationV1alpha2LeaseCandidate(it) => {
+            v.visit_api_coordination_v1alpha2_lease_candidate(it);
+        },
+        crate::AnyResource::CoreV1Binding(it) => {
+            v.visit_api_core_v1_binding(it);
+        },
+        crate::AnyResource::CoreV1ComponentStatus(it) => {
+            v.visit_api_core_v1_component_status(it);
+        },
+        crate::AnyResource::CoreV1ConfigMap(it) => {
+            v.visit_api_core_v1_config_map(it);
+        },
+        crate::AnyResource::CoreV1Endpoints(it) => {
+            v.visit_api_core_v1_endpoints(it);
+        },
+        crate::AnyResource::CoreV1Event(it) => {
+            v.visit_api_core_v1_event(it);
+        },
+        crate::AnyResource::CoreV1LimitRange(it) => {
+            v.visit_api_core_v1_limit_range(it);
+        },
+        crate::AnyResource::CoreV1Namespace(it) => {
+            v.visit_api_core_v1_namespace(it);
+        },
+        crate::AnyResource::CoreV1Node(it) => {
+            v.visit_api_core_v1_node(it);
+        },
+        crate::AnyResource::CoreV1PersistentVolume(it) => {
+            v.visit_api_core_v1_persistent_volume(it);
+        },
+        crate::AnyResource::CoreV1PersistentVolumeClaim(it) => {
+            v.visit_api_core_v1_persistent_volume_claim(it);
+        },
+        crate::AnyResource::CoreV1Pod(it) => {
+            v.visit_api_core_v1_pod(it);
+        },
+        crate::AnyResource::CoreV1PodTemplate(it) => {
+            v.visit_api_core_v1_pod_template(it);
+        },
+        crate::AnyResource::CoreV1ReplicationController(it) => {
+            v.visit_api_core_v1_replication_controller(it);
+        },
+        crate::AnyResource::CoreV1ResourceQuota(it) => {
+            v.visit_api_core_v1_resource_quota(it);
+        },
+        crate::AnyResource::CoreV1Secret(it) => {
+            v.visit_api_core_v1_secret(it);
+        },
+        crate::AnyResource::CoreV1Service(it) => {
+            v.visit_api_core_v1_service(it);
+        },
+        crate::AnyResource::CoreV1ServiceAccount(it) => {
+            v.visit_api_core_v1_service_account(it);
+        },
+        crate::AnyResource::DiscoveryV1EndpointSlice(it) => {
+            v.visit_api_discovery_v1_endpoint_slice(it);
+        },
+        crate::AnyResource::EventsV1Event(it) => {
+            v.visit_api_events_v1_event(it);
+        },
+        crate::AnyResource::FlowcontrolV1FlowSchema(it) => {
+            v.visit_api_flowcontrol_v1_flow_schema(it);
+        },
+        crate::AnyResource::FlowcontrolV1PriorityLevelConfiguration(it) => {
+            v.visit_api_flowcontrol_v1_priority_level_configuration(it);
+        },
+        crate::AnyResource::NetworkingV1Ingress(it) => {
+            v.visit_api_networking_v1_ingress(it);
+        },
+        crate::AnyResource::NetworkingV1IngressClass(it) => {
+            v.visit_api_networking_v1_ingress_class(it);
+        },
+        crate::AnyResource::NetworkingV1NetworkPolicy(it) => {
+            v.visit_api_networking_v1_network_policy(it);
+        },
+        crate::AnyResource::NetworkingV1beta1IPAddress(it) => {
+            v.visit_api_networking_v1beta1_ip_address(it);
+        },
+        crate::AnyResource::NetworkingV1beta1ServiceCIDR(it) => {
+            v.visit_api_networking_v1beta1_service_cidr(it);
+        },
+        crate::AnyResource::NodeV1RuntimeClass(it) => {
+            v.visit_api_node_v1_runtime_class(it);
+        },
+        crate::AnyResource::PolicyV1Eviction(it) => {
+            v.visit_api_policy_v1_eviction(it);
+        },
+        crate::AnyResource::PolicyV1PodDisruptionBudget(it) => {
+            v.visit_api_policy_v1_pod_disruption_budget(it);
+        },
+        crate::AnyResource::RbacV1ClusterRole(it) => {
+            v.visit_api_rbac_v1_cluster_role(it);
+        },
+        crate::AnyResource::RbacV1ClusterRoleBinding(it) => {
+            v.visit_api_rbac_v1_cluster_role_binding(it);
+        },
+        crate::AnyResource::RbacV1Role(it) => {
+            v.visit_api_rbac_v1_role(it);
+        },
+        crate::AnyResource::RbacV1RoleBinding(it) => {
+            v.visit_api_rbac_v1_role_binding(it);
+        },
+        crate::AnyResource::ResourceV1alpha3DeviceClass(it) => {
+            v.visit_api_resource_v1alpha3_device_class(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceClaim(it) => {
+            v.visit_api_resource_v1alpha3_resource_claim(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceClaimTemplate(it) => {
+            v.visit_api_resource_v1alpha3_resource_claim_template(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceSlice(it) => {
+            v.visit_api_resource_v1alpha3_resource_slice(it);
+        },
+        crate::AnyResource::ResourceV1beta1DeviceClass(it) => {
+            v.visit_api_resource_v1beta1_device_class(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceClaim(it) => {
+            v.visit_api_resource_v1beta1_resource_claim(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceClaimTemplate(it) => {
+            v.visit_api_resource_v1beta1_resource_claim_template(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceSlice(it) => {
+            v.visit_api_resource_v1beta1_resource_slice(it);
+        },
+        crate::AnyResource::SchedulingV1PriorityClass(it) => {
+            v.visit_api_scheduling_v1_priority_class(it);
+        },
+        crate::AnyResource::StorageV1CSIDriver(it) => {
+            v.visit_api_storage_v1_csi_driver(it);
+        },
+        crate::AnyResource::StorageV1CSINode(it) => {
+            v.visit_api_storage_v1_csi_node(it);
+        },
+        crate::AnyResource::StorageV1CSIStorageCapacity(it) => {
+            v.visit_api_storage_v1_csi_storage_capacity(it);
+        },
+        crate::AnyResource::StorageV1StorageClass(it) => {
+            v.visit_api_storage_v1_storage_class(it);
+        },
+        crate::AnyResource::StorageV1VolumeAttachment(it) => {
+            v.visit_api_storage_v1_volume_attachment(it);
+        },
+        crate::AnyResource::StorageV1alpha1VolumeAttributesClass(it) => {
+            v.visit_api_storage_v1alpha1_volume_attributes_class(it);
+        },
+        crate::AnyResource::StorageV1beta1VolumeAttributesClass(it) => {
+            v.visit_api_storage_v1beta1_volume_attributes_class(it);
+        },
+        crate::AnyResource::StoragemigrationV1alpha1StorageVersionMigration(it) => {
+            v.visit_api_storagemigration_v1alpha1_storage_version_migration(it);
+        },
+        crate::AnyResource::ApiextensionsV1CustomResourceDefinition(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition(it);
+        },
+        crate::AnyResource::ApiregistrationV1APIService(it) => {
+            v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service(it);
+        },
+        crate::AnyResource::Other(_) => (),
+    }
+}
+
+/// Visits the values contained in a value of type [`List`](crate::List).
+pub fn visit_list<'ast, V, T>(v: &mut V, node: &'ast crate::List<T>) where V: Visit<'ast> + ?Sized, T: crate::ListableResource + Visitable {
+    for it in &node.items {
+        Visitable::visit(it, v);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_list_meta(&node.metadata);
+}
+
+/// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1::AuditAnnotation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_audit_annotation<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::AuditAnnotation) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1::ExpressionWarning), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_expression_warning<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::ExpressionWarning) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_match_condition<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::MatchCondition) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1::MatchResources).
+pub fn visit_api_admissionregistration_v1_match_resources<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::MatchResources) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_named_rule_with_operations(it);
+        }
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_named_rule_with_operations(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingWebhook`](crate::api::admissionregistration::v1::MutatingWebhook).
+pub fn visit_api_admissionregistration_v1_mutating_webhook<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::MutatingWebhook) where V: Visit<'ast> + ?Sized {
+    v.visit_api_admissionregistration_v1_webhook_client_config(&node.client_config);
+    if let Some(it) = &node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition(it);
+        }
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_rule_with_operations(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingWebhookConfiguration`](crate::api::admissionregistration::v1::MutatingWebhookConfiguration).
+pub fn visit_api_admissionregistration_v1_mutating_webhook_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::MutatingWebhookConfiguration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.webhooks {
+        for it in it {
+            v.visit_api_admissionregistration_v1_mutating_webhook(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_named_rule_with_operations<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::NamedRuleWithOperations) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_param_kind<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::ParamKind) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1::ParamRef).
+pub fn visit_api_admissionregistration_v1_param_ref<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ParamRef) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits a value of type [`RuleWithOperations`](crate::api::admissionregistration::v1::RuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_rule_with_operations<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::RuleWithOperations) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ServiceReference`](crate::api::admissionregistration::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_service_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::ServiceReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TypeChecking`](crate::api::admissionregistration::v1::TypeChecking).
+pub fn visit_api_admissionregistration_v1_type_checking<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::TypeChecking) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.expression_warnings {
+        for it in it {
+            v.visit_api_admissionregistration_v1_expression_warning(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicy) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_binding<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_binding_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_resources {
+        v.visit_api_admissionregistration_v1_match_resources(it);
+    }
+    if let Some(it) = &node.param_ref {
+        v.visit_api_admissionregistration_v1_param_ref(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.audit_annotations {
+        for it in it {
+            v.visit_api_admissionregistration_v1_audit_annotation(it);
+        }
+    }
+    if let Some(it) = &node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition(it);
+        }
+    }
+    if let Some(it) = &node.match_constraints {
+        v.visit_api_admissionregistration_v1_match_resources(it);
+    }
+    if let Some(it) = &node.param_kind {
+        v.visit_api_admissionregistration_v1_param_kind(it);
+    }
+    if let Some(it) = &node.validations {
+        for it in it {
+            v.visit_api_admissionregistration_v1_validation(it);
+        }
+    }
+    if let Some(it) = &node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1_variable(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_status<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.type_checking {
+        v.visit_api_admissionregistration_v1_type_checking(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingWebhook`](crate::api::admissionregistration::v1::ValidatingWebhook).
+pub fn visit_api_admissionregistration_v1_validating_webhook<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingWebhook) where V: Visit<'ast> + ?Sized {
+    v.visit_api_admissionregistration_v1_webhook_client_config(&node.client_config);
+    if let Some(it) = &node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition(it);
+        }
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_rule_with_operations(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingWebhookConfiguration`](crate::api::admissionregistration::v1::ValidatingWebhookConfiguration).
+pub fn visit_api_admissionregistration_v1_validating_webhook_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::ValidatingWebhookConfiguration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.webhooks {
+        for it in it {
+            v.visit_api_admissionregistration_v1_validating_webhook(it);
+        }
+    }
+}
+
+/// Visits a value of type [`Validation`](crate::api::admissionregistration::v1::Validation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_validation<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::Validation) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_variable<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1::Variable) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WebhookClientConfig`](crate::api::admissionregistration::v1::WebhookClientConfig).
+pub fn visit_api_admissionregistration_v1_webhook_client_config<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1::WebhookClientConfig) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.service {
+        v.visit_api_admissionregistration_v1_service_reference(it);
+    }
+}
+
+/// Visits a value of type [`ApplyConfiguration`](crate::api::admissionregistration::v1alpha1::ApplyConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_apply_configuration<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::ApplyConfiguration) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`JSONPatch`](crate::api::admissionregistration::v1alpha1::JSONPatch), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_json_patch<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::JSONPatch) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1alpha1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_match_condition<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::MatchCondition) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1alpha1::MatchResources).
+pub fn visit_api_admissionregistration_v1alpha1_match_resources<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::MatchResources) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations(it);
+        }
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicy`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_resources {
+        v.visit_api_admissionregistration_v1alpha1_match_resources(it);
+    }
+    if let Some(it) = &node.param_ref {
+        v.visit_api_admissionregistration_v1alpha1_param_ref(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicySpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_match_condition(it);
+        }
+    }
+    if let Some(it) = &node.match_constraints {
+        v.visit_api_admissionregistration_v1alpha1_match_resources(it);
+    }
+    if let Some(it) = &node.mutations {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_mutation(it);
+        }
+    }
+    if let Some(it) = &node.param_kind {
+        v.visit_api_admissionregistration_v1alpha1_param_kind(it);
+    }
+    if let Some(it) = &node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_variable(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Mutation`](crate::api::admissionregistration::v1alpha1::Mutation).
+pub fn visit_api_admissionregistration_v1alpha1_mutation<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::Mutation) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.apply_configuration {
+        v.visit_api_admissionregistration_v1alpha1_apply_configuration(it);
+    }
+    if let Some(it) = &node.json_patch {
+        v.visit_api_admissionregistration_v1alpha1_json_patch(it);
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_named_rule_with_operations<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1alpha1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_param_kind<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::ParamKind) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1alpha1::ParamRef).
+pub fn visit_api_admissionregistration_v1alpha1_param_ref<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1alpha1::ParamRef) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1alpha1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_variable<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1alpha1::Variable) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1beta1::AuditAnnotation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_audit_annotation<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::AuditAnnotation) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1beta1::ExpressionWarning), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_expression_warning<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::ExpressionWarning) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1beta1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_match_condition<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::MatchCondition) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1beta1::MatchResources).
+pub fn visit_api_admissionregistration_v1beta1_match_resources<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::MatchResources) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_named_rule_with_operations(it);
+        }
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_named_rule_with_operations(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1beta1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_named_rule_with_operations<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::NamedRuleWithOperations) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1beta1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_param_kind<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::ParamKind) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1beta1::ParamRef).
+pub fn visit_api_admissionregistration_v1beta1_param_ref<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ParamRef) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TypeChecking`](crate::api::admissionregistration::v1beta1::TypeChecking).
+pub fn visit_api_admissionregistration_v1beta1_type_checking<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::TypeChecking) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.expression_warnings {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_expression_warning(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_resources {
+        v.visit_api_admissionregistration_v1beta1_match_resources(it);
+    }
+    if let Some(it) = &node.param_ref {
+        v.visit_api_admissionregistration_v1beta1_param_ref(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_spec<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.audit_annotations {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_audit_annotation(it);
+        }
+    }
+    if let Some(it) = &node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_match_condition(it);
+        }
+    }
+    if let Some(it) = &node.match_constraints {
+        v.visit_api_admissionregistration_v1beta1_match_resources(it);
+    }
+    if let Some(it) = &node.param_kind {
+        v.visit_api_admissionregistration_v1beta1_param_kind(it);
+    }
+    if let Some(it) = &node.validations {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_validation(it);
+        }
+    }
+    if let Some(it) = &node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_variable(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_status<'ast, V>(v: &mut V, node: &'ast crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.type_checking {
+        v.visit_api_admissionregistration_v1beta1_type_checking(it);
+    }
+}
+
+/// Visits a value of type [`Validation`](crate::api::admissionregistration::v1beta1::Validation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_validation<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::Validation) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1beta1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_variable<'ast, V>(_v: &mut V, _node: &'ast crate::api::admissionregistration::v1beta1::Variable) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ServerStorageVersion`](crate::api::apiserverinternal::v1alpha1::ServerStorageVersion), which does not contain values of other types that can be visited.
+pub fn visit_api_apiserverinternal_v1alpha1_server_storage_version<'ast, V>(_v: &mut V, _node: &'ast crate::api::apiserverinternal::v1alpha1::ServerStorageVersion) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageVersion`](crate::api::apiserverinternal::v1alpha1::StorageVersion).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version<'ast, V>(v: &mut V, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersion) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_apiserverinternal_v1alpha1_storage_version_spec(&node.spec);
+    v.visit_api_apiserverinternal_v1alpha1_storage_version_status(&node.status);
+}
+
+/// Visits the values contained in a value of type [`StorageVersionCondition`](crate::api::apiserverinternal::v1alpha1::StorageVersionCondition).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_condition<'ast, V>(v: &mut V, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`StorageVersionSpec`](crate::api::apiserverinternal::v1alpha1::StorageVersionSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageVersionStatus`](crate::api::apiserverinternal::v1alpha1::StorageVersionStatus).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_status<'ast, V>(v: &mut V, node: &'ast crate::api::apiserverinternal::v1alpha1::StorageVersionStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_apiserverinternal_v1alpha1_storage_version_condition(it);
+        }
+    }
+    if let Some(it) = &node.storage_versions {
+        for it in it {
+            v.visit_api_apiserverinternal_v1alpha1_server_storage_version(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ControllerRevision`](crate::api::apps::v1::ControllerRevision).
+pub fn visit_api_apps_v1_controller_revision<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::ControllerRevision) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits the values contained in a value of type [`DaemonSet`](crate::api::apps::v1::DaemonSet).
+pub fn visit_api_apps_v1_daemon_set<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DaemonSet) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_apps_v1_daemon_set_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_apps_v1_daemon_set_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetCondition`](crate::api::apps::v1::DaemonSetCondition).
+pub fn visit_api_apps_v1_daemon_set_condition<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DaemonSetCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetSpec`](crate::api::apps::v1::DaemonSetSpec).
+pub fn visit_api_apps_v1_daemon_set_spec<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DaemonSetSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector(&node.selector);
+    v.visit_api_core_v1_pod_template_spec(&node.template);
+    if let Some(it) = &node.update_strategy {
+        v.visit_api_apps_v1_daemon_set_update_strategy(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetStatus`](crate::api::apps::v1::DaemonSetStatus).
+pub fn visit_api_apps_v1_daemon_set_status<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DaemonSetStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_daemon_set_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetUpdateStrategy`](crate::api::apps::v1::DaemonSetUpdateStrategy).
+pub fn visit_api_apps_v1_daemon_set_update_strategy<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DaemonSetUpdateStrategy) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_daemon_set(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Deployment`](crate::api::apps::v1::Deployment).
+pub fn visit_api_apps_v1_deployment<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::Deployment) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_apps_v1_deployment_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_apps_v1_deployment_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentCondition`](crate::api::apps::v1::DeploymentCondition).
+pub fn visit_api_apps_v1_deployment_condition<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DeploymentCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentSpec`](crate::api::apps::v1::DeploymentSpec).
+pub fn visit_api_apps_v1_deployment_spec<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DeploymentSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector(&node.selector);
+    if let Some(it) = &node.strategy {
+        v.visit_api_apps_v1_deployment_strategy(it);
+    }
+    v.visit_api_core_v1_pod_template_spec(&node.template);
+}
+
+/// Visits the values contained in a value of type [`DeploymentStatus`](crate::api::apps::v1::DeploymentStatus).
+pub fn visit_api_apps_v1_deployment_status<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DeploymentStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_deployment_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentStrategy`](crate::api::apps::v1::DeploymentStrategy).
+pub fn visit_api_apps_v1_deployment_strategy<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::DeploymentStrategy) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_deployment(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSet`](crate::api::apps::v1::ReplicaSet).
+pub fn visit_api_apps_v1_replica_set<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::ReplicaSet) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_apps_v1_replica_set_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_apps_v1_replica_set_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetCondition`](crate::api::apps::v1::ReplicaSetCondition).
+pub fn visit_api_apps_v1_replica_set_condition<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::ReplicaSetCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetSpec`](crate::api::apps::v1::ReplicaSetSpec).
+pub fn visit_api_apps_v1_replica_set_spec<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::ReplicaSetSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector(&node.selector);
+    if let Some(it) = &node.template {
+        v.visit_api_core_v1_pod_template_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetStatus`](crate::api::apps::v1::ReplicaSetStatus).
+pub fn visit_api_apps_v1_replica_set_status<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::ReplicaSetStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_replica_set_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateDaemonSet`](crate::api::apps::v1::RollingUpdateDaemonSet).
+pub fn visit_api_apps_v1_rolling_update_daemon_set<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::RollingUpdateDaemonSet) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.max_surge {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+    if let Some(it) = &node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateDeployment`](crate::api::apps::v1::RollingUpdateDeployment).
+pub fn visit_api_apps_v1_rolling_update_deployment<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::RollingUpdateDeployment) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.max_surge {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+    if let Some(it) = &node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateStatefulSetStrategy`](crate::api::apps::v1::RollingUpdateStatefulSetStrategy).
+pub fn visit_api_apps_v1_rolling_update_stateful_set_strategy<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::RollingUpdateStatefulSetStrategy) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSet`](crate::api::apps::v1::StatefulSet).
+pub fn visit_api_apps_v1_stateful_set<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::StatefulSet) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_apps_v1_stateful_set_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_apps_v1_stateful_set_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetCondition`](crate::api::apps::v1::StatefulSetCondition).
+pub fn visit_api_apps_v1_stateful_set_condition<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::StatefulSetCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`StatefulSetOrdinals`](crate::api::apps::v1::StatefulSetOrdinals), which does not contain values of other types that can be visited.
+pub fn visit_api_apps_v1_stateful_set_ordinals<'ast, V>(_v: &mut V, _node: &'ast crate::api::apps::v1::StatefulSetOrdinals) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`StatefulSetPersistentVolumeClaimRetentionPolicy`](crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy<'ast, V>(_v: &mut V, _node: &'ast crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StatefulSetSpec`](crate::api::apps::v1::StatefulSetSpec).
+pub fn visit_api_apps_v1_stateful_set_spec<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::StatefulSetSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ordinals {
+        v.visit_api_apps_v1_stateful_set_ordinals(it);
+    }
+    if let Some(it) = &node.persistent_volume_claim_retention_policy {
+        v.visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector(&node.selector);
+    v.visit_api_core_v1_pod_template_spec(&node.template);
+    if let Some(it) = &node.update_strategy {
+        v.visit_api_apps_v1_stateful_set_update_strategy(it);
+    }
+    if let Some(it) = &node.volume_claim_templates {
+        for it in it {
+            v.visit_api_core_v1_persistent_volume_claim(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetStatus`](crate::api::apps::v1::StatefulSetStatus).
+pub fn visit_api_apps_v1_stateful_set_status<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::StatefulSetStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_stateful_set_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetUpdateStrategy`](crate::api::apps::v1::StatefulSetUpdateStrategy).
+pub fn visit_api_apps_v1_stateful_set_update_strategy<'ast, V>(v: &mut V, node: &'ast crate::api::apps::v1::StatefulSetUpdateStrategy) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_stateful_set_strategy(it);
+    }
+}
+
+/// Visits a value of type [`BoundObjectReference`](crate::api::authentication::v1::BoundObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_bound_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::authentication::v1::BoundObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReview`](crate::api::authentication::v1::SelfSubjectReview).
+pub fn visit_api_authentication_v1_self_subject_review<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::SelfSubjectReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.status {
+        v.visit_api_authentication_v1_self_subject_review_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1::SelfSubjectReviewStatus).
+pub fn visit_api_authentication_v1_self_subject_review_status<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::SelfSubjectReviewStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.user_info {
+        v.visit_api_authentication_v1_user_info(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequest`](crate::api::authentication::v1::TokenRequest).
+pub fn visit_api_authentication_v1_token_request<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::TokenRequest) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authentication_v1_token_request_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authentication_v1_token_request_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequestSpec`](crate::api::authentication::v1::TokenRequestSpec).
+pub fn visit_api_authentication_v1_token_request_spec<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::TokenRequestSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.bound_object_ref {
+        v.visit_api_authentication_v1_bound_object_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequestStatus`](crate::api::authentication::v1::TokenRequestStatus).
+pub fn visit_api_authentication_v1_token_request_status<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::TokenRequestStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_time(&node.expiration_timestamp);
+}
+
+/// Visits the values contained in a value of type [`TokenReview`](crate::api::authentication::v1::TokenReview).
+pub fn visit_api_authentication_v1_token_review<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::TokenReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authentication_v1_token_review_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authentication_v1_token_review_status(it);
+    }
+}
+
+/// Visits a value of type [`TokenReviewSpec`](crate::api::authentication::v1::TokenReviewSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_token_review_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::authentication::v1::TokenReviewSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TokenReviewStatus`](crate::api::authentication::v1::TokenReviewStatus).
+pub fn visit_api_authentication_v1_token_review_status<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1::TokenReviewStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.user {
+        v.visit_api_authentication_v1_user_info(it);
+    }
+}
+
+/// Visits a value of type [`UserInfo`](crate::api::authentication::v1::UserInfo), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_user_info<'ast, V>(_v: &mut V, _node: &'ast crate::api::authentication::v1::UserInfo) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReview`](crate::api::authentication::v1beta1::SelfSubjectReview).
+pub fn visit_api_authentication_v1beta1_self_subject_review<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1beta1::SelfSubjectReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.status {
+        v.visit_api_authentication_v1beta1_self_subject_review_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1beta1::SelfSubjectReviewStatus).
+pub fn visit_api_authentication_v1beta1_self_subject_review_status<'ast, V>(v: &mut V, node: &'ast crate::api::authentication::v1beta1::SelfSubjectReviewStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.user_info {
+        v.visit_api_authentication_v1_user_info(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FieldSelectorAttributes`](crate::api::authorization::v1::FieldSelectorAttributes).
+pub fn visit_api_authorization_v1_field_selector_attributes<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::FieldSelectorAttributes) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.requirements {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LabelSelectorAttributes`](crate::api::authorization::v1::LabelSelectorAttributes).
+pub fn visit_api_authorization_v1_label_selector_attributes<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::LabelSelectorAttributes) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.requirements {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LocalSubjectAccessReview`](crate::api::authorization::v1::LocalSubjectAccessReview).
+pub fn visit_api_authorization_v1_local_subject_access_review<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::LocalSubjectAccessReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authorization_v1_subject_access_review_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authorization_v1_subject_access_review_status(it);
+    }
+}
+
+/// Visits a value of type [`NonResourceAttributes`](crate::api::authorization::v1::NonResourceAttributes), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_non_resource_attributes<'ast, V>(_v: &mut V, _node: &'ast crate::api::authorization::v1::NonResourceAttributes) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`NonResourceRule`](crate::api::authorization::v1::NonResourceRule), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_non_resource_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::authorization::v1::NonResourceRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceAttributes`](crate::api::authorization::v1::ResourceAttributes).
+pub fn visit_api_authorization_v1_resource_attributes<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::ResourceAttributes) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.field_selector {
+        v.visit_api_authorization_v1_field_selector_attributes(it);
+    }
+    if let Some(it) = &node.label_selector {
+        v.visit_api_authorization_v1_label_selector_attributes(it);
+    }
+}
+
+/// Visits a value of type [`ResourceRule`](crate::api::authorization::v1::ResourceRule), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_resource_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::authorization::v1::ResourceRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectAccessReview`](crate::api::authorization::v1::SelfSubjectAccessReview).
+pub fn visit_api_authorization_v1_self_subject_access_review<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SelfSubjectAccessReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authorization_v1_self_subject_access_review_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authorization_v1_subject_access_review_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectAccessReviewSpec`](crate::api::authorization::v1::SelfSubjectAccessReviewSpec).
+pub fn visit_api_authorization_v1_self_subject_access_review_spec<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SelfSubjectAccessReviewSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.non_resource_attributes {
+        v.visit_api_authorization_v1_non_resource_attributes(it);
+    }
+    if let Some(it) = &node.resource_attributes {
+        v.visit_api_authorization_v1_resource_attributes(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectRulesReview`](crate::api::authorization::v1::SelfSubjectRulesReview).
+pub fn visit_api_authorization_v1_self_subject_rules_review<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SelfSubjectRulesReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authorization_v1_self_subject_rules_review_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authorization_v1_subject_rules_review_status(it);
+    }
+}
+
+/// Visits a value of type [`SelfSubjectRulesReviewSpec`](crate::api::authorization::v1::SelfSubjectRulesReviewSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_self_subject_rules_review_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::authorization::v1::SelfSubjectRulesReviewSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SubjectAccessReview`](crate::api::authorization::v1::SubjectAccessReview).
+pub fn visit_api_authorization_v1_subject_access_review<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SubjectAccessReview) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_authorization_v1_subject_access_review_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_authorization_v1_subject_access_review_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SubjectAccessReviewSpec`](crate::api::authorization::v1::SubjectAccessReviewSpec).
+pub fn visit_api_authorization_v1_subject_access_review_spec<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SubjectAccessReviewSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.non_resource_attributes {
+        v.visit_api_authorization_v1_non_resource_attributes(it);
+    }
+    if let Some(it) = &node.resource_attributes {
+        v.visit_api_authorization_v1_resource_attributes(it);
+    }
+}
+
+/// Visits a value of type [`SubjectAccessReviewStatus`](crate::api::authorization::v1::SubjectAccessReviewStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_subject_access_review_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::authorization::v1::SubjectAccessReviewStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SubjectRulesReviewStatus`](crate::api::authorization::v1::SubjectRulesReviewStatus).
+pub fn visit_api_authorization_v1_subject_rules_review_status<'ast, V>(v: &mut V, node: &'ast crate::api::authorization::v1::SubjectRulesReviewStatus) where V: Visit<'ast> + ?Sized {
+    for it in &node.non_resource_rules {
+        v.visit_api_authorization_v1_non_resource_rule(it);
+    }
+    for it in &node.resource_rules {
+        v.visit_api_authorization_v1_resource_rule(it);
+    }
+}
+
+/// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v1::CrossVersionObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_cross_version_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::autoscaling::v1::CrossVersionObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v1::HorizontalPodAutoscaler).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscaler) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v1_cross_version_object_reference(&node.scale_target_ref);
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_scale_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Scale`](crate::api::autoscaling::v1::Scale).
+pub fn visit_api_autoscaling_v1_scale<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v1::Scale) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_autoscaling_v1_scale_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_autoscaling_v1_scale_status(it);
+    }
+}
+
+/// Visits a value of type [`ScaleSpec`](crate::api::autoscaling::v1::ScaleSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_scale_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::autoscaling::v1::ScaleSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ScaleStatus`](crate::api::autoscaling::v1::ScaleStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_scale_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::autoscaling::v1::ScaleStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerResourceMetricSource`](crate::api::autoscaling::v2::ContainerResourceMetricSource).
+pub fn visit_api_autoscaling_v2_container_resource_metric_source<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ContainerResourceMetricSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_target(&node.target);
+}
+
+/// Visits the values contained in a value of type [`ContainerResourceMetricStatus`](crate::api::autoscaling::v2::ContainerResourceMetricStatus).
+pub fn visit_api_autoscaling_v2_container_resource_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ContainerResourceMetricStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status(&node.current);
+}
+
+/// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v2::CrossVersionObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v2_cross_version_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::autoscaling::v2::CrossVersionObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ExternalMetricSource`](crate::api::autoscaling::v2::ExternalMetricSource).
+pub fn visit_api_autoscaling_v2_external_metric_source<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ExternalMetricSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+    v.visit_api_autoscaling_v2_metric_target(&node.target);
+}
+
+/// Visits the values contained in a value of type [`ExternalMetricStatus`](crate::api::autoscaling::v2::ExternalMetricStatus).
+pub fn visit_api_autoscaling_v2_external_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ExternalMetricStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status(&node.current);
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+}
+
+/// Visits a value of type [`HPAScalingPolicy`](crate::api::autoscaling::v2::HPAScalingPolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v2_hpa_scaling_policy<'ast, V>(_v: &mut V, _node: &'ast crate::api::autoscaling::v2::HPAScalingPolicy) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HPAScalingRules`](crate::api::autoscaling::v2::HPAScalingRules).
+pub fn visit_api_autoscaling_v2_hpa_scaling_rules<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HPAScalingRules) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.policies {
+        for it in it {
+            v.visit_api_autoscaling_v2_hpa_scaling_policy(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v2::HorizontalPodAutoscaler).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscaler) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerBehavior`](crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.scale_down {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules(it);
+    }
+    if let Some(it) = &node.scale_up {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerCondition`](crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.behavior {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior(it);
+    }
+    if let Some(it) = &node.metrics {
+        for it in it {
+            v.visit_api_autoscaling_v2_metric_spec(it);
+        }
+    }
+    v.visit_api_autoscaling_v2_cross_version_object_reference(&node.scale_target_ref);
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition(it);
+        }
+    }
+    if let Some(it) = &node.current_metrics {
+        for it in it {
+            v.visit_api_autoscaling_v2_metric_status(it);
+        }
+    }
+    if let Some(it) = &node.last_scale_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricIdentifier`](crate::api::autoscaling::v2::MetricIdentifier).
+pub fn visit_api_autoscaling_v2_metric_identifier<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::MetricIdentifier) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricSpec`](crate::api::autoscaling::v2::MetricSpec).
+pub fn visit_api_autoscaling_v2_metric_spec<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::MetricSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.container_resource {
+        v.visit_api_autoscaling_v2_container_resource_metric_source(it);
+    }
+    if let Some(it) = &node.external {
+        v.visit_api_autoscaling_v2_external_metric_source(it);
+    }
+    if let Some(it) = &node.object {
+        v.visit_api_autoscaling_v2_object_metric_source(it);
+    }
+    if let Some(it) = &node.pods {
+        v.visit_api_autoscaling_v2_pods_metric_source(it);
+    }
+    if let Some(it) = &node.resource {
+        v.visit_api_autoscaling_v2_resource_metric_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricStatus`](crate::api::autoscaling::v2::MetricStatus).
+pub fn visit_api_autoscaling_v2_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::MetricStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.container_resource {
+        v.visit_api_autoscaling_v2_container_resource_metric_status(it);
+    }
+    if let Some(it) = &node.external {
+        v.visit_api_autoscaling_v2_external_metric_status(it);
+    }
+    if let Some(it) = &node.object {
+        v.visit_api_autoscaling_v2_object_metric_status(it);
+    }
+    if let Some(it) = &node.pods {
+        v.visit_api_autoscaling_v2_pods_metric_status(it);
+    }
+    if let Some(it) = &node.resource {
+        v.visit_api_autoscaling_v2_resource_metric_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricTarget`](crate::api::autoscaling::v2::MetricTarget).
+pub fn visit_api_autoscaling_v2_metric_target<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::MetricTarget) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.average_value {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+    if let Some(it) = &node.value {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricValueStatus`](crate::api::autoscaling::v2::MetricValueStatus).
+pub fn visit_api_autoscaling_v2_metric_value_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::MetricValueStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.average_value {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+    if let Some(it) = &node.value {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ObjectMetricSource`](crate::api::autoscaling::v2::ObjectMetricSource).
+pub fn visit_api_autoscaling_v2_object_metric_source<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ObjectMetricSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_cross_version_object_reference(&node.described_object);
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+    v.visit_api_autoscaling_v2_metric_target(&node.target);
+}
+
+/// Visits the values contained in a value of type [`ObjectMetricStatus`](crate::api::autoscaling::v2::ObjectMetricStatus).
+pub fn visit_api_autoscaling_v2_object_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ObjectMetricStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status(&node.current);
+    v.visit_api_autoscaling_v2_cross_version_object_reference(&node.described_object);
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+}
+
+/// Visits the values contained in a value of type [`PodsMetricSource`](crate::api::autoscaling::v2::PodsMetricSource).
+pub fn visit_api_autoscaling_v2_pods_metric_source<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::PodsMetricSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+    v.visit_api_autoscaling_v2_metric_target(&node.target);
+}
+
+/// Visits the values contained in a value of type [`PodsMetricStatus`](crate::api::autoscaling::v2::PodsMetricStatus).
+pub fn visit_api_autoscaling_v2_pods_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::PodsMetricStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status(&node.current);
+    v.visit_api_autoscaling_v2_metric_identifier(&node.metric);
+}
+
+/// Visits the values contained in a value of type [`ResourceMetricSource`](crate::api::autoscaling::v2::ResourceMetricSource).
+pub fn visit_api_autoscaling_v2_resource_metric_source<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ResourceMetricSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_target(&node.target);
+}
+
+/// Visits the values contained in a value of type [`ResourceMetricStatus`](crate::api::autoscaling::v2::ResourceMetricStatus).
+pub fn visit_api_autoscaling_v2_resource_metric_status<'ast, V>(v: &mut V, node: &'ast crate::api::autoscaling::v2::ResourceMetricStatus) where V: Visit<'ast> + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status(&node.current);
+}
+
+/// Visits the values contained in a value of type [`CronJob`](crate::api::batch::v1::CronJob).
+pub fn visit_api_batch_v1_cron_job<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::CronJob) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_batch_v1_cron_job_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_batch_v1_cron_job_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CronJobSpec`](crate::api::batch::v1::CronJobSpec).
+pub fn visit_api_batch_v1_cron_job_spec<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::CronJobSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_api_batch_v1_job_template_spec(&node.job_template);
+}
+
+/// Visits the values contained in a value of type [`CronJobStatus`](crate::api::batch::v1::CronJobStatus).
+pub fn visit_api_batch_v1_cron_job_status<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::CronJobStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.active {
+        for it in it {
+            v.visit_api_core_v1_object_reference(it);
+        }
+    }
+    if let Some(it) = &node.last_schedule_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_successful_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Job`](crate::api::batch::v1::Job).
+pub fn visit_api_batch_v1_job<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::Job) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_batch_v1_job_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_batch_v1_job_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobCondition`](crate::api::batch::v1::JobCondition).
+pub fn visit_api_batch_v1_job_condition<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::JobCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobSpec`](crate::api::batch::v1::JobSpec).
+pub fn visit_api_batch_v1_job_spec<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::JobSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.pod_failure_policy {
+        v.visit_api_batch_v1_pod_failure_policy(it);
+    }
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.success_policy {
+        v.visit_api_batch_v1_success_policy(it);
+    }
+    v.visit_api_core_v1_pod_template_spec(&node.template);
+}
+
+/// Visits the values contained in a value of type [`JobStatus`](crate::api::batch::v1::JobStatus).
+pub fn visit_api_batch_v1_job_status<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::JobStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.completion_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_batch_v1_job_condition(it);
+        }
+    }
+    if let Some(it) = &node.start_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.uncounted_terminated_pods {
+        v.visit_api_batch_v1_uncounted_terminated_pods(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobTemplateSpec`](crate::api::batch::v1::JobTemplateSpec).
+pub fn visit_api_batch_v1_job_template_spec<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::JobTemplateSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(it);
+    }
+    if let Some(it) = &node.spec {
+        v.visit_api_batch_v1_job_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodFailurePolicy`](crate::api::batch::v1::PodFailurePolicy).
+pub fn visit_api_batch_v1_pod_failure_policy<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::PodFailurePolicy) where V: Visit<'ast> + ?Sized {
+    for it in &node.rules {
+        v.visit_api_batch_v1_pod_failure_policy_rule(it);
+    }
+}
+
+/// Visits a value of type [`PodFailurePolicyOnExitCodesRequirement`](crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodFailurePolicyOnPodConditionsPattern`](crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern<'ast, V>(_v: &mut V, _node: &'ast crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PodFailurePolicyRule`](crate::api::batch::v1::PodFailurePolicyRule).
+pub fn visit_api_batch_v1_pod_failure_policy_rule<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::PodFailurePolicyRule) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.on_exit_codes {
+        v.visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement(it);
+    }
+    if let Some(it) = &node.on_pod_conditions {
+        for it in it {
+            v.visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`SuccessPolicy`](crate::api::batch::v1::SuccessPolicy).
+pub fn visit_api_batch_v1_success_policy<'ast, V>(v: &mut V, node: &'ast crate::api::batch::v1::SuccessPolicy) where V: Visit<'ast> + ?Sized {
+    for it in &node.rules {
+        v.visit_api_batch_v1_success_policy_rule(it);
+    }
+}
+
+/// Visits a value of type [`SuccessPolicyRule`](crate::api::batch::v1::SuccessPolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_success_policy_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::batch::v1::SuccessPolicyRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`UncountedTerminatedPods`](crate::api::batch::v1::UncountedTerminatedPods), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_uncounted_terminated_pods<'ast, V>(_v: &mut V, _node: &'ast crate::api::batch::v1::UncountedTerminatedPods) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequest`](crate::api::certificates::v1::CertificateSigningRequest).
+pub fn visit_api_certificates_v1_certificate_signing_request<'ast, V>(v: &mut V, node: &'ast crate::api::certificates::v1::CertificateSigningRequest) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_certificates_v1_certificate_signing_request_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_certificates_v1_certificate_signing_request_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequestCondition`](crate::api::certificates::v1::CertificateSigningRequestCondition).
+pub fn visit_api_certificates_v1_certificate_signing_request_condition<'ast, V>(v: &mut V, node: &'ast crate::api::certificates::v1::CertificateSigningRequestCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`CertificateSigningRequestSpec`](crate::api::certificates::v1::CertificateSigningRequestSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_certificates_v1_certificate_signing_request_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::certificates::v1::CertificateSigningRequestSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequestStatus`](crate::api::certificates::v1::CertificateSigningRequestStatus).
+pub fn visit_api_certificates_v1_certificate_signing_request_status<'ast, V>(v: &mut V, node: &'ast crate::api::certificates::v1::CertificateSigningRequestStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_certificates_v1_certificate_signing_request_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterTrustBundle`](crate::api::certificates::v1alpha1::ClusterTrustBundle).
+pub fn visit_api_certificates_v1alpha1_cluster_trust_bundle<'ast, V>(v: &mut V, node: &'ast crate::api::certificates::v1alpha1::ClusterTrustBundle) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_certificates_v1alpha1_cluster_trust_bundle_spec(&node.spec);
+}
+
+/// Visits a value of type [`ClusterTrustBundleSpec`](crate::api::certificates::v1alpha1::ClusterTrustBundleSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_certificates_v1alpha1_cluster_trust_bundle_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::certificates::v1alpha1::ClusterTrustBundleSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Lease`](crate::api::coordination::v1::Lease).
+pub fn visit_api_coordination_v1_lease<'ast, V>(v: &mut V, node: &'ast crate::api::coordination::v1::Lease) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_coordination_v1_lease_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseSpec`](crate::api::coordination::v1::LeaseSpec).
+pub fn visit_api_coordination_v1_lease_spec<'ast, V>(v: &mut V, node: &'ast crate::api::coordination::v1::LeaseSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.acquire_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+    if let Some(it) = &node.renew_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseCandidate`](crate::api::coordination::v1alpha2::LeaseCandidate).
+pub fn visit_api_coordination_v1alpha2_lease_candidate<'ast, V>(v: &mut V, node: &'ast crate::api::coordination::v1alpha2::LeaseCandidate) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_coordination_v1alpha2_lease_candidate_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseCandidateSpec`](crate::api::coordination::v1alpha2::LeaseCandidateSpec).
+pub fn visit_api_coordination_v1alpha2_lease_candidate_spec<'ast, V>(v: &mut V, node: &'ast crate::api::coordination::v1alpha2::LeaseCandidateSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ping_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+    if let Some(it) = &node.renew_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+}
+
+/// Visits a value of type [`AWSElasticBlockStoreVolumeSource`](crate::api::core::v1::AWSElasticBlockStoreVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_aws_elastic_block_store_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AWSElasticBlockStoreVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Affinity`](crate::api::core::v1::Affinity).
+pub fn visit_api_core_v1_affinity<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Affinity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.node_affinity {
+        v.visit_api_core_v1_node_affinity(it);
+    }
+    if let Some(it) = &node.pod_affinity {
+        v.visit_api_core_v1_pod_affinity(it);
+    }
+    if let Some(it) = &node.pod_anti_affinity {
+        v.visit_api_core_v1_pod_anti_affinity(it);
+    }
+}
+
+/// Visits a value of type [`AppArmorProfile`](crate::api::core::v1::AppArmorProfile), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_app_armor_profile<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AppArmorProfile) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`AttachedVolume`](crate::api::core::v1::AttachedVolume), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_attached_volume<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AttachedVolume) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`AzureDiskVolumeSource`](crate::api::core::v1::AzureDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_disk_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AzureDiskVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`AzureFilePersistentVolumeSource`](crate::api::core::v1::AzureFilePersistentVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_file_persistent_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AzureFilePersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`AzureFileVolumeSource`](crate::api::core::v1::AzureFileVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_file_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::AzureFileVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Binding`](crate::api::core::v1::Binding).
+pub fn visit_api_core_v1_binding<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Binding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_core_v1_object_reference(&node.target);
+}
+
+/// Visits the values contained in a value of type [`CSIPersistentVolumeSource`](crate::api::core::v1::CSIPersistentVolumeSource).
+pub fn visit_api_core_v1_csi_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CSIPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.controller_expand_secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+    if let Some(it) = &node.controller_publish_secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+    if let Some(it) = &node.node_expand_secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+    if let Some(it) = &node.node_publish_secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+    if let Some(it) = &node.node_stage_secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSIVolumeSource`](crate::api::core::v1::CSIVolumeSource).
+pub fn visit_api_core_v1_csi_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CSIVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.node_publish_secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`Capabilities`](crate::api::core::v1::Capabilities), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_capabilities<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::Capabilities) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CephFSPersistentVolumeSource`](crate::api::core::v1::CephFSPersistentVolumeSource).
+pub fn visit_api_core_v1_ceph_fs_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CephFSPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CephFSVolumeSource`](crate::api::core::v1::CephFSVolumeSource).
+pub fn visit_api_core_v1_ceph_fs_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CephFSVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CinderPersistentVolumeSource`](crate::api::core::v1::CinderPersistentVolumeSource).
+pub fn visit_api_core_v1_cinder_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CinderPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CinderVolumeSource`](crate::api::core::v1::CinderVolumeSource).
+pub fn visit_api_core_v1_cinder_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::CinderVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`ClientIPConfig`](crate::api::core::v1::ClientIPConfig), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_client_ip_config<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ClientIPConfig) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ClusterTrustBundleProjection`](crate::api::core::v1::ClusterTrustBundleProjection).
+pub fn visit_api_core_v1_cluster_trust_bundle_projection<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ClusterTrustBundleProjection) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits a value of type [`ComponentCondition`](crate::api::core::v1::ComponentCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_component_condition<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ComponentCondition) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ComponentStatus`](crate::api::core::v1::ComponentStatus).
+pub fn visit_api_core_v1_component_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ComponentStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_component_condition(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits the values contained in a value of type [`ConfigMap`](crate::api::core::v1::ConfigMap).
+pub fn visit_api_core_v1_config_map<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ConfigMap) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits a value of type [`ConfigMapEnvSource`](crate::api::core::v1::ConfigMapEnvSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_env_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ConfigMapEnvSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ConfigMapKeySelector`](crate::api::core::v1::ConfigMapKeySelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_key_selector<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ConfigMapKeySelector) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ConfigMapNodeConfigSource`](crate::api::core::v1::ConfigMapNodeConfigSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_node_config_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ConfigMapNodeConfigSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ConfigMapProjection`](crate::api::core::v1::ConfigMapProjection).
+pub fn visit_api_core_v1_config_map_projection<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ConfigMapProjection) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ConfigMapVolumeSource`](crate::api::core::v1::ConfigMapVolumeSource).
+pub fn visit_api_core_v1_config_map_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ConfigMapVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Container`](crate::api::core::v1::Container).
+pub fn visit_api_core_v1_container<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Container) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.env {
+        for it in it {
+            v.visit_api_core_v1_env_var(it);
+        }
+    }
+    if let Some(it) = &node.env_from {
+        for it in it {
+            v.visit_api_core_v1_env_from_source(it);
+        }
+    }
+    if let Some(it) = &node.lifecycle {
+        v.visit_api_core_v1_lifecycle(it);
+    }
+    if let Some(it) = &node.liveness_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_core_v1_container_port(it);
+        }
+    }
+    if let Some(it) = &node.readiness_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.resize_policy {
+        for it in it {
+            v.visit_api_core_v1_container_resize_policy(it);
+        }
+    }
+    if let Some(it) = &node.resources {
+        v.visit_api_core_v1_resource_requirements(it);
+    }
+    if let Some(it) = &node.security_context {
+        v.visit_api_core_v1_security_context(it);
+    }
+    if let Some(it) = &node.startup_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.volume_devices {
+        for it in it {
+            v.visit_api_core_v1_volume_device(it);
+        }
+    }
+    if let Some(it) = &node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ContainerImage`](crate::api::core::v1::ContainerImage), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_image<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ContainerImage) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ContainerPort`](crate::api::core::v1::ContainerPort), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_port<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ContainerPort) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ContainerResizePolicy`](crate::api::core::v1::ContainerResizePolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_resize_policy<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ContainerResizePolicy) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerState`](crate::api::core::v1::ContainerState).
+pub fn visit_api_core_v1_container_state<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ContainerState) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.running {
+        v.visit_api_core_v1_container_state_running(it);
+    }
+    if let Some(it) = &node.terminated {
+        v.visit_api_core_v1_container_state_terminated(it);
+    }
+    if let Some(it) = &node.waiting {
+        v.visit_api_core_v1_container_state_waiting(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerStateRunning`](crate::api::core::v1::ContainerStateRunning).
+pub fn visit_api_core_v1_container_state_running<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ContainerStateRunning) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.started_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerStateTerminated`](crate::api::core::v1::ContainerStateTerminated).
+pub fn visit_api_core_v1_container_state_terminated<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ContainerStateTerminated) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.finished_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.started_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`ContainerStateWaiting`](crate::api::core::v1::ContainerStateWaiting), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_state_waiting<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ContainerStateWaiting) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerStatus`](crate::api::core::v1::ContainerStatus).
+pub fn visit_api_core_v1_container_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ContainerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allocated_resources {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.allocated_resources_status {
+        for it in it {
+            v.visit_api_core_v1_resource_status(it);
+        }
+    }
+    if let Some(it) = &node.last_state {
+        v.visit_api_core_v1_container_state(it);
+    }
+    if let Some(it) = &node.resources {
+        v.visit_api_core_v1_resource_requirements(it);
+    }
+    if let Some(it) = &node.state {
+        v.visit_api_core_v1_container_state(it);
+    }
+    if let Some(it) = &node.user {
+        v.visit_api_core_v1_container_user(it);
+    }
+    if let Some(it) = &node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount_status(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerUser`](crate::api::core::v1::ContainerUser).
+pub fn visit_api_core_v1_container_user<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ContainerUser) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.linux {
+        v.visit_api_core_v1_linux_container_user(it);
+    }
+}
+
+/// Visits a value of type [`DaemonEndpoint`](crate::api::core::v1::DaemonEndpoint), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_daemon_endpoint<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::DaemonEndpoint) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIProjection`](crate::api::core::v1::DownwardAPIProjection).
+pub fn visit_api_core_v1_downward_api_projection<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::DownwardAPIProjection) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_downward_api_volume_file(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIVolumeFile`](crate::api::core::v1::DownwardAPIVolumeFile).
+pub fn visit_api_core_v1_downward_api_volume_file<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::DownwardAPIVolumeFile) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.field_ref {
+        v.visit_api_core_v1_object_field_selector(it);
+    }
+    if let Some(it) = &node.resource_field_ref {
+        v.visit_api_core_v1_resource_field_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIVolumeSource`](crate::api::core::v1::DownwardAPIVolumeSource).
+pub fn visit_api_core_v1_downward_api_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::DownwardAPIVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_downward_api_volume_file(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EmptyDirVolumeSource`](crate::api::core::v1::EmptyDirVolumeSource).
+pub fn visit_api_core_v1_empty_dir_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EmptyDirVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.size_limit {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EndpointAddress`](crate::api::core::v1::EndpointAddress).
+pub fn visit_api_core_v1_endpoint_address<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EndpointAddress) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.target_ref {
+        v.visit_api_core_v1_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`EndpointPort`](crate::api::core::v1::EndpointPort), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_endpoint_port<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::EndpointPort) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointSubset`](crate::api::core::v1::EndpointSubset).
+pub fn visit_api_core_v1_endpoint_subset<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EndpointSubset) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.addresses {
+        for it in it {
+            v.visit_api_core_v1_endpoint_address(it);
+        }
+    }
+    if let Some(it) = &node.not_ready_addresses {
+        for it in it {
+            v.visit_api_core_v1_endpoint_address(it);
+        }
+    }
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_core_v1_endpoint_port(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Endpoints`](crate::api::core::v1::Endpoints).
+pub fn visit_api_core_v1_endpoints<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Endpoints) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.subsets {
+        for it in it {
+            v.visit_api_core_v1_endpoint_subset(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvFromSource`](crate::api::core::v1::EnvFromSource).
+pub fn visit_api_core_v1_env_from_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EnvFromSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config_map_ref {
+        v.visit_api_core_v1_config_map_env_source(it);
+    }
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_env_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvVar`](crate::api::core::v1::EnvVar).
+pub fn visit_api_core_v1_env_var<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EnvVar) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.value_from {
+        v.visit_api_core_v1_env_var_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvVarSource`](crate::api::core::v1::EnvVarSource).
+pub fn visit_api_core_v1_env_var_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EnvVarSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config_map_key_ref {
+        v.visit_api_core_v1_config_map_key_selector(it);
+    }
+    if let Some(it) = &node.field_ref {
+        v.visit_api_core_v1_object_field_selector(it);
+    }
+    if let Some(it) = &node.resource_field_ref {
+        v.visit_api_core_v1_resource_field_selector(it);
+    }
+    if let Some(it) = &node.secret_key_ref {
+        v.visit_api_core_v1_secret_key_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EphemeralContainer`](crate::api::core::v1::EphemeralContainer).
+pub fn visit_api_core_v1_ephemeral_container<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EphemeralContainer) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.env {
+        for it in it {
+            v.visit_api_core_v1_env_var(it);
+        }
+    }
+    if let Some(it) = &node.env_from {
+        for it in it {
+            v.visit_api_core_v1_env_from_source(it);
+        }
+    }
+    if let Some(it) = &node.lifecycle {
+        v.visit_api_core_v1_lifecycle(it);
+    }
+    if let Some(it) = &node.liveness_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_core_v1_container_port(it);
+        }
+    }
+    if let Some(it) = &node.readiness_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.resize_policy {
+        for it in it {
+            v.visit_api_core_v1_container_resize_policy(it);
+        }
+    }
+    if let Some(it) = &node.resources {
+        v.visit_api_core_v1_resource_requirements(it);
+    }
+    if let Some(it) = &node.security_context {
+        v.visit_api_core_v1_security_context(it);
+    }
+    if let Some(it) = &node.startup_probe {
+        v.visit_api_core_v1_probe(it);
+    }
+    if let Some(it) = &node.volume_devices {
+        for it in it {
+            v.visit_api_core_v1_volume_device(it);
+        }
+    }
+    if let Some(it) = &node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EphemeralVolumeSource`](crate::api::core::v1::EphemeralVolumeSource).
+pub fn visit_api_core_v1_ephemeral_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EphemeralVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.volume_claim_template {
+        v.visit_api_core_v1_persistent_volume_claim_template(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Event`](crate::api::core::v1::Event).
+pub fn visit_api_core_v1_event<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Event) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.event_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+    if let Some(it) = &node.first_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    v.visit_api_core_v1_object_reference(&node.involved_object);
+    if let Some(it) = &node.last_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.related {
+        v.visit_api_core_v1_object_reference(it);
+    }
+    if let Some(it) = &node.series {
+        v.visit_api_core_v1_event_series(it);
+    }
+    if let Some(it) = &node.source {
+        v.visit_api_core_v1_event_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EventSeries`](crate::api::core::v1::EventSeries).
+pub fn visit_api_core_v1_event_series<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::EventSeries) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_observed_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+}
+
+/// Visits a value of type [`EventSource`](crate::api::core::v1::EventSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_event_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::EventSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ExecAction`](crate::api::core::v1::ExecAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_exec_action<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ExecAction) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`FCVolumeSource`](crate::api::core::v1::FCVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_fc_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::FCVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`FlexPersistentVolumeSource`](crate::api::core::v1::FlexPersistentVolumeSource).
+pub fn visit_api_core_v1_flex_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::FlexPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlexVolumeSource`](crate::api::core::v1::FlexVolumeSource).
+pub fn visit_api_core_v1_flex_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::FlexVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`FlockerVolumeSource`](crate::api::core::v1::FlockerVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_flocker_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::FlockerVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GCEPersistentDiskVolumeSource`](crate::api::core::v1::GCEPersistentDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_gce_persistent_disk_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::GCEPersistentDiskVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GRPCAction`](crate::api::core::v1::GRPCAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_grpc_action<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::GRPCAction) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GitRepoVolumeSource`](crate::api::core::v1::GitRepoVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_git_repo_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::GitRepoVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GlusterfsPersistentVolumeSource`](crate::api::core::v1::GlusterfsPersistentVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_glusterfs_persistent_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::GlusterfsPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GlusterfsVolumeSource`](crate::api::core::v1::GlusterfsVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_glusterfs_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::GlusterfsVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HTTPGetAction`](crate::api::core::v1::HTTPGetAction).
+pub fn visit_api_core_v1_http_get_action<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::HTTPGetAction) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.http_headers {
+        for it in it {
+            v.visit_api_core_v1_http_header(it);
+        }
+    }
+    v.visit_apimachinery_pkg_util_intstr_int_or_string(&node.port);
+}
+
+/// Visits a value of type [`HTTPHeader`](crate::api::core::v1::HTTPHeader), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_http_header<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::HTTPHeader) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`HostAlias`](crate::api::core::v1::HostAlias), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_alias<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::HostAlias) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`HostIP`](crate::api::core::v1::HostIP), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_ip<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::HostIP) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`HostPathVolumeSource`](crate::api::core::v1::HostPathVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_path_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::HostPathVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ISCSIPersistentVolumeSource`](crate::api::core::v1::ISCSIPersistentVolumeSource).
+pub fn visit_api_core_v1_iscsi_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ISCSIPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ISCSIVolumeSource`](crate::api::core::v1::ISCSIVolumeSource).
+pub fn visit_api_core_v1_iscsi_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ISCSIVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`ImageVolumeSource`](crate::api::core::v1::ImageVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_image_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ImageVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`KeyToPath`](crate::api::core::v1::KeyToPath), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_key_to_path<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::KeyToPath) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Lifecycle`](crate::api::core::v1::Lifecycle).
+pub fn visit_api_core_v1_lifecycle<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Lifecycle) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.post_start {
+        v.visit_api_core_v1_lifecycle_handler(it);
+    }
+    if let Some(it) = &node.pre_stop {
+        v.visit_api_core_v1_lifecycle_handler(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LifecycleHandler`](crate::api::core::v1::LifecycleHandler).
+pub fn visit_api_core_v1_lifecycle_handler<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LifecycleHandler) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exec {
+        v.visit_api_core_v1_exec_action(it);
+    }
+    if let Some(it) = &node.http_get {
+        v.visit_api_core_v1_http_get_action(it);
+    }
+    if let Some(it) = &node.sleep {
+        v.visit_api_core_v1_sleep_action(it);
+    }
+    if let Some(it) = &node.tcp_socket {
+        v.visit_api_core_v1_tcp_socket_action(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRange`](crate::api::core::v1::LimitRange).
+pub fn visit_api_core_v1_limit_range<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LimitRange) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_limit_range_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRangeItem`](crate::api::core::v1::LimitRangeItem).
+pub fn visit_api_core_v1_limit_range_item<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LimitRangeItem) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.default {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.default_request {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.max {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.max_limit_request_ratio {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.min {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRangeSpec`](crate::api::core::v1::LimitRangeSpec).
+pub fn visit_api_core_v1_limit_range_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LimitRangeSpec) where V: Visit<'ast> + ?Sized {
+    for it in &node.limits {
+        v.visit_api_core_v1_limit_range_item(it);
+    }
+}
+
+/// Visits a value of type [`LinuxContainerUser`](crate::api::core::v1::LinuxContainerUser), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_linux_container_user<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::LinuxContainerUser) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LoadBalancerIngress`](crate::api::core::v1::LoadBalancerIngress).
+pub fn visit_api_core_v1_load_balancer_ingress<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LoadBalancerIngress) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_core_v1_port_status(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LoadBalancerStatus`](crate::api::core::v1::LoadBalancerStatus).
+pub fn visit_api_core_v1_load_balancer_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::LoadBalancerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ingress {
+        for it in it {
+            v.visit_api_core_v1_load_balancer_ingress(it);
+        }
+    }
+}
+
+/// Visits a value of type [`LocalObjectReference`](crate::api::core::v1::LocalObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_local_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::LocalObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`LocalVolumeSource`](crate::api::core::v1::LocalVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_local_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::LocalVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ModifyVolumeStatus`](crate::api::core::v1::ModifyVolumeStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_modify_volume_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ModifyVolumeStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`NFSVolumeSource`](crate::api::core::v1::NFSVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_nfs_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NFSVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Namespace`](crate::api::core::v1::Namespace).
+pub fn visit_api_core_v1_namespace<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Namespace) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_namespace_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_namespace_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NamespaceCondition`](crate::api::core::v1::NamespaceCondition).
+pub fn visit_api_core_v1_namespace_condition<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NamespaceCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`NamespaceSpec`](crate::api::core::v1::NamespaceSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_namespace_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NamespaceSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NamespaceStatus`](crate::api::core::v1::NamespaceStatus).
+pub fn visit_api_core_v1_namespace_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NamespaceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_namespace_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Node`](crate::api::core::v1::Node).
+pub fn visit_api_core_v1_node<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Node) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_node_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_node_status(it);
+    }
+}
+
+/// Visits a value of type [`NodeAddress`](crate::api::core::v1::NodeAddress), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_address<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NodeAddress) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeAffinity`](crate::api::core::v1::NodeAffinity).
+pub fn visit_api_core_v1_node_affinity<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeAffinity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_preferred_scheduling_term(it);
+        }
+    }
+    if let Some(it) = &node.required_during_scheduling_ignored_during_execution {
+        v.visit_api_core_v1_node_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeCondition`](crate::api::core::v1::NodeCondition).
+pub fn visit_api_core_v1_node_condition<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_heartbeat_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeConfigSource`](crate::api::core::v1::NodeConfigSource).
+pub fn visit_api_core_v1_node_config_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeConfigSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config_map {
+        v.visit_api_core_v1_config_map_node_config_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeConfigStatus`](crate::api::core::v1::NodeConfigStatus).
+pub fn visit_api_core_v1_node_config_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeConfigStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.active {
+        v.visit_api_core_v1_node_config_source(it);
+    }
+    if let Some(it) = &node.assigned {
+        v.visit_api_core_v1_node_config_source(it);
+    }
+    if let Some(it) = &node.last_known_good {
+        v.visit_api_core_v1_node_config_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeDaemonEndpoints`](crate::api::core::v1::NodeDaemonEndpoints).
+pub fn visit_api_core_v1_node_daemon_endpoints<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeDaemonEndpoints) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.kubelet_endpoint {
+        v.visit_api_core_v1_daemon_endpoint(it);
+    }
+}
+
+/// Visits a value of type [`NodeFeatures`](crate::api::core::v1::NodeFeatures), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_features<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NodeFeatures) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeRuntimeHandler`](crate::api::core::v1::NodeRuntimeHandler).
+pub fn visit_api_core_v1_node_runtime_handler<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeRuntimeHandler) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.features {
+        v.visit_api_core_v1_node_runtime_handler_features(it);
+    }
+}
+
+/// Visits a value of type [`NodeRuntimeHandlerFeatures`](crate::api::core::v1::NodeRuntimeHandlerFeatures), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_runtime_handler_features<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NodeRuntimeHandlerFeatures) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeSelector`](crate::api::core::v1::NodeSelector).
+pub fn visit_api_core_v1_node_selector<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeSelector) where V: Visit<'ast> + ?Sized {
+    for it in &node.node_selector_terms {
+        v.visit_api_core_v1_node_selector_term(it);
+    }
+}
+
+/// Visits a value of type [`NodeSelectorRequirement`](crate::api::core::v1::NodeSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_selector_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NodeSelectorRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeSelectorTerm`](crate::api::core::v1::NodeSelectorTerm).
+pub fn visit_api_core_v1_node_selector_term<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeSelectorTerm) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_expressions {
+        for it in it {
+            v.visit_api_core_v1_node_selector_requirement(it);
+        }
+    }
+    if let Some(it) = &node.match_fields {
+        for it in it {
+            v.visit_api_core_v1_node_selector_requirement(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeSpec`](crate::api::core::v1::NodeSpec).
+pub fn visit_api_core_v1_node_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config_source {
+        v.visit_api_core_v1_node_config_source(it);
+    }
+    if let Some(it) = &node.taints {
+        for it in it {
+            v.visit_api_core_v1_taint(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeStatus`](crate::api::core::v1::NodeStatus).
+pub fn visit_api_core_v1_node_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::NodeStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.addresses {
+        for it in it {
+            v.visit_api_core_v1_node_address(it);
+        }
+    }
+    if let Some(it) = &node.allocatable {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.capacity {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_node_condition(it);
+        }
+    }
+    if let Some(it) = &node.config {
+        v.visit_api_core_v1_node_config_status(it);
+    }
+    if let Some(it) = &node.daemon_endpoints {
+        v.visit_api_core_v1_node_daemon_endpoints(it);
+    }
+    if let Some(it) = &node.features {
+        v.visit_api_core_v1_node_features(it);
+    }
+    if let Some(it) = &node.images {
+        for it in it {
+            v.visit_api_core_v1_container_image(it);
+        }
+    }
+    if let Some(it) = &node.node_info {
+        v.visit_api_core_v1_node_system_info(it);
+    }
+    if let Some(it) = &node.runtime_handlers {
+        for it in it {
+            v.visit_api_core_v1_node_runtime_handler(it);
+        }
+    }
+    if let Some(it) = &node.volumes_attached {
+        for it in it {
+            v.visit_api_core_v1_attached_volume(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NodeSystemInfo`](crate::api::core::v1::NodeSystemInfo), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_system_info<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::NodeSystemInfo) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ObjectFieldSelector`](crate::api::core::v1::ObjectFieldSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_object_field_selector<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ObjectFieldSelector) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ObjectReference`](crate::api::core::v1::ObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PersistentVolume`](crate::api::core::v1::PersistentVolume).
+pub fn visit_api_core_v1_persistent_volume<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolume) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_persistent_volume_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_persistent_volume_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaim`](crate::api::core::v1::PersistentVolumeClaim).
+pub fn visit_api_core_v1_persistent_volume_claim<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeClaim) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_persistent_volume_claim_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_persistent_volume_claim_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimCondition`](crate::api::core::v1::PersistentVolumeClaimCondition).
+pub fn visit_api_core_v1_persistent_volume_claim_condition<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeClaimCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimSpec`](crate::api::core::v1::PersistentVolumeClaimSpec).
+pub fn visit_api_core_v1_persistent_volume_claim_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeClaimSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.data_source {
+        v.visit_api_core_v1_typed_local_object_reference(it);
+    }
+    if let Some(it) = &node.data_source_ref {
+        v.visit_api_core_v1_typed_object_reference(it);
+    }
+    if let Some(it) = &node.resources {
+        v.visit_api_core_v1_volume_resource_requirements(it);
+    }
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimStatus`](crate::api::core::v1::PersistentVolumeClaimStatus).
+pub fn visit_api_core_v1_persistent_volume_claim_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeClaimStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allocated_resources {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.capacity {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_persistent_volume_claim_condition(it);
+        }
+    }
+    if let Some(it) = &node.modify_volume_status {
+        v.visit_api_core_v1_modify_volume_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimTemplate`](crate::api::core::v1::PersistentVolumeClaimTemplate).
+pub fn visit_api_core_v1_persistent_volume_claim_template<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeClaimTemplate) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(it);
+    }
+    v.visit_api_core_v1_persistent_volume_claim_spec(&node.spec);
+}
+
+/// Visits a value of type [`PersistentVolumeClaimVolumeSource`](crate::api::core::v1::PersistentVolumeClaimVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_persistent_volume_claim_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PersistentVolumeClaimVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeSpec`](crate::api::core::v1::PersistentVolumeSpec).
+pub fn visit_api_core_v1_persistent_volume_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.aws_elastic_block_store {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source(it);
+    }
+    if let Some(it) = &node.azure_disk {
+        v.visit_api_core_v1_azure_disk_volume_source(it);
+    }
+    if let Some(it) = &node.azure_file {
+        v.visit_api_core_v1_azure_file_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.capacity {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.cephfs {
+        v.visit_api_core_v1_ceph_fs_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.cinder {
+        v.visit_api_core_v1_cinder_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.claim_ref {
+        v.visit_api_core_v1_object_reference(it);
+    }
+    if let Some(it) = &node.csi {
+        v.visit_api_core_v1_csi_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.fc {
+        v.visit_api_core_v1_fc_volume_source(it);
+    }
+    if let Some(it) = &node.flex_volume {
+        v.visit_api_core_v1_flex_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.flocker {
+        v.visit_api_core_v1_flocker_volume_source(it);
+    }
+    if let Some(it) = &node.gce_persistent_disk {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source(it);
+    }
+    if let Some(it) = &node.glusterfs {
+        v.visit_api_core_v1_glusterfs_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.host_path {
+        v.visit_api_core_v1_host_path_volume_source(it);
+    }
+    if let Some(it) = &node.iscsi {
+        v.visit_api_core_v1_iscsi_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.local {
+        v.visit_api_core_v1_local_volume_source(it);
+    }
+    if let Some(it) = &node.nfs {
+        v.visit_api_core_v1_nfs_volume_source(it);
+    }
+    if let Some(it) = &node.node_affinity {
+        v.visit_api_core_v1_volume_node_affinity(it);
+    }
+    if let Some(it) = &node.photon_persistent_disk {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source(it);
+    }
+    if let Some(it) = &node.portworx_volume {
+        v.visit_api_core_v1_portworx_volume_source(it);
+    }
+    if let Some(it) = &node.quobyte {
+        v.visit_api_core_v1_quobyte_volume_source(it);
+    }
+    if let Some(it) = &node.rbd {
+        v.visit_api_core_v1_rbd_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.scale_io {
+        v.visit_api_core_v1_scale_io_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.storageos {
+        v.visit_api_core_v1_storage_os_persistent_volume_source(it);
+    }
+    if let Some(it) = &node.vsphere_volume {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeStatus`](crate::api::core::v1::PersistentVolumeStatus).
+pub fn visit_api_core_v1_persistent_volume_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PersistentVolumeStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_phase_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`PhotonPersistentDiskVolumeSource`](crate::api::core::v1::PhotonPersistentDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_photon_persistent_disk_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PhotonPersistentDiskVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Pod`](crate::api::core::v1::Pod).
+pub fn visit_api_core_v1_pod<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Pod) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_pod_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_pod_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAffinity`](crate::api::core::v1::PodAffinity).
+pub fn visit_api_core_v1_pod_affinity<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodAffinity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_weighted_pod_affinity_term(it);
+        }
+    }
+    if let Some(it) = &node.required_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_pod_affinity_term(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAffinityTerm`](crate::api::core::v1::PodAffinityTerm).
+pub fn visit_api_core_v1_pod_affinity_term<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodAffinityTerm) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAntiAffinity`](crate::api::core::v1::PodAntiAffinity).
+pub fn visit_api_core_v1_pod_anti_affinity<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodAntiAffinity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_weighted_pod_affinity_term(it);
+        }
+    }
+    if let Some(it) = &node.required_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_pod_affinity_term(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodCondition`](crate::api::core::v1::PodCondition).
+pub fn visit_api_core_v1_pod_condition<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDNSConfig`](crate::api::core::v1::PodDNSConfig).
+pub fn visit_api_core_v1_pod_dns_config<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodDNSConfig) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.options {
+        for it in it {
+            v.visit_api_core_v1_pod_dns_config_option(it);
+        }
+    }
+}
+
+/// Visits a value of type [`PodDNSConfigOption`](crate::api::core::v1::PodDNSConfigOption), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_dns_config_option<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodDNSConfigOption) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodIP`](crate::api::core::v1::PodIP), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_ip<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodIP) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodOS`](crate::api::core::v1::PodOS), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_os<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodOS) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodReadinessGate`](crate::api::core::v1::PodReadinessGate), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_readiness_gate<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodReadinessGate) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodResourceClaim`](crate::api::core::v1::PodResourceClaim), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_resource_claim<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodResourceClaim) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodResourceClaimStatus`](crate::api::core::v1::PodResourceClaimStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_resource_claim_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodResourceClaimStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PodSchedulingGate`](crate::api::core::v1::PodSchedulingGate), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_scheduling_gate<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PodSchedulingGate) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PodSecurityContext`](crate::api::core::v1::PodSecurityContext).
+pub fn visit_api_core_v1_pod_security_context<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodSecurityContext) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.app_armor_profile {
+        v.visit_api_core_v1_app_armor_profile(it);
+    }
+    if let Some(it) = &node.se_linux_options {
+        v.visit_api_core_v1_se_linux_options(it);
+    }
+    if let Some(it) = &node.seccomp_profile {
+        v.visit_api_core_v1_seccomp_profile(it);
+    }
+    if let Some(it) = &node.sysctls {
+        for it in it {
+            v.visit_api_core_v1_sysctl(it);
+        }
+    }
+    if let Some(it) = &node.windows_options {
+        v.visit_api_core_v1_windows_security_context_options(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodSpec`](crate::api::core::v1::PodSpec).
+pub fn visit_api_core_v1_pod_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.affinity {
+        v.visit_api_core_v1_affinity(it);
+    }
+    for it in &node.containers {
+        v.visit_api_core_v1_container(it);
+    }
+    if let Some(it) = &node.dns_config {
+        v.visit_api_core_v1_pod_dns_config(it);
+    }
+    if let Some(it) = &node.ephemeral_containers {
+        for it in it {
+            v.visit_api_core_v1_ephemeral_container(it);
+        }
+    }
+    if let Some(it) = &node.host_aliases {
+        for it in it {
+            v.visit_api_core_v1_host_alias(it);
+        }
+    }
+    if let Some(it) = &node.image_pull_secrets {
+        for it in it {
+            v.visit_api_core_v1_local_object_reference(it);
+        }
+    }
+    if let Some(it) = &node.init_containers {
+        for it in it {
+            v.visit_api_core_v1_container(it);
+        }
+    }
+    if let Some(it) = &node.os {
+        v.visit_api_core_v1_pod_os(it);
+    }
+    if let Some(it) = &node.overhead {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.readiness_gates {
+        for it in it {
+            v.visit_api_core_v1_pod_readiness_gate(it);
+        }
+    }
+    if let Some(it) = &node.resource_claims {
+        for it in it {
+            v.visit_api_core_v1_pod_resource_claim(it);
+        }
+    }
+    if let Some(it) = &node.resources {
+        v.visit_api_core_v1_resource_requirements(it);
+    }
+    if let Some(it) = &node.scheduling_gates {
+        for it in it {
+            v.visit_api_core_v1_pod_scheduling_gate(it);
+        }
+    }
+    if let Some(it) = &node.security_context {
+        v.visit_api_core_v1_pod_security_context(it);
+    }
+    if let Some(it) = &node.tolerations {
+        for it in it {
+            v.visit_api_core_v1_toleration(it);
+        }
+    }
+    if let Some(it) = &node.topology_spread_constraints {
+        for it in it {
+            v.visit_api_core_v1_topology_spread_constraint(it);
+        }
+    }
+    if let Some(it) = &node.volumes {
+        for it in it {
+            v.visit_api_core_v1_volume(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodStatus`](crate::api::core::v1::PodStatus).
+pub fn visit_api_core_v1_pod_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_pod_condition(it);
+        }
+    }
+    if let Some(it) = &node.container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status(it);
+        }
+    }
+    if let Some(it) = &node.ephemeral_container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status(it);
+        }
+    }
+    if let Some(it) = &node.host_ips {
+        for it in it {
+            v.visit_api_core_v1_host_ip(it);
+        }
+    }
+    if let Some(it) = &node.init_container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status(it);
+        }
+    }
+    if let Some(it) = &node.pod_ips {
+        for it in it {
+            v.visit_api_core_v1_pod_ip(it);
+        }
+    }
+    if let Some(it) = &node.resource_claim_statuses {
+        for it in it {
+            v.visit_api_core_v1_pod_resource_claim_status(it);
+        }
+    }
+    if let Some(it) = &node.start_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodTemplate`](crate::api::core::v1::PodTemplate).
+pub fn visit_api_core_v1_pod_template<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodTemplate) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.template {
+        v.visit_api_core_v1_pod_template_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodTemplateSpec`](crate::api::core::v1::PodTemplateSpec).
+pub fn visit_api_core_v1_pod_template_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PodTemplateSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(it);
+    }
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_pod_spec(it);
+    }
+}
+
+/// Visits a value of type [`PortStatus`](crate::api::core::v1::PortStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_port_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PortStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`PortworxVolumeSource`](crate::api::core::v1::PortworxVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_portworx_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::PortworxVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PreferredSchedulingTerm`](crate::api::core::v1::PreferredSchedulingTerm).
+pub fn visit_api_core_v1_preferred_scheduling_term<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::PreferredSchedulingTerm) where V: Visit<'ast> + ?Sized {
+    v.visit_api_core_v1_node_selector_term(&node.preference);
+}
+
+/// Visits the values contained in a value of type [`Probe`](crate::api::core::v1::Probe).
+pub fn visit_api_core_v1_probe<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Probe) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exec {
+        v.visit_api_core_v1_exec_action(it);
+    }
+    if let Some(it) = &node.grpc {
+        v.visit_api_core_v1_grpc_action(it);
+    }
+    if let Some(it) = &node.http_get {
+        v.visit_api_core_v1_http_get_action(it);
+    }
+    if let Some(it) = &node.tcp_socket {
+        v.visit_api_core_v1_tcp_socket_action(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ProjectedVolumeSource`](crate::api::core::v1::ProjectedVolumeSource).
+pub fn visit_api_core_v1_projected_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ProjectedVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.sources {
+        for it in it {
+            v.visit_api_core_v1_volume_projection(it);
+        }
+    }
+}
+
+/// Visits a value of type [`QuobyteVolumeSource`](crate::api::core::v1::QuobyteVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_quobyte_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::QuobyteVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`RBDPersistentVolumeSource`](crate::api::core::v1::RBDPersistentVolumeSource).
+pub fn visit_api_core_v1_rbd_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::RBDPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_secret_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RBDVolumeSource`](crate::api::core::v1::RBDVolumeSource).
+pub fn visit_api_core_v1_rbd_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::RBDVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationController`](crate::api::core::v1::ReplicationController).
+pub fn visit_api_core_v1_replication_controller<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ReplicationController) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_replication_controller_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_replication_controller_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerCondition`](crate::api::core::v1::ReplicationControllerCondition).
+pub fn visit_api_core_v1_replication_controller_condition<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ReplicationControllerCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerSpec`](crate::api::core::v1::ReplicationControllerSpec).
+pub fn visit_api_core_v1_replication_controller_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ReplicationControllerSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.template {
+        v.visit_api_core_v1_pod_template_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerStatus`](crate::api::core::v1::ReplicationControllerStatus).
+pub fn visit_api_core_v1_replication_controller_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ReplicationControllerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_core_v1_replication_controller_condition(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ResourceClaim`](crate::api::core::v1::ResourceClaim), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_resource_claim<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ResourceClaim) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceFieldSelector`](crate::api::core::v1::ResourceFieldSelector).
+pub fn visit_api_core_v1_resource_field_selector<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceFieldSelector) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.divisor {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+}
+
+/// Visits a value of type [`ResourceHealth`](crate::api::core::v1::ResourceHealth), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_resource_health<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ResourceHealth) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceQuota`](crate::api::core::v1::ResourceQuota).
+pub fn visit_api_core_v1_resource_quota<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceQuota) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_resource_quota_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_resource_quota_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceQuotaSpec`](crate::api::core::v1::ResourceQuotaSpec).
+pub fn visit_api_core_v1_resource_quota_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceQuotaSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.hard {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.scope_selector {
+        v.visit_api_core_v1_scope_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceQuotaStatus`](crate::api::core::v1::ResourceQuotaStatus).
+pub fn visit_api_core_v1_resource_quota_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceQuotaStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.hard {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.used {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceRequirements`](crate::api::core::v1::ResourceRequirements).
+pub fn visit_api_core_v1_resource_requirements<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceRequirements) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.claims {
+        for it in it {
+            v.visit_api_core_v1_resource_claim(it);
+        }
+    }
+    if let Some(it) = &node.limits {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.requests {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceStatus`](crate::api::core::v1::ResourceStatus).
+pub fn visit_api_core_v1_resource_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ResourceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.resources {
+        for it in it {
+            v.visit_api_core_v1_resource_health(it);
+        }
+    }
+}
+
+/// Visits a value of type [`SELinuxOptions`](crate::api::core::v1::SELinuxOptions), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_se_linux_options<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SELinuxOptions) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ScaleIOPersistentVolumeSource`](crate::api::core::v1::ScaleIOPersistentVolumeSource).
+pub fn visit_api_core_v1_scale_io_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ScaleIOPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_core_v1_secret_reference(&node.secret_ref);
+}
+
+/// Visits the values contained in a value of type [`ScaleIOVolumeSource`](crate::api::core::v1::ScaleIOVolumeSource).
+pub fn visit_api_core_v1_scale_io_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ScaleIOVolumeSource) where V: Visit<'ast> + ?Sized {
+    v.visit_api_core_v1_local_object_reference(&node.secret_ref);
+}
+
+/// Visits the values contained in a value of type [`ScopeSelector`](crate::api::core::v1::ScopeSelector).
+pub fn visit_api_core_v1_scope_selector<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ScopeSelector) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_expressions {
+        for it in it {
+            v.visit_api_core_v1_scoped_resource_selector_requirement(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ScopedResourceSelectorRequirement`](crate::api::core::v1::ScopedResourceSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_scoped_resource_selector_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ScopedResourceSelectorRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`SeccompProfile`](crate::api::core::v1::SeccompProfile), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_seccomp_profile<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SeccompProfile) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Secret`](crate::api::core::v1::Secret).
+pub fn visit_api_core_v1_secret<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Secret) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits a value of type [`SecretEnvSource`](crate::api::core::v1::SecretEnvSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_env_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SecretEnvSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`SecretKeySelector`](crate::api::core::v1::SecretKeySelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_key_selector<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SecretKeySelector) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SecretProjection`](crate::api::core::v1::SecretProjection).
+pub fn visit_api_core_v1_secret_projection<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::SecretProjection) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path(it);
+        }
+    }
+}
+
+/// Visits a value of type [`SecretReference`](crate::api::core::v1::SecretReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SecretReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SecretVolumeSource`](crate::api::core::v1::SecretVolumeSource).
+pub fn visit_api_core_v1_secret_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::SecretVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`SecurityContext`](crate::api::core::v1::SecurityContext).
+pub fn visit_api_core_v1_security_context<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::SecurityContext) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.app_armor_profile {
+        v.visit_api_core_v1_app_armor_profile(it);
+    }
+    if let Some(it) = &node.capabilities {
+        v.visit_api_core_v1_capabilities(it);
+    }
+    if let Some(it) = &node.se_linux_options {
+        v.visit_api_core_v1_se_linux_options(it);
+    }
+    if let Some(it) = &node.seccomp_profile {
+        v.visit_api_core_v1_seccomp_profile(it);
+    }
+    if let Some(it) = &node.windows_options {
+        v.visit_api_core_v1_windows_security_context_options(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Service`](crate::api::core::v1::Service).
+pub fn visit_api_core_v1_service<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Service) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_core_v1_service_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_core_v1_service_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceAccount`](crate::api::core::v1::ServiceAccount).
+pub fn visit_api_core_v1_service_account<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ServiceAccount) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.image_pull_secrets {
+        for it in it {
+            v.visit_api_core_v1_local_object_reference(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.secrets {
+        for it in it {
+            v.visit_api_core_v1_object_reference(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ServiceAccountTokenProjection`](crate::api::core::v1::ServiceAccountTokenProjection), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_service_account_token_projection<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::ServiceAccountTokenProjection) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServicePort`](crate::api::core::v1::ServicePort).
+pub fn visit_api_core_v1_service_port<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ServicePort) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.target_port {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceSpec`](crate::api::core::v1::ServiceSpec).
+pub fn visit_api_core_v1_service_spec<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ServiceSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_core_v1_service_port(it);
+        }
+    }
+    if let Some(it) = &node.session_affinity_config {
+        v.visit_api_core_v1_session_affinity_config(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceStatus`](crate::api::core::v1::ServiceStatus).
+pub fn visit_api_core_v1_service_status<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::ServiceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.load_balancer {
+        v.visit_api_core_v1_load_balancer_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SessionAffinityConfig`](crate::api::core::v1::SessionAffinityConfig).
+pub fn visit_api_core_v1_session_affinity_config<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::SessionAffinityConfig) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.client_ip {
+        v.visit_api_core_v1_client_ip_config(it);
+    }
+}
+
+/// Visits a value of type [`SleepAction`](crate::api::core::v1::SleepAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_sleep_action<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::SleepAction) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageOSPersistentVolumeSource`](crate::api::core::v1::StorageOSPersistentVolumeSource).
+pub fn visit_api_core_v1_storage_os_persistent_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::StorageOSPersistentVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_object_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageOSVolumeSource`](crate::api::core::v1::StorageOSVolumeSource).
+pub fn visit_api_core_v1_storage_os_volume_source<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::StorageOSVolumeSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.secret_ref {
+        v.visit_api_core_v1_local_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`Sysctl`](crate::api::core::v1::Sysctl), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_sysctl<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::Sysctl) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TCPSocketAction`](crate::api::core::v1::TCPSocketAction).
+pub fn visit_api_core_v1_tcp_socket_action<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::TCPSocketAction) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_util_intstr_int_or_string(&node.port);
+}
+
+/// Visits the values contained in a value of type [`Taint`](crate::api::core::v1::Taint).
+pub fn visit_api_core_v1_taint<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Taint) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.time_added {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`Toleration`](crate::api::core::v1::Toleration), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_toleration<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::Toleration) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`TopologySelectorLabelRequirement`](crate::api::core::v1::TopologySelectorLabelRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_topology_selector_label_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::TopologySelectorLabelRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TopologySelectorTerm`](crate::api::core::v1::TopologySelectorTerm).
+pub fn visit_api_core_v1_topology_selector_term<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::TopologySelectorTerm) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_label_expressions {
+        for it in it {
+            v.visit_api_core_v1_topology_selector_label_requirement(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`TopologySpreadConstraint`](crate::api::core::v1::TopologySpreadConstraint).
+pub fn visit_api_core_v1_topology_spread_constraint<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::TopologySpreadConstraint) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits a value of type [`TypedLocalObjectReference`](crate::api::core::v1::TypedLocalObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_typed_local_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::TypedLocalObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`TypedObjectReference`](crate::api::core::v1::TypedObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_typed_object_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::TypedObjectReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Volume`](crate::api::core::v1::Volume).
+pub fn visit_api_core_v1_volume<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::Volume) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.aws_elastic_block_store {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source(it);
+    }
+    if let Some(it) = &node.azure_disk {
+        v.visit_api_core_v1_azure_disk_volume_source(it);
+    }
+    if let Some(it) = &node.azure_file {
+        v.visit_api_core_v1_azure_file_volume_source(it);
+    }
+    if let Some(it) = &node.cephfs {
+        v.visit_api_core_v1_ceph_fs_volume_source(it);
+    }
+    if let Some(it) = &node.cinder {
+        v.visit_api_core_v1_cinder_volume_source(it);
+    }
+    if let Some(it) = &node.config_map {
+        v.visit_api_core_v1_config_map_volume_source(it);
+    }
+    if let Some(it) = &node.csi {
+        v.visit_api_core_v1_csi_volume_source(it);
+    }
+    if let Some(it) = &node.downward_api {
+        v.visit_api_core_v1_downward_api_volume_source(it);
+    }
+    if let Some(it) = &node.empty_dir {
+        v.visit_api_core_v1_empty_dir_volume_source(it);
+    }
+    if let Some(it) = &node.ephemeral {
+        v.visit_api_core_v1_ephemeral_volume_source(it);
+    }
+    if let Some(it) = &node.fc {
+        v.visit_api_core_v1_fc_volume_source(it);
+    }
+    if let Some(it) = &node.flex_volume {
+        v.visit_api_core_v1_flex_volume_source(it);
+    }
+    if let Some(it) = &node.flocker {
+        v.visit_api_core_v1_flocker_volume_source(it);
+    }
+    if let Some(it) = &node.gce_persistent_disk {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source(it);
+    }
+    if let Some(it) = &node.git_repo {
+        v.visit_api_core_v1_git_repo_volume_source(it);
+    }
+    if let Some(it) = &node.glusterfs {
+        v.visit_api_core_v1_glusterfs_volume_source(it);
+    }
+    if let Some(it) = &node.host_path {
+        v.visit_api_core_v1_host_path_volume_source(it);
+    }
+    if let Some(it) = &node.image {
+        v.visit_api_core_v1_image_volume_source(it);
+    }
+    if let Some(it) = &node.iscsi {
+        v.visit_api_core_v1_iscsi_volume_source(it);
+    }
+    if let Some(it) = &node.nfs {
+        v.visit_api_core_v1_nfs_volume_source(it);
+    }
+    if let Some(it) = &node.persistent_volume_claim {
+        v.visit_api_core_v1_persistent_volume_claim_volume_source(it);
+    }
+    if let Some(it) = &node.photon_persistent_disk {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source(it);
+    }
+    if let Some(it) = &node.portworx_volume {
+        v.visit_api_core_v1_portworx_volume_source(it);
+    }
+    if let Some(it) = &node.projected {
+        v.visit_api_core_v1_projected_volume_source(it);
+    }
+    if let Some(it) = &node.quobyte {
+        v.visit_api_core_v1_quobyte_volume_source(it);
+    }
+    if let Some(it) = &node.rbd {
+        v.visit_api_core_v1_rbd_volume_source(it);
+    }
+    if let Some(it) = &node.scale_io {
+        v.visit_api_core_v1_scale_io_volume_source(it);
+    }
+    if let Some(it) = &node.secret {
+        v.visit_api_core_v1_secret_volume_source(it);
+    }
+    if let Some(it) = &node.storageos {
+        v.visit_api_core_v1_storage_os_volume_source(it);
+    }
+    if let Some(it) = &node.vsphere_volume {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source(it);
+    }
+}
+
+/// Visits a value of type [`VolumeDevice`](crate::api::core::v1::VolumeDevice), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_device<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::VolumeDevice) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`VolumeMount`](crate::api::core::v1::VolumeMount), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_mount<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::VolumeMount) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`VolumeMountStatus`](crate::api::core::v1::VolumeMountStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_mount_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::VolumeMountStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeNodeAffinity`](crate::api::core::v1::VolumeNodeAffinity).
+pub fn visit_api_core_v1_volume_node_affinity<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::VolumeNodeAffinity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.required {
+        v.visit_api_core_v1_node_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeProjection`](crate::api::core::v1::VolumeProjection).
+pub fn visit_api_core_v1_volume_projection<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::VolumeProjection) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.cluster_trust_bundle {
+        v.visit_api_core_v1_cluster_trust_bundle_projection(it);
+    }
+    if let Some(it) = &node.config_map {
+        v.visit_api_core_v1_config_map_projection(it);
+    }
+    if let Some(it) = &node.downward_api {
+        v.visit_api_core_v1_downward_api_projection(it);
+    }
+    if let Some(it) = &node.secret {
+        v.visit_api_core_v1_secret_projection(it);
+    }
+    if let Some(it) = &node.service_account_token {
+        v.visit_api_core_v1_service_account_token_projection(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeResourceRequirements`](crate::api::core::v1::VolumeResourceRequirements).
+pub fn visit_api_core_v1_volume_resource_requirements<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::VolumeResourceRequirements) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.limits {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+    if let Some(it) = &node.requests {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits a value of type [`VsphereVirtualDiskVolumeSource`](crate::api::core::v1::VsphereVirtualDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_vsphere_virtual_disk_volume_source<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::VsphereVirtualDiskVolumeSource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WeightedPodAffinityTerm`](crate::api::core::v1::WeightedPodAffinityTerm).
+pub fn visit_api_core_v1_weighted_pod_affinity_term<'ast, V>(v: &mut V, node: &'ast crate::api::core::v1::WeightedPodAffinityTerm) where V: Visit<'ast> + ?Sized {
+    v.visit_api_core_v1_pod_affinity_term(&node.pod_affinity_term);
+}
+
+/// Visits a value of type [`WindowsSecurityContextOptions`](crate::api::core::v1::WindowsSecurityContextOptions), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_windows_security_context_options<'ast, V>(_v: &mut V, _node: &'ast crate::api::core::v1::WindowsSecurityContextOptions) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Endpoint`](crate::api::discovery::v1::Endpoint).
+pub fn visit_api_discovery_v1_endpoint<'ast, V>(v: &mut V, node: &'ast crate::api::discovery::v1::Endpoint) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        v.visit_api_discovery_v1_endpoint_conditions(it);
+    }
+    if let Some(it) = &node.hints {
+        v.visit_api_discovery_v1_endpoint_hints(it);
+    }
+    if let Some(it) = &node.target_ref {
+        v.visit_api_core_v1_object_reference(it);
+    }
+}
+
+/// Visits a value of type [`EndpointConditions`](crate::api::discovery::v1::EndpointConditions), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_endpoint_conditions<'ast, V>(_v: &mut V, _node: &'ast crate::api::discovery::v1::EndpointConditions) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointHints`](crate::api::discovery::v1::EndpointHints).
+pub fn visit_api_discovery_v1_endpoint_hints<'ast, V>(v: &mut V, node: &'ast crate::api::discovery::v1::EndpointHints) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.for_zones {
+        for it in it {
+            v.visit_api_discovery_v1_for_zone(it);
+        }
+    }
+}
+
+/// Visits a value of type [`EndpointPort`](crate::api::discovery::v1::EndpointPort), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_endpoint_port<'ast, V>(_v: &mut V, _node: &'ast crate::api::discovery::v1::EndpointPort) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointSlice`](crate::api::discovery::v1::EndpointSlice).
+pub fn visit_api_discovery_v1_endpoint_slice<'ast, V>(v: &mut V, node: &'ast crate::api::discovery::v1::EndpointSlice) where V: Visit<'ast> + ?Sized {
+    for it in &node.endpoints {
+        v.visit_api_discovery_v1_endpoint(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_discovery_v1_endpoint_port(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ForZone`](crate::api::discovery::v1::ForZone), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_for_zone<'ast, V>(_v: &mut V, _node: &'ast crate::api::discovery::v1::ForZone) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Event`](crate::api::events::v1::Event).
+pub fn visit_api_events_v1_event<'ast, V>(v: &mut V, node: &'ast crate::api::events::v1::Event) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.deprecated_first_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.deprecated_last_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.deprecated_source {
+        v.visit_api_core_v1_event_source(it);
+    }
+    if let Some(it) = &node.event_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.regarding {
+        v.visit_api_core_v1_object_reference(it);
+    }
+    if let Some(it) = &node.related {
+        v.visit_api_core_v1_object_reference(it);
+    }
+    if let Some(it) = &node.series {
+        v.visit_api_events_v1_event_series(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EventSeries`](crate::api::events::v1::EventSeries).
+pub fn visit_api_events_v1_event_series<'ast, V>(v: &mut V, node: &'ast crate::api::events::v1::EventSeries) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_micro_time(&node.last_observed_time);
+}
+
+/// Visits a value of type [`ExemptPriorityLevelConfiguration`](crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_exempt_priority_level_configuration<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`FlowDistinguisherMethod`](crate::api::flowcontrol::v1::FlowDistinguisherMethod), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_flow_distinguisher_method<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::FlowDistinguisherMethod) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`FlowSchema`](crate::api::flowcontrol::v1::FlowSchema).
+pub fn visit_api_flowcontrol_v1_flow_schema<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::FlowSchema) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_flowcontrol_v1_flow_schema_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_flowcontrol_v1_flow_schema_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaCondition`](crate::api::flowcontrol::v1::FlowSchemaCondition).
+pub fn visit_api_flowcontrol_v1_flow_schema_condition<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::FlowSchemaCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaSpec`](crate::api::flowcontrol::v1::FlowSchemaSpec).
+pub fn visit_api_flowcontrol_v1_flow_schema_spec<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::FlowSchemaSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.distinguisher_method {
+        v.visit_api_flowcontrol_v1_flow_distinguisher_method(it);
+    }
+    v.visit_api_flowcontrol_v1_priority_level_configuration_reference(&node.priority_level_configuration);
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_policy_rules_with_subjects(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaStatus`](crate::api::flowcontrol::v1::FlowSchemaStatus).
+pub fn visit_api_flowcontrol_v1_flow_schema_status<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::FlowSchemaStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_flowcontrol_v1_flow_schema_condition(it);
+        }
+    }
+}
+
+/// Visits a value of type [`GroupSubject`](crate::api::flowcontrol::v1::GroupSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_group_subject<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::GroupSubject) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LimitResponse`](crate::api::flowcontrol::v1::LimitResponse).
+pub fn visit_api_flowcontrol_v1_limit_response<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::LimitResponse) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.queuing {
+        v.visit_api_flowcontrol_v1_queuing_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitedPriorityLevelConfiguration`](crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration).
+pub fn visit_api_flowcontrol_v1_limited_priority_level_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.limit_response {
+        v.visit_api_flowcontrol_v1_limit_response(it);
+    }
+}
+
+/// Visits a value of type [`NonResourcePolicyRule`](crate::api::flowcontrol::v1::NonResourcePolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_non_resource_policy_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::NonResourcePolicyRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PolicyRulesWithSubjects`](crate::api::flowcontrol::v1::PolicyRulesWithSubjects).
+pub fn visit_api_flowcontrol_v1_policy_rules_with_subjects<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::PolicyRulesWithSubjects) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.non_resource_rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_non_resource_policy_rule(it);
+        }
+    }
+    if let Some(it) = &node.resource_rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_resource_policy_rule(it);
+        }
+    }
+    for it in &node.subjects {
+        v.visit_api_flowcontrol_v1_subject(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfiguration`](crate::api::flowcontrol::v1::PriorityLevelConfiguration).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfiguration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationCondition`](crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_condition<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`PriorityLevelConfigurationReference`](crate::api::flowcontrol::v1::PriorityLevelConfigurationReference), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationSpec`](crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_spec<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.exempt {
+        v.visit_api_flowcontrol_v1_exempt_priority_level_configuration(it);
+    }
+    if let Some(it) = &node.limited {
+        v.visit_api_flowcontrol_v1_limited_priority_level_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationStatus`](crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_status<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_flowcontrol_v1_priority_level_configuration_condition(it);
+        }
+    }
+}
+
+/// Visits a value of type [`QueuingConfiguration`](crate::api::flowcontrol::v1::QueuingConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_queuing_configuration<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::QueuingConfiguration) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ResourcePolicyRule`](crate::api::flowcontrol::v1::ResourcePolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_resource_policy_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::ResourcePolicyRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ServiceAccountSubject`](crate::api::flowcontrol::v1::ServiceAccountSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_service_account_subject<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::ServiceAccountSubject) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Subject`](crate::api::flowcontrol::v1::Subject).
+pub fn visit_api_flowcontrol_v1_subject<'ast, V>(v: &mut V, node: &'ast crate::api::flowcontrol::v1::Subject) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.group {
+        v.visit_api_flowcontrol_v1_group_subject(it);
+    }
+    if let Some(it) = &node.service_account {
+        v.visit_api_flowcontrol_v1_service_account_subject(it);
+    }
+    if let Some(it) = &node.user {
+        v.visit_api_flowcontrol_v1_user_subject(it);
+    }
+}
+
+/// Visits a value of type [`UserSubject`](crate::api::flowcontrol::v1::UserSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_user_subject<'ast, V>(_v: &mut V, _node: &'ast crate::api::flowcontrol::v1::UserSubject) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HTTPIngressPath`](crate::api::networking::v1::HTTPIngressPath).
+pub fn visit_api_networking_v1_http_ingress_path<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::HTTPIngressPath) where V: Visit<'ast> + ?Sized {
+    v.visit_api_networking_v1_ingress_backend(&node.backend);
+}
+
+/// Visits the values contained in a value of type [`HTTPIngressRuleValue`](crate::api::networking::v1::HTTPIngressRuleValue).
+pub fn visit_api_networking_v1_http_ingress_rule_value<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::HTTPIngressRuleValue) where V: Visit<'ast> + ?Sized {
+    for it in &node.paths {
+        v.visit_api_networking_v1_http_ingress_path(it);
+    }
+}
+
+/// Visits a value of type [`IPBlock`](crate::api::networking::v1::IPBlock), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ip_block<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1::IPBlock) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Ingress`](crate::api::networking::v1::Ingress).
+pub fn visit_api_networking_v1_ingress<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::Ingress) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_networking_v1_ingress_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_networking_v1_ingress_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressBackend`](crate::api::networking::v1::IngressBackend).
+pub fn visit_api_networking_v1_ingress_backend<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressBackend) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.resource {
+        v.visit_api_core_v1_typed_local_object_reference(it);
+    }
+    if let Some(it) = &node.service {
+        v.visit_api_networking_v1_ingress_service_backend(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressClass`](crate::api::networking::v1::IngressClass).
+pub fn visit_api_networking_v1_ingress_class<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_networking_v1_ingress_class_spec(it);
+    }
+}
+
+/// Visits a value of type [`IngressClassParametersReference`](crate::api::networking::v1::IngressClassParametersReference), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_class_parameters_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1::IngressClassParametersReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IngressClassSpec`](crate::api::networking::v1::IngressClassSpec).
+pub fn visit_api_networking_v1_ingress_class_spec<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressClassSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.parameters {
+        v.visit_api_networking_v1_ingress_class_parameters_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressLoadBalancerIngress`](crate::api::networking::v1::IngressLoadBalancerIngress).
+pub fn visit_api_networking_v1_ingress_load_balancer_ingress<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressLoadBalancerIngress) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_networking_v1_ingress_port_status(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressLoadBalancerStatus`](crate::api::networking::v1::IngressLoadBalancerStatus).
+pub fn visit_api_networking_v1_ingress_load_balancer_status<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressLoadBalancerStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ingress {
+        for it in it {
+            v.visit_api_networking_v1_ingress_load_balancer_ingress(it);
+        }
+    }
+}
+
+/// Visits a value of type [`IngressPortStatus`](crate::api::networking::v1::IngressPortStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_port_status<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1::IngressPortStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IngressRule`](crate::api::networking::v1::IngressRule).
+pub fn visit_api_networking_v1_ingress_rule<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressRule) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.http {
+        v.visit_api_networking_v1_http_ingress_rule_value(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressServiceBackend`](crate::api::networking::v1::IngressServiceBackend).
+pub fn visit_api_networking_v1_ingress_service_backend<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressServiceBackend) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.port {
+        v.visit_api_networking_v1_service_backend_port(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressSpec`](crate::api::networking::v1::IngressSpec).
+pub fn visit_api_networking_v1_ingress_spec<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.default_backend {
+        v.visit_api_networking_v1_ingress_backend(it);
+    }
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_networking_v1_ingress_rule(it);
+        }
+    }
+    if let Some(it) = &node.tls {
+        for it in it {
+            v.visit_api_networking_v1_ingress_tls(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressStatus`](crate::api::networking::v1::IngressStatus).
+pub fn visit_api_networking_v1_ingress_status<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::IngressStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.load_balancer {
+        v.visit_api_networking_v1_ingress_load_balancer_status(it);
+    }
+}
+
+/// Visits a value of type [`IngressTLS`](crate::api::networking::v1::IngressTLS), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_tls<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1::IngressTLS) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicy`](crate::api::networking::v1::NetworkPolicy).
+pub fn visit_api_networking_v1_network_policy<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicy) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_networking_v1_network_policy_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyEgressRule`](crate::api::networking::v1::NetworkPolicyEgressRule).
+pub fn visit_api_networking_v1_network_policy_egress_rule<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicyEgressRule) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_port(it);
+        }
+    }
+    if let Some(it) = &node.to {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_peer(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyIngressRule`](crate::api::networking::v1::NetworkPolicyIngressRule).
+pub fn visit_api_networking_v1_network_policy_ingress_rule<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicyIngressRule) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.from {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_peer(it);
+        }
+    }
+    if let Some(it) = &node.ports {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_port(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyPeer`](crate::api::networking::v1::NetworkPolicyPeer).
+pub fn visit_api_networking_v1_network_policy_peer<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicyPeer) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.ip_block {
+        v.visit_api_networking_v1_ip_block(it);
+    }
+    if let Some(it) = &node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+    if let Some(it) = &node.pod_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyPort`](crate::api::networking::v1::NetworkPolicyPort).
+pub fn visit_api_networking_v1_network_policy_port<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicyPort) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.port {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicySpec`](crate::api::networking::v1::NetworkPolicySpec).
+pub fn visit_api_networking_v1_network_policy_spec<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1::NetworkPolicySpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.egress {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_egress_rule(it);
+        }
+    }
+    if let Some(it) = &node.ingress {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_ingress_rule(it);
+        }
+    }
+    if let Some(it) = &node.pod_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits a value of type [`ServiceBackendPort`](crate::api::networking::v1::ServiceBackendPort), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_service_backend_port<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1::ServiceBackendPort) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IPAddress`](crate::api::networking::v1beta1::IPAddress).
+pub fn visit_api_networking_v1beta1_ip_address<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1beta1::IPAddress) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_networking_v1beta1_ip_address_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IPAddressSpec`](crate::api::networking::v1beta1::IPAddressSpec).
+pub fn visit_api_networking_v1beta1_ip_address_spec<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1beta1::IPAddressSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_api_networking_v1beta1_parent_reference(&node.parent_ref);
+}
+
+/// Visits a value of type [`ParentReference`](crate::api::networking::v1beta1::ParentReference), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1beta1_parent_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1beta1::ParentReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServiceCIDR`](crate::api::networking::v1beta1::ServiceCIDR).
+pub fn visit_api_networking_v1beta1_service_cidr<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1beta1::ServiceCIDR) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_networking_v1beta1_service_cidr_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_networking_v1beta1_service_cidr_status(it);
+    }
+}
+
+/// Visits a value of type [`ServiceCIDRSpec`](crate::api::networking::v1beta1::ServiceCIDRSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1beta1_service_cidr_spec<'ast, V>(_v: &mut V, _node: &'ast crate::api::networking::v1beta1::ServiceCIDRSpec) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServiceCIDRStatus`](crate::api::networking::v1beta1::ServiceCIDRStatus).
+pub fn visit_api_networking_v1beta1_service_cidr_status<'ast, V>(v: &mut V, node: &'ast crate::api::networking::v1beta1::ServiceCIDRStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Overhead`](crate::api::node::v1::Overhead).
+pub fn visit_api_node_v1_overhead<'ast, V>(v: &mut V, node: &'ast crate::api::node::v1::Overhead) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.pod_fixed {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RuntimeClass`](crate::api::node::v1::RuntimeClass).
+pub fn visit_api_node_v1_runtime_class<'ast, V>(v: &mut V, node: &'ast crate::api::node::v1::RuntimeClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.overhead {
+        v.visit_api_node_v1_overhead(it);
+    }
+    if let Some(it) = &node.scheduling {
+        v.visit_api_node_v1_scheduling(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Scheduling`](crate::api::node::v1::Scheduling).
+pub fn visit_api_node_v1_scheduling<'ast, V>(v: &mut V, node: &'ast crate::api::node::v1::Scheduling) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.tolerations {
+        for it in it {
+            v.visit_api_core_v1_toleration(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Eviction`](crate::api::policy::v1::Eviction).
+pub fn visit_api_policy_v1_eviction<'ast, V>(v: &mut V, node: &'ast crate::api::policy::v1::Eviction) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.delete_options {
+        v.visit_apimachinery_pkg_apis_meta_v1_delete_options(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudget`](crate::api::policy::v1::PodDisruptionBudget).
+pub fn visit_api_policy_v1_pod_disruption_budget<'ast, V>(v: &mut V, node: &'ast crate::api::policy::v1::PodDisruptionBudget) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_policy_v1_pod_disruption_budget_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_policy_v1_pod_disruption_budget_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudgetSpec`](crate::api::policy::v1::PodDisruptionBudgetSpec).
+pub fn visit_api_policy_v1_pod_disruption_budget_spec<'ast, V>(v: &mut V, node: &'ast crate::api::policy::v1::PodDisruptionBudgetSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+    if let Some(it) = &node.min_available {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(it);
+    }
+    if let Some(it) = &node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudgetStatus`](crate::api::policy::v1::PodDisruptionBudgetStatus).
+pub fn visit_api_policy_v1_pod_disruption_budget_status<'ast, V>(v: &mut V, node: &'ast crate::api::policy::v1::PodDisruptionBudgetStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.disrupted_pods {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`AggregationRule`](crate::api::rbac::v1::AggregationRule).
+pub fn visit_api_rbac_v1_aggregation_rule<'ast, V>(v: &mut V, node: &'ast crate::api::rbac::v1::AggregationRule) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.cluster_role_selectors {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterRole`](crate::api::rbac::v1::ClusterRole).
+pub fn visit_api_rbac_v1_cluster_role<'ast, V>(v: &mut V, node: &'ast crate::api::rbac::v1::ClusterRole) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.aggregation_rule {
+        v.visit_api_rbac_v1_aggregation_rule(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_rbac_v1_policy_rule(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterRoleBinding`](crate::api::rbac::v1::ClusterRoleBinding).
+pub fn visit_api_rbac_v1_cluster_role_binding<'ast, V>(v: &mut V, node: &'ast crate::api::rbac::v1::ClusterRoleBinding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_rbac_v1_role_ref(&node.role_ref);
+    if let Some(it) = &node.subjects {
+        for it in it {
+            v.visit_api_rbac_v1_subject(it);
+        }
+    }
+}
+
+/// Visits a value of type [`PolicyRule`](crate::api::rbac::v1::PolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_policy_rule<'ast, V>(_v: &mut V, _node: &'ast crate::api::rbac::v1::PolicyRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Role`](crate::api::rbac::v1::Role).
+pub fn visit_api_rbac_v1_role<'ast, V>(v: &mut V, node: &'ast crate::api::rbac::v1::Role) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.rules {
+        for it in it {
+            v.visit_api_rbac_v1_policy_rule(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RoleBinding`](crate::api::rbac::v1::RoleBinding).
+pub fn visit_api_rbac_v1_role_binding<'ast, V>(v: &mut V, node: &'ast crate::api::rbac::v1::RoleBinding) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_rbac_v1_role_ref(&node.role_ref);
+    if let Some(it) = &node.subjects {
+        for it in it {
+            v.visit_api_rbac_v1_subject(it);
+        }
+    }
+}
+
+/// Visits a value of type [`RoleRef`](crate::api::rbac::v1::RoleRef), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_role_ref<'ast, V>(_v: &mut V, _node: &'ast crate::api::rbac::v1::RoleRef) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`Subject`](crate::api::rbac::v1::Subject), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_subject<'ast, V>(_v: &mut V, _node: &'ast crate::api::rbac::v1::Subject) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1alpha3::AllocatedDeviceStatus).
+pub fn visit_api_resource_v1alpha3_allocated_device_status<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::AllocatedDeviceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension(it);
+    }
+    if let Some(it) = &node.network_data {
+        v.visit_api_resource_v1alpha3_network_device_data(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`AllocationResult`](crate::api::resource::v1alpha3::AllocationResult).
+pub fn visit_api_resource_v1alpha3_allocation_result<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::AllocationResult) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        v.visit_api_resource_v1alpha3_device_allocation_result(it);
+    }
+    if let Some(it) = &node.node_selector {
+        v.visit_api_core_v1_node_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`BasicDevice`](crate::api::resource::v1alpha3::BasicDevice).
+pub fn visit_api_resource_v1alpha3_basic_device<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::BasicDevice) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.attributes {
+        for it in it.values() {
+            v.visit_api_resource_v1alpha3_device_attribute(it);
+        }
+    }
+    if let Some(it) = &node.capacity {
+        for it in it.values() {
+            v.visit_apimachinery_pkg_api_resource_quantity(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1alpha3::CELDeviceSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_cel_device_selector<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::CELDeviceSelector) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Device`](crate::api::resource::v1alpha3::Device).
+pub fn visit_api_resource_v1alpha3_device<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::Device) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.basic {
+        v.visit_api_resource_v1alpha3_basic_device(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1alpha3::DeviceAllocationConfiguration).
+pub fn visit_api_resource_v1alpha3_device_allocation_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceAllocationConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationResult`](crate::api::resource::v1alpha3::DeviceAllocationResult).
+pub fn visit_api_resource_v1alpha3_device_allocation_result<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceAllocationResult) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_allocation_configuration(it);
+        }
+    }
+    if let Some(it) = &node.results {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_request_allocation_result(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1alpha3::DeviceAttribute), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_attribute<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::DeviceAttribute) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceClaim`](crate::api::resource::v1alpha3::DeviceClaim).
+pub fn visit_api_resource_v1alpha3_device_claim<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceClaim) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_claim_configuration(it);
+        }
+    }
+    if let Some(it) = &node.constraints {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_constraint(it);
+        }
+    }
+    if let Some(it) = &node.requests {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_request(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1alpha3::DeviceClaimConfiguration).
+pub fn visit_api_resource_v1alpha3_device_claim_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceClaimConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClass`](crate::api::resource::v1alpha3::DeviceClass).
+pub fn visit_api_resource_v1alpha3_device_class<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1alpha3_device_class_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`DeviceClassConfiguration`](crate::api::resource::v1alpha3::DeviceClassConfiguration).
+pub fn visit_api_resource_v1alpha3_device_class_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceClassConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClassSpec`](crate::api::resource::v1alpha3::DeviceClassSpec).
+pub fn visit_api_resource_v1alpha3_device_class_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceClassSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_class_configuration(it);
+        }
+    }
+    if let Some(it) = &node.selectors {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_selector(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1alpha3::DeviceConstraint), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_constraint<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::DeviceConstraint) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceRequest`](crate::api::resource::v1alpha3::DeviceRequest).
+pub fn visit_api_resource_v1alpha3_device_request<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceRequest) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selectors {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_selector(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1alpha3::DeviceRequestAllocationResult), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_request_allocation_result<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::DeviceRequestAllocationResult) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceSelector`](crate::api::resource::v1alpha3::DeviceSelector).
+pub fn visit_api_resource_v1alpha3_device_selector<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::DeviceSelector) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.cel {
+        v.visit_api_resource_v1alpha3_cel_device_selector(it);
+    }
+}
+
+/// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1alpha3::NetworkDeviceData), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_network_device_data<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::NetworkDeviceData) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1alpha3::OpaqueDeviceConfiguration).
+pub fn visit_api_resource_v1alpha3_opaque_device_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::OpaqueDeviceConfiguration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_runtime_raw_extension(&node.parameters);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaim`](crate::api::resource::v1alpha3::ResourceClaim).
+pub fn visit_api_resource_v1alpha3_resource_claim<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceClaim) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1alpha3_resource_claim_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_resource_v1alpha3_resource_claim_status(it);
+    }
+}
+
+/// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1alpha3::ResourceClaimConsumerReference), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_resource_claim_consumer_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::ResourceClaimConsumerReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimSpec`](crate::api::resource::v1alpha3::ResourceClaimSpec).
+pub fn visit_api_resource_v1alpha3_resource_claim_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceClaimSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        v.visit_api_resource_v1alpha3_device_claim(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimStatus`](crate::api::resource::v1alpha3::ResourceClaimStatus).
+pub fn visit_api_resource_v1alpha3_resource_claim_status<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceClaimStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allocation {
+        v.visit_api_resource_v1alpha3_allocation_result(it);
+    }
+    if let Some(it) = &node.devices {
+        for it in it {
+            v.visit_api_resource_v1alpha3_allocated_device_status(it);
+        }
+    }
+    if let Some(it) = &node.reserved_for {
+        for it in it {
+            v.visit_api_resource_v1alpha3_resource_claim_consumer_reference(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplate`](crate::api::resource::v1alpha3::ResourceClaimTemplate).
+pub fn visit_api_resource_v1alpha3_resource_claim_template<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceClaimTemplate) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1alpha3_resource_claim_template_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1alpha3::ResourceClaimTemplateSpec).
+pub fn visit_api_resource_v1alpha3_resource_claim_template_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceClaimTemplateSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(it);
+    }
+    v.visit_api_resource_v1alpha3_resource_claim_spec(&node.spec);
+}
+
+/// Visits a value of type [`ResourcePool`](crate::api::resource::v1alpha3::ResourcePool), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_resource_pool<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1alpha3::ResourcePool) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceSlice`](crate::api::resource::v1alpha3::ResourceSlice).
+pub fn visit_api_resource_v1alpha3_resource_slice<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceSlice) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1alpha3_resource_slice_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceSliceSpec`](crate::api::resource::v1alpha3::ResourceSliceSpec).
+pub fn visit_api_resource_v1alpha3_resource_slice_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1alpha3::ResourceSliceSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device(it);
+        }
+    }
+    if let Some(it) = &node.node_selector {
+        v.visit_api_core_v1_node_selector(it);
+    }
+    v.visit_api_resource_v1alpha3_resource_pool(&node.pool);
+}
+
+/// Visits the values contained in a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1beta1::AllocatedDeviceStatus).
+pub fn visit_api_resource_v1beta1_allocated_device_status<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::AllocatedDeviceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition(it);
+        }
+    }
+    if let Some(it) = &node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension(it);
+    }
+    if let Some(it) = &node.network_data {
+        v.visit_api_resource_v1beta1_network_device_data(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`AllocationResult`](crate::api::resource::v1beta1::AllocationResult).
+pub fn visit_api_resource_v1beta1_allocation_result<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::AllocationResult) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        v.visit_api_resource_v1beta1_device_allocation_result(it);
+    }
+    if let Some(it) = &node.node_selector {
+        v.visit_api_core_v1_node_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`BasicDevice`](crate::api::resource::v1beta1::BasicDevice).
+pub fn visit_api_resource_v1beta1_basic_device<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::BasicDevice) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.attributes {
+        for it in it.values() {
+            v.visit_api_resource_v1beta1_device_attribute(it);
+        }
+    }
+    if let Some(it) = &node.capacity {
+        for it in it.values() {
+            v.visit_api_resource_v1beta1_device_capacity(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1beta1::CELDeviceSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_cel_device_selector<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::CELDeviceSelector) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Device`](crate::api::resource::v1beta1::Device).
+pub fn visit_api_resource_v1beta1_device<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::Device) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.basic {
+        v.visit_api_resource_v1beta1_basic_device(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1beta1::DeviceAllocationConfiguration).
+pub fn visit_api_resource_v1beta1_device_allocation_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceAllocationConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationResult`](crate::api::resource::v1beta1::DeviceAllocationResult).
+pub fn visit_api_resource_v1beta1_device_allocation_result<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceAllocationResult) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_allocation_configuration(it);
+        }
+    }
+    if let Some(it) = &node.results {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_request_allocation_result(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1beta1::DeviceAttribute), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_attribute<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::DeviceAttribute) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceCapacity`](crate::api::resource::v1beta1::DeviceCapacity).
+pub fn visit_api_resource_v1beta1_device_capacity<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceCapacity) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_api_resource_quantity(&node.value);
+}
+
+/// Visits the values contained in a value of type [`DeviceClaim`](crate::api::resource::v1beta1::DeviceClaim).
+pub fn visit_api_resource_v1beta1_device_claim<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceClaim) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_claim_configuration(it);
+        }
+    }
+    if let Some(it) = &node.constraints {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_constraint(it);
+        }
+    }
+    if let Some(it) = &node.requests {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_request(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1beta1::DeviceClaimConfiguration).
+pub fn visit_api_resource_v1beta1_device_claim_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceClaimConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClass`](crate::api::resource::v1beta1::DeviceClass).
+pub fn visit_api_resource_v1beta1_device_class<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1beta1_device_class_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`DeviceClassConfiguration`](crate::api::resource::v1beta1::DeviceClassConfiguration).
+pub fn visit_api_resource_v1beta1_device_class_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceClassConfiguration) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClassSpec`](crate::api::resource::v1beta1::DeviceClassSpec).
+pub fn visit_api_resource_v1beta1_device_class_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceClassSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_class_configuration(it);
+        }
+    }
+    if let Some(it) = &node.selectors {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_selector(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1beta1::DeviceConstraint), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_constraint<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::DeviceConstraint) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceRequest`](crate::api::resource::v1beta1::DeviceRequest).
+pub fn visit_api_resource_v1beta1_device_request<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceRequest) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.selectors {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_selector(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1beta1::DeviceRequestAllocationResult), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_request_allocation_result<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::DeviceRequestAllocationResult) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceSelector`](crate::api::resource::v1beta1::DeviceSelector).
+pub fn visit_api_resource_v1beta1_device_selector<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::DeviceSelector) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.cel {
+        v.visit_api_resource_v1beta1_cel_device_selector(it);
+    }
+}
+
+/// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1beta1::NetworkDeviceData), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_network_device_data<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::NetworkDeviceData) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1beta1::OpaqueDeviceConfiguration).
+pub fn visit_api_resource_v1beta1_opaque_device_configuration<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::OpaqueDeviceConfiguration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_runtime_raw_extension(&node.parameters);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaim`](crate::api::resource::v1beta1::ResourceClaim).
+pub fn visit_api_resource_v1beta1_resource_claim<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceClaim) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1beta1_resource_claim_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_resource_v1beta1_resource_claim_status(it);
+    }
+}
+
+/// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1beta1::ResourceClaimConsumerReference), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_resource_claim_consumer_reference<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::ResourceClaimConsumerReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimSpec`](crate::api::resource::v1beta1::ResourceClaimSpec).
+pub fn visit_api_resource_v1beta1_resource_claim_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceClaimSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        v.visit_api_resource_v1beta1_device_claim(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimStatus`](crate::api::resource::v1beta1::ResourceClaimStatus).
+pub fn visit_api_resource_v1beta1_resource_claim_status<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceClaimStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allocation {
+        v.visit_api_resource_v1beta1_allocation_result(it);
+    }
+    if let Some(it) = &node.devices {
+        for it in it {
+            v.visit_api_resource_v1beta1_allocated_device_status(it);
+        }
+    }
+    if let Some(it) = &node.reserved_for {
+        for it in it {
+            v.visit_api_resource_v1beta1_resource_claim_consumer_reference(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplate`](crate::api::resource::v1beta1::ResourceClaimTemplate).
+pub fn visit_api_resource_v1beta1_resource_claim_template<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceClaimTemplate) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1beta1_resource_claim_template_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1beta1::ResourceClaimTemplateSpec).
+pub fn visit_api_resource_v1beta1_resource_claim_template_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceClaimTemplateSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(it);
+    }
+    v.visit_api_resource_v1beta1_resource_claim_spec(&node.spec);
+}
+
+/// Visits a value of type [`ResourcePool`](crate::api::resource::v1beta1::ResourcePool), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_resource_pool<'ast, V>(_v: &mut V, _node: &'ast crate::api::resource::v1beta1::ResourcePool) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceSlice`](crate::api::resource::v1beta1::ResourceSlice).
+pub fn visit_api_resource_v1beta1_resource_slice<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceSlice) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_resource_v1beta1_resource_slice_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceSliceSpec`](crate::api::resource::v1beta1::ResourceSliceSpec).
+pub fn visit_api_resource_v1beta1_resource_slice_spec<'ast, V>(v: &mut V, node: &'ast crate::api::resource::v1beta1::ResourceSliceSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.devices {
+        for it in it {
+            v.visit_api_resource_v1beta1_device(it);
+        }
+    }
+    if let Some(it) = &node.node_selector {
+        v.visit_api_core_v1_node_selector(it);
+    }
+    v.visit_api_resource_v1beta1_resource_pool(&node.pool);
+}
+
+/// Visits the values contained in a value of type [`PriorityClass`](crate::api::scheduling::v1::PriorityClass).
+pub fn visit_api_scheduling_v1_priority_class<'ast, V>(v: &mut V, node: &'ast crate::api::scheduling::v1::PriorityClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits the values contained in a value of type [`CSIDriver`](crate::api::storage::v1::CSIDriver).
+pub fn visit_api_storage_v1_csi_driver<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSIDriver) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_storage_v1_csi_driver_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`CSIDriverSpec`](crate::api::storage::v1::CSIDriverSpec).
+pub fn visit_api_storage_v1_csi_driver_spec<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSIDriverSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.token_requests {
+        for it in it {
+            v.visit_api_storage_v1_token_request(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`CSINode`](crate::api::storage::v1::CSINode).
+pub fn visit_api_storage_v1_csi_node<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSINode) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_storage_v1_csi_node_spec(&node.spec);
+}
+
+/// Visits the values contained in a value of type [`CSINodeDriver`](crate::api::storage::v1::CSINodeDriver).
+pub fn visit_api_storage_v1_csi_node_driver<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSINodeDriver) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allocatable {
+        v.visit_api_storage_v1_volume_node_resources(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSINodeSpec`](crate::api::storage::v1::CSINodeSpec).
+pub fn visit_api_storage_v1_csi_node_spec<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSINodeSpec) where V: Visit<'ast> + ?Sized {
+    for it in &node.drivers {
+        v.visit_api_storage_v1_csi_node_driver(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSIStorageCapacity`](crate::api::storage::v1::CSIStorageCapacity).
+pub fn visit_api_storage_v1_csi_storage_capacity<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::CSIStorageCapacity) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.capacity {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+    if let Some(it) = &node.maximum_volume_size {
+        v.visit_apimachinery_pkg_api_resource_quantity(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.node_topology {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageClass`](crate::api::storage::v1::StorageClass).
+pub fn visit_api_storage_v1_storage_class<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::StorageClass) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.allowed_topologies {
+        for it in it {
+            v.visit_api_core_v1_topology_selector_term(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits a value of type [`TokenRequest`](crate::api::storage::v1::TokenRequest), which does not contain values of other types that can be visited.
+pub fn visit_api_storage_v1_token_request<'ast, V>(_v: &mut V, _node: &'ast crate::api::storage::v1::TokenRequest) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachment`](crate::api::storage::v1::VolumeAttachment).
+pub fn visit_api_storage_v1_volume_attachment<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::VolumeAttachment) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_api_storage_v1_volume_attachment_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_api_storage_v1_volume_attachment_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentSource`](crate::api::storage::v1::VolumeAttachmentSource).
+pub fn visit_api_storage_v1_volume_attachment_source<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::VolumeAttachmentSource) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.inline_volume_spec {
+        v.visit_api_core_v1_persistent_volume_spec(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentSpec`](crate::api::storage::v1::VolumeAttachmentSpec).
+pub fn visit_api_storage_v1_volume_attachment_spec<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::VolumeAttachmentSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_api_storage_v1_volume_attachment_source(&node.source);
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentStatus`](crate::api::storage::v1::VolumeAttachmentStatus).
+pub fn visit_api_storage_v1_volume_attachment_status<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::VolumeAttachmentStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.attach_error {
+        v.visit_api_storage_v1_volume_error(it);
+    }
+    if let Some(it) = &node.detach_error {
+        v.visit_api_storage_v1_volume_error(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeError`](crate::api::storage::v1::VolumeError).
+pub fn visit_api_storage_v1_volume_error<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1::VolumeError) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`VolumeNodeResources`](crate::api::storage::v1::VolumeNodeResources), which does not contain values of other types that can be visited.
+pub fn visit_api_storage_v1_volume_node_resources<'ast, V>(_v: &mut V, _node: &'ast crate::api::storage::v1::VolumeNodeResources) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeAttributesClass`](crate::api::storage::v1alpha1::VolumeAttributesClass).
+pub fn visit_api_storage_v1alpha1_volume_attributes_class<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1alpha1::VolumeAttributesClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits the values contained in a value of type [`VolumeAttributesClass`](crate::api::storage::v1beta1::VolumeAttributesClass).
+pub fn visit_api_storage_v1beta1_volume_attributes_class<'ast, V>(v: &mut V, node: &'ast crate::api::storage::v1beta1::VolumeAttributesClass) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+}
+
+/// Visits a value of type [`GroupVersionResource`](crate::api::storagemigration::v1alpha1::GroupVersionResource), which does not contain values of other types that can be visited.
+pub fn visit_api_storagemigration_v1alpha1_group_version_resource<'ast, V>(_v: &mut V, _node: &'ast crate::api::storagemigration::v1alpha1::GroupVersionResource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MigrationCondition`](crate::api::storagemigration::v1alpha1::MigrationCondition).
+pub fn visit_api_storagemigration_v1alpha1_migration_condition<'ast, V>(v: &mut V, node: &'ast crate::api::storagemigration::v1alpha1::MigrationCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigration`](crate::api::storagemigration::v1alpha1::StorageVersionMigration).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration<'ast, V>(v: &mut V, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigration) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigrationSpec`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration_spec<'ast, V>(v: &mut V, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec) where V: Visit<'ast> + ?Sized {
+    v.visit_api_storagemigration_v1alpha1_group_version_resource(&node.resource);
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigrationStatus`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration_status<'ast, V>(v: &mut V, node: &'ast crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_api_storagemigration_v1alpha1_migration_condition(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CustomResourceColumnDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.webhook {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec(&node.spec);
+    if let Some(it) = &node.status {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionCondition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`CustomResourceDefinitionNames`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionSpec`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conversion {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion(it);
+    }
+    v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names(&node.names);
+    for it in &node.versions {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.accepted_names {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names(it);
+    }
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionVersion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.additional_printer_columns {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition(it);
+        }
+    }
+    if let Some(it) = &node.schema {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation(it);
+    }
+    if let Some(it) = &node.selectable_fields {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field(it);
+        }
+    }
+    if let Some(it) = &node.subresources {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources(it);
+    }
+}
+
+/// Visits a value of type [`CustomResourceSubresourceScale`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`CustomResourceSubresourceStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceSubresources`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.scale {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceValidation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.open_api_v3_schema {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+    }
+}
+
+/// Visits a value of type [`ExternalDocumentation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`JSON`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaProps`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.additional_items {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool(it);
+    }
+    if let Some(it) = &node.additional_properties {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool(it);
+    }
+    if let Some(it) = &node.all_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.any_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.default {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(it);
+    }
+    if let Some(it) = &node.definitions {
+        for it in it.values() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.dependencies {
+        for it in it.values() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array(it);
+        }
+    }
+    if let Some(it) = &node.enum_ {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(it);
+        }
+    }
+    if let Some(it) = &node.example {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(it);
+    }
+    if let Some(it) = &node.external_docs {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation(it);
+    }
+    if let Some(it) = &node.items {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array(it);
+    }
+    if let Some(it) = &node.not {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+    }
+    if let Some(it) = &node.one_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.pattern_properties {
+        for it in it.values() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.properties {
+        for it in it.values() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        }
+    }
+    if let Some(it) = &node.x_kubernetes_validations {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray) where V: Visit<'ast> + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray::Schemas(it) => {
+            for it in it {
+                v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+            }
+        },
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrBool`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool) where V: Visit<'ast> + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool::Bool(_) => (),
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrStringArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray) where V: Visit<'ast> + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray::Strings(_) => (),
+    }
+}
+
+/// Visits a value of type [`SelectableField`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ServiceReference`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ValidationRule`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule<'ast, V>(_v: &mut V, _node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WebhookClientConfig`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.service {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`WebhookConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion<'ast, V>(v: &mut V, node: &'ast crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.client_config {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config(it);
+    }
+}
+
+/// Visits a value of type [`Quantity`](crate::apimachinery::pkg::api::resource::Quantity), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_api_resource_quantity<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::api::resource::Quantity) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIGroup`](crate::apimachinery::pkg::apis::meta::v1::APIGroup).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_group<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIGroup) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.preferred_version {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery(it);
+    }
+    if let Some(it) = &node.server_address_by_client_cidrs {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr(it);
+        }
+    }
+    for it in &node.versions {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIGroupList`](crate::apimachinery::pkg::apis::meta::v1::APIGroupList).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_group_list<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIGroupList) where V: Visit<'ast> + ?Sized {
+    for it in &node.groups {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group(it);
+    }
+}
+
+/// Visits a value of type [`APIResource`](crate::apimachinery::pkg::apis::meta::v1::APIResource), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_resource<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIResource) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_resource_list<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIResourceList) where V: Visit<'ast> + ?Sized {
+    for it in &node.resources {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIVersions`](crate::apimachinery::pkg::apis::meta::v1::APIVersions).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_versions<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::APIVersions) where V: Visit<'ast> + ?Sized {
+    for it in &node.server_address_by_client_cidrs {
+        v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Condition`](crate::apimachinery::pkg::apis::meta::v1::Condition).
+pub fn visit_apimachinery_pkg_apis_meta_v1_condition<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Condition) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_time(&node.last_transition_time);
+}
+
+/// Visits the values contained in a value of type [`DeleteOptions`](crate::apimachinery::pkg::apis::meta::v1::DeleteOptions).
+pub fn visit_apimachinery_pkg_apis_meta_v1_delete_options<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::DeleteOptions) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.preconditions {
+        v.visit_apimachinery_pkg_apis_meta_v1_preconditions(it);
+    }
+}
+
+/// Visits a value of type [`FieldSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`FieldsV1`](crate::apimachinery::pkg::apis::meta::v1::FieldsV1), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_fields_v1<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::FieldsV1) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`GroupVersionForDiscovery`](crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LabelSelector`](crate::apimachinery::pkg::apis::meta::v1::LabelSelector).
+pub fn visit_apimachinery_pkg_apis_meta_v1_label_selector<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::LabelSelector) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.match_expressions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement(it);
+        }
+    }
+}
+
+/// Visits a value of type [`LabelSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ListMeta`](crate::apimachinery::pkg::apis::meta::v1::ListMeta), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_list_meta<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::ListMeta) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ManagedFieldsEntry`](crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry).
+pub fn visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.fields_v1 {
+        v.visit_apimachinery_pkg_apis_meta_v1_fields_v1(it);
+    }
+    if let Some(it) = &node.time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits a value of type [`MicroTime`](crate::apimachinery::pkg::apis::meta::v1::MicroTime), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_micro_time<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::MicroTime) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ObjectMeta`](crate::apimachinery::pkg::apis::meta::v1::ObjectMeta).
+pub fn visit_apimachinery_pkg_apis_meta_v1_object_meta<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.creation_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.deletion_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+    if let Some(it) = &node.managed_fields {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry(it);
+        }
+    }
+    if let Some(it) = &node.owner_references {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_owner_reference(it);
+        }
+    }
+}
+
+/// Visits a value of type [`OwnerReference`](crate::apimachinery::pkg::apis::meta::v1::OwnerReference), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_owner_reference<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::OwnerReference) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`Preconditions`](crate::apimachinery::pkg::apis::meta::v1::Preconditions), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_preconditions<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::Preconditions) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`ServerAddressByClientCIDR`](crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Status`](crate::apimachinery::pkg::apis::meta::v1::Status).
+pub fn visit_apimachinery_pkg_apis_meta_v1_status<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::Status) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.details {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_details(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_list_meta(&node.metadata);
+}
+
+/// Visits a value of type [`StatusCause`](crate::apimachinery::pkg::apis::meta::v1::StatusCause), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_status_cause<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::StatusCause) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StatusDetails`](crate::apimachinery::pkg::apis::meta::v1::StatusDetails).
+pub fn visit_apimachinery_pkg_apis_meta_v1_status_details<'ast, V>(v: &mut V, node: &'ast crate::apimachinery::pkg::apis::meta::v1::StatusDetails) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.causes {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_status_cause(it);
+        }
+    }
+}
+
+/// Visits a value of type [`Time`](crate::apimachinery::pkg::apis::meta::v1::Time), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_time<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::apis::meta::v1::Time) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`RawExtension`](crate::apimachinery::pkg::runtime::RawExtension), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_runtime_raw_extension<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::runtime::RawExtension) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`IntOrString`](crate::apimachinery::pkg::util::intstr::IntOrString), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_util_intstr_int_or_string<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::util::intstr::IntOrString) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits a value of type [`Info`](crate::apimachinery::pkg::version::Info), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_version_info<'ast, V>(_v: &mut V, _node: &'ast crate::apimachinery::pkg::version::Info) where V: Visit<'ast> + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIService`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service<'ast, V>(v: &mut V, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) where V: Visit<'ast> + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta(&node.metadata);
+    if let Some(it) = &node.spec {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec(it);
+    }
+    if let Some(it) = &node.status {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceCondition`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition<'ast, V>(v: &mut V, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceSpec`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec<'ast, V>(v: &mut V, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.service {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceStatus`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status<'ast, V>(v: &mut V, node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus) where V: Visit<'ast> + ?Sized {
+    if let Some(it) = &node.conditions {
+        for it in it {
+            v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ServiceReference`](crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference<'ast, V>(_v: &mut V, _node: &'ast crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference) where V: Visit<'ast> + ?Sized {
+}
+
+impl Visitable for crate::AnyResource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_any_resource(self);
+    }
+}
+
+impl<T> Visitable for crate::List<T> where T: crate::ListableResource + Visitable {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_list(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::AuditAnnotation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_audit_annotation(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ExpressionWarning {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_expression_warning(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::MatchCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_match_condition(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::MatchResources {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_match_resources(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::MutatingWebhook {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_mutating_webhook(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::MutatingWebhookConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_mutating_webhook_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::NamedRuleWithOperations {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_named_rule_with_operations(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ParamKind {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_param_kind(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ParamRef {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_param_ref(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::RuleWithOperations {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_rule_with_operations(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ServiceReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_service_reference(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::TypeChecking {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_type_checking(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingAdmissionPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_status(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingWebhook {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_webhook(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::ValidatingWebhookConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_webhook_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::Validation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_validation(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::Variable {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_variable(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1::WebhookClientConfig {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1_webhook_client_config(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::ApplyConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_apply_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::JSONPatch {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_json_patch(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MatchCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_match_condition(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MatchResources {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_match_resources(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::Mutation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutation(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::ParamKind {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_param_kind(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::ParamRef {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_param_ref(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1alpha1::Variable {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_variable(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::AuditAnnotation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_audit_annotation(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ExpressionWarning {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_expression_warning(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::MatchCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_match_condition(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::MatchResources {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_match_resources(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::NamedRuleWithOperations {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_named_rule_with_operations(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ParamKind {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_param_kind(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ParamRef {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_param_ref(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::TypeChecking {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_type_checking(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_spec(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_status(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::Validation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validation(self);
+    }
+}
+
+impl Visitable for crate::api::admissionregistration::v1beta1::Variable {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_variable(self);
+    }
+}
+
+impl Visitable for crate::api::apiserverinternal::v1alpha1::ServerStorageVersion {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_server_storage_version(self);
+    }
+}
+
+impl Visitable for crate::api::apiserverinternal::v1alpha1::StorageVersion {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version(self);
+    }
+}
+
+impl Visitable for crate::api::apiserverinternal::v1alpha1::StorageVersionCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_condition(self);
+    }
+}
+
+impl Visitable for crate::api::apiserverinternal::v1alpha1::StorageVersionSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_spec(self);
+    }
+}
+
+impl Visitable for crate::api::apiserverinternal::v1alpha1::StorageVersionStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_status(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::ControllerRevision {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_controller_revision(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DaemonSet {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_daemon_set(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DaemonSetCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_daemon_set_condition(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DaemonSetSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_daemon_set_spec(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DaemonSetStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_daemon_set_status(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DaemonSetUpdateStrategy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_daemon_set_update_strategy(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::Deployment {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_deployment(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DeploymentCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_deployment_condition(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DeploymentSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_deployment_spec(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DeploymentStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_deployment_status(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::DeploymentStrategy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_deployment_strategy(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::ReplicaSet {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_replica_set(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::ReplicaSetCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_replica_set_condition(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::ReplicaSetSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_replica_set_spec(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::ReplicaSetStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_replica_set_status(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::RollingUpdateDaemonSet {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_rolling_update_daemon_set(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::RollingUpdateDeployment {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_rolling_update_deployment(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::RollingUpdateStatefulSetStrategy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_rolling_update_stateful_set_strategy(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSet {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_condition(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetOrdinals {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_ordinals(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_spec(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_status(self);
+    }
+}
+
+impl Visitable for crate::api::apps::v1::StatefulSetUpdateStrategy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_apps_v1_stateful_set_update_strategy(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::BoundObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_bound_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::SelfSubjectReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_self_subject_review(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::SelfSubjectReviewStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_self_subject_review_status(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenRequest {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_request(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenRequestSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_request_spec(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenRequestStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_request_status(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_review(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenReviewSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_review_spec(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::TokenReviewStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_token_review_status(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1::UserInfo {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1_user_info(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1beta1::SelfSubjectReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1beta1_self_subject_review(self);
+    }
+}
+
+impl Visitable for crate::api::authentication::v1beta1::SelfSubjectReviewStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authentication_v1beta1_self_subject_review_status(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::FieldSelectorAttributes {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_field_selector_attributes(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::LabelSelectorAttributes {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_label_selector_attributes(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::LocalSubjectAccessReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_local_subject_access_review(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::NonResourceAttributes {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_non_resource_attributes(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::NonResourceRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_non_resource_rule(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::ResourceAttributes {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_resource_attributes(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::ResourceRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_resource_rule(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SelfSubjectAccessReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_self_subject_access_review(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SelfSubjectAccessReviewSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_self_subject_access_review_spec(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SelfSubjectRulesReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_self_subject_rules_review(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SelfSubjectRulesReviewSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_self_subject_rules_review_spec(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SubjectAccessReview {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SubjectAccessReviewSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review_spec(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SubjectAccessReviewStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review_status(self);
+    }
+}
+
+impl Visitable for crate::api::authorization::v1::SubjectRulesReviewStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_authorization_v1_subject_rules_review_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::CrossVersionObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_cross_version_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::HorizontalPodAutoscaler {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::Scale {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_scale(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::ScaleSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_scale_spec(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v1::ScaleStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v1_scale_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ContainerResourceMetricSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_container_resource_metric_source(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ContainerResourceMetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_container_resource_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::CrossVersionObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_cross_version_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ExternalMetricSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_external_metric_source(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ExternalMetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_external_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HPAScalingPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_hpa_scaling_policy(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HPAScalingRules {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HorizontalPodAutoscaler {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::MetricIdentifier {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_metric_identifier(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::MetricSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_metric_spec(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::MetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::MetricTarget {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_metric_target(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::MetricValueStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_metric_value_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ObjectMetricSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_object_metric_source(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ObjectMetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_object_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::PodsMetricSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_pods_metric_source(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::PodsMetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_pods_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ResourceMetricSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_resource_metric_source(self);
+    }
+}
+
+impl Visitable for crate::api::autoscaling::v2::ResourceMetricStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_autoscaling_v2_resource_metric_status(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::CronJob {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_cron_job(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::CronJobSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_cron_job_spec(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::CronJobStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_cron_job_status(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::Job {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_job(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::JobCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_job_condition(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::JobSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_job_spec(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::JobStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_job_status(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::JobTemplateSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_job_template_spec(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::PodFailurePolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::PodFailurePolicyRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_rule(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::SuccessPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_success_policy(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::SuccessPolicyRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_success_policy_rule(self);
+    }
+}
+
+impl Visitable for crate::api::batch::v1::UncountedTerminatedPods {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_batch_v1_uncounted_terminated_pods(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1::CertificateSigningRequest {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1::CertificateSigningRequestCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_condition(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1::CertificateSigningRequestSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_spec(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1::CertificateSigningRequestStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_status(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1alpha1::ClusterTrustBundle {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1alpha1_cluster_trust_bundle(self);
+    }
+}
+
+impl Visitable for crate::api::certificates::v1alpha1::ClusterTrustBundleSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_certificates_v1alpha1_cluster_trust_bundle_spec(self);
+    }
+}
+
+impl Visitable for crate::api::coordination::v1::Lease {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_coordination_v1_lease(self);
+    }
+}
+
+impl Visitable for crate::api::coordination::v1::LeaseSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_coordination_v1_lease_spec(self);
+    }
+}
+
+impl Visitable for crate::api::coordination::v1alpha2::LeaseCandidate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_coordination_v1alpha2_lease_candidate(self);
+    }
+}
+
+impl Visitable for crate::api::coordination::v1alpha2::LeaseCandidateSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_coordination_v1alpha2_lease_candidate_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AWSElasticBlockStoreVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Affinity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_affinity(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AppArmorProfile {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_app_armor_profile(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AttachedVolume {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_attached_volume(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AzureDiskVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_azure_disk_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AzureFilePersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_azure_file_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::AzureFileVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_azure_file_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Binding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_binding(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CSIPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_csi_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CSIVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_csi_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Capabilities {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_capabilities(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CephFSPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_ceph_fs_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CephFSVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_ceph_fs_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CinderPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_cinder_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::CinderVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_cinder_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ClientIPConfig {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_client_ip_config(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ClusterTrustBundleProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_cluster_trust_bundle_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ComponentCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_component_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ComponentStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_component_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMap {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMapEnvSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map_env_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMapKeySelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map_key_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMapNodeConfigSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map_node_config_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMapProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ConfigMapVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_config_map_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Container {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerImage {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_image(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerPort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_port(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerResizePolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_resize_policy(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerState {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_state(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerStateRunning {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_state_running(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerStateTerminated {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_state_terminated(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerStateWaiting {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_state_waiting(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ContainerUser {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_container_user(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::DaemonEndpoint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_daemon_endpoint(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::DownwardAPIProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_downward_api_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::DownwardAPIVolumeFile {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_downward_api_volume_file(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::DownwardAPIVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_downward_api_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EmptyDirVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_empty_dir_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EndpointAddress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_endpoint_address(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EndpointPort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_endpoint_port(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EndpointSubset {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_endpoint_subset(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Endpoints {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_endpoints(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EnvFromSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_env_from_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EnvVar {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_env_var(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EnvVarSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_env_var_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EphemeralContainer {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_ephemeral_container(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EphemeralVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_ephemeral_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Event {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_event(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EventSeries {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_event_series(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::EventSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_event_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ExecAction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_exec_action(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::FCVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_fc_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::FlexPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_flex_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::FlexVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_flex_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::FlockerVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_flocker_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::GCEPersistentDiskVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::GRPCAction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_grpc_action(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::GitRepoVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_git_repo_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::GlusterfsPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_glusterfs_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::GlusterfsVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_glusterfs_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::HTTPGetAction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_http_get_action(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::HTTPHeader {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_http_header(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::HostAlias {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_host_alias(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::HostIP {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_host_ip(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::HostPathVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_host_path_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ISCSIPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_iscsi_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ISCSIVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_iscsi_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ImageVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_image_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::KeyToPath {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_key_to_path(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Lifecycle {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_lifecycle(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LifecycleHandler {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_lifecycle_handler(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LimitRange {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_limit_range(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LimitRangeItem {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_limit_range_item(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LimitRangeSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_limit_range_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LinuxContainerUser {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_linux_container_user(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LoadBalancerIngress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_load_balancer_ingress(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LoadBalancerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_load_balancer_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LocalObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_local_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::LocalVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_local_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ModifyVolumeStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_modify_volume_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NFSVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_nfs_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Namespace {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_namespace(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NamespaceCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_namespace_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NamespaceSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_namespace_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NamespaceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_namespace_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Node {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeAddress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_address(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeAffinity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_affinity(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeConfigSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_config_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeConfigStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_config_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeDaemonEndpoints {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_daemon_endpoints(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeFeatures {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_features(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeRuntimeHandler {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_runtime_handler(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeRuntimeHandlerFeatures {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_runtime_handler_features(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeSelectorRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_selector_requirement(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeSelectorTerm {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_selector_term(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::NodeSystemInfo {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_node_system_info(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ObjectFieldSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_object_field_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolume {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaimCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaimSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaimStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaimTemplate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_template(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeClaimVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PersistentVolumeStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_persistent_volume_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PhotonPersistentDiskVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Pod {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodAffinity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_affinity(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodAffinityTerm {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_affinity_term(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodAntiAffinity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_anti_affinity(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodDNSConfig {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_dns_config(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodDNSConfigOption {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_dns_config_option(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodIP {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_ip(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodOS {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_os(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodReadinessGate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_readiness_gate(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodResourceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_resource_claim(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodResourceClaimStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_resource_claim_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodSchedulingGate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_scheduling_gate(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodSecurityContext {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_security_context(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodTemplate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_template(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PodTemplateSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_pod_template_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PortStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_port_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PortworxVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_portworx_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::PreferredSchedulingTerm {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_preferred_scheduling_term(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Probe {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_probe(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ProjectedVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_projected_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::QuobyteVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_quobyte_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::RBDPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_rbd_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::RBDVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_rbd_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ReplicationController {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_replication_controller(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ReplicationControllerCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_replication_controller_condition(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ReplicationControllerSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_replication_controller_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ReplicationControllerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_replication_controller_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_claim(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceFieldSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_field_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceHealth {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_health(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceQuota {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_quota(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceQuotaSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_quota_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceQuotaStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_quota_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceRequirements {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_requirements(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ResourceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_resource_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SELinuxOptions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_se_linux_options(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ScaleIOPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_scale_io_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ScaleIOVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_scale_io_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ScopeSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_scope_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ScopedResourceSelectorRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_scoped_resource_selector_requirement(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SeccompProfile {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_seccomp_profile(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Secret {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecretEnvSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret_env_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecretKeySelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret_key_selector(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecretProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecretReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret_reference(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecretVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_secret_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SecurityContext {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_security_context(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Service {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ServiceAccount {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service_account(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ServiceAccountTokenProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service_account_token_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ServicePort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service_port(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ServiceSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service_spec(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::ServiceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_service_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SessionAffinityConfig {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_session_affinity_config(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::SleepAction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_sleep_action(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::StorageOSPersistentVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_storage_os_persistent_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::StorageOSVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_storage_os_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Sysctl {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_sysctl(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TCPSocketAction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_tcp_socket_action(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Taint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_taint(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Toleration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_toleration(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TopologySelectorLabelRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_topology_selector_label_requirement(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TopologySelectorTerm {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_topology_selector_term(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TopologySpreadConstraint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_topology_spread_constraint(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TypedLocalObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_typed_local_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::TypedObjectReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_typed_object_reference(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::Volume {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeDevice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_device(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeMount {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_mount(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeMountStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_mount_status(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeNodeAffinity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_node_affinity(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeProjection {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_projection(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VolumeResourceRequirements {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_volume_resource_requirements(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::VsphereVirtualDiskVolumeSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::WeightedPodAffinityTerm {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_weighted_pod_affinity_term(self);
+    }
+}
+
+impl Visitable for crate::api::core::v1::WindowsSecurityContextOptions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_core_v1_windows_security_context_options(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::Endpoint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_endpoint(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::EndpointConditions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_endpoint_conditions(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::EndpointHints {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_endpoint_hints(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::EndpointPort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_endpoint_port(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::EndpointSlice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_endpoint_slice(self);
+    }
+}
+
+impl Visitable for crate::api::discovery::v1::ForZone {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_discovery_v1_for_zone(self);
+    }
+}
+
+impl Visitable for crate::api::events::v1::Event {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_events_v1_event(self);
+    }
+}
+
+impl Visitable for crate::api::events::v1::EventSeries {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_events_v1_event_series(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_exempt_priority_level_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::FlowDistinguisherMethod {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_distinguisher_method(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::FlowSchema {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::FlowSchemaCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_condition(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::FlowSchemaSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_spec(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::FlowSchemaStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_status(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::GroupSubject {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_group_subject(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::LimitResponse {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_limit_response(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_limited_priority_level_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::NonResourcePolicyRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_non_resource_policy_rule(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PolicyRulesWithSubjects {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_policy_rules_with_subjects(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PriorityLevelConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_condition(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PriorityLevelConfigurationReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_reference(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_spec(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_status(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::QueuingConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_queuing_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::ResourcePolicyRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_resource_policy_rule(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::ServiceAccountSubject {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_service_account_subject(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::Subject {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_subject(self);
+    }
+}
+
+impl Visitable for crate::api::flowcontrol::v1::UserSubject {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_flowcontrol_v1_user_subject(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::HTTPIngressPath {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_http_ingress_path(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::HTTPIngressRuleValue {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_http_ingress_rule_value(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IPBlock {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ip_block(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::Ingress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressBackend {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_backend(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_class(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressClassParametersReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_class_parameters_reference(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressClassSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_class_spec(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressLoadBalancerIngress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_load_balancer_ingress(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressLoadBalancerStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_load_balancer_status(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressPortStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_port_status(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_rule(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressServiceBackend {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_service_backend(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_spec(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_status(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::IngressTLS {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_ingress_tls(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicy {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicyEgressRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy_egress_rule(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicyIngressRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy_ingress_rule(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicyPeer {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy_peer(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicyPort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy_port(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::NetworkPolicySpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_network_policy_spec(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1::ServiceBackendPort {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1_service_backend_port(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::IPAddress {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_ip_address(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::IPAddressSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_ip_address_spec(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::ParentReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_parent_reference(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::ServiceCIDR {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::ServiceCIDRSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr_spec(self);
+    }
+}
+
+impl Visitable for crate::api::networking::v1beta1::ServiceCIDRStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr_status(self);
+    }
+}
+
+impl Visitable for crate::api::node::v1::Overhead {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_node_v1_overhead(self);
+    }
+}
+
+impl Visitable for crate::api::node::v1::RuntimeClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_node_v1_runtime_class(self);
+    }
+}
+
+impl Visitable for crate::api::node::v1::Scheduling {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_node_v1_scheduling(self);
+    }
+}
+
+impl Visitable for crate::api::policy::v1::Eviction {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_policy_v1_eviction(self);
+    }
+}
+
+impl Visitable for crate::api::policy::v1::PodDisruptionBudget {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget(self);
+    }
+}
+
+impl Visitable for crate::api::policy::v1::PodDisruptionBudgetSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget_spec(self);
+    }
+}
+
+impl Visitable for crate::api::policy::v1::PodDisruptionBudgetStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget_status(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::AggregationRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_aggregation_rule(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::ClusterRole {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_cluster_role(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::ClusterRoleBinding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_cluster_role_binding(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::PolicyRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_policy_rule(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::Role {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_role(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::RoleBinding {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_role_binding(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::RoleRef {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_role_ref(self);
+    }
+}
+
+impl Visitable for crate::api::rbac::v1::Subject {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_rbac_v1_subject(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::AllocatedDeviceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_allocated_device_status(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::AllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::BasicDevice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_basic_device(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::CELDeviceSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_cel_device_selector(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::Device {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceAllocationConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_allocation_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceAllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceAttribute {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_attribute(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_claim(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceClaimConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_claim_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceClassConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceClassSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceConstraint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_constraint(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceRequest {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_request(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceRequestAllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_request_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::DeviceSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_device_selector(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::NetworkDeviceData {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_network_device_data(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::OpaqueDeviceConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaimConsumerReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_consumer_reference(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaimSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaimStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_status(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaimTemplate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_template(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceClaimTemplateSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_template_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourcePool {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_pool(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceSlice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_slice(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1alpha3::ResourceSliceSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_slice_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::AllocatedDeviceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_allocated_device_status(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::AllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::BasicDevice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_basic_device(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::CELDeviceSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_cel_device_selector(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::Device {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceAllocationConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_allocation_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceAllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceAttribute {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_attribute(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceCapacity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_capacity(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_claim(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceClaimConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_claim_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_class(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceClassConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_class_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceClassSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_class_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceConstraint {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_constraint(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceRequest {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_request(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceRequestAllocationResult {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_request_allocation_result(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::DeviceSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_device_selector(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::NetworkDeviceData {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_network_device_data(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::OpaqueDeviceConfiguration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_opaque_device_configuration(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaim {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaimConsumerReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_consumer_reference(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaimSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaimStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_status(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaimTemplate {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_template(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceClaimTemplateSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_template_spec(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourcePool {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_pool(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceSlice {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_slice(self);
+    }
+}
+
+impl Visitable for crate::api::resource::v1beta1::ResourceSliceSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_resource_v1beta1_resource_slice_spec(self);
+    }
+}
+
+impl Visitable for crate::api::scheduling::v1::PriorityClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_scheduling_v1_priority_class(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSIDriver {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_driver(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSIDriverSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_driver_spec(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSINode {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_node(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSINodeDriver {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_node_driver(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSINodeSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_node_spec(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::CSIStorageCapacity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_csi_storage_capacity(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::StorageClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_storage_class(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::TokenRequest {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_token_request(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeAttachment {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_attachment(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeAttachmentSource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_source(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeAttachmentSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_spec(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeAttachmentStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_status(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeError {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_error(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1::VolumeNodeResources {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1_volume_node_resources(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1alpha1::VolumeAttributesClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1alpha1_volume_attributes_class(self);
+    }
+}
+
+impl Visitable for crate::api::storage::v1beta1::VolumeAttributesClass {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storage_v1beta1_volume_attributes_class(self);
+    }
+}
+
+impl Visitable for crate::api::storagemigration::v1alpha1::GroupVersionResource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_group_version_resource(self);
+    }
+}
+
+impl Visitable for crate::api::storagemigration::v1alpha1::MigrationCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_migration_condition(self);
+    }
+}
+
+impl Visitable for crate::api::storagemigration::v1alpha1::StorageVersionMigration {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration(self);
+    }
+}
+
+impl Visitable for crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_spec(self);
+    }
+}
+
+impl Visitable for crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_status(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config(self);
+    }
+}
+
+impl Visitable for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::api::resource::Quantity {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_api_resource_quantity(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::APIGroup {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::APIGroupList {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group_list(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::APIResource {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::APIResourceList {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource_list(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::APIVersions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_versions(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::Condition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_condition(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::DeleteOptions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_delete_options(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::FieldsV1 {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_fields_v1(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::LabelSelector {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_list_meta(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::MicroTime {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::OwnerReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_owner_reference(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::Preconditions {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_preconditions(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::Status {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::StatusCause {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_cause(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::StatusDetails {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_details(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::apis::meta::v1::Time {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_time(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::runtime::RawExtension {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_runtime_raw_extension(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::util::intstr::IntOrString {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string(self);
+    }
+}
+
+impl Visitable for crate::apimachinery::pkg::version::Info {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_apimachinery_pkg_version_info(self);
+    }
+}
+
+impl Visitable for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service(self);
+    }
+}
+
+impl Visitable for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition(self);
+    }
+}
+
+impl Visitable for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec(self);
+    }
+}
+
+impl Visitable for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status(self);
+    }
+}
+
+impl Visitable for crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference {
+    fn visit<'ast, V>(&'ast self, v: &mut V) where V: Visit<'ast> + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference(self);
+    }
+}
+
@@ -0,0 +1,11472 @@
+// Generated from all definitions
+
+//! Visitors that walk a value and the values of other types that it contains and can modify them, in the style of the `syn::visit_mut` module.
+//!
+//! [`VisitMut`] has a method for each type, which by default calls the function of the same name in this module to walk the values that
+//! the value contains. A visitor overrides the methods of the types that it is interested in, and calls the function of the same name
+//! if it wants to continue walking the value.
+//!
+//! ```rust,ignore
+//! struct PinImages;
+//!
+//! impl k8s_openapi::visit_mut::VisitMut for PinImages {
+//!     fn visit_api_core_v1_container_mut(&mut self, node: &mut Container) {
+//!         if let Some(image) = &mut node.image {
+//!             *image = pin(image);
+//!         }
+//!         k8s_openapi::visit_mut::visit_api_core_v1_container_mut(self, node);
+//!     }
+//! }
+//!
+//! k8s_openapi::visit_mut::VisitableMut::visit_mut(&mut deployment, &mut PinImages);
+//! ```
+//!
+//! See the [`visit`](super::visit) module for visitors that only read the values.
+
+/// A visitor of values of the types in this crate that can modify them.
+///
+/// The method of each type is named after the path of the type, like `visit_api_core_v1_pod_mut` for `api::core::v1::Pod`.
+pub trait VisitMut {
+    /// Visits a value of type [`AnyResource`](crate::AnyResource).
+    fn visit_any_resource_mut(&mut self, node: &mut crate::AnyResource) {
+        visit_any_resource_mut(self, node);
+    }
+
+    /// Visits a value of type [`List`](crate::List).
+    fn visit_list_mut<T>(&mut self, node: &mut crate::List<T>) where T: crate::ListableResource + VisitableMut {
+        visit_list_mut(self, node);
+    }
+
+    /// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1::AuditAnnotation).
+    fn visit_api_admissionregistration_v1_audit_annotation_mut(&mut self, node: &mut crate::api::admissionregistration::v1::AuditAnnotation) {
+        visit_api_admissionregistration_v1_audit_annotation_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1::ExpressionWarning).
+    fn visit_api_admissionregistration_v1_expression_warning_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ExpressionWarning) {
+        visit_api_admissionregistration_v1_expression_warning_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1::MatchCondition).
+    fn visit_api_admissionregistration_v1_match_condition_mut(&mut self, node: &mut crate::api::admissionregistration::v1::MatchCondition) {
+        visit_api_admissionregistration_v1_match_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1::MatchResources).
+    fn visit_api_admissionregistration_v1_match_resources_mut(&mut self, node: &mut crate::api::admissionregistration::v1::MatchResources) {
+        visit_api_admissionregistration_v1_match_resources_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingWebhook`](crate::api::admissionregistration::v1::MutatingWebhook).
+    fn visit_api_admissionregistration_v1_mutating_webhook_mut(&mut self, node: &mut crate::api::admissionregistration::v1::MutatingWebhook) {
+        visit_api_admissionregistration_v1_mutating_webhook_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingWebhookConfiguration`](crate::api::admissionregistration::v1::MutatingWebhookConfiguration).
+    fn visit_api_admissionregistration_v1_mutating_webhook_configuration_mut(&mut self, node: &mut crate::api::admissionregistration::v1::MutatingWebhookConfiguration) {
+        visit_api_admissionregistration_v1_mutating_webhook_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1_named_rule_with_operations_mut(&mut self, node: &mut crate::api::admissionregistration::v1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1_named_rule_with_operations_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1::ParamKind).
+    fn visit_api_admissionregistration_v1_param_kind_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ParamKind) {
+        visit_api_admissionregistration_v1_param_kind_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1::ParamRef).
+    fn visit_api_admissionregistration_v1_param_ref_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ParamRef) {
+        visit_api_admissionregistration_v1_param_ref_mut(self, node);
+    }
+
+    /// Visits a value of type [`RuleWithOperations`](crate::api::admissionregistration::v1::RuleWithOperations).
+    fn visit_api_admissionregistration_v1_rule_with_operations_mut(&mut self, node: &mut crate::api::admissionregistration::v1::RuleWithOperations) {
+        visit_api_admissionregistration_v1_rule_with_operations_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::api::admissionregistration::v1::ServiceReference).
+    fn visit_api_admissionregistration_v1_service_reference_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ServiceReference) {
+        visit_api_admissionregistration_v1_service_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`TypeChecking`](crate::api::admissionregistration::v1::TypeChecking).
+    fn visit_api_admissionregistration_v1_type_checking_mut(&mut self, node: &mut crate::api::admissionregistration::v1::TypeChecking) {
+        visit_api_admissionregistration_v1_type_checking_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1_validating_admission_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_binding_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1_validating_admission_policy_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_binding_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1_validating_admission_policy_binding_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1_validating_admission_policy_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus).
+    fn visit_api_admissionregistration_v1_validating_admission_policy_status_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus) {
+        visit_api_admissionregistration_v1_validating_admission_policy_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingWebhook`](crate::api::admissionregistration::v1::ValidatingWebhook).
+    fn visit_api_admissionregistration_v1_validating_webhook_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingWebhook) {
+        visit_api_admissionregistration_v1_validating_webhook_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingWebhookConfiguration`](crate::api::admissionregistration::v1::ValidatingWebhookConfiguration).
+    fn visit_api_admissionregistration_v1_validating_webhook_configuration_mut(&mut self, node: &mut crate::api::admissionregistration::v1::ValidatingWebhookConfiguration) {
+        visit_api_admissionregistration_v1_validating_webhook_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`Validation`](crate::api::admissionregistration::v1::Validation).
+    fn visit_api_admissionregistration_v1_validation_mut(&mut self, node: &mut crate::api::admissionregistration::v1::Validation) {
+        visit_api_admissionregistration_v1_validation_mut(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1::Variable).
+    fn visit_api_admissionregistration_v1_variable_mut(&mut self, node: &mut crate::api::admissionregistration::v1::Variable) {
+        visit_api_admissionregistration_v1_variable_mut(self, node);
+    }
+
+    /// Visits a value of type [`WebhookClientConfig`](crate::api::admissionregistration::v1::WebhookClientConfig).
+    fn visit_api_admissionregistration_v1_webhook_client_config_mut(&mut self, node: &mut crate::api::admissionregistration::v1::WebhookClientConfig) {
+        visit_api_admissionregistration_v1_webhook_client_config_mut(self, node);
+    }
+
+    /// Visits a value of type [`ApplyConfiguration`](crate::api::admissionregistration::v1alpha1::ApplyConfiguration).
+    fn visit_api_admissionregistration_v1alpha1_apply_configuration_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::ApplyConfiguration) {
+        visit_api_admissionregistration_v1alpha1_apply_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSONPatch`](crate::api::admissionregistration::v1alpha1::JSONPatch).
+    fn visit_api_admissionregistration_v1alpha1_json_patch_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::JSONPatch) {
+        visit_api_admissionregistration_v1alpha1_json_patch_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1alpha1::MatchCondition).
+    fn visit_api_admissionregistration_v1alpha1_match_condition_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MatchCondition) {
+        visit_api_admissionregistration_v1alpha1_match_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1alpha1::MatchResources).
+    fn visit_api_admissionregistration_v1alpha1_match_resources_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MatchResources) {
+        visit_api_admissionregistration_v1alpha1_match_resources_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicy`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`MutatingAdmissionPolicySpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`Mutation`](crate::api::admissionregistration::v1alpha1::Mutation).
+    fn visit_api_admissionregistration_v1alpha1_mutation_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::Mutation) {
+        visit_api_admissionregistration_v1alpha1_mutation_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1alpha1::ParamKind).
+    fn visit_api_admissionregistration_v1alpha1_param_kind_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::ParamKind) {
+        visit_api_admissionregistration_v1alpha1_param_kind_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1alpha1::ParamRef).
+    fn visit_api_admissionregistration_v1alpha1_param_ref_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::ParamRef) {
+        visit_api_admissionregistration_v1alpha1_param_ref_mut(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1alpha1::Variable).
+    fn visit_api_admissionregistration_v1alpha1_variable_mut(&mut self, node: &mut crate::api::admissionregistration::v1alpha1::Variable) {
+        visit_api_admissionregistration_v1alpha1_variable_mut(self, node);
+    }
+
+    /// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1beta1::AuditAnnotation).
+    fn visit_api_admissionregistration_v1beta1_audit_annotation_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::AuditAnnotation) {
+        visit_api_admissionregistration_v1beta1_audit_annotation_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1beta1::ExpressionWarning).
+    fn visit_api_admissionregistration_v1beta1_expression_warning_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ExpressionWarning) {
+        visit_api_admissionregistration_v1beta1_expression_warning_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1beta1::MatchCondition).
+    fn visit_api_admissionregistration_v1beta1_match_condition_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::MatchCondition) {
+        visit_api_admissionregistration_v1beta1_match_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`MatchResources`](crate::api::admissionregistration::v1beta1::MatchResources).
+    fn visit_api_admissionregistration_v1beta1_match_resources_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::MatchResources) {
+        visit_api_admissionregistration_v1beta1_match_resources_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1beta1::NamedRuleWithOperations).
+    fn visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::NamedRuleWithOperations) {
+        visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1beta1::ParamKind).
+    fn visit_api_admissionregistration_v1beta1_param_kind_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ParamKind) {
+        visit_api_admissionregistration_v1beta1_param_kind_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParamRef`](crate::api::admissionregistration::v1beta1::ParamRef).
+    fn visit_api_admissionregistration_v1beta1_param_ref_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ParamRef) {
+        visit_api_admissionregistration_v1beta1_param_ref_mut(self, node);
+    }
+
+    /// Visits a value of type [`TypeChecking`](crate::api::admissionregistration::v1beta1::TypeChecking).
+    fn visit_api_admissionregistration_v1beta1_type_checking_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::TypeChecking) {
+        visit_api_admissionregistration_v1beta1_type_checking_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_spec_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus).
+    fn visit_api_admissionregistration_v1beta1_validating_admission_policy_status_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus) {
+        visit_api_admissionregistration_v1beta1_validating_admission_policy_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`Validation`](crate::api::admissionregistration::v1beta1::Validation).
+    fn visit_api_admissionregistration_v1beta1_validation_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::Validation) {
+        visit_api_admissionregistration_v1beta1_validation_mut(self, node);
+    }
+
+    /// Visits a value of type [`Variable`](crate::api::admissionregistration::v1beta1::Variable).
+    fn visit_api_admissionregistration_v1beta1_variable_mut(&mut self, node: &mut crate::api::admissionregistration::v1beta1::Variable) {
+        visit_api_admissionregistration_v1beta1_variable_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServerStorageVersion`](crate::api::apiserverinternal::v1alpha1::ServerStorageVersion).
+    fn visit_api_apiserverinternal_v1alpha1_server_storage_version_mut(&mut self, node: &mut crate::api::apiserverinternal::v1alpha1::ServerStorageVersion) {
+        visit_api_apiserverinternal_v1alpha1_server_storage_version_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersion`](crate::api::apiserverinternal::v1alpha1::StorageVersion).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_mut(&mut self, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersion) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionCondition`](crate::api::apiserverinternal::v1alpha1::StorageVersionCondition).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_condition_mut(&mut self, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionCondition) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionSpec`](crate::api::apiserverinternal::v1alpha1::StorageVersionSpec).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_spec_mut(&mut self, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionSpec) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionStatus`](crate::api::apiserverinternal::v1alpha1::StorageVersionStatus).
+    fn visit_api_apiserverinternal_v1alpha1_storage_version_status_mut(&mut self, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionStatus) {
+        visit_api_apiserverinternal_v1alpha1_storage_version_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ControllerRevision`](crate::api::apps::v1::ControllerRevision).
+    fn visit_api_apps_v1_controller_revision_mut(&mut self, node: &mut crate::api::apps::v1::ControllerRevision) {
+        visit_api_apps_v1_controller_revision_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSet`](crate::api::apps::v1::DaemonSet).
+    fn visit_api_apps_v1_daemon_set_mut(&mut self, node: &mut crate::api::apps::v1::DaemonSet) {
+        visit_api_apps_v1_daemon_set_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetCondition`](crate::api::apps::v1::DaemonSetCondition).
+    fn visit_api_apps_v1_daemon_set_condition_mut(&mut self, node: &mut crate::api::apps::v1::DaemonSetCondition) {
+        visit_api_apps_v1_daemon_set_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetSpec`](crate::api::apps::v1::DaemonSetSpec).
+    fn visit_api_apps_v1_daemon_set_spec_mut(&mut self, node: &mut crate::api::apps::v1::DaemonSetSpec) {
+        visit_api_apps_v1_daemon_set_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetStatus`](crate::api::apps::v1::DaemonSetStatus).
+    fn visit_api_apps_v1_daemon_set_status_mut(&mut self, node: &mut crate::api::apps::v1::DaemonSetStatus) {
+        visit_api_apps_v1_daemon_set_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonSetUpdateStrategy`](crate::api::apps::v1::DaemonSetUpdateStrategy).
+    fn visit_api_apps_v1_daemon_set_update_strategy_mut(&mut self, node: &mut crate::api::apps::v1::DaemonSetUpdateStrategy) {
+        visit_api_apps_v1_daemon_set_update_strategy_mut(self, node);
+    }
+
+    /// Visits a value of type [`Deployment`](crate::api::apps::v1::Deployment).
+    fn visit_api_apps_v1_deployment_mut(&mut self, node: &mut crate::api::apps::v1::Deployment) {
+        visit_api_apps_v1_deployment_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentCondition`](crate::api::apps::v1::DeploymentCondition).
+    fn visit_api_apps_v1_deployment_condition_mut(&mut self, node: &mut crate::api::apps::v1::DeploymentCondition) {
+        visit_api_apps_v1_deployment_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentSpec`](crate::api::apps::v1::DeploymentSpec).
+    fn visit_api_apps_v1_deployment_spec_mut(&mut self, node: &mut crate::api::apps::v1::DeploymentSpec) {
+        visit_api_apps_v1_deployment_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentStatus`](crate::api::apps::v1::DeploymentStatus).
+    fn visit_api_apps_v1_deployment_status_mut(&mut self, node: &mut crate::api::apps::v1::DeploymentStatus) {
+        visit_api_apps_v1_deployment_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeploymentStrategy`](crate::api::apps::v1::DeploymentStrategy).
+    fn visit_api_apps_v1_deployment_strategy_mut(&mut self, node: &mut crate::api::apps::v1::DeploymentStrategy) {
+        visit_api_apps_v1_deployment_strategy_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSet`](crate::api::apps::v1::ReplicaSet).
+    fn visit_api_apps_v1_replica_set_mut(&mut self, node: &mut crate::api::apps::v1::ReplicaSet) {
+        visit_api_apps_v1_replica_set_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetCondition`](crate::api::apps::v1::ReplicaSetCondition).
+    fn visit_api_apps_v1_replica_set_condition_mut(&mut self, node: &mut crate::api::apps::v1::ReplicaSetCondition) {
+        visit_api_apps_v1_replica_set_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetSpec`](crate::api::apps::v1::ReplicaSetSpec).
+    fn visit_api_apps_v1_replica_set_spec_mut(&mut self, node: &mut crate::api::apps::v1::ReplicaSetSpec) {
+        visit_api_apps_v1_replica_set_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicaSetStatus`](crate::api::apps::v1::ReplicaSetStatus).
+    fn visit_api_apps_v1_replica_set_status_mut(&mut self, node: &mut crate::api::apps::v1::ReplicaSetStatus) {
+        visit_api_apps_v1_replica_set_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateDaemonSet`](crate::api::apps::v1::RollingUpdateDaemonSet).
+    fn visit_api_apps_v1_rolling_update_daemon_set_mut(&mut self, node: &mut crate::api::apps::v1::RollingUpdateDaemonSet) {
+        visit_api_apps_v1_rolling_update_daemon_set_mut(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateDeployment`](crate::api::apps::v1::RollingUpdateDeployment).
+    fn visit_api_apps_v1_rolling_update_deployment_mut(&mut self, node: &mut crate::api::apps::v1::RollingUpdateDeployment) {
+        visit_api_apps_v1_rolling_update_deployment_mut(self, node);
+    }
+
+    /// Visits a value of type [`RollingUpdateStatefulSetStrategy`](crate::api::apps::v1::RollingUpdateStatefulSetStrategy).
+    fn visit_api_apps_v1_rolling_update_stateful_set_strategy_mut(&mut self, node: &mut crate::api::apps::v1::RollingUpdateStatefulSetStrategy) {
+        visit_api_apps_v1_rolling_update_stateful_set_strategy_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSet`](crate::api::apps::v1::StatefulSet).
+    fn visit_api_apps_v1_stateful_set_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSet) {
+        visit_api_apps_v1_stateful_set_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetCondition`](crate::api::apps::v1::StatefulSetCondition).
+    fn visit_api_apps_v1_stateful_set_condition_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetCondition) {
+        visit_api_apps_v1_stateful_set_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetOrdinals`](crate::api::apps::v1::StatefulSetOrdinals).
+    fn visit_api_apps_v1_stateful_set_ordinals_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetOrdinals) {
+        visit_api_apps_v1_stateful_set_ordinals_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetPersistentVolumeClaimRetentionPolicy`](crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy).
+    fn visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy) {
+        visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetSpec`](crate::api::apps::v1::StatefulSetSpec).
+    fn visit_api_apps_v1_stateful_set_spec_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetSpec) {
+        visit_api_apps_v1_stateful_set_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetStatus`](crate::api::apps::v1::StatefulSetStatus).
+    fn visit_api_apps_v1_stateful_set_status_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetStatus) {
+        visit_api_apps_v1_stateful_set_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatefulSetUpdateStrategy`](crate::api::apps::v1::StatefulSetUpdateStrategy).
+    fn visit_api_apps_v1_stateful_set_update_strategy_mut(&mut self, node: &mut crate::api::apps::v1::StatefulSetUpdateStrategy) {
+        visit_api_apps_v1_stateful_set_update_strategy_mut(self, node);
+    }
+
+    /// Visits a value of type [`BoundObjectReference`](crate::api::authentication::v1::BoundObjectReference).
+    fn visit_api_authentication_v1_bound_object_reference_mut(&mut self, node: &mut crate::api::authentication::v1::BoundObjectReference) {
+        visit_api_authentication_v1_bound_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReview`](crate::api::authentication::v1::SelfSubjectReview).
+    fn visit_api_authentication_v1_self_subject_review_mut(&mut self, node: &mut crate::api::authentication::v1::SelfSubjectReview) {
+        visit_api_authentication_v1_self_subject_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1::SelfSubjectReviewStatus).
+    fn visit_api_authentication_v1_self_subject_review_status_mut(&mut self, node: &mut crate::api::authentication::v1::SelfSubjectReviewStatus) {
+        visit_api_authentication_v1_self_subject_review_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequest`](crate::api::authentication::v1::TokenRequest).
+    fn visit_api_authentication_v1_token_request_mut(&mut self, node: &mut crate::api::authentication::v1::TokenRequest) {
+        visit_api_authentication_v1_token_request_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequestSpec`](crate::api::authentication::v1::TokenRequestSpec).
+    fn visit_api_authentication_v1_token_request_spec_mut(&mut self, node: &mut crate::api::authentication::v1::TokenRequestSpec) {
+        visit_api_authentication_v1_token_request_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequestStatus`](crate::api::authentication::v1::TokenRequestStatus).
+    fn visit_api_authentication_v1_token_request_status_mut(&mut self, node: &mut crate::api::authentication::v1::TokenRequestStatus) {
+        visit_api_authentication_v1_token_request_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenReview`](crate::api::authentication::v1::TokenReview).
+    fn visit_api_authentication_v1_token_review_mut(&mut self, node: &mut crate::api::authentication::v1::TokenReview) {
+        visit_api_authentication_v1_token_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenReviewSpec`](crate::api::authentication::v1::TokenReviewSpec).
+    fn visit_api_authentication_v1_token_review_spec_mut(&mut self, node: &mut crate::api::authentication::v1::TokenReviewSpec) {
+        visit_api_authentication_v1_token_review_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenReviewStatus`](crate::api::authentication::v1::TokenReviewStatus).
+    fn visit_api_authentication_v1_token_review_status_mut(&mut self, node: &mut crate::api::authentication::v1::TokenReviewStatus) {
+        visit_api_authentication_v1_token_review_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`UserInfo`](crate::api::authentication::v1::UserInfo).
+    fn visit_api_authentication_v1_user_info_mut(&mut self, node: &mut crate::api::authentication::v1::UserInfo) {
+        visit_api_authentication_v1_user_info_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReview`](crate::api::authentication::v1beta1::SelfSubjectReview).
+    fn visit_api_authentication_v1beta1_self_subject_review_mut(&mut self, node: &mut crate::api::authentication::v1beta1::SelfSubjectReview) {
+        visit_api_authentication_v1beta1_self_subject_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1beta1::SelfSubjectReviewStatus).
+    fn visit_api_authentication_v1beta1_self_subject_review_status_mut(&mut self, node: &mut crate::api::authentication::v1beta1::SelfSubjectReviewStatus) {
+        visit_api_authentication_v1beta1_self_subject_review_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`FieldSelectorAttributes`](crate::api::authorization::v1::FieldSelectorAttributes).
+    fn visit_api_authorization_v1_field_selector_attributes_mut(&mut self, node: &mut crate::api::authorization::v1::FieldSelectorAttributes) {
+        visit_api_authorization_v1_field_selector_attributes_mut(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelectorAttributes`](crate::api::authorization::v1::LabelSelectorAttributes).
+    fn visit_api_authorization_v1_label_selector_attributes_mut(&mut self, node: &mut crate::api::authorization::v1::LabelSelectorAttributes) {
+        visit_api_authorization_v1_label_selector_attributes_mut(self, node);
+    }
+
+    /// Visits a value of type [`LocalSubjectAccessReview`](crate::api::authorization::v1::LocalSubjectAccessReview).
+    fn visit_api_authorization_v1_local_subject_access_review_mut(&mut self, node: &mut crate::api::authorization::v1::LocalSubjectAccessReview) {
+        visit_api_authorization_v1_local_subject_access_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`NonResourceAttributes`](crate::api::authorization::v1::NonResourceAttributes).
+    fn visit_api_authorization_v1_non_resource_attributes_mut(&mut self, node: &mut crate::api::authorization::v1::NonResourceAttributes) {
+        visit_api_authorization_v1_non_resource_attributes_mut(self, node);
+    }
+
+    /// Visits a value of type [`NonResourceRule`](crate::api::authorization::v1::NonResourceRule).
+    fn visit_api_authorization_v1_non_resource_rule_mut(&mut self, node: &mut crate::api::authorization::v1::NonResourceRule) {
+        visit_api_authorization_v1_non_resource_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceAttributes`](crate::api::authorization::v1::ResourceAttributes).
+    fn visit_api_authorization_v1_resource_attributes_mut(&mut self, node: &mut crate::api::authorization::v1::ResourceAttributes) {
+        visit_api_authorization_v1_resource_attributes_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceRule`](crate::api::authorization::v1::ResourceRule).
+    fn visit_api_authorization_v1_resource_rule_mut(&mut self, node: &mut crate::api::authorization::v1::ResourceRule) {
+        visit_api_authorization_v1_resource_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectAccessReview`](crate::api::authorization::v1::SelfSubjectAccessReview).
+    fn visit_api_authorization_v1_self_subject_access_review_mut(&mut self, node: &mut crate::api::authorization::v1::SelfSubjectAccessReview) {
+        visit_api_authorization_v1_self_subject_access_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectAccessReviewSpec`](crate::api::authorization::v1::SelfSubjectAccessReviewSpec).
+    fn visit_api_authorization_v1_self_subject_access_review_spec_mut(&mut self, node: &mut crate::api::authorization::v1::SelfSubjectAccessReviewSpec) {
+        visit_api_authorization_v1_self_subject_access_review_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectRulesReview`](crate::api::authorization::v1::SelfSubjectRulesReview).
+    fn visit_api_authorization_v1_self_subject_rules_review_mut(&mut self, node: &mut crate::api::authorization::v1::SelfSubjectRulesReview) {
+        visit_api_authorization_v1_self_subject_rules_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelfSubjectRulesReviewSpec`](crate::api::authorization::v1::SelfSubjectRulesReviewSpec).
+    fn visit_api_authorization_v1_self_subject_rules_review_spec_mut(&mut self, node: &mut crate::api::authorization::v1::SelfSubjectRulesReviewSpec) {
+        visit_api_authorization_v1_self_subject_rules_review_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReview`](crate::api::authorization::v1::SubjectAccessReview).
+    fn visit_api_authorization_v1_subject_access_review_mut(&mut self, node: &mut crate::api::authorization::v1::SubjectAccessReview) {
+        visit_api_authorization_v1_subject_access_review_mut(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReviewSpec`](crate::api::authorization::v1::SubjectAccessReviewSpec).
+    fn visit_api_authorization_v1_subject_access_review_spec_mut(&mut self, node: &mut crate::api::authorization::v1::SubjectAccessReviewSpec) {
+        visit_api_authorization_v1_subject_access_review_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`SubjectAccessReviewStatus`](crate::api::authorization::v1::SubjectAccessReviewStatus).
+    fn visit_api_authorization_v1_subject_access_review_status_mut(&mut self, node: &mut crate::api::authorization::v1::SubjectAccessReviewStatus) {
+        visit_api_authorization_v1_subject_access_review_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`SubjectRulesReviewStatus`](crate::api::authorization::v1::SubjectRulesReviewStatus).
+    fn visit_api_authorization_v1_subject_rules_review_status_mut(&mut self, node: &mut crate::api::authorization::v1::SubjectRulesReviewStatus) {
+        visit_api_authorization_v1_subject_rules_review_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v1::CrossVersionObjectReference).
+    fn visit_api_autoscaling_v1_cross_version_object_reference_mut(&mut self, node: &mut crate::api::autoscaling::v1::CrossVersionObjectReference) {
+        visit_api_autoscaling_v1_cross_version_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v1::HorizontalPodAutoscaler).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_mut(&mut self, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscaler) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec_mut(&mut self, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus).
+    fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_status_mut(&mut self, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus) {
+        visit_api_autoscaling_v1_horizontal_pod_autoscaler_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`Scale`](crate::api::autoscaling::v1::Scale).
+    fn visit_api_autoscaling_v1_scale_mut(&mut self, node: &mut crate::api::autoscaling::v1::Scale) {
+        visit_api_autoscaling_v1_scale_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScaleSpec`](crate::api::autoscaling::v1::ScaleSpec).
+    fn visit_api_autoscaling_v1_scale_spec_mut(&mut self, node: &mut crate::api::autoscaling::v1::ScaleSpec) {
+        visit_api_autoscaling_v1_scale_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScaleStatus`](crate::api::autoscaling::v1::ScaleStatus).
+    fn visit_api_autoscaling_v1_scale_status_mut(&mut self, node: &mut crate::api::autoscaling::v1::ScaleStatus) {
+        visit_api_autoscaling_v1_scale_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResourceMetricSource`](crate::api::autoscaling::v2::ContainerResourceMetricSource).
+    fn visit_api_autoscaling_v2_container_resource_metric_source_mut(&mut self, node: &mut crate::api::autoscaling::v2::ContainerResourceMetricSource) {
+        visit_api_autoscaling_v2_container_resource_metric_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResourceMetricStatus`](crate::api::autoscaling::v2::ContainerResourceMetricStatus).
+    fn visit_api_autoscaling_v2_container_resource_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::ContainerResourceMetricStatus) {
+        visit_api_autoscaling_v2_container_resource_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v2::CrossVersionObjectReference).
+    fn visit_api_autoscaling_v2_cross_version_object_reference_mut(&mut self, node: &mut crate::api::autoscaling::v2::CrossVersionObjectReference) {
+        visit_api_autoscaling_v2_cross_version_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExternalMetricSource`](crate::api::autoscaling::v2::ExternalMetricSource).
+    fn visit_api_autoscaling_v2_external_metric_source_mut(&mut self, node: &mut crate::api::autoscaling::v2::ExternalMetricSource) {
+        visit_api_autoscaling_v2_external_metric_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExternalMetricStatus`](crate::api::autoscaling::v2::ExternalMetricStatus).
+    fn visit_api_autoscaling_v2_external_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::ExternalMetricStatus) {
+        visit_api_autoscaling_v2_external_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`HPAScalingPolicy`](crate::api::autoscaling::v2::HPAScalingPolicy).
+    fn visit_api_autoscaling_v2_hpa_scaling_policy_mut(&mut self, node: &mut crate::api::autoscaling::v2::HPAScalingPolicy) {
+        visit_api_autoscaling_v2_hpa_scaling_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`HPAScalingRules`](crate::api::autoscaling::v2::HPAScalingRules).
+    fn visit_api_autoscaling_v2_hpa_scaling_rules_mut(&mut self, node: &mut crate::api::autoscaling::v2::HPAScalingRules) {
+        visit_api_autoscaling_v2_hpa_scaling_rules_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v2::HorizontalPodAutoscaler).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_mut(&mut self, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscaler) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerBehavior`](crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior_mut(&mut self, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerCondition`](crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition_mut(&mut self, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec_mut(&mut self, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus).
+    fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus) {
+        visit_api_autoscaling_v2_horizontal_pod_autoscaler_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`MetricIdentifier`](crate::api::autoscaling::v2::MetricIdentifier).
+    fn visit_api_autoscaling_v2_metric_identifier_mut(&mut self, node: &mut crate::api::autoscaling::v2::MetricIdentifier) {
+        visit_api_autoscaling_v2_metric_identifier_mut(self, node);
+    }
+
+    /// Visits a value of type [`MetricSpec`](crate::api::autoscaling::v2::MetricSpec).
+    fn visit_api_autoscaling_v2_metric_spec_mut(&mut self, node: &mut crate::api::autoscaling::v2::MetricSpec) {
+        visit_api_autoscaling_v2_metric_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`MetricStatus`](crate::api::autoscaling::v2::MetricStatus).
+    fn visit_api_autoscaling_v2_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::MetricStatus) {
+        visit_api_autoscaling_v2_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`MetricTarget`](crate::api::autoscaling::v2::MetricTarget).
+    fn visit_api_autoscaling_v2_metric_target_mut(&mut self, node: &mut crate::api::autoscaling::v2::MetricTarget) {
+        visit_api_autoscaling_v2_metric_target_mut(self, node);
+    }
+
+    /// Visits a value of type [`MetricValueStatus`](crate::api::autoscaling::v2::MetricValueStatus).
+    fn visit_api_autoscaling_v2_metric_value_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::MetricValueStatus) {
+        visit_api_autoscaling_v2_metric_value_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMetricSource`](crate::api::autoscaling::v2::ObjectMetricSource).
+    fn visit_api_autoscaling_v2_object_metric_source_mut(&mut self, node: &mut crate::api::autoscaling::v2::ObjectMetricSource) {
+        visit_api_autoscaling_v2_object_metric_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMetricStatus`](crate::api::autoscaling::v2::ObjectMetricStatus).
+    fn visit_api_autoscaling_v2_object_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::ObjectMetricStatus) {
+        visit_api_autoscaling_v2_object_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodsMetricSource`](crate::api::autoscaling::v2::PodsMetricSource).
+    fn visit_api_autoscaling_v2_pods_metric_source_mut(&mut self, node: &mut crate::api::autoscaling::v2::PodsMetricSource) {
+        visit_api_autoscaling_v2_pods_metric_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodsMetricStatus`](crate::api::autoscaling::v2::PodsMetricStatus).
+    fn visit_api_autoscaling_v2_pods_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::PodsMetricStatus) {
+        visit_api_autoscaling_v2_pods_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceMetricSource`](crate::api::autoscaling::v2::ResourceMetricSource).
+    fn visit_api_autoscaling_v2_resource_metric_source_mut(&mut self, node: &mut crate::api::autoscaling::v2::ResourceMetricSource) {
+        visit_api_autoscaling_v2_resource_metric_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceMetricStatus`](crate::api::autoscaling::v2::ResourceMetricStatus).
+    fn visit_api_autoscaling_v2_resource_metric_status_mut(&mut self, node: &mut crate::api::autoscaling::v2::ResourceMetricStatus) {
+        visit_api_autoscaling_v2_resource_metric_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CronJob`](crate::api::batch::v1::CronJob).
+    fn visit_api_batch_v1_cron_job_mut(&mut self, node: &mut crate::api::batch::v1::CronJob) {
+        visit_api_batch_v1_cron_job_mut(self, node);
+    }
+
+    /// Visits a value of type [`CronJobSpec`](crate::api::batch::v1::CronJobSpec).
+    fn visit_api_batch_v1_cron_job_spec_mut(&mut self, node: &mut crate::api::batch::v1::CronJobSpec) {
+        visit_api_batch_v1_cron_job_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`CronJobStatus`](crate::api::batch::v1::CronJobStatus).
+    fn visit_api_batch_v1_cron_job_status_mut(&mut self, node: &mut crate::api::batch::v1::CronJobStatus) {
+        visit_api_batch_v1_cron_job_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`Job`](crate::api::batch::v1::Job).
+    fn visit_api_batch_v1_job_mut(&mut self, node: &mut crate::api::batch::v1::Job) {
+        visit_api_batch_v1_job_mut(self, node);
+    }
+
+    /// Visits a value of type [`JobCondition`](crate::api::batch::v1::JobCondition).
+    fn visit_api_batch_v1_job_condition_mut(&mut self, node: &mut crate::api::batch::v1::JobCondition) {
+        visit_api_batch_v1_job_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`JobSpec`](crate::api::batch::v1::JobSpec).
+    fn visit_api_batch_v1_job_spec_mut(&mut self, node: &mut crate::api::batch::v1::JobSpec) {
+        visit_api_batch_v1_job_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`JobStatus`](crate::api::batch::v1::JobStatus).
+    fn visit_api_batch_v1_job_status_mut(&mut self, node: &mut crate::api::batch::v1::JobStatus) {
+        visit_api_batch_v1_job_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`JobTemplateSpec`](crate::api::batch::v1::JobTemplateSpec).
+    fn visit_api_batch_v1_job_template_spec_mut(&mut self, node: &mut crate::api::batch::v1::JobTemplateSpec) {
+        visit_api_batch_v1_job_template_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicy`](crate::api::batch::v1::PodFailurePolicy).
+    fn visit_api_batch_v1_pod_failure_policy_mut(&mut self, node: &mut crate::api::batch::v1::PodFailurePolicy) {
+        visit_api_batch_v1_pod_failure_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyOnExitCodesRequirement`](crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement).
+    fn visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement_mut(&mut self, node: &mut crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement) {
+        visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyOnPodConditionsPattern`](crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern).
+    fn visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern_mut(&mut self, node: &mut crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern) {
+        visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodFailurePolicyRule`](crate::api::batch::v1::PodFailurePolicyRule).
+    fn visit_api_batch_v1_pod_failure_policy_rule_mut(&mut self, node: &mut crate::api::batch::v1::PodFailurePolicyRule) {
+        visit_api_batch_v1_pod_failure_policy_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`SuccessPolicy`](crate::api::batch::v1::SuccessPolicy).
+    fn visit_api_batch_v1_success_policy_mut(&mut self, node: &mut crate::api::batch::v1::SuccessPolicy) {
+        visit_api_batch_v1_success_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`SuccessPolicyRule`](crate::api::batch::v1::SuccessPolicyRule).
+    fn visit_api_batch_v1_success_policy_rule_mut(&mut self, node: &mut crate::api::batch::v1::SuccessPolicyRule) {
+        visit_api_batch_v1_success_policy_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`UncountedTerminatedPods`](crate::api::batch::v1::UncountedTerminatedPods).
+    fn visit_api_batch_v1_uncounted_terminated_pods_mut(&mut self, node: &mut crate::api::batch::v1::UncountedTerminatedPods) {
+        visit_api_batch_v1_uncounted_terminated_pods_mut(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequest`](crate::api::certificates::v1::CertificateSigningRequest).
+    fn visit_api_certificates_v1_certificate_signing_request_mut(&mut self, node: &mut crate::api::certificates::v1::CertificateSigningRequest) {
+        visit_api_certificates_v1_certificate_signing_request_mut(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestCondition`](crate::api::certificates::v1::CertificateSigningRequestCondition).
+    fn visit_api_certificates_v1_certificate_signing_request_condition_mut(&mut self, node: &mut crate::api::certificates::v1::CertificateSigningRequestCondition) {
+        visit_api_certificates_v1_certificate_signing_request_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestSpec`](crate::api::certificates::v1::CertificateSigningRequestSpec).
+    fn visit_api_certificates_v1_certificate_signing_request_spec_mut(&mut self, node: &mut crate::api::certificates::v1::CertificateSigningRequestSpec) {
+        visit_api_certificates_v1_certificate_signing_request_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`CertificateSigningRequestStatus`](crate::api::certificates::v1::CertificateSigningRequestStatus).
+    fn visit_api_certificates_v1_certificate_signing_request_status_mut(&mut self, node: &mut crate::api::certificates::v1::CertificateSigningRequestStatus) {
+        visit_api_certificates_v1_certificate_signing_request_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundle`](crate::api::certificates::v1alpha1::ClusterTrustBundle).
+    fn visit_api_certificates_v1alpha1_cluster_trust_bundle_mut(&mut self, node: &mut crate::api::certificates::v1alpha1::ClusterTrustBundle) {
+        visit_api_certificates_v1alpha1_cluster_trust_bundle_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundleSpec`](crate::api::certificates::v1alpha1::ClusterTrustBundleSpec).
+    fn visit_api_certificates_v1alpha1_cluster_trust_bundle_spec_mut(&mut self, node: &mut crate::api::certificates::v1alpha1::ClusterTrustBundleSpec) {
+        visit_api_certificates_v1alpha1_cluster_trust_bundle_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`Lease`](crate::api::coordination::v1::Lease).
+    fn visit_api_coordination_v1_lease_mut(&mut self, node: &mut crate::api::coordination::v1::Lease) {
+        visit_api_coordination_v1_lease_mut(self, node);
+    }
+
+    /// Visits a value of type [`LeaseSpec`](crate::api::coordination::v1::LeaseSpec).
+    fn visit_api_coordination_v1_lease_spec_mut(&mut self, node: &mut crate::api::coordination::v1::LeaseSpec) {
+        visit_api_coordination_v1_lease_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`LeaseCandidate`](crate::api::coordination::v1alpha2::LeaseCandidate).
+    fn visit_api_coordination_v1alpha2_lease_candidate_mut(&mut self, node: &mut crate::api::coordination::v1alpha2::LeaseCandidate) {
+        visit_api_coordination_v1alpha2_lease_candidate_mut(self, node);
+    }
+
+    /// Visits a value of type [`LeaseCandidateSpec`](crate::api::coordination::v1alpha2::LeaseCandidateSpec).
+    fn visit_api_coordination_v1alpha2_lease_candidate_spec_mut(&mut self, node: &mut crate::api::coordination::v1alpha2::LeaseCandidateSpec) {
+        visit_api_coordination_v1alpha2_lease_candidate_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`AWSElasticBlockStoreVolumeSource`](crate::api::core::v1::AWSElasticBlockStoreVolumeSource).
+    fn visit_api_core_v1_aws_elastic_block_store_volume_source_mut(&mut self, node: &mut crate::api::core::v1::AWSElasticBlockStoreVolumeSource) {
+        visit_api_core_v1_aws_elastic_block_store_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Affinity`](crate::api::core::v1::Affinity).
+    fn visit_api_core_v1_affinity_mut(&mut self, node: &mut crate::api::core::v1::Affinity) {
+        visit_api_core_v1_affinity_mut(self, node);
+    }
+
+    /// Visits a value of type [`AppArmorProfile`](crate::api::core::v1::AppArmorProfile).
+    fn visit_api_core_v1_app_armor_profile_mut(&mut self, node: &mut crate::api::core::v1::AppArmorProfile) {
+        visit_api_core_v1_app_armor_profile_mut(self, node);
+    }
+
+    /// Visits a value of type [`AttachedVolume`](crate::api::core::v1::AttachedVolume).
+    fn visit_api_core_v1_attached_volume_mut(&mut self, node: &mut crate::api::core::v1::AttachedVolume) {
+        visit_api_core_v1_attached_volume_mut(self, node);
+    }
+
+    /// Visits a value of type [`AzureDiskVolumeSource`](crate::api::core::v1::AzureDiskVolumeSource).
+    fn visit_api_core_v1_azure_disk_volume_source_mut(&mut self, node: &mut crate::api::core::v1::AzureDiskVolumeSource) {
+        visit_api_core_v1_azure_disk_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`AzureFilePersistentVolumeSource`](crate::api::core::v1::AzureFilePersistentVolumeSource).
+    fn visit_api_core_v1_azure_file_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::AzureFilePersistentVolumeSource) {
+        visit_api_core_v1_azure_file_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`AzureFileVolumeSource`](crate::api::core::v1::AzureFileVolumeSource).
+    fn visit_api_core_v1_azure_file_volume_source_mut(&mut self, node: &mut crate::api::core::v1::AzureFileVolumeSource) {
+        visit_api_core_v1_azure_file_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Binding`](crate::api::core::v1::Binding).
+    fn visit_api_core_v1_binding_mut(&mut self, node: &mut crate::api::core::v1::Binding) {
+        visit_api_core_v1_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSIPersistentVolumeSource`](crate::api::core::v1::CSIPersistentVolumeSource).
+    fn visit_api_core_v1_csi_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CSIPersistentVolumeSource) {
+        visit_api_core_v1_csi_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSIVolumeSource`](crate::api::core::v1::CSIVolumeSource).
+    fn visit_api_core_v1_csi_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CSIVolumeSource) {
+        visit_api_core_v1_csi_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Capabilities`](crate::api::core::v1::Capabilities).
+    fn visit_api_core_v1_capabilities_mut(&mut self, node: &mut crate::api::core::v1::Capabilities) {
+        visit_api_core_v1_capabilities_mut(self, node);
+    }
+
+    /// Visits a value of type [`CephFSPersistentVolumeSource`](crate::api::core::v1::CephFSPersistentVolumeSource).
+    fn visit_api_core_v1_ceph_fs_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CephFSPersistentVolumeSource) {
+        visit_api_core_v1_ceph_fs_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`CephFSVolumeSource`](crate::api::core::v1::CephFSVolumeSource).
+    fn visit_api_core_v1_ceph_fs_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CephFSVolumeSource) {
+        visit_api_core_v1_ceph_fs_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`CinderPersistentVolumeSource`](crate::api::core::v1::CinderPersistentVolumeSource).
+    fn visit_api_core_v1_cinder_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CinderPersistentVolumeSource) {
+        visit_api_core_v1_cinder_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`CinderVolumeSource`](crate::api::core::v1::CinderVolumeSource).
+    fn visit_api_core_v1_cinder_volume_source_mut(&mut self, node: &mut crate::api::core::v1::CinderVolumeSource) {
+        visit_api_core_v1_cinder_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClientIPConfig`](crate::api::core::v1::ClientIPConfig).
+    fn visit_api_core_v1_client_ip_config_mut(&mut self, node: &mut crate::api::core::v1::ClientIPConfig) {
+        visit_api_core_v1_client_ip_config_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClusterTrustBundleProjection`](crate::api::core::v1::ClusterTrustBundleProjection).
+    fn visit_api_core_v1_cluster_trust_bundle_projection_mut(&mut self, node: &mut crate::api::core::v1::ClusterTrustBundleProjection) {
+        visit_api_core_v1_cluster_trust_bundle_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`ComponentCondition`](crate::api::core::v1::ComponentCondition).
+    fn visit_api_core_v1_component_condition_mut(&mut self, node: &mut crate::api::core::v1::ComponentCondition) {
+        visit_api_core_v1_component_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`ComponentStatus`](crate::api::core::v1::ComponentStatus).
+    fn visit_api_core_v1_component_status_mut(&mut self, node: &mut crate::api::core::v1::ComponentStatus) {
+        visit_api_core_v1_component_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMap`](crate::api::core::v1::ConfigMap).
+    fn visit_api_core_v1_config_map_mut(&mut self, node: &mut crate::api::core::v1::ConfigMap) {
+        visit_api_core_v1_config_map_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapEnvSource`](crate::api::core::v1::ConfigMapEnvSource).
+    fn visit_api_core_v1_config_map_env_source_mut(&mut self, node: &mut crate::api::core::v1::ConfigMapEnvSource) {
+        visit_api_core_v1_config_map_env_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapKeySelector`](crate::api::core::v1::ConfigMapKeySelector).
+    fn visit_api_core_v1_config_map_key_selector_mut(&mut self, node: &mut crate::api::core::v1::ConfigMapKeySelector) {
+        visit_api_core_v1_config_map_key_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapNodeConfigSource`](crate::api::core::v1::ConfigMapNodeConfigSource).
+    fn visit_api_core_v1_config_map_node_config_source_mut(&mut self, node: &mut crate::api::core::v1::ConfigMapNodeConfigSource) {
+        visit_api_core_v1_config_map_node_config_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapProjection`](crate::api::core::v1::ConfigMapProjection).
+    fn visit_api_core_v1_config_map_projection_mut(&mut self, node: &mut crate::api::core::v1::ConfigMapProjection) {
+        visit_api_core_v1_config_map_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`ConfigMapVolumeSource`](crate::api::core::v1::ConfigMapVolumeSource).
+    fn visit_api_core_v1_config_map_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ConfigMapVolumeSource) {
+        visit_api_core_v1_config_map_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Container`](crate::api::core::v1::Container).
+    fn visit_api_core_v1_container_mut(&mut self, node: &mut crate::api::core::v1::Container) {
+        visit_api_core_v1_container_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerImage`](crate::api::core::v1::ContainerImage).
+    fn visit_api_core_v1_container_image_mut(&mut self, node: &mut crate::api::core::v1::ContainerImage) {
+        visit_api_core_v1_container_image_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerPort`](crate::api::core::v1::ContainerPort).
+    fn visit_api_core_v1_container_port_mut(&mut self, node: &mut crate::api::core::v1::ContainerPort) {
+        visit_api_core_v1_container_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerResizePolicy`](crate::api::core::v1::ContainerResizePolicy).
+    fn visit_api_core_v1_container_resize_policy_mut(&mut self, node: &mut crate::api::core::v1::ContainerResizePolicy) {
+        visit_api_core_v1_container_resize_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerState`](crate::api::core::v1::ContainerState).
+    fn visit_api_core_v1_container_state_mut(&mut self, node: &mut crate::api::core::v1::ContainerState) {
+        visit_api_core_v1_container_state_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateRunning`](crate::api::core::v1::ContainerStateRunning).
+    fn visit_api_core_v1_container_state_running_mut(&mut self, node: &mut crate::api::core::v1::ContainerStateRunning) {
+        visit_api_core_v1_container_state_running_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateTerminated`](crate::api::core::v1::ContainerStateTerminated).
+    fn visit_api_core_v1_container_state_terminated_mut(&mut self, node: &mut crate::api::core::v1::ContainerStateTerminated) {
+        visit_api_core_v1_container_state_terminated_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStateWaiting`](crate::api::core::v1::ContainerStateWaiting).
+    fn visit_api_core_v1_container_state_waiting_mut(&mut self, node: &mut crate::api::core::v1::ContainerStateWaiting) {
+        visit_api_core_v1_container_state_waiting_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerStatus`](crate::api::core::v1::ContainerStatus).
+    fn visit_api_core_v1_container_status_mut(&mut self, node: &mut crate::api::core::v1::ContainerStatus) {
+        visit_api_core_v1_container_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ContainerUser`](crate::api::core::v1::ContainerUser).
+    fn visit_api_core_v1_container_user_mut(&mut self, node: &mut crate::api::core::v1::ContainerUser) {
+        visit_api_core_v1_container_user_mut(self, node);
+    }
+
+    /// Visits a value of type [`DaemonEndpoint`](crate::api::core::v1::DaemonEndpoint).
+    fn visit_api_core_v1_daemon_endpoint_mut(&mut self, node: &mut crate::api::core::v1::DaemonEndpoint) {
+        visit_api_core_v1_daemon_endpoint_mut(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIProjection`](crate::api::core::v1::DownwardAPIProjection).
+    fn visit_api_core_v1_downward_api_projection_mut(&mut self, node: &mut crate::api::core::v1::DownwardAPIProjection) {
+        visit_api_core_v1_downward_api_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIVolumeFile`](crate::api::core::v1::DownwardAPIVolumeFile).
+    fn visit_api_core_v1_downward_api_volume_file_mut(&mut self, node: &mut crate::api::core::v1::DownwardAPIVolumeFile) {
+        visit_api_core_v1_downward_api_volume_file_mut(self, node);
+    }
+
+    /// Visits a value of type [`DownwardAPIVolumeSource`](crate::api::core::v1::DownwardAPIVolumeSource).
+    fn visit_api_core_v1_downward_api_volume_source_mut(&mut self, node: &mut crate::api::core::v1::DownwardAPIVolumeSource) {
+        visit_api_core_v1_downward_api_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`EmptyDirVolumeSource`](crate::api::core::v1::EmptyDirVolumeSource).
+    fn visit_api_core_v1_empty_dir_volume_source_mut(&mut self, node: &mut crate::api::core::v1::EmptyDirVolumeSource) {
+        visit_api_core_v1_empty_dir_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointAddress`](crate::api::core::v1::EndpointAddress).
+    fn visit_api_core_v1_endpoint_address_mut(&mut self, node: &mut crate::api::core::v1::EndpointAddress) {
+        visit_api_core_v1_endpoint_address_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointPort`](crate::api::core::v1::EndpointPort).
+    fn visit_api_core_v1_endpoint_port_mut(&mut self, node: &mut crate::api::core::v1::EndpointPort) {
+        visit_api_core_v1_endpoint_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointSubset`](crate::api::core::v1::EndpointSubset).
+    fn visit_api_core_v1_endpoint_subset_mut(&mut self, node: &mut crate::api::core::v1::EndpointSubset) {
+        visit_api_core_v1_endpoint_subset_mut(self, node);
+    }
+
+    /// Visits a value of type [`Endpoints`](crate::api::core::v1::Endpoints).
+    fn visit_api_core_v1_endpoints_mut(&mut self, node: &mut crate::api::core::v1::Endpoints) {
+        visit_api_core_v1_endpoints_mut(self, node);
+    }
+
+    /// Visits a value of type [`EnvFromSource`](crate::api::core::v1::EnvFromSource).
+    fn visit_api_core_v1_env_from_source_mut(&mut self, node: &mut crate::api::core::v1::EnvFromSource) {
+        visit_api_core_v1_env_from_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`EnvVar`](crate::api::core::v1::EnvVar).
+    fn visit_api_core_v1_env_var_mut(&mut self, node: &mut crate::api::core::v1::EnvVar) {
+        visit_api_core_v1_env_var_mut(self, node);
+    }
+
+    /// Visits a value of type [`EnvVarSource`](crate::api::core::v1::EnvVarSource).
+    fn visit_api_core_v1_env_var_source_mut(&mut self, node: &mut crate::api::core::v1::EnvVarSource) {
+        visit_api_core_v1_env_var_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`EphemeralContainer`](crate::api::core::v1::EphemeralContainer).
+    fn visit_api_core_v1_ephemeral_container_mut(&mut self, node: &mut crate::api::core::v1::EphemeralContainer) {
+        visit_api_core_v1_ephemeral_container_mut(self, node);
+    }
+
+    /// Visits a value of type [`EphemeralVolumeSource`](crate::api::core::v1::EphemeralVolumeSource).
+    fn visit_api_core_v1_ephemeral_volume_source_mut(&mut self, node: &mut crate::api::core::v1::EphemeralVolumeSource) {
+        visit_api_core_v1_ephemeral_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Event`](crate::api::core::v1::Event).
+    fn visit_api_core_v1_event_mut(&mut self, node: &mut crate::api::core::v1::Event) {
+        visit_api_core_v1_event_mut(self, node);
+    }
+
+    /// Visits a value of type [`EventSeries`](crate::api::core::v1::EventSeries).
+    fn visit_api_core_v1_event_series_mut(&mut self, node: &mut crate::api::core::v1::EventSeries) {
+        visit_api_core_v1_event_series_mut(self, node);
+    }
+
+    /// Visits a value of type [`EventSource`](crate::api::core::v1::EventSource).
+    fn visit_api_core_v1_event_source_mut(&mut self, node: &mut crate::api::core::v1::EventSource) {
+        visit_api_core_v1_event_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExecAction`](crate::api::core::v1::ExecAction).
+    fn visit_api_core_v1_exec_action_mut(&mut self, node: &mut crate::api::core::v1::ExecAction) {
+        visit_api_core_v1_exec_action_mut(self, node);
+    }
+
+    /// Visits a value of type [`FCVolumeSource`](crate::api::core::v1::FCVolumeSource).
+    fn visit_api_core_v1_fc_volume_source_mut(&mut self, node: &mut crate::api::core::v1::FCVolumeSource) {
+        visit_api_core_v1_fc_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlexPersistentVolumeSource`](crate::api::core::v1::FlexPersistentVolumeSource).
+    fn visit_api_core_v1_flex_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::FlexPersistentVolumeSource) {
+        visit_api_core_v1_flex_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlexVolumeSource`](crate::api::core::v1::FlexVolumeSource).
+    fn visit_api_core_v1_flex_volume_source_mut(&mut self, node: &mut crate::api::core::v1::FlexVolumeSource) {
+        visit_api_core_v1_flex_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlockerVolumeSource`](crate::api::core::v1::FlockerVolumeSource).
+    fn visit_api_core_v1_flocker_volume_source_mut(&mut self, node: &mut crate::api::core::v1::FlockerVolumeSource) {
+        visit_api_core_v1_flocker_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`GCEPersistentDiskVolumeSource`](crate::api::core::v1::GCEPersistentDiskVolumeSource).
+    fn visit_api_core_v1_gce_persistent_disk_volume_source_mut(&mut self, node: &mut crate::api::core::v1::GCEPersistentDiskVolumeSource) {
+        visit_api_core_v1_gce_persistent_disk_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`GRPCAction`](crate::api::core::v1::GRPCAction).
+    fn visit_api_core_v1_grpc_action_mut(&mut self, node: &mut crate::api::core::v1::GRPCAction) {
+        visit_api_core_v1_grpc_action_mut(self, node);
+    }
+
+    /// Visits a value of type [`GitRepoVolumeSource`](crate::api::core::v1::GitRepoVolumeSource).
+    fn visit_api_core_v1_git_repo_volume_source_mut(&mut self, node: &mut crate::api::core::v1::GitRepoVolumeSource) {
+        visit_api_core_v1_git_repo_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`GlusterfsPersistentVolumeSource`](crate::api::core::v1::GlusterfsPersistentVolumeSource).
+    fn visit_api_core_v1_glusterfs_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::GlusterfsPersistentVolumeSource) {
+        visit_api_core_v1_glusterfs_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`GlusterfsVolumeSource`](crate::api::core::v1::GlusterfsVolumeSource).
+    fn visit_api_core_v1_glusterfs_volume_source_mut(&mut self, node: &mut crate::api::core::v1::GlusterfsVolumeSource) {
+        visit_api_core_v1_glusterfs_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`HTTPGetAction`](crate::api::core::v1::HTTPGetAction).
+    fn visit_api_core_v1_http_get_action_mut(&mut self, node: &mut crate::api::core::v1::HTTPGetAction) {
+        visit_api_core_v1_http_get_action_mut(self, node);
+    }
+
+    /// Visits a value of type [`HTTPHeader`](crate::api::core::v1::HTTPHeader).
+    fn visit_api_core_v1_http_header_mut(&mut self, node: &mut crate::api::core::v1::HTTPHeader) {
+        visit_api_core_v1_http_header_mut(self, node);
+    }
+
+    /// Visits a value of type [`HostAlias`](crate::api::core::v1::HostAlias).
+    fn visit_api_core_v1_host_alias_mut(&mut self, node: &mut crate::api::core::v1::HostAlias) {
+        visit_api_core_v1_host_alias_mut(self, node);
+    }
+
+    /// Visits a value of type [`HostIP`](crate::api::core::v1::HostIP).
+    fn visit_api_core_v1_host_ip_mut(&mut self, node: &mut crate::api::core::v1::HostIP) {
+        visit_api_core_v1_host_ip_mut(self, node);
+    }
+
+    /// Visits a value of type [`HostPathVolumeSource`](crate::api::core::v1::HostPathVolumeSource).
+    fn visit_api_core_v1_host_path_volume_source_mut(&mut self, node: &mut crate::api::core::v1::HostPathVolumeSource) {
+        visit_api_core_v1_host_path_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ISCSIPersistentVolumeSource`](crate::api::core::v1::ISCSIPersistentVolumeSource).
+    fn visit_api_core_v1_iscsi_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ISCSIPersistentVolumeSource) {
+        visit_api_core_v1_iscsi_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ISCSIVolumeSource`](crate::api::core::v1::ISCSIVolumeSource).
+    fn visit_api_core_v1_iscsi_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ISCSIVolumeSource) {
+        visit_api_core_v1_iscsi_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ImageVolumeSource`](crate::api::core::v1::ImageVolumeSource).
+    fn visit_api_core_v1_image_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ImageVolumeSource) {
+        visit_api_core_v1_image_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`KeyToPath`](crate::api::core::v1::KeyToPath).
+    fn visit_api_core_v1_key_to_path_mut(&mut self, node: &mut crate::api::core::v1::KeyToPath) {
+        visit_api_core_v1_key_to_path_mut(self, node);
+    }
+
+    /// Visits a value of type [`Lifecycle`](crate::api::core::v1::Lifecycle).
+    fn visit_api_core_v1_lifecycle_mut(&mut self, node: &mut crate::api::core::v1::Lifecycle) {
+        visit_api_core_v1_lifecycle_mut(self, node);
+    }
+
+    /// Visits a value of type [`LifecycleHandler`](crate::api::core::v1::LifecycleHandler).
+    fn visit_api_core_v1_lifecycle_handler_mut(&mut self, node: &mut crate::api::core::v1::LifecycleHandler) {
+        visit_api_core_v1_lifecycle_handler_mut(self, node);
+    }
+
+    /// Visits a value of type [`LimitRange`](crate::api::core::v1::LimitRange).
+    fn visit_api_core_v1_limit_range_mut(&mut self, node: &mut crate::api::core::v1::LimitRange) {
+        visit_api_core_v1_limit_range_mut(self, node);
+    }
+
+    /// Visits a value of type [`LimitRangeItem`](crate::api::core::v1::LimitRangeItem).
+    fn visit_api_core_v1_limit_range_item_mut(&mut self, node: &mut crate::api::core::v1::LimitRangeItem) {
+        visit_api_core_v1_limit_range_item_mut(self, node);
+    }
+
+    /// Visits a value of type [`LimitRangeSpec`](crate::api::core::v1::LimitRangeSpec).
+    fn visit_api_core_v1_limit_range_spec_mut(&mut self, node: &mut crate::api::core::v1::LimitRangeSpec) {
+        visit_api_core_v1_limit_range_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`LinuxContainerUser`](crate::api::core::v1::LinuxContainerUser).
+    fn visit_api_core_v1_linux_container_user_mut(&mut self, node: &mut crate::api::core::v1::LinuxContainerUser) {
+        visit_api_core_v1_linux_container_user_mut(self, node);
+    }
+
+    /// Visits a value of type [`LoadBalancerIngress`](crate::api::core::v1::LoadBalancerIngress).
+    fn visit_api_core_v1_load_balancer_ingress_mut(&mut self, node: &mut crate::api::core::v1::LoadBalancerIngress) {
+        visit_api_core_v1_load_balancer_ingress_mut(self, node);
+    }
+
+    /// Visits a value of type [`LoadBalancerStatus`](crate::api::core::v1::LoadBalancerStatus).
+    fn visit_api_core_v1_load_balancer_status_mut(&mut self, node: &mut crate::api::core::v1::LoadBalancerStatus) {
+        visit_api_core_v1_load_balancer_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`LocalObjectReference`](crate::api::core::v1::LocalObjectReference).
+    fn visit_api_core_v1_local_object_reference_mut(&mut self, node: &mut crate::api::core::v1::LocalObjectReference) {
+        visit_api_core_v1_local_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`LocalVolumeSource`](crate::api::core::v1::LocalVolumeSource).
+    fn visit_api_core_v1_local_volume_source_mut(&mut self, node: &mut crate::api::core::v1::LocalVolumeSource) {
+        visit_api_core_v1_local_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ModifyVolumeStatus`](crate::api::core::v1::ModifyVolumeStatus).
+    fn visit_api_core_v1_modify_volume_status_mut(&mut self, node: &mut crate::api::core::v1::ModifyVolumeStatus) {
+        visit_api_core_v1_modify_volume_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`NFSVolumeSource`](crate::api::core::v1::NFSVolumeSource).
+    fn visit_api_core_v1_nfs_volume_source_mut(&mut self, node: &mut crate::api::core::v1::NFSVolumeSource) {
+        visit_api_core_v1_nfs_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Namespace`](crate::api::core::v1::Namespace).
+    fn visit_api_core_v1_namespace_mut(&mut self, node: &mut crate::api::core::v1::Namespace) {
+        visit_api_core_v1_namespace_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceCondition`](crate::api::core::v1::NamespaceCondition).
+    fn visit_api_core_v1_namespace_condition_mut(&mut self, node: &mut crate::api::core::v1::NamespaceCondition) {
+        visit_api_core_v1_namespace_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceSpec`](crate::api::core::v1::NamespaceSpec).
+    fn visit_api_core_v1_namespace_spec_mut(&mut self, node: &mut crate::api::core::v1::NamespaceSpec) {
+        visit_api_core_v1_namespace_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`NamespaceStatus`](crate::api::core::v1::NamespaceStatus).
+    fn visit_api_core_v1_namespace_status_mut(&mut self, node: &mut crate::api::core::v1::NamespaceStatus) {
+        visit_api_core_v1_namespace_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`Node`](crate::api::core::v1::Node).
+    fn visit_api_core_v1_node_mut(&mut self, node: &mut crate::api::core::v1::Node) {
+        visit_api_core_v1_node_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeAddress`](crate::api::core::v1::NodeAddress).
+    fn visit_api_core_v1_node_address_mut(&mut self, node: &mut crate::api::core::v1::NodeAddress) {
+        visit_api_core_v1_node_address_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeAffinity`](crate::api::core::v1::NodeAffinity).
+    fn visit_api_core_v1_node_affinity_mut(&mut self, node: &mut crate::api::core::v1::NodeAffinity) {
+        visit_api_core_v1_node_affinity_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeCondition`](crate::api::core::v1::NodeCondition).
+    fn visit_api_core_v1_node_condition_mut(&mut self, node: &mut crate::api::core::v1::NodeCondition) {
+        visit_api_core_v1_node_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeConfigSource`](crate::api::core::v1::NodeConfigSource).
+    fn visit_api_core_v1_node_config_source_mut(&mut self, node: &mut crate::api::core::v1::NodeConfigSource) {
+        visit_api_core_v1_node_config_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeConfigStatus`](crate::api::core::v1::NodeConfigStatus).
+    fn visit_api_core_v1_node_config_status_mut(&mut self, node: &mut crate::api::core::v1::NodeConfigStatus) {
+        visit_api_core_v1_node_config_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeDaemonEndpoints`](crate::api::core::v1::NodeDaemonEndpoints).
+    fn visit_api_core_v1_node_daemon_endpoints_mut(&mut self, node: &mut crate::api::core::v1::NodeDaemonEndpoints) {
+        visit_api_core_v1_node_daemon_endpoints_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeFeatures`](crate::api::core::v1::NodeFeatures).
+    fn visit_api_core_v1_node_features_mut(&mut self, node: &mut crate::api::core::v1::NodeFeatures) {
+        visit_api_core_v1_node_features_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeRuntimeHandler`](crate::api::core::v1::NodeRuntimeHandler).
+    fn visit_api_core_v1_node_runtime_handler_mut(&mut self, node: &mut crate::api::core::v1::NodeRuntimeHandler) {
+        visit_api_core_v1_node_runtime_handler_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeRuntimeHandlerFeatures`](crate::api::core::v1::NodeRuntimeHandlerFeatures).
+    fn visit_api_core_v1_node_runtime_handler_features_mut(&mut self, node: &mut crate::api::core::v1::NodeRuntimeHandlerFeatures) {
+        visit_api_core_v1_node_runtime_handler_features_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelector`](crate::api::core::v1::NodeSelector).
+    fn visit_api_core_v1_node_selector_mut(&mut self, node: &mut crate::api::core::v1::NodeSelector) {
+        visit_api_core_v1_node_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelectorRequirement`](crate::api::core::v1::NodeSelectorRequirement).
+    fn visit_api_core_v1_node_selector_requirement_mut(&mut self, node: &mut crate::api::core::v1::NodeSelectorRequirement) {
+        visit_api_core_v1_node_selector_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeSelectorTerm`](crate::api::core::v1::NodeSelectorTerm).
+    fn visit_api_core_v1_node_selector_term_mut(&mut self, node: &mut crate::api::core::v1::NodeSelectorTerm) {
+        visit_api_core_v1_node_selector_term_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeSpec`](crate::api::core::v1::NodeSpec).
+    fn visit_api_core_v1_node_spec_mut(&mut self, node: &mut crate::api::core::v1::NodeSpec) {
+        visit_api_core_v1_node_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeStatus`](crate::api::core::v1::NodeStatus).
+    fn visit_api_core_v1_node_status_mut(&mut self, node: &mut crate::api::core::v1::NodeStatus) {
+        visit_api_core_v1_node_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`NodeSystemInfo`](crate::api::core::v1::NodeSystemInfo).
+    fn visit_api_core_v1_node_system_info_mut(&mut self, node: &mut crate::api::core::v1::NodeSystemInfo) {
+        visit_api_core_v1_node_system_info_mut(self, node);
+    }
+
+    /// Visits a value of type [`ObjectFieldSelector`](crate::api::core::v1::ObjectFieldSelector).
+    fn visit_api_core_v1_object_field_selector_mut(&mut self, node: &mut crate::api::core::v1::ObjectFieldSelector) {
+        visit_api_core_v1_object_field_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`ObjectReference`](crate::api::core::v1::ObjectReference).
+    fn visit_api_core_v1_object_reference_mut(&mut self, node: &mut crate::api::core::v1::ObjectReference) {
+        visit_api_core_v1_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolume`](crate::api::core::v1::PersistentVolume).
+    fn visit_api_core_v1_persistent_volume_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolume) {
+        visit_api_core_v1_persistent_volume_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaim`](crate::api::core::v1::PersistentVolumeClaim).
+    fn visit_api_core_v1_persistent_volume_claim_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaim) {
+        visit_api_core_v1_persistent_volume_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimCondition`](crate::api::core::v1::PersistentVolumeClaimCondition).
+    fn visit_api_core_v1_persistent_volume_claim_condition_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaimCondition) {
+        visit_api_core_v1_persistent_volume_claim_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimSpec`](crate::api::core::v1::PersistentVolumeClaimSpec).
+    fn visit_api_core_v1_persistent_volume_claim_spec_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaimSpec) {
+        visit_api_core_v1_persistent_volume_claim_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimStatus`](crate::api::core::v1::PersistentVolumeClaimStatus).
+    fn visit_api_core_v1_persistent_volume_claim_status_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaimStatus) {
+        visit_api_core_v1_persistent_volume_claim_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimTemplate`](crate::api::core::v1::PersistentVolumeClaimTemplate).
+    fn visit_api_core_v1_persistent_volume_claim_template_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaimTemplate) {
+        visit_api_core_v1_persistent_volume_claim_template_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeClaimVolumeSource`](crate::api::core::v1::PersistentVolumeClaimVolumeSource).
+    fn visit_api_core_v1_persistent_volume_claim_volume_source_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeClaimVolumeSource) {
+        visit_api_core_v1_persistent_volume_claim_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeSpec`](crate::api::core::v1::PersistentVolumeSpec).
+    fn visit_api_core_v1_persistent_volume_spec_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeSpec) {
+        visit_api_core_v1_persistent_volume_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PersistentVolumeStatus`](crate::api::core::v1::PersistentVolumeStatus).
+    fn visit_api_core_v1_persistent_volume_status_mut(&mut self, node: &mut crate::api::core::v1::PersistentVolumeStatus) {
+        visit_api_core_v1_persistent_volume_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PhotonPersistentDiskVolumeSource`](crate::api::core::v1::PhotonPersistentDiskVolumeSource).
+    fn visit_api_core_v1_photon_persistent_disk_volume_source_mut(&mut self, node: &mut crate::api::core::v1::PhotonPersistentDiskVolumeSource) {
+        visit_api_core_v1_photon_persistent_disk_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Pod`](crate::api::core::v1::Pod).
+    fn visit_api_core_v1_pod_mut(&mut self, node: &mut crate::api::core::v1::Pod) {
+        visit_api_core_v1_pod_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodAffinity`](crate::api::core::v1::PodAffinity).
+    fn visit_api_core_v1_pod_affinity_mut(&mut self, node: &mut crate::api::core::v1::PodAffinity) {
+        visit_api_core_v1_pod_affinity_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodAffinityTerm`](crate::api::core::v1::PodAffinityTerm).
+    fn visit_api_core_v1_pod_affinity_term_mut(&mut self, node: &mut crate::api::core::v1::PodAffinityTerm) {
+        visit_api_core_v1_pod_affinity_term_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodAntiAffinity`](crate::api::core::v1::PodAntiAffinity).
+    fn visit_api_core_v1_pod_anti_affinity_mut(&mut self, node: &mut crate::api::core::v1::PodAntiAffinity) {
+        visit_api_core_v1_pod_anti_affinity_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodCondition`](crate::api::core::v1::PodCondition).
+    fn visit_api_core_v1_pod_condition_mut(&mut self, node: &mut crate::api::core::v1::PodCondition) {
+        visit_api_core_v1_pod_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodDNSConfig`](crate::api::core::v1::PodDNSConfig).
+    fn visit_api_core_v1_pod_dns_config_mut(&mut self, node: &mut crate::api::core::v1::PodDNSConfig) {
+        visit_api_core_v1_pod_dns_config_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodDNSConfigOption`](crate::api::core::v1::PodDNSConfigOption).
+    fn visit_api_core_v1_pod_dns_config_option_mut(&mut self, node: &mut crate::api::core::v1::PodDNSConfigOption) {
+        visit_api_core_v1_pod_dns_config_option_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodIP`](crate::api::core::v1::PodIP).
+    fn visit_api_core_v1_pod_ip_mut(&mut self, node: &mut crate::api::core::v1::PodIP) {
+        visit_api_core_v1_pod_ip_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodOS`](crate::api::core::v1::PodOS).
+    fn visit_api_core_v1_pod_os_mut(&mut self, node: &mut crate::api::core::v1::PodOS) {
+        visit_api_core_v1_pod_os_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodReadinessGate`](crate::api::core::v1::PodReadinessGate).
+    fn visit_api_core_v1_pod_readiness_gate_mut(&mut self, node: &mut crate::api::core::v1::PodReadinessGate) {
+        visit_api_core_v1_pod_readiness_gate_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodResourceClaim`](crate::api::core::v1::PodResourceClaim).
+    fn visit_api_core_v1_pod_resource_claim_mut(&mut self, node: &mut crate::api::core::v1::PodResourceClaim) {
+        visit_api_core_v1_pod_resource_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodResourceClaimStatus`](crate::api::core::v1::PodResourceClaimStatus).
+    fn visit_api_core_v1_pod_resource_claim_status_mut(&mut self, node: &mut crate::api::core::v1::PodResourceClaimStatus) {
+        visit_api_core_v1_pod_resource_claim_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodSchedulingGate`](crate::api::core::v1::PodSchedulingGate).
+    fn visit_api_core_v1_pod_scheduling_gate_mut(&mut self, node: &mut crate::api::core::v1::PodSchedulingGate) {
+        visit_api_core_v1_pod_scheduling_gate_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodSecurityContext`](crate::api::core::v1::PodSecurityContext).
+    fn visit_api_core_v1_pod_security_context_mut(&mut self, node: &mut crate::api::core::v1::PodSecurityContext) {
+        visit_api_core_v1_pod_security_context_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodSpec`](crate::api::core::v1::PodSpec).
+    fn visit_api_core_v1_pod_spec_mut(&mut self, node: &mut crate::api::core::v1::PodSpec) {
+        visit_api_core_v1_pod_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodStatus`](crate::api::core::v1::PodStatus).
+    fn visit_api_core_v1_pod_status_mut(&mut self, node: &mut crate::api::core::v1::PodStatus) {
+        visit_api_core_v1_pod_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodTemplate`](crate::api::core::v1::PodTemplate).
+    fn visit_api_core_v1_pod_template_mut(&mut self, node: &mut crate::api::core::v1::PodTemplate) {
+        visit_api_core_v1_pod_template_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodTemplateSpec`](crate::api::core::v1::PodTemplateSpec).
+    fn visit_api_core_v1_pod_template_spec_mut(&mut self, node: &mut crate::api::core::v1::PodTemplateSpec) {
+        visit_api_core_v1_pod_template_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PortStatus`](crate::api::core::v1::PortStatus).
+    fn visit_api_core_v1_port_status_mut(&mut self, node: &mut crate::api::core::v1::PortStatus) {
+        visit_api_core_v1_port_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`PortworxVolumeSource`](crate::api::core::v1::PortworxVolumeSource).
+    fn visit_api_core_v1_portworx_volume_source_mut(&mut self, node: &mut crate::api::core::v1::PortworxVolumeSource) {
+        visit_api_core_v1_portworx_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`PreferredSchedulingTerm`](crate::api::core::v1::PreferredSchedulingTerm).
+    fn visit_api_core_v1_preferred_scheduling_term_mut(&mut self, node: &mut crate::api::core::v1::PreferredSchedulingTerm) {
+        visit_api_core_v1_preferred_scheduling_term_mut(self, node);
+    }
+
+    /// Visits a value of type [`Probe`](crate::api::core::v1::Probe).
+    fn visit_api_core_v1_probe_mut(&mut self, node: &mut crate::api::core::v1::Probe) {
+        visit_api_core_v1_probe_mut(self, node);
+    }
+
+    /// Visits a value of type [`ProjectedVolumeSource`](crate::api::core::v1::ProjectedVolumeSource).
+    fn visit_api_core_v1_projected_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ProjectedVolumeSource) {
+        visit_api_core_v1_projected_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`QuobyteVolumeSource`](crate::api::core::v1::QuobyteVolumeSource).
+    fn visit_api_core_v1_quobyte_volume_source_mut(&mut self, node: &mut crate::api::core::v1::QuobyteVolumeSource) {
+        visit_api_core_v1_quobyte_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`RBDPersistentVolumeSource`](crate::api::core::v1::RBDPersistentVolumeSource).
+    fn visit_api_core_v1_rbd_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::RBDPersistentVolumeSource) {
+        visit_api_core_v1_rbd_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`RBDVolumeSource`](crate::api::core::v1::RBDVolumeSource).
+    fn visit_api_core_v1_rbd_volume_source_mut(&mut self, node: &mut crate::api::core::v1::RBDVolumeSource) {
+        visit_api_core_v1_rbd_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationController`](crate::api::core::v1::ReplicationController).
+    fn visit_api_core_v1_replication_controller_mut(&mut self, node: &mut crate::api::core::v1::ReplicationController) {
+        visit_api_core_v1_replication_controller_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerCondition`](crate::api::core::v1::ReplicationControllerCondition).
+    fn visit_api_core_v1_replication_controller_condition_mut(&mut self, node: &mut crate::api::core::v1::ReplicationControllerCondition) {
+        visit_api_core_v1_replication_controller_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerSpec`](crate::api::core::v1::ReplicationControllerSpec).
+    fn visit_api_core_v1_replication_controller_spec_mut(&mut self, node: &mut crate::api::core::v1::ReplicationControllerSpec) {
+        visit_api_core_v1_replication_controller_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ReplicationControllerStatus`](crate::api::core::v1::ReplicationControllerStatus).
+    fn visit_api_core_v1_replication_controller_status_mut(&mut self, node: &mut crate::api::core::v1::ReplicationControllerStatus) {
+        visit_api_core_v1_replication_controller_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::core::v1::ResourceClaim).
+    fn visit_api_core_v1_resource_claim_mut(&mut self, node: &mut crate::api::core::v1::ResourceClaim) {
+        visit_api_core_v1_resource_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceFieldSelector`](crate::api::core::v1::ResourceFieldSelector).
+    fn visit_api_core_v1_resource_field_selector_mut(&mut self, node: &mut crate::api::core::v1::ResourceFieldSelector) {
+        visit_api_core_v1_resource_field_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceHealth`](crate::api::core::v1::ResourceHealth).
+    fn visit_api_core_v1_resource_health_mut(&mut self, node: &mut crate::api::core::v1::ResourceHealth) {
+        visit_api_core_v1_resource_health_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuota`](crate::api::core::v1::ResourceQuota).
+    fn visit_api_core_v1_resource_quota_mut(&mut self, node: &mut crate::api::core::v1::ResourceQuota) {
+        visit_api_core_v1_resource_quota_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuotaSpec`](crate::api::core::v1::ResourceQuotaSpec).
+    fn visit_api_core_v1_resource_quota_spec_mut(&mut self, node: &mut crate::api::core::v1::ResourceQuotaSpec) {
+        visit_api_core_v1_resource_quota_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceQuotaStatus`](crate::api::core::v1::ResourceQuotaStatus).
+    fn visit_api_core_v1_resource_quota_status_mut(&mut self, node: &mut crate::api::core::v1::ResourceQuotaStatus) {
+        visit_api_core_v1_resource_quota_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceRequirements`](crate::api::core::v1::ResourceRequirements).
+    fn visit_api_core_v1_resource_requirements_mut(&mut self, node: &mut crate::api::core::v1::ResourceRequirements) {
+        visit_api_core_v1_resource_requirements_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceStatus`](crate::api::core::v1::ResourceStatus).
+    fn visit_api_core_v1_resource_status_mut(&mut self, node: &mut crate::api::core::v1::ResourceStatus) {
+        visit_api_core_v1_resource_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`SELinuxOptions`](crate::api::core::v1::SELinuxOptions).
+    fn visit_api_core_v1_se_linux_options_mut(&mut self, node: &mut crate::api::core::v1::SELinuxOptions) {
+        visit_api_core_v1_se_linux_options_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScaleIOPersistentVolumeSource`](crate::api::core::v1::ScaleIOPersistentVolumeSource).
+    fn visit_api_core_v1_scale_io_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ScaleIOPersistentVolumeSource) {
+        visit_api_core_v1_scale_io_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScaleIOVolumeSource`](crate::api::core::v1::ScaleIOVolumeSource).
+    fn visit_api_core_v1_scale_io_volume_source_mut(&mut self, node: &mut crate::api::core::v1::ScaleIOVolumeSource) {
+        visit_api_core_v1_scale_io_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScopeSelector`](crate::api::core::v1::ScopeSelector).
+    fn visit_api_core_v1_scope_selector_mut(&mut self, node: &mut crate::api::core::v1::ScopeSelector) {
+        visit_api_core_v1_scope_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`ScopedResourceSelectorRequirement`](crate::api::core::v1::ScopedResourceSelectorRequirement).
+    fn visit_api_core_v1_scoped_resource_selector_requirement_mut(&mut self, node: &mut crate::api::core::v1::ScopedResourceSelectorRequirement) {
+        visit_api_core_v1_scoped_resource_selector_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`SeccompProfile`](crate::api::core::v1::SeccompProfile).
+    fn visit_api_core_v1_seccomp_profile_mut(&mut self, node: &mut crate::api::core::v1::SeccompProfile) {
+        visit_api_core_v1_seccomp_profile_mut(self, node);
+    }
+
+    /// Visits a value of type [`Secret`](crate::api::core::v1::Secret).
+    fn visit_api_core_v1_secret_mut(&mut self, node: &mut crate::api::core::v1::Secret) {
+        visit_api_core_v1_secret_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecretEnvSource`](crate::api::core::v1::SecretEnvSource).
+    fn visit_api_core_v1_secret_env_source_mut(&mut self, node: &mut crate::api::core::v1::SecretEnvSource) {
+        visit_api_core_v1_secret_env_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecretKeySelector`](crate::api::core::v1::SecretKeySelector).
+    fn visit_api_core_v1_secret_key_selector_mut(&mut self, node: &mut crate::api::core::v1::SecretKeySelector) {
+        visit_api_core_v1_secret_key_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecretProjection`](crate::api::core::v1::SecretProjection).
+    fn visit_api_core_v1_secret_projection_mut(&mut self, node: &mut crate::api::core::v1::SecretProjection) {
+        visit_api_core_v1_secret_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecretReference`](crate::api::core::v1::SecretReference).
+    fn visit_api_core_v1_secret_reference_mut(&mut self, node: &mut crate::api::core::v1::SecretReference) {
+        visit_api_core_v1_secret_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecretVolumeSource`](crate::api::core::v1::SecretVolumeSource).
+    fn visit_api_core_v1_secret_volume_source_mut(&mut self, node: &mut crate::api::core::v1::SecretVolumeSource) {
+        visit_api_core_v1_secret_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`SecurityContext`](crate::api::core::v1::SecurityContext).
+    fn visit_api_core_v1_security_context_mut(&mut self, node: &mut crate::api::core::v1::SecurityContext) {
+        visit_api_core_v1_security_context_mut(self, node);
+    }
+
+    /// Visits a value of type [`Service`](crate::api::core::v1::Service).
+    fn visit_api_core_v1_service_mut(&mut self, node: &mut crate::api::core::v1::Service) {
+        visit_api_core_v1_service_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccount`](crate::api::core::v1::ServiceAccount).
+    fn visit_api_core_v1_service_account_mut(&mut self, node: &mut crate::api::core::v1::ServiceAccount) {
+        visit_api_core_v1_service_account_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccountTokenProjection`](crate::api::core::v1::ServiceAccountTokenProjection).
+    fn visit_api_core_v1_service_account_token_projection_mut(&mut self, node: &mut crate::api::core::v1::ServiceAccountTokenProjection) {
+        visit_api_core_v1_service_account_token_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServicePort`](crate::api::core::v1::ServicePort).
+    fn visit_api_core_v1_service_port_mut(&mut self, node: &mut crate::api::core::v1::ServicePort) {
+        visit_api_core_v1_service_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceSpec`](crate::api::core::v1::ServiceSpec).
+    fn visit_api_core_v1_service_spec_mut(&mut self, node: &mut crate::api::core::v1::ServiceSpec) {
+        visit_api_core_v1_service_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceStatus`](crate::api::core::v1::ServiceStatus).
+    fn visit_api_core_v1_service_status_mut(&mut self, node: &mut crate::api::core::v1::ServiceStatus) {
+        visit_api_core_v1_service_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`SessionAffinityConfig`](crate::api::core::v1::SessionAffinityConfig).
+    fn visit_api_core_v1_session_affinity_config_mut(&mut self, node: &mut crate::api::core::v1::SessionAffinityConfig) {
+        visit_api_core_v1_session_affinity_config_mut(self, node);
+    }
+
+    /// Visits a value of type [`SleepAction`](crate::api::core::v1::SleepAction).
+    fn visit_api_core_v1_sleep_action_mut(&mut self, node: &mut crate::api::core::v1::SleepAction) {
+        visit_api_core_v1_sleep_action_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageOSPersistentVolumeSource`](crate::api::core::v1::StorageOSPersistentVolumeSource).
+    fn visit_api_core_v1_storage_os_persistent_volume_source_mut(&mut self, node: &mut crate::api::core::v1::StorageOSPersistentVolumeSource) {
+        visit_api_core_v1_storage_os_persistent_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageOSVolumeSource`](crate::api::core::v1::StorageOSVolumeSource).
+    fn visit_api_core_v1_storage_os_volume_source_mut(&mut self, node: &mut crate::api::core::v1::StorageOSVolumeSource) {
+        visit_api_core_v1_storage_os_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`Sysctl`](crate::api::core::v1::Sysctl).
+    fn visit_api_core_v1_sysctl_mut(&mut self, node: &mut crate::api::core::v1::Sysctl) {
+        visit_api_core_v1_sysctl_mut(self, node);
+    }
+
+    /// Visits a value of type [`TCPSocketAction`](crate::api::core::v1::TCPSocketAction).
+    fn visit_api_core_v1_tcp_socket_action_mut(&mut self, node: &mut crate::api::core::v1::TCPSocketAction) {
+        visit_api_core_v1_tcp_socket_action_mut(self, node);
+    }
+
+    /// Visits a value of type [`Taint`](crate::api::core::v1::Taint).
+    fn visit_api_core_v1_taint_mut(&mut self, node: &mut crate::api::core::v1::Taint) {
+        visit_api_core_v1_taint_mut(self, node);
+    }
+
+    /// Visits a value of type [`Toleration`](crate::api::core::v1::Toleration).
+    fn visit_api_core_v1_toleration_mut(&mut self, node: &mut crate::api::core::v1::Toleration) {
+        visit_api_core_v1_toleration_mut(self, node);
+    }
+
+    /// Visits a value of type [`TopologySelectorLabelRequirement`](crate::api::core::v1::TopologySelectorLabelRequirement).
+    fn visit_api_core_v1_topology_selector_label_requirement_mut(&mut self, node: &mut crate::api::core::v1::TopologySelectorLabelRequirement) {
+        visit_api_core_v1_topology_selector_label_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`TopologySelectorTerm`](crate::api::core::v1::TopologySelectorTerm).
+    fn visit_api_core_v1_topology_selector_term_mut(&mut self, node: &mut crate::api::core::v1::TopologySelectorTerm) {
+        visit_api_core_v1_topology_selector_term_mut(self, node);
+    }
+
+    /// Visits a value of type [`TopologySpreadConstraint`](crate::api::core::v1::TopologySpreadConstraint).
+    fn visit_api_core_v1_topology_spread_constraint_mut(&mut self, node: &mut crate::api::core::v1::TopologySpreadConstraint) {
+        visit_api_core_v1_topology_spread_constraint_mut(self, node);
+    }
+
+    /// Visits a value of type [`TypedLocalObjectReference`](crate::api::core::v1::TypedLocalObjectReference).
+    fn visit_api_core_v1_typed_local_object_reference_mut(&mut self, node: &mut crate::api::core::v1::TypedLocalObjectReference) {
+        visit_api_core_v1_typed_local_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`TypedObjectReference`](crate::api::core::v1::TypedObjectReference).
+    fn visit_api_core_v1_typed_object_reference_mut(&mut self, node: &mut crate::api::core::v1::TypedObjectReference) {
+        visit_api_core_v1_typed_object_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`Volume`](crate::api::core::v1::Volume).
+    fn visit_api_core_v1_volume_mut(&mut self, node: &mut crate::api::core::v1::Volume) {
+        visit_api_core_v1_volume_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeDevice`](crate::api::core::v1::VolumeDevice).
+    fn visit_api_core_v1_volume_device_mut(&mut self, node: &mut crate::api::core::v1::VolumeDevice) {
+        visit_api_core_v1_volume_device_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeMount`](crate::api::core::v1::VolumeMount).
+    fn visit_api_core_v1_volume_mount_mut(&mut self, node: &mut crate::api::core::v1::VolumeMount) {
+        visit_api_core_v1_volume_mount_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeMountStatus`](crate::api::core::v1::VolumeMountStatus).
+    fn visit_api_core_v1_volume_mount_status_mut(&mut self, node: &mut crate::api::core::v1::VolumeMountStatus) {
+        visit_api_core_v1_volume_mount_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeNodeAffinity`](crate::api::core::v1::VolumeNodeAffinity).
+    fn visit_api_core_v1_volume_node_affinity_mut(&mut self, node: &mut crate::api::core::v1::VolumeNodeAffinity) {
+        visit_api_core_v1_volume_node_affinity_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeProjection`](crate::api::core::v1::VolumeProjection).
+    fn visit_api_core_v1_volume_projection_mut(&mut self, node: &mut crate::api::core::v1::VolumeProjection) {
+        visit_api_core_v1_volume_projection_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeResourceRequirements`](crate::api::core::v1::VolumeResourceRequirements).
+    fn visit_api_core_v1_volume_resource_requirements_mut(&mut self, node: &mut crate::api::core::v1::VolumeResourceRequirements) {
+        visit_api_core_v1_volume_resource_requirements_mut(self, node);
+    }
+
+    /// Visits a value of type [`VsphereVirtualDiskVolumeSource`](crate::api::core::v1::VsphereVirtualDiskVolumeSource).
+    fn visit_api_core_v1_vsphere_virtual_disk_volume_source_mut(&mut self, node: &mut crate::api::core::v1::VsphereVirtualDiskVolumeSource) {
+        visit_api_core_v1_vsphere_virtual_disk_volume_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`WeightedPodAffinityTerm`](crate::api::core::v1::WeightedPodAffinityTerm).
+    fn visit_api_core_v1_weighted_pod_affinity_term_mut(&mut self, node: &mut crate::api::core::v1::WeightedPodAffinityTerm) {
+        visit_api_core_v1_weighted_pod_affinity_term_mut(self, node);
+    }
+
+    /// Visits a value of type [`WindowsSecurityContextOptions`](crate::api::core::v1::WindowsSecurityContextOptions).
+    fn visit_api_core_v1_windows_security_context_options_mut(&mut self, node: &mut crate::api::core::v1::WindowsSecurityContextOptions) {
+        visit_api_core_v1_windows_security_context_options_mut(self, node);
+    }
+
+    /// Visits a value of type [`Endpoint`](crate::api::discovery::v1::Endpoint).
+    fn visit_api_discovery_v1_endpoint_mut(&mut self, node: &mut crate::api::discovery::v1::Endpoint) {
+        visit_api_discovery_v1_endpoint_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointConditions`](crate::api::discovery::v1::EndpointConditions).
+    fn visit_api_discovery_v1_endpoint_conditions_mut(&mut self, node: &mut crate::api::discovery::v1::EndpointConditions) {
+        visit_api_discovery_v1_endpoint_conditions_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointHints`](crate::api::discovery::v1::EndpointHints).
+    fn visit_api_discovery_v1_endpoint_hints_mut(&mut self, node: &mut crate::api::discovery::v1::EndpointHints) {
+        visit_api_discovery_v1_endpoint_hints_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointPort`](crate::api::discovery::v1::EndpointPort).
+    fn visit_api_discovery_v1_endpoint_port_mut(&mut self, node: &mut crate::api::discovery::v1::EndpointPort) {
+        visit_api_discovery_v1_endpoint_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`EndpointSlice`](crate::api::discovery::v1::EndpointSlice).
+    fn visit_api_discovery_v1_endpoint_slice_mut(&mut self, node: &mut crate::api::discovery::v1::EndpointSlice) {
+        visit_api_discovery_v1_endpoint_slice_mut(self, node);
+    }
+
+    /// Visits a value of type [`ForZone`](crate::api::discovery::v1::ForZone).
+    fn visit_api_discovery_v1_for_zone_mut(&mut self, node: &mut crate::api::discovery::v1::ForZone) {
+        visit_api_discovery_v1_for_zone_mut(self, node);
+    }
+
+    /// Visits a value of type [`Event`](crate::api::events::v1::Event).
+    fn visit_api_events_v1_event_mut(&mut self, node: &mut crate::api::events::v1::Event) {
+        visit_api_events_v1_event_mut(self, node);
+    }
+
+    /// Visits a value of type [`EventSeries`](crate::api::events::v1::EventSeries).
+    fn visit_api_events_v1_event_series_mut(&mut self, node: &mut crate::api::events::v1::EventSeries) {
+        visit_api_events_v1_event_series_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExemptPriorityLevelConfiguration`](crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_exempt_priority_level_configuration_mut(&mut self, node: &mut crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_exempt_priority_level_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlowDistinguisherMethod`](crate::api::flowcontrol::v1::FlowDistinguisherMethod).
+    fn visit_api_flowcontrol_v1_flow_distinguisher_method_mut(&mut self, node: &mut crate::api::flowcontrol::v1::FlowDistinguisherMethod) {
+        visit_api_flowcontrol_v1_flow_distinguisher_method_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchema`](crate::api::flowcontrol::v1::FlowSchema).
+    fn visit_api_flowcontrol_v1_flow_schema_mut(&mut self, node: &mut crate::api::flowcontrol::v1::FlowSchema) {
+        visit_api_flowcontrol_v1_flow_schema_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaCondition`](crate::api::flowcontrol::v1::FlowSchemaCondition).
+    fn visit_api_flowcontrol_v1_flow_schema_condition_mut(&mut self, node: &mut crate::api::flowcontrol::v1::FlowSchemaCondition) {
+        visit_api_flowcontrol_v1_flow_schema_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaSpec`](crate::api::flowcontrol::v1::FlowSchemaSpec).
+    fn visit_api_flowcontrol_v1_flow_schema_spec_mut(&mut self, node: &mut crate::api::flowcontrol::v1::FlowSchemaSpec) {
+        visit_api_flowcontrol_v1_flow_schema_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`FlowSchemaStatus`](crate::api::flowcontrol::v1::FlowSchemaStatus).
+    fn visit_api_flowcontrol_v1_flow_schema_status_mut(&mut self, node: &mut crate::api::flowcontrol::v1::FlowSchemaStatus) {
+        visit_api_flowcontrol_v1_flow_schema_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`GroupSubject`](crate::api::flowcontrol::v1::GroupSubject).
+    fn visit_api_flowcontrol_v1_group_subject_mut(&mut self, node: &mut crate::api::flowcontrol::v1::GroupSubject) {
+        visit_api_flowcontrol_v1_group_subject_mut(self, node);
+    }
+
+    /// Visits a value of type [`LimitResponse`](crate::api::flowcontrol::v1::LimitResponse).
+    fn visit_api_flowcontrol_v1_limit_response_mut(&mut self, node: &mut crate::api::flowcontrol::v1::LimitResponse) {
+        visit_api_flowcontrol_v1_limit_response_mut(self, node);
+    }
+
+    /// Visits a value of type [`LimitedPriorityLevelConfiguration`](crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_limited_priority_level_configuration_mut(&mut self, node: &mut crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_limited_priority_level_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`NonResourcePolicyRule`](crate::api::flowcontrol::v1::NonResourcePolicyRule).
+    fn visit_api_flowcontrol_v1_non_resource_policy_rule_mut(&mut self, node: &mut crate::api::flowcontrol::v1::NonResourcePolicyRule) {
+        visit_api_flowcontrol_v1_non_resource_policy_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`PolicyRulesWithSubjects`](crate::api::flowcontrol::v1::PolicyRulesWithSubjects).
+    fn visit_api_flowcontrol_v1_policy_rules_with_subjects_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PolicyRulesWithSubjects) {
+        visit_api_flowcontrol_v1_policy_rules_with_subjects_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfiguration`](crate::api::flowcontrol::v1::PriorityLevelConfiguration).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfiguration) {
+        visit_api_flowcontrol_v1_priority_level_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationCondition`](crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_condition_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition) {
+        visit_api_flowcontrol_v1_priority_level_configuration_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationReference`](crate::api::flowcontrol::v1::PriorityLevelConfigurationReference).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_reference_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationReference) {
+        visit_api_flowcontrol_v1_priority_level_configuration_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationSpec`](crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_spec_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec) {
+        visit_api_flowcontrol_v1_priority_level_configuration_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityLevelConfigurationStatus`](crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus).
+    fn visit_api_flowcontrol_v1_priority_level_configuration_status_mut(&mut self, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus) {
+        visit_api_flowcontrol_v1_priority_level_configuration_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`QueuingConfiguration`](crate::api::flowcontrol::v1::QueuingConfiguration).
+    fn visit_api_flowcontrol_v1_queuing_configuration_mut(&mut self, node: &mut crate::api::flowcontrol::v1::QueuingConfiguration) {
+        visit_api_flowcontrol_v1_queuing_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePolicyRule`](crate::api::flowcontrol::v1::ResourcePolicyRule).
+    fn visit_api_flowcontrol_v1_resource_policy_rule_mut(&mut self, node: &mut crate::api::flowcontrol::v1::ResourcePolicyRule) {
+        visit_api_flowcontrol_v1_resource_policy_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceAccountSubject`](crate::api::flowcontrol::v1::ServiceAccountSubject).
+    fn visit_api_flowcontrol_v1_service_account_subject_mut(&mut self, node: &mut crate::api::flowcontrol::v1::ServiceAccountSubject) {
+        visit_api_flowcontrol_v1_service_account_subject_mut(self, node);
+    }
+
+    /// Visits a value of type [`Subject`](crate::api::flowcontrol::v1::Subject).
+    fn visit_api_flowcontrol_v1_subject_mut(&mut self, node: &mut crate::api::flowcontrol::v1::Subject) {
+        visit_api_flowcontrol_v1_subject_mut(self, node);
+    }
+
+    /// Visits a value of type [`UserSubject`](crate::api::flowcontrol::v1::UserSubject).
+    fn visit_api_flowcontrol_v1_user_subject_mut(&mut self, node: &mut crate::api::flowcontrol::v1::UserSubject) {
+        visit_api_flowcontrol_v1_user_subject_mut(self, node);
+    }
+
+    /// Visits a value of type [`HTTPIngressPath`](crate::api::networking::v1::HTTPIngressPath).
+    fn visit_api_networking_v1_http_ingress_path_mut(&mut self, node: &mut crate::api::networking::v1::HTTPIngressPath) {
+        visit_api_networking_v1_http_ingress_path_mut(self, node);
+    }
+
+    /// Visits a value of type [`HTTPIngressRuleValue`](crate::api::networking::v1::HTTPIngressRuleValue).
+    fn visit_api_networking_v1_http_ingress_rule_value_mut(&mut self, node: &mut crate::api::networking::v1::HTTPIngressRuleValue) {
+        visit_api_networking_v1_http_ingress_rule_value_mut(self, node);
+    }
+
+    /// Visits a value of type [`IPBlock`](crate::api::networking::v1::IPBlock).
+    fn visit_api_networking_v1_ip_block_mut(&mut self, node: &mut crate::api::networking::v1::IPBlock) {
+        visit_api_networking_v1_ip_block_mut(self, node);
+    }
+
+    /// Visits a value of type [`Ingress`](crate::api::networking::v1::Ingress).
+    fn visit_api_networking_v1_ingress_mut(&mut self, node: &mut crate::api::networking::v1::Ingress) {
+        visit_api_networking_v1_ingress_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressBackend`](crate::api::networking::v1::IngressBackend).
+    fn visit_api_networking_v1_ingress_backend_mut(&mut self, node: &mut crate::api::networking::v1::IngressBackend) {
+        visit_api_networking_v1_ingress_backend_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressClass`](crate::api::networking::v1::IngressClass).
+    fn visit_api_networking_v1_ingress_class_mut(&mut self, node: &mut crate::api::networking::v1::IngressClass) {
+        visit_api_networking_v1_ingress_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressClassParametersReference`](crate::api::networking::v1::IngressClassParametersReference).
+    fn visit_api_networking_v1_ingress_class_parameters_reference_mut(&mut self, node: &mut crate::api::networking::v1::IngressClassParametersReference) {
+        visit_api_networking_v1_ingress_class_parameters_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressClassSpec`](crate::api::networking::v1::IngressClassSpec).
+    fn visit_api_networking_v1_ingress_class_spec_mut(&mut self, node: &mut crate::api::networking::v1::IngressClassSpec) {
+        visit_api_networking_v1_ingress_class_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressLoadBalancerIngress`](crate::api::networking::v1::IngressLoadBalancerIngress).
+    fn visit_api_networking_v1_ingress_load_balancer_ingress_mut(&mut self, node: &mut crate::api::networking::v1::IngressLoadBalancerIngress) {
+        visit_api_networking_v1_ingress_load_balancer_ingress_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressLoadBalancerStatus`](crate::api::networking::v1::IngressLoadBalancerStatus).
+    fn visit_api_networking_v1_ingress_load_balancer_status_mut(&mut self, node: &mut crate::api::networking::v1::IngressLoadBalancerStatus) {
+        visit_api_networking_v1_ingress_load_balancer_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressPortStatus`](crate::api::networking::v1::IngressPortStatus).
+    fn visit_api_networking_v1_ingress_port_status_mut(&mut self, node: &mut crate::api::networking::v1::IngressPortStatus) {
+        visit_api_networking_v1_ingress_port_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressRule`](crate::api::networking::v1::IngressRule).
+    fn visit_api_networking_v1_ingress_rule_mut(&mut self, node: &mut crate::api::networking::v1::IngressRule) {
+        visit_api_networking_v1_ingress_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressServiceBackend`](crate::api::networking::v1::IngressServiceBackend).
+    fn visit_api_networking_v1_ingress_service_backend_mut(&mut self, node: &mut crate::api::networking::v1::IngressServiceBackend) {
+        visit_api_networking_v1_ingress_service_backend_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressSpec`](crate::api::networking::v1::IngressSpec).
+    fn visit_api_networking_v1_ingress_spec_mut(&mut self, node: &mut crate::api::networking::v1::IngressSpec) {
+        visit_api_networking_v1_ingress_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressStatus`](crate::api::networking::v1::IngressStatus).
+    fn visit_api_networking_v1_ingress_status_mut(&mut self, node: &mut crate::api::networking::v1::IngressStatus) {
+        visit_api_networking_v1_ingress_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`IngressTLS`](crate::api::networking::v1::IngressTLS).
+    fn visit_api_networking_v1_ingress_tls_mut(&mut self, node: &mut crate::api::networking::v1::IngressTLS) {
+        visit_api_networking_v1_ingress_tls_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicy`](crate::api::networking::v1::NetworkPolicy).
+    fn visit_api_networking_v1_network_policy_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicy) {
+        visit_api_networking_v1_network_policy_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyEgressRule`](crate::api::networking::v1::NetworkPolicyEgressRule).
+    fn visit_api_networking_v1_network_policy_egress_rule_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicyEgressRule) {
+        visit_api_networking_v1_network_policy_egress_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyIngressRule`](crate::api::networking::v1::NetworkPolicyIngressRule).
+    fn visit_api_networking_v1_network_policy_ingress_rule_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicyIngressRule) {
+        visit_api_networking_v1_network_policy_ingress_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyPeer`](crate::api::networking::v1::NetworkPolicyPeer).
+    fn visit_api_networking_v1_network_policy_peer_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicyPeer) {
+        visit_api_networking_v1_network_policy_peer_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicyPort`](crate::api::networking::v1::NetworkPolicyPort).
+    fn visit_api_networking_v1_network_policy_port_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicyPort) {
+        visit_api_networking_v1_network_policy_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkPolicySpec`](crate::api::networking::v1::NetworkPolicySpec).
+    fn visit_api_networking_v1_network_policy_spec_mut(&mut self, node: &mut crate::api::networking::v1::NetworkPolicySpec) {
+        visit_api_networking_v1_network_policy_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceBackendPort`](crate::api::networking::v1::ServiceBackendPort).
+    fn visit_api_networking_v1_service_backend_port_mut(&mut self, node: &mut crate::api::networking::v1::ServiceBackendPort) {
+        visit_api_networking_v1_service_backend_port_mut(self, node);
+    }
+
+    /// Visits a value of type [`IPAddress`](crate::api::networking::v1beta1::IPAddress).
+    fn visit_api_networking_v1beta1_ip_address_mut(&mut self, node: &mut crate::api::networking::v1beta1::IPAddress) {
+        visit_api_networking_v1beta1_ip_address_mut(self, node);
+    }
+
+    /// Visits a value of type [`IPAddressSpec`](crate::api::networking::v1beta1::IPAddressSpec).
+    fn visit_api_networking_v1beta1_ip_address_spec_mut(&mut self, node: &mut crate::api::networking::v1beta1::IPAddressSpec) {
+        visit_api_networking_v1beta1_ip_address_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ParentReference`](crate::api::networking::v1beta1::ParentReference).
+    fn visit_api_networking_v1beta1_parent_reference_mut(&mut self, node: &mut crate::api::networking::v1beta1::ParentReference) {
+        visit_api_networking_v1beta1_parent_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDR`](crate::api::networking::v1beta1::ServiceCIDR).
+    fn visit_api_networking_v1beta1_service_cidr_mut(&mut self, node: &mut crate::api::networking::v1beta1::ServiceCIDR) {
+        visit_api_networking_v1beta1_service_cidr_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDRSpec`](crate::api::networking::v1beta1::ServiceCIDRSpec).
+    fn visit_api_networking_v1beta1_service_cidr_spec_mut(&mut self, node: &mut crate::api::networking::v1beta1::ServiceCIDRSpec) {
+        visit_api_networking_v1beta1_service_cidr_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceCIDRStatus`](crate::api::networking::v1beta1::ServiceCIDRStatus).
+    fn visit_api_networking_v1beta1_service_cidr_status_mut(&mut self, node: &mut crate::api::networking::v1beta1::ServiceCIDRStatus) {
+        visit_api_networking_v1beta1_service_cidr_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`Overhead`](crate::api::node::v1::Overhead).
+    fn visit_api_node_v1_overhead_mut(&mut self, node: &mut crate::api::node::v1::Overhead) {
+        visit_api_node_v1_overhead_mut(self, node);
+    }
+
+    /// Visits a value of type [`RuntimeClass`](crate::api::node::v1::RuntimeClass).
+    fn visit_api_node_v1_runtime_class_mut(&mut self, node: &mut crate::api::node::v1::RuntimeClass) {
+        visit_api_node_v1_runtime_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`Scheduling`](crate::api::node::v1::Scheduling).
+    fn visit_api_node_v1_scheduling_mut(&mut self, node: &mut crate::api::node::v1::Scheduling) {
+        visit_api_node_v1_scheduling_mut(self, node);
+    }
+
+    /// Visits a value of type [`Eviction`](crate::api::policy::v1::Eviction).
+    fn visit_api_policy_v1_eviction_mut(&mut self, node: &mut crate::api::policy::v1::Eviction) {
+        visit_api_policy_v1_eviction_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudget`](crate::api::policy::v1::PodDisruptionBudget).
+    fn visit_api_policy_v1_pod_disruption_budget_mut(&mut self, node: &mut crate::api::policy::v1::PodDisruptionBudget) {
+        visit_api_policy_v1_pod_disruption_budget_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudgetSpec`](crate::api::policy::v1::PodDisruptionBudgetSpec).
+    fn visit_api_policy_v1_pod_disruption_budget_spec_mut(&mut self, node: &mut crate::api::policy::v1::PodDisruptionBudgetSpec) {
+        visit_api_policy_v1_pod_disruption_budget_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PodDisruptionBudgetStatus`](crate::api::policy::v1::PodDisruptionBudgetStatus).
+    fn visit_api_policy_v1_pod_disruption_budget_status_mut(&mut self, node: &mut crate::api::policy::v1::PodDisruptionBudgetStatus) {
+        visit_api_policy_v1_pod_disruption_budget_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`AggregationRule`](crate::api::rbac::v1::AggregationRule).
+    fn visit_api_rbac_v1_aggregation_rule_mut(&mut self, node: &mut crate::api::rbac::v1::AggregationRule) {
+        visit_api_rbac_v1_aggregation_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClusterRole`](crate::api::rbac::v1::ClusterRole).
+    fn visit_api_rbac_v1_cluster_role_mut(&mut self, node: &mut crate::api::rbac::v1::ClusterRole) {
+        visit_api_rbac_v1_cluster_role_mut(self, node);
+    }
+
+    /// Visits a value of type [`ClusterRoleBinding`](crate::api::rbac::v1::ClusterRoleBinding).
+    fn visit_api_rbac_v1_cluster_role_binding_mut(&mut self, node: &mut crate::api::rbac::v1::ClusterRoleBinding) {
+        visit_api_rbac_v1_cluster_role_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`PolicyRule`](crate::api::rbac::v1::PolicyRule).
+    fn visit_api_rbac_v1_policy_rule_mut(&mut self, node: &mut crate::api::rbac::v1::PolicyRule) {
+        visit_api_rbac_v1_policy_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`Role`](crate::api::rbac::v1::Role).
+    fn visit_api_rbac_v1_role_mut(&mut self, node: &mut crate::api::rbac::v1::Role) {
+        visit_api_rbac_v1_role_mut(self, node);
+    }
+
+    /// Visits a value of type [`RoleBinding`](crate::api::rbac::v1::RoleBinding).
+    fn visit_api_rbac_v1_role_binding_mut(&mut self, node: &mut crate::api::rbac::v1::RoleBinding) {
+        visit_api_rbac_v1_role_binding_mut(self, node);
+    }
+
+    /// Visits a value of type [`RoleRef`](crate::api::rbac::v1::RoleRef).
+    fn visit_api_rbac_v1_role_ref_mut(&mut self, node: &mut crate::api::rbac::v1::RoleRef) {
+        visit_api_rbac_v1_role_ref_mut(self, node);
+    }
+
+    /// Visits a value of type [`Subject`](crate::api::rbac::v1::Subject).
+    fn visit_api_rbac_v1_subject_mut(&mut self, node: &mut crate::api::rbac::v1::Subject) {
+        visit_api_rbac_v1_subject_mut(self, node);
+    }
+
+    /// Visits a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1alpha3::AllocatedDeviceStatus).
+    fn visit_api_resource_v1alpha3_allocated_device_status_mut(&mut self, node: &mut crate::api::resource::v1alpha3::AllocatedDeviceStatus) {
+        visit_api_resource_v1alpha3_allocated_device_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`AllocationResult`](crate::api::resource::v1alpha3::AllocationResult).
+    fn visit_api_resource_v1alpha3_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1alpha3::AllocationResult) {
+        visit_api_resource_v1alpha3_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`BasicDevice`](crate::api::resource::v1alpha3::BasicDevice).
+    fn visit_api_resource_v1alpha3_basic_device_mut(&mut self, node: &mut crate::api::resource::v1alpha3::BasicDevice) {
+        visit_api_resource_v1alpha3_basic_device_mut(self, node);
+    }
+
+    /// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1alpha3::CELDeviceSelector).
+    fn visit_api_resource_v1alpha3_cel_device_selector_mut(&mut self, node: &mut crate::api::resource::v1alpha3::CELDeviceSelector) {
+        visit_api_resource_v1alpha3_cel_device_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`Device`](crate::api::resource::v1alpha3::Device).
+    fn visit_api_resource_v1alpha3_device_mut(&mut self, node: &mut crate::api::resource::v1alpha3::Device) {
+        visit_api_resource_v1alpha3_device_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1alpha3::DeviceAllocationConfiguration).
+    fn visit_api_resource_v1alpha3_device_allocation_configuration_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceAllocationConfiguration) {
+        visit_api_resource_v1alpha3_device_allocation_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationResult`](crate::api::resource::v1alpha3::DeviceAllocationResult).
+    fn visit_api_resource_v1alpha3_device_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceAllocationResult) {
+        visit_api_resource_v1alpha3_device_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1alpha3::DeviceAttribute).
+    fn visit_api_resource_v1alpha3_device_attribute_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceAttribute) {
+        visit_api_resource_v1alpha3_device_attribute_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaim`](crate::api::resource::v1alpha3::DeviceClaim).
+    fn visit_api_resource_v1alpha3_device_claim_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceClaim) {
+        visit_api_resource_v1alpha3_device_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1alpha3::DeviceClaimConfiguration).
+    fn visit_api_resource_v1alpha3_device_claim_configuration_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceClaimConfiguration) {
+        visit_api_resource_v1alpha3_device_claim_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClass`](crate::api::resource::v1alpha3::DeviceClass).
+    fn visit_api_resource_v1alpha3_device_class_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceClass) {
+        visit_api_resource_v1alpha3_device_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassConfiguration`](crate::api::resource::v1alpha3::DeviceClassConfiguration).
+    fn visit_api_resource_v1alpha3_device_class_configuration_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceClassConfiguration) {
+        visit_api_resource_v1alpha3_device_class_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassSpec`](crate::api::resource::v1alpha3::DeviceClassSpec).
+    fn visit_api_resource_v1alpha3_device_class_spec_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceClassSpec) {
+        visit_api_resource_v1alpha3_device_class_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1alpha3::DeviceConstraint).
+    fn visit_api_resource_v1alpha3_device_constraint_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceConstraint) {
+        visit_api_resource_v1alpha3_device_constraint_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequest`](crate::api::resource::v1alpha3::DeviceRequest).
+    fn visit_api_resource_v1alpha3_device_request_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceRequest) {
+        visit_api_resource_v1alpha3_device_request_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1alpha3::DeviceRequestAllocationResult).
+    fn visit_api_resource_v1alpha3_device_request_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceRequestAllocationResult) {
+        visit_api_resource_v1alpha3_device_request_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceSelector`](crate::api::resource::v1alpha3::DeviceSelector).
+    fn visit_api_resource_v1alpha3_device_selector_mut(&mut self, node: &mut crate::api::resource::v1alpha3::DeviceSelector) {
+        visit_api_resource_v1alpha3_device_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1alpha3::NetworkDeviceData).
+    fn visit_api_resource_v1alpha3_network_device_data_mut(&mut self, node: &mut crate::api::resource::v1alpha3::NetworkDeviceData) {
+        visit_api_resource_v1alpha3_network_device_data_mut(self, node);
+    }
+
+    /// Visits a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1alpha3::OpaqueDeviceConfiguration).
+    fn visit_api_resource_v1alpha3_opaque_device_configuration_mut(&mut self, node: &mut crate::api::resource::v1alpha3::OpaqueDeviceConfiguration) {
+        visit_api_resource_v1alpha3_opaque_device_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::resource::v1alpha3::ResourceClaim).
+    fn visit_api_resource_v1alpha3_resource_claim_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaim) {
+        visit_api_resource_v1alpha3_resource_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1alpha3::ResourceClaimConsumerReference).
+    fn visit_api_resource_v1alpha3_resource_claim_consumer_reference_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaimConsumerReference) {
+        visit_api_resource_v1alpha3_resource_claim_consumer_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimSpec`](crate::api::resource::v1alpha3::ResourceClaimSpec).
+    fn visit_api_resource_v1alpha3_resource_claim_spec_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaimSpec) {
+        visit_api_resource_v1alpha3_resource_claim_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimStatus`](crate::api::resource::v1alpha3::ResourceClaimStatus).
+    fn visit_api_resource_v1alpha3_resource_claim_status_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaimStatus) {
+        visit_api_resource_v1alpha3_resource_claim_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplate`](crate::api::resource::v1alpha3::ResourceClaimTemplate).
+    fn visit_api_resource_v1alpha3_resource_claim_template_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaimTemplate) {
+        visit_api_resource_v1alpha3_resource_claim_template_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1alpha3::ResourceClaimTemplateSpec).
+    fn visit_api_resource_v1alpha3_resource_claim_template_spec_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceClaimTemplateSpec) {
+        visit_api_resource_v1alpha3_resource_claim_template_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePool`](crate::api::resource::v1alpha3::ResourcePool).
+    fn visit_api_resource_v1alpha3_resource_pool_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourcePool) {
+        visit_api_resource_v1alpha3_resource_pool_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSlice`](crate::api::resource::v1alpha3::ResourceSlice).
+    fn visit_api_resource_v1alpha3_resource_slice_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceSlice) {
+        visit_api_resource_v1alpha3_resource_slice_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSliceSpec`](crate::api::resource::v1alpha3::ResourceSliceSpec).
+    fn visit_api_resource_v1alpha3_resource_slice_spec_mut(&mut self, node: &mut crate::api::resource::v1alpha3::ResourceSliceSpec) {
+        visit_api_resource_v1alpha3_resource_slice_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1beta1::AllocatedDeviceStatus).
+    fn visit_api_resource_v1beta1_allocated_device_status_mut(&mut self, node: &mut crate::api::resource::v1beta1::AllocatedDeviceStatus) {
+        visit_api_resource_v1beta1_allocated_device_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`AllocationResult`](crate::api::resource::v1beta1::AllocationResult).
+    fn visit_api_resource_v1beta1_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1beta1::AllocationResult) {
+        visit_api_resource_v1beta1_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`BasicDevice`](crate::api::resource::v1beta1::BasicDevice).
+    fn visit_api_resource_v1beta1_basic_device_mut(&mut self, node: &mut crate::api::resource::v1beta1::BasicDevice) {
+        visit_api_resource_v1beta1_basic_device_mut(self, node);
+    }
+
+    /// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1beta1::CELDeviceSelector).
+    fn visit_api_resource_v1beta1_cel_device_selector_mut(&mut self, node: &mut crate::api::resource::v1beta1::CELDeviceSelector) {
+        visit_api_resource_v1beta1_cel_device_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`Device`](crate::api::resource::v1beta1::Device).
+    fn visit_api_resource_v1beta1_device_mut(&mut self, node: &mut crate::api::resource::v1beta1::Device) {
+        visit_api_resource_v1beta1_device_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1beta1::DeviceAllocationConfiguration).
+    fn visit_api_resource_v1beta1_device_allocation_configuration_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceAllocationConfiguration) {
+        visit_api_resource_v1beta1_device_allocation_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAllocationResult`](crate::api::resource::v1beta1::DeviceAllocationResult).
+    fn visit_api_resource_v1beta1_device_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceAllocationResult) {
+        visit_api_resource_v1beta1_device_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1beta1::DeviceAttribute).
+    fn visit_api_resource_v1beta1_device_attribute_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceAttribute) {
+        visit_api_resource_v1beta1_device_attribute_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceCapacity`](crate::api::resource::v1beta1::DeviceCapacity).
+    fn visit_api_resource_v1beta1_device_capacity_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceCapacity) {
+        visit_api_resource_v1beta1_device_capacity_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaim`](crate::api::resource::v1beta1::DeviceClaim).
+    fn visit_api_resource_v1beta1_device_claim_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceClaim) {
+        visit_api_resource_v1beta1_device_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1beta1::DeviceClaimConfiguration).
+    fn visit_api_resource_v1beta1_device_claim_configuration_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceClaimConfiguration) {
+        visit_api_resource_v1beta1_device_claim_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClass`](crate::api::resource::v1beta1::DeviceClass).
+    fn visit_api_resource_v1beta1_device_class_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceClass) {
+        visit_api_resource_v1beta1_device_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassConfiguration`](crate::api::resource::v1beta1::DeviceClassConfiguration).
+    fn visit_api_resource_v1beta1_device_class_configuration_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceClassConfiguration) {
+        visit_api_resource_v1beta1_device_class_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceClassSpec`](crate::api::resource::v1beta1::DeviceClassSpec).
+    fn visit_api_resource_v1beta1_device_class_spec_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceClassSpec) {
+        visit_api_resource_v1beta1_device_class_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1beta1::DeviceConstraint).
+    fn visit_api_resource_v1beta1_device_constraint_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceConstraint) {
+        visit_api_resource_v1beta1_device_constraint_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequest`](crate::api::resource::v1beta1::DeviceRequest).
+    fn visit_api_resource_v1beta1_device_request_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceRequest) {
+        visit_api_resource_v1beta1_device_request_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1beta1::DeviceRequestAllocationResult).
+    fn visit_api_resource_v1beta1_device_request_allocation_result_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceRequestAllocationResult) {
+        visit_api_resource_v1beta1_device_request_allocation_result_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeviceSelector`](crate::api::resource::v1beta1::DeviceSelector).
+    fn visit_api_resource_v1beta1_device_selector_mut(&mut self, node: &mut crate::api::resource::v1beta1::DeviceSelector) {
+        visit_api_resource_v1beta1_device_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1beta1::NetworkDeviceData).
+    fn visit_api_resource_v1beta1_network_device_data_mut(&mut self, node: &mut crate::api::resource::v1beta1::NetworkDeviceData) {
+        visit_api_resource_v1beta1_network_device_data_mut(self, node);
+    }
+
+    /// Visits a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1beta1::OpaqueDeviceConfiguration).
+    fn visit_api_resource_v1beta1_opaque_device_configuration_mut(&mut self, node: &mut crate::api::resource::v1beta1::OpaqueDeviceConfiguration) {
+        visit_api_resource_v1beta1_opaque_device_configuration_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaim`](crate::api::resource::v1beta1::ResourceClaim).
+    fn visit_api_resource_v1beta1_resource_claim_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaim) {
+        visit_api_resource_v1beta1_resource_claim_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1beta1::ResourceClaimConsumerReference).
+    fn visit_api_resource_v1beta1_resource_claim_consumer_reference_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaimConsumerReference) {
+        visit_api_resource_v1beta1_resource_claim_consumer_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimSpec`](crate::api::resource::v1beta1::ResourceClaimSpec).
+    fn visit_api_resource_v1beta1_resource_claim_spec_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaimSpec) {
+        visit_api_resource_v1beta1_resource_claim_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimStatus`](crate::api::resource::v1beta1::ResourceClaimStatus).
+    fn visit_api_resource_v1beta1_resource_claim_status_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaimStatus) {
+        visit_api_resource_v1beta1_resource_claim_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplate`](crate::api::resource::v1beta1::ResourceClaimTemplate).
+    fn visit_api_resource_v1beta1_resource_claim_template_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaimTemplate) {
+        visit_api_resource_v1beta1_resource_claim_template_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1beta1::ResourceClaimTemplateSpec).
+    fn visit_api_resource_v1beta1_resource_claim_template_spec_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceClaimTemplateSpec) {
+        visit_api_resource_v1beta1_resource_claim_template_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourcePool`](crate::api::resource::v1beta1::ResourcePool).
+    fn visit_api_resource_v1beta1_resource_pool_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourcePool) {
+        visit_api_resource_v1beta1_resource_pool_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSlice`](crate::api::resource::v1beta1::ResourceSlice).
+    fn visit_api_resource_v1beta1_resource_slice_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceSlice) {
+        visit_api_resource_v1beta1_resource_slice_mut(self, node);
+    }
+
+    /// Visits a value of type [`ResourceSliceSpec`](crate::api::resource::v1beta1::ResourceSliceSpec).
+    fn visit_api_resource_v1beta1_resource_slice_spec_mut(&mut self, node: &mut crate::api::resource::v1beta1::ResourceSliceSpec) {
+        visit_api_resource_v1beta1_resource_slice_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`PriorityClass`](crate::api::scheduling::v1::PriorityClass).
+    fn visit_api_scheduling_v1_priority_class_mut(&mut self, node: &mut crate::api::scheduling::v1::PriorityClass) {
+        visit_api_scheduling_v1_priority_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSIDriver`](crate::api::storage::v1::CSIDriver).
+    fn visit_api_storage_v1_csi_driver_mut(&mut self, node: &mut crate::api::storage::v1::CSIDriver) {
+        visit_api_storage_v1_csi_driver_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSIDriverSpec`](crate::api::storage::v1::CSIDriverSpec).
+    fn visit_api_storage_v1_csi_driver_spec_mut(&mut self, node: &mut crate::api::storage::v1::CSIDriverSpec) {
+        visit_api_storage_v1_csi_driver_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSINode`](crate::api::storage::v1::CSINode).
+    fn visit_api_storage_v1_csi_node_mut(&mut self, node: &mut crate::api::storage::v1::CSINode) {
+        visit_api_storage_v1_csi_node_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSINodeDriver`](crate::api::storage::v1::CSINodeDriver).
+    fn visit_api_storage_v1_csi_node_driver_mut(&mut self, node: &mut crate::api::storage::v1::CSINodeDriver) {
+        visit_api_storage_v1_csi_node_driver_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSINodeSpec`](crate::api::storage::v1::CSINodeSpec).
+    fn visit_api_storage_v1_csi_node_spec_mut(&mut self, node: &mut crate::api::storage::v1::CSINodeSpec) {
+        visit_api_storage_v1_csi_node_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`CSIStorageCapacity`](crate::api::storage::v1::CSIStorageCapacity).
+    fn visit_api_storage_v1_csi_storage_capacity_mut(&mut self, node: &mut crate::api::storage::v1::CSIStorageCapacity) {
+        visit_api_storage_v1_csi_storage_capacity_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageClass`](crate::api::storage::v1::StorageClass).
+    fn visit_api_storage_v1_storage_class_mut(&mut self, node: &mut crate::api::storage::v1::StorageClass) {
+        visit_api_storage_v1_storage_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`TokenRequest`](crate::api::storage::v1::TokenRequest).
+    fn visit_api_storage_v1_token_request_mut(&mut self, node: &mut crate::api::storage::v1::TokenRequest) {
+        visit_api_storage_v1_token_request_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachment`](crate::api::storage::v1::VolumeAttachment).
+    fn visit_api_storage_v1_volume_attachment_mut(&mut self, node: &mut crate::api::storage::v1::VolumeAttachment) {
+        visit_api_storage_v1_volume_attachment_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentSource`](crate::api::storage::v1::VolumeAttachmentSource).
+    fn visit_api_storage_v1_volume_attachment_source_mut(&mut self, node: &mut crate::api::storage::v1::VolumeAttachmentSource) {
+        visit_api_storage_v1_volume_attachment_source_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentSpec`](crate::api::storage::v1::VolumeAttachmentSpec).
+    fn visit_api_storage_v1_volume_attachment_spec_mut(&mut self, node: &mut crate::api::storage::v1::VolumeAttachmentSpec) {
+        visit_api_storage_v1_volume_attachment_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttachmentStatus`](crate::api::storage::v1::VolumeAttachmentStatus).
+    fn visit_api_storage_v1_volume_attachment_status_mut(&mut self, node: &mut crate::api::storage::v1::VolumeAttachmentStatus) {
+        visit_api_storage_v1_volume_attachment_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeError`](crate::api::storage::v1::VolumeError).
+    fn visit_api_storage_v1_volume_error_mut(&mut self, node: &mut crate::api::storage::v1::VolumeError) {
+        visit_api_storage_v1_volume_error_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeNodeResources`](crate::api::storage::v1::VolumeNodeResources).
+    fn visit_api_storage_v1_volume_node_resources_mut(&mut self, node: &mut crate::api::storage::v1::VolumeNodeResources) {
+        visit_api_storage_v1_volume_node_resources_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttributesClass`](crate::api::storage::v1alpha1::VolumeAttributesClass).
+    fn visit_api_storage_v1alpha1_volume_attributes_class_mut(&mut self, node: &mut crate::api::storage::v1alpha1::VolumeAttributesClass) {
+        visit_api_storage_v1alpha1_volume_attributes_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`VolumeAttributesClass`](crate::api::storage::v1beta1::VolumeAttributesClass).
+    fn visit_api_storage_v1beta1_volume_attributes_class_mut(&mut self, node: &mut crate::api::storage::v1beta1::VolumeAttributesClass) {
+        visit_api_storage_v1beta1_volume_attributes_class_mut(self, node);
+    }
+
+    /// Visits a value of type [`GroupVersionResource`](crate::api::storagemigration::v1alpha1::GroupVersionResource).
+    fn visit_api_storagemigration_v1alpha1_group_version_resource_mut(&mut self, node: &mut crate::api::storagemigration::v1alpha1::GroupVersionResource) {
+        visit_api_storagemigration_v1alpha1_group_version_resource_mut(self, node);
+    }
+
+    /// Visits a value of type [`MigrationCondition`](crate::api::storagemigration::v1alpha1::MigrationCondition).
+    fn visit_api_storagemigration_v1alpha1_migration_condition_mut(&mut self, node: &mut crate::api::storagemigration::v1alpha1::MigrationCondition) {
+        visit_api_storagemigration_v1alpha1_migration_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigration`](crate::api::storagemigration::v1alpha1::StorageVersionMigration).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration_mut(&mut self, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigration) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigrationSpec`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration_spec_mut(&mut self, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`StorageVersionMigrationStatus`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus).
+    fn visit_api_storagemigration_v1alpha1_storage_version_migration_status_mut(&mut self, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus) {
+        visit_api_storagemigration_v1alpha1_storage_version_migration_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceColumnDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionCondition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionNames`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionSpec`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceDefinitionVersion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresourceScale`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresourceStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceSubresources`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources_mut(self, node);
+    }
+
+    /// Visits a value of type [`CustomResourceValidation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation_mut(self, node);
+    }
+
+    /// Visits a value of type [`ExternalDocumentation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSON`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaProps`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrBool`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut(self, node);
+    }
+
+    /// Visits a value of type [`JSONSchemaPropsOrStringArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array_mut(self, node);
+    }
+
+    /// Visits a value of type [`SelectableField`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`ValidationRule`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule_mut(self, node);
+    }
+
+    /// Visits a value of type [`WebhookClientConfig`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config_mut(self, node);
+    }
+
+    /// Visits a value of type [`WebhookConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion).
+    fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion_mut(&mut self, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion) {
+        visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion_mut(self, node);
+    }
+
+    /// Visits a value of type [`Quantity`](crate::apimachinery::pkg::api::resource::Quantity).
+    fn visit_apimachinery_pkg_api_resource_quantity_mut(&mut self, node: &mut crate::apimachinery::pkg::api::resource::Quantity) {
+        visit_apimachinery_pkg_api_resource_quantity_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIGroup`](crate::apimachinery::pkg::apis::meta::v1::APIGroup).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_group_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIGroup) {
+        visit_apimachinery_pkg_apis_meta_v1_api_group_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIGroupList`](crate::apimachinery::pkg::apis::meta::v1::APIGroupList).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_group_list_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIGroupList) {
+        visit_apimachinery_pkg_apis_meta_v1_api_group_list_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIResource`](crate::apimachinery::pkg::apis::meta::v1::APIResource).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_resource_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIResource) {
+        visit_apimachinery_pkg_apis_meta_v1_api_resource_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_resource_list_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIResourceList) {
+        visit_apimachinery_pkg_apis_meta_v1_api_resource_list_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIVersions`](crate::apimachinery::pkg::apis::meta::v1::APIVersions).
+    fn visit_apimachinery_pkg_apis_meta_v1_api_versions_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIVersions) {
+        visit_apimachinery_pkg_apis_meta_v1_api_versions_mut(self, node);
+    }
+
+    /// Visits a value of type [`Condition`](crate::apimachinery::pkg::apis::meta::v1::Condition).
+    fn visit_apimachinery_pkg_apis_meta_v1_condition_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::Condition) {
+        visit_apimachinery_pkg_apis_meta_v1_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`DeleteOptions`](crate::apimachinery::pkg::apis::meta::v1::DeleteOptions).
+    fn visit_apimachinery_pkg_apis_meta_v1_delete_options_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::DeleteOptions) {
+        visit_apimachinery_pkg_apis_meta_v1_delete_options_mut(self, node);
+    }
+
+    /// Visits a value of type [`FieldSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement).
+    fn visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement) {
+        visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`FieldsV1`](crate::apimachinery::pkg::apis::meta::v1::FieldsV1).
+    fn visit_apimachinery_pkg_apis_meta_v1_fields_v1_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::FieldsV1) {
+        visit_apimachinery_pkg_apis_meta_v1_fields_v1_mut(self, node);
+    }
+
+    /// Visits a value of type [`GroupVersionForDiscovery`](crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery).
+    fn visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery) {
+        visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelector`](crate::apimachinery::pkg::apis::meta::v1::LabelSelector).
+    fn visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::LabelSelector) {
+        visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(self, node);
+    }
+
+    /// Visits a value of type [`LabelSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement).
+    fn visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement) {
+        visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut(self, node);
+    }
+
+    /// Visits a value of type [`ListMeta`](crate::apimachinery::pkg::apis::meta::v1::ListMeta).
+    fn visit_apimachinery_pkg_apis_meta_v1_list_meta_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::ListMeta) {
+        visit_apimachinery_pkg_apis_meta_v1_list_meta_mut(self, node);
+    }
+
+    /// Visits a value of type [`ManagedFieldsEntry`](crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry).
+    fn visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry) {
+        visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry_mut(self, node);
+    }
+
+    /// Visits a value of type [`MicroTime`](crate::apimachinery::pkg::apis::meta::v1::MicroTime).
+    fn visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::MicroTime) {
+        visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(self, node);
+    }
+
+    /// Visits a value of type [`ObjectMeta`](crate::apimachinery::pkg::apis::meta::v1::ObjectMeta).
+    fn visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) {
+        visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(self, node);
+    }
+
+    /// Visits a value of type [`OwnerReference`](crate::apimachinery::pkg::apis::meta::v1::OwnerReference).
+    fn visit_apimachinery_pkg_apis_meta_v1_owner_reference_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::OwnerReference) {
+        visit_apimachinery_pkg_apis_meta_v1_owner_reference_mut(self, node);
+    }
+
+    /// Visits a value of type [`Preconditions`](crate::apimachinery::pkg::apis::meta::v1::Preconditions).
+    fn visit_apimachinery_pkg_apis_meta_v1_preconditions_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::Preconditions) {
+        visit_apimachinery_pkg_apis_meta_v1_preconditions_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServerAddressByClientCIDR`](crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR).
+    fn visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR) {
+        visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut(self, node);
+    }
+
+    /// Visits a value of type [`Status`](crate::apimachinery::pkg::apis::meta::v1::Status).
+    fn visit_apimachinery_pkg_apis_meta_v1_status_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::Status) {
+        visit_apimachinery_pkg_apis_meta_v1_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatusCause`](crate::apimachinery::pkg::apis::meta::v1::StatusCause).
+    fn visit_apimachinery_pkg_apis_meta_v1_status_cause_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::StatusCause) {
+        visit_apimachinery_pkg_apis_meta_v1_status_cause_mut(self, node);
+    }
+
+    /// Visits a value of type [`StatusDetails`](crate::apimachinery::pkg::apis::meta::v1::StatusDetails).
+    fn visit_apimachinery_pkg_apis_meta_v1_status_details_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::StatusDetails) {
+        visit_apimachinery_pkg_apis_meta_v1_status_details_mut(self, node);
+    }
+
+    /// Visits a value of type [`Time`](crate::apimachinery::pkg::apis::meta::v1::Time).
+    fn visit_apimachinery_pkg_apis_meta_v1_time_mut(&mut self, node: &mut crate::apimachinery::pkg::apis::meta::v1::Time) {
+        visit_apimachinery_pkg_apis_meta_v1_time_mut(self, node);
+    }
+
+    /// Visits a value of type [`RawExtension`](crate::apimachinery::pkg::runtime::RawExtension).
+    fn visit_apimachinery_pkg_runtime_raw_extension_mut(&mut self, node: &mut crate::apimachinery::pkg::runtime::RawExtension) {
+        visit_apimachinery_pkg_runtime_raw_extension_mut(self, node);
+    }
+
+    /// Visits a value of type [`IntOrString`](crate::apimachinery::pkg::util::intstr::IntOrString).
+    fn visit_apimachinery_pkg_util_intstr_int_or_string_mut(&mut self, node: &mut crate::apimachinery::pkg::util::intstr::IntOrString) {
+        visit_apimachinery_pkg_util_intstr_int_or_string_mut(self, node);
+    }
+
+    /// Visits a value of type [`Info`](crate::apimachinery::pkg::version::Info).
+    fn visit_apimachinery_pkg_version_info_mut(&mut self, node: &mut crate::apimachinery::pkg::version::Info) {
+        visit_apimachinery_pkg_version_info_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIService`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_mut(&mut self, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceCondition`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition_mut(&mut self, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceSpec`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec_mut(&mut self, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec_mut(self, node);
+    }
+
+    /// Visits a value of type [`APIServiceStatus`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status_mut(&mut self, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status_mut(self, node);
+    }
+
+    /// Visits a value of type [`ServiceReference`](crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference).
+    fn visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference_mut(&mut self, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference) {
+        visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference_mut(self, node);
+    }
+}
+
+/// A value that can be walked by a [`VisitMut`] visitor.
+pub trait VisitableMut {
+    /// Calls the method of the visitor for the type of this value.
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized;
+}
+
+/// Visits the values contained in a value of type [`AnyResource`](crate::AnyResource).
+pub fn visit_any_resource_mut<V>(v: &mut V, node: &mut crate::AnyResource) where V: VisitMut + ?Sized {
+    match node {
+        crate::AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(it) => {
+            v.visit_api_admissionregistration_v1_mutating_webhook_configuration_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1_validating_admission_policy_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1_validating_admission_policy_binding_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(it) => {
+            v.visit_api_admissionregistration_v1_validating_webhook_configuration_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(it) => {
+            v.visit_api_admissionregistration_v1beta1_validating_admission_policy_mut(it);
+        },
+        crate::AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(it) => {
+            v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_mut(it);
+        },
+        crate::AnyResource::ApiserverinternalV1alpha1StorageVersion(it) => {
+            v.visit_api_apiserverinternal_v1alpha1_storage_version_mut(it);
+        },
+        crate::AnyResource::AppsV1ControllerRevision(it) => {
+            v.visit_api_apps_v1_controller_revision_mut(it);
+        },
+        crate::AnyResource::AppsV1DaemonSet(it) => {
+            v.visit_api_apps_v1_daemon_set_mut(it);
+        },
+        crate::AnyResource::AppsV1Deployment(it) => {
+            v.visit_api_apps_v1_deployment_mut(it);
+        },
+        crate::AnyResource::AppsV1ReplicaSet(it) => {
+            v.visit_api_apps_v1_replica_set_mut(it);
+        },
+        crate::AnyResource::AppsV1StatefulSet(it) => {
+            v.visit_api_apps_v1_stateful_set_mut(it);
+        },
+        crate::AnyResource::AuthenticationV1SelfSubjectReview(it) => {
+            v.visit_api_authentication_v1_self_subject_review_mut(it);
+        },
+        crate::AnyResource::AuthenticationV1TokenRequest(it) => {
+            v.visit_api_authentication_v1_token_request_mut(it);
+        },
+        crate::AnyResource::AuthenticationV1TokenReview(it) => {
+            v.visit_api_authentication_v1_token_review_mut(it);
+        },
+        crate::AnyResource::AuthenticationV1beta1SelfSubjectReview(it) => {
+            v.visit_api_authentication_v1beta1_self_subject_review_mut(it);
+        },
+        crate::AnyResource::AuthorizationV1LocalSubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_local_subject_access_review_mut(it);
+        },
+        crate::AnyResource::AuthorizationV1SelfSubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_self_subject_access_review_mut(it);
+        },
+        crate::AnyResource::AuthorizationV1SelfSubjectRulesReview(it) => {
+            v.visit_api_authorization_v1_self_subject_rules_review_mut(it);
+        },
+        crate::AnyResource::AuthorizationV1SubjectAccessReview(it) => {
+            v.visit_api_authorization_v1_subject_access_review_mut(it);
+        },
+        crate::AnyResource::AutoscalingV1HorizontalPodAutoscaler(it) => {
+            v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_mut(it);
+        },
+        crate::AnyResource::AutoscalingV1Scale(it) => {
+            v.visit_api_autoscaling_v1_scale_mut(it);
+        },
+        crate::AnyResource::AutoscalingV2HorizontalPodAutoscaler(it) => {
+            v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_mut(it);
+        },
+        crate::AnyResource::BatchV1CronJob(it) => {
+            v.visit_api_batch_v1_cron_job_mut(it);
+        },
+        crate::AnyResource::BatchV1Job(it) => {
+            v.visit_api_batch_v1_job_mut(it);
+        },
+        crate::AnyResource::CertificatesV1CertificateSigningRequest(it) => {
+            v.visit_api_certificates_v1_certificate_signing_request_mut(it);
+        },
+        crate::AnyResource::CertificatesV1alpha1ClusterTrustBundle(it) => {
+            v.visit_api_certificates_v1alpha1_cluster_trust_bundle_mut(it);
+        },
+        crate::AnyResource::CoordinationV1Lease(it) => {
+            v.visit_api_coordination_v1_lease_mut(it);
+        },
+        crate::AnyResource::CoordinationV1alpha2LeaseCandidate(it) => {
+            v.visit_api_coordination_v1alpha2_lease_candidate_mut(it);
+        },
+        crate::AnyResource::CoreV1Binding(it) => {
+            v.visit_api_core_v1_binding_mut(it);
+        },
+        crate::AnyResource::CoreV1ComponentStatus(it) => {
+            v.visit_api_core_v1_component_status_mut(it);
+        },
+        crate::AnyResource::CoreV1ConfigMap(it) => {
+            v.visit_api_core_v1_config_map_mut(it);
+        },
+        crate::AnyResource::CoreV1Endpoints(it) => {
+            v.visit_api_core_v1_endpoints_mut(it);
+        },
+        crate::AnyResource::CoreV1Event(it) => {
+            v.visit_api_core_v1_event_mut(it);
+        },
+        crate::AnyResource::CoreV1LimitRange(it) => {
+            v.visit_api_core_v1_limit_range_mut(it);
+        },
+        crate::AnyResource::CoreV1Namespace(it) => {
+            v.visit_api_core_v1_namespace_mut(it);
+        },
+        crate::AnyResource::CoreV1Node(it) => {
+            v.visit_api_core_v1_node_mut(it);
+        },
+        crate::AnyResource::CoreV1PersistentVolume(it) => {
+            v.visit_api_core_v1_persistent_volume_mut(it);
+        },
+        crate::AnyResource::CoreV1PersistentVolumeClaim(it) => {
+            v.visit_api_core_v1_persistent_volume_claim_mut(it);
+        },
+        crate::AnyResource::CoreV1Pod(it) => {
+            v.visit_api_core_v1_pod_mut(it);
+        },
+        crate::AnyResource::CoreV1PodTemplate(it) => {
+            v.visit_api_core_v1_pod_template_mut(it);
+        },
+        crate::AnyResource::CoreV1ReplicationController(it) => {
+            v.visit_api_core_v1_replication_controller_mut(it);
+        },
+        crate::AnyResource::CoreV1ResourceQuota(it) => {
+            v.visit_api_core_v1_resource_quota_mut(it);
+        },
+        crate::AnyResource::CoreV1Secret(it) => {
+            v.visit_api_core_v1_secret_mut(it);
+        },
+        crate::AnyResource::CoreV1Service(it) => {
+            v.visit_api_core_v1_service_mut(it);
+        },
+        crate::AnyResource::CoreV1ServiceAccount(it) => {
+            v.visit_api_core_v1_service_account_mut(it);
+        },
+        crate::AnyResource::DiscoveryV1EndpointSlice(it) => {
+            v.visit_api_discovery_v1_endpoint_slice_mut(it);
+        },
+        crate::AnyResource::EventsV1Event(it) => {
+            v.visit_api_events_v1_event_mut(it);
+        },
+        crate::AnyResource::FlowcontrolV1FlowSchema(it) => {
+            v.visit_api_flowcontrol_v1_flow_schema_mut(it);
+        },
+        crate::AnyResource::FlowcontrolV1PriorityLevelConfiguration(it) => {
+            v.visit_api_flowcontrol_v1_priority_level_configuration_mut(it);
+        },
+        crate::AnyResource::NetworkingV1Ingress(it) => {
+            v.visit_api_networking_v1_ingress_mut(it);
+        },
+        crate::AnyResource::NetworkingV1IngressClass(it) => {
+            v.visit_api_networking_v1_ingress_class_mut(it);
+        },
+        crate::AnyResource::NetworkingV1NetworkPolicy(it) => {
+            v.visit_api_networking_v1_network_policy_mut(it);
+        },
+        crate::AnyResource::NetworkingV1beta1IPAddress(it) => {
+            v.visit_api_networking_v1beta1_ip_address_mut(it);
+        },
+        crate::AnyResource::NetworkingV1beta1ServiceCIDR(it) => {
+            v.visit_api_networking_v1beta1_service_cidr_mut(it);
+        },
+        crate::AnyResource::NodeV1RuntimeClass(it) => {
+            v.visit_api_node_v1_runtime_class_mut(it);
+        },
+        crate::AnyResource::PolicyV1Eviction(it) => {
+            v.visit_api_policy_v1_eviction_mut(it);
+        },
+        crate::AnyResource::PolicyV1PodDisruptionBudget(it) => {
+            v.visit_api_policy_v1_pod_disruption_budget_mut(it);
+        },
+        crate::AnyResource::RbacV1ClusterRole(it) => {
+            v.visit_api_rbac_v1_cluster_role_mut(it);
+        },
+        crate::AnyResource::RbacV1ClusterRoleBinding(it) => {
+            v.visit_api_rbac_v1_cluster_role_binding_mut(it);
+        },
+        crate::AnyResource::RbacV1Role(it) => {
+            v.visit_api_rbac_v1_role_mut(it);
+        },
+        crate::AnyResource::RbacV1RoleBinding(it) => {
+            v.visit_api_rbac_v1_role_binding_mut(it);
+        },
+        crate::AnyResource::ResourceV1alpha3DeviceClass(it) => {
+            v.visit_api_resource_v1alpha3_device_class_mut(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceClaim(it) => {
+            v.visit_api_resource_v1alpha3_resource_claim_mut(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceClaimTemplate(it) => {
+            v.visit_api_resource_v1alpha3_resource_claim_template_mut(it);
+        },
+        crate::AnyResource::ResourceV1alpha3ResourceSlice(it) => {
+            v.visit_api_resource_v1alpha3_resource_slice_mut(it);
+        },
+        crate::AnyResource::ResourceV1beta1DeviceClass(it) => {
+            v.visit_api_resource_v1beta1_device_class_mut(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceClaim(it) => {
+            v.visit_api_resource_v1beta1_resource_claim_mut(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceClaimTemplate(it) => {
+            v.visit_api_resource_v1beta1_resource_claim_template_mut(it);
+        },
+        crate::AnyResource::ResourceV1beta1ResourceSlice(it) => {
+            v.visit_api_resource_v1beta1_resource_slice_mut(it);
+        },
+        crate::AnyResource::SchedulingV1PriorityClass(it) => {
+            v.visit_api_scheduling_v1_priority_class_mut(it);
+        },
+        crate::AnyResource::StorageV1CSIDriver(it) => {
+            v.visit_api_storage_v1_csi_driver_mut(it);
+        },
+        crate::AnyResource::StorageV1CSINode(it) => {
+            v.visit_api_storage_v1_csi_node_mut(it);
+        },
+        crate::AnyResource::StorageV1CSIStorageCapacity(it) => {
+            v.visit_api_storage_v1_csi_storage_capacity_mut(it);
+        },
+        crate::AnyResource::StorageV1StorageClass(it) => {
+            v.visit_api_storage_v1_storage_class_mut(it);
+        },
+        crate::AnyResource::StorageV1VolumeAttachment(it) => {
+            v.visit_api_storage_v1_volume_attachment_mut(it);
+        },
+        crate::AnyResource::StorageV1alpha1VolumeAttributesClass(it) => {
+            v.visit_api_storage_v1alpha1_volume_attributes_class_mut(it);
+        },
+        crate::AnyResource::StorageV1beta1VolumeAttributesClass(it) => {
+            v.visit_api_storage_v1beta1_volume_attributes_class_mut(it);
+        },
+        crate::AnyResource::StoragemigrationV1alpha1StorageVersionMigration(it) => {
+            v.visit_api_storagemigration_v1alpha1_storage_version_migration_mut(it);
+        },
+        crate::AnyResource::ApiextensionsV1CustomResourceDefinition(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_mut(it);
+        },
+        crate::AnyResource::ApiregistrationV1APIService(it) => {
+            v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_mut(it);
+        },
+        crate::AnyResource::Other(_) => (),
+    }
+}
+
+/// Visits the values contained in a value of type [`List`](crate::List).
+pub fn visit_list_mut<V, T>(v: &mut V, node: &mut crate::List<T>) where V: VisitMut + ?Sized, T: crate::ListableResource + VisitableMut {
+    for it in &mut node.items {
+        VisitableMut::visit_mut(it, v);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_list_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1::AuditAnnotation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_audit_annotation_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::AuditAnnotation) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1::ExpressionWarning), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_expression_warning_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::ExpressionWarning) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_match_condition_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::MatchCondition) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1::MatchResources).
+pub fn visit_api_admissionregistration_v1_match_resources_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::MatchResources) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_named_rule_with_operations_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_named_rule_with_operations_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingWebhook`](crate::api::admissionregistration::v1::MutatingWebhook).
+pub fn visit_api_admissionregistration_v1_mutating_webhook_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::MutatingWebhook) where V: VisitMut + ?Sized {
+    v.visit_api_admissionregistration_v1_webhook_client_config_mut(&mut node.client_config);
+    if let Some(it) = &mut node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_rule_with_operations_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingWebhookConfiguration`](crate::api::admissionregistration::v1::MutatingWebhookConfiguration).
+pub fn visit_api_admissionregistration_v1_mutating_webhook_configuration_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::MutatingWebhookConfiguration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.webhooks {
+        for it in it {
+            v.visit_api_admissionregistration_v1_mutating_webhook_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_named_rule_with_operations_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::NamedRuleWithOperations) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_param_kind_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::ParamKind) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1::ParamRef).
+pub fn visit_api_admissionregistration_v1_param_ref_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ParamRef) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`RuleWithOperations`](crate::api::admissionregistration::v1::RuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_rule_with_operations_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::RuleWithOperations) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ServiceReference`](crate::api::admissionregistration::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_service_reference_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::ServiceReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TypeChecking`](crate::api::admissionregistration::v1::TypeChecking).
+pub fn visit_api_admissionregistration_v1_type_checking_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::TypeChecking) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.expression_warnings {
+        for it in it {
+            v.visit_api_admissionregistration_v1_expression_warning_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicy) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_binding_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_binding_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_resources {
+        v.visit_api_admissionregistration_v1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.param_ref {
+        v.visit_api_admissionregistration_v1_param_ref_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.audit_annotations {
+        for it in it {
+            v.visit_api_admissionregistration_v1_audit_annotation_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_constraints {
+        v.visit_api_admissionregistration_v1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.param_kind {
+        v.visit_api_admissionregistration_v1_param_kind_mut(it);
+    }
+    if let Some(it) = &mut node.validations {
+        for it in it {
+            v.visit_api_admissionregistration_v1_validation_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1_variable_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus).
+pub fn visit_api_admissionregistration_v1_validating_admission_policy_status_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.type_checking {
+        v.visit_api_admissionregistration_v1_type_checking_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingWebhook`](crate::api::admissionregistration::v1::ValidatingWebhook).
+pub fn visit_api_admissionregistration_v1_validating_webhook_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingWebhook) where V: VisitMut + ?Sized {
+    v.visit_api_admissionregistration_v1_webhook_client_config_mut(&mut node.client_config);
+    if let Some(it) = &mut node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1_match_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1_rule_with_operations_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingWebhookConfiguration`](crate::api::admissionregistration::v1::ValidatingWebhookConfiguration).
+pub fn visit_api_admissionregistration_v1_validating_webhook_configuration_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::ValidatingWebhookConfiguration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.webhooks {
+        for it in it {
+            v.visit_api_admissionregistration_v1_validating_webhook_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`Validation`](crate::api::admissionregistration::v1::Validation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_validation_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::Validation) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1_variable_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1::Variable) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WebhookClientConfig`](crate::api::admissionregistration::v1::WebhookClientConfig).
+pub fn visit_api_admissionregistration_v1_webhook_client_config_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1::WebhookClientConfig) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.service {
+        v.visit_api_admissionregistration_v1_service_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`ApplyConfiguration`](crate::api::admissionregistration::v1alpha1::ApplyConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_apply_configuration_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::ApplyConfiguration) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`JSONPatch`](crate::api::admissionregistration::v1alpha1::JSONPatch), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_json_patch_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::JSONPatch) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1alpha1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_match_condition_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::MatchCondition) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1alpha1::MatchResources).
+pub fn visit_api_admissionregistration_v1alpha1_match_resources_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::MatchResources) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicy`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_resources {
+        v.visit_api_admissionregistration_v1alpha1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.param_ref {
+        v.visit_api_admissionregistration_v1alpha1_param_ref_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MutatingAdmissionPolicySpec`](crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_match_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_constraints {
+        v.visit_api_admissionregistration_v1alpha1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.mutations {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_mutation_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.param_kind {
+        v.visit_api_admissionregistration_v1alpha1_param_kind_mut(it);
+    }
+    if let Some(it) = &mut node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1alpha1_variable_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Mutation`](crate::api::admissionregistration::v1alpha1::Mutation).
+pub fn visit_api_admissionregistration_v1alpha1_mutation_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::Mutation) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.apply_configuration {
+        v.visit_api_admissionregistration_v1alpha1_apply_configuration_mut(it);
+    }
+    if let Some(it) = &mut node.json_patch {
+        v.visit_api_admissionregistration_v1alpha1_json_patch_mut(it);
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1alpha1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_param_kind_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::ParamKind) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1alpha1::ParamRef).
+pub fn visit_api_admissionregistration_v1alpha1_param_ref_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1alpha1::ParamRef) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1alpha1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1alpha1_variable_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1alpha1::Variable) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`AuditAnnotation`](crate::api::admissionregistration::v1beta1::AuditAnnotation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_audit_annotation_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::AuditAnnotation) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ExpressionWarning`](crate::api::admissionregistration::v1beta1::ExpressionWarning), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_expression_warning_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::ExpressionWarning) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`MatchCondition`](crate::api::admissionregistration::v1beta1::MatchCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_match_condition_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::MatchCondition) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MatchResources`](crate::api::admissionregistration::v1beta1::MatchResources).
+pub fn visit_api_admissionregistration_v1beta1_match_resources_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::MatchResources) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exclude_resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.object_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.resource_rules {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NamedRuleWithOperations`](crate::api::admissionregistration::v1beta1::NamedRuleWithOperations), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::NamedRuleWithOperations) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ParamKind`](crate::api::admissionregistration::v1beta1::ParamKind), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_param_kind_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::ParamKind) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ParamRef`](crate::api::admissionregistration::v1beta1::ParamRef).
+pub fn visit_api_admissionregistration_v1beta1_param_ref_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ParamRef) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TypeChecking`](crate::api::admissionregistration::v1beta1::TypeChecking).
+pub fn visit_api_admissionregistration_v1beta1_type_checking_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::TypeChecking) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.expression_warnings {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_expression_warning_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicy`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBinding`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyBindingSpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_resources {
+        v.visit_api_admissionregistration_v1beta1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.param_ref {
+        v.visit_api_admissionregistration_v1beta1_param_ref_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicySpec`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_spec_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.audit_annotations {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_audit_annotation_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_conditions {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_match_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_constraints {
+        v.visit_api_admissionregistration_v1beta1_match_resources_mut(it);
+    }
+    if let Some(it) = &mut node.param_kind {
+        v.visit_api_admissionregistration_v1beta1_param_kind_mut(it);
+    }
+    if let Some(it) = &mut node.validations {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_validation_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.variables {
+        for it in it {
+            v.visit_api_admissionregistration_v1beta1_variable_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ValidatingAdmissionPolicyStatus`](crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus).
+pub fn visit_api_admissionregistration_v1beta1_validating_admission_policy_status_mut<V>(v: &mut V, node: &mut crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.type_checking {
+        v.visit_api_admissionregistration_v1beta1_type_checking_mut(it);
+    }
+}
+
+/// Visits a value of type [`Validation`](crate::api::admissionregistration::v1beta1::Validation), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_validation_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::Validation) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`Variable`](crate::api::admissionregistration::v1beta1::Variable), which does not contain values of other types that can be visited.
+pub fn visit_api_admissionregistration_v1beta1_variable_mut<V>(_v: &mut V, _node: &mut crate::api::admissionregistration::v1beta1::Variable) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ServerStorageVersion`](crate::api::apiserverinternal::v1alpha1::ServerStorageVersion), which does not contain values of other types that can be visited.
+pub fn visit_api_apiserverinternal_v1alpha1_server_storage_version_mut<V>(_v: &mut V, _node: &mut crate::api::apiserverinternal::v1alpha1::ServerStorageVersion) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageVersion`](crate::api::apiserverinternal::v1alpha1::StorageVersion).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_mut<V>(v: &mut V, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersion) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_apiserverinternal_v1alpha1_storage_version_spec_mut(&mut node.spec);
+    v.visit_api_apiserverinternal_v1alpha1_storage_version_status_mut(&mut node.status);
+}
+
+/// Visits the values contained in a value of type [`StorageVersionCondition`](crate::api::apiserverinternal::v1alpha1::StorageVersionCondition).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_condition_mut<V>(v: &mut V, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`StorageVersionSpec`](crate::api::apiserverinternal::v1alpha1::StorageVersionSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_spec_mut<V>(_v: &mut V, _node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageVersionStatus`](crate::api::apiserverinternal::v1alpha1::StorageVersionStatus).
+pub fn visit_api_apiserverinternal_v1alpha1_storage_version_status_mut<V>(v: &mut V, node: &mut crate::api::apiserverinternal::v1alpha1::StorageVersionStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_apiserverinternal_v1alpha1_storage_version_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.storage_versions {
+        for it in it {
+            v.visit_api_apiserverinternal_v1alpha1_server_storage_version_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ControllerRevision`](crate::api::apps::v1::ControllerRevision).
+pub fn visit_api_apps_v1_controller_revision_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::ControllerRevision) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits the values contained in a value of type [`DaemonSet`](crate::api::apps::v1::DaemonSet).
+pub fn visit_api_apps_v1_daemon_set_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DaemonSet) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_apps_v1_daemon_set_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_apps_v1_daemon_set_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetCondition`](crate::api::apps::v1::DaemonSetCondition).
+pub fn visit_api_apps_v1_daemon_set_condition_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DaemonSetCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetSpec`](crate::api::apps::v1::DaemonSetSpec).
+pub fn visit_api_apps_v1_daemon_set_spec_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DaemonSetSpec) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(&mut node.selector);
+    v.visit_api_core_v1_pod_template_spec_mut(&mut node.template);
+    if let Some(it) = &mut node.update_strategy {
+        v.visit_api_apps_v1_daemon_set_update_strategy_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetStatus`](crate::api::apps::v1::DaemonSetStatus).
+pub fn visit_api_apps_v1_daemon_set_status_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DaemonSetStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_daemon_set_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DaemonSetUpdateStrategy`](crate::api::apps::v1::DaemonSetUpdateStrategy).
+pub fn visit_api_apps_v1_daemon_set_update_strategy_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DaemonSetUpdateStrategy) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_daemon_set_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Deployment`](crate::api::apps::v1::Deployment).
+pub fn visit_api_apps_v1_deployment_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::Deployment) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_apps_v1_deployment_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_apps_v1_deployment_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentCondition`](crate::api::apps::v1::DeploymentCondition).
+pub fn visit_api_apps_v1_deployment_condition_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DeploymentCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentSpec`](crate::api::apps::v1::DeploymentSpec).
+pub fn visit_api_apps_v1_deployment_spec_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DeploymentSpec) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(&mut node.selector);
+    if let Some(it) = &mut node.strategy {
+        v.visit_api_apps_v1_deployment_strategy_mut(it);
+    }
+    v.visit_api_core_v1_pod_template_spec_mut(&mut node.template);
+}
+
+/// Visits the values contained in a value of type [`DeploymentStatus`](crate::api::apps::v1::DeploymentStatus).
+pub fn visit_api_apps_v1_deployment_status_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DeploymentStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_deployment_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeploymentStrategy`](crate::api::apps::v1::DeploymentStrategy).
+pub fn visit_api_apps_v1_deployment_strategy_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::DeploymentStrategy) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_deployment_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSet`](crate::api::apps::v1::ReplicaSet).
+pub fn visit_api_apps_v1_replica_set_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::ReplicaSet) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_apps_v1_replica_set_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_apps_v1_replica_set_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetCondition`](crate::api::apps::v1::ReplicaSetCondition).
+pub fn visit_api_apps_v1_replica_set_condition_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::ReplicaSetCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetSpec`](crate::api::apps::v1::ReplicaSetSpec).
+pub fn visit_api_apps_v1_replica_set_spec_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::ReplicaSetSpec) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(&mut node.selector);
+    if let Some(it) = &mut node.template {
+        v.visit_api_core_v1_pod_template_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicaSetStatus`](crate::api::apps::v1::ReplicaSetStatus).
+pub fn visit_api_apps_v1_replica_set_status_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::ReplicaSetStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_replica_set_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateDaemonSet`](crate::api::apps::v1::RollingUpdateDaemonSet).
+pub fn visit_api_apps_v1_rolling_update_daemon_set_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::RollingUpdateDaemonSet) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.max_surge {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+    if let Some(it) = &mut node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateDeployment`](crate::api::apps::v1::RollingUpdateDeployment).
+pub fn visit_api_apps_v1_rolling_update_deployment_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::RollingUpdateDeployment) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.max_surge {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+    if let Some(it) = &mut node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RollingUpdateStatefulSetStrategy`](crate::api::apps::v1::RollingUpdateStatefulSetStrategy).
+pub fn visit_api_apps_v1_rolling_update_stateful_set_strategy_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::RollingUpdateStatefulSetStrategy) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSet`](crate::api::apps::v1::StatefulSet).
+pub fn visit_api_apps_v1_stateful_set_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::StatefulSet) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_apps_v1_stateful_set_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_apps_v1_stateful_set_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetCondition`](crate::api::apps::v1::StatefulSetCondition).
+pub fn visit_api_apps_v1_stateful_set_condition_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::StatefulSetCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`StatefulSetOrdinals`](crate::api::apps::v1::StatefulSetOrdinals), which does not contain values of other types that can be visited.
+pub fn visit_api_apps_v1_stateful_set_ordinals_mut<V>(_v: &mut V, _node: &mut crate::api::apps::v1::StatefulSetOrdinals) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`StatefulSetPersistentVolumeClaimRetentionPolicy`](crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy_mut<V>(_v: &mut V, _node: &mut crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StatefulSetSpec`](crate::api::apps::v1::StatefulSetSpec).
+pub fn visit_api_apps_v1_stateful_set_spec_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::StatefulSetSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ordinals {
+        v.visit_api_apps_v1_stateful_set_ordinals_mut(it);
+    }
+    if let Some(it) = &mut node.persistent_volume_claim_retention_policy {
+        v.visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(&mut node.selector);
+    v.visit_api_core_v1_pod_template_spec_mut(&mut node.template);
+    if let Some(it) = &mut node.update_strategy {
+        v.visit_api_apps_v1_stateful_set_update_strategy_mut(it);
+    }
+    if let Some(it) = &mut node.volume_claim_templates {
+        for it in it {
+            v.visit_api_core_v1_persistent_volume_claim_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetStatus`](crate::api::apps::v1::StatefulSetStatus).
+pub fn visit_api_apps_v1_stateful_set_status_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::StatefulSetStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_apps_v1_stateful_set_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`StatefulSetUpdateStrategy`](crate::api::apps::v1::StatefulSetUpdateStrategy).
+pub fn visit_api_apps_v1_stateful_set_update_strategy_mut<V>(v: &mut V, node: &mut crate::api::apps::v1::StatefulSetUpdateStrategy) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.rolling_update {
+        v.visit_api_apps_v1_rolling_update_stateful_set_strategy_mut(it);
+    }
+}
+
+/// Visits a value of type [`BoundObjectReference`](crate::api::authentication::v1::BoundObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_bound_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::authentication::v1::BoundObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReview`](crate::api::authentication::v1::SelfSubjectReview).
+pub fn visit_api_authentication_v1_self_subject_review_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::SelfSubjectReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authentication_v1_self_subject_review_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1::SelfSubjectReviewStatus).
+pub fn visit_api_authentication_v1_self_subject_review_status_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::SelfSubjectReviewStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.user_info {
+        v.visit_api_authentication_v1_user_info_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequest`](crate::api::authentication::v1::TokenRequest).
+pub fn visit_api_authentication_v1_token_request_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::TokenRequest) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authentication_v1_token_request_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authentication_v1_token_request_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequestSpec`](crate::api::authentication::v1::TokenRequestSpec).
+pub fn visit_api_authentication_v1_token_request_spec_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::TokenRequestSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.bound_object_ref {
+        v.visit_api_authentication_v1_bound_object_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`TokenRequestStatus`](crate::api::authentication::v1::TokenRequestStatus).
+pub fn visit_api_authentication_v1_token_request_status_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::TokenRequestStatus) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_time_mut(&mut node.expiration_timestamp);
+}
+
+/// Visits the values contained in a value of type [`TokenReview`](crate::api::authentication::v1::TokenReview).
+pub fn visit_api_authentication_v1_token_review_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::TokenReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authentication_v1_token_review_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authentication_v1_token_review_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`TokenReviewSpec`](crate::api::authentication::v1::TokenReviewSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_token_review_spec_mut<V>(_v: &mut V, _node: &mut crate::api::authentication::v1::TokenReviewSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TokenReviewStatus`](crate::api::authentication::v1::TokenReviewStatus).
+pub fn visit_api_authentication_v1_token_review_status_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1::TokenReviewStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.user {
+        v.visit_api_authentication_v1_user_info_mut(it);
+    }
+}
+
+/// Visits a value of type [`UserInfo`](crate::api::authentication::v1::UserInfo), which does not contain values of other types that can be visited.
+pub fn visit_api_authentication_v1_user_info_mut<V>(_v: &mut V, _node: &mut crate::api::authentication::v1::UserInfo) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReview`](crate::api::authentication::v1beta1::SelfSubjectReview).
+pub fn visit_api_authentication_v1beta1_self_subject_review_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1beta1::SelfSubjectReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authentication_v1beta1_self_subject_review_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectReviewStatus`](crate::api::authentication::v1beta1::SelfSubjectReviewStatus).
+pub fn visit_api_authentication_v1beta1_self_subject_review_status_mut<V>(v: &mut V, node: &mut crate::api::authentication::v1beta1::SelfSubjectReviewStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.user_info {
+        v.visit_api_authentication_v1_user_info_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FieldSelectorAttributes`](crate::api::authorization::v1::FieldSelectorAttributes).
+pub fn visit_api_authorization_v1_field_selector_attributes_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::FieldSelectorAttributes) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.requirements {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LabelSelectorAttributes`](crate::api::authorization::v1::LabelSelectorAttributes).
+pub fn visit_api_authorization_v1_label_selector_attributes_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::LabelSelectorAttributes) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.requirements {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LocalSubjectAccessReview`](crate::api::authorization::v1::LocalSubjectAccessReview).
+pub fn visit_api_authorization_v1_local_subject_access_review_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::LocalSubjectAccessReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authorization_v1_subject_access_review_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authorization_v1_subject_access_review_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`NonResourceAttributes`](crate::api::authorization::v1::NonResourceAttributes), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_non_resource_attributes_mut<V>(_v: &mut V, _node: &mut crate::api::authorization::v1::NonResourceAttributes) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`NonResourceRule`](crate::api::authorization::v1::NonResourceRule), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_non_resource_rule_mut<V>(_v: &mut V, _node: &mut crate::api::authorization::v1::NonResourceRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceAttributes`](crate::api::authorization::v1::ResourceAttributes).
+pub fn visit_api_authorization_v1_resource_attributes_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::ResourceAttributes) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.field_selector {
+        v.visit_api_authorization_v1_field_selector_attributes_mut(it);
+    }
+    if let Some(it) = &mut node.label_selector {
+        v.visit_api_authorization_v1_label_selector_attributes_mut(it);
+    }
+}
+
+/// Visits a value of type [`ResourceRule`](crate::api::authorization::v1::ResourceRule), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_resource_rule_mut<V>(_v: &mut V, _node: &mut crate::api::authorization::v1::ResourceRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectAccessReview`](crate::api::authorization::v1::SelfSubjectAccessReview).
+pub fn visit_api_authorization_v1_self_subject_access_review_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SelfSubjectAccessReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authorization_v1_self_subject_access_review_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authorization_v1_subject_access_review_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectAccessReviewSpec`](crate::api::authorization::v1::SelfSubjectAccessReviewSpec).
+pub fn visit_api_authorization_v1_self_subject_access_review_spec_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SelfSubjectAccessReviewSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.non_resource_attributes {
+        v.visit_api_authorization_v1_non_resource_attributes_mut(it);
+    }
+    if let Some(it) = &mut node.resource_attributes {
+        v.visit_api_authorization_v1_resource_attributes_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SelfSubjectRulesReview`](crate::api::authorization::v1::SelfSubjectRulesReview).
+pub fn visit_api_authorization_v1_self_subject_rules_review_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SelfSubjectRulesReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authorization_v1_self_subject_rules_review_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authorization_v1_subject_rules_review_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`SelfSubjectRulesReviewSpec`](crate::api::authorization::v1::SelfSubjectRulesReviewSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_self_subject_rules_review_spec_mut<V>(_v: &mut V, _node: &mut crate::api::authorization::v1::SelfSubjectRulesReviewSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SubjectAccessReview`](crate::api::authorization::v1::SubjectAccessReview).
+pub fn visit_api_authorization_v1_subject_access_review_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SubjectAccessReview) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_authorization_v1_subject_access_review_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_authorization_v1_subject_access_review_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SubjectAccessReviewSpec`](crate::api::authorization::v1::SubjectAccessReviewSpec).
+pub fn visit_api_authorization_v1_subject_access_review_spec_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SubjectAccessReviewSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.non_resource_attributes {
+        v.visit_api_authorization_v1_non_resource_attributes_mut(it);
+    }
+    if let Some(it) = &mut node.resource_attributes {
+        v.visit_api_authorization_v1_resource_attributes_mut(it);
+    }
+}
+
+/// Visits a value of type [`SubjectAccessReviewStatus`](crate::api::authorization::v1::SubjectAccessReviewStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_authorization_v1_subject_access_review_status_mut<V>(_v: &mut V, _node: &mut crate::api::authorization::v1::SubjectAccessReviewStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SubjectRulesReviewStatus`](crate::api::authorization::v1::SubjectRulesReviewStatus).
+pub fn visit_api_authorization_v1_subject_rules_review_status_mut<V>(v: &mut V, node: &mut crate::api::authorization::v1::SubjectRulesReviewStatus) where V: VisitMut + ?Sized {
+    for it in &mut node.non_resource_rules {
+        v.visit_api_authorization_v1_non_resource_rule_mut(it);
+    }
+    for it in &mut node.resource_rules {
+        v.visit_api_authorization_v1_resource_rule_mut(it);
+    }
+}
+
+/// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v1::CrossVersionObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_cross_version_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::autoscaling::v1::CrossVersionObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v1::HorizontalPodAutoscaler).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscaler) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v1_cross_version_object_reference_mut(&mut node.scale_target_ref);
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus).
+pub fn visit_api_autoscaling_v1_horizontal_pod_autoscaler_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_scale_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Scale`](crate::api::autoscaling::v1::Scale).
+pub fn visit_api_autoscaling_v1_scale_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v1::Scale) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_autoscaling_v1_scale_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_autoscaling_v1_scale_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`ScaleSpec`](crate::api::autoscaling::v1::ScaleSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_scale_spec_mut<V>(_v: &mut V, _node: &mut crate::api::autoscaling::v1::ScaleSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ScaleStatus`](crate::api::autoscaling::v1::ScaleStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v1_scale_status_mut<V>(_v: &mut V, _node: &mut crate::api::autoscaling::v1::ScaleStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerResourceMetricSource`](crate::api::autoscaling::v2::ContainerResourceMetricSource).
+pub fn visit_api_autoscaling_v2_container_resource_metric_source_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ContainerResourceMetricSource) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_target_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`ContainerResourceMetricStatus`](crate::api::autoscaling::v2::ContainerResourceMetricStatus).
+pub fn visit_api_autoscaling_v2_container_resource_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ContainerResourceMetricStatus) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status_mut(&mut node.current);
+}
+
+/// Visits a value of type [`CrossVersionObjectReference`](crate::api::autoscaling::v2::CrossVersionObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v2_cross_version_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::autoscaling::v2::CrossVersionObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ExternalMetricSource`](crate::api::autoscaling::v2::ExternalMetricSource).
+pub fn visit_api_autoscaling_v2_external_metric_source_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ExternalMetricSource) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+    v.visit_api_autoscaling_v2_metric_target_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`ExternalMetricStatus`](crate::api::autoscaling::v2::ExternalMetricStatus).
+pub fn visit_api_autoscaling_v2_external_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ExternalMetricStatus) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status_mut(&mut node.current);
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+}
+
+/// Visits a value of type [`HPAScalingPolicy`](crate::api::autoscaling::v2::HPAScalingPolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_autoscaling_v2_hpa_scaling_policy_mut<V>(_v: &mut V, _node: &mut crate::api::autoscaling::v2::HPAScalingPolicy) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HPAScalingRules`](crate::api::autoscaling::v2::HPAScalingRules).
+pub fn visit_api_autoscaling_v2_hpa_scaling_rules_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HPAScalingRules) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.policies {
+        for it in it {
+            v.visit_api_autoscaling_v2_hpa_scaling_policy_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscaler`](crate::api::autoscaling::v2::HorizontalPodAutoscaler).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscaler) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerBehavior`](crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.scale_down {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules_mut(it);
+    }
+    if let Some(it) = &mut node.scale_up {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerCondition`](crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerSpec`](crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.behavior {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior_mut(it);
+    }
+    if let Some(it) = &mut node.metrics {
+        for it in it {
+            v.visit_api_autoscaling_v2_metric_spec_mut(it);
+        }
+    }
+    v.visit_api_autoscaling_v2_cross_version_object_reference_mut(&mut node.scale_target_ref);
+}
+
+/// Visits the values contained in a value of type [`HorizontalPodAutoscalerStatus`](crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus).
+pub fn visit_api_autoscaling_v2_horizontal_pod_autoscaler_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.current_metrics {
+        for it in it {
+            v.visit_api_autoscaling_v2_metric_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.last_scale_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricIdentifier`](crate::api::autoscaling::v2::MetricIdentifier).
+pub fn visit_api_autoscaling_v2_metric_identifier_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::MetricIdentifier) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricSpec`](crate::api::autoscaling::v2::MetricSpec).
+pub fn visit_api_autoscaling_v2_metric_spec_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::MetricSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.container_resource {
+        v.visit_api_autoscaling_v2_container_resource_metric_source_mut(it);
+    }
+    if let Some(it) = &mut node.external {
+        v.visit_api_autoscaling_v2_external_metric_source_mut(it);
+    }
+    if let Some(it) = &mut node.object {
+        v.visit_api_autoscaling_v2_object_metric_source_mut(it);
+    }
+    if let Some(it) = &mut node.pods {
+        v.visit_api_autoscaling_v2_pods_metric_source_mut(it);
+    }
+    if let Some(it) = &mut node.resource {
+        v.visit_api_autoscaling_v2_resource_metric_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricStatus`](crate::api::autoscaling::v2::MetricStatus).
+pub fn visit_api_autoscaling_v2_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::MetricStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.container_resource {
+        v.visit_api_autoscaling_v2_container_resource_metric_status_mut(it);
+    }
+    if let Some(it) = &mut node.external {
+        v.visit_api_autoscaling_v2_external_metric_status_mut(it);
+    }
+    if let Some(it) = &mut node.object {
+        v.visit_api_autoscaling_v2_object_metric_status_mut(it);
+    }
+    if let Some(it) = &mut node.pods {
+        v.visit_api_autoscaling_v2_pods_metric_status_mut(it);
+    }
+    if let Some(it) = &mut node.resource {
+        v.visit_api_autoscaling_v2_resource_metric_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricTarget`](crate::api::autoscaling::v2::MetricTarget).
+pub fn visit_api_autoscaling_v2_metric_target_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::MetricTarget) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.average_value {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+    if let Some(it) = &mut node.value {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`MetricValueStatus`](crate::api::autoscaling::v2::MetricValueStatus).
+pub fn visit_api_autoscaling_v2_metric_value_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::MetricValueStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.average_value {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+    if let Some(it) = &mut node.value {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ObjectMetricSource`](crate::api::autoscaling::v2::ObjectMetricSource).
+pub fn visit_api_autoscaling_v2_object_metric_source_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ObjectMetricSource) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_cross_version_object_reference_mut(&mut node.described_object);
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+    v.visit_api_autoscaling_v2_metric_target_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`ObjectMetricStatus`](crate::api::autoscaling::v2::ObjectMetricStatus).
+pub fn visit_api_autoscaling_v2_object_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ObjectMetricStatus) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status_mut(&mut node.current);
+    v.visit_api_autoscaling_v2_cross_version_object_reference_mut(&mut node.described_object);
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+}
+
+/// Visits the values contained in a value of type [`PodsMetricSource`](crate::api::autoscaling::v2::PodsMetricSource).
+pub fn visit_api_autoscaling_v2_pods_metric_source_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::PodsMetricSource) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+    v.visit_api_autoscaling_v2_metric_target_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`PodsMetricStatus`](crate::api::autoscaling::v2::PodsMetricStatus).
+pub fn visit_api_autoscaling_v2_pods_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::PodsMetricStatus) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status_mut(&mut node.current);
+    v.visit_api_autoscaling_v2_metric_identifier_mut(&mut node.metric);
+}
+
+/// Visits the values contained in a value of type [`ResourceMetricSource`](crate::api::autoscaling::v2::ResourceMetricSource).
+pub fn visit_api_autoscaling_v2_resource_metric_source_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ResourceMetricSource) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_target_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`ResourceMetricStatus`](crate::api::autoscaling::v2::ResourceMetricStatus).
+pub fn visit_api_autoscaling_v2_resource_metric_status_mut<V>(v: &mut V, node: &mut crate::api::autoscaling::v2::ResourceMetricStatus) where V: VisitMut + ?Sized {
+    v.visit_api_autoscaling_v2_metric_value_status_mut(&mut node.current);
+}
+
+/// Visits the values contained in a value of type [`CronJob`](crate::api::batch::v1::CronJob).
+pub fn visit_api_batch_v1_cron_job_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::CronJob) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_batch_v1_cron_job_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_batch_v1_cron_job_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CronJobSpec`](crate::api::batch::v1::CronJobSpec).
+pub fn visit_api_batch_v1_cron_job_spec_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::CronJobSpec) where V: VisitMut + ?Sized {
+    v.visit_api_batch_v1_job_template_spec_mut(&mut node.job_template);
+}
+
+/// Visits the values contained in a value of type [`CronJobStatus`](crate::api::batch::v1::CronJobStatus).
+pub fn visit_api_batch_v1_cron_job_status_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::CronJobStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.active {
+        for it in it {
+            v.visit_api_core_v1_object_reference_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.last_schedule_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_successful_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Job`](crate::api::batch::v1::Job).
+pub fn visit_api_batch_v1_job_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::Job) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_batch_v1_job_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_batch_v1_job_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobCondition`](crate::api::batch::v1::JobCondition).
+pub fn visit_api_batch_v1_job_condition_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::JobCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobSpec`](crate::api::batch::v1::JobSpec).
+pub fn visit_api_batch_v1_job_spec_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::JobSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.pod_failure_policy {
+        v.visit_api_batch_v1_pod_failure_policy_mut(it);
+    }
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.success_policy {
+        v.visit_api_batch_v1_success_policy_mut(it);
+    }
+    v.visit_api_core_v1_pod_template_spec_mut(&mut node.template);
+}
+
+/// Visits the values contained in a value of type [`JobStatus`](crate::api::batch::v1::JobStatus).
+pub fn visit_api_batch_v1_job_status_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::JobStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.completion_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_batch_v1_job_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.start_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.uncounted_terminated_pods {
+        v.visit_api_batch_v1_uncounted_terminated_pods_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`JobTemplateSpec`](crate::api::batch::v1::JobTemplateSpec).
+pub fn visit_api_batch_v1_job_template_spec_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::JobTemplateSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(it);
+    }
+    if let Some(it) = &mut node.spec {
+        v.visit_api_batch_v1_job_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodFailurePolicy`](crate::api::batch::v1::PodFailurePolicy).
+pub fn visit_api_batch_v1_pod_failure_policy_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::PodFailurePolicy) where V: VisitMut + ?Sized {
+    for it in &mut node.rules {
+        v.visit_api_batch_v1_pod_failure_policy_rule_mut(it);
+    }
+}
+
+/// Visits a value of type [`PodFailurePolicyOnExitCodesRequirement`](crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement_mut<V>(_v: &mut V, _node: &mut crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodFailurePolicyOnPodConditionsPattern`](crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern_mut<V>(_v: &mut V, _node: &mut crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PodFailurePolicyRule`](crate::api::batch::v1::PodFailurePolicyRule).
+pub fn visit_api_batch_v1_pod_failure_policy_rule_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::PodFailurePolicyRule) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.on_exit_codes {
+        v.visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement_mut(it);
+    }
+    if let Some(it) = &mut node.on_pod_conditions {
+        for it in it {
+            v.visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`SuccessPolicy`](crate::api::batch::v1::SuccessPolicy).
+pub fn visit_api_batch_v1_success_policy_mut<V>(v: &mut V, node: &mut crate::api::batch::v1::SuccessPolicy) where V: VisitMut + ?Sized {
+    for it in &mut node.rules {
+        v.visit_api_batch_v1_success_policy_rule_mut(it);
+    }
+}
+
+/// Visits a value of type [`SuccessPolicyRule`](crate::api::batch::v1::SuccessPolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_success_policy_rule_mut<V>(_v: &mut V, _node: &mut crate::api::batch::v1::SuccessPolicyRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`UncountedTerminatedPods`](crate::api::batch::v1::UncountedTerminatedPods), which does not contain values of other types that can be visited.
+pub fn visit_api_batch_v1_uncounted_terminated_pods_mut<V>(_v: &mut V, _node: &mut crate::api::batch::v1::UncountedTerminatedPods) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequest`](crate::api::certificates::v1::CertificateSigningRequest).
+pub fn visit_api_certificates_v1_certificate_signing_request_mut<V>(v: &mut V, node: &mut crate::api::certificates::v1::CertificateSigningRequest) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_certificates_v1_certificate_signing_request_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_certificates_v1_certificate_signing_request_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequestCondition`](crate::api::certificates::v1::CertificateSigningRequestCondition).
+pub fn visit_api_certificates_v1_certificate_signing_request_condition_mut<V>(v: &mut V, node: &mut crate::api::certificates::v1::CertificateSigningRequestCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`CertificateSigningRequestSpec`](crate::api::certificates::v1::CertificateSigningRequestSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_certificates_v1_certificate_signing_request_spec_mut<V>(_v: &mut V, _node: &mut crate::api::certificates::v1::CertificateSigningRequestSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CertificateSigningRequestStatus`](crate::api::certificates::v1::CertificateSigningRequestStatus).
+pub fn visit_api_certificates_v1_certificate_signing_request_status_mut<V>(v: &mut V, node: &mut crate::api::certificates::v1::CertificateSigningRequestStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_certificates_v1_certificate_signing_request_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterTrustBundle`](crate::api::certificates::v1alpha1::ClusterTrustBundle).
+pub fn visit_api_certificates_v1alpha1_cluster_trust_bundle_mut<V>(v: &mut V, node: &mut crate::api::certificates::v1alpha1::ClusterTrustBundle) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_certificates_v1alpha1_cluster_trust_bundle_spec_mut(&mut node.spec);
+}
+
+/// Visits a value of type [`ClusterTrustBundleSpec`](crate::api::certificates::v1alpha1::ClusterTrustBundleSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_certificates_v1alpha1_cluster_trust_bundle_spec_mut<V>(_v: &mut V, _node: &mut crate::api::certificates::v1alpha1::ClusterTrustBundleSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Lease`](crate::api::coordination::v1::Lease).
+pub fn visit_api_coordination_v1_lease_mut<V>(v: &mut V, node: &mut crate::api::coordination::v1::Lease) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_coordination_v1_lease_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseSpec`](crate::api::coordination::v1::LeaseSpec).
+pub fn visit_api_coordination_v1_lease_spec_mut<V>(v: &mut V, node: &mut crate::api::coordination::v1::LeaseSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.acquire_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+    if let Some(it) = &mut node.renew_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseCandidate`](crate::api::coordination::v1alpha2::LeaseCandidate).
+pub fn visit_api_coordination_v1alpha2_lease_candidate_mut<V>(v: &mut V, node: &mut crate::api::coordination::v1alpha2::LeaseCandidate) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_coordination_v1alpha2_lease_candidate_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LeaseCandidateSpec`](crate::api::coordination::v1alpha2::LeaseCandidateSpec).
+pub fn visit_api_coordination_v1alpha2_lease_candidate_spec_mut<V>(v: &mut V, node: &mut crate::api::coordination::v1alpha2::LeaseCandidateSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ping_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+    if let Some(it) = &mut node.renew_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`AWSElasticBlockStoreVolumeSource`](crate::api::core::v1::AWSElasticBlockStoreVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_aws_elastic_block_store_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AWSElasticBlockStoreVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Affinity`](crate::api::core::v1::Affinity).
+pub fn visit_api_core_v1_affinity_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Affinity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.node_affinity {
+        v.visit_api_core_v1_node_affinity_mut(it);
+    }
+    if let Some(it) = &mut node.pod_affinity {
+        v.visit_api_core_v1_pod_affinity_mut(it);
+    }
+    if let Some(it) = &mut node.pod_anti_affinity {
+        v.visit_api_core_v1_pod_anti_affinity_mut(it);
+    }
+}
+
+/// Visits a value of type [`AppArmorProfile`](crate::api::core::v1::AppArmorProfile), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_app_armor_profile_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AppArmorProfile) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`AttachedVolume`](crate::api::core::v1::AttachedVolume), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_attached_volume_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AttachedVolume) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`AzureDiskVolumeSource`](crate::api::core::v1::AzureDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_disk_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AzureDiskVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`AzureFilePersistentVolumeSource`](crate::api::core::v1::AzureFilePersistentVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_file_persistent_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AzureFilePersistentVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`AzureFileVolumeSource`](crate::api::core::v1::AzureFileVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_azure_file_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::AzureFileVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Binding`](crate::api::core::v1::Binding).
+pub fn visit_api_core_v1_binding_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Binding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_core_v1_object_reference_mut(&mut node.target);
+}
+
+/// Visits the values contained in a value of type [`CSIPersistentVolumeSource`](crate::api::core::v1::CSIPersistentVolumeSource).
+pub fn visit_api_core_v1_csi_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CSIPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.controller_expand_secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+    if let Some(it) = &mut node.controller_publish_secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+    if let Some(it) = &mut node.node_expand_secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+    if let Some(it) = &mut node.node_publish_secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+    if let Some(it) = &mut node.node_stage_secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSIVolumeSource`](crate::api::core::v1::CSIVolumeSource).
+pub fn visit_api_core_v1_csi_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CSIVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.node_publish_secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`Capabilities`](crate::api::core::v1::Capabilities), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_capabilities_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::Capabilities) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CephFSPersistentVolumeSource`](crate::api::core::v1::CephFSPersistentVolumeSource).
+pub fn visit_api_core_v1_ceph_fs_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CephFSPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CephFSVolumeSource`](crate::api::core::v1::CephFSVolumeSource).
+pub fn visit_api_core_v1_ceph_fs_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CephFSVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CinderPersistentVolumeSource`](crate::api::core::v1::CinderPersistentVolumeSource).
+pub fn visit_api_core_v1_cinder_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CinderPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CinderVolumeSource`](crate::api::core::v1::CinderVolumeSource).
+pub fn visit_api_core_v1_cinder_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::CinderVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`ClientIPConfig`](crate::api::core::v1::ClientIPConfig), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_client_ip_config_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ClientIPConfig) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ClusterTrustBundleProjection`](crate::api::core::v1::ClusterTrustBundleProjection).
+pub fn visit_api_core_v1_cluster_trust_bundle_projection_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ClusterTrustBundleProjection) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`ComponentCondition`](crate::api::core::v1::ComponentCondition), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_component_condition_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ComponentCondition) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ComponentStatus`](crate::api::core::v1::ComponentStatus).
+pub fn visit_api_core_v1_component_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ComponentStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_component_condition_mut(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits the values contained in a value of type [`ConfigMap`](crate::api::core::v1::ConfigMap).
+pub fn visit_api_core_v1_config_map_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ConfigMap) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`ConfigMapEnvSource`](crate::api::core::v1::ConfigMapEnvSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_env_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ConfigMapEnvSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ConfigMapKeySelector`](crate::api::core::v1::ConfigMapKeySelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_key_selector_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ConfigMapKeySelector) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ConfigMapNodeConfigSource`](crate::api::core::v1::ConfigMapNodeConfigSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_config_map_node_config_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ConfigMapNodeConfigSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ConfigMapProjection`](crate::api::core::v1::ConfigMapProjection).
+pub fn visit_api_core_v1_config_map_projection_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ConfigMapProjection) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ConfigMapVolumeSource`](crate::api::core::v1::ConfigMapVolumeSource).
+pub fn visit_api_core_v1_config_map_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ConfigMapVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Container`](crate::api::core::v1::Container).
+pub fn visit_api_core_v1_container_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Container) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.env {
+        for it in it {
+            v.visit_api_core_v1_env_var_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.env_from {
+        for it in it {
+            v.visit_api_core_v1_env_from_source_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.lifecycle {
+        v.visit_api_core_v1_lifecycle_mut(it);
+    }
+    if let Some(it) = &mut node.liveness_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_core_v1_container_port_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.readiness_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.resize_policy {
+        for it in it {
+            v.visit_api_core_v1_container_resize_policy_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resources {
+        v.visit_api_core_v1_resource_requirements_mut(it);
+    }
+    if let Some(it) = &mut node.security_context {
+        v.visit_api_core_v1_security_context_mut(it);
+    }
+    if let Some(it) = &mut node.startup_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.volume_devices {
+        for it in it {
+            v.visit_api_core_v1_volume_device_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ContainerImage`](crate::api::core::v1::ContainerImage), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_image_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ContainerImage) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ContainerPort`](crate::api::core::v1::ContainerPort), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_port_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ContainerPort) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ContainerResizePolicy`](crate::api::core::v1::ContainerResizePolicy), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_resize_policy_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ContainerResizePolicy) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerState`](crate::api::core::v1::ContainerState).
+pub fn visit_api_core_v1_container_state_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ContainerState) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.running {
+        v.visit_api_core_v1_container_state_running_mut(it);
+    }
+    if let Some(it) = &mut node.terminated {
+        v.visit_api_core_v1_container_state_terminated_mut(it);
+    }
+    if let Some(it) = &mut node.waiting {
+        v.visit_api_core_v1_container_state_waiting_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerStateRunning`](crate::api::core::v1::ContainerStateRunning).
+pub fn visit_api_core_v1_container_state_running_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ContainerStateRunning) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.started_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerStateTerminated`](crate::api::core::v1::ContainerStateTerminated).
+pub fn visit_api_core_v1_container_state_terminated_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ContainerStateTerminated) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.finished_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.started_at {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`ContainerStateWaiting`](crate::api::core::v1::ContainerStateWaiting), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_container_state_waiting_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ContainerStateWaiting) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ContainerStatus`](crate::api::core::v1::ContainerStatus).
+pub fn visit_api_core_v1_container_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ContainerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allocated_resources {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.allocated_resources_status {
+        for it in it {
+            v.visit_api_core_v1_resource_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.last_state {
+        v.visit_api_core_v1_container_state_mut(it);
+    }
+    if let Some(it) = &mut node.resources {
+        v.visit_api_core_v1_resource_requirements_mut(it);
+    }
+    if let Some(it) = &mut node.state {
+        v.visit_api_core_v1_container_state_mut(it);
+    }
+    if let Some(it) = &mut node.user {
+        v.visit_api_core_v1_container_user_mut(it);
+    }
+    if let Some(it) = &mut node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount_status_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ContainerUser`](crate::api::core::v1::ContainerUser).
+pub fn visit_api_core_v1_container_user_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ContainerUser) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.linux {
+        v.visit_api_core_v1_linux_container_user_mut(it);
+    }
+}
+
+/// Visits a value of type [`DaemonEndpoint`](crate::api::core::v1::DaemonEndpoint), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_daemon_endpoint_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::DaemonEndpoint) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIProjection`](crate::api::core::v1::DownwardAPIProjection).
+pub fn visit_api_core_v1_downward_api_projection_mut<V>(v: &mut V, node: &mut crate::api::core::v1::DownwardAPIProjection) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_downward_api_volume_file_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIVolumeFile`](crate::api::core::v1::DownwardAPIVolumeFile).
+pub fn visit_api_core_v1_downward_api_volume_file_mut<V>(v: &mut V, node: &mut crate::api::core::v1::DownwardAPIVolumeFile) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.field_ref {
+        v.visit_api_core_v1_object_field_selector_mut(it);
+    }
+    if let Some(it) = &mut node.resource_field_ref {
+        v.visit_api_core_v1_resource_field_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DownwardAPIVolumeSource`](crate::api::core::v1::DownwardAPIVolumeSource).
+pub fn visit_api_core_v1_downward_api_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::DownwardAPIVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_downward_api_volume_file_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EmptyDirVolumeSource`](crate::api::core::v1::EmptyDirVolumeSource).
+pub fn visit_api_core_v1_empty_dir_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EmptyDirVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.size_limit {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EndpointAddress`](crate::api::core::v1::EndpointAddress).
+pub fn visit_api_core_v1_endpoint_address_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EndpointAddress) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.target_ref {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`EndpointPort`](crate::api::core::v1::EndpointPort), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_endpoint_port_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::EndpointPort) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointSubset`](crate::api::core::v1::EndpointSubset).
+pub fn visit_api_core_v1_endpoint_subset_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EndpointSubset) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.addresses {
+        for it in it {
+            v.visit_api_core_v1_endpoint_address_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.not_ready_addresses {
+        for it in it {
+            v.visit_api_core_v1_endpoint_address_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_core_v1_endpoint_port_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Endpoints`](crate::api::core::v1::Endpoints).
+pub fn visit_api_core_v1_endpoints_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Endpoints) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.subsets {
+        for it in it {
+            v.visit_api_core_v1_endpoint_subset_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvFromSource`](crate::api::core::v1::EnvFromSource).
+pub fn visit_api_core_v1_env_from_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EnvFromSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config_map_ref {
+        v.visit_api_core_v1_config_map_env_source_mut(it);
+    }
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_env_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvVar`](crate::api::core::v1::EnvVar).
+pub fn visit_api_core_v1_env_var_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EnvVar) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.value_from {
+        v.visit_api_core_v1_env_var_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EnvVarSource`](crate::api::core::v1::EnvVarSource).
+pub fn visit_api_core_v1_env_var_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EnvVarSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config_map_key_ref {
+        v.visit_api_core_v1_config_map_key_selector_mut(it);
+    }
+    if let Some(it) = &mut node.field_ref {
+        v.visit_api_core_v1_object_field_selector_mut(it);
+    }
+    if let Some(it) = &mut node.resource_field_ref {
+        v.visit_api_core_v1_resource_field_selector_mut(it);
+    }
+    if let Some(it) = &mut node.secret_key_ref {
+        v.visit_api_core_v1_secret_key_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EphemeralContainer`](crate::api::core::v1::EphemeralContainer).
+pub fn visit_api_core_v1_ephemeral_container_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EphemeralContainer) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.env {
+        for it in it {
+            v.visit_api_core_v1_env_var_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.env_from {
+        for it in it {
+            v.visit_api_core_v1_env_from_source_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.lifecycle {
+        v.visit_api_core_v1_lifecycle_mut(it);
+    }
+    if let Some(it) = &mut node.liveness_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_core_v1_container_port_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.readiness_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.resize_policy {
+        for it in it {
+            v.visit_api_core_v1_container_resize_policy_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resources {
+        v.visit_api_core_v1_resource_requirements_mut(it);
+    }
+    if let Some(it) = &mut node.security_context {
+        v.visit_api_core_v1_security_context_mut(it);
+    }
+    if let Some(it) = &mut node.startup_probe {
+        v.visit_api_core_v1_probe_mut(it);
+    }
+    if let Some(it) = &mut node.volume_devices {
+        for it in it {
+            v.visit_api_core_v1_volume_device_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.volume_mounts {
+        for it in it {
+            v.visit_api_core_v1_volume_mount_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`EphemeralVolumeSource`](crate::api::core::v1::EphemeralVolumeSource).
+pub fn visit_api_core_v1_ephemeral_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EphemeralVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.volume_claim_template {
+        v.visit_api_core_v1_persistent_volume_claim_template_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Event`](crate::api::core::v1::Event).
+pub fn visit_api_core_v1_event_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Event) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.event_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+    if let Some(it) = &mut node.first_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    v.visit_api_core_v1_object_reference_mut(&mut node.involved_object);
+    if let Some(it) = &mut node.last_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.related {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.series {
+        v.visit_api_core_v1_event_series_mut(it);
+    }
+    if let Some(it) = &mut node.source {
+        v.visit_api_core_v1_event_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EventSeries`](crate::api::core::v1::EventSeries).
+pub fn visit_api_core_v1_event_series_mut<V>(v: &mut V, node: &mut crate::api::core::v1::EventSeries) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_observed_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`EventSource`](crate::api::core::v1::EventSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_event_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::EventSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ExecAction`](crate::api::core::v1::ExecAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_exec_action_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ExecAction) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`FCVolumeSource`](crate::api::core::v1::FCVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_fc_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::FCVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`FlexPersistentVolumeSource`](crate::api::core::v1::FlexPersistentVolumeSource).
+pub fn visit_api_core_v1_flex_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::FlexPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlexVolumeSource`](crate::api::core::v1::FlexVolumeSource).
+pub fn visit_api_core_v1_flex_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::FlexVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`FlockerVolumeSource`](crate::api::core::v1::FlockerVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_flocker_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::FlockerVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GCEPersistentDiskVolumeSource`](crate::api::core::v1::GCEPersistentDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_gce_persistent_disk_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::GCEPersistentDiskVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GRPCAction`](crate::api::core::v1::GRPCAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_grpc_action_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::GRPCAction) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GitRepoVolumeSource`](crate::api::core::v1::GitRepoVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_git_repo_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::GitRepoVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GlusterfsPersistentVolumeSource`](crate::api::core::v1::GlusterfsPersistentVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_glusterfs_persistent_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::GlusterfsPersistentVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GlusterfsVolumeSource`](crate::api::core::v1::GlusterfsVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_glusterfs_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::GlusterfsVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HTTPGetAction`](crate::api::core::v1::HTTPGetAction).
+pub fn visit_api_core_v1_http_get_action_mut<V>(v: &mut V, node: &mut crate::api::core::v1::HTTPGetAction) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.http_headers {
+        for it in it {
+            v.visit_api_core_v1_http_header_mut(it);
+        }
+    }
+    v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(&mut node.port);
+}
+
+/// Visits a value of type [`HTTPHeader`](crate::api::core::v1::HTTPHeader), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_http_header_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::HTTPHeader) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`HostAlias`](crate::api::core::v1::HostAlias), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_alias_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::HostAlias) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`HostIP`](crate::api::core::v1::HostIP), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_ip_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::HostIP) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`HostPathVolumeSource`](crate::api::core::v1::HostPathVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_host_path_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::HostPathVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ISCSIPersistentVolumeSource`](crate::api::core::v1::ISCSIPersistentVolumeSource).
+pub fn visit_api_core_v1_iscsi_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ISCSIPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ISCSIVolumeSource`](crate::api::core::v1::ISCSIVolumeSource).
+pub fn visit_api_core_v1_iscsi_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ISCSIVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`ImageVolumeSource`](crate::api::core::v1::ImageVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_image_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ImageVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`KeyToPath`](crate::api::core::v1::KeyToPath), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_key_to_path_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::KeyToPath) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Lifecycle`](crate::api::core::v1::Lifecycle).
+pub fn visit_api_core_v1_lifecycle_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Lifecycle) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.post_start {
+        v.visit_api_core_v1_lifecycle_handler_mut(it);
+    }
+    if let Some(it) = &mut node.pre_stop {
+        v.visit_api_core_v1_lifecycle_handler_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LifecycleHandler`](crate::api::core::v1::LifecycleHandler).
+pub fn visit_api_core_v1_lifecycle_handler_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LifecycleHandler) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exec {
+        v.visit_api_core_v1_exec_action_mut(it);
+    }
+    if let Some(it) = &mut node.http_get {
+        v.visit_api_core_v1_http_get_action_mut(it);
+    }
+    if let Some(it) = &mut node.sleep {
+        v.visit_api_core_v1_sleep_action_mut(it);
+    }
+    if let Some(it) = &mut node.tcp_socket {
+        v.visit_api_core_v1_tcp_socket_action_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRange`](crate::api::core::v1::LimitRange).
+pub fn visit_api_core_v1_limit_range_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LimitRange) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_limit_range_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRangeItem`](crate::api::core::v1::LimitRangeItem).
+pub fn visit_api_core_v1_limit_range_item_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LimitRangeItem) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.default {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.default_request {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.max {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.max_limit_request_ratio {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.min {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitRangeSpec`](crate::api::core::v1::LimitRangeSpec).
+pub fn visit_api_core_v1_limit_range_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LimitRangeSpec) where V: VisitMut + ?Sized {
+    for it in &mut node.limits {
+        v.visit_api_core_v1_limit_range_item_mut(it);
+    }
+}
+
+/// Visits a value of type [`LinuxContainerUser`](crate::api::core::v1::LinuxContainerUser), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_linux_container_user_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::LinuxContainerUser) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LoadBalancerIngress`](crate::api::core::v1::LoadBalancerIngress).
+pub fn visit_api_core_v1_load_balancer_ingress_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LoadBalancerIngress) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_core_v1_port_status_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`LoadBalancerStatus`](crate::api::core::v1::LoadBalancerStatus).
+pub fn visit_api_core_v1_load_balancer_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::LoadBalancerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ingress {
+        for it in it {
+            v.visit_api_core_v1_load_balancer_ingress_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`LocalObjectReference`](crate::api::core::v1::LocalObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_local_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::LocalObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`LocalVolumeSource`](crate::api::core::v1::LocalVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_local_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::LocalVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ModifyVolumeStatus`](crate::api::core::v1::ModifyVolumeStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_modify_volume_status_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ModifyVolumeStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`NFSVolumeSource`](crate::api::core::v1::NFSVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_nfs_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NFSVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Namespace`](crate::api::core::v1::Namespace).
+pub fn visit_api_core_v1_namespace_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Namespace) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_namespace_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_namespace_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NamespaceCondition`](crate::api::core::v1::NamespaceCondition).
+pub fn visit_api_core_v1_namespace_condition_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NamespaceCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`NamespaceSpec`](crate::api::core::v1::NamespaceSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_namespace_spec_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NamespaceSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NamespaceStatus`](crate::api::core::v1::NamespaceStatus).
+pub fn visit_api_core_v1_namespace_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NamespaceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_namespace_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Node`](crate::api::core::v1::Node).
+pub fn visit_api_core_v1_node_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Node) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_node_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_node_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`NodeAddress`](crate::api::core::v1::NodeAddress), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_address_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NodeAddress) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeAffinity`](crate::api::core::v1::NodeAffinity).
+pub fn visit_api_core_v1_node_affinity_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeAffinity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_preferred_scheduling_term_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.required_during_scheduling_ignored_during_execution {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeCondition`](crate::api::core::v1::NodeCondition).
+pub fn visit_api_core_v1_node_condition_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_heartbeat_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeConfigSource`](crate::api::core::v1::NodeConfigSource).
+pub fn visit_api_core_v1_node_config_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeConfigSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config_map {
+        v.visit_api_core_v1_config_map_node_config_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeConfigStatus`](crate::api::core::v1::NodeConfigStatus).
+pub fn visit_api_core_v1_node_config_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeConfigStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.active {
+        v.visit_api_core_v1_node_config_source_mut(it);
+    }
+    if let Some(it) = &mut node.assigned {
+        v.visit_api_core_v1_node_config_source_mut(it);
+    }
+    if let Some(it) = &mut node.last_known_good {
+        v.visit_api_core_v1_node_config_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeDaemonEndpoints`](crate::api::core::v1::NodeDaemonEndpoints).
+pub fn visit_api_core_v1_node_daemon_endpoints_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeDaemonEndpoints) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.kubelet_endpoint {
+        v.visit_api_core_v1_daemon_endpoint_mut(it);
+    }
+}
+
+/// Visits a value of type [`NodeFeatures`](crate::api::core::v1::NodeFeatures), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_features_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NodeFeatures) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeRuntimeHandler`](crate::api::core::v1::NodeRuntimeHandler).
+pub fn visit_api_core_v1_node_runtime_handler_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeRuntimeHandler) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.features {
+        v.visit_api_core_v1_node_runtime_handler_features_mut(it);
+    }
+}
+
+/// Visits a value of type [`NodeRuntimeHandlerFeatures`](crate::api::core::v1::NodeRuntimeHandlerFeatures), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_runtime_handler_features_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NodeRuntimeHandlerFeatures) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeSelector`](crate::api::core::v1::NodeSelector).
+pub fn visit_api_core_v1_node_selector_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeSelector) where V: VisitMut + ?Sized {
+    for it in &mut node.node_selector_terms {
+        v.visit_api_core_v1_node_selector_term_mut(it);
+    }
+}
+
+/// Visits a value of type [`NodeSelectorRequirement`](crate::api::core::v1::NodeSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_selector_requirement_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NodeSelectorRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NodeSelectorTerm`](crate::api::core::v1::NodeSelectorTerm).
+pub fn visit_api_core_v1_node_selector_term_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeSelectorTerm) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_expressions {
+        for it in it {
+            v.visit_api_core_v1_node_selector_requirement_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.match_fields {
+        for it in it {
+            v.visit_api_core_v1_node_selector_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeSpec`](crate::api::core::v1::NodeSpec).
+pub fn visit_api_core_v1_node_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config_source {
+        v.visit_api_core_v1_node_config_source_mut(it);
+    }
+    if let Some(it) = &mut node.taints {
+        for it in it {
+            v.visit_api_core_v1_taint_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NodeStatus`](crate::api::core::v1::NodeStatus).
+pub fn visit_api_core_v1_node_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::NodeStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.addresses {
+        for it in it {
+            v.visit_api_core_v1_node_address_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.allocatable {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.capacity {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_node_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.config {
+        v.visit_api_core_v1_node_config_status_mut(it);
+    }
+    if let Some(it) = &mut node.daemon_endpoints {
+        v.visit_api_core_v1_node_daemon_endpoints_mut(it);
+    }
+    if let Some(it) = &mut node.features {
+        v.visit_api_core_v1_node_features_mut(it);
+    }
+    if let Some(it) = &mut node.images {
+        for it in it {
+            v.visit_api_core_v1_container_image_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.node_info {
+        v.visit_api_core_v1_node_system_info_mut(it);
+    }
+    if let Some(it) = &mut node.runtime_handlers {
+        for it in it {
+            v.visit_api_core_v1_node_runtime_handler_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.volumes_attached {
+        for it in it {
+            v.visit_api_core_v1_attached_volume_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`NodeSystemInfo`](crate::api::core::v1::NodeSystemInfo), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_node_system_info_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::NodeSystemInfo) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ObjectFieldSelector`](crate::api::core::v1::ObjectFieldSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_object_field_selector_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ObjectFieldSelector) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ObjectReference`](crate::api::core::v1::ObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PersistentVolume`](crate::api::core::v1::PersistentVolume).
+pub fn visit_api_core_v1_persistent_volume_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolume) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_persistent_volume_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_persistent_volume_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaim`](crate::api::core::v1::PersistentVolumeClaim).
+pub fn visit_api_core_v1_persistent_volume_claim_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeClaim) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_persistent_volume_claim_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_persistent_volume_claim_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimCondition`](crate::api::core::v1::PersistentVolumeClaimCondition).
+pub fn visit_api_core_v1_persistent_volume_claim_condition_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeClaimCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimSpec`](crate::api::core::v1::PersistentVolumeClaimSpec).
+pub fn visit_api_core_v1_persistent_volume_claim_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeClaimSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.data_source {
+        v.visit_api_core_v1_typed_local_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.data_source_ref {
+        v.visit_api_core_v1_typed_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.resources {
+        v.visit_api_core_v1_volume_resource_requirements_mut(it);
+    }
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimStatus`](crate::api::core::v1::PersistentVolumeClaimStatus).
+pub fn visit_api_core_v1_persistent_volume_claim_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeClaimStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allocated_resources {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.capacity {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_persistent_volume_claim_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.modify_volume_status {
+        v.visit_api_core_v1_modify_volume_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeClaimTemplate`](crate::api::core::v1::PersistentVolumeClaimTemplate).
+pub fn visit_api_core_v1_persistent_volume_claim_template_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeClaimTemplate) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(it);
+    }
+    v.visit_api_core_v1_persistent_volume_claim_spec_mut(&mut node.spec);
+}
+
+/// Visits a value of type [`PersistentVolumeClaimVolumeSource`](crate::api::core::v1::PersistentVolumeClaimVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_persistent_volume_claim_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PersistentVolumeClaimVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeSpec`](crate::api::core::v1::PersistentVolumeSpec).
+pub fn visit_api_core_v1_persistent_volume_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.aws_elastic_block_store {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.azure_disk {
+        v.visit_api_core_v1_azure_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.azure_file {
+        v.visit_api_core_v1_azure_file_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.capacity {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.cephfs {
+        v.visit_api_core_v1_ceph_fs_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.cinder {
+        v.visit_api_core_v1_cinder_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.claim_ref {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.csi {
+        v.visit_api_core_v1_csi_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.fc {
+        v.visit_api_core_v1_fc_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.flex_volume {
+        v.visit_api_core_v1_flex_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.flocker {
+        v.visit_api_core_v1_flocker_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.gce_persistent_disk {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.glusterfs {
+        v.visit_api_core_v1_glusterfs_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.host_path {
+        v.visit_api_core_v1_host_path_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.iscsi {
+        v.visit_api_core_v1_iscsi_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.local {
+        v.visit_api_core_v1_local_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.nfs {
+        v.visit_api_core_v1_nfs_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.node_affinity {
+        v.visit_api_core_v1_volume_node_affinity_mut(it);
+    }
+    if let Some(it) = &mut node.photon_persistent_disk {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.portworx_volume {
+        v.visit_api_core_v1_portworx_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.quobyte {
+        v.visit_api_core_v1_quobyte_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.rbd {
+        v.visit_api_core_v1_rbd_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.scale_io {
+        v.visit_api_core_v1_scale_io_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.storageos {
+        v.visit_api_core_v1_storage_os_persistent_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.vsphere_volume {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PersistentVolumeStatus`](crate::api::core::v1::PersistentVolumeStatus).
+pub fn visit_api_core_v1_persistent_volume_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PersistentVolumeStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_phase_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`PhotonPersistentDiskVolumeSource`](crate::api::core::v1::PhotonPersistentDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_photon_persistent_disk_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PhotonPersistentDiskVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Pod`](crate::api::core::v1::Pod).
+pub fn visit_api_core_v1_pod_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Pod) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_pod_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_pod_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAffinity`](crate::api::core::v1::PodAffinity).
+pub fn visit_api_core_v1_pod_affinity_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodAffinity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_weighted_pod_affinity_term_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.required_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_pod_affinity_term_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAffinityTerm`](crate::api::core::v1::PodAffinityTerm).
+pub fn visit_api_core_v1_pod_affinity_term_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodAffinityTerm) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodAntiAffinity`](crate::api::core::v1::PodAntiAffinity).
+pub fn visit_api_core_v1_pod_anti_affinity_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodAntiAffinity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.preferred_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_weighted_pod_affinity_term_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.required_during_scheduling_ignored_during_execution {
+        for it in it {
+            v.visit_api_core_v1_pod_affinity_term_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodCondition`](crate::api::core::v1::PodCondition).
+pub fn visit_api_core_v1_pod_condition_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_probe_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDNSConfig`](crate::api::core::v1::PodDNSConfig).
+pub fn visit_api_core_v1_pod_dns_config_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodDNSConfig) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.options {
+        for it in it {
+            v.visit_api_core_v1_pod_dns_config_option_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`PodDNSConfigOption`](crate::api::core::v1::PodDNSConfigOption), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_dns_config_option_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodDNSConfigOption) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodIP`](crate::api::core::v1::PodIP), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_ip_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodIP) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodOS`](crate::api::core::v1::PodOS), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_os_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodOS) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodReadinessGate`](crate::api::core::v1::PodReadinessGate), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_readiness_gate_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodReadinessGate) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodResourceClaim`](crate::api::core::v1::PodResourceClaim), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_resource_claim_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodResourceClaim) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodResourceClaimStatus`](crate::api::core::v1::PodResourceClaimStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_resource_claim_status_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodResourceClaimStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PodSchedulingGate`](crate::api::core::v1::PodSchedulingGate), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_pod_scheduling_gate_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PodSchedulingGate) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PodSecurityContext`](crate::api::core::v1::PodSecurityContext).
+pub fn visit_api_core_v1_pod_security_context_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodSecurityContext) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.app_armor_profile {
+        v.visit_api_core_v1_app_armor_profile_mut(it);
+    }
+    if let Some(it) = &mut node.se_linux_options {
+        v.visit_api_core_v1_se_linux_options_mut(it);
+    }
+    if let Some(it) = &mut node.seccomp_profile {
+        v.visit_api_core_v1_seccomp_profile_mut(it);
+    }
+    if let Some(it) = &mut node.sysctls {
+        for it in it {
+            v.visit_api_core_v1_sysctl_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.windows_options {
+        v.visit_api_core_v1_windows_security_context_options_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodSpec`](crate::api::core::v1::PodSpec).
+pub fn visit_api_core_v1_pod_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.affinity {
+        v.visit_api_core_v1_affinity_mut(it);
+    }
+    for it in &mut node.containers {
+        v.visit_api_core_v1_container_mut(it);
+    }
+    if let Some(it) = &mut node.dns_config {
+        v.visit_api_core_v1_pod_dns_config_mut(it);
+    }
+    if let Some(it) = &mut node.ephemeral_containers {
+        for it in it {
+            v.visit_api_core_v1_ephemeral_container_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.host_aliases {
+        for it in it {
+            v.visit_api_core_v1_host_alias_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.image_pull_secrets {
+        for it in it {
+            v.visit_api_core_v1_local_object_reference_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.init_containers {
+        for it in it {
+            v.visit_api_core_v1_container_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.os {
+        v.visit_api_core_v1_pod_os_mut(it);
+    }
+    if let Some(it) = &mut node.overhead {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.readiness_gates {
+        for it in it {
+            v.visit_api_core_v1_pod_readiness_gate_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resource_claims {
+        for it in it {
+            v.visit_api_core_v1_pod_resource_claim_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resources {
+        v.visit_api_core_v1_resource_requirements_mut(it);
+    }
+    if let Some(it) = &mut node.scheduling_gates {
+        for it in it {
+            v.visit_api_core_v1_pod_scheduling_gate_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.security_context {
+        v.visit_api_core_v1_pod_security_context_mut(it);
+    }
+    if let Some(it) = &mut node.tolerations {
+        for it in it {
+            v.visit_api_core_v1_toleration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.topology_spread_constraints {
+        for it in it {
+            v.visit_api_core_v1_topology_spread_constraint_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.volumes {
+        for it in it {
+            v.visit_api_core_v1_volume_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`PodStatus`](crate::api::core::v1::PodStatus).
+pub fn visit_api_core_v1_pod_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_pod_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.ephemeral_container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.host_ips {
+        for it in it {
+            v.visit_api_core_v1_host_ip_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.init_container_statuses {
+        for it in it {
+            v.visit_api_core_v1_container_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.pod_ips {
+        for it in it {
+            v.visit_api_core_v1_pod_ip_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resource_claim_statuses {
+        for it in it {
+            v.visit_api_core_v1_pod_resource_claim_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.start_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodTemplate`](crate::api::core::v1::PodTemplate).
+pub fn visit_api_core_v1_pod_template_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodTemplate) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.template {
+        v.visit_api_core_v1_pod_template_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodTemplateSpec`](crate::api::core::v1::PodTemplateSpec).
+pub fn visit_api_core_v1_pod_template_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PodTemplateSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(it);
+    }
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_pod_spec_mut(it);
+    }
+}
+
+/// Visits a value of type [`PortStatus`](crate::api::core::v1::PortStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_port_status_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PortStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`PortworxVolumeSource`](crate::api::core::v1::PortworxVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_portworx_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::PortworxVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PreferredSchedulingTerm`](crate::api::core::v1::PreferredSchedulingTerm).
+pub fn visit_api_core_v1_preferred_scheduling_term_mut<V>(v: &mut V, node: &mut crate::api::core::v1::PreferredSchedulingTerm) where V: VisitMut + ?Sized {
+    v.visit_api_core_v1_node_selector_term_mut(&mut node.preference);
+}
+
+/// Visits the values contained in a value of type [`Probe`](crate::api::core::v1::Probe).
+pub fn visit_api_core_v1_probe_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Probe) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exec {
+        v.visit_api_core_v1_exec_action_mut(it);
+    }
+    if let Some(it) = &mut node.grpc {
+        v.visit_api_core_v1_grpc_action_mut(it);
+    }
+    if let Some(it) = &mut node.http_get {
+        v.visit_api_core_v1_http_get_action_mut(it);
+    }
+    if let Some(it) = &mut node.tcp_socket {
+        v.visit_api_core_v1_tcp_socket_action_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ProjectedVolumeSource`](crate::api::core::v1::ProjectedVolumeSource).
+pub fn visit_api_core_v1_projected_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ProjectedVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.sources {
+        for it in it {
+            v.visit_api_core_v1_volume_projection_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`QuobyteVolumeSource`](crate::api::core::v1::QuobyteVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_quobyte_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::QuobyteVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`RBDPersistentVolumeSource`](crate::api::core::v1::RBDPersistentVolumeSource).
+pub fn visit_api_core_v1_rbd_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::RBDPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_secret_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`RBDVolumeSource`](crate::api::core::v1::RBDVolumeSource).
+pub fn visit_api_core_v1_rbd_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::RBDVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationController`](crate::api::core::v1::ReplicationController).
+pub fn visit_api_core_v1_replication_controller_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ReplicationController) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_replication_controller_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_replication_controller_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerCondition`](crate::api::core::v1::ReplicationControllerCondition).
+pub fn visit_api_core_v1_replication_controller_condition_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ReplicationControllerCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerSpec`](crate::api::core::v1::ReplicationControllerSpec).
+pub fn visit_api_core_v1_replication_controller_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ReplicationControllerSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.template {
+        v.visit_api_core_v1_pod_template_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ReplicationControllerStatus`](crate::api::core::v1::ReplicationControllerStatus).
+pub fn visit_api_core_v1_replication_controller_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ReplicationControllerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_core_v1_replication_controller_condition_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ResourceClaim`](crate::api::core::v1::ResourceClaim), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_resource_claim_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ResourceClaim) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceFieldSelector`](crate::api::core::v1::ResourceFieldSelector).
+pub fn visit_api_core_v1_resource_field_selector_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceFieldSelector) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.divisor {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+}
+
+/// Visits a value of type [`ResourceHealth`](crate::api::core::v1::ResourceHealth), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_resource_health_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ResourceHealth) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceQuota`](crate::api::core::v1::ResourceQuota).
+pub fn visit_api_core_v1_resource_quota_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceQuota) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_resource_quota_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_resource_quota_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceQuotaSpec`](crate::api::core::v1::ResourceQuotaSpec).
+pub fn visit_api_core_v1_resource_quota_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceQuotaSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.hard {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.scope_selector {
+        v.visit_api_core_v1_scope_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceQuotaStatus`](crate::api::core::v1::ResourceQuotaStatus).
+pub fn visit_api_core_v1_resource_quota_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceQuotaStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.hard {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.used {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceRequirements`](crate::api::core::v1::ResourceRequirements).
+pub fn visit_api_core_v1_resource_requirements_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceRequirements) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.claims {
+        for it in it {
+            v.visit_api_core_v1_resource_claim_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.limits {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.requests {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceStatus`](crate::api::core::v1::ResourceStatus).
+pub fn visit_api_core_v1_resource_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ResourceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.resources {
+        for it in it {
+            v.visit_api_core_v1_resource_health_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`SELinuxOptions`](crate::api::core::v1::SELinuxOptions), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_se_linux_options_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SELinuxOptions) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ScaleIOPersistentVolumeSource`](crate::api::core::v1::ScaleIOPersistentVolumeSource).
+pub fn visit_api_core_v1_scale_io_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ScaleIOPersistentVolumeSource) where V: VisitMut + ?Sized {
+    v.visit_api_core_v1_secret_reference_mut(&mut node.secret_ref);
+}
+
+/// Visits the values contained in a value of type [`ScaleIOVolumeSource`](crate::api::core::v1::ScaleIOVolumeSource).
+pub fn visit_api_core_v1_scale_io_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ScaleIOVolumeSource) where V: VisitMut + ?Sized {
+    v.visit_api_core_v1_local_object_reference_mut(&mut node.secret_ref);
+}
+
+/// Visits the values contained in a value of type [`ScopeSelector`](crate::api::core::v1::ScopeSelector).
+pub fn visit_api_core_v1_scope_selector_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ScopeSelector) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_expressions {
+        for it in it {
+            v.visit_api_core_v1_scoped_resource_selector_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ScopedResourceSelectorRequirement`](crate::api::core::v1::ScopedResourceSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_scoped_resource_selector_requirement_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ScopedResourceSelectorRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`SeccompProfile`](crate::api::core::v1::SeccompProfile), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_seccomp_profile_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SeccompProfile) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Secret`](crate::api::core::v1::Secret).
+pub fn visit_api_core_v1_secret_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Secret) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`SecretEnvSource`](crate::api::core::v1::SecretEnvSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_env_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SecretEnvSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`SecretKeySelector`](crate::api::core::v1::SecretKeySelector), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_key_selector_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SecretKeySelector) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SecretProjection`](crate::api::core::v1::SecretProjection).
+pub fn visit_api_core_v1_secret_projection_mut<V>(v: &mut V, node: &mut crate::api::core::v1::SecretProjection) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`SecretReference`](crate::api::core::v1::SecretReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_secret_reference_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SecretReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`SecretVolumeSource`](crate::api::core::v1::SecretVolumeSource).
+pub fn visit_api_core_v1_secret_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::SecretVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.items {
+        for it in it {
+            v.visit_api_core_v1_key_to_path_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`SecurityContext`](crate::api::core::v1::SecurityContext).
+pub fn visit_api_core_v1_security_context_mut<V>(v: &mut V, node: &mut crate::api::core::v1::SecurityContext) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.app_armor_profile {
+        v.visit_api_core_v1_app_armor_profile_mut(it);
+    }
+    if let Some(it) = &mut node.capabilities {
+        v.visit_api_core_v1_capabilities_mut(it);
+    }
+    if let Some(it) = &mut node.se_linux_options {
+        v.visit_api_core_v1_se_linux_options_mut(it);
+    }
+    if let Some(it) = &mut node.seccomp_profile {
+        v.visit_api_core_v1_seccomp_profile_mut(it);
+    }
+    if let Some(it) = &mut node.windows_options {
+        v.visit_api_core_v1_windows_security_context_options_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Service`](crate::api::core::v1::Service).
+pub fn visit_api_core_v1_service_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Service) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_core_v1_service_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_core_v1_service_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceAccount`](crate::api::core::v1::ServiceAccount).
+pub fn visit_api_core_v1_service_account_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ServiceAccount) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.image_pull_secrets {
+        for it in it {
+            v.visit_api_core_v1_local_object_reference_mut(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.secrets {
+        for it in it {
+            v.visit_api_core_v1_object_reference_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ServiceAccountTokenProjection`](crate::api::core::v1::ServiceAccountTokenProjection), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_service_account_token_projection_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::ServiceAccountTokenProjection) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServicePort`](crate::api::core::v1::ServicePort).
+pub fn visit_api_core_v1_service_port_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ServicePort) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.target_port {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceSpec`](crate::api::core::v1::ServiceSpec).
+pub fn visit_api_core_v1_service_spec_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ServiceSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_core_v1_service_port_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.session_affinity_config {
+        v.visit_api_core_v1_session_affinity_config_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ServiceStatus`](crate::api::core::v1::ServiceStatus).
+pub fn visit_api_core_v1_service_status_mut<V>(v: &mut V, node: &mut crate::api::core::v1::ServiceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.load_balancer {
+        v.visit_api_core_v1_load_balancer_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`SessionAffinityConfig`](crate::api::core::v1::SessionAffinityConfig).
+pub fn visit_api_core_v1_session_affinity_config_mut<V>(v: &mut V, node: &mut crate::api::core::v1::SessionAffinityConfig) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.client_ip {
+        v.visit_api_core_v1_client_ip_config_mut(it);
+    }
+}
+
+/// Visits a value of type [`SleepAction`](crate::api::core::v1::SleepAction), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_sleep_action_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::SleepAction) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StorageOSPersistentVolumeSource`](crate::api::core::v1::StorageOSPersistentVolumeSource).
+pub fn visit_api_core_v1_storage_os_persistent_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::StorageOSPersistentVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageOSVolumeSource`](crate::api::core::v1::StorageOSVolumeSource).
+pub fn visit_api_core_v1_storage_os_volume_source_mut<V>(v: &mut V, node: &mut crate::api::core::v1::StorageOSVolumeSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.secret_ref {
+        v.visit_api_core_v1_local_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`Sysctl`](crate::api::core::v1::Sysctl), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_sysctl_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::Sysctl) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TCPSocketAction`](crate::api::core::v1::TCPSocketAction).
+pub fn visit_api_core_v1_tcp_socket_action_mut<V>(v: &mut V, node: &mut crate::api::core::v1::TCPSocketAction) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(&mut node.port);
+}
+
+/// Visits the values contained in a value of type [`Taint`](crate::api::core::v1::Taint).
+pub fn visit_api_core_v1_taint_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Taint) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.time_added {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`Toleration`](crate::api::core::v1::Toleration), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_toleration_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::Toleration) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`TopologySelectorLabelRequirement`](crate::api::core::v1::TopologySelectorLabelRequirement), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_topology_selector_label_requirement_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::TopologySelectorLabelRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`TopologySelectorTerm`](crate::api::core::v1::TopologySelectorTerm).
+pub fn visit_api_core_v1_topology_selector_term_mut<V>(v: &mut V, node: &mut crate::api::core::v1::TopologySelectorTerm) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_label_expressions {
+        for it in it {
+            v.visit_api_core_v1_topology_selector_label_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`TopologySpreadConstraint`](crate::api::core::v1::TopologySpreadConstraint).
+pub fn visit_api_core_v1_topology_spread_constraint_mut<V>(v: &mut V, node: &mut crate::api::core::v1::TopologySpreadConstraint) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.label_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`TypedLocalObjectReference`](crate::api::core::v1::TypedLocalObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_typed_local_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::TypedLocalObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`TypedObjectReference`](crate::api::core::v1::TypedObjectReference), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_typed_object_reference_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::TypedObjectReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Volume`](crate::api::core::v1::Volume).
+pub fn visit_api_core_v1_volume_mut<V>(v: &mut V, node: &mut crate::api::core::v1::Volume) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.aws_elastic_block_store {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.azure_disk {
+        v.visit_api_core_v1_azure_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.azure_file {
+        v.visit_api_core_v1_azure_file_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.cephfs {
+        v.visit_api_core_v1_ceph_fs_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.cinder {
+        v.visit_api_core_v1_cinder_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.config_map {
+        v.visit_api_core_v1_config_map_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.csi {
+        v.visit_api_core_v1_csi_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.downward_api {
+        v.visit_api_core_v1_downward_api_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.empty_dir {
+        v.visit_api_core_v1_empty_dir_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.ephemeral {
+        v.visit_api_core_v1_ephemeral_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.fc {
+        v.visit_api_core_v1_fc_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.flex_volume {
+        v.visit_api_core_v1_flex_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.flocker {
+        v.visit_api_core_v1_flocker_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.gce_persistent_disk {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.git_repo {
+        v.visit_api_core_v1_git_repo_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.glusterfs {
+        v.visit_api_core_v1_glusterfs_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.host_path {
+        v.visit_api_core_v1_host_path_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.image {
+        v.visit_api_core_v1_image_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.iscsi {
+        v.visit_api_core_v1_iscsi_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.nfs {
+        v.visit_api_core_v1_nfs_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.persistent_volume_claim {
+        v.visit_api_core_v1_persistent_volume_claim_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.photon_persistent_disk {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.portworx_volume {
+        v.visit_api_core_v1_portworx_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.projected {
+        v.visit_api_core_v1_projected_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.quobyte {
+        v.visit_api_core_v1_quobyte_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.rbd {
+        v.visit_api_core_v1_rbd_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.scale_io {
+        v.visit_api_core_v1_scale_io_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.secret {
+        v.visit_api_core_v1_secret_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.storageos {
+        v.visit_api_core_v1_storage_os_volume_source_mut(it);
+    }
+    if let Some(it) = &mut node.vsphere_volume {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source_mut(it);
+    }
+}
+
+/// Visits a value of type [`VolumeDevice`](crate::api::core::v1::VolumeDevice), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_device_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::VolumeDevice) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`VolumeMount`](crate::api::core::v1::VolumeMount), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_mount_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::VolumeMount) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`VolumeMountStatus`](crate::api::core::v1::VolumeMountStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_volume_mount_status_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::VolumeMountStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeNodeAffinity`](crate::api::core::v1::VolumeNodeAffinity).
+pub fn visit_api_core_v1_volume_node_affinity_mut<V>(v: &mut V, node: &mut crate::api::core::v1::VolumeNodeAffinity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.required {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeProjection`](crate::api::core::v1::VolumeProjection).
+pub fn visit_api_core_v1_volume_projection_mut<V>(v: &mut V, node: &mut crate::api::core::v1::VolumeProjection) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.cluster_trust_bundle {
+        v.visit_api_core_v1_cluster_trust_bundle_projection_mut(it);
+    }
+    if let Some(it) = &mut node.config_map {
+        v.visit_api_core_v1_config_map_projection_mut(it);
+    }
+    if let Some(it) = &mut node.downward_api {
+        v.visit_api_core_v1_downward_api_projection_mut(it);
+    }
+    if let Some(it) = &mut node.secret {
+        v.visit_api_core_v1_secret_projection_mut(it);
+    }
+    if let Some(it) = &mut node.service_account_token {
+        v.visit_api_core_v1_service_account_token_projection_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeResourceRequirements`](crate::api::core::v1::VolumeResourceRequirements).
+pub fn visit_api_core_v1_volume_resource_requirements_mut<V>(v: &mut V, node: &mut crate::api::core::v1::VolumeResourceRequirements) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.limits {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.requests {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`VsphereVirtualDiskVolumeSource`](crate::api::core::v1::VsphereVirtualDiskVolumeSource), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_vsphere_virtual_disk_volume_source_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::VsphereVirtualDiskVolumeSource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WeightedPodAffinityTerm`](crate::api::core::v1::WeightedPodAffinityTerm).
+pub fn visit_api_core_v1_weighted_pod_affinity_term_mut<V>(v: &mut V, node: &mut crate::api::core::v1::WeightedPodAffinityTerm) where V: VisitMut + ?Sized {
+    v.visit_api_core_v1_pod_affinity_term_mut(&mut node.pod_affinity_term);
+}
+
+/// Visits a value of type [`WindowsSecurityContextOptions`](crate::api::core::v1::WindowsSecurityContextOptions), which does not contain values of other types that can be visited.
+pub fn visit_api_core_v1_windows_security_context_options_mut<V>(_v: &mut V, _node: &mut crate::api::core::v1::WindowsSecurityContextOptions) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Endpoint`](crate::api::discovery::v1::Endpoint).
+pub fn visit_api_discovery_v1_endpoint_mut<V>(v: &mut V, node: &mut crate::api::discovery::v1::Endpoint) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        v.visit_api_discovery_v1_endpoint_conditions_mut(it);
+    }
+    if let Some(it) = &mut node.hints {
+        v.visit_api_discovery_v1_endpoint_hints_mut(it);
+    }
+    if let Some(it) = &mut node.target_ref {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+}
+
+/// Visits a value of type [`EndpointConditions`](crate::api::discovery::v1::EndpointConditions), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_endpoint_conditions_mut<V>(_v: &mut V, _node: &mut crate::api::discovery::v1::EndpointConditions) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointHints`](crate::api::discovery::v1::EndpointHints).
+pub fn visit_api_discovery_v1_endpoint_hints_mut<V>(v: &mut V, node: &mut crate::api::discovery::v1::EndpointHints) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.for_zones {
+        for it in it {
+            v.visit_api_discovery_v1_for_zone_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`EndpointPort`](crate::api::discovery::v1::EndpointPort), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_endpoint_port_mut<V>(_v: &mut V, _node: &mut crate::api::discovery::v1::EndpointPort) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`EndpointSlice`](crate::api::discovery::v1::EndpointSlice).
+pub fn visit_api_discovery_v1_endpoint_slice_mut<V>(v: &mut V, node: &mut crate::api::discovery::v1::EndpointSlice) where V: VisitMut + ?Sized {
+    for it in &mut node.endpoints {
+        v.visit_api_discovery_v1_endpoint_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_discovery_v1_endpoint_port_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ForZone`](crate::api::discovery::v1::ForZone), which does not contain values of other types that can be visited.
+pub fn visit_api_discovery_v1_for_zone_mut<V>(_v: &mut V, _node: &mut crate::api::discovery::v1::ForZone) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Event`](crate::api::events::v1::Event).
+pub fn visit_api_events_v1_event_mut<V>(v: &mut V, node: &mut crate::api::events::v1::Event) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.deprecated_first_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.deprecated_last_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.deprecated_source {
+        v.visit_api_core_v1_event_source_mut(it);
+    }
+    if let Some(it) = &mut node.event_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.regarding {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.related {
+        v.visit_api_core_v1_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.series {
+        v.visit_api_events_v1_event_series_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`EventSeries`](crate::api::events::v1::EventSeries).
+pub fn visit_api_events_v1_event_series_mut<V>(v: &mut V, node: &mut crate::api::events::v1::EventSeries) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(&mut node.last_observed_time);
+}
+
+/// Visits a value of type [`ExemptPriorityLevelConfiguration`](crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_exempt_priority_level_configuration_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`FlowDistinguisherMethod`](crate::api::flowcontrol::v1::FlowDistinguisherMethod), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_flow_distinguisher_method_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::FlowDistinguisherMethod) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`FlowSchema`](crate::api::flowcontrol::v1::FlowSchema).
+pub fn visit_api_flowcontrol_v1_flow_schema_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::FlowSchema) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_flowcontrol_v1_flow_schema_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_flowcontrol_v1_flow_schema_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaCondition`](crate::api::flowcontrol::v1::FlowSchemaCondition).
+pub fn visit_api_flowcontrol_v1_flow_schema_condition_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::FlowSchemaCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaSpec`](crate::api::flowcontrol::v1::FlowSchemaSpec).
+pub fn visit_api_flowcontrol_v1_flow_schema_spec_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::FlowSchemaSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.distinguisher_method {
+        v.visit_api_flowcontrol_v1_flow_distinguisher_method_mut(it);
+    }
+    v.visit_api_flowcontrol_v1_priority_level_configuration_reference_mut(&mut node.priority_level_configuration);
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_policy_rules_with_subjects_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`FlowSchemaStatus`](crate::api::flowcontrol::v1::FlowSchemaStatus).
+pub fn visit_api_flowcontrol_v1_flow_schema_status_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::FlowSchemaStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_flowcontrol_v1_flow_schema_condition_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`GroupSubject`](crate::api::flowcontrol::v1::GroupSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_group_subject_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::GroupSubject) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LimitResponse`](crate::api::flowcontrol::v1::LimitResponse).
+pub fn visit_api_flowcontrol_v1_limit_response_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::LimitResponse) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.queuing {
+        v.visit_api_flowcontrol_v1_queuing_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`LimitedPriorityLevelConfiguration`](crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration).
+pub fn visit_api_flowcontrol_v1_limited_priority_level_configuration_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.limit_response {
+        v.visit_api_flowcontrol_v1_limit_response_mut(it);
+    }
+}
+
+/// Visits a value of type [`NonResourcePolicyRule`](crate::api::flowcontrol::v1::NonResourcePolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_non_resource_policy_rule_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::NonResourcePolicyRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PolicyRulesWithSubjects`](crate::api::flowcontrol::v1::PolicyRulesWithSubjects).
+pub fn visit_api_flowcontrol_v1_policy_rules_with_subjects_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::PolicyRulesWithSubjects) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.non_resource_rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_non_resource_policy_rule_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.resource_rules {
+        for it in it {
+            v.visit_api_flowcontrol_v1_resource_policy_rule_mut(it);
+        }
+    }
+    for it in &mut node.subjects {
+        v.visit_api_flowcontrol_v1_subject_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfiguration`](crate::api::flowcontrol::v1::PriorityLevelConfiguration).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfiguration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationCondition`](crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_condition_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`PriorityLevelConfigurationReference`](crate::api::flowcontrol::v1::PriorityLevelConfigurationReference), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_reference_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationSpec`](crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_spec_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.exempt {
+        v.visit_api_flowcontrol_v1_exempt_priority_level_configuration_mut(it);
+    }
+    if let Some(it) = &mut node.limited {
+        v.visit_api_flowcontrol_v1_limited_priority_level_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PriorityLevelConfigurationStatus`](crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus).
+pub fn visit_api_flowcontrol_v1_priority_level_configuration_status_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_flowcontrol_v1_priority_level_configuration_condition_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`QueuingConfiguration`](crate::api::flowcontrol::v1::QueuingConfiguration), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_queuing_configuration_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::QueuingConfiguration) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ResourcePolicyRule`](crate::api::flowcontrol::v1::ResourcePolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_resource_policy_rule_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::ResourcePolicyRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ServiceAccountSubject`](crate::api::flowcontrol::v1::ServiceAccountSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_service_account_subject_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::ServiceAccountSubject) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Subject`](crate::api::flowcontrol::v1::Subject).
+pub fn visit_api_flowcontrol_v1_subject_mut<V>(v: &mut V, node: &mut crate::api::flowcontrol::v1::Subject) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.group {
+        v.visit_api_flowcontrol_v1_group_subject_mut(it);
+    }
+    if let Some(it) = &mut node.service_account {
+        v.visit_api_flowcontrol_v1_service_account_subject_mut(it);
+    }
+    if let Some(it) = &mut node.user {
+        v.visit_api_flowcontrol_v1_user_subject_mut(it);
+    }
+}
+
+/// Visits a value of type [`UserSubject`](crate::api::flowcontrol::v1::UserSubject), which does not contain values of other types that can be visited.
+pub fn visit_api_flowcontrol_v1_user_subject_mut<V>(_v: &mut V, _node: &mut crate::api::flowcontrol::v1::UserSubject) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`HTTPIngressPath`](crate::api::networking::v1::HTTPIngressPath).
+pub fn visit_api_networking_v1_http_ingress_path_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::HTTPIngressPath) where V: VisitMut + ?Sized {
+    v.visit_api_networking_v1_ingress_backend_mut(&mut node.backend);
+}
+
+/// Visits the values contained in a value of type [`HTTPIngressRuleValue`](crate::api::networking::v1::HTTPIngressRuleValue).
+pub fn visit_api_networking_v1_http_ingress_rule_value_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::HTTPIngressRuleValue) where V: VisitMut + ?Sized {
+    for it in &mut node.paths {
+        v.visit_api_networking_v1_http_ingress_path_mut(it);
+    }
+}
+
+/// Visits a value of type [`IPBlock`](crate::api::networking::v1::IPBlock), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ip_block_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1::IPBlock) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Ingress`](crate::api::networking::v1::Ingress).
+pub fn visit_api_networking_v1_ingress_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::Ingress) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_networking_v1_ingress_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_networking_v1_ingress_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressBackend`](crate::api::networking::v1::IngressBackend).
+pub fn visit_api_networking_v1_ingress_backend_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressBackend) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.resource {
+        v.visit_api_core_v1_typed_local_object_reference_mut(it);
+    }
+    if let Some(it) = &mut node.service {
+        v.visit_api_networking_v1_ingress_service_backend_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressClass`](crate::api::networking::v1::IngressClass).
+pub fn visit_api_networking_v1_ingress_class_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_networking_v1_ingress_class_spec_mut(it);
+    }
+}
+
+/// Visits a value of type [`IngressClassParametersReference`](crate::api::networking::v1::IngressClassParametersReference), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_class_parameters_reference_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1::IngressClassParametersReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IngressClassSpec`](crate::api::networking::v1::IngressClassSpec).
+pub fn visit_api_networking_v1_ingress_class_spec_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressClassSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.parameters {
+        v.visit_api_networking_v1_ingress_class_parameters_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressLoadBalancerIngress`](crate::api::networking::v1::IngressLoadBalancerIngress).
+pub fn visit_api_networking_v1_ingress_load_balancer_ingress_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressLoadBalancerIngress) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_networking_v1_ingress_port_status_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressLoadBalancerStatus`](crate::api::networking::v1::IngressLoadBalancerStatus).
+pub fn visit_api_networking_v1_ingress_load_balancer_status_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressLoadBalancerStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ingress {
+        for it in it {
+            v.visit_api_networking_v1_ingress_load_balancer_ingress_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`IngressPortStatus`](crate::api::networking::v1::IngressPortStatus), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_port_status_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1::IngressPortStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IngressRule`](crate::api::networking::v1::IngressRule).
+pub fn visit_api_networking_v1_ingress_rule_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressRule) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.http {
+        v.visit_api_networking_v1_http_ingress_rule_value_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressServiceBackend`](crate::api::networking::v1::IngressServiceBackend).
+pub fn visit_api_networking_v1_ingress_service_backend_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressServiceBackend) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.port {
+        v.visit_api_networking_v1_service_backend_port_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressSpec`](crate::api::networking::v1::IngressSpec).
+pub fn visit_api_networking_v1_ingress_spec_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.default_backend {
+        v.visit_api_networking_v1_ingress_backend_mut(it);
+    }
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_networking_v1_ingress_rule_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.tls {
+        for it in it {
+            v.visit_api_networking_v1_ingress_tls_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`IngressStatus`](crate::api::networking::v1::IngressStatus).
+pub fn visit_api_networking_v1_ingress_status_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::IngressStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.load_balancer {
+        v.visit_api_networking_v1_ingress_load_balancer_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`IngressTLS`](crate::api::networking::v1::IngressTLS), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_ingress_tls_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1::IngressTLS) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicy`](crate::api::networking::v1::NetworkPolicy).
+pub fn visit_api_networking_v1_network_policy_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicy) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_networking_v1_network_policy_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyEgressRule`](crate::api::networking::v1::NetworkPolicyEgressRule).
+pub fn visit_api_networking_v1_network_policy_egress_rule_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicyEgressRule) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_port_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.to {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_peer_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyIngressRule`](crate::api::networking::v1::NetworkPolicyIngressRule).
+pub fn visit_api_networking_v1_network_policy_ingress_rule_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicyIngressRule) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.from {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_peer_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.ports {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_port_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyPeer`](crate::api::networking::v1::NetworkPolicyPeer).
+pub fn visit_api_networking_v1_network_policy_peer_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicyPeer) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.ip_block {
+        v.visit_api_networking_v1_ip_block_mut(it);
+    }
+    if let Some(it) = &mut node.namespace_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+    if let Some(it) = &mut node.pod_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicyPort`](crate::api::networking::v1::NetworkPolicyPort).
+pub fn visit_api_networking_v1_network_policy_port_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicyPort) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.port {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`NetworkPolicySpec`](crate::api::networking::v1::NetworkPolicySpec).
+pub fn visit_api_networking_v1_network_policy_spec_mut<V>(v: &mut V, node: &mut crate::api::networking::v1::NetworkPolicySpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.egress {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_egress_rule_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.ingress {
+        for it in it {
+            v.visit_api_networking_v1_network_policy_ingress_rule_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.pod_selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`ServiceBackendPort`](crate::api::networking::v1::ServiceBackendPort), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1_service_backend_port_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1::ServiceBackendPort) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`IPAddress`](crate::api::networking::v1beta1::IPAddress).
+pub fn visit_api_networking_v1beta1_ip_address_mut<V>(v: &mut V, node: &mut crate::api::networking::v1beta1::IPAddress) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_networking_v1beta1_ip_address_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`IPAddressSpec`](crate::api::networking::v1beta1::IPAddressSpec).
+pub fn visit_api_networking_v1beta1_ip_address_spec_mut<V>(v: &mut V, node: &mut crate::api::networking::v1beta1::IPAddressSpec) where V: VisitMut + ?Sized {
+    v.visit_api_networking_v1beta1_parent_reference_mut(&mut node.parent_ref);
+}
+
+/// Visits a value of type [`ParentReference`](crate::api::networking::v1beta1::ParentReference), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1beta1_parent_reference_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1beta1::ParentReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServiceCIDR`](crate::api::networking::v1beta1::ServiceCIDR).
+pub fn visit_api_networking_v1beta1_service_cidr_mut<V>(v: &mut V, node: &mut crate::api::networking::v1beta1::ServiceCIDR) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_networking_v1beta1_service_cidr_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_networking_v1beta1_service_cidr_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`ServiceCIDRSpec`](crate::api::networking::v1beta1::ServiceCIDRSpec), which does not contain values of other types that can be visited.
+pub fn visit_api_networking_v1beta1_service_cidr_spec_mut<V>(_v: &mut V, _node: &mut crate::api::networking::v1beta1::ServiceCIDRSpec) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ServiceCIDRStatus`](crate::api::networking::v1beta1::ServiceCIDRStatus).
+pub fn visit_api_networking_v1beta1_service_cidr_status_mut<V>(v: &mut V, node: &mut crate::api::networking::v1beta1::ServiceCIDRStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Overhead`](crate::api::node::v1::Overhead).
+pub fn visit_api_node_v1_overhead_mut<V>(v: &mut V, node: &mut crate::api::node::v1::Overhead) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.pod_fixed {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RuntimeClass`](crate::api::node::v1::RuntimeClass).
+pub fn visit_api_node_v1_runtime_class_mut<V>(v: &mut V, node: &mut crate::api::node::v1::RuntimeClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.overhead {
+        v.visit_api_node_v1_overhead_mut(it);
+    }
+    if let Some(it) = &mut node.scheduling {
+        v.visit_api_node_v1_scheduling_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Scheduling`](crate::api::node::v1::Scheduling).
+pub fn visit_api_node_v1_scheduling_mut<V>(v: &mut V, node: &mut crate::api::node::v1::Scheduling) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.tolerations {
+        for it in it {
+            v.visit_api_core_v1_toleration_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`Eviction`](crate::api::policy::v1::Eviction).
+pub fn visit_api_policy_v1_eviction_mut<V>(v: &mut V, node: &mut crate::api::policy::v1::Eviction) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.delete_options {
+        v.visit_apimachinery_pkg_apis_meta_v1_delete_options_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudget`](crate::api::policy::v1::PodDisruptionBudget).
+pub fn visit_api_policy_v1_pod_disruption_budget_mut<V>(v: &mut V, node: &mut crate::api::policy::v1::PodDisruptionBudget) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_policy_v1_pod_disruption_budget_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_policy_v1_pod_disruption_budget_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudgetSpec`](crate::api::policy::v1::PodDisruptionBudgetSpec).
+pub fn visit_api_policy_v1_pod_disruption_budget_spec_mut<V>(v: &mut V, node: &mut crate::api::policy::v1::PodDisruptionBudgetSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.max_unavailable {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+    if let Some(it) = &mut node.min_available {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(it);
+    }
+    if let Some(it) = &mut node.selector {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`PodDisruptionBudgetStatus`](crate::api::policy::v1::PodDisruptionBudgetStatus).
+pub fn visit_api_policy_v1_pod_disruption_budget_status_mut<V>(v: &mut V, node: &mut crate::api::policy::v1::PodDisruptionBudgetStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.disrupted_pods {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`AggregationRule`](crate::api::rbac::v1::AggregationRule).
+pub fn visit_api_rbac_v1_aggregation_rule_mut<V>(v: &mut V, node: &mut crate::api::rbac::v1::AggregationRule) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.cluster_role_selectors {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterRole`](crate::api::rbac::v1::ClusterRole).
+pub fn visit_api_rbac_v1_cluster_role_mut<V>(v: &mut V, node: &mut crate::api::rbac::v1::ClusterRole) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.aggregation_rule {
+        v.visit_api_rbac_v1_aggregation_rule_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_rbac_v1_policy_rule_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ClusterRoleBinding`](crate::api::rbac::v1::ClusterRoleBinding).
+pub fn visit_api_rbac_v1_cluster_role_binding_mut<V>(v: &mut V, node: &mut crate::api::rbac::v1::ClusterRoleBinding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_rbac_v1_role_ref_mut(&mut node.role_ref);
+    if let Some(it) = &mut node.subjects {
+        for it in it {
+            v.visit_api_rbac_v1_subject_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`PolicyRule`](crate::api::rbac::v1::PolicyRule), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_policy_rule_mut<V>(_v: &mut V, _node: &mut crate::api::rbac::v1::PolicyRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Role`](crate::api::rbac::v1::Role).
+pub fn visit_api_rbac_v1_role_mut<V>(v: &mut V, node: &mut crate::api::rbac::v1::Role) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.rules {
+        for it in it {
+            v.visit_api_rbac_v1_policy_rule_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`RoleBinding`](crate::api::rbac::v1::RoleBinding).
+pub fn visit_api_rbac_v1_role_binding_mut<V>(v: &mut V, node: &mut crate::api::rbac::v1::RoleBinding) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_rbac_v1_role_ref_mut(&mut node.role_ref);
+    if let Some(it) = &mut node.subjects {
+        for it in it {
+            v.visit_api_rbac_v1_subject_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`RoleRef`](crate::api::rbac::v1::RoleRef), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_role_ref_mut<V>(_v: &mut V, _node: &mut crate::api::rbac::v1::RoleRef) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`Subject`](crate::api::rbac::v1::Subject), which does not contain values of other types that can be visited.
+pub fn visit_api_rbac_v1_subject_mut<V>(_v: &mut V, _node: &mut crate::api::rbac::v1::Subject) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1alpha3::AllocatedDeviceStatus).
+pub fn visit_api_resource_v1alpha3_allocated_device_status_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::AllocatedDeviceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension_mut(it);
+    }
+    if let Some(it) = &mut node.network_data {
+        v.visit_api_resource_v1alpha3_network_device_data_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`AllocationResult`](crate::api::resource::v1alpha3::AllocationResult).
+pub fn visit_api_resource_v1alpha3_allocation_result_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::AllocationResult) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        v.visit_api_resource_v1alpha3_device_allocation_result_mut(it);
+    }
+    if let Some(it) = &mut node.node_selector {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`BasicDevice`](crate::api::resource::v1alpha3::BasicDevice).
+pub fn visit_api_resource_v1alpha3_basic_device_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::BasicDevice) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.attributes {
+        for it in it.values_mut() {
+            v.visit_api_resource_v1alpha3_device_attribute_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.capacity {
+        for it in it.values_mut() {
+            v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1alpha3::CELDeviceSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_cel_device_selector_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::CELDeviceSelector) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Device`](crate::api::resource::v1alpha3::Device).
+pub fn visit_api_resource_v1alpha3_device_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::Device) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.basic {
+        v.visit_api_resource_v1alpha3_basic_device_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1alpha3::DeviceAllocationConfiguration).
+pub fn visit_api_resource_v1alpha3_device_allocation_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceAllocationConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationResult`](crate::api::resource::v1alpha3::DeviceAllocationResult).
+pub fn visit_api_resource_v1alpha3_device_allocation_result_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceAllocationResult) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_allocation_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.results {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_request_allocation_result_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1alpha3::DeviceAttribute), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_attribute_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::DeviceAttribute) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceClaim`](crate::api::resource::v1alpha3::DeviceClaim).
+pub fn visit_api_resource_v1alpha3_device_claim_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceClaim) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_claim_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.constraints {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_constraint_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.requests {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_request_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1alpha3::DeviceClaimConfiguration).
+pub fn visit_api_resource_v1alpha3_device_claim_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceClaimConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClass`](crate::api::resource::v1alpha3::DeviceClass).
+pub fn visit_api_resource_v1alpha3_device_class_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1alpha3_device_class_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`DeviceClassConfiguration`](crate::api::resource::v1alpha3::DeviceClassConfiguration).
+pub fn visit_api_resource_v1alpha3_device_class_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceClassConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClassSpec`](crate::api::resource::v1alpha3::DeviceClassSpec).
+pub fn visit_api_resource_v1alpha3_device_class_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceClassSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_class_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.selectors {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_selector_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1alpha3::DeviceConstraint), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_constraint_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::DeviceConstraint) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceRequest`](crate::api::resource::v1alpha3::DeviceRequest).
+pub fn visit_api_resource_v1alpha3_device_request_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceRequest) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selectors {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_selector_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1alpha3::DeviceRequestAllocationResult), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_device_request_allocation_result_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::DeviceRequestAllocationResult) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceSelector`](crate::api::resource::v1alpha3::DeviceSelector).
+pub fn visit_api_resource_v1alpha3_device_selector_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::DeviceSelector) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.cel {
+        v.visit_api_resource_v1alpha3_cel_device_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1alpha3::NetworkDeviceData), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_network_device_data_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::NetworkDeviceData) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1alpha3::OpaqueDeviceConfiguration).
+pub fn visit_api_resource_v1alpha3_opaque_device_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::OpaqueDeviceConfiguration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_runtime_raw_extension_mut(&mut node.parameters);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaim`](crate::api::resource::v1alpha3::ResourceClaim).
+pub fn visit_api_resource_v1alpha3_resource_claim_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceClaim) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1alpha3_resource_claim_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_resource_v1alpha3_resource_claim_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1alpha3::ResourceClaimConsumerReference), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_resource_claim_consumer_reference_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::ResourceClaimConsumerReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimSpec`](crate::api::resource::v1alpha3::ResourceClaimSpec).
+pub fn visit_api_resource_v1alpha3_resource_claim_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceClaimSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        v.visit_api_resource_v1alpha3_device_claim_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimStatus`](crate::api::resource::v1alpha3::ResourceClaimStatus).
+pub fn visit_api_resource_v1alpha3_resource_claim_status_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceClaimStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allocation {
+        v.visit_api_resource_v1alpha3_allocation_result_mut(it);
+    }
+    if let Some(it) = &mut node.devices {
+        for it in it {
+            v.visit_api_resource_v1alpha3_allocated_device_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.reserved_for {
+        for it in it {
+            v.visit_api_resource_v1alpha3_resource_claim_consumer_reference_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplate`](crate::api::resource::v1alpha3::ResourceClaimTemplate).
+pub fn visit_api_resource_v1alpha3_resource_claim_template_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceClaimTemplate) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1alpha3_resource_claim_template_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1alpha3::ResourceClaimTemplateSpec).
+pub fn visit_api_resource_v1alpha3_resource_claim_template_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceClaimTemplateSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(it);
+    }
+    v.visit_api_resource_v1alpha3_resource_claim_spec_mut(&mut node.spec);
+}
+
+/// Visits a value of type [`ResourcePool`](crate::api::resource::v1alpha3::ResourcePool), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1alpha3_resource_pool_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1alpha3::ResourcePool) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceSlice`](crate::api::resource::v1alpha3::ResourceSlice).
+pub fn visit_api_resource_v1alpha3_resource_slice_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceSlice) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1alpha3_resource_slice_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceSliceSpec`](crate::api::resource::v1alpha3::ResourceSliceSpec).
+pub fn visit_api_resource_v1alpha3_resource_slice_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1alpha3::ResourceSliceSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        for it in it {
+            v.visit_api_resource_v1alpha3_device_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.node_selector {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+    v.visit_api_resource_v1alpha3_resource_pool_mut(&mut node.pool);
+}
+
+/// Visits the values contained in a value of type [`AllocatedDeviceStatus`](crate::api::resource::v1beta1::AllocatedDeviceStatus).
+pub fn visit_api_resource_v1beta1_allocated_device_status_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::AllocatedDeviceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.data {
+        v.visit_apimachinery_pkg_runtime_raw_extension_mut(it);
+    }
+    if let Some(it) = &mut node.network_data {
+        v.visit_api_resource_v1beta1_network_device_data_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`AllocationResult`](crate::api::resource::v1beta1::AllocationResult).
+pub fn visit_api_resource_v1beta1_allocation_result_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::AllocationResult) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        v.visit_api_resource_v1beta1_device_allocation_result_mut(it);
+    }
+    if let Some(it) = &mut node.node_selector {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`BasicDevice`](crate::api::resource::v1beta1::BasicDevice).
+pub fn visit_api_resource_v1beta1_basic_device_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::BasicDevice) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.attributes {
+        for it in it.values_mut() {
+            v.visit_api_resource_v1beta1_device_attribute_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.capacity {
+        for it in it.values_mut() {
+            v.visit_api_resource_v1beta1_device_capacity_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CELDeviceSelector`](crate::api::resource::v1beta1::CELDeviceSelector), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_cel_device_selector_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::CELDeviceSelector) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Device`](crate::api::resource::v1beta1::Device).
+pub fn visit_api_resource_v1beta1_device_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::Device) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.basic {
+        v.visit_api_resource_v1beta1_basic_device_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationConfiguration`](crate::api::resource::v1beta1::DeviceAllocationConfiguration).
+pub fn visit_api_resource_v1beta1_device_allocation_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceAllocationConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceAllocationResult`](crate::api::resource::v1beta1::DeviceAllocationResult).
+pub fn visit_api_resource_v1beta1_device_allocation_result_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceAllocationResult) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_allocation_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.results {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_request_allocation_result_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceAttribute`](crate::api::resource::v1beta1::DeviceAttribute), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_attribute_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::DeviceAttribute) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceCapacity`](crate::api::resource::v1beta1::DeviceCapacity).
+pub fn visit_api_resource_v1beta1_device_capacity_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceCapacity) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_api_resource_quantity_mut(&mut node.value);
+}
+
+/// Visits the values contained in a value of type [`DeviceClaim`](crate::api::resource::v1beta1::DeviceClaim).
+pub fn visit_api_resource_v1beta1_device_claim_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceClaim) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_claim_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.constraints {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_constraint_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.requests {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_request_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClaimConfiguration`](crate::api::resource::v1beta1::DeviceClaimConfiguration).
+pub fn visit_api_resource_v1beta1_device_claim_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceClaimConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClass`](crate::api::resource::v1beta1::DeviceClass).
+pub fn visit_api_resource_v1beta1_device_class_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1beta1_device_class_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`DeviceClassConfiguration`](crate::api::resource::v1beta1::DeviceClassConfiguration).
+pub fn visit_api_resource_v1beta1_device_class_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceClassConfiguration) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.opaque {
+        v.visit_api_resource_v1beta1_opaque_device_configuration_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`DeviceClassSpec`](crate::api::resource::v1beta1::DeviceClassSpec).
+pub fn visit_api_resource_v1beta1_device_class_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceClassSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.config {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_class_configuration_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.selectors {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_selector_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceConstraint`](crate::api::resource::v1beta1::DeviceConstraint), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_constraint_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::DeviceConstraint) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceRequest`](crate::api::resource::v1beta1::DeviceRequest).
+pub fn visit_api_resource_v1beta1_device_request_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceRequest) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.selectors {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_selector_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`DeviceRequestAllocationResult`](crate::api::resource::v1beta1::DeviceRequestAllocationResult), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_device_request_allocation_result_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::DeviceRequestAllocationResult) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`DeviceSelector`](crate::api::resource::v1beta1::DeviceSelector).
+pub fn visit_api_resource_v1beta1_device_selector_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::DeviceSelector) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.cel {
+        v.visit_api_resource_v1beta1_cel_device_selector_mut(it);
+    }
+}
+
+/// Visits a value of type [`NetworkDeviceData`](crate::api::resource::v1beta1::NetworkDeviceData), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_network_device_data_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::NetworkDeviceData) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`OpaqueDeviceConfiguration`](crate::api::resource::v1beta1::OpaqueDeviceConfiguration).
+pub fn visit_api_resource_v1beta1_opaque_device_configuration_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::OpaqueDeviceConfiguration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_runtime_raw_extension_mut(&mut node.parameters);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaim`](crate::api::resource::v1beta1::ResourceClaim).
+pub fn visit_api_resource_v1beta1_resource_claim_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceClaim) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1beta1_resource_claim_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_resource_v1beta1_resource_claim_status_mut(it);
+    }
+}
+
+/// Visits a value of type [`ResourceClaimConsumerReference`](crate::api::resource::v1beta1::ResourceClaimConsumerReference), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_resource_claim_consumer_reference_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::ResourceClaimConsumerReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimSpec`](crate::api::resource::v1beta1::ResourceClaimSpec).
+pub fn visit_api_resource_v1beta1_resource_claim_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceClaimSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        v.visit_api_resource_v1beta1_device_claim_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimStatus`](crate::api::resource::v1beta1::ResourceClaimStatus).
+pub fn visit_api_resource_v1beta1_resource_claim_status_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceClaimStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allocation {
+        v.visit_api_resource_v1beta1_allocation_result_mut(it);
+    }
+    if let Some(it) = &mut node.devices {
+        for it in it {
+            v.visit_api_resource_v1beta1_allocated_device_status_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.reserved_for {
+        for it in it {
+            v.visit_api_resource_v1beta1_resource_claim_consumer_reference_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplate`](crate::api::resource::v1beta1::ResourceClaimTemplate).
+pub fn visit_api_resource_v1beta1_resource_claim_template_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceClaimTemplate) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1beta1_resource_claim_template_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceClaimTemplateSpec`](crate::api::resource::v1beta1::ResourceClaimTemplateSpec).
+pub fn visit_api_resource_v1beta1_resource_claim_template_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceClaimTemplateSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.metadata {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(it);
+    }
+    v.visit_api_resource_v1beta1_resource_claim_spec_mut(&mut node.spec);
+}
+
+/// Visits a value of type [`ResourcePool`](crate::api::resource::v1beta1::ResourcePool), which does not contain values of other types that can be visited.
+pub fn visit_api_resource_v1beta1_resource_pool_mut<V>(_v: &mut V, _node: &mut crate::api::resource::v1beta1::ResourcePool) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ResourceSlice`](crate::api::resource::v1beta1::ResourceSlice).
+pub fn visit_api_resource_v1beta1_resource_slice_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceSlice) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_resource_v1beta1_resource_slice_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`ResourceSliceSpec`](crate::api::resource::v1beta1::ResourceSliceSpec).
+pub fn visit_api_resource_v1beta1_resource_slice_spec_mut<V>(v: &mut V, node: &mut crate::api::resource::v1beta1::ResourceSliceSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.devices {
+        for it in it {
+            v.visit_api_resource_v1beta1_device_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.node_selector {
+        v.visit_api_core_v1_node_selector_mut(it);
+    }
+    v.visit_api_resource_v1beta1_resource_pool_mut(&mut node.pool);
+}
+
+/// Visits the values contained in a value of type [`PriorityClass`](crate::api::scheduling::v1::PriorityClass).
+pub fn visit_api_scheduling_v1_priority_class_mut<V>(v: &mut V, node: &mut crate::api::scheduling::v1::PriorityClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits the values contained in a value of type [`CSIDriver`](crate::api::storage::v1::CSIDriver).
+pub fn visit_api_storage_v1_csi_driver_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSIDriver) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_storage_v1_csi_driver_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`CSIDriverSpec`](crate::api::storage::v1::CSIDriverSpec).
+pub fn visit_api_storage_v1_csi_driver_spec_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSIDriverSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.token_requests {
+        for it in it {
+            v.visit_api_storage_v1_token_request_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`CSINode`](crate::api::storage::v1::CSINode).
+pub fn visit_api_storage_v1_csi_node_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSINode) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_storage_v1_csi_node_spec_mut(&mut node.spec);
+}
+
+/// Visits the values contained in a value of type [`CSINodeDriver`](crate::api::storage::v1::CSINodeDriver).
+pub fn visit_api_storage_v1_csi_node_driver_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSINodeDriver) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allocatable {
+        v.visit_api_storage_v1_volume_node_resources_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSINodeSpec`](crate::api::storage::v1::CSINodeSpec).
+pub fn visit_api_storage_v1_csi_node_spec_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSINodeSpec) where V: VisitMut + ?Sized {
+    for it in &mut node.drivers {
+        v.visit_api_storage_v1_csi_node_driver_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CSIStorageCapacity`](crate::api::storage::v1::CSIStorageCapacity).
+pub fn visit_api_storage_v1_csi_storage_capacity_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::CSIStorageCapacity) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.capacity {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+    if let Some(it) = &mut node.maximum_volume_size {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.node_topology {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageClass`](crate::api::storage::v1::StorageClass).
+pub fn visit_api_storage_v1_storage_class_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::StorageClass) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.allowed_topologies {
+        for it in it {
+            v.visit_api_core_v1_topology_selector_term_mut(it);
+        }
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`TokenRequest`](crate::api::storage::v1::TokenRequest), which does not contain values of other types that can be visited.
+pub fn visit_api_storage_v1_token_request_mut<V>(_v: &mut V, _node: &mut crate::api::storage::v1::TokenRequest) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachment`](crate::api::storage::v1::VolumeAttachment).
+pub fn visit_api_storage_v1_volume_attachment_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::VolumeAttachment) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_api_storage_v1_volume_attachment_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_api_storage_v1_volume_attachment_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentSource`](crate::api::storage::v1::VolumeAttachmentSource).
+pub fn visit_api_storage_v1_volume_attachment_source_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::VolumeAttachmentSource) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.inline_volume_spec {
+        v.visit_api_core_v1_persistent_volume_spec_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentSpec`](crate::api::storage::v1::VolumeAttachmentSpec).
+pub fn visit_api_storage_v1_volume_attachment_spec_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::VolumeAttachmentSpec) where V: VisitMut + ?Sized {
+    v.visit_api_storage_v1_volume_attachment_source_mut(&mut node.source);
+}
+
+/// Visits the values contained in a value of type [`VolumeAttachmentStatus`](crate::api::storage::v1::VolumeAttachmentStatus).
+pub fn visit_api_storage_v1_volume_attachment_status_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::VolumeAttachmentStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.attach_error {
+        v.visit_api_storage_v1_volume_error_mut(it);
+    }
+    if let Some(it) = &mut node.detach_error {
+        v.visit_api_storage_v1_volume_error_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`VolumeError`](crate::api::storage::v1::VolumeError).
+pub fn visit_api_storage_v1_volume_error_mut<V>(v: &mut V, node: &mut crate::api::storage::v1::VolumeError) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`VolumeNodeResources`](crate::api::storage::v1::VolumeNodeResources), which does not contain values of other types that can be visited.
+pub fn visit_api_storage_v1_volume_node_resources_mut<V>(_v: &mut V, _node: &mut crate::api::storage::v1::VolumeNodeResources) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`VolumeAttributesClass`](crate::api::storage::v1alpha1::VolumeAttributesClass).
+pub fn visit_api_storage_v1alpha1_volume_attributes_class_mut<V>(v: &mut V, node: &mut crate::api::storage::v1alpha1::VolumeAttributesClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits the values contained in a value of type [`VolumeAttributesClass`](crate::api::storage::v1beta1::VolumeAttributesClass).
+pub fn visit_api_storage_v1beta1_volume_attributes_class_mut<V>(v: &mut V, node: &mut crate::api::storage::v1beta1::VolumeAttributesClass) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`GroupVersionResource`](crate::api::storagemigration::v1alpha1::GroupVersionResource), which does not contain values of other types that can be visited.
+pub fn visit_api_storagemigration_v1alpha1_group_version_resource_mut<V>(_v: &mut V, _node: &mut crate::api::storagemigration::v1alpha1::GroupVersionResource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`MigrationCondition`](crate::api::storagemigration::v1alpha1::MigrationCondition).
+pub fn visit_api_storagemigration_v1alpha1_migration_condition_mut<V>(v: &mut V, node: &mut crate::api::storagemigration::v1alpha1::MigrationCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_update_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigration`](crate::api::storagemigration::v1alpha1::StorageVersionMigration).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration_mut<V>(v: &mut V, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigration) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigrationSpec`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration_spec_mut<V>(v: &mut V, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec) where V: VisitMut + ?Sized {
+    v.visit_api_storagemigration_v1alpha1_group_version_resource_mut(&mut node.resource);
+}
+
+/// Visits the values contained in a value of type [`StorageVersionMigrationStatus`](crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus).
+pub fn visit_api_storagemigration_v1alpha1_storage_version_migration_status_mut<V>(v: &mut V, node: &mut crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_api_storagemigration_v1alpha1_migration_condition_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`CustomResourceColumnDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.webhook {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec_mut(&mut node.spec);
+    if let Some(it) = &mut node.status {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionCondition`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`CustomResourceDefinitionNames`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionSpec`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conversion {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion_mut(it);
+    }
+    v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut(&mut node.names);
+    for it in &mut node.versions {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.accepted_names {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut(it);
+    }
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceDefinitionVersion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.additional_printer_columns {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.schema {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation_mut(it);
+    }
+    if let Some(it) = &mut node.selectable_fields {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.subresources {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources_mut(it);
+    }
+}
+
+/// Visits a value of type [`CustomResourceSubresourceScale`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`CustomResourceSubresourceStatus`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`CustomResourceSubresources`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.scale {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`CustomResourceValidation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.open_api_v3_schema {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+    }
+}
+
+/// Visits a value of type [`ExternalDocumentation`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`JSON`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaProps`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.additional_items {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut(it);
+    }
+    if let Some(it) = &mut node.additional_properties {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut(it);
+    }
+    if let Some(it) = &mut node.all_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.any_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.default {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(it);
+    }
+    if let Some(it) = &mut node.definitions {
+        for it in it.values_mut() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.dependencies {
+        for it in it.values_mut() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.enum_ {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.example {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(it);
+    }
+    if let Some(it) = &mut node.external_docs {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation_mut(it);
+    }
+    if let Some(it) = &mut node.items {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array_mut(it);
+    }
+    if let Some(it) = &mut node.not {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+    }
+    if let Some(it) = &mut node.one_of {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.pattern_properties {
+        for it in it.values_mut() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.properties {
+        for it in it.values_mut() {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.x_kubernetes_validations {
+        for it in it {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule_mut(it);
+        }
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray) where V: VisitMut + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray::Schemas(it) => {
+            for it in it {
+                v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+            }
+        },
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrBool`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool) where V: VisitMut + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool::Bool(_) => (),
+    }
+}
+
+/// Visits the values contained in a value of type [`JSONSchemaPropsOrStringArray`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray) where V: VisitMut + ?Sized {
+    match node {
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray::Schema(it) => {
+            v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(it);
+        },
+        crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray::Strings(_) => (),
+    }
+}
+
+/// Visits a value of type [`SelectableField`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ServiceReference`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ValidationRule`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule), which does not contain values of other types that can be visited.
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule_mut<V>(_v: &mut V, _node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`WebhookClientConfig`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.service {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`WebhookConversion`](crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion).
+pub fn visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion_mut<V>(v: &mut V, node: &mut crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.client_config {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config_mut(it);
+    }
+}
+
+/// Visits a value of type [`Quantity`](crate::apimachinery::pkg::api::resource::Quantity), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_api_resource_quantity_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::api::resource::Quantity) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIGroup`](crate::apimachinery::pkg::apis::meta::v1::APIGroup).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_group_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIGroup) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.preferred_version {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut(it);
+    }
+    if let Some(it) = &mut node.server_address_by_client_cidrs {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut(it);
+        }
+    }
+    for it in &mut node.versions {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIGroupList`](crate::apimachinery::pkg::apis::meta::v1::APIGroupList).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_group_list_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIGroupList) where V: VisitMut + ?Sized {
+    for it in &mut node.groups {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group_mut(it);
+    }
+}
+
+/// Visits a value of type [`APIResource`](crate::apimachinery::pkg::apis::meta::v1::APIResource), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_resource_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::APIResource) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIResourceList`](crate::apimachinery::pkg::apis::meta::v1::APIResourceList).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_resource_list_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIResourceList) where V: VisitMut + ?Sized {
+    for it in &mut node.resources {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIVersions`](crate::apimachinery::pkg::apis::meta::v1::APIVersions).
+pub fn visit_apimachinery_pkg_apis_meta_v1_api_versions_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::APIVersions) where V: VisitMut + ?Sized {
+    for it in &mut node.server_address_by_client_cidrs {
+        v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`Condition`](crate::apimachinery::pkg::apis::meta::v1::Condition).
+pub fn visit_apimachinery_pkg_apis_meta_v1_condition_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::Condition) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_time_mut(&mut node.last_transition_time);
+}
+
+/// Visits the values contained in a value of type [`DeleteOptions`](crate::apimachinery::pkg::apis::meta::v1::DeleteOptions).
+pub fn visit_apimachinery_pkg_apis_meta_v1_delete_options_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::DeleteOptions) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.preconditions {
+        v.visit_apimachinery_pkg_apis_meta_v1_preconditions_mut(it);
+    }
+}
+
+/// Visits a value of type [`FieldSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`FieldsV1`](crate::apimachinery::pkg::apis::meta::v1::FieldsV1), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_fields_v1_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::FieldsV1) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`GroupVersionForDiscovery`](crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`LabelSelector`](crate::apimachinery::pkg::apis::meta::v1::LabelSelector).
+pub fn visit_apimachinery_pkg_apis_meta_v1_label_selector_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::LabelSelector) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.match_expressions {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`LabelSelectorRequirement`](crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ListMeta`](crate::apimachinery::pkg::apis::meta::v1::ListMeta), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_list_meta_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::ListMeta) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ManagedFieldsEntry`](crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry).
+pub fn visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.fields_v1 {
+        v.visit_apimachinery_pkg_apis_meta_v1_fields_v1_mut(it);
+    }
+    if let Some(it) = &mut node.time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits a value of type [`MicroTime`](crate::apimachinery::pkg::apis::meta::v1::MicroTime), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_micro_time_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::MicroTime) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`ObjectMeta`](crate::apimachinery::pkg::apis::meta::v1::ObjectMeta).
+pub fn visit_apimachinery_pkg_apis_meta_v1_object_meta_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::ObjectMeta) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.creation_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.deletion_timestamp {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+    if let Some(it) = &mut node.managed_fields {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.owner_references {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_owner_reference_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`OwnerReference`](crate::apimachinery::pkg::apis::meta::v1::OwnerReference), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_owner_reference_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::OwnerReference) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`Preconditions`](crate::apimachinery::pkg::apis::meta::v1::Preconditions), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_preconditions_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::Preconditions) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`ServerAddressByClientCIDR`](crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`Status`](crate::apimachinery::pkg::apis::meta::v1::Status).
+pub fn visit_apimachinery_pkg_apis_meta_v1_status_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::Status) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.details {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_details_mut(it);
+    }
+    v.visit_apimachinery_pkg_apis_meta_v1_list_meta_mut(&mut node.metadata);
+}
+
+/// Visits a value of type [`StatusCause`](crate::apimachinery::pkg::apis::meta::v1::StatusCause), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_status_cause_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::StatusCause) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`StatusDetails`](crate::apimachinery::pkg::apis::meta::v1::StatusDetails).
+pub fn visit_apimachinery_pkg_apis_meta_v1_status_details_mut<V>(v: &mut V, node: &mut crate::apimachinery::pkg::apis::meta::v1::StatusDetails) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.causes {
+        for it in it {
+            v.visit_apimachinery_pkg_apis_meta_v1_status_cause_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`Time`](crate::apimachinery::pkg::apis::meta::v1::Time), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_apis_meta_v1_time_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::apis::meta::v1::Time) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`RawExtension`](crate::apimachinery::pkg::runtime::RawExtension), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_runtime_raw_extension_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::runtime::RawExtension) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`IntOrString`](crate::apimachinery::pkg::util::intstr::IntOrString), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_util_intstr_int_or_string_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::util::intstr::IntOrString) where V: VisitMut + ?Sized {
+}
+
+/// Visits a value of type [`Info`](crate::apimachinery::pkg::version::Info), which does not contain values of other types that can be visited.
+pub fn visit_apimachinery_pkg_version_info_mut<V>(_v: &mut V, _node: &mut crate::apimachinery::pkg::version::Info) where V: VisitMut + ?Sized {
+}
+
+/// Visits the values contained in a value of type [`APIService`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_mut<V>(v: &mut V, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) where V: VisitMut + ?Sized {
+    v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(&mut node.metadata);
+    if let Some(it) = &mut node.spec {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec_mut(it);
+    }
+    if let Some(it) = &mut node.status {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceCondition`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition_mut<V>(v: &mut V, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.last_transition_time {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceSpec`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec_mut<V>(v: &mut V, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.service {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference_mut(it);
+    }
+}
+
+/// Visits the values contained in a value of type [`APIServiceStatus`](crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus).
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status_mut<V>(v: &mut V, node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus) where V: VisitMut + ?Sized {
+    if let Some(it) = &mut node.conditions {
+        for it in it {
+            v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition_mut(it);
+        }
+    }
+}
+
+/// Visits a value of type [`ServiceReference`](crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference), which does not contain values of other types that can be visited.
+pub fn visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference_mut<V>(_v: &mut V, _node: &mut crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference) where V: VisitMut + ?Sized {
+}
+
+impl VisitableMut for crate::AnyResource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_any_resource_mut(self);
+    }
+}
+
+impl<T> VisitableMut for crate::List<T> where T: crate::ListableResource + VisitableMut {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_list_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::AuditAnnotation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_audit_annotation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ExpressionWarning {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_expression_warning_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::MatchCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_match_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::MatchResources {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_match_resources_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::MutatingWebhook {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_mutating_webhook_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::MutatingWebhookConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_mutating_webhook_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::NamedRuleWithOperations {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_named_rule_with_operations_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ParamKind {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_param_kind_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ParamRef {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_param_ref_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::RuleWithOperations {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_rule_with_operations_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ServiceReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_service_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::TypeChecking {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_type_checking_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingAdmissionPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBindingSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_binding_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingAdmissionPolicySpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_admission_policy_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingWebhook {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_webhook_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::ValidatingWebhookConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validating_webhook_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::Validation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_validation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::Variable {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_variable_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1::WebhookClientConfig {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1_webhook_client_config_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::ApplyConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_apply_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::JSONPatch {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_json_patch_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MatchCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_match_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MatchResources {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_match_resources_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBindingSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_binding_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicySpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutating_admission_policy_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::Mutation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_mutation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::NamedRuleWithOperations {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_named_rule_with_operations_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::ParamKind {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_param_kind_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::ParamRef {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_param_ref_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1alpha1::Variable {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1alpha1_variable_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::AuditAnnotation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_audit_annotation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ExpressionWarning {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_expression_warning_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::MatchCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_match_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::MatchResources {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_match_resources_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::NamedRuleWithOperations {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_named_rule_with_operations_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ParamKind {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_param_kind_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ParamRef {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_param_ref_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::TypeChecking {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_type_checking_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBindingSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_binding_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicySpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validating_admission_policy_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::Validation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_validation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::admissionregistration::v1beta1::Variable {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_admissionregistration_v1beta1_variable_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apiserverinternal::v1alpha1::ServerStorageVersion {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_server_storage_version_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apiserverinternal::v1alpha1::StorageVersion {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apiserverinternal::v1alpha1::StorageVersionCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apiserverinternal::v1alpha1::StorageVersionSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apiserverinternal::v1alpha1::StorageVersionStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apiserverinternal_v1alpha1_storage_version_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::ControllerRevision {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_controller_revision_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DaemonSet {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_daemon_set_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DaemonSetCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_daemon_set_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DaemonSetSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_daemon_set_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DaemonSetStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_daemon_set_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DaemonSetUpdateStrategy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_daemon_set_update_strategy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::Deployment {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_deployment_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DeploymentCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_deployment_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DeploymentSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_deployment_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DeploymentStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_deployment_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::DeploymentStrategy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_deployment_strategy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::ReplicaSet {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_replica_set_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::ReplicaSetCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_replica_set_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::ReplicaSetSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_replica_set_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::ReplicaSetStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_replica_set_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::RollingUpdateDaemonSet {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_rolling_update_daemon_set_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::RollingUpdateDeployment {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_rolling_update_deployment_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::RollingUpdateStatefulSetStrategy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_rolling_update_stateful_set_strategy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSet {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetOrdinals {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_ordinals_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_persistent_volume_claim_retention_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::apps::v1::StatefulSetUpdateStrategy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_apps_v1_stateful_set_update_strategy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::BoundObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_bound_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::SelfSubjectReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_self_subject_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::SelfSubjectReviewStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_self_subject_review_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenRequest {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_request_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenRequestSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_request_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenRequestStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_request_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenReviewSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_review_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::TokenReviewStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_token_review_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1::UserInfo {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1_user_info_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1beta1::SelfSubjectReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1beta1_self_subject_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authentication::v1beta1::SelfSubjectReviewStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authentication_v1beta1_self_subject_review_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::FieldSelectorAttributes {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_field_selector_attributes_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::LabelSelectorAttributes {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_label_selector_attributes_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::LocalSubjectAccessReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_local_subject_access_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::NonResourceAttributes {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_non_resource_attributes_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::NonResourceRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_non_resource_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::ResourceAttributes {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_resource_attributes_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::ResourceRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_resource_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SelfSubjectAccessReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_self_subject_access_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SelfSubjectAccessReviewSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_self_subject_access_review_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SelfSubjectRulesReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_self_subject_rules_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SelfSubjectRulesReviewSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_self_subject_rules_review_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SubjectAccessReview {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SubjectAccessReviewSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SubjectAccessReviewStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_subject_access_review_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::authorization::v1::SubjectRulesReviewStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_authorization_v1_subject_rules_review_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::CrossVersionObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_cross_version_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::HorizontalPodAutoscaler {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::HorizontalPodAutoscalerSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::HorizontalPodAutoscalerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_horizontal_pod_autoscaler_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::Scale {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_scale_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::ScaleSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_scale_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v1::ScaleStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v1_scale_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ContainerResourceMetricSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_container_resource_metric_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ContainerResourceMetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_container_resource_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::CrossVersionObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_cross_version_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ExternalMetricSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_external_metric_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ExternalMetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_external_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HPAScalingPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_hpa_scaling_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HPAScalingRules {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_hpa_scaling_rules_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HorizontalPodAutoscaler {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_behavior_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HorizontalPodAutoscalerCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HorizontalPodAutoscalerSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::HorizontalPodAutoscalerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_horizontal_pod_autoscaler_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::MetricIdentifier {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_metric_identifier_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::MetricSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_metric_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::MetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::MetricTarget {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_metric_target_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::MetricValueStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_metric_value_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ObjectMetricSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_object_metric_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ObjectMetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_object_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::PodsMetricSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_pods_metric_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::PodsMetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_pods_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ResourceMetricSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_resource_metric_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::autoscaling::v2::ResourceMetricStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_autoscaling_v2_resource_metric_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::CronJob {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_cron_job_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::CronJobSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_cron_job_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::CronJobStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_cron_job_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::Job {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_job_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::JobCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_job_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::JobSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_job_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::JobStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_job_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::JobTemplateSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_job_template_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::PodFailurePolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_on_exit_codes_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_on_pod_conditions_pattern_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::PodFailurePolicyRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_pod_failure_policy_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::SuccessPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_success_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::SuccessPolicyRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_success_policy_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::batch::v1::UncountedTerminatedPods {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_batch_v1_uncounted_terminated_pods_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1::CertificateSigningRequest {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1::CertificateSigningRequestCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1::CertificateSigningRequestSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1::CertificateSigningRequestStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1_certificate_signing_request_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1alpha1::ClusterTrustBundle {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1alpha1_cluster_trust_bundle_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::certificates::v1alpha1::ClusterTrustBundleSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_certificates_v1alpha1_cluster_trust_bundle_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::coordination::v1::Lease {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_coordination_v1_lease_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::coordination::v1::LeaseSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_coordination_v1_lease_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::coordination::v1alpha2::LeaseCandidate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_coordination_v1alpha2_lease_candidate_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::coordination::v1alpha2::LeaseCandidateSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_coordination_v1alpha2_lease_candidate_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AWSElasticBlockStoreVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_aws_elastic_block_store_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Affinity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_affinity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AppArmorProfile {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_app_armor_profile_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AttachedVolume {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_attached_volume_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AzureDiskVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_azure_disk_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AzureFilePersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_azure_file_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::AzureFileVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_azure_file_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Binding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CSIPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_csi_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CSIVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_csi_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Capabilities {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_capabilities_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CephFSPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_ceph_fs_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CephFSVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_ceph_fs_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CinderPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_cinder_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::CinderVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_cinder_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ClientIPConfig {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_client_ip_config_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ClusterTrustBundleProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_cluster_trust_bundle_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ComponentCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_component_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ComponentStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_component_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMap {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMapEnvSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_env_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMapKeySelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_key_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMapNodeConfigSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_node_config_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMapProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ConfigMapVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_config_map_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Container {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerImage {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_image_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerPort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerResizePolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_resize_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerState {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_state_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerStateRunning {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_state_running_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerStateTerminated {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_state_terminated_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerStateWaiting {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_state_waiting_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ContainerUser {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_container_user_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::DaemonEndpoint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_daemon_endpoint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::DownwardAPIProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_downward_api_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::DownwardAPIVolumeFile {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_downward_api_volume_file_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::DownwardAPIVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_downward_api_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EmptyDirVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_empty_dir_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EndpointAddress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_endpoint_address_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EndpointPort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_endpoint_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EndpointSubset {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_endpoint_subset_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Endpoints {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_endpoints_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EnvFromSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_env_from_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EnvVar {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_env_var_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EnvVarSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_env_var_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EphemeralContainer {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_ephemeral_container_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EphemeralVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_ephemeral_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Event {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_event_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EventSeries {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_event_series_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::EventSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_event_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ExecAction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_exec_action_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::FCVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_fc_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::FlexPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_flex_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::FlexVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_flex_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::FlockerVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_flocker_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::GCEPersistentDiskVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_gce_persistent_disk_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::GRPCAction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_grpc_action_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::GitRepoVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_git_repo_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::GlusterfsPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_glusterfs_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::GlusterfsVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_glusterfs_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::HTTPGetAction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_http_get_action_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::HTTPHeader {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_http_header_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::HostAlias {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_host_alias_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::HostIP {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_host_ip_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::HostPathVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_host_path_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ISCSIPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_iscsi_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ISCSIVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_iscsi_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ImageVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_image_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::KeyToPath {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_key_to_path_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Lifecycle {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_lifecycle_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LifecycleHandler {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_lifecycle_handler_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LimitRange {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_limit_range_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LimitRangeItem {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_limit_range_item_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LimitRangeSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_limit_range_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LinuxContainerUser {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_linux_container_user_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LoadBalancerIngress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_load_balancer_ingress_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LoadBalancerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_load_balancer_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LocalObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_local_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::LocalVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_local_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ModifyVolumeStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_modify_volume_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NFSVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_nfs_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Namespace {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_namespace_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NamespaceCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_namespace_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NamespaceSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_namespace_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NamespaceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_namespace_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Node {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeAddress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_address_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeAffinity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_affinity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeConfigSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_config_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeConfigStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_config_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeDaemonEndpoints {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_daemon_endpoints_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeFeatures {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_features_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeRuntimeHandler {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_runtime_handler_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeRuntimeHandlerFeatures {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_runtime_handler_features_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeSelectorRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_selector_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeSelectorTerm {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_selector_term_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::NodeSystemInfo {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_node_system_info_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ObjectFieldSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_object_field_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolume {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaimCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaimSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaimStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaimTemplate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_template_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeClaimVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_claim_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PersistentVolumeStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_persistent_volume_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PhotonPersistentDiskVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_photon_persistent_disk_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Pod {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodAffinity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_affinity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodAffinityTerm {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_affinity_term_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodAntiAffinity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_anti_affinity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodDNSConfig {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_dns_config_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodDNSConfigOption {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_dns_config_option_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodIP {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_ip_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodOS {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_os_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodReadinessGate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_readiness_gate_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodResourceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_resource_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodResourceClaimStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_resource_claim_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodSchedulingGate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_scheduling_gate_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodSecurityContext {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_security_context_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodTemplate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_template_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PodTemplateSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_pod_template_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PortStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_port_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PortworxVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_portworx_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::PreferredSchedulingTerm {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_preferred_scheduling_term_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Probe {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_probe_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ProjectedVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_projected_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::QuobyteVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_quobyte_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::RBDPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_rbd_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::RBDVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_rbd_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ReplicationController {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_replication_controller_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ReplicationControllerCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_replication_controller_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ReplicationControllerSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_replication_controller_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ReplicationControllerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_replication_controller_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceFieldSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_field_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceHealth {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_health_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceQuota {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_quota_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceQuotaSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_quota_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceQuotaStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_quota_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceRequirements {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_requirements_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ResourceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_resource_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SELinuxOptions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_se_linux_options_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ScaleIOPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_scale_io_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ScaleIOVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_scale_io_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ScopeSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_scope_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ScopedResourceSelectorRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_scoped_resource_selector_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SeccompProfile {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_seccomp_profile_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Secret {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecretEnvSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_env_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecretKeySelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_key_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecretProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecretReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecretVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_secret_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SecurityContext {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_security_context_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Service {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ServiceAccount {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_account_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ServiceAccountTokenProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_account_token_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ServicePort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ServiceSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::ServiceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_service_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SessionAffinityConfig {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_session_affinity_config_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::SleepAction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_sleep_action_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::StorageOSPersistentVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_storage_os_persistent_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::StorageOSVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_storage_os_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Sysctl {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_sysctl_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TCPSocketAction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_tcp_socket_action_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Taint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_taint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Toleration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_toleration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TopologySelectorLabelRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_topology_selector_label_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TopologySelectorTerm {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_topology_selector_term_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TopologySpreadConstraint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_topology_spread_constraint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TypedLocalObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_typed_local_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::TypedObjectReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_typed_object_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::Volume {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeDevice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_device_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeMount {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_mount_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeMountStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_mount_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeNodeAffinity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_node_affinity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeProjection {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_projection_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VolumeResourceRequirements {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_volume_resource_requirements_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::VsphereVirtualDiskVolumeSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_vsphere_virtual_disk_volume_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::WeightedPodAffinityTerm {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_weighted_pod_affinity_term_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::core::v1::WindowsSecurityContextOptions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_core_v1_windows_security_context_options_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::Endpoint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_endpoint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::EndpointConditions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_endpoint_conditions_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::EndpointHints {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_endpoint_hints_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::EndpointPort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_endpoint_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::EndpointSlice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_endpoint_slice_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::discovery::v1::ForZone {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_discovery_v1_for_zone_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::events::v1::Event {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_events_v1_event_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::events::v1::EventSeries {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_events_v1_event_series_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::ExemptPriorityLevelConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_exempt_priority_level_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::FlowDistinguisherMethod {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_distinguisher_method_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::FlowSchema {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::FlowSchemaCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::FlowSchemaSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::FlowSchemaStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_flow_schema_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::GroupSubject {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_group_subject_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::LimitResponse {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_limit_response_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::LimitedPriorityLevelConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_limited_priority_level_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::NonResourcePolicyRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_non_resource_policy_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PolicyRulesWithSubjects {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_policy_rules_with_subjects_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PriorityLevelConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PriorityLevelConfigurationCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PriorityLevelConfigurationReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PriorityLevelConfigurationSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::PriorityLevelConfigurationStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_priority_level_configuration_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::QueuingConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_queuing_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::ResourcePolicyRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_resource_policy_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::ServiceAccountSubject {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_service_account_subject_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::Subject {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_subject_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::flowcontrol::v1::UserSubject {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_flowcontrol_v1_user_subject_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::HTTPIngressPath {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_http_ingress_path_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::HTTPIngressRuleValue {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_http_ingress_rule_value_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IPBlock {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ip_block_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::Ingress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressBackend {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_backend_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressClassParametersReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_class_parameters_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressClassSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_class_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressLoadBalancerIngress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_load_balancer_ingress_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressLoadBalancerStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_load_balancer_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressPortStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_port_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressServiceBackend {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_service_backend_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::IngressTLS {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_ingress_tls_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicy {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicyEgressRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_egress_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicyIngressRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_ingress_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicyPeer {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_peer_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicyPort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::NetworkPolicySpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_network_policy_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1::ServiceBackendPort {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1_service_backend_port_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::IPAddress {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_ip_address_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::IPAddressSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_ip_address_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::ParentReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_parent_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::ServiceCIDR {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::ServiceCIDRSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::networking::v1beta1::ServiceCIDRStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_networking_v1beta1_service_cidr_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::node::v1::Overhead {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_node_v1_overhead_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::node::v1::RuntimeClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_node_v1_runtime_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::node::v1::Scheduling {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_node_v1_scheduling_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::policy::v1::Eviction {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_policy_v1_eviction_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::policy::v1::PodDisruptionBudget {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::policy::v1::PodDisruptionBudgetSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::policy::v1::PodDisruptionBudgetStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_policy_v1_pod_disruption_budget_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::AggregationRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_aggregation_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::ClusterRole {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_cluster_role_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::ClusterRoleBinding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_cluster_role_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::PolicyRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_policy_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::Role {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_role_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::RoleBinding {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_role_binding_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::RoleRef {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_role_ref_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::rbac::v1::Subject {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_rbac_v1_subject_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::AllocatedDeviceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_allocated_device_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::AllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::BasicDevice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_basic_device_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::CELDeviceSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_cel_device_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::Device {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceAllocationConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_allocation_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceAllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceAttribute {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_attribute_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceClaimConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_claim_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceClassConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceClassSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_class_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceConstraint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_constraint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceRequest {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_request_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceRequestAllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_request_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::DeviceSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_device_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::NetworkDeviceData {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_network_device_data_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::OpaqueDeviceConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_opaque_device_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaimConsumerReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_consumer_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaimSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaimStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaimTemplate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_template_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceClaimTemplateSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_claim_template_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourcePool {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_pool_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceSlice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_slice_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1alpha3::ResourceSliceSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1alpha3_resource_slice_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::AllocatedDeviceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_allocated_device_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::AllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::BasicDevice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_basic_device_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::CELDeviceSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_cel_device_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::Device {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceAllocationConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_allocation_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceAllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceAttribute {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_attribute_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceCapacity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_capacity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceClaimConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_claim_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceClassConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_class_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceClassSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_class_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceConstraint {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_constraint_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceRequest {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_request_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceRequestAllocationResult {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_request_allocation_result_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::DeviceSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_device_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::NetworkDeviceData {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_network_device_data_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::OpaqueDeviceConfiguration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_opaque_device_configuration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaim {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaimConsumerReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_consumer_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaimSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaimStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaimTemplate {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_template_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceClaimTemplateSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_claim_template_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourcePool {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_pool_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceSlice {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_slice_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::resource::v1beta1::ResourceSliceSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_resource_v1beta1_resource_slice_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::scheduling::v1::PriorityClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_scheduling_v1_priority_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSIDriver {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_driver_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSIDriverSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_driver_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSINode {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_node_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSINodeDriver {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_node_driver_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSINodeSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_node_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::CSIStorageCapacity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_csi_storage_capacity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::StorageClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_storage_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::TokenRequest {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_token_request_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeAttachment {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeAttachmentSource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_source_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeAttachmentSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeAttachmentStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_attachment_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeError {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_error_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1::VolumeNodeResources {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1_volume_node_resources_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1alpha1::VolumeAttributesClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1alpha1_volume_attributes_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storage::v1beta1::VolumeAttributesClass {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storage_v1beta1_volume_attributes_class_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storagemigration::v1alpha1::GroupVersionResource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_group_version_resource_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storagemigration::v1alpha1::MigrationCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_migration_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storagemigration::v1alpha1::StorageVersionMigration {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storagemigration::v1alpha1::StorageVersionMigrationSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::api::storagemigration::v1alpha1::StorageVersionMigrationStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_api_storagemigration_v1alpha1_storage_version_migration_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_column_definition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_conversion_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_names_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_definition_version_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_scale_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresource_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_subresources_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_custom_resource_validation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_external_documentation_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_array_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_bool_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_json_schema_props_or_string_array_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_selectable_field_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_service_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_validation_rule_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookClientConfig {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_client_config_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::WebhookConversion {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apiextensions_apiserver_pkg_apis_apiextensions_v1_webhook_conversion_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::api::resource::Quantity {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_api_resource_quantity_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::APIGroup {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::APIGroupList {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_group_list_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::APIResource {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::APIResourceList {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_resource_list_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::APIVersions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_api_versions_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::Condition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::DeleteOptions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_delete_options_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_field_selector_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::FieldsV1 {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_fields_v1_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::GroupVersionForDiscovery {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_group_version_for_discovery_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::LabelSelector {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_label_selector_requirement_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::ListMeta {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_list_meta_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_managed_fields_entry_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::MicroTime {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_micro_time_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_object_meta_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::OwnerReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_owner_reference_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::Preconditions {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_preconditions_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_server_address_by_client_cidr_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::Status {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::StatusCause {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_cause_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::StatusDetails {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_status_details_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::apis::meta::v1::Time {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_apis_meta_v1_time_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::runtime::RawExtension {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_runtime_raw_extension_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::util::intstr::IntOrString {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_util_intstr_int_or_string_mut(self);
+    }
+}
+
+impl VisitableMut for crate::apimachinery::pkg::version::Info {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_apimachinery_pkg_version_info_mut(self);
+    }
+}
+
+impl VisitableMut for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_mut(self);
+    }
+}
+
+impl VisitableMut for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceCondition {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_condition_mut(self);
+    }
+}
+
+impl VisitableMut for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceSpec {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_spec_mut(self);
+    }
+}
+
+impl VisitableMut for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIServiceStatus {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_api_service_status_mut(self);
+    }
+}
+
+impl VisitableMut for crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference {
+    fn visit_mut<V>(&mut self, v: &mut V) where V: VisitMut + ?Sized {
+        v.visit_kube_aggregator_pkg_apis_apiregistration_v1_service_reference_mut(self);
+    }
+}
+
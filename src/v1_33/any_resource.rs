@@ -0,0 +1,1446 @@
+// Generated from definition io.k8s.AnyResource
+
+/// AnyResource is a resource of any type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyResource {
+    /// `admissionregistration.k8s.io/v1` `MutatingWebhookConfiguration`
+    AdmissionregistrationV1MutatingWebhookConfiguration(crate::api::admissionregistration::v1::MutatingWebhookConfiguration),
+
+    /// `admissionregistration.k8s.io/v1` `ValidatingAdmissionPolicy`
+    AdmissionregistrationV1ValidatingAdmissionPolicy(crate::api::admissionregistration::v1::ValidatingAdmissionPolicy),
+
+    /// `admissionregistration.k8s.io/v1` `ValidatingAdmissionPolicyBinding`
+    AdmissionregistrationV1ValidatingAdmissionPolicyBinding(crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding),
+
+    /// `admissionregistration.k8s.io/v1` `ValidatingWebhookConfiguration`
+    AdmissionregistrationV1ValidatingWebhookConfiguration(crate::api::admissionregistration::v1::ValidatingWebhookConfiguration),
+
+    /// `admissionregistration.k8s.io/v1alpha1` `MutatingAdmissionPolicy`
+    AdmissionregistrationV1alpha1MutatingAdmissionPolicy(crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy),
+
+    /// `admissionregistration.k8s.io/v1alpha1` `MutatingAdmissionPolicyBinding`
+    AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding),
+
+    /// `admissionregistration.k8s.io/v1beta1` `ValidatingAdmissionPolicy`
+    AdmissionregistrationV1beta1ValidatingAdmissionPolicy(crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy),
+
+    /// `admissionregistration.k8s.io/v1beta1` `ValidatingAdmissionPolicyBinding`
+    AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding),
+
+    /// `internal.apiserver.k8s.io/v1alpha1` `StorageVersion`
+    ApiserverinternalV1alpha1StorageVersion(crate::api::apiserverinternal::v1alpha1::StorageVersion),
+
+    /// `apps/v1` `ControllerRevision`
+    AppsV1ControllerRevision(crate::api::apps::v1::ControllerRevision),
+
+    /// `apps/v1` `DaemonSet`
+    AppsV1DaemonSet(crate::api::apps::v1::DaemonSet),
+
+    /// `apps/v1` `Deployment`
+    AppsV1Deployment(crate::api::apps::v1::Deployment),
+
+    /// `apps/v1` `ReplicaSet`
+    AppsV1ReplicaSet(crate::api::apps::v1::ReplicaSet),
+
+    /// `apps/v1` `StatefulSet`
+    AppsV1StatefulSet(crate::api::apps::v1::StatefulSet),
+
+    /// `authentication.k8s.io/v1` `SelfSubjectReview`
+    AuthenticationV1SelfSubjectReview(crate::api::authentication::v1::SelfSubjectReview),
+
+    /// `authentication.k8s.io/v1` `TokenRequest`
+    AuthenticationV1TokenRequest(crate::api::authentication::v1::TokenRequest),
+
+    /// `authentication.k8s.io/v1` `TokenReview`
+    AuthenticationV1TokenReview(crate::api::authentication::v1::TokenReview),
+
+    /// `authorization.k8s.io/v1` `LocalSubjectAccessReview`
+    AuthorizationV1LocalSubjectAccessReview(crate::api::authorization::v1::LocalSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1` `SelfSubjectAccessReview`
+    AuthorizationV1SelfSubjectAccessReview(crate::api::authorization::v1::SelfSubjectAccessReview),
+
+    /// `authorization.k8s.io/v1` `SelfSubjectRulesReview`
+    AuthorizationV1SelfSubjectRulesReview(crate::api::authorization::v1::SelfSubjectRulesReview),
+
+    /// `authorization.k8s.io/v1` `SubjectAccessReview`
+    AuthorizationV1SubjectAccessReview(crate::api::authorization::v1::SubjectAccessReview),
+
+    /// `autoscaling/v1` `HorizontalPodAutoscaler`
+    AutoscalingV1HorizontalPodAutoscaler(crate::api::autoscaling::v1::HorizontalPodAutoscaler),
+
+    /// `autoscaling/v1` `Scale`
+    AutoscalingV1Scale(crate::api::autoscaling::v1::Scale),
+
+    /// `autoscaling/v2` `HorizontalPodAutoscaler`
+    AutoscalingV2HorizontalPodAutoscaler(crate::api::autoscaling::v2::HorizontalPodAutoscaler),
+
+    /// `batch/v1` `CronJob`
+    BatchV1CronJob(crate::api::batch::v1::CronJob),
+
+    /// `batch/v1` `Job`
+    BatchV1Job(crate::api::batch::v1::Job),
+
+    /// `certificates.k8s.io/v1` `CertificateSigningRequest`
+    CertificatesV1CertificateSigningRequest(crate::api::certificates::v1::CertificateSigningRequest),
+
+    /// `certificates.k8s.io/v1alpha1` `ClusterTrustBundle`
+    CertificatesV1alpha1ClusterTrustBundle(crate::api::certificates::v1alpha1::ClusterTrustBundle),
+
+    /// `certificates.k8s.io/v1beta1` `ClusterTrustBundle`
+    CertificatesV1beta1ClusterTrustBundle(crate::api::certificates::v1beta1::ClusterTrustBundle),
+
+    /// `coordination.k8s.io/v1` `Lease`
+    CoordinationV1Lease(crate::api::coordination::v1::Lease),
+
+    /// `coordination.k8s.io/v1alpha2` `LeaseCandidate`
+    CoordinationV1alpha2LeaseCandidate(crate::api::coordination::v1alpha2::LeaseCandidate),
+
+    /// `coordination.k8s.io/v1beta1` `LeaseCandidate`
+    CoordinationV1beta1LeaseCandidate(crate::api::coordination::v1beta1::LeaseCandidate),
+
+    /// `v1` `Binding`
+    CoreV1Binding(crate::api::core::v1::Binding),
+
+    /// `v1` `ComponentStatus`
+    CoreV1ComponentStatus(crate::api::core::v1::ComponentStatus),
+
+    /// `v1` `ConfigMap`
+    CoreV1ConfigMap(crate::api::core::v1::ConfigMap),
+
+    /// `v1` `Endpoints`
+    CoreV1Endpoints(crate::api::core::v1::Endpoints),
+
+    /// `v1` `Event`
+    CoreV1Event(crate::api::core::v1::Event),
+
+    /// `v1` `LimitRange`
+    CoreV1LimitRange(crate::api::core::v1::LimitRange),
+
+    /// `v1` `Namespace`
+    CoreV1Namespace(crate::api::core::v1::Namespace),
+
+    /// `v1` `Node`
+    CoreV1Node(crate::api::core::v1::Node),
+
+    /// `v1` `PersistentVolume`
+    CoreV1PersistentVolume(crate::api::core::v1::PersistentVolume),
+
+    /// `v1` `PersistentVolumeClaim`
+    CoreV1PersistentVolumeClaim(crate::api::core::v1::PersistentVolumeClaim),
+
+    /// `v1` `Pod`
+    CoreV1Pod(crate::api::core::v1::Pod),
+
+    /// `v1` `PodTemplate`
+    CoreV1PodTemplate(crate::api::core::v1::PodTemplate),
+
+    /// `v1` `ReplicationController`
+    CoreV1ReplicationController(crate::api::core::v1::ReplicationController),
+
+    /// `v1` `ResourceQuota`
+    CoreV1ResourceQuota(crate::api::core::v1::ResourceQuota),
+
+    /// `v1` `Secret`
+    CoreV1Secret(crate::api::core::v1::Secret),
+
+    /// `v1` `Service`
+    CoreV1Service(crate::api::core::v1::Service),
+
+    /// `v1` `ServiceAccount`
+    CoreV1ServiceAccount(crate::api::core::v1::ServiceAccount),
+
+    /// `discovery.k8s.io/v1` `EndpointSlice`
+    DiscoveryV1EndpointSlice(crate::api::discovery::v1::EndpointSlice),
+
+    /// `events.k8s.io/v1` `Event`
+    EventsV1Event(crate::api::events::v1::Event),
+
+    /// `flowcontrol.apiserver.k8s.io/v1` `FlowSchema`
+    FlowcontrolV1FlowSchema(crate::api::flowcontrol::v1::FlowSchema),
+
+    /// `flowcontrol.apiserver.k8s.io/v1` `PriorityLevelConfiguration`
+    FlowcontrolV1PriorityLevelConfiguration(crate::api::flowcontrol::v1::PriorityLevelConfiguration),
+
+    /// `networking.k8s.io/v1` `IPAddress`
+    NetworkingV1IPAddress(crate::api::networking::v1::IPAddress),
+
+    /// `networking.k8s.io/v1` `Ingress`
+    NetworkingV1Ingress(crate::api::networking::v1::Ingress),
+
+    /// `networking.k8s.io/v1` `IngressClass`
+    NetworkingV1IngressClass(crate::api::networking::v1::IngressClass),
+
+    /// `networking.k8s.io/v1` `NetworkPolicy`
+    NetworkingV1NetworkPolicy(crate::api::networking::v1::NetworkPolicy),
+
+    /// `networking.k8s.io/v1` `ServiceCIDR`
+    NetworkingV1ServiceCIDR(crate::api::networking::v1::ServiceCIDR),
+
+    /// `networking.k8s.io/v1beta1` `IPAddress`
+    NetworkingV1beta1IPAddress(crate::api::networking::v1beta1::IPAddress),
+
+    /// `networking.k8s.io/v1beta1` `ServiceCIDR`
+    NetworkingV1beta1ServiceCIDR(crate::api::networking::v1beta1::ServiceCIDR),
+
+    /// `node.k8s.io/v1` `RuntimeClass`
+    NodeV1RuntimeClass(crate::api::node::v1::RuntimeClass),
+
+    /// `policy/v1` `Eviction`
+    PolicyV1Eviction(crate::api::policy::v1::Eviction),
+
+    /// `policy/v1` `PodDisruptionBudget`
+    PolicyV1PodDisruptionBudget(crate::api::policy::v1::PodDisruptionBudget),
+
+    /// `rbac.authorization.k8s.io/v1` `ClusterRole`
+    RbacV1ClusterRole(crate::api::rbac::v1::ClusterRole),
+
+    /// `rbac.authorization.k8s.io/v1` `ClusterRoleBinding`
+    RbacV1ClusterRoleBinding(crate::api::rbac::v1::ClusterRoleBinding),
+
+    /// `rbac.authorization.k8s.io/v1` `Role`
+    RbacV1Role(crate::api::rbac::v1::Role),
+
+    /// `rbac.authorization.k8s.io/v1` `RoleBinding`
+    RbacV1RoleBinding(crate::api::rbac::v1::RoleBinding),
+
+    /// `resource.k8s.io/v1alpha3` `DeviceClass`
+    ResourceV1alpha3DeviceClass(crate::api::resource::v1alpha3::DeviceClass),
+
+    /// `resource.k8s.io/v1alpha3` `DeviceTaintRule`
+    ResourceV1alpha3DeviceTaintRule(crate::api::resource::v1alpha3::DeviceTaintRule),
+
+    /// `resource.k8s.io/v1alpha3` `ResourceClaim`
+    ResourceV1alpha3ResourceClaim(crate::api::resource::v1alpha3::ResourceClaim),
+
+    /// `resource.k8s.io/v1alpha3` `ResourceClaimTemplate`
+    ResourceV1alpha3ResourceClaimTemplate(crate::api::resource::v1alpha3::ResourceClaimTemplate),
+
+    /// `resource.k8s.io/v1alpha3` `ResourceSlice`
+    ResourceV1alpha3ResourceSlice(crate::api::resource::v1alpha3::ResourceSlice),
+
+    /// `resource.k8s.io/v1beta1` `DeviceClass`
+    ResourceV1beta1DeviceClass(crate::api::resource::v1beta1::DeviceClass),
+
+    /// `resource.k8s.io/v1beta1` `ResourceClaim`
+    ResourceV1beta1ResourceClaim(crate::api::resource::v1beta1::ResourceClaim),
+
+    /// `resource.k8s.io/v1beta1` `ResourceClaimTemplate`
+    ResourceV1beta1ResourceClaimTemplate(crate::api::resource::v1beta1::ResourceClaimTemplate),
+
+    /// `resource.k8s.io/v1beta1` `ResourceSlice`
+    ResourceV1beta1ResourceSlice(crate::api::resource::v1beta1::ResourceSlice),
+
+    /// `resource.k8s.io/v1beta2` `DeviceClass`
+    ResourceV1beta2DeviceClass(crate::api::resource::v1beta2::DeviceClass),
+
+    /// `resource.k8s.io/v1beta2` `ResourceClaim`
+    ResourceV1beta2ResourceClaim(crate::api::resource::v1beta2::ResourceClaim),
+
+    /// `resource.k8s.io/v1beta2` `ResourceClaimTemplate`
+    ResourceV1beta2ResourceClaimTemplate(crate::api::resource::v1beta2::ResourceClaimTemplate),
+
+    /// `resource.k8s.io/v1beta2` `ResourceSlice`
+    ResourceV1beta2ResourceSlice(crate::api::resource::v1beta2::ResourceSlice),
+
+    /// `scheduling.k8s.io/v1` `PriorityClass`
+    SchedulingV1PriorityClass(crate::api::scheduling::v1::PriorityClass),
+
+    /// `storage.k8s.io/v1` `CSIDriver`
+    StorageV1CSIDriver(crate::api::storage::v1::CSIDriver),
+
+    /// `storage.k8s.io/v1` `CSINode`
+    StorageV1CSINode(crate::api::storage::v1::CSINode),
+
+    /// `storage.k8s.io/v1` `CSIStorageCapacity`
+    StorageV1CSIStorageCapacity(crate::api::storage::v1::CSIStorageCapacity),
+
+    /// `storage.k8s.io/v1` `StorageClass`
+    StorageV1StorageClass(crate::api::storage::v1::StorageClass),
+
+    /// `storage.k8s.io/v1` `VolumeAttachment`
+    StorageV1VolumeAttachment(crate::api::storage::v1::VolumeAttachment),
+
+    /// `storage.k8s.io/v1alpha1` `VolumeAttributesClass`
+    StorageV1alpha1VolumeAttributesClass(crate::api::storage::v1alpha1::VolumeAttributesClass),
+
+    /// `storage.k8s.io/v1beta1` `VolumeAttributesClass`
+    StorageV1beta1VolumeAttributesClass(crate::api::storage::v1beta1::VolumeAttributesClass),
+
+    /// `storagemigration.k8s.io/v1alpha1` `StorageVersionMigration`
+    StoragemigrationV1alpha1StorageVersionMigration(crate::api::storagemigration::v1alpha1::StorageVersionMigration),
+
+    /// `apiextensions.k8s.io/v1` `CustomResourceDefinition`
+    ApiextensionsV1CustomResourceDefinition(crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition),
+
+    /// `apiregistration.k8s.io/v1` `APIService`
+    ApiregistrationV1APIService(crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService),
+
+    /// A resource of any other type.
+    Other(crate::DynamicObject),
+}
+
+impl AnyResource {
+    /// Deserializes a resource of the type with the given `apiVersion` and `kind` from the given deserializer.
+    ///
+    /// Resources of types that are not known to this crate are deserialized as [`AnyResource::Other`].
+    pub fn deserialize_with_type<'de, D>(api_version: &str, kind: &str, deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        Ok(match (api_version, kind) {
+            ("admissionregistration.k8s.io/v1", "MutatingWebhookConfiguration") => AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1", "ValidatingAdmissionPolicy") => AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1", "ValidatingAdmissionPolicyBinding") => AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1", "ValidatingWebhookConfiguration") => AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1alpha1", "MutatingAdmissionPolicy") => AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1alpha1", "MutatingAdmissionPolicyBinding") => AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1beta1", "ValidatingAdmissionPolicy") => AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("admissionregistration.k8s.io/v1beta1", "ValidatingAdmissionPolicyBinding") => AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("internal.apiserver.k8s.io/v1alpha1", "StorageVersion") => AnyResource::ApiserverinternalV1alpha1StorageVersion(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apps/v1", "ControllerRevision") => AnyResource::AppsV1ControllerRevision(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apps/v1", "DaemonSet") => AnyResource::AppsV1DaemonSet(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apps/v1", "Deployment") => AnyResource::AppsV1Deployment(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apps/v1", "ReplicaSet") => AnyResource::AppsV1ReplicaSet(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apps/v1", "StatefulSet") => AnyResource::AppsV1StatefulSet(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authentication.k8s.io/v1", "SelfSubjectReview") => AnyResource::AuthenticationV1SelfSubjectReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authentication.k8s.io/v1", "TokenRequest") => AnyResource::AuthenticationV1TokenRequest(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authentication.k8s.io/v1", "TokenReview") => AnyResource::AuthenticationV1TokenReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authorization.k8s.io/v1", "LocalSubjectAccessReview") => AnyResource::AuthorizationV1LocalSubjectAccessReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authorization.k8s.io/v1", "SelfSubjectAccessReview") => AnyResource::AuthorizationV1SelfSubjectAccessReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authorization.k8s.io/v1", "SelfSubjectRulesReview") => AnyResource::AuthorizationV1SelfSubjectRulesReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("authorization.k8s.io/v1", "SubjectAccessReview") => AnyResource::AuthorizationV1SubjectAccessReview(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("autoscaling/v1", "HorizontalPodAutoscaler") => AnyResource::AutoscalingV1HorizontalPodAutoscaler(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("autoscaling/v1", "Scale") => AnyResource::AutoscalingV1Scale(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("autoscaling/v2", "HorizontalPodAutoscaler") => AnyResource::AutoscalingV2HorizontalPodAutoscaler(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("batch/v1", "CronJob") => AnyResource::BatchV1CronJob(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("batch/v1", "Job") => AnyResource::BatchV1Job(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("certificates.k8s.io/v1", "CertificateSigningRequest") => AnyResource::CertificatesV1CertificateSigningRequest(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("certificates.k8s.io/v1alpha1", "ClusterTrustBundle") => AnyResource::CertificatesV1alpha1ClusterTrustBundle(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("certificates.k8s.io/v1beta1", "ClusterTrustBundle") => AnyResource::CertificatesV1beta1ClusterTrustBundle(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("coordination.k8s.io/v1", "Lease") => AnyResource::CoordinationV1Lease(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("coordination.k8s.io/v1alpha2", "LeaseCandidate") => AnyResource::CoordinationV1alpha2LeaseCandidate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("coordination.k8s.io/v1beta1", "LeaseCandidate") => AnyResource::CoordinationV1beta1LeaseCandidate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Binding") => AnyResource::CoreV1Binding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "ComponentStatus") => AnyResource::CoreV1ComponentStatus(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "ConfigMap") => AnyResource::CoreV1ConfigMap(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Endpoints") => AnyResource::CoreV1Endpoints(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Event") => AnyResource::CoreV1Event(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "LimitRange") => AnyResource::CoreV1LimitRange(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Namespace") => AnyResource::CoreV1Namespace(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Node") => AnyResource::CoreV1Node(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "PersistentVolume") => AnyResource::CoreV1PersistentVolume(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "PersistentVolumeClaim") => AnyResource::CoreV1PersistentVolumeClaim(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Pod") => AnyResource::CoreV1Pod(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "PodTemplate") => AnyResource::CoreV1PodTemplate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "ReplicationController") => AnyResource::CoreV1ReplicationController(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "ResourceQuota") => AnyResource::CoreV1ResourceQuota(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Secret") => AnyResource::CoreV1Secret(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "Service") => AnyResource::CoreV1Service(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("v1", "ServiceAccount") => AnyResource::CoreV1ServiceAccount(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("discovery.k8s.io/v1", "EndpointSlice") => AnyResource::DiscoveryV1EndpointSlice(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("events.k8s.io/v1", "Event") => AnyResource::EventsV1Event(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("flowcontrol.apiserver.k8s.io/v1", "FlowSchema") => AnyResource::FlowcontrolV1FlowSchema(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("flowcontrol.apiserver.k8s.io/v1", "PriorityLevelConfiguration") => AnyResource::FlowcontrolV1PriorityLevelConfiguration(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1", "IPAddress") => AnyResource::NetworkingV1IPAddress(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1", "Ingress") => AnyResource::NetworkingV1Ingress(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1", "IngressClass") => AnyResource::NetworkingV1IngressClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1", "NetworkPolicy") => AnyResource::NetworkingV1NetworkPolicy(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1", "ServiceCIDR") => AnyResource::NetworkingV1ServiceCIDR(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1beta1", "IPAddress") => AnyResource::NetworkingV1beta1IPAddress(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("networking.k8s.io/v1beta1", "ServiceCIDR") => AnyResource::NetworkingV1beta1ServiceCIDR(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("node.k8s.io/v1", "RuntimeClass") => AnyResource::NodeV1RuntimeClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("policy/v1", "Eviction") => AnyResource::PolicyV1Eviction(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("policy/v1", "PodDisruptionBudget") => AnyResource::PolicyV1PodDisruptionBudget(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("rbac.authorization.k8s.io/v1", "ClusterRole") => AnyResource::RbacV1ClusterRole(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("rbac.authorization.k8s.io/v1", "ClusterRoleBinding") => AnyResource::RbacV1ClusterRoleBinding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("rbac.authorization.k8s.io/v1", "Role") => AnyResource::RbacV1Role(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("rbac.authorization.k8s.io/v1", "RoleBinding") => AnyResource::RbacV1RoleBinding(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1alpha3", "DeviceClass") => AnyResource::ResourceV1alpha3DeviceClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1alpha3", "DeviceTaintRule") => AnyResource::ResourceV1alpha3DeviceTaintRule(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1alpha3", "ResourceClaim") => AnyResource::ResourceV1alpha3ResourceClaim(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1alpha3", "ResourceClaimTemplate") => AnyResource::ResourceV1alpha3ResourceClaimTemplate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1alpha3", "ResourceSlice") => AnyResource::ResourceV1alpha3ResourceSlice(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta1", "DeviceClass") => AnyResource::ResourceV1beta1DeviceClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta1", "ResourceClaim") => AnyResource::ResourceV1beta1ResourceClaim(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta1", "ResourceClaimTemplate") => AnyResource::ResourceV1beta1ResourceClaimTemplate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta1", "ResourceSlice") => AnyResource::ResourceV1beta1ResourceSlice(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta2", "DeviceClass") => AnyResource::ResourceV1beta2DeviceClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta2", "ResourceClaim") => AnyResource::ResourceV1beta2ResourceClaim(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta2", "ResourceClaimTemplate") => AnyResource::ResourceV1beta2ResourceClaimTemplate(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("resource.k8s.io/v1beta2", "ResourceSlice") => AnyResource::ResourceV1beta2ResourceSlice(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("scheduling.k8s.io/v1", "PriorityClass") => AnyResource::SchedulingV1PriorityClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1", "CSIDriver") => AnyResource::StorageV1CSIDriver(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1", "CSINode") => AnyResource::StorageV1CSINode(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1", "CSIStorageCapacity") => AnyResource::StorageV1CSIStorageCapacity(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1", "StorageClass") => AnyResource::StorageV1StorageClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1", "VolumeAttachment") => AnyResource::StorageV1VolumeAttachment(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1alpha1", "VolumeAttributesClass") => AnyResource::StorageV1alpha1VolumeAttributesClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storage.k8s.io/v1beta1", "VolumeAttributesClass") => AnyResource::StorageV1beta1VolumeAttributesClass(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("storagemigration.k8s.io/v1alpha1", "StorageVersionMigration") => AnyResource::StoragemigrationV1alpha1StorageVersionMigration(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apiextensions.k8s.io/v1", "CustomResourceDefinition") => AnyResource::ApiextensionsV1CustomResourceDefinition(crate::serde::Deserialize::deserialize(deserializer)?),
+            ("apiregistration.k8s.io/v1", "APIService") => AnyResource::ApiregistrationV1APIService(crate::serde::Deserialize::deserialize(deserializer)?),
+            _ => AnyResource::Other(crate::serde::Deserialize::deserialize(deserializer)?),
+        })
+    }
+
+    /// Gets the `apiVersion` of this resource.
+    pub fn api_version(&self) -> &str {
+        match self {
+            AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value) => crate::api_version(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value) => crate::api_version(value),
+            AnyResource::ApiserverinternalV1alpha1StorageVersion(value) => crate::api_version(value),
+            AnyResource::AppsV1ControllerRevision(value) => crate::api_version(value),
+            AnyResource::AppsV1DaemonSet(value) => crate::api_version(value),
+            AnyResource::AppsV1Deployment(value) => crate::api_version(value),
+            AnyResource::AppsV1ReplicaSet(value) => crate::api_version(value),
+            AnyResource::AppsV1StatefulSet(value) => crate::api_version(value),
+            AnyResource::AuthenticationV1SelfSubjectReview(value) => crate::api_version(value),
+            AnyResource::AuthenticationV1TokenRequest(value) => crate::api_version(value),
+            AnyResource::AuthenticationV1TokenReview(value) => crate::api_version(value),
+            AnyResource::AuthorizationV1LocalSubjectAccessReview(value) => crate::api_version(value),
+            AnyResource::AuthorizationV1SelfSubjectAccessReview(value) => crate::api_version(value),
+            AnyResource::AuthorizationV1SelfSubjectRulesReview(value) => crate::api_version(value),
+            AnyResource::AuthorizationV1SubjectAccessReview(value) => crate::api_version(value),
+            AnyResource::AutoscalingV1HorizontalPodAutoscaler(value) => crate::api_version(value),
+            AnyResource::AutoscalingV1Scale(value) => crate::api_version(value),
+            AnyResource::AutoscalingV2HorizontalPodAutoscaler(value) => crate::api_version(value),
+            AnyResource::BatchV1CronJob(value) => crate::api_version(value),
+            AnyResource::BatchV1Job(value) => crate::api_version(value),
+            AnyResource::CertificatesV1CertificateSigningRequest(value) => crate::api_version(value),
+            AnyResource::CertificatesV1alpha1ClusterTrustBundle(value) => crate::api_version(value),
+            AnyResource::CertificatesV1beta1ClusterTrustBundle(value) => crate::api_version(value),
+            AnyResource::CoordinationV1Lease(value) => crate::api_version(value),
+            AnyResource::CoordinationV1alpha2LeaseCandidate(value) => crate::api_version(value),
+            AnyResource::CoordinationV1beta1LeaseCandidate(value) => crate::api_version(value),
+            AnyResource::CoreV1Binding(value) => crate::api_version(value),
+            AnyResource::CoreV1ComponentStatus(value) => crate::api_version(value),
+            AnyResource::CoreV1ConfigMap(value) => crate::api_version(value),
+            AnyResource::CoreV1Endpoints(value) => crate::api_version(value),
+            AnyResource::CoreV1Event(value) => crate::api_version(value),
+            AnyResource::CoreV1LimitRange(value) => crate::api_version(value),
+            AnyResource::CoreV1Namespace(value) => crate::api_version(value),
+            AnyResource::CoreV1Node(value) => crate::api_version(value),
+            AnyResource::CoreV1PersistentVolume(value) => crate::api_version(value),
+            AnyResource::CoreV1PersistentVolumeClaim(value) => crate::api_version(value),
+            AnyResource::CoreV1Pod(value) => crate::api_version(value),
+            AnyResource::CoreV1PodTemplate(value) => crate::api_version(value),
+            AnyResource::CoreV1ReplicationController(value) => crate::api_version(value),
+            AnyResource::CoreV1ResourceQuota(value) => crate::api_version(value),
+            AnyResource::CoreV1Secret(value) => crate::api_version(value),
+            AnyResource::CoreV1Service(value) => crate::api_version(value),
+            AnyResource::CoreV1ServiceAccount(value) => crate::api_version(value),
+            AnyResource::DiscoveryV1EndpointSlice(value) => crate::api_version(value),
+            AnyResource::EventsV1Event(value) => crate::api_version(value),
+            AnyResource::FlowcontrolV1FlowSchema(value) => crate::api_version(value),
+            AnyResource::FlowcontrolV1PriorityLevelConfiguration(value) => crate::api_version(value),
+            AnyResource::NetworkingV1IPAddress(value) => crate::api_version(value),
+            AnyResource::NetworkingV1Ingress(value) => crate::api_version(value),
+            AnyResource::NetworkingV1IngressClass(value) => crate::api_version(value),
+            AnyResource::NetworkingV1NetworkPolicy(value) => crate::api_version(value),
+            AnyResource::NetworkingV1ServiceCIDR(value) => crate::api_version(value),
+            AnyResource::NetworkingV1beta1IPAddress(value) => crate::api_version(value),
+            AnyResource::NetworkingV1beta1ServiceCIDR(value) => crate::api_version(value),
+            AnyResource::NodeV1RuntimeClass(value) => crate::api_version(value),
+            AnyResource::PolicyV1Eviction(value) => crate::api_version(value),
+            AnyResource::PolicyV1PodDisruptionBudget(value) => crate::api_version(value),
+            AnyResource::RbacV1ClusterRole(value) => crate::api_version(value),
+            AnyResource::RbacV1ClusterRoleBinding(value) => crate::api_version(value),
+            AnyResource::RbacV1Role(value) => crate::api_version(value),
+            AnyResource::RbacV1RoleBinding(value) => crate::api_version(value),
+            AnyResource::ResourceV1alpha3DeviceClass(value) => crate::api_version(value),
+            AnyResource::ResourceV1alpha3DeviceTaintRule(value) => crate::api_version(value),
+            AnyResource::ResourceV1alpha3ResourceClaim(value) => crate::api_version(value),
+            AnyResource::ResourceV1alpha3ResourceClaimTemplate(value) => crate::api_version(value),
+            AnyResource::ResourceV1alpha3ResourceSlice(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta1DeviceClass(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta1ResourceClaim(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta1ResourceClaimTemplate(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta1ResourceSlice(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta2DeviceClass(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta2ResourceClaim(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta2ResourceClaimTemplate(value) => crate::api_version(value),
+            AnyResource::ResourceV1beta2ResourceSlice(value) => crate::api_version(value),
+            AnyResource::SchedulingV1PriorityClass(value) => crate::api_version(value),
+            AnyResource::StorageV1CSIDriver(value) => crate::api_version(value),
+            AnyResource::StorageV1CSINode(value) => crate::api_version(value),
+            AnyResource::StorageV1CSIStorageCapacity(value) => crate::api_version(value),
+            AnyResource::StorageV1StorageClass(value) => crate::api_version(value),
+            AnyResource::StorageV1VolumeAttachment(value) => crate::api_version(value),
+            AnyResource::StorageV1alpha1VolumeAttributesClass(value) => crate::api_version(value),
+            AnyResource::StorageV1beta1VolumeAttributesClass(value) => crate::api_version(value),
+            AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value) => crate::api_version(value),
+            AnyResource::ApiextensionsV1CustomResourceDefinition(value) => crate::api_version(value),
+            AnyResource::ApiregistrationV1APIService(value) => crate::api_version(value),
+            AnyResource::Other(value) => &value.api_version,
+        }
+    }
+
+    /// Gets the `kind` of this resource.
+    pub fn kind(&self) -> &str {
+        match self {
+            AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value) => crate::kind(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value) => crate::kind(value),
+            AnyResource::ApiserverinternalV1alpha1StorageVersion(value) => crate::kind(value),
+            AnyResource::AppsV1ControllerRevision(value) => crate::kind(value),
+            AnyResource::AppsV1DaemonSet(value) => crate::kind(value),
+            AnyResource::AppsV1Deployment(value) => crate::kind(value),
+            AnyResource::AppsV1ReplicaSet(value) => crate::kind(value),
+            AnyResource::AppsV1StatefulSet(value) => crate::kind(value),
+            AnyResource::AuthenticationV1SelfSubjectReview(value) => crate::kind(value),
+            AnyResource::AuthenticationV1TokenRequest(value) => crate::kind(value),
+            AnyResource::AuthenticationV1TokenReview(value) => crate::kind(value),
+            AnyResource::AuthorizationV1LocalSubjectAccessReview(value) => crate::kind(value),
+            AnyResource::AuthorizationV1SelfSubjectAccessReview(value) => crate::kind(value),
+            AnyResource::AuthorizationV1SelfSubjectRulesReview(value) => crate::kind(value),
+            AnyResource::AuthorizationV1SubjectAccessReview(value) => crate::kind(value),
+            AnyResource::AutoscalingV1HorizontalPodAutoscaler(value) => crate::kind(value),
+            AnyResource::AutoscalingV1Scale(value) => crate::kind(value),
+            AnyResource::AutoscalingV2HorizontalPodAutoscaler(value) => crate::kind(value),
+            AnyResource::BatchV1CronJob(value) => crate::kind(value),
+            AnyResource::BatchV1Job(value) => crate::kind(value),
+            AnyResource::CertificatesV1CertificateSigningRequest(value) => crate::kind(value),
+            AnyResource::CertificatesV1alpha1ClusterTrustBundle(value) => crate::kind(value),
+            AnyResource::CertificatesV1beta1ClusterTrustBundle(value) => crate::kind(value),
+            AnyResource::CoordinationV1Lease(value) => crate::kind(value),
+            AnyResource::CoordinationV1alpha2LeaseCandidate(value) => crate::kind(value),
+            AnyResource::CoordinationV1beta1LeaseCandidate(value) => crate::kind(value),
+            AnyResource::CoreV1Binding(value) => crate::kind(value),
+            AnyResource::CoreV1ComponentStatus(value) => crate::kind(value),
+            AnyResource::CoreV1ConfigMap(value) => crate::kind(value),
+            AnyResource::CoreV1Endpoints(value) => crate::kind(value),
+            AnyResource::CoreV1Event(value) => crate::kind(value),
+            AnyResource::CoreV1LimitRange(value) => crate::kind(value),
+            AnyResource::CoreV1Namespace(value) => crate::kind(value),
+            AnyResource::CoreV1Node(value) => crate::kind(value),
+            AnyResource::CoreV1PersistentVolume(value) => crate::kind(value),
+            AnyResource::CoreV1PersistentVolumeClaim(value) => crate::kind(value),
+            AnyResource::CoreV1Pod(value) => crate::kind(value),
+            AnyResource::CoreV1PodTemplate(value) => crate::kind(value),
+            AnyResource::CoreV1ReplicationController(value) => crate::kind(value),
+            AnyResource::CoreV1ResourceQuota(value) => crate::kind(value),
+            AnyResource::CoreV1Secret(value) => crate::kind(value),
+            AnyResource::CoreV1Service(value) => crate::kind(value),
+            AnyResource::CoreV1ServiceAccount(value) => crate::kind(value),
+            AnyResource::DiscoveryV1EndpointSlice(value) => crate::kind(value),
+            AnyResource::EventsV1Event(value) => crate::kind(value),
+            AnyResource::FlowcontrolV1FlowSchema(value) => crate::kind(value),
+            AnyResource::FlowcontrolV1PriorityLevelConfiguration(value) => crate::kind(value),
+            AnyResource::NetworkingV1IPAddress(value) => crate::kind(value),
+            AnyResource::NetworkingV1Ingress(value) => crate::kind(value),
+            AnyResource::NetworkingV1IngressClass(value) => crate::kind(value),
+            AnyResource::NetworkingV1NetworkPolicy(value) => crate::kind(value),
+            AnyResource::NetworkingV1ServiceCIDR(value) => crate::kind(value),
+            AnyResource::NetworkingV1beta1IPAddress(value) => crate::kind(value),
+            AnyResource::NetworkingV1beta1ServiceCIDR(value) => crate::kind(value),
+            AnyResource::NodeV1RuntimeClass(value) => crate::kind(value),
+            AnyResource::PolicyV1Eviction(value) => crate::kind(value),
+            AnyResource::PolicyV1PodDisruptionBudget(value) => crate::kind(value),
+            AnyResource::RbacV1ClusterRole(value) => crate::kind(value),
+            AnyResource::RbacV1ClusterRoleBinding(value) => crate::kind(value),
+            AnyResource::RbacV1Role(value) => crate::kind(value),
+            AnyResource::RbacV1RoleBinding(value) => crate::kind(value),
+            AnyResource::ResourceV1alpha3DeviceClass(value) => crate::kind(value),
+            AnyResource::ResourceV1alpha3DeviceTaintRule(value) => crate::kind(value),
+            AnyResource::ResourceV1alpha3ResourceClaim(value) => crate::kind(value),
+            AnyResource::ResourceV1alpha3ResourceClaimTemplate(value) => crate::kind(value),
+            AnyResource::ResourceV1alpha3ResourceSlice(value) => crate::kind(value),
+            AnyResource::ResourceV1beta1DeviceClass(value) => crate::kind(value),
+            AnyResource::ResourceV1beta1ResourceClaim(value) => crate::kind(value),
+            AnyResource::ResourceV1beta1ResourceClaimTemplate(value) => crate::kind(value),
+            AnyResource::ResourceV1beta1ResourceSlice(value) => crate::kind(value),
+            AnyResource::ResourceV1beta2DeviceClass(value) => crate::kind(value),
+            AnyResource::ResourceV1beta2ResourceClaim(value) => crate::kind(value),
+            AnyResource::ResourceV1beta2ResourceClaimTemplate(value) => crate::kind(value),
+            AnyResource::ResourceV1beta2ResourceSlice(value) => crate::kind(value),
+            AnyResource::SchedulingV1PriorityClass(value) => crate::kind(value),
+            AnyResource::StorageV1CSIDriver(value) => crate::kind(value),
+            AnyResource::StorageV1CSINode(value) => crate::kind(value),
+            AnyResource::StorageV1CSIStorageCapacity(value) => crate::kind(value),
+            AnyResource::StorageV1StorageClass(value) => crate::kind(value),
+            AnyResource::StorageV1VolumeAttachment(value) => crate::kind(value),
+            AnyResource::StorageV1alpha1VolumeAttributesClass(value) => crate::kind(value),
+            AnyResource::StorageV1beta1VolumeAttributesClass(value) => crate::kind(value),
+            AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value) => crate::kind(value),
+            AnyResource::ApiextensionsV1CustomResourceDefinition(value) => crate::kind(value),
+            AnyResource::ApiregistrationV1APIService(value) => crate::kind(value),
+            AnyResource::Other(value) => &value.kind,
+        }
+    }
+
+    /// Gets a reference to the metadata of this resource.
+    pub fn metadata(&self) -> &crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        match self {
+            AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value) => crate::Metadata::metadata(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value) => crate::Metadata::metadata(value),
+            AnyResource::ApiserverinternalV1alpha1StorageVersion(value) => crate::Metadata::metadata(value),
+            AnyResource::AppsV1ControllerRevision(value) => crate::Metadata::metadata(value),
+            AnyResource::AppsV1DaemonSet(value) => crate::Metadata::metadata(value),
+            AnyResource::AppsV1Deployment(value) => crate::Metadata::metadata(value),
+            AnyResource::AppsV1ReplicaSet(value) => crate::Metadata::metadata(value),
+            AnyResource::AppsV1StatefulSet(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthenticationV1SelfSubjectReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthenticationV1TokenRequest(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthenticationV1TokenReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthorizationV1LocalSubjectAccessReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthorizationV1SelfSubjectAccessReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthorizationV1SelfSubjectRulesReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AuthorizationV1SubjectAccessReview(value) => crate::Metadata::metadata(value),
+            AnyResource::AutoscalingV1HorizontalPodAutoscaler(value) => crate::Metadata::metadata(value),
+            AnyResource::AutoscalingV1Scale(value) => crate::Metadata::metadata(value),
+            AnyResource::AutoscalingV2HorizontalPodAutoscaler(value) => crate::Metadata::metadata(value),
+            AnyResource::BatchV1CronJob(value) => crate::Metadata::metadata(value),
+            AnyResource::BatchV1Job(value) => crate::Metadata::metadata(value),
+            AnyResource::CertificatesV1CertificateSigningRequest(value) => crate::Metadata::metadata(value),
+            AnyResource::CertificatesV1alpha1ClusterTrustBundle(value) => crate::Metadata::metadata(value),
+            AnyResource::CertificatesV1beta1ClusterTrustBundle(value) => crate::Metadata::metadata(value),
+            AnyResource::CoordinationV1Lease(value) => crate::Metadata::metadata(value),
+            AnyResource::CoordinationV1alpha2LeaseCandidate(value) => crate::Metadata::metadata(value),
+            AnyResource::CoordinationV1beta1LeaseCandidate(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Binding(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1ComponentStatus(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1ConfigMap(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Endpoints(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Event(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1LimitRange(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Namespace(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Node(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1PersistentVolume(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1PersistentVolumeClaim(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Pod(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1PodTemplate(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1ReplicationController(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1ResourceQuota(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Secret(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1Service(value) => crate::Metadata::metadata(value),
+            AnyResource::CoreV1ServiceAccount(value) => crate::Metadata::metadata(value),
+            AnyResource::DiscoveryV1EndpointSlice(value) => crate::Metadata::metadata(value),
+            AnyResource::EventsV1Event(value) => crate::Metadata::metadata(value),
+            AnyResource::FlowcontrolV1FlowSchema(value) => crate::Metadata::metadata(value),
+            AnyResource::FlowcontrolV1PriorityLevelConfiguration(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1IPAddress(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1Ingress(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1IngressClass(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1NetworkPolicy(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1ServiceCIDR(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1beta1IPAddress(value) => crate::Metadata::metadata(value),
+            AnyResource::NetworkingV1beta1ServiceCIDR(value) => crate::Metadata::metadata(value),
+            AnyResource::NodeV1RuntimeClass(value) => crate::Metadata::metadata(value),
+            AnyResource::PolicyV1Eviction(value) => crate::Metadata::metadata(value),
+            AnyResource::PolicyV1PodDisruptionBudget(value) => crate::Metadata::metadata(value),
+            AnyResource::RbacV1ClusterRole(value) => crate::Metadata::metadata(value),
+            AnyResource::RbacV1ClusterRoleBinding(value) => crate::Metadata::metadata(value),
+            AnyResource::RbacV1Role(value) => crate::Metadata::metadata(value),
+            AnyResource::RbacV1RoleBinding(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1alpha3DeviceClass(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1alpha3DeviceTaintRule(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1alpha3ResourceClaim(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1alpha3ResourceClaimTemplate(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1alpha3ResourceSlice(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta1DeviceClass(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta1ResourceClaim(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta1ResourceClaimTemplate(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta1ResourceSlice(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta2DeviceClass(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta2ResourceClaim(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta2ResourceClaimTemplate(value) => crate::Metadata::metadata(value),
+            AnyResource::ResourceV1beta2ResourceSlice(value) => crate::Metadata::metadata(value),
+            AnyResource::SchedulingV1PriorityClass(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1CSIDriver(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1CSINode(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1CSIStorageCapacity(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1StorageClass(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1VolumeAttachment(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1alpha1VolumeAttributesClass(value) => crate::Metadata::metadata(value),
+            AnyResource::StorageV1beta1VolumeAttributesClass(value) => crate::Metadata::metadata(value),
+            AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value) => crate::Metadata::metadata(value),
+            AnyResource::ApiextensionsV1CustomResourceDefinition(value) => crate::Metadata::metadata(value),
+            AnyResource::ApiregistrationV1APIService(value) => crate::Metadata::metadata(value),
+            AnyResource::Other(value) => &value.metadata,
+        }
+    }
+
+    /// Gets a mutable reference to the metadata of this resource.
+    pub fn metadata_mut(&mut self) -> &mut crate::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        match self {
+            AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ApiserverinternalV1alpha1StorageVersion(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AppsV1ControllerRevision(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AppsV1DaemonSet(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AppsV1Deployment(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AppsV1ReplicaSet(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AppsV1StatefulSet(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthenticationV1SelfSubjectReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthenticationV1TokenRequest(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthenticationV1TokenReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthorizationV1LocalSubjectAccessReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthorizationV1SelfSubjectAccessReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthorizationV1SelfSubjectRulesReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AuthorizationV1SubjectAccessReview(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AutoscalingV1HorizontalPodAutoscaler(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AutoscalingV1Scale(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::AutoscalingV2HorizontalPodAutoscaler(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::BatchV1CronJob(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::BatchV1Job(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CertificatesV1CertificateSigningRequest(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CertificatesV1alpha1ClusterTrustBundle(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CertificatesV1beta1ClusterTrustBundle(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoordinationV1Lease(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoordinationV1alpha2LeaseCandidate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoordinationV1beta1LeaseCandidate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Binding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1ComponentStatus(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1ConfigMap(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Endpoints(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Event(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1LimitRange(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Namespace(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Node(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1PersistentVolume(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1PersistentVolumeClaim(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Pod(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1PodTemplate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1ReplicationController(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1ResourceQuota(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Secret(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1Service(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::CoreV1ServiceAccount(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::DiscoveryV1EndpointSlice(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::EventsV1Event(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::FlowcontrolV1FlowSchema(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::FlowcontrolV1PriorityLevelConfiguration(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1IPAddress(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1Ingress(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1IngressClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1NetworkPolicy(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1ServiceCIDR(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1beta1IPAddress(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NetworkingV1beta1ServiceCIDR(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::NodeV1RuntimeClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::PolicyV1Eviction(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::PolicyV1PodDisruptionBudget(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::RbacV1ClusterRole(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::RbacV1ClusterRoleBinding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::RbacV1Role(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::RbacV1RoleBinding(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1alpha3DeviceClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1alpha3DeviceTaintRule(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1alpha3ResourceClaim(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1alpha3ResourceClaimTemplate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1alpha3ResourceSlice(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta1DeviceClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta1ResourceClaim(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta1ResourceClaimTemplate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta1ResourceSlice(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta2DeviceClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta2ResourceClaim(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta2ResourceClaimTemplate(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ResourceV1beta2ResourceSlice(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::SchedulingV1PriorityClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1CSIDriver(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1CSINode(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1CSIStorageCapacity(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1StorageClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1VolumeAttachment(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1alpha1VolumeAttributesClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StorageV1beta1VolumeAttributesClass(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ApiextensionsV1CustomResourceDefinition(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::ApiregistrationV1APIService(value) => crate::Metadata::metadata_mut(value),
+            AnyResource::Other(value) => &mut value.metadata,
+        }
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AnyResource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
+        let value: crate::serde_json::Value = crate::serde::Deserialize::deserialize(deserializer)?;
+
+        let api_version = match value.get("apiVersion") {
+            Some(crate::serde_json::Value::String(api_version)) => api_version.clone(),
+            Some(_) => return Err(crate::serde::de::Error::invalid_type(crate::serde::de::Unexpected::Other("non-string apiVersion"), &"a string")),
+            None => return Err(crate::serde::de::Error::missing_field("apiVersion")),
+        };
+
+        let kind = match value.get("kind") {
+            Some(crate::serde_json::Value::String(kind)) => kind.clone(),
+            Some(_) => return Err(crate::serde::de::Error::invalid_type(crate::serde::de::Unexpected::Other("non-string kind"), &"a string")),
+            None => return Err(crate::serde::de::Error::missing_field("kind")),
+        };
+
+        Self::deserialize_with_type(&api_version, &kind, value).map_err(crate::serde::de::Error::custom)
+    }
+}
+
+impl crate::serde::Serialize for AnyResource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
+        match self {
+            AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ApiserverinternalV1alpha1StorageVersion(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AppsV1ControllerRevision(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AppsV1DaemonSet(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AppsV1Deployment(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AppsV1ReplicaSet(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AppsV1StatefulSet(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthenticationV1SelfSubjectReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthenticationV1TokenRequest(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthenticationV1TokenReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthorizationV1LocalSubjectAccessReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthorizationV1SelfSubjectAccessReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthorizationV1SelfSubjectRulesReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AuthorizationV1SubjectAccessReview(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AutoscalingV1HorizontalPodAutoscaler(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AutoscalingV1Scale(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::AutoscalingV2HorizontalPodAutoscaler(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::BatchV1CronJob(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::BatchV1Job(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CertificatesV1CertificateSigningRequest(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CertificatesV1alpha1ClusterTrustBundle(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CertificatesV1beta1ClusterTrustBundle(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoordinationV1Lease(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoordinationV1alpha2LeaseCandidate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoordinationV1beta1LeaseCandidate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Binding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1ComponentStatus(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1ConfigMap(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Endpoints(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Event(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1LimitRange(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Namespace(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Node(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1PersistentVolume(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1PersistentVolumeClaim(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Pod(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1PodTemplate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1ReplicationController(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1ResourceQuota(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Secret(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1Service(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::CoreV1ServiceAccount(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::DiscoveryV1EndpointSlice(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::EventsV1Event(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::FlowcontrolV1FlowSchema(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::FlowcontrolV1PriorityLevelConfiguration(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1IPAddress(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1Ingress(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1IngressClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1NetworkPolicy(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1ServiceCIDR(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1beta1IPAddress(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NetworkingV1beta1ServiceCIDR(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::NodeV1RuntimeClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::PolicyV1Eviction(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::PolicyV1PodDisruptionBudget(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::RbacV1ClusterRole(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::RbacV1ClusterRoleBinding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::RbacV1Role(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::RbacV1RoleBinding(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1alpha3DeviceClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1alpha3DeviceTaintRule(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1alpha3ResourceClaim(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1alpha3ResourceClaimTemplate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1alpha3ResourceSlice(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta1DeviceClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta1ResourceClaim(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta1ResourceClaimTemplate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta1ResourceSlice(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta2DeviceClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta2ResourceClaim(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta2ResourceClaimTemplate(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ResourceV1beta2ResourceSlice(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::SchedulingV1PriorityClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1CSIDriver(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1CSINode(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1CSIStorageCapacity(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1StorageClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1VolumeAttachment(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1alpha1VolumeAttributesClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StorageV1beta1VolumeAttributesClass(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ApiextensionsV1CustomResourceDefinition(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::ApiregistrationV1APIService(value) => crate::serde::Serialize::serialize(value, serializer),
+            AnyResource::Other(value) => crate::serde::Serialize::serialize(value, serializer),
+        }
+    }
+}
+
+impl From<crate::api::admissionregistration::v1::MutatingWebhookConfiguration> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1::MutatingWebhookConfiguration) -> Self {
+        AnyResource::AdmissionregistrationV1MutatingWebhookConfiguration(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1::ValidatingAdmissionPolicy> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1::ValidatingAdmissionPolicy) -> Self {
+        AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicy(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding) -> Self {
+        AnyResource::AdmissionregistrationV1ValidatingAdmissionPolicyBinding(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1::ValidatingWebhookConfiguration> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1::ValidatingWebhookConfiguration) -> Self {
+        AnyResource::AdmissionregistrationV1ValidatingWebhookConfiguration(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy) -> Self {
+        AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicy(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding) -> Self {
+        AnyResource::AdmissionregistrationV1alpha1MutatingAdmissionPolicyBinding(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicy) -> Self {
+        AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicy(value)
+    }
+}
+
+impl From<crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding> for AnyResource {
+    fn from(value: crate::api::admissionregistration::v1beta1::ValidatingAdmissionPolicyBinding) -> Self {
+        AnyResource::AdmissionregistrationV1beta1ValidatingAdmissionPolicyBinding(value)
+    }
+}
+
+impl From<crate::api::apiserverinternal::v1alpha1::StorageVersion> for AnyResource {
+    fn from(value: crate::api::apiserverinternal::v1alpha1::StorageVersion) -> Self {
+        AnyResource::ApiserverinternalV1alpha1StorageVersion(value)
+    }
+}
+
+impl From<crate::api::apps::v1::ControllerRevision> for AnyResource {
+    fn from(value: crate::api::apps::v1::ControllerRevision) -> Self {
+        AnyResource::AppsV1ControllerRevision(value)
+    }
+}
+
+impl From<crate::api::apps::v1::DaemonSet> for AnyResource {
+    fn from(value: crate::api::apps::v1::DaemonSet) -> Self {
+        AnyResource::AppsV1DaemonSet(value)
+    }
+}
+
+impl From<crate::api::apps::v1::Deployment> for AnyResource {
+    fn from(value: crate::api::apps::v1::Deployment) -> Self {
+        AnyResource::AppsV1Deployment(value)
+    }
+}
+
+impl From<crate::api::apps::v1::ReplicaSet> for AnyResource {
+    fn from(value: crate::api::apps::v1::ReplicaSet) -> Self {
+        AnyResource::AppsV1ReplicaSet(value)
+    }
+}
+
+impl From<crate::api::apps::v1::StatefulSet> for AnyResource {
+    fn from(value: crate::api::apps::v1::StatefulSet) -> Self {
+        AnyResource::AppsV1StatefulSet(value)
+    }
+}
+
+impl From<crate::api::authentication::v1::SelfSubjectReview> for AnyResource {
+    fn from(value: crate::api::authentication::v1::SelfSubjectReview) -> Self {
+        AnyResource::AuthenticationV1SelfSubjectReview(value)
+    }
+}
+
+impl From<crate::api::authentication::v1::TokenRequest> for AnyResource {
+    fn from(value: crate::api::authentication::v1::TokenRequest) -> Self {
+        AnyResource::AuthenticationV1TokenRequest(value)
+    }
+}
+
+impl From<crate::api::authentication::v1::TokenReview> for AnyResource {
+    fn from(value: crate::api::authentication::v1::TokenReview) -> Self {
+        AnyResource::AuthenticationV1TokenReview(value)
+    }
+}
+
+impl From<crate::api::authorization::v1::LocalSubjectAccessReview> for AnyResource {
+    fn from(value: crate::api::authorization::v1::LocalSubjectAccessReview) -> Self {
+        AnyResource::AuthorizationV1LocalSubjectAccessReview(value)
+    }
+}
+
+impl From<crate::api::authorization::v1::SelfSubjectAccessReview> for AnyResource {
+    fn from(value: crate::api::authorization::v1::SelfSubjectAccessReview) -> Self {
+        AnyResource::AuthorizationV1SelfSubjectAccessReview(value)
+    }
+}
+
+impl From<crate::api::authorization::v1::SelfSubjectRulesReview> for AnyResource {
+    fn from(value: crate::api::authorization::v1::SelfSubjectRulesReview) -> Self {
+        AnyResource::AuthorizationV1SelfSubjectRulesReview(value)
+    }
+}
+
+impl From<crate::api::authorization::v1::SubjectAccessReview> for AnyResource {
+    fn from(value: crate::api::authorization::v1::SubjectAccessReview) -> Self {
+        AnyResource::AuthorizationV1SubjectAccessReview(value)
+    }
+}
+
+impl From<crate::api::autoscaling::v1::HorizontalPodAutoscaler> for AnyResource {
+    fn from(value: crate::api::autoscaling::v1::HorizontalPodAutoscaler) -> Self {
+        AnyResource::AutoscalingV1HorizontalPodAutoscaler(value)
+    }
+}
+
+impl From<crate::api::autoscaling::v1::Scale> for AnyResource {
+    fn from(value: crate::api::autoscaling::v1::Scale) -> Self {
+        AnyResource::AutoscalingV1Scale(value)
+    }
+}
+
+impl From<crate::api::autoscaling::v2::HorizontalPodAutoscaler> for AnyResource {
+    fn from(value: crate::api::autoscaling::v2::HorizontalPodAutoscaler) -> Self {
+        AnyResource::AutoscalingV2HorizontalPodAutoscaler(value)
+    }
+}
+
+impl From<crate::api::batch::v1::CronJob> for AnyResource {
+    fn from(value: crate::api::batch::v1::CronJob) -> Self {
+        AnyResource::BatchV1CronJob(value)
+    }
+}
+
+impl From<crate::api::batch::v1::Job> for AnyResource {
+    fn from(value: crate::api::batch::v1::Job) -> Self {
+        AnyResource::BatchV1Job(value)
+    }
+}
+
+impl From<crate::api::certificates::v1::CertificateSigningRequest> for AnyResource {
+    fn from(value: crate::api::certificates::v1::CertificateSigningRequest) -> Self {
+        AnyResource::CertificatesV1CertificateSigningRequest(value)
+    }
+}
+
+impl From<crate::api::certificates::v1alpha1::ClusterTrustBundle> for AnyResource {
+    fn from(value: crate::api::certificates::v1alpha1::ClusterTrustBundle) -> Self {
+        AnyResource::CertificatesV1alpha1ClusterTrustBundle(value)
+    }
+}
+
+impl From<crate::api::certificates::v1beta1::ClusterTrustBundle> for AnyResource {
+    fn from(value: crate::api::certificates::v1beta1::ClusterTrustBundle) -> Self {
+        AnyResource::CertificatesV1beta1ClusterTrustBundle(value)
+    }
+}
+
+impl From<crate::api::coordination::v1::Lease> for AnyResource {
+    fn from(value: crate::api::coordination::v1::Lease) -> Self {
+        AnyResource::CoordinationV1Lease(value)
+    }
+}
+
+impl From<crate::api::coordination::v1alpha2::LeaseCandidate> for AnyResource {
+    fn from(value: crate::api::coordination::v1alpha2::LeaseCandidate) -> Self {
+        AnyResource::CoordinationV1alpha2LeaseCandidate(value)
+    }
+}
+
+impl From<crate::api::coordination::v1beta1::LeaseCandidate> for AnyResource {
+    fn from(value: crate::api::coordination::v1beta1::LeaseCandidate) -> Self {
+        AnyResource::CoordinationV1beta1LeaseCandidate(value)
+    }
+}
+
+impl From<crate::api::core::v1::Binding> for AnyResource {
+    fn from(value: crate::api::core::v1::Binding) -> Self {
+        AnyResource::CoreV1Binding(value)
+    }
+}
+
+impl From<crate::api::core::v1::ComponentStatus> for AnyResource {
+    fn from(value: crate::api::core::v1::ComponentStatus) -> Self {
+        AnyResource::CoreV1ComponentStatus(value)
+    }
+}
+
+impl From<crate::api::core::v1::ConfigMap> for AnyResource {
+    fn from(value: crate::api::core::v1::ConfigMap) -> Self {
+        AnyResource::CoreV1ConfigMap(value)
+    }
+}
+
+impl From<crate::api::core::v1::Endpoints> for AnyResource {
+    fn from(value: crate::api::core::v1::Endpoints) -> Self {
+        AnyResource::CoreV1Endpoints(value)
+    }
+}
+
+impl From<crate::api::core::v1::Event> for AnyResource {
+    fn from(value: crate::api::core::v1::Event) -> Self {
+        AnyResource::CoreV1Event(value)
+    }
+}
+
+impl From<crate::api::core::v1::LimitRange> for AnyResource {
+    fn from(value: crate::api::core::v1::LimitRange) -> Self {
+        AnyResource::CoreV1LimitRange(value)
+    }
+}
+
+impl From<crate::api::core::v1::Namespace> for AnyResource {
+    fn from(value: crate::api::core::v1::Namespace) -> Self {
+        AnyResource::CoreV1Namespace(value)
+    }
+}
+
+impl From<crate::api::core::v1::Node> for AnyResource {
+    fn from(value: crate::api::core::v1::Node) -> Self {
+        AnyResource::CoreV1Node(value)
+    }
+}
+
+impl From<crate::api::core::v1::PersistentVolume> for AnyResource {
+    fn from(value: crate::api::core::v1::PersistentVolume) -> Self {
+        AnyResource::CoreV1PersistentVolume(value)
+    }
+}
+
+impl From<crate::api::core::v1::PersistentVolumeClaim> for AnyResource {
+    fn from(value: crate::api::core::v1::PersistentVolumeClaim) -> Self {
+        AnyResource::CoreV1PersistentVolumeClaim(value)
+    }
+}
+
+impl From<crate::api::core::v1::Pod> for AnyResource {
+    fn from(value: crate::api::core::v1::Pod) -> Self {
+        AnyResource::CoreV1Pod(value)
+    }
+}
+
+impl From<crate::api::core::v1::PodTemplate> for AnyResource {
+    fn from(value: crate::api::core::v1::PodTemplate) -> Self {
+        AnyResource::CoreV1PodTemplate(value)
+    }
+}
+
+impl From<crate::api::core::v1::ReplicationController> for AnyResource {
+    fn from(value: crate::api::core::v1::ReplicationController) -> Self {
+        AnyResource::CoreV1ReplicationController(value)
+    }
+}
+
+impl From<crate::api::core::v1::ResourceQuota> for AnyResource {
+    fn from(value: crate::api::core::v1::ResourceQuota) -> Self {
+        AnyResource::CoreV1ResourceQuota(value)
+    }
+}
+
+impl From<crate::api::core::v1::Secret> for AnyResource {
+    fn from(value: crate::api::core::v1::Secret) -> Self {
+        AnyResource::CoreV1Secret(value)
+    }
+}
+
+impl From<crate::api::core::v1::Service> for AnyResource {
+    fn from(value: crate::api::core::v1::Service) -> Self {
+        AnyResource::CoreV1Service(value)
+    }
+}
+
+impl From<crate::api::core::v1::ServiceAccount> for AnyResource {
+    fn from(value: crate::api::core::v1::ServiceAccount) -> Self {
+        AnyResource::CoreV1ServiceAccount(value)
+    }
+}
+
+impl From<crate::api::discovery::v1::EndpointSlice> for AnyResource {
+    fn from(value: crate::api::discovery::v1::EndpointSlice) -> Self {
+        AnyResource::DiscoveryV1EndpointSlice(value)
+    }
+}
+
+impl From<crate::api::events::v1::Event> for AnyResource {
+    fn from(value: crate::api::events::v1::Event) -> Self {
+        AnyResource::EventsV1Event(value)
+    }
+}
+
+impl From<crate::api::flowcontrol::v1::FlowSchema> for AnyResource {
+    fn from(value: crate::api::flowcontrol::v1::FlowSchema) -> Self {
+        AnyResource::FlowcontrolV1FlowSchema(value)
+    }
+}
+
+impl From<crate::api::flowcontrol::v1::PriorityLevelConfiguration> for AnyResource {
+    fn from(value: crate::api::flowcontrol::v1::PriorityLevelConfiguration) -> Self {
+        AnyResource::FlowcontrolV1PriorityLevelConfiguration(value)
+    }
+}
+
+impl From<crate::api::networking::v1::IPAddress> for AnyResource {
+    fn from(value: crate::api::networking::v1::IPAddress) -> Self {
+        AnyResource::NetworkingV1IPAddress(value)
+    }
+}
+
+impl From<crate::api::networking::v1::Ingress> for AnyResource {
+    fn from(value: crate::api::networking::v1::Ingress) -> Self {
+        AnyResource::NetworkingV1Ingress(value)
+    }
+}
+
+impl From<crate::api::networking::v1::IngressClass> for AnyResource {
+    fn from(value: crate::api::networking::v1::IngressClass) -> Self {
+        AnyResource::NetworkingV1IngressClass(value)
+    }
+}
+
+impl From<crate::api::networking::v1::NetworkPolicy> for AnyResource {
+    fn from(value: crate::api::networking::v1::NetworkPolicy) -> Self {
+        AnyResource::NetworkingV1NetworkPolicy(value)
+    }
+}
+
+impl From<crate::api::networking::v1::ServiceCIDR> for AnyResource {
+    fn from(value: crate::api::networking::v1::ServiceCIDR) -> Self {
+        AnyResource::NetworkingV1ServiceCIDR(value)
+    }
+}
+
+impl From<crate::api::networking::v1beta1::IPAddress> for AnyResource {
+    fn from(value: crate::api::networking::v1beta1::IPAddress) -> Self {
+        AnyResource::NetworkingV1beta1IPAddress(value)
+    }
+}
+
+impl From<crate::api::networking::v1beta1::ServiceCIDR> for AnyResource {
+    fn from(value: crate::api::networking::v1beta1::ServiceCIDR) -> Self {
+        AnyResource::NetworkingV1beta1ServiceCIDR(value)
+    }
+}
+
+impl From<crate::api::node::v1::RuntimeClass> for AnyResource {
+    fn from(value: crate::api::node::v1::RuntimeClass) -> Self {
+        AnyResource::NodeV1RuntimeClass(value)
+    }
+}
+
+impl From<crate::api::policy::v1::Eviction> for AnyResource {
+    fn from(value: crate::api::policy::v1::Eviction) -> Self {
+        AnyResource::PolicyV1Eviction(value)
+    }
+}
+
+impl From<crate::api::policy::v1::PodDisruptionBudget> for AnyResource {
+    fn from(value: crate::api::policy::v1::PodDisruptionBudget) -> Self {
+        AnyResource::PolicyV1PodDisruptionBudget(value)
+    }
+}
+
+impl From<crate::api::rbac::v1::ClusterRole> for AnyResource {
+    fn from(value: crate::api::rbac::v1::ClusterRole) -> Self {
+        AnyResource::RbacV1ClusterRole(value)
+    }
+}
+
+impl From<crate::api::rbac::v1::ClusterRoleBinding> for AnyResource {
+    fn from(value: crate::api::rbac::v1::ClusterRoleBinding) -> Self {
+        AnyResource::RbacV1ClusterRoleBinding(value)
+    }
+}
+
+impl From<crate::api::rbac::v1::Role> for AnyResource {
+    fn from(value: crate::api::rbac::v1::Role) -> Self {
+        AnyResource::RbacV1Role(value)
+    }
+}
+
+impl From<crate::api::rbac::v1::RoleBinding> for AnyResource {
+    fn from(value: crate::api::rbac::v1::RoleBinding) -> Self {
+        AnyResource::RbacV1RoleBinding(value)
+    }
+}
+
+impl From<crate::api::resource::v1alpha3::DeviceClass> for AnyResource {
+    fn from(value: crate::api::resource::v1alpha3::DeviceClass) -> Self {
+        AnyResource::ResourceV1alpha3DeviceClass(value)
+    }
+}
+
+impl From<crate::api::resource::v1alpha3::DeviceTaintRule> for AnyResource {
+    fn from(value: crate::api::resource::v1alpha3::DeviceTaintRule) -> Self {
+        AnyResource::ResourceV1alpha3DeviceTaintRule(value)
+    }
+}
+
+impl From<crate::api::resource::v1alpha3::ResourceClaim> for AnyResource {
+    fn from(value: crate::api::resource::v1alpha3::ResourceClaim) -> Self {
+        AnyResource::ResourceV1alpha3ResourceClaim(value)
+    }
+}
+
+impl From<crate::api::resource::v1alpha3::ResourceClaimTemplate> for AnyResource {
+    fn from(value: crate::api::resource::v1alpha3::ResourceClaimTemplate) -> Self {
+        AnyResource::ResourceV1alpha3ResourceClaimTemplate(value)
+    }
+}
+
+impl From<crate::api::resource::v1alpha3::ResourceSlice> for AnyResource {
+    fn from(value: crate::api::resource::v1alpha3::ResourceSlice) -> Self {
+        AnyResource::ResourceV1alpha3ResourceSlice(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta1::DeviceClass> for AnyResource {
+    fn from(value: crate::api::resource::v1beta1::DeviceClass) -> Self {
+        AnyResource::ResourceV1beta1DeviceClass(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta1::ResourceClaim> for AnyResource {
+    fn from(value: crate::api::resource::v1beta1::ResourceClaim) -> Self {
+        AnyResource::ResourceV1beta1ResourceClaim(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta1::ResourceClaimTemplate> for AnyResource {
+    fn from(value: crate::api::resource::v1beta1::ResourceClaimTemplate) -> Self {
+        AnyResource::ResourceV1beta1ResourceClaimTemplate(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta1::ResourceSlice> for AnyResource {
+    fn from(value: crate::api::resource::v1beta1::ResourceSlice) -> Self {
+        AnyResource::ResourceV1beta1ResourceSlice(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta2::DeviceClass> for AnyResource {
+    fn from(value: crate::api::resource::v1beta2::DeviceClass) -> Self {
+        AnyResource::ResourceV1beta2DeviceClass(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta2::ResourceClaim> for AnyResource {
+    fn from(value: crate::api::resource::v1beta2::ResourceClaim) -> Self {
+        AnyResource::ResourceV1beta2ResourceClaim(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta2::ResourceClaimTemplate> for AnyResource {
+    fn from(value: crate::api::resource::v1beta2::ResourceClaimTemplate) -> Self {
+        AnyResource::ResourceV1beta2ResourceClaimTemplate(value)
+    }
+}
+
+impl From<crate::api::resource::v1beta2::ResourceSlice> for AnyResource {
+    fn from(value: crate::api::resource::v1beta2::ResourceSlice) -> Self {
+        AnyResource::ResourceV1beta2ResourceSlice(value)
+    }
+}
+
+impl From<crate::api::scheduling::v1::PriorityClass> for AnyResource {
+    fn from(value: crate::api::scheduling::v1::PriorityClass) -> Self {
+        AnyResource::SchedulingV1PriorityClass(value)
+    }
+}
+
+impl From<crate::api::storage::v1::CSIDriver> for AnyResource {
+    fn from(value: crate::api::storage::v1::CSIDriver) -> Self {
+        AnyResource::StorageV1CSIDriver(value)
+    }
+}
+
+impl From<crate::api::storage::v1::CSINode> for AnyResource {
+    fn from(value: crate::api::storage::v1::CSINode) -> Self {
+        AnyResource::StorageV1CSINode(value)
+    }
+}
+
+impl From<crate::api::storage::v1::CSIStorageCapacity> for AnyResource {
+    fn from(value: crate::api::storage::v1::CSIStorageCapacity) -> Self {
+        AnyResource::StorageV1CSIStorageCapacity(value)
+    }
+}
+
+impl From<crate::api::storage::v1::StorageClass> for AnyResource {
+    fn from(value: crate::api::storage::v1::StorageClass) -> Self {
+        AnyResource::StorageV1StorageClass(value)
+    }
+}
+
+impl From<crate::api::storage::v1::VolumeAttachment> for AnyResource {
+    fn from(value: crate::api::storage::v1::VolumeAttachment) -> Self {
+        AnyResource::StorageV1VolumeAttachment(value)
+    }
+}
+
+impl From<crate::api::storage::v1alpha1::VolumeAttributesClass> for AnyResource {
+    fn from(value: crate::api::storage::v1alpha1::VolumeAttributesClass) -> Self {
+        AnyResource::StorageV1alpha1VolumeAttributesClass(value)
+    }
+}
+
+impl From<crate::api::storage::v1beta1::VolumeAttributesClass> for AnyResource {
+    fn from(value: crate::api::storage::v1beta1::VolumeAttributesClass) -> Self {
+        AnyResource::StorageV1beta1VolumeAttributesClass(value)
+    }
+}
+
+impl From<crate::api::storagemigration::v1alpha1::StorageVersionMigration> for AnyResource {
+    fn from(value: crate::api::storagemigration::v1alpha1::StorageVersionMigration) -> Self {
+        AnyResource::StoragemigrationV1alpha1StorageVersionMigration(value)
+    }
+}
+
+impl From<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition> for AnyResource {
+    fn from(value: crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition) -> Self {
+        AnyResource::ApiextensionsV1CustomResourceDefinition(value)
+    }
+}
+
+impl From<crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService> for AnyResource {
+    fn from(value: crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService) -> Self {
+        AnyResource::ApiregistrationV1APIService(value)
+    }
+}
+
+impl From<crate::DynamicObject> for AnyResource {
+    fn from(value: crate::DynamicObject) -> Self {
+        AnyResource::Other(value)
+    }
+}
@@ -27,6 +27,46 @@ impl crate::DeepMerge for AuditAnnotation {
     }
 }
 
+impl crate::Defaulting for AuditAnnotation {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.value_expression);
+    }
+}
+
+impl crate::diff::Diff for AuditAnnotation {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.value_expression, &other.value_expression, &crate::diff::Path::Field { parent: path, name: "valueExpression" }, changes);
+    }
+}
+
+impl AuditAnnotation {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AuditAnnotation> {
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `valueExpression` field.
+    pub fn value_expression(self) -> crate::FieldPath<std::string::String> {
+        self.child("valueExpression")
+    }
+}
+
+impl crate::Normalize for AuditAnnotation {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.value_expression);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AuditAnnotation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -111,6 +151,36 @@ impl crate::serde::Serialize for AuditAnnotation {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AuditAnnotation {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.key, 1, buf);
+        crate::protobuf::Field::encode_field(&self.value_expression, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_value_expression: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value_expression, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AuditAnnotation {
+            key: value_key.unwrap_or_default(),
+            value_expression: value_value_expression.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AuditAnnotation {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AuditAnnotation {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -70,6 +70,128 @@ impl crate::DeepMerge for ValidatingAdmissionPolicySpec {
     }
 }
 
+impl crate::Defaulting for ValidatingAdmissionPolicySpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.audit_annotations);
+        crate::Defaulting::set_defaults(&mut self.failure_policy);
+        crate::Defaulting::set_defaults(&mut self.match_conditions);
+        crate::Defaulting::set_defaults(&mut self.match_constraints);
+        crate::Defaulting::set_defaults(&mut self.param_kind);
+        crate::Defaulting::set_defaults(&mut self.validations);
+        crate::Defaulting::set_defaults(&mut self.variables);
+    }
+}
+
+impl crate::diff::Diff for ValidatingAdmissionPolicySpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.audit_annotations,
+            &other.audit_annotations,
+            &crate::diff::Path::Field { parent: path, name: "auditAnnotations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.failure_policy, &other.failure_policy, &crate::diff::Path::Field { parent: path, name: "failurePolicy" }, changes);
+        crate::diff::strategies::list::map(
+            &self.match_conditions,
+            &other.match_conditions,
+            &crate::diff::Path::Field { parent: path, name: "matchConditions" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.match_constraints, &other.match_constraints, &crate::diff::Path::Field { parent: path, name: "matchConstraints" }, changes);
+        crate::diff::Diff::diff(&self.param_kind, &other.param_kind, &crate::diff::Path::Field { parent: path, name: "paramKind" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.validations,
+            &other.validations,
+            &crate::diff::Path::Field { parent: path, name: "validations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.variables,
+            &other.variables,
+            &crate::diff::Path::Field { parent: path, name: "variables" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ValidatingAdmissionPolicySpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ValidatingAdmissionPolicySpec> {
+    /// The path of the `auditAnnotations` field.
+    pub fn audit_annotations(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::AuditAnnotation>> {
+        self.child("auditAnnotations")
+    }
+
+    /// The path of the `failurePolicy` field.
+    pub fn failure_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("failurePolicy")
+    }
+
+    /// The path of the `matchConditions` field.
+    pub fn match_conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::MatchCondition>> {
+        self.child("matchConditions")
+    }
+
+    /// The path of the `matchConstraints` field.
+    pub fn match_constraints(self) -> crate::FieldPath<crate::api::admissionregistration::v1::MatchResources> {
+        self.child("matchConstraints")
+    }
+
+    /// The path of the `paramKind` field.
+    pub fn param_kind(self) -> crate::FieldPath<crate::api::admissionregistration::v1::ParamKind> {
+        self.child("paramKind")
+    }
+
+    /// The path of the `validations` field.
+    pub fn validations(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::Validation>> {
+        self.child("validations")
+    }
+
+    /// The path of the `variables` field.
+    pub fn variables(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::Variable>> {
+        self.child("variables")
+    }
+}
+
+impl crate::Normalize for ValidatingAdmissionPolicySpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.audit_annotations, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.failure_policy);
+        crate::normalize_strategies::list::map(&mut self.match_conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.match_constraints);
+        crate::Normalize::normalize(&mut self.param_kind);
+        crate::normalize_strategies::list::atomic(&mut self.validations, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.variables, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ValidatingAdmissionPolicySpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -209,6 +331,80 @@ impl crate::serde::Serialize for ValidatingAdmissionPolicySpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ValidatingAdmissionPolicySpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.param_kind {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.match_constraints {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.validations {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.failure_policy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.audit_annotations {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.match_conditions {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.variables {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audit_annotations: Option<std::vec::Vec<crate::api::admissionregistration::v1::AuditAnnotation>> = None;
+        let mut value_failure_policy: Option<std::string::String> = None;
+        let mut value_match_conditions: Option<std::vec::Vec<crate::api::admissionregistration::v1::MatchCondition>> = None;
+        let mut value_match_constraints: Option<crate::api::admissionregistration::v1::MatchResources> = None;
+        let mut value_param_kind: Option<crate::api::admissionregistration::v1::ParamKind> = None;
+        let mut value_validations: Option<std::vec::Vec<crate::api::admissionregistration::v1::Validation>> = None;
+        let mut value_variables: Option<std::vec::Vec<crate::api::admissionregistration::v1::Variable>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_param_kind, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_match_constraints, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_validations, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_failure_policy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_audit_annotations, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_match_conditions, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_variables, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ValidatingAdmissionPolicySpec {
+            audit_annotations: value_audit_annotations,
+            failure_policy: value_failure_policy,
+            match_conditions: value_match_conditions,
+            match_constraints: value_match_constraints,
+            param_kind: value_param_kind,
+            validations: value_validations,
+            variables: value_variables,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ValidatingAdmissionPolicySpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "matchConditions" | "variables" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ValidatingAdmissionPolicySpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
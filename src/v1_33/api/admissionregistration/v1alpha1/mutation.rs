@@ -21,6 +21,54 @@ impl crate::DeepMerge for Mutation {
     }
 }
 
+impl crate::Defaulting for Mutation {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.apply_configuration);
+        crate::Defaulting::set_defaults(&mut self.json_patch);
+        crate::Defaulting::set_defaults(&mut self.patch_type);
+    }
+}
+
+impl crate::diff::Diff for Mutation {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.apply_configuration, &other.apply_configuration, &crate::diff::Path::Field { parent: path, name: "applyConfiguration" }, changes);
+        crate::diff::Diff::diff(&self.json_patch, &other.json_patch, &crate::diff::Path::Field { parent: path, name: "jsonPatch" }, changes);
+        crate::diff::Diff::diff(&self.patch_type, &other.patch_type, &crate::diff::Path::Field { parent: path, name: "patchType" }, changes);
+    }
+}
+
+impl Mutation {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Mutation> {
+    /// The path of the `applyConfiguration` field.
+    pub fn apply_configuration(self) -> crate::FieldPath<crate::api::admissionregistration::v1alpha1::ApplyConfiguration> {
+        self.child("applyConfiguration")
+    }
+
+    /// The path of the `jsonPatch` field.
+    pub fn json_patch(self) -> crate::FieldPath<crate::api::admissionregistration::v1alpha1::JSONPatch> {
+        self.child("jsonPatch")
+    }
+
+    /// The path of the `patchType` field.
+    pub fn patch_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("patchType")
+    }
+}
+
+impl crate::Normalize for Mutation {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.apply_configuration);
+        crate::Normalize::normalize(&mut self.json_patch);
+        crate::Normalize::normalize(&mut self.patch_type);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Mutation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +166,44 @@ impl crate::serde::Serialize for Mutation {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Mutation {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.patch_type, 2, buf);
+        if let Some(value) = &self.apply_configuration {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.json_patch {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_apply_configuration: Option<crate::api::admissionregistration::v1alpha1::ApplyConfiguration> = None;
+        let mut value_json_patch: Option<crate::api::admissionregistration::v1alpha1::JSONPatch> = None;
+        let mut value_patch_type: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_patch_type, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_apply_configuration, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_json_patch, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Mutation {
+            apply_configuration: value_apply_configuration,
+            json_patch: value_json_patch,
+            patch_type: value_patch_type.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Mutation {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Mutation {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -65,6 +65,90 @@ impl crate::DeepMerge for MatchResources {
     }
 }
 
+impl crate::Defaulting for MatchResources {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.exclude_resource_rules);
+        crate::Defaulting::set_defaults(&mut self.match_policy);
+        crate::Defaulting::set_defaults(&mut self.namespace_selector);
+        crate::Defaulting::set_defaults(&mut self.object_selector);
+        crate::Defaulting::set_defaults(&mut self.resource_rules);
+    }
+}
+
+impl crate::diff::Diff for MatchResources {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.exclude_resource_rules,
+            &other.exclude_resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "excludeResourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.match_policy, &other.match_policy, &crate::diff::Path::Field { parent: path, name: "matchPolicy" }, changes);
+        crate::diff::Diff::diff(&self.namespace_selector, &other.namespace_selector, &crate::diff::Path::Field { parent: path, name: "namespaceSelector" }, changes);
+        crate::diff::Diff::diff(&self.object_selector, &other.object_selector, &crate::diff::Path::Field { parent: path, name: "objectSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.resource_rules,
+            &other.resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "resourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl MatchResources {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<MatchResources> {
+    /// The path of the `excludeResourceRules` field.
+    pub fn exclude_resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1beta1::NamedRuleWithOperations>> {
+        self.child("excludeResourceRules")
+    }
+
+    /// The path of the `matchPolicy` field.
+    pub fn match_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("matchPolicy")
+    }
+
+    /// The path of the `namespaceSelector` field.
+    pub fn namespace_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("namespaceSelector")
+    }
+
+    /// The path of the `objectSelector` field.
+    pub fn object_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("objectSelector")
+    }
+
+    /// The path of the `resourceRules` field.
+    pub fn resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1beta1::NamedRuleWithOperations>> {
+        self.child("resourceRules")
+    }
+}
+
+impl crate::Normalize for MatchResources {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.exclude_resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.match_policy);
+        crate::Normalize::normalize(&mut self.namespace_selector);
+        crate::Normalize::normalize(&mut self.object_selector);
+        crate::normalize_strategies::list::atomic(&mut self.resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MatchResources {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -184,6 +268,58 @@ impl crate::serde::Serialize for MatchResources {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MatchResources {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.namespace_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.object_selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.resource_rules {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.exclude_resource_rules {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.match_policy {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_exclude_resource_rules: Option<std::vec::Vec<crate::api::admissionregistration::v1beta1::NamedRuleWithOperations>> = None;
+        let mut value_match_policy: Option<std::string::String> = None;
+        let mut value_namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_object_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_resource_rules: Option<std::vec::Vec<crate::api::admissionregistration::v1beta1::NamedRuleWithOperations>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_namespace_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_object_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource_rules, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_exclude_resource_rules, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_match_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(MatchResources {
+            exclude_resource_rules: value_exclude_resource_rules,
+            match_policy: value_match_policy,
+            namespace_selector: value_namespace_selector,
+            object_selector: value_object_selector,
+            resource_rules: value_resource_rules,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for MatchResources {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MatchResources {
     fn schema_name() -> std::borrow::Cow<'static, str> {
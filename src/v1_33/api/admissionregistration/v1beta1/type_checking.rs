@@ -13,6 +13,48 @@ impl crate::DeepMerge for TypeChecking {
     }
 }
 
+impl crate::Defaulting for TypeChecking {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.expression_warnings);
+    }
+}
+
+impl crate::diff::Diff for TypeChecking {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.expression_warnings,
+            &other.expression_warnings,
+            &crate::diff::Path::Field { parent: path, name: "expressionWarnings" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl TypeChecking {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TypeChecking> {
+    /// The path of the `expressionWarnings` field.
+    pub fn expression_warnings(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1beta1::ExpressionWarning>> {
+        self.child("expressionWarnings")
+    }
+}
+
+impl crate::Normalize for TypeChecking {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.expression_warnings, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TypeChecking {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for TypeChecking {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TypeChecking {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.expression_warnings {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_expression_warnings: Option<std::vec::Vec<crate::api::admissionregistration::v1beta1::ExpressionWarning>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_expression_warnings, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TypeChecking {
+            expression_warnings: value_expression_warnings,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TypeChecking {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TypeChecking {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,52 @@ impl crate::DeepMerge for RollingUpdateDaemonSet {
     }
 }
 
+impl crate::Defaulting for RollingUpdateDaemonSet {
+    fn set_defaults(&mut self) {
+        if self.max_surge.is_none() {
+            self.max_surge = Some(crate::apimachinery::pkg::util::intstr::IntOrString::Int(0));
+        }
+        if self.max_unavailable.is_none() {
+            self.max_unavailable = Some(crate::apimachinery::pkg::util::intstr::IntOrString::Int(1));
+        }
+        crate::Defaulting::set_defaults(&mut self.max_surge);
+        crate::Defaulting::set_defaults(&mut self.max_unavailable);
+    }
+}
+
+impl crate::diff::Diff for RollingUpdateDaemonSet {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max_surge, &other.max_surge, &crate::diff::Path::Field { parent: path, name: "maxSurge" }, changes);
+        crate::diff::Diff::diff(&self.max_unavailable, &other.max_unavailable, &crate::diff::Path::Field { parent: path, name: "maxUnavailable" }, changes);
+    }
+}
+
+impl RollingUpdateDaemonSet {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<RollingUpdateDaemonSet> {
+    /// The path of the `maxSurge` field.
+    pub fn max_surge(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("maxSurge")
+    }
+
+    /// The path of the `maxUnavailable` field.
+    pub fn max_unavailable(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("maxUnavailable")
+    }
+}
+
+impl crate::Normalize for RollingUpdateDaemonSet {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max_surge);
+        crate::Normalize::normalize(&mut self.max_unavailable);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RollingUpdateDaemonSet {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +152,40 @@ impl crate::serde::Serialize for RollingUpdateDaemonSet {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for RollingUpdateDaemonSet {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.max_unavailable {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.max_surge {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max_surge: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_max_unavailable, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_max_surge, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(RollingUpdateDaemonSet {
+            max_surge: value_max_surge,
+            max_unavailable: value_max_unavailable,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for RollingUpdateDaemonSet {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RollingUpdateDaemonSet {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -16,6 +16,38 @@ impl crate::DeepMerge for StatefulSetOrdinals {
     }
 }
 
+impl crate::Defaulting for StatefulSetOrdinals {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.start);
+    }
+}
+
+impl crate::diff::Diff for StatefulSetOrdinals {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.start, &other.start, &crate::diff::Path::Field { parent: path, name: "start" }, changes);
+    }
+}
+
+impl StatefulSetOrdinals {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StatefulSetOrdinals> {
+    /// The path of the `start` field.
+    pub fn start(self) -> crate::FieldPath<i32> {
+        self.child("start")
+    }
+}
+
+impl crate::Normalize for StatefulSetOrdinals {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.start);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StatefulSetOrdinals {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -95,6 +127,34 @@ impl crate::serde::Serialize for StatefulSetOrdinals {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StatefulSetOrdinals {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.start {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_start: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_start, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StatefulSetOrdinals {
+            start: value_start,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StatefulSetOrdinals {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetOrdinals {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,52 @@ impl crate::DeepMerge for StatefulSetPersistentVolumeClaimRetentionPolicy {
     }
 }
 
+impl crate::Defaulting for StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn set_defaults(&mut self) {
+        if self.when_deleted.is_none() {
+            self.when_deleted = Some("Retain".into());
+        }
+        if self.when_scaled.is_none() {
+            self.when_scaled = Some("Retain".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.when_deleted);
+        crate::Defaulting::set_defaults(&mut self.when_scaled);
+    }
+}
+
+impl crate::diff::Diff for StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.when_deleted, &other.when_deleted, &crate::diff::Path::Field { parent: path, name: "whenDeleted" }, changes);
+        crate::diff::Diff::diff(&self.when_scaled, &other.when_scaled, &crate::diff::Path::Field { parent: path, name: "whenScaled" }, changes);
+    }
+}
+
+impl StatefulSetPersistentVolumeClaimRetentionPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StatefulSetPersistentVolumeClaimRetentionPolicy> {
+    /// The path of the `whenDeleted` field.
+    pub fn when_deleted(self) -> crate::FieldPath<std::string::String> {
+        self.child("whenDeleted")
+    }
+
+    /// The path of the `whenScaled` field.
+    pub fn when_scaled(self) -> crate::FieldPath<std::string::String> {
+        self.child("whenScaled")
+    }
+}
+
+impl crate::Normalize for StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.when_deleted);
+        crate::Normalize::normalize(&mut self.when_scaled);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StatefulSetPersistentVolumeClaimRetentionPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +152,40 @@ impl crate::serde::Serialize for StatefulSetPersistentVolumeClaimRetentionPolicy
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StatefulSetPersistentVolumeClaimRetentionPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.when_deleted {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.when_scaled {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_when_deleted: Option<std::string::String> = None;
+        let mut value_when_scaled: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_when_deleted, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_when_scaled, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StatefulSetPersistentVolumeClaimRetentionPolicy {
+            when_deleted: value_when_deleted,
+            when_scaled: value_when_scaled,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StatefulSetPersistentVolumeClaimRetentionPolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetPersistentVolumeClaimRetentionPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
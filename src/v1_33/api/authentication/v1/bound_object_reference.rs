@@ -25,6 +25,62 @@ impl crate::DeepMerge for BoundObjectReference {
     }
 }
 
+impl crate::Defaulting for BoundObjectReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_version);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for BoundObjectReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_version, &other.api_version, &crate::diff::Path::Field { parent: path, name: "apiVersion" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl BoundObjectReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<BoundObjectReference> {
+    /// The path of the `apiVersion` field.
+    pub fn api_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiVersion")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for BoundObjectReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_version);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for BoundObjectReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for BoundObjectReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for BoundObjectReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.kind {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.api_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_version: Option<std::string::String> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_api_version, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(BoundObjectReference {
+            api_version: value_api_version,
+            kind: value_kind,
+            name: value_name,
+            uid: value_uid,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for BoundObjectReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for BoundObjectReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
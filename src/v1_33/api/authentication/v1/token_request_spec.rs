@@ -21,6 +21,64 @@ impl crate::DeepMerge for TokenRequestSpec {
     }
 }
 
+impl crate::Defaulting for TokenRequestSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.audiences);
+        crate::Defaulting::set_defaults(&mut self.bound_object_ref);
+        crate::Defaulting::set_defaults(&mut self.expiration_seconds);
+    }
+}
+
+impl crate::diff::Diff for TokenRequestSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.audiences,
+            &other.audiences,
+            &crate::diff::Path::Field { parent: path, name: "audiences" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.bound_object_ref, &other.bound_object_ref, &crate::diff::Path::Field { parent: path, name: "boundObjectRef" }, changes);
+        crate::diff::Diff::diff(&self.expiration_seconds, &other.expiration_seconds, &crate::diff::Path::Field { parent: path, name: "expirationSeconds" }, changes);
+    }
+}
+
+impl TokenRequestSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TokenRequestSpec> {
+    /// The path of the `audiences` field.
+    pub fn audiences(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("audiences")
+    }
+
+    /// The path of the `boundObjectRef` field.
+    pub fn bound_object_ref(self) -> crate::FieldPath<crate::api::authentication::v1::BoundObjectReference> {
+        self.child("boundObjectRef")
+    }
+
+    /// The path of the `expirationSeconds` field.
+    pub fn expiration_seconds(self) -> crate::FieldPath<i64> {
+        self.child("expirationSeconds")
+    }
+}
+
+impl crate::Normalize for TokenRequestSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.audiences, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.bound_object_ref);
+        crate::Normalize::normalize(&mut self.expiration_seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TokenRequestSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +176,44 @@ impl crate::serde::Serialize for TokenRequestSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TokenRequestSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.audiences, 1, buf);
+        if let Some(value) = &self.bound_object_ref {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audiences: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_bound_object_ref: Option<crate::api::authentication::v1::BoundObjectReference> = None;
+        let mut value_expiration_seconds: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_audiences, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_bound_object_ref, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_expiration_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TokenRequestSpec {
+            audiences: value_audiences.unwrap_or_default(),
+            bound_object_ref: value_bound_object_ref,
+            expiration_seconds: value_expiration_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TokenRequestSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenRequestSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
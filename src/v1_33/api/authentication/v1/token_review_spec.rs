@@ -17,6 +17,56 @@ impl crate::DeepMerge for TokenReviewSpec {
     }
 }
 
+impl crate::Defaulting for TokenReviewSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.audiences);
+        crate::Defaulting::set_defaults(&mut self.token);
+    }
+}
+
+impl crate::diff::Diff for TokenReviewSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.audiences,
+            &other.audiences,
+            &crate::diff::Path::Field { parent: path, name: "audiences" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.token, &other.token, &crate::diff::Path::Field { parent: path, name: "token" }, changes);
+    }
+}
+
+impl TokenReviewSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TokenReviewSpec> {
+    /// The path of the `audiences` field.
+    pub fn audiences(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("audiences")
+    }
+
+    /// The path of the `token` field.
+    pub fn token(self) -> crate::FieldPath<std::string::String> {
+        self.child("token")
+    }
+}
+
+impl crate::Normalize for TokenReviewSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.audiences, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.token);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TokenReviewSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +156,40 @@ impl crate::serde::Serialize for TokenReviewSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TokenReviewSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.token {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.audiences {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audiences: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_token: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_token, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_audiences, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TokenReviewSpec {
+            audiences: value_audiences,
+            token: value_token,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TokenReviewSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenReviewSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
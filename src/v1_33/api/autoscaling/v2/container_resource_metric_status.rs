@@ -21,6 +21,54 @@ impl crate::DeepMerge for ContainerResourceMetricStatus {
     }
 }
 
+impl crate::Defaulting for ContainerResourceMetricStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container);
+        crate::Defaulting::set_defaults(&mut self.current);
+        crate::Defaulting::set_defaults(&mut self.name);
+    }
+}
+
+impl crate::diff::Diff for ContainerResourceMetricStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container, &other.container, &crate::diff::Path::Field { parent: path, name: "container" }, changes);
+        crate::diff::Diff::diff(&self.current, &other.current, &crate::diff::Path::Field { parent: path, name: "current" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+    }
+}
+
+impl ContainerResourceMetricStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerResourceMetricStatus> {
+    /// The path of the `container` field.
+    pub fn container(self) -> crate::FieldPath<std::string::String> {
+        self.child("container")
+    }
+
+    /// The path of the `current` field.
+    pub fn current(self) -> crate::FieldPath<crate::api::autoscaling::v2::MetricValueStatus> {
+        self.child("current")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+}
+
+impl crate::Normalize for ContainerResourceMetricStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container);
+        crate::Normalize::normalize(&mut self.current);
+        crate::Normalize::normalize(&mut self.name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerResourceMetricStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +160,40 @@ impl crate::serde::Serialize for ContainerResourceMetricStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerResourceMetricStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.current, 2, buf);
+        crate::protobuf::Field::encode_field(&self.container, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container: Option<std::string::String> = None;
+        let mut value_current: Option<crate::api::autoscaling::v2::MetricValueStatus> = None;
+        let mut value_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_current, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_container, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerResourceMetricStatus {
+            container: value_container.unwrap_or_default(),
+            current: value_current.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerResourceMetricStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerResourceMetricStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for HPAScalingPolicy {
     }
 }
 
+impl crate::Defaulting for HPAScalingPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.period_seconds);
+        crate::Defaulting::set_defaults(&mut self.type_);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for HPAScalingPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.period_seconds, &other.period_seconds, &crate::diff::Path::Field { parent: path, name: "periodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl HPAScalingPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HPAScalingPolicy> {
+    /// The path of the `periodSeconds` field.
+    pub fn period_seconds(self) -> crate::FieldPath<i32> {
+        self.child("periodSeconds")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<i32> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for HPAScalingPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.period_seconds);
+        crate::Normalize::normalize(&mut self.type_);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HPAScalingPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +160,40 @@ impl crate::serde::Serialize for HPAScalingPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HPAScalingPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        crate::protobuf::Field::encode_field(&self.value, 2, buf);
+        crate::protobuf::Field::encode_field(&self.period_seconds, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_period_seconds: Option<i32> = None;
+        let mut value_type_: Option<std::string::String> = None;
+        let mut value_value: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_period_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HPAScalingPolicy {
+            period_seconds: value_period_seconds.unwrap_or_default(),
+            type_: value_type_.unwrap_or_default(),
+            value: value_value.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HPAScalingPolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HPAScalingPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
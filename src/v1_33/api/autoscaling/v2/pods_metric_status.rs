@@ -17,6 +17,46 @@ impl crate::DeepMerge for PodsMetricStatus {
     }
 }
 
+impl crate::Defaulting for PodsMetricStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.current);
+        crate::Defaulting::set_defaults(&mut self.metric);
+    }
+}
+
+impl crate::diff::Diff for PodsMetricStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.current, &other.current, &crate::diff::Path::Field { parent: path, name: "current" }, changes);
+        crate::diff::Diff::diff(&self.metric, &other.metric, &crate::diff::Path::Field { parent: path, name: "metric" }, changes);
+    }
+}
+
+impl PodsMetricStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodsMetricStatus> {
+    /// The path of the `current` field.
+    pub fn current(self) -> crate::FieldPath<crate::api::autoscaling::v2::MetricValueStatus> {
+        self.child("current")
+    }
+
+    /// The path of the `metric` field.
+    pub fn metric(self) -> crate::FieldPath<crate::api::autoscaling::v2::MetricIdentifier> {
+        self.child("metric")
+    }
+}
+
+impl crate::Normalize for PodsMetricStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.current);
+        crate::Normalize::normalize(&mut self.metric);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodsMetricStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for PodsMetricStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodsMetricStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metric, 1, buf);
+        crate::protobuf::Field::encode_field(&self.current, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_current: Option<crate::api::autoscaling::v2::MetricValueStatus> = None;
+        let mut value_metric: Option<crate::api::autoscaling::v2::MetricIdentifier> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metric, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_current, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodsMetricStatus {
+            current: value_current.unwrap_or_default(),
+            metric: value_metric.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodsMetricStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodsMetricStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,64 @@ impl crate::DeepMerge for CronJobStatus {
     }
 }
 
+impl crate::Defaulting for CronJobStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.active);
+        crate::Defaulting::set_defaults(&mut self.last_schedule_time);
+        crate::Defaulting::set_defaults(&mut self.last_successful_time);
+    }
+}
+
+impl crate::diff::Diff for CronJobStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.active,
+            &other.active,
+            &crate::diff::Path::Field { parent: path, name: "active" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.last_schedule_time, &other.last_schedule_time, &crate::diff::Path::Field { parent: path, name: "lastScheduleTime" }, changes);
+        crate::diff::Diff::diff(&self.last_successful_time, &other.last_successful_time, &crate::diff::Path::Field { parent: path, name: "lastSuccessfulTime" }, changes);
+    }
+}
+
+impl CronJobStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CronJobStatus> {
+    /// The path of the `active` field.
+    pub fn active(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ObjectReference>> {
+        self.child("active")
+    }
+
+    /// The path of the `lastScheduleTime` field.
+    pub fn last_schedule_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("lastScheduleTime")
+    }
+
+    /// The path of the `lastSuccessfulTime` field.
+    pub fn last_successful_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("lastSuccessfulTime")
+    }
+}
+
+impl crate::Normalize for CronJobStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.active, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.last_schedule_time);
+        crate::Normalize::normalize(&mut self.last_successful_time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CronJobStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +178,46 @@ impl crate::serde::Serialize for CronJobStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CronJobStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.active {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.last_schedule_time {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.last_successful_time {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_active: Option<std::vec::Vec<crate::api::core::v1::ObjectReference>> = None;
+        let mut value_last_schedule_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_last_successful_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_active, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_last_schedule_time, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_last_successful_time, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CronJobStatus {
+            active: value_active,
+            last_schedule_time: value_last_schedule_time,
+            last_successful_time: value_last_successful_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CronJobStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CronJobStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -33,6 +33,88 @@ impl crate::DeepMerge for CephFSPersistentVolumeSource {
     }
 }
 
+impl crate::Defaulting for CephFSPersistentVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.monitors);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.secret_file);
+        crate::Defaulting::set_defaults(&mut self.secret_ref);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for CephFSPersistentVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.monitors,
+            &other.monitors,
+            &crate::diff::Path::Field { parent: path, name: "monitors" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.secret_file, &other.secret_file, &crate::diff::Path::Field { parent: path, name: "secretFile" }, changes);
+        crate::diff::Diff::diff(&self.secret_ref, &other.secret_ref, &crate::diff::Path::Field { parent: path, name: "secretRef" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl CephFSPersistentVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CephFSPersistentVolumeSource> {
+    /// The path of the `monitors` field.
+    pub fn monitors(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("monitors")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `secretFile` field.
+    pub fn secret_file(self) -> crate::FieldPath<std::string::String> {
+        self.child("secretFile")
+    }
+
+    /// The path of the `secretRef` field.
+    pub fn secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("secretRef")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<std::string::String> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for CephFSPersistentVolumeSource {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.monitors, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.secret_file);
+        crate::Normalize::normalize(&mut self.secret_ref);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CephFSPersistentVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -160,6 +242,62 @@ impl crate::serde::Serialize for CephFSPersistentVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CephFSPersistentVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.monitors, 1, buf);
+        if let Some(value) = &self.path {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.secret_file {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_monitors: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_secret_file: Option<std::string::String> = None;
+        let mut value_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_user: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_monitors, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_secret_file, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_secret_ref, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CephFSPersistentVolumeSource {
+            monitors: value_monitors.unwrap_or_default(),
+            path: value_path,
+            read_only: value_read_only,
+            secret_file: value_secret_file,
+            secret_ref: value_secret_ref,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CephFSPersistentVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CephFSPersistentVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for ClientIPConfig {
     }
 }
 
+impl crate::Defaulting for ClientIPConfig {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.timeout_seconds);
+    }
+}
+
+impl crate::diff::Diff for ClientIPConfig {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.timeout_seconds, &other.timeout_seconds, &crate::diff::Path::Field { parent: path, name: "timeoutSeconds" }, changes);
+    }
+}
+
+impl ClientIPConfig {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ClientIPConfig> {
+    /// The path of the `timeoutSeconds` field.
+    pub fn timeout_seconds(self) -> crate::FieldPath<i32> {
+        self.child("timeoutSeconds")
+    }
+}
+
+impl crate::Normalize for ClientIPConfig {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.timeout_seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ClientIPConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for ClientIPConfig {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ClientIPConfig {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.timeout_seconds {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_timeout_seconds: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_timeout_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ClientIPConfig {
+            timeout_seconds: value_timeout_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ClientIPConfig {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClientIPConfig {
     fn schema_name() -> std::borrow::Cow<'static, str> {
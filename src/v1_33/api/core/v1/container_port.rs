@@ -29,6 +29,73 @@ impl crate::DeepMerge for ContainerPort {
     }
 }
 
+impl crate::Defaulting for ContainerPort {
+    fn set_defaults(&mut self) {
+        if self.protocol.is_none() {
+            self.protocol = Some("TCP".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.container_port);
+        crate::Defaulting::set_defaults(&mut self.host_ip);
+        crate::Defaulting::set_defaults(&mut self.host_port);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.protocol);
+    }
+}
+
+impl crate::diff::Diff for ContainerPort {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_port, &other.container_port, &crate::diff::Path::Field { parent: path, name: "containerPort" }, changes);
+        crate::diff::Diff::diff(&self.host_ip, &other.host_ip, &crate::diff::Path::Field { parent: path, name: "hostIP" }, changes);
+        crate::diff::Diff::diff(&self.host_port, &other.host_port, &crate::diff::Path::Field { parent: path, name: "hostPort" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.protocol, &other.protocol, &crate::diff::Path::Field { parent: path, name: "protocol" }, changes);
+    }
+}
+
+impl ContainerPort {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerPort> {
+    /// The path of the `containerPort` field.
+    pub fn container_port(self) -> crate::FieldPath<i32> {
+        self.child("containerPort")
+    }
+
+    /// The path of the `hostIP` field.
+    pub fn host_ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostIP")
+    }
+
+    /// The path of the `hostPort` field.
+    pub fn host_port(self) -> crate::FieldPath<i32> {
+        self.child("hostPort")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `protocol` field.
+    pub fn protocol(self) -> crate::FieldPath<std::string::String> {
+        self.child("protocol")
+    }
+}
+
+impl crate::Normalize for ContainerPort {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_port);
+        crate::Normalize::normalize(&mut self.host_ip);
+        crate::Normalize::normalize(&mut self.host_port);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.protocol);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerPort {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -146,6 +213,56 @@ impl crate::serde::Serialize for ContainerPort {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerPort {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.host_port {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.container_port, 3, buf);
+        if let Some(value) = &self.protocol {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.host_ip {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_port: Option<i32> = None;
+        let mut value_host_ip: Option<std::string::String> = None;
+        let mut value_host_port: Option<i32> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_protocol: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_host_port, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_container_port, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_protocol, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_host_ip, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerPort {
+            container_port: value_container_port.unwrap_or_default(),
+            host_ip: value_host_ip,
+            host_port: value_host_port,
+            name: value_name,
+            protocol: value_protocol,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerPort {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerPort {
     fn schema_name() -> std::borrow::Cow<'static, str> {
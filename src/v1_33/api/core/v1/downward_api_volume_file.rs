@@ -25,6 +25,62 @@ impl crate::DeepMerge for DownwardAPIVolumeFile {
     }
 }
 
+impl crate::Defaulting for DownwardAPIVolumeFile {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.field_ref);
+        crate::Defaulting::set_defaults(&mut self.mode);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.resource_field_ref);
+    }
+}
+
+impl crate::diff::Diff for DownwardAPIVolumeFile {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.field_ref, &other.field_ref, &crate::diff::Path::Field { parent: path, name: "fieldRef" }, changes);
+        crate::diff::Diff::diff(&self.mode, &other.mode, &crate::diff::Path::Field { parent: path, name: "mode" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.resource_field_ref, &other.resource_field_ref, &crate::diff::Path::Field { parent: path, name: "resourceFieldRef" }, changes);
+    }
+}
+
+impl DownwardAPIVolumeFile {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DownwardAPIVolumeFile> {
+    /// The path of the `fieldRef` field.
+    pub fn field_ref(self) -> crate::FieldPath<crate::api::core::v1::ObjectFieldSelector> {
+        self.child("fieldRef")
+    }
+
+    /// The path of the `mode` field.
+    pub fn mode(self) -> crate::FieldPath<i32> {
+        self.child("mode")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `resourceFieldRef` field.
+    pub fn resource_field_ref(self) -> crate::FieldPath<crate::api::core::v1::ResourceFieldSelector> {
+        self.child("resourceFieldRef")
+    }
+}
+
+impl crate::Normalize for DownwardAPIVolumeFile {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.field_ref);
+        crate::Normalize::normalize(&mut self.mode);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.resource_field_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DownwardAPIVolumeFile {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +188,50 @@ impl crate::serde::Serialize for DownwardAPIVolumeFile {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DownwardAPIVolumeFile {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.path, 1, buf);
+        if let Some(value) = &self.field_ref {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.resource_field_ref {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.mode {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_field_ref: Option<crate::api::core::v1::ObjectFieldSelector> = None;
+        let mut value_mode: Option<i32> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_resource_field_ref: Option<crate::api::core::v1::ResourceFieldSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_field_ref, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource_field_ref, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_mode, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DownwardAPIVolumeFile {
+            field_ref: value_field_ref,
+            mode: value_mode,
+            path: value_path.unwrap_or_default(),
+            resource_field_ref: value_resource_field_ref,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DownwardAPIVolumeFile {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DownwardAPIVolumeFile {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for FlockerVolumeSource {
     }
 }
 
+impl crate::Defaulting for FlockerVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.dataset_name);
+        crate::Defaulting::set_defaults(&mut self.dataset_uuid);
+    }
+}
+
+impl crate::diff::Diff for FlockerVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.dataset_name, &other.dataset_name, &crate::diff::Path::Field { parent: path, name: "datasetName" }, changes);
+        crate::diff::Diff::diff(&self.dataset_uuid, &other.dataset_uuid, &crate::diff::Path::Field { parent: path, name: "datasetUUID" }, changes);
+    }
+}
+
+impl FlockerVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FlockerVolumeSource> {
+    /// The path of the `datasetName` field.
+    pub fn dataset_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("datasetName")
+    }
+
+    /// The path of the `datasetUUID` field.
+    pub fn dataset_uuid(self) -> crate::FieldPath<std::string::String> {
+        self.child("datasetUUID")
+    }
+}
+
+impl crate::Normalize for FlockerVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.dataset_name);
+        crate::Normalize::normalize(&mut self.dataset_uuid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FlockerVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for FlockerVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FlockerVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.dataset_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.dataset_uuid {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_dataset_name: Option<std::string::String> = None;
+        let mut value_dataset_uuid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_dataset_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_dataset_uuid, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FlockerVolumeSource {
+            dataset_name: value_dataset_name,
+            dataset_uuid: value_dataset_uuid,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FlockerVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FlockerVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for GlusterfsPersistentVolumeSource {
     }
 }
 
+impl crate::Defaulting for GlusterfsPersistentVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.endpoints);
+        crate::Defaulting::set_defaults(&mut self.endpoints_namespace);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+    }
+}
+
+impl crate::diff::Diff for GlusterfsPersistentVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.endpoints, &other.endpoints, &crate::diff::Path::Field { parent: path, name: "endpoints" }, changes);
+        crate::diff::Diff::diff(&self.endpoints_namespace, &other.endpoints_namespace, &crate::diff::Path::Field { parent: path, name: "endpointsNamespace" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+    }
+}
+
+impl GlusterfsPersistentVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GlusterfsPersistentVolumeSource> {
+    /// The path of the `endpoints` field.
+    pub fn endpoints(self) -> crate::FieldPath<std::string::String> {
+        self.child("endpoints")
+    }
+
+    /// The path of the `endpointsNamespace` field.
+    pub fn endpoints_namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("endpointsNamespace")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+}
+
+impl crate::Normalize for GlusterfsPersistentVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.endpoints);
+        crate::Normalize::normalize(&mut self.endpoints_namespace);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.read_only);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GlusterfsPersistentVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +185,48 @@ impl crate::serde::Serialize for GlusterfsPersistentVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GlusterfsPersistentVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.endpoints, 1, buf);
+        crate::protobuf::Field::encode_field(&self.path, 2, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.endpoints_namespace {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_endpoints: Option<std::string::String> = None;
+        let mut value_endpoints_namespace: Option<std::string::String> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_endpoints, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_endpoints_namespace, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GlusterfsPersistentVolumeSource {
+            endpoints: value_endpoints.unwrap_or_default(),
+            endpoints_namespace: value_endpoints_namespace,
+            path: value_path.unwrap_or_default(),
+            read_only: value_read_only,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GlusterfsPersistentVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GlusterfsPersistentVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
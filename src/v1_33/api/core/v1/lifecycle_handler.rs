@@ -25,6 +25,161 @@ impl crate::DeepMerge for LifecycleHandler {
     }
 }
 
+impl crate::Defaulting for LifecycleHandler {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.exec);
+        crate::Defaulting::set_defaults(&mut self.http_get);
+        crate::Defaulting::set_defaults(&mut self.sleep);
+        crate::Defaulting::set_defaults(&mut self.tcp_socket);
+    }
+}
+
+impl crate::diff::Diff for LifecycleHandler {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.exec, &other.exec, &crate::diff::Path::Field { parent: path, name: "exec" }, changes);
+        crate::diff::Diff::diff(&self.http_get, &other.http_get, &crate::diff::Path::Field { parent: path, name: "httpGet" }, changes);
+        crate::diff::Diff::diff(&self.sleep, &other.sleep, &crate::diff::Path::Field { parent: path, name: "sleep" }, changes);
+        crate::diff::Diff::diff(&self.tcp_socket, &other.tcp_socket, &crate::diff::Path::Field { parent: path, name: "tcpSocket" }, changes);
+    }
+}
+
+impl LifecycleHandler {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LifecycleHandler> {
+    /// The path of the `exec` field.
+    pub fn exec(self) -> crate::FieldPath<crate::api::core::v1::ExecAction> {
+        self.child("exec")
+    }
+
+    /// The path of the `httpGet` field.
+    pub fn http_get(self) -> crate::FieldPath<crate::api::core::v1::HTTPGetAction> {
+        self.child("httpGet")
+    }
+
+    /// The path of the `sleep` field.
+    pub fn sleep(self) -> crate::FieldPath<crate::api::core::v1::SleepAction> {
+        self.child("sleep")
+    }
+
+    /// The path of the `tcpSocket` field.
+    pub fn tcp_socket(self) -> crate::FieldPath<crate::api::core::v1::TCPSocketAction> {
+        self.child("tcpSocket")
+    }
+}
+
+impl crate::Normalize for LifecycleHandler {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.exec);
+        crate::Normalize::normalize(&mut self.http_get);
+        crate::Normalize::normalize(&mut self.sleep);
+        crate::Normalize::normalize(&mut self.tcp_socket);
+    }
+}
+
+/// The members of the [`LifecycleHandler`] union. At most one of them may be set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LifecycleHandlerUnion {
+    /// The `exec` member.
+    Exec(crate::api::core::v1::ExecAction),
+
+    /// The `httpGet` member.
+    HttpGet(crate::api::core::v1::HTTPGetAction),
+
+    /// The `sleep` member.
+    Sleep(crate::api::core::v1::SleepAction),
+
+    /// The `tcpSocket` member.
+    TcpSocket(crate::api::core::v1::TCPSocketAction),
+}
+
+/// A reference to the member of the [`LifecycleHandler`] union that is set, as returned by [`LifecycleHandler::union`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LifecycleHandlerUnionRef<'a> {
+    /// The `exec` member.
+    Exec(&'a crate::api::core::v1::ExecAction),
+
+    /// The `httpGet` member.
+    HttpGet(&'a crate::api::core::v1::HTTPGetAction),
+
+    /// The `sleep` member.
+    Sleep(&'a crate::api::core::v1::SleepAction),
+
+    /// The `tcpSocket` member.
+    TcpSocket(&'a crate::api::core::v1::TCPSocketAction),
+}
+
+impl LifecycleHandler {
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set.
+    pub fn union(&self) -> Result<Option<LifecycleHandlerUnionRef<'_>>, crate::UnionError> {
+        let mut members = std::vec![];
+        let mut result = None;
+        if let Some(value) = &self.exec {
+            members.push("exec");
+            result = Some(LifecycleHandlerUnionRef::Exec(value));
+        }
+        if let Some(value) = &self.http_get {
+            members.push("httpGet");
+            result = Some(LifecycleHandlerUnionRef::HttpGet(value));
+        }
+        if let Some(value) = &self.sleep {
+            members.push("sleep");
+            result = Some(LifecycleHandlerUnionRef::Sleep(value));
+        }
+        if let Some(value) = &self.tcp_socket {
+            members.push("tcpSocket");
+            result = Some(LifecycleHandlerUnionRef::TcpSocket(value));
+        }
+
+        if members.len() > 1 {
+            return Err(crate::UnionError::MultipleMembersSet(members));
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the given member of the union, and unsets all the others.
+    pub fn set_union(&mut self, value: Option<LifecycleHandlerUnion>) {
+        self.exec = None;
+        self.http_get = None;
+        self.sleep = None;
+        self.tcp_socket = None;
+
+        match value {
+            Some(LifecycleHandlerUnion::Exec(value)) => self.exec = Some(value),
+            Some(LifecycleHandlerUnion::HttpGet(value)) => self.http_get = Some(value),
+            Some(LifecycleHandlerUnion::Sleep(value)) => self.sleep = Some(value),
+            Some(LifecycleHandlerUnion::TcpSocket(value)) => self.tcp_socket = Some(value),
+            None => (),
+        }
+    }
+}
+
+impl From<LifecycleHandlerUnion> for LifecycleHandler {
+    fn from(value: LifecycleHandlerUnion) -> Self {
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }
+}
+
+impl From<LifecycleHandlerUnionRef<'_>> for LifecycleHandlerUnion {
+    fn from(value: LifecycleHandlerUnionRef<'_>) -> Self {
+        match value {
+            LifecycleHandlerUnionRef::Exec(value) => LifecycleHandlerUnion::Exec(value.clone()),
+            LifecycleHandlerUnionRef::HttpGet(value) => LifecycleHandlerUnion::HttpGet(value.clone()),
+            LifecycleHandlerUnionRef::Sleep(value) => LifecycleHandlerUnion::Sleep(value.clone()),
+            LifecycleHandlerUnionRef::TcpSocket(value) => LifecycleHandlerUnion::TcpSocket(value.clone()),
+        }
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LifecycleHandler {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +289,52 @@ impl crate::serde::Serialize for LifecycleHandler {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LifecycleHandler {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.exec {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.http_get {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.tcp_socket {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.sleep {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_exec: Option<crate::api::core::v1::ExecAction> = None;
+        let mut value_http_get: Option<crate::api::core::v1::HTTPGetAction> = None;
+        let mut value_sleep: Option<crate::api::core::v1::SleepAction> = None;
+        let mut value_tcp_socket: Option<crate::api::core::v1::TCPSocketAction> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_exec, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_http_get, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_tcp_socket, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_sleep, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LifecycleHandler {
+            exec: value_exec,
+            http_get: value_http_get,
+            sleep: value_sleep,
+            tcp_socket: value_tcp_socket,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LifecycleHandler {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LifecycleHandler {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,64 @@ impl crate::DeepMerge for LinuxContainerUser {
     }
 }
 
+impl crate::Defaulting for LinuxContainerUser {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.gid);
+        crate::Defaulting::set_defaults(&mut self.supplemental_groups);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for LinuxContainerUser {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.gid, &other.gid, &crate::diff::Path::Field { parent: path, name: "gid" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.supplemental_groups,
+            &other.supplemental_groups,
+            &crate::diff::Path::Field { parent: path, name: "supplementalGroups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl LinuxContainerUser {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LinuxContainerUser> {
+    /// The path of the `gid` field.
+    pub fn gid(self) -> crate::FieldPath<i64> {
+        self.child("gid")
+    }
+
+    /// The path of the `supplementalGroups` field.
+    pub fn supplemental_groups(self) -> crate::FieldPath<std::vec::Vec<i64>> {
+        self.child("supplementalGroups")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<i64> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for LinuxContainerUser {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.gid);
+        crate::normalize_strategies::list::atomic(&mut self.supplemental_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LinuxContainerUser {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +173,42 @@ impl crate::serde::Serialize for LinuxContainerUser {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LinuxContainerUser {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.uid, 1, buf);
+        crate::protobuf::Field::encode_field(&self.gid, 2, buf);
+        if let Some(value) = &self.supplemental_groups {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_gid: Option<i64> = None;
+        let mut value_supplemental_groups: Option<std::vec::Vec<i64>> = None;
+        let mut value_uid: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_gid, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_supplemental_groups, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LinuxContainerUser {
+            gid: value_gid.unwrap_or_default(),
+            supplemental_groups: value_supplemental_groups,
+            uid: value_uid.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LinuxContainerUser {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LinuxContainerUser {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for NodeConfigSource {
     }
 }
 
+impl crate::Defaulting for NodeConfigSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config_map);
+    }
+}
+
+impl crate::diff::Diff for NodeConfigSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.config_map, &other.config_map, &crate::diff::Path::Field { parent: path, name: "configMap" }, changes);
+    }
+}
+
+impl NodeConfigSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeConfigSource> {
+    /// The path of the `configMap` field.
+    pub fn config_map(self) -> crate::FieldPath<crate::api::core::v1::ConfigMapNodeConfigSource> {
+        self.child("configMap")
+    }
+}
+
+impl crate::Normalize for NodeConfigSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.config_map);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeConfigSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for NodeConfigSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeConfigSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.config_map {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config_map: Option<crate::api::core::v1::ConfigMapNodeConfigSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_config_map, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeConfigSource {
+            config_map: value_config_map,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeConfigSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeConfigSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
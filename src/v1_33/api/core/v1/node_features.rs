@@ -13,6 +13,38 @@ impl crate::DeepMerge for NodeFeatures {
     }
 }
 
+impl crate::Defaulting for NodeFeatures {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.supplemental_groups_policy);
+    }
+}
+
+impl crate::diff::Diff for NodeFeatures {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.supplemental_groups_policy, &other.supplemental_groups_policy, &crate::diff::Path::Field { parent: path, name: "supplementalGroupsPolicy" }, changes);
+    }
+}
+
+impl NodeFeatures {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeFeatures> {
+    /// The path of the `supplementalGroupsPolicy` field.
+    pub fn supplemental_groups_policy(self) -> crate::FieldPath<bool> {
+        self.child("supplementalGroupsPolicy")
+    }
+}
+
+impl crate::Normalize for NodeFeatures {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.supplemental_groups_policy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeFeatures {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for NodeFeatures {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeFeatures {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.supplemental_groups_policy {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_supplemental_groups_policy: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_supplemental_groups_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeFeatures {
+            supplemental_groups_policy: value_supplemental_groups_policy,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeFeatures {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeFeatures {
     fn schema_name() -> std::borrow::Cow<'static, str> {
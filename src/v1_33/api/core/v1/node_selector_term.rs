@@ -17,6 +17,66 @@ impl crate::DeepMerge for NodeSelectorTerm {
     }
 }
 
+impl crate::Defaulting for NodeSelectorTerm {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.match_expressions);
+        crate::Defaulting::set_defaults(&mut self.match_fields);
+    }
+}
+
+impl crate::diff::Diff for NodeSelectorTerm {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.match_expressions,
+            &other.match_expressions,
+            &crate::diff::Path::Field { parent: path, name: "matchExpressions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.match_fields,
+            &other.match_fields,
+            &crate::diff::Path::Field { parent: path, name: "matchFields" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NodeSelectorTerm {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeSelectorTerm> {
+    /// The path of the `matchExpressions` field.
+    pub fn match_expressions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeSelectorRequirement>> {
+        self.child("matchExpressions")
+    }
+
+    /// The path of the `matchFields` field.
+    pub fn match_fields(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeSelectorRequirement>> {
+        self.child("matchFields")
+    }
+}
+
+impl crate::Normalize for NodeSelectorTerm {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.match_expressions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.match_fields, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeSelectorTerm {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for NodeSelectorTerm {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeSelectorTerm {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.match_expressions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.match_fields {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_match_expressions: Option<std::vec::Vec<crate::api::core::v1::NodeSelectorRequirement>> = None;
+        let mut value_match_fields: Option<std::vec::Vec<crate::api::core::v1::NodeSelectorRequirement>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_match_expressions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_match_fields, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeSelectorTerm {
+            match_expressions: value_match_expressions,
+            match_fields: value_match_fields,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeSelectorTerm {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSelectorTerm {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for NodeSwapStatus {
     }
 }
 
+impl crate::Defaulting for NodeSwapStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.capacity);
+    }
+}
+
+impl crate::diff::Diff for NodeSwapStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.capacity, &other.capacity, &crate::diff::Path::Field { parent: path, name: "capacity" }, changes);
+    }
+}
+
+impl NodeSwapStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeSwapStatus> {
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<i64> {
+        self.child("capacity")
+    }
+}
+
+impl crate::Normalize for NodeSwapStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.capacity);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeSwapStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for NodeSwapStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeSwapStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_capacity: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeSwapStatus {
+            capacity: value_capacity,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeSwapStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSwapStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,66 @@ impl crate::DeepMerge for PodAffinity {
     }
 }
 
+impl crate::Defaulting for PodAffinity {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.preferred_during_scheduling_ignored_during_execution);
+        crate::Defaulting::set_defaults(&mut self.required_during_scheduling_ignored_during_execution);
+    }
+}
+
+impl crate::diff::Diff for PodAffinity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.preferred_during_scheduling_ignored_during_execution,
+            &other.preferred_during_scheduling_ignored_during_execution,
+            &crate::diff::Path::Field { parent: path, name: "preferredDuringSchedulingIgnoredDuringExecution" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.required_during_scheduling_ignored_during_execution,
+            &other.required_during_scheduling_ignored_during_execution,
+            &crate::diff::Path::Field { parent: path, name: "requiredDuringSchedulingIgnoredDuringExecution" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodAffinity {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodAffinity> {
+    /// The path of the `preferredDuringSchedulingIgnoredDuringExecution` field.
+    pub fn preferred_during_scheduling_ignored_during_execution(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::WeightedPodAffinityTerm>> {
+        self.child("preferredDuringSchedulingIgnoredDuringExecution")
+    }
+
+    /// The path of the `requiredDuringSchedulingIgnoredDuringExecution` field.
+    pub fn required_during_scheduling_ignored_during_execution(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodAffinityTerm>> {
+        self.child("requiredDuringSchedulingIgnoredDuringExecution")
+    }
+}
+
+impl crate::Normalize for PodAffinity {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.preferred_during_scheduling_ignored_during_execution, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.required_during_scheduling_ignored_during_execution, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodAffinity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for PodAffinity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodAffinity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.required_during_scheduling_ignored_during_execution {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.preferred_during_scheduling_ignored_during_execution {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_preferred_during_scheduling_ignored_during_execution: Option<std::vec::Vec<crate::api::core::v1::WeightedPodAffinityTerm>> = None;
+        let mut value_required_during_scheduling_ignored_during_execution: Option<std::vec::Vec<crate::api::core::v1::PodAffinityTerm>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_required_during_scheduling_ignored_during_execution, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_preferred_during_scheduling_ignored_during_execution, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodAffinity {
+            preferred_during_scheduling_ignored_during_execution: value_preferred_during_scheduling_ignored_during_execution,
+            required_during_scheduling_ignored_during_execution: value_required_during_scheduling_ignored_during_execution,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodAffinity {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodAffinity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
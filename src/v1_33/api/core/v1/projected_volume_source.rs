@@ -17,6 +17,59 @@ impl crate::DeepMerge for ProjectedVolumeSource {
     }
 }
 
+impl crate::Defaulting for ProjectedVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.default_mode.is_none() {
+            self.default_mode = Some(420);
+        }
+        crate::Defaulting::set_defaults(&mut self.default_mode);
+        crate::Defaulting::set_defaults(&mut self.sources);
+    }
+}
+
+impl crate::diff::Diff for ProjectedVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.default_mode, &other.default_mode, &crate::diff::Path::Field { parent: path, name: "defaultMode" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.sources,
+            &other.sources,
+            &crate::diff::Path::Field { parent: path, name: "sources" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ProjectedVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ProjectedVolumeSource> {
+    /// The path of the `defaultMode` field.
+    pub fn default_mode(self) -> crate::FieldPath<i32> {
+        self.child("defaultMode")
+    }
+
+    /// The path of the `sources` field.
+    pub fn sources(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::VolumeProjection>> {
+        self.child("sources")
+    }
+}
+
+impl crate::Normalize for ProjectedVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.default_mode);
+        crate::normalize_strategies::list::atomic(&mut self.sources, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ProjectedVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +159,40 @@ impl crate::serde::Serialize for ProjectedVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ProjectedVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.sources {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.default_mode {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default_mode: Option<i32> = None;
+        let mut value_sources: Option<std::vec::Vec<crate::api::core::v1::VolumeProjection>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_sources, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_default_mode, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ProjectedVolumeSource {
+            default_mode: value_default_mode,
+            sources: value_sources,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ProjectedVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ProjectedVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -41,6 +41,113 @@ impl crate::DeepMerge for RBDVolumeSource {
     }
 }
 
+impl crate::Defaulting for RBDVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.keyring.is_none() {
+            self.keyring = Some("/etc/ceph/keyring".into());
+        }
+        if self.pool.is_none() {
+            self.pool = Some("rbd".into());
+        }
+        if self.user.is_none() {
+            self.user = Some("admin".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.image);
+        crate::Defaulting::set_defaults(&mut self.keyring);
+        crate::Defaulting::set_defaults(&mut self.monitors);
+        crate::Defaulting::set_defaults(&mut self.pool);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.secret_ref);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for RBDVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.image, &other.image, &crate::diff::Path::Field { parent: path, name: "image" }, changes);
+        crate::diff::Diff::diff(&self.keyring, &other.keyring, &crate::diff::Path::Field { parent: path, name: "keyring" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.monitors,
+            &other.monitors,
+            &crate::diff::Path::Field { parent: path, name: "monitors" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.pool, &other.pool, &crate::diff::Path::Field { parent: path, name: "pool" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.secret_ref, &other.secret_ref, &crate::diff::Path::Field { parent: path, name: "secretRef" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl RBDVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<RBDVolumeSource> {
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `image` field.
+    pub fn image(self) -> crate::FieldPath<std::string::String> {
+        self.child("image")
+    }
+
+    /// The path of the `keyring` field.
+    pub fn keyring(self) -> crate::FieldPath<std::string::String> {
+        self.child("keyring")
+    }
+
+    /// The path of the `monitors` field.
+    pub fn monitors(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("monitors")
+    }
+
+    /// The path of the `pool` field.
+    pub fn pool(self) -> crate::FieldPath<std::string::String> {
+        self.child("pool")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `secretRef` field.
+    pub fn secret_ref(self) -> crate::FieldPath<crate::api::core::v1::LocalObjectReference> {
+        self.child("secretRef")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<std::string::String> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for RBDVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.image);
+        crate::Normalize::normalize(&mut self.keyring);
+        crate::normalize_strategies::list::atomic(&mut self.monitors, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.pool);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.secret_ref);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RBDVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -185,6 +292,72 @@ impl crate::serde::Serialize for RBDVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for RBDVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.monitors, 1, buf);
+        crate::protobuf::Field::encode_field(&self.image, 2, buf);
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.pool {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.keyring {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.secret_ref {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_image: Option<std::string::String> = None;
+        let mut value_keyring: Option<std::string::String> = None;
+        let mut value_monitors: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_pool: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_secret_ref: Option<crate::api::core::v1::LocalObjectReference> = None;
+        let mut value_user: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_monitors, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_image, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_pool, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_keyring, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_secret_ref, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(RBDVolumeSource {
+            fs_type: value_fs_type,
+            image: value_image.unwrap_or_default(),
+            keyring: value_keyring,
+            monitors: value_monitors.unwrap_or_default(),
+            pool: value_pool,
+            read_only: value_read_only,
+            secret_ref: value_secret_ref,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for RBDVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RBDVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
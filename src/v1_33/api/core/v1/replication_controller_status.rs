@@ -40,6 +40,89 @@ impl crate::DeepMerge for ReplicationControllerStatus {
     }
 }
 
+impl crate::Defaulting for ReplicationControllerStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.available_replicas);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.fully_labeled_replicas);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+        crate::Defaulting::set_defaults(&mut self.ready_replicas);
+        crate::Defaulting::set_defaults(&mut self.replicas);
+    }
+}
+
+impl crate::diff::Diff for ReplicationControllerStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.available_replicas, &other.available_replicas, &crate::diff::Path::Field { parent: path, name: "availableReplicas" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.fully_labeled_replicas, &other.fully_labeled_replicas, &crate::diff::Path::Field { parent: path, name: "fullyLabeledReplicas" }, changes);
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+        crate::diff::Diff::diff(&self.ready_replicas, &other.ready_replicas, &crate::diff::Path::Field { parent: path, name: "readyReplicas" }, changes);
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+    }
+}
+
+impl ReplicationControllerStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ReplicationControllerStatus> {
+    /// The path of the `availableReplicas` field.
+    pub fn available_replicas(self) -> crate::FieldPath<i32> {
+        self.child("availableReplicas")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ReplicationControllerCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `fullyLabeledReplicas` field.
+    pub fn fully_labeled_replicas(self) -> crate::FieldPath<i32> {
+        self.child("fullyLabeledReplicas")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+
+    /// The path of the `readyReplicas` field.
+    pub fn ready_replicas(self) -> crate::FieldPath<i32> {
+        self.child("readyReplicas")
+    }
+
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+}
+
+impl crate::Normalize for ReplicationControllerStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.available_replicas);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.fully_labeled_replicas);
+        crate::Normalize::normalize(&mut self.observed_generation);
+        crate::Normalize::normalize(&mut self.ready_replicas);
+        crate::Normalize::normalize(&mut self.replicas);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ReplicationControllerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -167,6 +250,72 @@ impl crate::serde::Serialize for ReplicationControllerStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ReplicationControllerStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.replicas, 1, buf);
+        if let Some(value) = &self.fully_labeled_replicas {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.ready_replicas {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.available_replicas {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_available_replicas: Option<i32> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::core::v1::ReplicationControllerCondition>> = None;
+        let mut value_fully_labeled_replicas: Option<i32> = None;
+        let mut value_observed_generation: Option<i64> = None;
+        let mut value_ready_replicas: Option<i32> = None;
+        let mut value_replicas: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_fully_labeled_replicas, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_ready_replicas, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_available_replicas, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ReplicationControllerStatus {
+            available_replicas: value_available_replicas,
+            conditions: value_conditions,
+            fully_labeled_replicas: value_fully_labeled_replicas,
+            observed_generation: value_observed_generation,
+            ready_replicas: value_ready_replicas,
+            replicas: value_replicas.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ReplicationControllerStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ReplicationControllerStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for SELinuxOptions {
     }
 }
 
+impl crate::Defaulting for SELinuxOptions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.level);
+        crate::Defaulting::set_defaults(&mut self.role);
+        crate::Defaulting::set_defaults(&mut self.type_);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for SELinuxOptions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.level, &other.level, &crate::diff::Path::Field { parent: path, name: "level" }, changes);
+        crate::diff::Diff::diff(&self.role, &other.role, &crate::diff::Path::Field { parent: path, name: "role" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl SELinuxOptions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SELinuxOptions> {
+    /// The path of the `level` field.
+    pub fn level(self) -> crate::FieldPath<std::string::String> {
+        self.child("level")
+    }
+
+    /// The path of the `role` field.
+    pub fn role(self) -> crate::FieldPath<std::string::String> {
+        self.child("role")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<std::string::String> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for SELinuxOptions {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.level);
+        crate::Normalize::normalize(&mut self.role);
+        crate::Normalize::normalize(&mut self.type_);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SELinuxOptions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for SELinuxOptions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SELinuxOptions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.role {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.level {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_level: Option<std::string::String> = None;
+        let mut value_role: Option<std::string::String> = None;
+        let mut value_type_: Option<std::string::String> = None;
+        let mut value_user: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_role, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_level, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SELinuxOptions {
+            level: value_level,
+            role: value_role,
+            type_: value_type_,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SELinuxOptions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SELinuxOptions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -57,6 +57,126 @@ impl crate::DeepMerge for SecurityContext {
     }
 }
 
+impl crate::Defaulting for SecurityContext {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allow_privilege_escalation);
+        crate::Defaulting::set_defaults(&mut self.app_armor_profile);
+        crate::Defaulting::set_defaults(&mut self.capabilities);
+        crate::Defaulting::set_defaults(&mut self.privileged);
+        crate::Defaulting::set_defaults(&mut self.proc_mount);
+        crate::Defaulting::set_defaults(&mut self.read_only_root_filesystem);
+        crate::Defaulting::set_defaults(&mut self.run_as_group);
+        crate::Defaulting::set_defaults(&mut self.run_as_non_root);
+        crate::Defaulting::set_defaults(&mut self.run_as_user);
+        crate::Defaulting::set_defaults(&mut self.se_linux_options);
+        crate::Defaulting::set_defaults(&mut self.seccomp_profile);
+        crate::Defaulting::set_defaults(&mut self.windows_options);
+    }
+}
+
+impl crate::diff::Diff for SecurityContext {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allow_privilege_escalation, &other.allow_privilege_escalation, &crate::diff::Path::Field { parent: path, name: "allowPrivilegeEscalation" }, changes);
+        crate::diff::Diff::diff(&self.app_armor_profile, &other.app_armor_profile, &crate::diff::Path::Field { parent: path, name: "appArmorProfile" }, changes);
+        crate::diff::Diff::diff(&self.capabilities, &other.capabilities, &crate::diff::Path::Field { parent: path, name: "capabilities" }, changes);
+        crate::diff::Diff::diff(&self.privileged, &other.privileged, &crate::diff::Path::Field { parent: path, name: "privileged" }, changes);
+        crate::diff::Diff::diff(&self.proc_mount, &other.proc_mount, &crate::diff::Path::Field { parent: path, name: "procMount" }, changes);
+        crate::diff::Diff::diff(&self.read_only_root_filesystem, &other.read_only_root_filesystem, &crate::diff::Path::Field { parent: path, name: "readOnlyRootFilesystem" }, changes);
+        crate::diff::Diff::diff(&self.run_as_group, &other.run_as_group, &crate::diff::Path::Field { parent: path, name: "runAsGroup" }, changes);
+        crate::diff::Diff::diff(&self.run_as_non_root, &other.run_as_non_root, &crate::diff::Path::Field { parent: path, name: "runAsNonRoot" }, changes);
+        crate::diff::Diff::diff(&self.run_as_user, &other.run_as_user, &crate::diff::Path::Field { parent: path, name: "runAsUser" }, changes);
+        crate::diff::Diff::diff(&self.se_linux_options, &other.se_linux_options, &crate::diff::Path::Field { parent: path, name: "seLinuxOptions" }, changes);
+        crate::diff::Diff::diff(&self.seccomp_profile, &other.seccomp_profile, &crate::diff::Path::Field { parent: path, name: "seccompProfile" }, changes);
+        crate::diff::Diff::diff(&self.windows_options, &other.windows_options, &crate::diff::Path::Field { parent: path, name: "windowsOptions" }, changes);
+    }
+}
+
+impl SecurityContext {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SecurityContext> {
+    /// The path of the `allowPrivilegeEscalation` field.
+    pub fn allow_privilege_escalation(self) -> crate::FieldPath<bool> {
+        self.child("allowPrivilegeEscalation")
+    }
+
+    /// The path of the `appArmorProfile` field.
+    pub fn app_armor_profile(self) -> crate::FieldPath<crate::api::core::v1::AppArmorProfile> {
+        self.child("appArmorProfile")
+    }
+
+    /// The path of the `capabilities` field.
+    pub fn capabilities(self) -> crate::FieldPath<crate::api::core::v1::Capabilities> {
+        self.child("capabilities")
+    }
+
+    /// The path of the `privileged` field.
+    pub fn privileged(self) -> crate::FieldPath<bool> {
+        self.child("privileged")
+    }
+
+    /// The path of the `procMount` field.
+    pub fn proc_mount(self) -> crate::FieldPath<std::string::String> {
+        self.child("procMount")
+    }
+
+    /// The path of the `readOnlyRootFilesystem` field.
+    pub fn read_only_root_filesystem(self) -> crate::FieldPath<bool> {
+        self.child("readOnlyRootFilesystem")
+    }
+
+    /// The path of the `runAsGroup` field.
+    pub fn run_as_group(self) -> crate::FieldPath<i64> {
+        self.child("runAsGroup")
+    }
+
+    /// The path of the `runAsNonRoot` field.
+    pub fn run_as_non_root(self) -> crate::FieldPath<bool> {
+        self.child("runAsNonRoot")
+    }
+
+    /// The path of the `runAsUser` field.
+    pub fn run_as_user(self) -> crate::FieldPath<i64> {
+        self.child("runAsUser")
+    }
+
+    /// The path of the `seLinuxOptions` field.
+    pub fn se_linux_options(self) -> crate::FieldPath<crate::api::core::v1::SELinuxOptions> {
+        self.child("seLinuxOptions")
+    }
+
+    /// The path of the `seccompProfile` field.
+    pub fn seccomp_profile(self) -> crate::FieldPath<crate::api::core::v1::SeccompProfile> {
+        self.child("seccompProfile")
+    }
+
+    /// The path of the `windowsOptions` field.
+    pub fn windows_options(self) -> crate::FieldPath<crate::api::core::v1::WindowsSecurityContextOptions> {
+        self.child("windowsOptions")
+    }
+}
+
+impl crate::Normalize for SecurityContext {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allow_privilege_escalation);
+        crate::Normalize::normalize(&mut self.app_armor_profile);
+        crate::Normalize::normalize(&mut self.capabilities);
+        crate::Normalize::normalize(&mut self.privileged);
+        crate::Normalize::normalize(&mut self.proc_mount);
+        crate::Normalize::normalize(&mut self.read_only_root_filesystem);
+        crate::Normalize::normalize(&mut self.run_as_group);
+        crate::Normalize::normalize(&mut self.run_as_non_root);
+        crate::Normalize::normalize(&mut self.run_as_user);
+        crate::Normalize::normalize(&mut self.se_linux_options);
+        crate::Normalize::normalize(&mut self.seccomp_profile);
+        crate::Normalize::normalize(&mut self.windows_options);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SecurityContext {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -246,6 +366,100 @@ impl crate::serde::Serialize for SecurityContext {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SecurityContext {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.capabilities {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.privileged {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.se_linux_options {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.run_as_user {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.run_as_non_root {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.read_only_root_filesystem {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.allow_privilege_escalation {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.run_as_group {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.proc_mount {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.windows_options {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.seccomp_profile {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.app_armor_profile {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allow_privilege_escalation: Option<bool> = None;
+        let mut value_app_armor_profile: Option<crate::api::core::v1::AppArmorProfile> = None;
+        let mut value_capabilities: Option<crate::api::core::v1::Capabilities> = None;
+        let mut value_privileged: Option<bool> = None;
+        let mut value_proc_mount: Option<std::string::String> = None;
+        let mut value_read_only_root_filesystem: Option<bool> = None;
+        let mut value_run_as_group: Option<i64> = None;
+        let mut value_run_as_non_root: Option<bool> = None;
+        let mut value_run_as_user: Option<i64> = None;
+        let mut value_se_linux_options: Option<crate::api::core::v1::SELinuxOptions> = None;
+        let mut value_seccomp_profile: Option<crate::api::core::v1::SeccompProfile> = None;
+        let mut value_windows_options: Option<crate::api::core::v1::WindowsSecurityContextOptions> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_capabilities, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_privileged, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_se_linux_options, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_run_as_user, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_run_as_non_root, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_read_only_root_filesystem, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_allow_privilege_escalation, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_run_as_group, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_proc_mount, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_windows_options, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_seccomp_profile, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_app_armor_profile, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SecurityContext {
+            allow_privilege_escalation: value_allow_privilege_escalation,
+            app_armor_profile: value_app_armor_profile,
+            capabilities: value_capabilities,
+            privileged: value_privileged,
+            proc_mount: value_proc_mount,
+            read_only_root_filesystem: value_read_only_root_filesystem,
+            run_as_group: value_run_as_group,
+            run_as_non_root: value_run_as_non_root,
+            run_as_user: value_run_as_user,
+            se_linux_options: value_se_linux_options,
+            seccomp_profile: value_seccomp_profile,
+            windows_options: value_windows_options,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SecurityContext {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SecurityContext {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -45,6 +45,86 @@ impl crate::DeepMerge for VolumeMount {
     }
 }
 
+impl crate::Defaulting for VolumeMount {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.mount_path);
+        crate::Defaulting::set_defaults(&mut self.mount_propagation);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.recursive_read_only);
+        crate::Defaulting::set_defaults(&mut self.sub_path);
+        crate::Defaulting::set_defaults(&mut self.sub_path_expr);
+    }
+}
+
+impl crate::diff::Diff for VolumeMount {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.mount_path, &other.mount_path, &crate::diff::Path::Field { parent: path, name: "mountPath" }, changes);
+        crate::diff::Diff::diff(&self.mount_propagation, &other.mount_propagation, &crate::diff::Path::Field { parent: path, name: "mountPropagation" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.recursive_read_only, &other.recursive_read_only, &crate::diff::Path::Field { parent: path, name: "recursiveReadOnly" }, changes);
+        crate::diff::Diff::diff(&self.sub_path, &other.sub_path, &crate::diff::Path::Field { parent: path, name: "subPath" }, changes);
+        crate::diff::Diff::diff(&self.sub_path_expr, &other.sub_path_expr, &crate::diff::Path::Field { parent: path, name: "subPathExpr" }, changes);
+    }
+}
+
+impl VolumeMount {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeMount> {
+    /// The path of the `mountPath` field.
+    pub fn mount_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("mountPath")
+    }
+
+    /// The path of the `mountPropagation` field.
+    pub fn mount_propagation(self) -> crate::FieldPath<std::string::String> {
+        self.child("mountPropagation")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `recursiveReadOnly` field.
+    pub fn recursive_read_only(self) -> crate::FieldPath<std::string::String> {
+        self.child("recursiveReadOnly")
+    }
+
+    /// The path of the `subPath` field.
+    pub fn sub_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("subPath")
+    }
+
+    /// The path of the `subPathExpr` field.
+    pub fn sub_path_expr(self) -> crate::FieldPath<std::string::String> {
+        self.child("subPathExpr")
+    }
+}
+
+impl crate::Normalize for VolumeMount {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.mount_path);
+        crate::Normalize::normalize(&mut self.mount_propagation);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.recursive_read_only);
+        crate::Normalize::normalize(&mut self.sub_path);
+        crate::Normalize::normalize(&mut self.sub_path_expr);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeMount {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -179,6 +259,66 @@ impl crate::serde::Serialize for VolumeMount {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeMount {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.mount_path, 3, buf);
+        if let Some(value) = &self.sub_path {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.mount_propagation {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.sub_path_expr {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.recursive_read_only {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_mount_path: Option<std::string::String> = None;
+        let mut value_mount_propagation: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_recursive_read_only: Option<std::string::String> = None;
+        let mut value_sub_path: Option<std::string::String> = None;
+        let mut value_sub_path_expr: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_mount_path, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_sub_path, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_mount_propagation, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_sub_path_expr, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_recursive_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeMount {
+            mount_path: value_mount_path.unwrap_or_default(),
+            mount_propagation: value_mount_propagation,
+            name: value_name.unwrap_or_default(),
+            read_only: value_read_only,
+            recursive_read_only: value_recursive_read_only,
+            sub_path: value_sub_path,
+            sub_path_expr: value_sub_path_expr,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeMount {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeMount {
     fn schema_name() -> std::borrow::Cow<'static, str> {
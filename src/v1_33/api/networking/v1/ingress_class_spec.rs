@@ -17,6 +17,46 @@ impl crate::DeepMerge for IngressClassSpec {
     }
 }
 
+impl crate::Defaulting for IngressClassSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.controller);
+        crate::Defaulting::set_defaults(&mut self.parameters);
+    }
+}
+
+impl crate::diff::Diff for IngressClassSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.controller, &other.controller, &crate::diff::Path::Field { parent: path, name: "controller" }, changes);
+        crate::diff::Diff::diff(&self.parameters, &other.parameters, &crate::diff::Path::Field { parent: path, name: "parameters" }, changes);
+    }
+}
+
+impl IngressClassSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressClassSpec> {
+    /// The path of the `controller` field.
+    pub fn controller(self) -> crate::FieldPath<std::string::String> {
+        self.child("controller")
+    }
+
+    /// The path of the `parameters` field.
+    pub fn parameters(self) -> crate::FieldPath<crate::api::networking::v1::IngressClassParametersReference> {
+        self.child("parameters")
+    }
+}
+
+impl crate::Normalize for IngressClassSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.controller);
+        crate::Normalize::normalize(&mut self.parameters);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressClassSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for IngressClassSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressClassSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.controller {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.parameters {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_controller: Option<std::string::String> = None;
+        let mut value_parameters: Option<crate::api::networking::v1::IngressClassParametersReference> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_controller, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_parameters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressClassSpec {
+            controller: value_controller,
+            parameters: value_parameters,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressClassSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressClassSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,64 @@ impl crate::DeepMerge for IngressLoadBalancerIngress {
     }
 }
 
+impl crate::Defaulting for IngressLoadBalancerIngress {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hostname);
+        crate::Defaulting::set_defaults(&mut self.ip);
+        crate::Defaulting::set_defaults(&mut self.ports);
+    }
+}
+
+impl crate::diff::Diff for IngressLoadBalancerIngress {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.hostname, &other.hostname, &crate::diff::Path::Field { parent: path, name: "hostname" }, changes);
+        crate::diff::Diff::diff(&self.ip, &other.ip, &crate::diff::Path::Field { parent: path, name: "ip" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl IngressLoadBalancerIngress {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressLoadBalancerIngress> {
+    /// The path of the `hostname` field.
+    pub fn hostname(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostname")
+    }
+
+    /// The path of the `ip` field.
+    pub fn ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("ip")
+    }
+
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::IngressPortStatus>> {
+        self.child("ports")
+    }
+}
+
+impl crate::Normalize for IngressLoadBalancerIngress {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.hostname);
+        crate::Normalize::normalize(&mut self.ip);
+        crate::normalize_strategies::list::atomic(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressLoadBalancerIngress {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +178,46 @@ impl crate::serde::Serialize for IngressLoadBalancerIngress {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressLoadBalancerIngress {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.ip {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.hostname {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hostname: Option<std::string::String> = None;
+        let mut value_ip: Option<std::string::String> = None;
+        let mut value_ports: Option<std::vec::Vec<crate::api::networking::v1::IngressPortStatus>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ip, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_hostname, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressLoadBalancerIngress {
+            hostname: value_hostname,
+            ip: value_ip,
+            ports: value_ports,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressLoadBalancerIngress {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressLoadBalancerIngress {
     fn schema_name() -> std::borrow::Cow<'static, str> {
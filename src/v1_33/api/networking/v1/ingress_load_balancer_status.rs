@@ -13,6 +13,48 @@ impl crate::DeepMerge for IngressLoadBalancerStatus {
     }
 }
 
+impl crate::Defaulting for IngressLoadBalancerStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ingress);
+    }
+}
+
+impl crate::diff::Diff for IngressLoadBalancerStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.ingress,
+            &other.ingress,
+            &crate::diff::Path::Field { parent: path, name: "ingress" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl IngressLoadBalancerStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressLoadBalancerStatus> {
+    /// The path of the `ingress` field.
+    pub fn ingress(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::IngressLoadBalancerIngress>> {
+        self.child("ingress")
+    }
+}
+
+impl crate::Normalize for IngressLoadBalancerStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.ingress, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressLoadBalancerStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for IngressLoadBalancerStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressLoadBalancerStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.ingress {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ingress: Option<std::vec::Vec<crate::api::networking::v1::IngressLoadBalancerIngress>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ingress, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressLoadBalancerStatus {
+            ingress: value_ingress,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressLoadBalancerStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressLoadBalancerStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
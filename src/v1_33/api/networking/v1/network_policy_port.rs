@@ -21,6 +21,57 @@ impl crate::DeepMerge for NetworkPolicyPort {
     }
 }
 
+impl crate::Defaulting for NetworkPolicyPort {
+    fn set_defaults(&mut self) {
+        if self.protocol.is_none() {
+            self.protocol = Some("TCP".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.end_port);
+        crate::Defaulting::set_defaults(&mut self.port);
+        crate::Defaulting::set_defaults(&mut self.protocol);
+    }
+}
+
+impl crate::diff::Diff for NetworkPolicyPort {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.end_port, &other.end_port, &crate::diff::Path::Field { parent: path, name: "endPort" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+        crate::diff::Diff::diff(&self.protocol, &other.protocol, &crate::diff::Path::Field { parent: path, name: "protocol" }, changes);
+    }
+}
+
+impl NetworkPolicyPort {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NetworkPolicyPort> {
+    /// The path of the `endPort` field.
+    pub fn end_port(self) -> crate::FieldPath<i32> {
+        self.child("endPort")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("port")
+    }
+
+    /// The path of the `protocol` field.
+    pub fn protocol(self) -> crate::FieldPath<std::string::String> {
+        self.child("protocol")
+    }
+}
+
+impl crate::Normalize for NetworkPolicyPort {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.end_port);
+        crate::Normalize::normalize(&mut self.port);
+        crate::Normalize::normalize(&mut self.protocol);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPort {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +171,46 @@ impl crate::serde::Serialize for NetworkPolicyPort {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NetworkPolicyPort {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.protocol {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.port {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.end_port {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_end_port: Option<i32> = None;
+        let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_protocol: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_protocol, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_end_port, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NetworkPolicyPort {
+            end_port: value_end_port,
+            port: value_port,
+            protocol: value_protocol,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NetworkPolicyPort {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyPort {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for IPAddressSpec {
     }
 }
 
+impl crate::Defaulting for IPAddressSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.parent_ref);
+    }
+}
+
+impl crate::diff::Diff for IPAddressSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.parent_ref, &other.parent_ref, &crate::diff::Path::Field { parent: path, name: "parentRef" }, changes);
+    }
+}
+
+impl IPAddressSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IPAddressSpec> {
+    /// The path of the `parentRef` field.
+    pub fn parent_ref(self) -> crate::FieldPath<crate::api::networking::v1beta1::ParentReference> {
+        self.child("parentRef")
+    }
+}
+
+impl crate::Normalize for IPAddressSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.parent_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IPAddressSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for IPAddressSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IPAddressSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.parent_ref, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_parent_ref: Option<crate::api::networking::v1beta1::ParentReference> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_parent_ref, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IPAddressSpec {
+            parent_ref: value_parent_ref.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IPAddressSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IPAddressSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -15,6 +15,48 @@ impl crate::DeepMerge for Overhead {
     }
 }
 
+impl crate::Defaulting for Overhead {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.pod_fixed);
+    }
+}
+
+impl crate::diff::Diff for Overhead {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.pod_fixed,
+            &other.pod_fixed,
+            &crate::diff::Path::Field { parent: path, name: "podFixed" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl Overhead {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Overhead> {
+    /// The path of the `podFixed` field.
+    pub fn pod_fixed(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("podFixed")
+    }
+}
+
+impl crate::Normalize for Overhead {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.pod_fixed, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Overhead {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -94,6 +136,34 @@ impl crate::serde::Serialize for Overhead {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Overhead {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.pod_fixed {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_pod_fixed: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pod_fixed, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Overhead {
+            pod_fixed: value_pod_fixed,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Overhead {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Overhead {
     fn schema_name() -> std::borrow::Cow<'static, str> {
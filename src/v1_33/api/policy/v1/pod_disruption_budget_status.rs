@@ -54,6 +54,107 @@ impl crate::DeepMerge for PodDisruptionBudgetStatus {
     }
 }
 
+impl crate::Defaulting for PodDisruptionBudgetStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.current_healthy);
+        crate::Defaulting::set_defaults(&mut self.desired_healthy);
+        crate::Defaulting::set_defaults(&mut self.disrupted_pods);
+        crate::Defaulting::set_defaults(&mut self.disruptions_allowed);
+        crate::Defaulting::set_defaults(&mut self.expected_pods);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+    }
+}
+
+impl crate::diff::Diff for PodDisruptionBudgetStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.current_healthy, &other.current_healthy, &crate::diff::Path::Field { parent: path, name: "currentHealthy" }, changes);
+        crate::diff::Diff::diff(&self.desired_healthy, &other.desired_healthy, &crate::diff::Path::Field { parent: path, name: "desiredHealthy" }, changes);
+        crate::diff::strategies::map::values(
+            &self.disrupted_pods,
+            &other.disrupted_pods,
+            &crate::diff::Path::Field { parent: path, name: "disruptedPods" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.disruptions_allowed, &other.disruptions_allowed, &crate::diff::Path::Field { parent: path, name: "disruptionsAllowed" }, changes);
+        crate::diff::Diff::diff(&self.expected_pods, &other.expected_pods, &crate::diff::Path::Field { parent: path, name: "expectedPods" }, changes);
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+    }
+}
+
+impl PodDisruptionBudgetStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodDisruptionBudgetStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `currentHealthy` field.
+    pub fn current_healthy(self) -> crate::FieldPath<i32> {
+        self.child("currentHealthy")
+    }
+
+    /// The path of the `desiredHealthy` field.
+    pub fn desired_healthy(self) -> crate::FieldPath<i32> {
+        self.child("desiredHealthy")
+    }
+
+    /// The path of the `disruptedPods` field.
+    pub fn disrupted_pods(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::apis::meta::v1::Time>> {
+        self.child("disruptedPods")
+    }
+
+    /// The path of the `disruptionsAllowed` field.
+    pub fn disruptions_allowed(self) -> crate::FieldPath<i32> {
+        self.child("disruptionsAllowed")
+    }
+
+    /// The path of the `expectedPods` field.
+    pub fn expected_pods(self) -> crate::FieldPath<i32> {
+        self.child("expectedPods")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+}
+
+impl crate::Normalize for PodDisruptionBudgetStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.current_healthy);
+        crate::Normalize::normalize(&mut self.desired_healthy);
+        crate::normalize_strategies::map::values(&mut self.disrupted_pods, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.disruptions_allowed);
+        crate::Normalize::normalize(&mut self.expected_pods);
+        crate::Normalize::normalize(&mut self.observed_generation);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -182,6 +283,72 @@ impl crate::serde::Serialize for PodDisruptionBudgetStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodDisruptionBudgetStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.disrupted_pods {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.disruptions_allowed, 3, buf);
+        crate::protobuf::Field::encode_field(&self.current_healthy, 4, buf);
+        crate::protobuf::Field::encode_field(&self.desired_healthy, 5, buf);
+        crate::protobuf::Field::encode_field(&self.expected_pods, 6, buf);
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> = None;
+        let mut value_current_healthy: Option<i32> = None;
+        let mut value_desired_healthy: Option<i32> = None;
+        let mut value_disrupted_pods: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::apis::meta::v1::Time>> = None;
+        let mut value_disruptions_allowed: Option<i32> = None;
+        let mut value_expected_pods: Option<i32> = None;
+        let mut value_observed_generation: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_disrupted_pods, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_disruptions_allowed, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_current_healthy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_desired_healthy, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_expected_pods, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodDisruptionBudgetStatus {
+            conditions: value_conditions,
+            current_healthy: value_current_healthy.unwrap_or_default(),
+            desired_healthy: value_desired_healthy.unwrap_or_default(),
+            disrupted_pods: value_disrupted_pods,
+            disruptions_allowed: value_disruptions_allowed.unwrap_or_default(),
+            expected_pods: value_expected_pods.unwrap_or_default(),
+            observed_generation: value_observed_generation,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodDisruptionBudgetStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDisruptionBudgetStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
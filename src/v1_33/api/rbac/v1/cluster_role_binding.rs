@@ -46,6 +46,68 @@ impl crate::DeepMerge for ClusterRoleBinding {
     }
 }
 
+impl crate::Defaulting for ClusterRoleBinding {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.role_ref);
+        crate::Defaulting::set_defaults(&mut self.subjects);
+    }
+}
+
+impl crate::diff::Diff for ClusterRoleBinding {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.role_ref, &other.role_ref, &crate::diff::Path::Field { parent: path, name: "roleRef" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.subjects,
+            &other.subjects,
+            &crate::diff::Path::Field { parent: path, name: "subjects" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ClusterRoleBinding {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ClusterRoleBinding> {
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `roleRef` field.
+    pub fn role_ref(self) -> crate::FieldPath<crate::api::rbac::v1::RoleRef> {
+        self.child("roleRef")
+    }
+
+    /// The path of the `subjects` field.
+    pub fn subjects(self) -> crate::FieldPath<std::vec::Vec<crate::api::rbac::v1::Subject>> {
+        self.child("subjects")
+    }
+}
+
+impl crate::Normalize for ClusterRoleBinding {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.role_ref);
+        crate::normalize_strategies::list::atomic(&mut self.subjects, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ClusterRoleBinding {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -160,6 +222,52 @@ impl crate::serde::Serialize for ClusterRoleBinding {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ClusterRoleBinding {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.subjects {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.role_ref, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_role_ref: Option<crate::api::rbac::v1::RoleRef> = None;
+        let mut value_subjects: Option<std::vec::Vec<crate::api::rbac::v1::Subject>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_subjects, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_role_ref, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ClusterRoleBinding {
+            metadata: value_metadata.unwrap_or_default(),
+            role_ref: value_role_ref.unwrap_or_default(),
+            subjects: value_subjects,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ClusterRoleBinding {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClusterRoleBinding {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,56 @@ impl crate::DeepMerge for CounterSet {
     }
 }
 
+impl crate::Defaulting for CounterSet {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.counters);
+        crate::Defaulting::set_defaults(&mut self.name);
+    }
+}
+
+impl crate::diff::Diff for CounterSet {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.counters,
+            &other.counters,
+            &crate::diff::Path::Field { parent: path, name: "counters" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+    }
+}
+
+impl CounterSet {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CounterSet> {
+    /// The path of the `counters` field.
+    pub fn counters(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::api::resource::v1alpha3::Counter>> {
+        self.child("counters")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+}
+
+impl crate::Normalize for CounterSet {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.counters, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CounterSet {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -109,6 +159,36 @@ impl crate::serde::Serialize for CounterSet {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CounterSet {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.counters, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_counters: Option<std::collections::BTreeMap<std::string::String, crate::api::resource::v1alpha3::Counter>> = None;
+        let mut value_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_counters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CounterSet {
+            counters: value_counters.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CounterSet {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CounterSet {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -19,6 +19,66 @@ impl crate::DeepMerge for DeviceAllocationResult {
     }
 }
 
+impl crate::Defaulting for DeviceAllocationResult {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config);
+        crate::Defaulting::set_defaults(&mut self.results);
+    }
+}
+
+impl crate::diff::Diff for DeviceAllocationResult {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.config,
+            &other.config,
+            &crate::diff::Path::Field { parent: path, name: "config" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.results,
+            &other.results,
+            &crate::diff::Path::Field { parent: path, name: "results" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl DeviceAllocationResult {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceAllocationResult> {
+    /// The path of the `config` field.
+    pub fn config(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceAllocationConfiguration>> {
+        self.child("config")
+    }
+
+    /// The path of the `results` field.
+    pub fn results(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceRequestAllocationResult>> {
+        self.child("results")
+    }
+}
+
+impl crate::Normalize for DeviceAllocationResult {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.config, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.results, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceAllocationResult {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -108,6 +168,40 @@ impl crate::serde::Serialize for DeviceAllocationResult {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceAllocationResult {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.results {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.config {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceAllocationConfiguration>> = None;
+        let mut value_results: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceRequestAllocationResult>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_results, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_config, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceAllocationResult {
+            config: value_config,
+            results: value_results,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceAllocationResult {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceAllocationResult {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,84 @@ impl crate::DeepMerge for DeviceClaim {
     }
 }
 
+impl crate::Defaulting for DeviceClaim {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config);
+        crate::Defaulting::set_defaults(&mut self.constraints);
+        crate::Defaulting::set_defaults(&mut self.requests);
+    }
+}
+
+impl crate::diff::Diff for DeviceClaim {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.config,
+            &other.config,
+            &crate::diff::Path::Field { parent: path, name: "config" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.constraints,
+            &other.constraints,
+            &crate::diff::Path::Field { parent: path, name: "constraints" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.requests,
+            &other.requests,
+            &crate::diff::Path::Field { parent: path, name: "requests" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl DeviceClaim {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceClaim> {
+    /// The path of the `config` field.
+    pub fn config(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceClaimConfiguration>> {
+        self.child("config")
+    }
+
+    /// The path of the `constraints` field.
+    pub fn constraints(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceConstraint>> {
+        self.child("constraints")
+    }
+
+    /// The path of the `requests` field.
+    pub fn requests(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceRequest>> {
+        self.child("requests")
+    }
+}
+
+impl crate::Normalize for DeviceClaim {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.config, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.constraints, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.requests, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceClaim {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +198,46 @@ impl crate::serde::Serialize for DeviceClaim {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceClaim {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.requests {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.constraints {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.config {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceClaimConfiguration>> = None;
+        let mut value_constraints: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceConstraint>> = None;
+        let mut value_requests: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceRequest>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_requests, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_constraints, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_config, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceClaim {
+            config: value_config,
+            constraints: value_constraints,
+            requests: value_requests,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceClaim {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceClaim {
     fn schema_name() -> std::borrow::Cow<'static, str> {
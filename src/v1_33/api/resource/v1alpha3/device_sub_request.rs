@@ -61,6 +61,98 @@ impl crate::DeepMerge for DeviceSubRequest {
     }
 }
 
+impl crate::Defaulting for DeviceSubRequest {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.allocation_mode);
+        crate::Defaulting::set_defaults(&mut self.count);
+        crate::Defaulting::set_defaults(&mut self.device_class_name);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.selectors);
+        crate::Defaulting::set_defaults(&mut self.tolerations);
+    }
+}
+
+impl crate::diff::Diff for DeviceSubRequest {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allocation_mode, &other.allocation_mode, &crate::diff::Path::Field { parent: path, name: "allocationMode" }, changes);
+        crate::diff::Diff::diff(&self.count, &other.count, &crate::diff::Path::Field { parent: path, name: "count" }, changes);
+        crate::diff::Diff::diff(&self.device_class_name, &other.device_class_name, &crate::diff::Path::Field { parent: path, name: "deviceClassName" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.selectors,
+            &other.selectors,
+            &crate::diff::Path::Field { parent: path, name: "selectors" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.tolerations,
+            &other.tolerations,
+            &crate::diff::Path::Field { parent: path, name: "tolerations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl DeviceSubRequest {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceSubRequest> {
+    /// The path of the `allocationMode` field.
+    pub fn allocation_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("allocationMode")
+    }
+
+    /// The path of the `count` field.
+    pub fn count(self) -> crate::FieldPath<i64> {
+        self.child("count")
+    }
+
+    /// The path of the `deviceClassName` field.
+    pub fn device_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("deviceClassName")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `selectors` field.
+    pub fn selectors(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceSelector>> {
+        self.child("selectors")
+    }
+
+    /// The path of the `tolerations` field.
+    pub fn tolerations(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1alpha3::DeviceToleration>> {
+        self.child("tolerations")
+    }
+}
+
+impl crate::Normalize for DeviceSubRequest {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allocation_mode);
+        crate::Normalize::normalize(&mut self.count);
+        crate::Normalize::normalize(&mut self.device_class_name);
+        crate::Normalize::normalize(&mut self.name);
+        crate::normalize_strategies::list::atomic(&mut self.selectors, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.tolerations, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceSubRequest {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -185,6 +277,60 @@ impl crate::serde::Serialize for DeviceSubRequest {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceSubRequest {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.device_class_name, 2, buf);
+        if let Some(value) = &self.selectors {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.allocation_mode {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.count {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.tolerations {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allocation_mode: Option<std::string::String> = None;
+        let mut value_count: Option<i64> = None;
+        let mut value_device_class_name: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_selectors: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceSelector>> = None;
+        let mut value_tolerations: Option<std::vec::Vec<crate::api::resource::v1alpha3::DeviceToleration>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_device_class_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_selectors, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_allocation_mode, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_count, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_tolerations, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceSubRequest {
+            allocation_mode: value_allocation_mode,
+            count: value_count,
+            device_class_name: value_device_class_name.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            selectors: value_selectors,
+            tolerations: value_tolerations,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceSubRequest {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceSubRequest {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for DeviceTaintRuleSpec {
     }
 }
 
+impl crate::Defaulting for DeviceTaintRuleSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.device_selector);
+        crate::Defaulting::set_defaults(&mut self.taint);
+    }
+}
+
+impl crate::diff::Diff for DeviceTaintRuleSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.device_selector, &other.device_selector, &crate::diff::Path::Field { parent: path, name: "deviceSelector" }, changes);
+        crate::diff::Diff::diff(&self.taint, &other.taint, &crate::diff::Path::Field { parent: path, name: "taint" }, changes);
+    }
+}
+
+impl DeviceTaintRuleSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceTaintRuleSpec> {
+    /// The path of the `deviceSelector` field.
+    pub fn device_selector(self) -> crate::FieldPath<crate::api::resource::v1alpha3::DeviceTaintSelector> {
+        self.child("deviceSelector")
+    }
+
+    /// The path of the `taint` field.
+    pub fn taint(self) -> crate::FieldPath<crate::api::resource::v1alpha3::DeviceTaint> {
+        self.child("taint")
+    }
+}
+
+impl crate::Normalize for DeviceTaintRuleSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.device_selector);
+        crate::Normalize::normalize(&mut self.taint);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceTaintRuleSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for DeviceTaintRuleSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceTaintRuleSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.device_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.taint, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_device_selector: Option<crate::api::resource::v1alpha3::DeviceTaintSelector> = None;
+        let mut value_taint: Option<crate::api::resource::v1alpha3::DeviceTaint> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_device_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_taint, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceTaintRuleSpec {
+            device_selector: value_device_selector,
+            taint: value_taint.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceTaintRuleSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceTaintRuleSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
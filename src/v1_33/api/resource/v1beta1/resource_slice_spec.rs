@@ -61,6 +61,114 @@ impl crate::DeepMerge for ResourceSliceSpec {
     }
 }
 
+impl crate::Defaulting for ResourceSliceSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.all_nodes);
+        crate::Defaulting::set_defaults(&mut self.devices);
+        crate::Defaulting::set_defaults(&mut self.driver);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.node_selector);
+        crate::Defaulting::set_defaults(&mut self.per_device_node_selection);
+        crate::Defaulting::set_defaults(&mut self.pool);
+        crate::Defaulting::set_defaults(&mut self.shared_counters);
+    }
+}
+
+impl crate::diff::Diff for ResourceSliceSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.all_nodes, &other.all_nodes, &crate::diff::Path::Field { parent: path, name: "allNodes" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.devices,
+            &other.devices,
+            &crate::diff::Path::Field { parent: path, name: "devices" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.driver, &other.driver, &crate::diff::Path::Field { parent: path, name: "driver" }, changes);
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.node_selector, &other.node_selector, &crate::diff::Path::Field { parent: path, name: "nodeSelector" }, changes);
+        crate::diff::Diff::diff(&self.per_device_node_selection, &other.per_device_node_selection, &crate::diff::Path::Field { parent: path, name: "perDeviceNodeSelection" }, changes);
+        crate::diff::Diff::diff(&self.pool, &other.pool, &crate::diff::Path::Field { parent: path, name: "pool" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.shared_counters,
+            &other.shared_counters,
+            &crate::diff::Path::Field { parent: path, name: "sharedCounters" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceSliceSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceSliceSpec> {
+    /// The path of the `allNodes` field.
+    pub fn all_nodes(self) -> crate::FieldPath<bool> {
+        self.child("allNodes")
+    }
+
+    /// The path of the `devices` field.
+    pub fn devices(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1beta1::Device>> {
+        self.child("devices")
+    }
+
+    /// The path of the `driver` field.
+    pub fn driver(self) -> crate::FieldPath<std::string::String> {
+        self.child("driver")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `nodeSelector` field.
+    pub fn node_selector(self) -> crate::FieldPath<crate::api::core::v1::NodeSelector> {
+        self.child("nodeSelector")
+    }
+
+    /// The path of the `perDeviceNodeSelection` field.
+    pub fn per_device_node_selection(self) -> crate::FieldPath<bool> {
+        self.child("perDeviceNodeSelection")
+    }
+
+    /// The path of the `pool` field.
+    pub fn pool(self) -> crate::FieldPath<crate::api::resource::v1beta1::ResourcePool> {
+        self.child("pool")
+    }
+
+    /// The path of the `sharedCounters` field.
+    pub fn shared_counters(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1beta1::CounterSet>> {
+        self.child("sharedCounters")
+    }
+}
+
+impl crate::Normalize for ResourceSliceSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.all_nodes);
+        crate::normalize_strategies::list::atomic(&mut self.devices, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.driver);
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.node_selector);
+        crate::Normalize::normalize(&mut self.per_device_node_selection);
+        crate::Normalize::normalize(&mut self.pool);
+        crate::normalize_strategies::list::atomic(&mut self.shared_counters, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceSliceSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -205,6 +313,72 @@ impl crate::serde::Serialize for ResourceSliceSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceSliceSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.driver, 1, buf);
+        crate::protobuf::Field::encode_field(&self.pool, 2, buf);
+        if let Some(value) = &self.node_name {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.node_selector {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.all_nodes {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.devices {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.per_device_node_selection {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.shared_counters {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_all_nodes: Option<bool> = None;
+        let mut value_devices: Option<std::vec::Vec<crate::api::resource::v1beta1::Device>> = None;
+        let mut value_driver: Option<std::string::String> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_node_selector: Option<crate::api::core::v1::NodeSelector> = None;
+        let mut value_per_device_node_selection: Option<bool> = None;
+        let mut value_pool: Option<crate::api::resource::v1beta1::ResourcePool> = None;
+        let mut value_shared_counters: Option<std::vec::Vec<crate::api::resource::v1beta1::CounterSet>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_driver, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pool, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_node_selector, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_all_nodes, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_devices, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_per_device_node_selection, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_shared_counters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceSliceSpec {
+            all_nodes: value_all_nodes,
+            devices: value_devices,
+            driver: value_driver.unwrap_or_default(),
+            node_name: value_node_name,
+            node_selector: value_node_selector,
+            per_device_node_selection: value_per_device_node_selection,
+            pool: value_pool.unwrap_or_default(),
+            shared_counters: value_shared_counters,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceSliceSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceSliceSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
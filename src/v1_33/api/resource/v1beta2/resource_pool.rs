@@ -27,6 +27,54 @@ impl crate::DeepMerge for ResourcePool {
     }
 }
 
+impl crate::Defaulting for ResourcePool {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.generation);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.resource_slice_count);
+    }
+}
+
+impl crate::diff::Diff for ResourcePool {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.generation, &other.generation, &crate::diff::Path::Field { parent: path, name: "generation" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.resource_slice_count, &other.resource_slice_count, &crate::diff::Path::Field { parent: path, name: "resourceSliceCount" }, changes);
+    }
+}
+
+impl ResourcePool {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourcePool> {
+    /// The path of the `generation` field.
+    pub fn generation(self) -> crate::FieldPath<i64> {
+        self.child("generation")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `resourceSliceCount` field.
+    pub fn resource_slice_count(self) -> crate::FieldPath<i64> {
+        self.child("resourceSliceCount")
+    }
+}
+
+impl crate::Normalize for ResourcePool {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.generation);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.resource_slice_count);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourcePool {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +166,40 @@ impl crate::serde::Serialize for ResourcePool {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourcePool {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.generation, 2, buf);
+        crate::protobuf::Field::encode_field(&self.resource_slice_count, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_generation: Option<i64> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_resource_slice_count: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_generation, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource_slice_count, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourcePool {
+            generation: value_generation.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            resource_slice_count: value_resource_slice_count.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourcePool {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourcePool {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -92,6 +92,114 @@ impl crate::DeepMerge for CSIDriverSpec {
     }
 }
 
+impl crate::Defaulting for CSIDriverSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.attach_required);
+        crate::Defaulting::set_defaults(&mut self.fs_group_policy);
+        crate::Defaulting::set_defaults(&mut self.node_allocatable_update_period_seconds);
+        crate::Defaulting::set_defaults(&mut self.pod_info_on_mount);
+        crate::Defaulting::set_defaults(&mut self.requires_republish);
+        crate::Defaulting::set_defaults(&mut self.se_linux_mount);
+        crate::Defaulting::set_defaults(&mut self.storage_capacity);
+        crate::Defaulting::set_defaults(&mut self.token_requests);
+        crate::Defaulting::set_defaults(&mut self.volume_lifecycle_modes);
+    }
+}
+
+impl crate::diff::Diff for CSIDriverSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.attach_required, &other.attach_required, &crate::diff::Path::Field { parent: path, name: "attachRequired" }, changes);
+        crate::diff::Diff::diff(&self.fs_group_policy, &other.fs_group_policy, &crate::diff::Path::Field { parent: path, name: "fsGroupPolicy" }, changes);
+        crate::diff::Diff::diff(&self.node_allocatable_update_period_seconds, &other.node_allocatable_update_period_seconds, &crate::diff::Path::Field { parent: path, name: "nodeAllocatableUpdatePeriodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.pod_info_on_mount, &other.pod_info_on_mount, &crate::diff::Path::Field { parent: path, name: "podInfoOnMount" }, changes);
+        crate::diff::Diff::diff(&self.requires_republish, &other.requires_republish, &crate::diff::Path::Field { parent: path, name: "requiresRepublish" }, changes);
+        crate::diff::Diff::diff(&self.se_linux_mount, &other.se_linux_mount, &crate::diff::Path::Field { parent: path, name: "seLinuxMount" }, changes);
+        crate::diff::Diff::diff(&self.storage_capacity, &other.storage_capacity, &crate::diff::Path::Field { parent: path, name: "storageCapacity" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.token_requests,
+            &other.token_requests,
+            &crate::diff::Path::Field { parent: path, name: "tokenRequests" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::set(&self.volume_lifecycle_modes, &other.volume_lifecycle_modes, &crate::diff::Path::Field { parent: path, name: "volumeLifecycleModes" }, changes);
+    }
+}
+
+impl CSIDriverSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CSIDriverSpec> {
+    /// The path of the `attachRequired` field.
+    pub fn attach_required(self) -> crate::FieldPath<bool> {
+        self.child("attachRequired")
+    }
+
+    /// The path of the `fsGroupPolicy` field.
+    pub fn fs_group_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsGroupPolicy")
+    }
+
+    /// The path of the `nodeAllocatableUpdatePeriodSeconds` field.
+    pub fn node_allocatable_update_period_seconds(self) -> crate::FieldPath<i64> {
+        self.child("nodeAllocatableUpdatePeriodSeconds")
+    }
+
+    /// The path of the `podInfoOnMount` field.
+    pub fn pod_info_on_mount(self) -> crate::FieldPath<bool> {
+        self.child("podInfoOnMount")
+    }
+
+    /// The path of the `requiresRepublish` field.
+    pub fn requires_republish(self) -> crate::FieldPath<bool> {
+        self.child("requiresRepublish")
+    }
+
+    /// The path of the `seLinuxMount` field.
+    pub fn se_linux_mount(self) -> crate::FieldPath<bool> {
+        self.child("seLinuxMount")
+    }
+
+    /// The path of the `storageCapacity` field.
+    pub fn storage_capacity(self) -> crate::FieldPath<bool> {
+        self.child("storageCapacity")
+    }
+
+    /// The path of the `tokenRequests` field.
+    pub fn token_requests(self) -> crate::FieldPath<std::vec::Vec<crate::api::storage::v1::TokenRequest>> {
+        self.child("tokenRequests")
+    }
+
+    /// The path of the `volumeLifecycleModes` field.
+    pub fn volume_lifecycle_modes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("volumeLifecycleModes")
+    }
+}
+
+impl crate::Normalize for CSIDriverSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.attach_required);
+        crate::Normalize::normalize(&mut self.fs_group_policy);
+        crate::Normalize::normalize(&mut self.node_allocatable_update_period_seconds);
+        crate::Normalize::normalize(&mut self.pod_info_on_mount);
+        crate::Normalize::normalize(&mut self.requires_republish);
+        crate::Normalize::normalize(&mut self.se_linux_mount);
+        crate::Normalize::normalize(&mut self.storage_capacity);
+        crate::normalize_strategies::list::atomic(&mut self.token_requests, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::set(&mut self.volume_lifecycle_modes, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CSIDriverSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -251,6 +359,82 @@ impl crate::serde::Serialize for CSIDriverSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CSIDriverSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.attach_required {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.pod_info_on_mount {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.volume_lifecycle_modes {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.storage_capacity {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.fs_group_policy {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.token_requests {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.requires_republish {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.se_linux_mount {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.node_allocatable_update_period_seconds {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_attach_required: Option<bool> = None;
+        let mut value_fs_group_policy: Option<std::string::String> = None;
+        let mut value_node_allocatable_update_period_seconds: Option<i64> = None;
+        let mut value_pod_info_on_mount: Option<bool> = None;
+        let mut value_requires_republish: Option<bool> = None;
+        let mut value_se_linux_mount: Option<bool> = None;
+        let mut value_storage_capacity: Option<bool> = None;
+        let mut value_token_requests: Option<std::vec::Vec<crate::api::storage::v1::TokenRequest>> = None;
+        let mut value_volume_lifecycle_modes: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_attach_required, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_info_on_mount, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_volume_lifecycle_modes, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_storage_capacity, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_fs_group_policy, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_token_requests, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_requires_republish, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_se_linux_mount, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_node_allocatable_update_period_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CSIDriverSpec {
+            attach_required: value_attach_required,
+            fs_group_policy: value_fs_group_policy,
+            node_allocatable_update_period_seconds: value_node_allocatable_update_period_seconds,
+            pod_info_on_mount: value_pod_info_on_mount,
+            requires_republish: value_requires_republish,
+            se_linux_mount: value_se_linux_mount,
+            storage_capacity: value_storage_capacity,
+            token_requests: value_token_requests,
+            volume_lifecycle_modes: value_volume_lifecycle_modes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CSIDriverSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSIDriverSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
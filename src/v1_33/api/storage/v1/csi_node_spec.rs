@@ -20,6 +20,49 @@ impl crate::DeepMerge for CSINodeSpec {
     }
 }
 
+impl crate::Defaulting for CSINodeSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.drivers);
+    }
+}
+
+impl crate::diff::Diff for CSINodeSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.drivers,
+            &other.drivers,
+            &crate::diff::Path::Field { parent: path, name: "drivers" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl CSINodeSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CSINodeSpec> {
+    /// The path of the `drivers` field.
+    pub fn drivers(self) -> crate::FieldPath<std::vec::Vec<crate::api::storage::v1::CSINodeDriver>> {
+        self.child("drivers")
+    }
+}
+
+impl crate::Normalize for CSINodeSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.drivers, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CSINodeSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -97,6 +140,42 @@ impl crate::serde::Serialize for CSINodeSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CSINodeSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.drivers, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_drivers: Option<std::vec::Vec<crate::api::storage::v1::CSINodeDriver>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_drivers, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CSINodeSpec {
+            drivers: value_drivers.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CSINodeSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "drivers" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSINodeSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
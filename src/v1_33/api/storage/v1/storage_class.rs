@@ -70,6 +70,134 @@ impl crate::DeepMerge for StorageClass {
     }
 }
 
+impl crate::Defaulting for StorageClass {
+    fn set_defaults(&mut self) {
+        if self.reclaim_policy.is_none() {
+            self.reclaim_policy = Some("Delete".into());
+        }
+        if self.volume_binding_mode.is_none() {
+            self.volume_binding_mode = Some("Immediate".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.allow_volume_expansion);
+        crate::Defaulting::set_defaults(&mut self.allowed_topologies);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.mount_options);
+        crate::Defaulting::set_defaults(&mut self.parameters);
+        crate::Defaulting::set_defaults(&mut self.provisioner);
+        crate::Defaulting::set_defaults(&mut self.reclaim_policy);
+        crate::Defaulting::set_defaults(&mut self.volume_binding_mode);
+    }
+}
+
+impl crate::diff::Diff for StorageClass {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allow_volume_expansion, &other.allow_volume_expansion, &crate::diff::Path::Field { parent: path, name: "allowVolumeExpansion" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.allowed_topologies,
+            &other.allowed_topologies,
+            &crate::diff::Path::Field { parent: path, name: "allowedTopologies" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.mount_options,
+            &other.mount_options,
+            &crate::diff::Path::Field { parent: path, name: "mountOptions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.parameters,
+            &other.parameters,
+            &crate::diff::Path::Field { parent: path, name: "parameters" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.provisioner, &other.provisioner, &crate::diff::Path::Field { parent: path, name: "provisioner" }, changes);
+        crate::diff::Diff::diff(&self.reclaim_policy, &other.reclaim_policy, &crate::diff::Path::Field { parent: path, name: "reclaimPolicy" }, changes);
+        crate::diff::Diff::diff(&self.volume_binding_mode, &other.volume_binding_mode, &crate::diff::Path::Field { parent: path, name: "volumeBindingMode" }, changes);
+    }
+}
+
+impl StorageClass {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StorageClass> {
+    /// The path of the `allowVolumeExpansion` field.
+    pub fn allow_volume_expansion(self) -> crate::FieldPath<bool> {
+        self.child("allowVolumeExpansion")
+    }
+
+    /// The path of the `allowedTopologies` field.
+    pub fn allowed_topologies(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::TopologySelectorTerm>> {
+        self.child("allowedTopologies")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `mountOptions` field.
+    pub fn mount_options(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("mountOptions")
+    }
+
+    /// The path of the `parameters` field.
+    pub fn parameters(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("parameters")
+    }
+
+    /// The path of the `provisioner` field.
+    pub fn provisioner(self) -> crate::FieldPath<std::string::String> {
+        self.child("provisioner")
+    }
+
+    /// The path of the `reclaimPolicy` field.
+    pub fn reclaim_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("reclaimPolicy")
+    }
+
+    /// The path of the `volumeBindingMode` field.
+    pub fn volume_binding_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeBindingMode")
+    }
+}
+
+impl crate::Normalize for StorageClass {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allow_volume_expansion);
+        crate::normalize_strategies::list::atomic(&mut self.allowed_topologies, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::list::atomic(&mut self.mount_options, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::map::values(&mut self.parameters, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.provisioner);
+        crate::Normalize::normalize(&mut self.reclaim_policy);
+        crate::Normalize::normalize(&mut self.volume_binding_mode);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StorageClass {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -234,6 +362,82 @@ impl crate::serde::Serialize for StorageClass {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StorageClass {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.provisioner, 2, buf);
+        if let Some(value) = &self.parameters {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.reclaim_policy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.mount_options {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.allow_volume_expansion {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.volume_binding_mode {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.allowed_topologies {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allow_volume_expansion: Option<bool> = None;
+        let mut value_allowed_topologies: Option<std::vec::Vec<crate::api::core::v1::TopologySelectorTerm>> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_mount_options: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_parameters: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_provisioner: Option<std::string::String> = None;
+        let mut value_reclaim_policy: Option<std::string::String> = None;
+        let mut value_volume_binding_mode: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_provisioner, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_parameters, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_reclaim_policy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_mount_options, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_allow_volume_expansion, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_volume_binding_mode, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_allowed_topologies, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StorageClass {
+            allow_volume_expansion: value_allow_volume_expansion,
+            allowed_topologies: value_allowed_topologies,
+            metadata: value_metadata.unwrap_or_default(),
+            mount_options: value_mount_options,
+            parameters: value_parameters,
+            provisioner: value_provisioner.unwrap_or_default(),
+            reclaim_policy: value_reclaim_policy,
+            volume_binding_mode: value_volume_binding_mode,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StorageClass {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StorageClass {
     fn schema_name() -> std::borrow::Cow<'static, str> {
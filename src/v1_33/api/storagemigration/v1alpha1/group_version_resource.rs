@@ -21,6 +21,54 @@ impl crate::DeepMerge for GroupVersionResource {
     }
 }
 
+impl crate::Defaulting for GroupVersionResource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.version);
+    }
+}
+
+impl crate::diff::Diff for GroupVersionResource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+    }
+}
+
+impl GroupVersionResource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GroupVersionResource> {
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+}
+
+impl crate::Normalize for GroupVersionResource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GroupVersionResource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for GroupVersionResource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GroupVersionResource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.resource {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_resource: Option<std::string::String> = None;
+        let mut value_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GroupVersionResource {
+            group: value_group,
+            resource: value_resource,
+            version: value_version,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GroupVersionResource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GroupVersionResource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
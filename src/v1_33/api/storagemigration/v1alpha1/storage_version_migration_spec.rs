@@ -17,6 +17,46 @@ impl crate::DeepMerge for StorageVersionMigrationSpec {
     }
 }
 
+impl crate::Defaulting for StorageVersionMigrationSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.continue_token);
+        crate::Defaulting::set_defaults(&mut self.resource);
+    }
+}
+
+impl crate::diff::Diff for StorageVersionMigrationSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.continue_token, &other.continue_token, &crate::diff::Path::Field { parent: path, name: "continueToken" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+    }
+}
+
+impl StorageVersionMigrationSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StorageVersionMigrationSpec> {
+    /// The path of the `continueToken` field.
+    pub fn continue_token(self) -> crate::FieldPath<std::string::String> {
+        self.child("continueToken")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<crate::api::storagemigration::v1alpha1::GroupVersionResource> {
+        self.child("resource")
+    }
+}
+
+impl crate::Normalize for StorageVersionMigrationSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.continue_token);
+        crate::Normalize::normalize(&mut self.resource);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StorageVersionMigrationSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for StorageVersionMigrationSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StorageVersionMigrationSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.resource, 1, buf);
+        if let Some(value) = &self.continue_token {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_continue_token: Option<std::string::String> = None;
+        let mut value_resource: Option<crate::api::storagemigration::v1alpha1::GroupVersionResource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_continue_token, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StorageVersionMigrationSpec {
+            continue_token: value_continue_token,
+            resource: value_resource.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StorageVersionMigrationSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StorageVersionMigrationSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for SelectableField {
     }
 }
 
+impl crate::Defaulting for SelectableField {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.json_path);
+    }
+}
+
+impl crate::diff::Diff for SelectableField {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.json_path, &other.json_path, &crate::diff::Path::Field { parent: path, name: "jsonPath" }, changes);
+    }
+}
+
+impl SelectableField {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SelectableField> {
+    /// The path of the `jsonPath` field.
+    pub fn json_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("jsonPath")
+    }
+}
+
+impl crate::Normalize for SelectableField {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.json_path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SelectableField {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for SelectableField {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SelectableField {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.json_path, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_json_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_json_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SelectableField {
+            json_path: value_json_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SelectableField {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelectableField {
     fn schema_name() -> std::borrow::Cow<'static, str> {
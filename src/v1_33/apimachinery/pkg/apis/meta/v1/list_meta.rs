@@ -25,6 +25,62 @@ impl crate::DeepMerge for ListMeta {
     }
 }
 
+impl crate::Defaulting for ListMeta {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.continue_);
+        crate::Defaulting::set_defaults(&mut self.remaining_item_count);
+        crate::Defaulting::set_defaults(&mut self.resource_version);
+        crate::Defaulting::set_defaults(&mut self.self_link);
+    }
+}
+
+impl crate::diff::Diff for ListMeta {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.continue_, &other.continue_, &crate::diff::Path::Field { parent: path, name: "continue" }, changes);
+        crate::diff::Diff::diff(&self.remaining_item_count, &other.remaining_item_count, &crate::diff::Path::Field { parent: path, name: "remainingItemCount" }, changes);
+        crate::diff::Diff::diff(&self.resource_version, &other.resource_version, &crate::diff::Path::Field { parent: path, name: "resourceVersion" }, changes);
+        crate::diff::Diff::diff(&self.self_link, &other.self_link, &crate::diff::Path::Field { parent: path, name: "selfLink" }, changes);
+    }
+}
+
+impl ListMeta {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ListMeta> {
+    /// The path of the `continue` field.
+    pub fn continue_(self) -> crate::FieldPath<std::string::String> {
+        self.child("continue")
+    }
+
+    /// The path of the `remainingItemCount` field.
+    pub fn remaining_item_count(self) -> crate::FieldPath<i64> {
+        self.child("remainingItemCount")
+    }
+
+    /// The path of the `resourceVersion` field.
+    pub fn resource_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceVersion")
+    }
+
+    /// The path of the `selfLink` field.
+    pub fn self_link(self) -> crate::FieldPath<std::string::String> {
+        self.child("selfLink")
+    }
+}
+
+impl crate::Normalize for ListMeta {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.continue_);
+        crate::Normalize::normalize(&mut self.remaining_item_count);
+        crate::Normalize::normalize(&mut self.resource_version);
+        crate::Normalize::normalize(&mut self.self_link);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ListMeta {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for ListMeta {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ListMeta {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.self_link {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.resource_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.continue_ {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.remaining_item_count {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_continue_: Option<std::string::String> = None;
+        let mut value_remaining_item_count: Option<i64> = None;
+        let mut value_resource_version: Option<std::string::String> = None;
+        let mut value_self_link: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_self_link, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_version, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_continue_, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_remaining_item_count, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ListMeta {
+            continue_: value_continue_,
+            remaining_item_count: value_remaining_item_count,
+            resource_version: value_resource_version,
+            self_link: value_self_link,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ListMeta {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ListMeta {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for Preconditions {
     }
 }
 
+impl crate::Defaulting for Preconditions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.resource_version);
+        crate::Defaulting::set_defaults(&mut self.uid);
+    }
+}
+
+impl crate::diff::Diff for Preconditions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.resource_version, &other.resource_version, &crate::diff::Path::Field { parent: path, name: "resourceVersion" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+    }
+}
+
+impl Preconditions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Preconditions> {
+    /// The path of the `resourceVersion` field.
+    pub fn resource_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceVersion")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+}
+
+impl crate::Normalize for Preconditions {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.resource_version);
+        crate::Normalize::normalize(&mut self.uid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Preconditions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for Preconditions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Preconditions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.resource_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_resource_version: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_version, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Preconditions {
+            resource_version: value_resource_version,
+            uid: value_uid,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Preconditions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Preconditions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
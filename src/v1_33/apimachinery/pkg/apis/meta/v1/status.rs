@@ -54,6 +54,78 @@ impl crate::DeepMerge for Status {
     }
 }
 
+impl crate::Defaulting for Status {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.code);
+        crate::Defaulting::set_defaults(&mut self.details);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.status);
+    }
+}
+
+impl crate::diff::Diff for Status {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.code, &other.code, &crate::diff::Path::Field { parent: path, name: "code" }, changes);
+        crate::diff::Diff::diff(&self.details, &other.details, &crate::diff::Path::Field { parent: path, name: "details" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.status, &other.status, &crate::diff::Path::Field { parent: path, name: "status" }, changes);
+    }
+}
+
+impl Status {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Status> {
+    /// The path of the `code` field.
+    pub fn code(self) -> crate::FieldPath<i32> {
+        self.child("code")
+    }
+
+    /// The path of the `details` field.
+    pub fn details(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::StatusDetails> {
+        self.child("details")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ListMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `status` field.
+    pub fn status(self) -> crate::FieldPath<std::string::String> {
+        self.child("status")
+    }
+}
+
+impl crate::Normalize for Status {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.code);
+        crate::Normalize::normalize(&mut self.details);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.status);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Status {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -201,6 +273,62 @@ impl crate::serde::Serialize for Status {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Status {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.status {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.details {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.code {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_code: Option<i32> = None;
+        let mut value_details: Option<crate::apimachinery::pkg::apis::meta::v1::StatusDetails> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ListMeta> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_status: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_status, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_details, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_code, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Status {
+            code: value_code,
+            details: value_details,
+            message: value_message,
+            metadata: value_metadata.unwrap_or_default(),
+            reason: value_reason,
+            status: value_status,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Status {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Status {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,54 @@ impl crate::DeepMerge for StatusCause {
     }
 }
 
+impl crate::Defaulting for StatusCause {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.field);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.reason);
+    }
+}
+
+impl crate::diff::Diff for StatusCause {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.field, &other.field, &crate::diff::Path::Field { parent: path, name: "field" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+    }
+}
+
+impl StatusCause {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StatusCause> {
+    /// The path of the `field` field.
+    pub fn field(self) -> crate::FieldPath<std::string::String> {
+        self.child("field")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+}
+
+impl crate::Normalize for StatusCause {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.field);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.reason);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StatusCause {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -124,6 +172,46 @@ impl crate::serde::Serialize for StatusCause {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StatusCause {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.field {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_field: Option<std::string::String> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_field, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StatusCause {
+            field: value_field,
+            message: value_message,
+            reason: value_reason,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StatusCause {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatusCause {
     fn schema_name() -> std::borrow::Cow<'static, str> {
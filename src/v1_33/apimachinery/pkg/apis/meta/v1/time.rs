@@ -10,6 +10,24 @@ impl crate::DeepMerge for Time {
     }
 }
 
+impl crate::Defaulting for Time {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.0);
+    }
+}
+
+impl crate::Normalize for Time {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for Time {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Time {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -38,7 +56,31 @@ impl From<crate::jiff::Timestamp> for Time {
 
 impl crate::serde::Serialize for Time {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: crate::serde::Serializer {
-        serializer.serialize_newtype_struct("Time", &crate::jiff::fmt::strtime::format("%Y-%m-%dT%H:%M:%SZ", self.0).map_err(crate::serde::ser::Error::custom)?)
+        #[cfg(not(feature = "full-precision-time"))]
+        let value = &crate::jiff::fmt::strtime::format("%Y-%m-%dT%H:%M:%SZ", self.0).map_err(crate::serde::ser::Error::custom)?;
+        #[cfg(feature = "full-precision-time")]
+        let value = &self.0;
+        serializer.serialize_newtype_struct("Time", value)
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Time {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::encode_timestamp(self.0.as_second(), 0, buf);
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        Ok(Time(crate::protobuf::decode_timestamp(buf)?))
+    }
+
+    fn decode_optional(buf: &[u8]) -> Result<Option<Self>, crate::protobuf::DecodeError> {
+        if buf.is_empty() {
+            Ok(None)
+        }
+        else {
+            Self::decode(buf).map(Some)
+        }
     }
 }
 
@@ -37,6 +37,86 @@ impl crate::DeepMerge for APIServiceSpec {
     }
 }
 
+impl crate::Defaulting for APIServiceSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ca_bundle);
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.group_priority_minimum);
+        crate::Defaulting::set_defaults(&mut self.insecure_skip_tls_verify);
+        crate::Defaulting::set_defaults(&mut self.service);
+        crate::Defaulting::set_defaults(&mut self.version);
+        crate::Defaulting::set_defaults(&mut self.version_priority);
+    }
+}
+
+impl crate::diff::Diff for APIServiceSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ca_bundle, &other.ca_bundle, &crate::diff::Path::Field { parent: path, name: "caBundle" }, changes);
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.group_priority_minimum, &other.group_priority_minimum, &crate::diff::Path::Field { parent: path, name: "groupPriorityMinimum" }, changes);
+        crate::diff::Diff::diff(&self.insecure_skip_tls_verify, &other.insecure_skip_tls_verify, &crate::diff::Path::Field { parent: path, name: "insecureSkipTLSVerify" }, changes);
+        crate::diff::Diff::diff(&self.service, &other.service, &crate::diff::Path::Field { parent: path, name: "service" }, changes);
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+        crate::diff::Diff::diff(&self.version_priority, &other.version_priority, &crate::diff::Path::Field { parent: path, name: "versionPriority" }, changes);
+    }
+}
+
+impl APIServiceSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIServiceSpec> {
+    /// The path of the `caBundle` field.
+    pub fn ca_bundle(self) -> crate::FieldPath<crate::ByteString> {
+        self.child("caBundle")
+    }
+
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `groupPriorityMinimum` field.
+    pub fn group_priority_minimum(self) -> crate::FieldPath<i32> {
+        self.child("groupPriorityMinimum")
+    }
+
+    /// The path of the `insecureSkipTLSVerify` field.
+    pub fn insecure_skip_tls_verify(self) -> crate::FieldPath<bool> {
+        self.child("insecureSkipTLSVerify")
+    }
+
+    /// The path of the `service` field.
+    pub fn service(self) -> crate::FieldPath<crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference> {
+        self.child("service")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+
+    /// The path of the `versionPriority` field.
+    pub fn version_priority(self) -> crate::FieldPath<i32> {
+        self.child("versionPriority")
+    }
+}
+
+impl crate::Normalize for APIServiceSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ca_bundle);
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.group_priority_minimum);
+        crate::Normalize::normalize(&mut self.insecure_skip_tls_verify);
+        crate::Normalize::normalize(&mut self.service);
+        crate::Normalize::normalize(&mut self.version);
+        crate::Normalize::normalize(&mut self.version_priority);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIServiceSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -171,6 +251,66 @@ impl crate::serde::Serialize for APIServiceSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIServiceSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.service {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.version {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.insecure_skip_tls_verify {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.ca_bundle {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.group_priority_minimum, 7, buf);
+        crate::protobuf::Field::encode_field(&self.version_priority, 8, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ca_bundle: Option<crate::ByteString> = None;
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_group_priority_minimum: Option<i32> = None;
+        let mut value_insecure_skip_tls_verify: Option<bool> = None;
+        let mut value_service: Option<crate::kube_aggregator::pkg::apis::apiregistration::v1::ServiceReference> = None;
+        let mut value_version: Option<std::string::String> = None;
+        let mut value_version_priority: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_service, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_insecure_skip_tls_verify, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_ca_bundle, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_group_priority_minimum, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_version_priority, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIServiceSpec {
+            ca_bundle: value_ca_bundle,
+            group: value_group,
+            group_priority_minimum: value_group_priority_minimum.unwrap_or_default(),
+            insecure_skip_tls_verify: value_insecure_skip_tls_verify,
+            service: value_service,
+            version: value_version,
+            version_priority: value_version_priority.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIServiceSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIServiceSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -113,6 +113,175 @@ impl crate::DeepMerge for MutatingWebhook {
     }
 }
 
+impl crate::Defaulting for MutatingWebhook {
+    fn set_defaults(&mut self) {
+        if self.failure_policy.is_none() {
+            self.failure_policy = Some("Fail".into());
+        }
+        if self.match_policy.is_none() {
+            self.match_policy = Some("Equivalent".into());
+        }
+        if self.namespace_selector.is_none() {
+            self.namespace_selector = Some(Default::default());
+        }
+        if self.object_selector.is_none() {
+            self.object_selector = Some(Default::default());
+        }
+        if self.reinvocation_policy.is_none() {
+            self.reinvocation_policy = Some("Never".into());
+        }
+        if self.timeout_seconds.is_none() {
+            self.timeout_seconds = Some(10);
+        }
+        crate::Defaulting::set_defaults(&mut self.admission_review_versions);
+        crate::Defaulting::set_defaults(&mut self.client_config);
+        crate::Defaulting::set_defaults(&mut self.failure_policy);
+        crate::Defaulting::set_defaults(&mut self.match_conditions);
+        crate::Defaulting::set_defaults(&mut self.match_policy);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.namespace_selector);
+        crate::Defaulting::set_defaults(&mut self.object_selector);
+        crate::Defaulting::set_defaults(&mut self.reinvocation_policy);
+        crate::Defaulting::set_defaults(&mut self.rules);
+        crate::Defaulting::set_defaults(&mut self.side_effects);
+        crate::Defaulting::set_defaults(&mut self.timeout_seconds);
+    }
+}
+
+impl crate::diff::Diff for MutatingWebhook {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.admission_review_versions,
+            &other.admission_review_versions,
+            &crate::diff::Path::Field { parent: path, name: "admissionReviewVersions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.client_config, &other.client_config, &crate::diff::Path::Field { parent: path, name: "clientConfig" }, changes);
+        crate::diff::Diff::diff(&self.failure_policy, &other.failure_policy, &crate::diff::Path::Field { parent: path, name: "failurePolicy" }, changes);
+        crate::diff::strategies::list::map(
+            &self.match_conditions,
+            &other.match_conditions,
+            &crate::diff::Path::Field { parent: path, name: "matchConditions" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.match_policy, &other.match_policy, &crate::diff::Path::Field { parent: path, name: "matchPolicy" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.namespace_selector, &other.namespace_selector, &crate::diff::Path::Field { parent: path, name: "namespaceSelector" }, changes);
+        crate::diff::Diff::diff(&self.object_selector, &other.object_selector, &crate::diff::Path::Field { parent: path, name: "objectSelector" }, changes);
+        crate::diff::Diff::diff(&self.reinvocation_policy, &other.reinvocation_policy, &crate::diff::Path::Field { parent: path, name: "reinvocationPolicy" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.rules,
+            &other.rules,
+            &crate::diff::Path::Field { parent: path, name: "rules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.side_effects, &other.side_effects, &crate::diff::Path::Field { parent: path, name: "sideEffects" }, changes);
+        crate::diff::Diff::diff(&self.timeout_seconds, &other.timeout_seconds, &crate::diff::Path::Field { parent: path, name: "timeoutSeconds" }, changes);
+    }
+}
+
+impl MutatingWebhook {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<MutatingWebhook> {
+    /// The path of the `admissionReviewVersions` field.
+    pub fn admission_review_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("admissionReviewVersions")
+    }
+
+    /// The path of the `clientConfig` field.
+    pub fn client_config(self) -> crate::FieldPath<crate::api::admissionregistration::v1::WebhookClientConfig> {
+        self.child("clientConfig")
+    }
+
+    /// The path of the `failurePolicy` field.
+    pub fn failure_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("failurePolicy")
+    }
+
+    /// The path of the `matchConditions` field.
+    pub fn match_conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::MatchCondition>> {
+        self.child("matchConditions")
+    }
+
+    /// The path of the `matchPolicy` field.
+    pub fn match_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("matchPolicy")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `namespaceSelector` field.
+    pub fn namespace_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("namespaceSelector")
+    }
+
+    /// The path of the `objectSelector` field.
+    pub fn object_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("objectSelector")
+    }
+
+    /// The path of the `reinvocationPolicy` field.
+    pub fn reinvocation_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("reinvocationPolicy")
+    }
+
+    /// The path of the `rules` field.
+    pub fn rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::admissionregistration::v1::RuleWithOperations>> {
+        self.child("rules")
+    }
+
+    /// The path of the `sideEffects` field.
+    pub fn side_effects(self) -> crate::FieldPath<std::string::String> {
+        self.child("sideEffects")
+    }
+
+    /// The path of the `timeoutSeconds` field.
+    pub fn timeout_seconds(self) -> crate::FieldPath<i32> {
+        self.child("timeoutSeconds")
+    }
+}
+
+impl crate::Normalize for MutatingWebhook {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.admission_review_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.client_config);
+        crate::Normalize::normalize(&mut self.failure_policy);
+        crate::normalize_strategies::list::map(&mut self.match_conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.match_policy);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.namespace_selector);
+        crate::Normalize::normalize(&mut self.object_selector);
+        crate::Normalize::normalize(&mut self.reinvocation_policy);
+        crate::normalize_strategies::list::atomic(&mut self.rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.side_effects);
+        crate::Normalize::normalize(&mut self.timeout_seconds);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MutatingWebhook {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -291,6 +460,102 @@ impl crate::serde::Serialize for MutatingWebhook {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MutatingWebhook {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.client_config, 2, buf);
+        if let Some(value) = &self.rules {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.failure_policy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.namespace_selector {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.side_effects, 6, buf);
+        if let Some(value) = &self.timeout_seconds {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.admission_review_versions, 8, buf);
+        if let Some(value) = &self.match_policy {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.reinvocation_policy {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.object_selector {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.match_conditions {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_admission_review_versions: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_client_config: Option<crate::api::admissionregistration::v1::WebhookClientConfig> = None;
+        let mut value_failure_policy: Option<std::string::String> = None;
+        let mut value_match_conditions: Option<std::vec::Vec<crate::api::admissionregistration::v1::MatchCondition>> = None;
+        let mut value_match_policy: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_object_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_reinvocation_policy: Option<std::string::String> = None;
+        let mut value_rules: Option<std::vec::Vec<crate::api::admissionregistration::v1::RuleWithOperations>> = None;
+        let mut value_side_effects: Option<std::string::String> = None;
+        let mut value_timeout_seconds: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_client_config, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_rules, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_failure_policy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_namespace_selector, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_side_effects, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_timeout_seconds, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_admission_review_versions, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_match_policy, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_reinvocation_policy, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_object_selector, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_match_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(MutatingWebhook {
+            admission_review_versions: value_admission_review_versions.unwrap_or_default(),
+            client_config: value_client_config.unwrap_or_default(),
+            failure_policy: value_failure_policy,
+            match_conditions: value_match_conditions,
+            match_policy: value_match_policy,
+            name: value_name.unwrap_or_default(),
+            namespace_selector: value_namespace_selector,
+            object_selector: value_object_selector,
+            reinvocation_policy: value_reinvocation_policy,
+            rules: value_rules,
+            side_effects: value_side_effects.unwrap_or_default(),
+            timeout_seconds: value_timeout_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for MutatingWebhook {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "matchConditions" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MutatingWebhook {
     fn schema_name() -> std::borrow::Cow<'static, str> {
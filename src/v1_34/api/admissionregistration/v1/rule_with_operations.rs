@@ -35,6 +35,113 @@ impl crate::DeepMerge for RuleWithOperations {
     }
 }
 
+impl crate::Defaulting for RuleWithOperations {
+    fn set_defaults(&mut self) {
+        if self.scope.is_none() {
+            self.scope = Some("*".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.api_groups);
+        crate::Defaulting::set_defaults(&mut self.api_versions);
+        crate::Defaulting::set_defaults(&mut self.operations);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.scope);
+    }
+}
+
+impl crate::diff::Diff for RuleWithOperations {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.api_groups,
+            &other.api_groups,
+            &crate::diff::Path::Field { parent: path, name: "apiGroups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.api_versions,
+            &other.api_versions,
+            &crate::diff::Path::Field { parent: path, name: "apiVersions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.operations,
+            &other.operations,
+            &crate::diff::Path::Field { parent: path, name: "operations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.resources,
+            &other.resources,
+            &crate::diff::Path::Field { parent: path, name: "resources" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.scope, &other.scope, &crate::diff::Path::Field { parent: path, name: "scope" }, changes);
+    }
+}
+
+impl RuleWithOperations {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<RuleWithOperations> {
+    /// The path of the `apiGroups` field.
+    pub fn api_groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("apiGroups")
+    }
+
+    /// The path of the `apiVersions` field.
+    pub fn api_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("apiVersions")
+    }
+
+    /// The path of the `operations` field.
+    pub fn operations(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("operations")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("resources")
+    }
+
+    /// The path of the `scope` field.
+    pub fn scope(self) -> crate::FieldPath<std::string::String> {
+        self.child("scope")
+    }
+}
+
+impl crate::Normalize for RuleWithOperations {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.api_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.api_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.operations, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.resources, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.scope);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for RuleWithOperations {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -154,6 +261,71 @@ impl crate::serde::Serialize for RuleWithOperations {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for RuleWithOperations {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.operations {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.api_groups {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            if let Some(value) = &self.api_versions {
+                crate::protobuf::Field::encode_field(value, 2, &mut embedded);
+            }
+            if let Some(value) = &self.resources {
+                crate::protobuf::Field::encode_field(value, 3, &mut embedded);
+            }
+            if let Some(value) = &self.scope {
+                crate::protobuf::Field::encode_field(value, 4, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(2, &embedded, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_api_versions: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_operations: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_resources: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_scope: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_operations, wire_type, &mut buf)?,
+                2 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_api_groups, wire_type, &mut embedded)?,
+                            2 => crate::protobuf::Field::merge_field(&mut value_api_versions, wire_type, &mut embedded)?,
+                            3 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut embedded)?,
+                            4 => crate::protobuf::Field::merge_field(&mut value_scope, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(RuleWithOperations {
+            api_groups: value_api_groups,
+            api_versions: value_api_versions,
+            operations: value_operations,
+            resources: value_resources,
+            scope: value_scope,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for RuleWithOperations {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for RuleWithOperations {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,66 @@ impl crate::DeepMerge for ServerStorageVersion {
     }
 }
 
+impl crate::Defaulting for ServerStorageVersion {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_server_id);
+        crate::Defaulting::set_defaults(&mut self.decodable_versions);
+        crate::Defaulting::set_defaults(&mut self.encoding_version);
+        crate::Defaulting::set_defaults(&mut self.served_versions);
+    }
+}
+
+impl crate::diff::Diff for ServerStorageVersion {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_server_id, &other.api_server_id, &crate::diff::Path::Field { parent: path, name: "apiServerID" }, changes);
+        crate::diff::strategies::list::set(&self.decodable_versions, &other.decodable_versions, &crate::diff::Path::Field { parent: path, name: "decodableVersions" }, changes);
+        crate::diff::Diff::diff(&self.encoding_version, &other.encoding_version, &crate::diff::Path::Field { parent: path, name: "encodingVersion" }, changes);
+        crate::diff::strategies::list::set(&self.served_versions, &other.served_versions, &crate::diff::Path::Field { parent: path, name: "servedVersions" }, changes);
+    }
+}
+
+impl ServerStorageVersion {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServerStorageVersion> {
+    /// The path of the `apiServerID` field.
+    pub fn api_server_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiServerID")
+    }
+
+    /// The path of the `decodableVersions` field.
+    pub fn decodable_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("decodableVersions")
+    }
+
+    /// The path of the `encodingVersion` field.
+    pub fn encoding_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("encodingVersion")
+    }
+
+    /// The path of the `servedVersions` field.
+    pub fn served_versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("servedVersions")
+    }
+}
+
+impl crate::Normalize for ServerStorageVersion {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_server_id);
+        crate::normalize_strategies::list::set(&mut self.decodable_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.encoding_version);
+        crate::normalize_strategies::list::set(&mut self.served_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServerStorageVersion {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +194,52 @@ impl crate::serde::Serialize for ServerStorageVersion {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServerStorageVersion {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.api_server_id {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.encoding_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.decodable_versions {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.served_versions {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_server_id: Option<std::string::String> = None;
+        let mut value_decodable_versions: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_encoding_version: Option<std::string::String> = None;
+        let mut value_served_versions: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_api_server_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_encoding_version, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_decodable_versions, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_served_versions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServerStorageVersion {
+            api_server_id: value_api_server_id,
+            decodable_versions: value_decodable_versions,
+            encoding_version: value_encoding_version,
+            served_versions: value_served_versions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServerStorageVersion {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServerStorageVersion {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -35,6 +35,76 @@ impl crate::DeepMerge for StorageVersionStatus {
     }
 }
 
+impl crate::Defaulting for StorageVersionStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.common_encoding_version);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.storage_versions);
+    }
+}
+
+impl crate::diff::Diff for StorageVersionStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.common_encoding_version, &other.common_encoding_version, &crate::diff::Path::Field { parent: path, name: "commonEncodingVersion" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.storage_versions,
+            &other.storage_versions,
+            &crate::diff::Path::Field { parent: path, name: "storageVersions" },
+            changes,
+            &[("apiServerID", |item| crate::diff::MergeKey::merge_key(&item.api_server_id))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl StorageVersionStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StorageVersionStatus> {
+    /// The path of the `commonEncodingVersion` field.
+    pub fn common_encoding_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("commonEncodingVersion")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::apiserverinternal::v1alpha1::StorageVersionCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `storageVersions` field.
+    pub fn storage_versions(self) -> crate::FieldPath<std::vec::Vec<crate::api::apiserverinternal::v1alpha1::ServerStorageVersion>> {
+        self.child("storageVersions")
+    }
+}
+
+impl crate::Normalize for StorageVersionStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.common_encoding_version);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.storage_versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StorageVersionStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +204,57 @@ impl crate::serde::Serialize for StorageVersionStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StorageVersionStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.storage_versions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.common_encoding_version {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_common_encoding_version: Option<std::string::String> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::apiserverinternal::v1alpha1::StorageVersionCondition>> = None;
+        let mut value_storage_versions: Option<std::vec::Vec<crate::api::apiserverinternal::v1alpha1::ServerStorageVersion>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_storage_versions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_common_encoding_version, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StorageVersionStatus {
+            common_encoding_version: value_common_encoding_version,
+            conditions: value_conditions,
+            storage_versions: value_storage_versions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StorageVersionStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                "storageVersions" => Some(&["apiServerID"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StorageVersionStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,76 @@ impl crate::DeepMerge for DaemonSetSpec {
     }
 }
 
+impl crate::Defaulting for DaemonSetSpec {
+    fn set_defaults(&mut self) {
+        if self.revision_history_limit.is_none() {
+            self.revision_history_limit = Some(10);
+        }
+        if self.update_strategy.is_none() {
+            self.update_strategy = Some(Default::default());
+        }
+        crate::Defaulting::set_defaults(&mut self.min_ready_seconds);
+        crate::Defaulting::set_defaults(&mut self.revision_history_limit);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.template);
+        crate::Defaulting::set_defaults(&mut self.update_strategy);
+    }
+}
+
+impl crate::diff::Diff for DaemonSetSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.min_ready_seconds, &other.min_ready_seconds, &crate::diff::Path::Field { parent: path, name: "minReadySeconds" }, changes);
+        crate::diff::Diff::diff(&self.revision_history_limit, &other.revision_history_limit, &crate::diff::Path::Field { parent: path, name: "revisionHistoryLimit" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.template, &other.template, &crate::diff::Path::Field { parent: path, name: "template" }, changes);
+        crate::diff::Diff::diff(&self.update_strategy, &other.update_strategy, &crate::diff::Path::Field { parent: path, name: "updateStrategy" }, changes);
+    }
+}
+
+impl DaemonSetSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DaemonSetSpec> {
+    /// The path of the `minReadySeconds` field.
+    pub fn min_ready_seconds(self) -> crate::FieldPath<i32> {
+        self.child("minReadySeconds")
+    }
+
+    /// The path of the `revisionHistoryLimit` field.
+    pub fn revision_history_limit(self) -> crate::FieldPath<i32> {
+        self.child("revisionHistoryLimit")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `template` field.
+    pub fn template(self) -> crate::FieldPath<crate::api::core::v1::PodTemplateSpec> {
+        self.child("template")
+    }
+
+    /// The path of the `updateStrategy` field.
+    pub fn update_strategy(self) -> crate::FieldPath<crate::api::apps::v1::DaemonSetUpdateStrategy> {
+        self.child("updateStrategy")
+    }
+}
+
+impl crate::Normalize for DaemonSetSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.min_ready_seconds);
+        crate::Normalize::normalize(&mut self.revision_history_limit);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.template);
+        crate::Normalize::normalize(&mut self.update_strategy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DaemonSetSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -143,6 +213,64 @@ impl crate::serde::Serialize for DaemonSetSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DaemonSetSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.selector, 1, buf);
+        crate::protobuf::Field::encode_field(&self.template, 2, buf);
+        if let Some(value) = &self.update_strategy {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.min_ready_seconds {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_min_ready_seconds: Option<i32> = None;
+        let mut value_revision_history_limit: Option<i32> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
+        let mut value_update_strategy: Option<crate::api::apps::v1::DaemonSetUpdateStrategy> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_template, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_update_strategy, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_min_ready_seconds, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_revision_history_limit, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DaemonSetSpec {
+            min_ready_seconds: value_min_ready_seconds,
+            revision_history_limit: value_revision_history_limit,
+            selector: value_selector.unwrap_or_default(),
+            template: value_template.unwrap_or_default(),
+            update_strategy: value_update_strategy,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DaemonSetSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "template" => <crate::api::core::v1::PodTemplateSpec as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
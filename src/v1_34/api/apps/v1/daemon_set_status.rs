@@ -56,6 +56,121 @@ impl crate::DeepMerge for DaemonSetStatus {
     }
 }
 
+impl crate::Defaulting for DaemonSetStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.collision_count);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.current_number_scheduled);
+        crate::Defaulting::set_defaults(&mut self.desired_number_scheduled);
+        crate::Defaulting::set_defaults(&mut self.number_available);
+        crate::Defaulting::set_defaults(&mut self.number_misscheduled);
+        crate::Defaulting::set_defaults(&mut self.number_ready);
+        crate::Defaulting::set_defaults(&mut self.number_unavailable);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+        crate::Defaulting::set_defaults(&mut self.updated_number_scheduled);
+    }
+}
+
+impl crate::diff::Diff for DaemonSetStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.collision_count, &other.collision_count, &crate::diff::Path::Field { parent: path, name: "collisionCount" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.current_number_scheduled, &other.current_number_scheduled, &crate::diff::Path::Field { parent: path, name: "currentNumberScheduled" }, changes);
+        crate::diff::Diff::diff(&self.desired_number_scheduled, &other.desired_number_scheduled, &crate::diff::Path::Field { parent: path, name: "desiredNumberScheduled" }, changes);
+        crate::diff::Diff::diff(&self.number_available, &other.number_available, &crate::diff::Path::Field { parent: path, name: "numberAvailable" }, changes);
+        crate::diff::Diff::diff(&self.number_misscheduled, &other.number_misscheduled, &crate::diff::Path::Field { parent: path, name: "numberMisscheduled" }, changes);
+        crate::diff::Diff::diff(&self.number_ready, &other.number_ready, &crate::diff::Path::Field { parent: path, name: "numberReady" }, changes);
+        crate::diff::Diff::diff(&self.number_unavailable, &other.number_unavailable, &crate::diff::Path::Field { parent: path, name: "numberUnavailable" }, changes);
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+        crate::diff::Diff::diff(&self.updated_number_scheduled, &other.updated_number_scheduled, &crate::diff::Path::Field { parent: path, name: "updatedNumberScheduled" }, changes);
+    }
+}
+
+impl DaemonSetStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DaemonSetStatus> {
+    /// The path of the `collisionCount` field.
+    pub fn collision_count(self) -> crate::FieldPath<i32> {
+        self.child("collisionCount")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::apps::v1::DaemonSetCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `currentNumberScheduled` field.
+    pub fn current_number_scheduled(self) -> crate::FieldPath<i32> {
+        self.child("currentNumberScheduled")
+    }
+
+    /// The path of the `desiredNumberScheduled` field.
+    pub fn desired_number_scheduled(self) -> crate::FieldPath<i32> {
+        self.child("desiredNumberScheduled")
+    }
+
+    /// The path of the `numberAvailable` field.
+    pub fn number_available(self) -> crate::FieldPath<i32> {
+        self.child("numberAvailable")
+    }
+
+    /// The path of the `numberMisscheduled` field.
+    pub fn number_misscheduled(self) -> crate::FieldPath<i32> {
+        self.child("numberMisscheduled")
+    }
+
+    /// The path of the `numberReady` field.
+    pub fn number_ready(self) -> crate::FieldPath<i32> {
+        self.child("numberReady")
+    }
+
+    /// The path of the `numberUnavailable` field.
+    pub fn number_unavailable(self) -> crate::FieldPath<i32> {
+        self.child("numberUnavailable")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+
+    /// The path of the `updatedNumberScheduled` field.
+    pub fn updated_number_scheduled(self) -> crate::FieldPath<i32> {
+        self.child("updatedNumberScheduled")
+    }
+}
+
+impl crate::Normalize for DaemonSetStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.collision_count);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.current_number_scheduled);
+        crate::Normalize::normalize(&mut self.desired_number_scheduled);
+        crate::Normalize::normalize(&mut self.number_available);
+        crate::Normalize::normalize(&mut self.number_misscheduled);
+        crate::Normalize::normalize(&mut self.number_ready);
+        crate::Normalize::normalize(&mut self.number_unavailable);
+        crate::Normalize::normalize(&mut self.observed_generation);
+        crate::Normalize::normalize(&mut self.updated_number_scheduled);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DaemonSetStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -214,6 +329,90 @@ impl crate::serde::Serialize for DaemonSetStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DaemonSetStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.current_number_scheduled, 1, buf);
+        crate::protobuf::Field::encode_field(&self.number_misscheduled, 2, buf);
+        crate::protobuf::Field::encode_field(&self.desired_number_scheduled, 3, buf);
+        crate::protobuf::Field::encode_field(&self.number_ready, 4, buf);
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.updated_number_scheduled {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.number_available {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.number_unavailable {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.collision_count {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_collision_count: Option<i32> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::apps::v1::DaemonSetCondition>> = None;
+        let mut value_current_number_scheduled: Option<i32> = None;
+        let mut value_desired_number_scheduled: Option<i32> = None;
+        let mut value_number_available: Option<i32> = None;
+        let mut value_number_misscheduled: Option<i32> = None;
+        let mut value_number_ready: Option<i32> = None;
+        let mut value_number_unavailable: Option<i32> = None;
+        let mut value_observed_generation: Option<i64> = None;
+        let mut value_updated_number_scheduled: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_current_number_scheduled, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_number_misscheduled, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_desired_number_scheduled, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_number_ready, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_updated_number_scheduled, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_number_available, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_number_unavailable, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_collision_count, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DaemonSetStatus {
+            collision_count: value_collision_count,
+            conditions: value_conditions,
+            current_number_scheduled: value_current_number_scheduled.unwrap_or_default(),
+            desired_number_scheduled: value_desired_number_scheduled.unwrap_or_default(),
+            number_available: value_number_available,
+            number_misscheduled: value_number_misscheduled.unwrap_or_default(),
+            number_ready: value_number_ready.unwrap_or_default(),
+            number_unavailable: value_number_unavailable,
+            observed_generation: value_observed_generation,
+            updated_number_scheduled: value_updated_number_scheduled,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DaemonSetStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
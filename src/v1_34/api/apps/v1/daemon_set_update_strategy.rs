@@ -17,6 +17,50 @@ impl crate::DeepMerge for DaemonSetUpdateStrategy {
     }
 }
 
+impl crate::Defaulting for DaemonSetUpdateStrategy {
+    fn set_defaults(&mut self) {
+        if self.type_.is_none() {
+            self.type_ = Some("RollingUpdate".into());
+        }
+        crate::defaulting_strategies::apps_v1::daemon_set_update_strategy(self);
+        crate::Defaulting::set_defaults(&mut self.rolling_update);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for DaemonSetUpdateStrategy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.rolling_update, &other.rolling_update, &crate::diff::Path::Field { parent: path, name: "rollingUpdate" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl DaemonSetUpdateStrategy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DaemonSetUpdateStrategy> {
+    /// The path of the `rollingUpdate` field.
+    pub fn rolling_update(self) -> crate::FieldPath<crate::api::apps::v1::RollingUpdateDaemonSet> {
+        self.child("rollingUpdate")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for DaemonSetUpdateStrategy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.rolling_update);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DaemonSetUpdateStrategy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +150,40 @@ impl crate::serde::Serialize for DaemonSetUpdateStrategy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DaemonSetUpdateStrategy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.rolling_update {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_rolling_update: Option<crate::api::apps::v1::RollingUpdateDaemonSet> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_rolling_update, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DaemonSetUpdateStrategy {
+            rolling_update: value_rolling_update,
+            type_: value_type_,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DaemonSetUpdateStrategy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonSetUpdateStrategy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
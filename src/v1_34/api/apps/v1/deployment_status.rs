@@ -54,6 +54,113 @@ impl crate::DeepMerge for DeploymentStatus {
     }
 }
 
+impl crate::Defaulting for DeploymentStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.available_replicas);
+        crate::Defaulting::set_defaults(&mut self.collision_count);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+        crate::Defaulting::set_defaults(&mut self.ready_replicas);
+        crate::Defaulting::set_defaults(&mut self.replicas);
+        crate::Defaulting::set_defaults(&mut self.terminating_replicas);
+        crate::Defaulting::set_defaults(&mut self.unavailable_replicas);
+        crate::Defaulting::set_defaults(&mut self.updated_replicas);
+    }
+}
+
+impl crate::diff::Diff for DeploymentStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.available_replicas, &other.available_replicas, &crate::diff::Path::Field { parent: path, name: "availableReplicas" }, changes);
+        crate::diff::Diff::diff(&self.collision_count, &other.collision_count, &crate::diff::Path::Field { parent: path, name: "collisionCount" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+        crate::diff::Diff::diff(&self.ready_replicas, &other.ready_replicas, &crate::diff::Path::Field { parent: path, name: "readyReplicas" }, changes);
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+        crate::diff::Diff::diff(&self.terminating_replicas, &other.terminating_replicas, &crate::diff::Path::Field { parent: path, name: "terminatingReplicas" }, changes);
+        crate::diff::Diff::diff(&self.unavailable_replicas, &other.unavailable_replicas, &crate::diff::Path::Field { parent: path, name: "unavailableReplicas" }, changes);
+        crate::diff::Diff::diff(&self.updated_replicas, &other.updated_replicas, &crate::diff::Path::Field { parent: path, name: "updatedReplicas" }, changes);
+    }
+}
+
+impl DeploymentStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeploymentStatus> {
+    /// The path of the `availableReplicas` field.
+    pub fn available_replicas(self) -> crate::FieldPath<i32> {
+        self.child("availableReplicas")
+    }
+
+    /// The path of the `collisionCount` field.
+    pub fn collision_count(self) -> crate::FieldPath<i32> {
+        self.child("collisionCount")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::apps::v1::DeploymentCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+
+    /// The path of the `readyReplicas` field.
+    pub fn ready_replicas(self) -> crate::FieldPath<i32> {
+        self.child("readyReplicas")
+    }
+
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+
+    /// The path of the `terminatingReplicas` field.
+    pub fn terminating_replicas(self) -> crate::FieldPath<i32> {
+        self.child("terminatingReplicas")
+    }
+
+    /// The path of the `unavailableReplicas` field.
+    pub fn unavailable_replicas(self) -> crate::FieldPath<i32> {
+        self.child("unavailableReplicas")
+    }
+
+    /// The path of the `updatedReplicas` field.
+    pub fn updated_replicas(self) -> crate::FieldPath<i32> {
+        self.child("updatedReplicas")
+    }
+}
+
+impl crate::Normalize for DeploymentStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.available_replicas);
+        crate::Normalize::normalize(&mut self.collision_count);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.observed_generation);
+        crate::Normalize::normalize(&mut self.ready_replicas);
+        crate::Normalize::normalize(&mut self.replicas);
+        crate::Normalize::normalize(&mut self.terminating_replicas);
+        crate::Normalize::normalize(&mut self.unavailable_replicas);
+        crate::Normalize::normalize(&mut self.updated_replicas);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeploymentStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -213,6 +320,92 @@ impl crate::serde::Serialize for DeploymentStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeploymentStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.replicas {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.updated_replicas {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.available_replicas {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.unavailable_replicas {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.ready_replicas {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.collision_count {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.terminating_replicas {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_available_replicas: Option<i32> = None;
+        let mut value_collision_count: Option<i32> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::apps::v1::DeploymentCondition>> = None;
+        let mut value_observed_generation: Option<i64> = None;
+        let mut value_ready_replicas: Option<i32> = None;
+        let mut value_replicas: Option<i32> = None;
+        let mut value_terminating_replicas: Option<i32> = None;
+        let mut value_unavailable_replicas: Option<i32> = None;
+        let mut value_updated_replicas: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_updated_replicas, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_available_replicas, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_unavailable_replicas, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_ready_replicas, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_collision_count, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_terminating_replicas, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeploymentStatus {
+            available_replicas: value_available_replicas,
+            collision_count: value_collision_count,
+            conditions: value_conditions,
+            observed_generation: value_observed_generation,
+            ready_replicas: value_ready_replicas,
+            replicas: value_replicas,
+            terminating_replicas: value_terminating_replicas,
+            unavailable_replicas: value_unavailable_replicas,
+            updated_replicas: value_updated_replicas,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeploymentStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -53,6 +53,143 @@ impl crate::DeepMerge for StatefulSetSpec {
     }
 }
 
+impl crate::Defaulting for StatefulSetSpec {
+    fn set_defaults(&mut self) {
+        if self.persistent_volume_claim_retention_policy.is_none() {
+            self.persistent_volume_claim_retention_policy = Some(Default::default());
+        }
+        if self.pod_management_policy.is_none() {
+            self.pod_management_policy = Some("OrderedReady".into());
+        }
+        if self.replicas.is_none() {
+            self.replicas = Some(1);
+        }
+        if self.revision_history_limit.is_none() {
+            self.revision_history_limit = Some(10);
+        }
+        if self.update_strategy.is_none() {
+            self.update_strategy = Some(Default::default());
+        }
+        crate::Defaulting::set_defaults(&mut self.min_ready_seconds);
+        crate::Defaulting::set_defaults(&mut self.ordinals);
+        crate::Defaulting::set_defaults(&mut self.persistent_volume_claim_retention_policy);
+        crate::Defaulting::set_defaults(&mut self.pod_management_policy);
+        crate::Defaulting::set_defaults(&mut self.replicas);
+        crate::Defaulting::set_defaults(&mut self.revision_history_limit);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.service_name);
+        crate::Defaulting::set_defaults(&mut self.template);
+        crate::Defaulting::set_defaults(&mut self.update_strategy);
+        crate::Defaulting::set_defaults(&mut self.volume_claim_templates);
+    }
+}
+
+impl crate::diff::Diff for StatefulSetSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.min_ready_seconds, &other.min_ready_seconds, &crate::diff::Path::Field { parent: path, name: "minReadySeconds" }, changes);
+        crate::diff::Diff::diff(&self.ordinals, &other.ordinals, &crate::diff::Path::Field { parent: path, name: "ordinals" }, changes);
+        crate::diff::Diff::diff(&self.persistent_volume_claim_retention_policy, &other.persistent_volume_claim_retention_policy, &crate::diff::Path::Field { parent: path, name: "persistentVolumeClaimRetentionPolicy" }, changes);
+        crate::diff::Diff::diff(&self.pod_management_policy, &other.pod_management_policy, &crate::diff::Path::Field { parent: path, name: "podManagementPolicy" }, changes);
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+        crate::diff::Diff::diff(&self.revision_history_limit, &other.revision_history_limit, &crate::diff::Path::Field { parent: path, name: "revisionHistoryLimit" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.service_name, &other.service_name, &crate::diff::Path::Field { parent: path, name: "serviceName" }, changes);
+        crate::diff::Diff::diff(&self.template, &other.template, &crate::diff::Path::Field { parent: path, name: "template" }, changes);
+        crate::diff::Diff::diff(&self.update_strategy, &other.update_strategy, &crate::diff::Path::Field { parent: path, name: "updateStrategy" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.volume_claim_templates,
+            &other.volume_claim_templates,
+            &crate::diff::Path::Field { parent: path, name: "volumeClaimTemplates" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl StatefulSetSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<StatefulSetSpec> {
+    /// The path of the `minReadySeconds` field.
+    pub fn min_ready_seconds(self) -> crate::FieldPath<i32> {
+        self.child("minReadySeconds")
+    }
+
+    /// The path of the `ordinals` field.
+    pub fn ordinals(self) -> crate::FieldPath<crate::api::apps::v1::StatefulSetOrdinals> {
+        self.child("ordinals")
+    }
+
+    /// The path of the `persistentVolumeClaimRetentionPolicy` field.
+    pub fn persistent_volume_claim_retention_policy(self) -> crate::FieldPath<crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy> {
+        self.child("persistentVolumeClaimRetentionPolicy")
+    }
+
+    /// The path of the `podManagementPolicy` field.
+    pub fn pod_management_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("podManagementPolicy")
+    }
+
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+
+    /// The path of the `revisionHistoryLimit` field.
+    pub fn revision_history_limit(self) -> crate::FieldPath<i32> {
+        self.child("revisionHistoryLimit")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `serviceName` field.
+    pub fn service_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("serviceName")
+    }
+
+    /// The path of the `template` field.
+    pub fn template(self) -> crate::FieldPath<crate::api::core::v1::PodTemplateSpec> {
+        self.child("template")
+    }
+
+    /// The path of the `updateStrategy` field.
+    pub fn update_strategy(self) -> crate::FieldPath<crate::api::apps::v1::StatefulSetUpdateStrategy> {
+        self.child("updateStrategy")
+    }
+
+    /// The path of the `volumeClaimTemplates` field.
+    pub fn volume_claim_templates(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PersistentVolumeClaim>> {
+        self.child("volumeClaimTemplates")
+    }
+}
+
+impl crate::Normalize for StatefulSetSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.min_ready_seconds);
+        crate::Normalize::normalize(&mut self.ordinals);
+        crate::Normalize::normalize(&mut self.persistent_volume_claim_retention_policy);
+        crate::Normalize::normalize(&mut self.pod_management_policy);
+        crate::Normalize::normalize(&mut self.replicas);
+        crate::Normalize::normalize(&mut self.revision_history_limit);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.service_name);
+        crate::Normalize::normalize(&mut self.template);
+        crate::Normalize::normalize(&mut self.update_strategy);
+        crate::normalize_strategies::list::atomic(&mut self.volume_claim_templates, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for StatefulSetSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -227,6 +364,101 @@ impl crate::serde::Serialize for StatefulSetSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for StatefulSetSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.replicas {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.selector, 2, buf);
+        crate::protobuf::Field::encode_field(&self.template, 3, buf);
+        if let Some(value) = &self.volume_claim_templates {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.service_name {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.pod_management_policy {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.update_strategy {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.min_ready_seconds {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.persistent_volume_claim_retention_policy {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.ordinals {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_min_ready_seconds: Option<i32> = None;
+        let mut value_ordinals: Option<crate::api::apps::v1::StatefulSetOrdinals> = None;
+        let mut value_persistent_volume_claim_retention_policy: Option<crate::api::apps::v1::StatefulSetPersistentVolumeClaimRetentionPolicy> = None;
+        let mut value_pod_management_policy: Option<std::string::String> = None;
+        let mut value_replicas: Option<i32> = None;
+        let mut value_revision_history_limit: Option<i32> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_service_name: Option<std::string::String> = None;
+        let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
+        let mut value_update_strategy: Option<crate::api::apps::v1::StatefulSetUpdateStrategy> = None;
+        let mut value_volume_claim_templates: Option<std::vec::Vec<crate::api::core::v1::PersistentVolumeClaim>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_template, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_volume_claim_templates, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_service_name, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_pod_management_policy, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_update_strategy, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_revision_history_limit, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_min_ready_seconds, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_persistent_volume_claim_retention_policy, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_ordinals, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(StatefulSetSpec {
+            min_ready_seconds: value_min_ready_seconds,
+            ordinals: value_ordinals,
+            persistent_volume_claim_retention_policy: value_persistent_volume_claim_retention_policy,
+            pod_management_policy: value_pod_management_policy,
+            replicas: value_replicas,
+            revision_history_limit: value_revision_history_limit,
+            selector: value_selector.unwrap_or_default(),
+            service_name: value_service_name,
+            template: value_template.unwrap_or_default(),
+            update_strategy: value_update_strategy,
+            volume_claim_templates: value_volume_claim_templates,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for StatefulSetSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "template" => <crate::api::core::v1::PodTemplateSpec as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "volumeClaimTemplates" => <Option<std::vec::Vec<crate::api::core::v1::PersistentVolumeClaim>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for StatefulSetSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,72 @@ impl crate::DeepMerge for TokenReviewStatus {
     }
 }
 
+impl crate::Defaulting for TokenReviewStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.audiences);
+        crate::Defaulting::set_defaults(&mut self.authenticated);
+        crate::Defaulting::set_defaults(&mut self.error);
+        crate::Defaulting::set_defaults(&mut self.user);
+    }
+}
+
+impl crate::diff::Diff for TokenReviewStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.audiences,
+            &other.audiences,
+            &crate::diff::Path::Field { parent: path, name: "audiences" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.authenticated, &other.authenticated, &crate::diff::Path::Field { parent: path, name: "authenticated" }, changes);
+        crate::diff::Diff::diff(&self.error, &other.error, &crate::diff::Path::Field { parent: path, name: "error" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+    }
+}
+
+impl TokenReviewStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TokenReviewStatus> {
+    /// The path of the `audiences` field.
+    pub fn audiences(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("audiences")
+    }
+
+    /// The path of the `authenticated` field.
+    pub fn authenticated(self) -> crate::FieldPath<bool> {
+        self.child("authenticated")
+    }
+
+    /// The path of the `error` field.
+    pub fn error(self) -> crate::FieldPath<std::string::String> {
+        self.child("error")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<crate::api::authentication::v1::UserInfo> {
+        self.child("user")
+    }
+}
+
+impl crate::Normalize for TokenReviewStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.audiences, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.authenticated);
+        crate::Normalize::normalize(&mut self.error);
+        crate::Normalize::normalize(&mut self.user);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TokenReviewStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +200,52 @@ impl crate::serde::Serialize for TokenReviewStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TokenReviewStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.authenticated {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.error {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.audiences {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audiences: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_authenticated: Option<bool> = None;
+        let mut value_error: Option<std::string::String> = None;
+        let mut value_user: Option<crate::api::authentication::v1::UserInfo> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_authenticated, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_error, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_audiences, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TokenReviewStatus {
+            audiences: value_audiences,
+            authenticated: value_authenticated,
+            error: value_error,
+            user: value_user,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TokenReviewStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TokenReviewStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,92 @@ impl crate::DeepMerge for UserInfo {
     }
 }
 
+impl crate::Defaulting for UserInfo {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.extra);
+        crate::Defaulting::set_defaults(&mut self.groups);
+        crate::Defaulting::set_defaults(&mut self.uid);
+        crate::Defaulting::set_defaults(&mut self.username);
+    }
+}
+
+impl crate::diff::Diff for UserInfo {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.extra,
+            &other.extra,
+            &crate::diff::Path::Field { parent: path, name: "extra" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::strategies::list::atomic(
+                    old_value,
+                    new_value,
+                    path,
+                    changes,
+                    |old_item, new_item, path, changes| {
+                        crate::diff::Diff::diff(old_item, new_item, path, changes);
+                    },
+                );
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.groups,
+            &other.groups,
+            &crate::diff::Path::Field { parent: path, name: "groups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+        crate::diff::Diff::diff(&self.username, &other.username, &crate::diff::Path::Field { parent: path, name: "username" }, changes);
+    }
+}
+
+impl UserInfo {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<UserInfo> {
+    /// The path of the `extra` field.
+    pub fn extra(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> {
+        self.child("extra")
+    }
+
+    /// The path of the `groups` field.
+    pub fn groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("groups")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+
+    /// The path of the `username` field.
+    pub fn username(self) -> crate::FieldPath<std::string::String> {
+        self.child("username")
+    }
+}
+
+impl crate::Normalize for UserInfo {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.extra, |value| {
+            crate::normalize_strategies::list::atomic(value, |item| {
+                crate::Normalize::normalize(item);
+            });
+        });
+        crate::normalize_strategies::list::atomic(&mut self.groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.uid);
+        crate::Normalize::normalize(&mut self.username);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for UserInfo {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -136,6 +222,52 @@ impl crate::serde::Serialize for UserInfo {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for UserInfo {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.username {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.groups {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.extra {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_extra: Option<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> = None;
+        let mut value_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_uid: Option<std::string::String> = None;
+        let mut value_username: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_username, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_groups, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_extra, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(UserInfo {
+            extra: value_extra,
+            groups: value_groups,
+            uid: value_uid,
+            username: value_username,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for UserInfo {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for UserInfo {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for SelfSubjectRulesReviewSpec {
     }
 }
 
+impl crate::Defaulting for SelfSubjectRulesReviewSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.namespace);
+    }
+}
+
+impl crate::diff::Diff for SelfSubjectRulesReviewSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.namespace, &other.namespace, &crate::diff::Path::Field { parent: path, name: "namespace" }, changes);
+    }
+}
+
+impl SelfSubjectRulesReviewSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SelfSubjectRulesReviewSpec> {
+    /// The path of the `namespace` field.
+    pub fn namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("namespace")
+    }
+}
+
+impl crate::Normalize for SelfSubjectRulesReviewSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.namespace);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SelfSubjectRulesReviewSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for SelfSubjectRulesReviewSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SelfSubjectRulesReviewSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.namespace {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_namespace: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_namespace, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SelfSubjectRulesReviewSpec {
+            namespace: value_namespace,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SelfSubjectRulesReviewSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelfSubjectRulesReviewSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
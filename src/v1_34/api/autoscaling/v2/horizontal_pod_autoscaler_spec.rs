@@ -29,6 +29,84 @@ impl crate::DeepMerge for HorizontalPodAutoscalerSpec {
     }
 }
 
+impl crate::Defaulting for HorizontalPodAutoscalerSpec {
+    fn set_defaults(&mut self) {
+        if self.min_replicas.is_none() {
+            self.min_replicas = Some(1);
+        }
+        crate::defaulting_strategies::autoscaling_v2::horizontal_pod_autoscaler_spec(self);
+        crate::Defaulting::set_defaults(&mut self.behavior);
+        crate::Defaulting::set_defaults(&mut self.max_replicas);
+        crate::Defaulting::set_defaults(&mut self.metrics);
+        crate::Defaulting::set_defaults(&mut self.min_replicas);
+        crate::Defaulting::set_defaults(&mut self.scale_target_ref);
+    }
+}
+
+impl crate::diff::Diff for HorizontalPodAutoscalerSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.behavior, &other.behavior, &crate::diff::Path::Field { parent: path, name: "behavior" }, changes);
+        crate::diff::Diff::diff(&self.max_replicas, &other.max_replicas, &crate::diff::Path::Field { parent: path, name: "maxReplicas" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.metrics,
+            &other.metrics,
+            &crate::diff::Path::Field { parent: path, name: "metrics" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.min_replicas, &other.min_replicas, &crate::diff::Path::Field { parent: path, name: "minReplicas" }, changes);
+        crate::diff::Diff::diff(&self.scale_target_ref, &other.scale_target_ref, &crate::diff::Path::Field { parent: path, name: "scaleTargetRef" }, changes);
+    }
+}
+
+impl HorizontalPodAutoscalerSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HorizontalPodAutoscalerSpec> {
+    /// The path of the `behavior` field.
+    pub fn behavior(self) -> crate::FieldPath<crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior> {
+        self.child("behavior")
+    }
+
+    /// The path of the `maxReplicas` field.
+    pub fn max_replicas(self) -> crate::FieldPath<i32> {
+        self.child("maxReplicas")
+    }
+
+    /// The path of the `metrics` field.
+    pub fn metrics(self) -> crate::FieldPath<std::vec::Vec<crate::api::autoscaling::v2::MetricSpec>> {
+        self.child("metrics")
+    }
+
+    /// The path of the `minReplicas` field.
+    pub fn min_replicas(self) -> crate::FieldPath<i32> {
+        self.child("minReplicas")
+    }
+
+    /// The path of the `scaleTargetRef` field.
+    pub fn scale_target_ref(self) -> crate::FieldPath<crate::api::autoscaling::v2::CrossVersionObjectReference> {
+        self.child("scaleTargetRef")
+    }
+}
+
+impl crate::Normalize for HorizontalPodAutoscalerSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.behavior);
+        crate::Normalize::normalize(&mut self.max_replicas);
+        crate::normalize_strategies::list::atomic(&mut self.metrics, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.min_replicas);
+        crate::Normalize::normalize(&mut self.scale_target_ref);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -143,6 +221,54 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HorizontalPodAutoscalerSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.scale_target_ref, 1, buf);
+        if let Some(value) = &self.min_replicas {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.max_replicas, 3, buf);
+        if let Some(value) = &self.metrics {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.behavior {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_behavior: Option<crate::api::autoscaling::v2::HorizontalPodAutoscalerBehavior> = None;
+        let mut value_max_replicas: Option<i32> = None;
+        let mut value_metrics: Option<std::vec::Vec<crate::api::autoscaling::v2::MetricSpec>> = None;
+        let mut value_min_replicas: Option<i32> = None;
+        let mut value_scale_target_ref: Option<crate::api::autoscaling::v2::CrossVersionObjectReference> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_scale_target_ref, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_min_replicas, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_max_replicas, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_metrics, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_behavior, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HorizontalPodAutoscalerSpec {
+            behavior: value_behavior,
+            max_replicas: value_max_replicas.unwrap_or_default(),
+            metrics: value_metrics,
+            min_replicas: value_min_replicas,
+            scale_target_ref: value_scale_target_ref.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HorizontalPodAutoscalerSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
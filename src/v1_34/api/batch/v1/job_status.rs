@@ -75,6 +75,129 @@ impl crate::DeepMerge for JobStatus {
     }
 }
 
+impl crate::Defaulting for JobStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.active);
+        crate::Defaulting::set_defaults(&mut self.completed_indexes);
+        crate::Defaulting::set_defaults(&mut self.completion_time);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.failed);
+        crate::Defaulting::set_defaults(&mut self.failed_indexes);
+        crate::Defaulting::set_defaults(&mut self.ready);
+        crate::Defaulting::set_defaults(&mut self.start_time);
+        crate::Defaulting::set_defaults(&mut self.succeeded);
+        crate::Defaulting::set_defaults(&mut self.terminating);
+        crate::Defaulting::set_defaults(&mut self.uncounted_terminated_pods);
+    }
+}
+
+impl crate::diff::Diff for JobStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.active, &other.active, &crate::diff::Path::Field { parent: path, name: "active" }, changes);
+        crate::diff::Diff::diff(&self.completed_indexes, &other.completed_indexes, &crate::diff::Path::Field { parent: path, name: "completedIndexes" }, changes);
+        crate::diff::Diff::diff(&self.completion_time, &other.completion_time, &crate::diff::Path::Field { parent: path, name: "completionTime" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.failed, &other.failed, &crate::diff::Path::Field { parent: path, name: "failed" }, changes);
+        crate::diff::Diff::diff(&self.failed_indexes, &other.failed_indexes, &crate::diff::Path::Field { parent: path, name: "failedIndexes" }, changes);
+        crate::diff::Diff::diff(&self.ready, &other.ready, &crate::diff::Path::Field { parent: path, name: "ready" }, changes);
+        crate::diff::Diff::diff(&self.start_time, &other.start_time, &crate::diff::Path::Field { parent: path, name: "startTime" }, changes);
+        crate::diff::Diff::diff(&self.succeeded, &other.succeeded, &crate::diff::Path::Field { parent: path, name: "succeeded" }, changes);
+        crate::diff::Diff::diff(&self.terminating, &other.terminating, &crate::diff::Path::Field { parent: path, name: "terminating" }, changes);
+        crate::diff::Diff::diff(&self.uncounted_terminated_pods, &other.uncounted_terminated_pods, &crate::diff::Path::Field { parent: path, name: "uncountedTerminatedPods" }, changes);
+    }
+}
+
+impl JobStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<JobStatus> {
+    /// The path of the `active` field.
+    pub fn active(self) -> crate::FieldPath<i32> {
+        self.child("active")
+    }
+
+    /// The path of the `completedIndexes` field.
+    pub fn completed_indexes(self) -> crate::FieldPath<std::string::String> {
+        self.child("completedIndexes")
+    }
+
+    /// The path of the `completionTime` field.
+    pub fn completion_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("completionTime")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::batch::v1::JobCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `failed` field.
+    pub fn failed(self) -> crate::FieldPath<i32> {
+        self.child("failed")
+    }
+
+    /// The path of the `failedIndexes` field.
+    pub fn failed_indexes(self) -> crate::FieldPath<std::string::String> {
+        self.child("failedIndexes")
+    }
+
+    /// The path of the `ready` field.
+    pub fn ready(self) -> crate::FieldPath<i32> {
+        self.child("ready")
+    }
+
+    /// The path of the `startTime` field.
+    pub fn start_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("startTime")
+    }
+
+    /// The path of the `succeeded` field.
+    pub fn succeeded(self) -> crate::FieldPath<i32> {
+        self.child("succeeded")
+    }
+
+    /// The path of the `terminating` field.
+    pub fn terminating(self) -> crate::FieldPath<i32> {
+        self.child("terminating")
+    }
+
+    /// The path of the `uncountedTerminatedPods` field.
+    pub fn uncounted_terminated_pods(self) -> crate::FieldPath<crate::api::batch::v1::UncountedTerminatedPods> {
+        self.child("uncountedTerminatedPods")
+    }
+}
+
+impl crate::Normalize for JobStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.active);
+        crate::Normalize::normalize(&mut self.completed_indexes);
+        crate::Normalize::normalize(&mut self.completion_time);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.failed);
+        crate::Normalize::normalize(&mut self.failed_indexes);
+        crate::Normalize::normalize(&mut self.ready);
+        crate::Normalize::normalize(&mut self.start_time);
+        crate::Normalize::normalize(&mut self.succeeded);
+        crate::Normalize::normalize(&mut self.terminating);
+        crate::Normalize::normalize(&mut self.uncounted_terminated_pods);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JobStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -254,6 +377,104 @@ impl crate::serde::Serialize for JobStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JobStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.start_time {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.completion_time {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.active {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.succeeded {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.failed {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.completed_indexes {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.uncounted_terminated_pods {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.ready {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.failed_indexes {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.terminating {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_active: Option<i32> = None;
+        let mut value_completed_indexes: Option<std::string::String> = None;
+        let mut value_completion_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::batch::v1::JobCondition>> = None;
+        let mut value_failed: Option<i32> = None;
+        let mut value_failed_indexes: Option<std::string::String> = None;
+        let mut value_ready: Option<i32> = None;
+        let mut value_start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_succeeded: Option<i32> = None;
+        let mut value_terminating: Option<i32> = None;
+        let mut value_uncounted_terminated_pods: Option<crate::api::batch::v1::UncountedTerminatedPods> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_start_time, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_completion_time, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_active, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_succeeded, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_failed, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_completed_indexes, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_uncounted_terminated_pods, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_ready, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_failed_indexes, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_terminating, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(JobStatus {
+            active: value_active,
+            completed_indexes: value_completed_indexes,
+            completion_time: value_completion_time,
+            conditions: value_conditions,
+            failed: value_failed,
+            failed_indexes: value_failed_indexes,
+            ready: value_ready,
+            start_time: value_start_time,
+            succeeded: value_succeeded,
+            terminating: value_terminating,
+            uncounted_terminated_pods: value_uncounted_terminated_pods,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for JobStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JobStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -31,6 +31,64 @@ impl crate::DeepMerge for PodFailurePolicyRule {
     }
 }
 
+impl crate::Defaulting for PodFailurePolicyRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.action);
+        crate::Defaulting::set_defaults(&mut self.on_exit_codes);
+        crate::Defaulting::set_defaults(&mut self.on_pod_conditions);
+    }
+}
+
+impl crate::diff::Diff for PodFailurePolicyRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.action, &other.action, &crate::diff::Path::Field { parent: path, name: "action" }, changes);
+        crate::diff::Diff::diff(&self.on_exit_codes, &other.on_exit_codes, &crate::diff::Path::Field { parent: path, name: "onExitCodes" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.on_pod_conditions,
+            &other.on_pod_conditions,
+            &crate::diff::Path::Field { parent: path, name: "onPodConditions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodFailurePolicyRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodFailurePolicyRule> {
+    /// The path of the `action` field.
+    pub fn action(self) -> crate::FieldPath<std::string::String> {
+        self.child("action")
+    }
+
+    /// The path of the `onExitCodes` field.
+    pub fn on_exit_codes(self) -> crate::FieldPath<crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement> {
+        self.child("onExitCodes")
+    }
+
+    /// The path of the `onPodConditions` field.
+    pub fn on_pod_conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern>> {
+        self.child("onPodConditions")
+    }
+}
+
+impl crate::Normalize for PodFailurePolicyRule {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.action);
+        crate::Normalize::normalize(&mut self.on_exit_codes);
+        crate::normalize_strategies::list::atomic(&mut self.on_pod_conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodFailurePolicyRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -128,6 +186,44 @@ impl crate::serde::Serialize for PodFailurePolicyRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodFailurePolicyRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.action, 1, buf);
+        if let Some(value) = &self.on_exit_codes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.on_pod_conditions {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_action: Option<std::string::String> = None;
+        let mut value_on_exit_codes: Option<crate::api::batch::v1::PodFailurePolicyOnExitCodesRequirement> = None;
+        let mut value_on_pod_conditions: Option<std::vec::Vec<crate::api::batch::v1::PodFailurePolicyOnPodConditionsPattern>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_action, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_on_exit_codes, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_on_pod_conditions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodFailurePolicyRule {
+            action: value_action.unwrap_or_default(),
+            on_exit_codes: value_on_exit_codes,
+            on_pod_conditions: value_on_pod_conditions,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodFailurePolicyRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodFailurePolicyRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,50 @@ impl crate::DeepMerge for UncountedTerminatedPods {
     }
 }
 
+impl crate::Defaulting for UncountedTerminatedPods {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.failed);
+        crate::Defaulting::set_defaults(&mut self.succeeded);
+    }
+}
+
+impl crate::diff::Diff for UncountedTerminatedPods {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::set(&self.failed, &other.failed, &crate::diff::Path::Field { parent: path, name: "failed" }, changes);
+        crate::diff::strategies::list::set(&self.succeeded, &other.succeeded, &crate::diff::Path::Field { parent: path, name: "succeeded" }, changes);
+    }
+}
+
+impl UncountedTerminatedPods {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<UncountedTerminatedPods> {
+    /// The path of the `failed` field.
+    pub fn failed(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("failed")
+    }
+
+    /// The path of the `succeeded` field.
+    pub fn succeeded(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("succeeded")
+    }
+}
+
+impl crate::Normalize for UncountedTerminatedPods {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::set(&mut self.failed, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::set(&mut self.succeeded, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for UncountedTerminatedPods {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +150,40 @@ impl crate::serde::Serialize for UncountedTerminatedPods {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for UncountedTerminatedPods {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.succeeded {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.failed {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_failed: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_succeeded: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_succeeded, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_failed, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(UncountedTerminatedPods {
+            failed: value_failed,
+            succeeded: value_succeeded,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for UncountedTerminatedPods {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for UncountedTerminatedPods {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,46 @@ impl crate::DeepMerge for ClusterTrustBundleSpec {
     }
 }
 
+impl crate::Defaulting for ClusterTrustBundleSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.signer_name);
+        crate::Defaulting::set_defaults(&mut self.trust_bundle);
+    }
+}
+
+impl crate::diff::Diff for ClusterTrustBundleSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.signer_name, &other.signer_name, &crate::diff::Path::Field { parent: path, name: "signerName" }, changes);
+        crate::diff::Diff::diff(&self.trust_bundle, &other.trust_bundle, &crate::diff::Path::Field { parent: path, name: "trustBundle" }, changes);
+    }
+}
+
+impl ClusterTrustBundleSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ClusterTrustBundleSpec> {
+    /// The path of the `signerName` field.
+    pub fn signer_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("signerName")
+    }
+
+    /// The path of the `trustBundle` field.
+    pub fn trust_bundle(self) -> crate::FieldPath<std::string::String> {
+        self.child("trustBundle")
+    }
+}
+
+impl crate::Normalize for ClusterTrustBundleSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.signer_name);
+        crate::Normalize::normalize(&mut self.trust_bundle);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ClusterTrustBundleSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -116,6 +156,38 @@ impl crate::serde::Serialize for ClusterTrustBundleSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ClusterTrustBundleSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.signer_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.trust_bundle, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_signer_name: Option<std::string::String> = None;
+        let mut value_trust_bundle: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_signer_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_trust_bundle, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ClusterTrustBundleSpec {
+            signer_name: value_signer_name,
+            trust_bundle: value_trust_bundle.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ClusterTrustBundleSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ClusterTrustBundleSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
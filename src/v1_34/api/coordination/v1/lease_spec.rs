@@ -37,6 +37,86 @@ impl crate::DeepMerge for LeaseSpec {
     }
 }
 
+impl crate::Defaulting for LeaseSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.acquire_time);
+        crate::Defaulting::set_defaults(&mut self.holder_identity);
+        crate::Defaulting::set_defaults(&mut self.lease_duration_seconds);
+        crate::Defaulting::set_defaults(&mut self.lease_transitions);
+        crate::Defaulting::set_defaults(&mut self.preferred_holder);
+        crate::Defaulting::set_defaults(&mut self.renew_time);
+        crate::Defaulting::set_defaults(&mut self.strategy);
+    }
+}
+
+impl crate::diff::Diff for LeaseSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.acquire_time, &other.acquire_time, &crate::diff::Path::Field { parent: path, name: "acquireTime" }, changes);
+        crate::diff::Diff::diff(&self.holder_identity, &other.holder_identity, &crate::diff::Path::Field { parent: path, name: "holderIdentity" }, changes);
+        crate::diff::Diff::diff(&self.lease_duration_seconds, &other.lease_duration_seconds, &crate::diff::Path::Field { parent: path, name: "leaseDurationSeconds" }, changes);
+        crate::diff::Diff::diff(&self.lease_transitions, &other.lease_transitions, &crate::diff::Path::Field { parent: path, name: "leaseTransitions" }, changes);
+        crate::diff::Diff::diff(&self.preferred_holder, &other.preferred_holder, &crate::diff::Path::Field { parent: path, name: "preferredHolder" }, changes);
+        crate::diff::Diff::diff(&self.renew_time, &other.renew_time, &crate::diff::Path::Field { parent: path, name: "renewTime" }, changes);
+        crate::diff::Diff::diff(&self.strategy, &other.strategy, &crate::diff::Path::Field { parent: path, name: "strategy" }, changes);
+    }
+}
+
+impl LeaseSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LeaseSpec> {
+    /// The path of the `acquireTime` field.
+    pub fn acquire_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("acquireTime")
+    }
+
+    /// The path of the `holderIdentity` field.
+    pub fn holder_identity(self) -> crate::FieldPath<std::string::String> {
+        self.child("holderIdentity")
+    }
+
+    /// The path of the `leaseDurationSeconds` field.
+    pub fn lease_duration_seconds(self) -> crate::FieldPath<i32> {
+        self.child("leaseDurationSeconds")
+    }
+
+    /// The path of the `leaseTransitions` field.
+    pub fn lease_transitions(self) -> crate::FieldPath<i32> {
+        self.child("leaseTransitions")
+    }
+
+    /// The path of the `preferredHolder` field.
+    pub fn preferred_holder(self) -> crate::FieldPath<std::string::String> {
+        self.child("preferredHolder")
+    }
+
+    /// The path of the `renewTime` field.
+    pub fn renew_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("renewTime")
+    }
+
+    /// The path of the `strategy` field.
+    pub fn strategy(self) -> crate::FieldPath<std::string::String> {
+        self.child("strategy")
+    }
+}
+
+impl crate::Normalize for LeaseSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.acquire_time);
+        crate::Normalize::normalize(&mut self.holder_identity);
+        crate::Normalize::normalize(&mut self.lease_duration_seconds);
+        crate::Normalize::normalize(&mut self.lease_transitions);
+        crate::Normalize::normalize(&mut self.preferred_holder);
+        crate::Normalize::normalize(&mut self.renew_time);
+        crate::Normalize::normalize(&mut self.strategy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LeaseSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -176,6 +256,70 @@ impl crate::serde::Serialize for LeaseSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LeaseSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.holder_identity {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.lease_duration_seconds {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.acquire_time {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.renew_time {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.lease_transitions {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.strategy {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.preferred_holder {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_acquire_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+        let mut value_holder_identity: Option<std::string::String> = None;
+        let mut value_lease_duration_seconds: Option<i32> = None;
+        let mut value_lease_transitions: Option<i32> = None;
+        let mut value_preferred_holder: Option<std::string::String> = None;
+        let mut value_renew_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+        let mut value_strategy: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_holder_identity, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_lease_duration_seconds, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_acquire_time, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_renew_time, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_lease_transitions, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_strategy, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_preferred_holder, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LeaseSpec {
+            acquire_time: value_acquire_time,
+            holder_identity: value_holder_identity,
+            lease_duration_seconds: value_lease_duration_seconds,
+            lease_transitions: value_lease_transitions,
+            preferred_holder: value_preferred_holder,
+            renew_time: value_renew_time,
+            strategy: value_strategy,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LeaseSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LeaseSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for Affinity {
     }
 }
 
+impl crate::Defaulting for Affinity {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.node_affinity);
+        crate::Defaulting::set_defaults(&mut self.pod_affinity);
+        crate::Defaulting::set_defaults(&mut self.pod_anti_affinity);
+    }
+}
+
+impl crate::diff::Diff for Affinity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.node_affinity, &other.node_affinity, &crate::diff::Path::Field { parent: path, name: "nodeAffinity" }, changes);
+        crate::diff::Diff::diff(&self.pod_affinity, &other.pod_affinity, &crate::diff::Path::Field { parent: path, name: "podAffinity" }, changes);
+        crate::diff::Diff::diff(&self.pod_anti_affinity, &other.pod_anti_affinity, &crate::diff::Path::Field { parent: path, name: "podAntiAffinity" }, changes);
+    }
+}
+
+impl Affinity {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Affinity> {
+    /// The path of the `nodeAffinity` field.
+    pub fn node_affinity(self) -> crate::FieldPath<crate::api::core::v1::NodeAffinity> {
+        self.child("nodeAffinity")
+    }
+
+    /// The path of the `podAffinity` field.
+    pub fn pod_affinity(self) -> crate::FieldPath<crate::api::core::v1::PodAffinity> {
+        self.child("podAffinity")
+    }
+
+    /// The path of the `podAntiAffinity` field.
+    pub fn pod_anti_affinity(self) -> crate::FieldPath<crate::api::core::v1::PodAntiAffinity> {
+        self.child("podAntiAffinity")
+    }
+}
+
+impl crate::Normalize for Affinity {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.node_affinity);
+        crate::Normalize::normalize(&mut self.pod_affinity);
+        crate::Normalize::normalize(&mut self.pod_anti_affinity);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Affinity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for Affinity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Affinity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.node_affinity {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.pod_affinity {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.pod_anti_affinity {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_node_affinity: Option<crate::api::core::v1::NodeAffinity> = None;
+        let mut value_pod_affinity: Option<crate::api::core::v1::PodAffinity> = None;
+        let mut value_pod_anti_affinity: Option<crate::api::core::v1::PodAntiAffinity> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_node_affinity, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_affinity, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pod_anti_affinity, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Affinity {
+            node_affinity: value_node_affinity,
+            pod_affinity: value_pod_affinity,
+            pod_anti_affinity: value_pod_anti_affinity,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Affinity {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Affinity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,66 @@ impl crate::DeepMerge for Capabilities {
     }
 }
 
+impl crate::Defaulting for Capabilities {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.add);
+        crate::Defaulting::set_defaults(&mut self.drop);
+    }
+}
+
+impl crate::diff::Diff for Capabilities {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.add,
+            &other.add,
+            &crate::diff::Path::Field { parent: path, name: "add" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.drop,
+            &other.drop,
+            &crate::diff::Path::Field { parent: path, name: "drop" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl Capabilities {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Capabilities> {
+    /// The path of the `add` field.
+    pub fn add(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("add")
+    }
+
+    /// The path of the `drop` field.
+    pub fn drop(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("drop")
+    }
+}
+
+impl crate::Normalize for Capabilities {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.add, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.drop, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Capabilities {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for Capabilities {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Capabilities {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.add {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.drop {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_add: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_drop: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_add, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_drop, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Capabilities {
+            add: value_add,
+            drop: value_drop,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Capabilities {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Capabilities {
     fn schema_name() -> std::borrow::Cow<'static, str> {
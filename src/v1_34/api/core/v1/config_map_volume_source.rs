@@ -27,6 +27,75 @@ impl crate::DeepMerge for ConfigMapVolumeSource {
     }
 }
 
+impl crate::Defaulting for ConfigMapVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.default_mode.is_none() {
+            self.default_mode = Some(420);
+        }
+        crate::Defaulting::set_defaults(&mut self.default_mode);
+        crate::Defaulting::set_defaults(&mut self.items);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.optional);
+    }
+}
+
+impl crate::diff::Diff for ConfigMapVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.default_mode, &other.default_mode, &crate::diff::Path::Field { parent: path, name: "defaultMode" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.items,
+            &other.items,
+            &crate::diff::Path::Field { parent: path, name: "items" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.optional, &other.optional, &crate::diff::Path::Field { parent: path, name: "optional" }, changes);
+    }
+}
+
+impl ConfigMapVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ConfigMapVolumeSource> {
+    /// The path of the `defaultMode` field.
+    pub fn default_mode(self) -> crate::FieldPath<i32> {
+        self.child("defaultMode")
+    }
+
+    /// The path of the `items` field.
+    pub fn items(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::KeyToPath>> {
+        self.child("items")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `optional` field.
+    pub fn optional(self) -> crate::FieldPath<bool> {
+        self.child("optional")
+    }
+}
+
+impl crate::Normalize for ConfigMapVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.default_mode);
+        crate::normalize_strategies::list::atomic(&mut self.items, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.optional);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ConfigMapVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +203,63 @@ impl crate::serde::Serialize for ConfigMapVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ConfigMapVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        {
+            let mut embedded = std::vec![];
+            crate::protobuf::Field::encode_field(&self.name, 1, &mut embedded);
+            crate::protobuf::encode_length_delimited(1, &embedded, buf);
+        }
+        if let Some(value) = &self.items {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.default_mode {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.optional {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default_mode: Option<i32> = None;
+        let mut value_items: Option<std::vec::Vec<crate::api::core::v1::KeyToPath>> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_optional: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                2 => crate::protobuf::Field::merge_field(&mut value_items, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_default_mode, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_optional, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ConfigMapVolumeSource {
+            default_mode: value_default_mode,
+            items: value_items,
+            name: value_name.unwrap_or_default(),
+            optional: value_optional,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ConfigMapVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ConfigMapVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
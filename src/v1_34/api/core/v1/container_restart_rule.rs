@@ -17,6 +17,46 @@ impl crate::DeepMerge for ContainerRestartRule {
     }
 }
 
+impl crate::Defaulting for ContainerRestartRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.action);
+        crate::Defaulting::set_defaults(&mut self.exit_codes);
+    }
+}
+
+impl crate::diff::Diff for ContainerRestartRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.action, &other.action, &crate::diff::Path::Field { parent: path, name: "action" }, changes);
+        crate::diff::Diff::diff(&self.exit_codes, &other.exit_codes, &crate::diff::Path::Field { parent: path, name: "exitCodes" }, changes);
+    }
+}
+
+impl ContainerRestartRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerRestartRule> {
+    /// The path of the `action` field.
+    pub fn action(self) -> crate::FieldPath<std::string::String> {
+        self.child("action")
+    }
+
+    /// The path of the `exitCodes` field.
+    pub fn exit_codes(self) -> crate::FieldPath<crate::api::core::v1::ContainerRestartRuleOnExitCodes> {
+        self.child("exitCodes")
+    }
+}
+
+impl crate::Normalize for ContainerRestartRule {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.action);
+        crate::Normalize::normalize(&mut self.exit_codes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerRestartRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for ContainerRestartRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerRestartRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.action, 1, buf);
+        if let Some(value) = &self.exit_codes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_action: Option<std::string::String> = None;
+        let mut value_exit_codes: Option<crate::api::core::v1::ContainerRestartRuleOnExitCodes> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_action, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_exit_codes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerRestartRule {
+            action: value_action.unwrap_or_default(),
+            exit_codes: value_exit_codes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerRestartRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerRestartRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -20,6 +20,48 @@ impl crate::DeepMerge for ContainerRestartRuleOnExitCodes {
     }
 }
 
+impl crate::Defaulting for ContainerRestartRuleOnExitCodes {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.operator);
+        crate::Defaulting::set_defaults(&mut self.values);
+    }
+}
+
+impl crate::diff::Diff for ContainerRestartRuleOnExitCodes {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.operator, &other.operator, &crate::diff::Path::Field { parent: path, name: "operator" }, changes);
+        crate::diff::strategies::list::set(&self.values, &other.values, &crate::diff::Path::Field { parent: path, name: "values" }, changes);
+    }
+}
+
+impl ContainerRestartRuleOnExitCodes {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerRestartRuleOnExitCodes> {
+    /// The path of the `operator` field.
+    pub fn operator(self) -> crate::FieldPath<std::string::String> {
+        self.child("operator")
+    }
+
+    /// The path of the `values` field.
+    pub fn values(self) -> crate::FieldPath<std::vec::Vec<i32>> {
+        self.child("values")
+    }
+}
+
+impl crate::Normalize for ContainerRestartRuleOnExitCodes {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.operator);
+        crate::normalize_strategies::list::set(&mut self.values, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerRestartRuleOnExitCodes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -107,6 +149,38 @@ impl crate::serde::Serialize for ContainerRestartRuleOnExitCodes {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerRestartRuleOnExitCodes {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.operator, 1, buf);
+        if let Some(value) = &self.values {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_operator: Option<std::string::String> = None;
+        let mut value_values: Option<std::vec::Vec<i32>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_operator, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_values, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerRestartRuleOnExitCodes {
+            operator: value_operator.unwrap_or_default(),
+            values: value_values,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerRestartRuleOnExitCodes {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerRestartRuleOnExitCodes {
     fn schema_name() -> std::borrow::Cow<'static, str> {
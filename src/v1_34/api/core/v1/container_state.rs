@@ -21,6 +21,54 @@ impl crate::DeepMerge for ContainerState {
     }
 }
 
+impl crate::Defaulting for ContainerState {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.running);
+        crate::Defaulting::set_defaults(&mut self.terminated);
+        crate::Defaulting::set_defaults(&mut self.waiting);
+    }
+}
+
+impl crate::diff::Diff for ContainerState {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.running, &other.running, &crate::diff::Path::Field { parent: path, name: "running" }, changes);
+        crate::diff::Diff::diff(&self.terminated, &other.terminated, &crate::diff::Path::Field { parent: path, name: "terminated" }, changes);
+        crate::diff::Diff::diff(&self.waiting, &other.waiting, &crate::diff::Path::Field { parent: path, name: "waiting" }, changes);
+    }
+}
+
+impl ContainerState {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerState> {
+    /// The path of the `running` field.
+    pub fn running(self) -> crate::FieldPath<crate::api::core::v1::ContainerStateRunning> {
+        self.child("running")
+    }
+
+    /// The path of the `terminated` field.
+    pub fn terminated(self) -> crate::FieldPath<crate::api::core::v1::ContainerStateTerminated> {
+        self.child("terminated")
+    }
+
+    /// The path of the `waiting` field.
+    pub fn waiting(self) -> crate::FieldPath<crate::api::core::v1::ContainerStateWaiting> {
+        self.child("waiting")
+    }
+}
+
+impl crate::Normalize for ContainerState {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.running);
+        crate::Normalize::normalize(&mut self.terminated);
+        crate::Normalize::normalize(&mut self.waiting);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerState {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,46 @@ impl crate::serde::Serialize for ContainerState {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerState {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.waiting {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.running {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.terminated {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_running: Option<crate::api::core::v1::ContainerStateRunning> = None;
+        let mut value_terminated: Option<crate::api::core::v1::ContainerStateTerminated> = None;
+        let mut value_waiting: Option<crate::api::core::v1::ContainerStateWaiting> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_waiting, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_running, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_terminated, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerState {
+            running: value_running,
+            terminated: value_terminated,
+            waiting: value_waiting,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerState {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerState {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -51,6 +51,120 @@ impl crate::DeepMerge for CSIPersistentVolumeSource {
     }
 }
 
+impl crate::Defaulting for CSIPersistentVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.controller_expand_secret_ref);
+        crate::Defaulting::set_defaults(&mut self.controller_publish_secret_ref);
+        crate::Defaulting::set_defaults(&mut self.driver);
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.node_expand_secret_ref);
+        crate::Defaulting::set_defaults(&mut self.node_publish_secret_ref);
+        crate::Defaulting::set_defaults(&mut self.node_stage_secret_ref);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.volume_attributes);
+        crate::Defaulting::set_defaults(&mut self.volume_handle);
+    }
+}
+
+impl crate::diff::Diff for CSIPersistentVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.controller_expand_secret_ref, &other.controller_expand_secret_ref, &crate::diff::Path::Field { parent: path, name: "controllerExpandSecretRef" }, changes);
+        crate::diff::Diff::diff(&self.controller_publish_secret_ref, &other.controller_publish_secret_ref, &crate::diff::Path::Field { parent: path, name: "controllerPublishSecretRef" }, changes);
+        crate::diff::Diff::diff(&self.driver, &other.driver, &crate::diff::Path::Field { parent: path, name: "driver" }, changes);
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.node_expand_secret_ref, &other.node_expand_secret_ref, &crate::diff::Path::Field { parent: path, name: "nodeExpandSecretRef" }, changes);
+        crate::diff::Diff::diff(&self.node_publish_secret_ref, &other.node_publish_secret_ref, &crate::diff::Path::Field { parent: path, name: "nodePublishSecretRef" }, changes);
+        crate::diff::Diff::diff(&self.node_stage_secret_ref, &other.node_stage_secret_ref, &crate::diff::Path::Field { parent: path, name: "nodeStageSecretRef" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::strategies::map::values(
+            &self.volume_attributes,
+            &other.volume_attributes,
+            &crate::diff::Path::Field { parent: path, name: "volumeAttributes" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.volume_handle, &other.volume_handle, &crate::diff::Path::Field { parent: path, name: "volumeHandle" }, changes);
+    }
+}
+
+impl CSIPersistentVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CSIPersistentVolumeSource> {
+    /// The path of the `controllerExpandSecretRef` field.
+    pub fn controller_expand_secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("controllerExpandSecretRef")
+    }
+
+    /// The path of the `controllerPublishSecretRef` field.
+    pub fn controller_publish_secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("controllerPublishSecretRef")
+    }
+
+    /// The path of the `driver` field.
+    pub fn driver(self) -> crate::FieldPath<std::string::String> {
+        self.child("driver")
+    }
+
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `nodeExpandSecretRef` field.
+    pub fn node_expand_secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("nodeExpandSecretRef")
+    }
+
+    /// The path of the `nodePublishSecretRef` field.
+    pub fn node_publish_secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("nodePublishSecretRef")
+    }
+
+    /// The path of the `nodeStageSecretRef` field.
+    pub fn node_stage_secret_ref(self) -> crate::FieldPath<crate::api::core::v1::SecretReference> {
+        self.child("nodeStageSecretRef")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `volumeAttributes` field.
+    pub fn volume_attributes(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("volumeAttributes")
+    }
+
+    /// The path of the `volumeHandle` field.
+    pub fn volume_handle(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeHandle")
+    }
+}
+
+impl crate::Normalize for CSIPersistentVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.controller_expand_secret_ref);
+        crate::Normalize::normalize(&mut self.controller_publish_secret_ref);
+        crate::Normalize::normalize(&mut self.driver);
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.node_expand_secret_ref);
+        crate::Normalize::normalize(&mut self.node_publish_secret_ref);
+        crate::Normalize::normalize(&mut self.node_stage_secret_ref);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::normalize_strategies::map::values(&mut self.volume_attributes, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.volume_handle);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CSIPersistentVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -215,6 +329,84 @@ impl crate::serde::Serialize for CSIPersistentVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CSIPersistentVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.driver, 1, buf);
+        crate::protobuf::Field::encode_field(&self.volume_handle, 2, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.volume_attributes {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.controller_publish_secret_ref {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.node_stage_secret_ref {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.node_publish_secret_ref {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.controller_expand_secret_ref {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.node_expand_secret_ref {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_controller_expand_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_controller_publish_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_driver: Option<std::string::String> = None;
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_node_expand_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_node_publish_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_node_stage_secret_ref: Option<crate::api::core::v1::SecretReference> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_volume_attributes: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_volume_handle: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_driver, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_volume_handle, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_volume_attributes, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_controller_publish_secret_ref, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_node_stage_secret_ref, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_node_publish_secret_ref, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_controller_expand_secret_ref, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_node_expand_secret_ref, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CSIPersistentVolumeSource {
+            controller_expand_secret_ref: value_controller_expand_secret_ref,
+            controller_publish_secret_ref: value_controller_publish_secret_ref,
+            driver: value_driver.unwrap_or_default(),
+            fs_type: value_fs_type,
+            node_expand_secret_ref: value_node_expand_secret_ref,
+            node_publish_secret_ref: value_node_publish_secret_ref,
+            node_stage_secret_ref: value_node_stage_secret_ref,
+            read_only: value_read_only,
+            volume_attributes: value_volume_attributes,
+            volume_handle: value_volume_handle.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CSIPersistentVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CSIPersistentVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for EmptyDirVolumeSource {
     }
 }
 
+impl crate::Defaulting for EmptyDirVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.medium);
+        crate::Defaulting::set_defaults(&mut self.size_limit);
+    }
+}
+
+impl crate::diff::Diff for EmptyDirVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.medium, &other.medium, &crate::diff::Path::Field { parent: path, name: "medium" }, changes);
+        crate::diff::Diff::diff(&self.size_limit, &other.size_limit, &crate::diff::Path::Field { parent: path, name: "sizeLimit" }, changes);
+    }
+}
+
+impl EmptyDirVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EmptyDirVolumeSource> {
+    /// The path of the `medium` field.
+    pub fn medium(self) -> crate::FieldPath<std::string::String> {
+        self.child("medium")
+    }
+
+    /// The path of the `sizeLimit` field.
+    pub fn size_limit(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("sizeLimit")
+    }
+}
+
+impl crate::Normalize for EmptyDirVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.medium);
+        crate::Normalize::normalize(&mut self.size_limit);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EmptyDirVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for EmptyDirVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EmptyDirVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.medium {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.size_limit {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_medium: Option<std::string::String> = None;
+        let mut value_size_limit: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_medium, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_size_limit, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EmptyDirVolumeSource {
+            medium: value_medium,
+            size_limit: value_size_limit,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EmptyDirVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EmptyDirVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
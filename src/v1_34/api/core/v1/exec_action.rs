@@ -13,6 +13,48 @@ impl crate::DeepMerge for ExecAction {
     }
 }
 
+impl crate::Defaulting for ExecAction {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.command);
+    }
+}
+
+impl crate::diff::Diff for ExecAction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.command,
+            &other.command,
+            &crate::diff::Path::Field { parent: path, name: "command" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ExecAction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ExecAction> {
+    /// The path of the `command` field.
+    pub fn command(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("command")
+    }
+}
+
+impl crate::Normalize for ExecAction {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.command, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ExecAction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for ExecAction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ExecAction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.command {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_command: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_command, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ExecAction {
+            command: value_command,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ExecAction {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExecAction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -23,6 +23,54 @@ impl crate::DeepMerge for GitRepoVolumeSource {
     }
 }
 
+impl crate::Defaulting for GitRepoVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.directory);
+        crate::Defaulting::set_defaults(&mut self.repository);
+        crate::Defaulting::set_defaults(&mut self.revision);
+    }
+}
+
+impl crate::diff::Diff for GitRepoVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.directory, &other.directory, &crate::diff::Path::Field { parent: path, name: "directory" }, changes);
+        crate::diff::Diff::diff(&self.repository, &other.repository, &crate::diff::Path::Field { parent: path, name: "repository" }, changes);
+        crate::diff::Diff::diff(&self.revision, &other.revision, &crate::diff::Path::Field { parent: path, name: "revision" }, changes);
+    }
+}
+
+impl GitRepoVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GitRepoVolumeSource> {
+    /// The path of the `directory` field.
+    pub fn directory(self) -> crate::FieldPath<std::string::String> {
+        self.child("directory")
+    }
+
+    /// The path of the `repository` field.
+    pub fn repository(self) -> crate::FieldPath<std::string::String> {
+        self.child("repository")
+    }
+
+    /// The path of the `revision` field.
+    pub fn revision(self) -> crate::FieldPath<std::string::String> {
+        self.child("revision")
+    }
+}
+
+impl crate::Normalize for GitRepoVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.directory);
+        crate::Normalize::normalize(&mut self.repository);
+        crate::Normalize::normalize(&mut self.revision);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GitRepoVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -120,6 +168,44 @@ impl crate::serde::Serialize for GitRepoVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GitRepoVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.repository, 1, buf);
+        if let Some(value) = &self.revision {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.directory {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_directory: Option<std::string::String> = None;
+        let mut value_repository: Option<std::string::String> = None;
+        let mut value_revision: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_repository, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_revision, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_directory, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GitRepoVolumeSource {
+            directory: value_directory,
+            repository: value_repository.unwrap_or_default(),
+            revision: value_revision,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GitRepoVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GitRepoVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
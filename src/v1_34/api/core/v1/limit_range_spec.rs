@@ -13,6 +13,48 @@ impl crate::DeepMerge for LimitRangeSpec {
     }
 }
 
+impl crate::Defaulting for LimitRangeSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.limits);
+    }
+}
+
+impl crate::diff::Diff for LimitRangeSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.limits,
+            &other.limits,
+            &crate::diff::Path::Field { parent: path, name: "limits" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl LimitRangeSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LimitRangeSpec> {
+    /// The path of the `limits` field.
+    pub fn limits(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::LimitRangeItem>> {
+        self.child("limits")
+    }
+}
+
+impl crate::Normalize for LimitRangeSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.limits, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LimitRangeSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +132,32 @@ impl crate::serde::Serialize for LimitRangeSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LimitRangeSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.limits, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_limits: Option<std::vec::Vec<crate::api::core::v1::LimitRangeItem>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_limits, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LimitRangeSpec {
+            limits: value_limits.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LimitRangeSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LimitRangeSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
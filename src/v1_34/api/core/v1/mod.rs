@@ -228,7 +228,7 @@ mod lifecycle;
 pub use self::lifecycle::Lifecycle;
 
 mod lifecycle_handler;
-pub use self::lifecycle_handler::LifecycleHandler;
+pub use self::lifecycle_handler::{LifecycleHandler, LifecycleHandlerUnion, LifecycleHandlerUnionRef};
 
 mod limit_range;
 pub use self::limit_range::LimitRange;
@@ -429,7 +429,7 @@ mod preferred_scheduling_term;
 pub use self::preferred_scheduling_term::PreferredSchedulingTerm;
 
 mod probe;
-pub use self::probe::Probe;
+pub use self::probe::{Probe, ProbeUnion, ProbeUnionRef};
 
 mod projected_volume_source;
 pub use self::projected_volume_source::ProjectedVolumeSource;
@@ -576,7 +576,7 @@ mod typed_object_reference;
 pub use self::typed_object_reference::TypedObjectReference;
 
 mod volume;
-pub use self::volume::Volume;
+pub use self::volume::{Volume, VolumeUnion, VolumeUnionRef};
 
 mod volume_device;
 pub use self::volume_device::VolumeDevice;
@@ -13,6 +13,48 @@ impl crate::DeepMerge for NodeSelector {
     }
 }
 
+impl crate::Defaulting for NodeSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.node_selector_terms);
+    }
+}
+
+impl crate::diff::Diff for NodeSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.node_selector_terms,
+            &other.node_selector_terms,
+            &crate::diff::Path::Field { parent: path, name: "nodeSelectorTerms" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NodeSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeSelector> {
+    /// The path of the `nodeSelectorTerms` field.
+    pub fn node_selector_terms(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeSelectorTerm>> {
+        self.child("nodeSelectorTerms")
+    }
+}
+
+impl crate::Normalize for NodeSelector {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.node_selector_terms, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +132,32 @@ impl crate::serde::Serialize for NodeSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.node_selector_terms, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_node_selector_terms: Option<std::vec::Vec<crate::api::core::v1::NodeSelectorTerm>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_node_selector_terms, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeSelector {
+            node_selector_terms: value_node_selector_terms.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
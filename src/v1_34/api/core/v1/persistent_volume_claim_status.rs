@@ -93,6 +93,145 @@ impl crate::DeepMerge for PersistentVolumeClaimStatus {
     }
 }
 
+impl crate::Defaulting for PersistentVolumeClaimStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.access_modes);
+        crate::Defaulting::set_defaults(&mut self.allocated_resource_statuses);
+        crate::Defaulting::set_defaults(&mut self.allocated_resources);
+        crate::Defaulting::set_defaults(&mut self.capacity);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.current_volume_attributes_class_name);
+        crate::Defaulting::set_defaults(&mut self.modify_volume_status);
+        crate::Defaulting::set_defaults(&mut self.phase);
+    }
+}
+
+impl crate::diff::Diff for PersistentVolumeClaimStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.access_modes,
+            &other.access_modes,
+            &crate::diff::Path::Field { parent: path, name: "accessModes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.allocated_resource_statuses,
+            &other.allocated_resource_statuses,
+            &crate::diff::Path::Field { parent: path, name: "allocatedResourceStatuses" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.allocated_resources,
+            &other.allocated_resources,
+            &crate::diff::Path::Field { parent: path, name: "allocatedResources" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.capacity,
+            &other.capacity,
+            &crate::diff::Path::Field { parent: path, name: "capacity" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.current_volume_attributes_class_name, &other.current_volume_attributes_class_name, &crate::diff::Path::Field { parent: path, name: "currentVolumeAttributesClassName" }, changes);
+        crate::diff::Diff::diff(&self.modify_volume_status, &other.modify_volume_status, &crate::diff::Path::Field { parent: path, name: "modifyVolumeStatus" }, changes);
+        crate::diff::Diff::diff(&self.phase, &other.phase, &crate::diff::Path::Field { parent: path, name: "phase" }, changes);
+    }
+}
+
+impl PersistentVolumeClaimStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PersistentVolumeClaimStatus> {
+    /// The path of the `accessModes` field.
+    pub fn access_modes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("accessModes")
+    }
+
+    /// The path of the `allocatedResourceStatuses` field.
+    pub fn allocated_resource_statuses(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("allocatedResourceStatuses")
+    }
+
+    /// The path of the `allocatedResources` field.
+    pub fn allocated_resources(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("allocatedResources")
+    }
+
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("capacity")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PersistentVolumeClaimCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `currentVolumeAttributesClassName` field.
+    pub fn current_volume_attributes_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("currentVolumeAttributesClassName")
+    }
+
+    /// The path of the `modifyVolumeStatus` field.
+    pub fn modify_volume_status(self) -> crate::FieldPath<crate::api::core::v1::ModifyVolumeStatus> {
+        self.child("modifyVolumeStatus")
+    }
+
+    /// The path of the `phase` field.
+    pub fn phase(self) -> crate::FieldPath<std::string::String> {
+        self.child("phase")
+    }
+}
+
+impl crate::Normalize for PersistentVolumeClaimStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.access_modes, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::map::values(&mut self.allocated_resource_statuses, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.allocated_resources, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.capacity, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.current_volume_attributes_class_name);
+        crate::Normalize::normalize(&mut self.modify_volume_status);
+        crate::Normalize::normalize(&mut self.phase);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -242,6 +381,86 @@ impl crate::serde::Serialize for PersistentVolumeClaimStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PersistentVolumeClaimStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.phase {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.access_modes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.allocated_resources {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.allocated_resource_statuses {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.current_volume_attributes_class_name {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.modify_volume_status {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_access_modes: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_allocated_resource_statuses: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_allocated_resources: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_capacity: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::core::v1::PersistentVolumeClaimCondition>> = None;
+        let mut value_current_volume_attributes_class_name: Option<std::string::String> = None;
+        let mut value_modify_volume_status: Option<crate::api::core::v1::ModifyVolumeStatus> = None;
+        let mut value_phase: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_phase, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_access_modes, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_allocated_resources, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_allocated_resource_statuses, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_current_volume_attributes_class_name, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_modify_volume_status, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PersistentVolumeClaimStatus {
+            access_modes: value_access_modes,
+            allocated_resource_statuses: value_allocated_resource_statuses,
+            allocated_resources: value_allocated_resources,
+            capacity: value_capacity,
+            conditions: value_conditions,
+            current_volume_attributes_class_name: value_current_volume_attributes_class_name,
+            modify_volume_status: value_modify_volume_status,
+            phase: value_phase,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PersistentVolumeClaimStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
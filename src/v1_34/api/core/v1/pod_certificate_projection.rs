@@ -51,6 +51,78 @@ impl crate::DeepMerge for PodCertificateProjection {
     }
 }
 
+impl crate::Defaulting for PodCertificateProjection {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.certificate_chain_path);
+        crate::Defaulting::set_defaults(&mut self.credential_bundle_path);
+        crate::Defaulting::set_defaults(&mut self.key_path);
+        crate::Defaulting::set_defaults(&mut self.key_type);
+        crate::Defaulting::set_defaults(&mut self.max_expiration_seconds);
+        crate::Defaulting::set_defaults(&mut self.signer_name);
+    }
+}
+
+impl crate::diff::Diff for PodCertificateProjection {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.certificate_chain_path, &other.certificate_chain_path, &crate::diff::Path::Field { parent: path, name: "certificateChainPath" }, changes);
+        crate::diff::Diff::diff(&self.credential_bundle_path, &other.credential_bundle_path, &crate::diff::Path::Field { parent: path, name: "credentialBundlePath" }, changes);
+        crate::diff::Diff::diff(&self.key_path, &other.key_path, &crate::diff::Path::Field { parent: path, name: "keyPath" }, changes);
+        crate::diff::Diff::diff(&self.key_type, &other.key_type, &crate::diff::Path::Field { parent: path, name: "keyType" }, changes);
+        crate::diff::Diff::diff(&self.max_expiration_seconds, &other.max_expiration_seconds, &crate::diff::Path::Field { parent: path, name: "maxExpirationSeconds" }, changes);
+        crate::diff::Diff::diff(&self.signer_name, &other.signer_name, &crate::diff::Path::Field { parent: path, name: "signerName" }, changes);
+    }
+}
+
+impl PodCertificateProjection {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodCertificateProjection> {
+    /// The path of the `certificateChainPath` field.
+    pub fn certificate_chain_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("certificateChainPath")
+    }
+
+    /// The path of the `credentialBundlePath` field.
+    pub fn credential_bundle_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("credentialBundlePath")
+    }
+
+    /// The path of the `keyPath` field.
+    pub fn key_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("keyPath")
+    }
+
+    /// The path of the `keyType` field.
+    pub fn key_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("keyType")
+    }
+
+    /// The path of the `maxExpirationSeconds` field.
+    pub fn max_expiration_seconds(self) -> crate::FieldPath<i32> {
+        self.child("maxExpirationSeconds")
+    }
+
+    /// The path of the `signerName` field.
+    pub fn signer_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("signerName")
+    }
+}
+
+impl crate::Normalize for PodCertificateProjection {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.certificate_chain_path);
+        crate::Normalize::normalize(&mut self.credential_bundle_path);
+        crate::Normalize::normalize(&mut self.key_path);
+        crate::Normalize::normalize(&mut self.key_type);
+        crate::Normalize::normalize(&mut self.max_expiration_seconds);
+        crate::Normalize::normalize(&mut self.signer_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodCertificateProjection {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -175,6 +247,60 @@ impl crate::serde::Serialize for PodCertificateProjection {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodCertificateProjection {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.signer_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.key_type, 2, buf);
+        if let Some(value) = &self.max_expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.credential_bundle_path {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.key_path {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.certificate_chain_path {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_certificate_chain_path: Option<std::string::String> = None;
+        let mut value_credential_bundle_path: Option<std::string::String> = None;
+        let mut value_key_path: Option<std::string::String> = None;
+        let mut value_key_type: Option<std::string::String> = None;
+        let mut value_max_expiration_seconds: Option<i32> = None;
+        let mut value_signer_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_signer_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_key_type, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_max_expiration_seconds, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_credential_bundle_path, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_key_path, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_certificate_chain_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodCertificateProjection {
+            certificate_chain_path: value_certificate_chain_path,
+            credential_bundle_path: value_credential_bundle_path,
+            key_path: value_key_path,
+            key_type: value_key_type.unwrap_or_default(),
+            max_expiration_seconds: value_max_expiration_seconds,
+            signer_name: value_signer_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodCertificateProjection {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodCertificateProjection {
     fn schema_name() -> std::borrow::Cow<'static, str> {
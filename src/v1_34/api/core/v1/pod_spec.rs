@@ -254,6 +254,512 @@ impl crate::DeepMerge for PodSpec {
     }
 }
 
+impl crate::Defaulting for PodSpec {
+    fn set_defaults(&mut self) {
+        if self.dns_policy.is_none() {
+            self.dns_policy = Some("ClusterFirst".into());
+        }
+        if self.restart_policy.is_none() {
+            self.restart_policy = Some("Always".into());
+        }
+        if self.scheduler_name.is_none() {
+            self.scheduler_name = Some("default-scheduler".into());
+        }
+        if self.security_context.is_none() {
+            self.security_context = Some(Default::default());
+        }
+        if self.termination_grace_period_seconds.is_none() {
+            self.termination_grace_period_seconds = Some(30);
+        }
+        crate::Defaulting::set_defaults(&mut self.active_deadline_seconds);
+        crate::Defaulting::set_defaults(&mut self.affinity);
+        crate::Defaulting::set_defaults(&mut self.automount_service_account_token);
+        crate::Defaulting::set_defaults(&mut self.containers);
+        crate::Defaulting::set_defaults(&mut self.dns_config);
+        crate::Defaulting::set_defaults(&mut self.dns_policy);
+        crate::Defaulting::set_defaults(&mut self.enable_service_links);
+        crate::Defaulting::set_defaults(&mut self.ephemeral_containers);
+        crate::Defaulting::set_defaults(&mut self.host_aliases);
+        crate::Defaulting::set_defaults(&mut self.host_ipc);
+        crate::Defaulting::set_defaults(&mut self.host_network);
+        crate::Defaulting::set_defaults(&mut self.host_pid);
+        crate::Defaulting::set_defaults(&mut self.host_users);
+        crate::Defaulting::set_defaults(&mut self.hostname);
+        crate::Defaulting::set_defaults(&mut self.hostname_override);
+        crate::Defaulting::set_defaults(&mut self.image_pull_secrets);
+        crate::Defaulting::set_defaults(&mut self.init_containers);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.node_selector);
+        crate::Defaulting::set_defaults(&mut self.os);
+        crate::Defaulting::set_defaults(&mut self.overhead);
+        crate::Defaulting::set_defaults(&mut self.preemption_policy);
+        crate::Defaulting::set_defaults(&mut self.priority);
+        crate::Defaulting::set_defaults(&mut self.priority_class_name);
+        crate::Defaulting::set_defaults(&mut self.readiness_gates);
+        crate::Defaulting::set_defaults(&mut self.resource_claims);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.restart_policy);
+        crate::Defaulting::set_defaults(&mut self.runtime_class_name);
+        crate::Defaulting::set_defaults(&mut self.scheduler_name);
+        crate::Defaulting::set_defaults(&mut self.scheduling_gates);
+        crate::Defaulting::set_defaults(&mut self.security_context);
+        crate::Defaulting::set_defaults(&mut self.service_account);
+        crate::Defaulting::set_defaults(&mut self.service_account_name);
+        crate::Defaulting::set_defaults(&mut self.set_hostname_as_fqdn);
+        crate::Defaulting::set_defaults(&mut self.share_process_namespace);
+        crate::Defaulting::set_defaults(&mut self.subdomain);
+        crate::Defaulting::set_defaults(&mut self.termination_grace_period_seconds);
+        crate::Defaulting::set_defaults(&mut self.tolerations);
+        crate::Defaulting::set_defaults(&mut self.topology_spread_constraints);
+        crate::Defaulting::set_defaults(&mut self.volumes);
+    }
+}
+
+impl crate::diff::Diff for PodSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.active_deadline_seconds, &other.active_deadline_seconds, &crate::diff::Path::Field { parent: path, name: "activeDeadlineSeconds" }, changes);
+        crate::diff::Diff::diff(&self.affinity, &other.affinity, &crate::diff::Path::Field { parent: path, name: "affinity" }, changes);
+        crate::diff::Diff::diff(&self.automount_service_account_token, &other.automount_service_account_token, &crate::diff::Path::Field { parent: path, name: "automountServiceAccountToken" }, changes);
+        crate::diff::strategies::list::map(
+            &self.containers,
+            &other.containers,
+            &crate::diff::Path::Field { parent: path, name: "containers" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.dns_config, &other.dns_config, &crate::diff::Path::Field { parent: path, name: "dnsConfig" }, changes);
+        crate::diff::Diff::diff(&self.dns_policy, &other.dns_policy, &crate::diff::Path::Field { parent: path, name: "dnsPolicy" }, changes);
+        crate::diff::Diff::diff(&self.enable_service_links, &other.enable_service_links, &crate::diff::Path::Field { parent: path, name: "enableServiceLinks" }, changes);
+        crate::diff::strategies::list::map(
+            &self.ephemeral_containers,
+            &other.ephemeral_containers,
+            &crate::diff::Path::Field { parent: path, name: "ephemeralContainers" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.host_aliases,
+            &other.host_aliases,
+            &crate::diff::Path::Field { parent: path, name: "hostAliases" },
+            changes,
+            &[("ip", |item| crate::diff::MergeKey::merge_key(&item.ip))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.host_ipc, &other.host_ipc, &crate::diff::Path::Field { parent: path, name: "hostIPC" }, changes);
+        crate::diff::Diff::diff(&self.host_network, &other.host_network, &crate::diff::Path::Field { parent: path, name: "hostNetwork" }, changes);
+        crate::diff::Diff::diff(&self.host_pid, &other.host_pid, &crate::diff::Path::Field { parent: path, name: "hostPID" }, changes);
+        crate::diff::Diff::diff(&self.host_users, &other.host_users, &crate::diff::Path::Field { parent: path, name: "hostUsers" }, changes);
+        crate::diff::Diff::diff(&self.hostname, &other.hostname, &crate::diff::Path::Field { parent: path, name: "hostname" }, changes);
+        crate::diff::Diff::diff(&self.hostname_override, &other.hostname_override, &crate::diff::Path::Field { parent: path, name: "hostnameOverride" }, changes);
+        crate::diff::strategies::list::map(
+            &self.image_pull_secrets,
+            &other.image_pull_secrets,
+            &crate::diff::Path::Field { parent: path, name: "imagePullSecrets" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.init_containers,
+            &other.init_containers,
+            &crate::diff::Path::Field { parent: path, name: "initContainers" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::strategies::map::values(
+            &self.node_selector,
+            &other.node_selector,
+            &crate::diff::Path::Field { parent: path, name: "nodeSelector" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.os, &other.os, &crate::diff::Path::Field { parent: path, name: "os" }, changes);
+        crate::diff::strategies::map::values(
+            &self.overhead,
+            &other.overhead,
+            &crate::diff::Path::Field { parent: path, name: "overhead" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.preemption_policy, &other.preemption_policy, &crate::diff::Path::Field { parent: path, name: "preemptionPolicy" }, changes);
+        crate::diff::Diff::diff(&self.priority, &other.priority, &crate::diff::Path::Field { parent: path, name: "priority" }, changes);
+        crate::diff::Diff::diff(&self.priority_class_name, &other.priority_class_name, &crate::diff::Path::Field { parent: path, name: "priorityClassName" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.readiness_gates,
+            &other.readiness_gates,
+            &crate::diff::Path::Field { parent: path, name: "readinessGates" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.resource_claims,
+            &other.resource_claims,
+            &crate::diff::Path::Field { parent: path, name: "resourceClaims" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.resources, &other.resources, &crate::diff::Path::Field { parent: path, name: "resources" }, changes);
+        crate::diff::Diff::diff(&self.restart_policy, &other.restart_policy, &crate::diff::Path::Field { parent: path, name: "restartPolicy" }, changes);
+        crate::diff::Diff::diff(&self.runtime_class_name, &other.runtime_class_name, &crate::diff::Path::Field { parent: path, name: "runtimeClassName" }, changes);
+        crate::diff::Diff::diff(&self.scheduler_name, &other.scheduler_name, &crate::diff::Path::Field { parent: path, name: "schedulerName" }, changes);
+        crate::diff::strategies::list::map(
+            &self.scheduling_gates,
+            &other.scheduling_gates,
+            &crate::diff::Path::Field { parent: path, name: "schedulingGates" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.security_context, &other.security_context, &crate::diff::Path::Field { parent: path, name: "securityContext" }, changes);
+        crate::diff::Diff::diff(&self.service_account, &other.service_account, &crate::diff::Path::Field { parent: path, name: "serviceAccount" }, changes);
+        crate::diff::Diff::diff(&self.service_account_name, &other.service_account_name, &crate::diff::Path::Field { parent: path, name: "serviceAccountName" }, changes);
+        crate::diff::Diff::diff(&self.set_hostname_as_fqdn, &other.set_hostname_as_fqdn, &crate::diff::Path::Field { parent: path, name: "setHostnameAsFQDN" }, changes);
+        crate::diff::Diff::diff(&self.share_process_namespace, &other.share_process_namespace, &crate::diff::Path::Field { parent: path, name: "shareProcessNamespace" }, changes);
+        crate::diff::Diff::diff(&self.subdomain, &other.subdomain, &crate::diff::Path::Field { parent: path, name: "subdomain" }, changes);
+        crate::diff::Diff::diff(&self.termination_grace_period_seconds, &other.termination_grace_period_seconds, &crate::diff::Path::Field { parent: path, name: "terminationGracePeriodSeconds" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.tolerations,
+            &other.tolerations,
+            &crate::diff::Path::Field { parent: path, name: "tolerations" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.topology_spread_constraints,
+            &other.topology_spread_constraints,
+            &crate::diff::Path::Field { parent: path, name: "topologySpreadConstraints" },
+            changes,
+            &[("topologyKey", |item| crate::diff::MergeKey::merge_key(&item.topology_key))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.volumes,
+            &other.volumes,
+            &crate::diff::Path::Field { parent: path, name: "volumes" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PodSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodSpec> {
+    /// The path of the `activeDeadlineSeconds` field.
+    pub fn active_deadline_seconds(self) -> crate::FieldPath<i64> {
+        self.child("activeDeadlineSeconds")
+    }
+
+    /// The path of the `affinity` field.
+    pub fn affinity(self) -> crate::FieldPath<crate::api::core::v1::Affinity> {
+        self.child("affinity")
+    }
+
+    /// The path of the `automountServiceAccountToken` field.
+    pub fn automount_service_account_token(self) -> crate::FieldPath<bool> {
+        self.child("automountServiceAccountToken")
+    }
+
+    /// The path of the `containers` field.
+    pub fn containers(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Container>> {
+        self.child("containers")
+    }
+
+    /// The path of the `dnsConfig` field.
+    pub fn dns_config(self) -> crate::FieldPath<crate::api::core::v1::PodDNSConfig> {
+        self.child("dnsConfig")
+    }
+
+    /// The path of the `dnsPolicy` field.
+    pub fn dns_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("dnsPolicy")
+    }
+
+    /// The path of the `enableServiceLinks` field.
+    pub fn enable_service_links(self) -> crate::FieldPath<bool> {
+        self.child("enableServiceLinks")
+    }
+
+    /// The path of the `ephemeralContainers` field.
+    pub fn ephemeral_containers(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::EphemeralContainer>> {
+        self.child("ephemeralContainers")
+    }
+
+    /// The path of the `hostAliases` field.
+    pub fn host_aliases(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::HostAlias>> {
+        self.child("hostAliases")
+    }
+
+    /// The path of the `hostIPC` field.
+    pub fn host_ipc(self) -> crate::FieldPath<bool> {
+        self.child("hostIPC")
+    }
+
+    /// The path of the `hostNetwork` field.
+    pub fn host_network(self) -> crate::FieldPath<bool> {
+        self.child("hostNetwork")
+    }
+
+    /// The path of the `hostPID` field.
+    pub fn host_pid(self) -> crate::FieldPath<bool> {
+        self.child("hostPID")
+    }
+
+    /// The path of the `hostUsers` field.
+    pub fn host_users(self) -> crate::FieldPath<bool> {
+        self.child("hostUsers")
+    }
+
+    /// The path of the `hostname` field.
+    pub fn hostname(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostname")
+    }
+
+    /// The path of the `hostnameOverride` field.
+    pub fn hostname_override(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostnameOverride")
+    }
+
+    /// The path of the `imagePullSecrets` field.
+    pub fn image_pull_secrets(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::LocalObjectReference>> {
+        self.child("imagePullSecrets")
+    }
+
+    /// The path of the `initContainers` field.
+    pub fn init_containers(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Container>> {
+        self.child("initContainers")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `nodeSelector` field.
+    pub fn node_selector(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("nodeSelector")
+    }
+
+    /// The path of the `os` field.
+    pub fn os(self) -> crate::FieldPath<crate::api::core::v1::PodOS> {
+        self.child("os")
+    }
+
+    /// The path of the `overhead` field.
+    pub fn overhead(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("overhead")
+    }
+
+    /// The path of the `preemptionPolicy` field.
+    pub fn preemption_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("preemptionPolicy")
+    }
+
+    /// The path of the `priority` field.
+    pub fn priority(self) -> crate::FieldPath<i32> {
+        self.child("priority")
+    }
+
+    /// The path of the `priorityClassName` field.
+    pub fn priority_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("priorityClassName")
+    }
+
+    /// The path of the `readinessGates` field.
+    pub fn readiness_gates(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodReadinessGate>> {
+        self.child("readinessGates")
+    }
+
+    /// The path of the `resourceClaims` field.
+    pub fn resource_claims(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodResourceClaim>> {
+        self.child("resourceClaims")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<crate::api::core::v1::ResourceRequirements> {
+        self.child("resources")
+    }
+
+    /// The path of the `restartPolicy` field.
+    pub fn restart_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("restartPolicy")
+    }
+
+    /// The path of the `runtimeClassName` field.
+    pub fn runtime_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("runtimeClassName")
+    }
+
+    /// The path of the `schedulerName` field.
+    pub fn scheduler_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("schedulerName")
+    }
+
+    /// The path of the `schedulingGates` field.
+    pub fn scheduling_gates(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodSchedulingGate>> {
+        self.child("schedulingGates")
+    }
+
+    /// The path of the `securityContext` field.
+    pub fn security_context(self) -> crate::FieldPath<crate::api::core::v1::PodSecurityContext> {
+        self.child("securityContext")
+    }
+
+    /// The path of the `serviceAccount` field.
+    pub fn service_account(self) -> crate::FieldPath<std::string::String> {
+        self.child("serviceAccount")
+    }
+
+    /// The path of the `serviceAccountName` field.
+    pub fn service_account_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("serviceAccountName")
+    }
+
+    /// The path of the `setHostnameAsFQDN` field.
+    pub fn set_hostname_as_fqdn(self) -> crate::FieldPath<bool> {
+        self.child("setHostnameAsFQDN")
+    }
+
+    /// The path of the `shareProcessNamespace` field.
+    pub fn share_process_namespace(self) -> crate::FieldPath<bool> {
+        self.child("shareProcessNamespace")
+    }
+
+    /// The path of the `subdomain` field.
+    pub fn subdomain(self) -> crate::FieldPath<std::string::String> {
+        self.child("subdomain")
+    }
+
+    /// The path of the `terminationGracePeriodSeconds` field.
+    pub fn termination_grace_period_seconds(self) -> crate::FieldPath<i64> {
+        self.child("terminationGracePeriodSeconds")
+    }
+
+    /// The path of the `tolerations` field.
+    pub fn tolerations(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Toleration>> {
+        self.child("tolerations")
+    }
+
+    /// The path of the `topologySpreadConstraints` field.
+    pub fn topology_spread_constraints(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::TopologySpreadConstraint>> {
+        self.child("topologySpreadConstraints")
+    }
+
+    /// The path of the `volumes` field.
+    pub fn volumes(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Volume>> {
+        self.child("volumes")
+    }
+}
+
+impl crate::Normalize for PodSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.active_deadline_seconds);
+        crate::Normalize::normalize(&mut self.affinity);
+        crate::Normalize::normalize(&mut self.automount_service_account_token);
+        crate::normalize_strategies::list::map(&mut self.containers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.dns_config);
+        crate::Normalize::normalize(&mut self.dns_policy);
+        crate::Normalize::normalize(&mut self.enable_service_links);
+        crate::normalize_strategies::list::map(&mut self.ephemeral_containers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.host_aliases, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.host_ipc);
+        crate::Normalize::normalize(&mut self.host_network);
+        crate::Normalize::normalize(&mut self.host_pid);
+        crate::Normalize::normalize(&mut self.host_users);
+        crate::Normalize::normalize(&mut self.hostname);
+        crate::Normalize::normalize(&mut self.hostname_override);
+        crate::normalize_strategies::list::map(&mut self.image_pull_secrets, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.init_containers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::normalize_strategies::map::values(&mut self.node_selector, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.os);
+        crate::normalize_strategies::map::values(&mut self.overhead, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.preemption_policy);
+        crate::Normalize::normalize(&mut self.priority);
+        crate::Normalize::normalize(&mut self.priority_class_name);
+        crate::normalize_strategies::list::atomic(&mut self.readiness_gates, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.resource_claims, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.resources);
+        crate::Normalize::normalize(&mut self.restart_policy);
+        crate::Normalize::normalize(&mut self.runtime_class_name);
+        crate::Normalize::normalize(&mut self.scheduler_name);
+        crate::normalize_strategies::list::map(&mut self.scheduling_gates, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.security_context);
+        crate::Normalize::normalize(&mut self.service_account);
+        crate::Normalize::normalize(&mut self.service_account_name);
+        crate::Normalize::normalize(&mut self.set_hostname_as_fqdn);
+        crate::Normalize::normalize(&mut self.share_process_namespace);
+        crate::Normalize::normalize(&mut self.subdomain);
+        crate::Normalize::normalize(&mut self.termination_grace_period_seconds);
+        crate::normalize_strategies::list::atomic(&mut self.tolerations, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.topology_spread_constraints, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.volumes, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -731,6 +1237,292 @@ impl crate::serde::Serialize for PodSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.volumes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.containers, 2, buf);
+        if let Some(value) = &self.restart_policy {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.termination_grace_period_seconds {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.active_deadline_seconds {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.dns_policy {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.node_selector {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.service_account_name {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.service_account {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.node_name {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.host_network {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.host_pid {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.host_ipc {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.security_context {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.image_pull_secrets {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+        if let Some(value) = &self.hostname {
+            crate::protobuf::Field::encode_field(value, 16, buf);
+        }
+        if let Some(value) = &self.subdomain {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+        if let Some(value) = &self.affinity {
+            crate::protobuf::Field::encode_field(value, 18, buf);
+        }
+        if let Some(value) = &self.scheduler_name {
+            crate::protobuf::Field::encode_field(value, 19, buf);
+        }
+        if let Some(value) = &self.init_containers {
+            crate::protobuf::Field::encode_field(value, 20, buf);
+        }
+        if let Some(value) = &self.automount_service_account_token {
+            crate::protobuf::Field::encode_field(value, 21, buf);
+        }
+        if let Some(value) = &self.tolerations {
+            crate::protobuf::Field::encode_field(value, 22, buf);
+        }
+        if let Some(value) = &self.host_aliases {
+            crate::protobuf::Field::encode_field(value, 23, buf);
+        }
+        if let Some(value) = &self.priority_class_name {
+            crate::protobuf::Field::encode_field(value, 24, buf);
+        }
+        if let Some(value) = &self.priority {
+            crate::protobuf::Field::encode_field(value, 25, buf);
+        }
+        if let Some(value) = &self.dns_config {
+            crate::protobuf::Field::encode_field(value, 26, buf);
+        }
+        if let Some(value) = &self.share_process_namespace {
+            crate::protobuf::Field::encode_field(value, 27, buf);
+        }
+        if let Some(value) = &self.readiness_gates {
+            crate::protobuf::Field::encode_field(value, 28, buf);
+        }
+        if let Some(value) = &self.runtime_class_name {
+            crate::protobuf::Field::encode_field(value, 29, buf);
+        }
+        if let Some(value) = &self.enable_service_links {
+            crate::protobuf::Field::encode_field(value, 30, buf);
+        }
+        if let Some(value) = &self.preemption_policy {
+            crate::protobuf::Field::encode_field(value, 31, buf);
+        }
+        if let Some(value) = &self.overhead {
+            crate::protobuf::Field::encode_field(value, 32, buf);
+        }
+        if let Some(value) = &self.topology_spread_constraints {
+            crate::protobuf::Field::encode_field(value, 33, buf);
+        }
+        if let Some(value) = &self.ephemeral_containers {
+            crate::protobuf::Field::encode_field(value, 34, buf);
+        }
+        if let Some(value) = &self.set_hostname_as_fqdn {
+            crate::protobuf::Field::encode_field(value, 35, buf);
+        }
+        if let Some(value) = &self.os {
+            crate::protobuf::Field::encode_field(value, 36, buf);
+        }
+        if let Some(value) = &self.host_users {
+            crate::protobuf::Field::encode_field(value, 37, buf);
+        }
+        if let Some(value) = &self.scheduling_gates {
+            crate::protobuf::Field::encode_field(value, 38, buf);
+        }
+        if let Some(value) = &self.resource_claims {
+            crate::protobuf::Field::encode_field(value, 39, buf);
+        }
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 40, buf);
+        }
+        if let Some(value) = &self.hostname_override {
+            crate::protobuf::Field::encode_field(value, 41, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_active_deadline_seconds: Option<i64> = None;
+        let mut value_affinity: Option<crate::api::core::v1::Affinity> = None;
+        let mut value_automount_service_account_token: Option<bool> = None;
+        let mut value_containers: Option<std::vec::Vec<crate::api::core::v1::Container>> = None;
+        let mut value_dns_config: Option<crate::api::core::v1::PodDNSConfig> = None;
+        let mut value_dns_policy: Option<std::string::String> = None;
+        let mut value_enable_service_links: Option<bool> = None;
+        let mut value_ephemeral_containers: Option<std::vec::Vec<crate::api::core::v1::EphemeralContainer>> = None;
+        let mut value_host_aliases: Option<std::vec::Vec<crate::api::core::v1::HostAlias>> = None;
+        let mut value_host_ipc: Option<bool> = None;
+        let mut value_host_network: Option<bool> = None;
+        let mut value_host_pid: Option<bool> = None;
+        let mut value_host_users: Option<bool> = None;
+        let mut value_hostname: Option<std::string::String> = None;
+        let mut value_hostname_override: Option<std::string::String> = None;
+        let mut value_image_pull_secrets: Option<std::vec::Vec<crate::api::core::v1::LocalObjectReference>> = None;
+        let mut value_init_containers: Option<std::vec::Vec<crate::api::core::v1::Container>> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_node_selector: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_os: Option<crate::api::core::v1::PodOS> = None;
+        let mut value_overhead: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_preemption_policy: Option<std::string::String> = None;
+        let mut value_priority: Option<i32> = None;
+        let mut value_priority_class_name: Option<std::string::String> = None;
+        let mut value_readiness_gates: Option<std::vec::Vec<crate::api::core::v1::PodReadinessGate>> = None;
+        let mut value_resource_claims: Option<std::vec::Vec<crate::api::core::v1::PodResourceClaim>> = None;
+        let mut value_resources: Option<crate::api::core::v1::ResourceRequirements> = None;
+        let mut value_restart_policy: Option<std::string::String> = None;
+        let mut value_runtime_class_name: Option<std::string::String> = None;
+        let mut value_scheduler_name: Option<std::string::String> = None;
+        let mut value_scheduling_gates: Option<std::vec::Vec<crate::api::core::v1::PodSchedulingGate>> = None;
+        let mut value_security_context: Option<crate::api::core::v1::PodSecurityContext> = None;
+        let mut value_service_account: Option<std::string::String> = None;
+        let mut value_service_account_name: Option<std::string::String> = None;
+        let mut value_set_hostname_as_fqdn: Option<bool> = None;
+        let mut value_share_process_namespace: Option<bool> = None;
+        let mut value_subdomain: Option<std::string::String> = None;
+        let mut value_termination_grace_period_seconds: Option<i64> = None;
+        let mut value_tolerations: Option<std::vec::Vec<crate::api::core::v1::Toleration>> = None;
+        let mut value_topology_spread_constraints: Option<std::vec::Vec<crate::api::core::v1::TopologySpreadConstraint>> = None;
+        let mut value_volumes: Option<std::vec::Vec<crate::api::core::v1::Volume>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_volumes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_containers, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_restart_policy, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_termination_grace_period_seconds, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_active_deadline_seconds, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_dns_policy, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_node_selector, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_service_account_name, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_service_account, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_host_network, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_host_pid, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_host_ipc, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_security_context, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_image_pull_secrets, wire_type, &mut buf)?,
+                16 => crate::protobuf::Field::merge_field(&mut value_hostname, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_subdomain, wire_type, &mut buf)?,
+                18 => crate::protobuf::Field::merge_field(&mut value_affinity, wire_type, &mut buf)?,
+                19 => crate::protobuf::Field::merge_field(&mut value_scheduler_name, wire_type, &mut buf)?,
+                20 => crate::protobuf::Field::merge_field(&mut value_init_containers, wire_type, &mut buf)?,
+                21 => crate::protobuf::Field::merge_field(&mut value_automount_service_account_token, wire_type, &mut buf)?,
+                22 => crate::protobuf::Field::merge_field(&mut value_tolerations, wire_type, &mut buf)?,
+                23 => crate::protobuf::Field::merge_field(&mut value_host_aliases, wire_type, &mut buf)?,
+                24 => crate::protobuf::Field::merge_field(&mut value_priority_class_name, wire_type, &mut buf)?,
+                25 => crate::protobuf::Field::merge_field(&mut value_priority, wire_type, &mut buf)?,
+                26 => crate::protobuf::Field::merge_field(&mut value_dns_config, wire_type, &mut buf)?,
+                27 => crate::protobuf::Field::merge_field(&mut value_share_process_namespace, wire_type, &mut buf)?,
+                28 => crate::protobuf::Field::merge_field(&mut value_readiness_gates, wire_type, &mut buf)?,
+                29 => crate::protobuf::Field::merge_field(&mut value_runtime_class_name, wire_type, &mut buf)?,
+                30 => crate::protobuf::Field::merge_field(&mut value_enable_service_links, wire_type, &mut buf)?,
+                31 => crate::protobuf::Field::merge_field(&mut value_preemption_policy, wire_type, &mut buf)?,
+                32 => crate::protobuf::Field::merge_field(&mut value_overhead, wire_type, &mut buf)?,
+                33 => crate::protobuf::Field::merge_field(&mut value_topology_spread_constraints, wire_type, &mut buf)?,
+                34 => crate::protobuf::Field::merge_field(&mut value_ephemeral_containers, wire_type, &mut buf)?,
+                35 => crate::protobuf::Field::merge_field(&mut value_set_hostname_as_fqdn, wire_type, &mut buf)?,
+                36 => crate::protobuf::Field::merge_field(&mut value_os, wire_type, &mut buf)?,
+                37 => crate::protobuf::Field::merge_field(&mut value_host_users, wire_type, &mut buf)?,
+                38 => crate::protobuf::Field::merge_field(&mut value_scheduling_gates, wire_type, &mut buf)?,
+                39 => crate::protobuf::Field::merge_field(&mut value_resource_claims, wire_type, &mut buf)?,
+                40 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                41 => crate::protobuf::Field::merge_field(&mut value_hostname_override, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodSpec {
+            active_deadline_seconds: value_active_deadline_seconds,
+            affinity: value_affinity,
+            automount_service_account_token: value_automount_service_account_token,
+            containers: value_containers.unwrap_or_default(),
+            dns_config: value_dns_config,
+            dns_policy: value_dns_policy,
+            enable_service_links: value_enable_service_links,
+            ephemeral_containers: value_ephemeral_containers,
+            host_aliases: value_host_aliases,
+            host_ipc: value_host_ipc,
+            host_network: value_host_network,
+            host_pid: value_host_pid,
+            host_users: value_host_users,
+            hostname: value_hostname,
+            hostname_override: value_hostname_override,
+            image_pull_secrets: value_image_pull_secrets,
+            init_containers: value_init_containers,
+            node_name: value_node_name,
+            node_selector: value_node_selector,
+            os: value_os,
+            overhead: value_overhead,
+            preemption_policy: value_preemption_policy,
+            priority: value_priority,
+            priority_class_name: value_priority_class_name,
+            readiness_gates: value_readiness_gates,
+            resource_claims: value_resource_claims,
+            resources: value_resources,
+            restart_policy: value_restart_policy,
+            runtime_class_name: value_runtime_class_name,
+            scheduler_name: value_scheduler_name,
+            scheduling_gates: value_scheduling_gates,
+            security_context: value_security_context,
+            service_account: value_service_account,
+            service_account_name: value_service_account_name,
+            set_hostname_as_fqdn: value_set_hostname_as_fqdn,
+            share_process_namespace: value_share_process_namespace,
+            subdomain: value_subdomain,
+            termination_grace_period_seconds: value_termination_grace_period_seconds,
+            tolerations: value_tolerations,
+            topology_spread_constraints: value_topology_spread_constraints,
+            volumes: value_volumes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "containers" | "ephemeralContainers" | "imagePullSecrets" | "initContainers" | "resourceClaims" | "schedulingGates" | "volumes" => Some(&["name"]),
+                "hostAliases" => Some(&["ip"]),
+                "topologySpreadConstraints" => Some(&["topologyKey"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "containers" => <std::vec::Vec<crate::api::core::v1::Container> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "ephemeralContainers" => <Option<std::vec::Vec<crate::api::core::v1::EphemeralContainer>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "initContainers" => <Option<std::vec::Vec<crate::api::core::v1::Container>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "resources" => <Option<crate::api::core::v1::ResourceRequirements> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "volumes" => <Option<std::vec::Vec<crate::api::core::v1::Volume>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
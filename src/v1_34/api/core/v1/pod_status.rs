@@ -113,6 +113,248 @@ impl crate::DeepMerge for PodStatus {
     }
 }
 
+impl crate::Defaulting for PodStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.container_statuses);
+        crate::Defaulting::set_defaults(&mut self.ephemeral_container_statuses);
+        crate::Defaulting::set_defaults(&mut self.extended_resource_claim_status);
+        crate::Defaulting::set_defaults(&mut self.host_ip);
+        crate::Defaulting::set_defaults(&mut self.host_ips);
+        crate::Defaulting::set_defaults(&mut self.init_container_statuses);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.nominated_node_name);
+        crate::Defaulting::set_defaults(&mut self.observed_generation);
+        crate::Defaulting::set_defaults(&mut self.phase);
+        crate::Defaulting::set_defaults(&mut self.pod_ip);
+        crate::Defaulting::set_defaults(&mut self.pod_ips);
+        crate::Defaulting::set_defaults(&mut self.qos_class);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.resize);
+        crate::Defaulting::set_defaults(&mut self.resource_claim_statuses);
+        crate::Defaulting::set_defaults(&mut self.start_time);
+    }
+}
+
+impl crate::diff::Diff for PodStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.container_statuses,
+            &other.container_statuses,
+            &crate::diff::Path::Field { parent: path, name: "containerStatuses" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.ephemeral_container_statuses,
+            &other.ephemeral_container_statuses,
+            &crate::diff::Path::Field { parent: path, name: "ephemeralContainerStatuses" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.extended_resource_claim_status, &other.extended_resource_claim_status, &crate::diff::Path::Field { parent: path, name: "extendedResourceClaimStatus" }, changes);
+        crate::diff::Diff::diff(&self.host_ip, &other.host_ip, &crate::diff::Path::Field { parent: path, name: "hostIP" }, changes);
+        crate::diff::strategies::list::map(
+            &self.host_ips,
+            &other.host_ips,
+            &crate::diff::Path::Field { parent: path, name: "hostIPs" },
+            changes,
+            &[("ip", |item| crate::diff::MergeKey::merge_key(&item.ip))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.init_container_statuses,
+            &other.init_container_statuses,
+            &crate::diff::Path::Field { parent: path, name: "initContainerStatuses" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.nominated_node_name, &other.nominated_node_name, &crate::diff::Path::Field { parent: path, name: "nominatedNodeName" }, changes);
+        crate::diff::Diff::diff(&self.observed_generation, &other.observed_generation, &crate::diff::Path::Field { parent: path, name: "observedGeneration" }, changes);
+        crate::diff::Diff::diff(&self.phase, &other.phase, &crate::diff::Path::Field { parent: path, name: "phase" }, changes);
+        crate::diff::Diff::diff(&self.pod_ip, &other.pod_ip, &crate::diff::Path::Field { parent: path, name: "podIP" }, changes);
+        crate::diff::strategies::list::map(
+            &self.pod_ips,
+            &other.pod_ips,
+            &crate::diff::Path::Field { parent: path, name: "podIPs" },
+            changes,
+            &[("ip", |item| crate::diff::MergeKey::merge_key(&item.ip))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.qos_class, &other.qos_class, &crate::diff::Path::Field { parent: path, name: "qosClass" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.resize, &other.resize, &crate::diff::Path::Field { parent: path, name: "resize" }, changes);
+        crate::diff::strategies::list::map(
+            &self.resource_claim_statuses,
+            &other.resource_claim_statuses,
+            &crate::diff::Path::Field { parent: path, name: "resourceClaimStatuses" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.start_time, &other.start_time, &crate::diff::Path::Field { parent: path, name: "startTime" }, changes);
+    }
+}
+
+impl PodStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodStatus> {
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `containerStatuses` field.
+    pub fn container_statuses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerStatus>> {
+        self.child("containerStatuses")
+    }
+
+    /// The path of the `ephemeralContainerStatuses` field.
+    pub fn ephemeral_container_statuses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerStatus>> {
+        self.child("ephemeralContainerStatuses")
+    }
+
+    /// The path of the `extendedResourceClaimStatus` field.
+    pub fn extended_resource_claim_status(self) -> crate::FieldPath<crate::api::core::v1::PodExtendedResourceClaimStatus> {
+        self.child("extendedResourceClaimStatus")
+    }
+
+    /// The path of the `hostIP` field.
+    pub fn host_ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostIP")
+    }
+
+    /// The path of the `hostIPs` field.
+    pub fn host_ips(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::HostIP>> {
+        self.child("hostIPs")
+    }
+
+    /// The path of the `initContainerStatuses` field.
+    pub fn init_container_statuses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerStatus>> {
+        self.child("initContainerStatuses")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `nominatedNodeName` field.
+    pub fn nominated_node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nominatedNodeName")
+    }
+
+    /// The path of the `observedGeneration` field.
+    pub fn observed_generation(self) -> crate::FieldPath<i64> {
+        self.child("observedGeneration")
+    }
+
+    /// The path of the `phase` field.
+    pub fn phase(self) -> crate::FieldPath<std::string::String> {
+        self.child("phase")
+    }
+
+    /// The path of the `podIP` field.
+    pub fn pod_ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("podIP")
+    }
+
+    /// The path of the `podIPs` field.
+    pub fn pod_ips(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodIP>> {
+        self.child("podIPs")
+    }
+
+    /// The path of the `qosClass` field.
+    pub fn qos_class(self) -> crate::FieldPath<std::string::String> {
+        self.child("qosClass")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `resize` field.
+    pub fn resize(self) -> crate::FieldPath<std::string::String> {
+        self.child("resize")
+    }
+
+    /// The path of the `resourceClaimStatuses` field.
+    pub fn resource_claim_statuses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::PodResourceClaimStatus>> {
+        self.child("resourceClaimStatuses")
+    }
+
+    /// The path of the `startTime` field.
+    pub fn start_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("startTime")
+    }
+}
+
+impl crate::Normalize for PodStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.container_statuses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.ephemeral_container_statuses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.extended_resource_claim_status);
+        crate::Normalize::normalize(&mut self.host_ip);
+        crate::normalize_strategies::list::map(&mut self.host_ips, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.init_container_statuses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.nominated_node_name);
+        crate::Normalize::normalize(&mut self.observed_generation);
+        crate::Normalize::normalize(&mut self.phase);
+        crate::Normalize::normalize(&mut self.pod_ip);
+        crate::normalize_strategies::list::map(&mut self.pod_ips, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.qos_class);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.resize);
+        crate::normalize_strategies::list::map(&mut self.resource_claim_statuses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.start_time);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -362,6 +604,152 @@ impl crate::serde::Serialize for PodStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.phase {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.host_ip {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.pod_ip {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.start_time {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.container_statuses {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.qos_class {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.init_container_statuses {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.nominated_node_name {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.pod_ips {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.ephemeral_container_statuses {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.resize {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.resource_claim_statuses {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+        if let Some(value) = &self.host_ips {
+            crate::protobuf::Field::encode_field(value, 16, buf);
+        }
+        if let Some(value) = &self.observed_generation {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+        if let Some(value) = &self.extended_resource_claim_status {
+            crate::protobuf::Field::encode_field(value, 18, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conditions: Option<std::vec::Vec<crate::api::core::v1::PodCondition>> = None;
+        let mut value_container_statuses: Option<std::vec::Vec<crate::api::core::v1::ContainerStatus>> = None;
+        let mut value_ephemeral_container_statuses: Option<std::vec::Vec<crate::api::core::v1::ContainerStatus>> = None;
+        let mut value_extended_resource_claim_status: Option<crate::api::core::v1::PodExtendedResourceClaimStatus> = None;
+        let mut value_host_ip: Option<std::string::String> = None;
+        let mut value_host_ips: Option<std::vec::Vec<crate::api::core::v1::HostIP>> = None;
+        let mut value_init_container_statuses: Option<std::vec::Vec<crate::api::core::v1::ContainerStatus>> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_nominated_node_name: Option<std::string::String> = None;
+        let mut value_observed_generation: Option<i64> = None;
+        let mut value_phase: Option<std::string::String> = None;
+        let mut value_pod_ip: Option<std::string::String> = None;
+        let mut value_pod_ips: Option<std::vec::Vec<crate::api::core::v1::PodIP>> = None;
+        let mut value_qos_class: Option<std::string::String> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_resize: Option<std::string::String> = None;
+        let mut value_resource_claim_statuses: Option<std::vec::Vec<crate::api::core::v1::PodResourceClaimStatus>> = None;
+        let mut value_start_time: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_phase, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_host_ip, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_pod_ip, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_start_time, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_container_statuses, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_qos_class, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_init_container_statuses, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_nominated_node_name, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_pod_ips, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_ephemeral_container_statuses, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_resize, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_resource_claim_statuses, wire_type, &mut buf)?,
+                16 => crate::protobuf::Field::merge_field(&mut value_host_ips, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_observed_generation, wire_type, &mut buf)?,
+                18 => crate::protobuf::Field::merge_field(&mut value_extended_resource_claim_status, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodStatus {
+            conditions: value_conditions,
+            container_statuses: value_container_statuses,
+            ephemeral_container_statuses: value_ephemeral_container_statuses,
+            extended_resource_claim_status: value_extended_resource_claim_status,
+            host_ip: value_host_ip,
+            host_ips: value_host_ips,
+            init_container_statuses: value_init_container_statuses,
+            message: value_message,
+            nominated_node_name: value_nominated_node_name,
+            observed_generation: value_observed_generation,
+            phase: value_phase,
+            pod_ip: value_pod_ip,
+            pod_ips: value_pod_ips,
+            qos_class: value_qos_class,
+            reason: value_reason,
+            resize: value_resize,
+            resource_claim_statuses: value_resource_claim_statuses,
+            start_time: value_start_time,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                "hostIPs" | "podIPs" => Some(&["ip"]),
+                "resourceClaimStatuses" => Some(&["name"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "containerStatuses" | "ephemeralContainerStatuses" | "initContainerStatuses" => <Option<std::vec::Vec<crate::api::core::v1::ContainerStatus>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -49,6 +49,221 @@ impl crate::DeepMerge for Probe {
     }
 }
 
+impl crate::Defaulting for Probe {
+    fn set_defaults(&mut self) {
+        if self.failure_threshold.is_none() {
+            self.failure_threshold = Some(3);
+        }
+        if self.period_seconds.is_none() {
+            self.period_seconds = Some(10);
+        }
+        if self.success_threshold.is_none() {
+            self.success_threshold = Some(1);
+        }
+        if self.timeout_seconds.is_none() {
+            self.timeout_seconds = Some(1);
+        }
+        crate::Defaulting::set_defaults(&mut self.exec);
+        crate::Defaulting::set_defaults(&mut self.failure_threshold);
+        crate::Defaulting::set_defaults(&mut self.grpc);
+        crate::Defaulting::set_defaults(&mut self.http_get);
+        crate::Defaulting::set_defaults(&mut self.initial_delay_seconds);
+        crate::Defaulting::set_defaults(&mut self.period_seconds);
+        crate::Defaulting::set_defaults(&mut self.success_threshold);
+        crate::Defaulting::set_defaults(&mut self.tcp_socket);
+        crate::Defaulting::set_defaults(&mut self.termination_grace_period_seconds);
+        crate::Defaulting::set_defaults(&mut self.timeout_seconds);
+    }
+}
+
+impl crate::diff::Diff for Probe {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.exec, &other.exec, &crate::diff::Path::Field { parent: path, name: "exec" }, changes);
+        crate::diff::Diff::diff(&self.failure_threshold, &other.failure_threshold, &crate::diff::Path::Field { parent: path, name: "failureThreshold" }, changes);
+        crate::diff::Diff::diff(&self.grpc, &other.grpc, &crate::diff::Path::Field { parent: path, name: "grpc" }, changes);
+        crate::diff::Diff::diff(&self.http_get, &other.http_get, &crate::diff::Path::Field { parent: path, name: "httpGet" }, changes);
+        crate::diff::Diff::diff(&self.initial_delay_seconds, &other.initial_delay_seconds, &crate::diff::Path::Field { parent: path, name: "initialDelaySeconds" }, changes);
+        crate::diff::Diff::diff(&self.period_seconds, &other.period_seconds, &crate::diff::Path::Field { parent: path, name: "periodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.success_threshold, &other.success_threshold, &crate::diff::Path::Field { parent: path, name: "successThreshold" }, changes);
+        crate::diff::Diff::diff(&self.tcp_socket, &other.tcp_socket, &crate::diff::Path::Field { parent: path, name: "tcpSocket" }, changes);
+        crate::diff::Diff::diff(&self.termination_grace_period_seconds, &other.termination_grace_period_seconds, &crate::diff::Path::Field { parent: path, name: "terminationGracePeriodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.timeout_seconds, &other.timeout_seconds, &crate::diff::Path::Field { parent: path, name: "timeoutSeconds" }, changes);
+    }
+}
+
+impl Probe {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Probe> {
+    /// The path of the `exec` field.
+    pub fn exec(self) -> crate::FieldPath<crate::api::core::v1::ExecAction> {
+        self.child("exec")
+    }
+
+    /// The path of the `failureThreshold` field.
+    pub fn failure_threshold(self) -> crate::FieldPath<i32> {
+        self.child("failureThreshold")
+    }
+
+    /// The path of the `grpc` field.
+    pub fn grpc(self) -> crate::FieldPath<crate::api::core::v1::GRPCAction> {
+        self.child("grpc")
+    }
+
+    /// The path of the `httpGet` field.
+    pub fn http_get(self) -> crate::FieldPath<crate::api::core::v1::HTTPGetAction> {
+        self.child("httpGet")
+    }
+
+    /// The path of the `initialDelaySeconds` field.
+    pub fn initial_delay_seconds(self) -> crate::FieldPath<i32> {
+        self.child("initialDelaySeconds")
+    }
+
+    /// The path of the `periodSeconds` field.
+    pub fn period_seconds(self) -> crate::FieldPath<i32> {
+        self.child("periodSeconds")
+    }
+
+    /// The path of the `successThreshold` field.
+    pub fn success_threshold(self) -> crate::FieldPath<i32> {
+        self.child("successThreshold")
+    }
+
+    /// The path of the `tcpSocket` field.
+    pub fn tcp_socket(self) -> crate::FieldPath<crate::api::core::v1::TCPSocketAction> {
+        self.child("tcpSocket")
+    }
+
+    /// The path of the `terminationGracePeriodSeconds` field.
+    pub fn termination_grace_period_seconds(self) -> crate::FieldPath<i64> {
+        self.child("terminationGracePeriodSeconds")
+    }
+
+    /// The path of the `timeoutSeconds` field.
+    pub fn timeout_seconds(self) -> crate::FieldPath<i32> {
+        self.child("timeoutSeconds")
+    }
+}
+
+impl crate::Normalize for Probe {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.exec);
+        crate::Normalize::normalize(&mut self.failure_threshold);
+        crate::Normalize::normalize(&mut self.grpc);
+        crate::Normalize::normalize(&mut self.http_get);
+        crate::Normalize::normalize(&mut self.initial_delay_seconds);
+        crate::Normalize::normalize(&mut self.period_seconds);
+        crate::Normalize::normalize(&mut self.success_threshold);
+        crate::Normalize::normalize(&mut self.tcp_socket);
+        crate::Normalize::normalize(&mut self.termination_grace_period_seconds);
+        crate::Normalize::normalize(&mut self.timeout_seconds);
+    }
+}
+
+/// The members of the [`Probe`] union. At most one of them may be set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProbeUnion {
+    /// The `exec` member.
+    Exec(crate::api::core::v1::ExecAction),
+
+    /// The `grpc` member.
+    Grpc(crate::api::core::v1::GRPCAction),
+
+    /// The `httpGet` member.
+    HttpGet(crate::api::core::v1::HTTPGetAction),
+
+    /// The `tcpSocket` member.
+    TcpSocket(crate::api::core::v1::TCPSocketAction),
+}
+
+/// A reference to the member of the [`Probe`] union that is set, as returned by [`Probe::union`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProbeUnionRef<'a> {
+    /// The `exec` member.
+    Exec(&'a crate::api::core::v1::ExecAction),
+
+    /// The `grpc` member.
+    Grpc(&'a crate::api::core::v1::GRPCAction),
+
+    /// The `httpGet` member.
+    HttpGet(&'a crate::api::core::v1::HTTPGetAction),
+
+    /// The `tcpSocket` member.
+    TcpSocket(&'a crate::api::core::v1::TCPSocketAction),
+}
+
+impl Probe {
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set.
+    pub fn union(&self) -> Result<Option<ProbeUnionRef<'_>>, crate::UnionError> {
+        let mut members = std::vec![];
+        let mut result = None;
+        if let Some(value) = &self.exec {
+            members.push("exec");
+            result = Some(ProbeUnionRef::Exec(value));
+        }
+        if let Some(value) = &self.grpc {
+            members.push("grpc");
+            result = Some(ProbeUnionRef::Grpc(value));
+        }
+        if let Some(value) = &self.http_get {
+            members.push("httpGet");
+            result = Some(ProbeUnionRef::HttpGet(value));
+        }
+        if let Some(value) = &self.tcp_socket {
+            members.push("tcpSocket");
+            result = Some(ProbeUnionRef::TcpSocket(value));
+        }
+
+        if members.len() > 1 {
+            return Err(crate::UnionError::MultipleMembersSet(members));
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the given member of the union, and unsets all the others.
+    pub fn set_union(&mut self, value: Option<ProbeUnion>) {
+        self.exec = None;
+        self.grpc = None;
+        self.http_get = None;
+        self.tcp_socket = None;
+
+        match value {
+            Some(ProbeUnion::Exec(value)) => self.exec = Some(value),
+            Some(ProbeUnion::Grpc(value)) => self.grpc = Some(value),
+            Some(ProbeUnion::HttpGet(value)) => self.http_get = Some(value),
+            Some(ProbeUnion::TcpSocket(value)) => self.tcp_socket = Some(value),
+            None => (),
+        }
+    }
+}
+
+impl From<ProbeUnion> for Probe {
+    fn from(value: ProbeUnion) -> Self {
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }
+}
+
+impl From<ProbeUnionRef<'_>> for ProbeUnion {
+    fn from(value: ProbeUnionRef<'_>) -> Self {
+        match value {
+            ProbeUnionRef::Exec(value) => ProbeUnion::Exec(value.clone()),
+            ProbeUnionRef::Grpc(value) => ProbeUnion::Grpc(value.clone()),
+            ProbeUnionRef::HttpGet(value) => ProbeUnion::HttpGet(value.clone()),
+            ProbeUnionRef::TcpSocket(value) => ProbeUnion::TcpSocket(value.clone()),
+        }
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Probe {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -218,6 +433,101 @@ impl crate::serde::Serialize for Probe {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Probe {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.exec {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            if let Some(value) = &self.http_get {
+                crate::protobuf::Field::encode_field(value, 2, &mut embedded);
+            }
+            if let Some(value) = &self.tcp_socket {
+                crate::protobuf::Field::encode_field(value, 3, &mut embedded);
+            }
+            if let Some(value) = &self.grpc {
+                crate::protobuf::Field::encode_field(value, 4, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(1, &embedded, buf);
+        }
+        if let Some(value) = &self.initial_delay_seconds {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.timeout_seconds {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.period_seconds {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.success_threshold {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.failure_threshold {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.termination_grace_period_seconds {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_exec: Option<crate::api::core::v1::ExecAction> = None;
+        let mut value_failure_threshold: Option<i32> = None;
+        let mut value_grpc: Option<crate::api::core::v1::GRPCAction> = None;
+        let mut value_http_get: Option<crate::api::core::v1::HTTPGetAction> = None;
+        let mut value_initial_delay_seconds: Option<i32> = None;
+        let mut value_period_seconds: Option<i32> = None;
+        let mut value_success_threshold: Option<i32> = None;
+        let mut value_tcp_socket: Option<crate::api::core::v1::TCPSocketAction> = None;
+        let mut value_termination_grace_period_seconds: Option<i64> = None;
+        let mut value_timeout_seconds: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_exec, wire_type, &mut embedded)?,
+                            2 => crate::protobuf::Field::merge_field(&mut value_http_get, wire_type, &mut embedded)?,
+                            3 => crate::protobuf::Field::merge_field(&mut value_tcp_socket, wire_type, &mut embedded)?,
+                            4 => crate::protobuf::Field::merge_field(&mut value_grpc, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                2 => crate::protobuf::Field::merge_field(&mut value_initial_delay_seconds, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_timeout_seconds, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_period_seconds, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_success_threshold, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_failure_threshold, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_termination_grace_period_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Probe {
+            exec: value_exec,
+            failure_threshold: value_failure_threshold,
+            grpc: value_grpc,
+            http_get: value_http_get,
+            initial_delay_seconds: value_initial_delay_seconds,
+            period_seconds: value_period_seconds,
+            success_threshold: value_success_threshold,
+            tcp_socket: value_tcp_socket,
+            termination_grace_period_seconds: value_termination_grace_period_seconds,
+            timeout_seconds: value_timeout_seconds,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Probe {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Probe {
     fn schema_name() -> std::borrow::Cow<'static, str> {
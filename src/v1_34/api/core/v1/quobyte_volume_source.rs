@@ -33,6 +33,78 @@ impl crate::DeepMerge for QuobyteVolumeSource {
     }
 }
 
+impl crate::Defaulting for QuobyteVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.registry);
+        crate::Defaulting::set_defaults(&mut self.tenant);
+        crate::Defaulting::set_defaults(&mut self.user);
+        crate::Defaulting::set_defaults(&mut self.volume);
+    }
+}
+
+impl crate::diff::Diff for QuobyteVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.registry, &other.registry, &crate::diff::Path::Field { parent: path, name: "registry" }, changes);
+        crate::diff::Diff::diff(&self.tenant, &other.tenant, &crate::diff::Path::Field { parent: path, name: "tenant" }, changes);
+        crate::diff::Diff::diff(&self.user, &other.user, &crate::diff::Path::Field { parent: path, name: "user" }, changes);
+        crate::diff::Diff::diff(&self.volume, &other.volume, &crate::diff::Path::Field { parent: path, name: "volume" }, changes);
+    }
+}
+
+impl QuobyteVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<QuobyteVolumeSource> {
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `registry` field.
+    pub fn registry(self) -> crate::FieldPath<std::string::String> {
+        self.child("registry")
+    }
+
+    /// The path of the `tenant` field.
+    pub fn tenant(self) -> crate::FieldPath<std::string::String> {
+        self.child("tenant")
+    }
+
+    /// The path of the `user` field.
+    pub fn user(self) -> crate::FieldPath<std::string::String> {
+        self.child("user")
+    }
+
+    /// The path of the `volume` field.
+    pub fn volume(self) -> crate::FieldPath<std::string::String> {
+        self.child("volume")
+    }
+}
+
+impl crate::Normalize for QuobyteVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.registry);
+        crate::Normalize::normalize(&mut self.tenant);
+        crate::Normalize::normalize(&mut self.user);
+        crate::Normalize::normalize(&mut self.volume);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for QuobyteVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -157,6 +229,60 @@ impl crate::serde::Serialize for QuobyteVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for QuobyteVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.registry, 1, buf);
+        crate::protobuf::Field::encode_field(&self.volume, 2, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.user {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.group {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.tenant {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_registry: Option<std::string::String> = None;
+        let mut value_tenant: Option<std::string::String> = None;
+        let mut value_user: Option<std::string::String> = None;
+        let mut value_volume: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_registry, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_volume, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_user, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_tenant, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(QuobyteVolumeSource {
+            group: value_group,
+            read_only: value_read_only,
+            registry: value_registry.unwrap_or_default(),
+            tenant: value_tenant,
+            user: value_user,
+            volume: value_volume.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for QuobyteVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for QuobyteVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,75 @@ impl crate::DeepMerge for ReplicationControllerSpec {
     }
 }
 
+impl crate::Defaulting for ReplicationControllerSpec {
+    fn set_defaults(&mut self) {
+        if self.replicas.is_none() {
+            self.replicas = Some(1);
+        }
+        crate::Defaulting::set_defaults(&mut self.min_ready_seconds);
+        crate::Defaulting::set_defaults(&mut self.replicas);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.template);
+    }
+}
+
+impl crate::diff::Diff for ReplicationControllerSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.min_ready_seconds, &other.min_ready_seconds, &crate::diff::Path::Field { parent: path, name: "minReadySeconds" }, changes);
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+        crate::diff::strategies::map::values(
+            &self.selector,
+            &other.selector,
+            &crate::diff::Path::Field { parent: path, name: "selector" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.template, &other.template, &crate::diff::Path::Field { parent: path, name: "template" }, changes);
+    }
+}
+
+impl ReplicationControllerSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ReplicationControllerSpec> {
+    /// The path of the `minReadySeconds` field.
+    pub fn min_ready_seconds(self) -> crate::FieldPath<i32> {
+        self.child("minReadySeconds")
+    }
+
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("selector")
+    }
+
+    /// The path of the `template` field.
+    pub fn template(self) -> crate::FieldPath<crate::api::core::v1::PodTemplateSpec> {
+        self.child("template")
+    }
+}
+
+impl crate::Normalize for ReplicationControllerSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.min_ready_seconds);
+        crate::Normalize::normalize(&mut self.replicas);
+        crate::normalize_strategies::map::values(&mut self.selector, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.template);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ReplicationControllerSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +203,62 @@ impl crate::serde::Serialize for ReplicationControllerSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ReplicationControllerSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.replicas {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.template {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.min_ready_seconds {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_min_ready_seconds: Option<i32> = None;
+        let mut value_replicas: Option<i32> = None;
+        let mut value_selector: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_template, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_min_ready_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ReplicationControllerSpec {
+            min_ready_seconds: value_min_ready_seconds,
+            replicas: value_replicas,
+            selector: value_selector,
+            template: value_template,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ReplicationControllerSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "template" => <Option<crate::api::core::v1::PodTemplateSpec> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ReplicationControllerSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
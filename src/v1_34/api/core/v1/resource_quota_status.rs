@@ -21,6 +21,66 @@ impl crate::DeepMerge for ResourceQuotaStatus {
     }
 }
 
+impl crate::Defaulting for ResourceQuotaStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hard);
+        crate::Defaulting::set_defaults(&mut self.used);
+    }
+}
+
+impl crate::diff::Diff for ResourceQuotaStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.hard,
+            &other.hard,
+            &crate::diff::Path::Field { parent: path, name: "hard" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.used,
+            &other.used,
+            &crate::diff::Path::Field { parent: path, name: "used" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceQuotaStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceQuotaStatus> {
+    /// The path of the `hard` field.
+    pub fn hard(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("hard")
+    }
+
+    /// The path of the `used` field.
+    pub fn used(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("used")
+    }
+}
+
+impl crate::Normalize for ResourceQuotaStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.hard, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.used, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -110,6 +170,40 @@ impl crate::serde::Serialize for ResourceQuotaStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceQuotaStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.hard {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.used {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hard: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_used: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_hard, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_used, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceQuotaStatus {
+            hard: value_hard,
+            used: value_used,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceQuotaStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceQuotaStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -57,6 +57,87 @@ impl crate::DeepMerge for ServiceAccount {
     }
 }
 
+impl crate::Defaulting for ServiceAccount {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.automount_service_account_token);
+        crate::Defaulting::set_defaults(&mut self.image_pull_secrets);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.secrets);
+    }
+}
+
+impl crate::diff::Diff for ServiceAccount {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.automount_service_account_token, &other.automount_service_account_token, &crate::diff::Path::Field { parent: path, name: "automountServiceAccountToken" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.image_pull_secrets,
+            &other.image_pull_secrets,
+            &crate::diff::Path::Field { parent: path, name: "imagePullSecrets" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::list::map(
+            &self.secrets,
+            &other.secrets,
+            &crate::diff::Path::Field { parent: path, name: "secrets" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ServiceAccount {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServiceAccount> {
+    /// The path of the `automountServiceAccountToken` field.
+    pub fn automount_service_account_token(self) -> crate::FieldPath<bool> {
+        self.child("automountServiceAccountToken")
+    }
+
+    /// The path of the `imagePullSecrets` field.
+    pub fn image_pull_secrets(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::LocalObjectReference>> {
+        self.child("imagePullSecrets")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `secrets` field.
+    pub fn secrets(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ObjectReference>> {
+        self.child("secrets")
+    }
+}
+
+impl crate::Normalize for ServiceAccount {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.automount_service_account_token);
+        crate::normalize_strategies::list::atomic(&mut self.image_pull_secrets, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::list::map(&mut self.secrets, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceAccount {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -184,6 +265,64 @@ impl crate::serde::Serialize for ServiceAccount {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServiceAccount {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.secrets {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.image_pull_secrets {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.automount_service_account_token {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_automount_service_account_token: Option<bool> = None;
+        let mut value_image_pull_secrets: Option<std::vec::Vec<crate::api::core::v1::LocalObjectReference>> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_secrets: Option<std::vec::Vec<crate::api::core::v1::ObjectReference>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_secrets, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_image_pull_secrets, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_automount_service_account_token, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServiceAccount {
+            automount_service_account_token: value_automount_service_account_token,
+            image_pull_secrets: value_image_pull_secrets,
+            metadata: value_metadata.unwrap_or_default(),
+            secrets: value_secrets,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServiceAccount {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "secrets" => Some(&["name"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceAccount {
     fn schema_name() -> std::borrow::Cow<'static, str> {
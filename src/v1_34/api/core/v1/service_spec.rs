@@ -100,6 +100,258 @@ impl crate::DeepMerge for ServiceSpec {
     }
 }
 
+impl crate::Defaulting for ServiceSpec {
+    fn set_defaults(&mut self) {
+        if self.session_affinity.is_none() {
+            self.session_affinity = Some("None".into());
+        }
+        if self.type_.is_none() {
+            self.type_ = Some("ClusterIP".into());
+        }
+        crate::defaulting_strategies::core_v1::service_spec(self);
+        crate::Defaulting::set_defaults(&mut self.allocate_load_balancer_node_ports);
+        crate::Defaulting::set_defaults(&mut self.cluster_ip);
+        crate::Defaulting::set_defaults(&mut self.cluster_ips);
+        crate::Defaulting::set_defaults(&mut self.external_ips);
+        crate::Defaulting::set_defaults(&mut self.external_name);
+        crate::Defaulting::set_defaults(&mut self.external_traffic_policy);
+        crate::Defaulting::set_defaults(&mut self.health_check_node_port);
+        crate::Defaulting::set_defaults(&mut self.internal_traffic_policy);
+        crate::Defaulting::set_defaults(&mut self.ip_families);
+        crate::Defaulting::set_defaults(&mut self.ip_family_policy);
+        crate::Defaulting::set_defaults(&mut self.load_balancer_class);
+        crate::Defaulting::set_defaults(&mut self.load_balancer_ip);
+        crate::Defaulting::set_defaults(&mut self.load_balancer_source_ranges);
+        crate::Defaulting::set_defaults(&mut self.ports);
+        crate::Defaulting::set_defaults(&mut self.publish_not_ready_addresses);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.session_affinity);
+        crate::Defaulting::set_defaults(&mut self.session_affinity_config);
+        crate::Defaulting::set_defaults(&mut self.traffic_distribution);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for ServiceSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.allocate_load_balancer_node_ports, &other.allocate_load_balancer_node_ports, &crate::diff::Path::Field { parent: path, name: "allocateLoadBalancerNodePorts" }, changes);
+        crate::diff::Diff::diff(&self.cluster_ip, &other.cluster_ip, &crate::diff::Path::Field { parent: path, name: "clusterIP" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.cluster_ips,
+            &other.cluster_ips,
+            &crate::diff::Path::Field { parent: path, name: "clusterIPs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.external_ips,
+            &other.external_ips,
+            &crate::diff::Path::Field { parent: path, name: "externalIPs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.external_name, &other.external_name, &crate::diff::Path::Field { parent: path, name: "externalName" }, changes);
+        crate::diff::Diff::diff(&self.external_traffic_policy, &other.external_traffic_policy, &crate::diff::Path::Field { parent: path, name: "externalTrafficPolicy" }, changes);
+        crate::diff::Diff::diff(&self.health_check_node_port, &other.health_check_node_port, &crate::diff::Path::Field { parent: path, name: "healthCheckNodePort" }, changes);
+        crate::diff::Diff::diff(&self.internal_traffic_policy, &other.internal_traffic_policy, &crate::diff::Path::Field { parent: path, name: "internalTrafficPolicy" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.ip_families,
+            &other.ip_families,
+            &crate::diff::Path::Field { parent: path, name: "ipFamilies" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.ip_family_policy, &other.ip_family_policy, &crate::diff::Path::Field { parent: path, name: "ipFamilyPolicy" }, changes);
+        crate::diff::Diff::diff(&self.load_balancer_class, &other.load_balancer_class, &crate::diff::Path::Field { parent: path, name: "loadBalancerClass" }, changes);
+        crate::diff::Diff::diff(&self.load_balancer_ip, &other.load_balancer_ip, &crate::diff::Path::Field { parent: path, name: "loadBalancerIP" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.load_balancer_source_ranges,
+            &other.load_balancer_source_ranges,
+            &crate::diff::Path::Field { parent: path, name: "loadBalancerSourceRanges" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            &[("port", |item| crate::diff::MergeKey::merge_key(&item.port))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.publish_not_ready_addresses, &other.publish_not_ready_addresses, &crate::diff::Path::Field { parent: path, name: "publishNotReadyAddresses" }, changes);
+        crate::diff::strategies::map::values(
+            &self.selector,
+            &other.selector,
+            &crate::diff::Path::Field { parent: path, name: "selector" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.session_affinity, &other.session_affinity, &crate::diff::Path::Field { parent: path, name: "sessionAffinity" }, changes);
+        crate::diff::Diff::diff(&self.session_affinity_config, &other.session_affinity_config, &crate::diff::Path::Field { parent: path, name: "sessionAffinityConfig" }, changes);
+        crate::diff::Diff::diff(&self.traffic_distribution, &other.traffic_distribution, &crate::diff::Path::Field { parent: path, name: "trafficDistribution" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl ServiceSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServiceSpec> {
+    /// The path of the `allocateLoadBalancerNodePorts` field.
+    pub fn allocate_load_balancer_node_ports(self) -> crate::FieldPath<bool> {
+        self.child("allocateLoadBalancerNodePorts")
+    }
+
+    /// The path of the `clusterIP` field.
+    pub fn cluster_ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("clusterIP")
+    }
+
+    /// The path of the `clusterIPs` field.
+    pub fn cluster_ips(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("clusterIPs")
+    }
+
+    /// The path of the `externalIPs` field.
+    pub fn external_ips(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("externalIPs")
+    }
+
+    /// The path of the `externalName` field.
+    pub fn external_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("externalName")
+    }
+
+    /// The path of the `externalTrafficPolicy` field.
+    pub fn external_traffic_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("externalTrafficPolicy")
+    }
+
+    /// The path of the `healthCheckNodePort` field.
+    pub fn health_check_node_port(self) -> crate::FieldPath<i32> {
+        self.child("healthCheckNodePort")
+    }
+
+    /// The path of the `internalTrafficPolicy` field.
+    pub fn internal_traffic_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("internalTrafficPolicy")
+    }
+
+    /// The path of the `ipFamilies` field.
+    pub fn ip_families(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("ipFamilies")
+    }
+
+    /// The path of the `ipFamilyPolicy` field.
+    pub fn ip_family_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("ipFamilyPolicy")
+    }
+
+    /// The path of the `loadBalancerClass` field.
+    pub fn load_balancer_class(self) -> crate::FieldPath<std::string::String> {
+        self.child("loadBalancerClass")
+    }
+
+    /// The path of the `loadBalancerIP` field.
+    pub fn load_balancer_ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("loadBalancerIP")
+    }
+
+    /// The path of the `loadBalancerSourceRanges` field.
+    pub fn load_balancer_source_ranges(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("loadBalancerSourceRanges")
+    }
+
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ServicePort>> {
+        self.child("ports")
+    }
+
+    /// The path of the `publishNotReadyAddresses` field.
+    pub fn publish_not_ready_addresses(self) -> crate::FieldPath<bool> {
+        self.child("publishNotReadyAddresses")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("selector")
+    }
+
+    /// The path of the `sessionAffinity` field.
+    pub fn session_affinity(self) -> crate::FieldPath<std::string::String> {
+        self.child("sessionAffinity")
+    }
+
+    /// The path of the `sessionAffinityConfig` field.
+    pub fn session_affinity_config(self) -> crate::FieldPath<crate::api::core::v1::SessionAffinityConfig> {
+        self.child("sessionAffinityConfig")
+    }
+
+    /// The path of the `trafficDistribution` field.
+    pub fn traffic_distribution(self) -> crate::FieldPath<std::string::String> {
+        self.child("trafficDistribution")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for ServiceSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.allocate_load_balancer_node_ports);
+        crate::Normalize::normalize(&mut self.cluster_ip);
+        crate::normalize_strategies::list::atomic(&mut self.cluster_ips, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.external_ips, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.external_name);
+        crate::Normalize::normalize(&mut self.external_traffic_policy);
+        crate::Normalize::normalize(&mut self.health_check_node_port);
+        crate::Normalize::normalize(&mut self.internal_traffic_policy);
+        crate::normalize_strategies::list::atomic(&mut self.ip_families, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.ip_family_policy);
+        crate::Normalize::normalize(&mut self.load_balancer_class);
+        crate::Normalize::normalize(&mut self.load_balancer_ip);
+        crate::normalize_strategies::list::atomic(&mut self.load_balancer_source_ranges, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::map(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.publish_not_ready_addresses);
+        crate::normalize_strategies::map::values(&mut self.selector, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.session_affinity);
+        crate::Normalize::normalize(&mut self.session_affinity_config);
+        crate::Normalize::normalize(&mut self.traffic_distribution);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -369,6 +621,158 @@ impl crate::serde::Serialize for ServiceSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServiceSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.cluster_ip {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.external_ips {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.session_affinity {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.load_balancer_ip {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.load_balancer_source_ranges {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.external_name {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.external_traffic_policy {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.health_check_node_port {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.publish_not_ready_addresses {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.session_affinity_config {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.ip_family_policy {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+        if let Some(value) = &self.cluster_ips {
+            crate::protobuf::Field::encode_field(value, 18, buf);
+        }
+        if let Some(value) = &self.ip_families {
+            crate::protobuf::Field::encode_field(value, 19, buf);
+        }
+        if let Some(value) = &self.allocate_load_balancer_node_ports {
+            crate::protobuf::Field::encode_field(value, 20, buf);
+        }
+        if let Some(value) = &self.load_balancer_class {
+            crate::protobuf::Field::encode_field(value, 21, buf);
+        }
+        if let Some(value) = &self.internal_traffic_policy {
+            crate::protobuf::Field::encode_field(value, 22, buf);
+        }
+        if let Some(value) = &self.traffic_distribution {
+            crate::protobuf::Field::encode_field(value, 23, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_allocate_load_balancer_node_ports: Option<bool> = None;
+        let mut value_cluster_ip: Option<std::string::String> = None;
+        let mut value_cluster_ips: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_external_ips: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_external_name: Option<std::string::String> = None;
+        let mut value_external_traffic_policy: Option<std::string::String> = None;
+        let mut value_health_check_node_port: Option<i32> = None;
+        let mut value_internal_traffic_policy: Option<std::string::String> = None;
+        let mut value_ip_families: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_ip_family_policy: Option<std::string::String> = None;
+        let mut value_load_balancer_class: Option<std::string::String> = None;
+        let mut value_load_balancer_ip: Option<std::string::String> = None;
+        let mut value_load_balancer_source_ranges: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_ports: Option<std::vec::Vec<crate::api::core::v1::ServicePort>> = None;
+        let mut value_publish_not_ready_addresses: Option<bool> = None;
+        let mut value_selector: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_session_affinity: Option<std::string::String> = None;
+        let mut value_session_affinity_config: Option<crate::api::core::v1::SessionAffinityConfig> = None;
+        let mut value_traffic_distribution: Option<std::string::String> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_cluster_ip, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_external_ips, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_session_affinity, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_load_balancer_ip, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_load_balancer_source_ranges, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_external_name, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_external_traffic_policy, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_health_check_node_port, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_publish_not_ready_addresses, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_session_affinity_config, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_ip_family_policy, wire_type, &mut buf)?,
+                18 => crate::protobuf::Field::merge_field(&mut value_cluster_ips, wire_type, &mut buf)?,
+                19 => crate::protobuf::Field::merge_field(&mut value_ip_families, wire_type, &mut buf)?,
+                20 => crate::protobuf::Field::merge_field(&mut value_allocate_load_balancer_node_ports, wire_type, &mut buf)?,
+                21 => crate::protobuf::Field::merge_field(&mut value_load_balancer_class, wire_type, &mut buf)?,
+                22 => crate::protobuf::Field::merge_field(&mut value_internal_traffic_policy, wire_type, &mut buf)?,
+                23 => crate::protobuf::Field::merge_field(&mut value_traffic_distribution, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServiceSpec {
+            allocate_load_balancer_node_ports: value_allocate_load_balancer_node_ports,
+            cluster_ip: value_cluster_ip,
+            cluster_ips: value_cluster_ips,
+            external_ips: value_external_ips,
+            external_name: value_external_name,
+            external_traffic_policy: value_external_traffic_policy,
+            health_check_node_port: value_health_check_node_port,
+            internal_traffic_policy: value_internal_traffic_policy,
+            ip_families: value_ip_families,
+            ip_family_policy: value_ip_family_policy,
+            load_balancer_class: value_load_balancer_class,
+            load_balancer_ip: value_load_balancer_ip,
+            load_balancer_source_ranges: value_load_balancer_source_ranges,
+            ports: value_ports,
+            publish_not_ready_addresses: value_publish_not_ready_addresses,
+            selector: value_selector,
+            session_affinity: value_session_affinity,
+            session_affinity_config: value_session_affinity_config,
+            traffic_distribution: value_traffic_distribution,
+            type_: value_type_,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServiceSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "ports" => Some(&["port"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -150,6 +150,715 @@ impl crate::DeepMerge for Volume {
     }
 }
 
+impl crate::Defaulting for Volume {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.aws_elastic_block_store);
+        crate::Defaulting::set_defaults(&mut self.azure_disk);
+        crate::Defaulting::set_defaults(&mut self.azure_file);
+        crate::Defaulting::set_defaults(&mut self.cephfs);
+        crate::Defaulting::set_defaults(&mut self.cinder);
+        crate::Defaulting::set_defaults(&mut self.config_map);
+        crate::Defaulting::set_defaults(&mut self.csi);
+        crate::Defaulting::set_defaults(&mut self.downward_api);
+        crate::Defaulting::set_defaults(&mut self.empty_dir);
+        crate::Defaulting::set_defaults(&mut self.ephemeral);
+        crate::Defaulting::set_defaults(&mut self.fc);
+        crate::Defaulting::set_defaults(&mut self.flex_volume);
+        crate::Defaulting::set_defaults(&mut self.flocker);
+        crate::Defaulting::set_defaults(&mut self.gce_persistent_disk);
+        crate::Defaulting::set_defaults(&mut self.git_repo);
+        crate::Defaulting::set_defaults(&mut self.glusterfs);
+        crate::Defaulting::set_defaults(&mut self.host_path);
+        crate::Defaulting::set_defaults(&mut self.image);
+        crate::Defaulting::set_defaults(&mut self.iscsi);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.nfs);
+        crate::Defaulting::set_defaults(&mut self.persistent_volume_claim);
+        crate::Defaulting::set_defaults(&mut self.photon_persistent_disk);
+        crate::Defaulting::set_defaults(&mut self.portworx_volume);
+        crate::Defaulting::set_defaults(&mut self.projected);
+        crate::Defaulting::set_defaults(&mut self.quobyte);
+        crate::Defaulting::set_defaults(&mut self.rbd);
+        crate::Defaulting::set_defaults(&mut self.scale_io);
+        crate::Defaulting::set_defaults(&mut self.secret);
+        crate::Defaulting::set_defaults(&mut self.storageos);
+        crate::Defaulting::set_defaults(&mut self.vsphere_volume);
+    }
+}
+
+impl crate::diff::Diff for Volume {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.aws_elastic_block_store, &other.aws_elastic_block_store, &crate::diff::Path::Field { parent: path, name: "awsElasticBlockStore" }, changes);
+        crate::diff::Diff::diff(&self.azure_disk, &other.azure_disk, &crate::diff::Path::Field { parent: path, name: "azureDisk" }, changes);
+        crate::diff::Diff::diff(&self.azure_file, &other.azure_file, &crate::diff::Path::Field { parent: path, name: "azureFile" }, changes);
+        crate::diff::Diff::diff(&self.cephfs, &other.cephfs, &crate::diff::Path::Field { parent: path, name: "cephfs" }, changes);
+        crate::diff::Diff::diff(&self.cinder, &other.cinder, &crate::diff::Path::Field { parent: path, name: "cinder" }, changes);
+        crate::diff::Diff::diff(&self.config_map, &other.config_map, &crate::diff::Path::Field { parent: path, name: "configMap" }, changes);
+        crate::diff::Diff::diff(&self.csi, &other.csi, &crate::diff::Path::Field { parent: path, name: "csi" }, changes);
+        crate::diff::Diff::diff(&self.downward_api, &other.downward_api, &crate::diff::Path::Field { parent: path, name: "downwardAPI" }, changes);
+        crate::diff::Diff::diff(&self.empty_dir, &other.empty_dir, &crate::diff::Path::Field { parent: path, name: "emptyDir" }, changes);
+        crate::diff::Diff::diff(&self.ephemeral, &other.ephemeral, &crate::diff::Path::Field { parent: path, name: "ephemeral" }, changes);
+        crate::diff::Diff::diff(&self.fc, &other.fc, &crate::diff::Path::Field { parent: path, name: "fc" }, changes);
+        crate::diff::Diff::diff(&self.flex_volume, &other.flex_volume, &crate::diff::Path::Field { parent: path, name: "flexVolume" }, changes);
+        crate::diff::Diff::diff(&self.flocker, &other.flocker, &crate::diff::Path::Field { parent: path, name: "flocker" }, changes);
+        crate::diff::Diff::diff(&self.gce_persistent_disk, &other.gce_persistent_disk, &crate::diff::Path::Field { parent: path, name: "gcePersistentDisk" }, changes);
+        crate::diff::Diff::diff(&self.git_repo, &other.git_repo, &crate::diff::Path::Field { parent: path, name: "gitRepo" }, changes);
+        crate::diff::Diff::diff(&self.glusterfs, &other.glusterfs, &crate::diff::Path::Field { parent: path, name: "glusterfs" }, changes);
+        crate::diff::Diff::diff(&self.host_path, &other.host_path, &crate::diff::Path::Field { parent: path, name: "hostPath" }, changes);
+        crate::diff::Diff::diff(&self.image, &other.image, &crate::diff::Path::Field { parent: path, name: "image" }, changes);
+        crate::diff::Diff::diff(&self.iscsi, &other.iscsi, &crate::diff::Path::Field { parent: path, name: "iscsi" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.nfs, &other.nfs, &crate::diff::Path::Field { parent: path, name: "nfs" }, changes);
+        crate::diff::Diff::diff(&self.persistent_volume_claim, &other.persistent_volume_claim, &crate::diff::Path::Field { parent: path, name: "persistentVolumeClaim" }, changes);
+        crate::diff::Diff::diff(&self.photon_persistent_disk, &other.photon_persistent_disk, &crate::diff::Path::Field { parent: path, name: "photonPersistentDisk" }, changes);
+        crate::diff::Diff::diff(&self.portworx_volume, &other.portworx_volume, &crate::diff::Path::Field { parent: path, name: "portworxVolume" }, changes);
+        crate::diff::Diff::diff(&self.projected, &other.projected, &crate::diff::Path::Field { parent: path, name: "projected" }, changes);
+        crate::diff::Diff::diff(&self.quobyte, &other.quobyte, &crate::diff::Path::Field { parent: path, name: "quobyte" }, changes);
+        crate::diff::Diff::diff(&self.rbd, &other.rbd, &crate::diff::Path::Field { parent: path, name: "rbd" }, changes);
+        crate::diff::Diff::diff(&self.scale_io, &other.scale_io, &crate::diff::Path::Field { parent: path, name: "scaleIO" }, changes);
+        crate::diff::Diff::diff(&self.secret, &other.secret, &crate::diff::Path::Field { parent: path, name: "secret" }, changes);
+        crate::diff::Diff::diff(&self.storageos, &other.storageos, &crate::diff::Path::Field { parent: path, name: "storageos" }, changes);
+        crate::diff::Diff::diff(&self.vsphere_volume, &other.vsphere_volume, &crate::diff::Path::Field { parent: path, name: "vsphereVolume" }, changes);
+    }
+}
+
+impl Volume {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Volume> {
+    /// The path of the `awsElasticBlockStore` field.
+    pub fn aws_elastic_block_store(self) -> crate::FieldPath<crate::api::core::v1::AWSElasticBlockStoreVolumeSource> {
+        self.child("awsElasticBlockStore")
+    }
+
+    /// The path of the `azureDisk` field.
+    pub fn azure_disk(self) -> crate::FieldPath<crate::api::core::v1::AzureDiskVolumeSource> {
+        self.child("azureDisk")
+    }
+
+    /// The path of the `azureFile` field.
+    pub fn azure_file(self) -> crate::FieldPath<crate::api::core::v1::AzureFileVolumeSource> {
+        self.child("azureFile")
+    }
+
+    /// The path of the `cephfs` field.
+    pub fn cephfs(self) -> crate::FieldPath<crate::api::core::v1::CephFSVolumeSource> {
+        self.child("cephfs")
+    }
+
+    /// The path of the `cinder` field.
+    pub fn cinder(self) -> crate::FieldPath<crate::api::core::v1::CinderVolumeSource> {
+        self.child("cinder")
+    }
+
+    /// The path of the `configMap` field.
+    pub fn config_map(self) -> crate::FieldPath<crate::api::core::v1::ConfigMapVolumeSource> {
+        self.child("configMap")
+    }
+
+    /// The path of the `csi` field.
+    pub fn csi(self) -> crate::FieldPath<crate::api::core::v1::CSIVolumeSource> {
+        self.child("csi")
+    }
+
+    /// The path of the `downwardAPI` field.
+    pub fn downward_api(self) -> crate::FieldPath<crate::api::core::v1::DownwardAPIVolumeSource> {
+        self.child("downwardAPI")
+    }
+
+    /// The path of the `emptyDir` field.
+    pub fn empty_dir(self) -> crate::FieldPath<crate::api::core::v1::EmptyDirVolumeSource> {
+        self.child("emptyDir")
+    }
+
+    /// The path of the `ephemeral` field.
+    pub fn ephemeral(self) -> crate::FieldPath<crate::api::core::v1::EphemeralVolumeSource> {
+        self.child("ephemeral")
+    }
+
+    /// The path of the `fc` field.
+    pub fn fc(self) -> crate::FieldPath<crate::api::core::v1::FCVolumeSource> {
+        self.child("fc")
+    }
+
+    /// The path of the `flexVolume` field.
+    pub fn flex_volume(self) -> crate::FieldPath<crate::api::core::v1::FlexVolumeSource> {
+        self.child("flexVolume")
+    }
+
+    /// The path of the `flocker` field.
+    pub fn flocker(self) -> crate::FieldPath<crate::api::core::v1::FlockerVolumeSource> {
+        self.child("flocker")
+    }
+
+    /// The path of the `gcePersistentDisk` field.
+    pub fn gce_persistent_disk(self) -> crate::FieldPath<crate::api::core::v1::GCEPersistentDiskVolumeSource> {
+        self.child("gcePersistentDisk")
+    }
+
+    /// The path of the `gitRepo` field.
+    pub fn git_repo(self) -> crate::FieldPath<crate::api::core::v1::GitRepoVolumeSource> {
+        self.child("gitRepo")
+    }
+
+    /// The path of the `glusterfs` field.
+    pub fn glusterfs(self) -> crate::FieldPath<crate::api::core::v1::GlusterfsVolumeSource> {
+        self.child("glusterfs")
+    }
+
+    /// The path of the `hostPath` field.
+    pub fn host_path(self) -> crate::FieldPath<crate::api::core::v1::HostPathVolumeSource> {
+        self.child("hostPath")
+    }
+
+    /// The path of the `image` field.
+    pub fn image(self) -> crate::FieldPath<crate::api::core::v1::ImageVolumeSource> {
+        self.child("image")
+    }
+
+    /// The path of the `iscsi` field.
+    pub fn iscsi(self) -> crate::FieldPath<crate::api::core::v1::ISCSIVolumeSource> {
+        self.child("iscsi")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `nfs` field.
+    pub fn nfs(self) -> crate::FieldPath<crate::api::core::v1::NFSVolumeSource> {
+        self.child("nfs")
+    }
+
+    /// The path of the `persistentVolumeClaim` field.
+    pub fn persistent_volume_claim(self) -> crate::FieldPath<crate::api::core::v1::PersistentVolumeClaimVolumeSource> {
+        self.child("persistentVolumeClaim")
+    }
+
+    /// The path of the `photonPersistentDisk` field.
+    pub fn photon_persistent_disk(self) -> crate::FieldPath<crate::api::core::v1::PhotonPersistentDiskVolumeSource> {
+        self.child("photonPersistentDisk")
+    }
+
+    /// The path of the `portworxVolume` field.
+    pub fn portworx_volume(self) -> crate::FieldPath<crate::api::core::v1::PortworxVolumeSource> {
+        self.child("portworxVolume")
+    }
+
+    /// The path of the `projected` field.
+    pub fn projected(self) -> crate::FieldPath<crate::api::core::v1::ProjectedVolumeSource> {
+        self.child("projected")
+    }
+
+    /// The path of the `quobyte` field.
+    pub fn quobyte(self) -> crate::FieldPath<crate::api::core::v1::QuobyteVolumeSource> {
+        self.child("quobyte")
+    }
+
+    /// The path of the `rbd` field.
+    pub fn rbd(self) -> crate::FieldPath<crate::api::core::v1::RBDVolumeSource> {
+        self.child("rbd")
+    }
+
+    /// The path of the `scaleIO` field.
+    pub fn scale_io(self) -> crate::FieldPath<crate::api::core::v1::ScaleIOVolumeSource> {
+        self.child("scaleIO")
+    }
+
+    /// The path of the `secret` field.
+    pub fn secret(self) -> crate::FieldPath<crate::api::core::v1::SecretVolumeSource> {
+        self.child("secret")
+    }
+
+    /// The path of the `storageos` field.
+    pub fn storageos(self) -> crate::FieldPath<crate::api::core::v1::StorageOSVolumeSource> {
+        self.child("storageos")
+    }
+
+    /// The path of the `vsphereVolume` field.
+    pub fn vsphere_volume(self) -> crate::FieldPath<crate::api::core::v1::VsphereVirtualDiskVolumeSource> {
+        self.child("vsphereVolume")
+    }
+}
+
+impl crate::Normalize for Volume {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.aws_elastic_block_store);
+        crate::Normalize::normalize(&mut self.azure_disk);
+        crate::Normalize::normalize(&mut self.azure_file);
+        crate::Normalize::normalize(&mut self.cephfs);
+        crate::Normalize::normalize(&mut self.cinder);
+        crate::Normalize::normalize(&mut self.config_map);
+        crate::Normalize::normalize(&mut self.csi);
+        crate::Normalize::normalize(&mut self.downward_api);
+        crate::Normalize::normalize(&mut self.empty_dir);
+        crate::Normalize::normalize(&mut self.ephemeral);
+        crate::Normalize::normalize(&mut self.fc);
+        crate::Normalize::normalize(&mut self.flex_volume);
+        crate::Normalize::normalize(&mut self.flocker);
+        crate::Normalize::normalize(&mut self.gce_persistent_disk);
+        crate::Normalize::normalize(&mut self.git_repo);
+        crate::Normalize::normalize(&mut self.glusterfs);
+        crate::Normalize::normalize(&mut self.host_path);
+        crate::Normalize::normalize(&mut self.image);
+        crate::Normalize::normalize(&mut self.iscsi);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.nfs);
+        crate::Normalize::normalize(&mut self.persistent_volume_claim);
+        crate::Normalize::normalize(&mut self.photon_persistent_disk);
+        crate::Normalize::normalize(&mut self.portworx_volume);
+        crate::Normalize::normalize(&mut self.projected);
+        crate::Normalize::normalize(&mut self.quobyte);
+        crate::Normalize::normalize(&mut self.rbd);
+        crate::Normalize::normalize(&mut self.scale_io);
+        crate::Normalize::normalize(&mut self.secret);
+        crate::Normalize::normalize(&mut self.storageos);
+        crate::Normalize::normalize(&mut self.vsphere_volume);
+    }
+}
+
+/// The members of the [`Volume`] union. At most one of them may be set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VolumeUnion {
+    /// The `awsElasticBlockStore` member.
+    AwsElasticBlockStore(crate::api::core::v1::AWSElasticBlockStoreVolumeSource),
+
+    /// The `azureDisk` member.
+    AzureDisk(crate::api::core::v1::AzureDiskVolumeSource),
+
+    /// The `azureFile` member.
+    AzureFile(crate::api::core::v1::AzureFileVolumeSource),
+
+    /// The `cephfs` member.
+    Cephfs(crate::api::core::v1::CephFSVolumeSource),
+
+    /// The `cinder` member.
+    Cinder(crate::api::core::v1::CinderVolumeSource),
+
+    /// The `configMap` member.
+    ConfigMap(crate::api::core::v1::ConfigMapVolumeSource),
+
+    /// The `csi` member.
+    Csi(crate::api::core::v1::CSIVolumeSource),
+
+    /// The `downwardAPI` member.
+    DownwardAPI(crate::api::core::v1::DownwardAPIVolumeSource),
+
+    /// The `emptyDir` member.
+    EmptyDir(crate::api::core::v1::EmptyDirVolumeSource),
+
+    /// The `ephemeral` member.
+    Ephemeral(crate::api::core::v1::EphemeralVolumeSource),
+
+    /// The `fc` member.
+    Fc(crate::api::core::v1::FCVolumeSource),
+
+    /// The `flexVolume` member.
+    FlexVolume(crate::api::core::v1::FlexVolumeSource),
+
+    /// The `flocker` member.
+    Flocker(crate::api::core::v1::FlockerVolumeSource),
+
+    /// The `gcePersistentDisk` member.
+    GcePersistentDisk(crate::api::core::v1::GCEPersistentDiskVolumeSource),
+
+    /// The `gitRepo` member.
+    GitRepo(crate::api::core::v1::GitRepoVolumeSource),
+
+    /// The `glusterfs` member.
+    Glusterfs(crate::api::core::v1::GlusterfsVolumeSource),
+
+    /// The `hostPath` member.
+    HostPath(crate::api::core::v1::HostPathVolumeSource),
+
+    /// The `image` member.
+    Image(crate::api::core::v1::ImageVolumeSource),
+
+    /// The `iscsi` member.
+    Iscsi(crate::api::core::v1::ISCSIVolumeSource),
+
+    /// The `nfs` member.
+    Nfs(crate::api::core::v1::NFSVolumeSource),
+
+    /// The `persistentVolumeClaim` member.
+    PersistentVolumeClaim(crate::api::core::v1::PersistentVolumeClaimVolumeSource),
+
+    /// The `photonPersistentDisk` member.
+    PhotonPersistentDisk(crate::api::core::v1::PhotonPersistentDiskVolumeSource),
+
+    /// The `portworxVolume` member.
+    PortworxVolume(crate::api::core::v1::PortworxVolumeSource),
+
+    /// The `projected` member.
+    Projected(crate::api::core::v1::ProjectedVolumeSource),
+
+    /// The `quobyte` member.
+    Quobyte(crate::api::core::v1::QuobyteVolumeSource),
+
+    /// The `rbd` member.
+    Rbd(crate::api::core::v1::RBDVolumeSource),
+
+    /// The `scaleIO` member.
+    ScaleIO(crate::api::core::v1::ScaleIOVolumeSource),
+
+    /// The `secret` member.
+    Secret(crate::api::core::v1::SecretVolumeSource),
+
+    /// The `storageos` member.
+    Storageos(crate::api::core::v1::StorageOSVolumeSource),
+
+    /// The `vsphereVolume` member.
+    VsphereVolume(crate::api::core::v1::VsphereVirtualDiskVolumeSource),
+}
+
+/// A reference to the member of the [`Volume`] union that is set, as returned by [`Volume::union`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeUnionRef<'a> {
+    /// The `awsElasticBlockStore` member.
+    AwsElasticBlockStore(&'a crate::api::core::v1::AWSElasticBlockStoreVolumeSource),
+
+    /// The `azureDisk` member.
+    AzureDisk(&'a crate::api::core::v1::AzureDiskVolumeSource),
+
+    /// The `azureFile` member.
+    AzureFile(&'a crate::api::core::v1::AzureFileVolumeSource),
+
+    /// The `cephfs` member.
+    Cephfs(&'a crate::api::core::v1::CephFSVolumeSource),
+
+    /// The `cinder` member.
+    Cinder(&'a crate::api::core::v1::CinderVolumeSource),
+
+    /// The `configMap` member.
+    ConfigMap(&'a crate::api::core::v1::ConfigMapVolumeSource),
+
+    /// The `csi` member.
+    Csi(&'a crate::api::core::v1::CSIVolumeSource),
+
+    /// The `downwardAPI` member.
+    DownwardAPI(&'a crate::api::core::v1::DownwardAPIVolumeSource),
+
+    /// The `emptyDir` member.
+    EmptyDir(&'a crate::api::core::v1::EmptyDirVolumeSource),
+
+    /// The `ephemeral` member.
+    Ephemeral(&'a crate::api::core::v1::EphemeralVolumeSource),
+
+    /// The `fc` member.
+    Fc(&'a crate::api::core::v1::FCVolumeSource),
+
+    /// The `flexVolume` member.
+    FlexVolume(&'a crate::api::core::v1::FlexVolumeSource),
+
+    /// The `flocker` member.
+    Flocker(&'a crate::api::core::v1::FlockerVolumeSource),
+
+    /// The `gcePersistentDisk` member.
+    GcePersistentDisk(&'a crate::api::core::v1::GCEPersistentDiskVolumeSource),
+
+    /// The `gitRepo` member.
+    GitRepo(&'a crate::api::core::v1::GitRepoVolumeSource),
+
+    /// The `glusterfs` member.
+    Glusterfs(&'a crate::api::core::v1::GlusterfsVolumeSource),
+
+    /// The `hostPath` member.
+    HostPath(&'a crate::api::core::v1::HostPathVolumeSource),
+
+    /// The `image` member.
+    Image(&'a crate::api::core::v1::ImageVolumeSource),
+
+    /// The `iscsi` member.
+    Iscsi(&'a crate::api::core::v1::ISCSIVolumeSource),
+
+    /// The `nfs` member.
+    Nfs(&'a crate::api::core::v1::NFSVolumeSource),
+
+    /// The `persistentVolumeClaim` member.
+    PersistentVolumeClaim(&'a crate::api::core::v1::PersistentVolumeClaimVolumeSource),
+
+    /// The `photonPersistentDisk` member.
+    PhotonPersistentDisk(&'a crate::api::core::v1::PhotonPersistentDiskVolumeSource),
+
+    /// The `portworxVolume` member.
+    PortworxVolume(&'a crate::api::core::v1::PortworxVolumeSource),
+
+    /// The `projected` member.
+    Projected(&'a crate::api::core::v1::ProjectedVolumeSource),
+
+    /// The `quobyte` member.
+    Quobyte(&'a crate::api::core::v1::QuobyteVolumeSource),
+
+    /// The `rbd` member.
+    Rbd(&'a crate::api::core::v1::RBDVolumeSource),
+
+    /// The `scaleIO` member.
+    ScaleIO(&'a crate::api::core::v1::ScaleIOVolumeSource),
+
+    /// The `secret` member.
+    Secret(&'a crate::api::core::v1::SecretVolumeSource),
+
+    /// The `storageos` member.
+    Storageos(&'a crate::api::core::v1::StorageOSVolumeSource),
+
+    /// The `vsphereVolume` member.
+    VsphereVolume(&'a crate::api::core::v1::VsphereVirtualDiskVolumeSource),
+}
+
+impl Volume {
+    /// Returns the member of the union that is set, if any.
+    ///
+    /// Returns an error if more than one member is set.
+    pub fn union(&self) -> Result<Option<VolumeUnionRef<'_>>, crate::UnionError> {
+        let mut members = std::vec![];
+        let mut result = None;
+        if let Some(value) = &self.aws_elastic_block_store {
+            members.push("awsElasticBlockStore");
+            result = Some(VolumeUnionRef::AwsElasticBlockStore(value));
+        }
+        if let Some(value) = &self.azure_disk {
+            members.push("azureDisk");
+            result = Some(VolumeUnionRef::AzureDisk(value));
+        }
+        if let Some(value) = &self.azure_file {
+            members.push("azureFile");
+            result = Some(VolumeUnionRef::AzureFile(value));
+        }
+        if let Some(value) = &self.cephfs {
+            members.push("cephfs");
+            result = Some(VolumeUnionRef::Cephfs(value));
+        }
+        if let Some(value) = &self.cinder {
+            members.push("cinder");
+            result = Some(VolumeUnionRef::Cinder(value));
+        }
+        if let Some(value) = &self.config_map {
+            members.push("configMap");
+            result = Some(VolumeUnionRef::ConfigMap(value));
+        }
+        if let Some(value) = &self.csi {
+            members.push("csi");
+            result = Some(VolumeUnionRef::Csi(value));
+        }
+        if let Some(value) = &self.downward_api {
+            members.push("downwardAPI");
+            result = Some(VolumeUnionRef::DownwardAPI(value));
+        }
+        if let Some(value) = &self.empty_dir {
+            members.push("emptyDir");
+            result = Some(VolumeUnionRef::EmptyDir(value));
+        }
+        if let Some(value) = &self.ephemeral {
+            members.push("ephemeral");
+            result = Some(VolumeUnionRef::Ephemeral(value));
+        }
+        if let Some(value) = &self.fc {
+            members.push("fc");
+            result = Some(VolumeUnionRef::Fc(value));
+        }
+        if let Some(value) = &self.flex_volume {
+            members.push("flexVolume");
+            result = Some(VolumeUnionRef::FlexVolume(value));
+        }
+        if let Some(value) = &self.flocker {
+            members.push("flocker");
+            result = Some(VolumeUnionRef::Flocker(value));
+        }
+        if let Some(value) = &self.gce_persistent_disk {
+            members.push("gcePersistentDisk");
+            result = Some(VolumeUnionRef::GcePersistentDisk(value));
+        }
+        if let Some(value) = &self.git_repo {
+            members.push("gitRepo");
+            result = Some(VolumeUnionRef::GitRepo(value));
+        }
+        if let Some(value) = &self.glusterfs {
+            members.push("glusterfs");
+            result = Some(VolumeUnionRef::Glusterfs(value));
+        }
+        if let Some(value) = &self.host_path {
+            members.push("hostPath");
+            result = Some(VolumeUnionRef::HostPath(value));
+        }
+        if let Some(value) = &self.image {
+            members.push("image");
+            result = Some(VolumeUnionRef::Image(value));
+        }
+        if let Some(value) = &self.iscsi {
+            members.push("iscsi");
+            result = Some(VolumeUnionRef::Iscsi(value));
+        }
+        if let Some(value) = &self.nfs {
+            members.push("nfs");
+            result = Some(VolumeUnionRef::Nfs(value));
+        }
+        if let Some(value) = &self.persistent_volume_claim {
+            members.push("persistentVolumeClaim");
+            result = Some(VolumeUnionRef::PersistentVolumeClaim(value));
+        }
+        if let Some(value) = &self.photon_persistent_disk {
+            members.push("photonPersistentDisk");
+            result = Some(VolumeUnionRef::PhotonPersistentDisk(value));
+        }
+        if let Some(value) = &self.portworx_volume {
+            members.push("portworxVolume");
+            result = Some(VolumeUnionRef::PortworxVolume(value));
+        }
+        if let Some(value) = &self.projected {
+            members.push("projected");
+            result = Some(VolumeUnionRef::Projected(value));
+        }
+        if let Some(value) = &self.quobyte {
+            members.push("quobyte");
+            result = Some(VolumeUnionRef::Quobyte(value));
+        }
+        if let Some(value) = &self.rbd {
+            members.push("rbd");
+            result = Some(VolumeUnionRef::Rbd(value));
+        }
+        if let Some(value) = &self.scale_io {
+            members.push("scaleIO");
+            result = Some(VolumeUnionRef::ScaleIO(value));
+        }
+        if let Some(value) = &self.secret {
+            members.push("secret");
+            result = Some(VolumeUnionRef::Secret(value));
+        }
+        if let Some(value) = &self.storageos {
+            members.push("storageos");
+            result = Some(VolumeUnionRef::Storageos(value));
+        }
+        if let Some(value) = &self.vsphere_volume {
+            members.push("vsphereVolume");
+            result = Some(VolumeUnionRef::VsphereVolume(value));
+        }
+
+        if members.len() > 1 {
+            return Err(crate::UnionError::MultipleMembersSet(members));
+        }
+
+        Ok(result)
+    }
+
+    /// Sets the given member of the union, and unsets all the others.
+    pub fn set_union(&mut self, value: Option<VolumeUnion>) {
+        self.aws_elastic_block_store = None;
+        self.azure_disk = None;
+        self.azure_file = None;
+        self.cephfs = None;
+        self.cinder = None;
+        self.config_map = None;
+        self.csi = None;
+        self.downward_api = None;
+        self.empty_dir = None;
+        self.ephemeral = None;
+        self.fc = None;
+        self.flex_volume = None;
+        self.flocker = None;
+        self.gce_persistent_disk = None;
+        self.git_repo = None;
+        self.glusterfs = None;
+        self.host_path = None;
+        self.image = None;
+        self.iscsi = None;
+        self.nfs = None;
+        self.persistent_volume_claim = None;
+        self.photon_persistent_disk = None;
+        self.portworx_volume = None;
+        self.projected = None;
+        self.quobyte = None;
+        self.rbd = None;
+        self.scale_io = None;
+        self.secret = None;
+        self.storageos = None;
+        self.vsphere_volume = None;
+
+        match value {
+            Some(VolumeUnion::AwsElasticBlockStore(value)) => self.aws_elastic_block_store = Some(value),
+            Some(VolumeUnion::AzureDisk(value)) => self.azure_disk = Some(value),
+            Some(VolumeUnion::AzureFile(value)) => self.azure_file = Some(value),
+            Some(VolumeUnion::Cephfs(value)) => self.cephfs = Some(value),
+            Some(VolumeUnion::Cinder(value)) => self.cinder = Some(value),
+            Some(VolumeUnion::ConfigMap(value)) => self.config_map = Some(value),
+            Some(VolumeUnion::Csi(value)) => self.csi = Some(value),
+            Some(VolumeUnion::DownwardAPI(value)) => self.downward_api = Some(value),
+            Some(VolumeUnion::EmptyDir(value)) => self.empty_dir = Some(value),
+            Some(VolumeUnion::Ephemeral(value)) => self.ephemeral = Some(value),
+            Some(VolumeUnion::Fc(value)) => self.fc = Some(value),
+            Some(VolumeUnion::FlexVolume(value)) => self.flex_volume = Some(value),
+            Some(VolumeUnion::Flocker(value)) => self.flocker = Some(value),
+            Some(VolumeUnion::GcePersistentDisk(value)) => self.gce_persistent_disk = Some(value),
+            Some(VolumeUnion::GitRepo(value)) => self.git_repo = Some(value),
+            Some(VolumeUnion::Glusterfs(value)) => self.glusterfs = Some(value),
+            Some(VolumeUnion::HostPath(value)) => self.host_path = Some(value),
+            Some(VolumeUnion::Image(value)) => self.image = Some(value),
+            Some(VolumeUnion::Iscsi(value)) => self.iscsi = Some(value),
+            Some(VolumeUnion::Nfs(value)) => self.nfs = Some(value),
+            Some(VolumeUnion::PersistentVolumeClaim(value)) => self.persistent_volume_claim = Some(value),
+            Some(VolumeUnion::PhotonPersistentDisk(value)) => self.photon_persistent_disk = Some(value),
+            Some(VolumeUnion::PortworxVolume(value)) => self.portworx_volume = Some(value),
+            Some(VolumeUnion::Projected(value)) => self.projected = Some(value),
+            Some(VolumeUnion::Quobyte(value)) => self.quobyte = Some(value),
+            Some(VolumeUnion::Rbd(value)) => self.rbd = Some(value),
+            Some(VolumeUnion::ScaleIO(value)) => self.scale_io = Some(value),
+            Some(VolumeUnion::Secret(value)) => self.secret = Some(value),
+            Some(VolumeUnion::Storageos(value)) => self.storageos = Some(value),
+            Some(VolumeUnion::VsphereVolume(value)) => self.vsphere_volume = Some(value),
+            None => (),
+        }
+    }
+}
+
+impl From<VolumeUnion> for Volume {
+    fn from(value: VolumeUnion) -> Self {
+        let mut result = Self::default();
+        result.set_union(Some(value));
+        result
+    }
+}
+
+impl From<VolumeUnionRef<'_>> for VolumeUnion {
+    fn from(value: VolumeUnionRef<'_>) -> Self {
+        match value {
+            VolumeUnionRef::AwsElasticBlockStore(value) => VolumeUnion::AwsElasticBlockStore(value.clone()),
+            VolumeUnionRef::AzureDisk(value) => VolumeUnion::AzureDisk(value.clone()),
+            VolumeUnionRef::AzureFile(value) => VolumeUnion::AzureFile(value.clone()),
+            VolumeUnionRef::Cephfs(value) => VolumeUnion::Cephfs(value.clone()),
+            VolumeUnionRef::Cinder(value) => VolumeUnion::Cinder(value.clone()),
+            VolumeUnionRef::ConfigMap(value) => VolumeUnion::ConfigMap(value.clone()),
+            VolumeUnionRef::Csi(value) => VolumeUnion::Csi(value.clone()),
+            VolumeUnionRef::DownwardAPI(value) => VolumeUnion::DownwardAPI(value.clone()),
+            VolumeUnionRef::EmptyDir(value) => VolumeUnion::EmptyDir(value.clone()),
+            VolumeUnionRef::Ephemeral(value) => VolumeUnion::Ephemeral(value.clone()),
+            VolumeUnionRef::Fc(value) => VolumeUnion::Fc(value.clone()),
+            VolumeUnionRef::FlexVolume(value) => VolumeUnion::FlexVolume(value.clone()),
+            VolumeUnionRef::Flocker(value) => VolumeUnion::Flocker(value.clone()),
+            VolumeUnionRef::GcePersistentDisk(value) => VolumeUnion::GcePersistentDisk(value.clone()),
+            VolumeUnionRef::GitRepo(value) => VolumeUnion::GitRepo(value.clone()),
+            VolumeUnionRef::Glusterfs(value) => VolumeUnion::Glusterfs(value.clone()),
+            VolumeUnionRef::HostPath(value) => VolumeUnion::HostPath(value.clone()),
+            VolumeUnionRef::Image(value) => VolumeUnion::Image(value.clone()),
+            VolumeUnionRef::Iscsi(value) => VolumeUnion::Iscsi(value.clone()),
+            VolumeUnionRef::Nfs(value) => VolumeUnion::Nfs(value.clone()),
+            VolumeUnionRef::PersistentVolumeClaim(value) => VolumeUnion::PersistentVolumeClaim(value.clone()),
+            VolumeUnionRef::PhotonPersistentDisk(value) => VolumeUnion::PhotonPersistentDisk(value.clone()),
+            VolumeUnionRef::PortworxVolume(value) => VolumeUnion::PortworxVolume(value.clone()),
+            VolumeUnionRef::Projected(value) => VolumeUnion::Projected(value.clone()),
+            VolumeUnionRef::Quobyte(value) => VolumeUnion::Quobyte(value.clone()),
+            VolumeUnionRef::Rbd(value) => VolumeUnion::Rbd(value.clone()),
+            VolumeUnionRef::ScaleIO(value) => VolumeUnion::ScaleIO(value.clone()),
+            VolumeUnionRef::Secret(value) => VolumeUnion::Secret(value.clone()),
+            VolumeUnionRef::Storageos(value) => VolumeUnion::Storageos(value.clone()),
+            VolumeUnionRef::VsphereVolume(value) => VolumeUnion::VsphereVolume(value.clone()),
+        }
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Volume {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -527,6 +1236,235 @@ impl crate::serde::Serialize for Volume {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Volume {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        {
+            let mut embedded = std::vec![];
+            if let Some(value) = &self.host_path {
+                crate::protobuf::Field::encode_field(value, 1, &mut embedded);
+            }
+            if let Some(value) = &self.empty_dir {
+                crate::protobuf::Field::encode_field(value, 2, &mut embedded);
+            }
+            if let Some(value) = &self.gce_persistent_disk {
+                crate::protobuf::Field::encode_field(value, 3, &mut embedded);
+            }
+            if let Some(value) = &self.aws_elastic_block_store {
+                crate::protobuf::Field::encode_field(value, 4, &mut embedded);
+            }
+            if let Some(value) = &self.git_repo {
+                crate::protobuf::Field::encode_field(value, 5, &mut embedded);
+            }
+            if let Some(value) = &self.secret {
+                crate::protobuf::Field::encode_field(value, 6, &mut embedded);
+            }
+            if let Some(value) = &self.nfs {
+                crate::protobuf::Field::encode_field(value, 7, &mut embedded);
+            }
+            if let Some(value) = &self.iscsi {
+                crate::protobuf::Field::encode_field(value, 8, &mut embedded);
+            }
+            if let Some(value) = &self.glusterfs {
+                crate::protobuf::Field::encode_field(value, 9, &mut embedded);
+            }
+            if let Some(value) = &self.persistent_volume_claim {
+                crate::protobuf::Field::encode_field(value, 10, &mut embedded);
+            }
+            if let Some(value) = &self.rbd {
+                crate::protobuf::Field::encode_field(value, 11, &mut embedded);
+            }
+            if let Some(value) = &self.flex_volume {
+                crate::protobuf::Field::encode_field(value, 12, &mut embedded);
+            }
+            if let Some(value) = &self.cinder {
+                crate::protobuf::Field::encode_field(value, 13, &mut embedded);
+            }
+            if let Some(value) = &self.cephfs {
+                crate::protobuf::Field::encode_field(value, 14, &mut embedded);
+            }
+            if let Some(value) = &self.flocker {
+                crate::protobuf::Field::encode_field(value, 15, &mut embedded);
+            }
+            if let Some(value) = &self.downward_api {
+                crate::protobuf::Field::encode_field(value, 16, &mut embedded);
+            }
+            if let Some(value) = &self.fc {
+                crate::protobuf::Field::encode_field(value, 17, &mut embedded);
+            }
+            if let Some(value) = &self.azure_file {
+                crate::protobuf::Field::encode_field(value, 18, &mut embedded);
+            }
+            if let Some(value) = &self.config_map {
+                crate::protobuf::Field::encode_field(value, 19, &mut embedded);
+            }
+            if let Some(value) = &self.vsphere_volume {
+                crate::protobuf::Field::encode_field(value, 20, &mut embedded);
+            }
+            if let Some(value) = &self.quobyte {
+                crate::protobuf::Field::encode_field(value, 21, &mut embedded);
+            }
+            if let Some(value) = &self.azure_disk {
+                crate::protobuf::Field::encode_field(value, 22, &mut embedded);
+            }
+            if let Some(value) = &self.photon_persistent_disk {
+                crate::protobuf::Field::encode_field(value, 23, &mut embedded);
+            }
+            if let Some(value) = &self.portworx_volume {
+                crate::protobuf::Field::encode_field(value, 24, &mut embedded);
+            }
+            if let Some(value) = &self.scale_io {
+                crate::protobuf::Field::encode_field(value, 25, &mut embedded);
+            }
+            if let Some(value) = &self.projected {
+                crate::protobuf::Field::encode_field(value, 26, &mut embedded);
+            }
+            if let Some(value) = &self.storageos {
+                crate::protobuf::Field::encode_field(value, 27, &mut embedded);
+            }
+            if let Some(value) = &self.csi {
+                crate::protobuf::Field::encode_field(value, 28, &mut embedded);
+            }
+            if let Some(value) = &self.ephemeral {
+                crate::protobuf::Field::encode_field(value, 29, &mut embedded);
+            }
+            if let Some(value) = &self.image {
+                crate::protobuf::Field::encode_field(value, 30, &mut embedded);
+            }
+            crate::protobuf::encode_length_delimited(2, &embedded, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_aws_elastic_block_store: Option<crate::api::core::v1::AWSElasticBlockStoreVolumeSource> = None;
+        let mut value_azure_disk: Option<crate::api::core::v1::AzureDiskVolumeSource> = None;
+        let mut value_azure_file: Option<crate::api::core::v1::AzureFileVolumeSource> = None;
+        let mut value_cephfs: Option<crate::api::core::v1::CephFSVolumeSource> = None;
+        let mut value_cinder: Option<crate::api::core::v1::CinderVolumeSource> = None;
+        let mut value_config_map: Option<crate::api::core::v1::ConfigMapVolumeSource> = None;
+        let mut value_csi: Option<crate::api::core::v1::CSIVolumeSource> = None;
+        let mut value_downward_api: Option<crate::api::core::v1::DownwardAPIVolumeSource> = None;
+        let mut value_empty_dir: Option<crate::api::core::v1::EmptyDirVolumeSource> = None;
+        let mut value_ephemeral: Option<crate::api::core::v1::EphemeralVolumeSource> = None;
+        let mut value_fc: Option<crate::api::core::v1::FCVolumeSource> = None;
+        let mut value_flex_volume: Option<crate::api::core::v1::FlexVolumeSource> = None;
+        let mut value_flocker: Option<crate::api::core::v1::FlockerVolumeSource> = None;
+        let mut value_gce_persistent_disk: Option<crate::api::core::v1::GCEPersistentDiskVolumeSource> = None;
+        let mut value_git_repo: Option<crate::api::core::v1::GitRepoVolumeSource> = None;
+        let mut value_glusterfs: Option<crate::api::core::v1::GlusterfsVolumeSource> = None;
+        let mut value_host_path: Option<crate::api::core::v1::HostPathVolumeSource> = None;
+        let mut value_image: Option<crate::api::core::v1::ImageVolumeSource> = None;
+        let mut value_iscsi: Option<crate::api::core::v1::ISCSIVolumeSource> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_nfs: Option<crate::api::core::v1::NFSVolumeSource> = None;
+        let mut value_persistent_volume_claim: Option<crate::api::core::v1::PersistentVolumeClaimVolumeSource> = None;
+        let mut value_photon_persistent_disk: Option<crate::api::core::v1::PhotonPersistentDiskVolumeSource> = None;
+        let mut value_portworx_volume: Option<crate::api::core::v1::PortworxVolumeSource> = None;
+        let mut value_projected: Option<crate::api::core::v1::ProjectedVolumeSource> = None;
+        let mut value_quobyte: Option<crate::api::core::v1::QuobyteVolumeSource> = None;
+        let mut value_rbd: Option<crate::api::core::v1::RBDVolumeSource> = None;
+        let mut value_scale_io: Option<crate::api::core::v1::ScaleIOVolumeSource> = None;
+        let mut value_secret: Option<crate::api::core::v1::SecretVolumeSource> = None;
+        let mut value_storageos: Option<crate::api::core::v1::StorageOSVolumeSource> = None;
+        let mut value_vsphere_volume: Option<crate::api::core::v1::VsphereVirtualDiskVolumeSource> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => {
+                    let mut embedded = crate::protobuf::decode_length_delimited(wire_type, &mut buf)?;
+                    while !embedded.is_empty() {
+                        let (field_number, wire_type) = crate::protobuf::decode_key(&mut embedded)?;
+                        match field_number {
+                            1 => crate::protobuf::Field::merge_field(&mut value_host_path, wire_type, &mut embedded)?,
+                            2 => crate::protobuf::Field::merge_field(&mut value_empty_dir, wire_type, &mut embedded)?,
+                            3 => crate::protobuf::Field::merge_field(&mut value_gce_persistent_disk, wire_type, &mut embedded)?,
+                            4 => crate::protobuf::Field::merge_field(&mut value_aws_elastic_block_store, wire_type, &mut embedded)?,
+                            5 => crate::protobuf::Field::merge_field(&mut value_git_repo, wire_type, &mut embedded)?,
+                            6 => crate::protobuf::Field::merge_field(&mut value_secret, wire_type, &mut embedded)?,
+                            7 => crate::protobuf::Field::merge_field(&mut value_nfs, wire_type, &mut embedded)?,
+                            8 => crate::protobuf::Field::merge_field(&mut value_iscsi, wire_type, &mut embedded)?,
+                            9 => crate::protobuf::Field::merge_field(&mut value_glusterfs, wire_type, &mut embedded)?,
+                            10 => crate::protobuf::Field::merge_field(&mut value_persistent_volume_claim, wire_type, &mut embedded)?,
+                            11 => crate::protobuf::Field::merge_field(&mut value_rbd, wire_type, &mut embedded)?,
+                            12 => crate::protobuf::Field::merge_field(&mut value_flex_volume, wire_type, &mut embedded)?,
+                            13 => crate::protobuf::Field::merge_field(&mut value_cinder, wire_type, &mut embedded)?,
+                            14 => crate::protobuf::Field::merge_field(&mut value_cephfs, wire_type, &mut embedded)?,
+                            15 => crate::protobuf::Field::merge_field(&mut value_flocker, wire_type, &mut embedded)?,
+                            16 => crate::protobuf::Field::merge_field(&mut value_downward_api, wire_type, &mut embedded)?,
+                            17 => crate::protobuf::Field::merge_field(&mut value_fc, wire_type, &mut embedded)?,
+                            18 => crate::protobuf::Field::merge_field(&mut value_azure_file, wire_type, &mut embedded)?,
+                            19 => crate::protobuf::Field::merge_field(&mut value_config_map, wire_type, &mut embedded)?,
+                            20 => crate::protobuf::Field::merge_field(&mut value_vsphere_volume, wire_type, &mut embedded)?,
+                            21 => crate::protobuf::Field::merge_field(&mut value_quobyte, wire_type, &mut embedded)?,
+                            22 => crate::protobuf::Field::merge_field(&mut value_azure_disk, wire_type, &mut embedded)?,
+                            23 => crate::protobuf::Field::merge_field(&mut value_photon_persistent_disk, wire_type, &mut embedded)?,
+                            24 => crate::protobuf::Field::merge_field(&mut value_portworx_volume, wire_type, &mut embedded)?,
+                            25 => crate::protobuf::Field::merge_field(&mut value_scale_io, wire_type, &mut embedded)?,
+                            26 => crate::protobuf::Field::merge_field(&mut value_projected, wire_type, &mut embedded)?,
+                            27 => crate::protobuf::Field::merge_field(&mut value_storageos, wire_type, &mut embedded)?,
+                            28 => crate::protobuf::Field::merge_field(&mut value_csi, wire_type, &mut embedded)?,
+                            29 => crate::protobuf::Field::merge_field(&mut value_ephemeral, wire_type, &mut embedded)?,
+                            30 => crate::protobuf::Field::merge_field(&mut value_image, wire_type, &mut embedded)?,
+                            _ => crate::protobuf::skip_field(wire_type, &mut embedded)?,
+                        }
+                    }
+                },
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Volume {
+            aws_elastic_block_store: value_aws_elastic_block_store,
+            azure_disk: value_azure_disk,
+            azure_file: value_azure_file,
+            cephfs: value_cephfs,
+            cinder: value_cinder,
+            config_map: value_config_map,
+            csi: value_csi,
+            downward_api: value_downward_api,
+            empty_dir: value_empty_dir,
+            ephemeral: value_ephemeral,
+            fc: value_fc,
+            flex_volume: value_flex_volume,
+            flocker: value_flocker,
+            gce_persistent_disk: value_gce_persistent_disk,
+            git_repo: value_git_repo,
+            glusterfs: value_glusterfs,
+            host_path: value_host_path,
+            image: value_image,
+            iscsi: value_iscsi,
+            name: value_name.unwrap_or_default(),
+            nfs: value_nfs,
+            persistent_volume_claim: value_persistent_volume_claim,
+            photon_persistent_disk: value_photon_persistent_disk,
+            portworx_volume: value_portworx_volume,
+            projected: value_projected,
+            quobyte: value_quobyte,
+            rbd: value_rbd,
+            scale_io: value_scale_io,
+            secret: value_secret,
+            storageos: value_storageos,
+            vsphere_volume: value_vsphere_volume,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Volume {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "ephemeral" => <Option<crate::api::core::v1::EphemeralVolumeSource> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Volume {
     fn schema_name() -> std::borrow::Cow<'static, str> {
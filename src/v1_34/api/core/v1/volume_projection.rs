@@ -51,6 +51,78 @@ impl crate::DeepMerge for VolumeProjection {
     }
 }
 
+impl crate::Defaulting for VolumeProjection {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.cluster_trust_bundle);
+        crate::Defaulting::set_defaults(&mut self.config_map);
+        crate::Defaulting::set_defaults(&mut self.downward_api);
+        crate::Defaulting::set_defaults(&mut self.pod_certificate);
+        crate::Defaulting::set_defaults(&mut self.secret);
+        crate::Defaulting::set_defaults(&mut self.service_account_token);
+    }
+}
+
+impl crate::diff::Diff for VolumeProjection {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.cluster_trust_bundle, &other.cluster_trust_bundle, &crate::diff::Path::Field { parent: path, name: "clusterTrustBundle" }, changes);
+        crate::diff::Diff::diff(&self.config_map, &other.config_map, &crate::diff::Path::Field { parent: path, name: "configMap" }, changes);
+        crate::diff::Diff::diff(&self.downward_api, &other.downward_api, &crate::diff::Path::Field { parent: path, name: "downwardAPI" }, changes);
+        crate::diff::Diff::diff(&self.pod_certificate, &other.pod_certificate, &crate::diff::Path::Field { parent: path, name: "podCertificate" }, changes);
+        crate::diff::Diff::diff(&self.secret, &other.secret, &crate::diff::Path::Field { parent: path, name: "secret" }, changes);
+        crate::diff::Diff::diff(&self.service_account_token, &other.service_account_token, &crate::diff::Path::Field { parent: path, name: "serviceAccountToken" }, changes);
+    }
+}
+
+impl VolumeProjection {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeProjection> {
+    /// The path of the `clusterTrustBundle` field.
+    pub fn cluster_trust_bundle(self) -> crate::FieldPath<crate::api::core::v1::ClusterTrustBundleProjection> {
+        self.child("clusterTrustBundle")
+    }
+
+    /// The path of the `configMap` field.
+    pub fn config_map(self) -> crate::FieldPath<crate::api::core::v1::ConfigMapProjection> {
+        self.child("configMap")
+    }
+
+    /// The path of the `downwardAPI` field.
+    pub fn downward_api(self) -> crate::FieldPath<crate::api::core::v1::DownwardAPIProjection> {
+        self.child("downwardAPI")
+    }
+
+    /// The path of the `podCertificate` field.
+    pub fn pod_certificate(self) -> crate::FieldPath<crate::api::core::v1::PodCertificateProjection> {
+        self.child("podCertificate")
+    }
+
+    /// The path of the `secret` field.
+    pub fn secret(self) -> crate::FieldPath<crate::api::core::v1::SecretProjection> {
+        self.child("secret")
+    }
+
+    /// The path of the `serviceAccountToken` field.
+    pub fn service_account_token(self) -> crate::FieldPath<crate::api::core::v1::ServiceAccountTokenProjection> {
+        self.child("serviceAccountToken")
+    }
+}
+
+impl crate::Normalize for VolumeProjection {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.cluster_trust_bundle);
+        crate::Normalize::normalize(&mut self.config_map);
+        crate::Normalize::normalize(&mut self.downward_api);
+        crate::Normalize::normalize(&mut self.pod_certificate);
+        crate::Normalize::normalize(&mut self.secret);
+        crate::Normalize::normalize(&mut self.service_account_token);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeProjection {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -180,6 +252,64 @@ impl crate::serde::Serialize for VolumeProjection {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeProjection {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.secret {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.downward_api {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.config_map {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.service_account_token {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.cluster_trust_bundle {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.pod_certificate {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_cluster_trust_bundle: Option<crate::api::core::v1::ClusterTrustBundleProjection> = None;
+        let mut value_config_map: Option<crate::api::core::v1::ConfigMapProjection> = None;
+        let mut value_downward_api: Option<crate::api::core::v1::DownwardAPIProjection> = None;
+        let mut value_pod_certificate: Option<crate::api::core::v1::PodCertificateProjection> = None;
+        let mut value_secret: Option<crate::api::core::v1::SecretProjection> = None;
+        let mut value_service_account_token: Option<crate::api::core::v1::ServiceAccountTokenProjection> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_secret, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_downward_api, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_config_map, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_service_account_token, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_cluster_trust_bundle, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_pod_certificate, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeProjection {
+            cluster_trust_bundle: value_cluster_trust_bundle,
+            config_map: value_config_map,
+            downward_api: value_downward_api,
+            pod_certificate: value_pod_certificate,
+            secret: value_secret,
+            service_account_token: value_service_account_token,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeProjection {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeProjection {
     fn schema_name() -> std::borrow::Cow<'static, str> {
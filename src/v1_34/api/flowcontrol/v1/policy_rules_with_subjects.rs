@@ -21,6 +21,84 @@ impl crate::DeepMerge for PolicyRulesWithSubjects {
     }
 }
 
+impl crate::Defaulting for PolicyRulesWithSubjects {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.non_resource_rules);
+        crate::Defaulting::set_defaults(&mut self.resource_rules);
+        crate::Defaulting::set_defaults(&mut self.subjects);
+    }
+}
+
+impl crate::diff::Diff for PolicyRulesWithSubjects {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.non_resource_rules,
+            &other.non_resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "nonResourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.resource_rules,
+            &other.resource_rules,
+            &crate::diff::Path::Field { parent: path, name: "resourceRules" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.subjects,
+            &other.subjects,
+            &crate::diff::Path::Field { parent: path, name: "subjects" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl PolicyRulesWithSubjects {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PolicyRulesWithSubjects> {
+    /// The path of the `nonResourceRules` field.
+    pub fn non_resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::flowcontrol::v1::NonResourcePolicyRule>> {
+        self.child("nonResourceRules")
+    }
+
+    /// The path of the `resourceRules` field.
+    pub fn resource_rules(self) -> crate::FieldPath<std::vec::Vec<crate::api::flowcontrol::v1::ResourcePolicyRule>> {
+        self.child("resourceRules")
+    }
+
+    /// The path of the `subjects` field.
+    pub fn subjects(self) -> crate::FieldPath<std::vec::Vec<crate::api::flowcontrol::v1::Subject>> {
+        self.child("subjects")
+    }
+}
+
+impl crate::Normalize for PolicyRulesWithSubjects {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.non_resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.resource_rules, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.subjects, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PolicyRulesWithSubjects {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +196,44 @@ impl crate::serde::Serialize for PolicyRulesWithSubjects {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PolicyRulesWithSubjects {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.subjects, 1, buf);
+        if let Some(value) = &self.resource_rules {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.non_resource_rules {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_non_resource_rules: Option<std::vec::Vec<crate::api::flowcontrol::v1::NonResourcePolicyRule>> = None;
+        let mut value_resource_rules: Option<std::vec::Vec<crate::api::flowcontrol::v1::ResourcePolicyRule>> = None;
+        let mut value_subjects: Option<std::vec::Vec<crate::api::flowcontrol::v1::Subject>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_subjects, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_rules, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_non_resource_rules, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PolicyRulesWithSubjects {
+            non_resource_rules: value_non_resource_rules,
+            resource_rules: value_resource_rules,
+            subjects: value_subjects.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PolicyRulesWithSubjects {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PolicyRulesWithSubjects {
     fn schema_name() -> std::borrow::Cow<'static, str> {
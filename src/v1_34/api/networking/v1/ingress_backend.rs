@@ -17,6 +17,46 @@ impl crate::DeepMerge for IngressBackend {
     }
 }
 
+impl crate::Defaulting for IngressBackend {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.resource);
+        crate::Defaulting::set_defaults(&mut self.service);
+    }
+}
+
+impl crate::diff::Diff for IngressBackend {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+        crate::diff::Diff::diff(&self.service, &other.service, &crate::diff::Path::Field { parent: path, name: "service" }, changes);
+    }
+}
+
+impl IngressBackend {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressBackend> {
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<crate::api::core::v1::TypedLocalObjectReference> {
+        self.child("resource")
+    }
+
+    /// The path of the `service` field.
+    pub fn service(self) -> crate::FieldPath<crate::api::networking::v1::IngressServiceBackend> {
+        self.child("service")
+    }
+}
+
+impl crate::Normalize for IngressBackend {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.resource);
+        crate::Normalize::normalize(&mut self.service);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressBackend {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for IngressBackend {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressBackend {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.resource {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.service {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_resource: Option<crate::api::core::v1::TypedLocalObjectReference> = None;
+        let mut value_service: Option<crate::api::networking::v1::IngressServiceBackend> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                3 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_service, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressBackend {
+            resource: value_resource,
+            service: value_service,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressBackend {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressBackend {
     fn schema_name() -> std::borrow::Cow<'static, str> {
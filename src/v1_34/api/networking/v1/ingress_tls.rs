@@ -17,6 +17,56 @@ impl crate::DeepMerge for IngressTLS {
     }
 }
 
+impl crate::Defaulting for IngressTLS {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hosts);
+        crate::Defaulting::set_defaults(&mut self.secret_name);
+    }
+}
+
+impl crate::diff::Diff for IngressTLS {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.hosts,
+            &other.hosts,
+            &crate::diff::Path::Field { parent: path, name: "hosts" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.secret_name, &other.secret_name, &crate::diff::Path::Field { parent: path, name: "secretName" }, changes);
+    }
+}
+
+impl IngressTLS {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressTLS> {
+    /// The path of the `hosts` field.
+    pub fn hosts(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("hosts")
+    }
+
+    /// The path of the `secretName` field.
+    pub fn secret_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("secretName")
+    }
+}
+
+impl crate::Normalize for IngressTLS {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.hosts, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.secret_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressTLS {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +156,40 @@ impl crate::serde::Serialize for IngressTLS {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressTLS {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.hosts {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.secret_name {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hosts: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_secret_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_hosts, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_secret_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressTLS {
+            hosts: value_hosts,
+            secret_name: value_secret_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressTLS {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressTLS {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,66 @@ impl crate::DeepMerge for NetworkPolicyEgressRule {
     }
 }
 
+impl crate::Defaulting for NetworkPolicyEgressRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ports);
+        crate::Defaulting::set_defaults(&mut self.to);
+    }
+}
+
+impl crate::diff::Diff for NetworkPolicyEgressRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.ports,
+            &other.ports,
+            &crate::diff::Path::Field { parent: path, name: "ports" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.to,
+            &other.to,
+            &crate::diff::Path::Field { parent: path, name: "to" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NetworkPolicyEgressRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NetworkPolicyEgressRule> {
+    /// The path of the `ports` field.
+    pub fn ports(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::NetworkPolicyPort>> {
+        self.child("ports")
+    }
+
+    /// The path of the `to` field.
+    pub fn to(self) -> crate::FieldPath<std::vec::Vec<crate::api::networking::v1::NetworkPolicyPeer>> {
+        self.child("to")
+    }
+}
+
+impl crate::Normalize for NetworkPolicyEgressRule {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.ports, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.to, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyEgressRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for NetworkPolicyEgressRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NetworkPolicyEgressRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.ports {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.to {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ports: Option<std::vec::Vec<crate::api::networking::v1::NetworkPolicyPort>> = None;
+        let mut value_to: Option<std::vec::Vec<crate::api::networking::v1::NetworkPolicyPeer>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ports, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_to, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NetworkPolicyEgressRule {
+            ports: value_ports,
+            to: value_to,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NetworkPolicyEgressRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyEgressRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,54 @@ impl crate::DeepMerge for NetworkPolicyPeer {
     }
 }
 
+impl crate::Defaulting for NetworkPolicyPeer {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ip_block);
+        crate::Defaulting::set_defaults(&mut self.namespace_selector);
+        crate::Defaulting::set_defaults(&mut self.pod_selector);
+    }
+}
+
+impl crate::diff::Diff for NetworkPolicyPeer {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ip_block, &other.ip_block, &crate::diff::Path::Field { parent: path, name: "ipBlock" }, changes);
+        crate::diff::Diff::diff(&self.namespace_selector, &other.namespace_selector, &crate::diff::Path::Field { parent: path, name: "namespaceSelector" }, changes);
+        crate::diff::Diff::diff(&self.pod_selector, &other.pod_selector, &crate::diff::Path::Field { parent: path, name: "podSelector" }, changes);
+    }
+}
+
+impl NetworkPolicyPeer {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NetworkPolicyPeer> {
+    /// The path of the `ipBlock` field.
+    pub fn ip_block(self) -> crate::FieldPath<crate::api::networking::v1::IPBlock> {
+        self.child("ipBlock")
+    }
+
+    /// The path of the `namespaceSelector` field.
+    pub fn namespace_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("namespaceSelector")
+    }
+
+    /// The path of the `podSelector` field.
+    pub fn pod_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("podSelector")
+    }
+}
+
+impl crate::Normalize for NetworkPolicyPeer {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ip_block);
+        crate::Normalize::normalize(&mut self.namespace_selector);
+        crate::Normalize::normalize(&mut self.pod_selector);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NetworkPolicyPeer {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -124,6 +172,46 @@ impl crate::serde::Serialize for NetworkPolicyPeer {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NetworkPolicyPeer {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.pod_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.namespace_selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.ip_block {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ip_block: Option<crate::api::networking::v1::IPBlock> = None;
+        let mut value_namespace_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_pod_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pod_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_namespace_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_ip_block, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NetworkPolicyPeer {
+            ip_block: value_ip_block,
+            namespace_selector: value_namespace_selector,
+            pod_selector: value_pod_selector,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NetworkPolicyPeer {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkPolicyPeer {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,64 @@ impl crate::DeepMerge for NetworkDeviceData {
     }
 }
 
+impl crate::Defaulting for NetworkDeviceData {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hardware_address);
+        crate::Defaulting::set_defaults(&mut self.interface_name);
+        crate::Defaulting::set_defaults(&mut self.ips);
+    }
+}
+
+impl crate::diff::Diff for NetworkDeviceData {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.hardware_address, &other.hardware_address, &crate::diff::Path::Field { parent: path, name: "hardwareAddress" }, changes);
+        crate::diff::Diff::diff(&self.interface_name, &other.interface_name, &crate::diff::Path::Field { parent: path, name: "interfaceName" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.ips,
+            &other.ips,
+            &crate::diff::Path::Field { parent: path, name: "ips" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NetworkDeviceData {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NetworkDeviceData> {
+    /// The path of the `hardwareAddress` field.
+    pub fn hardware_address(self) -> crate::FieldPath<std::string::String> {
+        self.child("hardwareAddress")
+    }
+
+    /// The path of the `interfaceName` field.
+    pub fn interface_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("interfaceName")
+    }
+
+    /// The path of the `ips` field.
+    pub fn ips(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("ips")
+    }
+}
+
+impl crate::Normalize for NetworkDeviceData {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.hardware_address);
+        crate::Normalize::normalize(&mut self.interface_name);
+        crate::normalize_strategies::list::atomic(&mut self.ips, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NetworkDeviceData {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -124,6 +182,46 @@ impl crate::serde::Serialize for NetworkDeviceData {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NetworkDeviceData {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.interface_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.ips {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.hardware_address {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hardware_address: Option<std::string::String> = None;
+        let mut value_interface_name: Option<std::string::String> = None;
+        let mut value_ips: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_interface_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_ips, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_hardware_address, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NetworkDeviceData {
+            hardware_address: value_hardware_address,
+            interface_name: value_interface_name,
+            ips: value_ips,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NetworkDeviceData {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NetworkDeviceData {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -41,6 +41,38 @@ impl crate::DeepMerge for CELDeviceSelector {
     }
 }
 
+impl crate::Defaulting for CELDeviceSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.expression);
+    }
+}
+
+impl crate::diff::Diff for CELDeviceSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.expression, &other.expression, &crate::diff::Path::Field { parent: path, name: "expression" }, changes);
+    }
+}
+
+impl CELDeviceSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CELDeviceSelector> {
+    /// The path of the `expression` field.
+    pub fn expression(self) -> crate::FieldPath<std::string::String> {
+        self.child("expression")
+    }
+}
+
+impl crate::Normalize for CELDeviceSelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.expression);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CELDeviceSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +150,32 @@ impl crate::serde::Serialize for CELDeviceSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CELDeviceSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.expression, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_expression: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_expression, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CELDeviceSelector {
+            expression: value_expression.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CELDeviceSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CELDeviceSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
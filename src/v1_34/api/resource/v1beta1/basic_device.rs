@@ -93,6 +93,178 @@ impl crate::DeepMerge for BasicDevice {
     }
 }
 
+impl crate::Defaulting for BasicDevice {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.all_nodes);
+        crate::Defaulting::set_defaults(&mut self.allow_multiple_allocations);
+        crate::Defaulting::set_defaults(&mut self.attributes);
+        crate::Defaulting::set_defaults(&mut self.binding_conditions);
+        crate::Defaulting::set_defaults(&mut self.binding_failure_conditions);
+        crate::Defaulting::set_defaults(&mut self.binds_to_node);
+        crate::Defaulting::set_defaults(&mut self.capacity);
+        crate::Defaulting::set_defaults(&mut self.consumes_counters);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.node_selector);
+        crate::Defaulting::set_defaults(&mut self.taints);
+    }
+}
+
+impl crate::diff::Diff for BasicDevice {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.all_nodes, &other.all_nodes, &crate::diff::Path::Field { parent: path, name: "allNodes" }, changes);
+        crate::diff::Diff::diff(&self.allow_multiple_allocations, &other.allow_multiple_allocations, &crate::diff::Path::Field { parent: path, name: "allowMultipleAllocations" }, changes);
+        crate::diff::strategies::map::values(
+            &self.attributes,
+            &other.attributes,
+            &crate::diff::Path::Field { parent: path, name: "attributes" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.binding_conditions,
+            &other.binding_conditions,
+            &crate::diff::Path::Field { parent: path, name: "bindingConditions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.binding_failure_conditions,
+            &other.binding_failure_conditions,
+            &crate::diff::Path::Field { parent: path, name: "bindingFailureConditions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.binds_to_node, &other.binds_to_node, &crate::diff::Path::Field { parent: path, name: "bindsToNode" }, changes);
+        crate::diff::strategies::map::values(
+            &self.capacity,
+            &other.capacity,
+            &crate::diff::Path::Field { parent: path, name: "capacity" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.consumes_counters,
+            &other.consumes_counters,
+            &crate::diff::Path::Field { parent: path, name: "consumesCounters" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.node_selector, &other.node_selector, &crate::diff::Path::Field { parent: path, name: "nodeSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.taints,
+            &other.taints,
+            &crate::diff::Path::Field { parent: path, name: "taints" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl BasicDevice {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<BasicDevice> {
+    /// The path of the `allNodes` field.
+    pub fn all_nodes(self) -> crate::FieldPath<bool> {
+        self.child("allNodes")
+    }
+
+    /// The path of the `allowMultipleAllocations` field.
+    pub fn allow_multiple_allocations(self) -> crate::FieldPath<bool> {
+        self.child("allowMultipleAllocations")
+    }
+
+    /// The path of the `attributes` field.
+    pub fn attributes(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta1::DeviceAttribute>> {
+        self.child("attributes")
+    }
+
+    /// The path of the `bindingConditions` field.
+    pub fn binding_conditions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("bindingConditions")
+    }
+
+    /// The path of the `bindingFailureConditions` field.
+    pub fn binding_failure_conditions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("bindingFailureConditions")
+    }
+
+    /// The path of the `bindsToNode` field.
+    pub fn binds_to_node(self) -> crate::FieldPath<bool> {
+        self.child("bindsToNode")
+    }
+
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta1::DeviceCapacity>> {
+        self.child("capacity")
+    }
+
+    /// The path of the `consumesCounters` field.
+    pub fn consumes_counters(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1beta1::DeviceCounterConsumption>> {
+        self.child("consumesCounters")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `nodeSelector` field.
+    pub fn node_selector(self) -> crate::FieldPath<crate::api::core::v1::NodeSelector> {
+        self.child("nodeSelector")
+    }
+
+    /// The path of the `taints` field.
+    pub fn taints(self) -> crate::FieldPath<std::vec::Vec<crate::api::resource::v1beta1::DeviceTaint>> {
+        self.child("taints")
+    }
+}
+
+impl crate::Normalize for BasicDevice {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.all_nodes);
+        crate::Normalize::normalize(&mut self.allow_multiple_allocations);
+        crate::normalize_strategies::map::values(&mut self.attributes, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.binding_conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.binding_failure_conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.binds_to_node);
+        crate::normalize_strategies::map::values(&mut self.capacity, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.consumes_counters, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.node_selector);
+        crate::normalize_strategies::list::atomic(&mut self.taints, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for BasicDevice {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -272,6 +444,94 @@ impl crate::serde::Serialize for BasicDevice {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for BasicDevice {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.attributes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.consumes_counters {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.node_name {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.node_selector {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.all_nodes {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.taints {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.binds_to_node {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.binding_conditions {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.binding_failure_conditions {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.allow_multiple_allocations {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_all_nodes: Option<bool> = None;
+        let mut value_allow_multiple_allocations: Option<bool> = None;
+        let mut value_attributes: Option<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta1::DeviceAttribute>> = None;
+        let mut value_binding_conditions: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_binding_failure_conditions: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_binds_to_node: Option<bool> = None;
+        let mut value_capacity: Option<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta1::DeviceCapacity>> = None;
+        let mut value_consumes_counters: Option<std::vec::Vec<crate::api::resource::v1beta1::DeviceCounterConsumption>> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_node_selector: Option<crate::api::core::v1::NodeSelector> = None;
+        let mut value_taints: Option<std::vec::Vec<crate::api::resource::v1beta1::DeviceTaint>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_attributes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_consumes_counters, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_node_selector, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_all_nodes, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_taints, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_binds_to_node, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_binding_conditions, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_binding_failure_conditions, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_allow_multiple_allocations, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(BasicDevice {
+            all_nodes: value_all_nodes,
+            allow_multiple_allocations: value_allow_multiple_allocations,
+            attributes: value_attributes,
+            binding_conditions: value_binding_conditions,
+            binding_failure_conditions: value_binding_failure_conditions,
+            binds_to_node: value_binds_to_node,
+            capacity: value_capacity,
+            consumes_counters: value_consumes_counters,
+            node_name: value_node_name,
+            node_selector: value_node_selector,
+            taints: value_taints,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for BasicDevice {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for BasicDevice {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for DeviceAttribute {
     }
 }
 
+impl crate::Defaulting for DeviceAttribute {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.bool);
+        crate::Defaulting::set_defaults(&mut self.int);
+        crate::Defaulting::set_defaults(&mut self.string);
+        crate::Defaulting::set_defaults(&mut self.version);
+    }
+}
+
+impl crate::diff::Diff for DeviceAttribute {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.bool, &other.bool, &crate::diff::Path::Field { parent: path, name: "bool" }, changes);
+        crate::diff::Diff::diff(&self.int, &other.int, &crate::diff::Path::Field { parent: path, name: "int" }, changes);
+        crate::diff::Diff::diff(&self.string, &other.string, &crate::diff::Path::Field { parent: path, name: "string" }, changes);
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+    }
+}
+
+impl DeviceAttribute {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceAttribute> {
+    /// The path of the `bool` field.
+    pub fn bool(self) -> crate::FieldPath<bool> {
+        self.child("bool")
+    }
+
+    /// The path of the `int` field.
+    pub fn int(self) -> crate::FieldPath<i64> {
+        self.child("int")
+    }
+
+    /// The path of the `string` field.
+    pub fn string(self) -> crate::FieldPath<std::string::String> {
+        self.child("string")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+}
+
+impl crate::Normalize for DeviceAttribute {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.bool);
+        crate::Normalize::normalize(&mut self.int);
+        crate::Normalize::normalize(&mut self.string);
+        crate::Normalize::normalize(&mut self.version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceAttribute {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -134,6 +190,52 @@ impl crate::serde::Serialize for DeviceAttribute {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceAttribute {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.int {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.bool {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.string {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.version {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_bool: Option<bool> = None;
+        let mut value_int: Option<i64> = None;
+        let mut value_string: Option<std::string::String> = None;
+        let mut value_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                2 => crate::protobuf::Field::merge_field(&mut value_int, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_bool, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_string, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceAttribute {
+            bool: value_bool,
+            int: value_int,
+            string: value_string,
+            version: value_version,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceAttribute {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceAttribute {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,70 @@ impl crate::DeepMerge for DeviceToleration {
     }
 }
 
+impl crate::Defaulting for DeviceToleration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.effect);
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.operator);
+        crate::Defaulting::set_defaults(&mut self.toleration_seconds);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for DeviceToleration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.effect, &other.effect, &crate::diff::Path::Field { parent: path, name: "effect" }, changes);
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.operator, &other.operator, &crate::diff::Path::Field { parent: path, name: "operator" }, changes);
+        crate::diff::Diff::diff(&self.toleration_seconds, &other.toleration_seconds, &crate::diff::Path::Field { parent: path, name: "tolerationSeconds" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl DeviceToleration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceToleration> {
+    /// The path of the `effect` field.
+    pub fn effect(self) -> crate::FieldPath<std::string::String> {
+        self.child("effect")
+    }
+
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `operator` field.
+    pub fn operator(self) -> crate::FieldPath<std::string::String> {
+        self.child("operator")
+    }
+
+    /// The path of the `tolerationSeconds` field.
+    pub fn toleration_seconds(self) -> crate::FieldPath<i64> {
+        self.child("tolerationSeconds")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<std::string::String> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for DeviceToleration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.effect);
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.operator);
+        crate::Normalize::normalize(&mut self.toleration_seconds);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceToleration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -148,6 +212,58 @@ impl crate::serde::Serialize for DeviceToleration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceToleration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.key {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.operator {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.value {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.effect {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.toleration_seconds {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_effect: Option<std::string::String> = None;
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_operator: Option<std::string::String> = None;
+        let mut value_toleration_seconds: Option<i64> = None;
+        let mut value_value: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_operator, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_effect, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_toleration_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceToleration {
+            effect: value_effect,
+            key: value_key,
+            operator: value_operator,
+            toleration_seconds: value_toleration_seconds,
+            value: value_value,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceToleration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceToleration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -54,6 +54,74 @@ impl crate::DeepMerge for PriorityClass {
     }
 }
 
+impl crate::Defaulting for PriorityClass {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.description);
+        crate::Defaulting::set_defaults(&mut self.global_default);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.preemption_policy);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for PriorityClass {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.description, &other.description, &crate::diff::Path::Field { parent: path, name: "description" }, changes);
+        crate::diff::Diff::diff(&self.global_default, &other.global_default, &crate::diff::Path::Field { parent: path, name: "globalDefault" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.preemption_policy, &other.preemption_policy, &crate::diff::Path::Field { parent: path, name: "preemptionPolicy" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl PriorityClass {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PriorityClass> {
+    /// The path of the `description` field.
+    pub fn description(self) -> crate::FieldPath<std::string::String> {
+        self.child("description")
+    }
+
+    /// The path of the `globalDefault` field.
+    pub fn global_default(self) -> crate::FieldPath<bool> {
+        self.child("globalDefault")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `preemptionPolicy` field.
+    pub fn preemption_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("preemptionPolicy")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<i32> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for PriorityClass {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.description);
+        crate::Normalize::normalize(&mut self.global_default);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.preemption_policy);
+        crate::Normalize::normalize(&mut self.value);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PriorityClass {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -188,6 +256,64 @@ impl crate::serde::Serialize for PriorityClass {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PriorityClass {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.value, 2, buf);
+        if let Some(value) = &self.global_default {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.description {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.preemption_policy {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_description: Option<std::string::String> = None;
+        let mut value_global_default: Option<bool> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_preemption_policy: Option<std::string::String> = None;
+        let mut value_value: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_global_default, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_description, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_preemption_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PriorityClass {
+            description: value_description,
+            global_default: value_global_default,
+            metadata: value_metadata.unwrap_or_default(),
+            preemption_policy: value_preemption_policy,
+            value: value_value.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PriorityClass {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PriorityClass {
     fn schema_name() -> std::borrow::Cow<'static, str> {
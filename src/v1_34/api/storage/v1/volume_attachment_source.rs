@@ -17,6 +17,46 @@ impl crate::DeepMerge for VolumeAttachmentSource {
     }
 }
 
+impl crate::Defaulting for VolumeAttachmentSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.inline_volume_spec);
+        crate::Defaulting::set_defaults(&mut self.persistent_volume_name);
+    }
+}
+
+impl crate::diff::Diff for VolumeAttachmentSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.inline_volume_spec, &other.inline_volume_spec, &crate::diff::Path::Field { parent: path, name: "inlineVolumeSpec" }, changes);
+        crate::diff::Diff::diff(&self.persistent_volume_name, &other.persistent_volume_name, &crate::diff::Path::Field { parent: path, name: "persistentVolumeName" }, changes);
+    }
+}
+
+impl VolumeAttachmentSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeAttachmentSource> {
+    /// The path of the `inlineVolumeSpec` field.
+    pub fn inline_volume_spec(self) -> crate::FieldPath<crate::api::core::v1::PersistentVolumeSpec> {
+        self.child("inlineVolumeSpec")
+    }
+
+    /// The path of the `persistentVolumeName` field.
+    pub fn persistent_volume_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("persistentVolumeName")
+    }
+}
+
+impl crate::Normalize for VolumeAttachmentSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.inline_volume_spec);
+        crate::Normalize::normalize(&mut self.persistent_volume_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeAttachmentSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for VolumeAttachmentSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeAttachmentSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.persistent_volume_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.inline_volume_spec {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_inline_volume_spec: Option<crate::api::core::v1::PersistentVolumeSpec> = None;
+        let mut value_persistent_volume_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_persistent_volume_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_inline_volume_spec, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeAttachmentSource {
+            inline_volume_spec: value_inline_volume_spec,
+            persistent_volume_name: value_persistent_volume_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeAttachmentSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttachmentSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
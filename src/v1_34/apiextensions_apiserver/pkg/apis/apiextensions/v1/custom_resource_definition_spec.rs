@@ -33,6 +33,88 @@ impl crate::DeepMerge for CustomResourceDefinitionSpec {
     }
 }
 
+impl crate::Defaulting for CustomResourceDefinitionSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.conversion);
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.names);
+        crate::Defaulting::set_defaults(&mut self.preserve_unknown_fields);
+        crate::Defaulting::set_defaults(&mut self.scope);
+        crate::Defaulting::set_defaults(&mut self.versions);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceDefinitionSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.conversion, &other.conversion, &crate::diff::Path::Field { parent: path, name: "conversion" }, changes);
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.names, &other.names, &crate::diff::Path::Field { parent: path, name: "names" }, changes);
+        crate::diff::Diff::diff(&self.preserve_unknown_fields, &other.preserve_unknown_fields, &crate::diff::Path::Field { parent: path, name: "preserveUnknownFields" }, changes);
+        crate::diff::Diff::diff(&self.scope, &other.scope, &crate::diff::Path::Field { parent: path, name: "scope" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.versions,
+            &other.versions,
+            &crate::diff::Path::Field { parent: path, name: "versions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl CustomResourceDefinitionSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceDefinitionSpec> {
+    /// The path of the `conversion` field.
+    pub fn conversion(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion> {
+        self.child("conversion")
+    }
+
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `names` field.
+    pub fn names(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames> {
+        self.child("names")
+    }
+
+    /// The path of the `preserveUnknownFields` field.
+    pub fn preserve_unknown_fields(self) -> crate::FieldPath<bool> {
+        self.child("preserveUnknownFields")
+    }
+
+    /// The path of the `scope` field.
+    pub fn scope(self) -> crate::FieldPath<std::string::String> {
+        self.child("scope")
+    }
+
+    /// The path of the `versions` field.
+    pub fn versions(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion>> {
+        self.child("versions")
+    }
+}
+
+impl crate::Normalize for CustomResourceDefinitionSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.conversion);
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.names);
+        crate::Normalize::normalize(&mut self.preserve_unknown_fields);
+        crate::Normalize::normalize(&mut self.scope);
+        crate::normalize_strategies::list::atomic(&mut self.versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -151,6 +233,66 @@ impl crate::serde::Serialize for CustomResourceDefinitionSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceDefinitionSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.group, 1, buf);
+        crate::protobuf::Field::encode_field(&self.names, 3, buf);
+        crate::protobuf::Field::encode_field(&self.scope, 4, buf);
+        crate::protobuf::Field::encode_field(&self.versions, 7, buf);
+        if let Some(value) = &self.conversion {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.preserve_unknown_fields {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_conversion: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceConversion> = None;
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_names: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionNames> = None;
+        let mut value_preserve_unknown_fields: Option<bool> = None;
+        let mut value_scope: Option<std::string::String> = None;
+        let mut value_versions: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_names, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_scope, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_versions, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_conversion, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_preserve_unknown_fields, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceDefinitionSpec {
+            conversion: value_conversion,
+            group: value_group.unwrap_or_default(),
+            names: value_names.unwrap_or_default(),
+            preserve_unknown_fields: value_preserve_unknown_fields,
+            scope: value_scope.unwrap_or_default(),
+            versions: value_versions.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceDefinitionSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "versions" => <std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionVersion> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -45,6 +45,122 @@ impl crate::DeepMerge for CustomResourceDefinitionVersion {
     }
 }
 
+impl crate::Defaulting for CustomResourceDefinitionVersion {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.additional_printer_columns);
+        crate::Defaulting::set_defaults(&mut self.deprecated);
+        crate::Defaulting::set_defaults(&mut self.deprecation_warning);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.schema);
+        crate::Defaulting::set_defaults(&mut self.selectable_fields);
+        crate::Defaulting::set_defaults(&mut self.served);
+        crate::Defaulting::set_defaults(&mut self.storage);
+        crate::Defaulting::set_defaults(&mut self.subresources);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceDefinitionVersion {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.additional_printer_columns,
+            &other.additional_printer_columns,
+            &crate::diff::Path::Field { parent: path, name: "additionalPrinterColumns" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.deprecated, &other.deprecated, &crate::diff::Path::Field { parent: path, name: "deprecated" }, changes);
+        crate::diff::Diff::diff(&self.deprecation_warning, &other.deprecation_warning, &crate::diff::Path::Field { parent: path, name: "deprecationWarning" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.schema, &other.schema, &crate::diff::Path::Field { parent: path, name: "schema" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.selectable_fields,
+            &other.selectable_fields,
+            &crate::diff::Path::Field { parent: path, name: "selectableFields" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.served, &other.served, &crate::diff::Path::Field { parent: path, name: "served" }, changes);
+        crate::diff::Diff::diff(&self.storage, &other.storage, &crate::diff::Path::Field { parent: path, name: "storage" }, changes);
+        crate::diff::Diff::diff(&self.subresources, &other.subresources, &crate::diff::Path::Field { parent: path, name: "subresources" }, changes);
+    }
+}
+
+impl CustomResourceDefinitionVersion {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceDefinitionVersion> {
+    /// The path of the `additionalPrinterColumns` field.
+    pub fn additional_printer_columns(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition>> {
+        self.child("additionalPrinterColumns")
+    }
+
+    /// The path of the `deprecated` field.
+    pub fn deprecated(self) -> crate::FieldPath<bool> {
+        self.child("deprecated")
+    }
+
+    /// The path of the `deprecationWarning` field.
+    pub fn deprecation_warning(self) -> crate::FieldPath<std::string::String> {
+        self.child("deprecationWarning")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `schema` field.
+    pub fn schema(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation> {
+        self.child("schema")
+    }
+
+    /// The path of the `selectableFields` field.
+    pub fn selectable_fields(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField>> {
+        self.child("selectableFields")
+    }
+
+    /// The path of the `served` field.
+    pub fn served(self) -> crate::FieldPath<bool> {
+        self.child("served")
+    }
+
+    /// The path of the `storage` field.
+    pub fn storage(self) -> crate::FieldPath<bool> {
+        self.child("storage")
+    }
+
+    /// The path of the `subresources` field.
+    pub fn subresources(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources> {
+        self.child("subresources")
+    }
+}
+
+impl crate::Normalize for CustomResourceDefinitionVersion {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.additional_printer_columns, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.deprecated);
+        crate::Normalize::normalize(&mut self.deprecation_warning);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.schema);
+        crate::normalize_strategies::list::atomic(&mut self.selectable_fields, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.served);
+        crate::Normalize::normalize(&mut self.storage);
+        crate::Normalize::normalize(&mut self.subresources);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionVersion {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -196,6 +312,86 @@ impl crate::serde::Serialize for CustomResourceDefinitionVersion {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceDefinitionVersion {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.served, 2, buf);
+        crate::protobuf::Field::encode_field(&self.storage, 3, buf);
+        if let Some(value) = &self.schema {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.subresources {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.additional_printer_columns {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.deprecated {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.deprecation_warning {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.selectable_fields {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_additional_printer_columns: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition>> = None;
+        let mut value_deprecated: Option<bool> = None;
+        let mut value_deprecation_warning: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_schema: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation> = None;
+        let mut value_selectable_fields: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::SelectableField>> = None;
+        let mut value_served: Option<bool> = None;
+        let mut value_storage: Option<bool> = None;
+        let mut value_subresources: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresources> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_served, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_storage, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_schema, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_subresources, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_additional_printer_columns, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_deprecated, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_deprecation_warning, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_selectable_fields, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceDefinitionVersion {
+            additional_printer_columns: value_additional_printer_columns,
+            deprecated: value_deprecated,
+            deprecation_warning: value_deprecation_warning,
+            name: value_name.unwrap_or_default(),
+            schema: value_schema,
+            selectable_fields: value_selectable_fields,
+            served: value_served.unwrap_or_default(),
+            storage: value_storage.unwrap_or_default(),
+            subresources: value_subresources,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceDefinitionVersion {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "schema" => <Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceValidation> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionVersion {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for CustomResourceSubresources {
     }
 }
 
+impl crate::Defaulting for CustomResourceSubresources {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.scale);
+        crate::Defaulting::set_defaults(&mut self.status);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceSubresources {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.scale, &other.scale, &crate::diff::Path::Field { parent: path, name: "scale" }, changes);
+        crate::diff::Diff::diff(&self.status, &other.status, &crate::diff::Path::Field { parent: path, name: "status" }, changes);
+    }
+}
+
+impl CustomResourceSubresources {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceSubresources> {
+    /// The path of the `scale` field.
+    pub fn scale(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale> {
+        self.child("scale")
+    }
+
+    /// The path of the `status` field.
+    pub fn status(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus> {
+        self.child("status")
+    }
+}
+
+impl crate::Normalize for CustomResourceSubresources {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.scale);
+        crate::Normalize::normalize(&mut self.status);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceSubresources {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for CustomResourceSubresources {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceSubresources {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.status {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.scale {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_scale: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceScale> = None;
+        let mut value_status: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceSubresourceStatus> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_status, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_scale, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceSubresources {
+            scale: value_scale,
+            status: value_status,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceSubresources {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceSubresources {
     fn schema_name() -> std::borrow::Cow<'static, str> {
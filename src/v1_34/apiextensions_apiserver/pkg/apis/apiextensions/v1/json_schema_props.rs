@@ -200,6 +200,495 @@ impl crate::DeepMerge for JSONSchemaProps {
     }
 }
 
+impl crate::Defaulting for JSONSchemaProps {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ref_path);
+        crate::Defaulting::set_defaults(&mut self.schema);
+        crate::Defaulting::set_defaults(&mut self.additional_items);
+        crate::Defaulting::set_defaults(&mut self.additional_properties);
+        crate::Defaulting::set_defaults(&mut self.all_of);
+        crate::Defaulting::set_defaults(&mut self.any_of);
+        crate::Defaulting::set_defaults(&mut self.default);
+        crate::Defaulting::set_defaults(&mut self.definitions);
+        crate::Defaulting::set_defaults(&mut self.dependencies);
+        crate::Defaulting::set_defaults(&mut self.description);
+        crate::Defaulting::set_defaults(&mut self.enum_);
+        crate::Defaulting::set_defaults(&mut self.example);
+        crate::Defaulting::set_defaults(&mut self.exclusive_maximum);
+        crate::Defaulting::set_defaults(&mut self.exclusive_minimum);
+        crate::Defaulting::set_defaults(&mut self.external_docs);
+        crate::Defaulting::set_defaults(&mut self.format);
+        crate::Defaulting::set_defaults(&mut self.id);
+        crate::Defaulting::set_defaults(&mut self.items);
+        crate::Defaulting::set_defaults(&mut self.max_items);
+        crate::Defaulting::set_defaults(&mut self.max_length);
+        crate::Defaulting::set_defaults(&mut self.max_properties);
+        crate::Defaulting::set_defaults(&mut self.maximum);
+        crate::Defaulting::set_defaults(&mut self.min_items);
+        crate::Defaulting::set_defaults(&mut self.min_length);
+        crate::Defaulting::set_defaults(&mut self.min_properties);
+        crate::Defaulting::set_defaults(&mut self.minimum);
+        crate::Defaulting::set_defaults(&mut self.multiple_of);
+        crate::Defaulting::set_defaults(&mut self.not);
+        crate::Defaulting::set_defaults(&mut self.nullable);
+        crate::Defaulting::set_defaults(&mut self.one_of);
+        crate::Defaulting::set_defaults(&mut self.pattern);
+        crate::Defaulting::set_defaults(&mut self.pattern_properties);
+        crate::Defaulting::set_defaults(&mut self.properties);
+        crate::Defaulting::set_defaults(&mut self.required);
+        crate::Defaulting::set_defaults(&mut self.title);
+        crate::Defaulting::set_defaults(&mut self.type_);
+        crate::Defaulting::set_defaults(&mut self.unique_items);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_embedded_resource);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_int_or_string);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_list_map_keys);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_list_type);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_map_type);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_preserve_unknown_fields);
+        crate::Defaulting::set_defaults(&mut self.x_kubernetes_validations);
+    }
+}
+
+impl crate::diff::Diff for JSONSchemaProps {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ref_path, &other.ref_path, &crate::diff::Path::Field { parent: path, name: "$ref" }, changes);
+        crate::diff::Diff::diff(&self.schema, &other.schema, &crate::diff::Path::Field { parent: path, name: "$schema" }, changes);
+        crate::diff::Diff::diff(&self.additional_items, &other.additional_items, &crate::diff::Path::Field { parent: path, name: "additionalItems" }, changes);
+        crate::diff::Diff::diff(&self.additional_properties, &other.additional_properties, &crate::diff::Path::Field { parent: path, name: "additionalProperties" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.all_of,
+            &other.all_of,
+            &crate::diff::Path::Field { parent: path, name: "allOf" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.any_of,
+            &other.any_of,
+            &crate::diff::Path::Field { parent: path, name: "anyOf" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.default, &other.default, &crate::diff::Path::Field { parent: path, name: "default" }, changes);
+        crate::diff::strategies::map::values(
+            &self.definitions,
+            &other.definitions,
+            &crate::diff::Path::Field { parent: path, name: "definitions" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.dependencies,
+            &other.dependencies,
+            &crate::diff::Path::Field { parent: path, name: "dependencies" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.description, &other.description, &crate::diff::Path::Field { parent: path, name: "description" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.enum_,
+            &other.enum_,
+            &crate::diff::Path::Field { parent: path, name: "enum" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.example, &other.example, &crate::diff::Path::Field { parent: path, name: "example" }, changes);
+        crate::diff::Diff::diff(&self.exclusive_maximum, &other.exclusive_maximum, &crate::diff::Path::Field { parent: path, name: "exclusiveMaximum" }, changes);
+        crate::diff::Diff::diff(&self.exclusive_minimum, &other.exclusive_minimum, &crate::diff::Path::Field { parent: path, name: "exclusiveMinimum" }, changes);
+        crate::diff::Diff::diff(&self.external_docs, &other.external_docs, &crate::diff::Path::Field { parent: path, name: "externalDocs" }, changes);
+        crate::diff::Diff::diff(&self.format, &other.format, &crate::diff::Path::Field { parent: path, name: "format" }, changes);
+        crate::diff::Diff::diff(&self.id, &other.id, &crate::diff::Path::Field { parent: path, name: "id" }, changes);
+        crate::diff::Diff::diff(&self.items, &other.items, &crate::diff::Path::Field { parent: path, name: "items" }, changes);
+        crate::diff::Diff::diff(&self.max_items, &other.max_items, &crate::diff::Path::Field { parent: path, name: "maxItems" }, changes);
+        crate::diff::Diff::diff(&self.max_length, &other.max_length, &crate::diff::Path::Field { parent: path, name: "maxLength" }, changes);
+        crate::diff::Diff::diff(&self.max_properties, &other.max_properties, &crate::diff::Path::Field { parent: path, name: "maxProperties" }, changes);
+        crate::diff::Diff::diff(&self.maximum, &other.maximum, &crate::diff::Path::Field { parent: path, name: "maximum" }, changes);
+        crate::diff::Diff::diff(&self.min_items, &other.min_items, &crate::diff::Path::Field { parent: path, name: "minItems" }, changes);
+        crate::diff::Diff::diff(&self.min_length, &other.min_length, &crate::diff::Path::Field { parent: path, name: "minLength" }, changes);
+        crate::diff::Diff::diff(&self.min_properties, &other.min_properties, &crate::diff::Path::Field { parent: path, name: "minProperties" }, changes);
+        crate::diff::Diff::diff(&self.minimum, &other.minimum, &crate::diff::Path::Field { parent: path, name: "minimum" }, changes);
+        crate::diff::Diff::diff(&self.multiple_of, &other.multiple_of, &crate::diff::Path::Field { parent: path, name: "multipleOf" }, changes);
+        crate::diff::Diff::diff(&self.not, &other.not, &crate::diff::Path::Field { parent: path, name: "not" }, changes);
+        crate::diff::Diff::diff(&self.nullable, &other.nullable, &crate::diff::Path::Field { parent: path, name: "nullable" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.one_of,
+            &other.one_of,
+            &crate::diff::Path::Field { parent: path, name: "oneOf" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.pattern, &other.pattern, &crate::diff::Path::Field { parent: path, name: "pattern" }, changes);
+        crate::diff::strategies::map::values(
+            &self.pattern_properties,
+            &other.pattern_properties,
+            &crate::diff::Path::Field { parent: path, name: "patternProperties" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.properties,
+            &other.properties,
+            &crate::diff::Path::Field { parent: path, name: "properties" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.required,
+            &other.required,
+            &crate::diff::Path::Field { parent: path, name: "required" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.title, &other.title, &crate::diff::Path::Field { parent: path, name: "title" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+        crate::diff::Diff::diff(&self.unique_items, &other.unique_items, &crate::diff::Path::Field { parent: path, name: "uniqueItems" }, changes);
+        crate::diff::Diff::diff(&self.x_kubernetes_embedded_resource, &other.x_kubernetes_embedded_resource, &crate::diff::Path::Field { parent: path, name: "x-kubernetes-embedded-resource" }, changes);
+        crate::diff::Diff::diff(&self.x_kubernetes_int_or_string, &other.x_kubernetes_int_or_string, &crate::diff::Path::Field { parent: path, name: "x-kubernetes-int-or-string" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.x_kubernetes_list_map_keys,
+            &other.x_kubernetes_list_map_keys,
+            &crate::diff::Path::Field { parent: path, name: "x-kubernetes-list-map-keys" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.x_kubernetes_list_type, &other.x_kubernetes_list_type, &crate::diff::Path::Field { parent: path, name: "x-kubernetes-list-type" }, changes);
+        crate::diff::Diff::diff(&self.x_kubernetes_map_type, &other.x_kubernetes_map_type, &crate::diff::Path::Field { parent: path, name: "x-kubernetes-map-type" }, changes);
+        crate::diff::Diff::diff(&self.x_kubernetes_preserve_unknown_fields, &other.x_kubernetes_preserve_unknown_fields, &crate::diff::Path::Field { parent: path, name: "x-kubernetes-preserve-unknown-fields" }, changes);
+        crate::diff::strategies::list::map(
+            &self.x_kubernetes_validations,
+            &other.x_kubernetes_validations,
+            &crate::diff::Path::Field { parent: path, name: "x-kubernetes-validations" },
+            changes,
+            &[("rule", |item| crate::diff::MergeKey::merge_key(&item.rule))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl JSONSchemaProps {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<JSONSchemaProps> {
+    /// The path of the `$ref` field.
+    pub fn ref_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("$ref")
+    }
+
+    /// The path of the `$schema` field.
+    pub fn schema(self) -> crate::FieldPath<std::string::String> {
+        self.child("$schema")
+    }
+
+    /// The path of the `additionalItems` field.
+    pub fn additional_items(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool> {
+        self.child("additionalItems")
+    }
+
+    /// The path of the `additionalProperties` field.
+    pub fn additional_properties(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool> {
+        self.child("additionalProperties")
+    }
+
+    /// The path of the `allOf` field.
+    pub fn all_of(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("allOf")
+    }
+
+    /// The path of the `anyOf` field.
+    pub fn any_of(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("anyOf")
+    }
+
+    /// The path of the `default` field.
+    pub fn default(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON> {
+        self.child("default")
+    }
+
+    /// The path of the `definitions` field.
+    pub fn definitions(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("definitions")
+    }
+
+    /// The path of the `dependencies` field.
+    pub fn dependencies(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray>> {
+        self.child("dependencies")
+    }
+
+    /// The path of the `description` field.
+    pub fn description(self) -> crate::FieldPath<std::string::String> {
+        self.child("description")
+    }
+
+    /// The path of the `enum` field.
+    pub fn enum_(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON>> {
+        self.child("enum")
+    }
+
+    /// The path of the `example` field.
+    pub fn example(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON> {
+        self.child("example")
+    }
+
+    /// The path of the `exclusiveMaximum` field.
+    pub fn exclusive_maximum(self) -> crate::FieldPath<bool> {
+        self.child("exclusiveMaximum")
+    }
+
+    /// The path of the `exclusiveMinimum` field.
+    pub fn exclusive_minimum(self) -> crate::FieldPath<bool> {
+        self.child("exclusiveMinimum")
+    }
+
+    /// The path of the `externalDocs` field.
+    pub fn external_docs(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation> {
+        self.child("externalDocs")
+    }
+
+    /// The path of the `format` field.
+    pub fn format(self) -> crate::FieldPath<std::string::String> {
+        self.child("format")
+    }
+
+    /// The path of the `id` field.
+    pub fn id(self) -> crate::FieldPath<std::string::String> {
+        self.child("id")
+    }
+
+    /// The path of the `items` field.
+    pub fn items(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray> {
+        self.child("items")
+    }
+
+    /// The path of the `maxItems` field.
+    pub fn max_items(self) -> crate::FieldPath<i64> {
+        self.child("maxItems")
+    }
+
+    /// The path of the `maxLength` field.
+    pub fn max_length(self) -> crate::FieldPath<i64> {
+        self.child("maxLength")
+    }
+
+    /// The path of the `maxProperties` field.
+    pub fn max_properties(self) -> crate::FieldPath<i64> {
+        self.child("maxProperties")
+    }
+
+    /// The path of the `maximum` field.
+    pub fn maximum(self) -> crate::FieldPath<f64> {
+        self.child("maximum")
+    }
+
+    /// The path of the `minItems` field.
+    pub fn min_items(self) -> crate::FieldPath<i64> {
+        self.child("minItems")
+    }
+
+    /// The path of the `minLength` field.
+    pub fn min_length(self) -> crate::FieldPath<i64> {
+        self.child("minLength")
+    }
+
+    /// The path of the `minProperties` field.
+    pub fn min_properties(self) -> crate::FieldPath<i64> {
+        self.child("minProperties")
+    }
+
+    /// The path of the `minimum` field.
+    pub fn minimum(self) -> crate::FieldPath<f64> {
+        self.child("minimum")
+    }
+
+    /// The path of the `multipleOf` field.
+    pub fn multiple_of(self) -> crate::FieldPath<f64> {
+        self.child("multipleOf")
+    }
+
+    /// The path of the `not` field.
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn not(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps> {
+        self.child("not")
+    }
+
+    /// The path of the `nullable` field.
+    pub fn nullable(self) -> crate::FieldPath<bool> {
+        self.child("nullable")
+    }
+
+    /// The path of the `oneOf` field.
+    pub fn one_of(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("oneOf")
+    }
+
+    /// The path of the `pattern` field.
+    pub fn pattern(self) -> crate::FieldPath<std::string::String> {
+        self.child("pattern")
+    }
+
+    /// The path of the `patternProperties` field.
+    pub fn pattern_properties(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("patternProperties")
+    }
+
+    /// The path of the `properties` field.
+    pub fn properties(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> {
+        self.child("properties")
+    }
+
+    /// The path of the `required` field.
+    pub fn required(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("required")
+    }
+
+    /// The path of the `title` field.
+    pub fn title(self) -> crate::FieldPath<std::string::String> {
+        self.child("title")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+
+    /// The path of the `uniqueItems` field.
+    pub fn unique_items(self) -> crate::FieldPath<bool> {
+        self.child("uniqueItems")
+    }
+
+    /// The path of the `x-kubernetes-embedded-resource` field.
+    pub fn x_kubernetes_embedded_resource(self) -> crate::FieldPath<bool> {
+        self.child("x-kubernetes-embedded-resource")
+    }
+
+    /// The path of the `x-kubernetes-int-or-string` field.
+    pub fn x_kubernetes_int_or_string(self) -> crate::FieldPath<bool> {
+        self.child("x-kubernetes-int-or-string")
+    }
+
+    /// The path of the `x-kubernetes-list-map-keys` field.
+    pub fn x_kubernetes_list_map_keys(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("x-kubernetes-list-map-keys")
+    }
+
+    /// The path of the `x-kubernetes-list-type` field.
+    pub fn x_kubernetes_list_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("x-kubernetes-list-type")
+    }
+
+    /// The path of the `x-kubernetes-map-type` field.
+    pub fn x_kubernetes_map_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("x-kubernetes-map-type")
+    }
+
+    /// The path of the `x-kubernetes-preserve-unknown-fields` field.
+    pub fn x_kubernetes_preserve_unknown_fields(self) -> crate::FieldPath<bool> {
+        self.child("x-kubernetes-preserve-unknown-fields")
+    }
+
+    /// The path of the `x-kubernetes-validations` field.
+    pub fn x_kubernetes_validations(self) -> crate::FieldPath<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule>> {
+        self.child("x-kubernetes-validations")
+    }
+}
+
+impl crate::Normalize for JSONSchemaProps {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ref_path);
+        crate::Normalize::normalize(&mut self.schema);
+        crate::Normalize::normalize(&mut self.additional_items);
+        crate::Normalize::normalize(&mut self.additional_properties);
+        crate::normalize_strategies::list::atomic(&mut self.all_of, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.any_of, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.default);
+        crate::normalize_strategies::map::values(&mut self.definitions, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.dependencies, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.description);
+        crate::normalize_strategies::list::atomic(&mut self.enum_, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.example);
+        crate::Normalize::normalize(&mut self.exclusive_maximum);
+        crate::Normalize::normalize(&mut self.exclusive_minimum);
+        crate::Normalize::normalize(&mut self.external_docs);
+        crate::Normalize::normalize(&mut self.format);
+        crate::Normalize::normalize(&mut self.id);
+        crate::Normalize::normalize(&mut self.items);
+        crate::Normalize::normalize(&mut self.max_items);
+        crate::Normalize::normalize(&mut self.max_length);
+        crate::Normalize::normalize(&mut self.max_properties);
+        crate::Normalize::normalize(&mut self.maximum);
+        crate::Normalize::normalize(&mut self.min_items);
+        crate::Normalize::normalize(&mut self.min_length);
+        crate::Normalize::normalize(&mut self.min_properties);
+        crate::Normalize::normalize(&mut self.minimum);
+        crate::Normalize::normalize(&mut self.multiple_of);
+        crate::Normalize::normalize(&mut self.not);
+        crate::Normalize::normalize(&mut self.nullable);
+        crate::normalize_strategies::list::atomic(&mut self.one_of, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.pattern);
+        crate::normalize_strategies::map::values(&mut self.pattern_properties, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.properties, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.required, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.title);
+        crate::Normalize::normalize(&mut self.type_);
+        crate::Normalize::normalize(&mut self.unique_items);
+        crate::Normalize::normalize(&mut self.x_kubernetes_embedded_resource);
+        crate::Normalize::normalize(&mut self.x_kubernetes_int_or_string);
+        crate::normalize_strategies::list::atomic(&mut self.x_kubernetes_list_map_keys, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.x_kubernetes_list_type);
+        crate::Normalize::normalize(&mut self.x_kubernetes_map_type);
+        crate::Normalize::normalize(&mut self.x_kubernetes_preserve_unknown_fields);
+        crate::normalize_strategies::list::map(&mut self.x_kubernetes_validations, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JSONSchemaProps {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -709,6 +1198,308 @@ impl crate::serde::Serialize for JSONSchemaProps {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JSONSchemaProps {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.id {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.schema {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.ref_path {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.description {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.format {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.title {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.default {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.maximum {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.exclusive_maximum {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.minimum {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.exclusive_minimum {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.max_length {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.min_length {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.pattern {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+        if let Some(value) = &self.max_items {
+            crate::protobuf::Field::encode_field(value, 16, buf);
+        }
+        if let Some(value) = &self.min_items {
+            crate::protobuf::Field::encode_field(value, 17, buf);
+        }
+        if let Some(value) = &self.unique_items {
+            crate::protobuf::Field::encode_field(value, 18, buf);
+        }
+        if let Some(value) = &self.multiple_of {
+            crate::protobuf::Field::encode_field(value, 19, buf);
+        }
+        if let Some(value) = &self.enum_ {
+            crate::protobuf::Field::encode_field(value, 20, buf);
+        }
+        if let Some(value) = &self.max_properties {
+            crate::protobuf::Field::encode_field(value, 21, buf);
+        }
+        if let Some(value) = &self.min_properties {
+            crate::protobuf::Field::encode_field(value, 22, buf);
+        }
+        if let Some(value) = &self.required {
+            crate::protobuf::Field::encode_field(value, 23, buf);
+        }
+        if let Some(value) = &self.items {
+            crate::protobuf::Field::encode_field(value, 24, buf);
+        }
+        if let Some(value) = &self.all_of {
+            crate::protobuf::Field::encode_field(value, 25, buf);
+        }
+        if let Some(value) = &self.one_of {
+            crate::protobuf::Field::encode_field(value, 26, buf);
+        }
+        if let Some(value) = &self.any_of {
+            crate::protobuf::Field::encode_field(value, 27, buf);
+        }
+        if let Some(value) = &self.not {
+            crate::protobuf::Field::encode_field(value, 28, buf);
+        }
+        if let Some(value) = &self.properties {
+            crate::protobuf::Field::encode_field(value, 29, buf);
+        }
+        if let Some(value) = &self.additional_properties {
+            crate::protobuf::Field::encode_field(value, 30, buf);
+        }
+        if let Some(value) = &self.pattern_properties {
+            crate::protobuf::Field::encode_field(value, 31, buf);
+        }
+        if let Some(value) = &self.dependencies {
+            crate::protobuf::Field::encode_field(value, 32, buf);
+        }
+        if let Some(value) = &self.additional_items {
+            crate::protobuf::Field::encode_field(value, 33, buf);
+        }
+        if let Some(value) = &self.definitions {
+            crate::protobuf::Field::encode_field(value, 34, buf);
+        }
+        if let Some(value) = &self.external_docs {
+            crate::protobuf::Field::encode_field(value, 35, buf);
+        }
+        if let Some(value) = &self.example {
+            crate::protobuf::Field::encode_field(value, 36, buf);
+        }
+        if let Some(value) = &self.nullable {
+            crate::protobuf::Field::encode_field(value, 37, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_preserve_unknown_fields {
+            crate::protobuf::Field::encode_field(value, 38, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_embedded_resource {
+            crate::protobuf::Field::encode_field(value, 39, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_int_or_string {
+            crate::protobuf::Field::encode_field(value, 40, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_list_map_keys {
+            crate::protobuf::Field::encode_field(value, 41, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_list_type {
+            crate::protobuf::Field::encode_field(value, 42, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_map_type {
+            crate::protobuf::Field::encode_field(value, 43, buf);
+        }
+        if let Some(value) = &self.x_kubernetes_validations {
+            crate::protobuf::Field::encode_field(value, 44, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ref_path: Option<std::string::String> = None;
+        let mut value_schema: Option<std::string::String> = None;
+        let mut value_additional_items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool> = None;
+        let mut value_additional_properties: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrBool> = None;
+        let mut value_all_of: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_any_of: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_default: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON> = None;
+        let mut value_definitions: Option<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_dependencies: Option<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrStringArray>> = None;
+        let mut value_description: Option<std::string::String> = None;
+        let mut value_enum_: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON>> = None;
+        let mut value_example: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSON> = None;
+        let mut value_exclusive_maximum: Option<bool> = None;
+        let mut value_exclusive_minimum: Option<bool> = None;
+        let mut value_external_docs: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ExternalDocumentation> = None;
+        let mut value_format: Option<std::string::String> = None;
+        let mut value_id: Option<std::string::String> = None;
+        let mut value_items: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaPropsOrArray> = None;
+        let mut value_max_items: Option<i64> = None;
+        let mut value_max_length: Option<i64> = None;
+        let mut value_max_properties: Option<i64> = None;
+        let mut value_maximum: Option<f64> = None;
+        let mut value_min_items: Option<i64> = None;
+        let mut value_min_length: Option<i64> = None;
+        let mut value_min_properties: Option<i64> = None;
+        let mut value_minimum: Option<f64> = None;
+        let mut value_multiple_of: Option<f64> = None;
+        let mut value_not: Option<std::boxed::Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_nullable: Option<bool> = None;
+        let mut value_one_of: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_pattern: Option<std::string::String> = None;
+        let mut value_pattern_properties: Option<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_properties: Option<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> = None;
+        let mut value_required: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_title: Option<std::string::String> = None;
+        let mut value_type_: Option<std::string::String> = None;
+        let mut value_unique_items: Option<bool> = None;
+        let mut value_x_kubernetes_embedded_resource: Option<bool> = None;
+        let mut value_x_kubernetes_int_or_string: Option<bool> = None;
+        let mut value_x_kubernetes_list_map_keys: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_x_kubernetes_list_type: Option<std::string::String> = None;
+        let mut value_x_kubernetes_map_type: Option<std::string::String> = None;
+        let mut value_x_kubernetes_preserve_unknown_fields: Option<bool> = None;
+        let mut value_x_kubernetes_validations: Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ValidationRule>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_schema, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_ref_path, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_description, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_format, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_title, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_default, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_maximum, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_exclusive_maximum, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_minimum, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_exclusive_minimum, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_max_length, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_min_length, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_pattern, wire_type, &mut buf)?,
+                16 => crate::protobuf::Field::merge_field(&mut value_max_items, wire_type, &mut buf)?,
+                17 => crate::protobuf::Field::merge_field(&mut value_min_items, wire_type, &mut buf)?,
+                18 => crate::protobuf::Field::merge_field(&mut value_unique_items, wire_type, &mut buf)?,
+                19 => crate::protobuf::Field::merge_field(&mut value_multiple_of, wire_type, &mut buf)?,
+                20 => crate::protobuf::Field::merge_field(&mut value_enum_, wire_type, &mut buf)?,
+                21 => crate::protobuf::Field::merge_field(&mut value_max_properties, wire_type, &mut buf)?,
+                22 => crate::protobuf::Field::merge_field(&mut value_min_properties, wire_type, &mut buf)?,
+                23 => crate::protobuf::Field::merge_field(&mut value_required, wire_type, &mut buf)?,
+                24 => crate::protobuf::Field::merge_field(&mut value_items, wire_type, &mut buf)?,
+                25 => crate::protobuf::Field::merge_field(&mut value_all_of, wire_type, &mut buf)?,
+                26 => crate::protobuf::Field::merge_field(&mut value_one_of, wire_type, &mut buf)?,
+                27 => crate::protobuf::Field::merge_field(&mut value_any_of, wire_type, &mut buf)?,
+                28 => crate::protobuf::Field::merge_field(&mut value_not, wire_type, &mut buf)?,
+                29 => crate::protobuf::Field::merge_field(&mut value_properties, wire_type, &mut buf)?,
+                30 => crate::protobuf::Field::merge_field(&mut value_additional_properties, wire_type, &mut buf)?,
+                31 => crate::protobuf::Field::merge_field(&mut value_pattern_properties, wire_type, &mut buf)?,
+                32 => crate::protobuf::Field::merge_field(&mut value_dependencies, wire_type, &mut buf)?,
+                33 => crate::protobuf::Field::merge_field(&mut value_additional_items, wire_type, &mut buf)?,
+                34 => crate::protobuf::Field::merge_field(&mut value_definitions, wire_type, &mut buf)?,
+                35 => crate::protobuf::Field::merge_field(&mut value_external_docs, wire_type, &mut buf)?,
+                36 => crate::protobuf::Field::merge_field(&mut value_example, wire_type, &mut buf)?,
+                37 => crate::protobuf::Field::merge_field(&mut value_nullable, wire_type, &mut buf)?,
+                38 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_preserve_unknown_fields, wire_type, &mut buf)?,
+                39 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_embedded_resource, wire_type, &mut buf)?,
+                40 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_int_or_string, wire_type, &mut buf)?,
+                41 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_list_map_keys, wire_type, &mut buf)?,
+                42 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_list_type, wire_type, &mut buf)?,
+                43 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_map_type, wire_type, &mut buf)?,
+                44 => crate::protobuf::Field::merge_field(&mut value_x_kubernetes_validations, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(JSONSchemaProps {
+            ref_path: value_ref_path,
+            schema: value_schema,
+            additional_items: value_additional_items,
+            additional_properties: value_additional_properties,
+            all_of: value_all_of,
+            any_of: value_any_of,
+            default: value_default,
+            definitions: value_definitions,
+            dependencies: value_dependencies,
+            description: value_description,
+            enum_: value_enum_,
+            example: value_example,
+            exclusive_maximum: value_exclusive_maximum,
+            exclusive_minimum: value_exclusive_minimum,
+            external_docs: value_external_docs,
+            format: value_format,
+            id: value_id,
+            items: value_items,
+            max_items: value_max_items,
+            max_length: value_max_length,
+            max_properties: value_max_properties,
+            maximum: value_maximum,
+            min_items: value_min_items,
+            min_length: value_min_length,
+            min_properties: value_min_properties,
+            minimum: value_minimum,
+            multiple_of: value_multiple_of,
+            not: value_not,
+            nullable: value_nullable,
+            one_of: value_one_of,
+            pattern: value_pattern,
+            pattern_properties: value_pattern_properties,
+            properties: value_properties,
+            required: value_required,
+            title: value_title,
+            type_: value_type_,
+            unique_items: value_unique_items,
+            x_kubernetes_embedded_resource: value_x_kubernetes_embedded_resource,
+            x_kubernetes_int_or_string: value_x_kubernetes_int_or_string,
+            x_kubernetes_list_map_keys: value_x_kubernetes_list_map_keys,
+            x_kubernetes_list_type: value_x_kubernetes_list_type,
+            x_kubernetes_map_type: value_x_kubernetes_map_type,
+            x_kubernetes_preserve_unknown_fields: value_x_kubernetes_preserve_unknown_fields,
+            x_kubernetes_validations: value_x_kubernetes_validations,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for JSONSchemaProps {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "x-kubernetes-validations" => Some(&["rule"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "allOf" | "anyOf" | "oneOf" => <Option<std::vec::Vec<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "definitions" | "patternProperties" | "properties" => <Option<std::collections::BTreeMap<std::string::String, crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                "not" => <Option<std::boxed::Box<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::JSONSchemaProps>> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSONSchemaProps {
     fn schema_name() -> std::borrow::Cow<'static, str> {
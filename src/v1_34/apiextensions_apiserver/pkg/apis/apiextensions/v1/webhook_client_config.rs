@@ -33,6 +33,54 @@ impl crate::DeepMerge for WebhookClientConfig {
     }
 }
 
+impl crate::Defaulting for WebhookClientConfig {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ca_bundle);
+        crate::Defaulting::set_defaults(&mut self.service);
+        crate::Defaulting::set_defaults(&mut self.url);
+    }
+}
+
+impl crate::diff::Diff for WebhookClientConfig {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ca_bundle, &other.ca_bundle, &crate::diff::Path::Field { parent: path, name: "caBundle" }, changes);
+        crate::diff::Diff::diff(&self.service, &other.service, &crate::diff::Path::Field { parent: path, name: "service" }, changes);
+        crate::diff::Diff::diff(&self.url, &other.url, &crate::diff::Path::Field { parent: path, name: "url" }, changes);
+    }
+}
+
+impl WebhookClientConfig {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WebhookClientConfig> {
+    /// The path of the `caBundle` field.
+    pub fn ca_bundle(self) -> crate::FieldPath<crate::ByteString> {
+        self.child("caBundle")
+    }
+
+    /// The path of the `service` field.
+    pub fn service(self) -> crate::FieldPath<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference> {
+        self.child("service")
+    }
+
+    /// The path of the `url` field.
+    pub fn url(self) -> crate::FieldPath<std::string::String> {
+        self.child("url")
+    }
+}
+
+impl crate::Normalize for WebhookClientConfig {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ca_bundle);
+        crate::Normalize::normalize(&mut self.service);
+        crate::Normalize::normalize(&mut self.url);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WebhookClientConfig {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +180,46 @@ impl crate::serde::Serialize for WebhookClientConfig {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WebhookClientConfig {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.service {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.ca_bundle {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.url {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ca_bundle: Option<crate::ByteString> = None;
+        let mut value_service: Option<crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::ServiceReference> = None;
+        let mut value_url: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_service, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_ca_bundle, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_url, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WebhookClientConfig {
+            ca_bundle: value_ca_bundle,
+            service: value_service,
+            url: value_url,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WebhookClientConfig {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WebhookClientConfig {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -26,6 +26,66 @@ impl crate::DeepMerge for APIVersions {
     }
 }
 
+impl crate::Defaulting for APIVersions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.server_address_by_client_cidrs);
+        crate::Defaulting::set_defaults(&mut self.versions);
+    }
+}
+
+impl crate::diff::Diff for APIVersions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.server_address_by_client_cidrs,
+            &other.server_address_by_client_cidrs,
+            &crate::diff::Path::Field { parent: path, name: "serverAddressByClientCIDRs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.versions,
+            &other.versions,
+            &crate::diff::Path::Field { parent: path, name: "versions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl APIVersions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<APIVersions> {
+    /// The path of the `serverAddressByClientCIDRs` field.
+    pub fn server_address_by_client_cidrs(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> {
+        self.child("serverAddressByClientCIDRs")
+    }
+
+    /// The path of the `versions` field.
+    pub fn versions(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("versions")
+    }
+}
+
+impl crate::Normalize for APIVersions {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.server_address_by_client_cidrs, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.versions, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for APIVersions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -130,6 +190,36 @@ impl crate::serde::Serialize for APIVersions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for APIVersions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.versions, 1, buf);
+        crate::protobuf::Field::encode_field(&self.server_address_by_client_cidrs, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_server_address_by_client_cidrs: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::ServerAddressByClientCIDR>> = None;
+        let mut value_versions: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_versions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_server_address_by_client_cidrs, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(APIVersions {
+            server_address_by_client_cidrs: value_server_address_by_client_cidrs.unwrap_or_default(),
+            versions: value_versions.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for APIVersions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for APIVersions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -41,6 +41,104 @@ impl crate::DeepMerge for DeleteOptions {
     }
 }
 
+impl crate::Defaulting for DeleteOptions {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_version);
+        crate::Defaulting::set_defaults(&mut self.dry_run);
+        crate::Defaulting::set_defaults(&mut self.grace_period_seconds);
+        crate::Defaulting::set_defaults(&mut self.ignore_store_read_error_with_cluster_breaking_potential);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.orphan_dependents);
+        crate::Defaulting::set_defaults(&mut self.preconditions);
+        crate::Defaulting::set_defaults(&mut self.propagation_policy);
+    }
+}
+
+impl crate::diff::Diff for DeleteOptions {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.api_version, &other.api_version, &crate::diff::Path::Field { parent: path, name: "apiVersion" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.dry_run,
+            &other.dry_run,
+            &crate::diff::Path::Field { parent: path, name: "dryRun" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.grace_period_seconds, &other.grace_period_seconds, &crate::diff::Path::Field { parent: path, name: "gracePeriodSeconds" }, changes);
+        crate::diff::Diff::diff(&self.ignore_store_read_error_with_cluster_breaking_potential, &other.ignore_store_read_error_with_cluster_breaking_potential, &crate::diff::Path::Field { parent: path, name: "ignoreStoreReadErrorWithClusterBreakingPotential" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.orphan_dependents, &other.orphan_dependents, &crate::diff::Path::Field { parent: path, name: "orphanDependents" }, changes);
+        crate::diff::Diff::diff(&self.preconditions, &other.preconditions, &crate::diff::Path::Field { parent: path, name: "preconditions" }, changes);
+        crate::diff::Diff::diff(&self.propagation_policy, &other.propagation_policy, &crate::diff::Path::Field { parent: path, name: "propagationPolicy" }, changes);
+    }
+}
+
+impl DeleteOptions {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeleteOptions> {
+    /// The path of the `apiVersion` field.
+    pub fn api_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("apiVersion")
+    }
+
+    /// The path of the `dryRun` field.
+    pub fn dry_run(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("dryRun")
+    }
+
+    /// The path of the `gracePeriodSeconds` field.
+    pub fn grace_period_seconds(self) -> crate::FieldPath<i64> {
+        self.child("gracePeriodSeconds")
+    }
+
+    /// The path of the `ignoreStoreReadErrorWithClusterBreakingPotential` field.
+    pub fn ignore_store_read_error_with_cluster_breaking_potential(self) -> crate::FieldPath<bool> {
+        self.child("ignoreStoreReadErrorWithClusterBreakingPotential")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `orphanDependents` field.
+    pub fn orphan_dependents(self) -> crate::FieldPath<bool> {
+        self.child("orphanDependents")
+    }
+
+    /// The path of the `preconditions` field.
+    pub fn preconditions(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Preconditions> {
+        self.child("preconditions")
+    }
+
+    /// The path of the `propagationPolicy` field.
+    pub fn propagation_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("propagationPolicy")
+    }
+}
+
+impl crate::Normalize for DeleteOptions {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.api_version);
+        crate::normalize_strategies::list::atomic(&mut self.dry_run, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.grace_period_seconds);
+        crate::Normalize::normalize(&mut self.ignore_store_read_error_with_cluster_breaking_potential);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.orphan_dependents);
+        crate::Normalize::normalize(&mut self.preconditions);
+        crate::Normalize::normalize(&mut self.propagation_policy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeleteOptions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -190,6 +288,66 @@ impl crate::serde::Serialize for DeleteOptions {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeleteOptions {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.grace_period_seconds {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.preconditions {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.orphan_dependents {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.propagation_policy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.dry_run {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.ignore_store_read_error_with_cluster_breaking_potential {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_dry_run: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_grace_period_seconds: Option<i64> = None;
+        let mut value_ignore_store_read_error_with_cluster_breaking_potential: Option<bool> = None;
+        let mut value_orphan_dependents: Option<bool> = None;
+        let mut value_preconditions: Option<crate::apimachinery::pkg::apis::meta::v1::Preconditions> = None;
+        let mut value_propagation_policy: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_grace_period_seconds, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_preconditions, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_orphan_dependents, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_propagation_policy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_dry_run, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_ignore_store_read_error_with_cluster_breaking_potential, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeleteOptions {
+            api_version: Default::default(),
+            dry_run: value_dry_run,
+            grace_period_seconds: value_grace_period_seconds,
+            ignore_store_read_error_with_cluster_breaking_potential: value_ignore_store_read_error_with_cluster_breaking_potential,
+            kind: Default::default(),
+            orphan_dependents: value_orphan_dependents,
+            preconditions: value_preconditions,
+            propagation_policy: value_propagation_policy,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeleteOptions {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeleteOptions {
     fn schema_name() -> std::borrow::Cow<'static, str> {
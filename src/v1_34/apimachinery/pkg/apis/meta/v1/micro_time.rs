@@ -10,6 +10,24 @@ impl crate::DeepMerge for MicroTime {
     }
 }
 
+impl crate::Defaulting for MicroTime {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.0);
+    }
+}
+
+impl crate::Normalize for MicroTime {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for MicroTime {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for MicroTime {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -42,6 +60,26 @@ impl crate::serde::Serialize for MicroTime {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for MicroTime {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::encode_timestamp(self.0.as_second(), self.0.subsec_microsecond() * 1000, buf);
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        Ok(MicroTime(crate::protobuf::decode_timestamp(buf)?))
+    }
+
+    fn decode_optional(buf: &[u8]) -> Result<Option<Self>, crate::protobuf::DecodeError> {
+        if buf.is_empty() {
+            Ok(None)
+        }
+        else {
+            Self::decode(buf).map(Some)
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for MicroTime {
     fn schema_name() -> std::borrow::Cow<'static, str> {
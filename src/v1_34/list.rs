@@ -45,6 +45,63 @@ impl<T> crate::DeepMerge for List<T> where T: crate::DeepMerge + crate::Metadata
     }
 }
 
+impl<T> crate::Defaulting for List<T> where T: crate::Defaulting + crate::Metadata<Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> + crate::ListableResource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.items);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+    }
+}
+
+impl<T> crate::diff::Diff for List<T> where T: crate::diff::Diff + crate::serde::Serialize + crate::Metadata<Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> + crate::ListableResource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.items,
+            &other.items,
+            &crate::diff::Path::Field { parent: path, name: "items" },
+            changes,
+            &[("metadata.namespace", |item| crate::diff::MergeKey::merge_key(&item.metadata().namespace)), ("metadata.name", |item| crate::diff::MergeKey::merge_key(&item.metadata().name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+    }
+}
+
+impl<T> List<T> where T: crate::ListableResource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl<T> crate::FieldPath<List<T>> where T: crate::ListableResource {
+    /// The path of the `items` field.
+    pub fn items(self) -> crate::FieldPath<std::vec::Vec<T>> {
+        self.child("items")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ListMeta> {
+        self.child("metadata")
+    }
+}
+
+impl<T> crate::Normalize for List<T> where T: crate::Normalize + crate::Metadata<Ty = crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> + crate::ListableResource {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.items, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.metadata);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        for item in &mut self.items {
+            crate::Normalize::clear_server_populated_fields(item);
+        }
+    }
+}
+
 impl<'de, T> crate::serde::Deserialize<'de> for List<T> where T: crate::serde::Deserialize<'de> + crate::ListableResource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -148,3 +205,47 @@ impl<T> crate::serde::Serialize for List<T> where T: crate::serde::Serialize + c
         crate::serde::ser::SerializeStruct::end(state)
     }
 }
+
+#[cfg(feature = "protobuf")]
+impl<T> crate::protobuf::Message for List<T> where T: crate::protobuf::Message + crate::ListableResource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.items, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_items: Option<std::vec::Vec<T>> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ListMeta> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_items, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(List {
+            items: value_items.unwrap_or_default(),
+            metadata: value_metadata.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl<T> crate::unknown_fields::ListMapKeys for List<T> where T: crate::unknown_fields::ListMapKeys + crate::ListableResource {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "items" => Some(&["metadata.namespace", "metadata.name"]),
+                _ => None,
+            },
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "items" => <std::vec::Vec<T> as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
@@ -41,6 +41,106 @@ impl crate::DeepMerge for DeploymentSpec {
     }
 }
 
+impl crate::Defaulting for DeploymentSpec {
+    fn set_defaults(&mut self) {
+        if self.progress_deadline_seconds.is_none() {
+            self.progress_deadline_seconds = Some(600);
+        }
+        if self.replicas.is_none() {
+            self.replicas = Some(1);
+        }
+        if self.revision_history_limit.is_none() {
+            self.revision_history_limit = Some(10);
+        }
+        if self.strategy.is_none() {
+            self.strategy = Some(Default::default());
+        }
+        crate::Defaulting::set_defaults(&mut self.min_ready_seconds);
+        crate::Defaulting::set_defaults(&mut self.paused);
+        crate::Defaulting::set_defaults(&mut self.progress_deadline_seconds);
+        crate::Defaulting::set_defaults(&mut self.replicas);
+        crate::Defaulting::set_defaults(&mut self.revision_history_limit);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.strategy);
+        crate::Defaulting::set_defaults(&mut self.template);
+    }
+}
+
+impl crate::diff::Diff for DeploymentSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.min_ready_seconds, &other.min_ready_seconds, &crate::diff::Path::Field { parent: path, name: "minReadySeconds" }, changes);
+        crate::diff::Diff::diff(&self.paused, &other.paused, &crate::diff::Path::Field { parent: path, name: "paused" }, changes);
+        crate::diff::Diff::diff(&self.progress_deadline_seconds, &other.progress_deadline_seconds, &crate::diff::Path::Field { parent: path, name: "progressDeadlineSeconds" }, changes);
+        crate::diff::Diff::diff(&self.replicas, &other.replicas, &crate::diff::Path::Field { parent: path, name: "replicas" }, changes);
+        crate::diff::Diff::diff(&self.revision_history_limit, &other.revision_history_limit, &crate::diff::Path::Field { parent: path, name: "revisionHistoryLimit" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.strategy, &other.strategy, &crate::diff::Path::Field { parent: path, name: "strategy" }, changes);
+        crate::diff::Diff::diff(&self.template, &other.template, &crate::diff::Path::Field { parent: path, name: "template" }, changes);
+    }
+}
+
+impl DeploymentSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeploymentSpec> {
+    /// The path of the `minReadySeconds` field.
+    pub fn min_ready_seconds(self) -> crate::FieldPath<i32> {
+        self.child("minReadySeconds")
+    }
+
+    /// The path of the `paused` field.
+    pub fn paused(self) -> crate::FieldPath<bool> {
+        self.child("paused")
+    }
+
+    /// The path of the `progressDeadlineSeconds` field.
+    pub fn progress_deadline_seconds(self) -> crate::FieldPath<i32> {
+        self.child("progressDeadlineSeconds")
+    }
+
+    /// The path of the `replicas` field.
+    pub fn replicas(self) -> crate::FieldPath<i32> {
+        self.child("replicas")
+    }
+
+    /// The path of the `revisionHistoryLimit` field.
+    pub fn revision_history_limit(self) -> crate::FieldPath<i32> {
+        self.child("revisionHistoryLimit")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `strategy` field.
+    pub fn strategy(self) -> crate::FieldPath<crate::api::apps::v1::DeploymentStrategy> {
+        self.child("strategy")
+    }
+
+    /// The path of the `template` field.
+    pub fn template(self) -> crate::FieldPath<crate::api::core::v1::PodTemplateSpec> {
+        self.child("template")
+    }
+}
+
+impl crate::Normalize for DeploymentSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.min_ready_seconds);
+        crate::Normalize::normalize(&mut self.paused);
+        crate::Normalize::normalize(&mut self.progress_deadline_seconds);
+        crate::Normalize::normalize(&mut self.replicas);
+        crate::Normalize::normalize(&mut self.revision_history_limit);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.strategy);
+        crate::Normalize::normalize(&mut self.template);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeploymentSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -185,6 +285,82 @@ impl crate::serde::Serialize for DeploymentSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeploymentSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.replicas {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.selector, 2, buf);
+        crate::protobuf::Field::encode_field(&self.template, 3, buf);
+        if let Some(value) = &self.strategy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.min_ready_seconds {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.revision_history_limit {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.paused {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.progress_deadline_seconds {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_min_ready_seconds: Option<i32> = None;
+        let mut value_paused: Option<bool> = None;
+        let mut value_progress_deadline_seconds: Option<i32> = None;
+        let mut value_replicas: Option<i32> = None;
+        let mut value_revision_history_limit: Option<i32> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_strategy: Option<crate::api::apps::v1::DeploymentStrategy> = None;
+        let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_replicas, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_template, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_strategy, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_min_ready_seconds, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_revision_history_limit, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_paused, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_progress_deadline_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeploymentSpec {
+            min_ready_seconds: value_min_ready_seconds,
+            paused: value_paused,
+            progress_deadline_seconds: value_progress_deadline_seconds,
+            replicas: value_replicas,
+            revision_history_limit: value_revision_history_limit,
+            selector: value_selector.unwrap_or_default(),
+            strategy: value_strategy,
+            template: value_template.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeploymentSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "template" => <crate::api::core::v1::PodTemplateSpec as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeploymentSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
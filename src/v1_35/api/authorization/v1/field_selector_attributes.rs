@@ -17,6 +17,56 @@ impl crate::DeepMerge for FieldSelectorAttributes {
     }
 }
 
+impl crate::Defaulting for FieldSelectorAttributes {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.raw_selector);
+        crate::Defaulting::set_defaults(&mut self.requirements);
+    }
+}
+
+impl crate::diff::Diff for FieldSelectorAttributes {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.raw_selector, &other.raw_selector, &crate::diff::Path::Field { parent: path, name: "rawSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.requirements,
+            &other.requirements,
+            &crate::diff::Path::Field { parent: path, name: "requirements" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl FieldSelectorAttributes {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FieldSelectorAttributes> {
+    /// The path of the `rawSelector` field.
+    pub fn raw_selector(self) -> crate::FieldPath<std::string::String> {
+        self.child("rawSelector")
+    }
+
+    /// The path of the `requirements` field.
+    pub fn requirements(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement>> {
+        self.child("requirements")
+    }
+}
+
+impl crate::Normalize for FieldSelectorAttributes {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.raw_selector);
+        crate::normalize_strategies::list::atomic(&mut self.requirements, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FieldSelectorAttributes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +156,40 @@ impl crate::serde::Serialize for FieldSelectorAttributes {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FieldSelectorAttributes {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.raw_selector {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.requirements {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_raw_selector: Option<std::string::String> = None;
+        let mut value_requirements: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::FieldSelectorRequirement>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_raw_selector, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_requirements, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FieldSelectorAttributes {
+            raw_selector: value_raw_selector,
+            requirements: value_requirements,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FieldSelectorAttributes {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FieldSelectorAttributes {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -26,6 +26,102 @@ impl crate::DeepMerge for ResourceRule {
     }
 }
 
+impl crate::Defaulting for ResourceRule {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.api_groups);
+        crate::Defaulting::set_defaults(&mut self.resource_names);
+        crate::Defaulting::set_defaults(&mut self.resources);
+        crate::Defaulting::set_defaults(&mut self.verbs);
+    }
+}
+
+impl crate::diff::Diff for ResourceRule {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.api_groups,
+            &other.api_groups,
+            &crate::diff::Path::Field { parent: path, name: "apiGroups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.resource_names,
+            &other.resource_names,
+            &crate::diff::Path::Field { parent: path, name: "resourceNames" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.resources,
+            &other.resources,
+            &crate::diff::Path::Field { parent: path, name: "resources" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.verbs,
+            &other.verbs,
+            &crate::diff::Path::Field { parent: path, name: "verbs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceRule {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceRule> {
+    /// The path of the `apiGroups` field.
+    pub fn api_groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("apiGroups")
+    }
+
+    /// The path of the `resourceNames` field.
+    pub fn resource_names(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("resourceNames")
+    }
+
+    /// The path of the `resources` field.
+    pub fn resources(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("resources")
+    }
+
+    /// The path of the `verbs` field.
+    pub fn verbs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("verbs")
+    }
+}
+
+impl crate::Normalize for ResourceRule {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.api_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.resource_names, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.resources, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.verbs, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -133,6 +229,50 @@ impl crate::serde::Serialize for ResourceRule {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceRule {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.verbs, 1, buf);
+        if let Some(value) = &self.api_groups {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.resources {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.resource_names {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_api_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_resource_names: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_resources: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_verbs: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_verbs, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_api_groups, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_resources, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_resource_names, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceRule {
+            api_groups: value_api_groups,
+            resource_names: value_resource_names,
+            resources: value_resources,
+            verbs: value_verbs.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceRule {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceRule {
     fn schema_name() -> std::borrow::Cow<'static, str> {
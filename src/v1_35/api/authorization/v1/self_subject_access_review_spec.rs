@@ -17,6 +17,46 @@ impl crate::DeepMerge for SelfSubjectAccessReviewSpec {
     }
 }
 
+impl crate::Defaulting for SelfSubjectAccessReviewSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.non_resource_attributes);
+        crate::Defaulting::set_defaults(&mut self.resource_attributes);
+    }
+}
+
+impl crate::diff::Diff for SelfSubjectAccessReviewSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.non_resource_attributes, &other.non_resource_attributes, &crate::diff::Path::Field { parent: path, name: "nonResourceAttributes" }, changes);
+        crate::diff::Diff::diff(&self.resource_attributes, &other.resource_attributes, &crate::diff::Path::Field { parent: path, name: "resourceAttributes" }, changes);
+    }
+}
+
+impl SelfSubjectAccessReviewSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<SelfSubjectAccessReviewSpec> {
+    /// The path of the `nonResourceAttributes` field.
+    pub fn non_resource_attributes(self) -> crate::FieldPath<crate::api::authorization::v1::NonResourceAttributes> {
+        self.child("nonResourceAttributes")
+    }
+
+    /// The path of the `resourceAttributes` field.
+    pub fn resource_attributes(self) -> crate::FieldPath<crate::api::authorization::v1::ResourceAttributes> {
+        self.child("resourceAttributes")
+    }
+}
+
+impl crate::Normalize for SelfSubjectAccessReviewSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.non_resource_attributes);
+        crate::Normalize::normalize(&mut self.resource_attributes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for SelfSubjectAccessReviewSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for SelfSubjectAccessReviewSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for SelfSubjectAccessReviewSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.resource_attributes {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.non_resource_attributes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_non_resource_attributes: Option<crate::api::authorization::v1::NonResourceAttributes> = None;
+        let mut value_resource_attributes: Option<crate::api::authorization::v1::ResourceAttributes> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_attributes, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_non_resource_attributes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(SelfSubjectAccessReviewSpec {
+            non_resource_attributes: value_non_resource_attributes,
+            resource_attributes: value_resource_attributes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for SelfSubjectAccessReviewSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for SelfSubjectAccessReviewSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -20,6 +20,46 @@ impl crate::DeepMerge for HorizontalPodAutoscalerBehavior {
     }
 }
 
+impl crate::Defaulting for HorizontalPodAutoscalerBehavior {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.scale_down);
+        crate::Defaulting::set_defaults(&mut self.scale_up);
+    }
+}
+
+impl crate::diff::Diff for HorizontalPodAutoscalerBehavior {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.scale_down, &other.scale_down, &crate::diff::Path::Field { parent: path, name: "scaleDown" }, changes);
+        crate::diff::Diff::diff(&self.scale_up, &other.scale_up, &crate::diff::Path::Field { parent: path, name: "scaleUp" }, changes);
+    }
+}
+
+impl HorizontalPodAutoscalerBehavior {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HorizontalPodAutoscalerBehavior> {
+    /// The path of the `scaleDown` field.
+    pub fn scale_down(self) -> crate::FieldPath<crate::api::autoscaling::v2::HPAScalingRules> {
+        self.child("scaleDown")
+    }
+
+    /// The path of the `scaleUp` field.
+    pub fn scale_up(self) -> crate::FieldPath<crate::api::autoscaling::v2::HPAScalingRules> {
+        self.child("scaleUp")
+    }
+}
+
+impl crate::Normalize for HorizontalPodAutoscalerBehavior {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.scale_down);
+        crate::Normalize::normalize(&mut self.scale_up);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HorizontalPodAutoscalerBehavior {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -109,6 +149,40 @@ impl crate::serde::Serialize for HorizontalPodAutoscalerBehavior {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HorizontalPodAutoscalerBehavior {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.scale_up {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.scale_down {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_scale_down: Option<crate::api::autoscaling::v2::HPAScalingRules> = None;
+        let mut value_scale_up: Option<crate::api::autoscaling::v2::HPAScalingRules> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_scale_up, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_scale_down, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HorizontalPodAutoscalerBehavior {
+            scale_down: value_scale_down,
+            scale_up: value_scale_up,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HorizontalPodAutoscalerBehavior {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HorizontalPodAutoscalerBehavior {
     fn schema_name() -> std::borrow::Cow<'static, str> {
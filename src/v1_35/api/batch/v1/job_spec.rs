@@ -84,6 +84,158 @@ impl crate::DeepMerge for JobSpec {
     }
 }
 
+impl crate::Defaulting for JobSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.active_deadline_seconds);
+        crate::Defaulting::set_defaults(&mut self.backoff_limit);
+        crate::Defaulting::set_defaults(&mut self.backoff_limit_per_index);
+        crate::Defaulting::set_defaults(&mut self.completion_mode);
+        crate::Defaulting::set_defaults(&mut self.completions);
+        crate::Defaulting::set_defaults(&mut self.managed_by);
+        crate::Defaulting::set_defaults(&mut self.manual_selector);
+        crate::Defaulting::set_defaults(&mut self.max_failed_indexes);
+        crate::Defaulting::set_defaults(&mut self.parallelism);
+        crate::Defaulting::set_defaults(&mut self.pod_failure_policy);
+        crate::Defaulting::set_defaults(&mut self.pod_replacement_policy);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.success_policy);
+        crate::Defaulting::set_defaults(&mut self.suspend);
+        crate::Defaulting::set_defaults(&mut self.template);
+        crate::Defaulting::set_defaults(&mut self.ttl_seconds_after_finished);
+    }
+}
+
+impl crate::diff::Diff for JobSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.active_deadline_seconds, &other.active_deadline_seconds, &crate::diff::Path::Field { parent: path, name: "activeDeadlineSeconds" }, changes);
+        crate::diff::Diff::diff(&self.backoff_limit, &other.backoff_limit, &crate::diff::Path::Field { parent: path, name: "backoffLimit" }, changes);
+        crate::diff::Diff::diff(&self.backoff_limit_per_index, &other.backoff_limit_per_index, &crate::diff::Path::Field { parent: path, name: "backoffLimitPerIndex" }, changes);
+        crate::diff::Diff::diff(&self.completion_mode, &other.completion_mode, &crate::diff::Path::Field { parent: path, name: "completionMode" }, changes);
+        crate::diff::Diff::diff(&self.completions, &other.completions, &crate::diff::Path::Field { parent: path, name: "completions" }, changes);
+        crate::diff::Diff::diff(&self.managed_by, &other.managed_by, &crate::diff::Path::Field { parent: path, name: "managedBy" }, changes);
+        crate::diff::Diff::diff(&self.manual_selector, &other.manual_selector, &crate::diff::Path::Field { parent: path, name: "manualSelector" }, changes);
+        crate::diff::Diff::diff(&self.max_failed_indexes, &other.max_failed_indexes, &crate::diff::Path::Field { parent: path, name: "maxFailedIndexes" }, changes);
+        crate::diff::Diff::diff(&self.parallelism, &other.parallelism, &crate::diff::Path::Field { parent: path, name: "parallelism" }, changes);
+        crate::diff::Diff::diff(&self.pod_failure_policy, &other.pod_failure_policy, &crate::diff::Path::Field { parent: path, name: "podFailurePolicy" }, changes);
+        crate::diff::Diff::diff(&self.pod_replacement_policy, &other.pod_replacement_policy, &crate::diff::Path::Field { parent: path, name: "podReplacementPolicy" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.success_policy, &other.success_policy, &crate::diff::Path::Field { parent: path, name: "successPolicy" }, changes);
+        crate::diff::Diff::diff(&self.suspend, &other.suspend, &crate::diff::Path::Field { parent: path, name: "suspend" }, changes);
+        crate::diff::Diff::diff(&self.template, &other.template, &crate::diff::Path::Field { parent: path, name: "template" }, changes);
+        crate::diff::Diff::diff(&self.ttl_seconds_after_finished, &other.ttl_seconds_after_finished, &crate::diff::Path::Field { parent: path, name: "ttlSecondsAfterFinished" }, changes);
+    }
+}
+
+impl JobSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<JobSpec> {
+    /// The path of the `activeDeadlineSeconds` field.
+    pub fn active_deadline_seconds(self) -> crate::FieldPath<i64> {
+        self.child("activeDeadlineSeconds")
+    }
+
+    /// The path of the `backoffLimit` field.
+    pub fn backoff_limit(self) -> crate::FieldPath<i32> {
+        self.child("backoffLimit")
+    }
+
+    /// The path of the `backoffLimitPerIndex` field.
+    pub fn backoff_limit_per_index(self) -> crate::FieldPath<i32> {
+        self.child("backoffLimitPerIndex")
+    }
+
+    /// The path of the `completionMode` field.
+    pub fn completion_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("completionMode")
+    }
+
+    /// The path of the `completions` field.
+    pub fn completions(self) -> crate::FieldPath<i32> {
+        self.child("completions")
+    }
+
+    /// The path of the `managedBy` field.
+    pub fn managed_by(self) -> crate::FieldPath<std::string::String> {
+        self.child("managedBy")
+    }
+
+    /// The path of the `manualSelector` field.
+    pub fn manual_selector(self) -> crate::FieldPath<bool> {
+        self.child("manualSelector")
+    }
+
+    /// The path of the `maxFailedIndexes` field.
+    pub fn max_failed_indexes(self) -> crate::FieldPath<i32> {
+        self.child("maxFailedIndexes")
+    }
+
+    /// The path of the `parallelism` field.
+    pub fn parallelism(self) -> crate::FieldPath<i32> {
+        self.child("parallelism")
+    }
+
+    /// The path of the `podFailurePolicy` field.
+    pub fn pod_failure_policy(self) -> crate::FieldPath<crate::api::batch::v1::PodFailurePolicy> {
+        self.child("podFailurePolicy")
+    }
+
+    /// The path of the `podReplacementPolicy` field.
+    pub fn pod_replacement_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("podReplacementPolicy")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `successPolicy` field.
+    pub fn success_policy(self) -> crate::FieldPath<crate::api::batch::v1::SuccessPolicy> {
+        self.child("successPolicy")
+    }
+
+    /// The path of the `suspend` field.
+    pub fn suspend(self) -> crate::FieldPath<bool> {
+        self.child("suspend")
+    }
+
+    /// The path of the `template` field.
+    pub fn template(self) -> crate::FieldPath<crate::api::core::v1::PodTemplateSpec> {
+        self.child("template")
+    }
+
+    /// The path of the `ttlSecondsAfterFinished` field.
+    pub fn ttl_seconds_after_finished(self) -> crate::FieldPath<i32> {
+        self.child("ttlSecondsAfterFinished")
+    }
+}
+
+impl crate::Normalize for JobSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.active_deadline_seconds);
+        crate::Normalize::normalize(&mut self.backoff_limit);
+        crate::Normalize::normalize(&mut self.backoff_limit_per_index);
+        crate::Normalize::normalize(&mut self.completion_mode);
+        crate::Normalize::normalize(&mut self.completions);
+        crate::Normalize::normalize(&mut self.managed_by);
+        crate::Normalize::normalize(&mut self.manual_selector);
+        crate::Normalize::normalize(&mut self.max_failed_indexes);
+        crate::Normalize::normalize(&mut self.parallelism);
+        crate::Normalize::normalize(&mut self.pod_failure_policy);
+        crate::Normalize::normalize(&mut self.pod_replacement_policy);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.success_policy);
+        crate::Normalize::normalize(&mut self.suspend);
+        crate::Normalize::normalize(&mut self.template);
+        crate::Normalize::normalize(&mut self.ttl_seconds_after_finished);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JobSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -311,6 +463,132 @@ impl crate::serde::Serialize for JobSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JobSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.parallelism {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.completions {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.active_deadline_seconds {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.manual_selector {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.template, 6, buf);
+        if let Some(value) = &self.backoff_limit {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.ttl_seconds_after_finished {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.completion_mode {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.suspend {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.pod_failure_policy {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.backoff_limit_per_index {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.max_failed_indexes {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.pod_replacement_policy {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.managed_by {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+        if let Some(value) = &self.success_policy {
+            crate::protobuf::Field::encode_field(value, 16, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_active_deadline_seconds: Option<i64> = None;
+        let mut value_backoff_limit: Option<i32> = None;
+        let mut value_backoff_limit_per_index: Option<i32> = None;
+        let mut value_completion_mode: Option<std::string::String> = None;
+        let mut value_completions: Option<i32> = None;
+        let mut value_managed_by: Option<std::string::String> = None;
+        let mut value_manual_selector: Option<bool> = None;
+        let mut value_max_failed_indexes: Option<i32> = None;
+        let mut value_parallelism: Option<i32> = None;
+        let mut value_pod_failure_policy: Option<crate::api::batch::v1::PodFailurePolicy> = None;
+        let mut value_pod_replacement_policy: Option<std::string::String> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_success_policy: Option<crate::api::batch::v1::SuccessPolicy> = None;
+        let mut value_suspend: Option<bool> = None;
+        let mut value_template: Option<crate::api::core::v1::PodTemplateSpec> = None;
+        let mut value_ttl_seconds_after_finished: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_parallelism, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_completions, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_active_deadline_seconds, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_manual_selector, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_template, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_backoff_limit, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_ttl_seconds_after_finished, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_completion_mode, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_suspend, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_pod_failure_policy, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_backoff_limit_per_index, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_max_failed_indexes, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_pod_replacement_policy, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_managed_by, wire_type, &mut buf)?,
+                16 => crate::protobuf::Field::merge_field(&mut value_success_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(JobSpec {
+            active_deadline_seconds: value_active_deadline_seconds,
+            backoff_limit: value_backoff_limit,
+            backoff_limit_per_index: value_backoff_limit_per_index,
+            completion_mode: value_completion_mode,
+            completions: value_completions,
+            managed_by: value_managed_by,
+            manual_selector: value_manual_selector,
+            max_failed_indexes: value_max_failed_indexes,
+            parallelism: value_parallelism,
+            pod_failure_policy: value_pod_failure_policy,
+            pod_replacement_policy: value_pod_replacement_policy,
+            selector: value_selector,
+            success_policy: value_success_policy,
+            suspend: value_suspend,
+            template: value_template.unwrap_or_default(),
+            ttl_seconds_after_finished: value_ttl_seconds_after_finished,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for JobSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "template" => <crate::api::core::v1::PodTemplateSpec as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JobSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -87,6 +87,134 @@ impl crate::DeepMerge for CertificateSigningRequestSpec {
     }
 }
 
+impl crate::Defaulting for CertificateSigningRequestSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.expiration_seconds);
+        crate::Defaulting::set_defaults(&mut self.extra);
+        crate::Defaulting::set_defaults(&mut self.groups);
+        crate::Defaulting::set_defaults(&mut self.request);
+        crate::Defaulting::set_defaults(&mut self.signer_name);
+        crate::Defaulting::set_defaults(&mut self.uid);
+        crate::Defaulting::set_defaults(&mut self.usages);
+        crate::Defaulting::set_defaults(&mut self.username);
+    }
+}
+
+impl crate::diff::Diff for CertificateSigningRequestSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.expiration_seconds, &other.expiration_seconds, &crate::diff::Path::Field { parent: path, name: "expirationSeconds" }, changes);
+        crate::diff::strategies::map::values(
+            &self.extra,
+            &other.extra,
+            &crate::diff::Path::Field { parent: path, name: "extra" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::strategies::list::atomic(
+                    old_value,
+                    new_value,
+                    path,
+                    changes,
+                    |old_item, new_item, path, changes| {
+                        crate::diff::Diff::diff(old_item, new_item, path, changes);
+                    },
+                );
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.groups,
+            &other.groups,
+            &crate::diff::Path::Field { parent: path, name: "groups" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.request, &other.request, &crate::diff::Path::Field { parent: path, name: "request" }, changes);
+        crate::diff::Diff::diff(&self.signer_name, &other.signer_name, &crate::diff::Path::Field { parent: path, name: "signerName" }, changes);
+        crate::diff::Diff::diff(&self.uid, &other.uid, &crate::diff::Path::Field { parent: path, name: "uid" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.usages,
+            &other.usages,
+            &crate::diff::Path::Field { parent: path, name: "usages" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.username, &other.username, &crate::diff::Path::Field { parent: path, name: "username" }, changes);
+    }
+}
+
+impl CertificateSigningRequestSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CertificateSigningRequestSpec> {
+    /// The path of the `expirationSeconds` field.
+    pub fn expiration_seconds(self) -> crate::FieldPath<i32> {
+        self.child("expirationSeconds")
+    }
+
+    /// The path of the `extra` field.
+    pub fn extra(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> {
+        self.child("extra")
+    }
+
+    /// The path of the `groups` field.
+    pub fn groups(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("groups")
+    }
+
+    /// The path of the `request` field.
+    pub fn request(self) -> crate::FieldPath<crate::ByteString> {
+        self.child("request")
+    }
+
+    /// The path of the `signerName` field.
+    pub fn signer_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("signerName")
+    }
+
+    /// The path of the `uid` field.
+    pub fn uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("uid")
+    }
+
+    /// The path of the `usages` field.
+    pub fn usages(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("usages")
+    }
+
+    /// The path of the `username` field.
+    pub fn username(self) -> crate::FieldPath<std::string::String> {
+        self.child("username")
+    }
+}
+
+impl crate::Normalize for CertificateSigningRequestSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.expiration_seconds);
+        crate::normalize_strategies::map::values(&mut self.extra, |value| {
+            crate::normalize_strategies::list::atomic(value, |item| {
+                crate::Normalize::normalize(item);
+            });
+        });
+        crate::normalize_strategies::list::atomic(&mut self.groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.request);
+        crate::Normalize::normalize(&mut self.signer_name);
+        crate::Normalize::normalize(&mut self.uid);
+        crate::normalize_strategies::list::atomic(&mut self.usages, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.username);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CertificateSigningRequestSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -231,6 +359,72 @@ impl crate::serde::Serialize for CertificateSigningRequestSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CertificateSigningRequestSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.request, 1, buf);
+        if let Some(value) = &self.username {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.uid {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.groups {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.usages {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.extra {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.signer_name, 7, buf);
+        if let Some(value) = &self.expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_expiration_seconds: Option<i32> = None;
+        let mut value_extra: Option<std::collections::BTreeMap<std::string::String, std::vec::Vec<std::string::String>>> = None;
+        let mut value_groups: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_request: Option<crate::ByteString> = None;
+        let mut value_signer_name: Option<std::string::String> = None;
+        let mut value_uid: Option<std::string::String> = None;
+        let mut value_usages: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_username: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_request, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_username, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_uid, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_groups, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_usages, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_extra, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_signer_name, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_expiration_seconds, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CertificateSigningRequestSpec {
+            expiration_seconds: value_expiration_seconds,
+            extra: value_extra,
+            groups: value_groups,
+            request: value_request.unwrap_or_default(),
+            signer_name: value_signer_name.unwrap_or_default(),
+            uid: value_uid,
+            usages: value_usages,
+            username: value_username,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CertificateSigningRequestSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CertificateSigningRequestSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
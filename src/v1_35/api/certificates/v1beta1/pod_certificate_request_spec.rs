@@ -79,6 +79,128 @@ impl crate::DeepMerge for PodCertificateRequestSpec {
     }
 }
 
+impl crate::Defaulting for PodCertificateRequestSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.max_expiration_seconds);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.node_uid);
+        crate::Defaulting::set_defaults(&mut self.pkix_public_key);
+        crate::Defaulting::set_defaults(&mut self.pod_name);
+        crate::Defaulting::set_defaults(&mut self.pod_uid);
+        crate::Defaulting::set_defaults(&mut self.proof_of_possession);
+        crate::Defaulting::set_defaults(&mut self.service_account_name);
+        crate::Defaulting::set_defaults(&mut self.service_account_uid);
+        crate::Defaulting::set_defaults(&mut self.signer_name);
+        crate::Defaulting::set_defaults(&mut self.unverified_user_annotations);
+    }
+}
+
+impl crate::diff::Diff for PodCertificateRequestSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max_expiration_seconds, &other.max_expiration_seconds, &crate::diff::Path::Field { parent: path, name: "maxExpirationSeconds" }, changes);
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.node_uid, &other.node_uid, &crate::diff::Path::Field { parent: path, name: "nodeUID" }, changes);
+        crate::diff::Diff::diff(&self.pkix_public_key, &other.pkix_public_key, &crate::diff::Path::Field { parent: path, name: "pkixPublicKey" }, changes);
+        crate::diff::Diff::diff(&self.pod_name, &other.pod_name, &crate::diff::Path::Field { parent: path, name: "podName" }, changes);
+        crate::diff::Diff::diff(&self.pod_uid, &other.pod_uid, &crate::diff::Path::Field { parent: path, name: "podUID" }, changes);
+        crate::diff::Diff::diff(&self.proof_of_possession, &other.proof_of_possession, &crate::diff::Path::Field { parent: path, name: "proofOfPossession" }, changes);
+        crate::diff::Diff::diff(&self.service_account_name, &other.service_account_name, &crate::diff::Path::Field { parent: path, name: "serviceAccountName" }, changes);
+        crate::diff::Diff::diff(&self.service_account_uid, &other.service_account_uid, &crate::diff::Path::Field { parent: path, name: "serviceAccountUID" }, changes);
+        crate::diff::Diff::diff(&self.signer_name, &other.signer_name, &crate::diff::Path::Field { parent: path, name: "signerName" }, changes);
+        crate::diff::strategies::map::values(
+            &self.unverified_user_annotations,
+            &other.unverified_user_annotations,
+            &crate::diff::Path::Field { parent: path, name: "unverifiedUserAnnotations" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl PodCertificateRequestSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodCertificateRequestSpec> {
+    /// The path of the `maxExpirationSeconds` field.
+    pub fn max_expiration_seconds(self) -> crate::FieldPath<i32> {
+        self.child("maxExpirationSeconds")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `nodeUID` field.
+    pub fn node_uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeUID")
+    }
+
+    /// The path of the `pkixPublicKey` field.
+    pub fn pkix_public_key(self) -> crate::FieldPath<crate::ByteString> {
+        self.child("pkixPublicKey")
+    }
+
+    /// The path of the `podName` field.
+    pub fn pod_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("podName")
+    }
+
+    /// The path of the `podUID` field.
+    pub fn pod_uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("podUID")
+    }
+
+    /// The path of the `proofOfPossession` field.
+    pub fn proof_of_possession(self) -> crate::FieldPath<crate::ByteString> {
+        self.child("proofOfPossession")
+    }
+
+    /// The path of the `serviceAccountName` field.
+    pub fn service_account_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("serviceAccountName")
+    }
+
+    /// The path of the `serviceAccountUID` field.
+    pub fn service_account_uid(self) -> crate::FieldPath<std::string::String> {
+        self.child("serviceAccountUID")
+    }
+
+    /// The path of the `signerName` field.
+    pub fn signer_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("signerName")
+    }
+
+    /// The path of the `unverifiedUserAnnotations` field.
+    pub fn unverified_user_annotations(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("unverifiedUserAnnotations")
+    }
+}
+
+impl crate::Normalize for PodCertificateRequestSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max_expiration_seconds);
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.node_uid);
+        crate::Normalize::normalize(&mut self.pkix_public_key);
+        crate::Normalize::normalize(&mut self.pod_name);
+        crate::Normalize::normalize(&mut self.pod_uid);
+        crate::Normalize::normalize(&mut self.proof_of_possession);
+        crate::Normalize::normalize(&mut self.service_account_name);
+        crate::Normalize::normalize(&mut self.service_account_uid);
+        crate::Normalize::normalize(&mut self.signer_name);
+        crate::normalize_strategies::map::values(&mut self.unverified_user_annotations, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodCertificateRequestSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -232,6 +354,76 @@ impl crate::serde::Serialize for PodCertificateRequestSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodCertificateRequestSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.signer_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.pod_name, 2, buf);
+        crate::protobuf::Field::encode_field(&self.pod_uid, 3, buf);
+        crate::protobuf::Field::encode_field(&self.service_account_name, 4, buf);
+        crate::protobuf::Field::encode_field(&self.service_account_uid, 5, buf);
+        crate::protobuf::Field::encode_field(&self.node_name, 6, buf);
+        crate::protobuf::Field::encode_field(&self.node_uid, 7, buf);
+        if let Some(value) = &self.max_expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.pkix_public_key, 9, buf);
+        crate::protobuf::Field::encode_field(&self.proof_of_possession, 10, buf);
+        if let Some(value) = &self.unverified_user_annotations {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max_expiration_seconds: Option<i32> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_node_uid: Option<std::string::String> = None;
+        let mut value_pkix_public_key: Option<crate::ByteString> = None;
+        let mut value_pod_name: Option<std::string::String> = None;
+        let mut value_pod_uid: Option<std::string::String> = None;
+        let mut value_proof_of_possession: Option<crate::ByteString> = None;
+        let mut value_service_account_name: Option<std::string::String> = None;
+        let mut value_service_account_uid: Option<std::string::String> = None;
+        let mut value_signer_name: Option<std::string::String> = None;
+        let mut value_unverified_user_annotations: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_signer_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pod_uid, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_service_account_name, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_service_account_uid, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_node_uid, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_max_expiration_seconds, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_pkix_public_key, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_proof_of_possession, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_unverified_user_annotations, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodCertificateRequestSpec {
+            max_expiration_seconds: value_max_expiration_seconds,
+            node_name: value_node_name.unwrap_or_default(),
+            node_uid: value_node_uid.unwrap_or_default(),
+            pkix_public_key: value_pkix_public_key.unwrap_or_default(),
+            pod_name: value_pod_name.unwrap_or_default(),
+            pod_uid: value_pod_uid.unwrap_or_default(),
+            proof_of_possession: value_proof_of_possession.unwrap_or_default(),
+            service_account_name: value_service_account_name.unwrap_or_default(),
+            service_account_uid: value_service_account_uid.unwrap_or_default(),
+            signer_name: value_signer_name.unwrap_or_default(),
+            unverified_user_annotations: value_unverified_user_annotations,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodCertificateRequestSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodCertificateRequestSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
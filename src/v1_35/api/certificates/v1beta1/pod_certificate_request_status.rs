@@ -51,6 +51,81 @@ impl crate::DeepMerge for PodCertificateRequestStatus {
     }
 }
 
+impl crate::Defaulting for PodCertificateRequestStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.begin_refresh_at);
+        crate::Defaulting::set_defaults(&mut self.certificate_chain);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.not_after);
+        crate::Defaulting::set_defaults(&mut self.not_before);
+    }
+}
+
+impl crate::diff::Diff for PodCertificateRequestStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.begin_refresh_at, &other.begin_refresh_at, &crate::diff::Path::Field { parent: path, name: "beginRefreshAt" }, changes);
+        crate::diff::Diff::diff(&self.certificate_chain, &other.certificate_chain, &crate::diff::Path::Field { parent: path, name: "certificateChain" }, changes);
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.not_after, &other.not_after, &crate::diff::Path::Field { parent: path, name: "notAfter" }, changes);
+        crate::diff::Diff::diff(&self.not_before, &other.not_before, &crate::diff::Path::Field { parent: path, name: "notBefore" }, changes);
+    }
+}
+
+impl PodCertificateRequestStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodCertificateRequestStatus> {
+    /// The path of the `beginRefreshAt` field.
+    pub fn begin_refresh_at(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("beginRefreshAt")
+    }
+
+    /// The path of the `certificateChain` field.
+    pub fn certificate_chain(self) -> crate::FieldPath<std::string::String> {
+        self.child("certificateChain")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `notAfter` field.
+    pub fn not_after(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("notAfter")
+    }
+
+    /// The path of the `notBefore` field.
+    pub fn not_before(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("notBefore")
+    }
+}
+
+impl crate::Normalize for PodCertificateRequestStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.begin_refresh_at);
+        crate::Normalize::normalize(&mut self.certificate_chain);
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.not_after);
+        crate::Normalize::normalize(&mut self.not_before);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodCertificateRequestStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -170,6 +245,68 @@ impl crate::serde::Serialize for PodCertificateRequestStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodCertificateRequestStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.certificate_chain {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.not_before {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.begin_refresh_at {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.not_after {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_begin_refresh_at: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_certificate_chain: Option<std::string::String> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::Condition>> = None;
+        let mut value_not_after: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_not_before: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_certificate_chain, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_not_before, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_begin_refresh_at, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_not_after, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodCertificateRequestStatus {
+            begin_refresh_at: value_begin_refresh_at,
+            certificate_chain: value_certificate_chain,
+            conditions: value_conditions,
+            not_after: value_not_after,
+            not_before: value_not_before,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodCertificateRequestStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodCertificateRequestStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
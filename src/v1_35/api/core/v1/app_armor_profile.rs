@@ -20,6 +20,46 @@ impl crate::DeepMerge for AppArmorProfile {
     }
 }
 
+impl crate::Defaulting for AppArmorProfile {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.localhost_profile);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for AppArmorProfile {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.localhost_profile, &other.localhost_profile, &crate::diff::Path::Field { parent: path, name: "localhostProfile" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl AppArmorProfile {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AppArmorProfile> {
+    /// The path of the `localhostProfile` field.
+    pub fn localhost_profile(self) -> crate::FieldPath<std::string::String> {
+        self.child("localhostProfile")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for AppArmorProfile {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.localhost_profile);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AppArmorProfile {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -107,6 +147,38 @@ impl crate::serde::Serialize for AppArmorProfile {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AppArmorProfile {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.type_, 1, buf);
+        if let Some(value) = &self.localhost_profile {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_localhost_profile: Option<std::string::String> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_localhost_profile, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AppArmorProfile {
+            localhost_profile: value_localhost_profile,
+            type_: value_type_.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AppArmorProfile {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AppArmorProfile {
     fn schema_name() -> std::borrow::Cow<'static, str> {
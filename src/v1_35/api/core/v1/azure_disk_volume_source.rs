@@ -33,6 +33,90 @@ impl crate::DeepMerge for AzureDiskVolumeSource {
     }
 }
 
+impl crate::Defaulting for AzureDiskVolumeSource {
+    fn set_defaults(&mut self) {
+        if self.caching_mode.is_none() {
+            self.caching_mode = Some("ReadWrite".into());
+        }
+        if self.fs_type.is_none() {
+            self.fs_type = Some("ext4".into());
+        }
+        if self.kind.is_none() {
+            self.kind = Some("Shared".into());
+        }
+        if self.read_only.is_none() {
+            self.read_only = Some(false);
+        }
+        crate::Defaulting::set_defaults(&mut self.caching_mode);
+        crate::Defaulting::set_defaults(&mut self.disk_name);
+        crate::Defaulting::set_defaults(&mut self.disk_uri);
+        crate::Defaulting::set_defaults(&mut self.fs_type);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+    }
+}
+
+impl crate::diff::Diff for AzureDiskVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.caching_mode, &other.caching_mode, &crate::diff::Path::Field { parent: path, name: "cachingMode" }, changes);
+        crate::diff::Diff::diff(&self.disk_name, &other.disk_name, &crate::diff::Path::Field { parent: path, name: "diskName" }, changes);
+        crate::diff::Diff::diff(&self.disk_uri, &other.disk_uri, &crate::diff::Path::Field { parent: path, name: "diskURI" }, changes);
+        crate::diff::Diff::diff(&self.fs_type, &other.fs_type, &crate::diff::Path::Field { parent: path, name: "fsType" }, changes);
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+    }
+}
+
+impl AzureDiskVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AzureDiskVolumeSource> {
+    /// The path of the `cachingMode` field.
+    pub fn caching_mode(self) -> crate::FieldPath<std::string::String> {
+        self.child("cachingMode")
+    }
+
+    /// The path of the `diskName` field.
+    pub fn disk_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("diskName")
+    }
+
+    /// The path of the `diskURI` field.
+    pub fn disk_uri(self) -> crate::FieldPath<std::string::String> {
+        self.child("diskURI")
+    }
+
+    /// The path of the `fsType` field.
+    pub fn fs_type(self) -> crate::FieldPath<std::string::String> {
+        self.child("fsType")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+}
+
+impl crate::Normalize for AzureDiskVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.caching_mode);
+        crate::Normalize::normalize(&mut self.disk_name);
+        crate::Normalize::normalize(&mut self.disk_uri);
+        crate::Normalize::normalize(&mut self.fs_type);
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.read_only);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AzureDiskVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -157,6 +241,60 @@ impl crate::serde::Serialize for AzureDiskVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AzureDiskVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.disk_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.disk_uri, 2, buf);
+        if let Some(value) = &self.caching_mode {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.fs_type {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.kind {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_caching_mode: Option<std::string::String> = None;
+        let mut value_disk_name: Option<std::string::String> = None;
+        let mut value_disk_uri: Option<std::string::String> = None;
+        let mut value_fs_type: Option<std::string::String> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_disk_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_disk_uri, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_caching_mode, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_fs_type, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AzureDiskVolumeSource {
+            caching_mode: value_caching_mode,
+            disk_name: value_disk_name.unwrap_or_default(),
+            disk_uri: value_disk_uri.unwrap_or_default(),
+            fs_type: value_fs_type,
+            kind: value_kind,
+            read_only: value_read_only,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AzureDiskVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AzureDiskVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -25,6 +25,62 @@ impl crate::DeepMerge for AzureFilePersistentVolumeSource {
     }
 }
 
+impl crate::Defaulting for AzureFilePersistentVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.secret_name);
+        crate::Defaulting::set_defaults(&mut self.secret_namespace);
+        crate::Defaulting::set_defaults(&mut self.share_name);
+    }
+}
+
+impl crate::diff::Diff for AzureFilePersistentVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.secret_name, &other.secret_name, &crate::diff::Path::Field { parent: path, name: "secretName" }, changes);
+        crate::diff::Diff::diff(&self.secret_namespace, &other.secret_namespace, &crate::diff::Path::Field { parent: path, name: "secretNamespace" }, changes);
+        crate::diff::Diff::diff(&self.share_name, &other.share_name, &crate::diff::Path::Field { parent: path, name: "shareName" }, changes);
+    }
+}
+
+impl AzureFilePersistentVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<AzureFilePersistentVolumeSource> {
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `secretName` field.
+    pub fn secret_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("secretName")
+    }
+
+    /// The path of the `secretNamespace` field.
+    pub fn secret_namespace(self) -> crate::FieldPath<std::string::String> {
+        self.child("secretNamespace")
+    }
+
+    /// The path of the `shareName` field.
+    pub fn share_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("shareName")
+    }
+}
+
+impl crate::Normalize for AzureFilePersistentVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.secret_name);
+        crate::Normalize::normalize(&mut self.secret_namespace);
+        crate::Normalize::normalize(&mut self.share_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for AzureFilePersistentVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +185,48 @@ impl crate::serde::Serialize for AzureFilePersistentVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for AzureFilePersistentVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.secret_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.share_name, 2, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.secret_namespace {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_read_only: Option<bool> = None;
+        let mut value_secret_name: Option<std::string::String> = None;
+        let mut value_secret_namespace: Option<std::string::String> = None;
+        let mut value_share_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_secret_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_share_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_secret_namespace, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(AzureFilePersistentVolumeSource {
+            read_only: value_read_only,
+            secret_name: value_secret_name.unwrap_or_default(),
+            secret_namespace: value_secret_namespace,
+            share_name: value_share_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for AzureFilePersistentVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for AzureFilePersistentVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for ContainerExtendedResourceRequest {
     }
 }
 
+impl crate::Defaulting for ContainerExtendedResourceRequest {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.container_name);
+        crate::Defaulting::set_defaults(&mut self.request_name);
+        crate::Defaulting::set_defaults(&mut self.resource_name);
+    }
+}
+
+impl crate::diff::Diff for ContainerExtendedResourceRequest {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.container_name, &other.container_name, &crate::diff::Path::Field { parent: path, name: "containerName" }, changes);
+        crate::diff::Diff::diff(&self.request_name, &other.request_name, &crate::diff::Path::Field { parent: path, name: "requestName" }, changes);
+        crate::diff::Diff::diff(&self.resource_name, &other.resource_name, &crate::diff::Path::Field { parent: path, name: "resourceName" }, changes);
+    }
+}
+
+impl ContainerExtendedResourceRequest {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerExtendedResourceRequest> {
+    /// The path of the `containerName` field.
+    pub fn container_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerName")
+    }
+
+    /// The path of the `requestName` field.
+    pub fn request_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("requestName")
+    }
+
+    /// The path of the `resourceName` field.
+    pub fn resource_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceName")
+    }
+}
+
+impl crate::Normalize for ContainerExtendedResourceRequest {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.container_name);
+        crate::Normalize::normalize(&mut self.request_name);
+        crate::Normalize::normalize(&mut self.resource_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerExtendedResourceRequest {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +160,40 @@ impl crate::serde::Serialize for ContainerExtendedResourceRequest {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerExtendedResourceRequest {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.container_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.resource_name, 2, buf);
+        crate::protobuf::Field::encode_field(&self.request_name, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_container_name: Option<std::string::String> = None;
+        let mut value_request_name: Option<std::string::String> = None;
+        let mut value_resource_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_container_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_request_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerExtendedResourceRequest {
+            container_name: value_container_name.unwrap_or_default(),
+            request_name: value_request_name.unwrap_or_default(),
+            resource_name: value_resource_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerExtendedResourceRequest {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerExtendedResourceRequest {
     fn schema_name() -> std::borrow::Cow<'static, str> {
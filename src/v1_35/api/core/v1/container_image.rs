@@ -17,6 +17,56 @@ impl crate::DeepMerge for ContainerImage {
     }
 }
 
+impl crate::Defaulting for ContainerImage {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.names);
+        crate::Defaulting::set_defaults(&mut self.size_bytes);
+    }
+}
+
+impl crate::diff::Diff for ContainerImage {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.names,
+            &other.names,
+            &crate::diff::Path::Field { parent: path, name: "names" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.size_bytes, &other.size_bytes, &crate::diff::Path::Field { parent: path, name: "sizeBytes" }, changes);
+    }
+}
+
+impl ContainerImage {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ContainerImage> {
+    /// The path of the `names` field.
+    pub fn names(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("names")
+    }
+
+    /// The path of the `sizeBytes` field.
+    pub fn size_bytes(self) -> crate::FieldPath<i64> {
+        self.child("sizeBytes")
+    }
+}
+
+impl crate::Normalize for ContainerImage {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.names, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.size_bytes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ContainerImage {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +156,40 @@ impl crate::serde::Serialize for ContainerImage {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ContainerImage {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.names {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.size_bytes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_names: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_size_bytes: Option<i64> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_names, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_size_bytes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ContainerImage {
+            names: value_names,
+            size_bytes: value_size_bytes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ContainerImage {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ContainerImage {
     fn schema_name() -> std::borrow::Cow<'static, str> {
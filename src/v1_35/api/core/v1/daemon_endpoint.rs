@@ -13,6 +13,38 @@ impl crate::DeepMerge for DaemonEndpoint {
     }
 }
 
+impl crate::Defaulting for DaemonEndpoint {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.port);
+    }
+}
+
+impl crate::diff::Diff for DaemonEndpoint {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "Port" }, changes);
+    }
+}
+
+impl DaemonEndpoint {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DaemonEndpoint> {
+    /// The path of the `Port` field.
+    pub fn port(self) -> crate::FieldPath<i32> {
+        self.child("Port")
+    }
+}
+
+impl crate::Normalize for DaemonEndpoint {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.port);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DaemonEndpoint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for DaemonEndpoint {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DaemonEndpoint {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.port, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_port: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DaemonEndpoint {
+            port: value_port.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DaemonEndpoint {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DaemonEndpoint {
     fn schema_name() -> std::borrow::Cow<'static, str> {
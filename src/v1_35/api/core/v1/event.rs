@@ -94,6 +94,154 @@ impl crate::DeepMerge for Event {
     }
 }
 
+impl crate::Defaulting for Event {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.action);
+        crate::Defaulting::set_defaults(&mut self.count);
+        crate::Defaulting::set_defaults(&mut self.event_time);
+        crate::Defaulting::set_defaults(&mut self.first_timestamp);
+        crate::Defaulting::set_defaults(&mut self.involved_object);
+        crate::Defaulting::set_defaults(&mut self.last_timestamp);
+        crate::Defaulting::set_defaults(&mut self.message);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.reason);
+        crate::Defaulting::set_defaults(&mut self.related);
+        crate::Defaulting::set_defaults(&mut self.reporting_component);
+        crate::Defaulting::set_defaults(&mut self.reporting_instance);
+        crate::Defaulting::set_defaults(&mut self.series);
+        crate::Defaulting::set_defaults(&mut self.source);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for Event {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.action, &other.action, &crate::diff::Path::Field { parent: path, name: "action" }, changes);
+        crate::diff::Diff::diff(&self.count, &other.count, &crate::diff::Path::Field { parent: path, name: "count" }, changes);
+        crate::diff::Diff::diff(&self.event_time, &other.event_time, &crate::diff::Path::Field { parent: path, name: "eventTime" }, changes);
+        crate::diff::Diff::diff(&self.first_timestamp, &other.first_timestamp, &crate::diff::Path::Field { parent: path, name: "firstTimestamp" }, changes);
+        crate::diff::Diff::diff(&self.involved_object, &other.involved_object, &crate::diff::Path::Field { parent: path, name: "involvedObject" }, changes);
+        crate::diff::Diff::diff(&self.last_timestamp, &other.last_timestamp, &crate::diff::Path::Field { parent: path, name: "lastTimestamp" }, changes);
+        crate::diff::Diff::diff(&self.message, &other.message, &crate::diff::Path::Field { parent: path, name: "message" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::Diff::diff(&self.reason, &other.reason, &crate::diff::Path::Field { parent: path, name: "reason" }, changes);
+        crate::diff::Diff::diff(&self.related, &other.related, &crate::diff::Path::Field { parent: path, name: "related" }, changes);
+        crate::diff::Diff::diff(&self.reporting_component, &other.reporting_component, &crate::diff::Path::Field { parent: path, name: "reportingComponent" }, changes);
+        crate::diff::Diff::diff(&self.reporting_instance, &other.reporting_instance, &crate::diff::Path::Field { parent: path, name: "reportingInstance" }, changes);
+        crate::diff::Diff::diff(&self.series, &other.series, &crate::diff::Path::Field { parent: path, name: "series" }, changes);
+        crate::diff::Diff::diff(&self.source, &other.source, &crate::diff::Path::Field { parent: path, name: "source" }, changes);
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl Event {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Event> {
+    /// The path of the `action` field.
+    pub fn action(self) -> crate::FieldPath<std::string::String> {
+        self.child("action")
+    }
+
+    /// The path of the `count` field.
+    pub fn count(self) -> crate::FieldPath<i32> {
+        self.child("count")
+    }
+
+    /// The path of the `eventTime` field.
+    pub fn event_time(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::MicroTime> {
+        self.child("eventTime")
+    }
+
+    /// The path of the `firstTimestamp` field.
+    pub fn first_timestamp(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("firstTimestamp")
+    }
+
+    /// The path of the `involvedObject` field.
+    pub fn involved_object(self) -> crate::FieldPath<crate::api::core::v1::ObjectReference> {
+        self.child("involvedObject")
+    }
+
+    /// The path of the `lastTimestamp` field.
+    pub fn last_timestamp(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("lastTimestamp")
+    }
+
+    /// The path of the `message` field.
+    pub fn message(self) -> crate::FieldPath<std::string::String> {
+        self.child("message")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `reason` field.
+    pub fn reason(self) -> crate::FieldPath<std::string::String> {
+        self.child("reason")
+    }
+
+    /// The path of the `related` field.
+    pub fn related(self) -> crate::FieldPath<crate::api::core::v1::ObjectReference> {
+        self.child("related")
+    }
+
+    /// The path of the `reportingComponent` field.
+    pub fn reporting_component(self) -> crate::FieldPath<std::string::String> {
+        self.child("reportingComponent")
+    }
+
+    /// The path of the `reportingInstance` field.
+    pub fn reporting_instance(self) -> crate::FieldPath<std::string::String> {
+        self.child("reportingInstance")
+    }
+
+    /// The path of the `series` field.
+    pub fn series(self) -> crate::FieldPath<crate::api::core::v1::EventSeries> {
+        self.child("series")
+    }
+
+    /// The path of the `source` field.
+    pub fn source(self) -> crate::FieldPath<crate::api::core::v1::EventSource> {
+        self.child("source")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for Event {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.action);
+        crate::Normalize::normalize(&mut self.count);
+        crate::Normalize::normalize(&mut self.event_time);
+        crate::Normalize::normalize(&mut self.first_timestamp);
+        crate::Normalize::normalize(&mut self.involved_object);
+        crate::Normalize::normalize(&mut self.last_timestamp);
+        crate::Normalize::normalize(&mut self.message);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::Normalize::normalize(&mut self.reason);
+        crate::Normalize::normalize(&mut self.related);
+        crate::Normalize::normalize(&mut self.reporting_component);
+        crate::Normalize::normalize(&mut self.reporting_instance);
+        crate::Normalize::normalize(&mut self.series);
+        crate::Normalize::normalize(&mut self.source);
+        crate::Normalize::normalize(&mut self.type_);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Event {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -328,6 +476,124 @@ impl crate::serde::Serialize for Event {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Event {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.involved_object, 2, buf);
+        if let Some(value) = &self.reason {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.message {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.source {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.first_timestamp {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.last_timestamp {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.count {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.event_time {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.series {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.action {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.related {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.reporting_component {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+        if let Some(value) = &self.reporting_instance {
+            crate::protobuf::Field::encode_field(value, 15, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_action: Option<std::string::String> = None;
+        let mut value_count: Option<i32> = None;
+        let mut value_event_time: Option<crate::apimachinery::pkg::apis::meta::v1::MicroTime> = None;
+        let mut value_first_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_involved_object: Option<crate::api::core::v1::ObjectReference> = None;
+        let mut value_last_timestamp: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_message: Option<std::string::String> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_reason: Option<std::string::String> = None;
+        let mut value_related: Option<crate::api::core::v1::ObjectReference> = None;
+        let mut value_reporting_component: Option<std::string::String> = None;
+        let mut value_reporting_instance: Option<std::string::String> = None;
+        let mut value_series: Option<crate::api::core::v1::EventSeries> = None;
+        let mut value_source: Option<crate::api::core::v1::EventSource> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_involved_object, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_reason, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_message, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_source, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_first_timestamp, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_last_timestamp, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_count, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_event_time, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_series, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_action, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_related, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_reporting_component, wire_type, &mut buf)?,
+                15 => crate::protobuf::Field::merge_field(&mut value_reporting_instance, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Event {
+            action: value_action,
+            count: value_count,
+            event_time: value_event_time,
+            first_timestamp: value_first_timestamp,
+            involved_object: value_involved_object.unwrap_or_default(),
+            last_timestamp: value_last_timestamp,
+            message: value_message,
+            metadata: value_metadata.unwrap_or_default(),
+            reason: value_reason,
+            related: value_related,
+            reporting_component: value_reporting_component,
+            reporting_instance: value_reporting_instance,
+            series: value_series,
+            source: value_source,
+            type_: value_type_,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Event {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Event {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,62 @@ impl crate::DeepMerge for FileKeySelector {
     }
 }
 
+impl crate::Defaulting for FileKeySelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.optional);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.volume_name);
+    }
+}
+
+impl crate::diff::Diff for FileKeySelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.optional, &other.optional, &crate::diff::Path::Field { parent: path, name: "optional" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.volume_name, &other.volume_name, &crate::diff::Path::Field { parent: path, name: "volumeName" }, changes);
+    }
+}
+
+impl FileKeySelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<FileKeySelector> {
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `optional` field.
+    pub fn optional(self) -> crate::FieldPath<bool> {
+        self.child("optional")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `volumeName` field.
+    pub fn volume_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("volumeName")
+    }
+}
+
+impl crate::Normalize for FileKeySelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.optional);
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.volume_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FileKeySelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -128,6 +184,46 @@ impl crate::serde::Serialize for FileKeySelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FileKeySelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.volume_name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.path, 2, buf);
+        crate::protobuf::Field::encode_field(&self.key, 3, buf);
+        if let Some(value) = &self.optional {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_optional: Option<bool> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_volume_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_volume_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_optional, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(FileKeySelector {
+            key: value_key.unwrap_or_default(),
+            optional: value_optional,
+            path: value_path.unwrap_or_default(),
+            volume_name: value_volume_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for FileKeySelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FileKeySelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -29,6 +29,86 @@ impl crate::DeepMerge for HTTPGetAction {
     }
 }
 
+impl crate::Defaulting for HTTPGetAction {
+    fn set_defaults(&mut self) {
+        if self.path.is_none() {
+            self.path = Some("/".into());
+        }
+        if self.scheme.is_none() {
+            self.scheme = Some("HTTP".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.host);
+        crate::Defaulting::set_defaults(&mut self.http_headers);
+        crate::Defaulting::set_defaults(&mut self.path);
+        crate::Defaulting::set_defaults(&mut self.port);
+        crate::Defaulting::set_defaults(&mut self.scheme);
+    }
+}
+
+impl crate::diff::Diff for HTTPGetAction {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.host, &other.host, &crate::diff::Path::Field { parent: path, name: "host" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.http_headers,
+            &other.http_headers,
+            &crate::diff::Path::Field { parent: path, name: "httpHeaders" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+        crate::diff::Diff::diff(&self.port, &other.port, &crate::diff::Path::Field { parent: path, name: "port" }, changes);
+        crate::diff::Diff::diff(&self.scheme, &other.scheme, &crate::diff::Path::Field { parent: path, name: "scheme" }, changes);
+    }
+}
+
+impl HTTPGetAction {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<HTTPGetAction> {
+    /// The path of the `host` field.
+    pub fn host(self) -> crate::FieldPath<std::string::String> {
+        self.child("host")
+    }
+
+    /// The path of the `httpHeaders` field.
+    pub fn http_headers(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::HTTPHeader>> {
+        self.child("httpHeaders")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+
+    /// The path of the `port` field.
+    pub fn port(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("port")
+    }
+
+    /// The path of the `scheme` field.
+    pub fn scheme(self) -> crate::FieldPath<std::string::String> {
+        self.child("scheme")
+    }
+}
+
+impl crate::Normalize for HTTPGetAction {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.host);
+        crate::normalize_strategies::list::atomic(&mut self.http_headers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.path);
+        crate::Normalize::normalize(&mut self.port);
+        crate::Normalize::normalize(&mut self.scheme);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for HTTPGetAction {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -146,6 +226,56 @@ impl crate::serde::Serialize for HTTPGetAction {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for HTTPGetAction {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.path {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.port, 2, buf);
+        if let Some(value) = &self.host {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.scheme {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.http_headers {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_host: Option<std::string::String> = None;
+        let mut value_http_headers: Option<std::vec::Vec<crate::api::core::v1::HTTPHeader>> = None;
+        let mut value_path: Option<std::string::String> = None;
+        let mut value_port: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_scheme: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_port, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_host, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_scheme, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_http_headers, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(HTTPGetAction {
+            host: value_host,
+            http_headers: value_http_headers,
+            path: value_path,
+            port: value_port.unwrap_or_default(),
+            scheme: value_scheme,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for HTTPGetAction {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for HTTPGetAction {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,54 @@ impl crate::DeepMerge for KeyToPath {
     }
 }
 
+impl crate::Defaulting for KeyToPath {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.mode);
+        crate::Defaulting::set_defaults(&mut self.path);
+    }
+}
+
+impl crate::diff::Diff for KeyToPath {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.mode, &other.mode, &crate::diff::Path::Field { parent: path, name: "mode" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+    }
+}
+
+impl KeyToPath {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<KeyToPath> {
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `mode` field.
+    pub fn mode(self) -> crate::FieldPath<i32> {
+        self.child("mode")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+}
+
+impl crate::Normalize for KeyToPath {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.mode);
+        crate::Normalize::normalize(&mut self.path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for KeyToPath {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +163,42 @@ impl crate::serde::Serialize for KeyToPath {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for KeyToPath {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.key, 1, buf);
+        crate::protobuf::Field::encode_field(&self.path, 2, buf);
+        if let Some(value) = &self.mode {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_mode: Option<i32> = None;
+        let mut value_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_mode, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(KeyToPath {
+            key: value_key.unwrap_or_default(),
+            mode: value_mode,
+            path: value_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for KeyToPath {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for KeyToPath {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -26,6 +26,46 @@ impl crate::DeepMerge for ModifyVolumeStatus {
     }
 }
 
+impl crate::Defaulting for ModifyVolumeStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.status);
+        crate::Defaulting::set_defaults(&mut self.target_volume_attributes_class_name);
+    }
+}
+
+impl crate::diff::Diff for ModifyVolumeStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.status, &other.status, &crate::diff::Path::Field { parent: path, name: "status" }, changes);
+        crate::diff::Diff::diff(&self.target_volume_attributes_class_name, &other.target_volume_attributes_class_name, &crate::diff::Path::Field { parent: path, name: "targetVolumeAttributesClassName" }, changes);
+    }
+}
+
+impl ModifyVolumeStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ModifyVolumeStatus> {
+    /// The path of the `status` field.
+    pub fn status(self) -> crate::FieldPath<std::string::String> {
+        self.child("status")
+    }
+
+    /// The path of the `targetVolumeAttributesClassName` field.
+    pub fn target_volume_attributes_class_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("targetVolumeAttributesClassName")
+    }
+}
+
+impl crate::Normalize for ModifyVolumeStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.status);
+        crate::Normalize::normalize(&mut self.target_volume_attributes_class_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ModifyVolumeStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -113,6 +153,38 @@ impl crate::serde::Serialize for ModifyVolumeStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ModifyVolumeStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.target_volume_attributes_class_name {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.status, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_status: Option<std::string::String> = None;
+        let mut value_target_volume_attributes_class_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_target_volume_attributes_class_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_status, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ModifyVolumeStatus {
+            status: value_status.unwrap_or_default(),
+            target_volume_attributes_class_name: value_target_volume_attributes_class_name,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ModifyVolumeStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ModifyVolumeStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -37,6 +37,98 @@ impl crate::DeepMerge for NodeSpec {
     }
 }
 
+impl crate::Defaulting for NodeSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.config_source);
+        crate::Defaulting::set_defaults(&mut self.external_id);
+        crate::Defaulting::set_defaults(&mut self.pod_cidr);
+        crate::Defaulting::set_defaults(&mut self.pod_cidrs);
+        crate::Defaulting::set_defaults(&mut self.provider_id);
+        crate::Defaulting::set_defaults(&mut self.taints);
+        crate::Defaulting::set_defaults(&mut self.unschedulable);
+    }
+}
+
+impl crate::diff::Diff for NodeSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.config_source, &other.config_source, &crate::diff::Path::Field { parent: path, name: "configSource" }, changes);
+        crate::diff::Diff::diff(&self.external_id, &other.external_id, &crate::diff::Path::Field { parent: path, name: "externalID" }, changes);
+        crate::diff::Diff::diff(&self.pod_cidr, &other.pod_cidr, &crate::diff::Path::Field { parent: path, name: "podCIDR" }, changes);
+        crate::diff::strategies::list::set(&self.pod_cidrs, &other.pod_cidrs, &crate::diff::Path::Field { parent: path, name: "podCIDRs" }, changes);
+        crate::diff::Diff::diff(&self.provider_id, &other.provider_id, &crate::diff::Path::Field { parent: path, name: "providerID" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.taints,
+            &other.taints,
+            &crate::diff::Path::Field { parent: path, name: "taints" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.unschedulable, &other.unschedulable, &crate::diff::Path::Field { parent: path, name: "unschedulable" }, changes);
+    }
+}
+
+impl NodeSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeSpec> {
+    /// The path of the `configSource` field.
+    pub fn config_source(self) -> crate::FieldPath<crate::api::core::v1::NodeConfigSource> {
+        self.child("configSource")
+    }
+
+    /// The path of the `externalID` field.
+    pub fn external_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("externalID")
+    }
+
+    /// The path of the `podCIDR` field.
+    pub fn pod_cidr(self) -> crate::FieldPath<std::string::String> {
+        self.child("podCIDR")
+    }
+
+    /// The path of the `podCIDRs` field.
+    pub fn pod_cidrs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("podCIDRs")
+    }
+
+    /// The path of the `providerID` field.
+    pub fn provider_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("providerID")
+    }
+
+    /// The path of the `taints` field.
+    pub fn taints(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::Taint>> {
+        self.child("taints")
+    }
+
+    /// The path of the `unschedulable` field.
+    pub fn unschedulable(self) -> crate::FieldPath<bool> {
+        self.child("unschedulable")
+    }
+}
+
+impl crate::Normalize for NodeSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.config_source);
+        crate::Normalize::normalize(&mut self.external_id);
+        crate::Normalize::normalize(&mut self.pod_cidr);
+        crate::normalize_strategies::list::set(&mut self.pod_cidrs, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.provider_id);
+        crate::normalize_strategies::list::atomic(&mut self.taints, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.unschedulable);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -176,6 +268,70 @@ impl crate::serde::Serialize for NodeSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.pod_cidr {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.external_id {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.provider_id {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.unschedulable {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.taints {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.config_source {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.pod_cidrs {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_config_source: Option<crate::api::core::v1::NodeConfigSource> = None;
+        let mut value_external_id: Option<std::string::String> = None;
+        let mut value_pod_cidr: Option<std::string::String> = None;
+        let mut value_pod_cidrs: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_provider_id: Option<std::string::String> = None;
+        let mut value_taints: Option<std::vec::Vec<crate::api::core::v1::Taint>> = None;
+        let mut value_unschedulable: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_pod_cidr, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_external_id, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_provider_id, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_unschedulable, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_taints, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_config_source, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_pod_cidrs, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeSpec {
+            config_source: value_config_source,
+            external_id: value_external_id,
+            pod_cidr: value_pod_cidr,
+            pod_cidrs: value_pod_cidrs,
+            provider_id: value_provider_id,
+            taints: value_taints,
+            unschedulable: value_unschedulable,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
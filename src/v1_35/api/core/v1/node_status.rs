@@ -83,6 +83,234 @@ impl crate::DeepMerge for NodeStatus {
     }
 }
 
+impl crate::Defaulting for NodeStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.addresses);
+        crate::Defaulting::set_defaults(&mut self.allocatable);
+        crate::Defaulting::set_defaults(&mut self.capacity);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.config);
+        crate::Defaulting::set_defaults(&mut self.daemon_endpoints);
+        crate::Defaulting::set_defaults(&mut self.declared_features);
+        crate::Defaulting::set_defaults(&mut self.features);
+        crate::Defaulting::set_defaults(&mut self.images);
+        crate::Defaulting::set_defaults(&mut self.node_info);
+        crate::Defaulting::set_defaults(&mut self.phase);
+        crate::Defaulting::set_defaults(&mut self.runtime_handlers);
+        crate::Defaulting::set_defaults(&mut self.volumes_attached);
+        crate::Defaulting::set_defaults(&mut self.volumes_in_use);
+    }
+}
+
+impl crate::diff::Diff for NodeStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::map(
+            &self.addresses,
+            &other.addresses,
+            &crate::diff::Path::Field { parent: path, name: "addresses" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.allocatable,
+            &other.allocatable,
+            &crate::diff::Path::Field { parent: path, name: "allocatable" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.capacity,
+            &other.capacity,
+            &crate::diff::Path::Field { parent: path, name: "capacity" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::strategies::list::map(
+            &self.conditions,
+            &other.conditions,
+            &crate::diff::Path::Field { parent: path, name: "conditions" },
+            changes,
+            &[("type", |item| crate::diff::MergeKey::merge_key(&item.type_))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.config, &other.config, &crate::diff::Path::Field { parent: path, name: "config" }, changes);
+        crate::diff::Diff::diff(&self.daemon_endpoints, &other.daemon_endpoints, &crate::diff::Path::Field { parent: path, name: "daemonEndpoints" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.declared_features,
+            &other.declared_features,
+            &crate::diff::Path::Field { parent: path, name: "declaredFeatures" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.features, &other.features, &crate::diff::Path::Field { parent: path, name: "features" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.images,
+            &other.images,
+            &crate::diff::Path::Field { parent: path, name: "images" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.node_info, &other.node_info, &crate::diff::Path::Field { parent: path, name: "nodeInfo" }, changes);
+        crate::diff::Diff::diff(&self.phase, &other.phase, &crate::diff::Path::Field { parent: path, name: "phase" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.runtime_handlers,
+            &other.runtime_handlers,
+            &crate::diff::Path::Field { parent: path, name: "runtimeHandlers" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.volumes_attached,
+            &other.volumes_attached,
+            &crate::diff::Path::Field { parent: path, name: "volumesAttached" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.volumes_in_use,
+            &other.volumes_in_use,
+            &crate::diff::Path::Field { parent: path, name: "volumesInUse" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl NodeStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeStatus> {
+    /// The path of the `addresses` field.
+    pub fn addresses(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeAddress>> {
+        self.child("addresses")
+    }
+
+    /// The path of the `allocatable` field.
+    pub fn allocatable(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("allocatable")
+    }
+
+    /// The path of the `capacity` field.
+    pub fn capacity(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("capacity")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeCondition>> {
+        self.child("conditions")
+    }
+
+    /// The path of the `config` field.
+    pub fn config(self) -> crate::FieldPath<crate::api::core::v1::NodeConfigStatus> {
+        self.child("config")
+    }
+
+    /// The path of the `daemonEndpoints` field.
+    pub fn daemon_endpoints(self) -> crate::FieldPath<crate::api::core::v1::NodeDaemonEndpoints> {
+        self.child("daemonEndpoints")
+    }
+
+    /// The path of the `declaredFeatures` field.
+    pub fn declared_features(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("declaredFeatures")
+    }
+
+    /// The path of the `features` field.
+    pub fn features(self) -> crate::FieldPath<crate::api::core::v1::NodeFeatures> {
+        self.child("features")
+    }
+
+    /// The path of the `images` field.
+    pub fn images(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerImage>> {
+        self.child("images")
+    }
+
+    /// The path of the `nodeInfo` field.
+    pub fn node_info(self) -> crate::FieldPath<crate::api::core::v1::NodeSystemInfo> {
+        self.child("nodeInfo")
+    }
+
+    /// The path of the `phase` field.
+    pub fn phase(self) -> crate::FieldPath<std::string::String> {
+        self.child("phase")
+    }
+
+    /// The path of the `runtimeHandlers` field.
+    pub fn runtime_handlers(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::NodeRuntimeHandler>> {
+        self.child("runtimeHandlers")
+    }
+
+    /// The path of the `volumesAttached` field.
+    pub fn volumes_attached(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::AttachedVolume>> {
+        self.child("volumesAttached")
+    }
+
+    /// The path of the `volumesInUse` field.
+    pub fn volumes_in_use(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("volumesInUse")
+    }
+}
+
+impl crate::Normalize for NodeStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::map(&mut self.addresses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::map::values(&mut self.allocatable, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::map::values(&mut self.capacity, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::normalize_strategies::list::map(&mut self.conditions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.config);
+        crate::Normalize::normalize(&mut self.daemon_endpoints);
+        crate::normalize_strategies::list::atomic(&mut self.declared_features, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.features);
+        crate::normalize_strategies::list::atomic(&mut self.images, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.node_info);
+        crate::Normalize::normalize(&mut self.phase);
+        crate::normalize_strategies::list::atomic(&mut self.runtime_handlers, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.volumes_attached, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.volumes_in_use, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -292,6 +520,122 @@ impl crate::serde::Serialize for NodeStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.capacity {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.allocatable {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.phase {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.addresses {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.daemon_endpoints {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.node_info {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.images {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+        if let Some(value) = &self.volumes_in_use {
+            crate::protobuf::Field::encode_field(value, 9, buf);
+        }
+        if let Some(value) = &self.volumes_attached {
+            crate::protobuf::Field::encode_field(value, 10, buf);
+        }
+        if let Some(value) = &self.config {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+        if let Some(value) = &self.runtime_handlers {
+            crate::protobuf::Field::encode_field(value, 12, buf);
+        }
+        if let Some(value) = &self.features {
+            crate::protobuf::Field::encode_field(value, 13, buf);
+        }
+        if let Some(value) = &self.declared_features {
+            crate::protobuf::Field::encode_field(value, 14, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_addresses: Option<std::vec::Vec<crate::api::core::v1::NodeAddress>> = None;
+        let mut value_allocatable: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_capacity: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_conditions: Option<std::vec::Vec<crate::api::core::v1::NodeCondition>> = None;
+        let mut value_config: Option<crate::api::core::v1::NodeConfigStatus> = None;
+        let mut value_daemon_endpoints: Option<crate::api::core::v1::NodeDaemonEndpoints> = None;
+        let mut value_declared_features: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_features: Option<crate::api::core::v1::NodeFeatures> = None;
+        let mut value_images: Option<std::vec::Vec<crate::api::core::v1::ContainerImage>> = None;
+        let mut value_node_info: Option<crate::api::core::v1::NodeSystemInfo> = None;
+        let mut value_phase: Option<std::string::String> = None;
+        let mut value_runtime_handlers: Option<std::vec::Vec<crate::api::core::v1::NodeRuntimeHandler>> = None;
+        let mut value_volumes_attached: Option<std::vec::Vec<crate::api::core::v1::AttachedVolume>> = None;
+        let mut value_volumes_in_use: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_capacity, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_allocatable, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_phase, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_addresses, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_daemon_endpoints, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_node_info, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_images, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_volumes_in_use, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_volumes_attached, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_config, wire_type, &mut buf)?,
+                12 => crate::protobuf::Field::merge_field(&mut value_runtime_handlers, wire_type, &mut buf)?,
+                13 => crate::protobuf::Field::merge_field(&mut value_features, wire_type, &mut buf)?,
+                14 => crate::protobuf::Field::merge_field(&mut value_declared_features, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeStatus {
+            addresses: value_addresses,
+            allocatable: value_allocatable,
+            capacity: value_capacity,
+            conditions: value_conditions,
+            config: value_config,
+            daemon_endpoints: value_daemon_endpoints,
+            declared_features: value_declared_features,
+            features: value_features,
+            images: value_images,
+            node_info: value_node_info,
+            phase: value_phase,
+            runtime_handlers: value_runtime_handlers,
+            volumes_attached: value_volumes_attached,
+            volumes_in_use: value_volumes_in_use,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeStatus {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "addresses" | "conditions" => Some(&["type"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -53,6 +53,118 @@ impl crate::DeepMerge for NodeSystemInfo {
     }
 }
 
+impl crate::Defaulting for NodeSystemInfo {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.architecture);
+        crate::Defaulting::set_defaults(&mut self.boot_id);
+        crate::Defaulting::set_defaults(&mut self.container_runtime_version);
+        crate::Defaulting::set_defaults(&mut self.kernel_version);
+        crate::Defaulting::set_defaults(&mut self.kube_proxy_version);
+        crate::Defaulting::set_defaults(&mut self.kubelet_version);
+        crate::Defaulting::set_defaults(&mut self.machine_id);
+        crate::Defaulting::set_defaults(&mut self.operating_system);
+        crate::Defaulting::set_defaults(&mut self.os_image);
+        crate::Defaulting::set_defaults(&mut self.swap);
+        crate::Defaulting::set_defaults(&mut self.system_uuid);
+    }
+}
+
+impl crate::diff::Diff for NodeSystemInfo {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.architecture, &other.architecture, &crate::diff::Path::Field { parent: path, name: "architecture" }, changes);
+        crate::diff::Diff::diff(&self.boot_id, &other.boot_id, &crate::diff::Path::Field { parent: path, name: "bootID" }, changes);
+        crate::diff::Diff::diff(&self.container_runtime_version, &other.container_runtime_version, &crate::diff::Path::Field { parent: path, name: "containerRuntimeVersion" }, changes);
+        crate::diff::Diff::diff(&self.kernel_version, &other.kernel_version, &crate::diff::Path::Field { parent: path, name: "kernelVersion" }, changes);
+        crate::diff::Diff::diff(&self.kube_proxy_version, &other.kube_proxy_version, &crate::diff::Path::Field { parent: path, name: "kubeProxyVersion" }, changes);
+        crate::diff::Diff::diff(&self.kubelet_version, &other.kubelet_version, &crate::diff::Path::Field { parent: path, name: "kubeletVersion" }, changes);
+        crate::diff::Diff::diff(&self.machine_id, &other.machine_id, &crate::diff::Path::Field { parent: path, name: "machineID" }, changes);
+        crate::diff::Diff::diff(&self.operating_system, &other.operating_system, &crate::diff::Path::Field { parent: path, name: "operatingSystem" }, changes);
+        crate::diff::Diff::diff(&self.os_image, &other.os_image, &crate::diff::Path::Field { parent: path, name: "osImage" }, changes);
+        crate::diff::Diff::diff(&self.swap, &other.swap, &crate::diff::Path::Field { parent: path, name: "swap" }, changes);
+        crate::diff::Diff::diff(&self.system_uuid, &other.system_uuid, &crate::diff::Path::Field { parent: path, name: "systemUUID" }, changes);
+    }
+}
+
+impl NodeSystemInfo {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<NodeSystemInfo> {
+    /// The path of the `architecture` field.
+    pub fn architecture(self) -> crate::FieldPath<std::string::String> {
+        self.child("architecture")
+    }
+
+    /// The path of the `bootID` field.
+    pub fn boot_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("bootID")
+    }
+
+    /// The path of the `containerRuntimeVersion` field.
+    pub fn container_runtime_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("containerRuntimeVersion")
+    }
+
+    /// The path of the `kernelVersion` field.
+    pub fn kernel_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("kernelVersion")
+    }
+
+    /// The path of the `kubeProxyVersion` field.
+    pub fn kube_proxy_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("kubeProxyVersion")
+    }
+
+    /// The path of the `kubeletVersion` field.
+    pub fn kubelet_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("kubeletVersion")
+    }
+
+    /// The path of the `machineID` field.
+    pub fn machine_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("machineID")
+    }
+
+    /// The path of the `operatingSystem` field.
+    pub fn operating_system(self) -> crate::FieldPath<std::string::String> {
+        self.child("operatingSystem")
+    }
+
+    /// The path of the `osImage` field.
+    pub fn os_image(self) -> crate::FieldPath<std::string::String> {
+        self.child("osImage")
+    }
+
+    /// The path of the `swap` field.
+    pub fn swap(self) -> crate::FieldPath<crate::api::core::v1::NodeSwapStatus> {
+        self.child("swap")
+    }
+
+    /// The path of the `systemUUID` field.
+    pub fn system_uuid(self) -> crate::FieldPath<std::string::String> {
+        self.child("systemUUID")
+    }
+}
+
+impl crate::Normalize for NodeSystemInfo {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.architecture);
+        crate::Normalize::normalize(&mut self.boot_id);
+        crate::Normalize::normalize(&mut self.container_runtime_version);
+        crate::Normalize::normalize(&mut self.kernel_version);
+        crate::Normalize::normalize(&mut self.kube_proxy_version);
+        crate::Normalize::normalize(&mut self.kubelet_version);
+        crate::Normalize::normalize(&mut self.machine_id);
+        crate::Normalize::normalize(&mut self.operating_system);
+        crate::Normalize::normalize(&mut self.os_image);
+        crate::Normalize::normalize(&mut self.swap);
+        crate::Normalize::normalize(&mut self.system_uuid);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for NodeSystemInfo {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -203,6 +315,74 @@ impl crate::serde::Serialize for NodeSystemInfo {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for NodeSystemInfo {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.machine_id, 1, buf);
+        crate::protobuf::Field::encode_field(&self.system_uuid, 2, buf);
+        crate::protobuf::Field::encode_field(&self.boot_id, 3, buf);
+        crate::protobuf::Field::encode_field(&self.kernel_version, 4, buf);
+        crate::protobuf::Field::encode_field(&self.os_image, 5, buf);
+        crate::protobuf::Field::encode_field(&self.container_runtime_version, 6, buf);
+        crate::protobuf::Field::encode_field(&self.kubelet_version, 7, buf);
+        crate::protobuf::Field::encode_field(&self.kube_proxy_version, 8, buf);
+        crate::protobuf::Field::encode_field(&self.operating_system, 9, buf);
+        crate::protobuf::Field::encode_field(&self.architecture, 10, buf);
+        if let Some(value) = &self.swap {
+            crate::protobuf::Field::encode_field(value, 11, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_architecture: Option<std::string::String> = None;
+        let mut value_boot_id: Option<std::string::String> = None;
+        let mut value_container_runtime_version: Option<std::string::String> = None;
+        let mut value_kernel_version: Option<std::string::String> = None;
+        let mut value_kube_proxy_version: Option<std::string::String> = None;
+        let mut value_kubelet_version: Option<std::string::String> = None;
+        let mut value_machine_id: Option<std::string::String> = None;
+        let mut value_operating_system: Option<std::string::String> = None;
+        let mut value_os_image: Option<std::string::String> = None;
+        let mut value_swap: Option<crate::api::core::v1::NodeSwapStatus> = None;
+        let mut value_system_uuid: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_machine_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_system_uuid, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_boot_id, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_kernel_version, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_os_image, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_container_runtime_version, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_kubelet_version, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_kube_proxy_version, wire_type, &mut buf)?,
+                9 => crate::protobuf::Field::merge_field(&mut value_operating_system, wire_type, &mut buf)?,
+                10 => crate::protobuf::Field::merge_field(&mut value_architecture, wire_type, &mut buf)?,
+                11 => crate::protobuf::Field::merge_field(&mut value_swap, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(NodeSystemInfo {
+            architecture: value_architecture.unwrap_or_default(),
+            boot_id: value_boot_id.unwrap_or_default(),
+            container_runtime_version: value_container_runtime_version.unwrap_or_default(),
+            kernel_version: value_kernel_version.unwrap_or_default(),
+            kube_proxy_version: value_kube_proxy_version.unwrap_or_default(),
+            kubelet_version: value_kubelet_version.unwrap_or_default(),
+            machine_id: value_machine_id.unwrap_or_default(),
+            operating_system: value_operating_system.unwrap_or_default(),
+            os_image: value_os_image.unwrap_or_default(),
+            swap: value_swap,
+            system_uuid: value_system_uuid.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for NodeSystemInfo {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for NodeSystemInfo {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for PersistentVolumeClaimVolumeSource {
     }
 }
 
+impl crate::Defaulting for PersistentVolumeClaimVolumeSource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.claim_name);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+    }
+}
+
+impl crate::diff::Diff for PersistentVolumeClaimVolumeSource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.claim_name, &other.claim_name, &crate::diff::Path::Field { parent: path, name: "claimName" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+    }
+}
+
+impl PersistentVolumeClaimVolumeSource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PersistentVolumeClaimVolumeSource> {
+    /// The path of the `claimName` field.
+    pub fn claim_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("claimName")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+}
+
+impl crate::Normalize for PersistentVolumeClaimVolumeSource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.claim_name);
+        crate::Normalize::normalize(&mut self.read_only);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PersistentVolumeClaimVolumeSource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -104,6 +144,38 @@ impl crate::serde::Serialize for PersistentVolumeClaimVolumeSource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PersistentVolumeClaimVolumeSource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.claim_name, 1, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_claim_name: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_claim_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PersistentVolumeClaimVolumeSource {
+            claim_name: value_claim_name.unwrap_or_default(),
+            read_only: value_read_only,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PersistentVolumeClaimVolumeSource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PersistentVolumeClaimVolumeSource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,56 @@ impl crate::DeepMerge for PodExtendedResourceClaimStatus {
     }
 }
 
+impl crate::Defaulting for PodExtendedResourceClaimStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.request_mappings);
+        crate::Defaulting::set_defaults(&mut self.resource_claim_name);
+    }
+}
+
+impl crate::diff::Diff for PodExtendedResourceClaimStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.request_mappings,
+            &other.request_mappings,
+            &crate::diff::Path::Field { parent: path, name: "requestMappings" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.resource_claim_name, &other.resource_claim_name, &crate::diff::Path::Field { parent: path, name: "resourceClaimName" }, changes);
+    }
+}
+
+impl PodExtendedResourceClaimStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodExtendedResourceClaimStatus> {
+    /// The path of the `requestMappings` field.
+    pub fn request_mappings(self) -> crate::FieldPath<std::vec::Vec<crate::api::core::v1::ContainerExtendedResourceRequest>> {
+        self.child("requestMappings")
+    }
+
+    /// The path of the `resourceClaimName` field.
+    pub fn resource_claim_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceClaimName")
+    }
+}
+
+impl crate::Normalize for PodExtendedResourceClaimStatus {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.request_mappings, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.resource_claim_name);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodExtendedResourceClaimStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +151,36 @@ impl crate::serde::Serialize for PodExtendedResourceClaimStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodExtendedResourceClaimStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.request_mappings, 1, buf);
+        crate::protobuf::Field::encode_field(&self.resource_claim_name, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_request_mappings: Option<std::vec::Vec<crate::api::core::v1::ContainerExtendedResourceRequest>> = None;
+        let mut value_resource_claim_name: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_request_mappings, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource_claim_name, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodExtendedResourceClaimStatus {
+            request_mappings: value_request_mappings.unwrap_or_default(),
+            resource_claim_name: value_resource_claim_name.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodExtendedResourceClaimStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodExtendedResourceClaimStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for PodIP {
     }
 }
 
+impl crate::Defaulting for PodIP {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.ip);
+    }
+}
+
+impl crate::diff::Diff for PodIP {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.ip, &other.ip, &crate::diff::Path::Field { parent: path, name: "ip" }, changes);
+    }
+}
+
+impl PodIP {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodIP> {
+    /// The path of the `ip` field.
+    pub fn ip(self) -> crate::FieldPath<std::string::String> {
+        self.child("ip")
+    }
+}
+
+impl crate::Normalize for PodIP {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.ip);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodIP {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for PodIP {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodIP {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.ip, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_ip: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_ip, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodIP {
+            ip: value_ip.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodIP {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodIP {
     fn schema_name() -> std::borrow::Cow<'static, str> {
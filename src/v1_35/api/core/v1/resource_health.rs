@@ -25,6 +25,46 @@ impl crate::DeepMerge for ResourceHealth {
     }
 }
 
+impl crate::Defaulting for ResourceHealth {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.health);
+        crate::Defaulting::set_defaults(&mut self.resource_id);
+    }
+}
+
+impl crate::diff::Diff for ResourceHealth {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.health, &other.health, &crate::diff::Path::Field { parent: path, name: "health" }, changes);
+        crate::diff::Diff::diff(&self.resource_id, &other.resource_id, &crate::diff::Path::Field { parent: path, name: "resourceID" }, changes);
+    }
+}
+
+impl ResourceHealth {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceHealth> {
+    /// The path of the `health` field.
+    pub fn health(self) -> crate::FieldPath<std::string::String> {
+        self.child("health")
+    }
+
+    /// The path of the `resourceID` field.
+    pub fn resource_id(self) -> crate::FieldPath<std::string::String> {
+        self.child("resourceID")
+    }
+}
+
+impl crate::Normalize for ResourceHealth {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.health);
+        crate::Normalize::normalize(&mut self.resource_id);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceHealth {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +152,38 @@ impl crate::serde::Serialize for ResourceHealth {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceHealth {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.resource_id, 1, buf);
+        if let Some(value) = &self.health {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_health: Option<std::string::String> = None;
+        let mut value_resource_id: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_resource_id, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_health, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceHealth {
+            health: value_health,
+            resource_id: value_resource_id.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceHealth {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceHealth {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -23,6 +23,74 @@ impl crate::DeepMerge for ResourceQuotaSpec {
     }
 }
 
+impl crate::Defaulting for ResourceQuotaSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.hard);
+        crate::Defaulting::set_defaults(&mut self.scope_selector);
+        crate::Defaulting::set_defaults(&mut self.scopes);
+    }
+}
+
+impl crate::diff::Diff for ResourceQuotaSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.hard,
+            &other.hard,
+            &crate::diff::Path::Field { parent: path, name: "hard" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.scope_selector, &other.scope_selector, &crate::diff::Path::Field { parent: path, name: "scopeSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.scopes,
+            &other.scopes,
+            &crate::diff::Path::Field { parent: path, name: "scopes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ResourceQuotaSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ResourceQuotaSpec> {
+    /// The path of the `hard` field.
+    pub fn hard(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("hard")
+    }
+
+    /// The path of the `scopeSelector` field.
+    pub fn scope_selector(self) -> crate::FieldPath<crate::api::core::v1::ScopeSelector> {
+        self.child("scopeSelector")
+    }
+
+    /// The path of the `scopes` field.
+    pub fn scopes(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("scopes")
+    }
+}
+
+impl crate::Normalize for ResourceQuotaSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.hard, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.scope_selector);
+        crate::normalize_strategies::list::atomic(&mut self.scopes, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ResourceQuotaSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -122,6 +190,46 @@ impl crate::serde::Serialize for ResourceQuotaSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ResourceQuotaSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.hard {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.scopes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.scope_selector {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_hard: Option<std::collections::BTreeMap<std::string::String, crate::apimachinery::pkg::api::resource::Quantity>> = None;
+        let mut value_scope_selector: Option<crate::api::core::v1::ScopeSelector> = None;
+        let mut value_scopes: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_hard, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_scopes, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_scope_selector, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ResourceQuotaSpec {
+            hard: value_hard,
+            scope_selector: value_scope_selector,
+            scopes: value_scopes,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ResourceQuotaSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ResourceQuotaSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
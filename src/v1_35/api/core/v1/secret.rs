@@ -58,6 +58,97 @@ impl crate::DeepMerge for Secret {
     }
 }
 
+impl crate::Defaulting for Secret {
+    fn set_defaults(&mut self) {
+        if self.type_.is_none() {
+            self.type_ = Some("Opaque".into());
+        }
+        crate::Defaulting::set_defaults(&mut self.data);
+        crate::Defaulting::set_defaults(&mut self.immutable);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.string_data);
+        crate::Defaulting::set_defaults(&mut self.type_);
+    }
+}
+
+impl crate::diff::Diff for Secret {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::map::values(
+            &self.data,
+            &other.data,
+            &crate::diff::Path::Field { parent: path, name: "data" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.immutable, &other.immutable, &crate::diff::Path::Field { parent: path, name: "immutable" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::map::values(
+            &self.string_data,
+            &other.string_data,
+            &crate::diff::Path::Field { parent: path, name: "stringData" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.type_, &other.type_, &crate::diff::Path::Field { parent: path, name: "type" }, changes);
+    }
+}
+
+impl Secret {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Secret> {
+    /// The path of the `data` field.
+    pub fn data(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::ByteString>> {
+        self.child("data")
+    }
+
+    /// The path of the `immutable` field.
+    pub fn immutable(self) -> crate::FieldPath<bool> {
+        self.child("immutable")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `stringData` field.
+    pub fn string_data(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("stringData")
+    }
+
+    /// The path of the `type` field.
+    pub fn type_(self) -> crate::FieldPath<std::string::String> {
+        self.child("type")
+    }
+}
+
+impl crate::Normalize for Secret {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::map::values(&mut self.data, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.immutable);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::map::values(&mut self.string_data, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.type_);
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Secret {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -195,6 +286,66 @@ impl crate::serde::Serialize for Secret {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Secret {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        if let Some(value) = &self.data {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.type_ {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.string_data {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.immutable {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_data: Option<std::collections::BTreeMap<std::string::String, crate::ByteString>> = None;
+        let mut value_immutable: Option<bool> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_string_data: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_type_: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_data, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_type_, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_string_data, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_immutable, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Secret {
+            data: value_data,
+            immutable: value_immutable,
+            metadata: value_metadata.unwrap_or_default(),
+            string_data: value_string_data,
+            type_: value_type_,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Secret {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Secret {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -21,6 +21,57 @@ impl crate::DeepMerge for ServiceAccountTokenProjection {
     }
 }
 
+impl crate::Defaulting for ServiceAccountTokenProjection {
+    fn set_defaults(&mut self) {
+        if self.expiration_seconds.is_none() {
+            self.expiration_seconds = Some(3600);
+        }
+        crate::Defaulting::set_defaults(&mut self.audience);
+        crate::Defaulting::set_defaults(&mut self.expiration_seconds);
+        crate::Defaulting::set_defaults(&mut self.path);
+    }
+}
+
+impl crate::diff::Diff for ServiceAccountTokenProjection {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.audience, &other.audience, &crate::diff::Path::Field { parent: path, name: "audience" }, changes);
+        crate::diff::Diff::diff(&self.expiration_seconds, &other.expiration_seconds, &crate::diff::Path::Field { parent: path, name: "expirationSeconds" }, changes);
+        crate::diff::Diff::diff(&self.path, &other.path, &crate::diff::Path::Field { parent: path, name: "path" }, changes);
+    }
+}
+
+impl ServiceAccountTokenProjection {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServiceAccountTokenProjection> {
+    /// The path of the `audience` field.
+    pub fn audience(self) -> crate::FieldPath<std::string::String> {
+        self.child("audience")
+    }
+
+    /// The path of the `expirationSeconds` field.
+    pub fn expiration_seconds(self) -> crate::FieldPath<i64> {
+        self.child("expirationSeconds")
+    }
+
+    /// The path of the `path` field.
+    pub fn path(self) -> crate::FieldPath<std::string::String> {
+        self.child("path")
+    }
+}
+
+impl crate::Normalize for ServiceAccountTokenProjection {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.audience);
+        crate::Normalize::normalize(&mut self.expiration_seconds);
+        crate::Normalize::normalize(&mut self.path);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceAccountTokenProjection {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -118,6 +169,44 @@ impl crate::serde::Serialize for ServiceAccountTokenProjection {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServiceAccountTokenProjection {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.audience {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.expiration_seconds {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.path, 3, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_audience: Option<std::string::String> = None;
+        let mut value_expiration_seconds: Option<i64> = None;
+        let mut value_path: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_audience, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_expiration_seconds, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_path, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServiceAccountTokenProjection {
+            audience: value_audience,
+            expiration_seconds: value_expiration_seconds,
+            path: value_path.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServiceAccountTokenProjection {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceAccountTokenProjection {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -52,6 +52,104 @@ impl crate::DeepMerge for TopologySpreadConstraint {
     }
 }
 
+impl crate::Defaulting for TopologySpreadConstraint {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.label_selector);
+        crate::Defaulting::set_defaults(&mut self.match_label_keys);
+        crate::Defaulting::set_defaults(&mut self.max_skew);
+        crate::Defaulting::set_defaults(&mut self.min_domains);
+        crate::Defaulting::set_defaults(&mut self.node_affinity_policy);
+        crate::Defaulting::set_defaults(&mut self.node_taints_policy);
+        crate::Defaulting::set_defaults(&mut self.topology_key);
+        crate::Defaulting::set_defaults(&mut self.when_unsatisfiable);
+    }
+}
+
+impl crate::diff::Diff for TopologySpreadConstraint {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.label_selector, &other.label_selector, &crate::diff::Path::Field { parent: path, name: "labelSelector" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.match_label_keys,
+            &other.match_label_keys,
+            &crate::diff::Path::Field { parent: path, name: "matchLabelKeys" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.max_skew, &other.max_skew, &crate::diff::Path::Field { parent: path, name: "maxSkew" }, changes);
+        crate::diff::Diff::diff(&self.min_domains, &other.min_domains, &crate::diff::Path::Field { parent: path, name: "minDomains" }, changes);
+        crate::diff::Diff::diff(&self.node_affinity_policy, &other.node_affinity_policy, &crate::diff::Path::Field { parent: path, name: "nodeAffinityPolicy" }, changes);
+        crate::diff::Diff::diff(&self.node_taints_policy, &other.node_taints_policy, &crate::diff::Path::Field { parent: path, name: "nodeTaintsPolicy" }, changes);
+        crate::diff::Diff::diff(&self.topology_key, &other.topology_key, &crate::diff::Path::Field { parent: path, name: "topologyKey" }, changes);
+        crate::diff::Diff::diff(&self.when_unsatisfiable, &other.when_unsatisfiable, &crate::diff::Path::Field { parent: path, name: "whenUnsatisfiable" }, changes);
+    }
+}
+
+impl TopologySpreadConstraint {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<TopologySpreadConstraint> {
+    /// The path of the `labelSelector` field.
+    pub fn label_selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("labelSelector")
+    }
+
+    /// The path of the `matchLabelKeys` field.
+    pub fn match_label_keys(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("matchLabelKeys")
+    }
+
+    /// The path of the `maxSkew` field.
+    pub fn max_skew(self) -> crate::FieldPath<i32> {
+        self.child("maxSkew")
+    }
+
+    /// The path of the `minDomains` field.
+    pub fn min_domains(self) -> crate::FieldPath<i32> {
+        self.child("minDomains")
+    }
+
+    /// The path of the `nodeAffinityPolicy` field.
+    pub fn node_affinity_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeAffinityPolicy")
+    }
+
+    /// The path of the `nodeTaintsPolicy` field.
+    pub fn node_taints_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeTaintsPolicy")
+    }
+
+    /// The path of the `topologyKey` field.
+    pub fn topology_key(self) -> crate::FieldPath<std::string::String> {
+        self.child("topologyKey")
+    }
+
+    /// The path of the `whenUnsatisfiable` field.
+    pub fn when_unsatisfiable(self) -> crate::FieldPath<std::string::String> {
+        self.child("whenUnsatisfiable")
+    }
+}
+
+impl crate::Normalize for TopologySpreadConstraint {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.label_selector);
+        crate::normalize_strategies::list::atomic(&mut self.match_label_keys, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.max_skew);
+        crate::Normalize::normalize(&mut self.min_domains);
+        crate::Normalize::normalize(&mut self.node_affinity_policy);
+        crate::Normalize::normalize(&mut self.node_taints_policy);
+        crate::Normalize::normalize(&mut self.topology_key);
+        crate::Normalize::normalize(&mut self.when_unsatisfiable);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for TopologySpreadConstraint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -193,6 +291,70 @@ impl crate::serde::Serialize for TopologySpreadConstraint {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for TopologySpreadConstraint {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.max_skew, 1, buf);
+        crate::protobuf::Field::encode_field(&self.topology_key, 2, buf);
+        crate::protobuf::Field::encode_field(&self.when_unsatisfiable, 3, buf);
+        if let Some(value) = &self.label_selector {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.min_domains {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.node_affinity_policy {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.node_taints_policy {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.match_label_keys {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_label_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_match_label_keys: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_max_skew: Option<i32> = None;
+        let mut value_min_domains: Option<i32> = None;
+        let mut value_node_affinity_policy: Option<std::string::String> = None;
+        let mut value_node_taints_policy: Option<std::string::String> = None;
+        let mut value_topology_key: Option<std::string::String> = None;
+        let mut value_when_unsatisfiable: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_max_skew, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_topology_key, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_when_unsatisfiable, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_label_selector, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_min_domains, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_node_affinity_policy, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_node_taints_policy, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_match_label_keys, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(TopologySpreadConstraint {
+            label_selector: value_label_selector,
+            match_label_keys: value_match_label_keys,
+            max_skew: value_max_skew.unwrap_or_default(),
+            min_domains: value_min_domains,
+            node_affinity_policy: value_node_affinity_policy,
+            node_taints_policy: value_node_taints_policy,
+            topology_key: value_topology_key.unwrap_or_default(),
+            when_unsatisfiable: value_when_unsatisfiable.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for TopologySpreadConstraint {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for TopologySpreadConstraint {
     fn schema_name() -> std::borrow::Cow<'static, str> {
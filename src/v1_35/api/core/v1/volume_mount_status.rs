@@ -25,6 +25,62 @@ impl crate::DeepMerge for VolumeMountStatus {
     }
 }
 
+impl crate::Defaulting for VolumeMountStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.mount_path);
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.read_only);
+        crate::Defaulting::set_defaults(&mut self.recursive_read_only);
+    }
+}
+
+impl crate::diff::Diff for VolumeMountStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.mount_path, &other.mount_path, &crate::diff::Path::Field { parent: path, name: "mountPath" }, changes);
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.read_only, &other.read_only, &crate::diff::Path::Field { parent: path, name: "readOnly" }, changes);
+        crate::diff::Diff::diff(&self.recursive_read_only, &other.recursive_read_only, &crate::diff::Path::Field { parent: path, name: "recursiveReadOnly" }, changes);
+    }
+}
+
+impl VolumeMountStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeMountStatus> {
+    /// The path of the `mountPath` field.
+    pub fn mount_path(self) -> crate::FieldPath<std::string::String> {
+        self.child("mountPath")
+    }
+
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `readOnly` field.
+    pub fn read_only(self) -> crate::FieldPath<bool> {
+        self.child("readOnly")
+    }
+
+    /// The path of the `recursiveReadOnly` field.
+    pub fn recursive_read_only(self) -> crate::FieldPath<std::string::String> {
+        self.child("recursiveReadOnly")
+    }
+}
+
+impl crate::Normalize for VolumeMountStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.mount_path);
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.read_only);
+        crate::Normalize::normalize(&mut self.recursive_read_only);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeMountStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -129,6 +185,48 @@ impl crate::serde::Serialize for VolumeMountStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeMountStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.mount_path, 2, buf);
+        if let Some(value) = &self.read_only {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.recursive_read_only {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_mount_path: Option<std::string::String> = None;
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_read_only: Option<bool> = None;
+        let mut value_recursive_read_only: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_mount_path, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_read_only, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_recursive_read_only, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeMountStatus {
+            mount_path: value_mount_path.unwrap_or_default(),
+            name: value_name.unwrap_or_default(),
+            read_only: value_read_only,
+            recursive_read_only: value_recursive_read_only,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeMountStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeMountStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for VolumeNodeAffinity {
     }
 }
 
+impl crate::Defaulting for VolumeNodeAffinity {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.required);
+    }
+}
+
+impl crate::diff::Diff for VolumeNodeAffinity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.required, &other.required, &crate::diff::Path::Field { parent: path, name: "required" }, changes);
+    }
+}
+
+impl VolumeNodeAffinity {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeNodeAffinity> {
+    /// The path of the `required` field.
+    pub fn required(self) -> crate::FieldPath<crate::api::core::v1::NodeSelector> {
+        self.child("required")
+    }
+}
+
+impl crate::Normalize for VolumeNodeAffinity {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.required);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeNodeAffinity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for VolumeNodeAffinity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeNodeAffinity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.required {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_required: Option<crate::api::core::v1::NodeSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_required, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeNodeAffinity {
+            required: value_required,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeNodeAffinity {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeNodeAffinity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
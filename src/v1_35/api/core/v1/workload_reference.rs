@@ -21,6 +21,54 @@ impl crate::DeepMerge for WorkloadReference {
     }
 }
 
+impl crate::Defaulting for WorkloadReference {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.pod_group);
+        crate::Defaulting::set_defaults(&mut self.pod_group_replica_key);
+    }
+}
+
+impl crate::diff::Diff for WorkloadReference {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.pod_group, &other.pod_group, &crate::diff::Path::Field { parent: path, name: "podGroup" }, changes);
+        crate::diff::Diff::diff(&self.pod_group_replica_key, &other.pod_group_replica_key, &crate::diff::Path::Field { parent: path, name: "podGroupReplicaKey" }, changes);
+    }
+}
+
+impl WorkloadReference {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WorkloadReference> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `podGroup` field.
+    pub fn pod_group(self) -> crate::FieldPath<std::string::String> {
+        self.child("podGroup")
+    }
+
+    /// The path of the `podGroupReplicaKey` field.
+    pub fn pod_group_replica_key(self) -> crate::FieldPath<std::string::String> {
+        self.child("podGroupReplicaKey")
+    }
+}
+
+impl crate::Normalize for WorkloadReference {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.pod_group);
+        crate::Normalize::normalize(&mut self.pod_group_replica_key);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WorkloadReference {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -115,6 +163,42 @@ impl crate::serde::Serialize for WorkloadReference {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WorkloadReference {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.pod_group, 2, buf);
+        if let Some(value) = &self.pod_group_replica_key {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_pod_group: Option<std::string::String> = None;
+        let mut value_pod_group_replica_key: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_group, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_pod_group_replica_key, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WorkloadReference {
+            name: value_name.unwrap_or_default(),
+            pod_group: value_pod_group.unwrap_or_default(),
+            pod_group_replica_key: value_pod_group_replica_key,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WorkloadReference {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WorkloadReference {
     fn schema_name() -> std::borrow::Cow<'static, str> {
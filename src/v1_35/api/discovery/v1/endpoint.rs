@@ -43,6 +43,106 @@ impl crate::DeepMerge for Endpoint {
     }
 }
 
+impl crate::Defaulting for Endpoint {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.addresses);
+        crate::Defaulting::set_defaults(&mut self.conditions);
+        crate::Defaulting::set_defaults(&mut self.deprecated_topology);
+        crate::Defaulting::set_defaults(&mut self.hints);
+        crate::Defaulting::set_defaults(&mut self.hostname);
+        crate::Defaulting::set_defaults(&mut self.node_name);
+        crate::Defaulting::set_defaults(&mut self.target_ref);
+        crate::Defaulting::set_defaults(&mut self.zone);
+    }
+}
+
+impl crate::diff::Diff for Endpoint {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::set(&self.addresses, &other.addresses, &crate::diff::Path::Field { parent: path, name: "addresses" }, changes);
+        crate::diff::Diff::diff(&self.conditions, &other.conditions, &crate::diff::Path::Field { parent: path, name: "conditions" }, changes);
+        crate::diff::strategies::map::values(
+            &self.deprecated_topology,
+            &other.deprecated_topology,
+            &crate::diff::Path::Field { parent: path, name: "deprecatedTopology" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.hints, &other.hints, &crate::diff::Path::Field { parent: path, name: "hints" }, changes);
+        crate::diff::Diff::diff(&self.hostname, &other.hostname, &crate::diff::Path::Field { parent: path, name: "hostname" }, changes);
+        crate::diff::Diff::diff(&self.node_name, &other.node_name, &crate::diff::Path::Field { parent: path, name: "nodeName" }, changes);
+        crate::diff::Diff::diff(&self.target_ref, &other.target_ref, &crate::diff::Path::Field { parent: path, name: "targetRef" }, changes);
+        crate::diff::Diff::diff(&self.zone, &other.zone, &crate::diff::Path::Field { parent: path, name: "zone" }, changes);
+    }
+}
+
+impl Endpoint {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<Endpoint> {
+    /// The path of the `addresses` field.
+    pub fn addresses(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("addresses")
+    }
+
+    /// The path of the `conditions` field.
+    pub fn conditions(self) -> crate::FieldPath<crate::api::discovery::v1::EndpointConditions> {
+        self.child("conditions")
+    }
+
+    /// The path of the `deprecatedTopology` field.
+    pub fn deprecated_topology(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("deprecatedTopology")
+    }
+
+    /// The path of the `hints` field.
+    pub fn hints(self) -> crate::FieldPath<crate::api::discovery::v1::EndpointHints> {
+        self.child("hints")
+    }
+
+    /// The path of the `hostname` field.
+    pub fn hostname(self) -> crate::FieldPath<std::string::String> {
+        self.child("hostname")
+    }
+
+    /// The path of the `nodeName` field.
+    pub fn node_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("nodeName")
+    }
+
+    /// The path of the `targetRef` field.
+    pub fn target_ref(self) -> crate::FieldPath<crate::api::core::v1::ObjectReference> {
+        self.child("targetRef")
+    }
+
+    /// The path of the `zone` field.
+    pub fn zone(self) -> crate::FieldPath<std::string::String> {
+        self.child("zone")
+    }
+}
+
+impl crate::Normalize for Endpoint {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::set(&mut self.addresses, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.conditions);
+        crate::normalize_strategies::map::values(&mut self.deprecated_topology, |value| {
+            crate::Normalize::normalize(value);
+        });
+        crate::Normalize::normalize(&mut self.hints);
+        crate::Normalize::normalize(&mut self.hostname);
+        crate::Normalize::normalize(&mut self.node_name);
+        crate::Normalize::normalize(&mut self.target_ref);
+        crate::Normalize::normalize(&mut self.zone);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for Endpoint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -190,6 +290,74 @@ impl crate::serde::Serialize for Endpoint {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for Endpoint {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.addresses, 1, buf);
+        if let Some(value) = &self.conditions {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.hostname {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.target_ref {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+        if let Some(value) = &self.deprecated_topology {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.node_name {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+        if let Some(value) = &self.zone {
+            crate::protobuf::Field::encode_field(value, 7, buf);
+        }
+        if let Some(value) = &self.hints {
+            crate::protobuf::Field::encode_field(value, 8, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_addresses: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_conditions: Option<crate::api::discovery::v1::EndpointConditions> = None;
+        let mut value_deprecated_topology: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+        let mut value_hints: Option<crate::api::discovery::v1::EndpointHints> = None;
+        let mut value_hostname: Option<std::string::String> = None;
+        let mut value_node_name: Option<std::string::String> = None;
+        let mut value_target_ref: Option<crate::api::core::v1::ObjectReference> = None;
+        let mut value_zone: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_addresses, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_conditions, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_hostname, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_target_ref, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_deprecated_topology, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_node_name, wire_type, &mut buf)?,
+                7 => crate::protobuf::Field::merge_field(&mut value_zone, wire_type, &mut buf)?,
+                8 => crate::protobuf::Field::merge_field(&mut value_hints, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(Endpoint {
+            addresses: value_addresses.unwrap_or_default(),
+            conditions: value_conditions,
+            deprecated_topology: value_deprecated_topology,
+            hints: value_hints,
+            hostname: value_hostname,
+            node_name: value_node_name,
+            target_ref: value_target_ref,
+            zone: value_zone,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for Endpoint {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for Endpoint {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,66 @@ impl crate::DeepMerge for EndpointHints {
     }
 }
 
+impl crate::Defaulting for EndpointHints {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.for_nodes);
+        crate::Defaulting::set_defaults(&mut self.for_zones);
+    }
+}
+
+impl crate::diff::Diff for EndpointHints {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.for_nodes,
+            &other.for_nodes,
+            &crate::diff::Path::Field { parent: path, name: "forNodes" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::list::atomic(
+            &self.for_zones,
+            &other.for_zones,
+            &crate::diff::Path::Field { parent: path, name: "forZones" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl EndpointHints {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<EndpointHints> {
+    /// The path of the `forNodes` field.
+    pub fn for_nodes(self) -> crate::FieldPath<std::vec::Vec<crate::api::discovery::v1::ForNode>> {
+        self.child("forNodes")
+    }
+
+    /// The path of the `forZones` field.
+    pub fn for_zones(self) -> crate::FieldPath<std::vec::Vec<crate::api::discovery::v1::ForZone>> {
+        self.child("forZones")
+    }
+}
+
+impl crate::Normalize for EndpointHints {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.for_nodes, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::list::atomic(&mut self.for_zones, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for EndpointHints {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +166,40 @@ impl crate::serde::Serialize for EndpointHints {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for EndpointHints {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.for_zones {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.for_nodes {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_for_nodes: Option<std::vec::Vec<crate::api::discovery::v1::ForNode>> = None;
+        let mut value_for_zones: Option<std::vec::Vec<crate::api::discovery::v1::ForZone>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_for_zones, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_for_nodes, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(EndpointHints {
+            for_nodes: value_for_nodes,
+            for_zones: value_for_zones,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for EndpointHints {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for EndpointHints {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -23,6 +23,46 @@ impl crate::DeepMerge for ExemptPriorityLevelConfiguration {
     }
 }
 
+impl crate::Defaulting for ExemptPriorityLevelConfiguration {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.lendable_percent);
+        crate::Defaulting::set_defaults(&mut self.nominal_concurrency_shares);
+    }
+}
+
+impl crate::diff::Diff for ExemptPriorityLevelConfiguration {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.lendable_percent, &other.lendable_percent, &crate::diff::Path::Field { parent: path, name: "lendablePercent" }, changes);
+        crate::diff::Diff::diff(&self.nominal_concurrency_shares, &other.nominal_concurrency_shares, &crate::diff::Path::Field { parent: path, name: "nominalConcurrencyShares" }, changes);
+    }
+}
+
+impl ExemptPriorityLevelConfiguration {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ExemptPriorityLevelConfiguration> {
+    /// The path of the `lendablePercent` field.
+    pub fn lendable_percent(self) -> crate::FieldPath<i32> {
+        self.child("lendablePercent")
+    }
+
+    /// The path of the `nominalConcurrencyShares` field.
+    pub fn nominal_concurrency_shares(self) -> crate::FieldPath<i32> {
+        self.child("nominalConcurrencyShares")
+    }
+}
+
+impl crate::Normalize for ExemptPriorityLevelConfiguration {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.lendable_percent);
+        crate::Normalize::normalize(&mut self.nominal_concurrency_shares);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ExemptPriorityLevelConfiguration {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -112,6 +152,40 @@ impl crate::serde::Serialize for ExemptPriorityLevelConfiguration {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ExemptPriorityLevelConfiguration {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.nominal_concurrency_shares {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.lendable_percent {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_lendable_percent: Option<i32> = None;
+        let mut value_nominal_concurrency_shares: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_nominal_concurrency_shares, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_lendable_percent, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ExemptPriorityLevelConfiguration {
+            lendable_percent: value_lendable_percent,
+            nominal_concurrency_shares: value_nominal_concurrency_shares,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ExemptPriorityLevelConfiguration {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ExemptPriorityLevelConfiguration {
     fn schema_name() -> std::borrow::Cow<'static, str> {
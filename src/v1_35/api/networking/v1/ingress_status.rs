@@ -13,6 +13,38 @@ impl crate::DeepMerge for IngressStatus {
     }
 }
 
+impl crate::Defaulting for IngressStatus {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.load_balancer);
+    }
+}
+
+impl crate::diff::Diff for IngressStatus {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.load_balancer, &other.load_balancer, &crate::diff::Path::Field { parent: path, name: "loadBalancer" }, changes);
+    }
+}
+
+impl IngressStatus {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<IngressStatus> {
+    /// The path of the `loadBalancer` field.
+    pub fn load_balancer(self) -> crate::FieldPath<crate::api::networking::v1::IngressLoadBalancerStatus> {
+        self.child("loadBalancer")
+    }
+}
+
+impl crate::Normalize for IngressStatus {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.load_balancer);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for IngressStatus {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for IngressStatus {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for IngressStatus {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.load_balancer {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_load_balancer: Option<crate::api::networking::v1::IngressLoadBalancerStatus> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_load_balancer, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(IngressStatus {
+            load_balancer: value_load_balancer,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for IngressStatus {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for IngressStatus {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,48 @@ impl crate::DeepMerge for ServiceCIDRSpec {
     }
 }
 
+impl crate::Defaulting for ServiceCIDRSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.cidrs);
+    }
+}
+
+impl crate::diff::Diff for ServiceCIDRSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.cidrs,
+            &other.cidrs,
+            &crate::diff::Path::Field { parent: path, name: "cidrs" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl ServiceCIDRSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServiceCIDRSpec> {
+    /// The path of the `cidrs` field.
+    pub fn cidrs(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("cidrs")
+    }
+}
+
+impl crate::Normalize for ServiceCIDRSpec {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.cidrs, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServiceCIDRSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +134,34 @@ impl crate::serde::Serialize for ServiceCIDRSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServiceCIDRSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.cidrs {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_cidrs: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_cidrs, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServiceCIDRSpec {
+            cidrs: value_cidrs,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServiceCIDRSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServiceCIDRSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -33,6 +33,62 @@ impl crate::DeepMerge for PodDisruptionBudgetSpec {
     }
 }
 
+impl crate::Defaulting for PodDisruptionBudgetSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.max_unavailable);
+        crate::Defaulting::set_defaults(&mut self.min_available);
+        crate::Defaulting::set_defaults(&mut self.selector);
+        crate::Defaulting::set_defaults(&mut self.unhealthy_pod_eviction_policy);
+    }
+}
+
+impl crate::diff::Diff for PodDisruptionBudgetSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max_unavailable, &other.max_unavailable, &crate::diff::Path::Field { parent: path, name: "maxUnavailable" }, changes);
+        crate::diff::Diff::diff(&self.min_available, &other.min_available, &crate::diff::Path::Field { parent: path, name: "minAvailable" }, changes);
+        crate::diff::Diff::diff(&self.selector, &other.selector, &crate::diff::Path::Field { parent: path, name: "selector" }, changes);
+        crate::diff::Diff::diff(&self.unhealthy_pod_eviction_policy, &other.unhealthy_pod_eviction_policy, &crate::diff::Path::Field { parent: path, name: "unhealthyPodEvictionPolicy" }, changes);
+    }
+}
+
+impl PodDisruptionBudgetSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodDisruptionBudgetSpec> {
+    /// The path of the `maxUnavailable` field.
+    pub fn max_unavailable(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("maxUnavailable")
+    }
+
+    /// The path of the `minAvailable` field.
+    pub fn min_available(self) -> crate::FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString> {
+        self.child("minAvailable")
+    }
+
+    /// The path of the `selector` field.
+    pub fn selector(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> {
+        self.child("selector")
+    }
+
+    /// The path of the `unhealthyPodEvictionPolicy` field.
+    pub fn unhealthy_pod_eviction_policy(self) -> crate::FieldPath<std::string::String> {
+        self.child("unhealthyPodEvictionPolicy")
+    }
+}
+
+impl crate::Normalize for PodDisruptionBudgetSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max_unavailable);
+        crate::Normalize::normalize(&mut self.min_available);
+        crate::Normalize::normalize(&mut self.selector);
+        crate::Normalize::normalize(&mut self.unhealthy_pod_eviction_policy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodDisruptionBudgetSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -142,6 +198,52 @@ impl crate::serde::Serialize for PodDisruptionBudgetSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodDisruptionBudgetSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.min_available {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.selector {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.max_unavailable {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        if let Some(value) = &self.unhealthy_pod_eviction_policy {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max_unavailable: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_min_available: Option<crate::apimachinery::pkg::util::intstr::IntOrString> = None;
+        let mut value_selector: Option<crate::apimachinery::pkg::apis::meta::v1::LabelSelector> = None;
+        let mut value_unhealthy_pod_eviction_policy: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_min_available, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_selector, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_max_unavailable, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_unhealthy_pod_eviction_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodDisruptionBudgetSpec {
+            max_unavailable: value_max_unavailable,
+            min_available: value_min_available,
+            selector: value_selector,
+            unhealthy_pod_eviction_policy: value_unhealthy_pod_eviction_policy,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodDisruptionBudgetSpec {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodDisruptionBudgetSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -27,6 +27,62 @@ impl crate::DeepMerge for DeviceTaint {
     }
 }
 
+impl crate::Defaulting for DeviceTaint {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.effect);
+        crate::Defaulting::set_defaults(&mut self.key);
+        crate::Defaulting::set_defaults(&mut self.time_added);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for DeviceTaint {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.effect, &other.effect, &crate::diff::Path::Field { parent: path, name: "effect" }, changes);
+        crate::diff::Diff::diff(&self.key, &other.key, &crate::diff::Path::Field { parent: path, name: "key" }, changes);
+        crate::diff::Diff::diff(&self.time_added, &other.time_added, &crate::diff::Path::Field { parent: path, name: "timeAdded" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl DeviceTaint {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceTaint> {
+    /// The path of the `effect` field.
+    pub fn effect(self) -> crate::FieldPath<std::string::String> {
+        self.child("effect")
+    }
+
+    /// The path of the `key` field.
+    pub fn key(self) -> crate::FieldPath<std::string::String> {
+        self.child("key")
+    }
+
+    /// The path of the `timeAdded` field.
+    pub fn time_added(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::Time> {
+        self.child("timeAdded")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<std::string::String> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for DeviceTaint {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.effect);
+        crate::Normalize::normalize(&mut self.key);
+        crate::Normalize::normalize(&mut self.time_added);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceTaint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -131,6 +187,48 @@ impl crate::serde::Serialize for DeviceTaint {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceTaint {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.key, 1, buf);
+        if let Some(value) = &self.value {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.effect, 3, buf);
+        if let Some(value) = &self.time_added {
+            crate::protobuf::Field::encode_field(value, 4, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_effect: Option<std::string::String> = None;
+        let mut value_key: Option<std::string::String> = None;
+        let mut value_time_added: Option<crate::apimachinery::pkg::apis::meta::v1::Time> = None;
+        let mut value_value: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_key, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_effect, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_time_added, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceTaint {
+            effect: value_effect.unwrap_or_default(),
+            key: value_key.unwrap_or_default(),
+            time_added: value_time_added,
+            value: value_value,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceTaint {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceTaint {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -34,6 +34,54 @@ impl crate::DeepMerge for CapacityRequestPolicyRange {
     }
 }
 
+impl crate::Defaulting for CapacityRequestPolicyRange {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.max);
+        crate::Defaulting::set_defaults(&mut self.min);
+        crate::Defaulting::set_defaults(&mut self.step);
+    }
+}
+
+impl crate::diff::Diff for CapacityRequestPolicyRange {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.max, &other.max, &crate::diff::Path::Field { parent: path, name: "max" }, changes);
+        crate::diff::Diff::diff(&self.min, &other.min, &crate::diff::Path::Field { parent: path, name: "min" }, changes);
+        crate::diff::Diff::diff(&self.step, &other.step, &crate::diff::Path::Field { parent: path, name: "step" }, changes);
+    }
+}
+
+impl CapacityRequestPolicyRange {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CapacityRequestPolicyRange> {
+    /// The path of the `max` field.
+    pub fn max(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("max")
+    }
+
+    /// The path of the `min` field.
+    pub fn min(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("min")
+    }
+
+    /// The path of the `step` field.
+    pub fn step(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("step")
+    }
+}
+
+impl crate::Normalize for CapacityRequestPolicyRange {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.max);
+        crate::Normalize::normalize(&mut self.min);
+        crate::Normalize::normalize(&mut self.step);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CapacityRequestPolicyRange {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -131,6 +179,44 @@ impl crate::serde::Serialize for CapacityRequestPolicyRange {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CapacityRequestPolicyRange {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.min, 1, buf);
+        if let Some(value) = &self.max {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.step {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_max: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_min: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_step: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_min, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_max, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_step, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CapacityRequestPolicyRange {
+            max: value_max,
+            min: value_min.unwrap_or_default(),
+            step: value_step,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CapacityRequestPolicyRange {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CapacityRequestPolicyRange {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -33,6 +33,64 @@ impl crate::DeepMerge for DeviceConstraint {
     }
 }
 
+impl crate::Defaulting for DeviceConstraint {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.distinct_attribute);
+        crate::Defaulting::set_defaults(&mut self.match_attribute);
+        crate::Defaulting::set_defaults(&mut self.requests);
+    }
+}
+
+impl crate::diff::Diff for DeviceConstraint {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.distinct_attribute, &other.distinct_attribute, &crate::diff::Path::Field { parent: path, name: "distinctAttribute" }, changes);
+        crate::diff::Diff::diff(&self.match_attribute, &other.match_attribute, &crate::diff::Path::Field { parent: path, name: "matchAttribute" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.requests,
+            &other.requests,
+            &crate::diff::Path::Field { parent: path, name: "requests" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl DeviceConstraint {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceConstraint> {
+    /// The path of the `distinctAttribute` field.
+    pub fn distinct_attribute(self) -> crate::FieldPath<std::string::String> {
+        self.child("distinctAttribute")
+    }
+
+    /// The path of the `matchAttribute` field.
+    pub fn match_attribute(self) -> crate::FieldPath<std::string::String> {
+        self.child("matchAttribute")
+    }
+
+    /// The path of the `requests` field.
+    pub fn requests(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("requests")
+    }
+}
+
+impl crate::Normalize for DeviceConstraint {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.distinct_attribute);
+        crate::Normalize::normalize(&mut self.match_attribute);
+        crate::normalize_strategies::list::atomic(&mut self.requests, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceConstraint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -132,6 +190,46 @@ impl crate::serde::Serialize for DeviceConstraint {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceConstraint {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.requests {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.match_attribute {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.distinct_attribute {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_distinct_attribute: Option<std::string::String> = None;
+        let mut value_match_attribute: Option<std::string::String> = None;
+        let mut value_requests: Option<std::vec::Vec<std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_requests, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_match_attribute, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_distinct_attribute, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceConstraint {
+            distinct_attribute: value_distinct_attribute,
+            match_attribute: value_match_attribute,
+            requests: value_requests,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceConstraint {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceConstraint {
     fn schema_name() -> std::borrow::Cow<'static, str> {
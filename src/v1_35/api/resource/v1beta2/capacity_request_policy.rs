@@ -37,6 +37,64 @@ impl crate::DeepMerge for CapacityRequestPolicy {
     }
 }
 
+impl crate::Defaulting for CapacityRequestPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.default);
+        crate::Defaulting::set_defaults(&mut self.valid_range);
+        crate::Defaulting::set_defaults(&mut self.valid_values);
+    }
+}
+
+impl crate::diff::Diff for CapacityRequestPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.default, &other.default, &crate::diff::Path::Field { parent: path, name: "default" }, changes);
+        crate::diff::Diff::diff(&self.valid_range, &other.valid_range, &crate::diff::Path::Field { parent: path, name: "validRange" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.valid_values,
+            &other.valid_values,
+            &crate::diff::Path::Field { parent: path, name: "validValues" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl CapacityRequestPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CapacityRequestPolicy> {
+    /// The path of the `default` field.
+    pub fn default(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("default")
+    }
+
+    /// The path of the `validRange` field.
+    pub fn valid_range(self) -> crate::FieldPath<crate::api::resource::v1beta2::CapacityRequestPolicyRange> {
+        self.child("validRange")
+    }
+
+    /// The path of the `validValues` field.
+    pub fn valid_values(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::api::resource::Quantity>> {
+        self.child("validValues")
+    }
+}
+
+impl crate::Normalize for CapacityRequestPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.default);
+        crate::Normalize::normalize(&mut self.valid_range);
+        crate::normalize_strategies::list::atomic(&mut self.valid_values, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CapacityRequestPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -136,6 +194,46 @@ impl crate::serde::Serialize for CapacityRequestPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CapacityRequestPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.default {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.valid_values {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.valid_range {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_default: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+        let mut value_valid_range: Option<crate::api::resource::v1beta2::CapacityRequestPolicyRange> = None;
+        let mut value_valid_values: Option<std::vec::Vec<crate::apimachinery::pkg::api::resource::Quantity>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_default, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_valid_values, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_valid_range, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CapacityRequestPolicy {
+            default: value_default,
+            valid_range: value_valid_range,
+            valid_values: value_valid_values,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CapacityRequestPolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CapacityRequestPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
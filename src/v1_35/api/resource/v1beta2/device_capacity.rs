@@ -23,6 +23,46 @@ impl crate::DeepMerge for DeviceCapacity {
     }
 }
 
+impl crate::Defaulting for DeviceCapacity {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.request_policy);
+        crate::Defaulting::set_defaults(&mut self.value);
+    }
+}
+
+impl crate::diff::Diff for DeviceCapacity {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.request_policy, &other.request_policy, &crate::diff::Path::Field { parent: path, name: "requestPolicy" }, changes);
+        crate::diff::Diff::diff(&self.value, &other.value, &crate::diff::Path::Field { parent: path, name: "value" }, changes);
+    }
+}
+
+impl DeviceCapacity {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceCapacity> {
+    /// The path of the `requestPolicy` field.
+    pub fn request_policy(self) -> crate::FieldPath<crate::api::resource::v1beta2::CapacityRequestPolicy> {
+        self.child("requestPolicy")
+    }
+
+    /// The path of the `value` field.
+    pub fn value(self) -> crate::FieldPath<crate::apimachinery::pkg::api::resource::Quantity> {
+        self.child("value")
+    }
+}
+
+impl crate::Normalize for DeviceCapacity {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.request_policy);
+        crate::Normalize::normalize(&mut self.value);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceCapacity {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -110,6 +150,38 @@ impl crate::serde::Serialize for DeviceCapacity {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceCapacity {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.value, 1, buf);
+        if let Some(value) = &self.request_policy {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_request_policy: Option<crate::api::resource::v1beta2::CapacityRequestPolicy> = None;
+        let mut value_value: Option<crate::apimachinery::pkg::api::resource::Quantity> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_value, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_request_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceCapacity {
+            request_policy: value_request_policy,
+            value: value_value.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceCapacity {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceCapacity {
     fn schema_name() -> std::borrow::Cow<'static, str> {
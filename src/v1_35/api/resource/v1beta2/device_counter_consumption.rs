@@ -21,6 +21,56 @@ impl crate::DeepMerge for DeviceCounterConsumption {
     }
 }
 
+impl crate::Defaulting for DeviceCounterConsumption {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.counter_set);
+        crate::Defaulting::set_defaults(&mut self.counters);
+    }
+}
+
+impl crate::diff::Diff for DeviceCounterConsumption {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.counter_set, &other.counter_set, &crate::diff::Path::Field { parent: path, name: "counterSet" }, changes);
+        crate::diff::strategies::map::values(
+            &self.counters,
+            &other.counters,
+            &crate::diff::Path::Field { parent: path, name: "counters" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl DeviceCounterConsumption {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceCounterConsumption> {
+    /// The path of the `counterSet` field.
+    pub fn counter_set(self) -> crate::FieldPath<std::string::String> {
+        self.child("counterSet")
+    }
+
+    /// The path of the `counters` field.
+    pub fn counters(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta2::Counter>> {
+        self.child("counters")
+    }
+}
+
+impl crate::Normalize for DeviceCounterConsumption {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.counter_set);
+        crate::normalize_strategies::map::values(&mut self.counters, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceCounterConsumption {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -105,6 +155,36 @@ impl crate::serde::Serialize for DeviceCounterConsumption {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceCounterConsumption {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.counter_set, 1, buf);
+        crate::protobuf::Field::encode_field(&self.counters, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_counter_set: Option<std::string::String> = None;
+        let mut value_counters: Option<std::collections::BTreeMap<std::string::String, crate::api::resource::v1beta2::Counter>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_counter_set, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_counters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceCounterConsumption {
+            counter_set: value_counter_set.unwrap_or_default(),
+            counters: value_counters.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceCounterConsumption {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceCounterConsumption {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for DeviceSelector {
     }
 }
 
+impl crate::Defaulting for DeviceSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.cel);
+    }
+}
+
+impl crate::diff::Diff for DeviceSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.cel, &other.cel, &crate::diff::Path::Field { parent: path, name: "cel" }, changes);
+    }
+}
+
+impl DeviceSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<DeviceSelector> {
+    /// The path of the `cel` field.
+    pub fn cel(self) -> crate::FieldPath<crate::api::resource::v1beta2::CELDeviceSelector> {
+        self.child("cel")
+    }
+}
+
+impl crate::Normalize for DeviceSelector {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.cel);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for DeviceSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -92,6 +124,34 @@ impl crate::serde::Serialize for DeviceSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for DeviceSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.cel {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_cel: Option<crate::api::resource::v1beta2::CELDeviceSelector> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_cel, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(DeviceSelector {
+            cel: value_cel,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for DeviceSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for DeviceSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -10,6 +10,24 @@ impl crate::DeepMerge for BasicSchedulingPolicy {
     }
 }
 
+impl crate::Defaulting for BasicSchedulingPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.0);
+    }
+}
+
+impl crate::Normalize for BasicSchedulingPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for BasicSchedulingPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for BasicSchedulingPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -42,6 +60,21 @@ impl crate::serde::Serialize for BasicSchedulingPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for BasicSchedulingPolicy {
+    fn encode(&self, _buf: &mut std::vec::Vec<u8>) {
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        while !buf.is_empty() {
+            let (_, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            crate::protobuf::skip_field(wire_type, &mut buf)?;
+        }
+
+        Ok(BasicSchedulingPolicy(crate::serde_json::Value::Object(Default::default())))
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for BasicSchedulingPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -13,6 +13,38 @@ impl crate::DeepMerge for GangSchedulingPolicy {
     }
 }
 
+impl crate::Defaulting for GangSchedulingPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.min_count);
+    }
+}
+
+impl crate::diff::Diff for GangSchedulingPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.min_count, &other.min_count, &crate::diff::Path::Field { parent: path, name: "minCount" }, changes);
+    }
+}
+
+impl GangSchedulingPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GangSchedulingPolicy> {
+    /// The path of the `minCount` field.
+    pub fn min_count(self) -> crate::FieldPath<i32> {
+        self.child("minCount")
+    }
+}
+
+impl crate::Normalize for GangSchedulingPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.min_count);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GangSchedulingPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -90,6 +122,32 @@ impl crate::serde::Serialize for GangSchedulingPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GangSchedulingPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.min_count, 1, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_min_count: Option<i32> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_min_count, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GangSchedulingPolicy {
+            min_count: value_min_count.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GangSchedulingPolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GangSchedulingPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
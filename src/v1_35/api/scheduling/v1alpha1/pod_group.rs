@@ -17,6 +17,46 @@ impl crate::DeepMerge for PodGroup {
     }
 }
 
+impl crate::Defaulting for PodGroup {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.name);
+        crate::Defaulting::set_defaults(&mut self.policy);
+    }
+}
+
+impl crate::diff::Diff for PodGroup {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.name, &other.name, &crate::diff::Path::Field { parent: path, name: "name" }, changes);
+        crate::diff::Diff::diff(&self.policy, &other.policy, &crate::diff::Path::Field { parent: path, name: "policy" }, changes);
+    }
+}
+
+impl PodGroup {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodGroup> {
+    /// The path of the `name` field.
+    pub fn name(self) -> crate::FieldPath<std::string::String> {
+        self.child("name")
+    }
+
+    /// The path of the `policy` field.
+    pub fn policy(self) -> crate::FieldPath<crate::api::scheduling::v1alpha1::PodGroupPolicy> {
+        self.child("policy")
+    }
+}
+
+impl crate::Normalize for PodGroup {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.name);
+        crate::Normalize::normalize(&mut self.policy);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodGroup {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for PodGroup {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodGroup {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.name, 1, buf);
+        crate::protobuf::Field::encode_field(&self.policy, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_name: Option<std::string::String> = None;
+        let mut value_policy: Option<crate::api::scheduling::v1alpha1::PodGroupPolicy> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_name, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_policy, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodGroup {
+            name: value_name.unwrap_or_default(),
+            policy: value_policy.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodGroup {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodGroup {
     fn schema_name() -> std::borrow::Cow<'static, str> {
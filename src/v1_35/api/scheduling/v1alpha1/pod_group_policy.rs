@@ -17,6 +17,46 @@ impl crate::DeepMerge for PodGroupPolicy {
     }
 }
 
+impl crate::Defaulting for PodGroupPolicy {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.basic);
+        crate::Defaulting::set_defaults(&mut self.gang);
+    }
+}
+
+impl crate::diff::Diff for PodGroupPolicy {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.basic, &other.basic, &crate::diff::Path::Field { parent: path, name: "basic" }, changes);
+        crate::diff::Diff::diff(&self.gang, &other.gang, &crate::diff::Path::Field { parent: path, name: "gang" }, changes);
+    }
+}
+
+impl PodGroupPolicy {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<PodGroupPolicy> {
+    /// The path of the `basic` field.
+    pub fn basic(self) -> crate::FieldPath<crate::api::scheduling::v1alpha1::BasicSchedulingPolicy> {
+        self.child("basic")
+    }
+
+    /// The path of the `gang` field.
+    pub fn gang(self) -> crate::FieldPath<crate::api::scheduling::v1alpha1::GangSchedulingPolicy> {
+        self.child("gang")
+    }
+}
+
+impl crate::Normalize for PodGroupPolicy {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.basic);
+        crate::Normalize::normalize(&mut self.gang);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for PodGroupPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -106,6 +146,40 @@ impl crate::serde::Serialize for PodGroupPolicy {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for PodGroupPolicy {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.basic {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.gang {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_basic: Option<crate::api::scheduling::v1alpha1::BasicSchedulingPolicy> = None;
+        let mut value_gang: Option<crate::api::scheduling::v1alpha1::GangSchedulingPolicy> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_basic, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_gang, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(PodGroupPolicy {
+            basic: value_basic,
+            gang: value_gang,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for PodGroupPolicy {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for PodGroupPolicy {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -24,6 +24,57 @@ impl crate::DeepMerge for WorkloadSpec {
     }
 }
 
+impl crate::Defaulting for WorkloadSpec {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.controller_ref);
+        crate::Defaulting::set_defaults(&mut self.pod_groups);
+    }
+}
+
+impl crate::diff::Diff for WorkloadSpec {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.controller_ref, &other.controller_ref, &crate::diff::Path::Field { parent: path, name: "controllerRef" }, changes);
+        crate::diff::strategies::list::map(
+            &self.pod_groups,
+            &other.pod_groups,
+            &crate::diff::Path::Field { parent: path, name: "podGroups" },
+            changes,
+            &[("name", |item| crate::diff::MergeKey::merge_key(&item.name))],
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+    }
+}
+
+impl WorkloadSpec {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<WorkloadSpec> {
+    /// The path of the `controllerRef` field.
+    pub fn controller_ref(self) -> crate::FieldPath<crate::api::scheduling::v1alpha1::TypedLocalObjectReference> {
+        self.child("controllerRef")
+    }
+
+    /// The path of the `podGroups` field.
+    pub fn pod_groups(self) -> crate::FieldPath<std::vec::Vec<crate::api::scheduling::v1alpha1::PodGroup>> {
+        self.child("podGroups")
+    }
+}
+
+impl crate::Normalize for WorkloadSpec {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.controller_ref);
+        crate::normalize_strategies::list::map(&mut self.pod_groups, |item| {
+            crate::Normalize::normalize(item);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for WorkloadSpec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -111,6 +162,48 @@ impl crate::serde::Serialize for WorkloadSpec {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for WorkloadSpec {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.controller_ref {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.pod_groups, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_controller_ref: Option<crate::api::scheduling::v1alpha1::TypedLocalObjectReference> = None;
+        let mut value_pod_groups: Option<std::vec::Vec<crate::api::scheduling::v1alpha1::PodGroup>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_controller_ref, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_pod_groups, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(WorkloadSpec {
+            controller_ref: value_controller_ref,
+            pod_groups: value_pod_groups.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for WorkloadSpec {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key)] => match &**key {
+                "podGroups" => Some(&["name"]),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for WorkloadSpec {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -50,6 +50,68 @@ impl crate::DeepMerge for VolumeAttributesClass {
     }
 }
 
+impl crate::Defaulting for VolumeAttributesClass {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.driver_name);
+        crate::Defaulting::set_defaults(&mut self.metadata);
+        crate::Defaulting::set_defaults(&mut self.parameters);
+    }
+}
+
+impl crate::diff::Diff for VolumeAttributesClass {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.driver_name, &other.driver_name, &crate::diff::Path::Field { parent: path, name: "driverName" }, changes);
+        crate::diff::Diff::diff(&self.metadata, &other.metadata, &crate::diff::Path::Field { parent: path, name: "metadata" }, changes);
+        crate::diff::strategies::map::values(
+            &self.parameters,
+            &other.parameters,
+            &crate::diff::Path::Field { parent: path, name: "parameters" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl VolumeAttributesClass {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<VolumeAttributesClass> {
+    /// The path of the `driverName` field.
+    pub fn driver_name(self) -> crate::FieldPath<std::string::String> {
+        self.child("driverName")
+    }
+
+    /// The path of the `metadata` field.
+    pub fn metadata(self) -> crate::FieldPath<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> {
+        self.child("metadata")
+    }
+
+    /// The path of the `parameters` field.
+    pub fn parameters(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("parameters")
+    }
+}
+
+impl crate::Normalize for VolumeAttributesClass {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.driver_name);
+        crate::Normalize::normalize(&mut self.metadata);
+        crate::normalize_strategies::map::values(&mut self.parameters, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+
+    fn clear_server_populated_fields(&mut self) {
+        crate::Normalize::clear_server_populated_fields(&mut self.metadata);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for VolumeAttributesClass {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -164,6 +226,52 @@ impl crate::serde::Serialize for VolumeAttributesClass {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for VolumeAttributesClass {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.metadata, 1, buf);
+        crate::protobuf::Field::encode_field(&self.driver_name, 2, buf);
+        if let Some(value) = &self.parameters {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_driver_name: Option<std::string::String> = None;
+        let mut value_metadata: Option<crate::apimachinery::pkg::apis::meta::v1::ObjectMeta> = None;
+        let mut value_parameters: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_metadata, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_driver_name, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_parameters, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(VolumeAttributesClass {
+            driver_name: value_driver_name.unwrap_or_default(),
+            metadata: value_metadata.unwrap_or_default(),
+            parameters: value_parameters,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for VolumeAttributesClass {
+    fn list_map_keys(path: &[crate::unknown_fields::PathSegment]) -> Option<&'static [&'static str]> {
+        match path {
+            [crate::unknown_fields::PathSegment::Key(key), path @ ..] => match &**key {
+                "metadata" => <crate::apimachinery::pkg::apis::meta::v1::ObjectMeta as crate::unknown_fields::ListMapKeys>::list_map_keys(path),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for VolumeAttributesClass {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -0,0 +1,1109 @@
+// Generated from all definitions and operations
+
+impl crate::DiscoverableResource for crate::api::core::v1::Binding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Binding",
+        plural: "bindings",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::ComponentStatus {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "ComponentStatus",
+        plural: "componentstatuses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::ConfigMap {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "ConfigMap",
+        plural: "configmaps",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Endpoints {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Endpoints",
+        plural: "endpoints",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Event {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Event",
+        plural: "events",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::LimitRange {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "LimitRange",
+        plural: "limitranges",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Namespace {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Namespace",
+        plural: "namespaces",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete],
+        subresources: &["finalize", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Node {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Node",
+        plural: "nodes",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["proxy", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::PersistentVolume {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "PersistentVolume",
+        plural: "persistentvolumes",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::PersistentVolumeClaim {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "PersistentVolumeClaim",
+        plural: "persistentvolumeclaims",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Pod {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Pod",
+        plural: "pods",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["attach", "binding", "ephemeralcontainers", "eviction", "exec", "log", "portforward", "proxy", "resize", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::PodTemplate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "PodTemplate",
+        plural: "podtemplates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::ReplicationController {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "ReplicationController",
+        plural: "replicationcontrollers",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["scale", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::ResourceQuota {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "ResourceQuota",
+        plural: "resourcequotas",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Secret {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Secret",
+        plural: "secrets",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::Service {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "Service",
+        plural: "services",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["proxy", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::core::v1::ServiceAccount {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "",
+        version: "v1",
+        kind: "ServiceAccount",
+        plural: "serviceaccounts",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["token"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1::MutatingWebhookConfiguration {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1",
+        kind: "MutatingWebhookConfiguration",
+        plural: "mutatingwebhookconfigurations",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1::ValidatingAdmissionPolicy {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1",
+        kind: "ValidatingAdmissionPolicy",
+        plural: "validatingadmissionpolicies",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1::ValidatingAdmissionPolicyBinding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1",
+        kind: "ValidatingAdmissionPolicyBinding",
+        plural: "validatingadmissionpolicybindings",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1::ValidatingWebhookConfiguration {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1",
+        kind: "ValidatingWebhookConfiguration",
+        plural: "validatingwebhookconfigurations",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicy {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1alpha1",
+        kind: "MutatingAdmissionPolicy",
+        plural: "mutatingadmissionpolicies",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1alpha1::MutatingAdmissionPolicyBinding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1alpha1",
+        kind: "MutatingAdmissionPolicyBinding",
+        plural: "mutatingadmissionpolicybindings",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1beta1::MutatingAdmissionPolicy {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1beta1",
+        kind: "MutatingAdmissionPolicy",
+        plural: "mutatingadmissionpolicies",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::admissionregistration::v1beta1::MutatingAdmissionPolicyBinding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "admissionregistration.k8s.io",
+        version: "v1beta1",
+        kind: "MutatingAdmissionPolicyBinding",
+        plural: "mutatingadmissionpolicybindings",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apiextensions.k8s.io",
+        version: "v1",
+        kind: "CustomResourceDefinition",
+        plural: "customresourcedefinitions",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::kube_aggregator::pkg::apis::apiregistration::v1::APIService {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apiregistration.k8s.io",
+        version: "v1",
+        kind: "APIService",
+        plural: "apiservices",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apps::v1::ControllerRevision {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apps",
+        version: "v1",
+        kind: "ControllerRevision",
+        plural: "controllerrevisions",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apps::v1::DaemonSet {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apps",
+        version: "v1",
+        kind: "DaemonSet",
+        plural: "daemonsets",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apps::v1::Deployment {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apps",
+        version: "v1",
+        kind: "Deployment",
+        plural: "deployments",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["scale", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apps::v1::ReplicaSet {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apps",
+        version: "v1",
+        kind: "ReplicaSet",
+        plural: "replicasets",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["scale", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apps::v1::StatefulSet {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "apps",
+        version: "v1",
+        kind: "StatefulSet",
+        plural: "statefulsets",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["scale", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authentication::v1::SelfSubjectReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authentication.k8s.io",
+        version: "v1",
+        kind: "SelfSubjectReview",
+        plural: "selfsubjectreviews",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authentication::v1::TokenReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authentication.k8s.io",
+        version: "v1",
+        kind: "TokenReview",
+        plural: "tokenreviews",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authorization::v1::LocalSubjectAccessReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authorization.k8s.io",
+        version: "v1",
+        kind: "LocalSubjectAccessReview",
+        plural: "localsubjectaccessreviews",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authorization::v1::SelfSubjectAccessReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authorization.k8s.io",
+        version: "v1",
+        kind: "SelfSubjectAccessReview",
+        plural: "selfsubjectaccessreviews",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authorization::v1::SelfSubjectRulesReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authorization.k8s.io",
+        version: "v1",
+        kind: "SelfSubjectRulesReview",
+        plural: "selfsubjectrulesreviews",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::authorization::v1::SubjectAccessReview {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "authorization.k8s.io",
+        version: "v1",
+        kind: "SubjectAccessReview",
+        plural: "subjectaccessreviews",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Create],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::autoscaling::v1::HorizontalPodAutoscaler {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "autoscaling",
+        version: "v1",
+        kind: "HorizontalPodAutoscaler",
+        plural: "horizontalpodautoscalers",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::autoscaling::v2::HorizontalPodAutoscaler {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "autoscaling",
+        version: "v2",
+        kind: "HorizontalPodAutoscaler",
+        plural: "horizontalpodautoscalers",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::batch::v1::CronJob {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "batch",
+        version: "v1",
+        kind: "CronJob",
+        plural: "cronjobs",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::batch::v1::Job {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "batch",
+        version: "v1",
+        kind: "Job",
+        plural: "jobs",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::certificates::v1::CertificateSigningRequest {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "certificates.k8s.io",
+        version: "v1",
+        kind: "CertificateSigningRequest",
+        plural: "certificatesigningrequests",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["approval", "status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::certificates::v1alpha1::ClusterTrustBundle {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "certificates.k8s.io",
+        version: "v1alpha1",
+        kind: "ClusterTrustBundle",
+        plural: "clustertrustbundles",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::certificates::v1beta1::ClusterTrustBundle {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "certificates.k8s.io",
+        version: "v1beta1",
+        kind: "ClusterTrustBundle",
+        plural: "clustertrustbundles",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::certificates::v1beta1::PodCertificateRequest {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "certificates.k8s.io",
+        version: "v1beta1",
+        kind: "PodCertificateRequest",
+        plural: "podcertificaterequests",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::coordination::v1::Lease {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "coordination.k8s.io",
+        version: "v1",
+        kind: "Lease",
+        plural: "leases",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::coordination::v1alpha2::LeaseCandidate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "coordination.k8s.io",
+        version: "v1alpha2",
+        kind: "LeaseCandidate",
+        plural: "leasecandidates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::coordination::v1beta1::LeaseCandidate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "coordination.k8s.io",
+        version: "v1beta1",
+        kind: "LeaseCandidate",
+        plural: "leasecandidates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::discovery::v1::EndpointSlice {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "discovery.k8s.io",
+        version: "v1",
+        kind: "EndpointSlice",
+        plural: "endpointslices",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::events::v1::Event {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "events.k8s.io",
+        version: "v1",
+        kind: "Event",
+        plural: "events",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::flowcontrol::v1::FlowSchema {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "flowcontrol.apiserver.k8s.io",
+        version: "v1",
+        kind: "FlowSchema",
+        plural: "flowschemas",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::flowcontrol::v1::PriorityLevelConfiguration {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "flowcontrol.apiserver.k8s.io",
+        version: "v1",
+        kind: "PriorityLevelConfiguration",
+        plural: "prioritylevelconfigurations",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::apiserverinternal::v1alpha1::StorageVersion {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "internal.apiserver.k8s.io",
+        version: "v1alpha1",
+        kind: "StorageVersion",
+        plural: "storageversions",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1::IPAddress {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1",
+        kind: "IPAddress",
+        plural: "ipaddresses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1::Ingress {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1",
+        kind: "Ingress",
+        plural: "ingresses",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1::IngressClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1",
+        kind: "IngressClass",
+        plural: "ingressclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1::NetworkPolicy {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1",
+        kind: "NetworkPolicy",
+        plural: "networkpolicies",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1::ServiceCIDR {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1",
+        kind: "ServiceCIDR",
+        plural: "servicecidrs",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1beta1::IPAddress {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1beta1",
+        kind: "IPAddress",
+        plural: "ipaddresses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::networking::v1beta1::ServiceCIDR {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "networking.k8s.io",
+        version: "v1beta1",
+        kind: "ServiceCIDR",
+        plural: "servicecidrs",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::node::v1::RuntimeClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "node.k8s.io",
+        version: "v1",
+        kind: "RuntimeClass",
+        plural: "runtimeclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::policy::v1::PodDisruptionBudget {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "policy",
+        version: "v1",
+        kind: "PodDisruptionBudget",
+        plural: "poddisruptionbudgets",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::rbac::v1::ClusterRole {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "rbac.authorization.k8s.io",
+        version: "v1",
+        kind: "ClusterRole",
+        plural: "clusterroles",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::rbac::v1::ClusterRoleBinding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "rbac.authorization.k8s.io",
+        version: "v1",
+        kind: "ClusterRoleBinding",
+        plural: "clusterrolebindings",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::rbac::v1::Role {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "rbac.authorization.k8s.io",
+        version: "v1",
+        kind: "Role",
+        plural: "roles",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::rbac::v1::RoleBinding {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "rbac.authorization.k8s.io",
+        version: "v1",
+        kind: "RoleBinding",
+        plural: "rolebindings",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1::DeviceClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1",
+        kind: "DeviceClass",
+        plural: "deviceclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1::ResourceClaim {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1",
+        kind: "ResourceClaim",
+        plural: "resourceclaims",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1::ResourceClaimTemplate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1",
+        kind: "ResourceClaimTemplate",
+        plural: "resourceclaimtemplates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1::ResourceSlice {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1",
+        kind: "ResourceSlice",
+        plural: "resourceslices",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1alpha3::DeviceTaintRule {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1alpha3",
+        kind: "DeviceTaintRule",
+        plural: "devicetaintrules",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta1::DeviceClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta1",
+        kind: "DeviceClass",
+        plural: "deviceclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta1::ResourceClaim {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta1",
+        kind: "ResourceClaim",
+        plural: "resourceclaims",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta1::ResourceClaimTemplate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta1",
+        kind: "ResourceClaimTemplate",
+        plural: "resourceclaimtemplates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta1::ResourceSlice {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta1",
+        kind: "ResourceSlice",
+        plural: "resourceslices",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta2::DeviceClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta2",
+        kind: "DeviceClass",
+        plural: "deviceclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta2::ResourceClaim {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta2",
+        kind: "ResourceClaim",
+        plural: "resourceclaims",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta2::ResourceClaimTemplate {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta2",
+        kind: "ResourceClaimTemplate",
+        plural: "resourceclaimtemplates",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::resource::v1beta2::ResourceSlice {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "resource.k8s.io",
+        version: "v1beta2",
+        kind: "ResourceSlice",
+        plural: "resourceslices",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::scheduling::v1::PriorityClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "scheduling.k8s.io",
+        version: "v1",
+        kind: "PriorityClass",
+        plural: "priorityclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::scheduling::v1alpha1::Workload {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "scheduling.k8s.io",
+        version: "v1alpha1",
+        kind: "Workload",
+        plural: "workloads",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::CSIDriver {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "CSIDriver",
+        plural: "csidrivers",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::CSINode {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "CSINode",
+        plural: "csinodes",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::CSIStorageCapacity {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "CSIStorageCapacity",
+        plural: "csistoragecapacities",
+        scope: crate::ApiResourceScope::Namespace,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::StorageClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "StorageClass",
+        plural: "storageclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::VolumeAttachment {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "VolumeAttachment",
+        plural: "volumeattachments",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1::VolumeAttributesClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1",
+        kind: "VolumeAttributesClass",
+        plural: "volumeattributesclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storage::v1beta1::VolumeAttributesClass {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storage.k8s.io",
+        version: "v1beta1",
+        kind: "VolumeAttributesClass",
+        plural: "volumeattributesclasses",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &[],
+    };
+}
+
+impl crate::DiscoverableResource for crate::api::storagemigration::v1beta1::StorageVersionMigration {
+    const API_RESOURCE: crate::ApiResource = crate::ApiResource {
+        group: "storagemigration.k8s.io",
+        version: "v1beta1",
+        kind: "StorageVersionMigration",
+        plural: "storageversionmigrations",
+        scope: crate::ApiResourceScope::Cluster,
+        verbs: &[crate::Verb::Get, crate::Verb::List, crate::Verb::Watch, crate::Verb::Create, crate::Verb::Update, crate::Verb::Patch, crate::Verb::Delete, crate::Verb::DeleteCollection],
+        subresources: &["status"],
+    };
+}
+
+impl crate::SubresourceOf<crate::api::apps::v1::Deployment> for crate::api::autoscaling::v1::Scale {
+    const NAME: &'static str = "scale";
+}
+
+impl crate::SubresourceOf<crate::api::apps::v1::ReplicaSet> for crate::api::autoscaling::v1::Scale {
+    const NAME: &'static str = "scale";
+}
+
+impl crate::SubresourceOf<crate::api::apps::v1::StatefulSet> for crate::api::autoscaling::v1::Scale {
+    const NAME: &'static str = "scale";
+}
+
+impl crate::SubresourceOf<crate::api::core::v1::Pod> for crate::api::core::v1::Binding {
+    const NAME: &'static str = "binding";
+}
+
+impl crate::SubresourceOf<crate::api::core::v1::Pod> for crate::api::policy::v1::Eviction {
+    const NAME: &'static str = "eviction";
+}
+
+impl crate::SubresourceOf<crate::api::core::v1::ReplicationController> for crate::api::autoscaling::v1::Scale {
+    const NAME: &'static str = "scale";
+}
+
+impl crate::SubresourceOf<crate::api::core::v1::ServiceAccount> for crate::api::authentication::v1::TokenRequest {
+    const NAME: &'static str = "token";
+}
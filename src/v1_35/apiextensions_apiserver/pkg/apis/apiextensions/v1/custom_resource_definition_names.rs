@@ -33,6 +33,98 @@ impl crate::DeepMerge for CustomResourceDefinitionNames {
     }
 }
 
+impl crate::Defaulting for CustomResourceDefinitionNames {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.categories);
+        crate::Defaulting::set_defaults(&mut self.kind);
+        crate::Defaulting::set_defaults(&mut self.list_kind);
+        crate::Defaulting::set_defaults(&mut self.plural);
+        crate::Defaulting::set_defaults(&mut self.short_names);
+        crate::Defaulting::set_defaults(&mut self.singular);
+    }
+}
+
+impl crate::diff::Diff for CustomResourceDefinitionNames {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.categories,
+            &other.categories,
+            &crate::diff::Path::Field { parent: path, name: "categories" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.kind, &other.kind, &crate::diff::Path::Field { parent: path, name: "kind" }, changes);
+        crate::diff::Diff::diff(&self.list_kind, &other.list_kind, &crate::diff::Path::Field { parent: path, name: "listKind" }, changes);
+        crate::diff::Diff::diff(&self.plural, &other.plural, &crate::diff::Path::Field { parent: path, name: "plural" }, changes);
+        crate::diff::strategies::list::atomic(
+            &self.short_names,
+            &other.short_names,
+            &crate::diff::Path::Field { parent: path, name: "shortNames" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::Diff::diff(&self.singular, &other.singular, &crate::diff::Path::Field { parent: path, name: "singular" }, changes);
+    }
+}
+
+impl CustomResourceDefinitionNames {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<CustomResourceDefinitionNames> {
+    /// The path of the `categories` field.
+    pub fn categories(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("categories")
+    }
+
+    /// The path of the `kind` field.
+    pub fn kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("kind")
+    }
+
+    /// The path of the `listKind` field.
+    pub fn list_kind(self) -> crate::FieldPath<std::string::String> {
+        self.child("listKind")
+    }
+
+    /// The path of the `plural` field.
+    pub fn plural(self) -> crate::FieldPath<std::string::String> {
+        self.child("plural")
+    }
+
+    /// The path of the `shortNames` field.
+    pub fn short_names(self) -> crate::FieldPath<std::vec::Vec<std::string::String>> {
+        self.child("shortNames")
+    }
+
+    /// The path of the `singular` field.
+    pub fn singular(self) -> crate::FieldPath<std::string::String> {
+        self.child("singular")
+    }
+}
+
+impl crate::Normalize for CustomResourceDefinitionNames {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.categories, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.kind);
+        crate::Normalize::normalize(&mut self.list_kind);
+        crate::Normalize::normalize(&mut self.plural);
+        crate::normalize_strategies::list::atomic(&mut self.short_names, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::Normalize::normalize(&mut self.singular);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for CustomResourceDefinitionNames {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -157,6 +249,60 @@ impl crate::serde::Serialize for CustomResourceDefinitionNames {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for CustomResourceDefinitionNames {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.plural, 1, buf);
+        if let Some(value) = &self.singular {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+        if let Some(value) = &self.short_names {
+            crate::protobuf::Field::encode_field(value, 3, buf);
+        }
+        crate::protobuf::Field::encode_field(&self.kind, 4, buf);
+        if let Some(value) = &self.list_kind {
+            crate::protobuf::Field::encode_field(value, 5, buf);
+        }
+        if let Some(value) = &self.categories {
+            crate::protobuf::Field::encode_field(value, 6, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_categories: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_kind: Option<std::string::String> = None;
+        let mut value_list_kind: Option<std::string::String> = None;
+        let mut value_plural: Option<std::string::String> = None;
+        let mut value_short_names: Option<std::vec::Vec<std::string::String>> = None;
+        let mut value_singular: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_plural, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_singular, wire_type, &mut buf)?,
+                3 => crate::protobuf::Field::merge_field(&mut value_short_names, wire_type, &mut buf)?,
+                4 => crate::protobuf::Field::merge_field(&mut value_kind, wire_type, &mut buf)?,
+                5 => crate::protobuf::Field::merge_field(&mut value_list_kind, wire_type, &mut buf)?,
+                6 => crate::protobuf::Field::merge_field(&mut value_categories, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(CustomResourceDefinitionNames {
+            categories: value_categories,
+            kind: value_kind.unwrap_or_default(),
+            list_kind: value_list_kind,
+            plural: value_plural.unwrap_or_default(),
+            short_names: value_short_names,
+            singular: value_singular,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for CustomResourceDefinitionNames {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for CustomResourceDefinitionNames {
     fn schema_name() -> std::borrow::Cow<'static, str> {
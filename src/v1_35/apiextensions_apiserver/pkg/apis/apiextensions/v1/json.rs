@@ -10,6 +10,24 @@ impl crate::DeepMerge for JSON {
     }
 }
 
+impl crate::Defaulting for JSON {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.0);
+    }
+}
+
+impl crate::Normalize for JSON {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for JSON {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for JSON {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -42,6 +60,17 @@ impl crate::serde::Serialize for JSON {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for JSON {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::encode_raw_json(&self.0, buf);
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        Ok(JSON(crate::protobuf::decode_raw_json(buf)?))
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for JSON {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -14,6 +14,24 @@ impl crate::DeepMerge for FieldsV1 {
     }
 }
 
+impl crate::Defaulting for FieldsV1 {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.0);
+    }
+}
+
+impl crate::Normalize for FieldsV1 {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.0);
+    }
+}
+
+impl crate::diff::Diff for FieldsV1 {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::scalar(self, other, path, changes);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for FieldsV1 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         struct Visitor;
@@ -46,6 +64,17 @@ impl crate::serde::Serialize for FieldsV1 {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for FieldsV1 {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::encode_raw_json(&self.0, buf);
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        Ok(FieldsV1(crate::protobuf::decode_raw_json(buf)?))
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for FieldsV1 {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -15,6 +15,46 @@ impl crate::DeepMerge for GroupResource {
     }
 }
 
+impl crate::Defaulting for GroupResource {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group);
+        crate::Defaulting::set_defaults(&mut self.resource);
+    }
+}
+
+impl crate::diff::Diff for GroupResource {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group, &other.group, &crate::diff::Path::Field { parent: path, name: "group" }, changes);
+        crate::diff::Diff::diff(&self.resource, &other.resource, &crate::diff::Path::Field { parent: path, name: "resource" }, changes);
+    }
+}
+
+impl GroupResource {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GroupResource> {
+    /// The path of the `group` field.
+    pub fn group(self) -> crate::FieldPath<std::string::String> {
+        self.child("group")
+    }
+
+    /// The path of the `resource` field.
+    pub fn resource(self) -> crate::FieldPath<std::string::String> {
+        self.child("resource")
+    }
+}
+
+impl crate::Normalize for GroupResource {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group);
+        crate::Normalize::normalize(&mut self.resource);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GroupResource {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -99,6 +139,36 @@ impl crate::serde::Serialize for GroupResource {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GroupResource {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.group, 1, buf);
+        crate::protobuf::Field::encode_field(&self.resource, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group: Option<std::string::String> = None;
+        let mut value_resource: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_resource, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GroupResource {
+            group: value_group.unwrap_or_default(),
+            resource: value_resource.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GroupResource {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GroupResource {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for GroupVersionForDiscovery {
     }
 }
 
+impl crate::Defaulting for GroupVersionForDiscovery {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.group_version);
+        crate::Defaulting::set_defaults(&mut self.version);
+    }
+}
+
+impl crate::diff::Diff for GroupVersionForDiscovery {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.group_version, &other.group_version, &crate::diff::Path::Field { parent: path, name: "groupVersion" }, changes);
+        crate::diff::Diff::diff(&self.version, &other.version, &crate::diff::Path::Field { parent: path, name: "version" }, changes);
+    }
+}
+
+impl GroupVersionForDiscovery {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<GroupVersionForDiscovery> {
+    /// The path of the `groupVersion` field.
+    pub fn group_version(self) -> crate::FieldPath<std::string::String> {
+        self.child("groupVersion")
+    }
+
+    /// The path of the `version` field.
+    pub fn version(self) -> crate::FieldPath<std::string::String> {
+        self.child("version")
+    }
+}
+
+impl crate::Normalize for GroupVersionForDiscovery {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.group_version);
+        crate::Normalize::normalize(&mut self.version);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for GroupVersionForDiscovery {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for GroupVersionForDiscovery {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for GroupVersionForDiscovery {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.group_version, 1, buf);
+        crate::protobuf::Field::encode_field(&self.version, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_group_version: Option<std::string::String> = None;
+        let mut value_version: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_group_version, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_version, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(GroupVersionForDiscovery {
+            group_version: value_group_version.unwrap_or_default(),
+            version: value_version.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for GroupVersionForDiscovery {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for GroupVersionForDiscovery {
     fn schema_name() -> std::borrow::Cow<'static, str> {
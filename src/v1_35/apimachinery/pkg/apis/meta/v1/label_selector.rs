@@ -19,6 +19,66 @@ impl crate::DeepMerge for LabelSelector {
     }
 }
 
+impl crate::Defaulting for LabelSelector {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.match_expressions);
+        crate::Defaulting::set_defaults(&mut self.match_labels);
+    }
+}
+
+impl crate::diff::Diff for LabelSelector {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::strategies::list::atomic(
+            &self.match_expressions,
+            &other.match_expressions,
+            &crate::diff::Path::Field { parent: path, name: "matchExpressions" },
+            changes,
+            |old_item, new_item, path, changes| {
+                crate::diff::Diff::diff(old_item, new_item, path, changes);
+            },
+        );
+        crate::diff::strategies::map::values(
+            &self.match_labels,
+            &other.match_labels,
+            &crate::diff::Path::Field { parent: path, name: "matchLabels" },
+            changes,
+            |old_value, new_value, path, changes| {
+                crate::diff::Diff::diff(old_value, new_value, path, changes);
+            },
+        );
+    }
+}
+
+impl LabelSelector {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<LabelSelector> {
+    /// The path of the `matchExpressions` field.
+    pub fn match_expressions(self) -> crate::FieldPath<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>> {
+        self.child("matchExpressions")
+    }
+
+    /// The path of the `matchLabels` field.
+    pub fn match_labels(self) -> crate::FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>> {
+        self.child("matchLabels")
+    }
+}
+
+impl crate::Normalize for LabelSelector {
+    fn normalize(&mut self) {
+        crate::normalize_strategies::list::atomic(&mut self.match_expressions, |item| {
+            crate::Normalize::normalize(item);
+        });
+        crate::normalize_strategies::map::values(&mut self.match_labels, |value| {
+            crate::Normalize::normalize(value);
+        });
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for LabelSelector {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -108,6 +168,40 @@ impl crate::serde::Serialize for LabelSelector {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for LabelSelector {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        if let Some(value) = &self.match_labels {
+            crate::protobuf::Field::encode_field(value, 1, buf);
+        }
+        if let Some(value) = &self.match_expressions {
+            crate::protobuf::Field::encode_field(value, 2, buf);
+        }
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_match_expressions: Option<std::vec::Vec<crate::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement>> = None;
+        let mut value_match_labels: Option<std::collections::BTreeMap<std::string::String, std::string::String>> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_match_labels, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_match_expressions, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(LabelSelector {
+            match_expressions: value_match_expressions,
+            match_labels: value_match_labels,
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for LabelSelector {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for LabelSelector {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -17,6 +17,46 @@ impl crate::DeepMerge for ServerAddressByClientCIDR {
     }
 }
 
+impl crate::Defaulting for ServerAddressByClientCIDR {
+    fn set_defaults(&mut self) {
+        crate::Defaulting::set_defaults(&mut self.client_cidr);
+        crate::Defaulting::set_defaults(&mut self.server_address);
+    }
+}
+
+impl crate::diff::Diff for ServerAddressByClientCIDR {
+    fn diff(&self, other: &Self, path: &crate::diff::Path<'_>, changes: &mut std::vec::Vec<crate::diff::Change>) {
+        crate::diff::Diff::diff(&self.client_cidr, &other.client_cidr, &crate::diff::Path::Field { parent: path, name: "clientCIDR" }, changes);
+        crate::diff::Diff::diff(&self.server_address, &other.server_address, &crate::diff::Path::Field { parent: path, name: "serverAddress" }, changes);
+    }
+}
+
+impl ServerAddressByClientCIDR {
+    /// Returns the path of a value of this type, from which the paths of its fields can be obtained.
+    pub fn fields() -> crate::FieldPath<Self> {
+        crate::FieldPath::root()
+    }
+}
+
+impl crate::FieldPath<ServerAddressByClientCIDR> {
+    /// The path of the `clientCIDR` field.
+    pub fn client_cidr(self) -> crate::FieldPath<std::string::String> {
+        self.child("clientCIDR")
+    }
+
+    /// The path of the `serverAddress` field.
+    pub fn server_address(self) -> crate::FieldPath<std::string::String> {
+        self.child("serverAddress")
+    }
+}
+
+impl crate::Normalize for ServerAddressByClientCIDR {
+    fn normalize(&mut self) {
+        crate::Normalize::normalize(&mut self.client_cidr);
+        crate::Normalize::normalize(&mut self.server_address);
+    }
+}
+
 impl<'de> crate::serde::Deserialize<'de> for ServerAddressByClientCIDR {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: crate::serde::Deserializer<'de> {
         #[allow(non_camel_case_types)]
@@ -101,6 +141,36 @@ impl crate::serde::Serialize for ServerAddressByClientCIDR {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl crate::protobuf::Message for ServerAddressByClientCIDR {
+    fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+        crate::protobuf::Field::encode_field(&self.client_cidr, 1, buf);
+        crate::protobuf::Field::encode_field(&self.server_address, 2, buf);
+    }
+
+    fn decode(mut buf: &[u8]) -> Result<Self, crate::protobuf::DecodeError> {
+        let mut value_client_cidr: Option<std::string::String> = None;
+        let mut value_server_address: Option<std::string::String> = None;
+
+        while !buf.is_empty() {
+            let (field_number, wire_type) = crate::protobuf::decode_key(&mut buf)?;
+            match field_number {
+                1 => crate::protobuf::Field::merge_field(&mut value_client_cidr, wire_type, &mut buf)?,
+                2 => crate::protobuf::Field::merge_field(&mut value_server_address, wire_type, &mut buf)?,
+                _ => crate::protobuf::skip_field(wire_type, &mut buf)?,
+            }
+        }
+
+        Ok(ServerAddressByClientCIDR {
+            client_cidr: value_client_cidr.unwrap_or_default(),
+            server_address: value_server_address.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(feature = "preserve-unknown-fields")]
+impl crate::unknown_fields::ListMapKeys for ServerAddressByClientCIDR {}
+
 #[cfg(feature = "schemars")]
 impl crate::schemars::JsonSchema for ServerAddressByClientCIDR {
     fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -0,0 +1,2127 @@
+//! Local validation of built-in resources, mirroring the validation that the API server does when they are created or updated.
+//!
+//! [`Validate::validate`] returns the errors that the API server would reject an object with, each with the path of the invalid field,
+//! like the `field.ErrorList` of apimachinery's `k8s.io/apimachinery/pkg/util/validation/field` package.
+//! [`Validate::validate_update`] additionally checks the fields that may not be changed by an update.
+//!
+//! ```rust,ignore
+//! let deployment: Deployment = serde_yaml::from_str(manifest)?;
+//! for error in deployment.validate() {
+//!     eprintln!("error: {error}");
+//! }
+//! ```
+//!
+//! ```text
+//! error: spec.template.metadata.labels: Invalid value: {"app":"web"}: `selector` does not match template `labels`
+//! error: spec.template.spec.containers[1].name: Duplicate value: "app"
+//! ```
+//!
+//! The checks are ported from the `pkg/apis/*/validation` packages of the API server for the most commonly used fields.
+//! They do not include checks that depend on the configuration of the cluster or on other objects, like feature gates or resource quotas.
+//!
+//! The API server sets the defaults of an object before validating it, so some checks pass or fail differently for objects whose defaults
+//! have not been set. Call [`Defaulting::set_defaults`](crate::Defaulting::set_defaults) before validating an object to get the same result as the API server.
+//! Unlike the API server, a namespaced object without a `metadata.namespace` is not an error, since the namespace is usually set by the client.
+
+use crate::FieldPath;
+use crate::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+
+/// A trait applies to resource types that the API server validates when they are created or updated.
+pub trait Validate {
+    /// Returns the errors that the API server would reject `self` with when it is created.
+    fn validate(&self) -> std::vec::Vec<FieldError>;
+
+    /// Returns the errors that the API server would reject `self` with when it replaces `old`.
+    ///
+    /// This includes the errors of [`validate`](Validate::validate), and errors for the fields that may not be changed.
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let _ = old;
+        self.validate()
+    }
+}
+
+/// An invalid field, like apimachinery's `field.Error`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldError {
+    /// How the field is invalid.
+    pub kind: FieldErrorKind,
+
+    /// The path of the field, like `spec.containers[0].image`. This is an empty string for errors that refer to the whole object.
+    pub path: std::string::String,
+
+    /// The invalid value, in its JSON representation, if the error includes it.
+    pub bad_value: Option<serde_json::Value>,
+
+    /// Details of why the value is invalid. This may be an empty string.
+    pub detail: std::string::String,
+}
+
+impl FieldError {
+    /// A required value was not set.
+    pub fn required<T>(path: &FieldPath<T>, detail: impl Into<std::string::String>) -> Self where T: ?Sized {
+        FieldError { kind: FieldErrorKind::Required, path: path.to_dotted(), bad_value: None, detail: detail.into() }
+    }
+
+    /// A value refers to something that does not exist.
+    pub fn not_found<T>(path: &FieldPath<T>, value: impl Into<serde_json::Value>) -> Self where T: ?Sized {
+        FieldError { kind: FieldErrorKind::NotFound, path: path.to_dotted(), bad_value: Some(value.into()), detail: std::string::String::new() }
+    }
+
+    /// A value that must be unique is repeated.
+    pub fn duplicate<T>(path: &FieldPath<T>, value: impl Into<serde_json::Value>) -> Self where T: ?Sized {
+        FieldError { kind: FieldErrorKind::Duplicate, path: path.to_dotted(), bad_value: Some(value.into()), detail: std::string::String::new() }
+    }
+
+    /// A value is invalid.
+    pub fn invalid<T>(path: &FieldPath<T>, value: impl Into<serde_json::Value>, detail: impl Into<std::string::String>) -> Self where T: ?Sized {
+        FieldError { kind: FieldErrorKind::Invalid, path: path.to_dotted(), bad_value: Some(value.into()), detail: detail.into() }
+    }
+
+    /// A value is not one of the given supported values.
+    pub fn not_supported<T>(path: &FieldPath<T>, value: impl Into<serde_json::Value>, supported_values: &[&str]) -> Self where T: ?Sized {
+        let mut detail = std::string::String::new();
+        if !supported_values.is_empty() {
+            detail.push_str("supported values: ");
+            for (i, supported_value) in supported_values.iter().enumerate() {
+                if i > 0 {
+                    detail.push_str(", ");
+                }
+                detail.push_str(&std::string::ToString::to_string(&json(*supported_value)));
+            }
+        }
+
+        FieldError { kind: FieldErrorKind::NotSupported, path: path.to_dotted(), bad_value: Some(value.into()), detail }
+    }
+
+    /// A value is valid, but is not allowed.
+    pub fn forbidden<T>(path: &FieldPath<T>, detail: impl Into<std::string::String>) -> Self where T: ?Sized {
+        FieldError { kind: FieldErrorKind::Forbidden, path: path.to_dotted(), bad_value: None, detail: detail.into() }
+    }
+
+    /// A value is longer than the given number of bytes.
+    pub fn too_long<T>(path: &FieldPath<T>, max_length: usize) -> Self where T: ?Sized {
+        let detail = std::format!("may not be more than {max_length} {}", if max_length == 1 { "byte" } else { "bytes" });
+        FieldError { kind: FieldErrorKind::TooLong, path: path.to_dotted(), bad_value: None, detail }
+    }
+
+    /// A list has more than the given number of items.
+    pub fn too_many<T>(path: &FieldPath<T>, actual: usize, max: usize) -> Self where T: ?Sized {
+        let detail = std::format!("must have at most {max} {}", if max == 1 { "item" } else { "items" });
+        FieldError { kind: FieldErrorKind::TooMany, path: path.to_dotted(), bad_value: Some(actual.into()), detail }
+    }
+}
+
+impl core::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind.as_str())?;
+        if let Some(bad_value) = &self.bad_value {
+            write!(f, ": {bad_value}")?;
+        }
+        if !self.detail.is_empty() {
+            write!(f, ": {}", self.detail)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for FieldError {}
+
+/// The kind of a [`FieldError`], like apimachinery's `field.ErrorType`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FieldErrorKind {
+    /// A value refers to something that does not exist.
+    NotFound,
+
+    /// A required value was not set.
+    Required,
+
+    /// A value that must be unique is repeated.
+    Duplicate,
+
+    /// A value is invalid.
+    Invalid,
+
+    /// A value is not one of the supported values.
+    NotSupported,
+
+    /// A value is valid, but is not allowed.
+    Forbidden,
+
+    /// A value is too long.
+    TooLong,
+
+    /// A list has too many items.
+    TooMany,
+}
+
+impl FieldErrorKind {
+    /// The human-readable description of the kind, as used in the messages of the API server.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FieldErrorKind::NotFound => "Not found",
+            FieldErrorKind::Required => "Required value",
+            FieldErrorKind::Duplicate => "Duplicate value",
+            FieldErrorKind::Invalid => "Invalid value",
+            FieldErrorKind::NotSupported => "Unsupported value",
+            FieldErrorKind::Forbidden => "Forbidden",
+            FieldErrorKind::TooLong => "Too long",
+            FieldErrorKind::TooMany => "Too many",
+        }
+    }
+
+    /// The reason of the `StatusCause` that the API server reports for errors of this kind.
+    pub fn cause_type(self) -> crate::status::CauseType {
+        match self {
+            FieldErrorKind::NotFound => crate::status::CauseType::FieldValueNotFound,
+            FieldErrorKind::Required => crate::status::CauseType::FieldValueRequired,
+            FieldErrorKind::Duplicate => crate::status::CauseType::FieldValueDuplicate,
+            FieldErrorKind::Invalid => crate::status::CauseType::FieldValueInvalid,
+            FieldErrorKind::NotSupported => crate::status::CauseType::FieldValueNotSupported,
+            FieldErrorKind::Forbidden => crate::status::CauseType::Forbidden,
+            FieldErrorKind::TooLong => crate::status::CauseType::TooLong,
+            FieldErrorKind::TooMany => crate::status::CauseType::TooMany,
+        }
+    }
+}
+
+/// The message of the errors of fields that may not be changed by an update.
+const FIELD_IMMUTABLE_ERROR_MSG: &str = "field is immutable";
+
+/// The maximum total size of the keys and values of an object's annotations.
+const TOTAL_ANNOTATION_SIZE_LIMIT_B: usize = 256 * 1024;
+
+/// The maximum total size of the data of a `ConfigMap` or `Secret`.
+const MAX_SECRET_SIZE: usize = 1024 * 1024;
+
+// Resources
+
+impl Validate for crate::api::apps::v1::DaemonSet {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/apps/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        validate_workload_selector(&spec.selector, &spec.template, "daemonset", path.clone().selector(), path.clone().template(), &mut errors);
+        validate_pod_template_spec(&spec.template, path.clone().template(), &mut errors);
+        validate_restart_policy_always(&spec.template, path.clone().template(), &mut errors);
+
+        if let Some(update_strategy) = &spec.update_strategy {
+            let path = path.clone().update_strategy();
+            match update_strategy.type_.as_deref() {
+                None | Some("OnDelete") => (),
+
+                Some("RollingUpdate") => if let Some(rolling_update) = &update_strategy.rolling_update {
+                    let path = path.rolling_update();
+
+                    validate_positive_int_or_percent(rolling_update.max_unavailable.as_ref(), &path.clone().max_unavailable(), &mut errors);
+                    validate_positive_int_or_percent(rolling_update.max_surge.as_ref(), &path.clone().max_surge(), &mut errors);
+                    validate_not_more_than_100_percent(rolling_update.max_unavailable.as_ref(), &path.clone().max_unavailable(), &mut errors);
+                    validate_not_more_than_100_percent(rolling_update.max_surge.as_ref(), &path.clone().max_surge(), &mut errors);
+
+                    // Exactly one of `maxSurge` and `maxUnavailable` must be non-zero.
+                    let has_unavailable = int_or_percent_value(rolling_update.max_unavailable.as_ref()) != Some(0);
+                    let has_surge = int_or_percent_value(rolling_update.max_surge.as_ref()).is_some_and(|value| value != 0);
+                    if has_unavailable && has_surge {
+                        errors.push(FieldError::invalid(&path.clone().max_surge(), json(&rolling_update.max_surge), "may not be set when maxUnavailable is non-zero"));
+                    }
+                    else if !has_unavailable && !has_surge {
+                        errors.push(FieldError::invalid(&path.max_unavailable(), json(&rolling_update.max_unavailable), "cannot be 0 when maxSurge is 0"));
+                    }
+                },
+
+                Some(type_) => errors.push(FieldError::not_supported(&path.type_(), type_, &["OnDelete", "RollingUpdate"])),
+            }
+        }
+
+        validate_nonnegative_field(spec.min_ready_seconds, &path.clone().min_ready_seconds(), &mut errors);
+        validate_nonnegative_field(spec.revision_history_limit, &path.revision_history_limit(), &mut errors);
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let old_spec = or_default(old.spec.as_ref());
+        validate_immutable_field(&spec.selector, &old_spec.selector, &path.spec().selector(), &mut errors);
+
+        errors
+    }
+}
+
+impl Validate for crate::api::apps::v1::Deployment {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/apps/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        validate_nonnegative_field(spec.replicas, &path.clone().replicas(), &mut errors);
+        validate_workload_selector(&spec.selector, &spec.template, "deployment", path.clone().selector(), path.clone().template(), &mut errors);
+        validate_pod_template_spec(&spec.template, path.clone().template(), &mut errors);
+        validate_restart_policy_always(&spec.template, path.clone().template(), &mut errors);
+
+        if let Some(strategy) = &spec.strategy {
+            let path = path.clone().strategy();
+            match strategy.type_.as_deref() {
+                Some("Recreate") if strategy.rolling_update.is_some() =>
+                    errors.push(FieldError::forbidden(&path.rolling_update(), "may not be specified when strategy `type` is 'Recreate'")),
+
+                None | Some("Recreate") => (),
+
+                Some("RollingUpdate") => if let Some(rolling_update) = &strategy.rolling_update {
+                    let path = path.rolling_update();
+
+                    validate_positive_int_or_percent(rolling_update.max_unavailable.as_ref(), &path.clone().max_unavailable(), &mut errors);
+                    validate_positive_int_or_percent(rolling_update.max_surge.as_ref(), &path.clone().max_surge(), &mut errors);
+                    if
+                        int_or_percent_value(rolling_update.max_unavailable.as_ref()) == Some(0) &&
+                        int_or_percent_value(rolling_update.max_surge.as_ref()) == Some(0)
+                    {
+                        errors.push(FieldError::invalid(&path.clone().max_unavailable(), json(&rolling_update.max_unavailable), "may not be 0 when `maxSurge` is 0"));
+                    }
+                    validate_not_more_than_100_percent(rolling_update.max_unavailable.as_ref(), &path.max_unavailable(), &mut errors);
+                },
+
+                Some(type_) => errors.push(FieldError::not_supported(&path.type_(), type_, &["Recreate", "RollingUpdate"])),
+            }
+        }
+
+        validate_nonnegative_field(spec.revision_history_limit, &path.clone().revision_history_limit(), &mut errors);
+        validate_nonnegative_field(spec.min_ready_seconds, &path.clone().min_ready_seconds(), &mut errors);
+        if let Some(progress_deadline_seconds) = spec.progress_deadline_seconds {
+            validate_nonnegative_field(Some(progress_deadline_seconds), &path.clone().progress_deadline_seconds(), &mut errors);
+            if progress_deadline_seconds <= spec.min_ready_seconds.unwrap_or_default() {
+                errors.push(FieldError::invalid(&path.progress_deadline_seconds(), progress_deadline_seconds, "must be greater than minReadySeconds"));
+            }
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let old_spec = or_default(old.spec.as_ref());
+        validate_immutable_field(&spec.selector, &old_spec.selector, &path.spec().selector(), &mut errors);
+
+        errors
+    }
+}
+
+impl Validate for crate::api::apps::v1::StatefulSet {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/apps/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        if let Some(pod_management_policy) = spec.pod_management_policy.as_deref() {
+            if !matches!(pod_management_policy, "OrderedReady" | "Parallel") {
+                errors.push(FieldError::not_supported(&path.clone().pod_management_policy(), pod_management_policy, &["OrderedReady", "Parallel"]));
+            }
+        }
+
+        if let Some(update_strategy) = &spec.update_strategy {
+            let path = path.clone().update_strategy();
+            match update_strategy.type_.as_deref() {
+                Some("OnDelete") if update_strategy.rolling_update.is_some() =>
+                    errors.push(FieldError::invalid(&path.rolling_update(), json(&update_strategy.rolling_update), "only allowed for updateStrategy 'RollingUpdate'")),
+
+                None | Some("OnDelete") => (),
+
+                Some("RollingUpdate") => if let Some(rolling_update) = &update_strategy.rolling_update {
+                    validate_nonnegative_field(rolling_update.partition, &path.rolling_update().partition(), &mut errors);
+                },
+
+                Some(type_) => errors.push(FieldError::invalid(&path, type_, "must be 'RollingUpdate' or 'OnDelete'")),
+            }
+        }
+
+        validate_nonnegative_field(spec.replicas, &path.clone().replicas(), &mut errors);
+        validate_nonnegative_field(spec.min_ready_seconds, &path.clone().min_ready_seconds(), &mut errors);
+        validate_nonnegative_field(spec.revision_history_limit, &path.clone().revision_history_limit(), &mut errors);
+        validate_workload_selector(&spec.selector, &spec.template, "statefulset", path.clone().selector(), path.clone().template(), &mut errors);
+        validate_pod_template_spec(&spec.template, path.clone().template(), &mut errors);
+        validate_restart_policy_always(&spec.template, path.template(), &mut errors);
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        // Only some fields of the spec may be updated, so compare the specs with those fields restored to their old values.
+        let mut spec = or_default(self.spec.as_ref()).into_owned();
+        let old_spec = or_default(old.spec.as_ref());
+        spec.replicas.clone_from(&old_spec.replicas);
+        spec.ordinals.clone_from(&old_spec.ordinals);
+        spec.template.clone_from(&old_spec.template);
+        spec.update_strategy.clone_from(&old_spec.update_strategy);
+        spec.revision_history_limit.clone_from(&old_spec.revision_history_limit);
+        spec.persistent_volume_claim_retention_policy.clone_from(&old_spec.persistent_volume_claim_retention_policy);
+        spec.min_ready_seconds.clone_from(&old_spec.min_ready_seconds);
+        if spec != *old_spec {
+            errors.push(FieldError::forbidden(
+                &path.spec(),
+                "updates to statefulset spec for fields other than 'replicas', 'ordinals', 'template', 'updateStrategy', \
+                 'revisionHistoryLimit', 'persistentVolumeClaimRetentionPolicy' and 'minReadySeconds' are forbidden",
+            ));
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::batch::v1::CronJob {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/batch/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        // The names of the jobs of a cron job are its name followed by a suffix of 11 characters, and must be DNS-1035 labels.
+        if let Some(name) = &self.metadata.name {
+            if name.len() > 52 {
+                errors.push(FieldError::invalid(&path.clone().metadata().name(), &**name, "must be no more than 52 characters"));
+            }
+        }
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        if spec.schedule.is_empty() {
+            errors.push(FieldError::required(&path.clone().schedule(), ""));
+        }
+        else if spec.schedule.contains("TZ") {
+            errors.push(FieldError::invalid(&path.clone().schedule(), &*spec.schedule, "cannot use TZ or CRON_TZ in schedule, use timeZone field instead"));
+        }
+        else if let Err(message) = validate_schedule(&spec.schedule) {
+            errors.push(FieldError::invalid(&path.clone().schedule(), &*spec.schedule, message));
+        }
+
+        if let Some(concurrency_policy) = spec.concurrency_policy.as_deref() {
+            if !matches!(concurrency_policy, "Allow" | "Forbid" | "Replace") {
+                errors.push(FieldError::not_supported(&path.clone().concurrency_policy(), concurrency_policy, &["Allow", "Forbid", "Replace"]));
+            }
+        }
+
+        validate_nonnegative_field(spec.starting_deadline_seconds, &path.clone().starting_deadline_seconds(), &mut errors);
+        validate_nonnegative_field(spec.successful_jobs_history_limit, &path.clone().successful_jobs_history_limit(), &mut errors);
+        validate_nonnegative_field(spec.failed_jobs_history_limit, &path.clone().failed_jobs_history_limit(), &mut errors);
+
+        let path = path.job_template();
+        if let Some(metadata) = &spec.job_template.metadata {
+            validate_labels(metadata.labels.as_ref(), &path.clone().metadata().labels(), &mut errors);
+            validate_annotations(metadata.annotations.as_ref(), &path.clone().metadata().annotations(), &mut errors);
+        }
+        validate_job_spec(&or_default(spec.job_template.spec.as_ref()), path.spec(), &mut errors);
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        validate_object_meta_update(&self.metadata, &old.metadata, Self::fields().metadata(), &mut errors);
+        errors
+    }
+}
+
+impl Validate for crate::api::batch::v1::Job {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/batch/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+        validate_job_spec(&or_default(self.spec.as_ref()), path.spec(), &mut errors);
+
+        errors
+    }
+
+    /// The pod template of a job may only be changed while the job is suspended.
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let old_spec = or_default(old.spec.as_ref());
+        let path = path.spec();
+
+        validate_immutable_field(&spec.selector, &old_spec.selector, &path.clone().selector(), &mut errors);
+        validate_immutable_field(&spec.completion_mode, &old_spec.completion_mode, &path.clone().completion_mode(), &mut errors);
+        validate_immutable_field(&spec.pod_failure_policy, &old_spec.pod_failure_policy, &path.clone().pod_failure_policy(), &mut errors);
+        validate_immutable_field(&spec.backoff_limit_per_index, &old_spec.backoff_limit_per_index, &path.clone().backoff_limit_per_index(), &mut errors);
+
+        // The completions of an indexed job may be changed along with its parallelism, to scale it.
+        let is_elastic_indexed_job = spec.completion_mode.as_deref() == Some("Indexed") && spec.completions == spec.parallelism;
+        if !is_elastic_indexed_job {
+            validate_immutable_field(&spec.completions, &old_spec.completions, &path.clone().completions(), &mut errors);
+        }
+
+        if old_spec.suspend != Some(true) {
+            validate_immutable_field(&spec.template, &old_spec.template, &path.template(), &mut errors);
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::core::v1::ConfigMap {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        let mut total_size = 0;
+
+        for (key, value) in self.data.iter().flatten() {
+            let key_path = path.clone().data().key(&**key);
+            for message in is_config_map_key(key) {
+                errors.push(FieldError::invalid(&key_path, &**key, message));
+            }
+            if self.binary_data.as_ref().is_some_and(|binary_data| binary_data.contains_key(key)) {
+                errors.push(FieldError::invalid(&key_path, &**key, "duplicate of key present in binaryData"));
+            }
+            total_size += value.len();
+        }
+
+        for (key, value) in self.binary_data.iter().flatten() {
+            let key_path = path.clone().binary_data().key(&**key);
+            for message in is_config_map_key(key) {
+                errors.push(FieldError::invalid(&key_path, &**key, message));
+            }
+            if self.data.as_ref().is_some_and(|data| data.contains_key(key)) {
+                errors.push(FieldError::invalid(&key_path, &**key, "duplicate of key present in data"));
+            }
+            total_size += value.0.len();
+        }
+
+        // The error refers to the whole object.
+        if total_size > MAX_SECRET_SIZE {
+            errors.push(FieldError::too_long(&path, MAX_SECRET_SIZE));
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        if old.immutable == Some(true) {
+            if self.immutable != Some(true) {
+                errors.push(FieldError::forbidden(&path.clone().immutable(), "field is immutable when `immutable` is set"));
+            }
+            if self.data != old.data {
+                errors.push(FieldError::forbidden(&path.clone().data(), "field is immutable when `immutable` is set"));
+            }
+            if self.binary_data != old.binary_data {
+                errors.push(FieldError::forbidden(&path.binary_data(), "field is immutable when `immutable` is set"));
+            }
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::core::v1::Pod {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+        let spec = or_default(self.spec.as_ref());
+        validate_pod_spec(&spec, &path.clone().spec(), &mut errors);
+        validate_containers_only_for_pod(&spec, &path.spec(), &mut errors);
+
+        errors
+    }
+
+    /// Only the images of the containers, the `activeDeadlineSeconds`, the `terminationGracePeriodSeconds` and additions to the `tolerations`
+    /// of a pod's spec may be updated.
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        let mut spec = or_default(self.spec.as_ref()).into_owned();
+        let old_spec = or_default(old.spec.as_ref());
+        let path = path.spec();
+
+        // Existing tolerations may not be removed or changed, except for their `tolerationSeconds`.
+        for old_toleration in old_spec.tolerations.iter().flatten() {
+            let is_kept = spec.tolerations.iter().flatten().any(|toleration|
+                toleration.key == old_toleration.key &&
+                toleration.operator == old_toleration.operator &&
+                toleration.value == old_toleration.value &&
+                toleration.effect == old_toleration.effect);
+            if !is_kept {
+                errors.push(FieldError::forbidden(&path.clone().tolerations(), "existing toleration can not be modified except its tolerationSeconds"));
+                break;
+            }
+        }
+
+        // Compare the specs with the updatable fields restored to their old values.
+        let restore_images = |containers: &mut std::vec::Vec<crate::api::core::v1::Container>, old_containers: &[crate::api::core::v1::Container]| {
+            if containers.len() == old_containers.len() {
+                for (container, old_container) in containers.iter_mut().zip(old_containers) {
+                    container.image.clone_from(&old_container.image);
+                }
+            }
+        };
+        restore_images(&mut spec.containers, &old_spec.containers);
+        if let (Some(init_containers), Some(old_init_containers)) = (&mut spec.init_containers, &old_spec.init_containers) {
+            restore_images(init_containers, old_init_containers);
+        }
+        spec.active_deadline_seconds = old_spec.active_deadline_seconds;
+        spec.tolerations.clone_from(&old_spec.tolerations);
+        spec.termination_grace_period_seconds = old_spec.termination_grace_period_seconds;
+        spec.ephemeral_containers.clone_from(&old_spec.ephemeral_containers);
+        spec.scheduling_gates.clone_from(&old_spec.scheduling_gates);
+
+        if spec != *old_spec {
+            errors.push(FieldError::forbidden(
+                &path,
+                "pod updates may not change fields other than `spec.containers[*].image`,`spec.initContainers[*].image`,\
+                 `spec.activeDeadlineSeconds`,`spec.tolerations` (only additions to existing tolerations),\
+                 `spec.terminationGracePeriodSeconds` (allow it to be set to 1 if it was previously negative)",
+            ));
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::core::v1::Secret {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        // The API server merges `stringData` into `data` before validating the secret.
+        let mut total_size = 0;
+        for (key, value) in self.data.iter().flatten() {
+            let key_path = path.clone().data().key(&**key);
+            for message in is_config_map_key(key) {
+                errors.push(FieldError::invalid(&key_path, &**key, message));
+            }
+            if !self.string_data.as_ref().is_some_and(|string_data| string_data.contains_key(key)) {
+                total_size += value.0.len();
+            }
+        }
+        for (key, value) in self.string_data.iter().flatten() {
+            let key_path = path.clone().string_data().key(&**key);
+            for message in is_config_map_key(key) {
+                errors.push(FieldError::invalid(&key_path, &**key, message));
+            }
+            total_size += value.len();
+        }
+        if total_size > MAX_SECRET_SIZE {
+            errors.push(FieldError::too_long(&path.clone().data(), MAX_SECRET_SIZE));
+        }
+
+        let has_key = |key: &str|
+            self.data.as_ref().is_some_and(|data| data.contains_key(key)) ||
+            self.string_data.as_ref().is_some_and(|string_data| string_data.contains_key(key));
+        let mut require_key = |key: &str| if !has_key(key) {
+            errors.push(FieldError::required(&path.clone().data().key(key), ""));
+        };
+
+        match self.type_.as_deref().unwrap_or_default() {
+            "kubernetes.io/service-account-token" => {
+                const SERVICE_ACCOUNT_NAME_KEY: &str = "kubernetes.io/service-account.name";
+                if self.metadata.annotations.as_ref().and_then(|annotations| annotations.get(SERVICE_ACCOUNT_NAME_KEY)).is_none_or(std::string::String::is_empty) {
+                    errors.push(FieldError::required(&path.clone().metadata().annotations().key(SERVICE_ACCOUNT_NAME_KEY), ""));
+                }
+            },
+
+            "kubernetes.io/dockercfg" => require_key(".dockercfg"),
+
+            "kubernetes.io/dockerconfigjson" => require_key(".dockerconfigjson"),
+
+            "kubernetes.io/basic-auth" if !has_key("username") && !has_key("password") => {
+                require_key("username");
+                require_key("password");
+            },
+
+            "kubernetes.io/ssh-auth" => require_key("ssh-privatekey"),
+
+            "kubernetes.io/tls" => {
+                require_key("tls.crt");
+                require_key("tls.key");
+            },
+
+            _ => (),
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        // The type of a secret defaults to `Opaque`.
+        let type_ = self.type_.as_deref().unwrap_or("Opaque");
+        let old_type = old.type_.as_deref().unwrap_or("Opaque");
+        if type_ != old_type {
+            errors.push(FieldError::invalid(&path.clone().type_(), type_, FIELD_IMMUTABLE_ERROR_MSG));
+        }
+
+        if old.immutable == Some(true) {
+            if self.immutable != Some(true) {
+                errors.push(FieldError::forbidden(&path.clone().immutable(), "field is immutable when `immutable` is set"));
+            }
+            if self.data != old.data || self.string_data.as_ref().is_some_and(|string_data| !string_data.is_empty()) {
+                errors.push(FieldError::forbidden(&path.data(), "field is immutable when `immutable` is set"));
+            }
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::core::v1::Service {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns1035_label, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        let type_ = spec.type_.as_deref().unwrap_or("ClusterIP");
+        if !matches!(type_, "ClusterIP" | "ExternalName" | "LoadBalancer" | "NodePort") {
+            errors.push(FieldError::not_supported(&path.clone().type_(), type_, &["ClusterIP", "ExternalName", "LoadBalancer", "NodePort"]));
+        }
+
+        if type_ == "ExternalName" {
+            match spec.external_name.as_deref() {
+                None | Some("") => errors.push(FieldError::required(&path.clone().external_name(), "")),
+                Some(external_name) => {
+                    // A trailing dot makes the name fully qualified.
+                    for message in is_dns1123_subdomain(external_name.strip_suffix('.').unwrap_or(external_name)) {
+                        errors.push(FieldError::invalid(&path.clone().external_name(), external_name, message));
+                    }
+                },
+            }
+        }
+
+        let is_headless = spec.cluster_ip.as_deref() == Some("None");
+        let ports = spec.ports.as_deref().unwrap_or_default();
+        if ports.is_empty() && !is_headless && type_ != "ExternalName" {
+            errors.push(FieldError::required(&path.clone().ports(), ""));
+        }
+
+        let mut port_names = std::collections::BTreeSet::new();
+        let mut port_numbers = std::collections::BTreeSet::new();
+        for (i, port) in ports.iter().enumerate() {
+            let port_path = path.clone().ports().at(i);
+
+            match port.name.as_deref() {
+                None | Some("") => if ports.len() > 1 {
+                    errors.push(FieldError::required(&port_path.clone().name(), ""));
+                },
+                Some(name) => {
+                    for message in is_dns1123_label(name) {
+                        errors.push(FieldError::invalid(&port_path.clone().name(), name, message));
+                    }
+                    if !port_names.insert(name) {
+                        errors.push(FieldError::duplicate(&port_path.clone().name(), name));
+                    }
+                },
+            }
+
+            for message in is_valid_port_num(port.port) {
+                errors.push(FieldError::invalid(&port_path.clone().port(), port.port, message));
+            }
+
+            let protocol = port.protocol.as_deref().unwrap_or("TCP");
+            validate_protocol(protocol, &port_path.clone().protocol(), &mut errors);
+            if !port_numbers.insert((port.port, protocol)) {
+                errors.push(FieldError::duplicate(&port_path, serde_json::json!({ "port": port.port, "protocol": protocol })));
+            }
+
+            if let Some(target_port) = &port.target_port {
+                validate_port_num_or_name(target_port, &port_path.clone().target_port(), &mut errors);
+            }
+
+            if let Some(node_port) = port.node_port.filter(|&node_port| node_port != 0) {
+                if type_ == "ClusterIP" {
+                    errors.push(FieldError::forbidden(&port_path.clone().node_port(), "may not be used when `type` is 'ClusterIP'"));
+                }
+                for message in is_valid_port_num(node_port) {
+                    errors.push(FieldError::invalid(&port_path.clone().node_port(), node_port, message));
+                }
+            }
+        }
+
+        validate_labels(spec.selector.as_ref(), &path.clone().selector(), &mut errors);
+
+        match spec.session_affinity.as_deref() {
+            None | Some("None") => (),
+
+            Some("ClientIP") => {
+                let timeout_seconds =
+                    spec.session_affinity_config.as_ref()
+                    .and_then(|session_affinity_config| session_affinity_config.client_ip.as_ref())
+                    .and_then(|client_ip| client_ip.timeout_seconds);
+                if let Some(timeout_seconds) = timeout_seconds {
+                    if timeout_seconds <= 0 || timeout_seconds > 86400 {
+                        errors.push(FieldError::invalid(
+                            &path.clone().session_affinity_config().client_ip().timeout_seconds(),
+                            timeout_seconds,
+                            "must be greater than 0 and less than 86400",
+                        ));
+                    }
+                }
+            },
+
+            Some(session_affinity) => errors.push(FieldError::not_supported(&path.clone().session_affinity(), session_affinity, &["ClientIP", "None"])),
+        }
+
+        if let Some(external_traffic_policy) = spec.external_traffic_policy.as_deref().filter(|policy| !policy.is_empty()) {
+            let is_externally_accessible =
+                matches!(type_, "LoadBalancer" | "NodePort") ||
+                spec.external_ips.as_ref().is_some_and(|external_ips| !external_ips.is_empty());
+            if !is_externally_accessible {
+                errors.push(FieldError::invalid(&path.clone().external_traffic_policy(), external_traffic_policy, "may only be set for externally-accessible services"));
+            }
+            else if !matches!(external_traffic_policy, "Cluster" | "Local") {
+                errors.push(FieldError::not_supported(&path.clone().external_traffic_policy(), external_traffic_policy, &["Cluster", "Local"]));
+            }
+        }
+
+        if let Some(internal_traffic_policy) = spec.internal_traffic_policy.as_deref() {
+            if !matches!(internal_traffic_policy, "Cluster" | "Local") {
+                errors.push(FieldError::not_supported(&path.internal_traffic_policy(), internal_traffic_policy, &["Cluster", "Local"]));
+            }
+        }
+
+        errors
+    }
+
+    /// The `clusterIP` of a service may not be changed, unless the service is changed to or from an `ExternalName` service.
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let old_spec = or_default(old.spec.as_ref());
+
+        // The API server keeps the old `clusterIP` if the update doesn't set one.
+        let needs_cluster_ip = |spec: &crate::api::core::v1::ServiceSpec| spec.type_.as_deref() != Some("ExternalName");
+        let cluster_ip = spec.cluster_ip.as_deref().unwrap_or_default();
+        let old_cluster_ip = old_spec.cluster_ip.as_deref().unwrap_or_default();
+        if needs_cluster_ip(&spec) && needs_cluster_ip(&old_spec) && !cluster_ip.is_empty() && !old_cluster_ip.is_empty() && cluster_ip != old_cluster_ip {
+            errors.push(FieldError::invalid(&path.spec().cluster_ip(), cluster_ip, FIELD_IMMUTABLE_ERROR_MSG));
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::networking::v1::Ingress {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/networking/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, name_is_dns_subdomain, path.clone().metadata(), &mut errors);
+
+        let spec = or_default(self.spec.as_ref());
+        let path = path.spec();
+
+        let rules = spec.rules.as_deref().unwrap_or_default();
+        if rules.is_empty() && spec.default_backend.is_none() {
+            errors.push(FieldError::invalid(&path, json(rules), "either `defaultBackend` or `rules` must be specified"));
+        }
+
+        if let Some(default_backend) = &spec.default_backend {
+            validate_ingress_backend(default_backend, &path.clone().default_backend(), &mut errors);
+        }
+
+        for (i, rule) in rules.iter().enumerate() {
+            let rule_path = path.clone().rules().at(i);
+
+            if let Some(host) = rule.host.as_deref().filter(|host| !host.is_empty()) {
+                if host.parse::<core::net::IpAddr>().is_ok() {
+                    errors.push(FieldError::invalid(&rule_path.clone().host(), host, "must be a DNS name, not an IP address"));
+                }
+                validate_host(host, &rule_path.clone().host(), &mut errors);
+            }
+
+            if let Some(http) = &rule.http {
+                let http_path = rule_path.http().paths();
+                if http.paths.is_empty() {
+                    errors.push(FieldError::required(&http_path, ""));
+                }
+                for (i, http_ingress_path) in http.paths.iter().enumerate() {
+                    validate_http_ingress_path(http_ingress_path, http_path.clone().at(i), &mut errors);
+                }
+            }
+        }
+
+        for (i, tls) in spec.tls.iter().flatten().enumerate() {
+            for (j, host) in tls.hosts.iter().flatten().enumerate() {
+                validate_host(host, &path.clone().tls().at(i).hosts().at(j), &mut errors);
+            }
+        }
+
+        if let Some(ingress_class_name) = &spec.ingress_class_name {
+            for message in is_dns1123_subdomain(ingress_class_name) {
+                errors.push(FieldError::invalid(&path.clone().ingress_class_name(), &**ingress_class_name, message));
+            }
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        validate_object_meta_update(&self.metadata, &old.metadata, Self::fields().metadata(), &mut errors);
+        errors
+    }
+}
+
+impl Validate for crate::api::rbac::v1::ClusterRole {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/rbac/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, false, validate_rbac_name, path.clone().metadata(), &mut errors);
+
+        for (i, rule) in self.rules.iter().flatten().enumerate() {
+            validate_policy_rule(rule, false, path.clone().rules().at(i), &mut errors);
+        }
+
+        if let Some(aggregation_rule) = &self.aggregation_rule {
+            let path = path.aggregation_rule().cluster_role_selectors();
+            let cluster_role_selectors = aggregation_rule.cluster_role_selectors.as_deref().unwrap_or_default();
+            if cluster_role_selectors.is_empty() {
+                errors.push(FieldError::required(&path, "at least one clusterRoleSelector required if aggregationRule is non-nil"));
+            }
+            for (i, selector) in cluster_role_selectors.iter().enumerate() {
+                validate_label_selector(selector, path.clone().at(i), &mut errors);
+            }
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        validate_object_meta_update(&self.metadata, &old.metadata, Self::fields().metadata(), &mut errors);
+        errors
+    }
+}
+
+impl Validate for crate::api::rbac::v1::ClusterRoleBinding {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/rbac/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, false, validate_rbac_name, path.clone().metadata(), &mut errors);
+        validate_role_ref(&self.role_ref, &["ClusterRole"], path.clone().role_ref(), &mut errors);
+        for (i, subject) in self.subjects.iter().flatten().enumerate() {
+            validate_role_binding_subject(subject, false, path.clone().subjects().at(i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+        if self.role_ref != old.role_ref {
+            errors.push(FieldError::invalid(&path.role_ref(), json(&self.role_ref), "cannot change roleRef"));
+        }
+
+        errors
+    }
+}
+
+impl Validate for crate::api::rbac::v1::Role {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/rbac/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, validate_rbac_name, path.clone().metadata(), &mut errors);
+        for (i, rule) in self.rules.iter().flatten().enumerate() {
+            validate_policy_rule(rule, true, path.clone().rules().at(i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        validate_object_meta_update(&self.metadata, &old.metadata, Self::fields().metadata(), &mut errors);
+        errors
+    }
+}
+
+impl Validate for crate::api::rbac::v1::RoleBinding {
+    fn validate(&self) -> std::vec::Vec<FieldError> {
+        // Ref: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/rbac/validation/validation.go
+
+        let mut errors = std::vec![];
+        let path = Self::fields();
+
+        validate_object_meta(&self.metadata, true, validate_rbac_name, path.clone().metadata(), &mut errors);
+        validate_role_ref(&self.role_ref, &["Role", "ClusterRole"], path.clone().role_ref(), &mut errors);
+        for (i, subject) in self.subjects.iter().flatten().enumerate() {
+            validate_role_binding_subject(subject, true, path.clone().subjects().at(i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn validate_update(&self, old: &Self) -> std::vec::Vec<FieldError> {
+        let mut errors = self.validate();
+        let path = Self::fields();
+
+        validate_object_meta_update(&self.metadata, &old.metadata, path.clone().metadata(), &mut errors);
+        if self.role_ref != old.role_ref {
+            errors.push(FieldError::invalid(&path.role_ref(), json(&self.role_ref), "cannot change roleRef"));
+        }
+
+        errors
+    }
+}
+
+// Object metadata
+
+/// Validates the name, namespace, labels and annotations of an object.
+///
+/// Ref: <https://github.com/kubernetes/apimachinery/blob/master/pkg/api/validation/objectmeta.go>
+fn validate_object_meta(
+    metadata: &ObjectMeta,
+    namespaced: bool,
+    name_fn: fn(&str, bool) -> std::vec::Vec<std::string::String>,
+    path: FieldPath<ObjectMeta>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    let generate_name = metadata.generate_name.as_deref().unwrap_or_default();
+    if !generate_name.is_empty() {
+        for message in name_fn(generate_name, true) {
+            errors.push(FieldError::invalid(&path.clone().generate_name(), generate_name, message));
+        }
+    }
+
+    match metadata.name.as_deref() {
+        None | Some("") => if generate_name.is_empty() {
+            errors.push(FieldError::required(&path.clone().name(), "name or generateName is required"));
+        },
+
+        Some(name) => for message in name_fn(name, false) {
+            errors.push(FieldError::invalid(&path.clone().name(), name, message));
+        },
+    }
+
+    if let Some(namespace) = metadata.namespace.as_deref().filter(|namespace| !namespace.is_empty()) {
+        if namespaced {
+            for message in is_dns1123_label(namespace) {
+                errors.push(FieldError::invalid(&path.clone().namespace(), namespace, message));
+            }
+        }
+        else {
+            errors.push(FieldError::forbidden(&path.clone().namespace(), "not allowed on this type"));
+        }
+    }
+
+    validate_labels(metadata.labels.as_ref(), &path.clone().labels(), errors);
+    validate_annotations(metadata.annotations.as_ref(), &path.annotations(), errors);
+}
+
+/// Validates that the name, namespace and UID of an object are not changed by an update.
+///
+/// Ref: <https://github.com/kubernetes/apimachinery/blob/master/pkg/api/validation/objectmeta.go>
+fn validate_object_meta_update(metadata: &ObjectMeta, old_metadata: &ObjectMeta, path: FieldPath<ObjectMeta>, errors: &mut std::vec::Vec<FieldError>) {
+    // The API server fills in the UID from the old object, and the namespace from the request, if they are not set.
+    let fields = [
+        (&metadata.name, &old_metadata.name, path.clone().name()),
+        (&metadata.namespace, &old_metadata.namespace, path.clone().namespace()),
+        (&metadata.uid, &old_metadata.uid, path.uid()),
+    ];
+    for (value, old_value, path) in fields {
+        if let (Some(value), Some(old_value)) = (value, old_value) {
+            if !value.is_empty() && value != old_value {
+                errors.push(FieldError::invalid(&path, &**value, FIELD_IMMUTABLE_ERROR_MSG));
+            }
+        }
+    }
+}
+
+fn validate_labels(
+    labels: Option<&std::collections::BTreeMap<std::string::String, std::string::String>>,
+    path: &FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    for (key, value) in labels.into_iter().flatten() {
+        for message in is_qualified_name(key) {
+            errors.push(FieldError::invalid(path, &**key, message));
+        }
+        for message in is_valid_label_value(value) {
+            errors.push(FieldError::invalid(path, &**value, message));
+        }
+    }
+}
+
+fn validate_annotations(
+    annotations: Option<&std::collections::BTreeMap<std::string::String, std::string::String>>,
+    path: &FieldPath<std::collections::BTreeMap<std::string::String, std::string::String>>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    let mut total_size = 0;
+    for (key, value) in annotations.into_iter().flatten() {
+        // The keys of annotations are qualified names, except that case doesn't matter.
+        for message in is_qualified_name(&key.to_ascii_lowercase()) {
+            errors.push(FieldError::invalid(path, &**key, message));
+        }
+        total_size += key.len() + value.len();
+    }
+    if total_size > TOTAL_ANNOTATION_SIZE_LIMIT_B {
+        errors.push(FieldError::too_long(path, TOTAL_ANNOTATION_SIZE_LIMIT_B));
+    }
+}
+
+// Label selectors
+
+/// Ref: <https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/validation/validation.go>
+fn validate_label_selector(selector: &LabelSelector, path: FieldPath<LabelSelector>, errors: &mut std::vec::Vec<FieldError>) {
+    for (i, requirement) in selector.match_expressions.iter().flatten().enumerate() {
+        let path = path.clone().match_expressions().at(i);
+
+        let has_values = requirement.values.as_ref().is_some_and(|values| !values.is_empty());
+        match &*requirement.operator {
+            "In" | "NotIn" => if !has_values {
+                errors.push(FieldError::required(&path.clone().values(), "must be specified when `operator` is 'In' or 'NotIn'"));
+            },
+
+            "Exists" | "DoesNotExist" => if has_values {
+                errors.push(FieldError::forbidden(&path.clone().values(), "may not be specified when `operator` is 'Exists' or 'DoesNotExist'"));
+            },
+
+            operator => errors.push(FieldError::invalid(&path.clone().operator(), operator, "not a valid selector operator")),
+        }
+
+        for message in is_qualified_name(&requirement.key) {
+            errors.push(FieldError::invalid(&path.clone().key(), &*requirement.key, message));
+        }
+    }
+
+    validate_labels(selector.match_labels.as_ref(), &path.match_labels(), errors);
+}
+
+/// Returns whether the label selector matches the given labels.
+fn label_selector_matches(selector: &LabelSelector, labels: Option<&std::collections::BTreeMap<std::string::String, std::string::String>>) -> bool {
+    let get = |key: &str| labels.and_then(|labels| labels.get(key));
+
+    let match_labels = selector.match_labels.iter().flatten().all(|(key, value)| get(key) == Some(value));
+
+    let match_expressions = selector.match_expressions.iter().flatten().all(|requirement| {
+        let value = get(&requirement.key);
+        let in_values = || value.is_some_and(|value| requirement.values.iter().flatten().any(|v| v == value));
+        match &*requirement.operator {
+            "In" => in_values(),
+            "NotIn" => !in_values(),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    });
+
+    match_labels && match_expressions
+}
+
+fn label_selector_is_empty(selector: &LabelSelector) -> bool {
+    selector.match_labels.as_ref().is_none_or(std::collections::BTreeMap::is_empty) &&
+    selector.match_expressions.as_ref().is_none_or(std::vec::Vec::is_empty)
+}
+
+/// Validates the selector of a workload, and that it matches the labels of the workload's pod template.
+fn validate_workload_selector(
+    selector: &LabelSelector,
+    template: &crate::api::core::v1::PodTemplateSpec,
+    kind: &str,
+    selector_path: FieldPath<LabelSelector>,
+    template_path: FieldPath<crate::api::core::v1::PodTemplateSpec>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if label_selector_is_empty(selector) {
+        errors.push(FieldError::invalid(&selector_path, json(selector), std::format!("empty selector is invalid for {kind}")));
+    }
+    else {
+        let labels = template.metadata.as_ref().and_then(|metadata| metadata.labels.as_ref());
+        if !label_selector_matches(selector, labels) {
+            errors.push(FieldError::invalid(&template_path.metadata().labels(), json(&labels), "`selector` does not match template `labels`"));
+        }
+    }
+
+    validate_label_selector(selector, selector_path, errors);
+}
+
+// Pods
+
+fn validate_pod_template_spec(
+    template: &crate::api::core::v1::PodTemplateSpec,
+    path: FieldPath<crate::api::core::v1::PodTemplateSpec>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if let Some(metadata) = &template.metadata {
+        validate_labels(metadata.labels.as_ref(), &path.clone().metadata().labels(), errors);
+        validate_annotations(metadata.annotations.as_ref(), &path.clone().metadata().annotations(), errors);
+    }
+
+    validate_pod_spec(&or_default(template.spec.as_ref()), &path.spec(), errors);
+}
+
+/// Validates that the pods of a workload that are not jobs restart always.
+fn validate_restart_policy_always(
+    template: &crate::api::core::v1::PodTemplateSpec,
+    path: FieldPath<crate::api::core::v1::PodTemplateSpec>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    let restart_policy = template.spec.as_ref().and_then(|spec| spec.restart_policy.as_deref()).unwrap_or("Always");
+    if restart_policy != "Always" {
+        errors.push(FieldError::not_supported(&path.spec().restart_policy(), restart_policy, &["Always"]));
+    }
+}
+
+fn validate_pod_spec(spec: &crate::api::core::v1::PodSpec, path: &FieldPath<crate::api::core::v1::PodSpec>, errors: &mut std::vec::Vec<FieldError>) {
+    let mut volume_names = std::collections::BTreeSet::new();
+    for (i, volume) in spec.volumes.iter().flatten().enumerate() {
+        let path = path.clone().volumes().at(i).name();
+        if volume.name.is_empty() {
+            errors.push(FieldError::required(&path, ""));
+            continue;
+        }
+        for message in is_dns1123_label(&volume.name) {
+            errors.push(FieldError::invalid(&path, &*volume.name, message));
+        }
+        if !volume_names.insert(&*volume.name) {
+            errors.push(FieldError::duplicate(&path, &*volume.name));
+        }
+    }
+
+    // The names of containers must be unique across all kinds of containers.
+    let mut container_names = std::collections::BTreeSet::new();
+
+    for (i, container) in spec.init_containers.iter().flatten().enumerate() {
+        validate_container(container, &volume_names, &mut container_names, &path.clone().init_containers().at(i), errors);
+    }
+
+    if spec.containers.is_empty() {
+        errors.push(FieldError::required(&path.clone().containers(), ""));
+    }
+    for (i, container) in spec.containers.iter().enumerate() {
+        validate_container(container, &volume_names, &mut container_names, &path.clone().containers().at(i), errors);
+    }
+
+    if let Some(restart_policy) = spec.restart_policy.as_deref() {
+        if !matches!(restart_policy, "Always" | "OnFailure" | "Never") {
+            errors.push(FieldError::not_supported(&path.clone().restart_policy(), restart_policy, &["Always", "OnFailure", "Never"]));
+        }
+    }
+
+    match spec.dns_policy.as_deref() {
+        None | Some("ClusterFirstWithHostNet" | "ClusterFirst" | "Default") => (),
+
+        Some("None") => if spec.dns_config.is_none() {
+            errors.push(FieldError::required(&path.clone().dns_config(), "must provide `dnsConfig` when `dnsPolicy` is None"));
+        },
+
+        Some(dns_policy) => errors.push(FieldError::not_supported(
+            &path.clone().dns_policy(),
+            dns_policy,
+            &["ClusterFirstWithHostNet", "ClusterFirst", "Default", "None"],
+        )),
+    }
+
+    validate_labels(spec.node_selector.as_ref(), &path.clone().node_selector(), errors);
+
+    for (value, path) in [(&spec.hostname, path.clone().hostname()), (&spec.subdomain, path.clone().subdomain())] {
+        if let Some(value) = value.as_deref().filter(|value| !value.is_empty()) {
+            for message in is_dns1123_label(value) {
+                errors.push(FieldError::invalid(&path, value, message));
+            }
+        }
+    }
+
+    for (value, path) in [(&spec.service_account_name, path.clone().service_account_name()), (&spec.priority_class_name, path.clone().priority_class_name())] {
+        if let Some(value) = value.as_deref().filter(|value| !value.is_empty()) {
+            for message in is_dns1123_subdomain(value) {
+                errors.push(FieldError::invalid(&path, value, message));
+            }
+        }
+    }
+
+    if let Some(active_deadline_seconds) = spec.active_deadline_seconds {
+        if !(1..=i64::from(i32::MAX)).contains(&active_deadline_seconds) {
+            errors.push(FieldError::invalid(&path.clone().active_deadline_seconds(), active_deadline_seconds, inclusive_range_error(1, i32::MAX.into())));
+        }
+    }
+
+    for (i, toleration) in spec.tolerations.iter().flatten().enumerate() {
+        validate_toleration(toleration, path.clone().tolerations().at(i), errors);
+    }
+}
+
+/// Validations of the containers of a pod that aren't applied to the pod templates of workload resources.
+fn validate_containers_only_for_pod(spec: &crate::api::core::v1::PodSpec, path: &FieldPath<crate::api::core::v1::PodSpec>, errors: &mut std::vec::Vec<FieldError>) {
+    let containers =
+        spec.containers.iter().enumerate().map(|(i, container)| (container, path.clone().containers().at(i)))
+        .chain(spec.init_containers.iter().flatten().enumerate().map(|(i, container)| (container, path.clone().init_containers().at(i))));
+    for (container, path) in containers {
+        if let Some(image) = container.image.as_deref() {
+            if image.trim() != image {
+                errors.push(FieldError::invalid(&path.image(), image, "must not have leading or trailing whitespace"));
+            }
+        }
+    }
+}
+
+fn validate_container(
+    container: &crate::api::core::v1::Container,
+    volume_names: &std::collections::BTreeSet<&str>,
+    container_names: &mut std::collections::BTreeSet<std::string::String>,
+    path: &FieldPath<crate::api::core::v1::Container>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if container.name.is_empty() {
+        errors.push(FieldError::required(&path.clone().name(), ""));
+    }
+    else {
+        for message in is_dns1123_label(&container.name) {
+            errors.push(FieldError::invalid(&path.clone().name(), &*container.name, message));
+        }
+        if !container_names.insert(container.name.clone()) {
+            errors.push(FieldError::duplicate(&path.clone().name(), &*container.name));
+        }
+    }
+
+    if container.image.as_deref().unwrap_or_default().is_empty() {
+        errors.push(FieldError::required(&path.clone().image(), ""));
+    }
+
+    let mut port_names = std::collections::BTreeSet::new();
+    for (i, port) in container.ports.iter().flatten().enumerate() {
+        let path = path.clone().ports().at(i);
+
+        if let Some(name) = port.name.as_deref().filter(|name| !name.is_empty()) {
+            for message in is_valid_port_name(name) {
+                errors.push(FieldError::invalid(&path.clone().name(), name, message));
+            }
+            if !port_names.insert(name) {
+                errors.push(FieldError::duplicate(&path.clone().name(), name));
+            }
+        }
+
+        for message in is_valid_port_num(port.container_port) {
+            errors.push(FieldError::invalid(&path.clone().container_port(), port.container_port, message));
+        }
+
+        if let Some(host_port) = port.host_port.filter(|&host_port| host_port != 0) {
+            for message in is_valid_port_num(host_port) {
+                errors.push(FieldError::invalid(&path.clone().host_port(), host_port, message));
+            }
+        }
+
+        if let Some(protocol) = port.protocol.as_deref() {
+            validate_protocol(protocol, &path.protocol(), errors);
+        }
+    }
+
+    for (i, env) in container.env.iter().flatten().enumerate() {
+        if env.name.is_empty() {
+            errors.push(FieldError::required(&path.clone().env().at(i).name(), ""));
+        }
+    }
+
+    let mut mount_paths = std::collections::BTreeSet::new();
+    for (i, volume_mount) in container.volume_mounts.iter().flatten().enumerate() {
+        let path = path.clone().volume_mounts().at(i);
+
+        if volume_mount.name.is_empty() {
+            errors.push(FieldError::required(&path.clone().name(), ""));
+        }
+        else if !volume_names.contains(&*volume_mount.name) {
+            errors.push(FieldError::not_found(&path.clone().name(), &*volume_mount.name));
+        }
+
+        if volume_mount.mount_path.is_empty() {
+            errors.push(FieldError::required(&path.mount_path(), ""));
+        }
+        else if !mount_paths.insert(&*volume_mount.mount_path) {
+            errors.push(FieldError::invalid(&path.mount_path(), &*volume_mount.mount_path, "must be unique"));
+        }
+    }
+
+    if let Some(image_pull_policy) = container.image_pull_policy.as_deref() {
+        if !matches!(image_pull_policy, "Always" | "IfNotPresent" | "Never") {
+            errors.push(FieldError::not_supported(&path.clone().image_pull_policy(), image_pull_policy, &["Always", "IfNotPresent", "Never"]));
+        }
+    }
+
+    if let Some(termination_message_policy) = container.termination_message_policy.as_deref() {
+        if !matches!(termination_message_policy, "File" | "FallbackToLogsOnError") {
+            errors.push(FieldError::not_supported(&path.clone().termination_message_policy(), termination_message_policy, &["File", "FallbackToLogsOnError"]));
+        }
+    }
+
+    for (probe, path, requires_one_success) in [
+        (&container.liveness_probe, path.clone().liveness_probe(), true),
+        (&container.readiness_probe, path.clone().readiness_probe(), false),
+        (&container.startup_probe, path.clone().startup_probe(), true),
+    ] {
+        if let Some(probe) = probe {
+            validate_probe(probe, requires_one_success, path, errors);
+        }
+    }
+}
+
+fn validate_probe(
+    probe: &crate::api::core::v1::Probe,
+    requires_one_success: bool,
+    path: FieldPath<crate::api::core::v1::Probe>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    match probe.union() {
+        Ok(Some(_)) => (),
+        Ok(None) => errors.push(FieldError::required(&path, "must specify a handler type")),
+        Err(_) => errors.push(FieldError::forbidden(&path, "may not specify more than 1 handler type")),
+    }
+
+    validate_nonnegative_field(probe.initial_delay_seconds, &path.clone().initial_delay_seconds(), errors);
+    validate_nonnegative_field(probe.timeout_seconds, &path.clone().timeout_seconds(), errors);
+    validate_nonnegative_field(probe.period_seconds, &path.clone().period_seconds(), errors);
+    validate_nonnegative_field(probe.success_threshold, &path.clone().success_threshold(), errors);
+    validate_nonnegative_field(probe.failure_threshold, &path.clone().failure_threshold(), errors);
+
+    if let Some(success_threshold) = probe.success_threshold {
+        if requires_one_success && success_threshold != 1 {
+            errors.push(FieldError::invalid(&path.success_threshold(), success_threshold, "must be 1"));
+        }
+    }
+}
+
+fn validate_toleration(toleration: &crate::api::core::v1::Toleration, path: FieldPath<crate::api::core::v1::Toleration>, errors: &mut std::vec::Vec<FieldError>) {
+    let key = toleration.key.as_deref().unwrap_or_default();
+    let operator = toleration.operator.as_deref().unwrap_or_default();
+    let value = toleration.value.as_deref().unwrap_or_default();
+    let effect = toleration.effect.as_deref().unwrap_or_default();
+
+    if key.is_empty() {
+        if operator != "Exists" {
+            errors.push(FieldError::invalid(&path.clone().operator(), operator, "operator must be Exists when `key` is empty, which means \"match all values and all keys\""));
+        }
+    }
+    else {
+        for message in is_qualified_name(key) {
+            errors.push(FieldError::invalid(&path.clone().key(), key, message));
+        }
+    }
+
+    if toleration.toleration_seconds.is_some() && effect != "NoExecute" {
+        errors.push(FieldError::invalid(&path.clone().effect(), effect, "effect must be 'NoExecute' when `tolerationSeconds` is set"));
+    }
+
+    match operator {
+        "" | "Equal" => for message in is_valid_label_value(value) {
+            errors.push(FieldError::invalid(&path.clone().operator(), value, message));
+        },
+
+        "Exists" => if !value.is_empty() {
+            errors.push(FieldError::invalid(&path.clone().operator(), value, "value must be empty when `operator` is 'Exists'"));
+        },
+
+        operator => errors.push(FieldError::not_supported(&path.clone().operator(), operator, &["Equal", "Exists"])),
+    }
+
+    if !effect.is_empty() && !matches!(effect, "NoSchedule" | "PreferNoSchedule" | "NoExecute") {
+        errors.push(FieldError::not_supported(&path.effect(), effect, &["NoSchedule", "PreferNoSchedule", "NoExecute"]));
+    }
+}
+
+fn validate_protocol(protocol: &str, path: &FieldPath<std::string::String>, errors: &mut std::vec::Vec<FieldError>) {
+    if !matches!(protocol, "SCTP" | "TCP" | "UDP") {
+        errors.push(FieldError::not_supported(path, protocol, &["SCTP", "TCP", "UDP"]));
+    }
+}
+
+fn validate_port_num_or_name(
+    port: &crate::apimachinery::pkg::util::intstr::IntOrString,
+    path: &FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    match port {
+        crate::apimachinery::pkg::util::intstr::IntOrString::Int(port) => for message in is_valid_port_num(*port) {
+            errors.push(FieldError::invalid(path, *port, message));
+        },
+
+        crate::apimachinery::pkg::util::intstr::IntOrString::String(port) => for message in is_valid_port_name(port) {
+            errors.push(FieldError::invalid(path, &**port, message));
+        },
+    }
+}
+
+// Jobs
+
+fn validate_job_spec(spec: &crate::api::batch::v1::JobSpec, path: FieldPath<crate::api::batch::v1::JobSpec>, errors: &mut std::vec::Vec<FieldError>) {
+    validate_nonnegative_field(spec.parallelism, &path.clone().parallelism(), errors);
+    validate_nonnegative_field(spec.completions, &path.clone().completions(), errors);
+    validate_nonnegative_field(spec.active_deadline_seconds, &path.clone().active_deadline_seconds(), errors);
+    validate_nonnegative_field(spec.backoff_limit, &path.clone().backoff_limit(), errors);
+    validate_nonnegative_field(spec.ttl_seconds_after_finished, &path.clone().ttl_seconds_after_finished(), errors);
+
+    let completion_mode = spec.completion_mode.as_deref().unwrap_or("NonIndexed");
+    match completion_mode {
+        "NonIndexed" => if let Some(backoff_limit_per_index) = spec.backoff_limit_per_index {
+            errors.push(FieldError::invalid(&path.clone().backoff_limit_per_index(), backoff_limit_per_index, "requires indexed completion mode"));
+        },
+
+        "Indexed" => if spec.completions.is_none() {
+            errors.push(FieldError::required(&path.clone().completions(), "when completion mode is Indexed"));
+        },
+
+        completion_mode => errors.push(FieldError::not_supported(&path.clone().completion_mode(), completion_mode, &["NonIndexed", "Indexed"])),
+    }
+
+    // The API server generates a selector that matches the labels it adds to the pod template, unless the job sets `manualSelector`.
+    if let Some(selector) = &spec.selector {
+        validate_label_selector(selector, path.clone().selector(), errors);
+        let labels = spec.template.metadata.as_ref().and_then(|metadata| metadata.labels.as_ref());
+        if !label_selector_is_empty(selector) && !label_selector_matches(selector, labels) {
+            errors.push(FieldError::invalid(&path.clone().template().metadata().labels(), json(&labels), "`selector` does not match template `labels`"));
+        }
+    }
+
+    validate_pod_template_spec(&spec.template, path.clone().template(), errors);
+
+    let restart_policy = spec.template.spec.as_ref().and_then(|spec| spec.restart_policy.as_deref()).unwrap_or("Always");
+    if !matches!(restart_policy, "OnFailure" | "Never") {
+        errors.push(FieldError::not_supported(&path.template().spec().restart_policy(), restart_policy, &["OnFailure", "Never"]));
+    }
+}
+
+/// Validates a cron schedule in the standard format of five fields, or one of the predefined schedules like `@daily`.
+/// Returns the same message as the cron parser of the API server for schedules with the wrong number of fields.
+fn validate_schedule(schedule: &str) -> Result<(), std::string::String> {
+    if let Some(descriptor) = schedule.strip_prefix('@') {
+        return match descriptor {
+            "yearly" | "annually" | "monthly" | "weekly" | "daily" | "midnight" | "hourly" => Ok(()),
+            descriptor if descriptor.starts_with("every ") => Ok(()),
+            _ => Err(std::format!("unrecognized descriptor: {schedule}")),
+        };
+    }
+
+    let fields: std::vec::Vec<_> = schedule.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Err(std::format!("expected exactly 5 fields, found {}: [{}]", fields.len(), fields.join(" ")));
+    }
+
+    Ok(())
+}
+
+// Ingresses
+
+fn validate_host(host: &str, path: &FieldPath<std::string::String>, errors: &mut std::vec::Vec<FieldError>) {
+    let messages = if host.contains('*') { is_wildcard_dns1123_subdomain(host) } else { is_dns1123_subdomain(host) };
+    for message in messages {
+        errors.push(FieldError::invalid(path, host, message));
+    }
+}
+
+fn validate_http_ingress_path(
+    http_ingress_path: &crate::api::networking::v1::HTTPIngressPath,
+    path: FieldPath<crate::api::networking::v1::HTTPIngressPath>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    let value = http_ingress_path.path.as_deref().unwrap_or_default();
+
+    match &*http_ingress_path.path_type {
+        "" => errors.push(FieldError::required(&path.clone().path_type(), "pathType must be specified")),
+
+        "Exact" | "Prefix" => {
+            if !value.starts_with('/') {
+                errors.push(FieldError::invalid(&path.clone().path(), value, "must be an absolute path"));
+            }
+            if !value.is_empty() {
+                for invalid_sequence in ["//", "/./", "/../", "%2f", "%2F"] {
+                    if value.contains(invalid_sequence) {
+                        errors.push(FieldError::invalid(&path.clone().path(), value, std::format!("must not contain '{invalid_sequence}'")));
+                    }
+                }
+                for invalid_suffix in ["/..", "/."] {
+                    if value.ends_with(invalid_suffix) {
+                        errors.push(FieldError::invalid(&path.clone().path(), value, std::format!("cannot end with '{invalid_suffix}'")));
+                    }
+                }
+            }
+        },
+
+        "ImplementationSpecific" => if !value.is_empty() && !value.starts_with('/') {
+            errors.push(FieldError::invalid(&path.clone().path(), value, "must be an absolute path"));
+        },
+
+        path_type => errors.push(FieldError::not_supported(&path.clone().path_type(), path_type, &["Exact", "ImplementationSpecific", "Prefix"])),
+    }
+
+    validate_ingress_backend(&http_ingress_path.backend, &path.backend(), errors);
+}
+
+fn validate_ingress_backend(
+    backend: &crate::api::networking::v1::IngressBackend,
+    path: &FieldPath<crate::api::networking::v1::IngressBackend>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    match (&backend.resource, &backend.service) {
+        (Some(_), Some(_)) => errors.push(FieldError::invalid(path, "", "cannot set both resource and service backends")),
+
+        (Some(resource), None) => {
+            if resource.kind.is_empty() {
+                errors.push(FieldError::required(&path.clone().resource().kind(), ""));
+            }
+            if resource.name.is_empty() {
+                errors.push(FieldError::required(&path.clone().resource().name(), ""));
+            }
+        },
+
+        (None, Some(service)) => {
+            let path = path.clone().service();
+
+            if service.name.is_empty() {
+                errors.push(FieldError::required(&path.clone().name(), ""));
+            }
+            else {
+                for message in name_is_dns1035_label(&service.name, false) {
+                    errors.push(FieldError::invalid(&path.clone().name(), &*service.name, message));
+                }
+            }
+
+            let port_name = service.port.as_ref().and_then(|port| port.name.as_deref()).filter(|name| !name.is_empty());
+            let port_number = service.port.as_ref().and_then(|port| port.number).filter(|&number| number != 0);
+            match (port_name, port_number) {
+                (Some(_), Some(_)) => errors.push(FieldError::invalid(&path.port(), "", "cannot set both port name & port number")),
+
+                (Some(port_name), None) => for message in is_valid_port_name(port_name) {
+                    errors.push(FieldError::invalid(&path.clone().port().name(), port_name, message));
+                },
+
+                (None, Some(port_number)) => for message in is_valid_port_num(port_number) {
+                    errors.push(FieldError::invalid(&path.clone().port().number(), port_number, message));
+                },
+
+                (None, None) => errors.push(FieldError::required(&path.port(), "port name or number is required")),
+            }
+        },
+
+        (None, None) => errors.push(FieldError::invalid(path, "", "resource or service backend is required")),
+    }
+}
+
+// RBAC
+
+fn validate_rbac_name(name: &str, _prefix: bool) -> std::vec::Vec<std::string::String> {
+    // Ref: https://github.com/kubernetes/apimachinery/blob/master/pkg/api/validation/path/name.go
+
+    for illegal_name in [".", ".."] {
+        if name == illegal_name {
+            return std::vec![std::format!("may not be '{illegal_name}'")];
+        }
+    }
+
+    ["/", "%"].into_iter()
+        .filter(|illegal_content| name.contains(illegal_content))
+        .map(|illegal_content| std::format!("may not contain '{illegal_content}'"))
+        .collect()
+}
+
+fn validate_policy_rule(
+    rule: &crate::api::rbac::v1::PolicyRule,
+    namespaced: bool,
+    path: FieldPath<crate::api::rbac::v1::PolicyRule>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if rule.verbs.is_empty() {
+        errors.push(FieldError::required(&path.clone().verbs(), "verbs must contain at least one value"));
+    }
+
+    let non_resource_urls = rule.non_resource_urls.as_deref().unwrap_or_default();
+    let api_groups = rule.api_groups.as_deref().unwrap_or_default();
+    let resources = rule.resources.as_deref().unwrap_or_default();
+    let resource_names = rule.resource_names.as_deref().unwrap_or_default();
+
+    if !non_resource_urls.is_empty() {
+        if namespaced {
+            errors.push(FieldError::invalid(&path.clone().non_resource_urls(), non_resource_urls, "namespaced rules cannot apply to non-resource URLs"));
+        }
+        if !api_groups.is_empty() || !resources.is_empty() || !resource_names.is_empty() {
+            errors.push(FieldError::invalid(&path.non_resource_urls(), non_resource_urls, "rules cannot apply to both regular resources and non-resource URLs"));
+        }
+        return;
+    }
+
+    if api_groups.is_empty() {
+        errors.push(FieldError::required(&path.clone().api_groups(), "resource rules must supply at least one api group"));
+    }
+    if resources.is_empty() {
+        errors.push(FieldError::required(&path.resources(), "resource rules must supply at least one resource"));
+    }
+}
+
+fn validate_role_ref(
+    role_ref: &crate::api::rbac::v1::RoleRef,
+    supported_kinds: &[&str],
+    path: FieldPath<crate::api::rbac::v1::RoleRef>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if role_ref.api_group != "rbac.authorization.k8s.io" {
+        errors.push(FieldError::not_supported(&path.clone().api_group(), &*role_ref.api_group, &["rbac.authorization.k8s.io"]));
+    }
+
+    if !supported_kinds.contains(&&*role_ref.kind) {
+        errors.push(FieldError::not_supported(&path.clone().kind(), &*role_ref.kind, supported_kinds));
+    }
+
+    if role_ref.name.is_empty() {
+        errors.push(FieldError::required(&path.name(), ""));
+    }
+    else {
+        for message in validate_rbac_name(&role_ref.name, false) {
+            errors.push(FieldError::invalid(&path.clone().name(), &*role_ref.name, message));
+        }
+    }
+}
+
+fn validate_role_binding_subject(
+    subject: &crate::api::rbac::v1::Subject,
+    namespaced: bool,
+    path: FieldPath<crate::api::rbac::v1::Subject>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if subject.name.is_empty() {
+        errors.push(FieldError::required(&path.clone().name(), ""));
+    }
+
+    let api_group = subject.api_group.as_deref().unwrap_or_default();
+
+    match &*subject.kind {
+        "ServiceAccount" => {
+            if !subject.name.is_empty() {
+                for message in is_dns1123_subdomain(&subject.name) {
+                    errors.push(FieldError::invalid(&path.clone().name(), &*subject.name, message));
+                }
+            }
+            if !api_group.is_empty() {
+                errors.push(FieldError::not_supported(&path.clone().api_group(), api_group, &[""]));
+            }
+            if !namespaced && subject.namespace.as_deref().unwrap_or_default().is_empty() {
+                errors.push(FieldError::required(&path.namespace(), ""));
+            }
+        },
+
+        "User" | "Group" => if api_group != "rbac.authorization.k8s.io" {
+            errors.push(FieldError::not_supported(&path.api_group(), api_group, &["rbac.authorization.k8s.io"]));
+        },
+
+        kind => errors.push(FieldError::not_supported(&path.kind(), kind, &["ServiceAccount", "User", "Group"])),
+    }
+}
+
+// Numbers
+
+fn validate_nonnegative_field<T>(value: Option<T>, path: &FieldPath<T>, errors: &mut std::vec::Vec<FieldError>)
+where
+    T: Copy + Default + PartialOrd + Into<serde_json::Value>,
+{
+    if let Some(value) = value {
+        if value < T::default() {
+            errors.push(FieldError::invalid(path, value, "must be greater than or equal to 0"));
+        }
+    }
+}
+
+/// The path of an optional field refers to the type of its value, so it is independent of the type of the values that are compared.
+fn validate_immutable_field<T, P>(value: &T, old_value: &T, path: &FieldPath<P>, errors: &mut std::vec::Vec<FieldError>) where T: PartialEq + serde::Serialize {
+    if value != old_value {
+        errors.push(FieldError::invalid(path, json(value), FIELD_IMMUTABLE_ERROR_MSG));
+    }
+}
+
+/// Returns the value of an integer or a percentage like `"25%"`, or `None` if it is unset or not a valid percentage.
+fn int_or_percent_value(value: Option<&crate::apimachinery::pkg::util::intstr::IntOrString>) -> Option<i32> {
+    match value? {
+        crate::apimachinery::pkg::util::intstr::IntOrString::Int(value) => Some(*value),
+        crate::apimachinery::pkg::util::intstr::IntOrString::String(value) => value.strip_suffix('%')?.parse().ok(),
+    }
+}
+
+fn validate_positive_int_or_percent(
+    value: Option<&crate::apimachinery::pkg::util::intstr::IntOrString>,
+    path: &FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    match value {
+        Some(crate::apimachinery::pkg::util::intstr::IntOrString::Int(value)) if *value < 0 =>
+            errors.push(FieldError::invalid(path, *value, "must be greater than or equal to 0")),
+
+        None | Some(crate::apimachinery::pkg::util::intstr::IntOrString::Int(_)) => (),
+
+        Some(crate::apimachinery::pkg::util::intstr::IntOrString::String(value)) => {
+            let is_valid_percent = value.strip_suffix('%').is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit()));
+            if !is_valid_percent {
+                errors.push(FieldError::invalid(
+                    path,
+                    &**value,
+                    regex_error("a valid percent string must be a numeric string followed by an ending '%'", "[0-9]+%", &["1%", "93%"]),
+                ));
+            }
+        },
+    }
+}
+
+fn validate_not_more_than_100_percent(
+    value: Option<&crate::apimachinery::pkg::util::intstr::IntOrString>,
+    path: &FieldPath<crate::apimachinery::pkg::util::intstr::IntOrString>,
+    errors: &mut std::vec::Vec<FieldError>,
+) {
+    if let Some(crate::apimachinery::pkg::util::intstr::IntOrString::String(percent)) = value {
+        if int_or_percent_value(value).is_some_and(|value| value > 100) {
+            errors.push(FieldError::invalid(path, &**percent, "must not be greater than 100%"));
+        }
+    }
+}
+
+fn inclusive_range_error(low: i64, high: i64) -> std::string::String {
+    std::format!("must be between {low} and {high}, inclusive")
+}
+
+// Names
+//
+// Ref: https://github.com/kubernetes/apimachinery/blob/master/pkg/util/validation/validation.go
+
+const DNS1123_LABEL_MAX_LENGTH: usize = 63;
+const DNS1123_SUBDOMAIN_MAX_LENGTH: usize = 253;
+const DNS1035_LABEL_MAX_LENGTH: usize = 63;
+const LABEL_VALUE_MAX_LENGTH: usize = 63;
+const QUALIFIED_NAME_MAX_LENGTH: usize = 63;
+const CONFIG_MAP_KEY_MAX_LENGTH: usize = 253;
+
+const DNS1123_LABEL_FMT: &str = "[a-z0-9]([-a-z0-9]*[a-z0-9])?";
+const DNS1123_SUBDOMAIN_FMT: &str = "[a-z0-9]([-a-z0-9]*[a-z0-9])?(\\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*";
+const DNS1035_LABEL_FMT: &str = "[a-z]([-a-z0-9]*[a-z0-9])?";
+const QUALIFIED_NAME_FMT: &str = "([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9]";
+const LABEL_VALUE_FMT: &str = "(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?";
+const WILDCARD_DNS1123_SUBDOMAIN_FMT: &str = "\\*\\.[a-z0-9]([-a-z0-9]*[a-z0-9])?(\\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*";
+const CONFIG_MAP_KEY_FMT: &str = "[-._a-zA-Z0-9]+";
+
+fn regex_error(message: &str, fmt: &str, examples: &[&str]) -> std::string::String {
+    let mut result = std::string::String::from(message);
+    result.push_str(" (e.g. ");
+    for (i, example) in examples.iter().enumerate() {
+        if i > 0 {
+            result.push_str(" or ");
+        }
+        result.push('\'');
+        result.push_str(example);
+        result.push_str("', ");
+    }
+    result.push_str("regex used for validation is '");
+    result.push_str(fmt);
+    result.push_str("')");
+    result
+}
+
+fn max_len_error(length: usize) -> std::string::String {
+    std::format!("must be no more than {length} characters")
+}
+
+/// Whether the value matches `[a-z0-9]([-a-z0-9]*[a-z0-9])?`, or `[a-z]([-a-z0-9]*[a-z0-9])?` if `first_is_alphabetic`.
+fn is_label(value: &str, first_is_alphabetic: bool) -> bool {
+    let bytes = value.as_bytes();
+    let (Some(first), Some(last)) = (bytes.first(), bytes.last()) else {
+        return false;
+    };
+
+    (if first_is_alphabetic { first.is_ascii_lowercase() } else { first.is_ascii_lowercase() || first.is_ascii_digit() }) &&
+    (last.is_ascii_lowercase() || last.is_ascii_digit()) &&
+    bytes.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+}
+
+/// Whether the value matches `([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9]`.
+fn is_qualified_name_part(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let (Some(first), Some(last)) = (bytes.first(), bytes.last()) else {
+        return false;
+    };
+
+    first.is_ascii_alphanumeric() &&
+    last.is_ascii_alphanumeric() &&
+    bytes.iter().all(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'_' | b'.'))
+}
+
+fn is_dns1123_label(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > DNS1123_LABEL_MAX_LENGTH {
+        result.push(max_len_error(DNS1123_LABEL_MAX_LENGTH));
+    }
+    if !is_label(value, false) {
+        result.push(regex_error(
+            "a lowercase RFC 1123 label must consist of lower case alphanumeric characters or '-', and must start and end with an alphanumeric character",
+            DNS1123_LABEL_FMT,
+            &["my-name", "123-abc"],
+        ));
+    }
+    result
+}
+
+fn is_dns1123_subdomain(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > DNS1123_SUBDOMAIN_MAX_LENGTH {
+        result.push(max_len_error(DNS1123_SUBDOMAIN_MAX_LENGTH));
+    }
+    if !value.split('.').all(|label| is_label(label, false)) {
+        result.push(regex_error(
+            "a lowercase RFC 1123 subdomain must consist of lower case alphanumeric characters, '-' or '.', and must start and end with an alphanumeric character",
+            DNS1123_SUBDOMAIN_FMT,
+            &["example.com"],
+        ));
+    }
+    result
+}
+
+fn is_dns1035_label(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > DNS1035_LABEL_MAX_LENGTH {
+        result.push(max_len_error(DNS1035_LABEL_MAX_LENGTH));
+    }
+    if !is_label(value, true) {
+        result.push(regex_error(
+            "a DNS-1035 label must consist of lower case alphanumeric characters or '-', start with an alphabetic character, and end with an alphanumeric character",
+            DNS1035_LABEL_FMT,
+            &["my-name", "abc-123"],
+        ));
+    }
+    result
+}
+
+fn is_wildcard_dns1123_subdomain(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > DNS1123_SUBDOMAIN_MAX_LENGTH {
+        result.push(max_len_error(DNS1123_SUBDOMAIN_MAX_LENGTH));
+    }
+    if !value.strip_prefix("*.").is_some_and(|value| value.split('.').all(|label| is_label(label, false))) {
+        result.push(regex_error(
+            "a wildcard DNS-1123 subdomain must start with '*.', followed by a valid DNS subdomain, \
+             which must consist of lower case alphanumeric characters, '-' or '.' and end with an alphanumeric character",
+            WILDCARD_DNS1123_SUBDOMAIN_FMT,
+            &["*.example.com"],
+        ));
+    }
+    result
+}
+
+fn is_qualified_name(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+
+    let parts: std::vec::Vec<_> = value.split('/').collect();
+    let name = match &*parts {
+        [name] => *name,
+
+        [prefix, name] => {
+            if prefix.is_empty() {
+                result.push("prefix part must be non-empty".into());
+            }
+            else {
+                for message in is_dns1123_subdomain(prefix) {
+                    result.push(std::format!("prefix part {message}"));
+                }
+            }
+            *name
+        },
+
+        _ => {
+            result.push(regex_error(
+                "a qualified name must consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character \
+                 with an optional DNS subdomain prefix and '/'",
+                QUALIFIED_NAME_FMT,
+                &["MyName", "my.name", "123-abc"],
+            ));
+            return result;
+        },
+    };
+
+    if name.is_empty() {
+        result.push("name part must be non-empty".into());
+    }
+    else if name.len() > QUALIFIED_NAME_MAX_LENGTH {
+        result.push(std::format!("name part {}", max_len_error(QUALIFIED_NAME_MAX_LENGTH)));
+    }
+    if !is_qualified_name_part(name) {
+        result.push(std::format!("name part {}", regex_error(
+            "must consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character",
+            QUALIFIED_NAME_FMT,
+            &["MyName", "my.name", "123-abc"],
+        )));
+    }
+
+    result
+}
+
+fn is_valid_label_value(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > LABEL_VALUE_MAX_LENGTH {
+        result.push(max_len_error(LABEL_VALUE_MAX_LENGTH));
+    }
+    if !value.is_empty() && !is_qualified_name_part(value) {
+        result.push(regex_error(
+            "a valid label must be an empty string or consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character",
+            LABEL_VALUE_FMT,
+            &["MyValue", "my_value", "12345"],
+        ));
+    }
+    result
+}
+
+fn is_valid_port_num(port: i32) -> std::vec::Vec<std::string::String> {
+    if (1..=65535).contains(&port) {
+        std::vec![]
+    }
+    else {
+        std::vec![inclusive_range_error(1, 65535)]
+    }
+}
+
+/// Whether the value is a valid IANA service name, as used for the names of ports.
+fn is_valid_port_name(port: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if port.len() > 15 {
+        result.push(max_len_error(15));
+    }
+    if port.is_empty() || !port.bytes().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'-') {
+        result.push("must contain only alpha-numeric characters (a-z, 0-9), and hyphens (-)".into());
+    }
+    if !port.bytes().any(|c| c.is_ascii_lowercase()) {
+        result.push("must contain at least one letter (a-z)".into());
+    }
+    if port.contains("--") {
+        result.push("must not contain consecutive hyphens".into());
+    }
+    if port.starts_with('-') || port.ends_with('-') {
+        result.push("must not begin or end with a hyphen".into());
+    }
+    result
+}
+
+fn is_config_map_key(value: &str) -> std::vec::Vec<std::string::String> {
+    let mut result = std::vec![];
+    if value.len() > CONFIG_MAP_KEY_MAX_LENGTH {
+        result.push(max_len_error(CONFIG_MAP_KEY_MAX_LENGTH));
+    }
+    if value.is_empty() || !value.bytes().all(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_')) {
+        result.push(regex_error(
+            "a valid config key must consist of alphanumeric characters, '-', '_' or '.'",
+            CONFIG_MAP_KEY_FMT,
+            &["key.name", "KEY_NAME", "key-name"],
+        ));
+    }
+    if value == "." {
+        result.push("must not be '.'".into());
+    }
+    else if value == ".." {
+        result.push("must not be '..'".into());
+    }
+    else if value.starts_with("..") {
+        result.push("must not start with '..'".into());
+    }
+    result
+}
+
+/// A `generateName` is validated with its trailing `-` replaced, since the API server appends a suffix to it.
+fn mask_trailing_dash(name: &str, prefix: bool) -> std::borrow::Cow<'_, str> {
+    match name.strip_suffix('-') {
+        Some(rest) if prefix && !rest.is_empty() => std::format!("{rest}a").into(),
+        _ => name.into(),
+    }
+}
+
+fn name_is_dns_subdomain(name: &str, prefix: bool) -> std::vec::Vec<std::string::String> {
+    is_dns1123_subdomain(&mask_trailing_dash(name, prefix))
+}
+
+fn name_is_dns1035_label(name: &str, prefix: bool) -> std::vec::Vec<std::string::String> {
+    is_dns1035_label(&mask_trailing_dash(name, prefix))
+}
+
+// Helpers
+
+/// The value of an optional field, or its default if it is unset, like the zero value of a non-pointer field of the API server's types.
+fn or_default<T>(value: Option<&T>) -> std::borrow::Cow<'_, T> where T: Clone + Default {
+    value.map_or_else(|| std::borrow::Cow::Owned(T::default()), std::borrow::Cow::Borrowed)
+}
+
+fn json<T>(value: &T) -> serde_json::Value where T: serde::Serialize + ?Sized {
+    serde_json::to_value(value).unwrap_or_default()
+}
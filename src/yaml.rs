@@ -0,0 +1,330 @@
+//! Loading resources from YAML manifests.
+//!
+//! A manifest is a stream of YAML documents separated by `---` lines, as accepted by `kubectl apply -f`. Each document is deserialized
+//! according to its `apiVersion` and `kind` into the corresponding variant of [`AnyResource`](crate::AnyResource). Documents of types
+//! that are not known to this crate, such as custom resources, are deserialized as [`AnyResource::Other`](crate::AnyResource::Other).
+//!
+//! Documents that are lists, ie `kind: List` or any other kind ending in `List` that has an `items` field, are expanded into their items.
+//! Empty documents are skipped.
+//!
+//! [`from_str_with_field_validation`] also reports the unknown and duplicate fields of each resource, like `kubectl apply --validate=strict` does.
+//!
+//! ```rust,ignore
+//! let manifest = r#"
+//! apiVersion: v1
+//! kind: Namespace
+//! metadata:
+//!   name: foo
+//! ---
+//! apiVersion: apps/v1
+//! kind: Deployment
+//! metadata:
+//!   name: bar
+//!   namespace: foo
+//! "#;
+//!
+//! for resource in k8s_openapi::yaml::from_str(manifest)? {
+//!     match resource {
+//!         k8s_openapi::AnyResource::CoreV1Namespace(namespace) => { ... },
+//!         k8s_openapi::AnyResource::AppsV1Deployment(deployment) => { ... },
+//!         other => println!("{} {}", other.api_version(), other.kind()),
+//!     }
+//! }
+//! ```
+
+/// Deserializes all the resources in the given YAML manifest.
+pub fn from_str(s: &str) -> Result<std::vec::Vec<crate::AnyResource>, Error> {
+    Documents::new(s).collect()
+}
+
+/// Deserializes all the resources in the given YAML manifest, and validates their fields according to the given
+/// [`FieldValidation`](crate::field_validation::FieldValidation).
+///
+/// Each resource is returned with its unknown and duplicate fields. Their paths are relative to the resource.
+pub fn from_str_with_field_validation(
+    s: &str,
+    field_validation: crate::field_validation::FieldValidation,
+) -> Result<std::vec::Vec<(crate::AnyResource, std::vec::Vec<crate::field_validation::InvalidField>)>, Error> {
+    let mut documents = Documents::new(s);
+    core::iter::from_fn(|| documents.next_with_field_validation(field_validation)).collect()
+}
+
+/// An iterator of the resources in a YAML manifest.
+///
+/// Unlike [`from_str`], this iterator continues with the next document after a document fails to deserialize.
+pub struct Documents<'a> {
+    type_meta_deserializers: serde_yaml::Deserializer<'a>,
+    deserializers: serde_yaml::Deserializer<'a>,
+    document_index: usize,
+
+    /// Used to find the types of the items of list documents. It's only advanced when a list document is found,
+    /// so `list_type_meta_document_index` is the index of its next document.
+    list_type_meta_deserializers: serde_yaml::Deserializer<'a>,
+    list_type_meta_document_index: usize,
+
+    list_items: std::vec::IntoIter<(crate::AnyResource, std::vec::Vec<crate::field_validation::InvalidField>)>,
+}
+
+impl<'a> Documents<'a> {
+    /// Creates an iterator of the resources in the given YAML manifest.
+    pub fn new(s: &'a str) -> Self {
+        Documents {
+            type_meta_deserializers: serde_yaml::Deserializer::from_str(s),
+            deserializers: serde_yaml::Deserializer::from_str(s),
+            document_index: 0,
+            list_type_meta_deserializers: serde_yaml::Deserializer::from_str(s),
+            list_type_meta_document_index: 0,
+            list_items: std::vec![].into_iter(),
+        }
+    }
+}
+
+impl Documents<'_> {
+    fn next_with_field_validation(
+        &mut self,
+        field_validation: crate::field_validation::FieldValidation,
+    ) -> Option<Result<(crate::AnyResource, std::vec::Vec<crate::field_validation::InvalidField>), Error>> {
+        loop {
+            if let Some(item) = self.list_items.next() {
+                return Some(Ok(item));
+            }
+
+            // The document is parsed twice, first to find its type and then to deserialize it as that type.
+            let type_meta_document = self.type_meta_deserializers.next()?;
+            let document = self.deserializers.next()?;
+            let document_index = self.document_index;
+            self.document_index += 1;
+
+            let type_meta = match serde::Deserializer::deserialize_any(type_meta_document, TypeMetaVisitor) {
+                Ok(Some(type_meta)) => type_meta,
+                Ok(None) => continue,
+                Err(source) => return Some(Err(Error { document_index, source })),
+            };
+            let TypeMeta { api_version, kind, has_items } = type_meta;
+
+            if has_items && kind.ends_with("List") {
+                let list_type_meta_document = loop {
+                    let list_type_meta_document = self.list_type_meta_deserializers.next()?;
+                    self.list_type_meta_document_index += 1;
+                    if self.list_type_meta_document_index > document_index {
+                        break list_type_meta_document;
+                    }
+                };
+
+                match list_items(list_type_meta_document, document, field_validation) {
+                    Ok(items) => self.list_items = items.into_iter(),
+                    Err(source) => return Some(Err(Error { document_index, source })),
+                }
+            }
+            else {
+                return Some(
+                    crate::field_validation::deserialize_seed(AnyResourceSeed { api_version: &api_version, kind: &kind }, document, field_validation)
+                    .map_err(|source| Error { document_index, source }));
+            }
+        }
+    }
+}
+
+impl Iterator for Documents<'_> {
+    type Item = Result<crate::AnyResource, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next_with_field_validation(crate::field_validation::FieldValidation::Ignore)?;
+        Some(result.map(|(resource, _)| resource))
+    }
+}
+
+/// An error from deserializing a YAML manifest.
+#[derive(Debug)]
+pub struct Error {
+    /// The zero-based index of the document in the manifest that failed to deserialize.
+    pub document_index: usize,
+
+    /// The underlying error.
+    pub source: serde_yaml::Error,
+}
+
+impl Error {
+    /// The one-based line number in the manifest where the error occurred, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.source.location().map(|location| location.line())
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "document {}: {}", self.document_index, self.source)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The fields of a document that determine how it is deserialized.
+struct TypeMeta {
+    api_version: std::string::String,
+    kind: std::string::String,
+    has_items: bool,
+}
+
+/// Deserializes the [`TypeMeta`] of a document, or `None` if the document is empty.
+struct TypeMetaVisitor;
+
+impl<'de> serde::de::Visitor<'de> for TypeMetaVisitor {
+    type Value = Option<TypeMeta>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a Kubernetes resource")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        Ok(None)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        Ok(None)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+        let mut value_api_version = None;
+        let mut value_kind = None;
+        let mut has_items = false;
+
+        while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+            match &*key {
+                "apiVersion" => value_api_version = Some(serde::de::MapAccess::next_value(&mut map)?),
+                "kind" => value_kind = Some(serde::de::MapAccess::next_value(&mut map)?),
+                key => {
+                    has_items = has_items || key == "items";
+                    let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?;
+                },
+            }
+        }
+
+        Ok(Some(TypeMeta {
+            api_version: value_api_version.ok_or_else(|| serde::de::Error::missing_field("apiVersion"))?,
+            kind: value_kind.ok_or_else(|| serde::de::Error::missing_field("kind"))?,
+            has_items,
+        }))
+    }
+}
+
+/// Deserializes the items of a list document, and validates their fields according to the given `field_validation`.
+///
+/// The type of each item is only known after its `apiVersion` and `kind` have been parsed, so the types of all the items are found
+/// from one copy of the document first, and then the items are deserialized from the other copy.
+fn list_items(
+    type_meta_document: serde_yaml::Deserializer<'_>,
+    document: serde_yaml::Deserializer<'_>,
+    field_validation: crate::field_validation::FieldValidation,
+) -> Result<std::vec::Vec<(crate::AnyResource, std::vec::Vec<crate::field_validation::InvalidField>)>, serde_yaml::Error> {
+    let type_metas = serde::Deserializer::deserialize_map(type_meta_document, ListVisitor(|| ItemTypeMetaSeed))?;
+    let mut type_metas = type_metas.into_iter();
+    serde::Deserializer::deserialize_map(document, ListVisitor(|| ItemSeed { type_meta: type_metas.next(), field_validation }))
+}
+
+/// Deserializes the [`TypeMeta`] of an item of a list document.
+struct ItemTypeMetaSeed;
+
+impl<'de> serde::de::DeserializeSeed<'de> for ItemTypeMetaSeed {
+    type Value = TypeMeta;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        let type_meta = serde::Deserializer::deserialize_any(deserializer, TypeMetaVisitor)?;
+        type_meta.ok_or_else(|| serde::de::Error::invalid_type(serde::de::Unexpected::Unit, &TypeMetaVisitor))
+    }
+}
+
+/// Deserializes an item of a list document with the given [`TypeMeta`], and validates its fields according to the given `field_validation`.
+struct ItemSeed {
+    type_meta: Option<TypeMeta>,
+    field_validation: crate::field_validation::FieldValidation,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for ItemSeed {
+    type Value = (crate::AnyResource, std::vec::Vec<crate::field_validation::InvalidField>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        // Both copies of the document have the same items, so this is only `None` if the list has more items than it had before.
+        let TypeMeta { api_version, kind, .. } = self.type_meta.ok_or_else(|| serde::de::Error::custom("list has more items than expected"))?;
+        crate::field_validation::deserialize_seed(AnyResourceSeed { api_version: &api_version, kind: &kind }, deserializer, self.field_validation)
+    }
+}
+
+/// Deserializes an [`AnyResource`](crate::AnyResource) of the given type.
+struct AnyResourceSeed<'a> {
+    api_version: &'a str,
+    kind: &'a str,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for AnyResourceSeed<'_> {
+    type Value = crate::AnyResource;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        crate::AnyResource::deserialize_with_type(self.api_version, self.kind, deserializer)
+    }
+}
+
+/// Deserializes the `items` field of a list document with the seeds returned by the given function, ignoring all other fields.
+struct ListVisitor<F>(F);
+
+impl<'de, F, S> serde::de::Visitor<'de> for ListVisitor<F> where F: FnMut() -> S, S: serde::de::DeserializeSeed<'de> {
+    type Value = std::vec::Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a list of Kubernetes resources")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+        let mut items = std::vec![];
+
+        while let Some(key) = serde::de::MapAccess::next_key::<std::string::String>(&mut map)? {
+            if key == "items" {
+                items = serde::de::MapAccess::next_value_seed(&mut map, ItemsSeed(&mut self.0))?;
+            }
+            else {
+                let _: serde::de::IgnoredAny = serde::de::MapAccess::next_value(&mut map)?;
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+/// Deserializes the `items` field of a list document with the seeds returned by the given function. A `null` value is treated as an empty list.
+struct ItemsSeed<'a, F>(&'a mut F);
+
+impl<'de, F, S> serde::de::DeserializeSeed<'de> for ItemsSeed<'_, F> where F: FnMut() -> S, S: serde::de::DeserializeSeed<'de> {
+    type Value = std::vec::Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        serde::Deserializer::deserialize_option(deserializer, self)
+    }
+}
+
+impl<'de, F, S> serde::de::Visitor<'de> for ItemsSeed<'_, F> where F: FnMut() -> S, S: serde::de::DeserializeSeed<'de> {
+    type Value = std::vec::Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a list of Kubernetes resources")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        Ok(std::vec![])
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        serde::Deserializer::deserialize_seq(deserializer, self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        let mut items = std::vec![];
+        while let Some(item) = serde::de::SeqAccess::next_element_seed(&mut seq, (self.0)())? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+}